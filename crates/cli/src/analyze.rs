@@ -16,7 +16,7 @@ use marzano_language::target_language::expand_paths;
 use indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget, ProgressStyle};
 #[allow(unused_imports)]
 use marzano_core::built_in_functions::BuiltIns;
-use marzano_core::pattern_compiler::{src_to_problem_libs, CompilationResult};
+use marzano_core::pattern_compiler::{src_to_problem_libs_with_variables, CompilationResult};
 use marzano_core::{
     api::{AnalysisLog, MatchResult},
     problem::Problem,
@@ -39,13 +39,14 @@ use crate::ux::CheckResult;
 use marzano_messenger::emit::{ApplyDetails, Messager};
 
 impl<'b> RichPattern<'b> {
-    #[instrument(skip(self, pattern_libs, targets))]
+    #[instrument(skip(self, pattern_libs, targets, variables))]
     pub fn compile(
         &self,
         pattern_libs: &BTreeMap<String, String>,
         language: Option<PatternLanguage>,
         targets: Option<Vec<FileRange>>,
         injected_limit: Option<usize>,
+        variables: &BTreeMap<String, serde_json::Value>,
     ) -> Result<CompilationResult> {
         let lang = language.unwrap_or_default();
         #[cfg(not(feature = "ai_builtins"))]
@@ -54,7 +55,7 @@ impl<'b> RichPattern<'b> {
         #[cfg(feature = "ai_builtins")]
         let injected_builtins = Some(ai_builtins::ai_builtins::get_ai_built_in_functions());
 
-        src_to_problem_libs(
+        src_to_problem_libs_with_variables(
             self.body.to_owned(),
             pattern_libs,
             lang.try_into().unwrap(),
@@ -62,6 +63,7 @@ impl<'b> RichPattern<'b> {
             targets,
             injected_builtins,
             injected_limit,
+            variables,
         )
     }
 }
@@ -194,10 +196,16 @@ where
         ApplyInput::Disk(ref my_input) => {
             let (file_paths_tx, file_paths_rx) = channel();
 
+            let languages: Vec<PatternLanguage> =
+                compiled.languages().map(PatternLanguage::from).collect();
             let file_walker = emit_error!(
                 owned_emitter,
                 &arg.visibility,
-                expand_paths(&my_input.paths, Some(&[(&compiled.language).into()]))
+                expand_paths(
+                    &my_input.paths,
+                    Some(&languages),
+                    &compiled.language_overrides
+                )
             );
 
             for file in file_walker {
@@ -205,19 +213,13 @@ where
                 if file.file_type().unwrap().is_dir() {
                     continue;
                 }
-                if !my_input.paths.contains(&file.path().to_path_buf()) {
-                    let ext = file
-                        .path()
-                        .extension()
-                        .unwrap_or_default()
-                        .to_str()
-                        .unwrap_or_default();
-                    if !&compiled.language.match_extension(ext) {
-                        // only skip the file if it was discovered by the walker
-                        // don't skip if it was explicitly passed in as a path
-                        // https://github.com/getgrit/gritql/issues/485
-                        continue;
-                    }
+                if !my_input.paths.contains(&file.path().to_path_buf())
+                    && !compiled.match_path(file.path())
+                {
+                    // only skip the file if it was discovered by the walker
+                    // don't skip if it was explicitly passed in as a path
+                    // https://github.com/getgrit/gritql/issues/485
+                    continue;
                 }
                 file_paths_tx.send(file.path().to_path_buf()).unwrap();
             }
@@ -4,8 +4,9 @@ use clap::Args;
 use dialoguer::Confirm;
 
 use marzano_gritmodule::config::{init_config_from_path, init_global_grit_modules};
-use marzano_gritmodule::resolver::get_grit_files_from_known_grit_dir;
+use marzano_gritmodule::resolver::{find_pattern_config, get_grit_files_from_known_grit_dir};
 use marzano_util::rich_path::RichFile;
+use marzano_util::runtime::ErrorTolerance;
 use tracing::instrument;
 #[cfg(feature = "grit_tracing")]
 use tracing::span;
@@ -18,9 +19,12 @@ use grit_util::Position;
 use indicatif::MultiProgress;
 use marzano_core::api::{AllDone, AllDoneReason, AnalysisLog, MatchResult};
 use marzano_core::pattern_compiler::CompilationResult;
+use marzano_gritmodule::api::read_language_overrides;
 use marzano_gritmodule::fetcher::KeepFetcherKind;
 use marzano_gritmodule::markdown::get_body_from_md_content;
-use marzano_gritmodule::searcher::{find_global_grit_dir, find_grit_modules_dir};
+use marzano_gritmodule::searcher::{
+    find_global_grit_dir, find_grit_dir_from, find_grit_modules_dir,
+};
 use marzano_gritmodule::utils::{infer_pattern, is_pattern_name, parse_remote_name};
 use marzano_language::target_language::PatternLanguage;
 use marzano_messenger::emit::FlushableMessenger as _;
@@ -49,6 +53,7 @@ use crate::resolver::{get_grit_files_from_flags_or_cwd, GritModuleResolver};
 use crate::utils::has_uncommitted_changes;
 
 use super::filters::SharedFilterArgs;
+use super::variables::{extract_variables, SharedVariableArgs};
 
 /// Apply a pattern to a set of paths on disk which will be rewritten in place
 #[derive(Deserialize)]
@@ -161,6 +166,14 @@ pub struct ApplyPatternArgs {
     /// Change the default language to use for the pattern (if unset, JavaScript is used by default)
     #[clap(long = "language", alias = "lang")]
     pub language: Option<PatternLanguage>,
+    /// How to handle files with syntax errors: `skip` them, only match code `outside` of the errors, or match `all` code
+    #[clap(long = "error-tolerance", default_value_t = ErrorTolerance::All)]
+    pub error_tolerance: ErrorTolerance,
+    /// Seed the random number generator used by `random` and `shuffle`, for reproducible results
+    #[clap(long = "seed")]
+    pub seed: Option<u64>,
+    #[clap(flatten)]
+    pub variables: SharedVariableArgs,
 }
 
 impl Default for ApplyPatternArgs {
@@ -179,6 +192,9 @@ impl Default for ApplyPatternArgs {
             ai: Default::default(),
             language: Default::default(),
             stdin: Default::default(),
+            error_tolerance: Default::default(),
+            seed: Default::default(),
+            variables: Default::default(),
         }
     }
 }
@@ -214,6 +230,8 @@ pub(crate) async fn run_apply_pattern(
         .unwrap()
         .get_context()
         .unwrap();
+    context.error_tolerance = arg.error_tolerance;
+    context.seed = arg.seed;
 
     let format = OutputFormat::from_flags(
         format_flags,
@@ -291,6 +309,7 @@ pub(crate) async fn run_apply_pattern(
         emitter,
         extract_filter_ranges(&shared, current_repo_root.as_ref())
     );
+    let mut variables = flushable_unwrap!(emitter, extract_variables(&arg.variables));
 
     #[cfg(feature = "grit_tracing")]
     let span_libs = span!(tracing::Level::INFO, "prep_libs",).entered();
@@ -396,6 +415,28 @@ pub(crate) async fn run_apply_pattern(
 
         if let Some(named_pattern) = named_pattern {
             details.named_pattern = Some(named_pattern.to_string());
+            // Parameters and seed configured for the pattern, unless set on the command line
+            if parse_remote_name(&pattern).is_none() {
+                let config = match find_grit_dir_from(target_grit_dir.clone()).await {
+                    Some(grit_dir) => flushable_unwrap!(
+                        emitter,
+                        find_pattern_config(
+                            grit_dir.parent().unwrap_or(&target_grit_dir),
+                            named_pattern
+                        )
+                        .await
+                    ),
+                    None => None,
+                };
+                if let Some(config) = config {
+                    for (name, value) in config.params.unwrap_or_default() {
+                        variables.entry(name).or_insert(value);
+                    }
+                    if context.seed.is_none() {
+                        context.seed = config.meta.seed;
+                    }
+                }
+            }
         }
 
         if let Some(lang_option) = &default_lang {
@@ -493,9 +534,15 @@ pub(crate) async fn run_apply_pattern(
     span_libs.exit();
 
     let CompilationResult {
-        problem: compiled,
+        problem: mut compiled,
         compilation_warnings,
-    } = match pattern.compile(final_input.pattern_libs(), lang, filter_range, arg.limit) {
+    } = match pattern.compile(
+        final_input.pattern_libs(),
+        lang,
+        filter_range,
+        arg.limit,
+        &variables,
+    ) {
         Ok(c) => c,
         Err(e) => {
             let log = match e.downcast::<grit_util::AnalysisLog>() {
@@ -531,6 +578,15 @@ pub(crate) async fn run_apply_pattern(
             .unwrap();
     }
 
+    let overrides_dir = format_flags
+        .grit_dir
+        .as_ref()
+        .and_then(|c| c.parent())
+        .unwrap_or_else(|| &cwd)
+        .to_path_buf();
+    compiled.language_overrides =
+        flushable_unwrap!(emitter, read_language_overrides(overrides_dir).await);
+
     let warn_uncommitted = !arg.dry_run && !arg.force && has_uncommitted_changes(cwd.clone()).await;
     if warn_uncommitted && has_rewrite(&compiled.pattern, &compiled.definitions()) {
         let term = console::Term::stderr();
@@ -11,17 +11,25 @@ use marzano_core::{
     fs::apply_rewrite,
     problem::Problem,
 };
-use marzano_gritmodule::{config::ResolvedGritDefinition, utils::extract_path};
+use marzano_gritmodule::{
+    api::read_language_overrides,
+    config::{ParamsOverrideMatcher, ResolvedGritDefinition},
+    searcher::find_grit_dir_from,
+    utils::extract_path,
+};
 use marzano_language::target_language::{expand_paths, PatternLanguage};
 use marzano_messenger::emit::{FlushableMessenger as _, VisibilityLevels};
 use marzano_util::cache::GritCache;
 use marzano_util::rich_path::RichPath;
+use marzano_util::runtime::ErrorTolerance;
 use marzano_util::{finder::get_input_files, rich_path::RichFile};
 use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
 use serde::Serialize;
 use std::{
+    borrow::Cow,
     collections::{HashMap, HashSet},
-    path::PathBuf,
+    path::{Path, PathBuf},
+    sync::Arc,
 };
 use tokio::try_join;
 
@@ -45,6 +53,7 @@ use crate::{
 };
 
 use super::filters::{extract_filter_ranges, SharedFilterArgs};
+use super::variables::{extract_variables, variables_for_pattern, SharedVariableArgs};
 
 #[derive(Args, Serialize, Debug)]
 pub struct CheckArg {
@@ -69,8 +78,16 @@ pub struct CheckArg {
     /// Output annotations for a GitHub actions workflow
     #[clap(long = "github-actions")]
     pub github_actions: bool,
+    /// How to handle files with syntax errors: `skip` them, only match code `outside` of the errors, or match `all` code
+    #[clap(long = "error-tolerance", default_value_t = ErrorTolerance::All)]
+    pub error_tolerance: ErrorTolerance,
+    /// Seed the random number generator used by `random` and `shuffle`, for reproducible results
+    #[clap(long = "seed")]
+    pub seed: Option<u64>,
     #[clap(flatten)]
     pub shared_filters: SharedFilterArgs,
+    #[clap(flatten)]
+    pub variables: SharedVariableArgs,
 }
 
 pub(crate) async fn run_check(
@@ -84,9 +101,13 @@ pub(crate) async fn run_check(
         bail!("--github-actions is not compatible with --json");
     }
 
-    let context = Updater::from_current_bin().await?.get_context()?;
+    let mut context = Updater::from_current_bin().await?.get_context()?;
+    context.error_tolerance = arg.error_tolerance;
+    context.seed = arg.seed;
 
-    let (cache, manager) = cache_for_cwd(arg.refresh_cache, arg.no_cache).await?;
+    // Files skipped for their errors would be cached as having no matches
+    let no_cache = arg.no_cache || arg.error_tolerance != ErrorTolerance::All;
+    let (cache, manager) = cache_for_cwd(arg.refresh_cache, no_cache).await?;
 
     let paths = arg.paths;
     let ((resolved_patterns, _), grit_files) = if plumbing {
@@ -123,46 +144,80 @@ pub(crate) async fn run_check(
     };
 
     let filter_range = extract_filter_ranges(&arg.shared_filters, Some(&current_dir))?;
+    let language_overrides = read_language_overrides(current_dir.clone()).await?;
+    let variables = extract_variables(&arg.variables)?;
 
     // Construct a resolver
     let resolver = GritModuleResolver::new();
 
+    let repo_root = find_grit_dir_from(current_dir.clone())
+        .await
+        .and_then(|grit_dir| grit_dir.parent().map(Path::to_path_buf))
+        .unwrap_or_else(|| current_dir.clone());
+
     let mut pattern_lookup: HashMap<[u8; 32], &ResolvedGritDefinition> = HashMap::new();
-    let compile_tasks: Result<HashMap<String, Problem>, _> = enforced
-        .iter()
-        .map(|p| {
-            let body = format!("{}()", p.local_name);
-            let lang = PatternLanguage::get_language(&p.body);
-            let grit_files = grit_files.get_language_directory_or_default(lang)?;
-            let rich_pattern = resolver
-                .make_pattern(&body, Some(p.local_name.to_string()))
-                .unwrap();
-            let lang = PatternLanguage::get_language(&p.body);
-            match rich_pattern.compile(&grit_files, lang, filter_range.clone(), None) {
+    // Patterns are compiled once for their params, and once more for each of
+    // their overrides, which run on the files matching their globs instead
+    let mut problems: Vec<(Problem, Arc<ParamsOverrideMatcher>, Option<usize>)> = Vec::new();
+    for p in enforced.iter() {
+        let body = format!("{}()", p.local_name);
+        let lang = PatternLanguage::get_language(&p.body);
+        let grit_files = grit_files.get_language_directory_or_default(lang)?;
+        let rich_pattern = resolver
+            .make_pattern(&body, Some(p.local_name.to_string()))
+            .unwrap();
+        let lang = PatternLanguage::get_language(&p.body);
+        let matcher = Arc::new(ParamsOverrideMatcher::new(&p.config, &repo_root)?);
+        let scopes = std::iter::once(None).chain((0..p.config.param_overrides.len()).map(Some));
+        for scope in scopes {
+            // Parameters configured for the pattern, unless set on the command line
+            let mut pattern_variables = p.config.params_for(scope);
+            pattern_variables.extend(variables_for_pattern(&variables, &p.local_name));
+            match rich_pattern.compile(
+                &grit_files,
+                lang,
+                filter_range.clone(),
+                None,
+                &pattern_variables,
+            ) {
                 Ok(c) => {
-                    pattern_lookup.insert(c.problem.hash, p);
-                    Ok((p.local_name.clone(), c.problem))
+                    let mut problem = c.problem;
+                    problem.language_overrides = language_overrides.clone();
+                    pattern_lookup.insert(problem.hash, p);
+                    problems.push((problem, matcher.clone(), scope));
                 }
                 Err(e) => {
                     bail!("Unable to compile pattern {}:\n{}", p.local_name, e);
                 }
             }
-        })
-        .collect();
-    let compiled_map = compile_tasks?;
-    let problems: Vec<_> = compiled_map.values().collect();
+        }
+    }
+
+    // Patterns can pin the seed used by `random` and `shuffle`, unless it is set on the command line
+    let context_for = |problem: &Problem| match pattern_lookup
+        .get(&problem.hash)
+        .and_then(|p| p.config.meta.seed)
+        .filter(|_| context.seed.is_none())
+    {
+        Some(seed) => {
+            let mut seeded = context.clone();
+            seeded.seed = Some(seed);
+            Cow::Owned(seeded)
+        }
+        None => Cow::Borrowed(&context),
+    };
 
     let results: DashMap<[u8; 32], Vec<MatchResult>> = DashMap::new();
 
-    let target_languages: Vec<PatternLanguage> = problems
+    let target_languages: HashSet<PatternLanguage> = problems
         .iter()
-        .map(|problem| (&problem.language).into())
+        .flat_map(|(problem, _, _)| problem.languages().map(PatternLanguage::from))
         .collect();
 
     let found_files: DashMap<String, Vec<RichPath>> = DashMap::new();
 
     for language in target_languages {
-        let file_walker = expand_paths(&paths, Some(&[language]))?;
+        let file_walker = expand_paths(&paths, Some(&[language]), &language_overrides)?;
         let mut language_paths = Vec::new();
         for file in file_walker {
             let file = file?;
@@ -176,7 +231,7 @@ pub(crate) async fn run_check(
         found_files.insert(language.to_string(), input_files);
     }
 
-    let pg: ProgressBar = multi.add(ProgressBar::new(compiled_map.len().try_into()?));
+    let pg: ProgressBar = multi.add(ProgressBar::new(problems.len().try_into()?));
     let style = ProgressStyle::with_template(
         "\n{prefix:.bold.dim} {wide_msg:.bold.dim}\n{wide_bar} {pos:}/{len}",
     )
@@ -184,14 +239,19 @@ pub(crate) async fn run_check(
     pg.set_style(style);
     pg.set_prefix("Checking");
 
-    problems.par_iter().for_each(|pattern| {
+    problems.par_iter().for_each(|(pattern, matcher, scope)| {
         if let Some(name) = &pattern.name {
             pg.set_message(name.to_string());
         }
-        let language_files = match found_files.get(&pattern.language.to_string()) {
-            Some(files) => files,
-            None => return,
-        };
+        // a pattern with language variants runs over the files of each of them
+        let mut seen = HashSet::new();
+        let language_files: Vec<RichPath> = pattern
+            .languages()
+            .filter_map(|language| found_files.get(&PatternLanguage::from(language).to_string()))
+            .flat_map(|files| files.value().clone())
+            .filter(|file| seen.insert(file.path.clone()))
+            .filter(|file| matcher.override_for(&file.path) == *scope)
+            .collect();
         let un_cached_input_files: Vec<_> = language_files
             .iter()
             .filter(|path| {
@@ -199,7 +259,8 @@ pub(crate) async fn run_check(
                 !cache.has_no_matches(hash, pattern.hash)
             })
             .collect();
-        let (result, no_match) = pattern.execute_paths(un_cached_input_files, &context);
+        let (result, no_match) =
+            pattern.execute_paths(un_cached_input_files, &context_for(pattern));
         if !no_match.is_empty() {
             for path in no_match.into_iter() {
                 let hash = path.hash.unwrap();
@@ -377,9 +438,20 @@ pub(crate) async fn run_check(
                     .map(|r| &r.pattern.local_name)
                     .collect::<HashSet<_>>();
                 for pattern in applicable_patterns {
-                    let problem = compiled_map.get(pattern).unwrap();
+                    // the pattern compiled with the params of the file's override
+                    let problem = problems
+                        .iter()
+                        .find(|(problem, matcher, scope)| {
+                            pattern_lookup
+                                .get(&problem.hash)
+                                .is_some_and(|p| &p.local_name == pattern)
+                                && matcher.override_for(Path::new(file.as_str())) == *scope
+                        })
+                        .map(|(problem, _, _)| problem)
+                        .unwrap();
                     let src = fs_err::read_to_string(file)?;
-                    let res = problem.execute_file(&RichFile::new(file.to_string(), src), &context);
+                    let res = problem
+                        .execute_file(&RichFile::new(file.to_string(), src), &context_for(problem));
                     for r in res {
                         if let MatchResult::Rewrite(r) = r {
                             apply_rewrite(&MatchResult::Rewrite(r))?;
@@ -67,6 +67,7 @@ use tracing_subscriber::{layer::SubscriberExt, EnvFilter, Registry};
 #[cfg(feature = "docgen")]
 pub(crate) mod docgen;
 mod filters;
+mod variables;
 
 use crate::{
     analytics::{
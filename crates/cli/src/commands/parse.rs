@@ -12,6 +12,7 @@ use marzano_messenger::{
     output_mode::OutputMode,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::io;
 use std::path::PathBuf;
 use tokio::fs;
@@ -90,7 +91,7 @@ async fn parse_one_pattern(body: String, path: Option<&PathBuf>) -> Result<Match
     let fake_flags = GlobalFormatFlags::default();
     let pattern_libs = crate::resolver::get_grit_files_from_flags_or_cwd(&fake_flags).await?;
     let pattern_libs = pattern_libs.get_language_directory_or_default(lang)?;
-    let problem = match pattern.compile(&pattern_libs, None, None, None) {
+    let problem = match pattern.compile(&pattern_libs, None, None, None, &BTreeMap::new()) {
         Ok(problem) => problem,
         Err(e) => {
             let log = match e.downcast::<grit_util::AnalysisLog>() {
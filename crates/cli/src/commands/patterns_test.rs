@@ -30,7 +30,7 @@ use marzano_messenger::emit::{get_visibility, VisibilityLevels};
 use super::patterns::PatternsTestArgs;
 
 use anyhow::{anyhow, bail, Context as _, Result};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use std::{path::Path, time::Duration};
 
@@ -71,7 +71,7 @@ pub async fn get_marzano_pattern_test_results(
                 .unwrap_or_else(|_| panic!("Failed to parse pattern {}", pattern.body));
 
             let compiled = rich_pattern
-                .compile(&libs, None, None, None)
+                .compile(&libs, None, None, None, &BTreeMap::new())
                 .map(|cr| cr.problem);
 
             match compiled {
@@ -81,6 +81,11 @@ pub async fn get_marzano_pattern_test_results(
                         .clone()
                         .unwrap_or(format!("<unknown pattern {}>", index));
 
+                    let mut runtime = runtime.clone();
+                    if let Some(seed) = pattern.config.meta.seed {
+                        runtime.seed = Some(seed);
+                    }
+
                     if let Some(samples) = &pattern.config.samples {
                         let mut results = Vec::with_capacity(samples.len());
                         for sample in samples {
@@ -0,0 +1,139 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use anyhow::{anyhow, bail, Context, Result};
+use clap::Args;
+use serde::Serialize;
+use serde_json::Value;
+
+#[derive(Args, Clone, Debug, Serialize, Default)]
+/// Shared arguments for binding pattern variables in apply and check commands.
+pub struct SharedVariableArgs {
+    /// Bind a global variable before running the pattern, for example `--var from=lodash`. Integers and JSON lists or maps are parsed, anything else is a string. With check, prefix the name with a pattern name, as in `--var rename_import.from=lodash`, to only bind it for that pattern
+    #[clap(long = "var", value_name = "NAME=VALUE")]
+    pub(crate) vars: Vec<String>,
+    /// Bind the global variables in a JSON file mapping variable names to values. Values passed with --var take precedence
+    #[clap(long = "vars-file", value_name = "PATH")]
+    pub(crate) vars_file: Option<PathBuf>,
+}
+
+pub(crate) fn extract_variables(args: &SharedVariableArgs) -> Result<BTreeMap<String, Value>> {
+    let mut variables = match &args.vars_file {
+        Some(path) => {
+            let content = fs_err::read_to_string(path)?;
+            serde_json::from_str::<BTreeMap<String, Value>>(&content).with_context(|| {
+                format!(
+                    "{} must contain a JSON object mapping variable names to values",
+                    path.display()
+                )
+            })?
+        }
+        None => BTreeMap::new(),
+    };
+    for var in &args.vars {
+        let (name, value) = var
+            .split_once('=')
+            .ok_or_else(|| anyhow!("invalid variable {var}, expected NAME=VALUE"))?;
+        variables.insert(name.trim().to_owned(), parse_value(value)?);
+    }
+    Ok(variables)
+}
+
+/// Selects the variables that apply to one of several patterns run together:
+/// unprefixed variables, and those prefixed with the pattern's name, such as
+/// `rename_import.from`, which take precedence.
+pub(crate) fn variables_for_pattern(
+    variables: &BTreeMap<String, Value>,
+    pattern: &str,
+) -> BTreeMap<String, Value> {
+    let mut scoped: BTreeMap<String, Value> = variables
+        .iter()
+        .filter(|(name, _)| !name.contains('.'))
+        .map(|(name, value)| (name.clone(), value.clone()))
+        .collect();
+    for (name, value) in variables {
+        if let Some(name) = name
+            .strip_prefix(pattern)
+            .and_then(|name| name.strip_prefix('.'))
+        {
+            scoped.insert(name.to_owned(), value.clone());
+        }
+    }
+    scoped
+}
+
+fn parse_value(value: &str) -> Result<Value> {
+    if let Ok(int) = value.parse::<i64>() {
+        return Ok(Value::from(int));
+    }
+    if value.starts_with('[') || value.starts_with('{') {
+        return match serde_json::from_str(value) {
+            Ok(json) => Ok(json),
+            Err(e) => bail!("invalid JSON value {value}: {e}"),
+        };
+    }
+    Ok(Value::String(value.to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parses_variable_values() {
+        let args = SharedVariableArgs {
+            vars: vec![
+                "from=lodash".to_string(),
+                "limit=5".to_string(),
+                "modules=[\"lodash\", \"moment\"]".to_string(),
+                "options={\"strict\": true}".to_string(),
+                "query=a=b".to_string(),
+            ],
+            vars_file: None,
+        };
+        let variables = extract_variables(&args).unwrap();
+        assert_eq!(variables["from"], json!("lodash"));
+        assert_eq!(variables["limit"], json!(5));
+        assert_eq!(variables["modules"], json!(["lodash", "moment"]));
+        assert_eq!(variables["options"], json!({ "strict": true }));
+        assert_eq!(variables["query"], json!("a=b"));
+    }
+
+    #[test]
+    fn scopes_variables_to_patterns() {
+        let variables = BTreeMap::from([
+            ("from".to_string(), json!("lodash")),
+            ("rename_import.from".to_string(), json!("moment")),
+            ("rename_import.to".to_string(), json!("dayjs")),
+            ("max_params.limit".to_string(), json!(5)),
+        ]);
+        let scoped = variables_for_pattern(&variables, "rename_import");
+        assert_eq!(
+            scoped,
+            BTreeMap::from([
+                ("from".to_string(), json!("moment")),
+                ("to".to_string(), json!("dayjs")),
+            ])
+        );
+        let scoped = variables_for_pattern(&variables, "no_console_log");
+        assert_eq!(
+            scoped,
+            BTreeMap::from([("from".to_string(), json!("lodash"))])
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_variables() {
+        let missing_value = SharedVariableArgs {
+            vars: vec!["from".to_string()],
+            vars_file: None,
+        };
+        assert!(extract_variables(&missing_value).is_err());
+
+        let invalid_json = SharedVariableArgs {
+            vars: vec!["modules=[lodash".to_string()],
+            vars_file: None,
+        };
+        assert!(extract_variables(&invalid_json).is_err());
+    }
+}
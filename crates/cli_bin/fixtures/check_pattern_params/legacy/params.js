@@ -0,0 +1,3 @@
+function old_style(a, b, c, d, e, f) {
+  return a + b + c + d + e + f;
+}
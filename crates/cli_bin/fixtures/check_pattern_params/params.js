@@ -0,0 +1,7 @@
+function few(a, b) {
+  return a + b;
+}
+
+function many(a, b, c, d, e, f) {
+  return a + b + c + d + e + f;
+}
@@ -0,0 +1,20 @@
+# ---
+# jupyter:
+#   jupytext:
+#     formats: ipynb,py:percent
+# ---
+
+# %% [markdown]
+# We print(things) here
+
+# %%
+import math
+
+print(math.pi)
+
+# %%
+# %%bash
+print(ls)
+
+# %%
+print("done")
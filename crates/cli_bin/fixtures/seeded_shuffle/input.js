@@ -0,0 +1 @@
+const animals = [1, 2, 3];
@@ -3321,3 +3321,58 @@ fn large_file_fails() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn apply_named_pattern_uses_configured_params() -> Result<()> {
+    let (_temp_dir, dir) = get_fixture("check_pattern_params", false)?;
+
+    let mut apply_cmd = get_test_cmd()?;
+    apply_cmd
+        .current_dir(dir.as_path())
+        .env("GRIT_DOWNLOADS_DISABLED", "true")
+        .arg("apply")
+        .arg("max_params")
+        .arg("params.js");
+    let output = apply_cmd.output()?;
+    let stdout = String::from_utf8(output.stdout)?;
+
+    assert!(
+        output.status.success(),
+        "Command didn't finish successfully: {}",
+        String::from_utf8(output.stderr)?
+    );
+    assert!(stdout.contains("many"));
+    assert!(!stdout.contains("few"));
+
+    Ok(())
+}
+
+#[test]
+fn apply_named_pattern_uses_configured_seed() -> Result<()> {
+    let mut contents = Vec::new();
+    for _ in 0..2 {
+        let (_temp_dir, dir) = get_fixture("seeded_shuffle", false)?;
+
+        let mut apply_cmd = get_test_cmd()?;
+        apply_cmd
+            .current_dir(dir.as_path())
+            .env("GRIT_DOWNLOADS_DISABLED", "true")
+            .arg("apply")
+            .arg("--force")
+            .arg("shuffle_animals")
+            .arg("input.js");
+        let output = apply_cmd.output()?;
+        assert!(
+            output.status.success(),
+            "Command didn't finish successfully: {}",
+            String::from_utf8(output.stderr)?
+        );
+
+        contents.push(fs_err::read_to_string(dir.join("input.js"))?);
+    }
+
+    assert!(contents[0].contains("zebra"));
+    assert_eq!(contents[0], contents[1]);
+
+    Ok(())
+}
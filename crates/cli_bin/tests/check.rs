@@ -207,3 +207,35 @@ fn check_only_in_diff() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn check_uses_configured_pattern_params() -> Result<()> {
+    let (_temp_dir, dir) = get_fixture("check_pattern_params", false)?;
+    let output = check_cmd_output(dir, &["params.js"], None)?;
+    assert!(output.contains("max_params"));
+    assert!(output.contains("many"));
+    assert!(!output.contains("few"));
+    Ok(())
+}
+
+#[test]
+fn check_uses_param_overrides_for_matching_paths() -> Result<()> {
+    let (_temp_dir, dir) = get_fixture("check_pattern_params", false)?;
+    let output = check_cmd_output(dir, &["."], None)?;
+    assert!(output.contains("many"));
+    assert!(!output.contains("old_style"));
+    Ok(())
+}
+
+#[test]
+fn check_accepts_seed() -> Result<()> {
+    let mut outputs = Vec::new();
+    for _ in 0..2 {
+        let (_temp_dir, dir) = get_fixture("seeded_shuffle", false)?;
+        let output = check_cmd_output(dir, &["--seed", "3", "input.js"], None)?;
+        assert!(output.contains("shuffle_animals"));
+        outputs.push(output);
+    }
+    assert_eq!(outputs[0], outputs[1]);
+    Ok(())
+}
@@ -75,7 +75,7 @@ impl Matcher<MarzanoQueryContext> for ASTNode {
             );
         }
 
-        if node.node.kind_id() != self.sort {
+        if node.node.kind_id() != self.sort || context.is_excluded_by_error(&node) {
             return Ok(false);
         }
         if self.args.is_empty() {
@@ -174,12 +174,15 @@ impl Matcher<MarzanoQueryContext> for AstLeafNode {
         &'a self,
         binding: &MarzanoResolvedPattern<'a>,
         _state: &mut State<'a, MarzanoQueryContext>,
-        _context: &'a MarzanoContext<'a>,
+        context: &'a MarzanoContext<'a>,
         _logs: &mut AnalysisLogs,
     ) -> GritResult<bool> {
         let Some(node) = binding.get_last_binding().and_then(Binding::singleton) else {
             return Ok(false);
         };
+        if context.is_excluded_by_error(&node) {
+            return Ok(false);
+        }
         if let Some(e) = &self.equivalence_class {
             Ok(e.are_equivalent(node.node.kind_id(), node.text()?.trim()))
         } else if self.sort != node.node.kind_id() {
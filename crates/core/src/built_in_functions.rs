@@ -1,6 +1,8 @@
 use crate::{
-    marzano_context::MarzanoContext, marzano_resolved_pattern::MarzanoResolvedPattern,
-    paths::resolve, problem::MarzanoQueryContext,
+    marzano_context::MarzanoContext,
+    marzano_resolved_pattern::MarzanoResolvedPattern,
+    paths::{basename, dirname, extname, normalize_path, path_join, relative_path, resolve},
+    problem::MarzanoQueryContext,
 };
 use anyhow::{anyhow, bail, Result};
 use grit_pattern_matcher::{
@@ -8,15 +10,20 @@ use grit_pattern_matcher::{
     constant::Constant,
     context::ExecContext,
     pattern::{
-        get_absolute_file_name, get_file_name, CallBuiltIn, CallbackPattern, JoinFn, LazyBuiltIn,
-        Pattern, ResolvedPattern, ResolvedSnippet, State,
+        cached_dynamic_regex, get_absolute_file_name, get_file_name, CallBuiltIn, CallbackPattern,
+        JoinFn, LazyBuiltIn, Pattern, ResolvedPattern, ResolvedSnippet, State,
     },
 };
-use grit_util::{AnalysisLogBuilder, AnalysisLogs, CodeRange, Language};
+use grit_util::{AnalysisLogBuilder, AnalysisLogs, AstNode, CodeRange, Language};
 use itertools::Itertools;
 use rand::prelude::SliceRandom;
 use rand::Rng;
-use std::collections::{BTreeMap, HashMap};
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap, HashSet},
+    path::Path,
+    sync::Arc,
+};
 
 // todo we can probably use a macro to generate a function that takes a vec and
 // and calls the input function with the vec args unpacked.
@@ -43,10 +50,11 @@ pub type CallbackFn = dyn for<'a, 'b> Fn(
     + Send
     + Sync;
 
+#[derive(Clone)]
 pub struct BuiltInFunction {
     pub name: &'static str,
     pub params: Vec<&'static str>,
-    pub(crate) func: Box<CallableFn>,
+    pub(crate) func: Arc<CallableFn>,
     pub(crate) position: BuiltInFunctionPosition,
 }
 
@@ -65,7 +73,7 @@ impl BuiltInFunction {
         Self {
             name,
             params,
-            func,
+            func: func.into(),
             position: BuiltInFunctionPosition::Pattern,
         }
     }
@@ -107,9 +115,10 @@ impl BuiltInFunctionPosition {
     }
 }
 
+#[derive(Clone)]
 pub struct BuiltIns {
     built_ins: Vec<BuiltInFunction>,
-    callbacks: Vec<Box<CallbackFn>>,
+    callbacks: Vec<Arc<CallbackFn>>,
 }
 
 impl std::fmt::Debug for BuiltIns {
@@ -160,7 +169,7 @@ impl BuiltIns {
     /// Add an anonymous built-in, used for callbacks
     /// Returns a pattern that can be used to call the callback
     pub fn add_callback(&mut self, func: Box<CallbackFn>) -> Pattern<MarzanoQueryContext> {
-        self.callbacks.push(func);
+        self.callbacks.push(func.into());
         let index = self.callbacks.len() - 1;
         Pattern::CallbackPattern(Box::new(CallbackPattern::new(index)))
     }
@@ -198,17 +207,75 @@ impl BuiltIns {
     pub fn get_built_in_functions() -> BuiltIns {
         vec![
             BuiltInFunction::new("resolve", vec!["path"], Box::new(resolve_path_fn)),
+            BuiltInFunction::new("dirname", vec!["path"], Box::new(dirname_fn)),
+            BuiltInFunction::new("basename", vec!["path", "ext"], Box::new(basename_fn)),
+            BuiltInFunction::new("extname", vec!["path"], Box::new(extname_fn)),
+            BuiltInFunction::new("path_join", vec!["base", "path"], Box::new(path_join_fn)),
+            BuiltInFunction::new("normalize_path", vec!["path"], Box::new(normalize_path_fn)),
+            BuiltInFunction::new(
+                "relative_path",
+                vec!["from", "to"],
+                Box::new(relative_path_fn),
+            ),
+            BuiltInFunction::new(
+                "project_dependency_version",
+                vec!["name"],
+                Box::new(project_dependency_version_fn),
+            ),
             BuiltInFunction::new("capitalize", vec!["string"], Box::new(capitalize_fn)),
             BuiltInFunction::new("lowercase", vec!["string"], Box::new(lowercase_fn)),
             BuiltInFunction::new("uppercase", vec!["string"], Box::new(uppercase_fn)),
+            BuiltInFunction::new("snake_case", vec!["string"], Box::new(snake_case_fn)),
+            BuiltInFunction::new("camel_case", vec!["string"], Box::new(camel_case_fn)),
+            BuiltInFunction::new("pascal_case", vec!["string"], Box::new(pascal_case_fn)),
+            BuiltInFunction::new("kebab_case", vec!["string"], Box::new(kebab_case_fn)),
+            BuiltInFunction::new("constant_case", vec!["string"], Box::new(constant_case_fn)),
+            BuiltInFunction::new("split_words", vec!["string"], Box::new(split_words_fn)),
             BuiltInFunction::new("text", vec!["string", "linearize"], Box::new(text_fn)),
             BuiltInFunction::new("trim", vec!["string", "trim_chars"], Box::new(trim_fn)),
             BuiltInFunction::new("join", vec!["list", "separator"], Box::new(join_fn)),
             BuiltInFunction::new("distinct", vec!["list"], Box::new(distinct_fn)),
             BuiltInFunction::new("length", vec!["target"], Box::new(length_fn)),
             BuiltInFunction::new("shuffle", vec!["list"], Box::new(shuffle_fn)),
+            BuiltInFunction::new("sort", vec!["list", "by"], Box::new(sort_fn)),
+            BuiltInFunction::new("reverse", vec!["list"], Box::new(reverse_fn)),
+            BuiltInFunction::new("slice", vec!["list", "start", "end"], Box::new(slice_fn)),
+            BuiltInFunction::new("flatten", vec!["list"], Box::new(flatten_fn)),
+            BuiltInFunction::new("zip", vec!["list", "other"], Box::new(zip_fn)),
+            BuiltInFunction::new("index_of", vec!["list", "item"], Box::new(index_of_fn)),
+            BuiltInFunction::new(
+                "contains_item",
+                vec!["list", "item"],
+                Box::new(contains_item_fn),
+            )
+            .as_predicate_or_pattern(),
+            BuiltInFunction::new("unique_by", vec!["list", "by"], Box::new(unique_by_fn)),
+            BuiltInFunction::new("keys", vec!["map"], Box::new(keys_fn)),
+            BuiltInFunction::new("values", vec!["map"], Box::new(values_fn)),
+            BuiltInFunction::new("entries", vec!["map"], Box::new(entries_fn)),
+            BuiltInFunction::new("merge", vec!["map", "other"], Box::new(merge_fn)),
+            BuiltInFunction::new("has_key", vec!["map", "key"], Box::new(has_key_fn))
+                .as_predicate_or_pattern(),
+            BuiltInFunction::new("parse_json", vec!["string"], Box::new(parse_json_fn)),
+            BuiltInFunction::new("parse_yaml", vec!["string"], Box::new(parse_yaml_fn)),
+            BuiltInFunction::new("to_json", vec!["value", "pretty"], Box::new(to_json_fn)),
             BuiltInFunction::new("random", vec!["floor", "ceiling"], Box::new(random_fn)),
             BuiltInFunction::new("split", vec!["string", "separator"], Box::new(split_fn)),
+            BuiltInFunction::new(
+                "regex_replace",
+                vec!["string", "regex", "replacement"],
+                Box::new(regex_replace_fn),
+            ),
+            BuiltInFunction::new(
+                "regex_find_all",
+                vec!["string", "regex"],
+                Box::new(regex_find_all_fn),
+            ),
+            BuiltInFunction::new(
+                "regex_split",
+                vec!["string", "regex"],
+                Box::new(regex_split_fn),
+            ),
             BuiltInFunction::new("log", vec!["message", "variable"], Box::new(log_fn))
                 .as_predicate_or_pattern(),
         ]
@@ -246,6 +313,116 @@ fn resolve_path_fn<'a>(
     Ok(ResolvedPattern::from_string(resolved_path))
 }
 
+/// Resolves the text of each argument, failing if a required one is missing.
+fn text_args<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+    name: &str,
+    required: usize,
+) -> Result<Vec<Option<String>>> {
+    let args = MarzanoResolvedPattern::from_patterns(args, state, context, logs)?;
+
+    let texts = args
+        .iter()
+        .map(|arg| {
+            arg.as_ref()
+                .map(|arg| Ok(arg.text(&state.files, context.language())?.into_owned()))
+                .transpose()
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if texts.iter().take(required).any(Option::is_none) {
+        bail!("{name} takes {required} argument(s)");
+    }
+    Ok(texts)
+}
+
+fn dirname_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let args = text_args(args, context, state, logs, "dirname", 1)?;
+    let path = args[0].as_deref().unwrap_or_default();
+    Ok(ResolvedPattern::from_string(dirname(path).to_owned()))
+}
+
+fn basename_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let args = text_args(args, context, state, logs, "basename", 1)?;
+    let path = args[0].as_deref().unwrap_or_default();
+    let name = basename(path, args[1].as_deref());
+    Ok(ResolvedPattern::from_string(name.to_owned()))
+}
+
+fn extname_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let args = text_args(args, context, state, logs, "extname", 1)?;
+    let path = args[0].as_deref().unwrap_or_default();
+    Ok(ResolvedPattern::from_string(extname(path).to_owned()))
+}
+
+fn path_join_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let args = text_args(args, context, state, logs, "path_join", 2)?;
+    let base = args[0].as_deref().unwrap_or_default();
+    let path = args[1].as_deref().unwrap_or_default();
+    Ok(ResolvedPattern::from_string(path_join(base, path)))
+}
+
+fn normalize_path_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let args = text_args(args, context, state, logs, "normalize_path", 1)?;
+    let path = args[0].as_deref().unwrap_or_default();
+    Ok(ResolvedPattern::from_string(normalize_path(path)))
+}
+
+fn relative_path_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let args = text_args(args, context, state, logs, "relative_path", 2)?;
+    let from = args[0].as_deref().unwrap_or_default();
+    let to = args[1].as_deref().unwrap_or_default();
+    Ok(ResolvedPattern::from_string(relative_path(from, to)?))
+}
+
+fn project_dependency_version_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let args = text_args(args, context, state, logs, "project_dependency_version", 1)?;
+    let name = args[0].as_deref().unwrap_or_default();
+    let current_file = get_absolute_file_name(state, context.language())?;
+
+    match context.project_dependency_version(Path::new(&current_file), name) {
+        Some(version) => Ok(ResolvedPattern::from_string(version)),
+        None => Ok(ResolvedPattern::from_constant(Constant::Undefined)),
+    }
+}
+
 fn capitalize(s: &str) -> String {
     let mut c = s.chars();
     match c.next() {
@@ -299,6 +476,120 @@ fn uppercase_fn<'a>(
     Ok(ResolvedPattern::from_string(s.to_uppercase()))
 }
 
+/// Splits an identifier into its words, on separators and case changes.
+///
+/// Acronyms stay together (`HTTPServer` is `HTTP` and `Server`) and digits
+/// stick to the word before them (`base64Encode` is `base64` and `Encode`).
+fn split_words(s: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    for part in s.split(|c: char| !c.is_alphanumeric()) {
+        let chars: Vec<char> = part.chars().collect();
+        let mut word = String::new();
+        for (i, &c) in chars.iter().enumerate() {
+            let starts_word = i > 0
+                && c.is_uppercase()
+                && (!chars[i - 1].is_uppercase()
+                    || chars.get(i + 1).is_some_and(|next| next.is_lowercase()));
+            if starts_word {
+                words.push(std::mem::take(&mut word));
+            }
+            word.push(c);
+        }
+        if !word.is_empty() {
+            words.push(word);
+        }
+    }
+    words
+}
+
+fn snake_case_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let args = text_args(args, context, state, logs, "snake_case", 1)?;
+    let s = args[0].as_deref().unwrap_or_default();
+    let words = split_words(s).iter().map(|w| w.to_lowercase()).join("_");
+    Ok(ResolvedPattern::from_string(words))
+}
+
+fn camel_case_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let args = text_args(args, context, state, logs, "camel_case", 1)?;
+    let s = args[0].as_deref().unwrap_or_default();
+    let words = split_words(s)
+        .iter()
+        .enumerate()
+        .map(|(i, w)| {
+            let w = w.to_lowercase();
+            if i == 0 {
+                w
+            } else {
+                capitalize(&w)
+            }
+        })
+        .join("");
+    Ok(ResolvedPattern::from_string(words))
+}
+
+fn pascal_case_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let args = text_args(args, context, state, logs, "pascal_case", 1)?;
+    let s = args[0].as_deref().unwrap_or_default();
+    let words = split_words(s)
+        .iter()
+        .map(|w| capitalize(&w.to_lowercase()))
+        .join("");
+    Ok(ResolvedPattern::from_string(words))
+}
+
+fn kebab_case_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let args = text_args(args, context, state, logs, "kebab_case", 1)?;
+    let s = args[0].as_deref().unwrap_or_default();
+    let words = split_words(s).iter().map(|w| w.to_lowercase()).join("-");
+    Ok(ResolvedPattern::from_string(words))
+}
+
+fn constant_case_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let args = text_args(args, context, state, logs, "constant_case", 1)?;
+    let s = args[0].as_deref().unwrap_or_default();
+    let words = split_words(s).iter().map(|w| w.to_uppercase()).join("_");
+    Ok(ResolvedPattern::from_string(words))
+}
+
+fn split_words_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let args = text_args(args, context, state, logs, "split_words", 1)?;
+    let s = args[0].as_deref().unwrap_or_default();
+    let words = split_words(s)
+        .into_iter()
+        .map(MarzanoResolvedPattern::from_string);
+    Ok(ResolvedPattern::from_list_parts(words))
+}
+
 fn text_fn<'a>(
     args: &'a [Option<Pattern<MarzanoQueryContext>>],
     context: &'a MarzanoContext<'a>,
@@ -380,6 +671,52 @@ fn split_fn<'a>(
     Ok(ResolvedPattern::from_list_parts(parts))
 }
 
+/// Replaces every match of `regex`, where the replacement can refer to
+/// capture groups as `$1` or `${name}`.
+fn regex_replace_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let args = text_args(args, context, state, logs, "regex_replace", 3)?;
+    let string = args[0].as_deref().unwrap_or_default();
+    let regex = cached_dynamic_regex(args[1].as_deref().unwrap_or_default())?;
+    let replacement = args[2].as_deref().unwrap_or_default();
+    let replaced = regex.replace_all(string, replacement);
+    Ok(ResolvedPattern::from_string(replaced.into_owned()))
+}
+
+fn regex_find_all_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let args = text_args(args, context, state, logs, "regex_find_all", 2)?;
+    let string = args[0].as_deref().unwrap_or_default();
+    let regex = cached_dynamic_regex(args[1].as_deref().unwrap_or_default())?;
+    let matches = regex
+        .find_iter(string)
+        .map(|m| MarzanoResolvedPattern::from_string(m.as_str().to_owned()));
+    Ok(ResolvedPattern::from_list_parts(matches))
+}
+
+fn regex_split_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let args = text_args(args, context, state, logs, "regex_split", 2)?;
+    let string = args[0].as_deref().unwrap_or_default();
+    let regex = cached_dynamic_regex(args[1].as_deref().unwrap_or_default())?;
+    let parts = regex
+        .split(string)
+        .map(|part| MarzanoResolvedPattern::from_string(part.to_owned()));
+    Ok(ResolvedPattern::from_list_parts(parts))
+}
+
 fn random_fn<'a>(
     args: &'a [Option<Pattern<MarzanoQueryContext>>],
     context: &'a MarzanoContext<'a>,
@@ -513,6 +850,498 @@ fn shuffle_fn<'a>(
     ))
 }
 
+/// Collects the items of a list argument, keeping node bindings intact so
+/// they are re-emitted with their original text.
+fn list_arg<'a>(
+    arg: Option<MarzanoResolvedPattern<'a>>,
+    name: &str,
+) -> Result<Vec<MarzanoResolvedPattern<'a>>> {
+    let Some(list) = arg else {
+        bail!("{name} requires a non-null list as the first argument");
+    };
+    if let Some(items) = list.get_list_items() {
+        Ok(items.cloned().collect())
+    } else if let Some(items) = list.get_list_binding_items() {
+        Ok(items.collect())
+    } else {
+        bail!("{name} takes a list as the first argument")
+    }
+}
+
+fn integer_arg<'a>(
+    arg: &Option<MarzanoResolvedPattern<'a>>,
+    state: &State<'a, MarzanoQueryContext>,
+    context: &'a MarzanoContext<'a>,
+    name: &str,
+) -> Result<Option<i64>> {
+    let Some(arg) = arg else {
+        return Ok(None);
+    };
+    let text = arg.text(&state.files, context.language())?;
+    let value = text
+        .trim()
+        .parse::<i64>()
+        .map_err(|_| anyhow!("{name} must be an integer, got {text}"))?;
+    Ok(Some(value))
+}
+
+/// The text an item is compared by: the text of its `by` field if given and
+/// the item is a node, or else the text of the item itself.
+fn item_key<'a>(
+    item: &MarzanoResolvedPattern<'a>,
+    by: Option<&str>,
+    state: &State<'a, MarzanoQueryContext>,
+    context: &'a MarzanoContext<'a>,
+) -> Result<String> {
+    let node = item.get_last_binding().and_then(Binding::singleton);
+    match (by, node) {
+        (Some(field), Some(node)) => Ok(node
+            .child_by_field_name(field)
+            .map(|child| child.text().map(|text| text.into_owned()))
+            .transpose()?
+            .unwrap_or_default()),
+        _ => Ok(item.text(&state.files, context.language())?.into_owned()),
+    }
+}
+
+/// Orders numeric keys by value before all other keys, which are ordered as
+/// text, so that lists mixing both still sort consistently.
+fn compare_keys(a: &str, b: &str) -> Ordering {
+    match (a.parse::<f64>(), b.parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.total_cmp(&b),
+        (Ok(_), Err(_)) => Ordering::Less,
+        (Err(_), Ok(_)) => Ordering::Greater,
+        (Err(_), Err(_)) => a.cmp(b),
+    }
+}
+
+fn sort_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let mut args = MarzanoResolvedPattern::from_patterns(args, state, context, logs)?.into_iter();
+
+    let list = list_arg(args.next().flatten(), "sort")?;
+    let by = args
+        .next()
+        .flatten()
+        .map(|by| {
+            by.text(&state.files, context.language())
+                .map(|t| t.into_owned())
+        })
+        .transpose()?;
+
+    let mut keyed = list
+        .into_iter()
+        .map(|item| Ok((item_key(&item, by.as_deref(), state, context)?, item)))
+        .collect::<Result<Vec<_>>>()?;
+    keyed.sort_by(|(a, _), (b, _)| compare_keys(a, b));
+    Ok(MarzanoResolvedPattern::from_list_parts(
+        keyed.into_iter().map(|(_, item)| item),
+    ))
+}
+
+fn reverse_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let args = MarzanoResolvedPattern::from_patterns(args, state, context, logs)?;
+
+    let list = list_arg(args.into_iter().next().flatten(), "reverse")?;
+    Ok(MarzanoResolvedPattern::from_list_parts(
+        list.into_iter().rev(),
+    ))
+}
+
+/// Slices a list from `start` up to `end`, where negative indices count
+/// from the end of the list.
+fn slice_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let args = MarzanoResolvedPattern::from_patterns(args, state, context, logs)?;
+
+    let start = integer_arg(&args[1], state, context, "slice start")?;
+    let end = integer_arg(&args[2], state, context, "slice end")?;
+    let list = list_arg(args.into_iter().next().flatten(), "slice")?;
+
+    let len = list.len() as i64;
+    let clamp = |index: i64| {
+        let index = if index < 0 { len + index } else { index };
+        index.clamp(0, len) as usize
+    };
+    let start = clamp(start.unwrap_or(0));
+    let end = clamp(end.unwrap_or(len));
+    Ok(MarzanoResolvedPattern::from_list_parts(
+        list.into_iter().skip(start).take(end.saturating_sub(start)),
+    ))
+}
+
+/// Flattens one level of nested lists.
+fn flatten_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let args = MarzanoResolvedPattern::from_patterns(args, state, context, logs)?;
+
+    let list = list_arg(args.into_iter().next().flatten(), "flatten")?;
+    let mut flattened = Vec::with_capacity(list.len());
+    for item in list {
+        if let Some(items) = item.get_list_items() {
+            flattened.extend(items.cloned());
+        } else if let Some(items) = item.get_list_binding_items() {
+            flattened.extend(items);
+        } else {
+            flattened.push(item);
+        }
+    }
+    Ok(MarzanoResolvedPattern::List(flattened))
+}
+
+/// Pairs up the items of two lists, stopping at the end of the shorter one.
+fn zip_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let mut args = MarzanoResolvedPattern::from_patterns(args, state, context, logs)?.into_iter();
+
+    let list = list_arg(args.next().flatten(), "zip")?;
+    let other = list_arg(args.next().flatten(), "zip")?;
+    let pairs = list
+        .into_iter()
+        .zip(other)
+        .map(|(a, b)| MarzanoResolvedPattern::List(vec![a, b]));
+    Ok(MarzanoResolvedPattern::from_list_parts(pairs))
+}
+
+/// Returns the index of the first item with the same text as `item`, or -1.
+fn index_of_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let index = find_item(args, context, state, logs, "index_of")?;
+    let index = index.map_or(-1, |index| index as i64);
+    Ok(ResolvedPattern::from_constant(Constant::Integer(index)))
+}
+
+fn contains_item_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let index = find_item(args, context, state, logs, "contains_item")?;
+    Ok(ResolvedPattern::from_constant(Constant::Boolean(
+        index.is_some(),
+    )))
+}
+
+fn find_item<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+    name: &str,
+) -> Result<Option<usize>> {
+    let mut args = MarzanoResolvedPattern::from_patterns(args, state, context, logs)?.into_iter();
+
+    let list = list_arg(args.next().flatten(), name)?;
+    let Some(item) = args.next().flatten() else {
+        bail!("{name} requires an item to look for");
+    };
+    let needle = item.text(&state.files, context.language())?;
+    for (index, item) in list.iter().enumerate() {
+        if item.text(&state.files, context.language())? == needle {
+            return Ok(Some(index));
+        }
+    }
+    Ok(None)
+}
+
+/// Removes items whose key, as computed for `sort`, was already seen.
+fn unique_by_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let mut args = MarzanoResolvedPattern::from_patterns(args, state, context, logs)?.into_iter();
+
+    let list = list_arg(args.next().flatten(), "unique_by")?;
+    let by = args
+        .next()
+        .flatten()
+        .map(|by| {
+            by.text(&state.files, context.language())
+                .map(|t| t.into_owned())
+        })
+        .transpose()?;
+
+    let mut seen = HashSet::new();
+    let mut unique = Vec::with_capacity(list.len());
+    for item in list {
+        if seen.insert(item_key(&item, by.as_deref(), state, context)?) {
+            unique.push(item);
+        }
+    }
+    Ok(MarzanoResolvedPattern::List(unique))
+}
+
+fn map_arg<'a>(
+    arg: Option<MarzanoResolvedPattern<'a>>,
+    name: &str,
+) -> Result<BTreeMap<String, MarzanoResolvedPattern<'a>>> {
+    let Some(MarzanoResolvedPattern::Map(map)) = arg else {
+        bail!("{name} takes a map as the first argument");
+    };
+    Ok(map)
+}
+
+fn keys_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let args = MarzanoResolvedPattern::from_patterns(args, state, context, logs)?;
+
+    let map = map_arg(args.into_iter().next().flatten(), "keys")?;
+    let keys = map
+        .into_keys()
+        .map(|key| ResolvedPattern::from_constant(Constant::String(key)));
+    Ok(MarzanoResolvedPattern::from_list_parts(keys))
+}
+
+fn values_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let args = MarzanoResolvedPattern::from_patterns(args, state, context, logs)?;
+
+    let map = map_arg(args.into_iter().next().flatten(), "values")?;
+    Ok(MarzanoResolvedPattern::from_list_parts(map.into_values()))
+}
+
+/// Lists the `[key, value]` pairs of a map, the same shape `some` and
+/// `every` bind when iterating over a map.
+fn entries_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let args = MarzanoResolvedPattern::from_patterns(args, state, context, logs)?;
+
+    let map = map_arg(args.into_iter().next().flatten(), "entries")?;
+    let entries = map.into_iter().map(|(key, value)| {
+        let key = ResolvedPattern::from_constant(Constant::String(key));
+        MarzanoResolvedPattern::List(vec![key, value])
+    });
+    Ok(MarzanoResolvedPattern::from_list_parts(entries))
+}
+
+/// Merges two maps, with the entries of `other` taking precedence.
+fn merge_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let mut args = MarzanoResolvedPattern::from_patterns(args, state, context, logs)?.into_iter();
+
+    let mut map = map_arg(args.next().flatten(), "merge")?;
+    let Some(MarzanoResolvedPattern::Map(other)) = args.next().flatten() else {
+        bail!("merge takes a map as the second argument");
+    };
+    map.extend(other);
+    Ok(MarzanoResolvedPattern::Map(map))
+}
+
+fn has_key_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let mut args = MarzanoResolvedPattern::from_patterns(args, state, context, logs)?.into_iter();
+
+    let map = map_arg(args.next().flatten(), "has_key")?;
+    let Some(key) = args.next().flatten() else {
+        bail!("has_key requires a key to look for");
+    };
+    let key = key.text(&state.files, context.language())?;
+    Ok(ResolvedPattern::from_constant(Constant::Boolean(
+        map.contains_key(key.as_ref()),
+    )))
+}
+
+/// Converts parsed JSON or YAML into nested Grit maps, lists and constants.
+pub(crate) fn from_json_value<'a>(value: serde_json::Value) -> MarzanoResolvedPattern<'a> {
+    match value {
+        serde_json::Value::Null => ResolvedPattern::from_constant(Constant::Undefined),
+        serde_json::Value::Bool(b) => ResolvedPattern::from_constant(Constant::Boolean(b)),
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => ResolvedPattern::from_constant(Constant::Integer(i)),
+            None => ResolvedPattern::from_constant(Constant::Float(n.as_f64().unwrap_or(f64::NAN))),
+        },
+        serde_json::Value::String(s) => ResolvedPattern::from_constant(Constant::String(s)),
+        serde_json::Value::Array(items) => {
+            MarzanoResolvedPattern::List(items.into_iter().map(from_json_value).collect())
+        }
+        serde_json::Value::Object(map) => MarzanoResolvedPattern::Map(
+            map.into_iter()
+                .map(|(key, value)| (key, from_json_value(value)))
+                .collect(),
+        ),
+    }
+}
+
+/// Converts maps, lists and constants into JSON. Anything else, such as a
+/// node, is serialized as its text.
+fn to_json_value<'a>(
+    value: &MarzanoResolvedPattern<'a>,
+    state: &State<'a, MarzanoQueryContext>,
+    context: &'a MarzanoContext<'a>,
+) -> Result<serde_json::Value> {
+    let json = match value {
+        MarzanoResolvedPattern::Map(map) => serde_json::Value::Object(
+            map.iter()
+                .map(|(key, value)| Ok((key.clone(), to_json_value(value, state, context)?)))
+                .collect::<Result<_>>()?,
+        ),
+        MarzanoResolvedPattern::List(items) => serde_json::Value::Array(
+            items
+                .iter()
+                .map(|item| to_json_value(item, state, context))
+                .collect::<Result<_>>()?,
+        ),
+        MarzanoResolvedPattern::Constant(constant) => match constant {
+            Constant::Boolean(b) => serde_json::Value::Bool(*b),
+            Constant::String(s) => serde_json::Value::String(s.clone()),
+            Constant::Integer(i) => serde_json::Value::from(*i),
+            Constant::Float(f) => serde_json::Value::from(*f),
+            Constant::Undefined => serde_json::Value::Null,
+        },
+        MarzanoResolvedPattern::Binding(_)
+        | MarzanoResolvedPattern::Snippets(_)
+        | MarzanoResolvedPattern::File(_)
+        | MarzanoResolvedPattern::Files(_) => match value.get_list_binding_items() {
+            Some(items) => serde_json::Value::Array(
+                items
+                    .map(|item| to_json_value(&item, state, context))
+                    .collect::<Result<_>>()?,
+            ),
+            None => serde_json::Value::String(
+                value.text(&state.files, context.language())?.into_owned(),
+            ),
+        },
+    };
+    Ok(json)
+}
+
+fn parse_json_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let args = text_args(args, context, state, logs, "parse_json", 1)?;
+    let string = args[0].as_deref().unwrap_or_default();
+    let value = serde_json::from_str(string)
+        .map_err(|e| anyhow!("parse_json could not parse {string}: {e}"))?;
+    Ok(from_json_value(value))
+}
+
+fn parse_yaml_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let args = text_args(args, context, state, logs, "parse_yaml", 1)?;
+    let string = args[0].as_deref().unwrap_or_default();
+    let value: serde_yaml::Value = serde_yaml::from_str(string)
+        .map_err(|e| anyhow!("parse_yaml could not parse {string}: {e}"))?;
+    Ok(from_json_value(yaml_to_json_value(value)?))
+}
+
+/// Converts YAML to JSON, turning keys that are not strings, such as `1:` or
+/// `true:`, into their text and dropping tags.
+fn yaml_to_json_value(value: serde_yaml::Value) -> Result<serde_json::Value> {
+    let json = match value {
+        serde_yaml::Value::Mapping(mapping) => serde_json::Value::Object(
+            mapping
+                .into_iter()
+                .map(|(key, value)| Ok((yaml_key(key)?, yaml_to_json_value(value)?)))
+                .collect::<Result<_>>()?,
+        ),
+        serde_yaml::Value::Sequence(items) => serde_json::Value::Array(
+            items
+                .into_iter()
+                .map(yaml_to_json_value)
+                .collect::<Result<_>>()?,
+        ),
+        serde_yaml::Value::Tagged(tagged) => yaml_to_json_value(tagged.value)?,
+        serde_yaml::Value::Null
+        | serde_yaml::Value::Bool(_)
+        | serde_yaml::Value::Number(_)
+        | serde_yaml::Value::String(_) => serde_json::to_value(value)?,
+    };
+    Ok(json)
+}
+
+fn yaml_key(key: serde_yaml::Value) -> Result<String> {
+    let key = match key {
+        serde_yaml::Value::String(key) => key,
+        serde_yaml::Value::Null => "null".to_owned(),
+        serde_yaml::Value::Bool(key) => key.to_string(),
+        serde_yaml::Value::Number(key) => key.to_string(),
+        serde_yaml::Value::Tagged(tagged) => yaml_key(tagged.value)?,
+        serde_yaml::Value::Sequence(_) | serde_yaml::Value::Mapping(_) => {
+            serde_json::to_string(&yaml_to_json_value(key)?)?
+        }
+    };
+    Ok(key)
+}
+
+fn to_json_fn<'a>(
+    args: &'a [Option<Pattern<MarzanoQueryContext>>],
+    context: &'a MarzanoContext<'a>,
+    state: &mut State<'a, MarzanoQueryContext>,
+    logs: &mut AnalysisLogs,
+) -> Result<MarzanoResolvedPattern<'a>> {
+    let args = MarzanoResolvedPattern::from_patterns(args, state, context, logs)?;
+
+    let Some(Some(value)) = args.first() else {
+        bail!("to_json takes 1 argument");
+    };
+    let pretty = match args.get(1) {
+        Some(Some(pretty)) => pretty.is_truthy(state, context.language())?,
+        _ => false,
+    };
+    let json = to_json_value(value, state, context)?;
+    let json = if pretty {
+        serde_json::to_string_pretty(&json)?
+    } else {
+        serde_json::to_string(&json)?
+    };
+    Ok(ResolvedPattern::from_string(json))
+}
+
 fn length_fn<'a>(
     args: &'a [Option<Pattern<MarzanoQueryContext>>],
     context: &'a MarzanoContext<'a>,
@@ -19,6 +19,7 @@ mod inline_snippets;
 pub mod sdk;
 
 mod limits;
+mod manifests;
 pub mod marzano_binding;
 pub mod marzano_code_snippet;
 pub mod marzano_context;
@@ -53,6 +54,8 @@ mod test_errors;
 #[cfg(test)]
 mod test_files;
 #[cfg(test)]
+mod test_injections;
+#[cfg(test)]
 mod test_notebooks;
 #[cfg(any(test, feature = "test_utils"))]
 pub mod test_utils;
@@ -0,0 +1,381 @@
+use marzano_util::runtime::{Dependencies, ManifestCache};
+use serde_json::Value as JsonValue;
+use std::path::Path;
+use toml::Value as TomlValue;
+
+/// Manifests that declare dependencies, in the order they are checked within
+/// a directory.
+const MANIFESTS: [&str; 5] = [
+    "package.json",
+    "Cargo.toml",
+    "pyproject.toml",
+    "requirements.txt",
+    "go.mod",
+];
+
+/// Looks up the version of a dependency declared by the manifests closest to
+/// `file`, walking up its ancestor directories until the root of its
+/// repository. Manifests that do not declare the dependency are skipped, so
+/// monorepo roots are found as well.
+///
+/// Each manifest is parsed once and stored in `cache`, which is shared by all
+/// files in a run.
+pub(crate) fn dependency_version(cache: &ManifestCache, file: &Path, name: &str) -> Option<String> {
+    for dir in file.ancestors().skip(1) {
+        for manifest in MANIFESTS {
+            let dependencies = cache.get_or_load(&dir.join(manifest), load_manifest);
+            let version = if manifest == "pyproject.toml" || manifest == "requirements.txt" {
+                dependencies.get(&normalize_python_name(name))
+            } else {
+                dependencies.get(name)
+            };
+            if let Some(version) = version {
+                return Some(version.to_owned());
+            }
+        }
+        // manifests above the repository belong to other projects
+        if dir.join(".git").exists() {
+            break;
+        }
+    }
+    None
+}
+
+fn load_manifest(path: &Path) -> Dependencies {
+    parse_manifest(path).unwrap_or_default()
+}
+
+fn parse_manifest(path: &Path) -> Option<Dependencies> {
+    let content = fs_err::read_to_string(path).ok()?;
+    match path.file_name()?.to_str()? {
+        "package.json" => parse_package_json(&content),
+        "Cargo.toml" => parse_cargo_toml(&content),
+        "pyproject.toml" => parse_pyproject_toml(&content),
+        "requirements.txt" => Some(parse_requirements_txt(&content)),
+        "go.mod" => Some(parse_go_mod(&content)),
+        _ => None,
+    }
+}
+
+fn parse_package_json(content: &str) -> Option<Dependencies> {
+    let manifest: JsonValue = serde_json::from_str(content).ok()?;
+    let mut dependencies = Dependencies::new();
+    // Later sections don't override earlier ones
+    for section in [
+        "dependencies",
+        "devDependencies",
+        "peerDependencies",
+        "optionalDependencies",
+    ] {
+        let Some(section) = manifest.get(section).and_then(JsonValue::as_object) else {
+            continue;
+        };
+        for (name, version) in section {
+            if let Some(version) = version.as_str() {
+                dependencies
+                    .entry(name.to_owned())
+                    .or_insert_with(|| version.to_owned());
+            }
+        }
+    }
+    Some(dependencies)
+}
+
+fn parse_cargo_toml(content: &str) -> Option<Dependencies> {
+    let manifest: TomlValue = toml::from_str(content).ok()?;
+    let mut tables = vec![&manifest];
+    if let Some(workspace) = manifest.get("workspace") {
+        tables.push(workspace);
+    }
+    if let Some(targets) = manifest.get("target").and_then(TomlValue::as_table) {
+        tables.extend(targets.values());
+    }
+    let mut dependencies = Dependencies::new();
+    for table in tables {
+        for section in ["dependencies", "dev-dependencies", "build-dependencies"] {
+            insert_toml_dependencies(&mut dependencies, table.get(section), |name| {
+                name.to_owned()
+            });
+        }
+    }
+    Some(dependencies)
+}
+
+fn parse_pyproject_toml(content: &str) -> Option<Dependencies> {
+    let manifest: TomlValue = toml::from_str(content).ok()?;
+    let mut dependencies = Dependencies::new();
+    if let Some(project) = manifest.get("project") {
+        let optional = project
+            .get("optional-dependencies")
+            .and_then(TomlValue::as_table)
+            .into_iter()
+            .flat_map(|groups| groups.values());
+        let requirements = project
+            .get("dependencies")
+            .into_iter()
+            .chain(optional)
+            .filter_map(TomlValue::as_array)
+            .flatten()
+            .filter_map(TomlValue::as_str);
+        for requirement in requirements {
+            if let Some((name, version)) = parse_requirement(requirement) {
+                dependencies.entry(name).or_insert(version);
+            }
+        }
+    }
+    if let Some(poetry) = manifest.get("tool").and_then(|tool| tool.get("poetry")) {
+        let groups = poetry
+            .get("group")
+            .and_then(TomlValue::as_table)
+            .into_iter()
+            .flat_map(|groups| groups.values());
+        for table in std::iter::once(poetry).chain(groups) {
+            for section in ["dependencies", "dev-dependencies"] {
+                insert_toml_dependencies(
+                    &mut dependencies,
+                    table.get(section),
+                    normalize_python_name,
+                );
+            }
+        }
+    }
+    Some(dependencies)
+}
+
+/// Adds the entries of a TOML dependency table, whose values are either a
+/// version string or a table with a `version` key.
+fn insert_toml_dependencies(
+    dependencies: &mut Dependencies,
+    section: Option<&TomlValue>,
+    normalize: impl Fn(&str) -> String,
+) {
+    let Some(section) = section.and_then(TomlValue::as_table) else {
+        return;
+    };
+    for (name, spec) in section {
+        let version = spec
+            .as_str()
+            .or_else(|| spec.get("version").and_then(TomlValue::as_str));
+        if let Some(version) = version {
+            dependencies
+                .entry(normalize(name))
+                .or_insert_with(|| version.to_owned());
+        }
+    }
+}
+
+fn parse_requirements_txt(content: &str) -> Dependencies {
+    let mut dependencies = Dependencies::new();
+    for line in content.lines() {
+        let line = line.split(" #").next().unwrap_or_default().trim();
+        // skip comments and options such as `-r other.txt` or `--index-url`
+        if line.is_empty() || line.starts_with('#') || line.starts_with('-') {
+            continue;
+        }
+        if let Some((name, version)) = parse_requirement(line) {
+            dependencies.entry(name).or_insert(version);
+        }
+    }
+    dependencies
+}
+
+/// Splits a PEP 508 requirement such as `requests[socks]>=2.31; python_version > "3.8"`
+/// into its normalized name and version specifier. Requirements without a
+/// specifier accept any version, `*`.
+fn parse_requirement(requirement: &str) -> Option<(String, String)> {
+    let requirement = requirement.split(';').next()?.trim();
+    let name_end = requirement
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+        .unwrap_or(requirement.len());
+    let (name, rest) = requirement.split_at(name_end);
+    if name.is_empty() {
+        return None;
+    }
+    let rest = rest.trim_start();
+    let rest = match rest.strip_prefix('[') {
+        Some(extras) => &extras[extras.find(']')? + 1..],
+        None => rest,
+    };
+    let version = rest
+        .trim()
+        .trim_start_matches('(')
+        .trim_end_matches(')')
+        .trim();
+    let version = if version.is_empty() { "*" } else { version };
+    Some((normalize_python_name(name), version.to_owned()))
+}
+
+fn parse_go_mod(content: &str) -> Dependencies {
+    let mut dependencies = Dependencies::new();
+    let mut in_block = false;
+    for line in content.lines() {
+        let line = line.split("//").next().unwrap_or_default().trim();
+        let requirement = if in_block {
+            if line == ")" {
+                in_block = false;
+                continue;
+            }
+            line
+        } else if let Some(rest) = line.strip_prefix("require") {
+            let rest = rest.trim();
+            if rest == "(" {
+                in_block = true;
+                continue;
+            }
+            rest
+        } else {
+            continue;
+        };
+        let mut parts = requirement.split_whitespace();
+        if let (Some(module), Some(version)) = (parts.next(), parts.next()) {
+            dependencies
+                .entry(module.to_owned())
+                .or_insert_with(|| version.to_owned());
+        }
+    }
+    dependencies
+}
+
+/// Normalizes a Python package name as per PEP 503, so `Foo_Bar` finds `foo-bar`.
+fn normalize_python_name(name: &str) -> String {
+    name.to_ascii_lowercase().replace(['_', '.'], "-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_package_json() {
+        let dependencies = parse_package_json(
+            r#"{
+                "name": "app",
+                "dependencies": { "react": "^18.2.0" },
+                "devDependencies": { "typescript": "~5.3.0", "react": "^17.0.0" }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(dependencies.get("react").unwrap(), "^18.2.0");
+        assert_eq!(dependencies.get("typescript").unwrap(), "~5.3.0");
+        assert_eq!(dependencies.get("name"), None);
+    }
+
+    #[test]
+    fn parses_cargo_toml() {
+        let dependencies = parse_cargo_toml(
+            r#"
+[dependencies]
+anyhow = "1.0.70"
+serde = { version = "1.0.164", features = ["derive"] }
+local = { path = "../local" }
+
+[target.'cfg(unix)'.dev-dependencies]
+nix = "0.27"
+
+[workspace.dependencies]
+tokio = { version = "1.35.1" }
+"#,
+        )
+        .unwrap();
+        assert_eq!(dependencies.get("anyhow").unwrap(), "1.0.70");
+        assert_eq!(dependencies.get("serde").unwrap(), "1.0.164");
+        assert_eq!(dependencies.get("nix").unwrap(), "0.27");
+        assert_eq!(dependencies.get("tokio").unwrap(), "1.35.1");
+        assert_eq!(dependencies.get("local"), None);
+    }
+
+    #[test]
+    fn parses_pyproject_toml() {
+        let dependencies = parse_pyproject_toml(
+            r#"
+[project]
+dependencies = ["requests[socks]>=2.31", "Django (>=4.2,<5)", "rich"]
+
+[project.optional-dependencies]
+test = ["pytest==8.0.0; python_version > '3.8'"]
+
+[tool.poetry.dependencies]
+Typing_Extensions = "^4.9"
+
+[tool.poetry.group.dev.dependencies]
+black = { version = "^24.1" }
+"#,
+        )
+        .unwrap();
+        assert_eq!(dependencies.get("requests").unwrap(), ">=2.31");
+        assert_eq!(dependencies.get("django").unwrap(), ">=4.2,<5");
+        assert_eq!(dependencies.get("rich").unwrap(), "*");
+        assert_eq!(dependencies.get("pytest").unwrap(), "==8.0.0");
+        assert_eq!(dependencies.get("typing-extensions").unwrap(), "^4.9");
+        assert_eq!(dependencies.get("black").unwrap(), "^24.1");
+    }
+
+    #[test]
+    fn parses_requirements_txt() {
+        let dependencies = parse_requirements_txt(
+            "# pinned\n-r base.txt\nFlask==3.0.2  # web\nzope.interface>=6\n\nnumpy\n",
+        );
+        assert_eq!(dependencies.get("flask").unwrap(), "==3.0.2");
+        assert_eq!(dependencies.get("zope-interface").unwrap(), ">=6");
+        assert_eq!(dependencies.get("numpy").unwrap(), "*");
+        assert_eq!(dependencies.len(), 3);
+    }
+
+    #[test]
+    fn parses_go_mod() {
+        let dependencies = parse_go_mod(
+            r#"module example.com/app
+
+go 1.21
+
+require github.com/pkg/errors v0.9.1
+
+require (
+	golang.org/x/sync v0.6.0
+	golang.org/x/text v0.14.0 // indirect
+)
+"#,
+        );
+        assert_eq!(dependencies.get("github.com/pkg/errors").unwrap(), "v0.9.1");
+        assert_eq!(dependencies.get("golang.org/x/sync").unwrap(), "v0.6.0");
+        assert_eq!(dependencies.get("golang.org/x/text").unwrap(), "v0.14.0");
+        assert_eq!(dependencies.len(), 3);
+    }
+
+    #[test]
+    fn finds_nearest_declaring_manifest() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = dir.path().join("repo");
+        let package = repo.join("packages/app");
+        fs_err::create_dir_all(repo.join(".git")).unwrap();
+        fs_err::create_dir_all(package.join("src")).unwrap();
+        fs_err::write(
+            dir.path().join("package.json"),
+            r#"{ "dependencies": { "lodash": "^4.17.21" } }"#,
+        )
+        .unwrap();
+        fs_err::write(
+            repo.join("package.json"),
+            r#"{ "devDependencies": { "typescript": "~5.3.0", "react": "^17.0.0" } }"#,
+        )
+        .unwrap();
+        fs_err::write(
+            package.join("package.json"),
+            r#"{ "dependencies": { "react": "^18.2.0" } }"#,
+        )
+        .unwrap();
+
+        let cache = ManifestCache::default();
+        let file = package.join("src/index.tsx");
+        assert_eq!(
+            dependency_version(&cache, &file, "react").as_deref(),
+            Some("^18.2.0")
+        );
+        assert_eq!(
+            dependency_version(&cache, &file, "typescript").as_deref(),
+            Some("~5.3.0")
+        );
+        // the walk stops at the repository root
+        assert_eq!(dependency_version(&cache, &file, "lodash"), None);
+    }
+}
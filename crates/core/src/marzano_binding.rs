@@ -372,6 +372,9 @@ impl<'a> Binding<'a, MarzanoQueryContext> for MarzanoBinding<'a> {
                 calculate_padding(&children, text, is_first, language).or_else(|| {
                     if children.len() == 1 {
                         let child = children.first().unwrap();
+                        if language.should_pad_like_first_child() && !text.starts_with('\n') {
+                            return leading_line_padding(child);
+                        }
                         if child.node.end_position().row() > child.node.start_position().row()
                             && !child.text().is_ok_and(|t| t.ends_with('\n'))
                             && !text.starts_with('\n')
@@ -609,3 +612,12 @@ fn get_range_nodes_for_list<'a>(
 
     Some((leading_comment, trailing_comment))
 }
+
+/// The line break and indentation in front of `node`, if it starts a line.
+fn leading_line_padding(node: &NodeWithSource) -> Option<String> {
+    let before = &node.source[..node.node.start_byte() as usize];
+    let whitespace = &before[before.trim_end().len()..];
+    whitespace
+        .rfind('\n')
+        .map(|newline| whitespace[newline..].to_string())
+}
@@ -3,6 +3,7 @@ use crate::{
     clean::{get_replacement_ranges, merge_ranges, replace_cleaned_ranges},
     foreign_function_definition::ForeignFunctionDefinition,
     limits::is_file_too_big,
+    manifests::dependency_version,
     marzano_resolved_pattern::{MarzanoFile, MarzanoResolvedPattern},
     pattern_compiler::file_owner_compiler::FileOwnerCompiler,
     problem::MarzanoQueryContext,
@@ -27,10 +28,14 @@ use marzano_language::{
     target_language::TargetLanguage,
 };
 use marzano_util::{
+    node_with_source::NodeWithSource,
     rich_path::{LoadableFile, RichFile},
-    runtime::ExecutionContext,
+    runtime::{ErrorTolerance, ExecutionContext},
+};
+use std::{
+    borrow::Cow,
+    path::{Path, PathBuf},
 };
-use std::{borrow::Cow, path::PathBuf};
 
 pub struct MarzanoContext<'a> {
     pub pattern_definitions: &'a Vec<PatternDefinition<MarzanoQueryContext>>,
@@ -91,6 +96,17 @@ impl<'a> MarzanoContext<'a> {
     pub(crate) fn foreign_function_definitions(&self) -> &[ForeignFunctionDefinition] {
         self.foreign_function_definitions
     }
+
+    /// Whether matching should ignore the node because it overlaps a syntax error.
+    pub(crate) fn is_excluded_by_error(&self, node: &NodeWithSource) -> bool {
+        self.runtime.error_tolerance == ErrorTolerance::Outside && node.overlaps_syntax_error()
+    }
+
+    /// The version of a dependency declared by the project manifests nearest
+    /// to `file`, reusing the manifests already parsed during this run.
+    pub(crate) fn project_dependency_version(&self, file: &Path, name: &str) -> Option<String> {
+        dependency_version(&self.runtime.manifests, file, name)
+    }
 }
 
 impl<'a> ExecContext<'a, MarzanoQueryContext> for MarzanoContext<'a> {
@@ -172,6 +188,15 @@ impl<'a> ExecContext<'a, MarzanoQueryContext> for MarzanoContext<'a> {
                     logs,
                 )?;
                 if let Some(file) = file {
+                    if self.runtime.error_tolerance == ErrorTolerance::Skip
+                        && file.tree.root_node().node.has_error()
+                    {
+                        logs.add_warning(
+                            Some(file.name),
+                            "Skipping file because it contains syntax errors",
+                        );
+                        return Ok(false);
+                    }
                     self.files.push(file);
                     state.files.load_file(ptr, self.files.last().unwrap());
                 }
@@ -37,3 +37,185 @@ pub(crate) fn resolve<'a>(target_path: Cow<'a, str>, from_file: Cow<'a, str>) ->
         })?
         .to_owned())
 }
+
+// The helpers below work on `/`-separated paths without touching the file
+// system, so they behave the same for `$filename`, `$absolute_filename` and
+// import specifiers.
+
+/// Lexically resolves `.` and `..` segments and repeated separators.
+pub(crate) fn normalize_path(path: &str) -> String {
+    let is_absolute = path.starts_with('/');
+    let mut parts: Vec<&str> = Vec::new();
+    for part in path.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                if parts.last().is_some_and(|last| *last != "..") {
+                    parts.pop();
+                } else if !is_absolute {
+                    parts.push("..");
+                }
+            }
+            part => parts.push(part),
+        }
+    }
+    let joined = parts.join("/");
+    if is_absolute {
+        format!("/{joined}")
+    } else if joined.is_empty() {
+        ".".to_owned()
+    } else {
+        joined
+    }
+}
+
+pub(crate) fn dirname(path: &str) -> &str {
+    let path = trim_trailing_separators(path);
+    match path.rfind('/') {
+        None => ".",
+        Some(0) => "/",
+        Some(index) => &path[..index],
+    }
+}
+
+/// Returns the last segment of the path, without `ext` if it ends with it.
+pub(crate) fn basename<'a>(path: &'a str, ext: Option<&str>) -> &'a str {
+    let path = trim_trailing_separators(path);
+    let name = path.rsplit('/').next().unwrap_or(path);
+    match ext {
+        Some(ext) if name != ext => name.strip_suffix(ext).unwrap_or(name),
+        _ => name,
+    }
+}
+
+/// Returns the extension of the path, including the dot, or an empty string.
+pub(crate) fn extname(path: &str) -> &str {
+    let name = basename(path, None);
+    match name.rfind('.') {
+        Some(index) if index > 0 => &name[index..],
+        _ => "",
+    }
+}
+
+pub(crate) fn path_join(base: &str, path: &str) -> String {
+    if base.is_empty() {
+        normalize_path(path)
+    } else {
+        normalize_path(&format!("{base}/{path}"))
+    }
+}
+
+/// Computes the specifier that imports `to` from the file `from`, such as
+/// `./utils/index.ts` or `../lib.ts`.
+pub(crate) fn relative_path(from: &str, to: &str) -> GritResult<String> {
+    let (from_dir, to) = if from.starts_with('/') == to.starts_with('/') {
+        (normalize_path(dirname(from)), normalize_path(to))
+    } else {
+        (
+            normalize_path(dirname(&absolute_string(from)?)),
+            normalize_path(&absolute_string(to)?),
+        )
+    };
+    let from_parts = path_segments(&from_dir);
+    let to_parts = path_segments(&to);
+    let common = from_parts
+        .iter()
+        .zip(&to_parts)
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    // Climbing out of a `..` segment needs the name of the directory above
+    // it, so resolve both paths against the working directory instead
+    if from_parts[common..].contains(&"..") {
+        return relative_path(
+            &absolute_string(&format!("{from_dir}/_"))?,
+            &absolute_string(&to)?,
+        );
+    }
+
+    let mut parts = vec![".."; from_parts.len() - common];
+    parts.extend(&to_parts[common..]);
+    let relative = parts.join("/");
+    Ok(if relative.is_empty() {
+        ".".to_owned()
+    } else if relative == ".." || relative.starts_with("../") {
+        relative
+    } else {
+        format!("./{relative}")
+    })
+}
+
+fn path_segments(path: &str) -> Vec<&str> {
+    path.split('/')
+        .filter(|part| !part.is_empty() && *part != ".")
+        .collect()
+}
+
+fn trim_trailing_separators(path: &str) -> &str {
+    match path.trim_end_matches('/') {
+        "" if path.starts_with('/') => "/",
+        trimmed => trimmed,
+    }
+}
+
+fn absolute_string(path: &str) -> GritResult<String> {
+    let absolute = Path::new(path)
+        .absolutize()
+        .map_err(|_| GritPatternError::new(format!("could not build absolute path from {path}")))?;
+    Ok(absolute.to_string_lossy().replace('\\', "/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_paths() {
+        assert_eq!(normalize_path("./src//lib/../index.ts"), "src/index.ts");
+        assert_eq!(normalize_path("../a/./b/.."), "../a");
+        assert_eq!(normalize_path("/../a"), "/a");
+        assert_eq!(normalize_path("a/.."), ".");
+    }
+
+    #[test]
+    fn splits_paths() {
+        assert_eq!(dirname("src/index.ts"), "src");
+        assert_eq!(dirname("index.ts"), ".");
+        assert_eq!(dirname("/index.ts"), "/");
+        assert_eq!(basename("src/index.test.ts", Some(".ts")), "index.test");
+        assert_eq!(basename("src/lib/", None), "lib");
+        assert_eq!(extname("src/index.test.ts"), ".ts");
+        assert_eq!(extname("src/.eslintrc"), "");
+        assert_eq!(path_join("src/lib", "../utils/x.ts"), "src/utils/x.ts");
+    }
+
+    #[test]
+    fn computes_relative_paths() {
+        assert_eq!(
+            relative_path("src/pages/home.tsx", "src/ui/button.tsx").unwrap(),
+            "../ui/button.tsx"
+        );
+        assert_eq!(
+            relative_path("/repo/src/index.ts", "/repo/src/lib/util.ts").unwrap(),
+            "./lib/util.ts"
+        );
+        assert_eq!(relative_path("index.ts", "util.ts").unwrap(), "./util.ts");
+        assert_eq!(relative_path("src/a/b.ts", "src").unwrap(), "..");
+        assert_eq!(
+            relative_path("/index.ts", "/lib/util.ts").unwrap(),
+            "./lib/util.ts"
+        );
+        assert_eq!(
+            relative_path("/lib/util.ts", "/index.ts").unwrap(),
+            "../index.ts"
+        );
+        assert_eq!(relative_path("../a/b.ts", "../c.ts").unwrap(), "../c.ts");
+
+        let cwd = std::env::current_dir().unwrap();
+        let name = cwd.file_name().unwrap().to_string_lossy();
+        assert_eq!(
+            relative_path("../a/b.ts", "c.ts").unwrap(),
+            format!("../{name}/c.ts")
+        );
+    }
+}
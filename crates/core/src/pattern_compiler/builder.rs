@@ -12,6 +12,7 @@ use crate::{
     built_in_functions::{BuiltInFunction, BuiltIns, CallbackFn},
     foreign_function_definition::ForeignFunctionDefinition,
     problem::{MarzanoQueryContext, Problem},
+    variables::{forward_injected_variables, register_injected_variables},
 };
 use crate::{built_in_functions::CallableFn, pattern_compiler::compiler::DefinitionOutput};
 use anyhow::{bail, Result};
@@ -24,7 +25,7 @@ use grit_pattern_matcher::{
         Predicate, PredicateDefinition, Rewrite, VariableSource, Where,
     },
 };
-use grit_util::{AnalysisLogs, Ast, FileRange};
+use grit_util::{AnalysisLogs, Ast, FileRange, Language};
 
 use marzano_language::{
     self, grit_parser::MarzanoGritParser, language::Tree, target_language::TargetLanguage,
@@ -47,6 +48,8 @@ pub struct CompiledPatternBuilder {
     current_scope_index: usize,
     vars_array: Vec<Vec<VariableSource>>,
     global_vars: BTreeMap<String, usize>,
+    /// Values of the variables injected from outside the pattern, by global index
+    injected_values: BTreeMap<usize, serde_json::Value>,
 
     pattern_definition_indices: BTreeMap<String, DefinitionInfo>,
     pattern_definitions: Vec<PatternDefinition<MarzanoQueryContext>>,
@@ -75,7 +78,6 @@ impl CompiledPatternBuilder {
         )
     }
 
-    #[allow(clippy::too_many_arguments)]
     pub fn start(
         src: String,
         libs: &BTreeMap<String, String>,
@@ -83,6 +85,29 @@ impl CompiledPatternBuilder {
         name: Option<String>,
         grit_parser: &mut MarzanoGritParser,
         custom_built_ins: Option<BuiltIns>,
+    ) -> Result<Self> {
+        Self::start_with_variables(
+            src,
+            libs,
+            lang,
+            name,
+            grit_parser,
+            custom_built_ins,
+            &BTreeMap::new(),
+        )
+    }
+
+    /// Start a pattern with global variables bound to the given values,
+    /// which also fill in the parameters of a top-level pattern call.
+    #[allow(clippy::too_many_arguments)]
+    pub fn start_with_variables(
+        src: String,
+        libs: &BTreeMap<String, String>,
+        lang: TargetLanguage,
+        name: Option<String>,
+        grit_parser: &mut MarzanoGritParser,
+        custom_built_ins: Option<BuiltIns>,
+        variables: &BTreeMap<String, serde_json::Value>,
     ) -> Result<Self> {
         if src == "." {
             let error = ". never matches and should not be used as a pattern. Did you mean to run 'grit apply <pattern> .'?";
@@ -97,6 +122,7 @@ impl CompiledPatternBuilder {
         }
         let mut logs: AnalysisLogs = vec![].into();
         let mut global_vars = build_standard_global_vars();
+        let injected_values = register_injected_variables(variables, &mut global_vars)?;
         let is_multifile = is_multifile(&root, libs, grit_parser)?;
         let has_limit = has_limit(&root, libs, grit_parser)?;
         let libs = filter_libs(libs, &src, grit_parser, !is_multifile)?;
@@ -144,7 +170,7 @@ impl CompiledPatternBuilder {
             logs: &mut logs,
         };
 
-        let pattern = if let Some(node) = root.child_by_field_name("pattern") {
+        let mut pattern = if let Some(node) = root.child_by_field_name("pattern") {
             PatternCompiler::from_node(&node, &mut node_context)?
         } else {
             let long_message = "No pattern found.
@@ -153,6 +179,13 @@ impl CompiledPatternBuilder {
         Check out the docs at https://docs.grit.io for help with writing patterns.";
             bail!("{}", long_message);
         };
+        forward_injected_variables(
+            &mut pattern,
+            &pattern_definitions,
+            &global_vars,
+            &injected_values,
+            lang.metavariable_prefix(),
+        )?;
 
         Ok(Self {
             tree: Some(src_tree),
@@ -167,6 +200,7 @@ impl CompiledPatternBuilder {
             vars,
             vars_array,
             global_vars,
+            injected_values,
 
             pattern_definition_indices,
             pattern_definitions,
@@ -292,7 +326,8 @@ impl CompiledPatternBuilder {
             target_builder.is_multifile,
             target_builder.has_limit,
             target_builder.name,
-            VariableLocations::new(target_builder.vars_array),
+            VariableLocations::new(target_builder.vars_array)
+                .with_values(target_builder.injected_values),
             target_builder.pattern_definitions,
             target_builder.predicate_definitions,
             target_builder.function_definitions,
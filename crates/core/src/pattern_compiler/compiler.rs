@@ -9,7 +9,7 @@ use super::{
     NodeCompiler,
 };
 use crate::{
-    built_in_functions::BuiltIns,
+    built_in_functions::{from_json_value, BuiltIns},
     foreign_function_definition::ForeignFunctionDefinition,
     problem::{MarzanoQueryContext, Problem},
     variables::{register_variable, register_variable_optional_range, FileLocation},
@@ -682,13 +682,161 @@ pub fn src_to_problem_libs(
     file_ranges: Option<Vec<FileRange>>,
     custom_built_ins: Option<BuiltIns>,
     injected_limit: Option<usize>,
+) -> Result<CompilationResult> {
+    src_to_problem_libs_with_variables(
+        src,
+        libs,
+        default_lang,
+        name,
+        file_ranges,
+        custom_built_ins,
+        injected_limit,
+        &BTreeMap::new(),
+    )
+}
+
+/// Compiles a pattern with global variables bound to the given values, such
+/// as those passed to `grit apply --var`.
+#[allow(clippy::too_many_arguments)]
+pub fn src_to_problem_libs_with_variables(
+    src: String,
+    libs: &BTreeMap<String, String>,
+    default_lang: TargetLanguage,
+    name: Option<String>,
+    file_ranges: Option<Vec<FileRange>>,
+    custom_built_ins: Option<BuiltIns>,
+    injected_limit: Option<usize>,
+    variables: &BTreeMap<String, serde_json::Value>,
 ) -> Result<CompilationResult> {
     let mut parser = MarzanoGritParser::new()?;
     let src_tree = parser.parse_file(&src, Some(Path::new(DEFAULT_FILE_NAME)))?;
     let lang = TargetLanguage::from_tree(&src_tree).unwrap_or(default_lang);
-    let builder =
-        CompiledPatternBuilder::start(src, libs, lang, name, &mut parser, custom_built_ins)?;
-    builder.compile(file_ranges, injected_limit, true)
+    let mut variants = split_by_language(&src, &src_tree, &lang).into_iter();
+    let Some((lang, src)) = variants.next() else {
+        let builder = CompiledPatternBuilder::start_with_variables(
+            src,
+            libs,
+            lang,
+            name,
+            &mut parser,
+            custom_built_ins,
+            variables,
+        )?;
+        return builder.compile(file_ranges, injected_limit, true);
+    };
+    let builder = CompiledPatternBuilder::start_with_variables(
+        src,
+        libs,
+        lang,
+        name.clone(),
+        &mut parser,
+        custom_built_ins.clone(),
+        variables,
+    )?;
+    let mut result = builder.compile(file_ranges.clone(), injected_limit, true)?;
+    for (lang, src) in variants {
+        let builder = CompiledPatternBuilder::start_with_variables(
+            src,
+            libs,
+            lang,
+            name.clone(),
+            &mut parser,
+            custom_built_ins.clone(),
+            variables,
+        )?;
+        let variant = builder.compile(file_ranges.clone(), injected_limit, true)?;
+        result
+            .compilation_warnings
+            .extend(variant.compilation_warnings.logs());
+        result.problem.variants.push(variant.problem);
+    }
+    Ok(result)
+}
+
+/// Splits a pattern whose top-level `or` or `any` has branches in several
+/// languages, marked with language-specific snippets such as `python"..."`,
+/// into one source per language. Branches without such snippets belong to the
+/// pattern's own language.
+///
+/// Branches in other languages are replaced with `false`, padded so that
+/// positions in each source still match the original.
+fn split_by_language(
+    src: &str,
+    tree: &Tree,
+    lang: &TargetLanguage,
+) -> Vec<(TargetLanguage, String)> {
+    let root = tree.root_node();
+    let Some(pattern) = root
+        .children_by_field_name("pattern")
+        .find(|n| n.node.is_named())
+        .filter(|n| matches!(n.node.kind().as_ref(), "patternOr" | "patternAny"))
+    else {
+        return vec![];
+    };
+
+    let mut languages: Vec<TargetLanguage> = Vec::new();
+    let mut branches = Vec::new();
+    for branch in pattern.named_children_by_field_name("patterns") {
+        let branch_lang = snippet_language(&branch).unwrap_or(*lang);
+        if !languages
+            .iter()
+            .any(|l| l.to_module_language() == branch_lang.to_module_language())
+        {
+            languages.push(branch_lang);
+        }
+        branches.push((branch.byte_range(), branch_lang.to_module_language()));
+    }
+    if languages.len() < 2 {
+        return vec![];
+    }
+
+    languages
+        .into_iter()
+        .map(|variant| {
+            let mut source = src.to_string();
+            // Replace from the end, so earlier ranges stay valid if a
+            // branch is too short to be padded
+            for (range, branch_lang) in branches.iter().rev() {
+                if *branch_lang != variant.to_module_language() {
+                    let blank = blank_branch(&src[range.start..range.end]);
+                    source.replace_range(range.start..range.end, &blank);
+                }
+            }
+            (variant, source)
+        })
+        .collect()
+}
+
+fn snippet_language(node: &NodeWithSource) -> Option<TargetLanguage> {
+    traverse(node.walk(), Order::Pre)
+        .filter(|n| n.node.kind() == "languageSpecificSnippet")
+        .find_map(|n| {
+            let name = n.child_by_field_name("language")?.text().ok()?;
+            TargetLanguage::from_string(name.trim(), None)
+        })
+}
+
+/// Replaces `branch` with `false`, keeping its length and line breaks so that
+/// positions after it are unchanged. `false` goes on the first line of the
+/// branch with room for it; only a branch too short to hold it grows.
+fn blank_branch(branch: &str) -> String {
+    const BLANK: &str = "false";
+    let mut offset = 0;
+    let start = branch.split('\n').find_map(|line| {
+        let start = offset;
+        offset += line.len() + 1;
+        (line.len() >= BLANK.len()).then_some(start)
+    });
+    let Some(start) = start else {
+        let newlines = "\n".repeat(branch.matches('\n').count());
+        return format!("{BLANK}{newlines}");
+    };
+    let mut blank: Vec<u8> = branch
+        .bytes()
+        .map(|b| if b == b'\n' { b'\n' } else { b' ' })
+        .collect();
+    blank[start..start + BLANK.len()].copy_from_slice(BLANK.as_bytes());
+    String::from_utf8(blank).unwrap_or_default()
 }
 
 /// Only use this for testing
@@ -706,11 +854,20 @@ pub fn src_to_problem(src: String, default_lang: TargetLanguage) -> Result<Probl
 pub struct VariableLocations {
     /// List of scopes, each scope with an array of variables
     pub(crate) locations: Vec<Vec<VariableSource>>,
+    /// Values bound to global variables before execution, by index
+    pub(crate) values: BTreeMap<usize, serde_json::Value>,
 }
 
 impl VariableLocations {
     pub(crate) fn new(locations: Vec<Vec<VariableSource>>) -> Self {
-        Self { locations }
+        Self {
+            locations,
+            values: BTreeMap::new(),
+        }
+    }
+
+    pub(crate) fn with_values(self, values: BTreeMap<usize, serde_json::Value>) -> Self {
+        Self { values, ..self }
     }
 
     pub(crate) fn initial_bindings(
@@ -718,10 +875,18 @@ impl VariableLocations {
     ) -> Vec<Vec<Vec<Box<VariableContent<MarzanoQueryContext>>>>> {
         self.locations
             .iter()
-            .map(|scope| {
+            .enumerate()
+            .map(|(scope_index, scope)| {
                 vec![scope
                     .iter()
-                    .map(|s| Box::new(VariableContent::new(s.name().to_string())))
+                    .enumerate()
+                    .map(|(index, s)| {
+                        let mut content = VariableContent::new(s.name().to_string());
+                        if scope_index == GLOBAL_VARS_SCOPE_INDEX as usize {
+                            content.value = self.values.get(&index).cloned().map(from_json_value);
+                        }
+                        Box::new(content)
+                    })
                     .collect()]
             })
             .collect()
@@ -817,4 +982,58 @@ mod tests {
             tsx.language_name()
         );
     }
+
+    #[test]
+    fn flavor_parsing() {
+        let cases = [
+            ("language python(2)", "Python2"),
+            ("language python(3)", "Python"),
+            ("language python", "Python"),
+            ("language js(typescript, jsx)", "TSX"),
+        ];
+        for (pattern, expected) in cases {
+            assert_eq!(
+                TargetLanguage::get_language(pattern)
+                    .unwrap()
+                    .language_name(),
+                expected,
+                "{}",
+                pattern
+            );
+        }
+    }
+
+    #[test]
+    fn python2_print_statements() {
+        let libs = BTreeMap::new();
+        let pattern = r#"
+            language python(2)
+            `print $x` => `print($x)`
+        "#
+        .to_owned();
+        let pattern = src_to_problem_libs(
+            pattern,
+            &libs,
+            PatternLanguage::Python.try_into().unwrap(),
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(pattern.problem.language.language_name(), "Python2");
+    }
+
+    #[test]
+    fn blank_branch_keeps_positions() {
+        let branch = "py\"\nfoo($x)\n\"";
+        let blank = blank_branch(branch);
+        assert_eq!(blank.len(), branch.len());
+        assert_eq!(blank, "   \nfalse  \n ");
+
+        let branch = "python\"é\"";
+        let blank = blank_branch(branch);
+        assert_eq!(blank.len(), branch.len());
+        assert!(blank.starts_with("false"));
+    }
 }
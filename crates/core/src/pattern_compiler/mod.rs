@@ -79,5 +79,5 @@ pub(crate) mod within_compiler;
 
 pub use builder::build_standard_global_vars;
 pub use builder::CompiledPatternBuilder;
-pub use compiler::{src_to_problem_libs, CompilationResult};
+pub use compiler::{src_to_problem_libs, src_to_problem_libs_with_variables, CompilationResult};
 pub(crate) use node_compiler::NodeCompiler;
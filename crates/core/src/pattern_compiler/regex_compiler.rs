@@ -4,7 +4,7 @@ use super::{
 };
 use crate::problem::MarzanoQueryContext;
 use anyhow::{anyhow, bail, Result};
-use grit_pattern_matcher::pattern::{RegexLike, RegexPattern};
+use grit_pattern_matcher::pattern::{cached_regex, whole_string_regex, RegexLike, RegexPattern};
 use grit_util::{AnalysisLogBuilder, AstNode, Language};
 use marzano_util::node_with_source::NodeWithSource;
 
@@ -32,6 +32,10 @@ impl NodeCompiler for RegexCompiler {
                 .ok_or_else(|| anyhow!("invalid regex prefix"))?
                 .strip_suffix('\"')
                 .ok_or_else(|| anyhow!("invalid regex postfix"))?;
+            // compiling now reports invalid regexes early, and warms the cache
+            // with the whole string form that matching uses
+            cached_regex(&whole_string_regex(regex))
+                .map_err(|e| anyhow!("invalid regex {}: {}", regex, e))?;
 
             RegexLike::Regex(regex.to_string())
         } else {
@@ -22,7 +22,11 @@ use grit_pattern_matcher::{
 use grit_util::VariableMatch;
 
 use log::error;
-use marzano_language::{language::Tree, target_language::TargetLanguage};
+use marzano_language::{
+    detection::LanguageOverrides,
+    language::Tree,
+    target_language::{PatternLanguage, TargetLanguage},
+};
 use marzano_util::{
     cache::{GritCache, NullCache},
     hasher::hash,
@@ -60,6 +64,12 @@ pub struct Problem {
     pub(crate) predicate_definitions: Vec<PredicateDefinition<MarzanoQueryContext>>,
     pub(crate) function_definitions: Vec<GritFunctionDefinition<MarzanoQueryContext>>,
     pub(crate) foreign_function_definitions: Vec<ForeignFunctionDefinition>,
+    /// The same pattern compiled for the other languages it declares, when
+    /// its top-level `or` or `any` has branches in several languages
+    pub(crate) variants: Vec<Problem>,
+    /// The `languageOverrides` of the repository the pattern runs on, which
+    /// decide the language of the files they match
+    pub language_overrides: LanguageOverrides,
 }
 
 impl Problem {
@@ -83,6 +93,39 @@ impl Problem {
         }
         defs
     }
+
+    /// All the languages the pattern targets, starting with its main language.
+    pub fn languages(&self) -> impl Iterator<Item = &TargetLanguage> {
+        std::iter::once(&self.language).chain(self.variants.iter().map(|v| &v.language))
+    }
+
+    pub fn match_extension(&self, ext: &str) -> bool {
+        self.languages().any(|lang| lang.match_extension(ext))
+    }
+
+    pub fn match_path(&self, path: &Path) -> bool {
+        self.languages()
+            .any(|lang| lang.match_path(path, &self.language_overrides))
+    }
+
+    /// Picks the variant of the pattern to run on the given file, preferring
+    /// the language the file is detected as over other languages that can
+    /// also process it.
+    fn variant_for_file(&self, name: &str) -> &Problem {
+        if self.variants.is_empty() {
+            return self;
+        }
+        let path = Path::new(name);
+        let problems = || std::iter::once(self).chain(self.variants.iter());
+        let file_lang = PatternLanguage::from_path(path, &self.language_overrides);
+        problems()
+            .find(|problem| Some(problem.language.to_module_language()) == file_lang)
+            .or_else(|| {
+                problems()
+                    .find(|problem| problem.language.match_path(path, &self.language_overrides))
+            })
+            .unwrap_or(self)
+    }
 }
 
 enum FilePattern {
@@ -147,6 +190,10 @@ impl Problem {
             .to_string()
             .as_str(),
         );
+        // Injected values change what the pattern matches, so they are part of its identity
+        if !variables.values.is_empty() {
+            hasher.update(format!("{:?}", variables.values));
+        }
         let hash = hasher.finalize().into();
 
         Self {
@@ -163,6 +210,8 @@ impl Problem {
             predicate_definitions,
             function_definitions,
             foreign_function_definitions,
+            variants: vec![],
+            language_overrides: LanguageOverrides::default(),
         }
     }
 
@@ -482,7 +531,24 @@ impl Problem {
         #[cfg(feature = "grit_tracing")]
         let parent_cx = parent_span.context();
 
-        if self.is_multifile {
+        if self.is_multifile && !self.variants.is_empty() {
+            let mut grouped: Vec<(&Problem, Vec<_>)> = Vec::new();
+            for file in files {
+                let problem = self.variant_for_file(&file.name());
+                match grouped.iter_mut().find(|(p, _)| std::ptr::eq(*p, problem)) {
+                    Some((_, group)) => group.push(file),
+                    None => grouped.push((problem, vec![file])),
+                }
+            }
+            for (problem, files) in grouped {
+                // The pattern's own language would land back in this branch
+                if std::ptr::eq(problem, self) {
+                    self.build_and_execute_resolved_pattern(&tx, files, context, &NullCache::new());
+                } else {
+                    problem.execute_shared(files, context, tx.clone(), cache);
+                }
+            }
+        } else if self.is_multifile {
             self.build_and_execute_resolved_pattern(&tx, files, context, &NullCache::new());
         } else {
             rayon::scope(|s| {
@@ -498,8 +564,9 @@ impl Problem {
                     event!(Level::INFO, "spawn execute_shared_body");
 
                     files.into_par_iter().for_each_with(tx, |sender, f| {
+                        let problem = self.variant_for_file(&f.name());
                         let vec = vec![f];
-                        self.build_and_execute_resolved_pattern(sender, vec, context, cache);
+                        problem.build_and_execute_resolved_pattern(sender, vec, context, cache);
                     });
                 })
             })
@@ -515,7 +582,10 @@ impl Problem {
         let file_registry: FileRegistry<MarzanoQueryContext> = FileRegistry::new_from_paths(vec![]);
 
         let bindings = self.variables.initial_bindings();
-        let state = State::new(bindings, file_registry);
+        let mut state = State::new(bindings, file_registry);
+        if let Some(seed) = context.seed {
+            state.reseed_rng(seed);
+        }
 
         (
             state,
@@ -563,6 +633,10 @@ impl Problem {
 
         let file_registry = FileRegistry::new_from_paths(file_names);
         let mut state = State::new(bindings, file_registry);
+        // Each file starts from the same seed, so results don't depend on scheduling
+        if let Some(seed) = context.runtime.seed {
+            state.reseed_rng(seed);
+        }
 
         let the_new_files = state.bindings[GLOBAL_VARS_SCOPE_INDEX as usize]
             .last_mut()
@@ -1,4 +1,4 @@
-use crate::pattern_compiler::src_to_problem_libs;
+use crate::pattern_compiler::{src_to_problem_libs, src_to_problem_libs_with_variables};
 use anyhow::{anyhow, Context, Result};
 use api::MatchResult;
 use built_in_functions::{BuiltInFunction, BuiltIns};
@@ -14,7 +14,7 @@ use marzano_language::language::MarzanoLanguage;
 use marzano_language::target_language::{PatternLanguage, TargetLanguage};
 use marzano_resolved_pattern::MarzanoResolvedPattern;
 use marzano_util::rich_path::RichFile;
-use marzano_util::runtime::{ExecutionContext, LanguageModelAPI};
+use marzano_util::runtime::{ErrorTolerance, ExecutionContext, LanguageModelAPI};
 use problem::{MarzanoQueryContext, Problem};
 use similar::{ChangeTag, TextDiff};
 use std::collections::{BTreeMap, HashMap};
@@ -3342,27 +3342,27 @@ fn kotlin_replace_string_literal() {
 }
 
 #[test]
-fn python_replace_string_literal() {
+fn dart_replace_widget_argument() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
-                |language python
+                |language dart
                 |
-                |`name = $name` => `name = "Marzano"`
+                |`Text($text, style: $style)` => `Text($text)`
                 |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                |def main():
-                |   name = "Hello"
-                |
+                |Widget build(BuildContext context) {
+                |  return Text('Hello', style: bold);
+                |}
                 |"#
             .trim_margin()
             .unwrap(),
             expected: r#"
-                |def main():
-                |   name = "Marzano"
-                |
+                |Widget build(BuildContext context) {
+                |  return Text('Hello');
+                |}
                 |"#
             .trim_margin()
             .unwrap(),
@@ -3372,26 +3372,30 @@ fn python_replace_string_literal() {
 }
 
 #[test]
-fn java_replace_string_literal() {
+fn dart_replace_statement() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
-                |language java
+                |language dart
                 |
-                |`public String name = $name;` => `public String name = "Marzano";`
+                |`setState($callback);` => `update($callback);`
                 |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                |public class Main {
-                |    public String name = "Hello";
+                |class Counter {
+                |  void increment() {
+                |    setState(() => count++);
+                |  }
                 |}
                 |"#
             .trim_margin()
             .unwrap(),
             expected: r#"
-                |public class Main {
-                |    public String name = "Marzano";
+                |class Counter {
+                |  void increment() {
+                |    update(() => count++);
+                |  }
                 |}
                 |"#
             .trim_margin()
@@ -3402,69 +3406,93 @@ fn java_replace_string_literal() {
 }
 
 #[test]
-fn kotlin_match_simple_assignment() {
-    run_test_match({
-        TestArg {
+fn graphql_rename_argument() {
+    run_test_expected({
+        TestArgExpected {
             pattern: r#"
-                |language kotlin
+                |language graphql
                 |
-                |`val key = $value`
+                |`user(id: $id)` => `user(userId: $id)`
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |query GetUser($id: ID!) {
+                |  user(id: $id) {
+                |    name
+                |  }
+                |}
+                |"#
+            .trim_margin()
+            .unwrap(),
+            expected: r#"
+                |query GetUser($id: ID!) {
+                |  user(userId: $id) {
+                |    name
+                |  }
+                |}
                 |"#
             .trim_margin()
             .unwrap(),
-            source: r#"val key = "value""#.to_string(),
         }
     })
     .unwrap();
 }
 
 #[test]
-fn python_match_simple_assignment() {
-    run_test_match({
-        TestArg {
+fn protobuf_rename_package() {
+    run_test_expected({
+        TestArgExpected {
             pattern: r#"
-                |language python
+                |language protobuf
                 |
-                |`key = $value`
+                |`package foo.v1;` => `package bar.v1;`
                 |"#
             .trim_margin()
             .unwrap(),
-            source: r#"key = "value""#.to_string(),
-        }
-    })
-    .unwrap();
-}
-
-#[test]
-fn javascript_match_simple_assignment() {
-    run_test_match({
-        TestArg {
-            pattern: r#"
-                |language js
+            source: r#"
+                |syntax = "proto3";
                 |
-                |`const key = $value`
+                |package foo.v1;
+                |
+                |message User {
+                |  string name = 1;
+                |}
+                |"#
+            .trim_margin()
+            .unwrap(),
+            expected: r#"
+                |syntax = "proto3";
+                |
+                |package bar.v1;
+                |
+                |message User {
+                |  string name = 1;
+                |}
                 |"#
             .trim_margin()
             .unwrap(),
-            source: r#"const key = "value""#.to_string(),
         }
     })
     .unwrap();
 }
 
 #[test]
-fn kotlin_multi_args_snippet() {
+fn protobuf_match_deprecated_field() {
     run_test_match({
         TestArg {
             pattern: r#"
-                |language kotlin
+                |language protobuf
                 |
-                |`arrayOf($args)`
+                |`$type $name = $number [deprecated = true];`
                 |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                |arrayOf(1, 2, 3)
+                |message User {
+                |  string name = 1;
+                |  string email = 2 [deprecated = true];
+                |}
                 |"#
             .trim_margin()
             .unwrap(),
@@ -3474,33 +3502,36 @@ fn kotlin_multi_args_snippet() {
 }
 
 #[test]
-fn kotlin_includes_any() {
+fn xml_append_child_element() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
-                |language kotlin
+                |language xml
                 |
-                |`println($_)` as $haystack where {
-                |   $haystack <: includes any { "Hello", "handsome" }
-                |} => `println("Goodbye world!")`
+                |`<dependencies>$deps</dependencies>` where {
+                |    $deps += `<dependency><artifactId>slf4j-api</artifactId></dependency>`
+                |}
                 |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                |println("Hello world!");
-                |println("Hello handsome!");
-                |println("But not me, handsome.");
-                |println("Hi, Hello world!");
-                |println("Just not this....");
+                |<project>
+                |  <dependencies>
+                |    <dependency><artifactId>junit</artifactId></dependency>
+                |    <dependency><artifactId>guava</artifactId></dependency>
+                |  </dependencies>
+                |</project>
                 |"#
             .trim_margin()
             .unwrap(),
             expected: r#"
-                |println("Goodbye world!");
-                |println("Goodbye world!");
-                |println("Goodbye world!");
-                |println("Goodbye world!");
-                |println("Just not this....");
+                |<project>
+                |  <dependencies>
+                |    <dependency><artifactId>junit</artifactId></dependency>
+                |    <dependency><artifactId>guava</artifactId></dependency>
+                |    <dependency><artifactId>slf4j-api</artifactId></dependency>
+                |  </dependencies>
+                |</project>
                 |"#
             .trim_margin()
             .unwrap(),
@@ -3510,27 +3541,34 @@ fn kotlin_includes_any() {
 }
 
 #[test]
-fn kotlin_simple_function() {
+fn xml_append_child_after_lone_element() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
-                |language kotlin
+                |language xml
                 |
-                |`fun $name($var: $type) $body` => `fun modified($var: $type) $body`
+                |`<dependencies>$deps</dependencies>` where {
+                |    $deps += `<dependency><artifactId>slf4j-api</artifactId></dependency>`
+                |}
                 |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                |fun hello(name: String) {
-                |    println("Hello, $name!")
-                |}
+                |<project>
+                |  <dependencies>
+                |    <dependency><artifactId>junit</artifactId></dependency>
+                |  </dependencies>
+                |</project>
                 |"#
             .trim_margin()
             .unwrap(),
             expected: r#"
-                |fun modified(name: String) {
-                |    println("Hello, $name!")
-                |}
+                |<project>
+                |  <dependencies>
+                |    <dependency><artifactId>junit</artifactId></dependency>
+                |    <dependency><artifactId>slf4j-api</artifactId></dependency>
+                |  </dependencies>
+                |</project>
                 |"#
             .trim_margin()
             .unwrap(),
@@ -3540,18 +3578,27 @@ fn kotlin_simple_function() {
 }
 
 #[test]
-fn multi_args_snippet() {
-    run_test_match({
-        TestArg {
+fn xml_replace_namespaced_attribute() {
+    run_test_expected({
+        TestArgExpected {
             pattern: r#"
-                |language js
+                |language xml
                 |
-                |`Array($args)`
+                |`<uses-sdk android:minSdkVersion="$version" />` => `<uses-sdk android:minSdkVersion="21" />`
                 |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                |Array(1, 2, 3);
+                |<manifest xmlns:android="http://schemas.android.com/apk/res/android">
+                |  <uses-sdk android:minSdkVersion="16" />
+                |</manifest>
+                |"#
+            .trim_margin()
+            .unwrap(),
+            expected: r#"
+                |<manifest xmlns:android="http://schemas.android.com/apk/res/android">
+                |  <uses-sdk android:minSdkVersion="21" />
+                |</manifest>
                 |"#
             .trim_margin()
             .unwrap(),
@@ -3561,33 +3608,20 @@ fn multi_args_snippet() {
 }
 
 #[test]
-fn hcl_pair() {
+fn xml_unprefixed_name_matches_any_namespace() {
     run_test_match({
         TestArg {
             pattern: r#"
-                |language hcl
+                |language xml
                 |
-                |`$arg: $red`
+                |`<dependency>$_</dependency>`
                 |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                |default_address = "127.0.0.1"
-                |default_message = upper("Incident: ${incident}")
-                |default_options = {
-                |  priority: "High",
-                |  color: "Red"
-                |}
-                |
-                |incident_rules {
-                |  # Rule number 1
-                |  rule "down_server" "infrastructure" {
-                |    incident = 100
-                |    options  = var.override_options ? var.override_options : var.default_options
-                |    server   = default_address
-                |    message  = default_message
-                |  }
-                |}
+                |<m:project xmlns:m="http://maven.apache.org/POM/4.0.0">
+                |  <m:dependency>junit</m:dependency>
+                |</m:project>
                 |"#
             .trim_margin()
             .unwrap(),
@@ -3597,35 +3631,33 @@ fn hcl_pair() {
 }
 
 #[test]
-fn hcl_implicit_regex() {
+fn lua_rewrite_require() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
-                |language hcl
+                |language lua
                 |
-                |`required_version = "~> $current_version"` => `required_version = "~> v1.5.0"`
+                |`local $name = require("socket")` => `local $name = require("luasocket")`
                 |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                |default_address = "127.0.0.1"
-                |required_version = "~> v5.4.0"
-                |incident_rules {
-                |  rule "down_server" "infrastructure" {
-                |    required_version = "~> v1.0.0"
-                |  }
-                |}
+                |local socket = require("socket")
+                |local json = require("json")
+                |
+                |function fetch(url)
+                |  return socket.connect(url, 80)
+                |end
                 |"#
             .trim_margin()
             .unwrap(),
             expected: r#"
-                |default_address = "127.0.0.1"
-                |required_version = "~> v1.5.0"
-                |incident_rules {
-                |  rule "down_server" "infrastructure" {
-                |    required_version = "~> v1.5.0"
-                |  }
-                |}
+                |local socket = require("luasocket")
+                |local json = require("json")
+                |
+                |function fetch(url)
+                |  return socket.connect(url, 80)
+                |end
                 |"#
             .trim_margin()
             .unwrap(),
@@ -3635,31 +3667,18 @@ fn hcl_implicit_regex() {
 }
 
 #[test]
-fn includes_or() {
-    run_test_expected({
-        TestArgExpected {
+fn lua_match_table_field() {
+    run_test_match({
+        TestArg {
             pattern: r#"
-                |language js
+                |language lua
                 |
-                |`console.log($_)` as $haystack where {
-                |   $haystack <: includes or { "world", "handsome" }
-                |} => `console.log("Goodbye world!")`
+                |`debug = true`
                 |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                |console.log("Hello world!");
-                |console.log("Hello handsome!");
-                |console.log("But not me, sadly.");
-                |console.log("Hi, Hello world!");
-                |"#
-            .trim_margin()
-            .unwrap(),
-            expected: r#"
-                |console.log("Goodbye world!");
-                |console.log("Goodbye world!");
-                |console.log("But not me, sadly.");
-                |console.log("Goodbye world!");
+                |local config = { name = "app", debug = true }
                 |"#
             .trim_margin()
             .unwrap(),
@@ -3669,31 +3688,33 @@ fn includes_or() {
 }
 
 #[test]
-fn includes_and() {
+fn nix_bump_flake_input() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
-                |language js
+                |language nix
                 |
-                |`console.log($_)` as $haystack where {
-                |   $haystack <: includes and { "Hello", "handsome" }
-                |} => `console.log("Goodbye world!")`
+                |`nixpkgs.url = "github:NixOS/nixpkgs/nixos-23.11";` => `nixpkgs.url = "github:NixOS/nixpkgs/nixos-24.05";`
                 |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                |console.log("Hello world!");
-                |console.log("Hello handsome!");
-                |console.log("But not me, handsome.");
-                |console.log("Hi, Hello world!");
+                |{
+                |  inputs = {
+                |    nixpkgs.url = "github:NixOS/nixpkgs/nixos-23.11";
+                |    flake-utils.url = "github:numtide/flake-utils";
+                |  };
+                |}
                 |"#
             .trim_margin()
             .unwrap(),
             expected: r#"
-                |console.log("Hello world!");
-                |console.log("Goodbye world!");
-                |console.log("But not me, handsome.");
-                |console.log("Hi, Hello world!");
+                |{
+                |  inputs = {
+                |    nixpkgs.url = "github:NixOS/nixpkgs/nixos-24.05";
+                |    flake-utils.url = "github:numtide/flake-utils";
+                |  };
+                |}
                 |"#
             .trim_margin()
             .unwrap(),
@@ -3703,33 +3724,29 @@ fn includes_and() {
 }
 
 #[test]
-fn includes_any() {
+fn nix_rewrite_function_application() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
-                |language js
+                |language nix
                 |
-                |`console.log($_)` as $haystack where {
-                |   $haystack <: includes any { "Hello", "handsome" }
-                |} => `console.log("Goodbye world!")`
+                |`import $path { inherit system; }` => `$path.legacyPackages.${system}`
                 |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                |console.log("Hello world!");
-                |console.log("Hello handsome!");
-                |console.log("But not me, handsome.");
-                |console.log("Hi, Hello world!");
-                |console.log("Just not this....");
+                |let
+                |  pkgs = import nixpkgs { inherit system; };
+                |in
+                |pkgs.hello
                 |"#
             .trim_margin()
             .unwrap(),
             expected: r#"
-                |console.log("Goodbye world!");
-                |console.log("Goodbye world!");
-                |console.log("Goodbye world!");
-                |console.log("Goodbye world!");
-                |console.log("Just not this....");
+                |let
+                |  pkgs = nixpkgs.legacyPackages.${system};
+                |in
+                |pkgs.hello
                 |"#
             .trim_margin()
             .unwrap(),
@@ -3739,31 +3756,43 @@ fn includes_any() {
 }
 
 #[test]
-fn includes_regex() {
+fn scss_rename_mixin_include() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
-                |language js
+                |language scss
                 |
-                |`console.log($_)` as $haystack where {
-                |   $haystack <: includes r"Hello"
-                |} => `console.log("Goodbye world!")`
+                |`@include old-button(^color);` => `@include new-button(^color);`
                 |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                |console.log("Hello world!");
-                |console.log("Hello handsome!");
-                |console.log("But not me, sadly.");
-                |console.log("Hi, Hello world!");
+                |@use "sass:math";
+                |
+                |$primary: #336699;
+                |
+                |.alert {
+                |  @include old-button($primary);
+                |  width: math.div(100%, 3);
+                |  &:hover {
+                |    @include old-button(darken($primary, 10%));
+                |  }
+                |}
                 |"#
             .trim_margin()
             .unwrap(),
             expected: r#"
-                |console.log("Goodbye world!");
-                |console.log("Goodbye world!");
-                |console.log("But not me, sadly.");
-                |console.log("Goodbye world!");
+                |@use "sass:math";
+                |
+                |$primary: #336699;
+                |
+                |.alert {
+                |  @include new-button($primary);
+                |  width: math.div(100%, 3);
+                |  &:hover {
+                |    @include new-button(darken($primary, 10%));
+                |  }
+                |}
                 |"#
             .trim_margin()
             .unwrap(),
@@ -3773,31 +3802,31 @@ fn includes_regex() {
 }
 
 #[test]
-fn includes_regex_with_capture() {
+fn scss_reuses_css_snippets() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
-                |language js
+                |language scss
                 |
-                |`console.log($_)` as $haystack where {
-                |   $haystack <: includes r"Hello (\w+)"($name)
-                |} => `console.log("Goodbye $name!")`
+                |`a { ^props }` where {
+                |  ^props <: contains `aspect-ratio: ^x` => `aspect-ratio: 3;`
+                |}
                 |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                |console.log("Hello world!");
-                |console.log("Hello handsome!");
-                |console.log("But not me, sadly.");
-                |console.log("Hi, Hello world!");
+                |a {
+                |  width: calc(100% - 80px);
+                |  aspect-ratio: $ratio;
+                |}
                 |"#
             .trim_margin()
             .unwrap(),
             expected: r#"
-                |console.log("Goodbye world!");
-                |console.log("Goodbye handsome!");
-                |console.log("But not me, sadly.");
-                |console.log("Goodbye world!");
+                |a {
+                |  width: calc(100% - 80px);
+                |  aspect-ratio: 3;
+                |}
                 |"#
             .trim_margin()
             .unwrap(),
@@ -3807,300 +3836,224 @@ fn includes_regex_with_capture() {
 }
 
 #[test]
-fn parses_simple_pattern() {
-    let pattern = r#"
-        |engine marzano(0.1)
-        |language js
-        |`console.log($msg)`"#
-        .trim_margin()
-        .unwrap();
-
-    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
-    let _pattern = src_to_problem(pattern, js_lang).unwrap();
+fn scss_match_variable_declaration() {
+    run_test_match({
+        TestArg {
+            pattern: r#"
+                |language scss
+                |
+                |`$primary: ^value;`
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |$secondary: red;
+                |$primary: #336699 !default;
+                |"#
+            .trim_margin()
+            .unwrap(),
+        }
+    })
+    .unwrap();
 }
 
 #[test]
-fn warning_rewrite_in_not() {
-    let pattern = r#"
-        |`async ($args) => { $body }` where {
-        |    $body <: not contains `try` => ` try {
-        |        $body
-        |    } catch { }`
-        |}"#
-    .trim_margin()
+fn less_rename_mixin_call() {
+    run_test_expected({
+        TestArgExpected {
+            pattern: r#"
+                |language less
+                |
+                |`.bordered($width);` => `.outlined($width);`
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |@primary: #428bca;
+                |
+                |.box {
+                |  .bordered(4px);
+                |  color: @primary;
+                |}
+                |"#
+            .trim_margin()
+            .unwrap(),
+            expected: r#"
+                |@primary: #428bca;
+                |
+                |.box {
+                |  .outlined(4px);
+                |  color: @primary;
+                |}
+                |"#
+            .trim_margin()
+            .unwrap(),
+        }
+    })
     .unwrap();
-
-    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
-    let libs = BTreeMap::new();
-    let cr = src_to_problem_libs(pattern, &libs, js_lang, None, None, None, None).unwrap();
-    assert_debug_snapshot!(cr.compilation_warnings)
 }
 
 #[test]
-fn should_log_variable() {
-    let pattern = r#"
-        |engine marzano(0.1)
-        |language js
-        |
-        |`function ($args) { $body }` => `($args) => {
-        |  $body
-        |}` where {
-        |    log(variable=$body)
-        |}
-        |"#
-    .trim_margin()
+fn python_replace_string_literal() {
+    run_test_expected({
+        TestArgExpected {
+            pattern: r#"
+                |language python
+                |
+                |`name = $name` => `name = "Marzano"`
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |def main():
+                |   name = "Hello"
+                |
+                |"#
+            .trim_margin()
+            .unwrap(),
+            expected: r#"
+                |def main():
+                |   name = "Marzano"
+                |
+                |"#
+            .trim_margin()
+            .unwrap(),
+        }
+    })
     .unwrap();
-
-    let source = r#"
-    |var increment = function (i) {
-    |    return i + 1;
-    |};"#
-        .trim_margin()
-        .unwrap();
-
-    let file = "test-file.tsx";
-
-    let context = ExecutionContext::default();
-    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
-    let pattern = src_to_problem(pattern, js_lang).unwrap();
-    let results =
-        pattern.execute_file(&RichFile::new(file.to_owned(), source.to_owned()), &context);
-    assert_grit_snapshot!(results);
 }
 
 #[test]
-fn rewrite_dot() {
-    let pattern = r#"
-            engine marzano(0.1)
-            language js
-
-            `new Date($bob)` where {
-                $bob <: . => `"fo"`
-            }"#
-    .to_owned();
-    let source = r#"
-            new Date()"#
-        .to_owned();
-    let file = "test-file.tsx";
-
-    let context = ExecutionContext::default();
-    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
-    let pattern = src_to_problem(pattern, js_lang).unwrap();
-    let results =
-        pattern.execute_file(&RichFile::new(file.to_owned(), source.to_owned()), &context);
-    assert_grit_snapshot!(results);
+fn java_replace_string_literal() {
+    run_test_expected({
+        TestArgExpected {
+            pattern: r#"
+                |language java
+                |
+                |`public String name = $name;` => `public String name = "Marzano";`
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |public class Main {
+                |    public String name = "Hello";
+                |}
+                |"#
+            .trim_margin()
+            .unwrap(),
+            expected: r#"
+                |public class Main {
+                |    public String name = "Marzano";
+                |}
+                |"#
+            .trim_margin()
+            .unwrap(),
+        }
+    })
+    .unwrap();
 }
 
 #[test]
-fn test_simple_log() {
-    let pattern = r#"
-        |language js
-        |
-        |program() as $x where {
-        |    log(message="this is a message",variable=$x)
-        |}
-        |"#
-    .trim_margin()
-    .unwrap();
-
-    let source = r#"
-    |foo;bar;baz;
-    |"#
-    .trim_margin()
+fn kotlin_match_simple_assignment() {
+    run_test_match({
+        TestArg {
+            pattern: r#"
+                |language kotlin
+                |
+                |`val key = $value`
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"val key = "value""#.to_string(),
+        }
+    })
     .unwrap();
-
-    let file = "test-file.tsx";
-
-    let context = ExecutionContext::default();
-    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
-    let pattern = src_to_problem(pattern, js_lang).unwrap();
-    let results = pattern.execute_file(&RichFile::new(file.to_owned(), source), &context);
-    assert_grit_snapshot!(results);
 }
 
 #[test]
-fn test_variable_message_log() {
-    let pattern = r#"
-        |language js
-        |
-        |program() as $x where {
-        |    $message = "this is a message",
-        |    log(message=$message,variable=$x),
-        |}
-        |"#
-    .trim_margin()
-    .unwrap();
-
-    let source = r#"
-    |foo;bar;baz;
-    |"#
-    .trim_margin()
+fn python_match_simple_assignment() {
+    run_test_match({
+        TestArg {
+            pattern: r#"
+                |language python
+                |
+                |`key = $value`
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"key = "value""#.to_string(),
+        }
+    })
     .unwrap();
-
-    let file = "test-file.tsx";
-
-    let context = ExecutionContext::default();
-    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
-    let pattern = src_to_problem(pattern, js_lang).unwrap();
-    let results = pattern.execute_file(&RichFile::new(file.to_owned(), source), &context);
-    assert_grit_snapshot!(results);
 }
 
 #[test]
-fn test_shorthand_log() {
-    let pattern = r#"
-        |language js
-        |
-        |`baz` as $x where {
-        |    $message = "this is a message",
-        |    $variable = $x,
-        |    log($message,$variable),
-        |}
-        |"#
-    .trim_margin()
+fn javascript_match_simple_assignment() {
+    run_test_match({
+        TestArg {
+            pattern: r#"
+                |language js
+                |
+                |`const key = $value`
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"const key = "value""#.to_string(),
+        }
+    })
     .unwrap();
-
-    let source = r#"
-    |foo;bar;baz;
-    |"#
-    .trim_margin()
-    .unwrap();
-
-    let file = "test-file.tsx";
-
-    let context = ExecutionContext::default();
-    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
-    let pattern = src_to_problem(pattern, js_lang).unwrap();
-    let results = pattern.execute_file(&RichFile::new(file.to_owned(), source), &context);
-    assert_grit_snapshot!(results);
-}
-
-#[test]
-fn test_before_each_file() {
-    let pattern = r#"
-        |language js
-        |
-        |pattern before_each_file() {
-        |  $p where $p <: contains `foo;` => raw`fooooo;`
-        |}
-        |
-        |`baz` => `baaaz`
-        |"#
-    .trim_margin()
-    .unwrap();
-
-    let source = r#"
-    |foo;bar;baz;
-    |"#
-    .trim_margin()
-    .unwrap();
-
-    let file = "test-file.tsx";
-    let context = ExecutionContext::default();
-    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
-    let pattern = src_to_problem(pattern, js_lang).unwrap();
-    let results = pattern.execute_file(&RichFile::new(file.to_owned(), source), &context);
-    assert_grit_snapshot!(results);
-}
-
-#[test]
-fn test_after_each_file() {
-    let pattern = r#"
-        |language js
-        |
-        |pattern after_each_file($) {
-        |  $p where $p <: contains `bar;` => raw`baaaar;`
-        |}
-        |
-        |`baz` => `baaaz`
-        |"#
-    .trim_margin()
-    .unwrap();
-
-    let source = r#"
-    |foo;bar;baz;
-    |"#
-    .trim_margin()
-    .unwrap();
-
-    let file = "test-file.tsx";
-
-    let context = ExecutionContext::default();
-    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
-    let pattern = src_to_problem(pattern, js_lang).unwrap();
-    let results = pattern.execute_file(&RichFile::new(file.to_owned(), source), &context);
-    assert_grit_snapshot!(results);
-}
-
-#[test]
-fn test_before_and_after_each_file() {
-    let pattern = r#"
-        |language js
-        |
-        |pattern before_each_file($) {
-        |  $p where $p <: contains `foo;` => `fooooo;`
-        |}
-        |
-        |pattern after_each_file($) {
-        |  $p where $p <: contains `bar;` => `baaaar;`
-        |}
-        |
-        |`baz` => `baaaz`
-        |"#
-    .trim_margin()
-    .unwrap();
-
-    let source = r#"
-    |foo;bar;baz;
-    |"#
-    .trim_margin()
-    .unwrap();
-
-    let file = "test-file.tsx";
-
-    let context = ExecutionContext::default();
-    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
-    let pattern = src_to_problem(pattern, js_lang).unwrap();
-    let results = pattern.execute_file(&RichFile::new(file.to_owned(), source), &context);
-    assert_grit_snapshot!(results);
-}
+}
 
 #[test]
-fn std_lib_array_snippet() {
+fn kotlin_multi_args_snippet() {
     run_test_match({
         TestArg {
             pattern: r#"
-                |language js
+                |language kotlin
                 |
-                |`const stdlib = [$old] as const`
+                |`arrayOf($args)`
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |arrayOf(1, 2, 3)
                 |"#
             .trim_margin()
             .unwrap(),
-            source: r#"const stdlib = [foo] as const"#.to_string(),
         }
     })
     .unwrap();
 }
 
 #[test]
-fn simple_yaml_rewrite() {
+fn kotlin_includes_any() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
-                |language yaml
+                |language kotlin
                 |
-                |`foo: bar` => `foo: baz`
+                |`println($_)` as $haystack where {
+                |   $haystack <: includes any { "Hello", "handsome" }
+                |} => `println("Goodbye world!")`
                 |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-            |foo: bar
-            |"#
+                |println("Hello world!");
+                |println("Hello handsome!");
+                |println("But not me, handsome.");
+                |println("Hi, Hello world!");
+                |println("Just not this....");
+                |"#
             .trim_margin()
             .unwrap(),
             expected: r#"
-            |foo: baz
-            |"#
+                |println("Goodbye world!");
+                |println("Goodbye world!");
+                |println("Goodbye world!");
+                |println("Goodbye world!");
+                |println("Just not this....");
+                |"#
             .trim_margin()
             .unwrap(),
         }
@@ -4109,26 +4062,28 @@ fn simple_yaml_rewrite() {
 }
 
 #[test]
-fn nested_yaml_rewrite() {
+fn kotlin_simple_function() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
-                |language yaml
+                |language kotlin
                 |
-                |`name: job` => `name: boz`
+                |`fun $name($var: $type) $body` => `fun modified($var: $type) $body`
                 |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-            |bob:
-            |  name: job
-            |"#
+                |fun hello(name: String) {
+                |    println("Hello, $name!")
+                |}
+                |"#
             .trim_margin()
             .unwrap(),
             expected: r#"
-            |bob:
-            |  name: boz
-            |"#
+                |fun modified(name: String) {
+                |    println("Hello, $name!")
+                |}
+                |"#
             .trim_margin()
             .unwrap(),
         }
@@ -4137,32 +4092,19 @@ fn nested_yaml_rewrite() {
 }
 
 #[test]
-fn yaml_object_rewrite() {
-    run_test_expected({
-        TestArgExpected {
+fn multi_args_snippet() {
+    run_test_match({
+        TestArg {
             pattern: r#"
-                |language yaml
+                |language js
                 |
-                |`foo: $obj` where { $obj <: contains `thing: bar` => `thing: baz` }
+                |`Array($args)`
                 |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-            |list:
-            |- item: one
-            |- item: two
-            |  foo:
-            |    thing: bar
-            |"#
-            .trim_margin()
-            .unwrap(),
-            expected: r#"
-            |list:
-            |- item: one
-            |- item: two
-            |  foo:
-            |    thing: baz
-            |"#
+                |Array(1, 2, 3);
+                |"#
             .trim_margin()
             .unwrap(),
         }
@@ -4171,52 +4113,34 @@ fn yaml_object_rewrite() {
 }
 
 #[test]
-fn simple_yaml_rewrite_sequence_items() {
-    run_test_expected({
-        TestArgExpected {
+fn hcl_pair() {
+    run_test_match({
+        TestArg {
             pattern: r#"
-                |language yaml
+                |language hcl
                 |
-                |`- $foo` => `- quux`
+                |`$arg: $red`
                 |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-            |version: v1beta1
-            |build:
-            |  roots:
-            |    - .
-            |    - foo
-            |    - bar
-            |lint:
-            |  ignore:
-            |    - dependencies
-            |  use:
-            |    - DEFAULT
-            |breaking:
-            |  ignore:
-            |  use:
-            |    - PACKAGE
-            |"#
-            .trim_margin()
-            .unwrap(),
-            expected: r#"
-            |version: v1beta1
-            |build:
-            |  roots:
-            |    - quux
-            |    - quux
-            |    - quux
-            |lint:
-            |  ignore:
-            |    - quux
-            |  use:
-            |    - quux
-            |breaking:
-            |  ignore:
-            |  use:
-            |    - quux
-            |"#
+                |default_address = "127.0.0.1"
+                |default_message = upper("Incident: ${incident}")
+                |default_options = {
+                |  priority: "High",
+                |  color: "Red"
+                |}
+                |
+                |incident_rules {
+                |  # Rule number 1
+                |  rule "down_server" "infrastructure" {
+                |    incident = 100
+                |    options  = var.override_options ? var.override_options : var.default_options
+                |    server   = default_address
+                |    message  = default_message
+                |  }
+                |}
+                |"#
             .trim_margin()
             .unwrap(),
         }
@@ -4225,55 +4149,70 @@ fn simple_yaml_rewrite_sequence_items() {
 }
 
 #[test]
-fn std_lib_activities_snippet() {
-    run_test_match({
-        TestArg {
+fn hcl_implicit_regex() {
+    run_test_expected({
+        TestArgExpected {
             pattern: r#"
-                |language js
+                |language hcl
                 |
-                |`const stdlib = { $activities }`
+                |`required_version = "~> $current_version"` => `required_version = "~> v1.5.0"`
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |default_address = "127.0.0.1"
+                |required_version = "~> v5.4.0"
+                |incident_rules {
+                |  rule "down_server" "infrastructure" {
+                |    required_version = "~> v1.0.0"
+                |  }
+                |}
+                |"#
+            .trim_margin()
+            .unwrap(),
+            expected: r#"
+                |default_address = "127.0.0.1"
+                |required_version = "~> v1.5.0"
+                |incident_rules {
+                |  rule "down_server" "infrastructure" {
+                |    required_version = "~> v1.5.0"
+                |  }
+                |}
                 |"#
             .trim_margin()
             .unwrap(),
-            source: r#"const stdlib = { foo: 7 }"#.to_string(),
         }
     })
     .unwrap();
 }
 
 #[test]
-fn import_snippet_variable() {
+fn includes_or() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
                 |language js
                 |
-                |`import $legacy_image from "next/legacy/image"` => `$legacy_image`
+                |`console.log($_)` as $haystack where {
+                |   $haystack <: includes or { "world", "handsome" }
+                |} => `console.log("Goodbye world!")`
                 |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                |import Image from "next/legacy/image";
-                |import ImageNew from "next/image";
-                |
-                |return (
-                |    <>
-                |        <Image />
-                |        <ImageNew />
-                |    </>
-                |)"#
+                |console.log("Hello world!");
+                |console.log("Hello handsome!");
+                |console.log("But not me, sadly.");
+                |console.log("Hi, Hello world!");
+                |"#
             .trim_margin()
             .unwrap(),
             expected: r#"
-                |Image
-                |import ImageNew from "next/image";
-                |
-                |return (
-                |    <>
-                |        <Image />
-                |        <ImageNew />
-                |    </>
-                |)"#
+                |console.log("Goodbye world!");
+                |console.log("Goodbye world!");
+                |console.log("But not me, sadly.");
+                |console.log("Goodbye world!");
+                |"#
             .trim_margin()
             .unwrap(),
         }
@@ -4282,213 +4221,135 @@ fn import_snippet_variable() {
 }
 
 #[test]
-fn js_match_single_arrow_args_list() {
-    run_test_match({
-        TestArg {
-            pattern: r#"
-            language js
-            `($args) => $body` where {
-                $args <: [$x, ...]
-            }"#
-            .to_owned(),
-            source: r#"
-            const x = y => {
-               return y;
-            }"#
-            .to_owned(),
-        }
-    })
-    .unwrap();
-}
-
-#[test]
-fn js_match_arrow_args_list() {
-    run_test_match({
-        TestArg {
-            pattern: r#"
-            language js
-            `($args) => $body` where {
-                $args <: [$x, ...]
-            }"#
-            .to_owned(),
-            source: r#"
-            const x = (y) => {
-               return y;
-            }"#
-            .to_owned(),
-        }
-    })
-    .unwrap();
-}
-
-#[test]
-fn js_match_single_arrow_args_list_without_parentheses() {
-    run_test_match({
-        TestArg {
-            pattern: r#"
-            language js
-            `$args => $body` where {
-                $args <: [$x, ...]
-            }"#
-            .to_owned(),
-            source: r#"
-            const x = y => {
-               return y;
-            }"#
-            .to_owned(),
-        }
-    })
-    .unwrap();
-}
-
-#[test]
-fn js_match_arrow_args_list_without_parentheses() {
-    run_test_match({
-        TestArg {
-            pattern: r#"
-            language js
-            `$args => $body` where {
-                $args <: [$x, ...]
-            }"#
-            .to_owned(),
-            source: r#"
-            const x = (y) => {
-               return y;
-            }"#
-            .to_owned(),
-        }
-    })
-    .unwrap();
-}
-
-#[test]
-fn js_paren_params() {
+fn includes_and() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
-            contains bubble arrow_function(parameters=$x, parenthesis = $y) where {
-                if ($y <: .) {
-                    $x => `()`
-                } else {
-                    $x => .
-                }
-            }"#
-            .to_owned(),
+                |language js
+                |
+                |`console.log($_)` as $haystack where {
+                |   $haystack <: includes and { "Hello", "handsome" }
+                |} => `console.log("Goodbye world!")`
+                |"#
+            .trim_margin()
+            .unwrap(),
             source: r#"
-            const foo = (bar) => {}
-            const baz = qux => {}"#
-                .to_owned(),
+                |console.log("Hello world!");
+                |console.log("Hello handsome!");
+                |console.log("But not me, handsome.");
+                |console.log("Hi, Hello world!");
+                |"#
+            .trim_margin()
+            .unwrap(),
             expected: r#"
-            const foo = () => {}
-            const baz = () => {}"#
-                .to_owned(),
+                |console.log("Hello world!");
+                |console.log("Goodbye world!");
+                |console.log("But not me, handsome.");
+                |console.log("Hi, Hello world!");
+                |"#
+            .trim_margin()
+            .unwrap(),
         }
     })
     .unwrap();
 }
 
 #[test]
-fn js_preserve_significant_empty_statements() {
+fn includes_any() {
     run_test_expected({
         TestArgExpected {
-            pattern: r#"`$body` => `$body`"#.to_owned(),
+            pattern: r#"
+                |language js
+                |
+                |`console.log($_)` as $haystack where {
+                |   $haystack <: includes any { "Hello", "handsome" }
+                |} => `console.log("Goodbye world!")`
+                |"#
+            .trim_margin()
+            .unwrap(),
             source: r#"
-            for(;;);
-            if(true);
-            while(true);"#
-                .to_owned(),
+                |console.log("Hello world!");
+                |console.log("Hello handsome!");
+                |console.log("But not me, handsome.");
+                |console.log("Hi, Hello world!");
+                |console.log("Just not this....");
+                |"#
+            .trim_margin()
+            .unwrap(),
             expected: r#"
-            for(;;);
-            if(true);
-            while(true);"#
-                .to_owned(),
+                |console.log("Goodbye world!");
+                |console.log("Goodbye world!");
+                |console.log("Goodbye world!");
+                |console.log("Goodbye world!");
+                |console.log("Just not this....");
+                |"#
+            .trim_margin()
+            .unwrap(),
         }
     })
     .unwrap();
 }
 
 #[test]
-fn js_remove_redundant_empty_statements() {
+fn includes_regex() {
     run_test_expected({
         TestArgExpected {
-            pattern: r#"`$body` => `$body`"#.to_owned(),
-            source: r#";;{;;};;"#.to_owned(),
-            expected: r#"{}"#.to_owned(),
-        }
-    })
-    .unwrap();
-}
-
-#[test]
-fn array_destrcutring_snippet() {
-    run_test_match({
-        TestArg {
             pattern: r#"
                 |language js
                 |
-                |`const [$name, $_] = useState($_)`
+                |`console.log($_)` as $haystack where {
+                |   $haystack <: includes r"Hello"
+                |} => `console.log("Goodbye world!")`
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |console.log("Hello world!");
+                |console.log("Hello handsome!");
+                |console.log("But not me, sadly.");
+                |console.log("Hi, Hello world!");
+                |"#
+            .trim_margin()
+            .unwrap(),
+            expected: r#"
+                |console.log("Goodbye world!");
+                |console.log("Goodbye world!");
+                |console.log("But not me, sadly.");
+                |console.log("Goodbye world!");
                 |"#
             .trim_margin()
             .unwrap(),
-            source: r#"const [foo, bar] = useState(5)"#.to_string(),
         }
     })
     .unwrap();
 }
 
 #[test]
-fn equals_zero_snippet() {
-    run_test_match({
-        TestArg {
+fn includes_regex_with_capture() {
+    run_test_expected({
+        TestArgExpected {
             pattern: r#"
                 |language js
                 |
-                |`$y = $x == -0`
-                |"#
-            .trim_margin()
-            .unwrap(),
-            source: r#"let bar = foo(5) + 3 == -0"#.to_string(),
-        }
-    })
-    .unwrap();
-}
-
-#[test]
-fn extends_component() {
-    run_test_match({
-        TestArg {
-            pattern: r#"
-                |language js
-                |
-                |`extends Component`
+                |`console.log($_)` as $haystack where {
+                |   $haystack <: includes r"Hello (\w+)"($name)
+                |} => `console.log("Goodbye $name!")`
                 |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                |class Button extends Component {
-                |  a = 1;
-                |}
-                |"#
-            .trim_margin()
-            .unwrap(),
-        }
-    })
-    .unwrap();
-}
-
-#[test]
-fn import_react_snippet_no_match() {
-    run_test_no_match({
-        TestArg {
-            pattern: r#"
-                |language js
-                |
-                |`import React from 'react'`
+                |console.log("Hello world!");
+                |console.log("Hello handsome!");
+                |console.log("But not me, sadly.");
+                |console.log("Hi, Hello world!");
                 |"#
             .trim_margin()
             .unwrap(),
-            source: r#"
-                |import * as React from "react"
+            expected: r#"
+                |console.log("Goodbye world!");
+                |console.log("Goodbye handsome!");
+                |console.log("But not me, sadly.");
+                |console.log("Goodbye world!");
                 |"#
             .trim_margin()
             .unwrap(),
@@ -4498,247 +4359,238 @@ fn import_react_snippet_no_match() {
 }
 
 #[test]
-fn test_ranges() {
-    run_test_match({
-        TestArg {
-            pattern: r#"
-                |language js(js_do_not_use)
-                |$obj where {
-                |    $obj <: object($properties),
-                |}"#
-            .trim_margin()
-            .unwrap(),
-            source: r#"
-                |const a = { foo: 'bar' };"#
-                .trim_margin()
-                .unwrap(),
-        }
-    })
-    .unwrap();
-}
+fn parses_simple_pattern() {
+    let pattern = r#"
+        |engine marzano(0.1)
+        |language js
+        |`console.log($msg)`"#
+        .trim_margin()
+        .unwrap();
 
-#[test]
-fn pattern_test_filename_match_and_use() {
-    run_test_expected({
-        TestArgExpected {
-            pattern: r#"
-                |language js
-                |
-                |file($name, body = contains `whatever` => `$name`)
-                |"#
-            .trim_margin()
-            .unwrap(),
-            source: r#"
-                |whatever()
-                |"#
-            .trim_margin()
-            .unwrap(),
-            expected: r#"
-                |test-file.tsx()
-                |"#
-            .trim_margin()
-            .unwrap(),
-        }
-    })
-    .unwrap();
+    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
+    let _pattern = src_to_problem(pattern, js_lang).unwrap();
 }
 
 #[test]
-fn test_conditional_snippet() {
-    run_test_expected({
-        TestArgExpected {
-            pattern: r#"
-                |language js
-                |`if ($cond) { $cond_true }` => `$cond`"#
-                .trim_margin()
-                .unwrap(),
-            source: r#"
-                if (!response) {
-                  return false;
-                }"#
-            .to_owned(),
-            expected: r#"!response"#.to_owned(),
-        }
-    })
+fn warning_rewrite_in_not() {
+    let pattern = r#"
+        |`async ($args) => { $body }` where {
+        |    $body <: not contains `try` => ` try {
+        |        $body
+        |    } catch { }`
+        |}"#
+    .trim_margin()
     .unwrap();
+
+    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
+    let libs = BTreeMap::new();
+    let cr = src_to_problem_libs(pattern, &libs, js_lang, None, None, None, None).unwrap();
+    assert_debug_snapshot!(cr.compilation_warnings)
 }
 
 #[test]
-fn test_file_rename() {
+fn should_log_variable() {
     let pattern = r#"
+        |engine marzano(0.1)
         |language js
         |
-        |file($name, $body) where {
-        |    $body <: contains `rename_to($new_name)` => `we_renamed_to($new_name)`,
-        |    $name => `$new_name`
+        |`function ($args) { $body }` => `($args) => {
+        |  $body
+        |}` where {
+        |    log(variable=$body)
         |}
         |"#
     .trim_margin()
     .unwrap();
 
     let source = r#"
-    |rename_to(the_new_name)
-    |"#
-    .trim_margin()
-    .unwrap();
+    |var increment = function (i) {
+    |    return i + 1;
+    |};"#
+        .trim_margin()
+        .unwrap();
 
     let file = "test-file.tsx";
 
     let context = ExecutionContext::default();
     let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
     let pattern = src_to_problem(pattern, js_lang).unwrap();
-    let results = pattern.execute_file(&RichFile::new(file.to_owned(), source), &context);
+    let results =
+        pattern.execute_file(&RichFile::new(file.to_owned(), source.to_owned()), &context);
     assert_grit_snapshot!(results);
 }
 
 #[test]
-fn test_json_file_match_snippet() {
+fn rewrite_dot() {
     let pattern = r#"
-        |language json
-        |
-        |file($name, $body) where {
-        |    $name <: r"(?:.+)package.json",
-        |    $body <: contains pair(key=`"name"`, $value),
-        |    $value => `"bar"`
-        |}"#
-    .trim_margin()
-    .unwrap();
+            engine marzano(0.1)
+            language js
 
-    let source = r#"
-        {
-          "not-name": "foo",
-          "name": "foo"
-        }
-        "#
+            `new Date($bob)` where {
+                $bob <: . => `"fo"`
+            }"#
     .to_owned();
-
-    let file = "foo.package.json";
+    let source = r#"
+            new Date()"#
+        .to_owned();
+    let file = "test-file.tsx";
 
     let context = ExecutionContext::default();
-    let json_lang: TargetLanguage = PatternLanguage::Json.try_into().unwrap();
-    let pattern = src_to_problem(pattern, json_lang).unwrap();
-    let results = pattern.execute_file(&RichFile::new(file.to_owned(), source), &context);
+    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
+    let pattern = src_to_problem(pattern, js_lang).unwrap();
+    let results =
+        pattern.execute_file(&RichFile::new(file.to_owned(), source.to_owned()), &context);
     assert_grit_snapshot!(results);
 }
 
 #[test]
-fn test_basic_python() {
+fn error_tolerance_outside_error_regions() {
     let pattern = r#"
-        |language python
-        |
-        |`print($msg)` => `log($msg)`
-        |"#
-    .trim_margin()
-    .unwrap();
-
-    let source = r#"|print("hello world!")
-                    |log("this is python")"#
-        .trim_margin()
-        .unwrap();
+            language js
 
-    let file = "foo.py";
+            `console.log($x)` => `console.warn($x)`"#
+        .to_owned();
+    let source = r#"console.log("ok");
+console.log("broken" +);
+"#
+    .to_owned();
+    let file = "test-file.tsx";
 
-    let context = ExecutionContext::default();
-    let language: TargetLanguage = PatternLanguage::Python.try_into().unwrap();
-    let pattern = src_to_problem(pattern, language).unwrap();
+    let mut context = ExecutionContext::default();
+    context.error_tolerance = ErrorTolerance::Outside;
+    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
+    let pattern = src_to_problem(pattern, js_lang).unwrap();
     let results = pattern.execute_file(&RichFile::new(file.to_owned(), source), &context);
-    assert_grit_snapshot!(results);
+    let rewritten = results
+        .iter()
+        .find_map(|r| {
+            if let MatchResult::Rewrite(r) = r {
+                r.rewritten.content.clone()
+            } else {
+                None
+            }
+        })
+        .unwrap();
+    assert_eq!(
+        rewritten,
+        "console.warn(\"ok\");\nconsole.log(\"broken\" +);\n"
+    );
+    assert!(results.iter().any(|r| matches!(
+        r,
+        MatchResult::AnalysisLog(log) if log.message.contains("Missing `identifier`")
+    )));
 }
 
 #[test]
-fn test_json_file_match_string() {
+fn error_tolerance_skip_files() {
     let pattern = r#"
-        |language json
-        |
-        |file($name, $body) where {
-        |    $name <: r"(?:.+)package.json",
-        |    $body <: contains pair(key="\"name\"", $value),
-        |    $value => `"bar"`
-        |}"#
-    .trim_margin()
-    .unwrap();
+            language js
 
-    let source = r#"
-        {
-          "not-name": "foo",
-          "name": "foo"
-        }
-        "#
+            `console.log($x)` => `console.warn($x)`"#
+        .to_owned();
+    let source = r#"console.log("ok");
+console.log("broken" +);
+"#
     .to_owned();
+    let file = "test-file.tsx";
 
-    let file = "foo.package.json";
-
-    let context = ExecutionContext::default();
-    let json_lang: TargetLanguage = PatternLanguage::Json.try_into().unwrap();
-    let pattern = src_to_problem(pattern, json_lang).unwrap();
+    let mut context = ExecutionContext::default();
+    context.error_tolerance = ErrorTolerance::Skip;
+    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
+    let pattern = src_to_problem(pattern, js_lang).unwrap();
     let results = pattern.execute_file(&RichFile::new(file.to_owned(), source), &context);
-    assert_grit_snapshot!(results);
+    assert!(!results.iter().any(MatchResult::is_match));
+    assert!(results.iter().any(|r| matches!(
+        r,
+        MatchResult::AnalysisLog(log) if log.message.contains("syntax errors")
+    )));
 }
 
-#[test]
-fn pattern_test_new_files() {
-    run_test_expected_with_new_file({
-        TestArgExpectedWithNewFile {
-            pattern: r#"
-                |language js
-                |
-                |`foo($name, $body)` => `bar()` where {
-                |    $new_files += file($name, $body)
-                |}
-                |"#
-            .trim_margin()
-            .unwrap(),
-            source: r#"
-                |foo(the_new_file.tsx, whatever)
-                |"#
-            .trim_margin()
-            .unwrap(),
-            expected: r#"
-                |bar()
-                |"#
-            .trim_margin()
-            .unwrap(),
-            new_file_name: "the_new_file.tsx".into(),
-            new_file_body: r#"
-                |whatever
-                |"#
-            .trim_margin()
-            .unwrap(),
+fn rewrite_with_variables(
+    pattern: &str,
+    variables: serde_json::Value,
+    source: &str,
+) -> Option<String> {
+    let libs = BTreeMap::new();
+    let variables: BTreeMap<String, serde_json::Value> = serde_json::from_value(variables).unwrap();
+    let problem = src_to_problem_libs_with_variables(
+        pattern.to_owned(),
+        &libs,
+        TargetLanguage::default(),
+        None,
+        None,
+        None,
+        None,
+        &variables,
+    )
+    .unwrap()
+    .problem;
+    let context = ExecutionContext::default();
+    let results = problem.execute_file(
+        &RichFile::new("test-file.tsx".to_owned(), source.to_owned()),
+        &context,
+    );
+    results.into_iter().find_map(|r| {
+        if let MatchResult::Rewrite(r) = r {
+            r.rewritten.content
+        } else {
+            None
         }
     })
-    .unwrap();
 }
 
 #[test]
-fn test_saving_info() {
+fn injected_variables_are_prebound() {
+    let pattern = r#"
+        language js
+
+        `console.log($arg)` where {
+            $arg <: $secret,
+            $arg => `$replacement($limit)`
+        }"#;
+    let rewritten = rewrite_with_variables(
+        pattern,
+        serde_json::json!({ "secret": "token", "replacement": "hidden", "limit": 3 }),
+        "console.log(token);\nconsole.log(other);",
+    );
+    assert_eq!(
+        rewritten.as_deref(),
+        Some("console.log(hidden(3));\nconsole.log(other);")
+    );
+}
+
+#[test]
+fn injected_variables_fill_pattern_parameters() {
+    let pattern = r#"
+        language js
+
+        pattern rename_call($from, $to) {
+            `$fn($args)` where { $fn <: $from, $fn => $to }
+        }
+
+        rename_call()"#;
+    let rewritten = rewrite_with_variables(
+        pattern,
+        serde_json::json!({ "from": "foo", "to": "bar" }),
+        "foo(1);\nbaz(2);",
+    );
+    assert_eq!(rewritten.as_deref(), Some("bar(1);\nbaz(2);"));
+}
+
+#[test]
+fn test_simple_log() {
     let pattern = r#"
         |language js
         |
-        |program() where {
-        |  $l = [],
-        |  $keys = [],
-        |  $output = ``,
-        |  $program <: contains bubble ($l, $keys, $output) `foo($key, $value)` as $x where {
-        |    if ($l <: not some [$key, $value]) $l += [$key, $value],
-        |    if ($keys <: not some $key) $keys += $key
-        |  },
-        |  $keys <: some bubble($output, $l) $key where {
-        |    $values = [],
-        |    $l <: some bubble($key, $values) [$key, $value] where $values += $value,
-        |    $values_string = join(list = $values, separator = ", "),
-        |    $output += `$key->($values_string)`
-        |  },
-        |  $program => `$output`
+        |program() as $x where {
+        |    log(message="this is a message",variable=$x)
         |}
-        |
         |"#
     .trim_margin()
     .unwrap();
 
     let source = r#"
-    |foo(1, 11);
-    |foo(1, 12);
-    |foo(2, 21)
+    |foo;bar;baz;
     |"#
     .trim_margin()
     .unwrap();
@@ -4753,24 +4605,21 @@ fn test_saving_info() {
 }
 
 #[test]
-fn test_assoc_matcher() {
+fn test_variable_message_log() {
     let pattern = r#"
         |language js
         |
-        |program() where {
-        |  $l = [[`1`, `2`, `3`]],
-        |  $x = `5`,
-        |  $l <: some [$x, $y, $z], // should not match because of $x
-        |  $program => `$x, $y, $z`
+        |program() as $x where {
+        |    $message = "this is a message",
+        |    log(message=$message,variable=$x),
         |}
-        |
         |"#
     .trim_margin()
     .unwrap();
 
     let source = r#"
-        |whatever
-        |"#
+    |foo;bar;baz;
+    |"#
     .trim_margin()
     .unwrap();
 
@@ -4784,85 +4633,112 @@ fn test_assoc_matcher() {
 }
 
 #[test]
-fn add_to_set() {
+fn test_shorthand_log() {
     let pattern = r#"
-          pattern add_to_set($set) {
-            $element where {
-              if ($set <: not some $element) {
-                $set += $element
-              } else {
-                true
-              }
-            }
-          }
-          `end` as $end where {
-            $big_set = [],
-            $program <: contains bubble($big_set) `foo($x, $y)` where {
-                // this should work too:
-                // $element = [$x, $y],
-                //$element <: add_to_set(set = $big_set)
+        |language js
+        |
+        |`baz` as $x where {
+        |    $message = "this is a message",
+        |    $variable = $x,
+        |    log($message,$variable),
+        |}
+        |"#
+    .trim_margin()
+    .unwrap();
 
-                if ($big_set <: not some [$x, $y]) {
-                    $big_set += [$x, $y]
-                  } else {
-                    true
-                  }
-            },
-            $big_set_string = join(list = $big_set, separator = "||| "),
-            $end => `$big_set_string`
-          }
-            "#;
+    let source = r#"
+    |foo;bar;baz;
+    |"#
+    .trim_margin()
+    .unwrap();
+
+    let file = "test-file.tsx";
+
+    let context = ExecutionContext::default();
+    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
+    let pattern = src_to_problem(pattern, js_lang).unwrap();
+    let results = pattern.execute_file(&RichFile::new(file.to_owned(), source), &context);
+    assert_grit_snapshot!(results);
+}
+
+#[test]
+fn test_before_each_file() {
+    let pattern = r#"
+        |language js
+        |
+        |pattern before_each_file() {
+        |  $p where $p <: contains `foo;` => raw`fooooo;`
+        |}
+        |
+        |`baz` => `baaaz`
+        |"#
+    .trim_margin()
+    .unwrap();
 
     let source = r#"
-        |foo(1, 11);
-        |foo(1, 12);
-        |foo(1, 12);
-        |foo(2, 21);
-        |end
+    |foo;bar;baz;
+    |"#
+    .trim_margin()
+    .unwrap();
+
+    let file = "test-file.tsx";
+    let context = ExecutionContext::default();
+    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
+    let pattern = src_to_problem(pattern, js_lang).unwrap();
+    let results = pattern.execute_file(&RichFile::new(file.to_owned(), source), &context);
+    assert_grit_snapshot!(results);
+}
+
+#[test]
+fn test_after_each_file() {
+    let pattern = r#"
+        |language js
+        |
+        |pattern after_each_file($) {
+        |  $p where $p <: contains `bar;` => raw`baaaar;`
+        |}
+        |
+        |`baz` => `baaaz`
         |"#
     .trim_margin()
     .unwrap();
 
+    let source = r#"
+    |foo;bar;baz;
+    |"#
+    .trim_margin()
+    .unwrap();
+
     let file = "test-file.tsx";
 
     let context = ExecutionContext::default();
     let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
-    let pattern = src_to_problem(pattern.to_owned(), js_lang).unwrap();
+    let pattern = src_to_problem(pattern, js_lang).unwrap();
     let results = pattern.execute_file(&RichFile::new(file.to_owned(), source), &context);
     assert_grit_snapshot!(results);
 }
 
 #[test]
-fn add_to_set_via_pattern() {
+fn test_before_and_after_each_file() {
     let pattern = r#"
-          pattern add_to_set($set) {
-            $element where {
-              if ($set <: not some $element) {
-                $set += $element
-              } else {
-                true
-              }
-            }
-          }
-          `end` as $end where {
-            $big_set = [],
-            $program <: contains bubble($big_set) `foo($x, $y)` where {
-                // this should work too:
-                $element = [$x, $y],
-                $element <: add_to_set(set = $big_set)
-            },
-            $big_set_string = join(list = $big_set, separator = "||| "),
-            $end => `$big_set_string`
-          }
-            "#;
+        |language js
+        |
+        |pattern before_each_file($) {
+        |  $p where $p <: contains `foo;` => `fooooo;`
+        |}
+        |
+        |pattern after_each_file($) {
+        |  $p where $p <: contains `bar;` => `baaaar;`
+        |}
+        |
+        |`baz` => `baaaz`
+        |"#
+    .trim_margin()
+    .unwrap();
 
     let source = r#"
-        |foo(1, 11);
-        |foo(1, 12);
-        |foo(1, 12);
-        |foo(2, 21);
-        |end
-        |"#
+    |foo;bar;baz;
+    |"#
     .trim_margin()
     .unwrap();
 
@@ -4870,41 +4746,47 @@ fn add_to_set_via_pattern() {
 
     let context = ExecutionContext::default();
     let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
-    let pattern = src_to_problem(pattern.to_owned(), js_lang).unwrap();
+    let pattern = src_to_problem(pattern, js_lang).unwrap();
     let results = pattern.execute_file(&RichFile::new(file.to_owned(), source), &context);
     assert_grit_snapshot!(results);
 }
 
 #[test]
-fn test_assignment_in_method_call() {
-    run_test_expected({
-        TestArgExpected {
+fn std_lib_array_snippet() {
+    run_test_match({
+        TestArg {
             pattern: r#"
                 |language js
                 |
-                |pattern use_info($comp_name) {
-                |    variable_declarator($name, $value, $type) where {
-                |        $comp_name = `foo`
-                |    }
-                |}
-                |
-                |use_info($comp_name) => `$comp_name`
-                |
+                |`const stdlib = [$old] as const`
                 |"#
             .trim_margin()
             .unwrap(),
-            source: r#"
-                |const _DateSelect = {
-                |    parameters: {
-                |      info: 'This is a Select date Component',
-                |    },
-                |  };
+            source: r#"const stdlib = [foo] as const"#.to_string(),
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn simple_yaml_rewrite() {
+    run_test_expected({
+        TestArgExpected {
+            pattern: r#"
+                |language yaml
+                |
+                |`foo: bar` => `foo: baz`
                 |"#
             .trim_margin()
             .unwrap(),
+            source: r#"
+            |foo: bar
+            |"#
+            .trim_margin()
+            .unwrap(),
             expected: r#"
-                |const foo;
-                |"#
+            |foo: baz
+            |"#
             .trim_margin()
             .unwrap(),
         }
@@ -4913,439 +4795,475 @@ fn test_assignment_in_method_call() {
 }
 
 #[test]
-fn test_same_var_twice_in_snippet() {
+fn nested_yaml_rewrite() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
-                |engine marzano(0.1)
-                |language js
-                |`<$name $props>$children</$name>` where {
-                |    $name <: `Foo` => `Bar`,
-                |}
+                |language yaml
                 |
+                |`name: job` => `name: boz`
                 |"#
             .trim_margin()
             .unwrap(),
-            source: r#"<Foo name='sam'>Test</Foo>"#.to_owned(),
-            expected: r#"<Bar name='sam'>Test</Bar>"#.to_owned(),
+            source: r#"
+            |bob:
+            |  name: job
+            |"#
+            .trim_margin()
+            .unwrap(),
+            expected: r#"
+            |bob:
+            |  name: boz
+            |"#
+            .trim_margin()
+            .unwrap(),
         }
     })
     .unwrap();
 }
 
 #[test]
-fn simple_predicate() {
+fn yaml_object_rewrite() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
-                |engine marzano(0.1)
-                |language js
-                |
-                |predicate is_foo($name) {
-                |    $name <: `Foo`
-                |}
-                |predicate rewrite($name) {
-                |    $name => `Bar`,
-                |}
-                |
-                |`<$name $props>$children</$name>` where {
-                |    if (is_foo($name)) {
-                |          rewrite($name)
-                |    }
-                |}
+                |language yaml
                 |
+                |`foo: $obj` where { $obj <: contains `thing: bar` => `thing: baz` }
                 |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                | <Foo name='sam'>Test</Foo>
-                | <Baz name='sam'>Test</Baz>"#
-                .trim_margin()
-                .unwrap(),
+            |list:
+            |- item: one
+            |- item: two
+            |  foo:
+            |    thing: bar
+            |"#
+            .trim_margin()
+            .unwrap(),
             expected: r#"
-                | <Bar name='sam'>Test</Bar>
-                | <Baz name='sam'>Test</Baz>"#
-                .trim_margin()
-                .unwrap(),
+            |list:
+            |- item: one
+            |- item: two
+            |  foo:
+            |    thing: baz
+            |"#
+            .trim_margin()
+            .unwrap(),
         }
     })
     .unwrap();
 }
 
 #[test]
-fn simple_predicate_false() {
-    let res = run_test_expected({
+fn simple_yaml_rewrite_sequence_items() {
+    run_test_expected({
         TestArgExpected {
             pattern: r#"
-                |engine marzano(0.1)
-                |language js
-                |
-                |predicate is_foo($name) {
-                |    $name <: `Foo`
-                |}
-                |predicate rewrite($name) {
-                |    $name => `Bar`,
-                |}
-                |
-                |`<$name $props>$children</$name>` where {
-                |    if (is_foo($name)) {
-                |          rewrite($name)
-                |    }
-                |}
+                |language yaml
                 |
+                |`- $foo` => `- quux`
                 |"#
             .trim_margin()
             .unwrap(),
-            source: r#"<Baz name='sam'>Test</Baz>"#.to_owned(),
-            expected: r#"<Baz name='sam'>Test</Baz>"#.to_owned(),
+            source: r#"
+            |version: v1beta1
+            |build:
+            |  roots:
+            |    - .
+            |    - foo
+            |    - bar
+            |lint:
+            |  ignore:
+            |    - dependencies
+            |  use:
+            |    - DEFAULT
+            |breaking:
+            |  ignore:
+            |  use:
+            |    - PACKAGE
+            |"#
+            .trim_margin()
+            .unwrap(),
+            expected: r#"
+            |version: v1beta1
+            |build:
+            |  roots:
+            |    - quux
+            |    - quux
+            |    - quux
+            |lint:
+            |  ignore:
+            |    - quux
+            |  use:
+            |    - quux
+            |breaking:
+            |  ignore:
+            |  use:
+            |    - quux
+            |"#
+            .trim_margin()
+            .unwrap(),
         }
-    });
-    assert_eq!(res.unwrap_err().to_string(), "found a match but no rewrite");
+    })
+    .unwrap();
 }
 
 #[test]
-fn test_import_none() {
-    let root = get_fixtures_root().unwrap();
-    let import_patterns = format!("{}/test_patterns/imports.grit", root.display());
-    let import_patterns = fs_err::read_to_string(import_patterns).unwrap();
-
-    let pattern = r#"
-
-        `foo`
-        "#;
-
-    let pattern = format!("{}\n{}", import_patterns, pattern);
-
-    let source = r#"
-        |import { foo, bar } from 'bar'
-        |import { other } from 'other.file'
-        |"#
-    .trim_margin()
+fn std_lib_activities_snippet() {
+    run_test_match({
+        TestArg {
+            pattern: r#"
+                |language js
+                |
+                |`const stdlib = { $activities }`
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"const stdlib = { foo: 7 }"#.to_string(),
+        }
+    })
     .unwrap();
-
-    let results = execute(pattern, source);
-    assert_grit_snapshot!(results);
 }
 
 #[test]
-fn rewrite_or_bubble_pattern_argument() {
-    let pattern = r#"
-        engine marzano(1.0)
-        language js
-
-        `it($_, $body)` as $m where {
-            $body <: contains  `foo` as $to_rewrite,
-
-            $m <: bubble($to_rewrite) $_ where {
-                $to_rewrite => `MARKER`
-            }
+fn import_snippet_variable() {
+    run_test_expected({
+        TestArgExpected {
+            pattern: r#"
+                |language js
+                |
+                |`import $legacy_image from "next/legacy/image"` => `$legacy_image`
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |import Image from "next/legacy/image";
+                |import ImageNew from "next/image";
+                |
+                |return (
+                |    <>
+                |        <Image />
+                |        <ImageNew />
+                |    </>
+                |)"#
+            .trim_margin()
+            .unwrap(),
+            expected: r#"
+                |Image
+                |import ImageNew from "next/image";
+                |
+                |return (
+                |    <>
+                |        <Image />
+                |        <ImageNew />
+                |    </>
+                |)"#
+            .trim_margin()
+            .unwrap(),
         }
-
-        "#;
-
-    let source = r#"
-            it('consolidates', async () => {
-              foo;
-              foo;
-              foo;
-            });
-        "#;
-
-    let results = execute(pattern.into(), source.into());
-    assert_grit_snapshot!(results);
+    })
+    .unwrap();
 }
 
 #[test]
-fn test_import_just_insert() {
-    let root = get_fixtures_root().unwrap();
-    let import_patterns = format!("{}/test_patterns/imports.grit", root.display());
-    let import_patterns = fs_err::read_to_string(import_patterns).unwrap();
-
-    let pattern = r#"
-
-        `foo` as $f where {
-            $name = `buz`,
-            $buuuz_source = `'buuuz'`,
-            $name <: ensure_import_from(source = $buuuz_source),
+fn js_match_single_arrow_args_list() {
+    run_test_match({
+        TestArg {
+            pattern: r#"
+            language js
+            `($args) => $body` where {
+                $args <: [$x, ...]
+            }"#
+            .to_owned(),
+            source: r#"
+            const x = y => {
+               return y;
+            }"#
+            .to_owned(),
         }
-        "#;
-
-    let pattern = format!("{}\n{}", import_patterns, pattern);
-
-    let source = r#"
-        |import { foo, bar } from 'bar'
-        |import { other } from 'other.file'
-        |"#
-    .trim_margin()
+    })
     .unwrap();
-
-    let results = execute(pattern, source);
-    assert_grit_snapshot!(results);
 }
 
 #[test]
-fn pattern_call_as_rhs() {
-    let root = get_fixtures_root().unwrap();
-    let import_patterns = format!("{}/test_patterns/imports.grit", root.display());
-    let import_patterns = fs_err::read_to_string(import_patterns).unwrap();
-
-    let pattern = r#"
-        // does not work:
-        `orderBy` as $ob where { $ob <: ensure_import_from(source=`"lodash"`) }
-        // does work:
-        // `orderBy` as $ob where { $source=`"lodash"`, $ob <: ensure_import_from($source) }
-        "#;
-
-    let pattern = format!("{}\n{}", import_patterns, pattern);
-
-    let source = r#"
-        |var increment = function (i) {
-        |    orderBy();
-        |  return i + 1;
-        |};
-        |"#
-    .trim_margin()
-    .unwrap();
-    let expected = r#"
-        |import { orderBy } from "lodash";
-        |var increment = function (i) {
-        |    orderBy();
-        |  return i + 1;
-        |};
-        |"#
-    .trim_margin()
-    .unwrap();
-    run_test_expected(TestArgExpected {
-        pattern,
-        source,
-        expected,
+fn js_match_arrow_args_list() {
+    run_test_match({
+        TestArg {
+            pattern: r#"
+            language js
+            `($args) => $body` where {
+                $args <: [$x, ...]
+            }"#
+            .to_owned(),
+            source: r#"
+            const x = (y) => {
+               return y;
+            }"#
+            .to_owned(),
+        }
     })
     .unwrap();
 }
 
 #[test]
-fn pattern_call_as_lhs_and_rhs() {
-    let root = get_fixtures_root().unwrap();
-    let import_patterns = format!("{}/test_patterns/imports.grit", root.display());
-    let import_patterns = fs_err::read_to_string(import_patterns).unwrap();
-
-    let pattern = r#"
-            $x where {
-                $x <: js"makeStyles",
-                $x <: remove_import(from=`'@material-ui/core'`)
-            }"#;
-
-    let pattern = format!("{}\n{}", import_patterns, pattern);
-
-    let source = r#"import { Box, Paper, makeStyles } from "@material-ui/core";"#.to_owned();
-    let expected = r#"import { Box, Paper  } from "@material-ui/core";"#.to_owned();
-    run_test_expected(TestArgExpected {
-        pattern,
-        source,
-        expected,
+fn js_match_single_arrow_args_list_without_parentheses() {
+    run_test_match({
+        TestArg {
+            pattern: r#"
+            language js
+            `$args => $body` where {
+                $args <: [$x, ...]
+            }"#
+            .to_owned(),
+            source: r#"
+            const x = y => {
+               return y;
+            }"#
+            .to_owned(),
+        }
     })
     .unwrap();
 }
 
 #[test]
-fn test_import_all_already_there() {
-    let root = get_fixtures_root().unwrap();
-    let import_patterns = format!("{}/test_patterns/imports.grit", root.display());
-    let import_patterns = fs_err::read_to_string(import_patterns).unwrap();
-
-    let pattern = r#"
-
-        `foo` as $f where {
-            $new_name = `bar`,
-            $bar_source = `'bar'`,
-            $new_name <: ensure_import_from(source = $bar_source),
-            $new_name <: ensure_import_from(source = $bar_source),
-            $other_name = `other`,
-            $other_source = `'other.file'`,
-            $other_name <: ensure_import_from(source = $other_source),
+fn js_match_arrow_args_list_without_parentheses() {
+    run_test_match({
+        TestArg {
+            pattern: r#"
+            language js
+            `$args => $body` where {
+                $args <: [$x, ...]
+            }"#
+            .to_owned(),
+            source: r#"
+            const x = (y) => {
+               return y;
+            }"#
+            .to_owned(),
         }
-        "#;
-
-    let pattern = format!("{}\n{}", import_patterns, pattern);
-
-    let source = r#"
-        |import { foo, bar } from 'bar'
-        |import { other } from 'other.file'
-        |"#
-    .trim_margin()
+    })
     .unwrap();
-
-    let results = execute(pattern, source);
-    assert_grit_snapshot!(results);
 }
 
 #[test]
-fn test_import_multiple() {
-    let root = get_fixtures_root().unwrap();
-    let import_patterns = format!("{}/test_patterns/imports.grit", root.display());
-    let import_patterns = fs_err::read_to_string(import_patterns).unwrap();
-
-    let pattern = r#"
-
-        `foo` as $f where {
-            $name = `buz`,
-            $buuuz_source = `'buuuz'`,
-            $name <: ensure_import_from(source = $buuuz_source),
-            $name <: ensure_import_from(source = $buuuz_source),
-            $new_name = `morefoo`,
-            $bar_source = `'bar'`,
-            $new_name <: ensure_import_from(source = $bar_source),
-            $new_name <: ensure_import_from(source = $bar_source),
-            $other_name = `otherfoo`,
-            $other_source = `'other.file'`,
-            $other_name <: ensure_import_from(source = $other_source),
+fn js_paren_params() {
+    run_test_expected({
+        TestArgExpected {
+            pattern: r#"
+            contains bubble arrow_function(parameters=$x, parenthesis = $y) where {
+                if ($y <: .) {
+                    $x => `()`
+                } else {
+                    $x => .
+                }
+            }"#
+            .to_owned(),
+            source: r#"
+            const foo = (bar) => {}
+            const baz = qux => {}"#
+                .to_owned(),
+            expected: r#"
+            const foo = () => {}
+            const baz = () => {}"#
+                .to_owned(),
         }
-        "#;
-
-    let pattern = format!("{}\n{}", import_patterns, pattern);
-
-    let source = r#"
-        |import { foo, bar } from 'bar'
-        |import { other } from 'other.file'
-        |"#
-    .trim_margin()
+    })
     .unwrap();
-
-    let results = execute(pattern, source);
-    assert_grit_snapshot!(results);
 }
 
 #[test]
-fn test_replace_program() {
-    run_test_expected(TestArgExpected {
-        pattern: r#"
-            program() as $p where {
-                $p <: contains `foo` => `buz`,
-                //$p <: contains `next` => `$p`,
-                $p => `>>$p<<`
-            }
-            "#
-        .to_owned(),
-        source: r#"foo(bar);"#.to_owned(),
-        expected: r#">>buz(bar);<<"#.trim_margin().unwrap(),
+fn js_preserve_significant_empty_statements() {
+    run_test_expected({
+        TestArgExpected {
+            pattern: r#"`$body` => `$body`"#.to_owned(),
+            source: r#"
+            for(;;);
+            if(true);
+            while(true);"#
+                .to_owned(),
+            expected: r#"
+            for(;;);
+            if(true);
+            while(true);"#
+                .to_owned(),
+        }
     })
     .unwrap();
 }
 
 #[test]
-#[ignore = "special case we don't care about yet"]
-// to handle we could replace our HashMap<CodeRanges, Option<String>>
-// with a Hashmap<CodeRanges, String>, and HashSet<Effects> so that
-// we can apply both effects in a specified order.
-fn test_duplicate_rewrite() {
-    run_test_expected(TestArgExpected {
-        pattern: r#"
-            program() as $p where {
-                $p => `foo($p)`,
-                $p => `bar($p)`
-            }
-            "#
-        .to_owned(),
-        source: r#"123"#.to_owned(),
-        expected: r#"foo(bar(123))"#.trim_margin().unwrap(),
+fn js_remove_redundant_empty_statements() {
+    run_test_expected({
+        TestArgExpected {
+            pattern: r#"`$body` => `$body`"#.to_owned(),
+            source: r#";;{;;};;"#.to_owned(),
+            expected: r#"{}"#.to_owned(),
+        }
     })
     .unwrap();
 }
 
-fn execute(pattern: String, source: String) -> Vec<MatchResult> {
-    let file = "test-file.tsx";
+#[test]
+fn array_destrcutring_snippet() {
+    run_test_match({
+        TestArg {
+            pattern: r#"
+                |language js
+                |
+                |`const [$name, $_] = useState($_)`
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"const [foo, bar] = useState(5)"#.to_string(),
+        }
+    })
+    .unwrap();
+}
 
-    let context = ExecutionContext::default();
-    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
-    let pattern = src_to_problem(pattern, js_lang).unwrap();
-    pattern.execute_file(&RichFile::new(file.to_owned(), source), &context)
+#[test]
+fn equals_zero_snippet() {
+    run_test_match({
+        TestArg {
+            pattern: r#"
+                |language js
+                |
+                |`$y = $x == -0`
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"let bar = foo(5) + 3 == -0"#.to_string(),
+        }
+    })
+    .unwrap();
 }
 
 #[test]
-fn clone_activites() {
-    let pattern = r#"
-language js
+fn extends_component() {
+    run_test_match({
+        TestArg {
+            pattern: r#"
+                |language js
+                |
+                |`extends Component`
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |class Button extends Component {
+                |  a = 1;
+                |}
+                |"#
+            .trim_margin()
+            .unwrap(),
+        }
+    })
+    .unwrap();
+}
 
-multifile {
-    $names = [],
-    bubble($names) file($name, $body) where {
-        $name <: r".+sdk/src/stdlib/index.ts",
-        $body <: contains `const stdlib = { $activities }` where {
-            $activities <: some bubble($names) $activity where {
-                $names += `'$activity'`
-            }
+#[test]
+fn import_react_snippet_no_match() {
+    run_test_no_match({
+        TestArg {
+            pattern: r#"
+                |language js
+                |
+                |`import React from 'react'`
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |import * as React from "react"
+                |"#
+            .trim_margin()
+            .unwrap(),
         }
-    },
-    bubble($names) file($name, $body) where {
-      $body <: contains `const stdlib = [$old] as const`,
-      $name <: r".+__generated__/stdlib.ts",
-      $new_names = join(list = $names, separator = ", "),
-      $old => `$new_names`,
-    },
-}"#
-    .into();
-    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
-    let pattern = src_to_problem(pattern, js_lang).unwrap();
-    let content1 = r#"
-            |import { findConfigs } from './configs';
-            |// Remember: ./packages/timekeeper/src/__generated__/stdlib.ts
-            |export const stdlib = {
-            |  show,
-            |  apply,
-            |  sh,
-            |};
-            |
-            |export type StdLibFunctions = typeof stdlib;"#
-        .trim_margin()
-        .unwrap();
-    let content2 = r#"
-            |export const stdlib = [
-            |  'foo',
-            |  'bar',
-            |  'baz',
-            |  'qux'
-            |] as const;
-            |
-            |export type StdLibNamedFunctions = typeof stdlib;"#
-        .trim_margin()
-        .unwrap();
-    let context = ExecutionContext::default();
-    let results = pattern.execute_files(
-        vec![
-            RichFile::new(
-                "~/dev/rewriter/packages/sdk/src/stdlib/index.ts".to_string(),
-                content1,
-            ),
-            RichFile::new(
-                "~/dev/rewriter/packages/timekeeper/src/__generated__/stdlib.ts".to_string(),
-                content2,
-            ),
-        ],
-        &context,
-    );
+    })
+    .unwrap();
+}
 
-    assert_grit_snapshot!(results);
+#[test]
+fn test_ranges() {
+    run_test_match({
+        TestArg {
+            pattern: r#"
+                |language js(js_do_not_use)
+                |$obj where {
+                |    $obj <: object($properties),
+                |}"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |const a = { foo: 'bar' };"#
+                .trim_margin()
+                .unwrap(),
+        }
+    })
+    .unwrap();
 }
 
 #[test]
-fn test_filename() {
+fn pattern_test_filename_match_and_use() {
+    run_test_expected({
+        TestArgExpected {
+            pattern: r#"
+                |language js
+                |
+                |file($name, body = contains `whatever` => `$name`)
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |whatever()
+                |"#
+            .trim_margin()
+            .unwrap(),
+            expected: r#"
+                |test-file.tsx()
+                |"#
+            .trim_margin()
+            .unwrap(),
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn test_conditional_snippet() {
+    run_test_expected({
+        TestArgExpected {
+            pattern: r#"
+                |language js
+                |`if ($cond) { $cond_true }` => `$cond`"#
+                .trim_margin()
+                .unwrap(),
+            source: r#"
+                if (!response) {
+                  return false;
+                }"#
+            .to_owned(),
+            expected: r#"!response"#.to_owned(),
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn test_file_rename() {
     let pattern = r#"
         |language js
         |
-        |pattern foo() {
-        |  $b where {
-        |    $b <: contains `whatever` => `$filename`,
-        |    $filename => `the_new_name`,
-        |  }
+        |file($name, $body) where {
+        |    $body <: contains `rename_to($new_name)` => `we_renamed_to($new_name)`,
+        |    $name => `$new_name`
         |}
-        |
-        |file(body = foo())
         |"#
     .trim_margin()
     .unwrap();
 
     let source = r#"
-    |whatever;
+    |rename_to(the_new_name)
     |"#
     .trim_margin()
     .unwrap();
@@ -5360,162 +5278,1554 @@ fn test_filename() {
 }
 
 #[test]
-fn test_program() {
+fn test_json_file_match_snippet() {
     let pattern = r#"
-        |language js
-        |
-        |pattern foo() {
-        |  $_ where $program <: contains `find_this` => `replace_with_this`
-        |}
+        |language json
         |
-        |file(body = $program) where {
-        |  $program <: foo()
-        |}
-        |"#
+        |file($name, $body) where {
+        |    $name <: r"(?:.+)package.json",
+        |    $body <: contains pair(key=`"name"`, $value),
+        |    $value => `"bar"`
+        |}"#
     .trim_margin()
     .unwrap();
 
     let source = r#"
-    |other_stuff;
-    |find_this;
-    |and_more;
-    |"#
-    .trim_margin()
-    .unwrap();
+        {
+          "not-name": "foo",
+          "name": "foo"
+        }
+        "#
+    .to_owned();
 
-    let file = "test-file.tsx";
+    let file = "foo.package.json";
 
     let context = ExecutionContext::default();
-    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
-    let pattern = src_to_problem(pattern, js_lang).unwrap();
+    let json_lang: TargetLanguage = PatternLanguage::Json.try_into().unwrap();
+    let pattern = src_to_problem(pattern, json_lang).unwrap();
     let results = pattern.execute_file(&RichFile::new(file.to_owned(), source), &context);
     assert_grit_snapshot!(results);
 }
 
 #[test]
-fn pattern_test_new_files_complex() {
-    run_test_expected_with_new_file({
-        TestArgExpectedWithNewFile {
+fn test_basic_python() {
+    let pattern = r#"
+        |language python
+        |
+        |`print($msg)` => `log($msg)`
+        |"#
+    .trim_margin()
+    .unwrap();
+
+    let source = r#"|print("hello world!")
+                    |log("this is python")"#
+        .trim_margin()
+        .unwrap();
+
+    let file = "foo.py";
+
+    let context = ExecutionContext::default();
+    let language: TargetLanguage = PatternLanguage::Python.try_into().unwrap();
+    let pattern = src_to_problem(pattern, language).unwrap();
+    let results = pattern.execute_file(&RichFile::new(file.to_owned(), source), &context);
+    assert_grit_snapshot!(results);
+}
+
+#[test]
+fn test_json_file_match_string() {
+    let pattern = r#"
+        |language json
+        |
+        |file($name, $body) where {
+        |    $name <: r"(?:.+)package.json",
+        |    $body <: contains pair(key="\"name\"", $value),
+        |    $value => `"bar"`
+        |}"#
+    .trim_margin()
+    .unwrap();
+
+    let source = r#"
+        {
+          "not-name": "foo",
+          "name": "foo"
+        }
+        "#
+    .to_owned();
+
+    let file = "foo.package.json";
+
+    let context = ExecutionContext::default();
+    let json_lang: TargetLanguage = PatternLanguage::Json.try_into().unwrap();
+    let pattern = src_to_problem(pattern, json_lang).unwrap();
+    let results = pattern.execute_file(&RichFile::new(file.to_owned(), source), &context);
+    assert_grit_snapshot!(results);
+}
+
+#[test]
+fn pattern_test_new_files() {
+    run_test_expected_with_new_file({
+        TestArgExpectedWithNewFile {
+            pattern: r#"
+                |language js
+                |
+                |`foo($name, $body)` => `bar()` where {
+                |    $new_files += file($name, $body)
+                |}
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |foo(the_new_file.tsx, whatever)
+                |"#
+            .trim_margin()
+            .unwrap(),
+            expected: r#"
+                |bar()
+                |"#
+            .trim_margin()
+            .unwrap(),
+            new_file_name: "the_new_file.tsx".into(),
+            new_file_body: r#"
+                |whatever
+                |"#
+            .trim_margin()
+            .unwrap(),
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn test_saving_info() {
+    let pattern = r#"
+        |language js
+        |
+        |program() where {
+        |  $l = [],
+        |  $keys = [],
+        |  $output = ``,
+        |  $program <: contains bubble ($l, $keys, $output) `foo($key, $value)` as $x where {
+        |    if ($l <: not some [$key, $value]) $l += [$key, $value],
+        |    if ($keys <: not some $key) $keys += $key
+        |  },
+        |  $keys <: some bubble($output, $l) $key where {
+        |    $values = [],
+        |    $l <: some bubble($key, $values) [$key, $value] where $values += $value,
+        |    $values_string = join(list = $values, separator = ", "),
+        |    $output += `$key->($values_string)`
+        |  },
+        |  $program => `$output`
+        |}
+        |
+        |"#
+    .trim_margin()
+    .unwrap();
+
+    let source = r#"
+    |foo(1, 11);
+    |foo(1, 12);
+    |foo(2, 21)
+    |"#
+    .trim_margin()
+    .unwrap();
+
+    let file = "test-file.tsx";
+
+    let context = ExecutionContext::default();
+    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
+    let pattern = src_to_problem(pattern, js_lang).unwrap();
+    let results = pattern.execute_file(&RichFile::new(file.to_owned(), source), &context);
+    assert_grit_snapshot!(results);
+}
+
+#[test]
+fn test_assoc_matcher() {
+    let pattern = r#"
+        |language js
+        |
+        |program() where {
+        |  $l = [[`1`, `2`, `3`]],
+        |  $x = `5`,
+        |  $l <: some [$x, $y, $z], // should not match because of $x
+        |  $program => `$x, $y, $z`
+        |}
+        |
+        |"#
+    .trim_margin()
+    .unwrap();
+
+    let source = r#"
+        |whatever
+        |"#
+    .trim_margin()
+    .unwrap();
+
+    let file = "test-file.tsx";
+
+    let context = ExecutionContext::default();
+    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
+    let pattern = src_to_problem(pattern, js_lang).unwrap();
+    let results = pattern.execute_file(&RichFile::new(file.to_owned(), source), &context);
+    assert_grit_snapshot!(results);
+}
+
+#[test]
+fn add_to_set() {
+    let pattern = r#"
+          pattern add_to_set($set) {
+            $element where {
+              if ($set <: not some $element) {
+                $set += $element
+              } else {
+                true
+              }
+            }
+          }
+          `end` as $end where {
+            $big_set = [],
+            $program <: contains bubble($big_set) `foo($x, $y)` where {
+                // this should work too:
+                // $element = [$x, $y],
+                //$element <: add_to_set(set = $big_set)
+
+                if ($big_set <: not some [$x, $y]) {
+                    $big_set += [$x, $y]
+                  } else {
+                    true
+                  }
+            },
+            $big_set_string = join(list = $big_set, separator = "||| "),
+            $end => `$big_set_string`
+          }
+            "#;
+
+    let source = r#"
+        |foo(1, 11);
+        |foo(1, 12);
+        |foo(1, 12);
+        |foo(2, 21);
+        |end
+        |"#
+    .trim_margin()
+    .unwrap();
+
+    let file = "test-file.tsx";
+
+    let context = ExecutionContext::default();
+    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
+    let pattern = src_to_problem(pattern.to_owned(), js_lang).unwrap();
+    let results = pattern.execute_file(&RichFile::new(file.to_owned(), source), &context);
+    assert_grit_snapshot!(results);
+}
+
+#[test]
+fn add_to_set_via_pattern() {
+    let pattern = r#"
+          pattern add_to_set($set) {
+            $element where {
+              if ($set <: not some $element) {
+                $set += $element
+              } else {
+                true
+              }
+            }
+          }
+          `end` as $end where {
+            $big_set = [],
+            $program <: contains bubble($big_set) `foo($x, $y)` where {
+                // this should work too:
+                $element = [$x, $y],
+                $element <: add_to_set(set = $big_set)
+            },
+            $big_set_string = join(list = $big_set, separator = "||| "),
+            $end => `$big_set_string`
+          }
+            "#;
+
+    let source = r#"
+        |foo(1, 11);
+        |foo(1, 12);
+        |foo(1, 12);
+        |foo(2, 21);
+        |end
+        |"#
+    .trim_margin()
+    .unwrap();
+
+    let file = "test-file.tsx";
+
+    let context = ExecutionContext::default();
+    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
+    let pattern = src_to_problem(pattern.to_owned(), js_lang).unwrap();
+    let results = pattern.execute_file(&RichFile::new(file.to_owned(), source), &context);
+    assert_grit_snapshot!(results);
+}
+
+#[test]
+fn test_assignment_in_method_call() {
+    run_test_expected({
+        TestArgExpected {
+            pattern: r#"
+                |language js
+                |
+                |pattern use_info($comp_name) {
+                |    variable_declarator($name, $value, $type) where {
+                |        $comp_name = `foo`
+                |    }
+                |}
+                |
+                |use_info($comp_name) => `$comp_name`
+                |
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |const _DateSelect = {
+                |    parameters: {
+                |      info: 'This is a Select date Component',
+                |    },
+                |  };
+                |"#
+            .trim_margin()
+            .unwrap(),
+            expected: r#"
+                |const foo;
+                |"#
+            .trim_margin()
+            .unwrap(),
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn test_same_var_twice_in_snippet() {
+    run_test_expected({
+        TestArgExpected {
+            pattern: r#"
+                |engine marzano(0.1)
+                |language js
+                |`<$name $props>$children</$name>` where {
+                |    $name <: `Foo` => `Bar`,
+                |}
+                |
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"<Foo name='sam'>Test</Foo>"#.to_owned(),
+            expected: r#"<Bar name='sam'>Test</Bar>"#.to_owned(),
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn simple_predicate() {
+    run_test_expected({
+        TestArgExpected {
+            pattern: r#"
+                |engine marzano(0.1)
+                |language js
+                |
+                |predicate is_foo($name) {
+                |    $name <: `Foo`
+                |}
+                |predicate rewrite($name) {
+                |    $name => `Bar`,
+                |}
+                |
+                |`<$name $props>$children</$name>` where {
+                |    if (is_foo($name)) {
+                |          rewrite($name)
+                |    }
+                |}
+                |
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                | <Foo name='sam'>Test</Foo>
+                | <Baz name='sam'>Test</Baz>"#
+                .trim_margin()
+                .unwrap(),
+            expected: r#"
+                | <Bar name='sam'>Test</Bar>
+                | <Baz name='sam'>Test</Baz>"#
+                .trim_margin()
+                .unwrap(),
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn simple_predicate_false() {
+    let res = run_test_expected({
+        TestArgExpected {
+            pattern: r#"
+                |engine marzano(0.1)
+                |language js
+                |
+                |predicate is_foo($name) {
+                |    $name <: `Foo`
+                |}
+                |predicate rewrite($name) {
+                |    $name => `Bar`,
+                |}
+                |
+                |`<$name $props>$children</$name>` where {
+                |    if (is_foo($name)) {
+                |          rewrite($name)
+                |    }
+                |}
+                |
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"<Baz name='sam'>Test</Baz>"#.to_owned(),
+            expected: r#"<Baz name='sam'>Test</Baz>"#.to_owned(),
+        }
+    });
+    assert_eq!(res.unwrap_err().to_string(), "found a match but no rewrite");
+}
+
+#[test]
+fn test_import_none() {
+    let root = get_fixtures_root().unwrap();
+    let import_patterns = format!("{}/test_patterns/imports.grit", root.display());
+    let import_patterns = fs_err::read_to_string(import_patterns).unwrap();
+
+    let pattern = r#"
+
+        `foo`
+        "#;
+
+    let pattern = format!("{}\n{}", import_patterns, pattern);
+
+    let source = r#"
+        |import { foo, bar } from 'bar'
+        |import { other } from 'other.file'
+        |"#
+    .trim_margin()
+    .unwrap();
+
+    let results = execute(pattern, source);
+    assert_grit_snapshot!(results);
+}
+
+#[test]
+fn rewrite_or_bubble_pattern_argument() {
+    let pattern = r#"
+        engine marzano(1.0)
+        language js
+
+        `it($_, $body)` as $m where {
+            $body <: contains  `foo` as $to_rewrite,
+
+            $m <: bubble($to_rewrite) $_ where {
+                $to_rewrite => `MARKER`
+            }
+        }
+
+        "#;
+
+    let source = r#"
+            it('consolidates', async () => {
+              foo;
+              foo;
+              foo;
+            });
+        "#;
+
+    let results = execute(pattern.into(), source.into());
+    assert_grit_snapshot!(results);
+}
+
+#[test]
+fn test_import_just_insert() {
+    let root = get_fixtures_root().unwrap();
+    let import_patterns = format!("{}/test_patterns/imports.grit", root.display());
+    let import_patterns = fs_err::read_to_string(import_patterns).unwrap();
+
+    let pattern = r#"
+
+        `foo` as $f where {
+            $name = `buz`,
+            $buuuz_source = `'buuuz'`,
+            $name <: ensure_import_from(source = $buuuz_source),
+        }
+        "#;
+
+    let pattern = format!("{}\n{}", import_patterns, pattern);
+
+    let source = r#"
+        |import { foo, bar } from 'bar'
+        |import { other } from 'other.file'
+        |"#
+    .trim_margin()
+    .unwrap();
+
+    let results = execute(pattern, source);
+    assert_grit_snapshot!(results);
+}
+
+#[test]
+fn pattern_call_as_rhs() {
+    let root = get_fixtures_root().unwrap();
+    let import_patterns = format!("{}/test_patterns/imports.grit", root.display());
+    let import_patterns = fs_err::read_to_string(import_patterns).unwrap();
+
+    let pattern = r#"
+        // does not work:
+        `orderBy` as $ob where { $ob <: ensure_import_from(source=`"lodash"`) }
+        // does work:
+        // `orderBy` as $ob where { $source=`"lodash"`, $ob <: ensure_import_from($source) }
+        "#;
+
+    let pattern = format!("{}\n{}", import_patterns, pattern);
+
+    let source = r#"
+        |var increment = function (i) {
+        |    orderBy();
+        |  return i + 1;
+        |};
+        |"#
+    .trim_margin()
+    .unwrap();
+    let expected = r#"
+        |import { orderBy } from "lodash";
+        |var increment = function (i) {
+        |    orderBy();
+        |  return i + 1;
+        |};
+        |"#
+    .trim_margin()
+    .unwrap();
+    run_test_expected(TestArgExpected {
+        pattern,
+        source,
+        expected,
+    })
+    .unwrap();
+}
+
+#[test]
+fn pattern_call_as_lhs_and_rhs() {
+    let root = get_fixtures_root().unwrap();
+    let import_patterns = format!("{}/test_patterns/imports.grit", root.display());
+    let import_patterns = fs_err::read_to_string(import_patterns).unwrap();
+
+    let pattern = r#"
+            $x where {
+                $x <: js"makeStyles",
+                $x <: remove_import(from=`'@material-ui/core'`)
+            }"#;
+
+    let pattern = format!("{}\n{}", import_patterns, pattern);
+
+    let source = r#"import { Box, Paper, makeStyles } from "@material-ui/core";"#.to_owned();
+    let expected = r#"import { Box, Paper  } from "@material-ui/core";"#.to_owned();
+    run_test_expected(TestArgExpected {
+        pattern,
+        source,
+        expected,
+    })
+    .unwrap();
+}
+
+#[test]
+fn test_import_all_already_there() {
+    let root = get_fixtures_root().unwrap();
+    let import_patterns = format!("{}/test_patterns/imports.grit", root.display());
+    let import_patterns = fs_err::read_to_string(import_patterns).unwrap();
+
+    let pattern = r#"
+
+        `foo` as $f where {
+            $new_name = `bar`,
+            $bar_source = `'bar'`,
+            $new_name <: ensure_import_from(source = $bar_source),
+            $new_name <: ensure_import_from(source = $bar_source),
+            $other_name = `other`,
+            $other_source = `'other.file'`,
+            $other_name <: ensure_import_from(source = $other_source),
+        }
+        "#;
+
+    let pattern = format!("{}\n{}", import_patterns, pattern);
+
+    let source = r#"
+        |import { foo, bar } from 'bar'
+        |import { other } from 'other.file'
+        |"#
+    .trim_margin()
+    .unwrap();
+
+    let results = execute(pattern, source);
+    assert_grit_snapshot!(results);
+}
+
+#[test]
+fn test_import_multiple() {
+    let root = get_fixtures_root().unwrap();
+    let import_patterns = format!("{}/test_patterns/imports.grit", root.display());
+    let import_patterns = fs_err::read_to_string(import_patterns).unwrap();
+
+    let pattern = r#"
+
+        `foo` as $f where {
+            $name = `buz`,
+            $buuuz_source = `'buuuz'`,
+            $name <: ensure_import_from(source = $buuuz_source),
+            $name <: ensure_import_from(source = $buuuz_source),
+            $new_name = `morefoo`,
+            $bar_source = `'bar'`,
+            $new_name <: ensure_import_from(source = $bar_source),
+            $new_name <: ensure_import_from(source = $bar_source),
+            $other_name = `otherfoo`,
+            $other_source = `'other.file'`,
+            $other_name <: ensure_import_from(source = $other_source),
+        }
+        "#;
+
+    let pattern = format!("{}\n{}", import_patterns, pattern);
+
+    let source = r#"
+        |import { foo, bar } from 'bar'
+        |import { other } from 'other.file'
+        |"#
+    .trim_margin()
+    .unwrap();
+
+    let results = execute(pattern, source);
+    assert_grit_snapshot!(results);
+}
+
+#[test]
+fn test_replace_program() {
+    run_test_expected(TestArgExpected {
+        pattern: r#"
+            program() as $p where {
+                $p <: contains `foo` => `buz`,
+                //$p <: contains `next` => `$p`,
+                $p => `>>$p<<`
+            }
+            "#
+        .to_owned(),
+        source: r#"foo(bar);"#.to_owned(),
+        expected: r#">>buz(bar);<<"#.trim_margin().unwrap(),
+    })
+    .unwrap();
+}
+
+#[test]
+#[ignore = "special case we don't care about yet"]
+// to handle we could replace our HashMap<CodeRanges, Option<String>>
+// with a Hashmap<CodeRanges, String>, and HashSet<Effects> so that
+// we can apply both effects in a specified order.
+fn test_duplicate_rewrite() {
+    run_test_expected(TestArgExpected {
+        pattern: r#"
+            program() as $p where {
+                $p => `foo($p)`,
+                $p => `bar($p)`
+            }
+            "#
+        .to_owned(),
+        source: r#"123"#.to_owned(),
+        expected: r#"foo(bar(123))"#.trim_margin().unwrap(),
+    })
+    .unwrap();
+}
+
+fn execute(pattern: String, source: String) -> Vec<MatchResult> {
+    let file = "test-file.tsx";
+
+    let context = ExecutionContext::default();
+    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
+    let pattern = src_to_problem(pattern, js_lang).unwrap();
+    pattern.execute_file(&RichFile::new(file.to_owned(), source), &context)
+}
+
+#[test]
+fn clone_activites() {
+    let pattern = r#"
+language js
+
+multifile {
+    $names = [],
+    bubble($names) file($name, $body) where {
+        $name <: r".+sdk/src/stdlib/index.ts",
+        $body <: contains `const stdlib = { $activities }` where {
+            $activities <: some bubble($names) $activity where {
+                $names += `'$activity'`
+            }
+        }
+    },
+    bubble($names) file($name, $body) where {
+      $body <: contains `const stdlib = [$old] as const`,
+      $name <: r".+__generated__/stdlib.ts",
+      $new_names = join(list = $names, separator = ", "),
+      $old => `$new_names`,
+    },
+}"#
+    .into();
+    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
+    let pattern = src_to_problem(pattern, js_lang).unwrap();
+    let content1 = r#"
+            |import { findConfigs } from './configs';
+            |// Remember: ./packages/timekeeper/src/__generated__/stdlib.ts
+            |export const stdlib = {
+            |  show,
+            |  apply,
+            |  sh,
+            |};
+            |
+            |export type StdLibFunctions = typeof stdlib;"#
+        .trim_margin()
+        .unwrap();
+    let content2 = r#"
+            |export const stdlib = [
+            |  'foo',
+            |  'bar',
+            |  'baz',
+            |  'qux'
+            |] as const;
+            |
+            |export type StdLibNamedFunctions = typeof stdlib;"#
+        .trim_margin()
+        .unwrap();
+    let context = ExecutionContext::default();
+    let results = pattern.execute_files(
+        vec![
+            RichFile::new(
+                "~/dev/rewriter/packages/sdk/src/stdlib/index.ts".to_string(),
+                content1,
+            ),
+            RichFile::new(
+                "~/dev/rewriter/packages/timekeeper/src/__generated__/stdlib.ts".to_string(),
+                content2,
+            ),
+        ],
+        &context,
+    );
+
+    assert_grit_snapshot!(results);
+}
+
+#[test]
+fn test_filename() {
+    let pattern = r#"
+        |language js
+        |
+        |pattern foo() {
+        |  $b where {
+        |    $b <: contains `whatever` => `$filename`,
+        |    $filename => `the_new_name`,
+        |  }
+        |}
+        |
+        |file(body = foo())
+        |"#
+    .trim_margin()
+    .unwrap();
+
+    let source = r#"
+    |whatever;
+    |"#
+    .trim_margin()
+    .unwrap();
+
+    let file = "test-file.tsx";
+
+    let context = ExecutionContext::default();
+    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
+    let pattern = src_to_problem(pattern, js_lang).unwrap();
+    let results = pattern.execute_file(&RichFile::new(file.to_owned(), source), &context);
+    assert_grit_snapshot!(results);
+}
+
+#[test]
+fn test_program() {
+    let pattern = r#"
+        |language js
+        |
+        |pattern foo() {
+        |  $_ where $program <: contains `find_this` => `replace_with_this`
+        |}
+        |
+        |file(body = $program) where {
+        |  $program <: foo()
+        |}
+        |"#
+    .trim_margin()
+    .unwrap();
+
+    let source = r#"
+    |other_stuff;
+    |find_this;
+    |and_more;
+    |"#
+    .trim_margin()
+    .unwrap();
+
+    let file = "test-file.tsx";
+
+    let context = ExecutionContext::default();
+    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
+    let pattern = src_to_problem(pattern, js_lang).unwrap();
+    let results = pattern.execute_file(&RichFile::new(file.to_owned(), source), &context);
+    assert_grit_snapshot!(results);
+}
+
+#[test]
+fn pattern_test_new_files_complex() {
+    run_test_expected_with_new_file({
+        TestArgExpectedWithNewFile {
+            pattern: r#"
+                language js
+
+                file($name, $body) where {
+                    $body <: contains `theNameOfTheFileIs($new_name)` => `'$name'`,
+                    $body <: contains bubble `createNewFile($name, $body)` where {
+                        $new_files += file($name, $body)
+                    },
+                    $name => `$new_name`
+                }
+                "#
+            .into(),
+            source: r#"
+                |theNameOfTheFileIs(the_newwww)
+                |createNewFile(the_new_name, the_new_body)
+                |"#
+            .trim_margin()
+            .unwrap(),
+            expected: r#"
+                |'test-file.tsx'
+                |createNewFile(the_new_name, the_new_body)
+                |"#
+            .trim_margin()
+            .unwrap(),
+            new_file_name: "the_new_name".into(),
+            new_file_body: r#"
+                |the_new_body
+                |"#
+            .trim_margin()
+            .unwrap(),
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn test_filename_autoset() {
+    let pattern = r#"
+        |language js
+        |
+        |`whatever` => `changed` where $filename => `the_new_name`
+        |"#
+    .trim_margin()
+    .unwrap();
+
+    let source = r#"
+    |whatever;
+    |"#
+    .trim_margin()
+    .unwrap();
+
+    let file = "test-file.tsx";
+
+    let context = ExecutionContext::default();
+    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
+    let pattern = src_to_problem(pattern, js_lang).unwrap();
+    let results = pattern.execute_file(&RichFile::new(file.to_owned(), source), &context);
+    assert_grit_snapshot!(results);
+}
+
+#[test]
+fn test_program_autoset() {
+    let pattern = r#"
+        |language js
+        |
+        |`foo` where $program <: contains `bar` => `baz`
+        |"#
+    .trim_margin()
+    .unwrap();
+
+    let source = r#"
+    |foo(bar);
+    |"#
+    .trim_margin()
+    .unwrap();
+
+    let file = "test-file.tsx";
+
+    let context = ExecutionContext::default();
+    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
+    let pattern = src_to_problem(pattern, js_lang).unwrap();
+    let results = pattern.execute_file(&RichFile::new(file.to_owned(), source), &context);
+    assert_grit_snapshot!(results);
+}
+
+#[test]
+fn pattern_test_new_files_in_pattern_def() {
+    run_test_expected_with_new_file({
+        TestArgExpectedWithNewFile {
+            pattern: r#"
+                language js
+
+                pattern make_new_file() {
+                    `createNewFile($name, $body)` where {
+                        $new_files += file($name, $body)
+                    }
+                }
+
+                file($name, $body) where {
+                    $body <: contains `theNameOfTheFileIs($new_name)` => `'$name'`,
+                    $body <: contains make_new_file(),
+                    $name => `$new_name`
+                }
+                "#
+            .into(),
+            source: r#"
+                |theNameOfTheFileIs(the_newwww)
+                |createNewFile(the_new_name, the_new_body)
+                |"#
+            .trim_margin()
+            .unwrap(),
+            expected: r#"
+                |'test-file.tsx'
+                |createNewFile(the_new_name, the_new_body)
+                |"#
+            .trim_margin()
+            .unwrap(),
+            new_file_name: "the_new_name".into(),
+            new_file_body: r#"
+                |the_new_body
+                |"#
+            .trim_margin()
+            .unwrap(),
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn pattern_def_with_bad_arg() {
+    run_test_no_match({
+        TestArg {
+            pattern: r#"
+                |language js
+                |pattern MainPattern($supertype) {
+                |    class_declaration(
+                |       name = $className,
+                |       heritage = contains $supertype,
+                |       body = $body
+                |   ) => `const $className = $body`
+                |}
+                |contains MainPattern(supertype = "NotComponent")"#
+                .trim_margin()
+                .unwrap(),
+            source: r#"
+                |class Button extends Component {
+                |  a = 1;
+                |}
+                |
+                |class AnotherButton extends Component {
+                |  b = 2;
+                |}"#
+            .trim_margin()
+            .unwrap(),
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn pattern_by_reference() {
+    run_test_match({
+        TestArg {
+            pattern: r#"
+                |pattern foo($bar) {
+                |    class_declaration(
+                |        name = $className,
+                |        heritage = contains "Component",
+                |        body = $body
+                |    ) => `const $className = $bar`
+                |}
+                |and {
+                |   $baz = "{ b = 2; }",
+                |   foo(bar = $baz)
+                |}"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |class Button extends Component {
+                |  a = 1;
+                |}
+                |
+                |class AnotherButton extends Component {
+                |  b = 2;
+                |}"#
+            .trim_margin()
+            .unwrap(),
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn test_args_syntactic_sugar() {
+    run_test_match({
+        TestArg {
+            pattern: r#"
+                |pattern foo($bar) {
+                |    class_declaration(
+                |        name = $className,
+                |        heritage = contains "Component",
+                |        $body
+                |    ) => `const $className = $bar`
+                |}
+                |and {
+                |   $bar = "{ b = 2; }",
+                |   foo($bar)
+                |}"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |class Button extends Component {
+                |  a = 1;
+                |}
+                |
+                |class AnotherButton extends Component {
+                |  b = 2;
+                |}"#
+            .trim_margin()
+            .unwrap(),
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn curly_metavar() {
+    run_test_match({
+        TestArg {
+            pattern: r#"
+                |pattern foo($bar) {
+                |    class_declaration(
+                |        name = $className,
+                |        heritage = contains "Component",
+                |        body = $body
+                |    ) => `const $className = $[bar]`
+                |}
+                |and {
+                |   $baz = "{ b = 2; }",
+                |   foo(bar = $baz)
+                |}"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |class Button extends Component {
+                |  a = 1;
+                |}
+                |
+                |class AnotherButton extends Component {
+                |  b = 2;
+                |}"#
+            .trim_margin()
+            .unwrap(),
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn pattern_append_string() {
+    run_test_match({
+        TestArg {
+            pattern: r#"
+                |pattern handlePairs($accum) {
+                |    or {
+                |       pair_pattern(key = $key, value = $value) where {
+                |           $accum += `$key as $value, `
+                |       }
+                |   }
+                |}
+                |and {
+                |   $result = "{ ",
+                |   variable_declarator(name = contains handlePairs(accum = $result)),
+                |   $result += "}",
+                |   $x => `$result`
+                |}"#
+            .trim_margin()
+            .unwrap(),
+            source:
+                r#"const { named1, alias2: named2, alias3: named3 } = require('path/to/file/1');"#
+                    .to_string(),
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn export_object() {
+    run_test_match({
+            TestArg {
+                pattern: r#"
+                |and {
+                |   $program,
+                |   contains $x where {
+                |       $x <: shorthand_property_identifier(),
+                |       $program <: contains lexical_declaration(declarations = [variable_declarator(name = $x, value = $val)]) => `export const $x = $val`,
+                |       $x => ``
+                |   }
+                |}"#
+                    .trim_margin()
+                    .unwrap(),
+                source: r#"
+                |const king = '9';
+                |
+                |module.exports = {
+                |  king,
+                |  queen: '8',
+                |};"#
+                .trim_margin()
+                .unwrap(),
+            }
+        })
+        .unwrap();
+}
+
+#[test]
+fn transform_test() {
+    run_test_match({
+        TestArg {
+            pattern: r#"
+                |language js
+                |
+                |`$name` where {
+                |    $name <: within `var $name = $_`
+                |}"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |// This is a comment. Please ignore it.
+                |// Really we should just call variables by their number
+                |var a = 1;
+                |var b = 2;
+                |// This is a comment. Please ignore it.
+                |var c = 3;
+                |var bigLongName = 4;"#
+                .trim_margin()
+                .unwrap(),
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn json_snippet_simple() {
+    run_test_match({
+        TestArg {
+            pattern: r#"
+                |language json
+                |
+                |`{ "foo": $x }` => `{ "foo": "baz" }`"#
+                .trim_margin()
+                .unwrap(),
+            source: r#"
+                |{
+                |    "foo": "bar"
+                |}"#
+            .trim_margin()
+            .unwrap(),
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn python_node_type_mismatch() {
+    run_test_match({
+        TestArg {
+            pattern: r#"
+            |engine marzano(0.1)
+            |language python
+            |
+            |`def stuff(): $body` where {
+            |    $body <: contains `$x = "9"`,
+            |    $body <: contains `print($x)`
+            |}"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+            |def stuff():
+            |  foo = "9"
+            |  print(foo)"#
+                .trim_margin()
+                .unwrap(),
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn test_json_swap_kv() {
+    run_test_expected({
+        TestArgExpected {
+            pattern: r#"
+                |language json
+                |`{ $x: $y }` => `{ $y: $x }`"#
+                .trim_margin()
+                .unwrap(),
+            source: r#"
+                |{
+                |    "foo": "bar"
+                |}"#
+            .trim_margin()
+            .unwrap(),
+            expected: r#"
+                |{ "bar": "foo" }"#
+                .trim_margin()
+                .unwrap(),
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn test_step1() {
+    run_test_expected({
+        TestArgExpected {
+            pattern: r#"
+                |language js
+                |contains `{ foo: $y }` => `{ bar: $y }`"#
+                .trim_margin()
+                .unwrap(),
+            source: r#"
+                |let x = {
+                |    foo: "bar",
+                |};"#
+                .trim_margin()
+                .unwrap(),
+            expected: r#"
+                |let x = { bar: "bar" };"#
+                .trim_margin()
+                .unwrap(),
+        }
+    })
+    .unwrap();
+}
+#[test]
+fn test_step2() {
+    run_test_expected({
+        TestArgExpected {
+            pattern: r#"
+                |language js
+                |contains `{ bar: $y }` => `{ baz: $y }`"#
+                .trim_margin()
+                .unwrap(),
+            source: r#"
+                |let x = { bar: "bar" };"#
+                .trim_margin()
+                .unwrap(),
+            expected: r#"
+                |let x = { baz: "bar" };"#
+                .trim_margin()
+                .unwrap(),
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn no_export_class() {
+    run_test_no_match({
+        TestArg {
+            pattern: r#"
+                |language js
+                |pattern js_class_export() {
+                |    $class where {
+                |        $class <: and {
+                |            class_declaration(name = $name, body = $body),
+                |            not within export_statement()
+                |        }
+                |    }
+                |}
+                |
+                |js_class_export()"#
+                .trim_margin()
+                .unwrap(),
+            source: r#"export class Pattern1b {}"#.trim_margin().unwrap(),
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn test_any_pattern() {
+    run_test_expected({
+        TestArgExpected {
+            pattern: r#"
+                |language js
+                |
+                |any {
+                |    call_expression(function = "f3", arguments = [$args]) => `g($args)`,
+                |    call_expression(function = "f", arguments = [$args]) => `g($args)`,
+                |    call_expression(function = "f2", arguments = [$args]) => `g($args)`
+                |}"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |   f("hello")
+                |   f2("hello")
+                |"#
+            .trim_margin()
+            .unwrap(),
+            expected: r#"
+                |   g("hello")
+                |   g("hello")
+                |"#
+            .trim_margin()
+            .unwrap(),
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn test_any_predicate() {
+    run_test_expected({
+        TestArgExpected {
+            pattern: r#"
+                |language js
+                |
+                |$x where any {
+                |    true,
+                |    $x => `foo`,
+                |    false,
+                |}"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |   f("hello")
+                |"#
+            .trim_margin()
+            .unwrap(),
+            expected: r#"
+                |   foo
+                |"#
+            .trim_margin()
+            .unwrap(),
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn test_after_pattern() {
+    run_test_expected({
+        TestArgExpected {
             pattern: r#"
-                language js
-
-                file($name, $body) where {
-                    $body <: contains `theNameOfTheFileIs($new_name)` => `'$name'`,
-                    $body <: contains bubble `createNewFile($name, $body)` where {
-                        $new_files += file($name, $body)
-                    },
-                    $name => `$new_name`
-                }
-                "#
-            .into(),
+                |language js
+                |
+                |call_expression(function = $f, arguments = [$args]) as $call => `g($args)` where {
+                |   $call <: after contains call_expression()
+                |}"#
+            .trim_margin()
+            .unwrap(),
             source: r#"
-                |theNameOfTheFileIs(the_newwww)
-                |createNewFile(the_new_name, the_new_body)
+                |   f("hello")
+                |   f2("hello")
                 |"#
             .trim_margin()
             .unwrap(),
             expected: r#"
-                |'test-file.tsx'
-                |createNewFile(the_new_name, the_new_body)
+                |   f("hello")
+                |   g("hello")
                 |"#
             .trim_margin()
             .unwrap(),
-            new_file_name: "the_new_name".into(),
-            new_file_body: r#"
-                |the_new_body
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn test_before_pattern() {
+    run_test_expected({
+        TestArgExpected {
+            pattern: r#"
+                |language js
+                |
+                |call_expression(function = $f, arguments = [$args]) as $call => `g($args)` where {
+                |   $call <: before contains call_expression()
+                |}"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |f("hi")
+                |f2("hi")
+                |// this will not be touched
+                |function() {
+                |  f3("bob");
+                |}
+                |// this one will
+                |function() {
+                |  f4("cool");
+                |  f5("school");
+                |}
                 |"#
             .trim_margin()
             .unwrap(),
+            expected: r#"
+            |g("hi")
+            |f2("hi")
+            |// this will not be touched
+            |function() {
+            |  f3("bob");
+            |}
+            |// this one will
+            |function() {
+            |  g("cool");
+            |  f5("school");
+            |}
+            |
+            |"#
+            .trim_margin()
+            .unwrap(),
         }
     })
     .unwrap();
 }
 
 #[test]
-fn test_filename_autoset() {
-    let pattern = r#"
-        |language js
-        |
-        |`whatever` => `changed` where $filename => `the_new_name`
-        |"#
-    .trim_margin()
-    .unwrap();
-
-    let source = r#"
-    |whatever;
-    |"#
-    .trim_margin()
+fn test_after_pattern_rhs() {
+    run_test_expected({
+        TestArgExpected {
+            pattern: r#"
+                |engine marzano(0.1)
+                |language js
+                |
+                |`const foo = 9;` as $foo where {
+                |    $previous = after $foo,
+                |    $foo => $previous
+                |}"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |var increment = function (i) {
+                |    const foo = 9;
+                |    const x = 10;
+                |};"#
+                .trim_margin()
+                .unwrap(),
+            expected: r#"
+            |var increment = function (i) {
+            |    const x = 10;
+            |    const x = 10;
+            |};"#
+                .trim_margin()
+                .unwrap(),
+        }
+    })
     .unwrap();
-
-    let file = "test-file.tsx";
-
-    let context = ExecutionContext::default();
-    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
-    let pattern = src_to_problem(pattern, js_lang).unwrap();
-    let results = pattern.execute_file(&RichFile::new(file.to_owned(), source), &context);
-    assert_grit_snapshot!(results);
 }
 
 #[test]
-fn test_program_autoset() {
-    let pattern = r#"
-        |language js
-        |
-        |`foo` where $program <: contains `bar` => `baz`
-        |"#
-    .trim_margin()
-    .unwrap();
-
-    let source = r#"
-    |foo(bar);
-    |"#
-    .trim_margin()
+fn test_before_pattern_rhs() {
+    run_test_expected({
+        TestArgExpected {
+            pattern: r#"
+                |engine marzano(0.1)
+                |language js
+                |
+                |`const foo = 9;` as $foo where {
+                |    $previous = before $foo,
+                |    $foo => $previous
+                |}"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |var increment = function (i) {
+                |    const x = 10;
+                |    const foo = 9;
+                |};"#
+                .trim_margin()
+                .unwrap(),
+            expected: r#"
+            |var increment = function (i) {
+            |    const x = 10;
+            |    const x = 10;
+            |};"#
+                .trim_margin()
+                .unwrap(),
+        }
+    })
     .unwrap();
-
-    let file = "test-file.tsx";
-
-    let context = ExecutionContext::default();
-    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
-    let pattern = src_to_problem(pattern, js_lang).unwrap();
-    let results = pattern.execute_file(&RichFile::new(file.to_owned(), source), &context);
-    assert_grit_snapshot!(results);
 }
 
 #[test]
-fn pattern_test_new_files_in_pattern_def() {
-    run_test_expected_with_new_file({
-        TestArgExpectedWithNewFile {
+fn test_global_variables() {
+    run_test_expected({
+        TestArgExpected {
             pattern: r#"
-                language js
-
-                pattern make_new_file() {
-                    `createNewFile($name, $body)` where {
-                        $new_files += file($name, $body)
-                    }
-                }
-
-                file($name, $body) where {
-                    $body <: contains `theNameOfTheFileIs($new_name)` => `'$name'`,
-                    $body <: contains make_new_file(),
-                    $name => `$new_name`
-                }
-                "#
-            .into(),
+                |language js
+                |
+                |pattern the_foo() {
+                |   `foo($GLOBAL_VAR)`
+                |}
+                |pattern the_bar() {
+                |   `bar` => `baz($GLOBAL_VAR)`
+                |}
+                |
+                |program(statements = and {
+                |  contains the_foo(),
+                |  contains the_bar()
+                |})
+                |"#
+            .trim_margin()
+            .unwrap(),
             source: r#"
-                |theNameOfTheFileIs(the_newwww)
-                |createNewFile(the_new_name, the_new_body)
+                |   foo(1)
+                |   bar
                 |"#
             .trim_margin()
             .unwrap(),
             expected: r#"
-                |'test-file.tsx'
-                |createNewFile(the_new_name, the_new_body)
+                |   foo(1)
+                |   baz(1)
+                |"#
+            .trim_margin()
+            .unwrap(),
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn test_pattern_call() {
+    run_test_expected({
+        TestArgExpected {
+            pattern: r#"
+                |language js
+                |
+                |pattern foo() {
+                |    call_expression(function="f", arguments=[$args]) => `g($args)`
+                |}
+                |contains foo()"#
+                .trim_margin()
+                .unwrap(),
+            source: r#"
+                |   f("hello")
+                |   f("hello2")
                 |"#
             .trim_margin()
             .unwrap(),
-            new_file_name: "the_new_name".into(),
-            new_file_body: r#"
-                |the_new_body
+            expected: r#"
+                |   g("hello")
+                |   g("hello2")
                 |"#
             .trim_margin()
             .unwrap(),
@@ -5525,29 +6835,25 @@ fn pattern_test_new_files_in_pattern_def() {
 }
 
 #[test]
-fn pattern_def_with_bad_arg() {
-    run_test_no_match({
-        TestArg {
+fn test_bubble() {
+    run_test_expected({
+        TestArgExpected {
             pattern: r#"
                 |language js
-                |pattern MainPattern($supertype) {
-                |    class_declaration(
-                |       name = $className,
-                |       heritage = contains $supertype,
-                |       body = $body
-                |   ) => `const $className = $body`
-                |}
-                |contains MainPattern(supertype = "NotComponent")"#
+                |
+                |call_expression(function="f", arguments=[$args]) => `g($args)`"#
                 .trim_margin()
                 .unwrap(),
             source: r#"
-                |class Button extends Component {
-                |  a = 1;
-                |}
-                |
-                |class AnotherButton extends Component {
-                |  b = 2;
-                |}"#
+                |   f("hello")
+                |   f("hello2")
+                |"#
+            .trim_margin()
+            .unwrap(),
+            expected: r#"
+                |   g("hello")
+                |   g("hello2")
+                |"#
             .trim_margin()
             .unwrap(),
         }
@@ -5556,31 +6862,31 @@ fn pattern_def_with_bad_arg() {
 }
 
 #[test]
-fn pattern_by_reference() {
-    run_test_match({
-        TestArg {
+fn test_range_line() {
+    run_test_expected({
+        TestArgExpected {
             pattern: r#"
-                |pattern foo($bar) {
-                |    class_declaration(
-                |        name = $className,
-                |        heritage = contains "Component",
-                |        body = $body
-                |    ) => `const $className = $bar`
-                |}
-                |and {
-                |   $baz = "{ b = 2; }",
-                |   foo(bar = $baz)
+                |language js
+                |`const $x` where {
+                |    $x <: within range(start_line=2, end_line=3),
+                |    $x => `FOO = 5`
                 |}"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                |class Button extends Component {
-                |  a = 1;
-                |}
-                |
-                |class AnotherButton extends Component {
-                |  b = 2;
-                |}"#
+                |   const foo = 5
+                |   const foo = 5
+                |   const foo = 5
+                |   const foo = 5
+                |"#
+            .trim_margin()
+            .unwrap(),
+            expected: r#"
+            |   const foo = 5
+            |   const FOO = 5
+            |   const FOO = 5
+            |   const foo = 5
+            |"#
             .trim_margin()
             .unwrap(),
         }
@@ -5589,317 +6895,418 @@ fn pattern_by_reference() {
 }
 
 #[test]
-fn test_args_syntactic_sugar() {
-    run_test_match({
-        TestArg {
+fn test_lazy_join() {
+    run_test_expected({
+        TestArgExpected {
             pattern: r#"
-                |pattern foo($bar) {
-                |    class_declaration(
-                |        name = $className,
-                |        heritage = contains "Component",
-                |        $body
-                |    ) => `const $className = $bar`
-                |}
-                |and {
-                |   $bar = "{ b = 2; }",
-                |   foo($bar)
-                |}"#
-            .trim_margin()
-            .unwrap(),
+engine marzano(0.1)
+language js
+
+`class $foo { $body }` where {
+    $fns = [],
+    $body <: contains bubble($fns) {
+        `$name($_) { $body }` as $fn where {
+                $body => `"foo"`,
+                $fns += $fn
+            }
+        },
+    $joined = join(list=$fns, separator=`\n`),
+    $body => $joined
+}"#
+            .to_owned(),
             source: r#"
-                |class Button extends Component {
-                |  a = 1;
-                |}
-                |
-                |class AnotherButton extends Component {
-                |  b = 2;
-                |}"#
-            .trim_margin()
-            .unwrap(),
+class Rectangle {
+  height = 2;
+  constructor(height, width) {
+    this.height = height;
+    this.width = width;
+  }
+
+  first() {
+    return 1 + 1;
+  }
+}"#
+            .to_owned(),
+            expected: r#"
+class Rectangle {
+  constructor(height, width) {
+    "foo"
+  }
+first() {
+    "foo"
+  }
+}"#
+            .to_owned(),
         }
     })
     .unwrap();
 }
 
 #[test]
-fn curly_metavar() {
-    run_test_match({
-        TestArg {
+fn contains_lazy_join() {
+    run_test_expected({
+        TestArgExpected {
             pattern: r#"
-                |pattern foo($bar) {
-                |    class_declaration(
-                |        name = $className,
-                |        heritage = contains "Component",
-                |        body = $body
-                |    ) => `const $className = $[bar]`
-                |}
-                |and {
-                |   $baz = "{ b = 2; }",
-                |   foo(bar = $baz)
-                |}"#
-            .trim_margin()
-            .unwrap(),
+engine marzano(0.1)
+language js
+
+`class $foo { $body }` where {
+    $fns = [],
+    $body <: contains bubble($fns) {
+        `$name($_) { $body }` as $fn where {
+                $body => `"foo"`,
+                $fns += $fn
+            }
+        },
+    $joined = join(list=$fns, separator=`\n`),
+    $joined <: contains bubble `$name($_) { $body }` where {
+        $name => `hammering_time`
+    },
+    $body => $joined
+}"#
+            .to_owned(),
             source: r#"
-                |class Button extends Component {
-                |  a = 1;
-                |}
-                |
-                |class AnotherButton extends Component {
-                |  b = 2;
-                |}"#
-            .trim_margin()
-            .unwrap(),
+class Rectangle {
+  height = 2;
+  constructor(height, width) {
+    this.height = height;
+    this.width = width;
+  }
+
+  first() {
+    return 1 + 1;
+  }
+}"#
+            .to_owned(),
+            expected: r#"
+class Rectangle {
+  hammering_time(height, width) {
+    "foo"
+  }
+hammering_time() {
+    "foo"
+  }
+}"#
+            .to_owned(),
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn debug_imports_lazy_join() {
+    run_test_expected({
+        TestArgExpected {
+            pattern: r#"
+engine marzano(0.1)
+language js
+
+pattern before_each_file() {
+    $_ where {
+        $GLOBAL_IMPORTED_SOURCES = [],
+        $GLOBAL_IMPORTED_NAMES = [],
+    }
+}
+
+pattern the_import_statement($imports, $source) {
+    import_statement(import = import_clause(name = named_imports($imports)), $source)
+}
+
+pattern imported_from($from) {
+    $name where {
+        $program <: program($statements),
+        $statements <: some bubble($name, $from) the_import_statement($imports, source = $from) where {
+            $imports <: some $name,
+        }
+    }
+}
+
+pattern ensure_import_from1($source) {
+    $name where {
+        if ($name <: not imported_from(from = $source)) {
+            if ($GLOBAL_IMPORTED_SOURCES <: not some [$program, $source]) {
+                $GLOBAL_IMPORTED_SOURCES += [$program, $source]
+            } else {
+                true
+            },
+            if ($GLOBAL_IMPORTED_NAMES <: not some [$program, $name, $source]) {
+                $GLOBAL_IMPORTED_NAMES += [$program, $name, $source]
+            } else {
+                true
+            }
+        } else {
+            true
         }
-    })
-    .unwrap();
+    }
 }
 
-#[test]
-fn pattern_append_string() {
-    run_test_match({
-        TestArg {
-            pattern: r#"
-                |pattern handlePairs($accum) {
-                |    or {
-                |       pair_pattern(key = $key, value = $value) where {
-                |           $accum += `$key as $value, `
-                |       }
-                |   }
-                |}
-                |and {
-                |   $result = "{ ",
-                |   variable_declarator(name = contains handlePairs(accum = $result)),
-                |   $result += "}",
-                |   $x => `$result`
-                |}"#
-            .trim_margin()
-            .unwrap(),
-            source:
-                r#"const { named1, alias2: named2, alias3: named3 } = require('path/to/file/1');"#
-                    .to_string(),
+pattern process_one_source($p, $all_imports) {
+    [$p, $source] where {
+        $imported_names = [],
+        $GLOBAL_IMPORTED_NAMES <: some bubble($p, $source, $imported_names, $all_imports) [$p, $name, $source] where {
+            $imported_names += $name,
+        },
+        $joined_imported_names = text(join(list = $imported_names, separator = ", ")),
+        if ($p <: program(statements = some the_import_statement($imports, $source))) {
+            $imports => `$imports, $joined_imported_names`
+        } else {
+            $all_imports += `import { $joined_imported_names } from $source;\n`
         }
-    })
-    .unwrap();
+    }
 }
 
-#[test]
-fn export_object() {
-    run_test_match({
-            TestArg {
-                pattern: r#"
-                |and {
-                |   $program,
-                |   contains $x where {
-                |       $x <: shorthand_property_identifier(),
-                |       $program <: contains lexical_declaration(declarations = [variable_declarator(name = $x, value = $val)]) => `export const $x = $val`,
-                |       $x => ``
-                |   }
-                |}"#
-                    .trim_margin()
-                    .unwrap(),
-                source: r#"
-                |const king = '9';
-                |
-                |module.exports = {
-                |  king,
-                |  queen: '8',
-                |};"#
-                .trim_margin()
-                .unwrap(),
+pattern insert_imports() {
+    $p where {
+        $all_imports = [],
+        $GLOBAL_IMPORTED_SOURCES <: some process_one_source($p, $all_imports),
+        if ($all_imports <: not []) {
+            or {
+              // Try to find a shebang and insert after that
+              $p <: program(hash_bang=$h) where {
+                $h <: hash_bang_line() += `\n$all_imports`
+              },
+              // Find an import statement to anchor on
+              $p <: program($statements) where {
+                $statements <: some $anchor where { $anchor <: import_statement() },
+                $anchor += `\n$all_imports`
+              },
+              // Fall back to inserting the whole program
+              $p => `$all_imports\n$p`
             }
-        })
-        .unwrap();
+        } else {
+            true
+        }
+    }
 }
 
-#[test]
-fn transform_test() {
-    run_test_match({
-        TestArg {
-            pattern: r#"
-                |language js
-                |
-                |`$name` where {
-                |    $name <: within `var $name = $_`
-                |}"#
-            .trim_margin()
-            .unwrap(),
-            source: r#"
-                |// This is a comment. Please ignore it.
-                |// Really we should just call variables by their number
-                |var a = 1;
-                |var b = 2;
-                |// This is a comment. Please ignore it.
-                |var c = 3;
-                |var bigLongName = 4;"#
-                .trim_margin()
-                .unwrap(),
-        }
-    })
-    .unwrap();
+pattern after_each_file() {
+  file($body) where $body <: maybe insert_imports()
 }
 
-#[test]
-fn json_snippet_simple() {
-    run_test_match({
-        TestArg {
-            pattern: r#"
-                |language json
-                |
-                |`{ "foo": $x }` => `{ "foo": "baz" }`"#
-                .trim_margin()
-                .unwrap(),
-            source: r#"
-                |{
-                |    "foo": "bar"
-                |}"#
-            .trim_margin()
-            .unwrap(),
+pattern remove_import($from) {
+    $name where {
+        // Handle named imports
+        $program <: maybe contains bubble($name, $from) `import $clause from $raw_source` as $import where {
+          $raw_source <: contains $from,
+          $clause <: or {
+            // Handle module import
+            import_clause(default=$name) where {
+                $import => .
+            },
+            // Handle named import
+            import_clause($default, name=named_imports($imports)) as $clause where {
+                $others = `false`,
+                if ($imports <: [$name]) {
+                    if ($default <: .) {
+                        $import => .
+                    } else {
+                        $clause => $default
+                    }
+                } else {
+                    $imports <: some $name => .
+                }
+            }
+          }
         }
-    })
-    .unwrap();
+    }
 }
 
-#[test]
-fn python_node_type_mismatch() {
-    run_test_match({
-        TestArg {
-            pattern: r#"
-            |engine marzano(0.1)
-            |language python
-            |
-            |`def stuff(): $body` where {
-            |    $body <: contains `$x = "9"`,
-            |    $body <: contains `print($x)`
-            |}"#
-            .trim_margin()
-            .unwrap(),
+pattern replace_import($old, $new) {
+    $name where {
+        $name <: remove_import(from=$old),
+        $name <: ensure_import_from1(source=$new)
+    }
+}
+
+`ThemeProvider` as $target where {
+  $target <: replace_import(old= `'@mui/styles'`, new=`'@mui/material/styles'`),
+}
+"#
+            .to_owned(),
             source: r#"
-            |def stuff():
-            |  foo = "9"
-            |  print(foo)"#
-                .trim_margin()
-                .unwrap(),
+import { ThemeProvider, styles } from '@mui/styles';"#
+            .to_owned(),
+            expected: r#"
+import {  styles } from '@mui/styles';
+import { ThemeProvider } from '@mui/material/styles';"#
+            .to_owned(),
         }
     })
     .unwrap();
 }
 
 #[test]
-fn test_json_swap_kv() {
+fn test_range_line_no_within() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
-                |language json
-                |`{ $x: $y }` => `{ $y: $x }`"#
-                .trim_margin()
-                .unwrap(),
-            source: r#"
-                |{
-                |    "foo": "bar"
+                |language js
+                |range(start_line=2, end_line=3) as $foo where {
+                |    $foo <: `const $x` where {
+                |        $x => `FOO = 5`
+                |    }
                 |}"#
             .trim_margin()
             .unwrap(),
+            source: r#"
+                |   const foo = 5
+                |   const foo = 5
+                |   const foo = 5
+                |   const foo = 5
+                |"#
+            .trim_margin()
+            .unwrap(),
             expected: r#"
-                |{ "bar": "foo" }"#
-                .trim_margin()
-                .unwrap(),
+            |   const foo = 5
+            |   const FOO = 5
+            |   const FOO = 5
+            |   const foo = 5
+            |"#
+            .trim_margin()
+            .unwrap(),
         }
     })
     .unwrap();
 }
 
 #[test]
-fn test_step1() {
+fn test_range_column() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
                 |language js
-                |contains `{ foo: $y }` => `{ bar: $y }`"#
-                .trim_margin()
-                .unwrap(),
+                |`const $x` where {
+                |    $x <: within range(start_line=2, start_column=10, end_line=4, end_column=17),
+                |    $x => `FOO = 5`
+                |}"#
+            .trim_margin()
+            .unwrap(),
             source: r#"
-                |let x = {
-                |    foo: "bar",
-                |};"#
-                .trim_margin()
-                .unwrap(),
-            expected: r#"
-                |let x = { bar: "bar" };"#
-                .trim_margin()
-                .unwrap(),
+                |   const foo = 5
+                |   const foo = 5
+                |   const foo = 5
+                |   const foo = 5
+                |"#
+            .trim_margin()
+            .unwrap(),
+            expected: r#"
+            |   const foo = 5
+            |   const FOO = 5
+            |   const FOO = 5
+            |   const FOO = 5
+            |"#
+            .trim_margin()
+            .unwrap(),
         }
     })
     .unwrap();
 }
+
 #[test]
-fn test_step2() {
+fn test_range_column_out_of_range() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
                 |language js
-                |contains `{ bar: $y }` => `{ baz: $y }`"#
-                .trim_margin()
-                .unwrap(),
+                |`const $x` where {
+                |    $x <: within range(start_line=2, start_column=11, end_line=4, end_column=16),
+                |    $x => `FOO = 5`
+                |}"#
+            .trim_margin()
+            .unwrap(),
             source: r#"
-                |let x = { bar: "bar" };"#
-                .trim_margin()
-                .unwrap(),
+                |   const foo = 5
+                |   const foo = 5
+                |   const foo = 5
+                |   const foo = 5
+                |"#
+            .trim_margin()
+            .unwrap(),
             expected: r#"
-                |let x = { baz: "bar" };"#
-                .trim_margin()
-                .unwrap(),
+            |   const foo = 5
+            |   const foo = 5
+            |   const FOO = 5
+            |   const foo = 5
+            |"#
+            .trim_margin()
+            .unwrap(),
         }
     })
     .unwrap();
 }
 
+// Built-ins
+
 #[test]
-fn no_export_class() {
-    run_test_no_match({
-        TestArg {
-            pattern: r#"
-                |language js
-                |pattern js_class_export() {
-                |    $class where {
-                |        $class <: and {
-                |            class_declaration(name = $name, body = $body),
-                |            not within export_statement()
-                |        }
-                |    }
-                |}
-                |
-                |js_class_export()"#
-                .trim_margin()
-                .unwrap(),
-            source: r#"export class Pattern1b {}"#.trim_margin().unwrap(),
-        }
-    })
-    .unwrap();
+fn builtin_capitalize() {
+    test_rewrite("built_ins", "capitalize", "object.js").unwrap();
 }
 
 #[test]
-fn test_any_pattern() {
+fn builtin_uppercase() {
+    test_rewrite("built_ins", "uppercase", "object.js").unwrap();
+}
+
+#[test]
+fn builtin_lowercase() {
+    test_rewrite("built_ins", "lowercase", "object.js").unwrap();
+}
+
+#[test]
+fn nested_builtins() {
+    test_rewrite("built_ins", "nested", "object.js").unwrap();
+}
+
+#[test]
+fn builtin_length() {
+    test_rewrite("built_ins", "length", "main.js").unwrap();
+}
+
+#[test]
+fn builtin_join() {
+    test_rewrite("built_ins", "join", "main.js").unwrap();
+}
+
+#[test]
+fn builtin_distinct() {
+    test_rewrite("built_ins", "distinct", "main.js").unwrap();
+}
+
+#[test]
+fn builtin_case_conversions() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
                 |language js
                 |
-                |any {
-                |    call_expression(function = "f3", arguments = [$args]) => `g($args)`,
-                |    call_expression(function = "f", arguments = [$args]) => `g($args)`,
-                |    call_expression(function = "f2", arguments = [$args]) => `g($args)`
-                |}"#
+                |or {
+                |    `snake($x)` => snake_case($x),
+                |    `camel($x)` => camel_case($x),
+                |    `pascal($x)` => pascal_case($x),
+                |    `kebab($x)` => kebab_case($x),
+                |    `constant($x)` => constant_case($x),
+                |    `words($x)` => join(list=split_words(string=$x), separator=" ")
+                |}
+                |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                |   f("hello")
-                |   f2("hello")
+                |snake(getUserID);
+                |camel(HTTPServer_config);
+                |pascal(base64_encode);
+                |kebab(parseHTML5Doc);
+                |constant(userId2Name);
+                |words(XMLHttpRequest);
                 |"#
             .trim_margin()
             .unwrap(),
             expected: r#"
-                |   g("hello")
-                |   g("hello")
+                |get_user_id;
+                |httpServerConfig;
+                |Base64Encode;
+                |parse-html5-doc;
+                |USER_ID2_NAME;
+                |XML Http Request;
                 |"#
             .trim_margin()
             .unwrap(),
@@ -5909,26 +7316,27 @@ fn test_any_pattern() {
 }
 
 #[test]
-fn test_any_predicate() {
+fn builtin_snake_case_python() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
-                |language js
+                |language python
                 |
-                |$x where any {
-                |    true,
-                |    $x => `foo`,
-                |    false,
-                |}"#
+                |`def $name($params): $body` where {
+                |    $name => snake_case($name)
+                |}
+                |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                |   f("hello")
+                |def getHTTPResponse(url):
+                |    return url
                 |"#
             .trim_margin()
             .unwrap(),
             expected: r#"
-                |   foo
+                |def get_http_response(url):
+                |    return url
                 |"#
             .trim_margin()
             .unwrap(),
@@ -5938,26 +7346,25 @@ fn test_any_predicate() {
 }
 
 #[test]
-fn test_after_pattern() {
+fn builtin_constant_case_rust() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
-                |language js
+                |language rust
                 |
-                |call_expression(function = $f, arguments = [$args]) as $call => `g($args)` where {
-                |   $call <: after contains call_expression()
-                |}"#
+                |`const $name: $type = $value;` where {
+                |    $name => constant_case($name)
+                |}
+                |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                |   f("hello")
-                |   f2("hello")
+                |const maxRetries: u32 = 3;
                 |"#
             .trim_margin()
             .unwrap(),
             expected: r#"
-                |   f("hello")
-                |   g("hello")
+                |const MAX_RETRIES: u32 = 3;
                 |"#
             .trim_margin()
             .unwrap(),
@@ -5967,46 +7374,26 @@ fn test_after_pattern() {
 }
 
 #[test]
-fn test_before_pattern() {
+fn builtin_sort_by_field() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
                 |language js
                 |
-                |call_expression(function = $f, arguments = [$args]) as $call => `g($args)` where {
-                |   $call <: before contains call_expression()
-                |}"#
+                |`const $args` where {
+                |    $args => join(list=sort(list=$args, by="name"), separator=", ")
+                |}
+                |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                |f("hi")
-                |f2("hi")
-                |// this will not be touched
-                |function() {
-                |  f3("bob");
-                |}
-                |// this one will
-                |function() {
-                |  f4("cool");
-                |  f5("school");
-                |}
+                |const c = 3, a = 1, b = 2;
                 |"#
             .trim_margin()
             .unwrap(),
             expected: r#"
-            |g("hi")
-            |f2("hi")
-            |// this will not be touched
-            |function() {
-            |  f3("bob");
-            |}
-            |// this one will
-            |function() {
-            |  g("cool");
-            |  f5("school");
-            |}
-            |
-            |"#
+                |const a = 1, b = 2, c = 3;
+                |"#
             .trim_margin()
             .unwrap(),
         }
@@ -6015,101 +7402,81 @@ fn test_before_pattern() {
 }
 
 #[test]
-fn test_after_pattern_rhs() {
+fn builtin_sort_numbers() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
-                |engine marzano(0.1)
                 |language js
                 |
-                |`const foo = 9;` as $foo where {
-                |    $previous = after $foo,
-                |    $foo => $previous
-                |}"#
+                |`foo($args)` where {
+                |    $args => join(list=sort(list=$args), separator=", ")
+                |}
+                |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                |var increment = function (i) {
-                |    const foo = 9;
-                |    const x = 10;
-                |};"#
-                .trim_margin()
-                .unwrap(),
+                |foo(10, 9, 100, 1);
+                |"#
+            .trim_margin()
+            .unwrap(),
             expected: r#"
-            |var increment = function (i) {
-            |    const x = 10;
-            |    const x = 10;
-            |};"#
-                .trim_margin()
-                .unwrap(),
+                |foo(1, 9, 10, 100);
+                |"#
+            .trim_margin()
+            .unwrap(),
         }
     })
     .unwrap();
 }
 
 #[test]
-fn test_before_pattern_rhs() {
+fn builtin_sort_mixed_keys() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
-                |engine marzano(0.1)
                 |language js
                 |
-                |`const foo = 9;` as $foo where {
-                |    $previous = before $foo,
-                |    $foo => $previous
-                |}"#
+                |`foo($args)` where {
+                |    $args => join(list=sort(list=$args), separator=", ")
+                |}
+                |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                |var increment = function (i) {
-                |    const x = 10;
-                |    const foo = 9;
-                |};"#
-                .trim_margin()
-                .unwrap(),
+                |foo(b, 10, "a", 9, a, 1.5);
+                |"#
+            .trim_margin()
+            .unwrap(),
             expected: r#"
-            |var increment = function (i) {
-            |    const x = 10;
-            |    const x = 10;
-            |};"#
-                .trim_margin()
-                .unwrap(),
+                |foo(1.5, 9, 10, "a", a, b);
+                |"#
+            .trim_margin()
+            .unwrap(),
         }
     })
     .unwrap();
 }
 
 #[test]
-fn test_global_variables() {
+fn builtin_reverse_slice() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
                 |language js
                 |
-                |pattern the_foo() {
-                |   `foo($GLOBAL_VAR)`
-                |}
-                |pattern the_bar() {
-                |   `bar` => `baz($GLOBAL_VAR)`
+                |`foo($args)` where {
+                |    $args => join(list=reverse(list=slice(list=$args, start=1)), separator=", ")
                 |}
-                |
-                |program(statements = and {
-                |  contains the_foo(),
-                |  contains the_bar()
-                |})
                 |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                |   foo(1)
-                |   bar
+                |foo(a, b, c, d);
                 |"#
             .trim_margin()
             .unwrap(),
             expected: r#"
-                |   foo(1)
-                |   baz(1)
+                |foo(d, c, b);
                 |"#
             .trim_margin()
             .unwrap(),
@@ -6119,27 +7486,25 @@ fn test_global_variables() {
 }
 
 #[test]
-fn test_pattern_call() {
+fn builtin_slice_negative() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
                 |language js
                 |
-                |pattern foo() {
-                |    call_expression(function="f", arguments=[$args]) => `g($args)`
+                |`foo($args)` where {
+                |    $args => join(list=slice(list=$args, start=0, end=-1), separator=", ")
                 |}
-                |contains foo()"#
-                .trim_margin()
-                .unwrap(),
+                |"#
+            .trim_margin()
+            .unwrap(),
             source: r#"
-                |   f("hello")
-                |   f("hello2")
+                |foo(a, b, c, d);
                 |"#
             .trim_margin()
             .unwrap(),
             expected: r#"
-                |   g("hello")
-                |   g("hello2")
+                |foo(a, b, c);
                 |"#
             .trim_margin()
             .unwrap(),
@@ -6149,24 +7514,25 @@ fn test_pattern_call() {
 }
 
 #[test]
-fn test_bubble() {
+fn builtin_unique_by_field() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
                 |language js
                 |
-                |call_expression(function="f", arguments=[$args]) => `g($args)`"#
-                .trim_margin()
-                .unwrap(),
+                |`const $args` where {
+                |    $args => join(list=unique_by(list=$args, by="value"), separator=", ")
+                |}
+                |"#
+            .trim_margin()
+            .unwrap(),
             source: r#"
-                |   f("hello")
-                |   f("hello2")
+                |const a = 1, b = 2, c = 1;
                 |"#
             .trim_margin()
             .unwrap(),
             expected: r#"
-                |   g("hello")
-                |   g("hello2")
+                |const a = 1, b = 2;
                 |"#
             .trim_margin()
             .unwrap(),
@@ -6176,31 +7542,27 @@ fn test_bubble() {
 }
 
 #[test]
-fn test_range_line() {
+fn builtin_zip_flatten() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
                 |language js
-                |`const $x` where {
-                |    $x <: within range(start_line=2, end_line=3),
-                |    $x => `FOO = 5`
-                |}"#
+                |
+                |`foo($args)` where {
+                |    $names = [`x`, `y`],
+                |    $args => join(list=flatten(list=zip(list=$names, other=$args)), separator=", ")
+                |}
+                |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                |   const foo = 5
-                |   const foo = 5
-                |   const foo = 5
-                |   const foo = 5
+                |foo(1, 2, 3);
                 |"#
             .trim_margin()
             .unwrap(),
             expected: r#"
-            |   const foo = 5
-            |   const FOO = 5
-            |   const FOO = 5
-            |   const foo = 5
-            |"#
+                |foo(x, 1, y, 2);
+                |"#
             .trim_margin()
             .unwrap(),
         }
@@ -6209,273 +7571,129 @@ fn test_range_line() {
 }
 
 #[test]
-fn test_lazy_join() {
+fn builtin_index_of_contains_item() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
-engine marzano(0.1)
-language js
-
-`class $foo { $body }` where {
-    $fns = [],
-    $body <: contains bubble($fns) {
-        `$name($_) { $body }` as $fn where {
-                $body => `"foo"`,
-                $fns += $fn
-            }
-        },
-    $joined = join(list=$fns, separator=`\n`),
-    $body => $joined
-}"#
-            .to_owned(),
+                |language js
+                |
+                |`foo($args)` where {
+                |    contains_item(list=$args, item="b"),
+                |    $index = index_of(list=$args, item="c"),
+                |    $args => $index
+                |}
+                |"#
+            .trim_margin()
+            .unwrap(),
             source: r#"
-class Rectangle {
-  height = 2;
-  constructor(height, width) {
-    this.height = height;
-    this.width = width;
-  }
-
-  first() {
-    return 1 + 1;
-  }
-}"#
-            .to_owned(),
+                |foo(a, b, c);
+                |foo(a, c);
+                |"#
+            .trim_margin()
+            .unwrap(),
             expected: r#"
-class Rectangle {
-  constructor(height, width) {
-    "foo"
-  }
-first() {
-    "foo"
-  }
-}"#
-            .to_owned(),
+                |foo(2);
+                |foo(a, c);
+                |"#
+            .trim_margin()
+            .unwrap(),
         }
     })
     .unwrap();
 }
 
 #[test]
-fn contains_lazy_join() {
+fn builtin_map_keys_values() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
-engine marzano(0.1)
-language js
-
-`class $foo { $body }` where {
-    $fns = [],
-    $body <: contains bubble($fns) {
-        `$name($_) { $body }` as $fn where {
-                $body => `"foo"`,
-                $fns += $fn
-            }
-        },
-    $joined = join(list=$fns, separator=`\n`),
-    $joined <: contains bubble `$name($_) { $body }` where {
-        $name => `hammering_time`
-    },
-    $body => $joined
-}"#
-            .to_owned(),
+                |language js
+                |
+                |`const $x = $foo` where {
+                |    $capitals = { england: "london", france: "paris" },
+                |    $x => join(list=values(map=$capitals), separator="_"),
+                |    $foo => join(list=keys(map=$capitals), separator=", ")
+                |}
+                |"#
+            .trim_margin()
+            .unwrap(),
             source: r#"
-class Rectangle {
-  height = 2;
-  constructor(height, width) {
-    this.height = height;
-    this.width = width;
-  }
-
-  first() {
-    return 1 + 1;
-  }
-}"#
-            .to_owned(),
+                |const x = hello;
+                |"#
+            .trim_margin()
+            .unwrap(),
             expected: r#"
-class Rectangle {
-  hammering_time(height, width) {
-    "foo"
-  }
-hammering_time() {
-    "foo"
-  }
-}"#
-            .to_owned(),
+                |const london_paris = england, france;
+                |"#
+            .trim_margin()
+            .unwrap(),
         }
     })
     .unwrap();
 }
 
 #[test]
-fn debug_imports_lazy_join() {
+fn builtin_map_merge_has_key() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
-engine marzano(0.1)
-language js
-
-pattern before_each_file() {
-    $_ where {
-        $GLOBAL_IMPORTED_SOURCES = [],
-        $GLOBAL_IMPORTED_NAMES = [],
-    }
-}
-
-pattern the_import_statement($imports, $source) {
-    import_statement(import = import_clause(name = named_imports($imports)), $source)
-}
-
-pattern imported_from($from) {
-    $name where {
-        $program <: program($statements),
-        $statements <: some bubble($name, $from) the_import_statement($imports, source = $from) where {
-            $imports <: some $name,
-        }
-    }
-}
-
-pattern ensure_import_from1($source) {
-    $name where {
-        if ($name <: not imported_from(from = $source)) {
-            if ($GLOBAL_IMPORTED_SOURCES <: not some [$program, $source]) {
-                $GLOBAL_IMPORTED_SOURCES += [$program, $source]
-            } else {
-                true
-            },
-            if ($GLOBAL_IMPORTED_NAMES <: not some [$program, $name, $source]) {
-                $GLOBAL_IMPORTED_NAMES += [$program, $name, $source]
-            } else {
-                true
-            }
-        } else {
-            true
-        }
-    }
-}
-
-pattern process_one_source($p, $all_imports) {
-    [$p, $source] where {
-        $imported_names = [],
-        $GLOBAL_IMPORTED_NAMES <: some bubble($p, $source, $imported_names, $all_imports) [$p, $name, $source] where {
-            $imported_names += $name,
-        },
-        $joined_imported_names = text(join(list = $imported_names, separator = ", ")),
-        if ($p <: program(statements = some the_import_statement($imports, $source))) {
-            $imports => `$imports, $joined_imported_names`
-        } else {
-            $all_imports += `import { $joined_imported_names } from $source;\n`
-        }
-    }
-}
-
-pattern insert_imports() {
-    $p where {
-        $all_imports = [],
-        $GLOBAL_IMPORTED_SOURCES <: some process_one_source($p, $all_imports),
-        if ($all_imports <: not []) {
-            or {
-              // Try to find a shebang and insert after that
-              $p <: program(hash_bang=$h) where {
-                $h <: hash_bang_line() += `\n$all_imports`
-              },
-              // Find an import statement to anchor on
-              $p <: program($statements) where {
-                $statements <: some $anchor where { $anchor <: import_statement() },
-                $anchor += `\n$all_imports`
-              },
-              // Fall back to inserting the whole program
-              $p => `$all_imports\n$p`
-            }
-        } else {
-            true
-        }
-    }
-}
-
-pattern after_each_file() {
-  file($body) where $body <: maybe insert_imports()
-}
-
-pattern remove_import($from) {
-    $name where {
-        // Handle named imports
-        $program <: maybe contains bubble($name, $from) `import $clause from $raw_source` as $import where {
-          $raw_source <: contains $from,
-          $clause <: or {
-            // Handle module import
-            import_clause(default=$name) where {
-                $import => .
-            },
-            // Handle named import
-            import_clause($default, name=named_imports($imports)) as $clause where {
-                $others = `false`,
-                if ($imports <: [$name]) {
-                    if ($default <: .) {
-                        $import => .
-                    } else {
-                        $clause => $default
-                    }
-                } else {
-                    $imports <: some $name => .
-                }
-            }
-          }
-        }
-    }
-}
-
-pattern replace_import($old, $new) {
-    $name where {
-        $name <: remove_import(from=$old),
-        $name <: ensure_import_from1(source=$new)
-    }
-}
-
-`ThemeProvider` as $target where {
-  $target <: replace_import(old= `'@mui/styles'`, new=`'@mui/material/styles'`),
-}
-"#
-            .to_owned(),
+                |language js
+                |
+                |`const $x = $foo` where {
+                |    $defaults = { retries: "3", timeout: "10" },
+                |    $config = merge(map=$defaults, other={ timeout: "30" }),
+                |    has_key(map=$config, key="retries"),
+                |    not has_key(map=$config, key="verbose"),
+                |    $config <: some ["timeout", $value],
+                |    $foo => $value
+                |}
+                |"#
+            .trim_margin()
+            .unwrap(),
             source: r#"
-import { ThemeProvider, styles } from '@mui/styles';"#
-            .to_owned(),
+                |const x = hello;
+                |"#
+            .trim_margin()
+            .unwrap(),
             expected: r#"
-import {  styles } from '@mui/styles';
-import { ThemeProvider } from '@mui/material/styles';"#
-            .to_owned(),
+                |const x = 30;
+                |"#
+            .trim_margin()
+            .unwrap(),
         }
     })
     .unwrap();
 }
 
 #[test]
-fn test_range_line_no_within() {
+fn builtin_map_entries() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
                 |language js
-                |range(start_line=2, end_line=3) as $foo where {
-                |    $foo <: `const $x` where {
-                |        $x => `FOO = 5`
-                |    }
-                |}"#
+                |
+                |`const $x = $foo` where {
+                |    $flags = { beta: "on", legacy: "off" },
+                |    $enabled = [],
+                |    $entries = entries(map=$flags),
+                |    $entries <: every [$name, $state] where {
+                |        if ($state <: "on") {
+                |            $enabled += $name
+                |        }
+                |    },
+                |    $foo => join(list=$enabled, separator=", ")
+                |}
+                |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                |   const foo = 5
-                |   const foo = 5
-                |   const foo = 5
-                |   const foo = 5
+                |const x = hello;
                 |"#
             .trim_margin()
             .unwrap(),
             expected: r#"
-            |   const foo = 5
-            |   const FOO = 5
-            |   const FOO = 5
-            |   const foo = 5
-            |"#
+                |const x = beta;
+                |"#
             .trim_margin()
             .unwrap(),
         }
@@ -6484,31 +7702,35 @@ fn test_range_line_no_within() {
 }
 
 #[test]
-fn test_range_column() {
+fn builtin_path_helpers() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
                 |language js
-                |`const $x` where {
-                |    $x <: within range(start_line=2, start_column=10, end_line=4, end_column=17),
-                |    $x => `FOO = 5`
-                |}"#
+                |
+                |`const $x = $y` where {
+                |    $path = "src/components/../ui/Button.tsx",
+                |    $dir = dirname(path=$path),
+                |    $name = basename(path=$path, ext=".tsx"),
+                |    $ext = extname(path=$path),
+                |    $normalized = normalize_path(path=$path),
+                |    $joined = path_join(base="src", path="./lib/../utils"),
+                |    $relative = relative_path(from="src/pages/home.tsx", to=$normalized),
+                |    $local = relative_path(from=$filename, to="lib/util.ts"),
+                |    $parts = [$dir, $name, $ext, $normalized, $joined, $relative, $local],
+                |    $y => join(list=$parts, separator=" ")
+                |}
+                |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                |   const foo = 5
-                |   const foo = 5
-                |   const foo = 5
-                |   const foo = 5
+                |const x = 1;
                 |"#
             .trim_margin()
             .unwrap(),
             expected: r#"
-            |   const foo = 5
-            |   const FOO = 5
-            |   const FOO = 5
-            |   const FOO = 5
-            |"#
+                |const x = src/components/../ui Button .tsx src/ui/Button.tsx src/utils ../ui/Button.tsx ./lib/util.ts;
+                |"#
             .trim_margin()
             .unwrap(),
         }
@@ -6517,31 +7739,56 @@ fn test_range_column() {
 }
 
 #[test]
-fn test_range_column_out_of_range() {
+fn builtin_project_dependency_version() {
+    let dir = tempfile::tempdir().unwrap();
+    fs_err::create_dir_all(dir.path().join(".git")).unwrap();
+    fs_err::create_dir_all(dir.path().join("src")).unwrap();
+    fs_err::write(
+        dir.path().join("package.json"),
+        r#"{ "dependencies": { "react": "^18.2.0" } }"#,
+    )
+    .unwrap();
+    let file = dir.path().join("src/index.tsx");
+
+    let pattern = r#"
+        |language js
+        |
+        |`const $x = $y` where {
+        |    $version = project_dependency_version(name="react"),
+        |    $missing = project_dependency_version(name="left-pad"),
+        |    $missing <: undefined,
+        |    $y => `"$version"`
+        |}
+        |"#
+    .trim_margin()
+    .unwrap();
+    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
+    let result =
+        match_pattern_one_file(pattern, file.to_str().unwrap(), "const x = 1;", js_lang).unwrap();
+    validate_execution_result(result, r#"const x = "^18.2.0";"#.to_owned()).unwrap();
+}
+
+#[test]
+fn builtin_regex_replace() {
     run_test_expected({
         TestArgExpected {
             pattern: r#"
                 |language js
-                |`const $x` where {
-                |    $x <: within range(start_line=2, start_column=11, end_line=4, end_column=16),
-                |    $x => `FOO = 5`
-                |}"#
+                |
+                |`const $x = $y` where {
+                |    $y => regex_replace(string=$y, regex="(\d+)-(\d+)-(?P<day>\d+)", replacement="${day}/$2/$1")
+                |}
+                |"#
             .trim_margin()
             .unwrap(),
             source: r#"
-                |   const foo = 5
-                |   const foo = 5
-                |   const foo = 5
-                |   const foo = 5
+                |const releaseDate = "2024-01-15";
                 |"#
             .trim_margin()
             .unwrap(),
             expected: r#"
-            |   const foo = 5
-            |   const foo = 5
-            |   const FOO = 5
-            |   const foo = 5
-            |"#
+                |const releaseDate = "15/01/2024";
+                |"#
             .trim_margin()
             .unwrap(),
         }
@@ -6549,41 +7796,131 @@ fn test_range_column_out_of_range() {
     .unwrap();
 }
 
-// Built-ins
-
-#[test]
-fn builtin_capitalize() {
-    test_rewrite("built_ins", "capitalize", "object.js").unwrap();
-}
-
-#[test]
-fn builtin_uppercase() {
-    test_rewrite("built_ins", "uppercase", "object.js").unwrap();
-}
-
-#[test]
-fn builtin_lowercase() {
-    test_rewrite("built_ins", "lowercase", "object.js").unwrap();
-}
-
 #[test]
-fn nested_builtins() {
-    test_rewrite("built_ins", "nested", "object.js").unwrap();
+fn builtin_regex_find_all_split() {
+    run_test_expected({
+        TestArgExpected {
+            pattern: r#"
+                |language js
+                |
+                |`const $x = $y` where {
+                |    $found = regex_find_all(string=$y, regex="[a-z]"),
+                |    $x => join(list=$found, separator="_"),
+                |    $parts = regex_split(string=$y, regex="[,;] ?"),
+                |    $y => join(list=$parts, separator="|")
+                |}
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |const tags = "a, b;c";
+                |"#
+            .trim_margin()
+            .unwrap(),
+            expected: r#"
+                |const a_b_c = "a|b|c";
+                |"#
+            .trim_margin()
+            .unwrap(),
+        }
+    })
+    .unwrap();
 }
 
 #[test]
-fn builtin_length() {
-    test_rewrite("built_ins", "length", "main.js").unwrap();
+fn builtin_parse_json() {
+    run_test_expected({
+        TestArgExpected {
+            pattern: r#"
+                |language js
+                |
+                |`const $x = $y` where {
+                |    $json = trim(string=$y, trim_chars="'"),
+                |    $config = parse_json(string=$json),
+                |    $x => $config.tags[0],
+                |    $y => $config.limit
+                |}
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |const flags = '{"beta": true, "limit": 5, "tags": ["a", "b"]}';
+                |"#
+            .trim_margin()
+            .unwrap(),
+            expected: r#"
+                |const a = 5;
+                |"#
+            .trim_margin()
+            .unwrap(),
+        }
+    })
+    .unwrap();
 }
 
 #[test]
-fn builtin_join() {
-    test_rewrite("built_ins", "join", "main.js").unwrap();
+fn builtin_parse_yaml_to_json() {
+    run_test_expected({
+        TestArgExpected {
+            pattern: r#"
+                |language js
+                |
+                |`const $x = $y` where {
+                |    $yaml = trim(string=$y, trim_chars="`"),
+                |    $config = parse_yaml(string=$yaml),
+                |    $y => to_json(value=$config)
+                |}
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |const config = `retries: 3
+                |hosts:
+                |  - a
+                |  - b`;
+                |"#
+            .trim_margin()
+            .unwrap(),
+            expected: r#"
+                |const config = {"hosts":["a","b"],"retries":3};
+                |"#
+            .trim_margin()
+            .unwrap(),
+        }
+    })
+    .unwrap();
 }
 
 #[test]
-fn builtin_distinct() {
-    test_rewrite("built_ins", "distinct", "main.js").unwrap();
+fn builtin_parse_yaml_non_string_keys() {
+    run_test_expected({
+        TestArgExpected {
+            pattern: r#"
+                |language js
+                |
+                |`const $x = $y` where {
+                |    $yaml = trim(string=$y, trim_chars="`"),
+                |    $config = parse_yaml(string=$yaml),
+                |    $y => to_json(value=$config)
+                |}
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |const codes = `200: ok
+                |404: missing
+                |true: yes`;
+                |"#
+            .trim_margin()
+            .unwrap(),
+            expected: r#"
+                |const codes = {"200":"ok","404":"missing","true":"yes"};
+                |"#
+            .trim_margin()
+            .unwrap(),
+        }
+    })
+    .unwrap();
 }
 
 // Other rewrites
@@ -7707,6 +9044,43 @@ fn test_random() {
     .unwrap();
 }
 
+#[test]
+fn seeded_shuffle_is_reproducible() {
+    let pattern = r#"
+            language js
+
+            `console.log($x)` where {
+                $list = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+                $x => join(list=shuffle($list), separator=",")
+            }"#
+    .to_owned();
+    let js_lang: TargetLanguage = PatternLanguage::Tsx.try_into().unwrap();
+    let pattern = src_to_problem(pattern, js_lang).unwrap();
+    let shuffle_with_seed = |file: &str, seed: u64| {
+        let mut context = ExecutionContext::default();
+        context.seed = Some(seed);
+        let results = pattern.execute_file(
+            &RichFile::new(file.to_owned(), "console.log(0)".to_owned()),
+            &context,
+        );
+        results
+            .iter()
+            .find_map(|r| {
+                if let MatchResult::Rewrite(r) = r {
+                    r.rewritten.content.clone()
+                } else {
+                    None
+                }
+            })
+            .unwrap()
+    };
+    let first = shuffle_with_seed("a.tsx", 7);
+    assert_eq!(first, shuffle_with_seed("a.tsx", 7));
+    // every file starts from the seed, regardless of which thread runs it
+    assert_eq!(first, shuffle_with_seed("b.tsx", 7));
+    assert_ne!(first, shuffle_with_seed("a.tsx", 8));
+}
+
 #[test]
 fn test_list_bind_to_empty_list() {
     run_test_expected({
@@ -11436,6 +12810,76 @@ fn css_respects_suppression() {
     .unwrap();
 }
 
+#[test]
+fn lua_respects_suppression() {
+    run_test_no_match({
+        TestArg {
+            pattern: r#"
+                |language lua
+                |
+                |`print($msg)`
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |-- grit-ignore
+                |print("debugging")
+                |"#
+            .trim_margin()
+            .unwrap(),
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn nix_respects_suppression() {
+    run_test_no_match({
+        TestArg {
+            pattern: r#"
+                |language nix
+                |
+                |`builtins.trace $msg $value`
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |{
+                |  # grit-ignore
+                |  value = builtins.trace "debugging" 42;
+                |}
+                |"#
+            .trim_margin()
+            .unwrap(),
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn scss_respects_suppression() {
+    run_test_no_match({
+        TestArg {
+            pattern: r#"
+                |language scss
+                |
+                |`@debug ^value;`
+                |"#
+            .trim_margin()
+            .unwrap(),
+            source: r#"
+                |.a {
+                |  // grit-ignore
+                |  @debug $primary;
+                |}
+                |"#
+            .trim_margin()
+            .unwrap(),
+        }
+    })
+    .unwrap();
+}
+
 #[test]
 fn java_respects_suppression() {
     run_test_no_match({
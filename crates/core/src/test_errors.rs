@@ -1,6 +1,6 @@
 use marzano_language::target_language::TargetLanguage;
 
-use self::pattern_compiler::src_to_problem_libs;
+use self::pattern_compiler::{src_to_problem_libs, src_to_problem_libs_with_variables};
 
 use super::*;
 use std::collections::BTreeMap;
@@ -28,3 +28,99 @@ fn test_error_invalid_field() {
         "invalid field `fragment` for AST node `string_fragment`. `string_fragment` does not expose any fields."
     );
 }
+
+#[test]
+fn test_error_invalid_regex() {
+    let pattern_src = r#"
+        `foo($x)` where { $x <: r"(unclosed" }
+        "#;
+    let libs = BTreeMap::new();
+
+    let err = src_to_problem_libs(
+        pattern_src.to_string(),
+        &libs,
+        TargetLanguage::default(),
+        None,
+        None,
+        None,
+        None,
+    )
+    .err()
+    .unwrap();
+    assert!(err.to_string().contains("invalid regex (unclosed"));
+}
+
+#[test]
+fn test_error_missing_injected_parameter() {
+    let pattern_src = r#"
+        pattern rename_call($from, $to) {
+            `$fn($args)` where { $fn <: $from, $fn => $to }
+        }
+        rename_call()
+        "#;
+    let libs = BTreeMap::new();
+    let variables = BTreeMap::from([("from".to_string(), serde_json::json!("foo"))]);
+
+    let err = src_to_problem_libs_with_variables(
+        pattern_src.to_string(),
+        &libs,
+        TargetLanguage::default(),
+        None,
+        None,
+        None,
+        None,
+        &variables,
+    )
+    .err()
+    .unwrap();
+    assert_eq!(
+        err.to_string(),
+        "missing value for parameter(s) to of pattern rename_call"
+    );
+}
+
+#[test]
+fn test_unbound_parameters_without_injected_values() {
+    let pattern_src = r#"
+        pattern rename_call($from, $to) {
+            `$fn($args)` where { $fn <: $from, $fn => $to }
+        }
+        rename_call()
+        "#;
+    let variables = BTreeMap::from([("limit".to_string(), serde_json::json!(5))]);
+
+    for variables in [BTreeMap::new(), variables] {
+        let problem = src_to_problem_libs_with_variables(
+            pattern_src.to_string(),
+            &BTreeMap::new(),
+            TargetLanguage::default(),
+            Some("rename_call".to_string()),
+            None,
+            None,
+            None,
+            &variables,
+        );
+        assert!(problem.is_ok());
+    }
+}
+
+#[test]
+fn test_error_invalid_injected_variable() {
+    let variables = BTreeMap::from([("not-a-name".to_string(), serde_json::json!(1))]);
+
+    let err = src_to_problem_libs_with_variables(
+        "`foo`".to_string(),
+        &BTreeMap::new(),
+        TargetLanguage::default(),
+        None,
+        None,
+        None,
+        None,
+        &variables,
+    )
+    .err()
+    .unwrap();
+    assert!(err
+        .to_string()
+        .starts_with("invalid variable name not-a-name"));
+}
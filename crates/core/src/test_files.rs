@@ -429,3 +429,204 @@ fn avoid_unsafe_hoists() {
     assert_eq!(results.len(), 4);
     assert!(results.iter().any(|r| r.is_match()));
 }
+
+#[test]
+fn routes_files_to_language_variants() {
+    let pattern_src = r#"
+        or {
+            python"print($x)" => python"log($x)",
+            js"console.log($x)" => js"logger.info($x)"
+        }
+        "#;
+    let libs = BTreeMap::new();
+
+    let pattern = src_to_problem_libs(
+        pattern_src.to_string(),
+        &libs,
+        TargetLanguage::default(),
+        Some("rename_logging".to_string()),
+        None,
+        None,
+        None,
+    )
+    .unwrap()
+    .problem;
+    let languages: Vec<_> = pattern.languages().map(|l| l.to_string()).collect();
+    assert_eq!(languages, vec!["python", "js"]);
+    assert!(pattern.match_extension("py"));
+    assert!(pattern.match_extension("js"));
+
+    let test_files = vec![
+        SyntheticFile::new("app.py".to_owned(), "print(\"hi\")\n".to_owned(), true),
+        SyntheticFile::new(
+            "app.js".to_owned(),
+            "console.log(\"hi\");\n".to_owned(),
+            true,
+        ),
+    ];
+    let results = run_on_test_files(&pattern, &test_files);
+    assert!(!results.iter().any(|r| r.is_error()));
+
+    let rewrites: BTreeMap<_, _> = results
+        .into_iter()
+        .filter_map(|r| {
+            if let MatchResult::Rewrite(r) = r {
+                Some((r.rewritten.source_file, r.rewritten.content?))
+            } else {
+                None
+            }
+        })
+        .collect();
+    assert_eq!(rewrites["app.py"], "log(\"hi\")\n");
+    assert_eq!(rewrites["app.js"], "logger.info(\"hi\");\n");
+}
+
+#[test]
+fn compiles_language_variants_with_custom_built_ins() {
+    let pattern_src = r#"
+        or {
+            python"print($x)" => python"log($x)",
+            js"console.log($x)" => js"logger.info($x)"
+        }
+        "#;
+    let libs = BTreeMap::new();
+
+    let pattern = src_to_problem_libs(
+        pattern_src.to_string(),
+        &libs,
+        TargetLanguage::default(),
+        None,
+        None,
+        crate::built_in_functions::get_ai_placeholder_functions(),
+        None,
+    )
+    .unwrap()
+    .problem;
+    assert_eq!(pattern.languages().count(), 2);
+}
+
+#[test]
+fn routes_files_to_multifile_language_variants() {
+    let pattern_src = r#"
+        or {
+            multifile { contains python"print($x)" => python"log($x)" },
+            multifile { contains js"console.log($x)" => js"logger.info($x)" }
+        }
+        "#;
+    let libs = BTreeMap::new();
+
+    let pattern = src_to_problem_libs(
+        pattern_src.to_string(),
+        &libs,
+        TargetLanguage::default(),
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap()
+    .problem;
+    assert!(pattern.is_multifile);
+    let languages: Vec<_> = pattern.languages().map(|l| l.to_string()).collect();
+    assert_eq!(languages, vec!["python", "js"]);
+
+    let test_files = vec![
+        SyntheticFile::new("app.py".to_owned(), "print(\"hi\")\n".to_owned(), true),
+        SyntheticFile::new(
+            "app.js".to_owned(),
+            "console.log(\"hi\");\n".to_owned(),
+            true,
+        ),
+    ];
+    let results = run_on_test_files(&pattern, &test_files);
+    assert!(!results.iter().any(|r| r.is_error()));
+
+    let rewrites: BTreeMap<_, _> = results
+        .into_iter()
+        .filter_map(|r| {
+            if let MatchResult::Rewrite(r) = r {
+                Some((r.rewritten.source_file, r.rewritten.content?))
+            } else {
+                None
+            }
+        })
+        .collect();
+    assert_eq!(rewrites["app.py"], "log(\"hi\")\n");
+    assert_eq!(rewrites["app.js"], "logger.info(\"hi\");\n");
+}
+
+#[test]
+fn rewrites_svelte_and_astro_scripts() {
+    let pattern_src = r#"
+        `console.log($x)` => `logger.info($x)`
+        "#;
+    let libs = BTreeMap::new();
+
+    let pattern = src_to_problem_libs(
+        pattern_src.to_string(),
+        &libs,
+        TargetLanguage::default(),
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap()
+    .problem;
+
+    let svelte = r#"<script>
+  let name = 'world';
+  console.log(name);
+</script>
+
+<h1>console.log(name)</h1>
+"#;
+    let astro = r#"---
+const name = "world";
+console.log(name);
+---
+<h1>console.log(name)</h1>
+<script>
+  console.log("loaded");
+</script>
+"#;
+    let test_files = vec![
+        SyntheticFile::new("App.svelte".to_owned(), svelte.to_owned(), true),
+        SyntheticFile::new("index.astro".to_owned(), astro.to_owned(), true),
+    ];
+    let results = run_on_test_files(&pattern, &test_files);
+    assert!(!results.iter().any(|r| r.is_error()));
+
+    let rewrites: BTreeMap<_, _> = results
+        .into_iter()
+        .filter_map(|r| {
+            if let MatchResult::Rewrite(r) = r {
+                Some((r.rewritten.source_file, r.rewritten.content?))
+            } else {
+                None
+            }
+        })
+        .collect();
+    assert_eq!(
+        rewrites["App.svelte"],
+        r#"<script>
+  let name = 'world';
+  logger.info(name);
+</script>
+
+<h1>console.log(name)</h1>
+"#
+    );
+    assert_eq!(
+        rewrites["index.astro"],
+        r#"---
+const name = "world";
+logger.info(name);
+---
+<h1>console.log(name)</h1>
+<script>
+  logger.info("loaded");
+</script>
+"#
+    );
+}
@@ -0,0 +1,162 @@
+#[cfg(test)]
+mod tests {
+    use marzano_language::target_language::TargetLanguage;
+
+    use crate::{
+        api::MatchResult,
+        pattern_compiler::src_to_problem_libs,
+        test_utils::{run_on_test_files, SyntheticFile},
+    };
+
+    use std::collections::BTreeMap;
+
+    fn rewrite_file(pattern_src: &str, file_name: &str, source: &str) -> Option<String> {
+        let libs = BTreeMap::new();
+        let pattern = src_to_problem_libs(
+            pattern_src.to_string(),
+            &libs,
+            TargetLanguage::default(),
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap()
+        .problem;
+
+        let test_files = vec![SyntheticFile::new(
+            file_name.to_owned(),
+            source.to_owned(),
+            true,
+        )];
+        let results = run_on_test_files(&pattern, &test_files);
+        assert!(!results.iter().any(|r| r.is_error()));
+
+        results.into_iter().find_map(|r| {
+            if let MatchResult::Rewrite(rewrite) = r {
+                rewrite.rewritten.content
+            } else {
+                None
+            }
+        })
+    }
+
+    #[test]
+    fn test_sql_in_python() {
+        let pattern_src = r#"
+        language sql
+
+        `public.$table` => `private.$table`
+        "#;
+
+        let source = r#"import pandas as pd
+
+def active_users(cursor):
+    cursor.execute("SELECT name FROM public.users WHERE active")
+    return pd.read_sql("SELECT id FROM public.orders", cursor.connection)
+
+print("public.users")
+"#;
+
+        let rewritten = rewrite_file(pattern_src, "queries.py", source).unwrap();
+        assert_eq!(
+            rewritten,
+            r#"import pandas as pd
+
+def active_users(cursor):
+    cursor.execute("SELECT name FROM private.users WHERE active")
+    return pd.read_sql("SELECT id FROM private.orders", cursor.connection)
+
+print("public.users")
+"#
+        );
+    }
+
+    #[test]
+    fn test_css_in_styled_components() {
+        let pattern_src = r#"
+        language css
+
+        `color: $c` => `background-color: $c;`
+        "#;
+
+        let source = r#"const Button = styled.button`
+  color: red;
+  padding: 4px;
+`;
+
+const Title = styled(Button)`
+  color: blue;
+`;
+
+const message = "color: green;";
+"#;
+
+        let rewritten = rewrite_file(pattern_src, "button.tsx", source).unwrap();
+        assert_eq!(
+            rewritten,
+            r#"const Button = styled.button`
+  background-color: red;
+  padding: 4px;
+`;
+
+const Title = styled(Button)`
+  background-color: blue;
+`;
+
+const message = "color: green;";
+"#
+        );
+    }
+
+    #[test]
+    fn test_graphql_in_tagged_templates() {
+        let pattern_src = r#"
+        language graphql
+
+        `user(id: $id)` => `user(userId: $id)`
+        "#;
+
+        let source = r#"const USER = gql`
+  query GetUser($id: ID!) {
+    user(id: $id) {
+      ${fields}
+      name
+    }
+  }
+`;
+
+const message = "user(id: $id)";
+"#;
+
+        let rewritten = rewrite_file(pattern_src, "queries.ts", source).unwrap();
+        assert_eq!(
+            rewritten,
+            r#"const USER = gql`
+  query GetUser($id: ID!) {
+    user(userId: $id) {
+      ${fields}
+      name
+    }
+  }
+`;
+
+const message = "user(id: $id)";
+"#
+        );
+    }
+
+    #[test]
+    fn test_host_without_injections() {
+        let pattern_src = r#"
+        language css
+
+        `color: $c` => `background-color: $c;`
+        "#;
+
+        let source = r#"const message = "color: green;";
+"#;
+
+        assert!(rewrite_file(pattern_src, "message.ts", source).is_none());
+    }
+}
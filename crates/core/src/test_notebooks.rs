@@ -445,4 +445,80 @@ mod tests {
             panic!("Expected a rewrite");
         }
     }
+
+    fn rewrite_document(file_name: &str, source: &str) -> String {
+        let pattern_src = r#"
+        language python
+
+        `print($x)` => `flink($x)`
+        "#;
+        let libs = BTreeMap::new();
+
+        let pattern = src_to_problem_libs(
+            pattern_src.to_string(),
+            &libs,
+            TargetLanguage::from_extension("py").unwrap(),
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap()
+        .problem;
+
+        let test_files = vec![SyntheticFile::new(
+            file_name.to_owned(),
+            source.to_owned(),
+            true,
+        )];
+        let results = run_on_test_files(&pattern, &test_files);
+        assert!(!results.iter().any(|r| r.is_error()));
+
+        let rewrite = results
+            .iter()
+            .find(|r| matches!(r, MatchResult::Rewrite(_)))
+            .unwrap();
+
+        if let MatchResult::Rewrite(rewrite) = rewrite {
+            rewrite.content().unwrap().to_string()
+        } else {
+            panic!("Expected a rewrite");
+        }
+    }
+
+    #[test]
+    fn test_jupytext_percent_script() {
+        let source = include_str!("../../../crates/cli_bin/fixtures/notebooks/percent.py");
+        let content = rewrite_document("analysis.py", source);
+        assert_eq!(
+            content,
+            source
+                .replace("print(math.pi)", "flink(math.pi)")
+                .replace(r#"print("done")"#, r#"flink("done")"#)
+        );
+    }
+
+    #[test]
+    fn test_r_markdown_chunks() {
+        let source = include_str!("../../../crates/cli_bin/fixtures/notebooks/analysis.Rmd");
+        let content = rewrite_document("analysis.Rmd", source);
+        assert_eq!(
+            content,
+            source
+                .replace("print(math.pi)", "flink(math.pi)")
+                .replace(r#"print("done")"#, r#"flink("done")"#)
+        );
+    }
+
+    #[test]
+    fn test_quarto_chunks() {
+        let source = include_str!("../../../crates/cli_bin/fixtures/notebooks/report.qmd");
+        let content = rewrite_document("report.qmd", source);
+        assert_eq!(
+            content,
+            source
+                .replace("print(1)", "flink(1)")
+                .replace("print(3)", "flink(3)")
+        );
+    }
 }
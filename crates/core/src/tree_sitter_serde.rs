@@ -23,6 +23,12 @@ pub fn tree_sitter_node_to_json(
     let mut cursor = node.walk();
     let mut node_json = json!({});
     node_json["SORT"] = node.kind().to_string().into();
+    // Flag nodes the parser had to make up or skip, so they stand out
+    if node.is_error() {
+        node_json["ERROR"] = true.into();
+    } else if node.is_missing() {
+        node_json["MISSING"] = true.into();
+    }
     let start_position = json!({
         "line": node.start_position().row() + 1,
         "column": node.start_position().column() + 1,
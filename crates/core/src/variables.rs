@@ -1,11 +1,11 @@
-use crate::pattern_compiler::compiler::NodeCompilationContext;
-use anyhow::Result;
+use crate::{pattern_compiler::compiler::NodeCompilationContext, problem::MarzanoQueryContext};
+use anyhow::{bail, Result};
 use grit_pattern_matcher::{
     constants::{DEFAULT_FILE_NAME, GLOBAL_VARS_SCOPE_INDEX},
-    pattern::{Variable, VariableSource},
+    pattern::{Pattern, PatternDefinition, Variable, VariableSource},
 };
 use grit_util::ByteRange;
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 pub(crate) fn get_variables(
     params: &[(String, ByteRange)],
@@ -94,3 +94,81 @@ pub(crate) fn register_variable_optional_range(
 
     Ok(Variable::new(scope_index as usize, index))
 }
+
+/// Registers variables provided from outside the pattern, such as with
+/// `grit apply --var`, as globals. Returns their values by global index, so
+/// they can be bound before execution.
+pub(crate) fn register_injected_variables(
+    variables: &BTreeMap<String, serde_json::Value>,
+    global_vars: &mut BTreeMap<String, usize>,
+) -> Result<BTreeMap<usize, serde_json::Value>> {
+    let mut values = BTreeMap::new();
+    for (name, value) in variables {
+        let bare_name = name.strip_prefix('$').unwrap_or(name);
+        let is_identifier = bare_name
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && bare_name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !is_identifier {
+            bail!("invalid variable name {name}, variable names must match /^[A-Za-z_][A-Za-z0-9_]*$/");
+        }
+        let name = format!("${bare_name}");
+        if global_vars.contains_key(&name) {
+            bail!("cannot provide a value for built-in variable {name}");
+        }
+        let index = global_vars.len();
+        global_vars.insert(name, index);
+        values.insert(index, value.clone());
+    }
+    Ok(values)
+}
+
+/// Passes injected variables to the parameters a top-level pattern call left
+/// out, so `rename_import()` picks up `--var from=...`. Once any parameter of
+/// the pattern is provided this way, all of them are required.
+pub(crate) fn forward_injected_variables(
+    pattern: &mut Pattern<MarzanoQueryContext>,
+    pattern_definitions: &[PatternDefinition<MarzanoQueryContext>],
+    global_vars: &BTreeMap<String, usize>,
+    values: &BTreeMap<usize, serde_json::Value>,
+    metavariable_prefix: &str,
+) -> Result<()> {
+    let Pattern::Call(call) = pattern else {
+        return Ok(());
+    };
+    let Some(definition) = pattern_definitions.get(call.index) else {
+        return Ok(());
+    };
+    let mut forwarded = false;
+    let mut missing = vec![];
+    for ((param, _), arg) in definition.params().iter().zip(call.args.iter_mut()) {
+        if arg.is_some() {
+            continue;
+        }
+        let bare_name = param.strip_prefix(metavariable_prefix).unwrap_or(param);
+        match global_vars
+            .get(&format!("${bare_name}"))
+            .filter(|index| values.contains_key(index))
+        {
+            Some(index) => {
+                *arg = Some(Pattern::Variable(Variable::new(
+                    GLOBAL_VARS_SCOPE_INDEX as usize,
+                    *index,
+                )));
+                forwarded = true;
+            }
+            None => missing.push(bare_name),
+        }
+    }
+    if forwarded && !missing.is_empty() {
+        bail!(
+            "missing value for parameter(s) {} of pattern {}",
+            missing.join(", "),
+            definition.name
+        );
+    }
+    Ok(())
+}
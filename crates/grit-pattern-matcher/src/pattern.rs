@@ -106,7 +106,7 @@ pub use r#if::{If, PrIf};
 pub use r#match::Match;
 pub use r#where::Where;
 pub use range::{Point, Range};
-pub use regex::{RegexLike, RegexPattern};
+pub use regex::{cached_dynamic_regex, cached_regex, whole_string_regex, RegexLike, RegexPattern};
 pub use resolved_pattern::ResolvedPattern;
 pub use resolved_pattern::{File, JoinFn, LazyBuiltIn, ResolvedFile, ResolvedSnippet};
 pub use rewrite::Rewrite;
@@ -14,6 +14,70 @@ use grit_util::{
     AnalysisLogs,
 };
 use regex::Regex;
+use std::{
+    collections::HashMap,
+    sync::{Mutex, OnceLock, RwLock},
+};
+
+/// Regexes built from metavariables can differ on every match, so only this
+/// many of them are kept, evicting the least recently used.
+const MAX_DYNAMIC_REGEXES: usize = 1024;
+
+/// Compiles a regex written in a pattern, reusing the compiled form across
+/// matches. Patterns only contain so many regexes, so these are never evicted.
+pub fn cached_regex(regex: &str) -> GritResult<Regex> {
+    static CACHE: OnceLock<RwLock<HashMap<String, Regex>>> = OnceLock::new();
+    let cache = CACHE.get_or_init(Default::default);
+    if let Some(compiled) = cache.read().unwrap().get(regex) {
+        return Ok(compiled.clone());
+    }
+    let compiled = Regex::new(regex)?;
+    cache
+        .write()
+        .unwrap()
+        .insert(regex.to_owned(), compiled.clone());
+    Ok(compiled)
+}
+
+/// Compiles a regex built while matching, such as one interpolating
+/// metavariables or passed to a regex builtin, keeping the most recently used
+/// ones compiled.
+pub fn cached_dynamic_regex(regex: &str) -> GritResult<Regex> {
+    static CACHE: OnceLock<Mutex<DynamicRegexes>> = OnceLock::new();
+    let mut cache = CACHE.get_or_init(Default::default).lock().unwrap();
+    cache.tick += 1;
+    let tick = cache.tick;
+    if let Some((compiled, last_used)) = cache.regexes.get_mut(regex) {
+        *last_used = tick;
+        return Ok(compiled.clone());
+    }
+    let compiled = Regex::new(regex)?;
+    if cache.regexes.len() >= MAX_DYNAMIC_REGEXES {
+        let least_recent = cache
+            .regexes
+            .iter()
+            .min_by_key(|(_, (_, last_used))| *last_used)
+            .map(|(regex, _)| regex.clone());
+        if let Some(least_recent) = least_recent {
+            cache.regexes.remove(&least_recent);
+        }
+    }
+    cache
+        .regexes
+        .insert(regex.to_owned(), (compiled.clone(), tick));
+    Ok(compiled)
+}
+
+#[derive(Default)]
+struct DynamicRegexes {
+    tick: u64,
+    regexes: HashMap<String, (Regex, u64)>,
+}
+
+/// The regex actually run for `regex` when it has to match a whole string.
+pub fn whole_string_regex(regex: &str) -> String {
+    format!("^{}$", regex)
+}
 
 #[derive(Debug, Clone)]
 pub struct RegexPattern<Q: QueryContext> {
@@ -41,21 +105,20 @@ impl<Q: QueryContext> RegexPattern<Q> {
         must_match_entire_string: bool,
     ) -> GritResult<bool> {
         let text = binding.text(&state.files, context.language())?;
-        let resolved_regex_text = match &self.regex {
+        let final_regex = match &self.regex {
             RegexLike::Regex(regex) => match must_match_entire_string {
-                true => format!("^{}$", regex),
-                false => regex.to_string(),
+                true => cached_regex(&whole_string_regex(regex))?,
+                false => cached_regex(regex)?,
             },
             RegexLike::Pattern(ref pattern) => {
                 let resolved = Q::ResolvedPattern::from_pattern(pattern, state, context, logs)?;
                 let text = resolved.text(&state.files, context.language())?;
                 match must_match_entire_string {
-                    true => format!("^{}$", text),
-                    false => text.to_string(),
+                    true => cached_dynamic_regex(&whole_string_regex(&text))?,
+                    false => cached_dynamic_regex(&text)?,
                 }
             }
         };
-        let final_regex = Regex::new(&resolved_regex_text)?;
         let captures = match final_regex.captures(&text) {
             Some(captures) => captures,
             None => return Ok(false),
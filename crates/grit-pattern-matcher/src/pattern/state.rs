@@ -270,6 +270,11 @@ impl<'a, Q: QueryContext> State<'a, Q> {
         &mut self.rng
     }
 
+    /// Restarts the RNG from the given seed, in place of the default one
+    pub fn reseed_rng(&mut self, seed: u64) {
+        self.rng = rand::rngs::StdRng::seed_from_u64(seed);
+    }
+
     /// Enter a scope by copying the current scope and adding the new variables
     /// When you are done with a scope, you *must* call exit_scope
     ///
@@ -130,6 +130,15 @@ pub trait Language: Sized {
         false
     }
 
+    /// Whether an item inserted after a lone child is separated from it the
+    /// way the child is separated from what precedes it.
+    ///
+    /// This suits markup, where the whitespace between children is only there
+    /// for layout.
+    fn should_pad_like_first_child(&self) -> bool {
+        false
+    }
+
     fn make_single_line_comment(&self, text: &str) -> String {
         format!("// {text}\n")
     }
@@ -1,11 +1,13 @@
 /// API for the gritmodule
 /// This module exposes functions that should be callable anywhere, including from the bridge
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::Result;
+use marzano_language::detection::LanguageOverrides;
 
 use crate::{
     config::GritConfig,
+    searcher::find_grit_dir_from,
     yaml::{get_grit_config, read_grit_yaml},
 };
 
@@ -25,6 +27,25 @@ pub async fn parse_grit_config(yaml_config: &str) -> Result<GritConfig> {
     Ok(config)
 }
 
+/// Reads the `languageOverrides` of the repository containing `dir`.
+/// Only the repository's own config is read, since the overrides describe its files.
+pub async fn read_language_overrides(dir: PathBuf) -> Result<LanguageOverrides> {
+    let Some(repo_dir) = find_grit_dir_from(dir)
+        .await
+        .and_then(|grit_dir| grit_dir.parent().map(Path::to_path_buf))
+    else {
+        return Ok(LanguageOverrides::default());
+    };
+    let Some(config) = read_grit_config(&repo_dir).await? else {
+        return Ok(LanguageOverrides::default());
+    };
+    let (overrides, warnings) = LanguageOverrides::new(&config.language_overrides, &repo_dir)?;
+    for warning in warnings {
+        log::warn!("{}", warning);
+    }
+    Ok(overrides)
+}
+
 #[cfg(test)]
 mod tests {
     use std::{path::PathBuf, str::FromStr};
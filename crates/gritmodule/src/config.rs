@@ -1,11 +1,15 @@
 use anyhow::Context;
 use grit_util::Range;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use log::info;
 use marzano_core::api::EnforcementLevel;
-use marzano_language::{grit_parser::MarzanoGritParser, target_language::PatternLanguage};
+use marzano_language::{
+    grit_parser::MarzanoGritParser, plugin::PluginLanguageConfig, target_language::PatternLanguage,
+};
 use serde::{Deserialize, Serialize};
 use std::{
     cmp::Ordering,
+    collections::BTreeMap,
     path::{Path, PathBuf},
     vec::Vec,
 };
@@ -46,6 +50,8 @@ pub struct GritConfig {
     pub patterns: Vec<GritDefinitionConfig>,
     pub pattern_files: Option<Vec<GritPatternFile>>,
     pub github: Option<GritGitHubConfig>,
+    pub languages: Vec<PluginLanguageConfig>,
+    pub language_overrides: BTreeMap<String, String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -60,6 +66,12 @@ pub enum GritPatternConfig {
 pub struct SerializedGritConfig {
     pub patterns: Vec<GritPatternConfig>,
     pub github: Option<GritGitHubConfig>,
+    /// Extra languages backed by grammars loaded at runtime
+    #[serde(default)]
+    pub languages: Vec<PluginLanguageConfig>,
+    /// Languages forced for files matching a glob, e.g. `Jenkinsfile: groovy`
+    #[serde(default, rename = "languageOverrides")]
+    pub language_overrides: BTreeMap<String, String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Default, PartialEq)]
@@ -79,6 +91,9 @@ pub struct GritPatternMetadata {
     pub title: Option<String>,
     pub description: Option<String>,
     pub tags: Option<Vec<String>>,
+    /// Seed for `random` and `shuffle` when testing or checking the pattern
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
 }
 
 /// This contains the raw pattern data
@@ -103,6 +118,12 @@ pub struct GritDefinitionConfig {
     pub path: String,
     pub range: Option<Range>,
     pub raw: Option<RawGritDefinition>,
+    /// Values for the pattern's parameters, keyed by parameter name without the `$`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub params: Option<BTreeMap<String, serde_json::Value>>,
+    /// Values for the pattern's parameters in files matching some globs
+    #[serde(default, rename = "overrides", skip_serializing_if = "Vec::is_empty")]
+    pub param_overrides: Vec<GritParamsOverride>,
 }
 
 impl GritDefinitionConfig {
@@ -116,7 +137,19 @@ impl GritDefinitionConfig {
             path,
             range: None,
             raw: None,
+            params: serialized.params,
+            param_overrides: serialized.param_overrides,
+        }
+    }
+
+    /// The values of the pattern's parameters in the files of the override
+    /// at `index` in `param_overrides`, or in other files when `None`.
+    pub fn params_for(&self, index: Option<usize>) -> BTreeMap<String, serde_json::Value> {
+        let mut params = self.params.clone().unwrap_or_default();
+        if let Some(param_override) = index.and_then(|index| self.param_overrides.get(index)) {
+            params.extend(param_override.params.clone());
         }
+        params
     }
 }
 
@@ -132,6 +165,73 @@ pub struct GritSerializedDefinitionConfig {
     #[serde(skip)]
     pub kind: Option<DefinitionKind>,
     pub samples: Option<Vec<GritPatternSample>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub params: Option<BTreeMap<String, serde_json::Value>>,
+    #[serde(default, rename = "overrides", skip_serializing_if = "Vec::is_empty")]
+    pub param_overrides: Vec<GritParamsOverride>,
+}
+
+/// Values for the parameters of a pattern in the files matching `files`, as
+/// listed under its `overrides` in grit.yaml. When several overrides match a
+/// file, the last one applies.
+#[derive(Clone, Debug, Serialize, Deserialize, Default, PartialEq)]
+pub struct GritParamsOverride {
+    /// Globs in `.gitignore` syntax, relative to the repository root
+    pub files: Vec<String>,
+    pub params: BTreeMap<String, serde_json::Value>,
+}
+
+/// Matches files to the `overrides` of a pattern's config.
+#[derive(Debug)]
+pub struct ParamsOverrideMatcher {
+    root: PathBuf,
+    matchers: Vec<Gitignore>,
+}
+
+impl ParamsOverrideMatcher {
+    /// Builds a matcher for the overrides of `config`, whose globs are
+    /// relative to the repository at `root`.
+    pub fn new(config: &GritDefinitionConfig, root: &Path) -> Result<Self> {
+        let root = absolute(root)?;
+        let matchers = config
+            .param_overrides
+            .iter()
+            .map(|param_override| -> Result<Gitignore> {
+                let mut builder = GitignoreBuilder::new(&root);
+                for glob in &param_override.files {
+                    builder
+                        .add_line(None, glob)
+                        .with_context(|| format!("invalid glob {} in overrides", glob))?;
+                }
+                Ok(builder.build()?)
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { root, matchers })
+    }
+
+    /// Returns the index of the last override matching the file at `path`, if any.
+    pub fn override_for(&self, path: &Path) -> Option<usize> {
+        if self.matchers.is_empty() {
+            return None;
+        }
+        let path = absolute(path).ok()?;
+        if !path.starts_with(&self.root) {
+            return None;
+        }
+        self.matchers.iter().rposition(|matcher| {
+            matcher
+                .matched_path_or_any_parents(&path, false)
+                .is_ignore()
+        })
+    }
+}
+
+fn absolute(path: &Path) -> Result<PathBuf> {
+    Ok(if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        env::current_dir()?.join(path)
+    })
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
@@ -135,7 +135,9 @@ async fn format_temp_dir(dir: &TempDir, languages: Vec<&PatternLanguage>) -> Res
         }
     }
 
-    if languages.contains(&&PatternLanguage::Python) {
+    if languages.contains(&&PatternLanguage::Python)
+        || languages.contains(&&PatternLanguage::Python2)
+    {
         let mut cmd = Command::new("ruff");
         cmd.current_dir(dir);
         cmd.arg("format");
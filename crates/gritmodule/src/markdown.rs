@@ -1,6 +1,6 @@
 use crate::config::{DefinitionKind, GritPatternMetadata, RawGritDefinition};
 use crate::{
-    config::{GritDefinitionConfig, GritPatternSample, ModuleGritPattern},
+    config::{GritDefinitionConfig, GritParamsOverride, GritPatternSample, ModuleGritPattern},
     fetcher::ModuleRepo,
     parser::extract_relative_file_path,
     utils::is_pattern_name,
@@ -16,6 +16,7 @@ use marzano_util::cursor_wrapper::CursorWrapper;
 use marzano_util::node_with_source::NodeWithSource;
 use marzano_util::rich_path::RichFile;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::io::{Read, Seek, Write};
 use std::path::Path;
 use tokio::io::SeekFrom;
@@ -52,6 +53,12 @@ pub fn make_md_parser() -> Result<Parser> {
 #[derive(Clone, Debug, Serialize, Deserialize, Default, PartialEq)]
 pub struct GritDefinitionOverrides {
     pub name: Option<String>,
+    /// Values for the parameters of the patterns in the file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub params: Option<BTreeMap<String, serde_json::Value>>,
+    /// Values for the parameters of the patterns in files matching some globs
+    #[serde(default, rename = "overrides", skip_serializing_if = "Vec::is_empty")]
+    pub param_overrides: Vec<GritParamsOverride>,
 }
 
 pub fn get_patterns_from_md(
@@ -237,6 +244,8 @@ js"hello world"
                         content: src.to_string(),
                         format: crate::parser::PatternFileExt::Md,
                     }),
+                    params: overrides.params.clone(),
+                    param_overrides: overrides.param_overrides.clone(),
                 },
                 module: source_module.clone(),
                 local_name,
@@ -737,6 +746,36 @@ language js
         assert_eq!(patterns[0].config.meta.level, Some(EnforcementLevel::Error));
     }
 
+    #[test]
+    fn test_frontmatter_seed() {
+        let module = Default::default();
+        let mut rich_file = RichFile {
+            path: "shuffle_args.md".to_string(),
+            content: r#"---
+title: Shuffle arguments
+seed: 42
+---
+
+```grit
+engine marzano(0.1)
+language js
+
+`f($args)` => `f($shuffled)` where { $shuffled = shuffle($args) }
+```
+
+"#
+            .to_string(),
+        };
+        let patterns = get_patterns_from_md(
+            &mut rich_file,
+            &module,
+            &None,
+            GritDefinitionOverrides::default(),
+        )
+        .unwrap();
+        assert_eq!(patterns[0].config.meta.seed, Some(42));
+    }
+
     #[test]
     fn test_with_override() {
         let module = Default::default();
@@ -760,6 +799,7 @@ language js
             &None,
             GritDefinitionOverrides {
                 name: Some("ok_name".to_owned()),
+                ..Default::default()
             },
         )
         .unwrap();
@@ -1,7 +1,9 @@
 use std::{collections::BTreeMap, mem};
 
 use anyhow::{bail, Result};
-use marzano_language::target_language::PatternLanguage;
+use marzano_language::{plugin::PluginId, target_language::PatternLanguage};
+
+static EMPTY_DIRECTORY: BTreeMap<String, String> = BTreeMap::new();
 
 #[derive(Debug)]
 pub struct PatternsDirectory {
@@ -16,6 +18,7 @@ pub struct PatternsDirectory {
     kotlin: BTreeMap<String, String>,
     c_sharp: BTreeMap<String, String>,
     python: BTreeMap<String, String>,
+    python2: BTreeMap<String, String>,
     markdown_block: BTreeMap<String, String>,
     markdown_inline: BTreeMap<String, String>,
     go: BTreeMap<String, String>,
@@ -30,6 +33,17 @@ pub struct PatternsDirectory {
     toml: BTreeMap<String, String>,
     php: BTreeMap<String, String>,
     php_only: BTreeMap<String, String>,
+    dart: BTreeMap<String, String>,
+    svelte: BTreeMap<String, String>,
+    astro: BTreeMap<String, String>,
+    graphql: BTreeMap<String, String>,
+    protobuf: BTreeMap<String, String>,
+    xml: BTreeMap<String, String>,
+    lua: BTreeMap<String, String>,
+    nix: BTreeMap<String, String>,
+    scss: BTreeMap<String, String>,
+    less: BTreeMap<String, String>,
+    plugins: BTreeMap<PluginId, BTreeMap<String, String>>,
     universal: BTreeMap<String, String>,
 }
 
@@ -74,6 +88,7 @@ impl PatternsDirectory {
             kotlin: BTreeMap::new(),
             c_sharp: BTreeMap::new(),
             python: BTreeMap::new(),
+            python2: BTreeMap::new(),
             markdown_block: BTreeMap::new(),
             markdown_inline: BTreeMap::new(),
             go: BTreeMap::new(),
@@ -88,6 +103,17 @@ impl PatternsDirectory {
             toml: BTreeMap::new(),
             php: BTreeMap::new(),
             php_only: BTreeMap::new(),
+            dart: BTreeMap::new(),
+            svelte: BTreeMap::new(),
+            astro: BTreeMap::new(),
+            graphql: BTreeMap::new(),
+            protobuf: BTreeMap::new(),
+            xml: BTreeMap::new(),
+            lua: BTreeMap::new(),
+            nix: BTreeMap::new(),
+            scss: BTreeMap::new(),
+            less: BTreeMap::new(),
+            plugins: BTreeMap::new(),
             universal: BTreeMap::new(),
         }
     }
@@ -107,6 +133,7 @@ impl PatternsDirectory {
             PatternLanguage::Kotlin => &mut self.kotlin,
             PatternLanguage::CSharp => &mut self.c_sharp,
             PatternLanguage::Python => &mut self.python,
+            PatternLanguage::Python2 => &mut self.python2,
             PatternLanguage::MarkdownBlock => &mut self.markdown_block,
             PatternLanguage::MarkdownInline => &mut self.markdown_inline,
             PatternLanguage::Go => &mut self.go,
@@ -121,6 +148,17 @@ impl PatternsDirectory {
             PatternLanguage::Toml => &mut self.toml,
             PatternLanguage::Php => &mut self.php,
             PatternLanguage::PhpOnly => &mut self.php_only,
+            PatternLanguage::Dart => &mut self.dart,
+            PatternLanguage::Svelte => &mut self.svelte,
+            PatternLanguage::Astro => &mut self.astro,
+            PatternLanguage::GraphQL => &mut self.graphql,
+            PatternLanguage::Protobuf => &mut self.protobuf,
+            PatternLanguage::Xml => &mut self.xml,
+            PatternLanguage::Lua => &mut self.lua,
+            PatternLanguage::Nix => &mut self.nix,
+            PatternLanguage::Scss => &mut self.scss,
+            PatternLanguage::Less => &mut self.less,
+            PatternLanguage::Plugin(id) => self.plugins.entry(id).or_default(),
             PatternLanguage::Universal => &mut self.universal,
         }
     }
@@ -137,6 +175,7 @@ impl PatternsDirectory {
             PatternLanguage::Kotlin => &self.kotlin,
             PatternLanguage::CSharp => &self.c_sharp,
             PatternLanguage::Python => &self.python,
+            PatternLanguage::Python2 => &self.python2,
             PatternLanguage::MarkdownBlock => &self.markdown_block,
             PatternLanguage::MarkdownInline => &self.markdown_inline,
             PatternLanguage::Go => &self.go,
@@ -151,6 +190,17 @@ impl PatternsDirectory {
             PatternLanguage::Toml => &self.toml,
             PatternLanguage::Php => &self.php,
             PatternLanguage::PhpOnly => &self.php_only,
+            PatternLanguage::Dart => &self.dart,
+            PatternLanguage::Svelte => &self.svelte,
+            PatternLanguage::Astro => &self.astro,
+            PatternLanguage::GraphQL => &self.graphql,
+            PatternLanguage::Protobuf => &self.protobuf,
+            PatternLanguage::Xml => &self.xml,
+            PatternLanguage::Lua => &self.lua,
+            PatternLanguage::Nix => &self.nix,
+            PatternLanguage::Scss => &self.scss,
+            PatternLanguage::Less => &self.less,
+            PatternLanguage::Plugin(id) => self.plugins.get(&id).unwrap_or(&EMPTY_DIRECTORY),
             PatternLanguage::Universal => &self.universal,
         }
     }
@@ -252,6 +302,8 @@ impl PatternsDirectory {
         self.c_sharp = other.c_sharp;
         other.python.extend(mem::take(&mut self.python));
         self.python = other.python;
+        other.python2.extend(mem::take(&mut self.python2));
+        self.python2 = other.python2;
         other
             .markdown_block
             .extend(mem::take(&mut self.markdown_block));
@@ -284,6 +336,30 @@ impl PatternsDirectory {
         self.php = other.php;
         other.php_only.extend(mem::take(&mut self.php_only));
         self.php_only = other.php_only;
+        other.dart.extend(mem::take(&mut self.dart));
+        self.dart = other.dart;
+        other.svelte.extend(mem::take(&mut self.svelte));
+        self.svelte = other.svelte;
+        other.astro.extend(mem::take(&mut self.astro));
+        self.astro = other.astro;
+        other.graphql.extend(mem::take(&mut self.graphql));
+        self.graphql = other.graphql;
+        other.protobuf.extend(mem::take(&mut self.protobuf));
+        self.protobuf = other.protobuf;
+        other.xml.extend(mem::take(&mut self.xml));
+        self.xml = other.xml;
+        other.lua.extend(mem::take(&mut self.lua));
+        self.lua = other.lua;
+        other.nix.extend(mem::take(&mut self.nix));
+        self.nix = other.nix;
+        other.scss.extend(mem::take(&mut self.scss));
+        self.scss = other.scss;
+        other.less.extend(mem::take(&mut self.less));
+        self.less = other.less;
+        for (id, library) in mem::take(&mut self.plugins) {
+            other.plugins.entry(id).or_default().extend(library);
+        }
+        self.plugins = other.plugins;
         other.universal.extend(mem::take(&mut self.universal));
         self.universal = other.universal;
     }
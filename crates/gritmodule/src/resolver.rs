@@ -6,24 +6,27 @@ use crate::{
 use std::{
     collections::{HashMap, HashSet},
     env,
+    ffi::OsStr,
     path::{Path, PathBuf},
     str::FromStr,
 };
 
 use crate::{
     config::{
-        is_namespace_import, ModuleGritPattern, ResolvedGritDefinition, GRIT_MODULE_DIR,
-        REPO_CONFIG_DIR_NAME, REPO_CONFIG_PATTERNS_DIR,
+        is_namespace_import, GritDefinitionConfig, ModuleGritPattern, ResolvedGritDefinition,
+        GRIT_MODULE_DIR, REPO_CONFIG_DIR_NAME, REPO_CONFIG_PATTERNS_DIR,
     },
     fetcher::{FetcherType, ModuleRepo},
     installer::{install_default_stdlib, install_grit_modules},
-    parser::PatternFileExt,
+    markdown::GritDefinitionOverrides,
+    parser::{get_patterns_from_file, PatternFileExt},
     patterns_directory::PatternsDirectory,
     searcher::{collect_patterns, find_repo_root_from},
-    yaml::{get_patterns_from_yaml, read_grit_yaml},
+    yaml::{get_grit_config, get_patterns_from_yaml, read_grit_yaml},
 };
 use anyhow::{bail, Context, Result};
 use homedir::get_my_home;
+use ignore::{Walk, WalkBuilder};
 use marzano_language::{grit_parser::MarzanoGritParser, target_language::PatternLanguage};
 use tokio::{fs, join};
 
@@ -47,6 +50,100 @@ pub async fn find_local_patterns(
         .collect())
 }
 
+/// Finds the config of the pattern `name` in the repository at `repo_dir`,
+/// reading only the files that can define it instead of resolving every
+/// pattern: the repository's grit.yaml, the pattern files it lists, and the
+/// markdown files named after the pattern. As when resolving patterns, the
+/// settings of a grit.yaml entry take precedence over the pattern's own.
+pub async fn find_pattern_config(
+    repo_dir: &Path,
+    name: &str,
+) -> Result<Option<GritDefinitionConfig>> {
+    let mut entry = None;
+    let mut definition = None;
+    if let Some(file) = read_grit_yaml(repo_dir).await {
+        let config = get_grit_config(&file.content, &file.path)?;
+        entry = config
+            .patterns
+            .into_iter()
+            .find(|pattern| pattern.name.rsplit('#').next() == Some(name));
+        for pattern_file in config.pattern_files.unwrap_or_default() {
+            let path = repo_dir.join(REPO_CONFIG_DIR_NAME).join(&pattern_file.file);
+            let defines_name = match &pattern_file.overrides.name {
+                Some(overridden) => overridden == name,
+                None => path.file_stem() == Some(OsStr::new(name)),
+            };
+            let Some(ext) = PatternFileExt::from_path(&path).filter(|_| defines_name) else {
+                continue;
+            };
+            definition = get_patterns_from_file(path, None, ext, pattern_file.overrides)
+                .await?
+                .into_iter()
+                .find(|pattern| pattern.local_name == name);
+            if definition.is_some() {
+                break;
+            }
+        }
+    }
+    if definition.is_none() {
+        definition = find_markdown_pattern(repo_dir, name).await?;
+    }
+
+    let Some(mut config) = definition.map(|pattern| pattern.config) else {
+        return Ok(entry);
+    };
+    if let Some(entry) = entry {
+        if let Some(params) = entry.params {
+            config
+                .params
+                .get_or_insert_with(Default::default)
+                .extend(params);
+        }
+        if !entry.param_overrides.is_empty() {
+            config.param_overrides = entry.param_overrides;
+        }
+        if let Some(seed) = entry.meta.seed {
+            config.meta.seed = Some(seed);
+        }
+    }
+    Ok(Some(config))
+}
+
+/// Finds the markdown pattern `name` in the patterns directory of the
+/// repository at `repo_dir`, or else in those of the modules it imports.
+async fn find_markdown_pattern(repo_dir: &Path, name: &str) -> Result<Option<ModuleGritPattern>> {
+    let grit_dir = repo_dir.join(REPO_CONFIG_DIR_NAME);
+    let file_name = format!("{}.{}", name, PatternFileExt::Md.get_ext());
+    // modules are usually ignored by the repository, so their walk can't respect ignore files
+    let candidates = Walk::new(grit_dir.join(REPO_CONFIG_PATTERNS_DIR)).chain(
+        WalkBuilder::new(grit_dir.join(GRIT_MODULE_DIR))
+            .standard_filters(false)
+            .build(),
+    );
+    for entry in candidates.flatten() {
+        let path = entry.path();
+        let in_patterns_dir = path
+            .ancestors()
+            .any(|dir| dir.file_name() == Some(OsStr::new(REPO_CONFIG_PATTERNS_DIR)));
+        if path.file_name() != Some(OsStr::new(&file_name)) || !in_patterns_dir {
+            continue;
+        }
+        let pattern = get_patterns_from_file(
+            path.to_path_buf(),
+            None,
+            PatternFileExt::Md,
+            GritDefinitionOverrides::default(),
+        )
+        .await?
+        .into_iter()
+        .find(|pattern| pattern.local_name == name);
+        if pattern.is_some() {
+            return Ok(pattern);
+        }
+    }
+    Ok(None)
+}
+
 pub async fn find_user_patterns() -> Result<Vec<ResolvedGritDefinition>> {
     let mut resolved_patterns: HashMap<String, HashMap<String, ResolvedGritDefinition>> =
         HashMap::new();
@@ -715,6 +812,15 @@ fn merge_local_with_remote(
     if let Some(level) = local.config.meta.level {
         config.meta.level = Some(level);
     }
+    if let Some(params) = local.config.params {
+        config
+            .params
+            .get_or_insert_with(Default::default)
+            .extend(params);
+    }
+    if !local.config.param_overrides.is_empty() {
+        config.param_overrides = local.config.param_overrides;
+    }
     ResolvedGritDefinition {
         config,
         module: remote.module,
@@ -772,7 +878,7 @@ pub async fn find_and_resolve_grit_dir(
 mod tests {
     use std::{
         collections::{BTreeMap, HashMap},
-        path::PathBuf,
+        path::{Path, PathBuf},
         str::FromStr,
     };
 
@@ -956,4 +1062,62 @@ mod tests {
         resolved_patterns.sort_by(|a, b| a.local_name.cmp(&b.local_name));
         assert_yaml_snapshot!(resolved_patterns);
     }
+
+    #[tokio::test]
+    async fn resolves_pattern_params() {
+        let module_repo = ModuleRepo::from_host_repo("github.com", "getgrit/rewriter").unwrap();
+        let repo_dir = "fixtures/pattern_params";
+        let (resolved_patterns, errored_patterns) =
+            super::resolve_patterns(&module_repo, repo_dir, None)
+                .await
+                .unwrap();
+        assert_eq!(errored_patterns.len(), 0);
+
+        let params = |name: &str| {
+            resolved_patterns
+                .iter()
+                .find(|p| p.local_name == name)
+                .and_then(|p| p.config.params.clone())
+                .unwrap()
+        };
+        assert_eq!(params("max_params")["limit"], serde_json::json!(5));
+        assert_eq!(
+            params("banned_imports")["modules"],
+            serde_json::json!(["lodash"])
+        );
+        let max_params = resolved_patterns
+            .iter()
+            .find(|p| p.local_name == "max_params")
+            .unwrap();
+        assert_eq!(
+            max_params.config.params_for(Some(0))["limit"],
+            serde_json::json!(10)
+        );
+    }
+
+    #[tokio::test]
+    async fn finds_single_pattern_config() {
+        let repo_dir = Path::new("fixtures/pattern_params");
+        let max_params = super::find_pattern_config(repo_dir, "max_params")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(max_params.params_for(None)["limit"], serde_json::json!(5));
+        assert_eq!(
+            max_params.params_for(Some(0))["limit"],
+            serde_json::json!(10)
+        );
+        let banned_imports = super::find_pattern_config(repo_dir, "banned_imports")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            banned_imports.params_for(None)["modules"],
+            serde_json::json!(["lodash"])
+        );
+        assert!(super::find_pattern_config(repo_dir, "unknown")
+            .await
+            .unwrap()
+            .is_none());
+    }
 }
@@ -1,6 +1,7 @@
 use anyhow::{bail, Result};
 use futures::{future::BoxFuture, FutureExt as _};
 use grit_util::{Position, Range};
+use marzano_language::plugin::register_plugin_language;
 use marzano_util::rich_path::RichFile;
 use std::{
     collections::HashSet,
@@ -12,7 +13,7 @@ use crate::{
     config::{
         pattern_config_to_model, DefinitionKind, GritConfig, GritDefinitionConfig,
         ModuleGritPattern, RawGritDefinition, SerializedGritConfig, CONFIG_FILE_NAMES,
-        REPO_CONFIG_DIR_NAME,
+        GRIT_MODULE_DIR, REPO_CONFIG_DIR_NAME,
     },
     fetcher::ModuleRepo,
     parser::{extract_relative_file_path, get_patterns_from_file, PatternFileExt},
@@ -52,6 +53,8 @@ pub fn get_grit_config(source: &str, source_path: &str) -> Result<GritConfig> {
 
     let new_config = GritConfig {
         github: serialized.github,
+        languages: serialized.languages,
+        language_overrides: serialized.language_overrides,
         pattern_files: if pattern_files.is_empty() {
             None
         } else {
@@ -73,6 +76,21 @@ pub fn get_patterns_from_yaml<'a>(
         let grit_path = extract_relative_file_path(file, root);
         let mut config = get_grit_config(&file.content, &grit_path)?;
 
+        // languages load native code, so only the repository's own config may declare them
+        if !config.languages.is_empty() && !is_root_config(Path::new(&file.path)) {
+            bail!(
+                "Invalid configuration file '{}': languages can only be declared in the repository's own {}/grit.yaml",
+                grit_path,
+                REPO_CONFIG_DIR_NAME
+            );
+        }
+
+        // languages need to be registered before any pattern targeting them is parsed
+        let config_dir = Path::new(&file.path).parent().unwrap_or(Path::new(""));
+        for language in config.languages.drain(..) {
+            register_plugin_language(language.with_base_dir(config_dir))?;
+        }
+
         for pattern in config.patterns.iter_mut() {
             pattern.kind = Some(DefinitionKind::Pattern);
             let offset = file.content.find(&pattern.name).unwrap_or(0);
@@ -122,6 +140,23 @@ pub fn get_patterns_from_yaml<'a>(
     .boxed()
 }
 
+/// Whether `path` is a repository's own `.grit/grit.yaml`, rather than the
+/// config of a module fetched into `.gritmodules`.
+fn is_root_config(path: &Path) -> bool {
+    let in_config_dir = path
+        .parent()
+        .and_then(Path::file_name)
+        .is_some_and(|dir| dir == REPO_CONFIG_DIR_NAME);
+    let is_config_file = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| CONFIG_FILE_NAMES.contains(&name));
+    let in_module = path
+        .components()
+        .any(|component| component.as_os_str() == GRIT_MODULE_DIR);
+    in_config_dir && is_config_file && !in_module
+}
+
 pub fn extract_grit_modules(content: &str, path: &str) -> Result<Vec<String>> {
     let config = get_grit_config(content, path)?;
 
@@ -247,4 +282,101 @@ github:
         println!("{:?}", config);
         assert_eq!(config.github.unwrap().reviewers.len(), 2);
     }
+
+    #[test]
+    fn gets_plugin_languages() {
+        let grit_yaml = r##"version: 0.1.0
+patterns: []
+languages:
+  - name: mydsl
+    grammar: grammars/mydsl.so
+    nodeTypes: grammars/mydsl-node-types.json
+    extensions: [dsl]
+    commentPrefix: "#"
+    metavariablePrefix: "^"
+    snippetContexts:
+      - ["", ""]
+      - ["rule GRIT_RULE { ", " }"]
+    "##;
+        let config = get_grit_config(grit_yaml, ".grit/grit.yaml").unwrap();
+        assert_eq!(config.languages.len(), 1);
+        let language = &config.languages[0];
+        assert_eq!(language.name, "mydsl");
+        assert_eq!(language.extensions, vec!["dsl"]);
+        assert_eq!(language.metavariable_prefix, "^");
+        assert_eq!(language.snippet_contexts.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn rejects_languages_from_modules() {
+        let grit_yaml = RichFile {
+            path: ".gritmodules/github.com/example/dsl/.grit/grit.yaml".to_string(),
+            content: r#"version: 0.1.0
+patterns: []
+languages:
+  - name: mydsl
+    grammar: grammars/mydsl.so
+    nodeTypes: grammars/mydsl-node-types.json
+    extensions: [dsl]
+"#
+            .to_string(),
+        };
+        let err = get_patterns_from_yaml(&grit_yaml, None, &None, "")
+            .await
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("languages can only be declared in the repository's own .grit/grit.yaml"));
+    }
+
+    #[test]
+    fn recognizes_root_config() {
+        assert!(is_root_config(Path::new("repo/.grit/grit.yaml")));
+        assert!(is_root_config(Path::new(".grit/grit.yml")));
+        assert!(!is_root_config(Path::new("repo/grit.yaml")));
+        assert!(!is_root_config(Path::new(
+            "repo/.grit/.gritmodules/github.com/example/dsl/.grit/grit.yaml"
+        )));
+    }
+
+    #[test]
+    fn gets_language_overrides() {
+        let grit_yaml = r#"version: 0.1.0
+patterns: []
+languageOverrides:
+  Vagrantfile: ruby
+  "scripts/*.tmpl": yaml
+    "#;
+        let config = get_grit_config(grit_yaml, ".grit/grit.yaml").unwrap();
+        assert_eq!(config.language_overrides.len(), 2);
+        assert_eq!(config.language_overrides["Vagrantfile"], "ruby");
+        assert_eq!(config.language_overrides["scripts/*.tmpl"], "yaml");
+    }
+
+    #[test]
+    fn gets_pattern_params() {
+        let grit_yaml = r#"version: 0.1.0
+patterns:
+  - name: github.com/getgrit/stdlib#max_params
+    level: error
+    params:
+      limit: 5
+    overrides:
+      - files: [legacy/]
+        params:
+          limit: 10
+  - file: ../docs/banned_imports.md
+    params:
+      modules: [lodash, moment]
+    "#;
+        let config = get_grit_config(grit_yaml, ".grit/grit.yaml").unwrap();
+        let params = config.patterns[0].params.as_ref().unwrap();
+        assert_eq!(params["limit"], serde_json::json!(5));
+        let param_overrides = &config.patterns[0].param_overrides;
+        assert_eq!(param_overrides[0].files, vec!["legacy/"]);
+        assert_eq!(param_overrides[0].params["limit"], serde_json::json!(10));
+        let pattern_files = config.pattern_files.unwrap();
+        let params = pattern_files[0].overrides.params.as_ref().unwrap();
+        assert_eq!(params["modules"], serde_json::json!(["lodash", "moment"]));
+    }
 }
@@ -1,13 +1,15 @@
 use crate::{
+    astro::get_astro_ranges,
     language::{
         fields_for_nodes, Field, MarzanoLanguage, MarzanoParser, NodeTypes, SortId, TSLanguage,
         Tree,
     },
+    svelte::get_svelte_ranges,
     vue::get_vue_ranges,
 };
 use grit_util::{AnalysisLogs, FileOrigin, Language, Parser, SnippetTree};
 use marzano_util::node_with_source::NodeWithSource;
-use std::{path::Path, sync::OnceLock};
+use std::{ffi::OsStr, path::Path, sync::OnceLock};
 
 static NODE_TYPES_STRING: &str = include_str!("../../../resources/node-types/css-node-types.json");
 
@@ -113,25 +115,23 @@ impl Parser for MarzanoCssParser {
         logs: &mut AnalysisLogs,
         old_tree: FileOrigin<'_, Tree>,
     ) -> Option<Tree> {
-        if path
-            .and_then(Path::extension)
-            .is_some_and(|ext| ext == "vue")
-        {
-            let parent_node_kind = "style_element";
-            let ranges = get_vue_ranges(body, parent_node_kind, None).ok()?;
-            if ranges.is_empty() {
-                return None;
-            }
-
-            self.0.parser.set_included_ranges(&ranges).ok()?;
-            self.0
-                .parser
-                .parse(body, None)
-                .ok()?
-                .map(|tree| Tree::new(tree, body))
-        } else {
-            self.0.parse_file(body, path, logs, old_tree)
+        let parent_node_kind = "style_element";
+        let ranges = match path.and_then(Path::extension).and_then(OsStr::to_str) {
+            Some("vue") => get_vue_ranges(body, parent_node_kind, None).ok()?,
+            Some("svelte") => get_svelte_ranges(body, &[parent_node_kind], None).ok()?,
+            Some("astro") => get_astro_ranges(body, &[parent_node_kind], None).ok()?,
+            _ => return self.0.parse_file(body, path, logs, old_tree),
+        };
+        if ranges.is_empty() {
+            return None;
         }
+
+        self.0.parser.set_included_ranges(&ranges).ok()?;
+        self.0
+            .parser
+            .parse(body, None)
+            .ok()?
+            .map(|tree| Tree::new(tree, body))
     }
 
     fn parse_snippet(
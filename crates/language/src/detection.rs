@@ -0,0 +1,308 @@
+use crate::target_language::PatternLanguage;
+use anyhow::{Context, Result};
+use globset::{Glob, GlobMatcher};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+/// Number of bytes read from the start of a file when sniffing its language.
+const SNIFF_LENGTH: usize = 512;
+
+/// Modelines are only looked for in the first few lines of a file.
+const MODELINE_LINES: usize = 5;
+
+/// Interpreters named in shebangs, with the name of their language.
+const INTERPRETERS: &[(&str, &str)] = &[
+    ("python", "python"),
+    ("pypy", "python"),
+    ("node", "js"),
+    ("nodejs", "js"),
+    ("deno", "js"),
+    ("bun", "js"),
+    ("ts-node", "js"),
+    ("tsx", "js"),
+    ("ruby", "ruby"),
+    ("php", "php"),
+    ("elixir", "elixir"),
+    ("lua", "lua"),
+    ("luajit", "lua"),
+    ("sh", "bash"),
+    ("bash", "bash"),
+    ("zsh", "bash"),
+    ("dash", "bash"),
+    ("ksh", "bash"),
+];
+
+lazy_static! {
+    static ref VIM_MODELINE: Regex =
+        Regex::new(r"(?:^|\s)(?:vim?|ex):.*?\b(?:ft|filetype|syn|syntax)=([\w.+-]+)").unwrap();
+}
+
+/// Languages forced for paths matching a glob, as listed under
+/// `languageOverrides` in `.grit/grit.yaml`.
+#[derive(Debug, Clone, Default)]
+pub struct LanguageOverrides {
+    /// Directory that globs containing a `/` are relative to.
+    root: PathBuf,
+    globs: Vec<(GlobMatcher, PatternLanguage)>,
+}
+
+impl LanguageOverrides {
+    /// Builds the `languageOverrides` of the config of the repository at
+    /// `root`, mapping globs to language names. Globs without a `/` match file
+    /// names anywhere in the tree, others match paths relative to `root`.
+    ///
+    /// Overrides naming an unknown language are skipped, returning a warning
+    /// for each of them.
+    pub fn new(overrides: &BTreeMap<String, String>, root: &Path) -> Result<(Self, Vec<String>)> {
+        let mut globs = Vec::with_capacity(overrides.len());
+        let mut warnings = Vec::new();
+        for (glob, name) in overrides {
+            let Some(language) = PatternLanguage::from_string(name, None) else {
+                warnings.push(format!(
+                    "Ignoring unknown language {} for {} in languageOverrides",
+                    name, glob
+                ));
+                continue;
+            };
+            let glob = if glob.contains('/') {
+                glob.trim_start_matches("./").to_string()
+            } else {
+                format!("**/{}", glob)
+            };
+            let matcher = Glob::new(&glob)
+                .with_context(|| format!("invalid glob {} in languageOverrides", glob))?
+                .compile_matcher();
+            globs.push((matcher, language));
+        }
+        let overrides = Self {
+            root: absolute(root),
+            globs,
+        };
+        Ok((overrides, warnings))
+    }
+
+    /// Returns the language configured for the path, if any.
+    pub fn language(&self, path: &Path) -> Option<PatternLanguage> {
+        if self.globs.is_empty() {
+            return None;
+        }
+        let absolute = absolute(path);
+        let path = absolute.strip_prefix(&self.root).unwrap_or(path);
+        self.globs
+            .iter()
+            .find(|(matcher, _)| matcher.is_match(path))
+            .map(|(_, language)| *language)
+    }
+}
+
+fn absolute(path: &Path) -> PathBuf {
+    let path = path.strip_prefix("./").unwrap_or(path);
+    if path.is_absolute() {
+        return path.to_path_buf();
+    }
+    std::env::current_dir()
+        .map(|cwd| cwd.join(path))
+        .unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Whether files in `language` are commonly scripts without an extension, so
+/// that files without one are worth sniffing: languages run by one of the
+/// known interpreters, and plugin languages, whose interpreters are matched
+/// by name.
+pub fn is_sniffed(language: PatternLanguage) -> bool {
+    matches!(
+        language,
+        PatternLanguage::Python2 | PatternLanguage::Plugin(_)
+    ) || INTERPRETERS
+        .iter()
+        .any(|(_, name)| known_language(name) == Some(language))
+}
+
+/// Detects the language of a file without an extension by reading its first
+/// bytes, looking for a shebang or an editor modeline.
+pub fn sniff_file(path: &Path) -> Option<PatternLanguage> {
+    let mut head = [0; SNIFF_LENGTH];
+    let len = File::open(path).and_then(|mut f| f.read(&mut head)).ok()?;
+    sniff_language(&String::from_utf8_lossy(&head[..len]))
+}
+
+/// Detects the language from the start of a file's content.
+pub fn sniff_language(head: &str) -> Option<PatternLanguage> {
+    head.lines()
+        .next()
+        .and_then(language_from_shebang)
+        .or_else(|| {
+            head.lines()
+                .take(MODELINE_LINES)
+                .find_map(language_from_modeline)
+        })
+}
+
+/// Maps a shebang such as `#!/usr/bin/env python3` to its language.
+fn language_from_shebang(line: &str) -> Option<PatternLanguage> {
+    let mut words = line.strip_prefix("#!")?.split_whitespace();
+    let mut program = words.next()?.rsplit('/').next()?;
+    if program == "env" {
+        // skip flags like `-S` and variable assignments
+        program = words.find(|word| !word.starts_with('-') && !word.contains('='))?;
+    }
+    if program.starts_with("python2") {
+        return Some(PatternLanguage::Python2);
+    }
+    // `python3.11` and `lua5.1` are versions of `python` and `lua`
+    let interpreter = program.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.');
+    let name = INTERPRETERS
+        .iter()
+        .find(|(program, _)| *program == interpreter)
+        .map_or(interpreter, |(_, name)| *name);
+    known_language(name)
+}
+
+/// Maps a vim (`vim: set ft=ruby:`) or emacs (`-*- mode: ruby -*-`) modeline
+/// to its language.
+fn language_from_modeline(line: &str) -> Option<PatternLanguage> {
+    if let Some(start) = line.find("-*-") {
+        let vars = &line[start + 3..];
+        let vars = &vars[..vars.find("-*-")?];
+        let mode = vars.split(';').find_map(|var| match var.split_once(':') {
+            Some((key, value)) => key
+                .trim()
+                .eq_ignore_ascii_case("mode")
+                .then_some(value.trim()),
+            None => Some(var.trim()),
+        })?;
+        return editor_language(mode.trim_end_matches("-mode"));
+    }
+    let filetype = VIM_MODELINE.captures(line)?.get(1)?.as_str();
+    editor_language(filetype)
+}
+
+fn editor_language(mode: &str) -> Option<PatternLanguage> {
+    let mode = mode.to_ascii_lowercase();
+    let name = match mode.as_str() {
+        "javascript" | "javascriptreact" | "js2" | "rjsx" | "jsx" | "typescript"
+        | "typescriptreact" | "ts" | "tsx" => "js",
+        "sh" | "shell-script" | "zsh" => "bash",
+        "py" => "python",
+        "cs" => "csharp",
+        "terraform" => "hcl",
+        "md" => "markdown",
+        "rs" => "rust",
+        other => other,
+    };
+    known_language(name)
+}
+
+fn known_language(name: &str) -> Option<PatternLanguage> {
+    PatternLanguage::from_string(name, None).filter(|lang| *lang != PatternLanguage::Universal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_shebangs() {
+        let cases = [
+            (
+                "#!/usr/bin/env python3\nprint(1)",
+                Some(PatternLanguage::Python),
+            ),
+            ("#!/usr/bin/python2.7", Some(PatternLanguage::Python2)),
+            ("#!/usr/bin/python3.11", Some(PatternLanguage::Python)),
+            ("#!/usr/bin/env node\n", Some(PatternLanguage::Tsx)),
+            (
+                "#!/usr/bin/env -S deno run --allow-net",
+                Some(PatternLanguage::Tsx),
+            ),
+            ("#!/usr/bin/env ruby -w", Some(PatternLanguage::Ruby)),
+            (
+                "#!/usr/bin/env NODE_ENV=prod node",
+                Some(PatternLanguage::Tsx),
+            ),
+            ("#!/usr/local/bin/lua5.1", Some(PatternLanguage::Lua)),
+            // shells are only known once registered as a plugin language
+            ("#!/bin/bash\necho hi", None),
+            ("echo hi\n#!/usr/bin/env python3", None),
+        ];
+        for (head, expected) in cases {
+            assert_eq!(sniff_language(head), expected, "{}", head);
+        }
+    }
+
+    #[test]
+    fn detects_modelines() {
+        let cases = [
+            ("# vim: set ft=ruby:\nputs 1", Some(PatternLanguage::Ruby)),
+            (
+                "// vim: filetype=typescriptreact",
+                Some(PatternLanguage::Tsx),
+            ),
+            (
+                "# -*- mode: python; coding: utf-8 -*-",
+                Some(PatternLanguage::Python),
+            ),
+            ("# -*- yaml -*-", Some(PatternLanguage::Yaml)),
+            (
+                "#!/bin/false\n# vi: syntax=terraform",
+                Some(PatternLanguage::Hcl),
+            ),
+            ("# -*- coding: utf-8 -*-", None),
+            ("line 1\n2\n3\n4\n5\n# vim: ft=python", None),
+        ];
+        for (head, expected) in cases {
+            assert_eq!(sniff_language(head), expected, "{}", head);
+        }
+    }
+
+    #[test]
+    fn sniffs_only_script_languages() {
+        assert!(is_sniffed(PatternLanguage::Python));
+        assert!(is_sniffed(PatternLanguage::Python2));
+        assert!(is_sniffed(PatternLanguage::Tsx));
+        assert!(is_sniffed(PatternLanguage::Lua));
+        assert!(!is_sniffed(PatternLanguage::Java));
+        assert!(!is_sniffed(PatternLanguage::Css));
+    }
+
+    #[test]
+    fn overrides_languages_by_glob() {
+        let root = std::env::current_dir().unwrap().join("repo");
+        let overrides = BTreeMap::from([
+            ("Jenkinsfile.test".to_string(), "java".to_string()),
+            ("ci/*.tmpl".to_string(), "yaml".to_string()),
+            ("Jenkinsfile".to_string(), "nope".to_string()),
+        ]);
+        let (overrides, warnings) = LanguageOverrides::new(&overrides, &root).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("nope"));
+        assert_eq!(
+            overrides.language(Path::new("./repo/jobs/Jenkinsfile.test")),
+            Some(PatternLanguage::Java)
+        );
+        assert_eq!(
+            overrides.language(&root.join("ci/deploy.tmpl")),
+            Some(PatternLanguage::Yaml)
+        );
+        assert_eq!(
+            overrides.language(Path::new("repo/ci/deploy.tmpl")),
+            Some(PatternLanguage::Yaml)
+        );
+        assert_eq!(
+            overrides.language(Path::new("repo/other/deploy.tmpl")),
+            None
+        );
+        assert_eq!(overrides.language(Path::new("repo/Jenkinsfile")), None);
+
+        let path = Path::new("repo/ci/deploy.tmpl");
+        assert!(PatternLanguage::Yaml.match_path(path, &overrides));
+        assert!(!PatternLanguage::Java.match_path(path, &overrides));
+        assert!(!PatternLanguage::Yaml.match_path(path, &LanguageOverrides::default()));
+    }
+}
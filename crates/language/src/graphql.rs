@@ -0,0 +1,250 @@
+use crate::language::{
+    check_disregarded_field_map, fields_for_nodes, kind_and_field_id_for_field_map, Field,
+    FieldExpectation, FieldExpectationCondition, MarzanoLanguage, NodeTypes, SortId, TSLanguage,
+};
+use grit_util::Language;
+use marzano_util::node_with_source::NodeWithSource;
+use std::sync::OnceLock;
+
+static NODE_TYPES_STRING: &str =
+    include_str!("../../../resources/node-types/graphql-node-types.json");
+static NODE_TYPES: OnceLock<Vec<Vec<Field>>> = OnceLock::new();
+static LANGUAGE: OnceLock<TSLanguage> = OnceLock::new();
+static DISREGARDED_SNIPPET_FIELDS: OnceLock<Vec<FieldExpectation>> = OnceLock::new();
+
+/// Optional parts of a definition or selection which a snippet matches
+/// regardless of their value when it leaves them out.
+fn graphql_disregarded_field_values() -> Vec<(&'static str, &'static str, FieldExpectationCondition)>
+{
+    let optional_fields: &[(&str, &[&str])] = &[
+        (
+            "field",
+            &["alias", "arguments", "directives", "selection_set"],
+        ),
+        ("operation_definition", &["name", "variables", "directives"]),
+        ("fragment_definition", &["directives"]),
+        ("fragment_spread", &["directives"]),
+        ("inline_fragment", &["type_condition", "directives"]),
+        ("directive", &["arguments"]),
+        ("variable_definition", &["default_value", "directives"]),
+        (
+            "field_definition",
+            &["description", "arguments", "directives"],
+        ),
+        (
+            "input_value_definition",
+            &["description", "default_value", "directives"],
+        ),
+        ("enum_value_definition", &["description", "directives"]),
+        ("scalar_type_definition", &["description", "directives"]),
+        (
+            "object_type_definition",
+            &["description", "interfaces", "directives", "fields"],
+        ),
+        (
+            "interface_type_definition",
+            &["description", "interfaces", "directives", "fields"],
+        ),
+        (
+            "union_type_definition",
+            &["description", "directives", "members"],
+        ),
+        (
+            "enum_type_definition",
+            &["description", "directives", "values"],
+        ),
+        (
+            "input_object_type_definition",
+            &["description", "directives", "fields"],
+        ),
+    ];
+    optional_fields
+        .iter()
+        .flat_map(|(kind, fields)| {
+            fields
+                .iter()
+                .map(|field| (*kind, *field, FieldExpectationCondition::OnlyIf(vec![""])))
+        })
+        .collect()
+}
+
+#[cfg(not(feature = "builtin-parser"))]
+fn language() -> TSLanguage {
+    unimplemented!(
+        "tree-sitter parser must be initialized before use when [builtin-parser] is off."
+    )
+}
+#[cfg(feature = "builtin-parser")]
+fn language() -> TSLanguage {
+    tree_sitter_graphql::language().into()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct GraphQL {
+    node_types: &'static [Vec<Field>],
+    metavariable_sort: SortId,
+    comment_sort: SortId,
+    language: &'static TSLanguage,
+    disregarded_snippet_fields: &'static Vec<FieldExpectation>,
+}
+
+impl NodeTypes for GraphQL {
+    fn node_types(&self) -> &[Vec<Field>] {
+        self.node_types
+    }
+}
+
+impl GraphQL {
+    pub(crate) fn new(lang: Option<TSLanguage>) -> Self {
+        let language = LANGUAGE.get_or_init(|| lang.unwrap_or_else(language));
+        let node_types = NODE_TYPES.get_or_init(|| fields_for_nodes(language, NODE_TYPES_STRING));
+        let metavariable_sort = language.id_for_node_kind("grit_metavariable", true);
+        let comment_sort = language.id_for_node_kind("comment", true);
+        let disregarded_snippet_fields = DISREGARDED_SNIPPET_FIELDS.get_or_init(|| {
+            kind_and_field_id_for_field_map(language, graphql_disregarded_field_values())
+        });
+        Self {
+            node_types,
+            metavariable_sort,
+            comment_sort,
+            language,
+            disregarded_snippet_fields,
+        }
+    }
+    pub(crate) fn is_initialized() -> bool {
+        LANGUAGE.get().is_some()
+    }
+}
+
+impl Language for GraphQL {
+    use_marzano_delegate!();
+
+    fn language_name(&self) -> &'static str {
+        "GraphQL"
+    }
+
+    fn snippet_context_strings(&self) -> &[(&'static str, &'static str)] {
+        &[
+            ("", ""),
+            ("{ ", " }"),
+            ("type GRIT_TYPE { ", " }"),
+            ("{ GRIT_FIELD(", ") }"),
+            ("enum GRIT_ENUM { ", " }"),
+            ("input GRIT_INPUT { ", " }"),
+            ("type GRIT_TYPE { GRIT_FIELD: ", " }"),
+        ]
+    }
+
+    fn comment_prefix(&self) -> &'static str {
+        "#"
+    }
+
+    fn make_single_line_comment(&self, text: &str) -> String {
+        format!("# {}\n", text)
+    }
+}
+
+impl<'a> MarzanoLanguage<'a> for GraphQL {
+    fn get_ts_language(&self) -> &TSLanguage {
+        self.language
+    }
+
+    fn is_disregarded_snippet_field(
+        &self,
+        sort_id: SortId,
+        field_id: crate::language::FieldId,
+        field_node: &Option<NodeWithSource<'_>>,
+    ) -> bool {
+        check_disregarded_field_map(
+            self.disregarded_snippet_fields,
+            sort_id,
+            field_id,
+            field_node,
+        )
+    }
+
+    fn is_comment_sort(&self, id: SortId) -> bool {
+        id == self.comment_sort
+    }
+
+    fn metavariable_sort(&self) -> SortId {
+        self.metavariable_sort
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::nodes_from_indices;
+    use grit_util::{Ast, AstNode, FileOrigin, Parser};
+    use std::path::Path;
+
+    #[test]
+    fn field_snippet() {
+        let snippet = "user(id: $id) { $fields }";
+        let lang = GraphQL::new(None);
+        let snippets = lang.parse_snippet_contexts(snippet);
+        let nodes = nodes_from_indices(&snippets);
+        assert!(!nodes.is_empty());
+    }
+
+    #[test]
+    fn field_definition_snippet() {
+        let snippet = "$name: $type @deprecated";
+        let lang = GraphQL::new(None);
+        let snippets = lang.parse_snippet_contexts(snippet);
+        let nodes = nodes_from_indices(&snippets);
+        assert!(!nodes.is_empty());
+    }
+
+    #[test]
+    fn type_definition_snippet() {
+        let snippet = "type $name implements Node { id: ID! }";
+        let lang = GraphQL::new(None);
+        let snippets = lang.parse_snippet_contexts(snippet);
+        let nodes = nodes_from_indices(&snippets);
+        assert!(!nodes.is_empty());
+    }
+
+    #[test]
+    fn parses_tagged_templates() {
+        let code = r#"
+const name = "user";
+const QUERY = gql`
+  query GetUser($id: ID!) {
+    user(id: $id) {
+      ${fields}
+      name
+    }
+  }
+`;
+const other = css`color: red;`;
+"#;
+        let lang = GraphQL::new(None);
+        let tree = lang
+            .get_parser()
+            .parse_file(
+                code,
+                Some(Path::new("test.ts")),
+                &mut vec![].into(),
+                FileOrigin::Fresh,
+            )
+            .unwrap();
+        let root = tree.root_node();
+        assert!(!root.node.has_error());
+        assert!(root.text().unwrap().contains("query GetUser"));
+    }
+
+    #[test]
+    fn skips_scripts_without_tagged_templates() {
+        let code = "const query = `{ user { name } }`;";
+        let lang = GraphQL::new(None);
+        let tree = lang.get_parser().parse_file(
+            code,
+            Some(Path::new("test.js")),
+            &mut vec![].into(),
+            FileOrigin::Fresh,
+        );
+        assert!(tree.is_none());
+    }
+}
@@ -0,0 +1,75 @@
+use crate::language::MarzanoLanguage;
+use anyhow::{anyhow, Result};
+use grit_util::{traverse, AstNode, Order};
+use marzano_util::{cursor_wrapper::CursorWrapper, node_with_source::NodeWithSource};
+use tree_sitter::{Parser, Range};
+
+/// Node kinds holding the embedded code of an element.
+static CODE_NODE_KINDS: &[&str] = &["raw_text", "frontmatter_js_block"];
+
+fn lang_attribute(element: &NodeWithSource) -> Option<String> {
+    let start_tag = element
+        .named_children()
+        .find(|n| n.node.kind() == "start_tag")?;
+    start_tag
+        .named_children()
+        .filter(|n| n.node.kind() == "attribute")
+        .find_map(|attribute| {
+            let mut children = attribute.named_children();
+            let name = children.next()?;
+            if name.node.kind() != "attribute_name" || name.text().ok()?.trim() != "lang" {
+                return None;
+            }
+            let value = children.next()?;
+            let value = if value.node.kind() == "quoted_attribute_value" {
+                value.named_children().next()?
+            } else {
+                value
+            };
+            Some(value.text().ok()?.trim().to_string())
+        })
+}
+
+fn append_code_range(
+    node: &NodeWithSource,
+    ranges: &mut Vec<Range>,
+    parent_node_kinds: &[&str],
+    name_array: Option<&[&str]>,
+) {
+    if !parent_node_kinds.contains(&node.node.kind().as_ref()) {
+        return;
+    }
+    // unlike vue, a missing lang attribute means the default language of the block
+    let is_matching_lang = match (lang_attribute(node), name_array) {
+        (Some(lang), Some(name_array)) => name_array.contains(&lang.as_str()),
+        _ => true,
+    };
+    if !is_matching_lang {
+        return;
+    }
+    if let Some(code) = node
+        .named_children()
+        .find(|n| CODE_NODE_KINDS.contains(&n.node.kind().as_ref()))
+    {
+        ranges.push(code.node.range())
+    }
+}
+
+/// Finds the ranges of code embedded in the given kinds of elements of an
+/// html-based component file, such as svelte or astro.
+pub(crate) fn get_html_like_ranges<'a>(
+    lang: &impl MarzanoLanguage<'a>,
+    file: &str,
+    parent_node_kinds: &[&str],
+    name_array: Option<&[&str]>,
+) -> Result<Vec<Range>> {
+    let mut parser = Parser::new()?;
+    parser.set_language(lang.get_ts_language())?;
+    let tree = parser.parse(file, None)?.ok_or(anyhow!("missing tree"))?;
+    let cursor = tree.walk();
+    let mut ranges = Vec::new();
+    for n in traverse(CursorWrapper::new(cursor, file), Order::Pre) {
+        append_code_range(&n, &mut ranges, parent_node_kinds, name_array)
+    }
+    Ok(ranges)
+}
@@ -0,0 +1,514 @@
+//! Languages embedded in other languages, such as SQL passed to a Python
+//! database cursor or CSS in a styled-components template.
+//!
+//! Embedded regions are found with queries in the format of tree-sitter's
+//! `injections.scm` files, run with tree-sitter's own query engine. Besides
+//! the predicates tree-sitter evaluates itself, the `injection.language` and
+//! `injection.terminator` properties are read from `#set!`. Other predicates,
+//! such as `#offset!`, are rejected.
+//!
+//! Only JavaScript, TypeScript and Python files are searched for embedded
+//! code. Other hosts, such as shell scripts in the `run:` steps of GitHub
+//! Actions workflows, need a language for the embedded code as well, and
+//! there is no shell language yet.
+
+use crate::{
+    language::{TSParser, Tree},
+    sourcemap::{EmbeddedSourceMap, SourceValueFormat},
+    target_language::PatternLanguage,
+};
+use anyhow::{bail, Result};
+use regex::Regex;
+use std::{ffi::OsStr, ops::Range, path::Path, sync::OnceLock};
+use tree_sitter_native::{
+    Language as NativeLanguage, Parser as NativeParser, Query, QueryCursor, QueryPredicateArg,
+};
+
+static JAVASCRIPT_INJECTIONS: &str = include_str!("../../../resources/injections/javascript.scm");
+static PYTHON_INJECTIONS: &str = include_str!("../../../resources/injections/python.scm");
+
+static TSX_QUERY: OnceLock<InjectionQuery> = OnceLock::new();
+static PYTHON_QUERY: OnceLock<InjectionQuery> = OnceLock::new();
+
+/// Extensions of the files searched for embedded code. Notebooks are left
+/// out, since they are not code in the host language as a whole.
+const HOST_EXTENSIONS: &[&str] = &[
+    "js", "jsx", "ts", "tsx", "cjs", "mjs", "cts", "mts", "py", "pyi",
+];
+
+const CONTENT_CAPTURE: &str = "injection.content";
+const LANGUAGE_CAPTURE: &str = "injection.language";
+const LANGUAGE_PROPERTY: &str = "injection.language";
+/// Appended to each embedded region that doesn't already end with it, so
+/// that regions joined together still parse, eg. `;` between SQL statements.
+const TERMINATOR_PROPERTY: &str = "injection.terminator";
+
+fn host_query(host: PatternLanguage) -> Option<&'static InjectionQuery> {
+    let (query, language, source): (_, fn() -> NativeLanguage, _) = match host {
+        PatternLanguage::Tsx => (
+            &TSX_QUERY,
+            tree_sitter_typescript::language_tsx,
+            JAVASCRIPT_INJECTIONS,
+        ),
+        PatternLanguage::Python | PatternLanguage::Python2 => (
+            &PYTHON_QUERY,
+            tree_sitter_python::language,
+            PYTHON_INJECTIONS,
+        ),
+        _ => return None,
+    };
+    Some(
+        query.get_or_init(|| {
+            InjectionQuery::new(language, source).expect("invalid injection query")
+        }),
+    )
+}
+
+fn host_for_extension(extension: &str) -> Option<PatternLanguage> {
+    if !HOST_EXTENSIONS.contains(&extension) {
+        return None;
+    }
+    PatternLanguage::from_extension(extension)
+}
+
+/// Whether files with `extension` may embed code in `language`, according to
+/// the injection rules of their own language.
+pub(crate) fn may_embed(extension: &str, language: PatternLanguage) -> bool {
+    host_for_extension(extension)
+        .filter(|host| *host != language)
+        .and_then(host_query)
+        .is_some_and(|query| query.may_inject(language.language_name()))
+}
+
+/// The extensions of the files which may embed code in `language`.
+pub(crate) fn embedding_extensions(
+    language: PatternLanguage,
+) -> impl Iterator<Item = &'static str> {
+    HOST_EXTENSIONS
+        .iter()
+        .copied()
+        .filter(move |extension| may_embed(extension, language))
+}
+
+fn is_language(name: &str, language_name: &str) -> bool {
+    PatternLanguage::from_string(name.trim(), None)
+        .is_some_and(|lang| lang.language_name() == language_name)
+}
+
+/// A file written in another language, which may embed code in the language
+/// being parsed.
+pub(crate) struct InjectionHost {
+    query: &'static InjectionQuery,
+}
+
+impl InjectionHost {
+    /// Returns the host for `path`, if its language has injection rules that
+    /// can produce code in the language named `language_name`.
+    pub(crate) fn for_file(path: &Path, language_name: &str) -> Option<Self> {
+        let extension = path.extension().and_then(OsStr::to_str)?;
+        let host = host_for_extension(extension)?;
+        let query = host_query(host)?;
+        if host.language_name() == language_name || !query.may_inject(language_name) {
+            return None;
+        }
+        Some(Self { query })
+    }
+
+    /// Parses the code embedded in `body` with `parser`.
+    ///
+    /// The embedded regions are joined into a single inner source, with a
+    /// source map to write rewrites back into the host file. Returns `None`
+    /// if the file doesn't embed any code in the language.
+    pub(crate) fn parse(
+        &self,
+        parser: &mut TSParser,
+        language_name: &str,
+        body: &str,
+    ) -> Option<Tree> {
+        // Most files embed nothing, so skip parsing those that can't
+        if !self.query.may_match(body, language_name) {
+            return None;
+        }
+        let injections = self.query.injections(body, language_name);
+        if injections.is_empty() {
+            return None;
+        }
+
+        let mut inner_source = String::new();
+        let mut source_map = EmbeddedSourceMap::new(body);
+        for injection in injections {
+            let content = &body[injection.range.clone()];
+            inner_source.push_str(content);
+            let mut trim = 0;
+            if let Some(terminator) = injection.terminator {
+                if !content.trim_end().ends_with(terminator) {
+                    inner_source.push_str(terminator);
+                    trim += terminator.len();
+                }
+            }
+            // Separate regions with a newline
+            inner_source.push('\n');
+            trim += 1;
+            source_map.new_section(
+                injection.range,
+                inner_source.len(),
+                SourceValueFormat::Raw,
+                trim,
+            );
+        }
+
+        let tree = parser.parse(&inner_source, None).ok()??;
+        let mut tree = Tree::new(tree, inner_source);
+        tree.source_map = Some(source_map);
+        Some(tree)
+    }
+}
+
+#[derive(Debug)]
+struct Injection<'q> {
+    range: Range<usize>,
+    terminator: Option<&'q str>,
+}
+
+/// An `injections.scm` query, compiled for the grammar of its host language.
+pub(crate) struct InjectionQuery {
+    language: fn() -> NativeLanguage,
+    query: Query,
+    patterns: Vec<InjectionPattern>,
+    content_capture: Option<u32>,
+    language_capture: Option<u32>,
+}
+
+#[derive(Debug, Default)]
+struct InjectionPattern {
+    language: Option<String>,
+    terminator: Option<String>,
+    /// Strings that `#eq?` and `#any-of?` compare captures against. When
+    /// there are any, a file can only match if it contains one of them, so
+    /// rules only use these predicates on captures that always match.
+    hints: Vec<String>,
+    /// `#any-of?` predicates left to us by tree-sitter, as the capture, the
+    /// values, and whether the predicate is negated.
+    any_of: Vec<(u32, Vec<String>, bool)>,
+}
+
+impl InjectionQuery {
+    pub(crate) fn new(language: fn() -> NativeLanguage, source: &str) -> Result<Self> {
+        let query = Query::new(language(), source)?;
+        let capture_index = |name: &str| {
+            query
+                .capture_names()
+                .iter()
+                .position(|capture| capture == name)
+                .map(|index| index as u32)
+        };
+        let content_capture = capture_index(CONTENT_CAPTURE);
+        let language_capture = capture_index(LANGUAGE_CAPTURE);
+
+        let mut patterns = Vec::with_capacity(query.pattern_count());
+        for index in 0..query.pattern_count() {
+            let mut pattern = InjectionPattern::default();
+            for property in query.property_settings(index) {
+                let value = property.value.as_deref().map(str::to_string);
+                match &*property.key {
+                    LANGUAGE_PROPERTY => pattern.language = value,
+                    TERMINATOR_PROPERTY => pattern.terminator = value,
+                    _ => {}
+                }
+            }
+            for predicate in query.general_predicates(index) {
+                let negated = match &*predicate.operator {
+                    "any-of?" => false,
+                    "not-any-of?" => true,
+                    operator => bail!("unsupported predicate `#{}` in injection query", operator),
+                };
+                let mut args = predicate.args.iter();
+                let Some(QueryPredicateArg::Capture(capture)) = args.next() else {
+                    bail!("invalid arguments to `#{}`", predicate.operator);
+                };
+                let values = args
+                    .map(|arg| match arg {
+                        QueryPredicateArg::String(value) => Ok(value.to_string()),
+                        QueryPredicateArg::Capture(_) => {
+                            bail!("invalid arguments to `#{}`", predicate.operator)
+                        }
+                    })
+                    .collect::<Result<_>>()?;
+                pattern.any_of.push((*capture, values, negated));
+            }
+            let end = if index + 1 < query.pattern_count() {
+                query.start_byte_for_pattern(index + 1)
+            } else {
+                source.len()
+            };
+            pattern.hints = literal_hints(&source[query.start_byte_for_pattern(index)..end]);
+            patterns.push(pattern);
+        }
+
+        Ok(Self {
+            language,
+            query,
+            patterns,
+            content_capture,
+            language_capture,
+        })
+    }
+
+    /// Whether any of the patterns can produce code in the given language.
+    fn may_inject(&self, language_name: &str) -> bool {
+        self.patterns.iter().any(|pattern| match &pattern.language {
+            Some(name) => is_language(name, language_name),
+            None => true,
+        })
+    }
+
+    /// Whether `source` contains what any pattern producing code in the
+    /// given language would need to match.
+    fn may_match(&self, source: &str, language_name: &str) -> bool {
+        self.patterns.iter().any(|pattern| {
+            pattern
+                .language
+                .as_ref()
+                .map_or(true, |name| is_language(name, language_name))
+                && (pattern.hints.is_empty()
+                    || pattern
+                        .hints
+                        .iter()
+                        .any(|hint| source.contains(hint.as_str())))
+        })
+    }
+
+    /// Finds the byte ranges of the code in `language_name` embedded in
+    /// `source`, in order and without overlaps.
+    fn injections(&self, source: &str, language_name: &str) -> Vec<Injection> {
+        let Some(content_capture) = self.content_capture else {
+            return Vec::new();
+        };
+        let mut parser = NativeParser::new();
+        if parser.set_language((self.language)()).is_err() {
+            return Vec::new();
+        }
+        let Some(tree) = parser.parse(source, None) else {
+            return Vec::new();
+        };
+
+        let mut injections = Vec::new();
+        let mut cursor = QueryCursor::new();
+        for query_match in cursor.matches(&self.query, tree.root_node(), source.as_bytes()) {
+            let pattern = &self.patterns[query_match.pattern_index];
+            let capture_text = |index: u32| {
+                query_match
+                    .captures
+                    .iter()
+                    .find(|capture| capture.index == index)
+                    .and_then(|capture| capture.node.utf8_text(source.as_bytes()).ok())
+            };
+            let passes_any_of = pattern.any_of.iter().all(|(capture, values, negated)| {
+                capture_text(*capture).map_or(true, |text| {
+                    values.iter().any(|value| value == text) != *negated
+                })
+            });
+            if !passes_any_of {
+                continue;
+            }
+            let language = match &pattern.language {
+                Some(name) => Some(name.as_str()),
+                None => self.language_capture.and_then(capture_text),
+            };
+            if !language.is_some_and(|name| is_language(name, language_name)) {
+                continue;
+            }
+            for capture in query_match
+                .captures
+                .iter()
+                .filter(|capture| capture.index == content_capture)
+            {
+                injections.push(Injection {
+                    range: capture.node.byte_range(),
+                    terminator: pattern.terminator.as_deref(),
+                });
+            }
+        }
+
+        // Nested and overlapping regions are left to the outermost one
+        injections.sort_by_key(|injection| injection.range.start);
+        let mut last_end = 0;
+        injections.retain(|injection| {
+            let keep = injection.range.start >= last_end && !injection.range.is_empty();
+            if keep {
+                last_end = injection.range.end;
+            }
+            keep
+        });
+        injections
+    }
+}
+
+/// Collects the strings compared against by the `#eq?` and `#any-of?`
+/// predicates in the source of a single pattern.
+fn literal_hints(pattern: &str) -> Vec<String> {
+    static PREDICATE: OnceLock<Regex> = OnceLock::new();
+    static STRING: OnceLock<Regex> = OnceLock::new();
+    let predicate = PREDICATE.get_or_init(|| {
+        Regex::new(r#"\(#(?:eq|any-of)\?\s+@[\w.-]+((?:\s+"(?:[^"\\]|\\.)*")+)\s*\)"#).unwrap()
+    });
+    let string = STRING.get_or_init(|| Regex::new(r#""((?:[^"\\]|\\.)*)""#).unwrap());
+    predicate
+        .captures_iter(pattern)
+        .flat_map(|captures| {
+            string
+                .captures_iter(captures.get(1).map_or("", |m| m.as_str()))
+                .map(|value| unescape(&value[1]))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn unescape(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('t') => unescaped.push('\t'),
+            Some(c) => unescaped.push(c),
+            None => {}
+        }
+    }
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::ValueEnum;
+
+    fn injected_code(
+        query: &str,
+        language: fn() -> NativeLanguage,
+        source: &str,
+        language_name: &str,
+    ) -> Vec<String> {
+        InjectionQuery::new(language, query)
+            .unwrap()
+            .injections(source, language_name)
+            .into_iter()
+            .map(|injection| source[injection.range].to_string())
+            .collect()
+    }
+
+    #[test]
+    fn parses_builtin_queries() {
+        assert!(host_query(PatternLanguage::Tsx).is_some());
+        assert!(host_query(PatternLanguage::Python).is_some());
+    }
+
+    #[test]
+    fn rejects_unsupported_predicates() {
+        let query = r#"((string) @injection.content (#lua-match? @injection.content "^SELECT"))"#;
+        assert!(InjectionQuery::new(tree_sitter_python::language, query).is_err());
+    }
+
+    #[test]
+    fn supports_anchors() {
+        let query = r#"((call arguments: (argument_list . (string (string_content) @injection.content))) (#set! injection.language "sql"))"#;
+        let source = r#"run("SELECT 1", "SELECT 2")"#;
+        let code = injected_code(query, tree_sitter_python::language, source, "SQL");
+        assert_eq!(code, vec!["SELECT 1"]);
+    }
+
+    #[test]
+    fn skips_files_without_hints() {
+        let query = host_query(PatternLanguage::Python).unwrap();
+        assert!(query.may_match("cursor.execute(sql)", "SQL"));
+        assert!(!query.may_match("print('SELECT 1')", "SQL"));
+        assert!(!query.may_match("cursor.execute(sql)", "CSS"));
+        assert_eq!(
+            literal_hints(r#"(#any-of? @_tag "css" "say \"hi\"") (#not-eq? @_tag "x")"#),
+            vec!["css", "say \"hi\""]
+        );
+    }
+
+    #[test]
+    fn routes_host_files_to_embedded_languages() {
+        assert!(may_embed("py", PatternLanguage::Sql));
+        assert!(may_embed("tsx", PatternLanguage::Css));
+        assert!(!may_embed("py", PatternLanguage::Css));
+        assert!(!may_embed("ts", PatternLanguage::Tsx));
+        assert!(!may_embed("ipynb", PatternLanguage::Sql));
+        let extensions: Vec<_> = embedding_extensions(PatternLanguage::Sql).collect();
+        assert_eq!(extensions, vec!["py", "pyi"]);
+        let sql = PatternLanguage::Sql.to_possible_value().unwrap();
+        assert!(!sql.matches("py", false));
+    }
+
+    #[test]
+    fn finds_sql_in_python() {
+        let source = r#"cursor.execute("SELECT * FROM users")
+print("SELECT 1")
+pd.read_sql("DELETE FROM users", conn)
+"#;
+        let code = injected_code(
+            PYTHON_INJECTIONS,
+            tree_sitter_python::language,
+            source,
+            "SQL",
+        );
+        assert_eq!(code, vec!["SELECT * FROM users", "DELETE FROM users"]);
+    }
+
+    #[test]
+    fn finds_css_in_styled_components() {
+        let source = r#"const Button = styled.button`
+  color: red;
+`;
+const Link = styled(Button)`color: blue;`;
+const label = html`<b>bold</b>`;
+"#;
+        let code = injected_code(
+            JAVASCRIPT_INJECTIONS,
+            tree_sitter_typescript::language_tsx,
+            source,
+            "CSS",
+        );
+        assert_eq!(code, vec!["\n  color: red;\n", "color: blue;"]);
+    }
+
+    #[test]
+    fn finds_graphql_in_tagged_templates() {
+        let source = r#"const QUERY = gql`
+  query { user { ${fields} name } }
+`;
+const label = `query { user }`;
+"#;
+        let code = injected_code(
+            JAVASCRIPT_INJECTIONS,
+            tree_sitter_typescript::language_tsx,
+            source,
+            "GraphQL",
+        );
+        assert_eq!(code, vec!["\n  query { user { ", " name } }\n"]);
+    }
+
+    #[test]
+    fn matches_alternations_and_dynamic_languages() {
+        let query = r#"
+            (call
+              function: [(identifier) (attribute)] @_function
+              arguments: (argument_list
+                (string (string_content) @injection.language)
+                (string (string_content) @injection.content))
+              (#match? @_function "^(db\\.)?run$"))
+        "#;
+        let source = r#"run("sql", "SELECT 1")
+db.run("css", "a { color: red; }")
+other("sql", "SELECT 2")
+"#;
+        let code = injected_code(query, tree_sitter_python::language, source, "SQL");
+        assert_eq!(code, vec!["SELECT 1"]);
+    }
+}
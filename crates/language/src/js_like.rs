@@ -1,14 +1,16 @@
 use crate::{
+    astro::get_astro_ranges,
     language::{
         FieldExpectationCondition, FieldExpectationCondition::Always,
         FieldExpectationCondition::OnlyIf, MarzanoLanguage, MarzanoParser, SortId, TSLanguage,
         Tree,
     },
+    svelte::get_svelte_ranges,
     vue::get_vue_ranges,
 };
 use grit_util::{AnalysisLogs, AstNode, FileOrigin, Parser, Replacement, SnippetTree};
 use marzano_util::node_with_source::NodeWithSource;
-use std::path::Path;
+use std::{ffi::OsStr, path::Path};
 
 static STATEMENT_NODE_NAMES: &[&str] = &[
     "break_statement",
@@ -122,23 +124,29 @@ impl Parser for MarzanoJsLikeParser {
         logs: &mut AnalysisLogs,
         old_tree: FileOrigin<'_, Tree>,
     ) -> Option<Tree> {
-        if path
-            .and_then(Path::extension)
-            .is_some_and(|ext| ext == "vue")
-        {
-            let js_name_array = ["js", "ts", "tsx", "jsx", "javascript", "typescript"];
-            let parent_node_kind = "script_element";
-            let ranges = get_vue_ranges(body, parent_node_kind, Some(&js_name_array)).ok()?;
+        let js_name_array = ["js", "ts", "tsx", "jsx", "javascript", "typescript"];
+        // svelte and astro components without code would otherwise be parsed as a whole
+        let ranges = match path.and_then(Path::extension).and_then(OsStr::to_str) {
+            Some("vue") => get_vue_ranges(body, "script_element", Some(&js_name_array)).ok()?,
+            Some("svelte") => get_svelte_ranges(body, &["script_element"], Some(&js_name_array))
+                .ok()
+                .filter(|ranges| !ranges.is_empty())?,
+            Some("astro") => get_astro_ranges(
+                body,
+                &["frontmatter", "script_element"],
+                Some(&js_name_array),
+            )
+            .ok()
+            .filter(|ranges| !ranges.is_empty())?,
+            _ => return self.0.parse_file(body, path, logs, old_tree),
+        };
 
-            self.0.parser.set_included_ranges(&ranges).ok()?;
-            self.0
-                .parser
-                .parse(body, None)
-                .ok()?
-                .map(|tree| Tree::new(tree, body))
-        } else {
-            self.0.parse_file(body, path, logs, old_tree)
-        }
+        self.0.parser.set_included_ranges(&ranges).ok()?;
+        self.0
+            .parser
+            .parse(body, None)
+            .ok()?
+            .map(|tree| Tree::new(tree, body))
     }
 
     fn parse_snippet(
@@ -261,4 +269,37 @@ defineProps<{
             .unwrap();
         print_node(&tree.root_node().node);
     }
+
+    #[test]
+    fn parses_svelte_and_astro_scripts() {
+        let svelte = r#"
+<script>
+    let count = 0;
+</script>
+
+<button on:click={() => count++}>{count}</button>
+"#;
+        let astro = r#"---
+import Layout from '../layouts/Layout.astro';
+const title = "Hello";
+---
+
+<Layout title={title}>
+    <h1>{title}</h1>
+</Layout>
+"#;
+        let ts = Tsx::new(None);
+        let mut parser = MarzanoJsLikeParser::new(&ts);
+        for (snippet, path) in [(svelte, "test.svelte"), (astro, "test.astro")] {
+            let tree = parser
+                .parse_file(
+                    snippet,
+                    Some(Path::new(path)),
+                    &mut vec![].into(),
+                    FileOrigin::Fresh,
+                )
+                .unwrap();
+            assert!(!tree.root_node().node.has_error());
+        }
+    }
 }
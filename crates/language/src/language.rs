@@ -10,6 +10,8 @@ use serde_json::Value;
 use std::{borrow::Cow, cmp::max, collections::HashMap, path::Path};
 pub(crate) use tree_sitter::{Language as TSLanguage, Parser as TSParser, Tree as TSTree};
 
+#[cfg(all(feature = "builtin-parser", not(target_arch = "wasm32")))]
+use crate::injections::InjectionHost;
 use crate::sourcemap::EmbeddedSourceMap;
 
 pub type SortId = u16;
@@ -235,6 +237,7 @@ impl PartialEq for Tree {
 
 pub struct MarzanoParser {
     pub(crate) parser: TSParser,
+    language_name: &'static str,
 }
 
 impl MarzanoParser {
@@ -243,7 +246,10 @@ impl MarzanoParser {
         parser
             .set_language(lang.get_ts_language())
             .expect("failed to set TreeSitter language");
-        Self { parser }
+        Self {
+            parser,
+            language_name: lang.language_name(),
+        }
     }
 }
 
@@ -257,6 +263,15 @@ impl Parser for MarzanoParser {
         logs: &mut AnalysisLogs,
         old_tree: FileOrigin<'_, Tree>,
     ) -> Option<Tree> {
+        // Files in other languages are searched for embedded code instead
+        #[cfg(all(feature = "builtin-parser", not(target_arch = "wasm32")))]
+        if let Some(host) = path
+            .filter(|_| old_tree.is_fresh())
+            .and_then(|path| InjectionHost::for_file(path, self.language_name))
+        {
+            return host.parse(&mut self.parser, self.language_name, body);
+        }
+
         let tree = self.parser.parse(body, None).ok()??;
 
         if let Some(path) = path {
@@ -524,16 +539,19 @@ fn file_parsing_error(
 
     for n in traverse(CursorWrapper::new(cursor, body), Order::Pre) {
         if n.node.is_error() || n.node.is_missing() {
-            let position = n.range().start;
+            let range = n.range();
             let message = format!(
-                "Error parsing source code at {position} in {}. This may cause \
-                otherwise applicable queries to not match.",
-                file_name.display()
+                "Error parsing source code at {} in {}. This may cause \
+                otherwise applicable queries to not match. {}.",
+                range.start,
+                file_name.display(),
+                describe_syntax_error(&n)
             );
             if let Ok(log) = log_builder
                 .clone()
                 .message(message)
-                .position(position)
+                .position(range.start)
+                .range(range)
                 .build()
             {
                 errors.push(log);
@@ -543,6 +561,19 @@ fn file_parsing_error(
     Ok(errors.into())
 }
 
+/// Describes an `ERROR` or `MISSING` node, along with the node it is in.
+fn describe_syntax_error(node: &NodeWithSource) -> String {
+    let description = if node.node.is_missing() {
+        format!("Missing `{}`", node.node.kind())
+    } else {
+        "Unexpected `ERROR` node".to_owned()
+    };
+    match node.parent() {
+        Some(parent) => format!("{description} in `{}`", parent.node.kind()),
+        None => description,
+    }
+}
+
 /// Returns the byte offset of `sub` relative to `sup`.
 ///
 /// This function assumes `sub` starts at or after `sup`, since it cannot return
@@ -56,36 +56,52 @@ macro_rules! use_marzano_delegate {
     };
 }
 
+pub mod astro;
 pub mod csharp;
 pub mod css;
+pub mod dart;
+pub mod detection;
 pub mod elixir;
 pub mod foreign_language;
 pub mod go;
+pub mod graphql;
 pub mod grit_parser;
 pub mod grit_ts_node;
 pub mod hcl;
 pub mod html;
+mod html_like;
+#[cfg(all(feature = "builtin-parser", not(target_arch = "wasm32")))]
+mod injections;
 pub mod java;
 pub mod javascript;
 mod js_like;
 pub mod json;
 pub mod kotlin;
 pub mod language;
+pub mod less;
+pub mod lua;
 pub mod markdown_block;
 pub mod markdown_inline;
+pub mod nix;
 mod notebooks;
 pub mod php;
 mod php_like;
 pub mod php_only;
+pub mod plugin;
+pub mod protobuf;
 pub mod python;
+pub mod python2;
 pub mod ruby;
 pub mod rust;
+pub mod scss;
 pub mod solidity;
 pub mod sourcemap;
 pub mod sql;
+pub mod svelte;
 pub mod target_language;
 pub mod toml;
 pub mod tsx;
 pub mod typescript;
 pub mod vue;
+pub mod xml;
 pub mod yaml;
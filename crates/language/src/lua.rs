@@ -0,0 +1,166 @@
+use crate::language::{
+    check_disregarded_field_map, fields_for_nodes, kind_and_field_id_for_field_map, Field,
+    FieldExpectation, FieldExpectationCondition, FieldExpectationCondition::OnlyIf,
+    MarzanoLanguage, NodeTypes, SortId, TSLanguage,
+};
+use grit_util::Language;
+use marzano_util::node_with_source::NodeWithSource;
+use std::sync::OnceLock;
+
+static NODE_TYPES_STRING: &str = include_str!("../../../resources/node-types/lua-node-types.json");
+static NODE_TYPES: OnceLock<Vec<Vec<Field>>> = OnceLock::new();
+static LANGUAGE: OnceLock<TSLanguage> = OnceLock::new();
+static DISREGARDED_SNIPPET_FIELDS: OnceLock<Vec<FieldExpectation>> = OnceLock::new();
+
+#[cfg(not(feature = "builtin-parser"))]
+fn language() -> TSLanguage {
+    unimplemented!(
+        "tree-sitter parser must be initialized before use when [builtin-parser] is off."
+    )
+}
+#[cfg(feature = "builtin-parser")]
+fn language() -> TSLanguage {
+    tree_sitter_lua::language().into()
+}
+
+fn lua_disregarded_field_values() -> Vec<(&'static str, &'static str, FieldExpectationCondition)> {
+    vec![
+        // disregarded if empty:
+        ("chunk", "hash_bang", OnlyIf(vec![""])),
+        ("for_numeric_clause", "step", OnlyIf(vec![""])),
+    ]
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Lua {
+    node_types: &'static [Vec<Field>],
+    metavariable_sort: SortId,
+    comment_sort: SortId,
+    language: &'static TSLanguage,
+    disregarded_snippet_fields: &'static Vec<FieldExpectation>,
+}
+
+impl NodeTypes for Lua {
+    fn node_types(&self) -> &[Vec<Field>] {
+        self.node_types
+    }
+}
+
+impl Lua {
+    pub(crate) fn new(lang: Option<TSLanguage>) -> Self {
+        let language = LANGUAGE.get_or_init(|| lang.unwrap_or_else(language));
+        let node_types = NODE_TYPES.get_or_init(|| fields_for_nodes(language, NODE_TYPES_STRING));
+        let metavariable_sort = language.id_for_node_kind("grit_metavariable", true);
+        let comment_sort = language.id_for_node_kind("comment", true);
+        let disregarded_snippet_fields = DISREGARDED_SNIPPET_FIELDS.get_or_init(|| {
+            kind_and_field_id_for_field_map(language, lua_disregarded_field_values())
+        });
+        Self {
+            node_types,
+            metavariable_sort,
+            comment_sort,
+            language,
+            disregarded_snippet_fields,
+        }
+    }
+    pub(crate) fn is_initialized() -> bool {
+        LANGUAGE.get().is_some()
+    }
+}
+
+impl Language for Lua {
+    use_marzano_delegate!();
+
+    fn language_name(&self) -> &'static str {
+        "Lua"
+    }
+
+    fn snippet_context_strings(&self) -> &[(&'static str, &'static str)] {
+        &[
+            ("", ""),
+            ("return ", ""),
+            ("local GRIT_VAR = { ", " }"),
+            ("GRIT_FUNCTION(", ")"),
+            ("function GRIT_FUNCTION() ", " end"),
+        ]
+    }
+
+    fn comment_prefix(&self) -> &'static str {
+        "--"
+    }
+
+    fn make_single_line_comment(&self, text: &str) -> String {
+        format!("-- {}\n", text)
+    }
+}
+
+impl<'a> MarzanoLanguage<'a> for Lua {
+    fn get_ts_language(&self) -> &TSLanguage {
+        self.language
+    }
+
+    fn is_disregarded_snippet_field(
+        &self,
+        sort_id: SortId,
+        field_id: crate::language::FieldId,
+        field_node: &Option<NodeWithSource<'_>>,
+    ) -> bool {
+        check_disregarded_field_map(
+            self.disregarded_snippet_fields,
+            sort_id,
+            field_id,
+            field_node,
+        )
+    }
+
+    fn is_comment_sort(&self, id: SortId) -> bool {
+        id == self.comment_sort
+    }
+
+    fn metavariable_sort(&self) -> SortId {
+        self.metavariable_sort
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::language::nodes_from_indices;
+
+    use super::*;
+
+    #[test]
+    fn statement_snippet() {
+        let snippet = "local $name = require($module)";
+        let lang = Lua::new(None);
+        let snippets = lang.parse_snippet_contexts(snippet);
+        let nodes = nodes_from_indices(&snippets);
+        assert!(!nodes.is_empty());
+    }
+
+    #[test]
+    fn expression_snippet() {
+        let snippet = "$a .. $b";
+        let lang = Lua::new(None);
+        let snippets = lang.parse_snippet_contexts(snippet);
+        let nodes = nodes_from_indices(&snippets);
+        assert!(!nodes.is_empty());
+    }
+
+    #[test]
+    fn table_field_snippet() {
+        let snippet = "$key = $value";
+        let lang = Lua::new(None);
+        let snippets = lang.parse_snippet_contexts(snippet);
+        let nodes = nodes_from_indices(&snippets);
+        assert!(!nodes.is_empty());
+    }
+
+    #[test]
+    fn function_body_snippet() {
+        let snippet = "function $name($params) $body end";
+        let lang = Lua::new(None);
+        let snippets = lang.parse_snippet_contexts(snippet);
+        let nodes = nodes_from_indices(&snippets);
+        assert!(!nodes.is_empty());
+    }
+}
@@ -0,0 +1,123 @@
+use crate::language::{fields_for_nodes, Field, MarzanoLanguage, NodeTypes, SortId, TSLanguage};
+use grit_util::Language;
+use std::sync::OnceLock;
+
+static NODE_TYPES_STRING: &str = include_str!("../../../resources/node-types/nix-node-types.json");
+static NODE_TYPES: OnceLock<Vec<Vec<Field>>> = OnceLock::new();
+static LANGUAGE: OnceLock<TSLanguage> = OnceLock::new();
+
+#[cfg(not(feature = "builtin-parser"))]
+fn language() -> TSLanguage {
+    unimplemented!(
+        "tree-sitter parser must be initialized before use when [builtin-parser] is off."
+    )
+}
+#[cfg(feature = "builtin-parser")]
+fn language() -> TSLanguage {
+    tree_sitter_nix::language().into()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Nix {
+    node_types: &'static [Vec<Field>],
+    metavariable_sort: SortId,
+    comment_sort: SortId,
+    language: &'static TSLanguage,
+}
+
+impl NodeTypes for Nix {
+    fn node_types(&self) -> &[Vec<Field>] {
+        self.node_types
+    }
+}
+
+impl Nix {
+    pub(crate) fn new(lang: Option<TSLanguage>) -> Self {
+        let language = LANGUAGE.get_or_init(|| lang.unwrap_or_else(language));
+        let node_types = NODE_TYPES.get_or_init(|| fields_for_nodes(language, NODE_TYPES_STRING));
+        let metavariable_sort = language.id_for_node_kind("grit_metavariable", true);
+        let comment_sort = language.id_for_node_kind("comment", true);
+        Self {
+            node_types,
+            metavariable_sort,
+            comment_sort,
+            language,
+        }
+    }
+    pub(crate) fn is_initialized() -> bool {
+        LANGUAGE.get().is_some()
+    }
+}
+
+impl Language for Nix {
+    use_marzano_delegate!();
+
+    fn language_name(&self) -> &'static str {
+        "Nix"
+    }
+
+    fn snippet_context_strings(&self) -> &[(&'static str, &'static str)] {
+        &[
+            ("", ""),
+            // bindings and `inherit`s only parse inside an attribute set
+            ("{ ", " }"),
+            ("[ ", " ]"),
+        ]
+    }
+
+    fn comment_prefix(&self) -> &'static str {
+        "#"
+    }
+
+    fn make_single_line_comment(&self, text: &str) -> String {
+        format!("# {}\n", text)
+    }
+}
+
+impl<'a> MarzanoLanguage<'a> for Nix {
+    fn get_ts_language(&self) -> &TSLanguage {
+        self.language
+    }
+
+    fn is_comment_sort(&self, id: SortId) -> bool {
+        id == self.comment_sort
+    }
+
+    fn metavariable_sort(&self) -> SortId {
+        self.metavariable_sort
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::language::nodes_from_indices;
+
+    use super::*;
+
+    #[test]
+    fn binding_snippet() {
+        let snippet = "inputs.nixpkgs.url = $url;";
+        let lang = Nix::new(None);
+        let snippets = lang.parse_snippet_contexts(snippet);
+        let nodes = nodes_from_indices(&snippets);
+        assert!(!nodes.is_empty());
+    }
+
+    #[test]
+    fn let_snippet() {
+        let snippet = "let $bindings in $body";
+        let lang = Nix::new(None);
+        let snippets = lang.parse_snippet_contexts(snippet);
+        let nodes = nodes_from_indices(&snippets);
+        assert!(!nodes.is_empty());
+    }
+
+    #[test]
+    fn application_snippet() {
+        let snippet = "pkgs.mkShell { buildInputs = $inputs; }";
+        let lang = Nix::new(None);
+        let snippets = lang.parse_snippet_contexts(snippet);
+        let nodes = nodes_from_indices(&snippets);
+        assert!(!nodes.is_empty());
+    }
+}
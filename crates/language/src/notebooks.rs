@@ -388,6 +388,136 @@ impl MarzanoNotebookParser {
                 tree
             })
     }
+
+    /// Parses a Jupytext percent-format script, where cells start with a
+    /// `# %%` marker. Markdown, raw and magic cells are skipped.
+    fn parse_file_as_percent_script(&mut self, body: &str) -> Option<Tree> {
+        let mut cells = Vec::new();
+        let mut cell_start = 0;
+        let mut is_code_cell = true;
+        let mut offset = 0;
+        for line in body.split_inclusive('\n') {
+            if let Some(marker) = percent_cell_marker(line) {
+                if is_code_cell && offset > cell_start {
+                    cells.push(cell_start..offset);
+                }
+                cell_start = offset + line.len();
+                is_code_cell = !["[markdown]", "[md]", "[raw]"]
+                    .iter()
+                    .any(|kind| marker.starts_with(kind));
+            }
+            offset += line.len();
+        }
+        if is_code_cell {
+            cells.push(cell_start..body.len());
+        }
+
+        // Jupytext comments out magics, so look at them without the comment
+        cells.retain(|cell| {
+            let lines = body[cell.clone()].lines().map(|line| {
+                line.strip_prefix("# ")
+                    .filter(|line| line.starts_with('%'))
+                    .unwrap_or(line)
+            });
+            !is_magic_cell(lines)
+        });
+        self.parse_cells(body, cells)
+    }
+
+    /// Parses the code chunks of an R Markdown or Quarto document, such as
+    /// ```` ```{python} ````. Chunks in other languages are skipped.
+    fn parse_file_as_chunked_document(&mut self, body: &str) -> Option<Tree> {
+        let mut cells = Vec::new();
+        let mut open_chunk: Option<(&str, bool, usize)> = None;
+        let mut offset = 0;
+        for line in body.split_inclusive('\n') {
+            match open_chunk {
+                Some((fence, is_code_chunk, start)) => {
+                    let trimmed = line.trim_end();
+                    if trimmed.len() >= fence.len() && trimmed.chars().all(|c| c == '`') {
+                        if is_code_chunk {
+                            cells.push(start..offset);
+                        }
+                        open_chunk = None;
+                    }
+                }
+                None => {
+                    if let Some((fence, language)) = chunk_header(line) {
+                        let is_code_chunk = language.eq_ignore_ascii_case(self.language);
+                        open_chunk = Some((fence, is_code_chunk, offset + line.len()));
+                    }
+                }
+            }
+            offset += line.len();
+        }
+        self.parse_cells(body, cells)
+    }
+
+    /// Parses the given ranges of `body` as a single program, mapping each
+    /// of them back into the document.
+    fn parse_cells(&mut self, body: &str, cells: Vec<std::ops::Range<usize>>) -> Option<Tree> {
+        if cells.is_empty() {
+            return None;
+        }
+        let mut inner_code_body = String::new();
+        let mut source_map = EmbeddedSourceMap::new(body);
+        for cell in cells {
+            let content = &body[cell.clone()];
+            inner_code_body.push_str(content);
+            // Separate cells with a newline
+            let mut trim = 0;
+            if !content.ends_with('\n') {
+                inner_code_body.push('\n');
+                trim = 1;
+            }
+            source_map.new_section(cell, inner_code_body.len(), SourceValueFormat::Raw, trim);
+        }
+
+        self.parser
+            .parser
+            .parse(inner_code_body.clone(), None)
+            .ok()?
+            .map(|tree| {
+                let mut tree = Tree::new(tree, inner_code_body);
+                tree.source_map = Some(source_map);
+                tree
+            })
+    }
+}
+
+/// Returns the text following a Jupytext percent cell marker, such as
+/// `[markdown]` in `# %% [markdown]`.
+fn percent_cell_marker(line: &str) -> Option<&str> {
+    let rest = line.strip_prefix("# %%")?;
+    (rest.is_empty() || rest.starts_with(char::is_whitespace)).then_some(rest.trim())
+}
+
+/// Whether a script starts with the YAML header Jupytext writes, such as
+/// `# ---` followed by `# jupyter:`.
+fn has_jupytext_header(body: &str) -> bool {
+    let mut lines = body.lines().skip_while(|line| line.starts_with("#!"));
+    lines.next().is_some_and(|line| line.trim_end() == "# ---")
+        && lines
+            .take_while(|line| line.starts_with('#') && line.trim_end() != "# ---")
+            .any(|line| matches!(line.trim_end(), "# jupyter:" | "#   jupytext:"))
+}
+
+/// Returns the fence and the language of an R Markdown or Quarto chunk
+/// header, such as ```` ```{python, echo=FALSE} ```` or ```` ```{.python} ````.
+fn chunk_header(line: &str) -> Option<(&str, &str)> {
+    let line = line.trim_end();
+    let fence_len = line.len() - line.trim_start_matches('`').len();
+    if fence_len < 3 {
+        return None;
+    }
+    let (fence, info) = line.split_at(fence_len);
+    let info = info.trim().strip_prefix('{')?.strip_suffix('}')?;
+    let language = info
+        .trim_start()
+        .trim_start_matches('.')
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .next()?;
+    Some((fence, language))
 }
 
 impl grit_util::Parser for MarzanoNotebookParser {
@@ -400,11 +530,8 @@ impl grit_util::Parser for MarzanoNotebookParser {
         logs: &mut AnalysisLogs,
         old_tree: FileOrigin<'_, Tree>,
     ) -> Option<Tree> {
-        if path
-            .and_then(Path::extension)
-            .is_some_and(|ext| ext == "ipynb")
-            && old_tree.is_fresh()
-        {
+        let extension = path.and_then(Path::extension).and_then(|ext| ext.to_str());
+        if extension == Some("ipynb") && old_tree.is_fresh() {
             let tree = self.parse_file_as_notebook(body, path, logs);
             if let Some(tree) = tree {
                 return Some(tree);
@@ -415,6 +542,34 @@ impl grit_util::Parser for MarzanoNotebookParser {
             }
         }
 
+        if matches!(extension, Some("Rmd" | "rmd" | "qmd")) && old_tree.is_fresh() {
+            // Documents without chunks in the language have nothing to parse
+            return self
+                .parse_file_as_chunked_document(body)
+                .or_else(|| self.parser.parse_file("", path, logs, old_tree));
+        }
+
+        if extension == Some("py")
+            && old_tree.is_fresh()
+            && body.lines().any(|line| percent_cell_marker(line).is_some())
+        {
+            // `# %%` is also a common comment in plain scripts, so only read
+            // cells from Jupytext scripts or when the script itself won't parse
+            if has_jupytext_header(body) {
+                if let Some(tree) = self.parse_file_as_percent_script(body) {
+                    return Some(tree);
+                }
+            }
+            let tree = self.parser.parse_file(body, path, logs, old_tree);
+            if !tree
+                .as_ref()
+                .is_some_and(|tree| tree.root_node().node.has_error())
+            {
+                return tree;
+            }
+            return self.parse_file_as_percent_script(body).or(tree);
+        }
+
         self.parser.parse_file(body, path, logs, old_tree)
     }
 
@@ -454,4 +609,49 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn chunk_headers() {
+        assert_eq!(chunk_header("```{python}\n"), Some(("```", "python")));
+        assert_eq!(
+            chunk_header("```{python, echo=FALSE}"),
+            Some(("```", "python"))
+        );
+        assert_eq!(chunk_header("````{.python}"), Some(("````", "python")));
+        assert_eq!(chunk_header("```{r setup}"), Some(("```", "r")));
+        assert_eq!(chunk_header("```python"), None);
+        assert_eq!(chunk_header("`{python}`"), None);
+    }
+
+    #[test]
+    fn percent_cell_markers() {
+        assert_eq!(percent_cell_marker("# %%\n"), Some(""));
+        assert_eq!(percent_cell_marker("# %% [markdown]"), Some("[markdown]"));
+        assert_eq!(percent_cell_marker("# %%bash"), None);
+        assert_eq!(percent_cell_marker("#%%"), None);
+    }
+
+    #[test]
+    fn jupytext_headers() {
+        let header = "# ---\n# jupyter:\n#   jupytext:\n#     formats: ipynb,py:percent\n# ---\n\n# %%\nx = 1\n";
+        assert!(has_jupytext_header(header));
+        assert!(!has_jupytext_header("# %%\nx = 1\n"));
+        assert!(!has_jupytext_header("# ---\n# title: notes\n# ---\n"));
+    }
+
+    #[test]
+    fn plain_scripts_with_cell_comments() {
+        let code = "x = 1\n# %% [markdown]\ny = 2\n";
+        let mut parser = MarzanoNotebookParser::new(&Python::new(None), "python");
+        let tree = parser
+            .parse_file(
+                code,
+                Some(Path::new("script.py")),
+                &mut AnalysisLogs::default(),
+                FileOrigin::Fresh,
+            )
+            .unwrap();
+        assert!(tree.source_map.is_none());
+        assert_eq!(tree.source, code);
+    }
 }
@@ -0,0 +1,604 @@
+use crate::{
+    language::{fields_for_nodes, Field, MarzanoLanguage, NodeTypes, SortId, TSLanguage},
+    target_language::PatternLanguage,
+};
+use anyhow::{anyhow, bail, Context, Result};
+use grit_util::Language;
+use marzano_util::node_with_source::NodeWithSource;
+use regex::Regex;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+    sync::RwLock,
+};
+
+/// Languages registered at runtime, indexed by their `PluginId`.
+///
+/// Entries are leaked on registration, since the language structs handed to
+/// the engine need `'static` data, just like the builtin languages. Each entry
+/// owns the library its grammar was loaded from, so grammar libraries stay
+/// loaded for the rest of the process, even after their entry is replaced:
+/// `PluginLanguage` values handed out earlier may still point at them. A
+/// language registered again under the same name keeps its slot, so the list
+/// only grows with new names.
+static PLUGIN_LANGUAGES: RwLock<Vec<&'static PluginLanguageInfo>> = RwLock::new(Vec::new());
+
+/// Configuration for a language whose tree-sitter grammar is loaded at
+/// runtime, as listed under `languages` in `.grit/grit.yaml`.
+///
+/// The grammar must define a `grit_metavariable` node matching identifiers
+/// prefixed with `µ`, like the grammars in `resources/language-metavariables`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginLanguageConfig {
+    /// Name used to refer to the language in pattern headers, e.g. `language mydsl`.
+    pub name: String,
+    /// Path to the compiled grammar: a shared library (`.so`, `.dylib`,
+    /// `.dll`), or a `.wasm` file when running in the browser.
+    pub grammar: PathBuf,
+    /// Path to the grammar's `node-types.json`.
+    pub node_types: PathBuf,
+    /// Name of the exported language function. Defaults to `tree_sitter_<name>`.
+    pub symbol: Option<String>,
+    /// File extensions (without the leading dot) handled by the language.
+    pub extensions: Vec<String>,
+    #[serde(default = "default_comment_prefix")]
+    pub comment_prefix: String,
+    #[serde(default = "default_metavariable_prefix")]
+    pub metavariable_prefix: String,
+    /// Prefix/suffix pairs snippets are wrapped in before parsing.
+    #[serde(default = "default_snippet_contexts")]
+    pub snippet_contexts: Vec<(String, String)>,
+}
+
+fn default_comment_prefix() -> String {
+    "//".to_string()
+}
+
+fn default_metavariable_prefix() -> String {
+    "$".to_string()
+}
+
+fn default_snippet_contexts() -> Vec<(String, String)> {
+    vec![(String::new(), String::new())]
+}
+
+impl PluginLanguageConfig {
+    /// Resolves relative grammar and node types paths against `base_dir`.
+    pub fn with_base_dir(mut self, base_dir: &Path) -> Self {
+        if self.grammar.is_relative() {
+            self.grammar = base_dir.join(&self.grammar);
+        }
+        if self.node_types.is_relative() {
+            self.node_types = base_dir.join(&self.node_types);
+        }
+        self
+    }
+
+    fn symbol(&self) -> String {
+        self.symbol
+            .clone()
+            .unwrap_or_else(|| format!("tree_sitter_{}", self.name.replace('-', "_")))
+    }
+}
+
+/// Identifies a registered plugin language.
+///
+/// Serialized by name, so it remains stable across runs regardless of the
+/// order in which languages get registered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PluginId(u16);
+
+impl PluginId {
+    pub fn from_name(name: &str) -> Option<Self> {
+        registered()
+            .into_iter()
+            .find(|p| p.name == name)
+            .map(|p| p.id)
+    }
+
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        registered()
+            .into_iter()
+            .find(|p| p.extensions.contains(&extension))
+            .map(|p| p.id)
+    }
+
+    pub fn name(self) -> &'static str {
+        self.info().name
+    }
+
+    pub fn extensions(self) -> &'static [&'static str] {
+        self.info().extensions
+    }
+
+    pub fn comment_prefix(self) -> &'static str {
+        self.info().comment_prefix
+    }
+
+    pub fn language(self) -> PluginLanguage {
+        PluginLanguage { info: self.info() }
+    }
+
+    fn info(self) -> &'static PluginLanguageInfo {
+        // ids are only handed out by the registry, which never shrinks
+        PLUGIN_LANGUAGES.read().unwrap()[self.0 as usize]
+    }
+}
+
+impl fmt::Display for PluginId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Serialize for PluginId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for PluginId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Self::from_name(&name)
+            .ok_or_else(|| serde::de::Error::custom(format!("unknown plugin language: {}", name)))
+    }
+}
+
+/// Returns the ids of all plugin languages registered so far.
+pub fn registered_plugins() -> Vec<PluginId> {
+    registered().into_iter().map(|p| p.id).collect()
+}
+
+fn registered() -> Vec<&'static PluginLanguageInfo> {
+    PLUGIN_LANGUAGES.read().unwrap().clone()
+}
+
+/// Loads the grammar described by `config` and registers it as a language.
+///
+/// Registering the same configuration again returns the existing id without
+/// loading the grammar twice, so a configuration may safely be read more than
+/// once. A changed configuration for a known name replaces the previous one.
+///
+/// Grammars are native code, so callers must only pass configuration the user
+/// trusts, such as the repository's own `.grit/grit.yaml`.
+pub fn register_plugin_language(config: PluginLanguageConfig) -> Result<PluginId> {
+    if let Some(existing) = registered().into_iter().find(|p| p.config == config) {
+        return Ok(existing.id);
+    }
+    let node_types = std::fs::read_to_string(&config.node_types).with_context(|| {
+        format!(
+            "failed to read node types for language {} from {}",
+            config.name,
+            config.node_types.display()
+        )
+    })?;
+    let (language, library) = load_grammar(&config.grammar, &config.symbol())?;
+    register(config, language, Some(library), &node_types)
+}
+
+/// Registers a language for a grammar that has already been loaded, such as
+/// a WebAssembly grammar loaded through web-tree-sitter.
+pub fn register_plugin_language_with_ts_lang(
+    config: PluginLanguageConfig,
+    language: TSLanguage,
+    node_types: &str,
+) -> Result<PluginId> {
+    register(config, language, None, node_types)
+}
+
+fn register(
+    config: PluginLanguageConfig,
+    language: TSLanguage,
+    library: Option<GrammarLibrary>,
+    node_types: &str,
+) -> Result<PluginId> {
+    if is_builtin_name(&config.name) {
+        bail!(
+            "plugin language {} conflicts with a builtin language",
+            config.name
+        );
+    }
+    if let Some(extension) = config
+        .extensions
+        .iter()
+        .find(|ext| is_builtin_extension(ext.trim_start_matches('.')))
+    {
+        bail!(
+            "plugin language {} cannot claim .{}, which belongs to a builtin language",
+            config.name,
+            extension.trim_start_matches('.')
+        );
+    }
+    if config.extensions.is_empty() {
+        bail!(
+            "plugin language {} must declare at least one file extension",
+            config.name
+        );
+    }
+    if config.snippet_contexts.is_empty() {
+        bail!(
+            "plugin language {} must declare at least one snippet context",
+            config.name
+        );
+    }
+    serde_json::from_str::<serde_json::Value>(node_types)
+        .with_context(|| format!("invalid node types for language {}", config.name))?;
+
+    let mut languages = PLUGIN_LANGUAGES.write().unwrap();
+    if let Some(other) = languages.iter().find(|p| {
+        p.name != config.name
+            && config
+                .extensions
+                .iter()
+                .any(|ext| p.extensions.contains(&ext.trim_start_matches('.')))
+    }) {
+        bail!(
+            "plugin language {} shares a file extension with plugin language {}",
+            config.name,
+            other.name
+        );
+    }
+    let slot = languages.iter().position(|p| p.name == config.name);
+    let id = PluginId(
+        slot.unwrap_or(languages.len())
+            .try_into()
+            .map_err(|_| anyhow!("too many plugin languages registered"))?,
+    );
+    let info: &'static PluginLanguageInfo = Box::leak(Box::new(PluginLanguageInfo::new(
+        id, config, language, library, node_types,
+    )?));
+    match slot {
+        Some(slot) => languages[slot] = info,
+        None => languages.push(info),
+    }
+    Ok(id)
+}
+
+/// The shared library a grammar was loaded from. Its parse tables back the
+/// loaded `TSLanguage`, so it must stay loaded for as long as the language.
+#[cfg(all(feature = "plugin-loader", not(target_arch = "wasm32")))]
+type GrammarLibrary = libloading::Library;
+
+#[cfg(not(all(feature = "plugin-loader", not(target_arch = "wasm32"))))]
+type GrammarLibrary = ();
+
+#[cfg(all(feature = "plugin-loader", not(target_arch = "wasm32")))]
+fn load_grammar(path: &Path, symbol: &str) -> Result<(TSLanguage, GrammarLibrary)> {
+    if path.extension().is_some_and(|ext| ext == "wasm") {
+        bail!(
+            "{} is a WebAssembly grammar, which can only be loaded in the browser; compile the grammar to a shared library instead",
+            path.display()
+        );
+    }
+    // SAFETY: loading a library runs its initializers, and calling the symbol
+    // runs its code, with no way to check either. Grammars are only registered
+    // from configuration the user trusts (see `register_plugin_language`), and
+    // the configured symbol must be an `extern "C" fn() -> Language`, like the
+    // `tree_sitter_<name>` functions tree-sitter generates. The symbol name is
+    // user-configurable, so we cannot verify this: calling a symbol with any
+    // other signature is undefined behavior. The returned language points into
+    // the library, which the registry entry keeps loaded.
+    let library = unsafe { libloading::Library::new(path) }
+        .with_context(|| format!("failed to load grammar from {}", path.display()))?;
+    let language = unsafe {
+        let constructor: libloading::Symbol<
+            unsafe extern "C" fn() -> tree_sitter_native::Language,
+        > = library
+            .get(symbol.as_bytes())
+            .with_context(|| format!("grammar {} does not export {}", path.display(), symbol))?;
+        constructor()
+    };
+    Ok((language.into(), library))
+}
+
+#[cfg(not(all(feature = "plugin-loader", not(target_arch = "wasm32"))))]
+fn load_grammar(path: &Path, _symbol: &str) -> Result<(TSLanguage, GrammarLibrary)> {
+    bail!(
+        "cannot load grammar from {}: loading grammars from files requires the [plugin-loader] feature",
+        path.display()
+    )
+}
+
+// plugins must not shadow the builtin languages, nor their extensions or aliases
+fn is_builtin_name(name: &str) -> bool {
+    PatternLanguage::from_string(name, None)
+        .is_some_and(|lang| !matches!(lang, PatternLanguage::Plugin(_)))
+}
+
+fn is_builtin_extension(extension: &str) -> bool {
+    PatternLanguage::from_extension(extension)
+        .is_some_and(|lang| !matches!(lang, PatternLanguage::Plugin(_)))
+}
+
+#[derive(Debug)]
+struct PluginLanguageInfo {
+    id: PluginId,
+    config: PluginLanguageConfig,
+    name: &'static str,
+    extensions: &'static [&'static str],
+    comment_prefix: &'static str,
+    metavariable_prefix: &'static str,
+    snippet_contexts: &'static [(&'static str, &'static str)],
+    language: TSLanguage,
+    /// Never dropped, since entries are leaked; see `PLUGIN_LANGUAGES`.
+    _library: Option<GrammarLibrary>,
+    node_types: Vec<Vec<Field>>,
+    metavariable_sort: SortId,
+    comment_sort: SortId,
+    metavariable_regex: Regex,
+    metavariable_bracket_regex: Regex,
+    exact_variable_regex: Regex,
+}
+
+impl PluginLanguageInfo {
+    fn new(
+        id: PluginId,
+        config: PluginLanguageConfig,
+        language: TSLanguage,
+        library: Option<GrammarLibrary>,
+        node_types: &str,
+    ) -> Result<Self> {
+        let registered_config = config.clone();
+        let metavariable_sort = language.id_for_node_kind("grit_metavariable", true);
+        if metavariable_sort == 0 {
+            bail!(
+                "grammar for language {} does not define a grit_metavariable node",
+                config.name
+            );
+        }
+        let comment_sort = language.id_for_node_kind("comment", true);
+        let node_types = fields_for_nodes(&language, node_types);
+
+        let prefix = regex::escape(&config.metavariable_prefix);
+        let metavariable_regex = Regex::new(&format!(r"{prefix}(\.\.\.|[A-Za-z_][A-Za-z0-9_]*)"))?;
+        let metavariable_bracket_regex =
+            Regex::new(&format!(r"{prefix}\[([A-Za-z_][A-Za-z0-9_]*)\]"))?;
+        let exact_variable_regex = Regex::new(&format!(r"^{prefix}([A-Za-z_][A-Za-z0-9_]*)$"))?;
+
+        let extensions: Vec<&'static str> = config
+            .extensions
+            .into_iter()
+            .map(|ext| leak(ext.trim_start_matches('.').to_string()))
+            .collect();
+        let snippet_contexts: Vec<(&'static str, &'static str)> = config
+            .snippet_contexts
+            .into_iter()
+            .map(|(prefix, suffix)| (leak(prefix), leak(suffix)))
+            .collect();
+
+        Ok(Self {
+            id,
+            config: registered_config,
+            name: leak(config.name),
+            extensions: Box::leak(extensions.into_boxed_slice()),
+            comment_prefix: leak(config.comment_prefix),
+            metavariable_prefix: leak(config.metavariable_prefix),
+            snippet_contexts: Box::leak(snippet_contexts.into_boxed_slice()),
+            language,
+            _library: library,
+            node_types,
+            metavariable_sort,
+            comment_sort,
+            metavariable_regex,
+            metavariable_bracket_regex,
+            exact_variable_regex,
+        })
+    }
+}
+
+fn leak(s: String) -> &'static str {
+    Box::leak(s.into_boxed_str())
+}
+
+/// A language backed by a grammar that was registered at runtime.
+#[derive(Debug, Clone, Copy)]
+pub struct PluginLanguage {
+    info: &'static PluginLanguageInfo,
+}
+
+impl PluginLanguage {
+    pub fn id(&self) -> PluginId {
+        self.info.id
+    }
+
+    pub fn ts_language(&self) -> &'static TSLanguage {
+        &self.info.language
+    }
+}
+
+impl NodeTypes for PluginLanguage {
+    fn node_types(&self) -> &[Vec<Field>] {
+        &self.info.node_types
+    }
+}
+
+impl Language for PluginLanguage {
+    use_marzano_delegate!();
+
+    fn language_name(&self) -> &'static str {
+        self.info.name
+    }
+
+    fn snippet_context_strings(&self) -> &[(&'static str, &'static str)] {
+        self.info.snippet_contexts
+    }
+
+    fn metavariable_prefix(&self) -> &'static str {
+        self.info.metavariable_prefix
+    }
+
+    fn comment_prefix(&self) -> &'static str {
+        self.info.comment_prefix
+    }
+
+    fn metavariable_regex(&self) -> &'static Regex {
+        &self.info.metavariable_regex
+    }
+
+    fn metavariable_bracket_regex(&self) -> &'static Regex {
+        &self.info.metavariable_bracket_regex
+    }
+
+    fn exact_variable_regex(&self) -> &'static Regex {
+        &self.info.exact_variable_regex
+    }
+
+    fn make_single_line_comment(&self, text: &str) -> String {
+        format!("{} {}\n", self.info.comment_prefix, text)
+    }
+}
+
+impl<'a> MarzanoLanguage<'a> for PluginLanguage {
+    fn get_ts_language(&self) -> &TSLanguage {
+        &self.info.language
+    }
+
+    fn is_comment_sort(&self, id: SortId) -> bool {
+        id == self.info.comment_sort
+    }
+
+    fn metavariable_sort(&self) -> SortId {
+        self.info.metavariable_sort
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::language::nodes_from_indices;
+    use serde_json::json;
+
+    use super::*;
+
+    fn register_toml_as(name: &str, extension: &str) -> PluginId {
+        let config: PluginLanguageConfig = serde_json::from_value(json!({
+            "name": name,
+            "grammar": format!("grammars/{name}.so"),
+            "nodeTypes": format!("grammars/{name}-node-types.json"),
+            "extensions": [extension],
+            "commentPrefix": "#",
+            "snippetContexts": [["", ""], ["GRIT_KEY = ", ""]],
+        }))
+        .unwrap();
+        register_plugin_language_with_ts_lang(
+            config,
+            tree_sitter_toml::language().into(),
+            include_str!("../../../resources/node-types/toml-node-types.json"),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn parses_config_defaults() {
+        let config: PluginLanguageConfig = serde_json::from_value(json!({
+            "name": "my-dsl",
+            "grammar": "grammars/my-dsl.so",
+            "nodeTypes": "grammars/node-types.json",
+            "extensions": ["dsl"],
+        }))
+        .unwrap();
+        assert_eq!(config.comment_prefix, "//");
+        assert_eq!(config.metavariable_prefix, "$");
+        assert_eq!(
+            config.snippet_contexts,
+            vec![(String::new(), String::new())]
+        );
+        assert_eq!(config.symbol(), "tree_sitter_my_dsl");
+        let config = config.with_base_dir(Path::new("/repo/.grit"));
+        assert_eq!(
+            config.grammar,
+            PathBuf::from("/repo/.grit/grammars/my-dsl.so")
+        );
+    }
+
+    #[test]
+    fn resolves_registered_language() {
+        let id = register_toml_as("plugintoml", "ptoml");
+        assert_eq!(register_toml_as("plugintoml", "ptoml"), id);
+        assert_eq!(
+            PatternLanguage::from_string("plugintoml", None),
+            Some(PatternLanguage::Plugin(id))
+        );
+        assert_eq!(
+            PatternLanguage::from_extension("ptoml"),
+            Some(PatternLanguage::Plugin(id))
+        );
+        assert_eq!(PatternLanguage::Plugin(id).to_string(), "plugintoml");
+    }
+
+    #[test]
+    fn plugin_snippets() {
+        let id = register_toml_as("snippettoml", "stoml");
+        let lang = id.language();
+        let snippets = lang.parse_snippet_contexts("$key = $value");
+        let nodes = nodes_from_indices(&snippets);
+        assert!(!nodes.is_empty());
+        let snippets = lang.parse_snippet_contexts("\"1.0.0\"");
+        let nodes = nodes_from_indices(&snippets);
+        assert!(!nodes.is_empty());
+    }
+
+    #[test]
+    fn rejects_builtin_names() {
+        let config = PluginLanguageConfig {
+            name: "toml".to_string(),
+            grammar: PathBuf::from("toml.so"),
+            node_types: PathBuf::from("node-types.json"),
+            symbol: None,
+            extensions: vec!["toml".to_string()],
+            comment_prefix: "#".to_string(),
+            metavariable_prefix: "$".to_string(),
+            snippet_contexts: default_snippet_contexts(),
+        };
+        assert!(register_plugin_language_with_ts_lang(
+            config,
+            tree_sitter_toml::language().into(),
+            include_str!("../../../resources/node-types/toml-node-types.json"),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn rejects_builtin_extensions() {
+        let config = PluginLanguageConfig {
+            name: "mytoml".to_string(),
+            grammar: PathBuf::from("toml.so"),
+            node_types: PathBuf::from("node-types.json"),
+            symbol: None,
+            extensions: vec![".py".to_string()],
+            comment_prefix: "#".to_string(),
+            metavariable_prefix: "$".to_string(),
+            snippet_contexts: default_snippet_contexts(),
+        };
+        assert!(register_plugin_language_with_ts_lang(
+            config,
+            tree_sitter_toml::language().into(),
+            include_str!("../../../resources/node-types/toml-node-types.json"),
+        )
+        .is_err());
+        assert_eq!(
+            PatternLanguage::from_extension("py"),
+            Some(PatternLanguage::Python)
+        );
+    }
+
+    #[test]
+    fn rejects_shared_plugin_extensions() {
+        register_toml_as("firsttoml", "sharedtoml");
+        let config: PluginLanguageConfig = serde_json::from_value(json!({
+            "name": "secondtoml",
+            "grammar": "grammars/secondtoml.so",
+            "nodeTypes": "grammars/secondtoml-node-types.json",
+            "extensions": ["sharedtoml"],
+        }))
+        .unwrap();
+        assert!(register_plugin_language_with_ts_lang(
+            config,
+            tree_sitter_toml::language().into(),
+            include_str!("../../../resources/node-types/toml-node-types.json"),
+        )
+        .is_err());
+    }
+}
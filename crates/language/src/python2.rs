@@ -0,0 +1,118 @@
+use crate::{
+    language::{Field, MarzanoLanguage, NodeTypes, SortId, TSLanguage, Tree},
+    python::Python,
+};
+use grit_util::{CodeRange, Language, Parser, Replacement};
+use marzano_util::node_with_source::NodeWithSource;
+
+/// Python 2, selected with `language python(2)`.
+///
+/// The Python grammar also understands `print` and `exec` statements, so
+/// this reuses it, along with snippet contexts for statement arguments.
+/// Other syntax removed in Python 3, such as `except Error, e:` or backtick
+/// repr, still parses with errors.
+#[derive(Debug, Clone, Copy)]
+pub struct Python2(Python);
+
+impl Python2 {
+    pub(crate) fn new(lang: Option<TSLanguage>) -> Self {
+        Self(Python::new(lang))
+    }
+    pub(crate) fn is_initialized() -> bool {
+        Python::is_initialized()
+    }
+}
+
+impl NodeTypes for Python2 {
+    fn node_types(&self) -> &[Vec<Field>] {
+        self.0.node_types()
+    }
+}
+
+impl Language for Python2 {
+    use_marzano_delegate!();
+
+    fn language_name(&self) -> &'static str {
+        "Python2"
+    }
+
+    fn snippet_context_strings(&self) -> &[(&'static str, &'static str)] {
+        &[
+            ("", ""),
+            ("{ ", " }"),
+            ("", "\ndef GRIT_FUNCTION():\n    return;"),
+            ("GRIT_FN(", ")"),
+            ("print ", ""),
+            ("exec ", ""),
+        ]
+    }
+
+    fn comment_prefix(&self) -> &'static str {
+        "#"
+    }
+
+    fn check_replacements(&self, n: NodeWithSource<'_>, replacements: &mut Vec<Replacement>) {
+        self.0.check_replacements(n, replacements)
+    }
+
+    fn should_pad_snippet(&self) -> bool {
+        true
+    }
+
+    fn make_single_line_comment(&self, text: &str) -> String {
+        self.0.make_single_line_comment(text)
+    }
+}
+
+impl<'a> MarzanoLanguage<'a> for Python2 {
+    fn get_ts_language(&self) -> &TSLanguage {
+        self.0.get_ts_language()
+    }
+
+    fn is_comment_sort(&self, id: SortId) -> bool {
+        self.0.is_comment_sort(id)
+    }
+
+    fn metavariable_sort(&self) -> SortId {
+        self.0.metavariable_sort()
+    }
+
+    fn get_parser(&self) -> Box<dyn Parser<Tree = Tree>> {
+        self.0.get_parser()
+    }
+
+    fn should_skip_padding(&self, node: &NodeWithSource<'_>) -> bool {
+        self.0.should_skip_padding(node)
+    }
+
+    fn get_skip_padding_ranges_for_snippet(&self, snippet: &str) -> Vec<CodeRange> {
+        self.0.get_skip_padding_ranges_for_snippet(snippet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::language::nodes_from_indices;
+
+    use super::*;
+
+    #[test]
+    fn print_statement_snippet() {
+        let snippet = r#"print "hello", $name"#;
+        let lang = Python2::new(None);
+        let snippets = lang.parse_snippet_contexts(snippet);
+        let nodes = nodes_from_indices(&snippets);
+        println!("nodes: {:#?}", nodes);
+        assert!(nodes.iter().any(|n| n.node.kind() == "print_statement"));
+    }
+
+    #[test]
+    fn print_argument_snippet() {
+        let snippet = ">>sys.stderr, $message";
+        let lang = Python2::new(None);
+        let snippets = lang.parse_snippet_contexts(snippet);
+        let nodes = nodes_from_indices(&snippets);
+        println!("nodes: {:#?}", nodes);
+        assert!(!nodes.is_empty());
+    }
+}
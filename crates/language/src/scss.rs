@@ -0,0 +1,155 @@
+use crate::{
+    language::{fields_for_nodes, Field, MarzanoLanguage, NodeTypes, SortId, TSLanguage},
+    php_like::{
+        php_like_exact_variable_regex, php_like_metavariable_bracket_regex,
+        php_like_metavariable_prefix, php_like_metavariable_regex,
+    },
+};
+use grit_util::Language;
+use regex::Regex;
+use std::sync::OnceLock;
+
+static NODE_TYPES_STRING: &str = include_str!("../../../resources/node-types/scss-node-types.json");
+static NODE_TYPES: OnceLock<Vec<Vec<Field>>> = OnceLock::new();
+static LANGUAGE: OnceLock<TSLanguage> = OnceLock::new();
+
+#[cfg(not(feature = "builtin-parser"))]
+fn language() -> TSLanguage {
+    unimplemented!(
+        "tree-sitter parser must be initialized before use when [builtin-parser] is off."
+    )
+}
+#[cfg(feature = "builtin-parser")]
+fn language() -> TSLanguage {
+    tree_sitter_scss::language().into()
+}
+
+/// SCSS patterns use a grammar of their own, so `.scss` files are only
+/// matched by patterns declared with `language scss`, not by CSS patterns.
+/// Since `$` starts an SCSS variable, metavariables are written with `^`
+/// instead, as in PHP.
+#[derive(Debug, Clone, Copy)]
+pub struct Scss {
+    node_types: &'static [Vec<Field>],
+    metavariable_sort: SortId,
+    comment_sort: SortId,
+    language: &'static TSLanguage,
+}
+
+impl NodeTypes for Scss {
+    fn node_types(&self) -> &[Vec<Field>] {
+        self.node_types
+    }
+}
+
+impl Scss {
+    pub(crate) fn new(lang: Option<TSLanguage>) -> Self {
+        let language = LANGUAGE.get_or_init(|| lang.unwrap_or_else(language));
+        let node_types = NODE_TYPES.get_or_init(|| fields_for_nodes(language, NODE_TYPES_STRING));
+        let metavariable_sort = language.id_for_node_kind("grit_metavariable", true);
+        let comment_sort = language.id_for_node_kind("comment", true);
+        Self {
+            node_types,
+            metavariable_sort,
+            comment_sort,
+            language,
+        }
+    }
+    pub(crate) fn is_initialized() -> bool {
+        LANGUAGE.get().is_some()
+    }
+}
+
+impl Language for Scss {
+    use_marzano_delegate!();
+
+    fn language_name(&self) -> &'static str {
+        "SCSS"
+    }
+
+    fn snippet_context_strings(&self) -> &[(&'static str, &'static str)] {
+        &[
+            ("", ""),
+            ("GRIT_BLOCK { ", " }"),
+            ("GRIT_BLOCK { GRIT_PROPERTY: ", " }"),
+            ("GRIT_BLOCK { @include GRIT_MIXIN(", "); }"),
+        ]
+    }
+
+    fn metavariable_prefix(&self) -> &'static str {
+        php_like_metavariable_prefix()
+    }
+
+    fn metavariable_regex(&self) -> &'static Regex {
+        php_like_metavariable_regex()
+    }
+
+    fn metavariable_bracket_regex(&self) -> &'static Regex {
+        php_like_metavariable_bracket_regex()
+    }
+
+    fn exact_variable_regex(&self) -> &'static Regex {
+        php_like_exact_variable_regex()
+    }
+
+    fn make_single_line_comment(&self, text: &str) -> String {
+        format!("// {}\n", text)
+    }
+}
+
+impl<'a> MarzanoLanguage<'a> for Scss {
+    fn get_ts_language(&self) -> &TSLanguage {
+        self.language
+    }
+
+    fn is_comment_sort(&self, id: SortId) -> bool {
+        id == self.comment_sort
+    }
+
+    fn metavariable_sort(&self) -> SortId {
+        self.metavariable_sort
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::language::nodes_from_indices;
+
+    use super::*;
+
+    #[test]
+    fn include_snippet() {
+        let snippet = "@include ^mixin(^args);";
+        let lang = Scss::new(None);
+        let snippets = lang.parse_snippet_contexts(snippet);
+        let nodes = nodes_from_indices(&snippets);
+        assert!(!nodes.is_empty());
+    }
+
+    #[test]
+    fn variable_declaration_snippet() {
+        let snippet = "$primary: ^color;";
+        let lang = Scss::new(None);
+        let snippets = lang.parse_snippet_contexts(snippet);
+        let nodes = nodes_from_indices(&snippets);
+        assert!(!nodes.is_empty());
+    }
+
+    #[test]
+    fn use_snippet() {
+        let snippet = "@use ^module as ^namespace;";
+        let lang = Scss::new(None);
+        let snippets = lang.parse_snippet_contexts(snippet);
+        let nodes = nodes_from_indices(&snippets);
+        assert!(!nodes.is_empty());
+    }
+
+    #[test]
+    fn css_declaration_snippet() {
+        let snippet = "color: ^color;";
+        let lang = Scss::new(None);
+        let snippets = lang.parse_snippet_contexts(snippet);
+        let nodes = nodes_from_indices(&snippets);
+        assert!(!nodes.is_empty());
+    }
+}
@@ -128,6 +128,7 @@ impl SourceMapSection {
             SourceValueFormat::Array => {
                 json!(vec![code])
             }
+            SourceValueFormat::Raw => return code.to_string(),
         };
         structure.to_string()
     }
@@ -151,6 +152,8 @@ impl SourceMapSection {
 pub enum SourceValueFormat {
     String,
     Array,
+    /// Code written back verbatim, such as code injected into another language
+    Raw,
 }
 
 #[cfg(test)]
@@ -279,4 +282,30 @@ mod tests {
             r#"["abcd", "efgh", "zPPo", "znzo"]"#
         );
     }
+
+    #[test]
+    fn test_raw_sections_with_terminators() {
+        // SQL in Python strings, with `;` appended to the inner document
+        let outer = r#"db.execute("SELECT a"); db.execute("SELECT b;")"#;
+        let mut source_map = EmbeddedSourceMap::new(outer);
+        source_map.new_section(12..20, 10, SourceValueFormat::Raw, 2);
+        source_map.new_section(36..45, 20, SourceValueFormat::Raw, 1);
+
+        assert_eq!(
+            source_map
+                .fill_with_inner("SELECT a;\nSELECT b;\n")
+                .unwrap(),
+            outer
+        );
+
+        // a -> abc
+        let adjustments = [(7..8, 3)];
+        let adjusted = source_map.clone_with_edits(adjustments.iter()).unwrap();
+        assert_eq!(
+            adjusted
+                .fill_with_inner("SELECT abc;\nSELECT b;\n")
+                .unwrap(),
+            r#"db.execute("SELECT abc"); db.execute("SELECT b;")"#
+        );
+    }
 }
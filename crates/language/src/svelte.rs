@@ -0,0 +1,131 @@
+use crate::{
+    html_like::get_html_like_ranges,
+    language::{fields_for_nodes, Field, MarzanoLanguage, NodeTypes, SortId, TSLanguage},
+};
+use anyhow::Result;
+use grit_util::Language;
+use marzano_util::node_with_source::NodeWithSource;
+use std::sync::OnceLock;
+use tree_sitter::Range;
+
+static NODE_TYPES_STRING: &str =
+    include_str!("../../../resources/node-types/svelte-node-types.json");
+
+static NODE_TYPES: OnceLock<Vec<Vec<Field>>> = OnceLock::new();
+static LANGUAGE: OnceLock<TSLanguage> = OnceLock::new();
+
+#[cfg(not(feature = "builtin-parser"))]
+fn language() -> TSLanguage {
+    unimplemented!(
+        "tree-sitter parser must be initialized before use when [builtin-parser] is off."
+    )
+}
+#[cfg(feature = "builtin-parser")]
+fn language() -> TSLanguage {
+    tree_sitter_svelte::language().into()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Svelte {
+    node_types: &'static [Vec<Field>],
+    metavariable_sort: SortId,
+    comment_sort: SortId,
+    language: &'static TSLanguage,
+}
+
+impl Svelte {
+    pub(crate) fn new(lang: Option<TSLanguage>) -> Self {
+        let language = LANGUAGE.get_or_init(|| lang.unwrap_or_else(language));
+        let node_types = NODE_TYPES.get_or_init(|| fields_for_nodes(language, NODE_TYPES_STRING));
+        let metavariable_sort = language.id_for_node_kind("grit_metavariable", true);
+        let comment_sort = language.id_for_node_kind("comment", true);
+        Self {
+            node_types,
+            metavariable_sort,
+            comment_sort,
+            language,
+        }
+    }
+    pub(crate) fn is_initialized() -> bool {
+        LANGUAGE.get().is_some()
+    }
+}
+
+impl NodeTypes for Svelte {
+    fn node_types(&self) -> &[Vec<Field>] {
+        self.node_types
+    }
+}
+
+impl Language for Svelte {
+    use_marzano_delegate!();
+
+    fn language_name(&self) -> &'static str {
+        "Svelte"
+    }
+
+    fn snippet_context_strings(&self) -> &[(&'static str, &'static str)] {
+        &[("", "")]
+    }
+
+    fn make_single_line_comment(&self, text: &str) -> String {
+        format!("<!-- {} -->\n", text)
+    }
+}
+
+impl<'a> MarzanoLanguage<'a> for Svelte {
+    fn get_ts_language(&self) -> &TSLanguage {
+        self.language
+    }
+
+    fn is_comment_sort(&self, id: SortId) -> bool {
+        id == self.comment_sort
+    }
+
+    fn metavariable_sort(&self) -> SortId {
+        self.metavariable_sort
+    }
+}
+
+pub(crate) fn get_svelte_ranges(
+    file: &str,
+    parent_node_kinds: &[&str],
+    name_array: Option<&[&str]>,
+) -> Result<Vec<Range>> {
+    let svelte = Svelte::new(None);
+    get_html_like_ranges(&svelte, file, parent_node_kinds, name_array)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::language::nodes_from_indices;
+
+    use super::*;
+
+    #[test]
+    fn simple_svelte() {
+        let snippet = "<h1>Hello {name}!</h1>";
+        let lang = Svelte::new(None);
+        let snippets = lang.parse_snippet_contexts(snippet);
+        let nodes = nodes_from_indices(&snippets);
+        assert!(!nodes.is_empty());
+    }
+
+    #[test]
+    fn gets_script_and_style_ranges() {
+        let file = r#"<script>
+    let count = 0;
+</script>
+
+<button on:click={() => count++}>{count}</button>
+
+<style>
+    button { color: red; }
+</style>
+"#;
+        let script_ranges = get_svelte_ranges(file, &["script_element"], None).unwrap();
+        assert_eq!(script_ranges.len(), 1);
+        let style_ranges = get_svelte_ranges(file, &["style_element"], None).unwrap();
+        assert_eq!(style_ranges.len(), 1);
+    }
+}
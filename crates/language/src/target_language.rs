@@ -1,8 +1,12 @@
 use crate::{
+    astro::Astro,
     csharp::CSharp,
     css::Css,
+    dart::Dart,
+    detection::{is_sniffed, sniff_file, LanguageOverrides},
     elixir::Elixir,
     go::Go,
+    graphql::GraphQL,
     grit_parser::MarzanoGritParser,
     hcl::Hcl,
     html::Html,
@@ -13,19 +17,28 @@ use crate::{
     language::{
         Field, FieldId, LeafEquivalenceClass, MarzanoLanguage, NodeTypes, SortId, TSLanguage, Tree,
     },
+    less::Less,
+    lua::Lua,
     markdown_block::MarkdownBlock,
     markdown_inline::MarkdownInline,
+    nix::Nix,
     php::Php,
     php_only::PhpOnly,
+    plugin::{registered_plugins, PluginId, PluginLanguage},
+    protobuf::Protobuf,
     python::Python,
+    python2::Python2,
     ruby::Ruby,
     rust::Rust,
+    scss::Scss,
     solidity::Solidity,
     sql::Sql,
+    svelte::Svelte,
     toml::Toml,
     tsx::Tsx,
     typescript::TypeScript,
     vue::Vue,
+    xml::Xml,
     yaml::Yaml,
 };
 use anyhow::Result;
@@ -38,7 +51,16 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::hash::Hash;
-
+use std::path::Path;
+
+#[cfg(all(
+    feature = "finder",
+    feature = "builtin-parser",
+    not(target_arch = "wasm32")
+))]
+use crate::injections::embedding_extensions;
+#[cfg(all(feature = "builtin-parser", not(target_arch = "wasm32")))]
+use crate::injections::may_embed;
 #[cfg(feature = "finder")]
 use anyhow::Error;
 #[cfg(feature = "finder")]
@@ -63,6 +85,7 @@ pub enum PatternLanguage {
     Kotlin,
     CSharp,
     Python,
+    Python2,
     MarkdownBlock,
     MarkdownInline,
     Go,
@@ -77,6 +100,17 @@ pub enum PatternLanguage {
     Toml,
     Php,
     PhpOnly,
+    Dart,
+    Svelte,
+    Astro,
+    GraphQL,
+    Protobuf,
+    Xml,
+    Lua,
+    Nix,
+    Scss,
+    Less,
+    Plugin(PluginId),
     Universal,
 }
 
@@ -93,6 +127,7 @@ impl fmt::Display for PatternLanguage {
             PatternLanguage::Kotlin => write!(f, "kotlin"),
             PatternLanguage::CSharp => write!(f, "csharp"),
             PatternLanguage::Python => write!(f, "python"),
+            PatternLanguage::Python2 => write!(f, "python2"),
             PatternLanguage::MarkdownBlock => write!(f, "markdown"),
             PatternLanguage::MarkdownInline => write!(f, "markdown"),
             PatternLanguage::Go => write!(f, "go"),
@@ -108,6 +143,17 @@ impl fmt::Display for PatternLanguage {
             PatternLanguage::Universal => write!(f, "universal"),
             PatternLanguage::Php => write!(f, "php"),
             PatternLanguage::PhpOnly => write!(f, "php"),
+            PatternLanguage::Dart => write!(f, "dart"),
+            PatternLanguage::Svelte => write!(f, "svelte"),
+            PatternLanguage::Astro => write!(f, "astro"),
+            PatternLanguage::GraphQL => write!(f, "graphql"),
+            PatternLanguage::Protobuf => write!(f, "protobuf"),
+            PatternLanguage::Xml => write!(f, "xml"),
+            PatternLanguage::Lua => write!(f, "lua"),
+            PatternLanguage::Nix => write!(f, "nix"),
+            PatternLanguage::Scss => write!(f, "scss"),
+            PatternLanguage::Less => write!(f, "less"),
+            PatternLanguage::Plugin(id) => write!(f, "{}", id),
         }
     }
 }
@@ -131,6 +177,7 @@ impl ValueEnum for PatternLanguage {
             Self::Kotlin,
             Self::CSharp,
             Self::Python,
+            Self::Python2,
             Self::MarkdownBlock,
             Self::Go,
             Self::Rust,
@@ -144,6 +191,16 @@ impl ValueEnum for PatternLanguage {
             Self::Toml,
             Self::Php,
             Self::PhpOnly,
+            Self::Dart,
+            Self::Svelte,
+            Self::Astro,
+            Self::GraphQL,
+            Self::Protobuf,
+            Self::Xml,
+            Self::Lua,
+            Self::Nix,
+            Self::Scss,
+            Self::Less,
         ]
     }
 
@@ -169,8 +226,18 @@ impl PatternLanguage {
             let lang = langdecl.child_by_field_name("name")?;
             let lang = lang.text().ok()?;
             let lang = lang.trim();
-            let flavor = langdecl.child_by_field_name("flavor");
-            let flavor = flavor.as_ref().and_then(|f| f.text().ok());
+            // flavors can be listed together, as in `js(typescript,jsx)`
+            let flavor = langdecl
+                .text()
+                .ok()
+                .and_then(|decl| {
+                    let flavors = &decl[decl.find('(')? + 1..decl.rfind(')')?];
+                    Some(flavors.replace(char::is_whitespace, ""))
+                })
+                .or_else(|| {
+                    let flavor = langdecl.child_by_field_name("flavor");
+                    flavor.and_then(|f| f.text().ok().map(|t| t.to_string()))
+                });
             Self::from_string(lang, flavor.as_deref())
         });
 
@@ -217,14 +284,16 @@ impl PatternLanguage {
 
     pub fn from_string(name: &str, flavor: Option<&str>) -> Option<Self> {
         let lang = match name {
-            "js" => match flavor {
-                Some("jsx") => Some(Self::Tsx),
-                Some("flow") => Some(Self::Tsx),
-                Some("flowComments") => Some(Self::Tsx),
-                Some("typescript") => Some(Self::TypeScript),
-                Some("js_do_not_use") => Some(Self::JavaScript),
-                _ => Some(Self::Tsx),
-            },
+            "js" => {
+                let flavors: Vec<&str> = flavor.map(|f| f.split(',').collect()).unwrap_or_default();
+                if flavors.contains(&"js_do_not_use") {
+                    Some(Self::JavaScript)
+                } else if flavors.contains(&"typescript") && !flavors.contains(&"jsx") {
+                    Some(Self::TypeScript)
+                } else {
+                    Some(Self::Tsx)
+                }
+            }
             "html" => Some(Self::Html),
             "css" => Some(Self::Css),
             "json" => Some(Self::Json),
@@ -237,7 +306,11 @@ impl PatternLanguage {
                 _ => Some(Self::MarkdownInline),
             },
             "ipynb" => Some(Self::Python),
-            "python" => Some(Self::Python),
+            "python" => match flavor {
+                Some("2" | "python2") => Some(Self::Python2),
+                _ => Some(Self::Python),
+            },
+            "python2" => Some(Self::Python2),
             "go" => Some(Self::Go),
             "rust" => Some(Self::Rust),
             "ruby" => Some(Self::Ruby),
@@ -253,8 +326,18 @@ impl PatternLanguage {
                 Some("only") => Some(Self::PhpOnly),
                 _ => Some(Self::Php),
             },
+            "dart" => Some(Self::Dart),
+            "svelte" => Some(Self::Svelte),
+            "astro" => Some(Self::Astro),
+            "graphql" => Some(Self::GraphQL),
+            "proto" | "protobuf" => Some(Self::Protobuf),
+            "xml" => Some(Self::Xml),
+            "lua" => Some(Self::Lua),
+            "nix" => Some(Self::Nix),
+            "scss" => Some(Self::Scss),
+            "less" => Some(Self::Less),
             "universal" => Some(Self::Universal),
-            _ => None,
+            _ => PluginId::from_name(name).map(Self::Plugin),
         };
         if let Some(lang) = lang {
             return Some(lang);
@@ -279,7 +362,7 @@ impl PatternLanguage {
         match self {
             PatternLanguage::JavaScript => &["javascript"],
             PatternLanguage::TypeScript => &["typescript"],
-            PatternLanguage::Tsx => &["javascript", "typescript", "flow"],
+            PatternLanguage::Tsx => &["javascript", "typescript"],
             PatternLanguage::Sql => &["mysql", "postgresql"],
             _ => &[],
         }
@@ -287,18 +370,22 @@ impl PatternLanguage {
 
     fn get_file_extensions(&self) -> &'static [&'static str] {
         match self {
-            PatternLanguage::JavaScript => &["js", "jsx", "cjs", "mjs", "vue"],
-            PatternLanguage::TypeScript => {
-                &["js", "jsx", "ts", "tsx", "cjs", "mjs", "cts", "mts", "vue"]
-            }
-            PatternLanguage::Tsx => &["js", "jsx", "ts", "tsx", "cjs", "mjs", "cts", "mts", "vue"],
+            PatternLanguage::JavaScript => &["js", "jsx", "cjs", "mjs", "vue", "svelte", "astro"],
+            PatternLanguage::TypeScript => &[
+                "js", "jsx", "ts", "tsx", "cjs", "mjs", "cts", "mts", "vue", "svelte", "astro",
+            ],
+            PatternLanguage::Tsx => &[
+                "js", "jsx", "ts", "tsx", "cjs", "mjs", "cts", "mts", "vue", "svelte", "astro",
+            ],
             PatternLanguage::Html => &["html"],
-            PatternLanguage::Css => &["css", "vue"],
+            PatternLanguage::Css => &["css", "vue", "svelte", "astro"],
             PatternLanguage::Json => &["json"],
             PatternLanguage::Java => &["java"],
             PatternLanguage::Kotlin => &["kt", "kts"],
             PatternLanguage::CSharp => &["cs"],
-            PatternLanguage::Python => &["py", "pyi", "ipynb"],
+            PatternLanguage::Python | PatternLanguage::Python2 => {
+                &["py", "pyi", "ipynb", "Rmd", "rmd", "qmd"]
+            }
             PatternLanguage::MarkdownBlock => &["md", "mdx", "mdoc"],
             PatternLanguage::MarkdownInline => &["md", "mdx", "mdoc"],
             PatternLanguage::Go => &["go"],
@@ -313,6 +400,20 @@ impl PatternLanguage {
             PatternLanguage::Toml => &["toml"],
             PatternLanguage::Php => &["php", "phps", "phar", "phtml", "pht"],
             PatternLanguage::PhpOnly => &["php", "phps", "phar", "phtml", "pht"],
+            PatternLanguage::Dart => &["dart"],
+            PatternLanguage::Svelte => &["svelte"],
+            PatternLanguage::Astro => &["astro"],
+            PatternLanguage::GraphQL => &["graphql", "gql"],
+            PatternLanguage::Protobuf => &["proto"],
+            PatternLanguage::Xml => &[
+                "xml", "xsd", "xsl", "xslt", "svg", "csproj", "fsproj", "vbproj", "props",
+                "targets", "nuspec", "resx", "xaml", "plist",
+            ],
+            PatternLanguage::Lua => &["lua"],
+            PatternLanguage::Nix => &["nix"],
+            PatternLanguage::Scss => &["scss"],
+            PatternLanguage::Less => &["less"],
+            PatternLanguage::Plugin(id) => id.extensions(),
             PatternLanguage::Universal => &[],
         }
     }
@@ -329,6 +430,7 @@ impl PatternLanguage {
             PatternLanguage::Kotlin => Some("kt"),
             PatternLanguage::CSharp => Some("cs"),
             PatternLanguage::Python => Some("py"),
+            PatternLanguage::Python2 => Some("py"),
             PatternLanguage::MarkdownBlock => Some("md"),
             PatternLanguage::MarkdownInline => Some("md"),
             PatternLanguage::Go => Some("go"),
@@ -343,6 +445,17 @@ impl PatternLanguage {
             PatternLanguage::Toml => Some("toml"),
             PatternLanguage::Php => Some("php"),
             PatternLanguage::PhpOnly => Some("php"),
+            PatternLanguage::Dart => Some("dart"),
+            PatternLanguage::Svelte => Some("svelte"),
+            PatternLanguage::Astro => Some("astro"),
+            PatternLanguage::GraphQL => Some("graphql"),
+            PatternLanguage::Protobuf => Some("proto"),
+            PatternLanguage::Xml => Some("xml"),
+            PatternLanguage::Lua => Some("lua"),
+            PatternLanguage::Nix => Some("nix"),
+            PatternLanguage::Scss => Some("scss"),
+            PatternLanguage::Less => Some("less"),
+            PatternLanguage::Plugin(id) => id.extensions().first().copied(),
             PatternLanguage::Universal => None,
         }
     }
@@ -357,7 +470,7 @@ impl PatternLanguage {
             "java" => Some(Self::Java),
             "kt" | "kts" => Some(Self::Kotlin),
             "cs" => Some(Self::CSharp),
-            "ipynb" => Some(Self::Python),
+            "ipynb" | "Rmd" | "rmd" | "qmd" => Some(Self::Python),
             "py" | "pyi" => Some(Self::Python),
             "md" | "mdx" | "mdoc" => Some(Self::MarkdownBlock),
             "go" => Some(Self::Go),
@@ -370,12 +483,58 @@ impl PatternLanguage {
             "sql" => Some(Self::Sql),
             "vue" => Some(Self::Vue),
             "php" | "phps" | "phtml" | "pht" => Some(Self::Php),
-            _ => None,
+            "dart" => Some(Self::Dart),
+            "svelte" => Some(Self::Svelte),
+            "astro" => Some(Self::Astro),
+            "graphql" | "gql" => Some(Self::GraphQL),
+            "proto" => Some(Self::Protobuf),
+            "xml" | "xsd" | "xsl" | "xslt" | "svg" | "csproj" | "fsproj" | "vbproj" | "props"
+            | "targets" | "nuspec" | "resx" | "xaml" | "plist" => Some(Self::Xml),
+            "lua" => Some(Self::Lua),
+            "nix" => Some(Self::Nix),
+            "scss" => Some(Self::Scss),
+            "less" => Some(Self::Less),
+            _ => PluginId::from_extension(extension).map(Self::Plugin),
         }
     }
 
+    /// Whether files with the extension are in this language, or may embed
+    /// code in it.
     pub fn match_extension(&self, ext: &str) -> bool {
-        self.get_file_extensions().contains(&ext)
+        self.get_file_extensions().contains(&ext) || may_embed(ext, *self)
+    }
+
+    /// Detects the language of a file, from `languageOverrides` first, then
+    /// from its extension, falling back to the shebang or modeline of files
+    /// without an extension.
+    pub fn from_path(path: &Path, overrides: &LanguageOverrides) -> Option<Self> {
+        if let Some(lang) = overrides.language(path) {
+            return Some(lang);
+        }
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) => Self::from_extension(ext),
+            None => sniff_file(path),
+        }
+    }
+
+    /// Whether files at the given path should be processed by patterns in
+    /// this language.
+    pub fn match_path(&self, path: &Path, overrides: &LanguageOverrides) -> bool {
+        if let Some(lang) = overrides.language(path) {
+            return self.handles(lang);
+        }
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) => self.match_extension(ext),
+            None => is_sniffed(*self) && sniff_file(path).is_some_and(|lang| self.handles(lang)),
+        }
+    }
+
+    /// Whether patterns in this language can process files detected as `lang`.
+    fn handles(&self, lang: Self) -> bool {
+        *self == lang
+            || lang
+                .get_default_extension()
+                .is_some_and(|ext| self.match_extension(ext))
     }
 
     // slightly inefficient but ensures the names are consistent
@@ -402,6 +561,7 @@ impl PatternLanguage {
             PatternLanguage::Java => Ok(TargetLanguage::Java(Java::new(Some(lang)))),
             PatternLanguage::CSharp => Ok(TargetLanguage::CSharp(CSharp::new(Some(lang)))),
             PatternLanguage::Python => Ok(TargetLanguage::Python(Python::new(Some(lang)))),
+            PatternLanguage::Python2 => Ok(TargetLanguage::Python2(Python2::new(Some(lang)))),
             PatternLanguage::MarkdownBlock => Ok(TargetLanguage::MarkdownBlock(
                 MarkdownBlock::new(Some(lang)),
             )),
@@ -420,6 +580,18 @@ impl PatternLanguage {
             PatternLanguage::Toml => Ok(TargetLanguage::Toml(Toml::new(Some(lang)))),
             PatternLanguage::Php => Ok(TargetLanguage::Php(Php::new(Some(lang)))),
             PatternLanguage::PhpOnly => Ok(TargetLanguage::PhpOnly(PhpOnly::new(Some(lang)))),
+            PatternLanguage::Dart => Ok(TargetLanguage::Dart(Dart::new(Some(lang)))),
+            PatternLanguage::Svelte => Ok(TargetLanguage::Svelte(Svelte::new(Some(lang)))),
+            PatternLanguage::Astro => Ok(TargetLanguage::Astro(Astro::new(Some(lang)))),
+            PatternLanguage::GraphQL => Ok(TargetLanguage::GraphQL(GraphQL::new(Some(lang)))),
+            PatternLanguage::Protobuf => Ok(TargetLanguage::Protobuf(Protobuf::new(Some(lang)))),
+            PatternLanguage::Xml => Ok(TargetLanguage::Xml(Xml::new(Some(lang)))),
+            PatternLanguage::Lua => Ok(TargetLanguage::Lua(Lua::new(Some(lang)))),
+            PatternLanguage::Nix => Ok(TargetLanguage::Nix(Nix::new(Some(lang)))),
+            PatternLanguage::Scss => Ok(TargetLanguage::Scss(Scss::new(Some(lang)))),
+            PatternLanguage::Less => Ok(TargetLanguage::Less(Less::new(Some(lang)))),
+            // plugin grammars are loaded when the language is registered
+            PatternLanguage::Plugin(id) => Ok(TargetLanguage::Plugin(id.language())),
             PatternLanguage::Universal => Err(anyhow::anyhow!(
                 "Cannot convert universal to TSLang".to_string()
             )),
@@ -432,10 +604,25 @@ impl PatternLanguage {
     }
 }
 
+// Without the builtin grammars, files are never searched for embedded code
+#[cfg(not(all(feature = "builtin-parser", not(target_arch = "wasm32"))))]
+fn may_embed(_extension: &str, _language: PatternLanguage) -> bool {
+    false
+}
+
+#[cfg(all(
+    feature = "finder",
+    not(all(feature = "builtin-parser", not(target_arch = "wasm32")))
+))]
+fn embedding_extensions(_language: PatternLanguage) -> std::iter::Empty<&'static str> {
+    std::iter::empty()
+}
+
 #[cfg(feature = "finder")]
 pub fn expand_paths(
     start_paths: &[PathBuf],
     target_languages: Option<&[PatternLanguage]>,
+    overrides: &LanguageOverrides,
 ) -> Result<Walk, Error> {
     use ignore::overrides::OverrideBuilder;
 
@@ -452,7 +639,12 @@ pub fn expand_paths(
                     }
                     PatternLanguage::Universal => {}
                     _ => {
-                        for ext in target_language.get_file_extensions() {
+                        for ext in target_language
+                            .get_file_extensions()
+                            .iter()
+                            .copied()
+                            .chain(embedding_extensions(target_language))
+                        {
                             file_types.add(ext, &format!("*.{}", ext)).unwrap();
                             file_types.select(ext);
                         }
@@ -466,8 +658,34 @@ pub fn expand_paths(
         }
     }
 
+    let file_types = file_types.build()?;
+    let languages = target_languages.map_or_else(|| vec![PatternLanguage::Tsx], <[_]>::to_vec);
+    // reading files is only worth it if they may be scripts in a target language
+    let sniff = languages
+        .iter()
+        .any(|l| *l == PatternLanguage::Universal || is_sniffed(*l));
+    let overrides = overrides.clone();
+
     let mut file_walker = WalkBuilder::new(start_paths[0].clone());
-    file_walker.types(file_types.build()?);
+    file_walker.filter_entry(move |entry| {
+        if entry.file_type().map_or(true, |t| t.is_dir()) {
+            return true;
+        }
+        // files whose extension doesn't tell their language may be picked up
+        // through `languageOverrides`, their shebang or their modeline
+        let path = entry.path();
+        let detected = overrides.language(path).or_else(|| {
+            (sniff && path.extension().is_none())
+                .then(|| sniff_file(path))
+                .flatten()
+        });
+        match detected {
+            Some(detected) => languages
+                .iter()
+                .any(|l| *l == PatternLanguage::Universal || l.handles(detected)),
+            None => !file_types.matched(path, false).is_ignore(),
+        }
+    });
     for path in start_paths.iter().skip(1) {
         file_walker.add(path);
     }
@@ -491,7 +709,8 @@ macro_rules! generate_target_language {
     ($($language:ident),+) => {
         #[derive(Debug, Clone, Copy)]
         pub enum TargetLanguage {
-            $($language($language)),+
+            $($language($language),)+
+            Plugin(PluginLanguage),
         }
 
         impl Language for TargetLanguage {
@@ -499,109 +718,127 @@ macro_rules! generate_target_language {
 
             fn language_name(&self) -> &'static str {
                 match self {
-                    $(Self::$language(lang) => Language::language_name(lang)),+
+                    $(Self::$language(lang) => Language::language_name(lang),)+
+                    Self::Plugin(lang) => Language::language_name(lang),
                 }
             }
 
             fn snippet_context_strings(&self) -> &[(&'static str, &'static str)] {
                 match self {
-                    $(Self::$language(lang) => Language::snippet_context_strings(lang)),+
+                    $(Self::$language(lang) => Language::snippet_context_strings(lang),)+
+                    Self::Plugin(lang) => Language::snippet_context_strings(lang),
                 }
             }
 
             fn metavariable_prefix(&self) -> &'static str {
                 match self {
-                    $(Self::$language(lang) => Language::metavariable_prefix(lang)),+
+                    $(Self::$language(lang) => Language::metavariable_prefix(lang),)+
+                    Self::Plugin(lang) => Language::metavariable_prefix(lang),
                 }
             }
 
             fn comment_prefix(&self) -> &'static str {
                 match self {
-                    $(Self::$language(lang) => Language::comment_prefix(lang)),+
+                    $(Self::$language(lang) => Language::comment_prefix(lang),)+
+                    Self::Plugin(lang) => Language::comment_prefix(lang),
                 }
             }
 
             fn metavariable_prefix_substitute(&self) -> &'static str {
                 match self {
-                    $(Self::$language(lang) => Language::metavariable_prefix_substitute(lang)),+
+                    $(Self::$language(lang) => Language::metavariable_prefix_substitute(lang),)+
+                    Self::Plugin(lang) => Language::metavariable_prefix_substitute(lang),
                 }
             }
 
             fn metavariable_regex(&self) -> &'static Regex {
                 match self {
-                    $(Self::$language(lang) => Language::metavariable_regex(lang)),+
+                    $(Self::$language(lang) => Language::metavariable_regex(lang),)+
+                    Self::Plugin(lang) => Language::metavariable_regex(lang),
                 }
             }
 
             fn replaced_metavariable_regex(&self) -> &'static Regex {
                 match self {
-                    $(Self::$language(lang) => Language::replaced_metavariable_regex(lang)),+
+                    $(Self::$language(lang) => Language::replaced_metavariable_regex(lang),)+
+                    Self::Plugin(lang) => Language::replaced_metavariable_regex(lang),
                 }
             }
 
             fn metavariable_bracket_regex(&self) -> &'static Regex {
                 match self {
-                    $(Self::$language(lang) => Language::metavariable_bracket_regex(lang)),+
+                    $(Self::$language(lang) => Language::metavariable_bracket_regex(lang),)+
+                    Self::Plugin(lang) => Language::metavariable_bracket_regex(lang),
                 }
             }
 
             fn exact_variable_regex(&self) -> &'static Regex {
                 match self {
-                    $(Self::$language(lang) => Language::exact_variable_regex(lang)),+
+                    $(Self::$language(lang) => Language::exact_variable_regex(lang),)+
+                    Self::Plugin(lang) => Language::exact_variable_regex(lang),
                 }
             }
 
             fn exact_replaced_variable_regex(&self) -> &'static Regex {
                 match self {
-                    $(Self::$language(lang) => Language::exact_replaced_variable_regex(lang)),+
+                    $(Self::$language(lang) => Language::exact_replaced_variable_regex(lang),)+
+                    Self::Plugin(lang) => Language::exact_replaced_variable_regex(lang),
                 }
             }
 
             fn is_comment(&self, node: &Self::Node<'_>) -> bool {
                 match self {
-                    $(Self::$language(lang) => Language::is_comment(lang, node)),+
+                    $(Self::$language(lang) => Language::is_comment(lang, node),)+
+                    Self::Plugin(lang) => Language::is_comment(lang, node),
                 }
             }
 
             fn is_metavariable(&self, node: &Self::Node<'_>) -> bool {
                 match self {
-                    $(Self::$language(lang) => Language::is_metavariable(lang, node)),+
+                    $(Self::$language(lang) => Language::is_metavariable(lang, node),)+
+                    Self::Plugin(lang) => Language::is_metavariable(lang, node),
                 }
             }
 
             fn is_statement(&self, node: &Self::Node<'_>) -> bool {
                 match self {
-                    $(Self::$language(lang) => Language::is_statement(lang, node)),+
+                    $(Self::$language(lang) => Language::is_statement(lang, node),)+
+                    Self::Plugin(lang) => Language::is_statement(lang, node),
                 }
             }
 
             fn comment_text_range(&self, node: &Self::Node<'_>) -> Option<ByteRange> {
                 match self {
-                    $(Self::$language(lang) => Language::comment_text_range(lang, node)),+
+                    $(Self::$language(lang) => Language::comment_text_range(lang, node),)+
+                    Self::Plugin(lang) => Language::comment_text_range(lang, node),
                 }
             }
 
             fn substitute_metavariable_prefix(&self, src: &str) -> String {
                 match self {
-                    $(Self::$language(lang) => Language::substitute_metavariable_prefix(lang, src)),+
+                    $(Self::$language(lang) => Language::substitute_metavariable_prefix(lang, src),)+
+                    Self::Plugin(lang) => Language::substitute_metavariable_prefix(lang, src),
                 }
             }
 
             fn snippet_metavariable_to_grit_metavariable(&self, src: &str) -> Option<grit_util::GritMetaValue> {
                 match self {
-                    $(Self::$language(lang) => Language::snippet_metavariable_to_grit_metavariable(lang, src)),+
+                    $(Self::$language(lang) => Language::snippet_metavariable_to_grit_metavariable(lang, src),)+
+                    Self::Plugin(lang) => Language::snippet_metavariable_to_grit_metavariable(lang, src),
                 }
             }
 
             fn check_replacements(&self, node: Self::Node<'_>, replacements: &mut Vec<grit_util::Replacement>) {
                 match self {
-                    $(Self::$language(lang) => Language::check_replacements(lang, node, replacements)),+
+                    $(Self::$language(lang) => Language::check_replacements(lang, node, replacements),)+
+                    Self::Plugin(lang) => Language::check_replacements(lang, node, replacements),
                 }
             }
 
             fn take_padding(&self, current: char, next: Option<char>) -> Option<char> {
                 match self {
-                    $(Self::$language(lang) => Language::take_padding(lang, current, next)),+
+                    $(Self::$language(lang) => Language::take_padding(lang, current, next),)+
+                    Self::Plugin(lang) => Language::take_padding(lang, current, next),
                 }
             }
 
@@ -623,31 +860,51 @@ macro_rules! generate_target_language {
                         new_padding,
                         offset,
                         substitutions
-                    )),+
+                    ),)+
+                    Self::Plugin(lang) => Language::align_padding(
+                        lang,
+                        node,
+                        range,
+                        skip_ranges,
+                        new_padding,
+                        offset,
+                        substitutions
+                    ),
                 }
             }
 
             fn pad_snippet<'a>(&self, snippet: &'a str, padding: &str) -> std::borrow::Cow<'a, str> {
                 match self {
-                    $(Self::$language(lang) => Language::pad_snippet(lang, snippet, padding)),+
+                    $(Self::$language(lang) => Language::pad_snippet(lang, snippet, padding),)+
+                    Self::Plugin(lang) => Language::pad_snippet(lang, snippet, padding),
                 }
             }
 
             fn get_skip_padding_ranges(&self, node: &Self::Node<'_>) -> Vec<grit_util::CodeRange> {
                 match self {
-                    $(Self::$language(lang) => Language::get_skip_padding_ranges(lang, node)),+
+                    $(Self::$language(lang) => Language::get_skip_padding_ranges(lang, node),)+
+                    Self::Plugin(lang) => Language::get_skip_padding_ranges(lang, node),
                 }
             }
 
             fn should_pad_snippet(&self) -> bool {
                 match self {
-                    $(Self::$language(lang) => Language::should_pad_snippet(lang)),+
+                    $(Self::$language(lang) => Language::should_pad_snippet(lang),)+
+                    Self::Plugin(lang) => Language::should_pad_snippet(lang),
+                }
+            }
+
+            fn should_pad_like_first_child(&self) -> bool {
+                match self {
+                    $(Self::$language(lang) => Language::should_pad_like_first_child(lang),)+
+                    Self::Plugin(lang) => Language::should_pad_like_first_child(lang),
                 }
             }
 
             fn make_single_line_comment(&self, text: &str) -> String {
                 match self {
-                    $(Self::$language(lang) => Language::make_single_line_comment(lang, text)),+
+                    $(Self::$language(lang) => Language::make_single_line_comment(lang, text),)+
+                    Self::Plugin(lang) => Language::make_single_line_comment(lang, text),
                 }
             }
         }
@@ -655,7 +912,8 @@ macro_rules! generate_target_language {
         impl NodeTypes for TargetLanguage {
             fn node_types(&self) -> &[Vec<Field>] {
                 match self {
-                    $(Self::$language(lang) => NodeTypes::node_types(lang)),+
+                    $(Self::$language(lang) => NodeTypes::node_types(lang),)+
+                    Self::Plugin(lang) => NodeTypes::node_types(lang),
                 }
             }
         }
@@ -663,43 +921,50 @@ macro_rules! generate_target_language {
         impl<'a> MarzanoLanguage<'a> for TargetLanguage {
             fn get_ts_language(&self) -> &TSLanguage {
                 match self {
-                    $(Self::$language(lang) => MarzanoLanguage::get_ts_language(lang)),+
+                    $(Self::$language(lang) => MarzanoLanguage::get_ts_language(lang),)+
+                    Self::Plugin(lang) => MarzanoLanguage::get_ts_language(lang),
                 }
             }
 
             fn get_parser(&self) -> Box<dyn Parser<Tree = Tree>> {
                 match self {
-                    $(Self::$language(lang) => MarzanoLanguage::get_parser(lang)),+
+                    $(Self::$language(lang) => MarzanoLanguage::get_parser(lang),)+
+                    Self::Plugin(lang) => MarzanoLanguage::get_parser(lang),
                 }
             }
 
             fn parse_snippet_contexts(&self, source: &str) -> Vec<SnippetTree<Tree>> {
                 match self {
-                    $(Self::$language(lang) => MarzanoLanguage::parse_snippet_contexts(lang, source)),+
+                    $(Self::$language(lang) => MarzanoLanguage::parse_snippet_contexts(lang, source),)+
+                    Self::Plugin(lang) => MarzanoLanguage::parse_snippet_contexts(lang, source),
                 }
             }
 
             fn is_disregarded_snippet_field(&self, sort_id: SortId, field_id: FieldId, field_value: &Option<NodeWithSource<'_>>) -> bool {
                 match self {
-                    $(Self::$language(lang) => MarzanoLanguage::is_disregarded_snippet_field(lang, sort_id, field_id, field_value)),+
+                    $(Self::$language(lang) => MarzanoLanguage::is_disregarded_snippet_field(lang, sort_id, field_id, field_value),)+
+                    Self::Plugin(lang) => MarzanoLanguage::is_disregarded_snippet_field(lang, sort_id, field_id, field_value),
                 }
             }
 
             fn is_comment_sort(&self, id: SortId) -> bool {
                 match self {
-                    $(Self::$language(lang) => MarzanoLanguage::is_comment_sort(lang, id)),+
+                    $(Self::$language(lang) => MarzanoLanguage::is_comment_sort(lang, id),)+
+                    Self::Plugin(lang) => MarzanoLanguage::is_comment_sort(lang, id),
                 }
             }
 
             fn is_comment_node(&self, node: &NodeWithSource<'_>) -> bool {
                 match self {
-                    $(Self::$language(lang) => MarzanoLanguage::is_comment_node(lang, node)),+
+                    $(Self::$language(lang) => MarzanoLanguage::is_comment_node(lang, node),)+
+                    Self::Plugin(lang) => MarzanoLanguage::is_comment_node(lang, node),
                 }
             }
 
             fn metavariable_sort(&self) -> SortId {
                 match self {
-                    $(Self::$language(lang) => MarzanoLanguage::metavariable_sort(lang)),+
+                    $(Self::$language(lang) => MarzanoLanguage::metavariable_sort(lang),)+
+                    Self::Plugin(lang) => MarzanoLanguage::metavariable_sort(lang),
                 }
             }
 
@@ -709,7 +974,8 @@ macro_rules! generate_target_language {
                 text: &str,
             ) -> Result<Option<LeafEquivalenceClass>, String> {
                 match self {
-                    $(Self::$language(lang) => MarzanoLanguage::get_equivalence_class(lang, sort, text)),+
+                    $(Self::$language(lang) => MarzanoLanguage::get_equivalence_class(lang, sort, text),)+
+                    Self::Plugin(lang) => MarzanoLanguage::get_equivalence_class(lang, sort, text),
                 }
             }
         }
@@ -721,6 +987,7 @@ macro_rules! generate_target_language {
             fn try_from(lang: PatternLanguage) -> Result<Self> {
                 match lang {
                     $(PatternLanguage::$language => Ok(Self::$language($language::new(None)))),+,
+                    PatternLanguage::Plugin(id) => Ok(Self::Plugin(id.language())),
                     PatternLanguage::Universal => Err(
                         anyhow::anyhow!("cannot instantiate Universal as a target language".to_string())
                     )
@@ -730,12 +997,15 @@ macro_rules! generate_target_language {
 
         impl PatternLanguage {
             pub fn enumerate() -> Vec<Self> {
-                vec![$(Self::$language),+]
+                let mut languages = vec![$(Self::$language),+];
+                languages.extend(registered_plugins().into_iter().map(Self::Plugin));
+                languages
             }
 
             pub fn is_initialized(&self) -> bool {
                 match self {
                     $(Self::$language => $language::is_initialized()),+,
+                    Self::Plugin(_) => true,
                     Self::Universal => false,
                 }
             }
@@ -744,7 +1014,8 @@ macro_rules! generate_target_language {
         impl TargetLanguage {
             pub fn to_module_language(&self) -> PatternLanguage {
                 match self {
-                    $(Self::$language(_) => PatternLanguage::$language),+
+                    $(Self::$language(_) => PatternLanguage::$language,)+
+                    Self::Plugin(lang) => PatternLanguage::Plugin(lang.id()),
                 }
             }
         }
@@ -762,6 +1033,7 @@ generate_target_language! {
     Kotlin,
     CSharp,
     Python,
+    Python2,
     MarkdownBlock,
     MarkdownInline,
     Go,
@@ -775,7 +1047,17 @@ generate_target_language! {
     Toml,
     Sql,
     Php,
-    PhpOnly
+    PhpOnly,
+    Dart,
+    Svelte,
+    Astro,
+    GraphQL,
+    Protobuf,
+    Xml,
+    Lua,
+    Nix,
+    Scss,
+    Less
 }
 
 impl fmt::Display for TargetLanguage {
@@ -791,6 +1073,7 @@ impl fmt::Display for TargetLanguage {
             TargetLanguage::Kotlin(_) => write!(f, "kotlin"),
             TargetLanguage::CSharp(_) => write!(f, "csharp"),
             TargetLanguage::Python(_) => write!(f, "python"),
+            TargetLanguage::Python2(_) => write!(f, "python2"),
             TargetLanguage::MarkdownBlock(_) => write!(f, "markdown"),
             TargetLanguage::MarkdownInline(_) => write!(f, "markdown"),
             TargetLanguage::Go(_) => write!(f, "go"),
@@ -805,6 +1088,17 @@ impl fmt::Display for TargetLanguage {
             TargetLanguage::Toml(_) => write!(f, "toml"),
             TargetLanguage::Php(_) => write!(f, "php"),
             TargetLanguage::PhpOnly(_) => write!(f, "php"),
+            TargetLanguage::Dart(_) => write!(f, "dart"),
+            TargetLanguage::Svelte(_) => write!(f, "svelte"),
+            TargetLanguage::Astro(_) => write!(f, "astro"),
+            TargetLanguage::GraphQL(_) => write!(f, "graphql"),
+            TargetLanguage::Protobuf(_) => write!(f, "protobuf"),
+            TargetLanguage::Xml(_) => write!(f, "xml"),
+            TargetLanguage::Lua(_) => write!(f, "lua"),
+            TargetLanguage::Nix(_) => write!(f, "nix"),
+            TargetLanguage::Scss(_) => write!(f, "scss"),
+            TargetLanguage::Less(_) => write!(f, "less"),
+            TargetLanguage::Plugin(lang) => write!(f, "{}", lang.id()),
         }
     }
 }
@@ -834,6 +1128,10 @@ impl TargetLanguage {
         self.to_module_language().match_extension(ext)
     }
 
+    pub fn match_path(&self, path: &Path, overrides: &LanguageOverrides) -> bool {
+        self.to_module_language().match_path(path, overrides)
+    }
+
     pub fn extract_single_line_comment(&self, text: &str) -> Option<String> {
         let re = match self {
             TargetLanguage::CSharp(_)
@@ -847,19 +1145,35 @@ impl TargetLanguage {
             | TargetLanguage::Tsx(_)
             | TargetLanguage::Php(_)
             | TargetLanguage::PhpOnly(_)
+            | TargetLanguage::Dart(_)
+            | TargetLanguage::Protobuf(_)
             | TargetLanguage::TypeScript(_) => Regex::new(r"//\s*(.*)").unwrap(),
             TargetLanguage::Python(_)
+            | TargetLanguage::Python2(_)
             | TargetLanguage::Ruby(_)
             | TargetLanguage::Elixir(_)
             | TargetLanguage::Toml(_)
+            | TargetLanguage::GraphQL(_)
+            | TargetLanguage::Nix(_)
             | TargetLanguage::Yaml(_) => Regex::new(r"#\s*(.*)").unwrap(),
             TargetLanguage::Hcl(_) => Regex::new(r"(#|//)\s*(.*)").unwrap(),
             TargetLanguage::Html(_)
             | TargetLanguage::Vue(_)
+            | TargetLanguage::Svelte(_)
+            | TargetLanguage::Astro(_)
+            | TargetLanguage::Xml(_)
             | TargetLanguage::MarkdownBlock(_)
             | TargetLanguage::MarkdownInline(_) => Regex::new(r"<!--\s*(.*?)\s*-->").unwrap(),
             TargetLanguage::Css(_) => Regex::new(r"/\*\s*(.*?)\s*\*/").unwrap(),
-            TargetLanguage::Sql(_) => Regex::new(r"--\s*(.*)").unwrap(),
+            TargetLanguage::Scss(_) | TargetLanguage::Less(_) => {
+                Regex::new(r"(?://|/\*)\s*(.*?)\s*(?:\*/)?$").unwrap()
+            }
+            TargetLanguage::Sql(_) | TargetLanguage::Lua(_) => Regex::new(r"--\s*(.*)").unwrap(),
+            TargetLanguage::Plugin(lang) => Regex::new(&format!(
+                r"{}\s*(.*)",
+                regex::escape(lang.id().comment_prefix())
+            ))
+            .unwrap(),
         };
         let comment = re
             .captures(text)
@@ -936,6 +1250,14 @@ mod tests {
         assert_eq!(other_comment, "this is a comment");
     }
 
+    #[test]
+    fn extract_dart_comment() {
+        let text = "// this is a comment\nprint('hello');";
+        let lang = TargetLanguage::Dart(Dart::new(None));
+        let comment = lang.extract_single_line_comment(text).unwrap();
+        assert_eq!(comment, "this is a comment");
+    }
+
     #[test]
     fn detect_language_from_snippet() {
         let src = r#"go"package""#;
@@ -948,4 +1270,18 @@ mod tests {
             .to_module_language();
         assert_eq!(lang, PatternLanguage::Go);
     }
+
+    #[test]
+    fn python2_name_round_trips() {
+        let name = PatternLanguage::Python2.to_string();
+        assert_eq!(name, "python2");
+        assert_eq!(
+            PatternLanguage::from_string(&name, None),
+            Some(PatternLanguage::Python2)
+        );
+        assert_eq!(
+            <PatternLanguage as ValueEnum>::from_str(&name, true),
+            Ok(PatternLanguage::Python2)
+        );
+    }
 }
@@ -0,0 +1,159 @@
+use crate::language::{
+    check_disregarded_field_map, fields_for_nodes, kind_and_field_id_for_field_map, Field,
+    FieldExpectation, FieldExpectationCondition, FieldExpectationCondition::OnlyIf,
+    MarzanoLanguage, NodeTypes, SortId, TSLanguage,
+};
+use grit_util::Language;
+use marzano_util::node_with_source::NodeWithSource;
+use std::sync::OnceLock;
+
+static NODE_TYPES_STRING: &str = include_str!("../../../resources/node-types/xml-node-types.json");
+static NODE_TYPES: OnceLock<Vec<Vec<Field>>> = OnceLock::new();
+static LANGUAGE: OnceLock<TSLanguage> = OnceLock::new();
+static DISREGARDED_SNIPPET_FIELDS: OnceLock<Vec<FieldExpectation>> = OnceLock::new();
+
+#[cfg(not(feature = "builtin-parser"))]
+fn language() -> TSLanguage {
+    unimplemented!(
+        "tree-sitter parser must be initialized before use when [builtin-parser] is off."
+    )
+}
+#[cfg(feature = "builtin-parser")]
+fn language() -> TSLanguage {
+    tree_sitter_xml::language().into()
+}
+
+fn xml_disregarded_field_values() -> Vec<(&'static str, &'static str, FieldExpectationCondition)> {
+    vec![
+        // an unprefixed name in a snippet matches the name in any namespace,
+        // so `<dependency>` also matches `<m:dependency>`
+        ("Name", "prefix", OnlyIf(vec![""])),
+    ]
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Xml {
+    node_types: &'static [Vec<Field>],
+    metavariable_sort: SortId,
+    comment_sort: SortId,
+    language: &'static TSLanguage,
+    disregarded_snippet_fields: &'static Vec<FieldExpectation>,
+}
+
+impl NodeTypes for Xml {
+    fn node_types(&self) -> &[Vec<Field>] {
+        self.node_types
+    }
+}
+
+impl Xml {
+    pub(crate) fn new(lang: Option<TSLanguage>) -> Self {
+        let language = LANGUAGE.get_or_init(|| lang.unwrap_or_else(language));
+        let node_types = NODE_TYPES.get_or_init(|| fields_for_nodes(language, NODE_TYPES_STRING));
+        let metavariable_sort = language.id_for_node_kind("grit_metavariable", true);
+        let comment_sort = language.id_for_node_kind("Comment", true);
+        let disregarded_snippet_fields = DISREGARDED_SNIPPET_FIELDS.get_or_init(|| {
+            kind_and_field_id_for_field_map(language, xml_disregarded_field_values())
+        });
+        Self {
+            node_types,
+            metavariable_sort,
+            comment_sort,
+            language,
+            disregarded_snippet_fields,
+        }
+    }
+    pub(crate) fn is_initialized() -> bool {
+        LANGUAGE.get().is_some()
+    }
+}
+
+impl Language for Xml {
+    use_marzano_delegate!();
+
+    fn language_name(&self) -> &'static str {
+        "XML"
+    }
+
+    fn snippet_context_strings(&self) -> &[(&'static str, &'static str)] {
+        &[
+            ("", ""),
+            ("<GRIT_ELEMENT>", "</GRIT_ELEMENT>"),
+            ("<GRIT_ELEMENT ", " />"),
+        ]
+    }
+
+    fn should_pad_like_first_child(&self) -> bool {
+        true
+    }
+
+    fn comment_prefix(&self) -> &'static str {
+        "<!--"
+    }
+
+    fn make_single_line_comment(&self, text: &str) -> String {
+        format!("<!-- {} -->\n", text)
+    }
+}
+
+impl<'a> MarzanoLanguage<'a> for Xml {
+    fn get_ts_language(&self) -> &TSLanguage {
+        self.language
+    }
+
+    fn is_disregarded_snippet_field(
+        &self,
+        sort_id: SortId,
+        field_id: crate::language::FieldId,
+        field_node: &Option<NodeWithSource<'_>>,
+    ) -> bool {
+        check_disregarded_field_map(
+            self.disregarded_snippet_fields,
+            sort_id,
+            field_id,
+            field_node,
+        )
+    }
+
+    fn is_comment_sort(&self, id: SortId) -> bool {
+        id == self.comment_sort
+    }
+
+    fn metavariable_sort(&self) -> SortId {
+        self.metavariable_sort
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::language::nodes_from_indices;
+
+    use super::*;
+
+    #[test]
+    fn element_snippet() {
+        let snippet = "<dependency><artifactId>$artifact</artifactId>$rest</dependency>";
+        let lang = Xml::new(None);
+        let snippets = lang.parse_snippet_contexts(snippet);
+        let nodes = nodes_from_indices(&snippets);
+        assert!(!nodes.is_empty());
+    }
+
+    #[test]
+    fn attribute_snippet() {
+        let snippet = r#"android:name="$name""#;
+        let lang = Xml::new(None);
+        let snippets = lang.parse_snippet_contexts(snippet);
+        let nodes = nodes_from_indices(&snippets);
+        assert!(!nodes.is_empty());
+    }
+
+    #[test]
+    fn children_snippet() {
+        let snippet = "<groupId>$group</groupId><version>$version</version>";
+        let lang = Xml::new(None);
+        let snippets = lang.parse_snippet_contexts(snippet);
+        let nodes = nodes_from_indices(&snippets);
+        assert!(!nodes.is_empty());
+    }
+}
@@ -10,7 +10,7 @@ use grit_cache::cache::Cache;
 use marzano_util::hasher::hash;
 
 use marzano_core::api::{EnforcementLevel, MatchResult};
-use marzano_core::pattern_compiler::{src_to_problem_libs, CompilationResult};
+use marzano_core::pattern_compiler::{src_to_problem_libs_with_variables, CompilationResult};
 use marzano_gritmodule::config::ResolvedGritDefinition;
 use marzano_language::target_language::{PatternLanguage, TargetLanguage};
 use marzano_util::rich_path::RichFile;
@@ -81,7 +81,7 @@ pub fn check_file(
         let CompilationResult {
             problem,
             compilation_warnings,
-        } = src_to_problem_libs(
+        } = src_to_problem_libs_with_variables(
             pattern.body.to_string(),
             pattern_libs,
             language,
@@ -89,6 +89,7 @@ pub fn check_file(
             None,
             get_ai_built_in_functions_for_feature(),
             None,
+            &pattern.config.params.clone().unwrap_or_default(),
         )?;
         let logs = compilation_warnings
             .clone()
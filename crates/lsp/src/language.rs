@@ -24,6 +24,16 @@ pub fn language_id_to_pattern_language(language_id: &str) -> Option<PatternLangu
         "vue" => Some(PatternLanguage::Vue),
         "toml" => Some(PatternLanguage::Toml),
         "php" => Some(PatternLanguage::PhpOnly),
+        "dart" => Some(PatternLanguage::Dart),
+        "svelte" => Some(PatternLanguage::Svelte),
+        "astro" => Some(PatternLanguage::Astro),
+        "graphql" => Some(PatternLanguage::GraphQL),
+        "proto" => Some(PatternLanguage::Protobuf),
+        "xml" => Some(PatternLanguage::Xml),
+        "lua" => Some(PatternLanguage::Lua),
+        "nix" => Some(PatternLanguage::Nix),
+        "scss" => Some(PatternLanguage::Scss),
+        "less" => Some(PatternLanguage::Less),
         _ => None,
     }
 }
@@ -39,7 +49,7 @@ pub fn target_language_to_language_id(target_language: TargetLanguage) -> &'stat
         TargetLanguage::Java(_) => "java",
         TargetLanguage::Kotlin(_) => "kotlin",
         TargetLanguage::CSharp(_) => "csharp",
-        TargetLanguage::Python(_) => "python",
+        TargetLanguage::Python(_) | TargetLanguage::Python2(_) => "python",
         TargetLanguage::MarkdownInline(_) => "markdown",
         TargetLanguage::Go(_) => "go",
         TargetLanguage::Rust(_) => "rust",
@@ -55,6 +65,17 @@ pub fn target_language_to_language_id(target_language: TargetLanguage) -> &'stat
         TargetLanguage::Toml(_) => "toml",
         TargetLanguage::Php(_) => "php",
         TargetLanguage::PhpOnly(_) => "php",
+        TargetLanguage::Dart(_) => "dart",
+        TargetLanguage::Svelte(_) => "svelte",
+        TargetLanguage::Astro(_) => "astro",
+        TargetLanguage::GraphQL(_) => "graphql",
+        TargetLanguage::Protobuf(_) => "proto",
+        TargetLanguage::Xml(_) => "xml",
+        TargetLanguage::Lua(_) => "lua",
+        TargetLanguage::Nix(_) => "nix",
+        TargetLanguage::Scss(_) => "scss",
+        TargetLanguage::Less(_) => "less",
+        TargetLanguage::Plugin(lang) => lang.id().name(),
     }
 }
 
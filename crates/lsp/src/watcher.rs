@@ -1,3 +1,4 @@
+use marzano_language::detection::LanguageOverrides;
 use marzano_language::target_language::expand_paths;
 use marzano_language::target_language::PatternLanguage;
 use tower_lsp::lsp_types::Url;
@@ -11,7 +12,7 @@ pub fn get_watched_files(root_uri: Url) -> Vec<String> {
     };
     let paths = vec![root];
     let languages = PatternLanguage::enumerate();
-    let walker = match expand_paths(&paths, Some(&languages)) {
+    let walker = match expand_paths(&paths, Some(&languages), &LanguageOverrides::default()) {
         Ok(walker) => walker,
         Err(_) => {
             return vec![];
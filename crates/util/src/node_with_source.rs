@@ -70,6 +70,14 @@ impl<'a> NodeWithSource<'a> {
         }
     }
 
+    /// Whether the node lies within, or itself contains, a region the parser
+    /// could not parse. The root node is exempt, since every error is in it.
+    pub fn overlaps_syntax_error(&self) -> bool {
+        (self.node.has_error() && self.node.parent().is_some())
+            || self.node.is_missing()
+            || self.ancestors().any(|n| n.node.is_error())
+    }
+
     pub fn print_node_tree(&self) {
         let mut stack = vec![(self.node.clone(), 0)];
         while let Some((node, depth)) = stack.pop() {
@@ -1,6 +1,13 @@
 use anyhow::Result;
 use http::HeaderMap;
-use std::env;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    env, fmt,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{Arc, RwLock},
+};
 #[cfg(feature = "network_requests")]
 use tokio::runtime::Handle;
 
@@ -21,6 +28,12 @@ pub struct ExecutionContext {
     reqwest: reqwest::Client,
     /// Ignore limit patterns - this is important for scans
     pub ignore_limit_pattern: bool,
+    /// How to treat files the parser could not fully parse
+    pub error_tolerance: ErrorTolerance,
+    /// Project manifests parsed so far, shared by every file in the run
+    pub manifests: ManifestCache,
+    /// Seed for `random` and `shuffle`, used for every file instead of the default seed
+    pub seed: Option<u64>,
 }
 
 #[cfg(all(
@@ -54,6 +67,9 @@ pub struct ExecutionContext {
     fetch: FetchFn,
     pub exec_external: ExecExternalFn,
     pub ignore_limit_pattern: bool,
+    pub error_tolerance: ErrorTolerance,
+    pub manifests: ManifestCache,
+    pub seed: Option<u64>,
 }
 
 #[cfg(not(any(test, feature = "network_requests_common")))]
@@ -61,6 +77,9 @@ pub struct ExecutionContext {
 pub struct ExecutionContext {
     llm_api: Option<LanguageModelAPI>,
     pub ignore_limit_pattern: bool,
+    pub error_tolerance: ErrorTolerance,
+    pub manifests: ManifestCache,
+    pub seed: Option<u64>,
 }
 
 impl ExecutionContext {
@@ -107,6 +126,9 @@ impl ExecutionContext {
             fetch,
             exec_external,
             ignore_limit_pattern: false,
+            error_tolerance: ErrorTolerance::default(),
+            manifests: ManifestCache::default(),
+            seed: None,
         }
     }
 
@@ -191,6 +213,9 @@ impl Default for ExecutionContext {
             handle: Handle::try_current().ok(),
             reqwest: reqwest::Client::new(),
             ignore_limit_pattern: false,
+            error_tolerance: ErrorTolerance::default(),
+            manifests: ManifestCache::default(),
+            seed: None,
         }
     }
 
@@ -208,6 +233,9 @@ impl Default for ExecutionContext {
                 Err(anyhow::anyhow!("External functions are disabled"))
             },
             ignore_limit_pattern: false,
+            error_tolerance: ErrorTolerance::default(),
+            manifests: ManifestCache::default(),
+            seed: None,
         }
     }
 
@@ -216,10 +244,78 @@ impl Default for ExecutionContext {
         Self {
             llm_api: None,
             ignore_limit_pattern: false,
+            error_tolerance: ErrorTolerance::default(),
+            manifests: ManifestCache::default(),
+            seed: None,
         }
     }
 }
 
+/// How matching treats files containing syntax errors.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ErrorTolerance {
+    /// Skip files with syntax errors entirely
+    Skip,
+    /// Only match nodes outside of error regions
+    Outside,
+    /// Match everything, including nodes in error regions
+    #[default]
+    All,
+}
+
+impl FromStr for ErrorTolerance {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "skip" => Ok(Self::Skip),
+            "outside" => Ok(Self::Outside),
+            "all" => Ok(Self::All),
+            _ => Err(format!(
+                "invalid error tolerance {s}, expected one of: skip, outside, all"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ErrorTolerance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Skip => write!(f, "skip"),
+            Self::Outside => write!(f, "outside"),
+            Self::All => write!(f, "all"),
+        }
+    }
+}
+
+/// Dependencies declared by a project manifest, mapped to their version requirement.
+pub type Dependencies = BTreeMap<String, String>;
+
+/// Caches the dependencies of each project manifest by path, so manifests are
+/// only parsed once per run. Clones share the same cache.
+#[derive(Clone, Debug, Default)]
+pub struct ManifestCache(Arc<RwLock<HashMap<PathBuf, Arc<Dependencies>>>>);
+
+impl ManifestCache {
+    pub fn get_or_load(
+        &self,
+        path: &Path,
+        load: impl FnOnce(&Path) -> Dependencies,
+    ) -> Arc<Dependencies> {
+        if let Some(dependencies) = self.0.read().unwrap().get(path) {
+            return dependencies.clone();
+        }
+        let dependencies = Arc::new(load(path));
+        self.0
+            .write()
+            .unwrap()
+            .entry(path.to_owned())
+            .or_insert(dependencies)
+            .clone()
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct LanguageModelAPI {
     pub base_endpoint: String,
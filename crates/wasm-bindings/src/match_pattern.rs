@@ -13,6 +13,7 @@ use marzano_language::language::{fields_for_nodes, Field, NodeTypes};
 use marzano_language::{
     grit_parser::MarzanoGritParser,
     language::Tree,
+    plugin::{register_plugin_language_with_ts_lang, PluginLanguageConfig},
     target_language::{PatternLanguage, TargetLanguage},
 };
 use marzano_util::rich_path::RichFile;
@@ -51,6 +52,16 @@ static VUE_LANGUAGE: OnceLock<TSLanguage> = OnceLock::new();
 static TOML_LANGUAGE: OnceLock<TSLanguage> = OnceLock::new();
 static PHP_LANGUAGE: OnceLock<TSLanguage> = OnceLock::new();
 static PHP_ONLY_LANGUAGE: OnceLock<TSLanguage> = OnceLock::new();
+static DART_LANGUAGE: OnceLock<TSLanguage> = OnceLock::new();
+static SVELTE_LANGUAGE: OnceLock<TSLanguage> = OnceLock::new();
+static ASTRO_LANGUAGE: OnceLock<TSLanguage> = OnceLock::new();
+static GRAPHQL_LANGUAGE: OnceLock<TSLanguage> = OnceLock::new();
+static PROTOBUF_LANGUAGE: OnceLock<TSLanguage> = OnceLock::new();
+static XML_LANGUAGE: OnceLock<TSLanguage> = OnceLock::new();
+static LUA_LANGUAGE: OnceLock<TSLanguage> = OnceLock::new();
+static NIX_LANGUAGE: OnceLock<TSLanguage> = OnceLock::new();
+static SCSS_LANGUAGE: OnceLock<TSLanguage> = OnceLock::new();
+static LESS_LANGUAGE: OnceLock<TSLanguage> = OnceLock::new();
 
 pub use marzano_core::UncompiledPatternBuilder;
 
@@ -59,6 +70,23 @@ pub async fn initialize_tree_sitter() -> Result<(), JsError> {
     web_tree_sitter_sg::TreeSitter::init().await
 }
 
+/// Registers a language backed by a WebAssembly grammar. The configuration
+/// has the same shape as the `languages` entries in `.grit/grit.yaml`.
+#[wasm_bindgen(js_name = registerPluginLanguage)]
+pub async fn register_plugin_language(
+    config: JsValue,
+    // The contents of the grammar's node-types.json.
+    node_types: String,
+) -> Result<(), JsError> {
+    let config: PluginLanguageConfig = serde_wasm_bindgen::from_value(config)?;
+    let lang = get_lang(&config.grammar.to_string_lossy())
+        .await
+        .map_err(|e| JsError::new(&e.to_string()))?;
+    register_plugin_language_with_ts_lang(config, lang, &node_types)
+        .map_err(|e| JsError::new(&e.to_string()))?;
+    Ok(())
+}
+
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen(catch)]
@@ -401,6 +429,9 @@ async fn setup_language_parser(lang: PatternLanguage) -> anyhow::Result<TSParser
 }
 
 async fn get_cached_lang(lang: &PatternLanguage) -> anyhow::Result<&'static TSLanguage> {
+    if let PatternLanguage::Plugin(id) = lang {
+        return Ok(id.language().ts_language());
+    }
     let lang_store = get_lang_store(lang)?;
     if let Some(lang) = lang_store.get() {
         Ok(lang)
@@ -471,7 +502,7 @@ fn pattern_language_to_path(lang: &PatternLanguage) -> anyhow::Result<String> {
         PatternLanguage::Java => Ok("/tree-sitter-java.wasm"),
         PatternLanguage::Kotlin => Ok("/tree-sitter-kotlin.wasm"),
         PatternLanguage::CSharp => Err(anyhow::anyhow!("CSharp wasm is not currently supported")),
-        PatternLanguage::Python => Ok("/tree-sitter-python.wasm"),
+        PatternLanguage::Python | PatternLanguage::Python2 => Ok("/tree-sitter-python.wasm"),
         PatternLanguage::MarkdownBlock => Ok("/tree-sitter-markdown-block.wasm"), // def wrong
         PatternLanguage::MarkdownInline => Ok("/tree-sitter-markdown_inline.wasm"), // def wrong
         PatternLanguage::Go => Ok("/tree-sitter-go.wasm"),
@@ -486,6 +517,19 @@ fn pattern_language_to_path(lang: &PatternLanguage) -> anyhow::Result<String> {
         PatternLanguage::Toml => Ok("/tree-sitter-toml.wasm"),
         PatternLanguage::Php => Ok("/tree-sitter-php.wasm"),
         PatternLanguage::PhpOnly => Ok("/tree-sitter-php_only.wasm"),
+        PatternLanguage::Dart => Ok("/tree-sitter-dart.wasm"),
+        PatternLanguage::Svelte => Ok("/tree-sitter-svelte.wasm"),
+        PatternLanguage::Astro => Ok("/tree-sitter-astro.wasm"),
+        PatternLanguage::GraphQL => Ok("/tree-sitter-graphql.wasm"),
+        PatternLanguage::Protobuf => Ok("/tree-sitter-proto.wasm"),
+        PatternLanguage::Xml => Ok("/tree-sitter-xml.wasm"),
+        PatternLanguage::Lua => Ok("/tree-sitter-lua.wasm"),
+        PatternLanguage::Nix => Ok("/tree-sitter-nix.wasm"),
+        PatternLanguage::Scss => Ok("/tree-sitter-scss.wasm"),
+        PatternLanguage::Less => Ok("/tree-sitter-less.wasm"),
+        PatternLanguage::Plugin(_) => Err(anyhow::anyhow!(
+            "Plugin languages are loaded through registerPluginLanguage"
+        )),
         PatternLanguage::Universal => Err(anyhow::anyhow!("Universal does not have a parser")),
     }?;
     let final_file = format!("{}{}", get_parser_path(), wasm_file);
@@ -517,7 +561,7 @@ fn get_lang_store(language: &PatternLanguage) -> anyhow::Result<&'static OnceLoc
         PatternLanguage::Java => Ok(&JAVA_LANGUAGE),
         PatternLanguage::Kotlin => Ok(&KOTLIN_LANGUAGE),
         PatternLanguage::CSharp => Ok(&CSHARP_LANGUAGE),
-        PatternLanguage::Python => Ok(&PYTHON_LANGUAGE),
+        PatternLanguage::Python | PatternLanguage::Python2 => Ok(&PYTHON_LANGUAGE),
         PatternLanguage::MarkdownBlock => Ok(&MARKDOWN_BLOCK_LANGUAGE),
         PatternLanguage::MarkdownInline => Ok(&MARKDOWN_INLINE_LANGUAGE),
         PatternLanguage::Go => Ok(&GO_LANGUAGE),
@@ -532,6 +576,19 @@ fn get_lang_store(language: &PatternLanguage) -> anyhow::Result<&'static OnceLoc
         PatternLanguage::Toml => Ok(&TOML_LANGUAGE),
         PatternLanguage::Php => Ok(&PHP_LANGUAGE),
         PatternLanguage::PhpOnly => Ok(&PHP_ONLY_LANGUAGE),
+        PatternLanguage::Dart => Ok(&DART_LANGUAGE),
+        PatternLanguage::Svelte => Ok(&SVELTE_LANGUAGE),
+        PatternLanguage::Astro => Ok(&ASTRO_LANGUAGE),
+        PatternLanguage::GraphQL => Ok(&GRAPHQL_LANGUAGE),
+        PatternLanguage::Protobuf => Ok(&PROTOBUF_LANGUAGE),
+        PatternLanguage::Xml => Ok(&XML_LANGUAGE),
+        PatternLanguage::Lua => Ok(&LUA_LANGUAGE),
+        PatternLanguage::Nix => Ok(&NIX_LANGUAGE),
+        PatternLanguage::Scss => Ok(&SCSS_LANGUAGE),
+        PatternLanguage::Less => Ok(&LESS_LANGUAGE),
+        PatternLanguage::Plugin(_) => Err(anyhow::anyhow!(
+            "Plugin languages are loaded through registerPluginLanguage"
+        )),
         PatternLanguage::Universal => Err(anyhow::anyhow!("Universal does not have a parser")),
     }
 }
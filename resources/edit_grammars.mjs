@@ -42,25 +42,35 @@ process.on("uncaughtException", (err) => {
 // Constants
 ///////////////////////////////////////////////////
 const allLanguages = [
+  "astro",
   "c-sharp",
   "css",
+  "dart",
   "go",
+  "graphql",
   "hcl",
   "html",
   "java",
   "javascript",
   "json",
   "kotlin",
+  "less",
+  "lua",
   "markdown",
+  "nix",
   "php",
+  "proto",
   "python",
   "ruby",
   "rust",
+  "scss",
   "solidity",
   "sql",
+  "svelte",
   "toml",
   "typescript",
   "vue",
+  "xml",
   "yaml",
 ];
 
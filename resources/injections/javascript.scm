@@ -0,0 +1,37 @@
+; styled-components and emotion templates
+
+; styled.div`...`
+(call_expression
+  function: (member_expression
+    object: (identifier) @_styled)
+  arguments: (template_string
+    template: (template_content) @injection.content)
+  (#eq? @_styled "styled")
+  (#set! injection.language "css"))
+
+; styled(Button)`...`
+(call_expression
+  function: (call_expression
+    function: (identifier) @_styled)
+  arguments: (template_string
+    template: (template_content) @injection.content)
+  (#eq? @_styled "styled")
+  (#set! injection.language "css"))
+
+; css`...`, keyframes`...`, createGlobalStyle`...`
+(call_expression
+  function: (identifier) @_tag
+  arguments: (template_string
+    template: (template_content) @injection.content)
+  (#any-of? @_tag "css" "keyframes" "createGlobalStyle" "injectGlobal")
+  (#set! injection.language "css"))
+
+; GraphQL documents in gql`...` and graphql`...`, without their substitutions
+
+(call_expression
+  function: (identifier) @_tag
+  arguments: (template_string
+    template: (template_content
+      content: (string_fragment)+ @injection.content))
+  (#any-of? @_tag "gql" "graphql")
+  (#set! injection.language "graphql"))
@@ -0,0 +1,11 @@
+; SQL passed to DB-API cursors and pandas readers
+
+(call
+  function: (attribute
+    attribute: (identifier) @_method)
+  arguments: (argument_list
+    (string
+      content: (string_content) @injection.content))
+  (#any-of? @_method "execute" "executemany" "executescript" "read_sql" "read_sql_query")
+  (#set! injection.language "sql")
+  (#set! injection.terminator ";"))
@@ -0,0 +1,4186 @@
+#include "tree_sitter/parser.h"
+
+#if defined(__GNUC__) || defined(__clang__)
+#pragma GCC diagnostic ignored "-Wmissing-field-initializers"
+#endif
+
+#define LANGUAGE_VERSION 14
+#define STATE_COUNT 167
+#define LARGE_STATE_COUNT 2
+#define SYMBOL_COUNT 62
+#define ALIAS_COUNT 0
+#define TOKEN_COUNT 36
+#define EXTERNAL_TOKEN_COUNT 16
+#define FIELD_COUNT 0
+#define MAX_ALIAS_SEQUENCE_LENGTH 4
+#define PRODUCTION_ID_COUNT 1
+
+enum ts_symbol_identifiers {
+  anon_sym_LT_BANG = 1,
+  aux_sym_doctype_token1 = 2,
+  anon_sym_GT = 3,
+  sym__doctype = 4,
+  anon_sym_LT = 5,
+  anon_sym_SLASH_GT = 6,
+  anon_sym_LT_SLASH = 7,
+  anon_sym_EQ = 8,
+  aux_sym_attribute_name_token1 = 9,
+  aux_sym_attribute_value_token1 = 10,
+  anon_sym_SQUOTE = 11,
+  aux_sym_quoted_attribute_value_token1 = 12,
+  anon_sym_DQUOTE = 13,
+  aux_sym_quoted_attribute_value_token2 = 14,
+  aux_sym_text_token1 = 15,
+  anon_sym_DASH_DASH_DASH = 16,
+  anon_sym_DASH_DASH_DASH2 = 17,
+  anon_sym_LBRACE = 18,
+  anon_sym_RBRACE = 19,
+  sym_grit_metavariable = 20,
+  sym__start_tag_name = 21,
+  sym__script_start_tag_name = 22,
+  sym__style_start_tag_name = 23,
+  sym__end_tag_name = 24,
+  sym_erroneous_end_tag_name = 25,
+  sym__implicit_end_tag = 26,
+  sym_raw_text = 27,
+  sym_comment = 28,
+  sym__html_interpolation_start = 29,
+  sym__html_interpolation_end = 30,
+  sym_frontmatter_js_block = 31,
+  sym_attribute_js_expr = 32,
+  sym_attribute_backtick_string = 33,
+  sym_permissible_text = 34,
+  sym__fragment_tag_delim = 35,
+  sym_document = 36,
+  sym_doctype = 37,
+  sym__node = 38,
+  sym_element = 39,
+  sym_script_element = 40,
+  sym_style_element = 41,
+  sym_start_tag = 42,
+  sym_script_start_tag = 43,
+  sym_style_start_tag = 44,
+  sym_self_closing_tag = 45,
+  sym_end_tag = 46,
+  sym_erroneous_end_tag = 47,
+  sym_attribute = 48,
+  sym_attribute_name = 49,
+  sym_attribute_value = 50,
+  sym_quoted_attribute_value = 51,
+  sym_text = 52,
+  sym__node_with_permissible_text = 53,
+  sym_frontmatter = 54,
+  sym_attribute_interpolation = 55,
+  sym_html_interpolation = 56,
+  sym_self_closing_script_tag = 57,
+  sym_self_closing_style_tag = 58,
+  aux_sym_document_repeat1 = 59,
+  aux_sym_start_tag_repeat1 = 60,
+  aux_sym_html_interpolation_repeat1 = 61,
+};
+
+static const char * const ts_symbol_names[] = {
+  [ts_builtin_sym_end] = "end",
+  [anon_sym_LT_BANG] = "<!",
+  [aux_sym_doctype_token1] = "doctype_token1",
+  [anon_sym_GT] = ">",
+  [sym__doctype] = "doctype",
+  [anon_sym_LT] = "<",
+  [anon_sym_SLASH_GT] = "/>",
+  [anon_sym_LT_SLASH] = "</",
+  [anon_sym_EQ] = "=",
+  [aux_sym_attribute_name_token1] = "attribute_name_token1",
+  [aux_sym_attribute_value_token1] = "attribute_value_token1",
+  [anon_sym_SQUOTE] = "'",
+  [aux_sym_quoted_attribute_value_token1] = "attribute_value",
+  [anon_sym_DQUOTE] = "\"",
+  [aux_sym_quoted_attribute_value_token2] = "attribute_value",
+  [aux_sym_text_token1] = "text_token1",
+  [anon_sym_DASH_DASH_DASH] = "---",
+  [anon_sym_DASH_DASH_DASH2] = "---",
+  [anon_sym_LBRACE] = "{",
+  [anon_sym_RBRACE] = "}",
+  [sym_grit_metavariable] = "grit_metavariable",
+  [sym__start_tag_name] = "tag_name",
+  [sym__script_start_tag_name] = "tag_name",
+  [sym__style_start_tag_name] = "tag_name",
+  [sym__end_tag_name] = "tag_name",
+  [sym_erroneous_end_tag_name] = "erroneous_end_tag_name",
+  [sym__implicit_end_tag] = "_implicit_end_tag",
+  [sym_raw_text] = "raw_text",
+  [sym_comment] = "comment",
+  [sym__html_interpolation_start] = "{",
+  [sym__html_interpolation_end] = "}",
+  [sym_frontmatter_js_block] = "frontmatter_js_block",
+  [sym_attribute_js_expr] = "attribute_js_expr",
+  [sym_attribute_backtick_string] = "attribute_backtick_string",
+  [sym_permissible_text] = "permissible_text",
+  [sym__fragment_tag_delim] = ">",
+  [sym_document] = "document",
+  [sym_doctype] = "doctype",
+  [sym__node] = "_node",
+  [sym_element] = "element",
+  [sym_script_element] = "script_element",
+  [sym_style_element] = "style_element",
+  [sym_start_tag] = "start_tag",
+  [sym_script_start_tag] = "start_tag",
+  [sym_style_start_tag] = "start_tag",
+  [sym_self_closing_tag] = "self_closing_tag",
+  [sym_end_tag] = "end_tag",
+  [sym_erroneous_end_tag] = "erroneous_end_tag",
+  [sym_attribute] = "attribute",
+  [sym_attribute_name] = "attribute_name",
+  [sym_attribute_value] = "attribute_value",
+  [sym_quoted_attribute_value] = "quoted_attribute_value",
+  [sym_text] = "text",
+  [sym__node_with_permissible_text] = "_node_with_permissible_text",
+  [sym_frontmatter] = "frontmatter",
+  [sym_attribute_interpolation] = "attribute_interpolation",
+  [sym_html_interpolation] = "html_interpolation",
+  [sym_self_closing_script_tag] = "self_closing_tag",
+  [sym_self_closing_style_tag] = "self_closing_tag",
+  [aux_sym_document_repeat1] = "document_repeat1",
+  [aux_sym_start_tag_repeat1] = "start_tag_repeat1",
+  [aux_sym_html_interpolation_repeat1] = "html_interpolation_repeat1",
+};
+
+static const TSSymbol ts_symbol_map[] = {
+  [ts_builtin_sym_end] = ts_builtin_sym_end,
+  [anon_sym_LT_BANG] = anon_sym_LT_BANG,
+  [aux_sym_doctype_token1] = aux_sym_doctype_token1,
+  [anon_sym_GT] = anon_sym_GT,
+  [sym__doctype] = sym__doctype,
+  [anon_sym_LT] = anon_sym_LT,
+  [anon_sym_SLASH_GT] = anon_sym_SLASH_GT,
+  [anon_sym_LT_SLASH] = anon_sym_LT_SLASH,
+  [anon_sym_EQ] = anon_sym_EQ,
+  [aux_sym_attribute_name_token1] = aux_sym_attribute_name_token1,
+  [aux_sym_attribute_value_token1] = aux_sym_attribute_value_token1,
+  [anon_sym_SQUOTE] = anon_sym_SQUOTE,
+  [aux_sym_quoted_attribute_value_token1] = sym_attribute_value,
+  [anon_sym_DQUOTE] = anon_sym_DQUOTE,
+  [aux_sym_quoted_attribute_value_token2] = sym_attribute_value,
+  [aux_sym_text_token1] = aux_sym_text_token1,
+  [anon_sym_DASH_DASH_DASH] = anon_sym_DASH_DASH_DASH,
+  [anon_sym_DASH_DASH_DASH2] = anon_sym_DASH_DASH_DASH,
+  [anon_sym_LBRACE] = anon_sym_LBRACE,
+  [anon_sym_RBRACE] = anon_sym_RBRACE,
+  [sym_grit_metavariable] = sym_grit_metavariable,
+  [sym__start_tag_name] = sym__start_tag_name,
+  [sym__script_start_tag_name] = sym__start_tag_name,
+  [sym__style_start_tag_name] = sym__start_tag_name,
+  [sym__end_tag_name] = sym__start_tag_name,
+  [sym_erroneous_end_tag_name] = sym_erroneous_end_tag_name,
+  [sym__implicit_end_tag] = sym__implicit_end_tag,
+  [sym_raw_text] = sym_raw_text,
+  [sym_comment] = sym_comment,
+  [sym__html_interpolation_start] = anon_sym_LBRACE,
+  [sym__html_interpolation_end] = anon_sym_RBRACE,
+  [sym_frontmatter_js_block] = sym_frontmatter_js_block,
+  [sym_attribute_js_expr] = sym_attribute_js_expr,
+  [sym_attribute_backtick_string] = sym_attribute_backtick_string,
+  [sym_permissible_text] = sym_permissible_text,
+  [sym__fragment_tag_delim] = anon_sym_GT,
+  [sym_document] = sym_document,
+  [sym_doctype] = sym_doctype,
+  [sym__node] = sym__node,
+  [sym_element] = sym_element,
+  [sym_script_element] = sym_script_element,
+  [sym_style_element] = sym_style_element,
+  [sym_start_tag] = sym_start_tag,
+  [sym_script_start_tag] = sym_start_tag,
+  [sym_style_start_tag] = sym_start_tag,
+  [sym_self_closing_tag] = sym_self_closing_tag,
+  [sym_end_tag] = sym_end_tag,
+  [sym_erroneous_end_tag] = sym_erroneous_end_tag,
+  [sym_attribute] = sym_attribute,
+  [sym_attribute_name] = sym_attribute_name,
+  [sym_attribute_value] = sym_attribute_value,
+  [sym_quoted_attribute_value] = sym_quoted_attribute_value,
+  [sym_text] = sym_text,
+  [sym__node_with_permissible_text] = sym__node_with_permissible_text,
+  [sym_frontmatter] = sym_frontmatter,
+  [sym_attribute_interpolation] = sym_attribute_interpolation,
+  [sym_html_interpolation] = sym_html_interpolation,
+  [sym_self_closing_script_tag] = sym_self_closing_tag,
+  [sym_self_closing_style_tag] = sym_self_closing_tag,
+  [aux_sym_document_repeat1] = aux_sym_document_repeat1,
+  [aux_sym_start_tag_repeat1] = aux_sym_start_tag_repeat1,
+  [aux_sym_html_interpolation_repeat1] = aux_sym_html_interpolation_repeat1,
+};
+
+static const TSSymbolMetadata ts_symbol_metadata[] = {
+  [ts_builtin_sym_end] = {
+    .visible = false,
+    .named = true,
+  },
+  [anon_sym_LT_BANG] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym_doctype_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [anon_sym_GT] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym__doctype] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_SLASH_GT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LT_SLASH] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym_attribute_name_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_attribute_value_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [anon_sym_SQUOTE] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym_quoted_attribute_value_token1] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_DQUOTE] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym_quoted_attribute_value_token2] = {
+    .visible = true,
+    .named = true,
+  },
+  [aux_sym_text_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [anon_sym_DASH_DASH_DASH] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DASH_DASH_DASH2] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_grit_metavariable] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__start_tag_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__script_start_tag_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__style_start_tag_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__end_tag_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_erroneous_end_tag_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__implicit_end_tag] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_raw_text] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_comment] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__html_interpolation_start] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym__html_interpolation_end] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_frontmatter_js_block] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_attribute_js_expr] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_attribute_backtick_string] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_permissible_text] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__fragment_tag_delim] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_document] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_doctype] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__node] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_element] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_script_element] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_style_element] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_start_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_script_start_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_style_start_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_self_closing_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_end_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_erroneous_end_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_attribute] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_attribute_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_attribute_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_quoted_attribute_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_text] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__node_with_permissible_text] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_frontmatter] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_attribute_interpolation] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_html_interpolation] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_self_closing_script_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_self_closing_style_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [aux_sym_document_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_start_tag_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_html_interpolation_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+};
+
+static const TSSymbol ts_alias_sequences[PRODUCTION_ID_COUNT][MAX_ALIAS_SEQUENCE_LENGTH] = {
+  [0] = {0},
+};
+
+static const uint16_t ts_non_terminal_alias_map[] = {
+  0,
+};
+
+static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
+  [0] = 0,
+  [1] = 1,
+  [2] = 2,
+  [3] = 3,
+  [4] = 3,
+  [5] = 3,
+  [6] = 2,
+  [7] = 2,
+  [8] = 8,
+  [9] = 9,
+  [10] = 8,
+  [11] = 11,
+  [12] = 12,
+  [13] = 13,
+  [14] = 13,
+  [15] = 15,
+  [16] = 15,
+  [17] = 17,
+  [18] = 15,
+  [19] = 13,
+  [20] = 20,
+  [21] = 21,
+  [22] = 22,
+  [23] = 23,
+  [24] = 24,
+  [25] = 25,
+  [26] = 23,
+  [27] = 25,
+  [28] = 20,
+  [29] = 29,
+  [30] = 22,
+  [31] = 31,
+  [32] = 31,
+  [33] = 25,
+  [34] = 23,
+  [35] = 20,
+  [36] = 29,
+  [37] = 31,
+  [38] = 29,
+  [39] = 22,
+  [40] = 40,
+  [41] = 41,
+  [42] = 42,
+  [43] = 43,
+  [44] = 44,
+  [45] = 45,
+  [46] = 46,
+  [47] = 47,
+  [48] = 48,
+  [49] = 49,
+  [50] = 50,
+  [51] = 51,
+  [52] = 52,
+  [53] = 53,
+  [54] = 54,
+  [55] = 55,
+  [56] = 40,
+  [57] = 57,
+  [58] = 58,
+  [59] = 59,
+  [60] = 46,
+  [61] = 61,
+  [62] = 42,
+  [63] = 44,
+  [64] = 53,
+  [65] = 65,
+  [66] = 66,
+  [67] = 67,
+  [68] = 68,
+  [69] = 69,
+  [70] = 43,
+  [71] = 45,
+  [72] = 48,
+  [73] = 49,
+  [74] = 50,
+  [75] = 51,
+  [76] = 52,
+  [77] = 55,
+  [78] = 57,
+  [79] = 58,
+  [80] = 59,
+  [81] = 81,
+  [82] = 82,
+  [83] = 65,
+  [84] = 66,
+  [85] = 67,
+  [86] = 61,
+  [87] = 68,
+  [88] = 69,
+  [89] = 89,
+  [90] = 90,
+  [91] = 43,
+  [92] = 45,
+  [93] = 48,
+  [94] = 49,
+  [95] = 50,
+  [96] = 51,
+  [97] = 52,
+  [98] = 55,
+  [99] = 57,
+  [100] = 58,
+  [101] = 59,
+  [102] = 69,
+  [103] = 103,
+  [104] = 104,
+  [105] = 105,
+  [106] = 106,
+  [107] = 107,
+  [108] = 61,
+  [109] = 109,
+  [110] = 42,
+  [111] = 44,
+  [112] = 53,
+  [113] = 65,
+  [114] = 66,
+  [115] = 67,
+  [116] = 68,
+  [117] = 117,
+  [118] = 117,
+  [119] = 117,
+  [120] = 120,
+  [121] = 121,
+  [122] = 122,
+  [123] = 120,
+  [124] = 121,
+  [125] = 120,
+  [126] = 122,
+  [127] = 127,
+  [128] = 128,
+  [129] = 122,
+  [130] = 121,
+  [131] = 131,
+  [132] = 132,
+  [133] = 133,
+  [134] = 134,
+  [135] = 135,
+  [136] = 135,
+  [137] = 134,
+  [138] = 138,
+  [139] = 133,
+  [140] = 140,
+  [141] = 135,
+  [142] = 134,
+  [143] = 133,
+  [144] = 144,
+  [145] = 145,
+  [146] = 146,
+  [147] = 147,
+  [148] = 148,
+  [149] = 145,
+  [150] = 146,
+  [151] = 151,
+  [152] = 152,
+  [153] = 153,
+  [154] = 154,
+  [155] = 147,
+  [156] = 156,
+  [157] = 147,
+  [158] = 158,
+  [159] = 145,
+  [160] = 160,
+  [161] = 152,
+  [162] = 151,
+  [163] = 163,
+  [164] = 151,
+  [165] = 156,
+  [166] = 156,
+};
+
+static bool ts_lex(TSLexer *lexer, TSStateId state) {
+  START_LEXER();
+  eof = lexer->eof(lexer);
+  switch (state) {
+    case 0:
+      if (eof) ADVANCE(24);
+      ADVANCE_MAP(
+        '"', 48,
+        '\'', 42,
+        '-', 6,
+        '/', 13,
+        '<', 30,
+        '=', 33,
+        '>', 28,
+        '{', 62,
+        '}', 65,
+        0xb5, 9,
+        'D', 16,
+        'd', 16,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(0);
+      END_STATE();
+    case 1:
+      if (lookahead == '"') ADVANCE(48);
+      if (lookahead == '\'') ADVANCE(42);
+      if (lookahead == '{') ADVANCE(64);
+      if (lookahead == 0xb5) ADVANCE(39);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(1);
+      if (lookahead != 0 &&
+          (lookahead < '<' || '>' < lookahead)) ADVANCE(41);
+      END_STATE();
+    case 2:
+      if (lookahead == '"') ADVANCE(48);
+      if (lookahead == 0xb5) ADVANCE(50);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(52);
+      if (lookahead != 0) ADVANCE(53);
+      END_STATE();
+    case 3:
+      if (lookahead == '\'') ADVANCE(42);
+      if (lookahead == 0xb5) ADVANCE(44);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(46);
+      if (lookahead != 0) ADVANCE(47);
+      END_STATE();
+    case 4:
+      if (lookahead == '-') ADVANCE(60);
+      END_STATE();
+    case 5:
+      if (lookahead == '-') ADVANCE(61);
+      END_STATE();
+    case 6:
+      if (lookahead == '-') ADVANCE(4);
+      END_STATE();
+    case 7:
+      if (lookahead == '-') ADVANCE(5);
+      END_STATE();
+    case 8:
+      if (lookahead == '-') ADVANCE(7);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(8);
+      END_STATE();
+    case 9:
+      if (lookahead == '.') ADVANCE(11);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      END_STATE();
+    case 10:
+      if (lookahead == '.') ADVANCE(66);
+      END_STATE();
+    case 11:
+      if (lookahead == '.') ADVANCE(10);
+      END_STATE();
+    case 12:
+      if (lookahead == '/') ADVANCE(13);
+      if (lookahead == '=') ADVANCE(33);
+      if (lookahead == '>') ADVANCE(28);
+      if (lookahead == '{') ADVANCE(63);
+      if (lookahead == 0xb5) ADVANCE(35);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(12);
+      if (lookahead != 0 &&
+          lookahead != '"' &&
+          lookahead != '\'' &&
+          (lookahead < '<' || '>' < lookahead)) ADVANCE(37);
+      END_STATE();
+    case 13:
+      if (lookahead == '>') ADVANCE(31);
+      END_STATE();
+    case 14:
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(18);
+      END_STATE();
+    case 15:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(29);
+      END_STATE();
+    case 16:
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(14);
+      END_STATE();
+    case 17:
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(15);
+      END_STATE();
+    case 18:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(19);
+      END_STATE();
+    case 19:
+      if (lookahead == 'Y' ||
+          lookahead == 'y') ADVANCE(17);
+      END_STATE();
+    case 20:
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(20);
+      if (lookahead != 0 &&
+          lookahead != '<' &&
+          lookahead != '>' &&
+          lookahead != '{' &&
+          lookahead != '}') ADVANCE(59);
+      END_STATE();
+    case 21:
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(26);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(27);
+      END_STATE();
+    case 22:
+      if (eof) ADVANCE(24);
+      if (lookahead == '-') ADVANCE(55);
+      if (lookahead == '<') ADVANCE(30);
+      if (lookahead == 0xb5) ADVANCE(57);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(22);
+      if (lookahead != 0 &&
+          lookahead != '>' &&
+          lookahead != '{' &&
+          lookahead != '}') ADVANCE(59);
+      END_STATE();
+    case 23:
+      if (eof) ADVANCE(24);
+      if (lookahead == '<') ADVANCE(30);
+      if (lookahead == 0xb5) ADVANCE(57);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(23);
+      if (lookahead != 0 &&
+          lookahead != '>' &&
+          lookahead != '{' &&
+          lookahead != '}') ADVANCE(59);
+      END_STATE();
+    case 24:
+      ACCEPT_TOKEN(ts_builtin_sym_end);
+      END_STATE();
+    case 25:
+      ACCEPT_TOKEN(anon_sym_LT_BANG);
+      END_STATE();
+    case 26:
+      ACCEPT_TOKEN(aux_sym_doctype_token1);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(26);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(27);
+      END_STATE();
+    case 27:
+      ACCEPT_TOKEN(aux_sym_doctype_token1);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(27);
+      END_STATE();
+    case 28:
+      ACCEPT_TOKEN(anon_sym_GT);
+      END_STATE();
+    case 29:
+      ACCEPT_TOKEN(sym__doctype);
+      END_STATE();
+    case 30:
+      ACCEPT_TOKEN(anon_sym_LT);
+      if (lookahead == '!') ADVANCE(25);
+      if (lookahead == '/') ADVANCE(32);
+      END_STATE();
+    case 31:
+      ACCEPT_TOKEN(anon_sym_SLASH_GT);
+      END_STATE();
+    case 32:
+      ACCEPT_TOKEN(anon_sym_LT_SLASH);
+      END_STATE();
+    case 33:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      END_STATE();
+    case 34:
+      ACCEPT_TOKEN(aux_sym_attribute_name_token1);
+      if (lookahead == '.') ADVANCE(66);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '"' &&
+          lookahead != '\'' &&
+          lookahead != '.' &&
+          lookahead != '/' &&
+          (lookahead < '<' || '>' < lookahead)) ADVANCE(37);
+      END_STATE();
+    case 35:
+      ACCEPT_TOKEN(aux_sym_attribute_name_token1);
+      if (lookahead == '.') ADVANCE(36);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '"' &&
+          lookahead != '\'' &&
+          lookahead != '.' &&
+          lookahead != '/' &&
+          (lookahead < '<' || '>' < lookahead)) ADVANCE(37);
+      END_STATE();
+    case 36:
+      ACCEPT_TOKEN(aux_sym_attribute_name_token1);
+      if (lookahead == '.') ADVANCE(34);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '"' &&
+          lookahead != '\'' &&
+          lookahead != '.' &&
+          lookahead != '/' &&
+          (lookahead < '<' || '>' < lookahead)) ADVANCE(37);
+      END_STATE();
+    case 37:
+      ACCEPT_TOKEN(aux_sym_attribute_name_token1);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '"' &&
+          lookahead != '\'' &&
+          lookahead != '/' &&
+          (lookahead < '<' || '>' < lookahead)) ADVANCE(37);
+      END_STATE();
+    case 38:
+      ACCEPT_TOKEN(aux_sym_attribute_value_token1);
+      if (lookahead == '.') ADVANCE(66);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '"' &&
+          lookahead != '\'' &&
+          (lookahead < '<' || '>' < lookahead)) ADVANCE(41);
+      END_STATE();
+    case 39:
+      ACCEPT_TOKEN(aux_sym_attribute_value_token1);
+      if (lookahead == '.') ADVANCE(40);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '"' &&
+          lookahead != '\'' &&
+          (lookahead < '<' || '>' < lookahead)) ADVANCE(41);
+      END_STATE();
+    case 40:
+      ACCEPT_TOKEN(aux_sym_attribute_value_token1);
+      if (lookahead == '.') ADVANCE(38);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '"' &&
+          lookahead != '\'' &&
+          (lookahead < '<' || '>' < lookahead)) ADVANCE(41);
+      END_STATE();
+    case 41:
+      ACCEPT_TOKEN(aux_sym_attribute_value_token1);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '"' &&
+          lookahead != '\'' &&
+          (lookahead < '<' || '>' < lookahead)) ADVANCE(41);
+      END_STATE();
+    case 42:
+      ACCEPT_TOKEN(anon_sym_SQUOTE);
+      END_STATE();
+    case 43:
+      ACCEPT_TOKEN(aux_sym_quoted_attribute_value_token1);
+      if (lookahead == '.') ADVANCE(66);
+      if (lookahead != 0 &&
+          lookahead != '\'') ADVANCE(47);
+      END_STATE();
+    case 44:
+      ACCEPT_TOKEN(aux_sym_quoted_attribute_value_token1);
+      if (lookahead == '.') ADVANCE(45);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead != 0 &&
+          lookahead != '\'') ADVANCE(47);
+      END_STATE();
+    case 45:
+      ACCEPT_TOKEN(aux_sym_quoted_attribute_value_token1);
+      if (lookahead == '.') ADVANCE(43);
+      if (lookahead != 0 &&
+          lookahead != '\'') ADVANCE(47);
+      END_STATE();
+    case 46:
+      ACCEPT_TOKEN(aux_sym_quoted_attribute_value_token1);
+      if (lookahead == 0xb5) ADVANCE(44);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(46);
+      if (lookahead != 0 &&
+          lookahead != '\'') ADVANCE(47);
+      END_STATE();
+    case 47:
+      ACCEPT_TOKEN(aux_sym_quoted_attribute_value_token1);
+      if (lookahead != 0 &&
+          lookahead != '\'') ADVANCE(47);
+      END_STATE();
+    case 48:
+      ACCEPT_TOKEN(anon_sym_DQUOTE);
+      END_STATE();
+    case 49:
+      ACCEPT_TOKEN(aux_sym_quoted_attribute_value_token2);
+      if (lookahead == '.') ADVANCE(66);
+      if (lookahead != 0 &&
+          lookahead != '"') ADVANCE(53);
+      END_STATE();
+    case 50:
+      ACCEPT_TOKEN(aux_sym_quoted_attribute_value_token2);
+      if (lookahead == '.') ADVANCE(51);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead != 0 &&
+          lookahead != '"') ADVANCE(53);
+      END_STATE();
+    case 51:
+      ACCEPT_TOKEN(aux_sym_quoted_attribute_value_token2);
+      if (lookahead == '.') ADVANCE(49);
+      if (lookahead != 0 &&
+          lookahead != '"') ADVANCE(53);
+      END_STATE();
+    case 52:
+      ACCEPT_TOKEN(aux_sym_quoted_attribute_value_token2);
+      if (lookahead == 0xb5) ADVANCE(50);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(52);
+      if (lookahead != 0 &&
+          lookahead != '"') ADVANCE(53);
+      END_STATE();
+    case 53:
+      ACCEPT_TOKEN(aux_sym_quoted_attribute_value_token2);
+      if (lookahead != 0 &&
+          lookahead != '"') ADVANCE(53);
+      END_STATE();
+    case 54:
+      ACCEPT_TOKEN(aux_sym_text_token1);
+      if (lookahead == '-') ADVANCE(60);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(20);
+      if (lookahead != 0 &&
+          lookahead != '<' &&
+          lookahead != '>' &&
+          lookahead != '{' &&
+          lookahead != '}') ADVANCE(59);
+      END_STATE();
+    case 55:
+      ACCEPT_TOKEN(aux_sym_text_token1);
+      if (lookahead == '-') ADVANCE(54);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(20);
+      if (lookahead != 0 &&
+          lookahead != '<' &&
+          lookahead != '>' &&
+          lookahead != '{' &&
+          lookahead != '}') ADVANCE(59);
+      END_STATE();
+    case 56:
+      ACCEPT_TOKEN(aux_sym_text_token1);
+      if (lookahead == '.') ADVANCE(66);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(20);
+      if (lookahead != 0 &&
+          lookahead != '<' &&
+          lookahead != '>' &&
+          lookahead != '{' &&
+          lookahead != '}') ADVANCE(59);
+      END_STATE();
+    case 57:
+      ACCEPT_TOKEN(aux_sym_text_token1);
+      if (lookahead == '.') ADVANCE(58);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(20);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead != 0 &&
+          lookahead != '<' &&
+          lookahead != '>' &&
+          (lookahead < 'a' || '{' < lookahead) &&
+          lookahead != '}') ADVANCE(59);
+      END_STATE();
+    case 58:
+      ACCEPT_TOKEN(aux_sym_text_token1);
+      if (lookahead == '.') ADVANCE(56);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(20);
+      if (lookahead != 0 &&
+          lookahead != '<' &&
+          lookahead != '>' &&
+          lookahead != '{' &&
+          lookahead != '}') ADVANCE(59);
+      END_STATE();
+    case 59:
+      ACCEPT_TOKEN(aux_sym_text_token1);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(20);
+      if (lookahead != 0 &&
+          lookahead != '<' &&
+          lookahead != '>' &&
+          lookahead != '{' &&
+          lookahead != '}') ADVANCE(59);
+      END_STATE();
+    case 60:
+      ACCEPT_TOKEN(anon_sym_DASH_DASH_DASH);
+      END_STATE();
+    case 61:
+      ACCEPT_TOKEN(anon_sym_DASH_DASH_DASH2);
+      END_STATE();
+    case 62:
+      ACCEPT_TOKEN(anon_sym_LBRACE);
+      END_STATE();
+    case 63:
+      ACCEPT_TOKEN(anon_sym_LBRACE);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '"' &&
+          lookahead != '\'' &&
+          lookahead != '/' &&
+          (lookahead < '<' || '>' < lookahead)) ADVANCE(37);
+      END_STATE();
+    case 64:
+      ACCEPT_TOKEN(anon_sym_LBRACE);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '"' &&
+          lookahead != '\'' &&
+          (lookahead < '<' || '>' < lookahead)) ADVANCE(41);
+      END_STATE();
+    case 65:
+      ACCEPT_TOKEN(anon_sym_RBRACE);
+      END_STATE();
+    case 66:
+      ACCEPT_TOKEN(sym_grit_metavariable);
+      END_STATE();
+    case 67:
+      ACCEPT_TOKEN(sym_grit_metavariable);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static const TSLexMode ts_lex_modes[STATE_COUNT] = {
+  [0] = {.lex_state = 0, .external_lex_state = 1},
+  [1] = {.lex_state = 22, .external_lex_state = 2},
+  [2] = {.lex_state = 23, .external_lex_state = 3},
+  [3] = {.lex_state = 23, .external_lex_state = 3},
+  [4] = {.lex_state = 23, .external_lex_state = 3},
+  [5] = {.lex_state = 23, .external_lex_state = 3},
+  [6] = {.lex_state = 23, .external_lex_state = 3},
+  [7] = {.lex_state = 23, .external_lex_state = 3},
+  [8] = {.lex_state = 23, .external_lex_state = 2},
+  [9] = {.lex_state = 23, .external_lex_state = 2},
+  [10] = {.lex_state = 23, .external_lex_state = 3},
+  [11] = {.lex_state = 23, .external_lex_state = 2},
+  [12] = {.lex_state = 23, .external_lex_state = 2},
+  [13] = {.lex_state = 0, .external_lex_state = 4},
+  [14] = {.lex_state = 0, .external_lex_state = 4},
+  [15] = {.lex_state = 0, .external_lex_state = 4},
+  [16] = {.lex_state = 0, .external_lex_state = 4},
+  [17] = {.lex_state = 0, .external_lex_state = 4},
+  [18] = {.lex_state = 0, .external_lex_state = 4},
+  [19] = {.lex_state = 0, .external_lex_state = 4},
+  [20] = {.lex_state = 12, .external_lex_state = 5},
+  [21] = {.lex_state = 1, .external_lex_state = 6},
+  [22] = {.lex_state = 12, .external_lex_state = 5},
+  [23] = {.lex_state = 12, .external_lex_state = 5},
+  [24] = {.lex_state = 12, .external_lex_state = 5},
+  [25] = {.lex_state = 12, .external_lex_state = 5},
+  [26] = {.lex_state = 12, .external_lex_state = 5},
+  [27] = {.lex_state = 12, .external_lex_state = 5},
+  [28] = {.lex_state = 12, .external_lex_state = 5},
+  [29] = {.lex_state = 12, .external_lex_state = 5},
+  [30] = {.lex_state = 12, .external_lex_state = 5},
+  [31] = {.lex_state = 12, .external_lex_state = 5},
+  [32] = {.lex_state = 12, .external_lex_state = 5},
+  [33] = {.lex_state = 12, .external_lex_state = 5},
+  [34] = {.lex_state = 12, .external_lex_state = 5},
+  [35] = {.lex_state = 12, .external_lex_state = 5},
+  [36] = {.lex_state = 12, .external_lex_state = 5},
+  [37] = {.lex_state = 12, .external_lex_state = 5},
+  [38] = {.lex_state = 12, .external_lex_state = 5},
+  [39] = {.lex_state = 12, .external_lex_state = 5},
+  [40] = {.lex_state = 23, .external_lex_state = 3},
+  [41] = {.lex_state = 23, .external_lex_state = 3},
+  [42] = {.lex_state = 23, .external_lex_state = 2},
+  [43] = {.lex_state = 23, .external_lex_state = 2},
+  [44] = {.lex_state = 23, .external_lex_state = 2},
+  [45] = {.lex_state = 23, .external_lex_state = 2},
+  [46] = {.lex_state = 23, .external_lex_state = 2},
+  [47] = {.lex_state = 23, .external_lex_state = 2},
+  [48] = {.lex_state = 23, .external_lex_state = 2},
+  [49] = {.lex_state = 23, .external_lex_state = 2},
+  [50] = {.lex_state = 23, .external_lex_state = 2},
+  [51] = {.lex_state = 23, .external_lex_state = 2},
+  [52] = {.lex_state = 23, .external_lex_state = 2},
+  [53] = {.lex_state = 23, .external_lex_state = 2},
+  [54] = {.lex_state = 23, .external_lex_state = 3},
+  [55] = {.lex_state = 23, .external_lex_state = 2},
+  [56] = {.lex_state = 23, .external_lex_state = 2},
+  [57] = {.lex_state = 23, .external_lex_state = 2},
+  [58] = {.lex_state = 23, .external_lex_state = 2},
+  [59] = {.lex_state = 23, .external_lex_state = 2},
+  [60] = {.lex_state = 23, .external_lex_state = 3},
+  [61] = {.lex_state = 23, .external_lex_state = 3},
+  [62] = {.lex_state = 23, .external_lex_state = 3},
+  [63] = {.lex_state = 23, .external_lex_state = 3},
+  [64] = {.lex_state = 23, .external_lex_state = 3},
+  [65] = {.lex_state = 23, .external_lex_state = 3},
+  [66] = {.lex_state = 23, .external_lex_state = 3},
+  [67] = {.lex_state = 23, .external_lex_state = 3},
+  [68] = {.lex_state = 23, .external_lex_state = 3},
+  [69] = {.lex_state = 23, .external_lex_state = 3},
+  [70] = {.lex_state = 23, .external_lex_state = 3},
+  [71] = {.lex_state = 23, .external_lex_state = 3},
+  [72] = {.lex_state = 23, .external_lex_state = 3},
+  [73] = {.lex_state = 23, .external_lex_state = 3},
+  [74] = {.lex_state = 23, .external_lex_state = 3},
+  [75] = {.lex_state = 23, .external_lex_state = 3},
+  [76] = {.lex_state = 23, .external_lex_state = 3},
+  [77] = {.lex_state = 23, .external_lex_state = 3},
+  [78] = {.lex_state = 23, .external_lex_state = 3},
+  [79] = {.lex_state = 23, .external_lex_state = 3},
+  [80] = {.lex_state = 23, .external_lex_state = 3},
+  [81] = {.lex_state = 23, .external_lex_state = 3},
+  [82] = {.lex_state = 23, .external_lex_state = 2},
+  [83] = {.lex_state = 23, .external_lex_state = 2},
+  [84] = {.lex_state = 23, .external_lex_state = 2},
+  [85] = {.lex_state = 23, .external_lex_state = 2},
+  [86] = {.lex_state = 23, .external_lex_state = 2},
+  [87] = {.lex_state = 23, .external_lex_state = 2},
+  [88] = {.lex_state = 23, .external_lex_state = 2},
+  [89] = {.lex_state = 12, .external_lex_state = 5},
+  [90] = {.lex_state = 12, .external_lex_state = 5},
+  [91] = {.lex_state = 0, .external_lex_state = 4},
+  [92] = {.lex_state = 0, .external_lex_state = 4},
+  [93] = {.lex_state = 0, .external_lex_state = 4},
+  [94] = {.lex_state = 0, .external_lex_state = 4},
+  [95] = {.lex_state = 0, .external_lex_state = 4},
+  [96] = {.lex_state = 0, .external_lex_state = 4},
+  [97] = {.lex_state = 0, .external_lex_state = 4},
+  [98] = {.lex_state = 0, .external_lex_state = 4},
+  [99] = {.lex_state = 0, .external_lex_state = 4},
+  [100] = {.lex_state = 0, .external_lex_state = 4},
+  [101] = {.lex_state = 0, .external_lex_state = 4},
+  [102] = {.lex_state = 0, .external_lex_state = 4},
+  [103] = {.lex_state = 12, .external_lex_state = 5},
+  [104] = {.lex_state = 12, .external_lex_state = 5},
+  [105] = {.lex_state = 12, .external_lex_state = 5},
+  [106] = {.lex_state = 12, .external_lex_state = 5},
+  [107] = {.lex_state = 12, .external_lex_state = 5},
+  [108] = {.lex_state = 0, .external_lex_state = 4},
+  [109] = {.lex_state = 12, .external_lex_state = 5},
+  [110] = {.lex_state = 0, .external_lex_state = 4},
+  [111] = {.lex_state = 0, .external_lex_state = 4},
+  [112] = {.lex_state = 0, .external_lex_state = 4},
+  [113] = {.lex_state = 0, .external_lex_state = 4},
+  [114] = {.lex_state = 0, .external_lex_state = 4},
+  [115] = {.lex_state = 0, .external_lex_state = 4},
+  [116] = {.lex_state = 0, .external_lex_state = 4},
+  [117] = {.lex_state = 0, .external_lex_state = 7},
+  [118] = {.lex_state = 0, .external_lex_state = 7},
+  [119] = {.lex_state = 0, .external_lex_state = 7},
+  [120] = {.lex_state = 0, .external_lex_state = 8},
+  [121] = {.lex_state = 0, .external_lex_state = 9},
+  [122] = {.lex_state = 0, .external_lex_state = 8},
+  [123] = {.lex_state = 0, .external_lex_state = 8},
+  [124] = {.lex_state = 0, .external_lex_state = 9},
+  [125] = {.lex_state = 0, .external_lex_state = 8},
+  [126] = {.lex_state = 0, .external_lex_state = 8},
+  [127] = {.lex_state = 2, .external_lex_state = 10},
+  [128] = {.lex_state = 3, .external_lex_state = 10},
+  [129] = {.lex_state = 0, .external_lex_state = 8},
+  [130] = {.lex_state = 0, .external_lex_state = 9},
+  [131] = {.lex_state = 0, .external_lex_state = 8},
+  [132] = {.lex_state = 0, .external_lex_state = 8},
+  [133] = {.lex_state = 0, .external_lex_state = 10},
+  [134] = {.lex_state = 0, .external_lex_state = 11},
+  [135] = {.lex_state = 0, .external_lex_state = 10},
+  [136] = {.lex_state = 0, .external_lex_state = 10},
+  [137] = {.lex_state = 0, .external_lex_state = 11},
+  [138] = {.lex_state = 0, .external_lex_state = 8},
+  [139] = {.lex_state = 0, .external_lex_state = 10},
+  [140] = {.lex_state = 8, .external_lex_state = 12},
+  [141] = {.lex_state = 0, .external_lex_state = 10},
+  [142] = {.lex_state = 0, .external_lex_state = 11},
+  [143] = {.lex_state = 0, .external_lex_state = 10},
+  [144] = {.lex_state = 0, .external_lex_state = 8},
+  [145] = {.lex_state = 0, .external_lex_state = 10},
+  [146] = {.lex_state = 0, .external_lex_state = 10},
+  [147] = {.lex_state = 0, .external_lex_state = 10},
+  [148] = {.lex_state = 8, .external_lex_state = 10},
+  [149] = {.lex_state = 0, .external_lex_state = 10},
+  [150] = {.lex_state = 0, .external_lex_state = 10},
+  [151] = {.lex_state = 21, .external_lex_state = 10},
+  [152] = {.lex_state = 0, .external_lex_state = 13},
+  [153] = {.lex_state = 0, .external_lex_state = 10},
+  [154] = {.lex_state = 0, .external_lex_state = 10},
+  [155] = {.lex_state = 0, .external_lex_state = 10},
+  [156] = {.lex_state = 0, .external_lex_state = 10},
+  [157] = {.lex_state = 0, .external_lex_state = 10},
+  [158] = {.lex_state = 0, .external_lex_state = 10},
+  [159] = {.lex_state = 0, .external_lex_state = 10},
+  [160] = {.lex_state = 0, .external_lex_state = 10},
+  [161] = {.lex_state = 0, .external_lex_state = 13},
+  [162] = {.lex_state = 21, .external_lex_state = 10},
+  [163] = {.lex_state = 0, .external_lex_state = 14},
+  [164] = {.lex_state = 21, .external_lex_state = 10},
+  [165] = {.lex_state = 0, .external_lex_state = 10},
+  [166] = {.lex_state = 0, .external_lex_state = 10},
+};
+
+static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
+  [0] = {
+    [ts_builtin_sym_end] = ACTIONS(1),
+    [anon_sym_LT_BANG] = ACTIONS(1),
+    [anon_sym_GT] = ACTIONS(1),
+    [sym__doctype] = ACTIONS(1),
+    [anon_sym_LT] = ACTIONS(1),
+    [anon_sym_SLASH_GT] = ACTIONS(1),
+    [anon_sym_LT_SLASH] = ACTIONS(1),
+    [anon_sym_EQ] = ACTIONS(1),
+    [anon_sym_SQUOTE] = ACTIONS(1),
+    [anon_sym_DQUOTE] = ACTIONS(1),
+    [anon_sym_DASH_DASH_DASH] = ACTIONS(1),
+    [anon_sym_DASH_DASH_DASH2] = ACTIONS(1),
+    [anon_sym_LBRACE] = ACTIONS(1),
+    [anon_sym_RBRACE] = ACTIONS(1),
+    [sym_grit_metavariable] = ACTIONS(1),
+    [sym__start_tag_name] = ACTIONS(1),
+    [sym__script_start_tag_name] = ACTIONS(1),
+    [sym__style_start_tag_name] = ACTIONS(1),
+    [sym__end_tag_name] = ACTIONS(1),
+    [sym_erroneous_end_tag_name] = ACTIONS(1),
+    [sym__implicit_end_tag] = ACTIONS(1),
+    [sym_raw_text] = ACTIONS(1),
+    [sym_comment] = ACTIONS(3),
+    [sym__html_interpolation_start] = ACTIONS(1),
+    [sym__html_interpolation_end] = ACTIONS(1),
+    [sym_frontmatter_js_block] = ACTIONS(1),
+    [sym_attribute_js_expr] = ACTIONS(1),
+    [sym_attribute_backtick_string] = ACTIONS(1),
+    [sym_permissible_text] = ACTIONS(1),
+    [sym__fragment_tag_delim] = ACTIONS(1),
+  },
+  [1] = {
+    [sym_document] = STATE(154),
+    [sym_doctype] = STATE(11),
+    [sym__node] = STATE(11),
+    [sym_element] = STATE(11),
+    [sym_script_element] = STATE(11),
+    [sym_style_element] = STATE(11),
+    [sym_start_tag] = STATE(2),
+    [sym_script_start_tag] = STATE(123),
+    [sym_style_start_tag] = STATE(129),
+    [sym_self_closing_tag] = STATE(86),
+    [sym_erroneous_end_tag] = STATE(11),
+    [sym_text] = STATE(11),
+    [sym_frontmatter] = STATE(9),
+    [sym_html_interpolation] = STATE(11),
+    [sym_self_closing_script_tag] = STATE(42),
+    [sym_self_closing_style_tag] = STATE(44),
+    [aux_sym_document_repeat1] = STATE(11),
+    [ts_builtin_sym_end] = ACTIONS(5),
+    [anon_sym_LT_BANG] = ACTIONS(7),
+    [anon_sym_LT] = ACTIONS(9),
+    [anon_sym_LT_SLASH] = ACTIONS(11),
+    [aux_sym_text_token1] = ACTIONS(13),
+    [anon_sym_DASH_DASH_DASH] = ACTIONS(15),
+    [sym_grit_metavariable] = ACTIONS(17),
+    [sym_comment] = ACTIONS(3),
+    [sym__html_interpolation_start] = ACTIONS(19),
+  },
+};
+
+static const uint16_t ts_small_parse_table[] = {
+  [0] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(21), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(23), 1,
+      anon_sym_LT,
+    ACTIONS(25), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(27), 1,
+      aux_sym_text_token1,
+    ACTIONS(29), 1,
+      sym_grit_metavariable,
+    ACTIONS(31), 1,
+      sym__implicit_end_tag,
+    ACTIONS(33), 1,
+      sym__html_interpolation_start,
+    STATE(6), 1,
+      sym_start_tag,
+    STATE(61), 1,
+      sym_self_closing_tag,
+    STATE(62), 1,
+      sym_self_closing_script_tag,
+    STATE(63), 1,
+      sym_self_closing_style_tag,
+    STATE(83), 1,
+      sym_end_tag,
+    STATE(125), 1,
+      sym_script_start_tag,
+    STATE(126), 1,
+      sym_style_start_tag,
+    STATE(4), 9,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_html_interpolation,
+      aux_sym_document_repeat1,
+  [57] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(21), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(23), 1,
+      anon_sym_LT,
+    ACTIONS(27), 1,
+      aux_sym_text_token1,
+    ACTIONS(29), 1,
+      sym_grit_metavariable,
+    ACTIONS(33), 1,
+      sym__html_interpolation_start,
+    ACTIONS(35), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(37), 1,
+      sym__implicit_end_tag,
+    STATE(6), 1,
+      sym_start_tag,
+    STATE(61), 1,
+      sym_self_closing_tag,
+    STATE(62), 1,
+      sym_self_closing_script_tag,
+    STATE(63), 1,
+      sym_self_closing_style_tag,
+    STATE(73), 1,
+      sym_end_tag,
+    STATE(125), 1,
+      sym_script_start_tag,
+    STATE(126), 1,
+      sym_style_start_tag,
+    STATE(10), 9,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_html_interpolation,
+      aux_sym_document_repeat1,
+  [114] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(21), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(23), 1,
+      anon_sym_LT,
+    ACTIONS(25), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(27), 1,
+      aux_sym_text_token1,
+    ACTIONS(29), 1,
+      sym_grit_metavariable,
+    ACTIONS(33), 1,
+      sym__html_interpolation_start,
+    ACTIONS(39), 1,
+      sym__implicit_end_tag,
+    STATE(6), 1,
+      sym_start_tag,
+    STATE(49), 1,
+      sym_end_tag,
+    STATE(61), 1,
+      sym_self_closing_tag,
+    STATE(62), 1,
+      sym_self_closing_script_tag,
+    STATE(63), 1,
+      sym_self_closing_style_tag,
+    STATE(125), 1,
+      sym_script_start_tag,
+    STATE(126), 1,
+      sym_style_start_tag,
+    STATE(10), 9,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_html_interpolation,
+      aux_sym_document_repeat1,
+  [171] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(21), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(23), 1,
+      anon_sym_LT,
+    ACTIONS(27), 1,
+      aux_sym_text_token1,
+    ACTIONS(29), 1,
+      sym_grit_metavariable,
+    ACTIONS(33), 1,
+      sym__html_interpolation_start,
+    ACTIONS(41), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(43), 1,
+      sym__implicit_end_tag,
+    STATE(6), 1,
+      sym_start_tag,
+    STATE(61), 1,
+      sym_self_closing_tag,
+    STATE(62), 1,
+      sym_self_closing_script_tag,
+    STATE(63), 1,
+      sym_self_closing_style_tag,
+    STATE(94), 1,
+      sym_end_tag,
+    STATE(125), 1,
+      sym_script_start_tag,
+    STATE(126), 1,
+      sym_style_start_tag,
+    STATE(10), 9,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_html_interpolation,
+      aux_sym_document_repeat1,
+  [228] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(21), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(23), 1,
+      anon_sym_LT,
+    ACTIONS(27), 1,
+      aux_sym_text_token1,
+    ACTIONS(29), 1,
+      sym_grit_metavariable,
+    ACTIONS(33), 1,
+      sym__html_interpolation_start,
+    ACTIONS(35), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(45), 1,
+      sym__implicit_end_tag,
+    STATE(6), 1,
+      sym_start_tag,
+    STATE(61), 1,
+      sym_self_closing_tag,
+    STATE(62), 1,
+      sym_self_closing_script_tag,
+    STATE(63), 1,
+      sym_self_closing_style_tag,
+    STATE(65), 1,
+      sym_end_tag,
+    STATE(125), 1,
+      sym_script_start_tag,
+    STATE(126), 1,
+      sym_style_start_tag,
+    STATE(3), 9,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_html_interpolation,
+      aux_sym_document_repeat1,
+  [285] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(21), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(23), 1,
+      anon_sym_LT,
+    ACTIONS(27), 1,
+      aux_sym_text_token1,
+    ACTIONS(29), 1,
+      sym_grit_metavariable,
+    ACTIONS(33), 1,
+      sym__html_interpolation_start,
+    ACTIONS(41), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(47), 1,
+      sym__implicit_end_tag,
+    STATE(6), 1,
+      sym_start_tag,
+    STATE(61), 1,
+      sym_self_closing_tag,
+    STATE(62), 1,
+      sym_self_closing_script_tag,
+    STATE(63), 1,
+      sym_self_closing_style_tag,
+    STATE(113), 1,
+      sym_end_tag,
+    STATE(125), 1,
+      sym_script_start_tag,
+    STATE(126), 1,
+      sym_style_start_tag,
+    STATE(5), 9,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_html_interpolation,
+      aux_sym_document_repeat1,
+  [342] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(49), 1,
+      ts_builtin_sym_end,
+    ACTIONS(51), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(54), 1,
+      anon_sym_LT,
+    ACTIONS(57), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(60), 1,
+      aux_sym_text_token1,
+    ACTIONS(63), 1,
+      sym_grit_metavariable,
+    ACTIONS(66), 1,
+      sym__html_interpolation_start,
+    STATE(2), 1,
+      sym_start_tag,
+    STATE(42), 1,
+      sym_self_closing_script_tag,
+    STATE(44), 1,
+      sym_self_closing_style_tag,
+    STATE(86), 1,
+      sym_self_closing_tag,
+    STATE(123), 1,
+      sym_script_start_tag,
+    STATE(129), 1,
+      sym_style_start_tag,
+    STATE(8), 9,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_html_interpolation,
+      aux_sym_document_repeat1,
+  [396] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      aux_sym_text_token1,
+    ACTIONS(17), 1,
+      sym_grit_metavariable,
+    ACTIONS(19), 1,
+      sym__html_interpolation_start,
+    ACTIONS(69), 1,
+      ts_builtin_sym_end,
+    STATE(2), 1,
+      sym_start_tag,
+    STATE(42), 1,
+      sym_self_closing_script_tag,
+    STATE(44), 1,
+      sym_self_closing_style_tag,
+    STATE(86), 1,
+      sym_self_closing_tag,
+    STATE(123), 1,
+      sym_script_start_tag,
+    STATE(129), 1,
+      sym_style_start_tag,
+    STATE(12), 9,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_html_interpolation,
+      aux_sym_document_repeat1,
+  [450] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(49), 1,
+      sym__implicit_end_tag,
+    ACTIONS(71), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(74), 1,
+      anon_sym_LT,
+    ACTIONS(77), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(80), 1,
+      aux_sym_text_token1,
+    ACTIONS(83), 1,
+      sym_grit_metavariable,
+    ACTIONS(86), 1,
+      sym__html_interpolation_start,
+    STATE(6), 1,
+      sym_start_tag,
+    STATE(61), 1,
+      sym_self_closing_tag,
+    STATE(62), 1,
+      sym_self_closing_script_tag,
+    STATE(63), 1,
+      sym_self_closing_style_tag,
+    STATE(125), 1,
+      sym_script_start_tag,
+    STATE(126), 1,
+      sym_style_start_tag,
+    STATE(10), 9,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_html_interpolation,
+      aux_sym_document_repeat1,
+  [504] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      aux_sym_text_token1,
+    ACTIONS(17), 1,
+      sym_grit_metavariable,
+    ACTIONS(19), 1,
+      sym__html_interpolation_start,
+    ACTIONS(69), 1,
+      ts_builtin_sym_end,
+    STATE(2), 1,
+      sym_start_tag,
+    STATE(42), 1,
+      sym_self_closing_script_tag,
+    STATE(44), 1,
+      sym_self_closing_style_tag,
+    STATE(86), 1,
+      sym_self_closing_tag,
+    STATE(123), 1,
+      sym_script_start_tag,
+    STATE(129), 1,
+      sym_style_start_tag,
+    STATE(8), 9,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_html_interpolation,
+      aux_sym_document_repeat1,
+  [558] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      aux_sym_text_token1,
+    ACTIONS(17), 1,
+      sym_grit_metavariable,
+    ACTIONS(19), 1,
+      sym__html_interpolation_start,
+    ACTIONS(89), 1,
+      ts_builtin_sym_end,
+    STATE(2), 1,
+      sym_start_tag,
+    STATE(42), 1,
+      sym_self_closing_script_tag,
+    STATE(44), 1,
+      sym_self_closing_style_tag,
+    STATE(86), 1,
+      sym_self_closing_tag,
+    STATE(123), 1,
+      sym_script_start_tag,
+    STATE(129), 1,
+      sym_style_start_tag,
+    STATE(8), 9,
+      sym_doctype,
+      sym__node,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_html_interpolation,
+      aux_sym_document_repeat1,
+  [612] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(91), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(93), 1,
+      anon_sym_LT,
+    ACTIONS(95), 1,
+      sym__html_interpolation_start,
+    ACTIONS(97), 1,
+      sym__html_interpolation_end,
+    ACTIONS(99), 1,
+      sym_permissible_text,
+    STATE(7), 1,
+      sym_start_tag,
+    STATE(108), 1,
+      sym_self_closing_tag,
+    STATE(110), 1,
+      sym_self_closing_script_tag,
+    STATE(111), 1,
+      sym_self_closing_style_tag,
+    STATE(120), 1,
+      sym_script_start_tag,
+    STATE(122), 1,
+      sym_style_start_tag,
+    STATE(15), 7,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym__node_with_permissible_text,
+      sym_html_interpolation,
+      aux_sym_html_interpolation_repeat1,
+  [658] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(91), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(93), 1,
+      anon_sym_LT,
+    ACTIONS(95), 1,
+      sym__html_interpolation_start,
+    ACTIONS(101), 1,
+      sym__html_interpolation_end,
+    ACTIONS(103), 1,
+      sym_permissible_text,
+    STATE(7), 1,
+      sym_start_tag,
+    STATE(108), 1,
+      sym_self_closing_tag,
+    STATE(110), 1,
+      sym_self_closing_script_tag,
+    STATE(111), 1,
+      sym_self_closing_style_tag,
+    STATE(120), 1,
+      sym_script_start_tag,
+    STATE(122), 1,
+      sym_style_start_tag,
+    STATE(16), 7,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym__node_with_permissible_text,
+      sym_html_interpolation,
+      aux_sym_html_interpolation_repeat1,
+  [704] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(91), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(93), 1,
+      anon_sym_LT,
+    ACTIONS(95), 1,
+      sym__html_interpolation_start,
+    ACTIONS(105), 1,
+      sym__html_interpolation_end,
+    ACTIONS(107), 1,
+      sym_permissible_text,
+    STATE(7), 1,
+      sym_start_tag,
+    STATE(108), 1,
+      sym_self_closing_tag,
+    STATE(110), 1,
+      sym_self_closing_script_tag,
+    STATE(111), 1,
+      sym_self_closing_style_tag,
+    STATE(120), 1,
+      sym_script_start_tag,
+    STATE(122), 1,
+      sym_style_start_tag,
+    STATE(17), 7,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym__node_with_permissible_text,
+      sym_html_interpolation,
+      aux_sym_html_interpolation_repeat1,
+  [750] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(91), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(93), 1,
+      anon_sym_LT,
+    ACTIONS(95), 1,
+      sym__html_interpolation_start,
+    ACTIONS(107), 1,
+      sym_permissible_text,
+    ACTIONS(109), 1,
+      sym__html_interpolation_end,
+    STATE(7), 1,
+      sym_start_tag,
+    STATE(108), 1,
+      sym_self_closing_tag,
+    STATE(110), 1,
+      sym_self_closing_script_tag,
+    STATE(111), 1,
+      sym_self_closing_style_tag,
+    STATE(120), 1,
+      sym_script_start_tag,
+    STATE(122), 1,
+      sym_style_start_tag,
+    STATE(17), 7,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym__node_with_permissible_text,
+      sym_html_interpolation,
+      aux_sym_html_interpolation_repeat1,
+  [796] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(111), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(114), 1,
+      anon_sym_LT,
+    ACTIONS(117), 1,
+      sym__html_interpolation_start,
+    ACTIONS(120), 1,
+      sym__html_interpolation_end,
+    ACTIONS(122), 1,
+      sym_permissible_text,
+    STATE(7), 1,
+      sym_start_tag,
+    STATE(108), 1,
+      sym_self_closing_tag,
+    STATE(110), 1,
+      sym_self_closing_script_tag,
+    STATE(111), 1,
+      sym_self_closing_style_tag,
+    STATE(120), 1,
+      sym_script_start_tag,
+    STATE(122), 1,
+      sym_style_start_tag,
+    STATE(17), 7,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym__node_with_permissible_text,
+      sym_html_interpolation,
+      aux_sym_html_interpolation_repeat1,
+  [842] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(91), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(93), 1,
+      anon_sym_LT,
+    ACTIONS(95), 1,
+      sym__html_interpolation_start,
+    ACTIONS(107), 1,
+      sym_permissible_text,
+    ACTIONS(125), 1,
+      sym__html_interpolation_end,
+    STATE(7), 1,
+      sym_start_tag,
+    STATE(108), 1,
+      sym_self_closing_tag,
+    STATE(110), 1,
+      sym_self_closing_script_tag,
+    STATE(111), 1,
+      sym_self_closing_style_tag,
+    STATE(120), 1,
+      sym_script_start_tag,
+    STATE(122), 1,
+      sym_style_start_tag,
+    STATE(17), 7,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym__node_with_permissible_text,
+      sym_html_interpolation,
+      aux_sym_html_interpolation_repeat1,
+  [888] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(91), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(93), 1,
+      anon_sym_LT,
+    ACTIONS(95), 1,
+      sym__html_interpolation_start,
+    ACTIONS(127), 1,
+      sym__html_interpolation_end,
+    ACTIONS(129), 1,
+      sym_permissible_text,
+    STATE(7), 1,
+      sym_start_tag,
+    STATE(108), 1,
+      sym_self_closing_tag,
+    STATE(110), 1,
+      sym_self_closing_script_tag,
+    STATE(111), 1,
+      sym_self_closing_style_tag,
+    STATE(120), 1,
+      sym_script_start_tag,
+    STATE(122), 1,
+      sym_style_start_tag,
+    STATE(18), 7,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym__node_with_permissible_text,
+      sym_html_interpolation,
+      aux_sym_html_interpolation_repeat1,
+  [934] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(131), 1,
+      anon_sym_GT,
+    ACTIONS(133), 1,
+      anon_sym_SLASH_GT,
+    ACTIONS(135), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(137), 1,
+      anon_sym_LBRACE,
+    ACTIONS(139), 1,
+      sym_grit_metavariable,
+    STATE(89), 1,
+      sym_attribute_name,
+    STATE(103), 1,
+      sym_attribute_interpolation,
+    STATE(22), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [963] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(137), 1,
+      anon_sym_LBRACE,
+    ACTIONS(141), 1,
+      aux_sym_attribute_value_token1,
+    ACTIONS(143), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(145), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(147), 1,
+      sym_grit_metavariable,
+    ACTIONS(149), 1,
+      sym_attribute_backtick_string,
+    STATE(109), 3,
+      sym_attribute_value,
+      sym_quoted_attribute_value,
+      sym_attribute_interpolation,
+  [990] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(135), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(137), 1,
+      anon_sym_LBRACE,
+    ACTIONS(139), 1,
+      sym_grit_metavariable,
+    ACTIONS(151), 1,
+      anon_sym_GT,
+    ACTIONS(153), 1,
+      anon_sym_SLASH_GT,
+    STATE(89), 1,
+      sym_attribute_name,
+    STATE(103), 1,
+      sym_attribute_interpolation,
+    STATE(24), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [1019] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(135), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(137), 1,
+      anon_sym_LBRACE,
+    ACTIONS(139), 1,
+      sym_grit_metavariable,
+    ACTIONS(155), 1,
+      anon_sym_GT,
+    ACTIONS(157), 1,
+      anon_sym_SLASH_GT,
+    STATE(89), 1,
+      sym_attribute_name,
+    STATE(103), 1,
+      sym_attribute_interpolation,
+    STATE(36), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [1048] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(161), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(164), 1,
+      anon_sym_LBRACE,
+    ACTIONS(167), 1,
+      sym_grit_metavariable,
+    STATE(89), 1,
+      sym_attribute_name,
+    STATE(103), 1,
+      sym_attribute_interpolation,
+    ACTIONS(159), 2,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+    STATE(24), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [1075] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(135), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(137), 1,
+      anon_sym_LBRACE,
+    ACTIONS(139), 1,
+      sym_grit_metavariable,
+    ACTIONS(170), 1,
+      anon_sym_GT,
+    ACTIONS(172), 1,
+      anon_sym_SLASH_GT,
+    STATE(89), 1,
+      sym_attribute_name,
+    STATE(103), 1,
+      sym_attribute_interpolation,
+    STATE(32), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [1104] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(135), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(137), 1,
+      anon_sym_LBRACE,
+    ACTIONS(139), 1,
+      sym_grit_metavariable,
+    ACTIONS(155), 1,
+      anon_sym_GT,
+    ACTIONS(174), 1,
+      anon_sym_SLASH_GT,
+    STATE(89), 1,
+      sym_attribute_name,
+    STATE(103), 1,
+      sym_attribute_interpolation,
+    STATE(29), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [1133] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(135), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(137), 1,
+      anon_sym_LBRACE,
+    ACTIONS(139), 1,
+      sym_grit_metavariable,
+    ACTIONS(170), 1,
+      anon_sym_GT,
+    ACTIONS(176), 1,
+      anon_sym_SLASH_GT,
+    STATE(89), 1,
+      sym_attribute_name,
+    STATE(103), 1,
+      sym_attribute_interpolation,
+    STATE(31), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [1162] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(131), 1,
+      anon_sym_GT,
+    ACTIONS(135), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(137), 1,
+      anon_sym_LBRACE,
+    ACTIONS(139), 1,
+      sym_grit_metavariable,
+    ACTIONS(178), 1,
+      anon_sym_SLASH_GT,
+    STATE(89), 1,
+      sym_attribute_name,
+    STATE(103), 1,
+      sym_attribute_interpolation,
+    STATE(30), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [1191] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(135), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(137), 1,
+      anon_sym_LBRACE,
+    ACTIONS(139), 1,
+      sym_grit_metavariable,
+    ACTIONS(180), 1,
+      anon_sym_GT,
+    ACTIONS(182), 1,
+      anon_sym_SLASH_GT,
+    STATE(89), 1,
+      sym_attribute_name,
+    STATE(103), 1,
+      sym_attribute_interpolation,
+    STATE(24), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [1220] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(135), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(137), 1,
+      anon_sym_LBRACE,
+    ACTIONS(139), 1,
+      sym_grit_metavariable,
+    ACTIONS(151), 1,
+      anon_sym_GT,
+    ACTIONS(184), 1,
+      anon_sym_SLASH_GT,
+    STATE(89), 1,
+      sym_attribute_name,
+    STATE(103), 1,
+      sym_attribute_interpolation,
+    STATE(24), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [1249] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(135), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(137), 1,
+      anon_sym_LBRACE,
+    ACTIONS(139), 1,
+      sym_grit_metavariable,
+    ACTIONS(186), 1,
+      anon_sym_GT,
+    ACTIONS(188), 1,
+      anon_sym_SLASH_GT,
+    STATE(89), 1,
+      sym_attribute_name,
+    STATE(103), 1,
+      sym_attribute_interpolation,
+    STATE(24), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [1278] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(135), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(137), 1,
+      anon_sym_LBRACE,
+    ACTIONS(139), 1,
+      sym_grit_metavariable,
+    ACTIONS(186), 1,
+      anon_sym_GT,
+    ACTIONS(190), 1,
+      anon_sym_SLASH_GT,
+    STATE(89), 1,
+      sym_attribute_name,
+    STATE(103), 1,
+      sym_attribute_interpolation,
+    STATE(24), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [1307] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(135), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(137), 1,
+      anon_sym_LBRACE,
+    ACTIONS(139), 1,
+      sym_grit_metavariable,
+    ACTIONS(170), 1,
+      anon_sym_GT,
+    ACTIONS(192), 1,
+      anon_sym_SLASH_GT,
+    STATE(89), 1,
+      sym_attribute_name,
+    STATE(103), 1,
+      sym_attribute_interpolation,
+    STATE(37), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [1336] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(135), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(137), 1,
+      anon_sym_LBRACE,
+    ACTIONS(139), 1,
+      sym_grit_metavariable,
+    ACTIONS(155), 1,
+      anon_sym_GT,
+    ACTIONS(194), 1,
+      anon_sym_SLASH_GT,
+    STATE(89), 1,
+      sym_attribute_name,
+    STATE(103), 1,
+      sym_attribute_interpolation,
+    STATE(38), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [1365] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(131), 1,
+      anon_sym_GT,
+    ACTIONS(135), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(137), 1,
+      anon_sym_LBRACE,
+    ACTIONS(139), 1,
+      sym_grit_metavariable,
+    ACTIONS(196), 1,
+      anon_sym_SLASH_GT,
+    STATE(89), 1,
+      sym_attribute_name,
+    STATE(103), 1,
+      sym_attribute_interpolation,
+    STATE(39), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [1394] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(135), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(137), 1,
+      anon_sym_LBRACE,
+    ACTIONS(139), 1,
+      sym_grit_metavariable,
+    ACTIONS(180), 1,
+      anon_sym_GT,
+    ACTIONS(198), 1,
+      anon_sym_SLASH_GT,
+    STATE(89), 1,
+      sym_attribute_name,
+    STATE(103), 1,
+      sym_attribute_interpolation,
+    STATE(24), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [1423] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(135), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(137), 1,
+      anon_sym_LBRACE,
+    ACTIONS(139), 1,
+      sym_grit_metavariable,
+    ACTIONS(186), 1,
+      anon_sym_GT,
+    ACTIONS(200), 1,
+      anon_sym_SLASH_GT,
+    STATE(89), 1,
+      sym_attribute_name,
+    STATE(103), 1,
+      sym_attribute_interpolation,
+    STATE(24), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [1452] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(135), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(137), 1,
+      anon_sym_LBRACE,
+    ACTIONS(139), 1,
+      sym_grit_metavariable,
+    ACTIONS(180), 1,
+      anon_sym_GT,
+    ACTIONS(202), 1,
+      anon_sym_SLASH_GT,
+    STATE(89), 1,
+      sym_attribute_name,
+    STATE(103), 1,
+      sym_attribute_interpolation,
+    STATE(24), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [1481] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(135), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(137), 1,
+      anon_sym_LBRACE,
+    ACTIONS(139), 1,
+      sym_grit_metavariable,
+    ACTIONS(151), 1,
+      anon_sym_GT,
+    ACTIONS(204), 1,
+      anon_sym_SLASH_GT,
+    STATE(89), 1,
+      sym_attribute_name,
+    STATE(103), 1,
+      sym_attribute_interpolation,
+    STATE(24), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [1510] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(208), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(206), 5,
+      sym__implicit_end_tag,
+      sym__html_interpolation_start,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1525] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(212), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(210), 5,
+      sym__implicit_end_tag,
+      sym__html_interpolation_start,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1540] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(216), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(214), 5,
+      sym__html_interpolation_start,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1555] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(220), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(218), 5,
+      sym__html_interpolation_start,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1570] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(224), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(222), 5,
+      sym__html_interpolation_start,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1585] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(228), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(226), 5,
+      sym__html_interpolation_start,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1600] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(232), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(230), 5,
+      sym__html_interpolation_start,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1615] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(236), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(234), 5,
+      sym__html_interpolation_start,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1630] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(240), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(238), 5,
+      sym__html_interpolation_start,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1645] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(244), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(242), 5,
+      sym__html_interpolation_start,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1660] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(248), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(246), 5,
+      sym__html_interpolation_start,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1675] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(252), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(250), 5,
+      sym__html_interpolation_start,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1690] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(256), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(254), 5,
+      sym__html_interpolation_start,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1705] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(260), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(258), 5,
+      sym__html_interpolation_start,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1720] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(264), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(262), 5,
+      sym__implicit_end_tag,
+      sym__html_interpolation_start,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1735] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(268), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(266), 5,
+      sym__html_interpolation_start,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1750] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(208), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(206), 5,
+      sym__html_interpolation_start,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1765] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(272), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(270), 5,
+      sym__html_interpolation_start,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1780] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(276), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(274), 5,
+      sym__html_interpolation_start,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1795] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(280), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(278), 5,
+      sym__html_interpolation_start,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1810] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(232), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(230), 5,
+      sym__implicit_end_tag,
+      sym__html_interpolation_start,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1825] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(284), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(282), 5,
+      sym__implicit_end_tag,
+      sym__html_interpolation_start,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1840] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(216), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(214), 5,
+      sym__implicit_end_tag,
+      sym__html_interpolation_start,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1855] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(224), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(222), 5,
+      sym__implicit_end_tag,
+      sym__html_interpolation_start,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1870] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(260), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(258), 5,
+      sym__implicit_end_tag,
+      sym__html_interpolation_start,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1885] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(288), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(286), 5,
+      sym__implicit_end_tag,
+      sym__html_interpolation_start,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1900] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(290), 5,
+      sym__implicit_end_tag,
+      sym__html_interpolation_start,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1915] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(296), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(294), 5,
+      sym__implicit_end_tag,
+      sym__html_interpolation_start,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1930] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(300), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(298), 5,
+      sym__implicit_end_tag,
+      sym__html_interpolation_start,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1945] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(304), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(302), 5,
+      sym__implicit_end_tag,
+      sym__html_interpolation_start,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1960] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(220), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(218), 5,
+      sym__implicit_end_tag,
+      sym__html_interpolation_start,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1975] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(228), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(226), 5,
+      sym__implicit_end_tag,
+      sym__html_interpolation_start,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [1990] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(240), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(238), 5,
+      sym__implicit_end_tag,
+      sym__html_interpolation_start,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [2005] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(244), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(242), 5,
+      sym__implicit_end_tag,
+      sym__html_interpolation_start,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [2020] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(248), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(246), 5,
+      sym__implicit_end_tag,
+      sym__html_interpolation_start,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [2035] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(252), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(250), 5,
+      sym__implicit_end_tag,
+      sym__html_interpolation_start,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [2050] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(256), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(254), 5,
+      sym__implicit_end_tag,
+      sym__html_interpolation_start,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [2065] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(268), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(266), 5,
+      sym__implicit_end_tag,
+      sym__html_interpolation_start,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [2080] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(272), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(270), 5,
+      sym__implicit_end_tag,
+      sym__html_interpolation_start,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [2095] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(276), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(274), 5,
+      sym__implicit_end_tag,
+      sym__html_interpolation_start,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [2110] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(280), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(278), 5,
+      sym__implicit_end_tag,
+      sym__html_interpolation_start,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [2125] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(308), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(306), 5,
+      sym__implicit_end_tag,
+      sym__html_interpolation_start,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [2140] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(312), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(310), 5,
+      sym__html_interpolation_start,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [2155] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(288), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(286), 5,
+      sym__html_interpolation_start,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [2170] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(290), 5,
+      sym__html_interpolation_start,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [2185] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(296), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(294), 5,
+      sym__html_interpolation_start,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [2200] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(284), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(282), 5,
+      sym__html_interpolation_start,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [2215] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(300), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(298), 5,
+      sym__html_interpolation_start,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [2230] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(304), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(302), 5,
+      sym__html_interpolation_start,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_grit_metavariable,
+  [2245] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(316), 1,
+      anon_sym_EQ,
+    ACTIONS(318), 2,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+    ACTIONS(314), 3,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      sym_grit_metavariable,
+  [2261] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(322), 2,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+    ACTIONS(320), 4,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      anon_sym_EQ,
+      sym_grit_metavariable,
+  [2275] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(220), 1,
+      anon_sym_LT,
+    ACTIONS(218), 4,
+      sym__html_interpolation_start,
+      sym__html_interpolation_end,
+      sym_permissible_text,
+      anon_sym_LT_BANG,
+  [2288] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(228), 1,
+      anon_sym_LT,
+    ACTIONS(226), 4,
+      sym__html_interpolation_start,
+      sym__html_interpolation_end,
+      sym_permissible_text,
+      anon_sym_LT_BANG,
+  [2301] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(240), 1,
+      anon_sym_LT,
+    ACTIONS(238), 4,
+      sym__html_interpolation_start,
+      sym__html_interpolation_end,
+      sym_permissible_text,
+      anon_sym_LT_BANG,
+  [2314] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(244), 1,
+      anon_sym_LT,
+    ACTIONS(242), 4,
+      sym__html_interpolation_start,
+      sym__html_interpolation_end,
+      sym_permissible_text,
+      anon_sym_LT_BANG,
+  [2327] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(248), 1,
+      anon_sym_LT,
+    ACTIONS(246), 4,
+      sym__html_interpolation_start,
+      sym__html_interpolation_end,
+      sym_permissible_text,
+      anon_sym_LT_BANG,
+  [2340] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(252), 1,
+      anon_sym_LT,
+    ACTIONS(250), 4,
+      sym__html_interpolation_start,
+      sym__html_interpolation_end,
+      sym_permissible_text,
+      anon_sym_LT_BANG,
+  [2353] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(256), 1,
+      anon_sym_LT,
+    ACTIONS(254), 4,
+      sym__html_interpolation_start,
+      sym__html_interpolation_end,
+      sym_permissible_text,
+      anon_sym_LT_BANG,
+  [2366] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(268), 1,
+      anon_sym_LT,
+    ACTIONS(266), 4,
+      sym__html_interpolation_start,
+      sym__html_interpolation_end,
+      sym_permissible_text,
+      anon_sym_LT_BANG,
+  [2379] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(272), 1,
+      anon_sym_LT,
+    ACTIONS(270), 4,
+      sym__html_interpolation_start,
+      sym__html_interpolation_end,
+      sym_permissible_text,
+      anon_sym_LT_BANG,
+  [2392] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(276), 1,
+      anon_sym_LT,
+    ACTIONS(274), 4,
+      sym__html_interpolation_start,
+      sym__html_interpolation_end,
+      sym_permissible_text,
+      anon_sym_LT_BANG,
+  [2405] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(280), 1,
+      anon_sym_LT,
+    ACTIONS(278), 4,
+      sym__html_interpolation_start,
+      sym__html_interpolation_end,
+      sym_permissible_text,
+      anon_sym_LT_BANG,
+  [2418] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(304), 1,
+      anon_sym_LT,
+    ACTIONS(302), 4,
+      sym__html_interpolation_start,
+      sym__html_interpolation_end,
+      sym_permissible_text,
+      anon_sym_LT_BANG,
+  [2431] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(318), 2,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+    ACTIONS(314), 3,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      sym_grit_metavariable,
+  [2444] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(326), 2,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+    ACTIONS(324), 3,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      sym_grit_metavariable,
+  [2457] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(330), 2,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+    ACTIONS(328), 3,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      sym_grit_metavariable,
+  [2470] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(334), 2,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+    ACTIONS(332), 3,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      sym_grit_metavariable,
+  [2483] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(338), 2,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+    ACTIONS(336), 3,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      sym_grit_metavariable,
+  [2496] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(284), 1,
+      anon_sym_LT,
+    ACTIONS(282), 4,
+      sym__html_interpolation_start,
+      sym__html_interpolation_end,
+      sym_permissible_text,
+      anon_sym_LT_BANG,
+  [2509] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(342), 2,
+      aux_sym_attribute_name_token1,
+      anon_sym_LBRACE,
+    ACTIONS(340), 3,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      sym_grit_metavariable,
+  [2522] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(216), 1,
+      anon_sym_LT,
+    ACTIONS(214), 4,
+      sym__html_interpolation_start,
+      sym__html_interpolation_end,
+      sym_permissible_text,
+      anon_sym_LT_BANG,
+  [2535] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(224), 1,
+      anon_sym_LT,
+    ACTIONS(222), 4,
+      sym__html_interpolation_start,
+      sym__html_interpolation_end,
+      sym_permissible_text,
+      anon_sym_LT_BANG,
+  [2548] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(260), 1,
+      anon_sym_LT,
+    ACTIONS(258), 4,
+      sym__html_interpolation_start,
+      sym__html_interpolation_end,
+      sym_permissible_text,
+      anon_sym_LT_BANG,
+  [2561] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(288), 1,
+      anon_sym_LT,
+    ACTIONS(286), 4,
+      sym__html_interpolation_start,
+      sym__html_interpolation_end,
+      sym_permissible_text,
+      anon_sym_LT_BANG,
+  [2574] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_LT,
+    ACTIONS(290), 4,
+      sym__html_interpolation_start,
+      sym__html_interpolation_end,
+      sym_permissible_text,
+      anon_sym_LT_BANG,
+  [2587] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(296), 1,
+      anon_sym_LT,
+    ACTIONS(294), 4,
+      sym__html_interpolation_start,
+      sym__html_interpolation_end,
+      sym_permissible_text,
+      anon_sym_LT_BANG,
+  [2600] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(300), 1,
+      anon_sym_LT,
+    ACTIONS(298), 4,
+      sym__html_interpolation_start,
+      sym__html_interpolation_end,
+      sym_permissible_text,
+      anon_sym_LT_BANG,
+  [2613] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(344), 1,
+      sym__start_tag_name,
+    ACTIONS(346), 1,
+      sym__script_start_tag_name,
+    ACTIONS(348), 1,
+      sym__style_start_tag_name,
+    ACTIONS(350), 1,
+      sym__fragment_tag_delim,
+  [2629] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(350), 1,
+      sym__fragment_tag_delim,
+    ACTIONS(352), 1,
+      sym__start_tag_name,
+    ACTIONS(354), 1,
+      sym__script_start_tag_name,
+    ACTIONS(356), 1,
+      sym__style_start_tag_name,
+  [2645] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(350), 1,
+      sym__fragment_tag_delim,
+    ACTIONS(358), 1,
+      sym__start_tag_name,
+    ACTIONS(360), 1,
+      sym__script_start_tag_name,
+    ACTIONS(362), 1,
+      sym__style_start_tag_name,
+  [2661] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(364), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(366), 1,
+      sym_raw_text,
+    STATE(114), 1,
+      sym_end_tag,
+  [2674] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      sym__end_tag_name,
+    ACTIONS(370), 1,
+      sym_erroneous_end_tag_name,
+    ACTIONS(372), 1,
+      sym__fragment_tag_delim,
+  [2687] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(364), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(374), 1,
+      sym_raw_text,
+    STATE(115), 1,
+      sym_end_tag,
+  [2700] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(376), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(378), 1,
+      sym_raw_text,
+    STATE(84), 1,
+      sym_end_tag,
+  [2713] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(370), 1,
+      sym_erroneous_end_tag_name,
+    ACTIONS(380), 1,
+      sym__end_tag_name,
+    ACTIONS(382), 1,
+      sym__fragment_tag_delim,
+  [2726] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(384), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(386), 1,
+      sym_raw_text,
+    STATE(66), 1,
+      sym_end_tag,
+  [2739] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(384), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(388), 1,
+      sym_raw_text,
+    STATE(67), 1,
+      sym_end_tag,
+  [2752] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(390), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(392), 2,
+      aux_sym_quoted_attribute_value_token2,
+      sym_grit_metavariable,
+  [2763] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(390), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(394), 2,
+      aux_sym_quoted_attribute_value_token1,
+      sym_grit_metavariable,
+  [2774] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(376), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(396), 1,
+      sym_raw_text,
+    STATE(85), 1,
+      sym_end_tag,
+  [2787] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(370), 1,
+      sym_erroneous_end_tag_name,
+    ACTIONS(398), 1,
+      sym__end_tag_name,
+    ACTIONS(400), 1,
+      sym__fragment_tag_delim,
+  [2800] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(402), 2,
+      sym_raw_text,
+      anon_sym_LT_SLASH,
+  [2808] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(404), 2,
+      sym_raw_text,
+      anon_sym_LT_SLASH,
+  [2816] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(364), 1,
+      anon_sym_LT_SLASH,
+    STATE(96), 1,
+      sym_end_tag,
+  [2826] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      sym__end_tag_name,
+    ACTIONS(372), 1,
+      sym__fragment_tag_delim,
+  [2836] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(364), 1,
+      anon_sym_LT_SLASH,
+    STATE(95), 1,
+      sym_end_tag,
+  [2846] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(376), 1,
+      anon_sym_LT_SLASH,
+    STATE(50), 1,
+      sym_end_tag,
+  [2856] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(380), 1,
+      sym__end_tag_name,
+    ACTIONS(382), 1,
+      sym__fragment_tag_delim,
+  [2866] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(406), 2,
+      sym_raw_text,
+      anon_sym_LT_SLASH,
+  [2874] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(376), 1,
+      anon_sym_LT_SLASH,
+    STATE(51), 1,
+      sym_end_tag,
+  [2884] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(408), 1,
+      anon_sym_DASH_DASH_DASH2,
+    ACTIONS(410), 1,
+      sym_frontmatter_js_block,
+  [2894] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(384), 1,
+      anon_sym_LT_SLASH,
+    STATE(74), 1,
+      sym_end_tag,
+  [2904] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(398), 1,
+      sym__end_tag_name,
+    ACTIONS(400), 1,
+      sym__fragment_tag_delim,
+  [2914] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(384), 1,
+      anon_sym_LT_SLASH,
+    STATE(75), 1,
+      sym_end_tag,
+  [2924] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(412), 2,
+      sym_raw_text,
+      anon_sym_LT_SLASH,
+  [2932] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(414), 1,
+      anon_sym_GT,
+  [2939] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(416), 1,
+      anon_sym_GT,
+  [2946] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(418), 1,
+      anon_sym_GT,
+  [2953] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(420), 1,
+      anon_sym_DASH_DASH_DASH2,
+  [2960] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(422), 1,
+      anon_sym_GT,
+  [2967] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(424), 1,
+      anon_sym_GT,
+  [2974] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(426), 1,
+      aux_sym_doctype_token1,
+  [2981] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(428), 1,
+      sym_erroneous_end_tag_name,
+  [2988] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(430), 1,
+      anon_sym_RBRACE,
+  [2995] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(432), 1,
+      ts_builtin_sym_end,
+  [3002] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(434), 1,
+      anon_sym_GT,
+  [3009] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(436), 1,
+      sym__doctype,
+  [3016] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(438), 1,
+      anon_sym_GT,
+  [3023] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(440), 1,
+      anon_sym_SQUOTE,
+  [3030] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_GT,
+  [3037] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(440), 1,
+      anon_sym_DQUOTE,
+  [3044] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(370), 1,
+      sym_erroneous_end_tag_name,
+  [3051] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(444), 1,
+      aux_sym_doctype_token1,
+  [3058] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(446), 1,
+      sym_attribute_js_expr,
+  [3065] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(448), 1,
+      aux_sym_doctype_token1,
+  [3072] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(450), 1,
+      sym__doctype,
+  [3079] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(452), 1,
+      sym__doctype,
+};
+
+static const uint32_t ts_small_parse_table_map[] = {
+  [SMALL_STATE(2)] = 0,
+  [SMALL_STATE(3)] = 57,
+  [SMALL_STATE(4)] = 114,
+  [SMALL_STATE(5)] = 171,
+  [SMALL_STATE(6)] = 228,
+  [SMALL_STATE(7)] = 285,
+  [SMALL_STATE(8)] = 342,
+  [SMALL_STATE(9)] = 396,
+  [SMALL_STATE(10)] = 450,
+  [SMALL_STATE(11)] = 504,
+  [SMALL_STATE(12)] = 558,
+  [SMALL_STATE(13)] = 612,
+  [SMALL_STATE(14)] = 658,
+  [SMALL_STATE(15)] = 704,
+  [SMALL_STATE(16)] = 750,
+  [SMALL_STATE(17)] = 796,
+  [SMALL_STATE(18)] = 842,
+  [SMALL_STATE(19)] = 888,
+  [SMALL_STATE(20)] = 934,
+  [SMALL_STATE(21)] = 963,
+  [SMALL_STATE(22)] = 990,
+  [SMALL_STATE(23)] = 1019,
+  [SMALL_STATE(24)] = 1048,
+  [SMALL_STATE(25)] = 1075,
+  [SMALL_STATE(26)] = 1104,
+  [SMALL_STATE(27)] = 1133,
+  [SMALL_STATE(28)] = 1162,
+  [SMALL_STATE(29)] = 1191,
+  [SMALL_STATE(30)] = 1220,
+  [SMALL_STATE(31)] = 1249,
+  [SMALL_STATE(32)] = 1278,
+  [SMALL_STATE(33)] = 1307,
+  [SMALL_STATE(34)] = 1336,
+  [SMALL_STATE(35)] = 1365,
+  [SMALL_STATE(36)] = 1394,
+  [SMALL_STATE(37)] = 1423,
+  [SMALL_STATE(38)] = 1452,
+  [SMALL_STATE(39)] = 1481,
+  [SMALL_STATE(40)] = 1510,
+  [SMALL_STATE(41)] = 1525,
+  [SMALL_STATE(42)] = 1540,
+  [SMALL_STATE(43)] = 1555,
+  [SMALL_STATE(44)] = 1570,
+  [SMALL_STATE(45)] = 1585,
+  [SMALL_STATE(46)] = 1600,
+  [SMALL_STATE(47)] = 1615,
+  [SMALL_STATE(48)] = 1630,
+  [SMALL_STATE(49)] = 1645,
+  [SMALL_STATE(50)] = 1660,
+  [SMALL_STATE(51)] = 1675,
+  [SMALL_STATE(52)] = 1690,
+  [SMALL_STATE(53)] = 1705,
+  [SMALL_STATE(54)] = 1720,
+  [SMALL_STATE(55)] = 1735,
+  [SMALL_STATE(56)] = 1750,
+  [SMALL_STATE(57)] = 1765,
+  [SMALL_STATE(58)] = 1780,
+  [SMALL_STATE(59)] = 1795,
+  [SMALL_STATE(60)] = 1810,
+  [SMALL_STATE(61)] = 1825,
+  [SMALL_STATE(62)] = 1840,
+  [SMALL_STATE(63)] = 1855,
+  [SMALL_STATE(64)] = 1870,
+  [SMALL_STATE(65)] = 1885,
+  [SMALL_STATE(66)] = 1900,
+  [SMALL_STATE(67)] = 1915,
+  [SMALL_STATE(68)] = 1930,
+  [SMALL_STATE(69)] = 1945,
+  [SMALL_STATE(70)] = 1960,
+  [SMALL_STATE(71)] = 1975,
+  [SMALL_STATE(72)] = 1990,
+  [SMALL_STATE(73)] = 2005,
+  [SMALL_STATE(74)] = 2020,
+  [SMALL_STATE(75)] = 2035,
+  [SMALL_STATE(76)] = 2050,
+  [SMALL_STATE(77)] = 2065,
+  [SMALL_STATE(78)] = 2080,
+  [SMALL_STATE(79)] = 2095,
+  [SMALL_STATE(80)] = 2110,
+  [SMALL_STATE(81)] = 2125,
+  [SMALL_STATE(82)] = 2140,
+  [SMALL_STATE(83)] = 2155,
+  [SMALL_STATE(84)] = 2170,
+  [SMALL_STATE(85)] = 2185,
+  [SMALL_STATE(86)] = 2200,
+  [SMALL_STATE(87)] = 2215,
+  [SMALL_STATE(88)] = 2230,
+  [SMALL_STATE(89)] = 2245,
+  [SMALL_STATE(90)] = 2261,
+  [SMALL_STATE(91)] = 2275,
+  [SMALL_STATE(92)] = 2288,
+  [SMALL_STATE(93)] = 2301,
+  [SMALL_STATE(94)] = 2314,
+  [SMALL_STATE(95)] = 2327,
+  [SMALL_STATE(96)] = 2340,
+  [SMALL_STATE(97)] = 2353,
+  [SMALL_STATE(98)] = 2366,
+  [SMALL_STATE(99)] = 2379,
+  [SMALL_STATE(100)] = 2392,
+  [SMALL_STATE(101)] = 2405,
+  [SMALL_STATE(102)] = 2418,
+  [SMALL_STATE(103)] = 2431,
+  [SMALL_STATE(104)] = 2444,
+  [SMALL_STATE(105)] = 2457,
+  [SMALL_STATE(106)] = 2470,
+  [SMALL_STATE(107)] = 2483,
+  [SMALL_STATE(108)] = 2496,
+  [SMALL_STATE(109)] = 2509,
+  [SMALL_STATE(110)] = 2522,
+  [SMALL_STATE(111)] = 2535,
+  [SMALL_STATE(112)] = 2548,
+  [SMALL_STATE(113)] = 2561,
+  [SMALL_STATE(114)] = 2574,
+  [SMALL_STATE(115)] = 2587,
+  [SMALL_STATE(116)] = 2600,
+  [SMALL_STATE(117)] = 2613,
+  [SMALL_STATE(118)] = 2629,
+  [SMALL_STATE(119)] = 2645,
+  [SMALL_STATE(120)] = 2661,
+  [SMALL_STATE(121)] = 2674,
+  [SMALL_STATE(122)] = 2687,
+  [SMALL_STATE(123)] = 2700,
+  [SMALL_STATE(124)] = 2713,
+  [SMALL_STATE(125)] = 2726,
+  [SMALL_STATE(126)] = 2739,
+  [SMALL_STATE(127)] = 2752,
+  [SMALL_STATE(128)] = 2763,
+  [SMALL_STATE(129)] = 2774,
+  [SMALL_STATE(130)] = 2787,
+  [SMALL_STATE(131)] = 2800,
+  [SMALL_STATE(132)] = 2808,
+  [SMALL_STATE(133)] = 2816,
+  [SMALL_STATE(134)] = 2826,
+  [SMALL_STATE(135)] = 2836,
+  [SMALL_STATE(136)] = 2846,
+  [SMALL_STATE(137)] = 2856,
+  [SMALL_STATE(138)] = 2866,
+  [SMALL_STATE(139)] = 2874,
+  [SMALL_STATE(140)] = 2884,
+  [SMALL_STATE(141)] = 2894,
+  [SMALL_STATE(142)] = 2904,
+  [SMALL_STATE(143)] = 2914,
+  [SMALL_STATE(144)] = 2924,
+  [SMALL_STATE(145)] = 2932,
+  [SMALL_STATE(146)] = 2939,
+  [SMALL_STATE(147)] = 2946,
+  [SMALL_STATE(148)] = 2953,
+  [SMALL_STATE(149)] = 2960,
+  [SMALL_STATE(150)] = 2967,
+  [SMALL_STATE(151)] = 2974,
+  [SMALL_STATE(152)] = 2981,
+  [SMALL_STATE(153)] = 2988,
+  [SMALL_STATE(154)] = 2995,
+  [SMALL_STATE(155)] = 3002,
+  [SMALL_STATE(156)] = 3009,
+  [SMALL_STATE(157)] = 3016,
+  [SMALL_STATE(158)] = 3023,
+  [SMALL_STATE(159)] = 3030,
+  [SMALL_STATE(160)] = 3037,
+  [SMALL_STATE(161)] = 3044,
+  [SMALL_STATE(162)] = 3051,
+  [SMALL_STATE(163)] = 3058,
+  [SMALL_STATE(164)] = 3065,
+  [SMALL_STATE(165)] = 3072,
+  [SMALL_STATE(166)] = 3079,
+};
+
+static const TSParseActionEntry ts_parse_actions[] = {
+  [0] = {.entry = {.count = 0, .reusable = false}},
+  [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
+  [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT_EXTRA(),
+  [5] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_document, 0, 0, 0),
+  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(156),
+  [9] = {.entry = {.count = 1, .reusable = false}}, SHIFT(117),
+  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(152),
+  [13] = {.entry = {.count = 1, .reusable = false}}, SHIFT(56),
+  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(140),
+  [17] = {.entry = {.count = 1, .reusable = true}}, SHIFT(56),
+  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
+  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(166),
+  [23] = {.entry = {.count = 1, .reusable = false}}, SHIFT(119),
+  [25] = {.entry = {.count = 1, .reusable = true}}, SHIFT(124),
+  [27] = {.entry = {.count = 1, .reusable = false}}, SHIFT(40),
+  [29] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
+  [31] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
+  [33] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
+  [35] = {.entry = {.count = 1, .reusable = true}}, SHIFT(130),
+  [37] = {.entry = {.count = 1, .reusable = true}}, SHIFT(73),
+  [39] = {.entry = {.count = 1, .reusable = true}}, SHIFT(49),
+  [41] = {.entry = {.count = 1, .reusable = true}}, SHIFT(121),
+  [43] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
+  [45] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
+  [47] = {.entry = {.count = 1, .reusable = true}}, SHIFT(113),
+  [49] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0),
+  [51] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(156),
+  [54] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(117),
+  [57] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(152),
+  [60] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(56),
+  [63] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(56),
+  [66] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(14),
+  [69] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_document, 1, 0, 0),
+  [71] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(166),
+  [74] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(119),
+  [77] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(161),
+  [80] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(40),
+  [83] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(40),
+  [86] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(19),
+  [89] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_document, 2, 0, 0),
+  [91] = {.entry = {.count = 1, .reusable = true}}, SHIFT(165),
+  [93] = {.entry = {.count = 1, .reusable = false}}, SHIFT(118),
+  [95] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
+  [97] = {.entry = {.count = 1, .reusable = true}}, SHIFT(112),
+  [99] = {.entry = {.count = 1, .reusable = true}}, SHIFT(15),
+  [101] = {.entry = {.count = 1, .reusable = true}}, SHIFT(53),
+  [103] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
+  [105] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
+  [107] = {.entry = {.count = 1, .reusable = true}}, SHIFT(17),
+  [109] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
+  [111] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_html_interpolation_repeat1, 2, 0, 0), SHIFT_REPEAT(165),
+  [114] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_html_interpolation_repeat1, 2, 0, 0), SHIFT_REPEAT(118),
+  [117] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_html_interpolation_repeat1, 2, 0, 0), SHIFT_REPEAT(13),
+  [120] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_html_interpolation_repeat1, 2, 0, 0),
+  [122] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_html_interpolation_repeat1, 2, 0, 0), SHIFT_REPEAT(17),
+  [125] = {.entry = {.count = 1, .reusable = true}}, SHIFT(68),
+  [127] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
+  [129] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
+  [131] = {.entry = {.count = 1, .reusable = true}}, SHIFT(138),
+  [133] = {.entry = {.count = 1, .reusable = true}}, SHIFT(45),
+  [135] = {.entry = {.count = 1, .reusable = false}}, SHIFT(90),
+  [137] = {.entry = {.count = 1, .reusable = false}}, SHIFT(163),
+  [139] = {.entry = {.count = 1, .reusable = true}}, SHIFT(90),
+  [141] = {.entry = {.count = 1, .reusable = false}}, SHIFT(105),
+  [143] = {.entry = {.count = 1, .reusable = true}}, SHIFT(128),
+  [145] = {.entry = {.count = 1, .reusable = true}}, SHIFT(127),
+  [147] = {.entry = {.count = 1, .reusable = true}}, SHIFT(105),
+  [149] = {.entry = {.count = 1, .reusable = true}}, SHIFT(109),
+  [151] = {.entry = {.count = 1, .reusable = true}}, SHIFT(132),
+  [153] = {.entry = {.count = 1, .reusable = true}}, SHIFT(58),
+  [155] = {.entry = {.count = 1, .reusable = true}}, SHIFT(131),
+  [157] = {.entry = {.count = 1, .reusable = true}}, SHIFT(43),
+  [159] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0),
+  [161] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(90),
+  [164] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(163),
+  [167] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(90),
+  [170] = {.entry = {.count = 1, .reusable = true}}, SHIFT(41),
+  [172] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
+  [174] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
+  [176] = {.entry = {.count = 1, .reusable = true}}, SHIFT(88),
+  [178] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
+  [180] = {.entry = {.count = 1, .reusable = true}}, SHIFT(144),
+  [182] = {.entry = {.count = 1, .reusable = true}}, SHIFT(99),
+  [184] = {.entry = {.count = 1, .reusable = true}}, SHIFT(100),
+  [186] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
+  [188] = {.entry = {.count = 1, .reusable = true}}, SHIFT(55),
+  [190] = {.entry = {.count = 1, .reusable = true}}, SHIFT(98),
+  [192] = {.entry = {.count = 1, .reusable = true}}, SHIFT(69),
+  [194] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
+  [196] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
+  [198] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
+  [200] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
+  [202] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
+  [204] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
+  [206] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_text, 1, 0, 0),
+  [208] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_text, 1, 0, 0),
+  [210] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_start_tag, 3, 0, 0),
+  [212] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_start_tag, 3, 0, 0),
+  [214] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_script_element, 1, 0, 0),
+  [216] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_script_element, 1, 0, 0),
+  [218] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_self_closing_script_tag, 3, 0, 0),
+  [220] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_self_closing_script_tag, 3, 0, 0),
+  [222] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_style_element, 1, 0, 0),
+  [224] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_style_element, 1, 0, 0),
+  [226] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_self_closing_style_tag, 3, 0, 0),
+  [228] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_self_closing_style_tag, 3, 0, 0),
+  [230] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_erroneous_end_tag, 3, 0, 0),
+  [232] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_erroneous_end_tag, 3, 0, 0),
+  [234] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_frontmatter, 3, 0, 0),
+  [236] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_frontmatter, 3, 0, 0),
+  [238] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_end_tag, 2, 0, 0),
+  [240] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_end_tag, 2, 0, 0),
+  [242] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 3, 0, 0),
+  [244] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 3, 0, 0),
+  [246] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_script_element, 3, 0, 0),
+  [248] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_script_element, 3, 0, 0),
+  [250] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_style_element, 3, 0, 0),
+  [252] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_style_element, 3, 0, 0),
+  [254] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctype, 4, 0, 0),
+  [256] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctype, 4, 0, 0),
+  [258] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_html_interpolation, 2, 0, 0),
+  [260] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_html_interpolation, 2, 0, 0),
+  [262] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_start_tag, 4, 0, 0),
+  [264] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_start_tag, 4, 0, 0),
+  [266] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_self_closing_tag, 4, 0, 0),
+  [268] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_self_closing_tag, 4, 0, 0),
+  [270] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_self_closing_script_tag, 4, 0, 0),
+  [272] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_self_closing_script_tag, 4, 0, 0),
+  [274] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_self_closing_style_tag, 4, 0, 0),
+  [276] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_self_closing_style_tag, 4, 0, 0),
+  [278] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_end_tag, 3, 0, 0),
+  [280] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_end_tag, 3, 0, 0),
+  [282] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 1, 0, 0),
+  [284] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 1, 0, 0),
+  [286] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 2, 0, 0),
+  [288] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 2, 0, 0),
+  [290] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_script_element, 2, 0, 0),
+  [292] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_script_element, 2, 0, 0),
+  [294] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_style_element, 2, 0, 0),
+  [296] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_style_element, 2, 0, 0),
+  [298] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_html_interpolation, 3, 0, 0),
+  [300] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_html_interpolation, 3, 0, 0),
+  [302] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_self_closing_tag, 3, 0, 0),
+  [304] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_self_closing_tag, 3, 0, 0),
+  [306] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_start_tag, 2, 0, 0),
+  [308] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_start_tag, 2, 0, 0),
+  [310] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_frontmatter, 2, 0, 0),
+  [312] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_frontmatter, 2, 0, 0),
+  [314] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 1, 0, 0),
+  [316] = {.entry = {.count = 1, .reusable = true}}, SHIFT(21),
+  [318] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute, 1, 0, 0),
+  [320] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute_name, 1, 0, 0),
+  [322] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute_name, 1, 0, 0),
+  [324] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute_interpolation, 3, 0, 0),
+  [326] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute_interpolation, 3, 0, 0),
+  [328] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute_value, 1, 0, 0),
+  [330] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute_value, 1, 0, 0),
+  [332] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_quoted_attribute_value, 2, 0, 0),
+  [334] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_quoted_attribute_value, 2, 0, 0),
+  [336] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_quoted_attribute_value, 3, 0, 0),
+  [338] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_quoted_attribute_value, 3, 0, 0),
+  [340] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 3, 0, 0),
+  [342] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute, 3, 0, 0),
+  [344] = {.entry = {.count = 1, .reusable = true}}, SHIFT(27),
+  [346] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
+  [348] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
+  [350] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
+  [352] = {.entry = {.count = 1, .reusable = true}}, SHIFT(25),
+  [354] = {.entry = {.count = 1, .reusable = true}}, SHIFT(26),
+  [356] = {.entry = {.count = 1, .reusable = true}}, SHIFT(28),
+  [358] = {.entry = {.count = 1, .reusable = true}}, SHIFT(33),
+  [360] = {.entry = {.count = 1, .reusable = true}}, SHIFT(34),
+  [362] = {.entry = {.count = 1, .reusable = true}}, SHIFT(35),
+  [364] = {.entry = {.count = 1, .reusable = true}}, SHIFT(134),
+  [366] = {.entry = {.count = 1, .reusable = true}}, SHIFT(135),
+  [368] = {.entry = {.count = 1, .reusable = true}}, SHIFT(145),
+  [370] = {.entry = {.count = 1, .reusable = true}}, SHIFT(150),
+  [372] = {.entry = {.count = 1, .reusable = true}}, SHIFT(93),
+  [374] = {.entry = {.count = 1, .reusable = true}}, SHIFT(133),
+  [376] = {.entry = {.count = 1, .reusable = true}}, SHIFT(137),
+  [378] = {.entry = {.count = 1, .reusable = true}}, SHIFT(136),
+  [380] = {.entry = {.count = 1, .reusable = true}}, SHIFT(149),
+  [382] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
+  [384] = {.entry = {.count = 1, .reusable = true}}, SHIFT(142),
+  [386] = {.entry = {.count = 1, .reusable = true}}, SHIFT(141),
+  [388] = {.entry = {.count = 1, .reusable = true}}, SHIFT(143),
+  [390] = {.entry = {.count = 1, .reusable = false}}, SHIFT(106),
+  [392] = {.entry = {.count = 1, .reusable = false}}, SHIFT(160),
+  [394] = {.entry = {.count = 1, .reusable = false}}, SHIFT(158),
+  [396] = {.entry = {.count = 1, .reusable = true}}, SHIFT(139),
+  [398] = {.entry = {.count = 1, .reusable = true}}, SHIFT(159),
+  [400] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
+  [402] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_script_start_tag, 3, 0, 0),
+  [404] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_style_start_tag, 4, 0, 0),
+  [406] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_style_start_tag, 3, 0, 0),
+  [408] = {.entry = {.count = 1, .reusable = true}}, SHIFT(82),
+  [410] = {.entry = {.count = 1, .reusable = true}}, SHIFT(148),
+  [412] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_script_start_tag, 4, 0, 0),
+  [414] = {.entry = {.count = 1, .reusable = true}}, SHIFT(101),
+  [416] = {.entry = {.count = 1, .reusable = true}}, SHIFT(46),
+  [418] = {.entry = {.count = 1, .reusable = true}}, SHIFT(97),
+  [420] = {.entry = {.count = 1, .reusable = true}}, SHIFT(47),
+  [422] = {.entry = {.count = 1, .reusable = true}}, SHIFT(59),
+  [424] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
+  [426] = {.entry = {.count = 1, .reusable = true}}, SHIFT(157),
+  [428] = {.entry = {.count = 1, .reusable = true}}, SHIFT(146),
+  [430] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
+  [432] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [434] = {.entry = {.count = 1, .reusable = true}}, SHIFT(76),
+  [436] = {.entry = {.count = 1, .reusable = true}}, SHIFT(151),
+  [438] = {.entry = {.count = 1, .reusable = true}}, SHIFT(52),
+  [440] = {.entry = {.count = 1, .reusable = true}}, SHIFT(107),
+  [442] = {.entry = {.count = 1, .reusable = true}}, SHIFT(80),
+  [444] = {.entry = {.count = 1, .reusable = true}}, SHIFT(147),
+  [446] = {.entry = {.count = 1, .reusable = true}}, SHIFT(153),
+  [448] = {.entry = {.count = 1, .reusable = true}}, SHIFT(155),
+  [450] = {.entry = {.count = 1, .reusable = true}}, SHIFT(162),
+  [452] = {.entry = {.count = 1, .reusable = true}}, SHIFT(164),
+};
+
+enum ts_external_scanner_symbol_identifiers {
+  ts_external_token__start_tag_name = 0,
+  ts_external_token__script_start_tag_name = 1,
+  ts_external_token__style_start_tag_name = 2,
+  ts_external_token__end_tag_name = 3,
+  ts_external_token_erroneous_end_tag_name = 4,
+  ts_external_token_SLASH_GT = 5,
+  ts_external_token__implicit_end_tag = 6,
+  ts_external_token_raw_text = 7,
+  ts_external_token_comment = 8,
+  ts_external_token__html_interpolation_start = 9,
+  ts_external_token__html_interpolation_end = 10,
+  ts_external_token_frontmatter_js_block = 11,
+  ts_external_token_attribute_js_expr = 12,
+  ts_external_token_attribute_backtick_string = 13,
+  ts_external_token_permissible_text = 14,
+  ts_external_token__fragment_tag_delim = 15,
+};
+
+static const TSSymbol ts_external_scanner_symbol_map[EXTERNAL_TOKEN_COUNT] = {
+  [ts_external_token__start_tag_name] = sym__start_tag_name,
+  [ts_external_token__script_start_tag_name] = sym__script_start_tag_name,
+  [ts_external_token__style_start_tag_name] = sym__style_start_tag_name,
+  [ts_external_token__end_tag_name] = sym__end_tag_name,
+  [ts_external_token_erroneous_end_tag_name] = sym_erroneous_end_tag_name,
+  [ts_external_token_SLASH_GT] = anon_sym_SLASH_GT,
+  [ts_external_token__implicit_end_tag] = sym__implicit_end_tag,
+  [ts_external_token_raw_text] = sym_raw_text,
+  [ts_external_token_comment] = sym_comment,
+  [ts_external_token__html_interpolation_start] = sym__html_interpolation_start,
+  [ts_external_token__html_interpolation_end] = sym__html_interpolation_end,
+  [ts_external_token_frontmatter_js_block] = sym_frontmatter_js_block,
+  [ts_external_token_attribute_js_expr] = sym_attribute_js_expr,
+  [ts_external_token_attribute_backtick_string] = sym_attribute_backtick_string,
+  [ts_external_token_permissible_text] = sym_permissible_text,
+  [ts_external_token__fragment_tag_delim] = sym__fragment_tag_delim,
+};
+
+static const bool ts_external_scanner_states[15][EXTERNAL_TOKEN_COUNT] = {
+  [1] = {
+    [ts_external_token__start_tag_name] = true,
+    [ts_external_token__script_start_tag_name] = true,
+    [ts_external_token__style_start_tag_name] = true,
+    [ts_external_token__end_tag_name] = true,
+    [ts_external_token_erroneous_end_tag_name] = true,
+    [ts_external_token_SLASH_GT] = true,
+    [ts_external_token__implicit_end_tag] = true,
+    [ts_external_token_raw_text] = true,
+    [ts_external_token_comment] = true,
+    [ts_external_token__html_interpolation_start] = true,
+    [ts_external_token__html_interpolation_end] = true,
+    [ts_external_token_frontmatter_js_block] = true,
+    [ts_external_token_attribute_js_expr] = true,
+    [ts_external_token_attribute_backtick_string] = true,
+    [ts_external_token_permissible_text] = true,
+    [ts_external_token__fragment_tag_delim] = true,
+  },
+  [2] = {
+    [ts_external_token_comment] = true,
+    [ts_external_token__html_interpolation_start] = true,
+  },
+  [3] = {
+    [ts_external_token__implicit_end_tag] = true,
+    [ts_external_token_comment] = true,
+    [ts_external_token__html_interpolation_start] = true,
+  },
+  [4] = {
+    [ts_external_token_comment] = true,
+    [ts_external_token__html_interpolation_start] = true,
+    [ts_external_token__html_interpolation_end] = true,
+    [ts_external_token_permissible_text] = true,
+  },
+  [5] = {
+    [ts_external_token_SLASH_GT] = true,
+    [ts_external_token_comment] = true,
+  },
+  [6] = {
+    [ts_external_token_comment] = true,
+    [ts_external_token_attribute_backtick_string] = true,
+  },
+  [7] = {
+    [ts_external_token__start_tag_name] = true,
+    [ts_external_token__script_start_tag_name] = true,
+    [ts_external_token__style_start_tag_name] = true,
+    [ts_external_token_comment] = true,
+    [ts_external_token__fragment_tag_delim] = true,
+  },
+  [8] = {
+    [ts_external_token_raw_text] = true,
+    [ts_external_token_comment] = true,
+  },
+  [9] = {
+    [ts_external_token__end_tag_name] = true,
+    [ts_external_token_erroneous_end_tag_name] = true,
+    [ts_external_token_comment] = true,
+    [ts_external_token__fragment_tag_delim] = true,
+  },
+  [10] = {
+    [ts_external_token_comment] = true,
+  },
+  [11] = {
+    [ts_external_token__end_tag_name] = true,
+    [ts_external_token_comment] = true,
+    [ts_external_token__fragment_tag_delim] = true,
+  },
+  [12] = {
+    [ts_external_token_comment] = true,
+    [ts_external_token_frontmatter_js_block] = true,
+  },
+  [13] = {
+    [ts_external_token_erroneous_end_tag_name] = true,
+    [ts_external_token_comment] = true,
+  },
+  [14] = {
+    [ts_external_token_comment] = true,
+    [ts_external_token_attribute_js_expr] = true,
+  },
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+void *tree_sitter_astro_external_scanner_create(void);
+void tree_sitter_astro_external_scanner_destroy(void *);
+bool tree_sitter_astro_external_scanner_scan(void *, TSLexer *, const bool *);
+unsigned tree_sitter_astro_external_scanner_serialize(void *, char *);
+void tree_sitter_astro_external_scanner_deserialize(void *, const char *, unsigned);
+
+#ifdef TREE_SITTER_HIDE_SYMBOLS
+#define TS_PUBLIC
+#elif defined(_WIN32)
+#define TS_PUBLIC __declspec(dllexport)
+#else
+#define TS_PUBLIC __attribute__((visibility("default")))
+#endif
+
+TS_PUBLIC const TSLanguage *tree_sitter_astro(void) {
+  static const TSLanguage language = {
+    .version = LANGUAGE_VERSION,
+    .symbol_count = SYMBOL_COUNT,
+    .alias_count = ALIAS_COUNT,
+    .token_count = TOKEN_COUNT,
+    .external_token_count = EXTERNAL_TOKEN_COUNT,
+    .state_count = STATE_COUNT,
+    .large_state_count = LARGE_STATE_COUNT,
+    .production_id_count = PRODUCTION_ID_COUNT,
+    .field_count = FIELD_COUNT,
+    .max_alias_sequence_length = MAX_ALIAS_SEQUENCE_LENGTH,
+    .parse_table = &ts_parse_table[0][0],
+    .small_parse_table = ts_small_parse_table,
+    .small_parse_table_map = ts_small_parse_table_map,
+    .parse_actions = ts_parse_actions,
+    .symbol_names = ts_symbol_names,
+    .symbol_metadata = ts_symbol_metadata,
+    .public_symbol_map = ts_symbol_map,
+    .alias_map = ts_non_terminal_alias_map,
+    .alias_sequences = &ts_alias_sequences[0][0],
+    .lex_modes = ts_lex_modes,
+    .lex_fn = ts_lex,
+    .external_scanner = {
+      &ts_external_scanner_states[0][0],
+      ts_external_scanner_symbol_map,
+      tree_sitter_astro_external_scanner_create,
+      tree_sitter_astro_external_scanner_destroy,
+      tree_sitter_astro_external_scanner_scan,
+      tree_sitter_astro_external_scanner_serialize,
+      tree_sitter_astro_external_scanner_deserialize,
+    },
+    .primary_state_ids = ts_primary_state_ids,
+  };
+  return &language;
+}
+#ifdef __cplusplus
+}
+#endif
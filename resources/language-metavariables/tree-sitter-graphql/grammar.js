@@ -0,0 +1,473 @@
+module.exports = grammar({
+  name: "graphql",
+
+  extras: ($) => [/[\s﻿\u0009 \u000A\u000D]/, $.comma, $.comment],
+
+  word: ($) => $._name,
+
+  rules: {
+    source_file: ($) => field("document", $.document),
+    document: ($) => repeat1(field("definition", $.definition)),
+    definition: ($) =>
+      field(
+        "definition",
+        choice(
+          $.executable_definition,
+          $.type_system_definition,
+          $.type_system_extension
+        )
+      ),
+    executable_definition: ($) =>
+      field("definition", choice($.operation_definition, $.fragment_definition)),
+    type_system_definition: ($) =>
+      field(
+        "definition",
+        choice($.schema_definition, $.type_definition, $.directive_definition)
+      ),
+    type_system_extension: ($) =>
+      field("extension", choice($.schema_extension, $.type_extension)),
+    schema_definition: ($) =>
+      seq(
+        optional(field("description", $.description)),
+        "schema",
+        optional(field("directives", $.directives)),
+        "{",
+        repeat1(field("operation_type", $.root_operation_type_definition)),
+        "}"
+      ),
+    schema_extension: ($) =>
+      seq(
+        "extend",
+        "schema",
+        optional(field("directives", $.directives)),
+        "{",
+        field("operation_type", $.root_operation_type_definition),
+        "}"
+      ),
+    type_extension: ($) =>
+      field(
+        "extension",
+        choice(
+          $.scalar_type_extension,
+          $.object_type_extension,
+          $.interface_type_extension,
+          $.union_type_extension,
+          $.enum_type_extension,
+          $.input_object_type_extension
+        )
+      ),
+    scalar_type_extension: ($) =>
+      seq(
+        "extend",
+        "scalar",
+        field("name", $.name),
+        field("directives", $.directives)
+      ),
+    object_type_extension: ($) =>
+      prec.right(
+        choice(
+          seq(
+            "extend",
+            "type",
+            field("name", $.name),
+            optional(field("interfaces", $.implements_interfaces)),
+            optional(field("directives", $.directives)),
+            field("fields", $.fields_definition)
+          ),
+          seq(
+            "extend",
+            "type",
+            field("name", $.name),
+            optional(field("interfaces", $.implements_interfaces)),
+            optional(field("directives", $.directives))
+          )
+        )
+      ),
+    interface_type_extension: ($) =>
+      prec.right(
+        choice(
+          seq(
+            "extend",
+            "interface",
+            field("name", $.name),
+            optional(field("interfaces", $.implements_interfaces)),
+            optional(field("directives", $.directives)),
+            field("fields", $.fields_definition)
+          ),
+          seq(
+            "extend",
+            "interface",
+            field("name", $.name),
+            optional(field("interfaces", $.implements_interfaces)),
+            optional(field("directives", $.directives))
+          )
+        )
+      ),
+    union_type_extension: ($) =>
+      prec.right(
+        choice(
+          seq(
+            "extend",
+            "union",
+            field("name", $.name),
+            optional(field("directives", $.directives)),
+            field("members", $.union_member_types)
+          ),
+          seq(
+            "extend",
+            "union",
+            field("name", $.name),
+            optional(field("directives", $.directives))
+          )
+        )
+      ),
+    enum_type_extension: ($) =>
+      prec.right(
+        choice(
+          seq(
+            "extend",
+            "enum",
+            field("name", $.name),
+            optional(field("directives", $.directives)),
+            field("values", $.enum_values_definition)
+          ),
+          seq(
+            "extend",
+            "enum",
+            field("name", $.name),
+            optional(field("directives", $.directives))
+          )
+        )
+      ),
+    input_object_type_extension: ($) =>
+      prec.right(
+        choice(
+          seq(
+            "extend",
+            "input",
+            field("name", $.name),
+            optional(field("directives", $.directives)),
+            repeat1(field("fields", $.input_fields_definition))
+          ),
+          seq(
+            "extend",
+            "input",
+            field("name", $.name),
+            optional(field("directives", $.directives))
+          )
+        )
+      ),
+    input_fields_definition: ($) =>
+      seq("{", repeat1(field("field", $.input_value_definition)), "}"),
+    enum_values_definition: ($) =>
+      seq("{", repeat1(field("value", $.enum_value_definition)), "}"),
+    enum_value_definition: ($) =>
+      seq(
+        optional(field("description", $.description)),
+        field("value", $.enum_value),
+        optional(field("directives", $.directives))
+      ),
+    implements_interfaces: ($) =>
+      choice(
+        seq(
+          field("interfaces", $.implements_interfaces),
+          "&",
+          field("interface", $.named_type)
+        ),
+        seq("implements", optional("&"), field("interface", $.named_type))
+      ),
+    fields_definition: ($) =>
+      seq("{", repeat1(field("field", $.field_definition)), "}"),
+    field_definition: ($) =>
+      seq(
+        optional(field("description", $.description)),
+        field("name", $.name),
+        optional(field("arguments", $.arguments_definition)),
+        ":",
+        field("type", $.type),
+        optional(field("directives", $.directives))
+      ),
+    arguments_definition: ($) =>
+      seq("(", repeat1(field("argument", $.input_value_definition)), ")"),
+    input_value_definition: ($) =>
+      seq(
+        optional(field("description", $.description)),
+        field("name", $.name),
+        ":",
+        field("type", $.type),
+        optional(field("default_value", $.default_value)),
+        optional(field("directives", $.directives))
+      ),
+    default_value: ($) => seq("=", field("value", $.value)),
+    union_member_types: ($) =>
+      choice(
+        seq(
+          field("members", $.union_member_types),
+          "|",
+          field("member", $.named_type)
+        ),
+        seq("=", optional("|"), field("member", $.named_type))
+      ),
+    root_operation_type_definition: ($) =>
+      seq(
+        field("operation_type", $.operation_type),
+        ":",
+        field("type", $.named_type)
+      ),
+    operation_definition: ($) =>
+      choice(
+        field("selection_set", $.selection_set),
+        seq(
+          field("operation_type", $.operation_type),
+          optional(field("name", $.name)),
+          optional(field("variables", $.variable_definitions)),
+          optional(field("directives", $.directives)),
+          field("selection_set", $.selection_set)
+        )
+      ),
+    operation_type: ($) => choice("query", "mutation", "subscription"),
+    type_definition: ($) =>
+      field(
+        "definition",
+        choice(
+          $.scalar_type_definition,
+          $.object_type_definition,
+          $.interface_type_definition,
+          $.union_type_definition,
+          $.enum_type_definition,
+          $.input_object_type_definition
+        )
+      ),
+    scalar_type_definition: ($) =>
+      prec.right(
+        seq(
+          optional(field("description", $.description)),
+          "scalar",
+          field("name", $.name),
+          optional(field("directives", $.directives))
+        )
+      ),
+    object_type_definition: ($) =>
+      prec.right(
+        seq(
+          optional(field("description", $.description)),
+          "type",
+          field("name", $.name),
+          optional(field("interfaces", $.implements_interfaces)),
+          optional(field("directives", $.directives)),
+          optional(field("fields", $.fields_definition))
+        )
+      ),
+    interface_type_definition: ($) =>
+      prec.right(
+        seq(
+          optional(field("description", $.description)),
+          "interface",
+          field("name", $.name),
+          optional(field("interfaces", $.implements_interfaces)),
+          optional(field("directives", $.directives)),
+          optional(field("fields", $.fields_definition))
+        )
+      ),
+    union_type_definition: ($) =>
+      prec.right(
+        seq(
+          optional(field("description", $.description)),
+          "union",
+          field("name", $.name),
+          optional(field("directives", $.directives)),
+          optional(field("members", $.union_member_types))
+        )
+      ),
+    enum_type_definition: ($) =>
+      prec.right(
+        seq(
+          optional(field("description", $.description)),
+          "enum",
+          field("name", $.name),
+          optional(field("directives", $.directives)),
+          optional(field("values", $.enum_values_definition))
+        )
+      ),
+    input_object_type_definition: ($) =>
+      prec.right(
+        seq(
+          optional(field("description", $.description)),
+          "input",
+          field("name", $.name),
+          optional(field("directives", $.directives)),
+          optional(field("fields", $.input_fields_definition))
+        )
+      ),
+    variable_definitions: ($) =>
+      seq("(", repeat1(field("variable", $.variable_definition)), ")"),
+    variable_definition: ($) =>
+      seq(
+        field("variable", $.variable),
+        ":",
+        field("type", $.type),
+        optional(field("default_value", $.default_value)),
+        optional(field("directives", $.directives)),
+        optional($.comma)
+      ),
+    selection_set: ($) =>
+      seq("{", repeat1(field("selection", $.selection)), "}"),
+    selection: ($) =>
+      field(
+        "selection",
+        choice($.field, $.inline_fragment, $.fragment_spread)
+      ),
+    field: ($) =>
+      prec.right(
+        seq(
+          optional(field("alias", $.alias)),
+          field("name", $.name),
+          optional(field("arguments", $.arguments)),
+          optional(field("directives", $.directives)),
+          optional(field("selection_set", $.selection_set))
+        )
+      ),
+    alias: ($) => seq(field("name", $.name), ":"),
+    arguments: ($) => seq("(", repeat1(field("argument", $.argument)), ")"),
+    argument: ($) => seq(field("name", $.name), ":", field("value", $.value)),
+    value: ($) =>
+      field(
+        "value",
+        choice(
+          $.variable,
+          $.string_value,
+          $.int_value,
+          $.float_value,
+          $.boolean_value,
+          $.null_value,
+          $.enum_value,
+          $.list_value,
+          $.object_value
+        )
+      ),
+    variable: ($) => seq("$", field("name", $.name)),
+    string_value: ($) =>
+      choice(
+        seq('"""', /([^"]|\n|""?[^"])*/, '"""'),
+        seq('"', /[^"\\\n]*/, '"')
+      ),
+    int_value: ($) => /-?(0|[1-9][0-9]*)/,
+    float_value: ($) =>
+      token(
+        seq(
+          /-?(0|[1-9][0-9]*)/,
+          choice(
+            /\.[0-9]+/,
+            /(e|E)(\+|-)?[0-9]+/,
+            seq(/\.[0-9]+/, /(e|E)(\+|-)?[0-9]+/)
+          )
+        )
+      ),
+    boolean_value: ($) => choice("true", "false"),
+    null_value: ($) => "null",
+    enum_value: ($) => $.name,
+    list_value: ($) => seq("[", repeat(field("value", $.value)), "]"),
+    object_value: ($) => seq("{", repeat(field("field", $.object_field)), "}"),
+    object_field: ($) =>
+      seq(
+        field("name", $.name),
+        ":",
+        field("value", $.value),
+        optional($.comma)
+      ),
+    fragment_spread: ($) =>
+      seq(
+        "...",
+        field("name", $.fragment_name),
+        optional(field("directives", $.directives))
+      ),
+    fragment_definition: ($) =>
+      seq(
+        "fragment",
+        field("name", $.fragment_name),
+        field("type_condition", $.type_condition),
+        optional(field("directives", $.directives)),
+        field("selection_set", $.selection_set)
+      ),
+    fragment_name: ($) => $.name,
+    inline_fragment: ($) =>
+      seq(
+        "...",
+        optional(field("type_condition", $.type_condition)),
+        optional(field("directives", $.directives)),
+        field("selection_set", $.selection_set)
+      ),
+    type_condition: ($) => seq("on", field("type", $.named_type)),
+    directives: ($) => prec.right(repeat1(field("directive", $.directive))),
+    directive: ($) =>
+      prec.right(
+        seq("@", field("name", $.name), optional(field("arguments", $.arguments)))
+      ),
+    directive_definition: ($) =>
+      prec.right(
+        1,
+        seq(
+          optional(field("description", $.description)),
+          "directive",
+          "@",
+          field("name", $.name),
+          optional(field("arguments", $.arguments_definition)),
+          optional("repeatable"),
+          "on",
+          field("locations", $.directive_locations)
+        )
+      ),
+    directive_locations: ($) =>
+      choice(
+        seq(
+          field("locations", $.directive_locations),
+          "|",
+          field("location", $.directive_location)
+        ),
+        seq(optional("|"), field("location", $.directive_location))
+      ),
+    directive_location: ($) =>
+      choice($.executable_directive_location, $.type_system_directive_location),
+    executable_directive_location: ($) =>
+      choice(
+        "QUERY",
+        "MUTATION",
+        "SUBSCRIPTION",
+        "FIELD",
+        "FRAGMENT_DEFINITION",
+        "FRAGMENT_SPREAD",
+        "INLINE_FRAGMENT",
+        "VARIABLE_DEFINITION"
+      ),
+    type_system_directive_location: ($) =>
+      choice(
+        "SCHEMA",
+        "SCALAR",
+        "OBJECT",
+        "FIELD_DEFINITION",
+        "ARGUMENT_DEFINITION",
+        "INTERFACE",
+        "UNION",
+        "ENUM",
+        "ENUM_VALUE",
+        "INPUT_OBJECT",
+        "INPUT_FIELD_DEFINITION"
+      ),
+    type: ($) =>
+      field("type", choice($.named_type, $.list_type, $.non_null_type)),
+    named_type: ($) => $.name,
+    list_type: ($) => seq("[", field("type", $.type), "]"),
+    non_null_type: ($) =>
+      choice(
+        seq(field("type", $.named_type), "!"),
+        seq(field("type", $.list_type), "!")
+      ),
+    name: ($) => choice($._name, $.grit_metavariable),
+    _name: ($) => /[_A-Za-z][_0-9A-Za-z]*/,
+    comment: ($) => token(seq("#", /.*/)),
+    comma: ($) => ",",
+    description: ($) => $.string_value,
+    grit_metavariable: ($) =>
+      token(prec(100, choice("µ...", /µ[a-zA-Z_][a-zA-Z0-9_]*/))),
+  },
+});
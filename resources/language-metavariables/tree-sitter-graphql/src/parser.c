@@ -0,0 +1,13161 @@
+#include "tree_sitter/parser.h"
+
+#if defined(__GNUC__) || defined(__clang__)
+#pragma GCC diagnostic ignored "-Wmissing-field-initializers"
+#endif
+
+#define LANGUAGE_VERSION 14
+#define STATE_COUNT 435
+#define LARGE_STATE_COUNT 2
+#define SYMBOL_COUNT 145
+#define ALIAS_COUNT 0
+#define TOKEN_COUNT 63
+#define EXTERNAL_TOKEN_COUNT 0
+#define FIELD_COUNT 28
+#define MAX_ALIAS_SEQUENCE_LENGTH 8
+#define PRODUCTION_ID_COUNT 145
+
+enum ts_symbol_identifiers {
+  sym__name = 1,
+  anon_sym_schema = 2,
+  anon_sym_LBRACE = 3,
+  anon_sym_RBRACE = 4,
+  anon_sym_extend = 5,
+  anon_sym_scalar = 6,
+  anon_sym_type = 7,
+  anon_sym_interface = 8,
+  anon_sym_union = 9,
+  anon_sym_enum = 10,
+  anon_sym_input = 11,
+  anon_sym_AMP = 12,
+  anon_sym_implements = 13,
+  anon_sym_COLON = 14,
+  anon_sym_LPAREN = 15,
+  anon_sym_RPAREN = 16,
+  anon_sym_EQ = 17,
+  anon_sym_PIPE = 18,
+  anon_sym_query = 19,
+  anon_sym_mutation = 20,
+  anon_sym_subscription = 21,
+  anon_sym_DOLLAR = 22,
+  anon_sym_DQUOTE_DQUOTE_DQUOTE = 23,
+  aux_sym_string_value_token1 = 24,
+  anon_sym_DQUOTE = 25,
+  aux_sym_string_value_token2 = 26,
+  sym_int_value = 27,
+  sym_float_value = 28,
+  anon_sym_true = 29,
+  anon_sym_false = 30,
+  sym_null_value = 31,
+  anon_sym_LBRACK = 32,
+  anon_sym_RBRACK = 33,
+  anon_sym_DOT_DOT_DOT = 34,
+  anon_sym_fragment = 35,
+  anon_sym_on = 36,
+  anon_sym_AT = 37,
+  anon_sym_directive = 38,
+  anon_sym_repeatable = 39,
+  anon_sym_QUERY = 40,
+  anon_sym_MUTATION = 41,
+  anon_sym_SUBSCRIPTION = 42,
+  anon_sym_FIELD = 43,
+  anon_sym_FRAGMENT_DEFINITION = 44,
+  anon_sym_FRAGMENT_SPREAD = 45,
+  anon_sym_INLINE_FRAGMENT = 46,
+  anon_sym_VARIABLE_DEFINITION = 47,
+  anon_sym_SCHEMA = 48,
+  anon_sym_SCALAR = 49,
+  anon_sym_OBJECT = 50,
+  anon_sym_FIELD_DEFINITION = 51,
+  anon_sym_ARGUMENT_DEFINITION = 52,
+  anon_sym_INTERFACE = 53,
+  anon_sym_UNION = 54,
+  anon_sym_ENUM = 55,
+  anon_sym_ENUM_VALUE = 56,
+  anon_sym_INPUT_OBJECT = 57,
+  anon_sym_INPUT_FIELD_DEFINITION = 58,
+  anon_sym_BANG = 59,
+  sym_comment = 60,
+  sym_comma = 61,
+  sym_grit_metavariable = 62,
+  sym_source_file = 63,
+  sym_document = 64,
+  sym_definition = 65,
+  sym_executable_definition = 66,
+  sym_type_system_definition = 67,
+  sym_type_system_extension = 68,
+  sym_schema_definition = 69,
+  sym_schema_extension = 70,
+  sym_type_extension = 71,
+  sym_scalar_type_extension = 72,
+  sym_object_type_extension = 73,
+  sym_interface_type_extension = 74,
+  sym_union_type_extension = 75,
+  sym_enum_type_extension = 76,
+  sym_input_object_type_extension = 77,
+  sym_input_fields_definition = 78,
+  sym_enum_values_definition = 79,
+  sym_enum_value_definition = 80,
+  sym_implements_interfaces = 81,
+  sym_fields_definition = 82,
+  sym_field_definition = 83,
+  sym_arguments_definition = 84,
+  sym_input_value_definition = 85,
+  sym_default_value = 86,
+  sym_union_member_types = 87,
+  sym_root_operation_type_definition = 88,
+  sym_operation_definition = 89,
+  sym_operation_type = 90,
+  sym_type_definition = 91,
+  sym_scalar_type_definition = 92,
+  sym_object_type_definition = 93,
+  sym_interface_type_definition = 94,
+  sym_union_type_definition = 95,
+  sym_enum_type_definition = 96,
+  sym_input_object_type_definition = 97,
+  sym_variable_definitions = 98,
+  sym_variable_definition = 99,
+  sym_selection_set = 100,
+  sym_selection = 101,
+  sym_field = 102,
+  sym_alias = 103,
+  sym_arguments = 104,
+  sym_argument = 105,
+  sym_value = 106,
+  sym_variable = 107,
+  sym_string_value = 108,
+  sym_boolean_value = 109,
+  sym_enum_value = 110,
+  sym_list_value = 111,
+  sym_object_value = 112,
+  sym_object_field = 113,
+  sym_fragment_spread = 114,
+  sym_fragment_definition = 115,
+  sym_fragment_name = 116,
+  sym_inline_fragment = 117,
+  sym_type_condition = 118,
+  sym_directives = 119,
+  sym_directive = 120,
+  sym_directive_definition = 121,
+  sym_directive_locations = 122,
+  sym_directive_location = 123,
+  sym_executable_directive_location = 124,
+  sym_type_system_directive_location = 125,
+  sym_type = 126,
+  sym_named_type = 127,
+  sym_list_type = 128,
+  sym_non_null_type = 129,
+  sym_name = 130,
+  sym_description = 131,
+  aux_sym_document_repeat1 = 132,
+  aux_sym_schema_definition_repeat1 = 133,
+  aux_sym_input_object_type_extension_repeat1 = 134,
+  aux_sym_input_fields_definition_repeat1 = 135,
+  aux_sym_enum_values_definition_repeat1 = 136,
+  aux_sym_fields_definition_repeat1 = 137,
+  aux_sym_arguments_definition_repeat1 = 138,
+  aux_sym_variable_definitions_repeat1 = 139,
+  aux_sym_selection_set_repeat1 = 140,
+  aux_sym_arguments_repeat1 = 141,
+  aux_sym_list_value_repeat1 = 142,
+  aux_sym_object_value_repeat1 = 143,
+  aux_sym_directives_repeat1 = 144,
+};
+
+static const char * const ts_symbol_names[] = {
+  [ts_builtin_sym_end] = "end",
+  [sym__name] = "_name",
+  [anon_sym_schema] = "schema",
+  [anon_sym_LBRACE] = "{",
+  [anon_sym_RBRACE] = "}",
+  [anon_sym_extend] = "extend",
+  [anon_sym_scalar] = "scalar",
+  [anon_sym_type] = "type",
+  [anon_sym_interface] = "interface",
+  [anon_sym_union] = "union",
+  [anon_sym_enum] = "enum",
+  [anon_sym_input] = "input",
+  [anon_sym_AMP] = "&",
+  [anon_sym_implements] = "implements",
+  [anon_sym_COLON] = ":",
+  [anon_sym_LPAREN] = "(",
+  [anon_sym_RPAREN] = ")",
+  [anon_sym_EQ] = "=",
+  [anon_sym_PIPE] = "|",
+  [anon_sym_query] = "query",
+  [anon_sym_mutation] = "mutation",
+  [anon_sym_subscription] = "subscription",
+  [anon_sym_DOLLAR] = "$",
+  [anon_sym_DQUOTE_DQUOTE_DQUOTE] = "\"\"\"",
+  [aux_sym_string_value_token1] = "string_value_token1",
+  [anon_sym_DQUOTE] = "\"",
+  [aux_sym_string_value_token2] = "string_value_token2",
+  [sym_int_value] = "int_value",
+  [sym_float_value] = "float_value",
+  [anon_sym_true] = "true",
+  [anon_sym_false] = "false",
+  [sym_null_value] = "null_value",
+  [anon_sym_LBRACK] = "[",
+  [anon_sym_RBRACK] = "]",
+  [anon_sym_DOT_DOT_DOT] = "...",
+  [anon_sym_fragment] = "fragment",
+  [anon_sym_on] = "on",
+  [anon_sym_AT] = "@",
+  [anon_sym_directive] = "directive",
+  [anon_sym_repeatable] = "repeatable",
+  [anon_sym_QUERY] = "QUERY",
+  [anon_sym_MUTATION] = "MUTATION",
+  [anon_sym_SUBSCRIPTION] = "SUBSCRIPTION",
+  [anon_sym_FIELD] = "FIELD",
+  [anon_sym_FRAGMENT_DEFINITION] = "FRAGMENT_DEFINITION",
+  [anon_sym_FRAGMENT_SPREAD] = "FRAGMENT_SPREAD",
+  [anon_sym_INLINE_FRAGMENT] = "INLINE_FRAGMENT",
+  [anon_sym_VARIABLE_DEFINITION] = "VARIABLE_DEFINITION",
+  [anon_sym_SCHEMA] = "SCHEMA",
+  [anon_sym_SCALAR] = "SCALAR",
+  [anon_sym_OBJECT] = "OBJECT",
+  [anon_sym_FIELD_DEFINITION] = "FIELD_DEFINITION",
+  [anon_sym_ARGUMENT_DEFINITION] = "ARGUMENT_DEFINITION",
+  [anon_sym_INTERFACE] = "INTERFACE",
+  [anon_sym_UNION] = "UNION",
+  [anon_sym_ENUM] = "ENUM",
+  [anon_sym_ENUM_VALUE] = "ENUM_VALUE",
+  [anon_sym_INPUT_OBJECT] = "INPUT_OBJECT",
+  [anon_sym_INPUT_FIELD_DEFINITION] = "INPUT_FIELD_DEFINITION",
+  [anon_sym_BANG] = "!",
+  [sym_comment] = "comment",
+  [sym_comma] = "comma",
+  [sym_grit_metavariable] = "grit_metavariable",
+  [sym_source_file] = "source_file",
+  [sym_document] = "document",
+  [sym_definition] = "definition",
+  [sym_executable_definition] = "executable_definition",
+  [sym_type_system_definition] = "type_system_definition",
+  [sym_type_system_extension] = "type_system_extension",
+  [sym_schema_definition] = "schema_definition",
+  [sym_schema_extension] = "schema_extension",
+  [sym_type_extension] = "type_extension",
+  [sym_scalar_type_extension] = "scalar_type_extension",
+  [sym_object_type_extension] = "object_type_extension",
+  [sym_interface_type_extension] = "interface_type_extension",
+  [sym_union_type_extension] = "union_type_extension",
+  [sym_enum_type_extension] = "enum_type_extension",
+  [sym_input_object_type_extension] = "input_object_type_extension",
+  [sym_input_fields_definition] = "input_fields_definition",
+  [sym_enum_values_definition] = "enum_values_definition",
+  [sym_enum_value_definition] = "enum_value_definition",
+  [sym_implements_interfaces] = "implements_interfaces",
+  [sym_fields_definition] = "fields_definition",
+  [sym_field_definition] = "field_definition",
+  [sym_arguments_definition] = "arguments_definition",
+  [sym_input_value_definition] = "input_value_definition",
+  [sym_default_value] = "default_value",
+  [sym_union_member_types] = "union_member_types",
+  [sym_root_operation_type_definition] = "root_operation_type_definition",
+  [sym_operation_definition] = "operation_definition",
+  [sym_operation_type] = "operation_type",
+  [sym_type_definition] = "type_definition",
+  [sym_scalar_type_definition] = "scalar_type_definition",
+  [sym_object_type_definition] = "object_type_definition",
+  [sym_interface_type_definition] = "interface_type_definition",
+  [sym_union_type_definition] = "union_type_definition",
+  [sym_enum_type_definition] = "enum_type_definition",
+  [sym_input_object_type_definition] = "input_object_type_definition",
+  [sym_variable_definitions] = "variable_definitions",
+  [sym_variable_definition] = "variable_definition",
+  [sym_selection_set] = "selection_set",
+  [sym_selection] = "selection",
+  [sym_field] = "field",
+  [sym_alias] = "alias",
+  [sym_arguments] = "arguments",
+  [sym_argument] = "argument",
+  [sym_value] = "value",
+  [sym_variable] = "variable",
+  [sym_string_value] = "string_value",
+  [sym_boolean_value] = "boolean_value",
+  [sym_enum_value] = "enum_value",
+  [sym_list_value] = "list_value",
+  [sym_object_value] = "object_value",
+  [sym_object_field] = "object_field",
+  [sym_fragment_spread] = "fragment_spread",
+  [sym_fragment_definition] = "fragment_definition",
+  [sym_fragment_name] = "fragment_name",
+  [sym_inline_fragment] = "inline_fragment",
+  [sym_type_condition] = "type_condition",
+  [sym_directives] = "directives",
+  [sym_directive] = "directive",
+  [sym_directive_definition] = "directive_definition",
+  [sym_directive_locations] = "directive_locations",
+  [sym_directive_location] = "directive_location",
+  [sym_executable_directive_location] = "executable_directive_location",
+  [sym_type_system_directive_location] = "type_system_directive_location",
+  [sym_type] = "type",
+  [sym_named_type] = "named_type",
+  [sym_list_type] = "list_type",
+  [sym_non_null_type] = "non_null_type",
+  [sym_name] = "name",
+  [sym_description] = "description",
+  [aux_sym_document_repeat1] = "document_repeat1",
+  [aux_sym_schema_definition_repeat1] = "schema_definition_repeat1",
+  [aux_sym_input_object_type_extension_repeat1] = "input_object_type_extension_repeat1",
+  [aux_sym_input_fields_definition_repeat1] = "input_fields_definition_repeat1",
+  [aux_sym_enum_values_definition_repeat1] = "enum_values_definition_repeat1",
+  [aux_sym_fields_definition_repeat1] = "fields_definition_repeat1",
+  [aux_sym_arguments_definition_repeat1] = "arguments_definition_repeat1",
+  [aux_sym_variable_definitions_repeat1] = "variable_definitions_repeat1",
+  [aux_sym_selection_set_repeat1] = "selection_set_repeat1",
+  [aux_sym_arguments_repeat1] = "arguments_repeat1",
+  [aux_sym_list_value_repeat1] = "list_value_repeat1",
+  [aux_sym_object_value_repeat1] = "object_value_repeat1",
+  [aux_sym_directives_repeat1] = "directives_repeat1",
+};
+
+static const TSSymbol ts_symbol_map[] = {
+  [ts_builtin_sym_end] = ts_builtin_sym_end,
+  [sym__name] = sym__name,
+  [anon_sym_schema] = anon_sym_schema,
+  [anon_sym_LBRACE] = anon_sym_LBRACE,
+  [anon_sym_RBRACE] = anon_sym_RBRACE,
+  [anon_sym_extend] = anon_sym_extend,
+  [anon_sym_scalar] = anon_sym_scalar,
+  [anon_sym_type] = anon_sym_type,
+  [anon_sym_interface] = anon_sym_interface,
+  [anon_sym_union] = anon_sym_union,
+  [anon_sym_enum] = anon_sym_enum,
+  [anon_sym_input] = anon_sym_input,
+  [anon_sym_AMP] = anon_sym_AMP,
+  [anon_sym_implements] = anon_sym_implements,
+  [anon_sym_COLON] = anon_sym_COLON,
+  [anon_sym_LPAREN] = anon_sym_LPAREN,
+  [anon_sym_RPAREN] = anon_sym_RPAREN,
+  [anon_sym_EQ] = anon_sym_EQ,
+  [anon_sym_PIPE] = anon_sym_PIPE,
+  [anon_sym_query] = anon_sym_query,
+  [anon_sym_mutation] = anon_sym_mutation,
+  [anon_sym_subscription] = anon_sym_subscription,
+  [anon_sym_DOLLAR] = anon_sym_DOLLAR,
+  [anon_sym_DQUOTE_DQUOTE_DQUOTE] = anon_sym_DQUOTE_DQUOTE_DQUOTE,
+  [aux_sym_string_value_token1] = aux_sym_string_value_token1,
+  [anon_sym_DQUOTE] = anon_sym_DQUOTE,
+  [aux_sym_string_value_token2] = aux_sym_string_value_token2,
+  [sym_int_value] = sym_int_value,
+  [sym_float_value] = sym_float_value,
+  [anon_sym_true] = anon_sym_true,
+  [anon_sym_false] = anon_sym_false,
+  [sym_null_value] = sym_null_value,
+  [anon_sym_LBRACK] = anon_sym_LBRACK,
+  [anon_sym_RBRACK] = anon_sym_RBRACK,
+  [anon_sym_DOT_DOT_DOT] = anon_sym_DOT_DOT_DOT,
+  [anon_sym_fragment] = anon_sym_fragment,
+  [anon_sym_on] = anon_sym_on,
+  [anon_sym_AT] = anon_sym_AT,
+  [anon_sym_directive] = anon_sym_directive,
+  [anon_sym_repeatable] = anon_sym_repeatable,
+  [anon_sym_QUERY] = anon_sym_QUERY,
+  [anon_sym_MUTATION] = anon_sym_MUTATION,
+  [anon_sym_SUBSCRIPTION] = anon_sym_SUBSCRIPTION,
+  [anon_sym_FIELD] = anon_sym_FIELD,
+  [anon_sym_FRAGMENT_DEFINITION] = anon_sym_FRAGMENT_DEFINITION,
+  [anon_sym_FRAGMENT_SPREAD] = anon_sym_FRAGMENT_SPREAD,
+  [anon_sym_INLINE_FRAGMENT] = anon_sym_INLINE_FRAGMENT,
+  [anon_sym_VARIABLE_DEFINITION] = anon_sym_VARIABLE_DEFINITION,
+  [anon_sym_SCHEMA] = anon_sym_SCHEMA,
+  [anon_sym_SCALAR] = anon_sym_SCALAR,
+  [anon_sym_OBJECT] = anon_sym_OBJECT,
+  [anon_sym_FIELD_DEFINITION] = anon_sym_FIELD_DEFINITION,
+  [anon_sym_ARGUMENT_DEFINITION] = anon_sym_ARGUMENT_DEFINITION,
+  [anon_sym_INTERFACE] = anon_sym_INTERFACE,
+  [anon_sym_UNION] = anon_sym_UNION,
+  [anon_sym_ENUM] = anon_sym_ENUM,
+  [anon_sym_ENUM_VALUE] = anon_sym_ENUM_VALUE,
+  [anon_sym_INPUT_OBJECT] = anon_sym_INPUT_OBJECT,
+  [anon_sym_INPUT_FIELD_DEFINITION] = anon_sym_INPUT_FIELD_DEFINITION,
+  [anon_sym_BANG] = anon_sym_BANG,
+  [sym_comment] = sym_comment,
+  [sym_comma] = sym_comma,
+  [sym_grit_metavariable] = sym_grit_metavariable,
+  [sym_source_file] = sym_source_file,
+  [sym_document] = sym_document,
+  [sym_definition] = sym_definition,
+  [sym_executable_definition] = sym_executable_definition,
+  [sym_type_system_definition] = sym_type_system_definition,
+  [sym_type_system_extension] = sym_type_system_extension,
+  [sym_schema_definition] = sym_schema_definition,
+  [sym_schema_extension] = sym_schema_extension,
+  [sym_type_extension] = sym_type_extension,
+  [sym_scalar_type_extension] = sym_scalar_type_extension,
+  [sym_object_type_extension] = sym_object_type_extension,
+  [sym_interface_type_extension] = sym_interface_type_extension,
+  [sym_union_type_extension] = sym_union_type_extension,
+  [sym_enum_type_extension] = sym_enum_type_extension,
+  [sym_input_object_type_extension] = sym_input_object_type_extension,
+  [sym_input_fields_definition] = sym_input_fields_definition,
+  [sym_enum_values_definition] = sym_enum_values_definition,
+  [sym_enum_value_definition] = sym_enum_value_definition,
+  [sym_implements_interfaces] = sym_implements_interfaces,
+  [sym_fields_definition] = sym_fields_definition,
+  [sym_field_definition] = sym_field_definition,
+  [sym_arguments_definition] = sym_arguments_definition,
+  [sym_input_value_definition] = sym_input_value_definition,
+  [sym_default_value] = sym_default_value,
+  [sym_union_member_types] = sym_union_member_types,
+  [sym_root_operation_type_definition] = sym_root_operation_type_definition,
+  [sym_operation_definition] = sym_operation_definition,
+  [sym_operation_type] = sym_operation_type,
+  [sym_type_definition] = sym_type_definition,
+  [sym_scalar_type_definition] = sym_scalar_type_definition,
+  [sym_object_type_definition] = sym_object_type_definition,
+  [sym_interface_type_definition] = sym_interface_type_definition,
+  [sym_union_type_definition] = sym_union_type_definition,
+  [sym_enum_type_definition] = sym_enum_type_definition,
+  [sym_input_object_type_definition] = sym_input_object_type_definition,
+  [sym_variable_definitions] = sym_variable_definitions,
+  [sym_variable_definition] = sym_variable_definition,
+  [sym_selection_set] = sym_selection_set,
+  [sym_selection] = sym_selection,
+  [sym_field] = sym_field,
+  [sym_alias] = sym_alias,
+  [sym_arguments] = sym_arguments,
+  [sym_argument] = sym_argument,
+  [sym_value] = sym_value,
+  [sym_variable] = sym_variable,
+  [sym_string_value] = sym_string_value,
+  [sym_boolean_value] = sym_boolean_value,
+  [sym_enum_value] = sym_enum_value,
+  [sym_list_value] = sym_list_value,
+  [sym_object_value] = sym_object_value,
+  [sym_object_field] = sym_object_field,
+  [sym_fragment_spread] = sym_fragment_spread,
+  [sym_fragment_definition] = sym_fragment_definition,
+  [sym_fragment_name] = sym_fragment_name,
+  [sym_inline_fragment] = sym_inline_fragment,
+  [sym_type_condition] = sym_type_condition,
+  [sym_directives] = sym_directives,
+  [sym_directive] = sym_directive,
+  [sym_directive_definition] = sym_directive_definition,
+  [sym_directive_locations] = sym_directive_locations,
+  [sym_directive_location] = sym_directive_location,
+  [sym_executable_directive_location] = sym_executable_directive_location,
+  [sym_type_system_directive_location] = sym_type_system_directive_location,
+  [sym_type] = sym_type,
+  [sym_named_type] = sym_named_type,
+  [sym_list_type] = sym_list_type,
+  [sym_non_null_type] = sym_non_null_type,
+  [sym_name] = sym_name,
+  [sym_description] = sym_description,
+  [aux_sym_document_repeat1] = aux_sym_document_repeat1,
+  [aux_sym_schema_definition_repeat1] = aux_sym_schema_definition_repeat1,
+  [aux_sym_input_object_type_extension_repeat1] = aux_sym_input_object_type_extension_repeat1,
+  [aux_sym_input_fields_definition_repeat1] = aux_sym_input_fields_definition_repeat1,
+  [aux_sym_enum_values_definition_repeat1] = aux_sym_enum_values_definition_repeat1,
+  [aux_sym_fields_definition_repeat1] = aux_sym_fields_definition_repeat1,
+  [aux_sym_arguments_definition_repeat1] = aux_sym_arguments_definition_repeat1,
+  [aux_sym_variable_definitions_repeat1] = aux_sym_variable_definitions_repeat1,
+  [aux_sym_selection_set_repeat1] = aux_sym_selection_set_repeat1,
+  [aux_sym_arguments_repeat1] = aux_sym_arguments_repeat1,
+  [aux_sym_list_value_repeat1] = aux_sym_list_value_repeat1,
+  [aux_sym_object_value_repeat1] = aux_sym_object_value_repeat1,
+  [aux_sym_directives_repeat1] = aux_sym_directives_repeat1,
+};
+
+static const TSSymbolMetadata ts_symbol_metadata[] = {
+  [ts_builtin_sym_end] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym__name] = {
+    .visible = false,
+    .named = true,
+  },
+  [anon_sym_schema] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_extend] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_scalar] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_type] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_interface] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_union] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_enum] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_input] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_AMP] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_implements] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_COLON] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LPAREN] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RPAREN] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_PIPE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_query] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_mutation] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_subscription] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOLLAR] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DQUOTE_DQUOTE_DQUOTE] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym_string_value_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [anon_sym_DQUOTE] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym_string_value_token2] = {
+    .visible = false,
+    .named = false,
+  },
+  [sym_int_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_float_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_true] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_false] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_null_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_LBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOT_DOT_DOT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_fragment] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_on] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_AT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_directive] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_repeatable] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_QUERY] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_MUTATION] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_SUBSCRIPTION] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_FIELD] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_FRAGMENT_DEFINITION] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_FRAGMENT_SPREAD] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_INLINE_FRAGMENT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_VARIABLE_DEFINITION] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_SCHEMA] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_SCALAR] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_OBJECT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_FIELD_DEFINITION] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ARGUMENT_DEFINITION] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_INTERFACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_UNION] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ENUM] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ENUM_VALUE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_INPUT_OBJECT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_INPUT_FIELD_DEFINITION] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_BANG] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_comment] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_comma] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_grit_metavariable] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_source_file] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_document] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_executable_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_type_system_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_type_system_extension] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_schema_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_schema_extension] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_type_extension] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_scalar_type_extension] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_object_type_extension] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_interface_type_extension] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_union_type_extension] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_enum_type_extension] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_input_object_type_extension] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_input_fields_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_enum_values_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_enum_value_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_implements_interfaces] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_fields_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_field_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_arguments_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_input_value_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_default_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_union_member_types] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_root_operation_type_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_operation_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_operation_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_type_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_scalar_type_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_object_type_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_interface_type_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_union_type_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_enum_type_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_input_object_type_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_variable_definitions] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_variable_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_selection_set] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_selection] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_field] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_alias] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_arguments] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_argument] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_variable] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_string_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_boolean_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_enum_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_list_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_object_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_object_field] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_fragment_spread] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_fragment_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_fragment_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_inline_fragment] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_type_condition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_directives] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_directive] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_directive_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_directive_locations] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_directive_location] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_executable_directive_location] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_type_system_directive_location] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_named_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_list_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_non_null_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_description] = {
+    .visible = true,
+    .named = true,
+  },
+  [aux_sym_document_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_schema_definition_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_input_object_type_extension_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_input_fields_definition_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_enum_values_definition_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_fields_definition_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_arguments_definition_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_variable_definitions_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_selection_set_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_arguments_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_list_value_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_object_value_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_directives_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+};
+
+enum ts_field_identifiers {
+  field_alias = 1,
+  field_argument = 2,
+  field_arguments = 3,
+  field_default_value = 4,
+  field_definition = 5,
+  field_description = 6,
+  field_directive = 7,
+  field_directives = 8,
+  field_document = 9,
+  field_extension = 10,
+  field_field = 11,
+  field_fields = 12,
+  field_interface = 13,
+  field_interfaces = 14,
+  field_location = 15,
+  field_locations = 16,
+  field_member = 17,
+  field_members = 18,
+  field_name = 19,
+  field_operation_type = 20,
+  field_selection = 21,
+  field_selection_set = 22,
+  field_type = 23,
+  field_type_condition = 24,
+  field_value = 25,
+  field_values = 26,
+  field_variable = 27,
+  field_variables = 28,
+};
+
+static const char * const ts_field_names[] = {
+  [0] = NULL,
+  [field_alias] = "alias",
+  [field_argument] = "argument",
+  [field_arguments] = "arguments",
+  [field_default_value] = "default_value",
+  [field_definition] = "definition",
+  [field_description] = "description",
+  [field_directive] = "directive",
+  [field_directives] = "directives",
+  [field_document] = "document",
+  [field_extension] = "extension",
+  [field_field] = "field",
+  [field_fields] = "fields",
+  [field_interface] = "interface",
+  [field_interfaces] = "interfaces",
+  [field_location] = "location",
+  [field_locations] = "locations",
+  [field_member] = "member",
+  [field_members] = "members",
+  [field_name] = "name",
+  [field_operation_type] = "operation_type",
+  [field_selection] = "selection",
+  [field_selection_set] = "selection_set",
+  [field_type] = "type",
+  [field_type_condition] = "type_condition",
+  [field_value] = "value",
+  [field_values] = "values",
+  [field_variable] = "variable",
+  [field_variables] = "variables",
+};
+
+static const TSFieldMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
+  [1] = {.index = 0, .length = 1},
+  [2] = {.index = 1, .length = 1},
+  [3] = {.index = 2, .length = 1},
+  [4] = {.index = 3, .length = 1},
+  [5] = {.index = 4, .length = 1},
+  [6] = {.index = 5, .length = 1},
+  [7] = {.index = 6, .length = 1},
+  [8] = {.index = 7, .length = 1},
+  [9] = {.index = 8, .length = 1},
+  [10] = {.index = 9, .length = 1},
+  [11] = {.index = 10, .length = 2},
+  [12] = {.index = 12, .length = 2},
+  [13] = {.index = 14, .length = 1},
+  [14] = {.index = 15, .length = 2},
+  [15] = {.index = 17, .length = 1},
+  [16] = {.index = 18, .length = 2},
+  [17] = {.index = 20, .length = 2},
+  [18] = {.index = 22, .length = 2},
+  [19] = {.index = 24, .length = 2},
+  [20] = {.index = 26, .length = 1},
+  [21] = {.index = 27, .length = 2},
+  [22] = {.index = 29, .length = 1},
+  [23] = {.index = 30, .length = 2},
+  [24] = {.index = 32, .length = 2},
+  [25] = {.index = 34, .length = 2},
+  [26] = {.index = 36, .length = 2},
+  [27] = {.index = 38, .length = 2},
+  [28] = {.index = 40, .length = 1},
+  [29] = {.index = 41, .length = 3},
+  [30] = {.index = 44, .length = 3},
+  [31] = {.index = 47, .length = 3},
+  [32] = {.index = 50, .length = 2},
+  [33] = {.index = 52, .length = 1},
+  [34] = {.index = 53, .length = 2},
+  [35] = {.index = 55, .length = 2},
+  [36] = {.index = 57, .length = 1},
+  [37] = {.index = 58, .length = 2},
+  [38] = {.index = 60, .length = 2},
+  [39] = {.index = 62, .length = 3},
+  [40] = {.index = 65, .length = 3},
+  [41] = {.index = 68, .length = 3},
+  [42] = {.index = 71, .length = 1},
+  [43] = {.index = 72, .length = 3},
+  [44] = {.index = 75, .length = 3},
+  [45] = {.index = 78, .length = 3},
+  [46] = {.index = 81, .length = 2},
+  [47] = {.index = 83, .length = 2},
+  [48] = {.index = 85, .length = 2},
+  [49] = {.index = 87, .length = 2},
+  [50] = {.index = 89, .length = 2},
+  [51] = {.index = 91, .length = 1},
+  [52] = {.index = 92, .length = 2},
+  [53] = {.index = 94, .length = 1},
+  [54] = {.index = 95, .length = 1},
+  [55] = {.index = 96, .length = 3},
+  [56] = {.index = 99, .length = 3},
+  [57] = {.index = 102, .length = 3},
+  [58] = {.index = 105, .length = 1},
+  [59] = {.index = 106, .length = 3},
+  [60] = {.index = 109, .length = 1},
+  [61] = {.index = 110, .length = 3},
+  [62] = {.index = 113, .length = 3},
+  [63] = {.index = 116, .length = 1},
+  [64] = {.index = 117, .length = 2},
+  [65] = {.index = 119, .length = 4},
+  [66] = {.index = 123, .length = 4},
+  [67] = {.index = 127, .length = 4},
+  [68] = {.index = 131, .length = 3},
+  [69] = {.index = 134, .length = 3},
+  [70] = {.index = 137, .length = 3},
+  [71] = {.index = 140, .length = 3},
+  [72] = {.index = 143, .length = 3},
+  [73] = {.index = 146, .length = 2},
+  [74] = {.index = 148, .length = 2},
+  [75] = {.index = 150, .length = 3},
+  [76] = {.index = 153, .length = 4},
+  [77] = {.index = 157, .length = 4},
+  [78] = {.index = 161, .length = 4},
+  [79] = {.index = 165, .length = 1},
+  [80] = {.index = 166, .length = 2},
+  [81] = {.index = 168, .length = 4},
+  [82] = {.index = 172, .length = 1},
+  [83] = {.index = 173, .length = 3},
+  [84] = {.index = 176, .length = 3},
+  [85] = {.index = 179, .length = 3},
+  [86] = {.index = 182, .length = 3},
+  [87] = {.index = 185, .length = 3},
+  [88] = {.index = 188, .length = 3},
+  [89] = {.index = 191, .length = 2},
+  [90] = {.index = 193, .length = 1},
+  [91] = {.index = 194, .length = 2},
+  [92] = {.index = 196, .length = 1},
+  [93] = {.index = 197, .length = 2},
+  [94] = {.index = 199, .length = 4},
+  [95] = {.index = 203, .length = 1},
+  [96] = {.index = 204, .length = 2},
+  [97] = {.index = 206, .length = 2},
+  [98] = {.index = 208, .length = 2},
+  [99] = {.index = 210, .length = 1},
+  [100] = {.index = 211, .length = 2},
+  [101] = {.index = 213, .length = 4},
+  [102] = {.index = 217, .length = 2},
+  [103] = {.index = 219, .length = 1},
+  [104] = {.index = 220, .length = 2},
+  [105] = {.index = 222, .length = 1},
+  [106] = {.index = 223, .length = 5},
+  [107] = {.index = 228, .length = 2},
+  [108] = {.index = 230, .length = 4},
+  [109] = {.index = 234, .length = 4},
+  [110] = {.index = 238, .length = 4},
+  [111] = {.index = 242, .length = 4},
+  [112] = {.index = 246, .length = 4},
+  [113] = {.index = 250, .length = 5},
+  [114] = {.index = 255, .length = 2},
+  [115] = {.index = 257, .length = 2},
+  [116] = {.index = 259, .length = 4},
+  [117] = {.index = 263, .length = 2},
+  [118] = {.index = 265, .length = 3},
+  [119] = {.index = 268, .length = 1},
+  [120] = {.index = 269, .length = 2},
+  [121] = {.index = 271, .length = 3},
+  [122] = {.index = 274, .length = 3},
+  [123] = {.index = 277, .length = 3},
+  [124] = {.index = 280, .length = 3},
+  [125] = {.index = 283, .length = 5},
+  [126] = {.index = 288, .length = 3},
+  [127] = {.index = 291, .length = 3},
+  [128] = {.index = 294, .length = 3},
+  [129] = {.index = 297, .length = 3},
+  [130] = {.index = 300, .length = 3},
+  [131] = {.index = 303, .length = 2},
+  [132] = {.index = 305, .length = 3},
+  [133] = {.index = 308, .length = 1},
+  [134] = {.index = 309, .length = 4},
+  [135] = {.index = 313, .length = 3},
+  [136] = {.index = 316, .length = 4},
+  [137] = {.index = 320, .length = 4},
+  [138] = {.index = 324, .length = 4},
+  [139] = {.index = 328, .length = 4},
+  [140] = {.index = 332, .length = 4},
+  [141] = {.index = 336, .length = 4},
+  [142] = {.index = 340, .length = 4},
+  [143] = {.index = 344, .length = 5},
+  [144] = {.index = 349, .length = 5},
+};
+
+static const TSFieldMapEntry ts_field_map_entries[] = {
+  [0] =
+    {field_document, 0},
+  [1] =
+    {field_definition, 0},
+  [2] =
+    {field_extension, 0},
+  [3] =
+    {field_selection_set, 0},
+  [4] =
+    {field_definition, 0, .inherited = true},
+  [5] =
+    {field_directive, 0},
+  [6] =
+    {field_directive, 0, .inherited = true},
+  [7] =
+    {field_selection, 0},
+  [8] =
+    {field_name, 0},
+  [9] =
+    {field_name, 1},
+  [10] =
+    {field_operation_type, 0},
+    {field_selection_set, 1},
+  [12] =
+    {field_definition, 0, .inherited = true},
+    {field_definition, 1, .inherited = true},
+  [14] =
+    {field_operation_type, 0},
+  [15] =
+    {field_directive, 0, .inherited = true},
+    {field_directive, 1, .inherited = true},
+  [17] =
+    {field_selection_set, 1},
+  [18] =
+    {field_alias, 0},
+    {field_name, 1},
+  [20] =
+    {field_name, 0},
+    {field_selection_set, 1},
+  [22] =
+    {field_arguments, 1},
+    {field_name, 0},
+  [24] =
+    {field_directives, 1},
+    {field_name, 0},
+  [26] =
+    {field_selection, 1, .inherited = true},
+  [27] =
+    {field_selection, 0, .inherited = true},
+    {field_selection, 1, .inherited = true},
+  [29] =
+    {field_name, 2},
+  [30] =
+    {field_directives, 2},
+    {field_name, 1},
+  [32] =
+    {field_interfaces, 2},
+    {field_name, 1},
+  [34] =
+    {field_fields, 2},
+    {field_name, 1},
+  [36] =
+    {field_members, 2},
+    {field_name, 1},
+  [38] =
+    {field_name, 1},
+    {field_values, 2},
+  [40] =
+    {field_variable, 0},
+  [41] =
+    {field_operation_type, 0},
+    {field_selection_set, 2},
+    {field_variables, 1},
+  [44] =
+    {field_directives, 1},
+    {field_operation_type, 0},
+    {field_selection_set, 2},
+  [47] =
+    {field_name, 1},
+    {field_operation_type, 0},
+    {field_selection_set, 2},
+  [50] =
+    {field_description, 0},
+    {field_name, 2},
+  [52] =
+    {field_operation_type, 2, .inherited = true},
+  [53] =
+    {field_operation_type, 0, .inherited = true},
+    {field_operation_type, 1, .inherited = true},
+  [55] =
+    {field_arguments, 2},
+    {field_name, 1},
+  [57] =
+    {field_type, 1},
+  [58] =
+    {field_selection_set, 2},
+    {field_type_condition, 1},
+  [60] =
+    {field_directives, 1},
+    {field_selection_set, 2},
+  [62] =
+    {field_alias, 0},
+    {field_name, 1},
+    {field_selection_set, 2},
+  [65] =
+    {field_alias, 0},
+    {field_arguments, 2},
+    {field_name, 1},
+  [68] =
+    {field_alias, 0},
+    {field_directives, 2},
+    {field_name, 1},
+  [71] =
+    {field_argument, 0},
+  [72] =
+    {field_arguments, 1},
+    {field_name, 0},
+    {field_selection_set, 2},
+  [75] =
+    {field_arguments, 1},
+    {field_directives, 2},
+    {field_name, 0},
+  [78] =
+    {field_directives, 1},
+    {field_name, 0},
+    {field_selection_set, 2},
+  [81] =
+    {field_directives, 3},
+    {field_name, 2},
+  [83] =
+    {field_interfaces, 3},
+    {field_name, 2},
+  [85] =
+    {field_fields, 3},
+    {field_name, 2},
+  [87] =
+    {field_members, 3},
+    {field_name, 2},
+  [89] =
+    {field_name, 2},
+    {field_values, 3},
+  [91] =
+    {field_fields, 0},
+  [92] =
+    {field_fields, 3, .inherited = true},
+    {field_name, 2},
+  [94] =
+    {field_field, 0},
+  [95] =
+    {field_interface, 1},
+  [96] =
+    {field_fields, 3},
+    {field_interfaces, 2},
+    {field_name, 1},
+  [99] =
+    {field_directives, 3},
+    {field_interfaces, 2},
+    {field_name, 1},
+  [102] =
+    {field_directives, 2},
+    {field_fields, 3},
+    {field_name, 1},
+  [105] =
+    {field_member, 1},
+  [106] =
+    {field_directives, 2},
+    {field_members, 3},
+    {field_name, 1},
+  [109] =
+    {field_value, 0},
+  [110] =
+    {field_directives, 2},
+    {field_name, 1},
+    {field_values, 3},
+  [113] =
+    {field_name, 1},
+    {field_selection_set, 3},
+    {field_type_condition, 2},
+  [116] =
+    {field_variable, 1, .inherited = true},
+  [117] =
+    {field_variable, 0, .inherited = true},
+    {field_variable, 1, .inherited = true},
+  [119] =
+    {field_directives, 2},
+    {field_operation_type, 0},
+    {field_selection_set, 3},
+    {field_variables, 1},
+  [123] =
+    {field_name, 1},
+    {field_operation_type, 0},
+    {field_selection_set, 3},
+    {field_variables, 2},
+  [127] =
+    {field_directives, 2},
+    {field_name, 1},
+    {field_operation_type, 0},
+    {field_selection_set, 3},
+  [131] =
+    {field_description, 0},
+    {field_directives, 3},
+    {field_name, 2},
+  [134] =
+    {field_description, 0},
+    {field_interfaces, 3},
+    {field_name, 2},
+  [137] =
+    {field_description, 0},
+    {field_fields, 3},
+    {field_name, 2},
+  [140] =
+    {field_description, 0},
+    {field_members, 3},
+    {field_name, 2},
+  [143] =
+    {field_description, 0},
+    {field_name, 2},
+    {field_values, 3},
+  [146] =
+    {field_operation_type, 0},
+    {field_type, 2},
+  [148] =
+    {field_directives, 1},
+    {field_operation_type, 3, .inherited = true},
+  [150] =
+    {field_directives, 2},
+    {field_selection_set, 3},
+    {field_type_condition, 1},
+  [153] =
+    {field_alias, 0},
+    {field_arguments, 2},
+    {field_name, 1},
+    {field_selection_set, 3},
+  [157] =
+    {field_alias, 0},
+    {field_arguments, 2},
+    {field_directives, 3},
+    {field_name, 1},
+  [161] =
+    {field_alias, 0},
+    {field_directives, 2},
+    {field_name, 1},
+    {field_selection_set, 3},
+  [165] =
+    {field_argument, 1, .inherited = true},
+  [166] =
+    {field_argument, 0, .inherited = true},
+    {field_argument, 1, .inherited = true},
+  [168] =
+    {field_arguments, 1},
+    {field_directives, 2},
+    {field_name, 0},
+    {field_selection_set, 3},
+  [172] =
+    {field_operation_type, 3},
+  [173] =
+    {field_fields, 4},
+    {field_interfaces, 3},
+    {field_name, 2},
+  [176] =
+    {field_directives, 4},
+    {field_interfaces, 3},
+    {field_name, 2},
+  [179] =
+    {field_directives, 3},
+    {field_fields, 4},
+    {field_name, 2},
+  [182] =
+    {field_directives, 3},
+    {field_members, 4},
+    {field_name, 2},
+  [185] =
+    {field_directives, 3},
+    {field_name, 2},
+    {field_values, 4},
+  [188] =
+    {field_directives, 3},
+    {field_fields, 4, .inherited = true},
+    {field_name, 2},
+  [191] =
+    {field_fields, 0, .inherited = true},
+    {field_fields, 1, .inherited = true},
+  [193] =
+    {field_field, 1, .inherited = true},
+  [194] =
+    {field_field, 0, .inherited = true},
+    {field_field, 1, .inherited = true},
+  [196] =
+    {field_interface, 2},
+  [197] =
+    {field_interface, 2},
+    {field_interfaces, 0},
+  [199] =
+    {field_directives, 3},
+    {field_fields, 4},
+    {field_interfaces, 2},
+    {field_name, 1},
+  [203] =
+    {field_member, 2},
+  [204] =
+    {field_member, 2},
+    {field_members, 0},
+  [206] =
+    {field_directives, 1},
+    {field_value, 0},
+  [208] =
+    {field_description, 0},
+    {field_value, 1},
+  [210] =
+    {field_value, 1, .inherited = true},
+  [211] =
+    {field_value, 0, .inherited = true},
+    {field_value, 1, .inherited = true},
+  [213] =
+    {field_directives, 3},
+    {field_name, 1},
+    {field_selection_set, 4},
+    {field_type_condition, 2},
+  [217] =
+    {field_locations, 4},
+    {field_name, 2},
+  [219] =
+    {field_location, 0},
+  [220] =
+    {field_type, 2},
+    {field_variable, 0},
+  [222] =
+    {field_type, 0},
+  [223] =
+    {field_directives, 3},
+    {field_name, 1},
+    {field_operation_type, 0},
+    {field_selection_set, 4},
+    {field_variables, 2},
+  [228] =
+    {field_description, 0},
+    {field_operation_type, 3, .inherited = true},
+  [230] =
+    {field_description, 0},
+    {field_fields, 4},
+    {field_interfaces, 3},
+    {field_name, 2},
+  [234] =
+    {field_description, 0},
+    {field_directives, 4},
+    {field_interfaces, 3},
+    {field_name, 2},
+  [238] =
+    {field_description, 0},
+    {field_directives, 3},
+    {field_fields, 4},
+    {field_name, 2},
+  [242] =
+    {field_description, 0},
+    {field_directives, 3},
+    {field_members, 4},
+    {field_name, 2},
+  [246] =
+    {field_description, 0},
+    {field_directives, 3},
+    {field_name, 2},
+    {field_values, 4},
+  [250] =
+    {field_alias, 0},
+    {field_arguments, 2},
+    {field_directives, 3},
+    {field_name, 1},
+    {field_selection_set, 4},
+  [255] =
+    {field_name, 0},
+    {field_value, 2},
+  [257] =
+    {field_directives, 2},
+    {field_operation_type, 4},
+  [259] =
+    {field_directives, 4},
+    {field_fields, 5},
+    {field_interfaces, 3},
+    {field_name, 2},
+  [263] =
+    {field_name, 0},
+    {field_type, 2},
+  [265] =
+    {field_description, 0},
+    {field_directives, 2},
+    {field_value, 1},
+  [268] =
+    {field_location, 1},
+  [269] =
+    {field_locations, 5},
+    {field_name, 2},
+  [271] =
+    {field_arguments, 3},
+    {field_locations, 5},
+    {field_name, 2},
+  [274] =
+    {field_default_value, 3},
+    {field_type, 2},
+    {field_variable, 0},
+  [277] =
+    {field_directives, 3},
+    {field_type, 2},
+    {field_variable, 0},
+  [280] =
+    {field_description, 0},
+    {field_directives, 2},
+    {field_operation_type, 4, .inherited = true},
+  [283] =
+    {field_description, 0},
+    {field_directives, 4},
+    {field_fields, 5},
+    {field_interfaces, 3},
+    {field_name, 2},
+  [288] =
+    {field_description, 0},
+    {field_locations, 5},
+    {field_name, 3},
+  [291] =
+    {field_directives, 3},
+    {field_name, 0},
+    {field_type, 2},
+  [294] =
+    {field_arguments, 1},
+    {field_name, 0},
+    {field_type, 3},
+  [297] =
+    {field_description, 0},
+    {field_name, 1},
+    {field_type, 3},
+  [300] =
+    {field_default_value, 3},
+    {field_name, 0},
+    {field_type, 2},
+  [303] =
+    {field_location, 2},
+    {field_locations, 0},
+  [305] =
+    {field_arguments, 3},
+    {field_locations, 6},
+    {field_name, 2},
+  [308] =
+    {field_value, 1},
+  [309] =
+    {field_default_value, 3},
+    {field_directives, 4},
+    {field_type, 2},
+    {field_variable, 0},
+  [313] =
+    {field_description, 0},
+    {field_locations, 6},
+    {field_name, 3},
+  [316] =
+    {field_arguments, 4},
+    {field_description, 0},
+    {field_locations, 6},
+    {field_name, 3},
+  [320] =
+    {field_arguments, 1},
+    {field_directives, 4},
+    {field_name, 0},
+    {field_type, 3},
+  [324] =
+    {field_description, 0},
+    {field_directives, 4},
+    {field_name, 1},
+    {field_type, 3},
+  [328] =
+    {field_arguments, 2},
+    {field_description, 0},
+    {field_name, 1},
+    {field_type, 4},
+  [332] =
+    {field_default_value, 3},
+    {field_directives, 4},
+    {field_name, 0},
+    {field_type, 2},
+  [336] =
+    {field_default_value, 4},
+    {field_description, 0},
+    {field_name, 1},
+    {field_type, 3},
+  [340] =
+    {field_arguments, 4},
+    {field_description, 0},
+    {field_locations, 7},
+    {field_name, 3},
+  [344] =
+    {field_arguments, 2},
+    {field_description, 0},
+    {field_directives, 5},
+    {field_name, 1},
+    {field_type, 4},
+  [349] =
+    {field_default_value, 4},
+    {field_description, 0},
+    {field_directives, 5},
+    {field_name, 1},
+    {field_type, 3},
+};
+
+static const TSSymbol ts_alias_sequences[PRODUCTION_ID_COUNT][MAX_ALIAS_SEQUENCE_LENGTH] = {
+  [0] = {0},
+};
+
+static const uint16_t ts_non_terminal_alias_map[] = {
+  0,
+};
+
+static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
+  [0] = 0,
+  [1] = 1,
+  [2] = 2,
+  [3] = 3,
+  [4] = 4,
+  [5] = 5,
+  [6] = 6,
+  [7] = 7,
+  [8] = 8,
+  [9] = 9,
+  [10] = 10,
+  [11] = 11,
+  [12] = 12,
+  [13] = 13,
+  [14] = 14,
+  [15] = 15,
+  [16] = 16,
+  [17] = 17,
+  [18] = 18,
+  [19] = 19,
+  [20] = 20,
+  [21] = 21,
+  [22] = 22,
+  [23] = 23,
+  [24] = 24,
+  [25] = 25,
+  [26] = 26,
+  [27] = 27,
+  [28] = 28,
+  [29] = 29,
+  [30] = 30,
+  [31] = 4,
+  [32] = 32,
+  [33] = 33,
+  [34] = 34,
+  [35] = 35,
+  [36] = 36,
+  [37] = 37,
+  [38] = 38,
+  [39] = 39,
+  [40] = 40,
+  [41] = 39,
+  [42] = 42,
+  [43] = 38,
+  [44] = 44,
+  [45] = 45,
+  [46] = 46,
+  [47] = 47,
+  [48] = 48,
+  [49] = 49,
+  [50] = 50,
+  [51] = 51,
+  [52] = 50,
+  [53] = 53,
+  [54] = 54,
+  [55] = 55,
+  [56] = 56,
+  [57] = 57,
+  [58] = 58,
+  [59] = 59,
+  [60] = 60,
+  [61] = 61,
+  [62] = 62,
+  [63] = 63,
+  [64] = 64,
+  [65] = 65,
+  [66] = 66,
+  [67] = 67,
+  [68] = 68,
+  [69] = 69,
+  [70] = 70,
+  [71] = 71,
+  [72] = 72,
+  [73] = 73,
+  [74] = 74,
+  [75] = 75,
+  [76] = 76,
+  [77] = 77,
+  [78] = 78,
+  [79] = 79,
+  [80] = 80,
+  [81] = 81,
+  [82] = 82,
+  [83] = 83,
+  [84] = 84,
+  [85] = 85,
+  [86] = 86,
+  [87] = 87,
+  [88] = 88,
+  [89] = 89,
+  [90] = 90,
+  [91] = 91,
+  [92] = 92,
+  [93] = 93,
+  [94] = 94,
+  [95] = 95,
+  [96] = 96,
+  [97] = 97,
+  [98] = 98,
+  [99] = 99,
+  [100] = 100,
+  [101] = 101,
+  [102] = 102,
+  [103] = 103,
+  [104] = 104,
+  [105] = 105,
+  [106] = 106,
+  [107] = 107,
+  [108] = 108,
+  [109] = 109,
+  [110] = 110,
+  [111] = 111,
+  [112] = 112,
+  [113] = 113,
+  [114] = 114,
+  [115] = 115,
+  [116] = 116,
+  [117] = 117,
+  [118] = 118,
+  [119] = 119,
+  [120] = 120,
+  [121] = 121,
+  [122] = 122,
+  [123] = 123,
+  [124] = 124,
+  [125] = 125,
+  [126] = 126,
+  [127] = 127,
+  [128] = 128,
+  [129] = 129,
+  [130] = 130,
+  [131] = 131,
+  [132] = 132,
+  [133] = 133,
+  [134] = 134,
+  [135] = 135,
+  [136] = 136,
+  [137] = 137,
+  [138] = 138,
+  [139] = 139,
+  [140] = 140,
+  [141] = 141,
+  [142] = 142,
+  [143] = 143,
+  [144] = 144,
+  [145] = 145,
+  [146] = 146,
+  [147] = 147,
+  [148] = 148,
+  [149] = 149,
+  [150] = 150,
+  [151] = 151,
+  [152] = 152,
+  [153] = 153,
+  [154] = 154,
+  [155] = 155,
+  [156] = 156,
+  [157] = 157,
+  [158] = 158,
+  [159] = 159,
+  [160] = 160,
+  [161] = 161,
+  [162] = 162,
+  [163] = 163,
+  [164] = 164,
+  [165] = 165,
+  [166] = 166,
+  [167] = 167,
+  [168] = 168,
+  [169] = 169,
+  [170] = 170,
+  [171] = 171,
+  [172] = 172,
+  [173] = 173,
+  [174] = 174,
+  [175] = 175,
+  [176] = 176,
+  [177] = 177,
+  [178] = 178,
+  [179] = 179,
+  [180] = 180,
+  [181] = 181,
+  [182] = 182,
+  [183] = 183,
+  [184] = 184,
+  [185] = 185,
+  [186] = 48,
+  [187] = 45,
+  [188] = 188,
+  [189] = 189,
+  [190] = 190,
+  [191] = 191,
+  [192] = 192,
+  [193] = 190,
+  [194] = 47,
+  [195] = 195,
+  [196] = 196,
+  [197] = 197,
+  [198] = 198,
+  [199] = 199,
+  [200] = 200,
+  [201] = 196,
+  [202] = 202,
+  [203] = 203,
+  [204] = 204,
+  [205] = 205,
+  [206] = 206,
+  [207] = 207,
+  [208] = 208,
+  [209] = 209,
+  [210] = 210,
+  [211] = 211,
+  [212] = 212,
+  [213] = 64,
+  [214] = 53,
+  [215] = 215,
+  [216] = 216,
+  [217] = 217,
+  [218] = 54,
+  [219] = 219,
+  [220] = 220,
+  [221] = 221,
+  [222] = 222,
+  [223] = 223,
+  [224] = 4,
+  [225] = 23,
+  [226] = 226,
+  [227] = 227,
+  [228] = 227,
+  [229] = 229,
+  [230] = 230,
+  [231] = 231,
+  [232] = 232,
+  [233] = 233,
+  [234] = 234,
+  [235] = 235,
+  [236] = 236,
+  [237] = 237,
+  [238] = 238,
+  [239] = 239,
+  [240] = 240,
+  [241] = 241,
+  [242] = 242,
+  [243] = 243,
+  [244] = 244,
+  [245] = 245,
+  [246] = 246,
+  [247] = 247,
+  [248] = 248,
+  [249] = 249,
+  [250] = 250,
+  [251] = 251,
+  [252] = 252,
+  [253] = 253,
+  [254] = 254,
+  [255] = 255,
+  [256] = 256,
+  [257] = 257,
+  [258] = 258,
+  [259] = 259,
+  [260] = 260,
+  [261] = 261,
+  [262] = 262,
+  [263] = 263,
+  [264] = 9,
+  [265] = 179,
+  [266] = 115,
+  [267] = 267,
+  [268] = 173,
+  [269] = 175,
+  [270] = 270,
+  [271] = 174,
+  [272] = 176,
+  [273] = 273,
+  [274] = 178,
+  [275] = 177,
+  [276] = 276,
+  [277] = 277,
+  [278] = 256,
+  [279] = 252,
+  [280] = 277,
+  [281] = 281,
+  [282] = 256,
+  [283] = 283,
+  [284] = 202,
+  [285] = 285,
+  [286] = 286,
+  [287] = 287,
+  [288] = 288,
+  [289] = 45,
+  [290] = 290,
+  [291] = 291,
+  [292] = 47,
+  [293] = 48,
+  [294] = 294,
+  [295] = 295,
+  [296] = 296,
+  [297] = 297,
+  [298] = 23,
+  [299] = 299,
+  [300] = 300,
+  [301] = 210,
+  [302] = 302,
+  [303] = 303,
+  [304] = 304,
+  [305] = 305,
+  [306] = 306,
+  [307] = 307,
+  [308] = 308,
+  [309] = 295,
+  [310] = 310,
+  [311] = 295,
+  [312] = 312,
+  [313] = 313,
+  [314] = 314,
+  [315] = 315,
+  [316] = 316,
+  [317] = 317,
+  [318] = 318,
+  [319] = 319,
+  [320] = 320,
+  [321] = 321,
+  [322] = 222,
+  [323] = 323,
+  [324] = 324,
+  [325] = 325,
+  [326] = 326,
+  [327] = 327,
+  [328] = 328,
+  [329] = 329,
+  [330] = 330,
+  [331] = 331,
+  [332] = 332,
+  [333] = 333,
+  [334] = 120,
+  [335] = 335,
+  [336] = 336,
+  [337] = 337,
+  [338] = 338,
+  [339] = 339,
+  [340] = 221,
+  [341] = 341,
+  [342] = 342,
+  [343] = 343,
+  [344] = 344,
+  [345] = 345,
+  [346] = 346,
+  [347] = 347,
+  [348] = 348,
+  [349] = 246,
+  [350] = 350,
+  [351] = 351,
+  [352] = 352,
+  [353] = 353,
+  [354] = 354,
+  [355] = 355,
+  [356] = 356,
+  [357] = 357,
+  [358] = 358,
+  [359] = 359,
+  [360] = 360,
+  [361] = 361,
+  [362] = 362,
+  [363] = 363,
+  [364] = 364,
+  [365] = 64,
+  [366] = 366,
+  [367] = 367,
+  [368] = 368,
+  [369] = 369,
+  [370] = 54,
+  [371] = 371,
+  [372] = 369,
+  [373] = 373,
+  [374] = 374,
+  [375] = 375,
+  [376] = 53,
+  [377] = 346,
+  [378] = 378,
+  [379] = 379,
+  [380] = 346,
+  [381] = 381,
+  [382] = 382,
+  [383] = 383,
+  [384] = 384,
+  [385] = 385,
+  [386] = 386,
+  [387] = 387,
+  [388] = 388,
+  [389] = 389,
+  [390] = 390,
+  [391] = 391,
+  [392] = 392,
+  [393] = 393,
+  [394] = 394,
+  [395] = 395,
+  [396] = 396,
+  [397] = 397,
+  [398] = 398,
+  [399] = 399,
+  [400] = 400,
+  [401] = 401,
+  [402] = 402,
+  [403] = 403,
+  [404] = 404,
+  [405] = 405,
+  [406] = 406,
+  [407] = 407,
+  [408] = 408,
+  [409] = 409,
+  [410] = 410,
+  [411] = 411,
+  [412] = 412,
+  [413] = 413,
+  [414] = 414,
+  [415] = 415,
+  [416] = 416,
+  [417] = 417,
+  [418] = 418,
+  [419] = 419,
+  [420] = 405,
+  [421] = 421,
+  [422] = 422,
+  [423] = 423,
+  [424] = 422,
+  [425] = 425,
+  [426] = 426,
+  [427] = 427,
+  [428] = 419,
+  [429] = 429,
+  [430] = 407,
+  [431] = 415,
+  [432] = 432,
+  [433] = 433,
+  [434] = 434,
+};
+
+static bool ts_lex(TSLexer *lexer, TSStateId state) {
+  START_LEXER();
+  eof = lexer->eof(lexer);
+  switch (state) {
+    case 0:
+      if (eof) ADVANCE(14);
+      ADVANCE_MAP(
+        '!', 41,
+        '"', 29,
+        '#', 45,
+        '$', 23,
+        '&', 17,
+        '(', 19,
+        ')', 20,
+        ',', 46,
+        '-', 9,
+        '.', 6,
+        '0', 33,
+        ':', 18,
+        '=', 21,
+        '@', 40,
+        '[', 37,
+        ']', 38,
+        '{', 15,
+        '|', 22,
+        '}', 16,
+        0xb5, 8,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ' ||
+          lookahead == 0xfeff) SKIP(0);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(34);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(42);
+      END_STATE();
+    case 1:
+      if (lookahead == '"') ADVANCE(24);
+      END_STATE();
+    case 2:
+      if (lookahead == '"') ADVANCE(28);
+      if (lookahead == '#') ADVANCE(45);
+      if (lookahead == ',') ADVANCE(46);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ' ||
+          lookahead == 0xfeff) SKIP(2);
+      END_STATE();
+    case 3:
+      if (lookahead == '"') ADVANCE(13);
+      if (lookahead != 0) ADVANCE(27);
+      END_STATE();
+    case 4:
+      if (lookahead == '.') ADVANCE(39);
+      END_STATE();
+    case 5:
+      if (lookahead == '.') ADVANCE(49);
+      END_STATE();
+    case 6:
+      if (lookahead == '.') ADVANCE(4);
+      END_STATE();
+    case 7:
+      if (lookahead == '.') ADVANCE(5);
+      END_STATE();
+    case 8:
+      if (lookahead == '.') ADVANCE(7);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(50);
+      END_STATE();
+    case 9:
+      if (lookahead == '0') ADVANCE(33);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(34);
+      END_STATE();
+    case 10:
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(12);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(36);
+      END_STATE();
+    case 11:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(35);
+      END_STATE();
+    case 12:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(36);
+      END_STATE();
+    case 13:
+      if (lookahead != 0 &&
+          lookahead != '"') ADVANCE(27);
+      END_STATE();
+    case 14:
+      ACCEPT_TOKEN(ts_builtin_sym_end);
+      END_STATE();
+    case 15:
+      ACCEPT_TOKEN(anon_sym_LBRACE);
+      END_STATE();
+    case 16:
+      ACCEPT_TOKEN(anon_sym_RBRACE);
+      END_STATE();
+    case 17:
+      ACCEPT_TOKEN(anon_sym_AMP);
+      END_STATE();
+    case 18:
+      ACCEPT_TOKEN(anon_sym_COLON);
+      END_STATE();
+    case 19:
+      ACCEPT_TOKEN(anon_sym_LPAREN);
+      END_STATE();
+    case 20:
+      ACCEPT_TOKEN(anon_sym_RPAREN);
+      END_STATE();
+    case 21:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      END_STATE();
+    case 22:
+      ACCEPT_TOKEN(anon_sym_PIPE);
+      END_STATE();
+    case 23:
+      ACCEPT_TOKEN(anon_sym_DOLLAR);
+      END_STATE();
+    case 24:
+      ACCEPT_TOKEN(anon_sym_DQUOTE_DQUOTE_DQUOTE);
+      END_STATE();
+    case 25:
+      ACCEPT_TOKEN(aux_sym_string_value_token1);
+      if (lookahead == '\n') ADVANCE(27);
+      if (lookahead == '"') ADVANCE(44);
+      if (lookahead != 0) ADVANCE(25);
+      END_STATE();
+    case 26:
+      ACCEPT_TOKEN(aux_sym_string_value_token1);
+      if (lookahead == '"') ADVANCE(3);
+      if (lookahead == '#') ADVANCE(25);
+      if (lookahead == ',') ADVANCE(47);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ' ||
+          lookahead == 0xfeff) ADVANCE(26);
+      if (lookahead != 0) ADVANCE(27);
+      END_STATE();
+    case 27:
+      ACCEPT_TOKEN(aux_sym_string_value_token1);
+      if (lookahead == '"') ADVANCE(3);
+      if (lookahead != 0) ADVANCE(27);
+      END_STATE();
+    case 28:
+      ACCEPT_TOKEN(anon_sym_DQUOTE);
+      END_STATE();
+    case 29:
+      ACCEPT_TOKEN(anon_sym_DQUOTE);
+      if (lookahead == '"') ADVANCE(1);
+      END_STATE();
+    case 30:
+      ACCEPT_TOKEN(aux_sym_string_value_token2);
+      if (lookahead == '#') ADVANCE(31);
+      if (lookahead == ',') ADVANCE(48);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ' ||
+          lookahead == 0xfeff) ADVANCE(30);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != '"' &&
+          lookahead != '#' &&
+          lookahead != '\\') ADVANCE(32);
+      END_STATE();
+    case 31:
+      ACCEPT_TOKEN(aux_sym_string_value_token2);
+      if (lookahead == '"' ||
+          lookahead == '\\') ADVANCE(45);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(31);
+      END_STATE();
+    case 32:
+      ACCEPT_TOKEN(aux_sym_string_value_token2);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '"' &&
+          lookahead != '\\') ADVANCE(32);
+      END_STATE();
+    case 33:
+      ACCEPT_TOKEN(sym_int_value);
+      if (lookahead == '.') ADVANCE(11);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(10);
+      END_STATE();
+    case 34:
+      ACCEPT_TOKEN(sym_int_value);
+      if (lookahead == '.') ADVANCE(11);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(10);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(34);
+      END_STATE();
+    case 35:
+      ACCEPT_TOKEN(sym_float_value);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(10);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(35);
+      END_STATE();
+    case 36:
+      ACCEPT_TOKEN(sym_float_value);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(36);
+      END_STATE();
+    case 37:
+      ACCEPT_TOKEN(anon_sym_LBRACK);
+      END_STATE();
+    case 38:
+      ACCEPT_TOKEN(anon_sym_RBRACK);
+      END_STATE();
+    case 39:
+      ACCEPT_TOKEN(anon_sym_DOT_DOT_DOT);
+      END_STATE();
+    case 40:
+      ACCEPT_TOKEN(anon_sym_AT);
+      END_STATE();
+    case 41:
+      ACCEPT_TOKEN(anon_sym_BANG);
+      END_STATE();
+    case 42:
+      ACCEPT_TOKEN(sym__name);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(42);
+      END_STATE();
+    case 43:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead == '\n') ADVANCE(27);
+      if (lookahead == '"') ADVANCE(45);
+      if (lookahead != 0) ADVANCE(25);
+      END_STATE();
+    case 44:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead == '\n') ADVANCE(27);
+      if (lookahead == '"') ADVANCE(43);
+      if (lookahead != 0) ADVANCE(25);
+      END_STATE();
+    case 45:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(45);
+      END_STATE();
+    case 46:
+      ACCEPT_TOKEN(sym_comma);
+      END_STATE();
+    case 47:
+      ACCEPT_TOKEN(sym_comma);
+      if (lookahead == '"') ADVANCE(3);
+      if (lookahead != 0) ADVANCE(27);
+      END_STATE();
+    case 48:
+      ACCEPT_TOKEN(sym_comma);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '"' &&
+          lookahead != '\\') ADVANCE(32);
+      END_STATE();
+    case 49:
+      ACCEPT_TOKEN(sym_grit_metavariable);
+      END_STATE();
+    case 50:
+      ACCEPT_TOKEN(sym_grit_metavariable);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(50);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static bool ts_lex_keywords(TSLexer *lexer, TSStateId state) {
+  START_LEXER();
+  eof = lexer->eof(lexer);
+  switch (state) {
+    case 0:
+      ADVANCE_MAP(
+        'A', 1,
+        'E', 2,
+        'F', 3,
+        'I', 4,
+        'M', 5,
+        'O', 6,
+        'Q', 7,
+        'S', 8,
+        'U', 9,
+        'V', 10,
+        'd', 11,
+        'e', 12,
+        'f', 13,
+        'i', 14,
+        'm', 15,
+        'n', 16,
+        'o', 17,
+        'q', 18,
+        'r', 19,
+        's', 20,
+        't', 21,
+        'u', 22,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ' ||
+          lookahead == 0xfeff) SKIP(0);
+      END_STATE();
+    case 1:
+      if (lookahead == 'R') ADVANCE(23);
+      END_STATE();
+    case 2:
+      if (lookahead == 'N') ADVANCE(24);
+      END_STATE();
+    case 3:
+      if (lookahead == 'I') ADVANCE(25);
+      if (lookahead == 'R') ADVANCE(26);
+      END_STATE();
+    case 4:
+      if (lookahead == 'N') ADVANCE(27);
+      END_STATE();
+    case 5:
+      if (lookahead == 'U') ADVANCE(28);
+      END_STATE();
+    case 6:
+      if (lookahead == 'B') ADVANCE(29);
+      END_STATE();
+    case 7:
+      if (lookahead == 'U') ADVANCE(30);
+      END_STATE();
+    case 8:
+      if (lookahead == 'C') ADVANCE(31);
+      if (lookahead == 'U') ADVANCE(32);
+      END_STATE();
+    case 9:
+      if (lookahead == 'N') ADVANCE(33);
+      END_STATE();
+    case 10:
+      if (lookahead == 'A') ADVANCE(34);
+      END_STATE();
+    case 11:
+      if (lookahead == 'i') ADVANCE(35);
+      END_STATE();
+    case 12:
+      if (lookahead == 'n') ADVANCE(36);
+      if (lookahead == 'x') ADVANCE(37);
+      END_STATE();
+    case 13:
+      if (lookahead == 'a') ADVANCE(38);
+      if (lookahead == 'r') ADVANCE(39);
+      END_STATE();
+    case 14:
+      if (lookahead == 'm') ADVANCE(40);
+      if (lookahead == 'n') ADVANCE(41);
+      END_STATE();
+    case 15:
+      if (lookahead == 'u') ADVANCE(42);
+      END_STATE();
+    case 16:
+      if (lookahead == 'u') ADVANCE(43);
+      END_STATE();
+    case 17:
+      if (lookahead == 'n') ADVANCE(44);
+      END_STATE();
+    case 18:
+      if (lookahead == 'u') ADVANCE(45);
+      END_STATE();
+    case 19:
+      if (lookahead == 'e') ADVANCE(46);
+      END_STATE();
+    case 20:
+      if (lookahead == 'c') ADVANCE(47);
+      if (lookahead == 'u') ADVANCE(48);
+      END_STATE();
+    case 21:
+      if (lookahead == 'r') ADVANCE(49);
+      if (lookahead == 'y') ADVANCE(50);
+      END_STATE();
+    case 22:
+      if (lookahead == 'n') ADVANCE(51);
+      END_STATE();
+    case 23:
+      if (lookahead == 'G') ADVANCE(52);
+      END_STATE();
+    case 24:
+      if (lookahead == 'U') ADVANCE(53);
+      END_STATE();
+    case 25:
+      if (lookahead == 'E') ADVANCE(54);
+      END_STATE();
+    case 26:
+      if (lookahead == 'A') ADVANCE(55);
+      END_STATE();
+    case 27:
+      if (lookahead == 'L') ADVANCE(56);
+      if (lookahead == 'P') ADVANCE(57);
+      if (lookahead == 'T') ADVANCE(58);
+      END_STATE();
+    case 28:
+      if (lookahead == 'T') ADVANCE(59);
+      END_STATE();
+    case 29:
+      if (lookahead == 'J') ADVANCE(60);
+      END_STATE();
+    case 30:
+      if (lookahead == 'E') ADVANCE(61);
+      END_STATE();
+    case 31:
+      if (lookahead == 'A') ADVANCE(62);
+      if (lookahead == 'H') ADVANCE(63);
+      END_STATE();
+    case 32:
+      if (lookahead == 'B') ADVANCE(64);
+      END_STATE();
+    case 33:
+      if (lookahead == 'I') ADVANCE(65);
+      END_STATE();
+    case 34:
+      if (lookahead == 'R') ADVANCE(66);
+      END_STATE();
+    case 35:
+      if (lookahead == 'r') ADVANCE(67);
+      END_STATE();
+    case 36:
+      if (lookahead == 'u') ADVANCE(68);
+      END_STATE();
+    case 37:
+      if (lookahead == 't') ADVANCE(69);
+      END_STATE();
+    case 38:
+      if (lookahead == 'l') ADVANCE(70);
+      END_STATE();
+    case 39:
+      if (lookahead == 'a') ADVANCE(71);
+      END_STATE();
+    case 40:
+      if (lookahead == 'p') ADVANCE(72);
+      END_STATE();
+    case 41:
+      if (lookahead == 'p') ADVANCE(73);
+      if (lookahead == 't') ADVANCE(74);
+      END_STATE();
+    case 42:
+      if (lookahead == 't') ADVANCE(75);
+      END_STATE();
+    case 43:
+      if (lookahead == 'l') ADVANCE(76);
+      END_STATE();
+    case 44:
+      ACCEPT_TOKEN(anon_sym_on);
+      END_STATE();
+    case 45:
+      if (lookahead == 'e') ADVANCE(77);
+      END_STATE();
+    case 46:
+      if (lookahead == 'p') ADVANCE(78);
+      END_STATE();
+    case 47:
+      if (lookahead == 'a') ADVANCE(79);
+      if (lookahead == 'h') ADVANCE(80);
+      END_STATE();
+    case 48:
+      if (lookahead == 'b') ADVANCE(81);
+      END_STATE();
+    case 49:
+      if (lookahead == 'u') ADVANCE(82);
+      END_STATE();
+    case 50:
+      if (lookahead == 'p') ADVANCE(83);
+      END_STATE();
+    case 51:
+      if (lookahead == 'i') ADVANCE(84);
+      END_STATE();
+    case 52:
+      if (lookahead == 'U') ADVANCE(85);
+      END_STATE();
+    case 53:
+      if (lookahead == 'M') ADVANCE(86);
+      END_STATE();
+    case 54:
+      if (lookahead == 'L') ADVANCE(87);
+      END_STATE();
+    case 55:
+      if (lookahead == 'G') ADVANCE(88);
+      END_STATE();
+    case 56:
+      if (lookahead == 'I') ADVANCE(89);
+      END_STATE();
+    case 57:
+      if (lookahead == 'U') ADVANCE(90);
+      END_STATE();
+    case 58:
+      if (lookahead == 'E') ADVANCE(91);
+      END_STATE();
+    case 59:
+      if (lookahead == 'A') ADVANCE(92);
+      END_STATE();
+    case 60:
+      if (lookahead == 'E') ADVANCE(93);
+      END_STATE();
+    case 61:
+      if (lookahead == 'R') ADVANCE(94);
+      END_STATE();
+    case 62:
+      if (lookahead == 'L') ADVANCE(95);
+      END_STATE();
+    case 63:
+      if (lookahead == 'E') ADVANCE(96);
+      END_STATE();
+    case 64:
+      if (lookahead == 'S') ADVANCE(97);
+      END_STATE();
+    case 65:
+      if (lookahead == 'O') ADVANCE(98);
+      END_STATE();
+    case 66:
+      if (lookahead == 'I') ADVANCE(99);
+      END_STATE();
+    case 67:
+      if (lookahead == 'e') ADVANCE(100);
+      END_STATE();
+    case 68:
+      if (lookahead == 'm') ADVANCE(101);
+      END_STATE();
+    case 69:
+      if (lookahead == 'e') ADVANCE(102);
+      END_STATE();
+    case 70:
+      if (lookahead == 's') ADVANCE(103);
+      END_STATE();
+    case 71:
+      if (lookahead == 'g') ADVANCE(104);
+      END_STATE();
+    case 72:
+      if (lookahead == 'l') ADVANCE(105);
+      END_STATE();
+    case 73:
+      if (lookahead == 'u') ADVANCE(106);
+      END_STATE();
+    case 74:
+      if (lookahead == 'e') ADVANCE(107);
+      END_STATE();
+    case 75:
+      if (lookahead == 'a') ADVANCE(108);
+      END_STATE();
+    case 76:
+      if (lookahead == 'l') ADVANCE(109);
+      END_STATE();
+    case 77:
+      if (lookahead == 'r') ADVANCE(110);
+      END_STATE();
+    case 78:
+      if (lookahead == 'e') ADVANCE(111);
+      END_STATE();
+    case 79:
+      if (lookahead == 'l') ADVANCE(112);
+      END_STATE();
+    case 80:
+      if (lookahead == 'e') ADVANCE(113);
+      END_STATE();
+    case 81:
+      if (lookahead == 's') ADVANCE(114);
+      END_STATE();
+    case 82:
+      if (lookahead == 'e') ADVANCE(115);
+      END_STATE();
+    case 83:
+      if (lookahead == 'e') ADVANCE(116);
+      END_STATE();
+    case 84:
+      if (lookahead == 'o') ADVANCE(117);
+      END_STATE();
+    case 85:
+      if (lookahead == 'M') ADVANCE(118);
+      END_STATE();
+    case 86:
+      ACCEPT_TOKEN(anon_sym_ENUM);
+      if (lookahead == '_') ADVANCE(119);
+      END_STATE();
+    case 87:
+      if (lookahead == 'D') ADVANCE(120);
+      END_STATE();
+    case 88:
+      if (lookahead == 'M') ADVANCE(121);
+      END_STATE();
+    case 89:
+      if (lookahead == 'N') ADVANCE(122);
+      END_STATE();
+    case 90:
+      if (lookahead == 'T') ADVANCE(123);
+      END_STATE();
+    case 91:
+      if (lookahead == 'R') ADVANCE(124);
+      END_STATE();
+    case 92:
+      if (lookahead == 'T') ADVANCE(125);
+      END_STATE();
+    case 93:
+      if (lookahead == 'C') ADVANCE(126);
+      END_STATE();
+    case 94:
+      if (lookahead == 'Y') ADVANCE(127);
+      END_STATE();
+    case 95:
+      if (lookahead == 'A') ADVANCE(128);
+      END_STATE();
+    case 96:
+      if (lookahead == 'M') ADVANCE(129);
+      END_STATE();
+    case 97:
+      if (lookahead == 'C') ADVANCE(130);
+      END_STATE();
+    case 98:
+      if (lookahead == 'N') ADVANCE(131);
+      END_STATE();
+    case 99:
+      if (lookahead == 'A') ADVANCE(132);
+      END_STATE();
+    case 100:
+      if (lookahead == 'c') ADVANCE(133);
+      END_STATE();
+    case 101:
+      ACCEPT_TOKEN(anon_sym_enum);
+      END_STATE();
+    case 102:
+      if (lookahead == 'n') ADVANCE(134);
+      END_STATE();
+    case 103:
+      if (lookahead == 'e') ADVANCE(135);
+      END_STATE();
+    case 104:
+      if (lookahead == 'm') ADVANCE(136);
+      END_STATE();
+    case 105:
+      if (lookahead == 'e') ADVANCE(137);
+      END_STATE();
+    case 106:
+      if (lookahead == 't') ADVANCE(138);
+      END_STATE();
+    case 107:
+      if (lookahead == 'r') ADVANCE(139);
+      END_STATE();
+    case 108:
+      if (lookahead == 't') ADVANCE(140);
+      END_STATE();
+    case 109:
+      ACCEPT_TOKEN(sym_null_value);
+      END_STATE();
+    case 110:
+      if (lookahead == 'y') ADVANCE(141);
+      END_STATE();
+    case 111:
+      if (lookahead == 'a') ADVANCE(142);
+      END_STATE();
+    case 112:
+      if (lookahead == 'a') ADVANCE(143);
+      END_STATE();
+    case 113:
+      if (lookahead == 'm') ADVANCE(144);
+      END_STATE();
+    case 114:
+      if (lookahead == 'c') ADVANCE(145);
+      END_STATE();
+    case 115:
+      ACCEPT_TOKEN(anon_sym_true);
+      END_STATE();
+    case 116:
+      ACCEPT_TOKEN(anon_sym_type);
+      END_STATE();
+    case 117:
+      if (lookahead == 'n') ADVANCE(146);
+      END_STATE();
+    case 118:
+      if (lookahead == 'E') ADVANCE(147);
+      END_STATE();
+    case 119:
+      if (lookahead == 'V') ADVANCE(148);
+      END_STATE();
+    case 120:
+      ACCEPT_TOKEN(anon_sym_FIELD);
+      if (lookahead == '_') ADVANCE(149);
+      END_STATE();
+    case 121:
+      if (lookahead == 'E') ADVANCE(150);
+      END_STATE();
+    case 122:
+      if (lookahead == 'E') ADVANCE(151);
+      END_STATE();
+    case 123:
+      if (lookahead == '_') ADVANCE(152);
+      END_STATE();
+    case 124:
+      if (lookahead == 'F') ADVANCE(153);
+      END_STATE();
+    case 125:
+      if (lookahead == 'I') ADVANCE(154);
+      END_STATE();
+    case 126:
+      if (lookahead == 'T') ADVANCE(155);
+      END_STATE();
+    case 127:
+      ACCEPT_TOKEN(anon_sym_QUERY);
+      END_STATE();
+    case 128:
+      if (lookahead == 'R') ADVANCE(156);
+      END_STATE();
+    case 129:
+      if (lookahead == 'A') ADVANCE(157);
+      END_STATE();
+    case 130:
+      if (lookahead == 'R') ADVANCE(158);
+      END_STATE();
+    case 131:
+      ACCEPT_TOKEN(anon_sym_UNION);
+      END_STATE();
+    case 132:
+      if (lookahead == 'B') ADVANCE(159);
+      END_STATE();
+    case 133:
+      if (lookahead == 't') ADVANCE(160);
+      END_STATE();
+    case 134:
+      if (lookahead == 'd') ADVANCE(161);
+      END_STATE();
+    case 135:
+      ACCEPT_TOKEN(anon_sym_false);
+      END_STATE();
+    case 136:
+      if (lookahead == 'e') ADVANCE(162);
+      END_STATE();
+    case 137:
+      if (lookahead == 'm') ADVANCE(163);
+      END_STATE();
+    case 138:
+      ACCEPT_TOKEN(anon_sym_input);
+      END_STATE();
+    case 139:
+      if (lookahead == 'f') ADVANCE(164);
+      END_STATE();
+    case 140:
+      if (lookahead == 'i') ADVANCE(165);
+      END_STATE();
+    case 141:
+      ACCEPT_TOKEN(anon_sym_query);
+      END_STATE();
+    case 142:
+      if (lookahead == 't') ADVANCE(166);
+      END_STATE();
+    case 143:
+      if (lookahead == 'r') ADVANCE(167);
+      END_STATE();
+    case 144:
+      if (lookahead == 'a') ADVANCE(168);
+      END_STATE();
+    case 145:
+      if (lookahead == 'r') ADVANCE(169);
+      END_STATE();
+    case 146:
+      ACCEPT_TOKEN(anon_sym_union);
+      END_STATE();
+    case 147:
+      if (lookahead == 'N') ADVANCE(170);
+      END_STATE();
+    case 148:
+      if (lookahead == 'A') ADVANCE(171);
+      END_STATE();
+    case 149:
+      if (lookahead == 'D') ADVANCE(172);
+      END_STATE();
+    case 150:
+      if (lookahead == 'N') ADVANCE(173);
+      END_STATE();
+    case 151:
+      if (lookahead == '_') ADVANCE(174);
+      END_STATE();
+    case 152:
+      if (lookahead == 'F') ADVANCE(175);
+      if (lookahead == 'O') ADVANCE(176);
+      END_STATE();
+    case 153:
+      if (lookahead == 'A') ADVANCE(177);
+      END_STATE();
+    case 154:
+      if (lookahead == 'O') ADVANCE(178);
+      END_STATE();
+    case 155:
+      ACCEPT_TOKEN(anon_sym_OBJECT);
+      END_STATE();
+    case 156:
+      ACCEPT_TOKEN(anon_sym_SCALAR);
+      END_STATE();
+    case 157:
+      ACCEPT_TOKEN(anon_sym_SCHEMA);
+      END_STATE();
+    case 158:
+      if (lookahead == 'I') ADVANCE(179);
+      END_STATE();
+    case 159:
+      if (lookahead == 'L') ADVANCE(180);
+      END_STATE();
+    case 160:
+      if (lookahead == 'i') ADVANCE(181);
+      END_STATE();
+    case 161:
+      ACCEPT_TOKEN(anon_sym_extend);
+      END_STATE();
+    case 162:
+      if (lookahead == 'n') ADVANCE(182);
+      END_STATE();
+    case 163:
+      if (lookahead == 'e') ADVANCE(183);
+      END_STATE();
+    case 164:
+      if (lookahead == 'a') ADVANCE(184);
+      END_STATE();
+    case 165:
+      if (lookahead == 'o') ADVANCE(185);
+      END_STATE();
+    case 166:
+      if (lookahead == 'a') ADVANCE(186);
+      END_STATE();
+    case 167:
+      ACCEPT_TOKEN(anon_sym_scalar);
+      END_STATE();
+    case 168:
+      ACCEPT_TOKEN(anon_sym_schema);
+      END_STATE();
+    case 169:
+      if (lookahead == 'i') ADVANCE(187);
+      END_STATE();
+    case 170:
+      if (lookahead == 'T') ADVANCE(188);
+      END_STATE();
+    case 171:
+      if (lookahead == 'L') ADVANCE(189);
+      END_STATE();
+    case 172:
+      if (lookahead == 'E') ADVANCE(190);
+      END_STATE();
+    case 173:
+      if (lookahead == 'T') ADVANCE(191);
+      END_STATE();
+    case 174:
+      if (lookahead == 'F') ADVANCE(192);
+      END_STATE();
+    case 175:
+      if (lookahead == 'I') ADVANCE(193);
+      END_STATE();
+    case 176:
+      if (lookahead == 'B') ADVANCE(194);
+      END_STATE();
+    case 177:
+      if (lookahead == 'C') ADVANCE(195);
+      END_STATE();
+    case 178:
+      if (lookahead == 'N') ADVANCE(196);
+      END_STATE();
+    case 179:
+      if (lookahead == 'P') ADVANCE(197);
+      END_STATE();
+    case 180:
+      if (lookahead == 'E') ADVANCE(198);
+      END_STATE();
+    case 181:
+      if (lookahead == 'v') ADVANCE(199);
+      END_STATE();
+    case 182:
+      if (lookahead == 't') ADVANCE(200);
+      END_STATE();
+    case 183:
+      if (lookahead == 'n') ADVANCE(201);
+      END_STATE();
+    case 184:
+      if (lookahead == 'c') ADVANCE(202);
+      END_STATE();
+    case 185:
+      if (lookahead == 'n') ADVANCE(203);
+      END_STATE();
+    case 186:
+      if (lookahead == 'b') ADVANCE(204);
+      END_STATE();
+    case 187:
+      if (lookahead == 'p') ADVANCE(205);
+      END_STATE();
+    case 188:
+      if (lookahead == '_') ADVANCE(206);
+      END_STATE();
+    case 189:
+      if (lookahead == 'U') ADVANCE(207);
+      END_STATE();
+    case 190:
+      if (lookahead == 'F') ADVANCE(208);
+      END_STATE();
+    case 191:
+      if (lookahead == '_') ADVANCE(209);
+      END_STATE();
+    case 192:
+      if (lookahead == 'R') ADVANCE(210);
+      END_STATE();
+    case 193:
+      if (lookahead == 'E') ADVANCE(211);
+      END_STATE();
+    case 194:
+      if (lookahead == 'J') ADVANCE(212);
+      END_STATE();
+    case 195:
+      if (lookahead == 'E') ADVANCE(213);
+      END_STATE();
+    case 196:
+      ACCEPT_TOKEN(anon_sym_MUTATION);
+      END_STATE();
+    case 197:
+      if (lookahead == 'T') ADVANCE(214);
+      END_STATE();
+    case 198:
+      if (lookahead == '_') ADVANCE(215);
+      END_STATE();
+    case 199:
+      if (lookahead == 'e') ADVANCE(216);
+      END_STATE();
+    case 200:
+      ACCEPT_TOKEN(anon_sym_fragment);
+      END_STATE();
+    case 201:
+      if (lookahead == 't') ADVANCE(217);
+      END_STATE();
+    case 202:
+      if (lookahead == 'e') ADVANCE(218);
+      END_STATE();
+    case 203:
+      ACCEPT_TOKEN(anon_sym_mutation);
+      END_STATE();
+    case 204:
+      if (lookahead == 'l') ADVANCE(219);
+      END_STATE();
+    case 205:
+      if (lookahead == 't') ADVANCE(220);
+      END_STATE();
+    case 206:
+      if (lookahead == 'D') ADVANCE(221);
+      END_STATE();
+    case 207:
+      if (lookahead == 'E') ADVANCE(222);
+      END_STATE();
+    case 208:
+      if (lookahead == 'I') ADVANCE(223);
+      END_STATE();
+    case 209:
+      if (lookahead == 'D') ADVANCE(224);
+      if (lookahead == 'S') ADVANCE(225);
+      END_STATE();
+    case 210:
+      if (lookahead == 'A') ADVANCE(226);
+      END_STATE();
+    case 211:
+      if (lookahead == 'L') ADVANCE(227);
+      END_STATE();
+    case 212:
+      if (lookahead == 'E') ADVANCE(228);
+      END_STATE();
+    case 213:
+      ACCEPT_TOKEN(anon_sym_INTERFACE);
+      END_STATE();
+    case 214:
+      if (lookahead == 'I') ADVANCE(229);
+      END_STATE();
+    case 215:
+      if (lookahead == 'D') ADVANCE(230);
+      END_STATE();
+    case 216:
+      ACCEPT_TOKEN(anon_sym_directive);
+      END_STATE();
+    case 217:
+      if (lookahead == 's') ADVANCE(231);
+      END_STATE();
+    case 218:
+      ACCEPT_TOKEN(anon_sym_interface);
+      END_STATE();
+    case 219:
+      if (lookahead == 'e') ADVANCE(232);
+      END_STATE();
+    case 220:
+      if (lookahead == 'i') ADVANCE(233);
+      END_STATE();
+    case 221:
+      if (lookahead == 'E') ADVANCE(234);
+      END_STATE();
+    case 222:
+      ACCEPT_TOKEN(anon_sym_ENUM_VALUE);
+      END_STATE();
+    case 223:
+      if (lookahead == 'N') ADVANCE(235);
+      END_STATE();
+    case 224:
+      if (lookahead == 'E') ADVANCE(236);
+      END_STATE();
+    case 225:
+      if (lookahead == 'P') ADVANCE(237);
+      END_STATE();
+    case 226:
+      if (lookahead == 'G') ADVANCE(238);
+      END_STATE();
+    case 227:
+      if (lookahead == 'D') ADVANCE(239);
+      END_STATE();
+    case 228:
+      if (lookahead == 'C') ADVANCE(240);
+      END_STATE();
+    case 229:
+      if (lookahead == 'O') ADVANCE(241);
+      END_STATE();
+    case 230:
+      if (lookahead == 'E') ADVANCE(242);
+      END_STATE();
+    case 231:
+      ACCEPT_TOKEN(anon_sym_implements);
+      END_STATE();
+    case 232:
+      ACCEPT_TOKEN(anon_sym_repeatable);
+      END_STATE();
+    case 233:
+      if (lookahead == 'o') ADVANCE(243);
+      END_STATE();
+    case 234:
+      if (lookahead == 'F') ADVANCE(244);
+      END_STATE();
+    case 235:
+      if (lookahead == 'I') ADVANCE(245);
+      END_STATE();
+    case 236:
+      if (lookahead == 'F') ADVANCE(246);
+      END_STATE();
+    case 237:
+      if (lookahead == 'R') ADVANCE(247);
+      END_STATE();
+    case 238:
+      if (lookahead == 'M') ADVANCE(248);
+      END_STATE();
+    case 239:
+      if (lookahead == '_') ADVANCE(249);
+      END_STATE();
+    case 240:
+      if (lookahead == 'T') ADVANCE(250);
+      END_STATE();
+    case 241:
+      if (lookahead == 'N') ADVANCE(251);
+      END_STATE();
+    case 242:
+      if (lookahead == 'F') ADVANCE(252);
+      END_STATE();
+    case 243:
+      if (lookahead == 'n') ADVANCE(253);
+      END_STATE();
+    case 244:
+      if (lookahead == 'I') ADVANCE(254);
+      END_STATE();
+    case 245:
+      if (lookahead == 'T') ADVANCE(255);
+      END_STATE();
+    case 246:
+      if (lookahead == 'I') ADVANCE(256);
+      END_STATE();
+    case 247:
+      if (lookahead == 'E') ADVANCE(257);
+      END_STATE();
+    case 248:
+      if (lookahead == 'E') ADVANCE(258);
+      END_STATE();
+    case 249:
+      if (lookahead == 'D') ADVANCE(259);
+      END_STATE();
+    case 250:
+      ACCEPT_TOKEN(anon_sym_INPUT_OBJECT);
+      END_STATE();
+    case 251:
+      ACCEPT_TOKEN(anon_sym_SUBSCRIPTION);
+      END_STATE();
+    case 252:
+      if (lookahead == 'I') ADVANCE(260);
+      END_STATE();
+    case 253:
+      ACCEPT_TOKEN(anon_sym_subscription);
+      END_STATE();
+    case 254:
+      if (lookahead == 'N') ADVANCE(261);
+      END_STATE();
+    case 255:
+      if (lookahead == 'I') ADVANCE(262);
+      END_STATE();
+    case 256:
+      if (lookahead == 'N') ADVANCE(263);
+      END_STATE();
+    case 257:
+      if (lookahead == 'A') ADVANCE(264);
+      END_STATE();
+    case 258:
+      if (lookahead == 'N') ADVANCE(265);
+      END_STATE();
+    case 259:
+      if (lookahead == 'E') ADVANCE(266);
+      END_STATE();
+    case 260:
+      if (lookahead == 'N') ADVANCE(267);
+      END_STATE();
+    case 261:
+      if (lookahead == 'I') ADVANCE(268);
+      END_STATE();
+    case 262:
+      if (lookahead == 'O') ADVANCE(269);
+      END_STATE();
+    case 263:
+      if (lookahead == 'I') ADVANCE(270);
+      END_STATE();
+    case 264:
+      if (lookahead == 'D') ADVANCE(271);
+      END_STATE();
+    case 265:
+      if (lookahead == 'T') ADVANCE(272);
+      END_STATE();
+    case 266:
+      if (lookahead == 'F') ADVANCE(273);
+      END_STATE();
+    case 267:
+      if (lookahead == 'I') ADVANCE(274);
+      END_STATE();
+    case 268:
+      if (lookahead == 'T') ADVANCE(275);
+      END_STATE();
+    case 269:
+      if (lookahead == 'N') ADVANCE(276);
+      END_STATE();
+    case 270:
+      if (lookahead == 'T') ADVANCE(277);
+      END_STATE();
+    case 271:
+      ACCEPT_TOKEN(anon_sym_FRAGMENT_SPREAD);
+      END_STATE();
+    case 272:
+      ACCEPT_TOKEN(anon_sym_INLINE_FRAGMENT);
+      END_STATE();
+    case 273:
+      if (lookahead == 'I') ADVANCE(278);
+      END_STATE();
+    case 274:
+      if (lookahead == 'T') ADVANCE(279);
+      END_STATE();
+    case 275:
+      if (lookahead == 'I') ADVANCE(280);
+      END_STATE();
+    case 276:
+      ACCEPT_TOKEN(anon_sym_FIELD_DEFINITION);
+      END_STATE();
+    case 277:
+      if (lookahead == 'I') ADVANCE(281);
+      END_STATE();
+    case 278:
+      if (lookahead == 'N') ADVANCE(282);
+      END_STATE();
+    case 279:
+      if (lookahead == 'I') ADVANCE(283);
+      END_STATE();
+    case 280:
+      if (lookahead == 'O') ADVANCE(284);
+      END_STATE();
+    case 281:
+      if (lookahead == 'O') ADVANCE(285);
+      END_STATE();
+    case 282:
+      if (lookahead == 'I') ADVANCE(286);
+      END_STATE();
+    case 283:
+      if (lookahead == 'O') ADVANCE(287);
+      END_STATE();
+    case 284:
+      if (lookahead == 'N') ADVANCE(288);
+      END_STATE();
+    case 285:
+      if (lookahead == 'N') ADVANCE(289);
+      END_STATE();
+    case 286:
+      if (lookahead == 'T') ADVANCE(290);
+      END_STATE();
+    case 287:
+      if (lookahead == 'N') ADVANCE(291);
+      END_STATE();
+    case 288:
+      ACCEPT_TOKEN(anon_sym_ARGUMENT_DEFINITION);
+      END_STATE();
+    case 289:
+      ACCEPT_TOKEN(anon_sym_FRAGMENT_DEFINITION);
+      END_STATE();
+    case 290:
+      if (lookahead == 'I') ADVANCE(292);
+      END_STATE();
+    case 291:
+      ACCEPT_TOKEN(anon_sym_VARIABLE_DEFINITION);
+      END_STATE();
+    case 292:
+      if (lookahead == 'O') ADVANCE(293);
+      END_STATE();
+    case 293:
+      if (lookahead == 'N') ADVANCE(294);
+      END_STATE();
+    case 294:
+      ACCEPT_TOKEN(anon_sym_INPUT_FIELD_DEFINITION);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static const TSLexMode ts_lex_modes[STATE_COUNT] = {
+  [0] = {.lex_state = 0},
+  [1] = {.lex_state = 0},
+  [2] = {.lex_state = 0},
+  [3] = {.lex_state = 0},
+  [4] = {.lex_state = 0},
+  [5] = {.lex_state = 0},
+  [6] = {.lex_state = 0},
+  [7] = {.lex_state = 0},
+  [8] = {.lex_state = 0},
+  [9] = {.lex_state = 0},
+  [10] = {.lex_state = 0},
+  [11] = {.lex_state = 0},
+  [12] = {.lex_state = 0},
+  [13] = {.lex_state = 0},
+  [14] = {.lex_state = 0},
+  [15] = {.lex_state = 0},
+  [16] = {.lex_state = 0},
+  [17] = {.lex_state = 0},
+  [18] = {.lex_state = 0},
+  [19] = {.lex_state = 0},
+  [20] = {.lex_state = 0},
+  [21] = {.lex_state = 0},
+  [22] = {.lex_state = 0},
+  [23] = {.lex_state = 0},
+  [24] = {.lex_state = 0},
+  [25] = {.lex_state = 0},
+  [26] = {.lex_state = 0},
+  [27] = {.lex_state = 0},
+  [28] = {.lex_state = 0},
+  [29] = {.lex_state = 0},
+  [30] = {.lex_state = 0},
+  [31] = {.lex_state = 0},
+  [32] = {.lex_state = 0},
+  [33] = {.lex_state = 0},
+  [34] = {.lex_state = 0},
+  [35] = {.lex_state = 0},
+  [36] = {.lex_state = 0},
+  [37] = {.lex_state = 0},
+  [38] = {.lex_state = 0},
+  [39] = {.lex_state = 0},
+  [40] = {.lex_state = 0},
+  [41] = {.lex_state = 0},
+  [42] = {.lex_state = 0},
+  [43] = {.lex_state = 0},
+  [44] = {.lex_state = 0},
+  [45] = {.lex_state = 0},
+  [46] = {.lex_state = 0},
+  [47] = {.lex_state = 0},
+  [48] = {.lex_state = 0},
+  [49] = {.lex_state = 0},
+  [50] = {.lex_state = 0},
+  [51] = {.lex_state = 0},
+  [52] = {.lex_state = 0},
+  [53] = {.lex_state = 0},
+  [54] = {.lex_state = 0},
+  [55] = {.lex_state = 0},
+  [56] = {.lex_state = 0},
+  [57] = {.lex_state = 0},
+  [58] = {.lex_state = 0},
+  [59] = {.lex_state = 0},
+  [60] = {.lex_state = 0},
+  [61] = {.lex_state = 0},
+  [62] = {.lex_state = 0},
+  [63] = {.lex_state = 0},
+  [64] = {.lex_state = 0},
+  [65] = {.lex_state = 0},
+  [66] = {.lex_state = 0},
+  [67] = {.lex_state = 0},
+  [68] = {.lex_state = 0},
+  [69] = {.lex_state = 0},
+  [70] = {.lex_state = 0},
+  [71] = {.lex_state = 0},
+  [72] = {.lex_state = 0},
+  [73] = {.lex_state = 0},
+  [74] = {.lex_state = 0},
+  [75] = {.lex_state = 0},
+  [76] = {.lex_state = 0},
+  [77] = {.lex_state = 0},
+  [78] = {.lex_state = 0},
+  [79] = {.lex_state = 0},
+  [80] = {.lex_state = 0},
+  [81] = {.lex_state = 0},
+  [82] = {.lex_state = 0},
+  [83] = {.lex_state = 0},
+  [84] = {.lex_state = 0},
+  [85] = {.lex_state = 0},
+  [86] = {.lex_state = 0},
+  [87] = {.lex_state = 0},
+  [88] = {.lex_state = 0},
+  [89] = {.lex_state = 0},
+  [90] = {.lex_state = 0},
+  [91] = {.lex_state = 0},
+  [92] = {.lex_state = 0},
+  [93] = {.lex_state = 0},
+  [94] = {.lex_state = 0},
+  [95] = {.lex_state = 0},
+  [96] = {.lex_state = 0},
+  [97] = {.lex_state = 0},
+  [98] = {.lex_state = 0},
+  [99] = {.lex_state = 0},
+  [100] = {.lex_state = 0},
+  [101] = {.lex_state = 0},
+  [102] = {.lex_state = 0},
+  [103] = {.lex_state = 0},
+  [104] = {.lex_state = 0},
+  [105] = {.lex_state = 0},
+  [106] = {.lex_state = 0},
+  [107] = {.lex_state = 0},
+  [108] = {.lex_state = 0},
+  [109] = {.lex_state = 0},
+  [110] = {.lex_state = 0},
+  [111] = {.lex_state = 0},
+  [112] = {.lex_state = 0},
+  [113] = {.lex_state = 0},
+  [114] = {.lex_state = 0},
+  [115] = {.lex_state = 0},
+  [116] = {.lex_state = 0},
+  [117] = {.lex_state = 0},
+  [118] = {.lex_state = 0},
+  [119] = {.lex_state = 0},
+  [120] = {.lex_state = 0},
+  [121] = {.lex_state = 0},
+  [122] = {.lex_state = 0},
+  [123] = {.lex_state = 0},
+  [124] = {.lex_state = 0},
+  [125] = {.lex_state = 0},
+  [126] = {.lex_state = 0},
+  [127] = {.lex_state = 0},
+  [128] = {.lex_state = 0},
+  [129] = {.lex_state = 0},
+  [130] = {.lex_state = 0},
+  [131] = {.lex_state = 0},
+  [132] = {.lex_state = 0},
+  [133] = {.lex_state = 0},
+  [134] = {.lex_state = 0},
+  [135] = {.lex_state = 0},
+  [136] = {.lex_state = 0},
+  [137] = {.lex_state = 0},
+  [138] = {.lex_state = 0},
+  [139] = {.lex_state = 0},
+  [140] = {.lex_state = 0},
+  [141] = {.lex_state = 0},
+  [142] = {.lex_state = 0},
+  [143] = {.lex_state = 0},
+  [144] = {.lex_state = 0},
+  [145] = {.lex_state = 0},
+  [146] = {.lex_state = 0},
+  [147] = {.lex_state = 0},
+  [148] = {.lex_state = 0},
+  [149] = {.lex_state = 0},
+  [150] = {.lex_state = 0},
+  [151] = {.lex_state = 0},
+  [152] = {.lex_state = 0},
+  [153] = {.lex_state = 0},
+  [154] = {.lex_state = 0},
+  [155] = {.lex_state = 0},
+  [156] = {.lex_state = 0},
+  [157] = {.lex_state = 0},
+  [158] = {.lex_state = 0},
+  [159] = {.lex_state = 0},
+  [160] = {.lex_state = 0},
+  [161] = {.lex_state = 0},
+  [162] = {.lex_state = 0},
+  [163] = {.lex_state = 0},
+  [164] = {.lex_state = 0},
+  [165] = {.lex_state = 0},
+  [166] = {.lex_state = 0},
+  [167] = {.lex_state = 0},
+  [168] = {.lex_state = 0},
+  [169] = {.lex_state = 0},
+  [170] = {.lex_state = 0},
+  [171] = {.lex_state = 0},
+  [172] = {.lex_state = 0},
+  [173] = {.lex_state = 0},
+  [174] = {.lex_state = 0},
+  [175] = {.lex_state = 0},
+  [176] = {.lex_state = 0},
+  [177] = {.lex_state = 0},
+  [178] = {.lex_state = 0},
+  [179] = {.lex_state = 0},
+  [180] = {.lex_state = 0},
+  [181] = {.lex_state = 0},
+  [182] = {.lex_state = 0},
+  [183] = {.lex_state = 0},
+  [184] = {.lex_state = 0},
+  [185] = {.lex_state = 0},
+  [186] = {.lex_state = 0},
+  [187] = {.lex_state = 0},
+  [188] = {.lex_state = 0},
+  [189] = {.lex_state = 0},
+  [190] = {.lex_state = 0},
+  [191] = {.lex_state = 0},
+  [192] = {.lex_state = 0},
+  [193] = {.lex_state = 0},
+  [194] = {.lex_state = 0},
+  [195] = {.lex_state = 0},
+  [196] = {.lex_state = 0},
+  [197] = {.lex_state = 0},
+  [198] = {.lex_state = 0},
+  [199] = {.lex_state = 0},
+  [200] = {.lex_state = 0},
+  [201] = {.lex_state = 0},
+  [202] = {.lex_state = 0},
+  [203] = {.lex_state = 0},
+  [204] = {.lex_state = 0},
+  [205] = {.lex_state = 0},
+  [206] = {.lex_state = 0},
+  [207] = {.lex_state = 0},
+  [208] = {.lex_state = 0},
+  [209] = {.lex_state = 0},
+  [210] = {.lex_state = 0},
+  [211] = {.lex_state = 0},
+  [212] = {.lex_state = 0},
+  [213] = {.lex_state = 0},
+  [214] = {.lex_state = 0},
+  [215] = {.lex_state = 0},
+  [216] = {.lex_state = 0},
+  [217] = {.lex_state = 0},
+  [218] = {.lex_state = 0},
+  [219] = {.lex_state = 0},
+  [220] = {.lex_state = 0},
+  [221] = {.lex_state = 0},
+  [222] = {.lex_state = 0},
+  [223] = {.lex_state = 0},
+  [224] = {.lex_state = 0},
+  [225] = {.lex_state = 0},
+  [226] = {.lex_state = 0},
+  [227] = {.lex_state = 0},
+  [228] = {.lex_state = 0},
+  [229] = {.lex_state = 0},
+  [230] = {.lex_state = 0},
+  [231] = {.lex_state = 0},
+  [232] = {.lex_state = 0},
+  [233] = {.lex_state = 0},
+  [234] = {.lex_state = 0},
+  [235] = {.lex_state = 0},
+  [236] = {.lex_state = 0},
+  [237] = {.lex_state = 0},
+  [238] = {.lex_state = 0},
+  [239] = {.lex_state = 0},
+  [240] = {.lex_state = 0},
+  [241] = {.lex_state = 0},
+  [242] = {.lex_state = 0},
+  [243] = {.lex_state = 0},
+  [244] = {.lex_state = 0},
+  [245] = {.lex_state = 0},
+  [246] = {.lex_state = 0},
+  [247] = {.lex_state = 0},
+  [248] = {.lex_state = 0},
+  [249] = {.lex_state = 0},
+  [250] = {.lex_state = 0},
+  [251] = {.lex_state = 0},
+  [252] = {.lex_state = 0},
+  [253] = {.lex_state = 0},
+  [254] = {.lex_state = 0},
+  [255] = {.lex_state = 0},
+  [256] = {.lex_state = 0},
+  [257] = {.lex_state = 0},
+  [258] = {.lex_state = 0},
+  [259] = {.lex_state = 0},
+  [260] = {.lex_state = 0},
+  [261] = {.lex_state = 0},
+  [262] = {.lex_state = 0},
+  [263] = {.lex_state = 0},
+  [264] = {.lex_state = 0},
+  [265] = {.lex_state = 0},
+  [266] = {.lex_state = 0},
+  [267] = {.lex_state = 0},
+  [268] = {.lex_state = 0},
+  [269] = {.lex_state = 0},
+  [270] = {.lex_state = 0},
+  [271] = {.lex_state = 0},
+  [272] = {.lex_state = 0},
+  [273] = {.lex_state = 0},
+  [274] = {.lex_state = 0},
+  [275] = {.lex_state = 0},
+  [276] = {.lex_state = 0},
+  [277] = {.lex_state = 0},
+  [278] = {.lex_state = 0},
+  [279] = {.lex_state = 0},
+  [280] = {.lex_state = 0},
+  [281] = {.lex_state = 0},
+  [282] = {.lex_state = 0},
+  [283] = {.lex_state = 0},
+  [284] = {.lex_state = 0},
+  [285] = {.lex_state = 0},
+  [286] = {.lex_state = 0},
+  [287] = {.lex_state = 0},
+  [288] = {.lex_state = 0},
+  [289] = {.lex_state = 0},
+  [290] = {.lex_state = 0},
+  [291] = {.lex_state = 0},
+  [292] = {.lex_state = 0},
+  [293] = {.lex_state = 0},
+  [294] = {.lex_state = 0},
+  [295] = {.lex_state = 0},
+  [296] = {.lex_state = 0},
+  [297] = {.lex_state = 0},
+  [298] = {.lex_state = 0},
+  [299] = {.lex_state = 0},
+  [300] = {.lex_state = 0},
+  [301] = {.lex_state = 0},
+  [302] = {.lex_state = 0},
+  [303] = {.lex_state = 0},
+  [304] = {.lex_state = 0},
+  [305] = {.lex_state = 0},
+  [306] = {.lex_state = 0},
+  [307] = {.lex_state = 0},
+  [308] = {.lex_state = 0},
+  [309] = {.lex_state = 0},
+  [310] = {.lex_state = 0},
+  [311] = {.lex_state = 0},
+  [312] = {.lex_state = 0},
+  [313] = {.lex_state = 0},
+  [314] = {.lex_state = 0},
+  [315] = {.lex_state = 0},
+  [316] = {.lex_state = 0},
+  [317] = {.lex_state = 0},
+  [318] = {.lex_state = 0},
+  [319] = {.lex_state = 0},
+  [320] = {.lex_state = 0},
+  [321] = {.lex_state = 0},
+  [322] = {.lex_state = 0},
+  [323] = {.lex_state = 0},
+  [324] = {.lex_state = 0},
+  [325] = {.lex_state = 0},
+  [326] = {.lex_state = 0},
+  [327] = {.lex_state = 0},
+  [328] = {.lex_state = 0},
+  [329] = {.lex_state = 0},
+  [330] = {.lex_state = 0},
+  [331] = {.lex_state = 0},
+  [332] = {.lex_state = 0},
+  [333] = {.lex_state = 0},
+  [334] = {.lex_state = 0},
+  [335] = {.lex_state = 0},
+  [336] = {.lex_state = 0},
+  [337] = {.lex_state = 0},
+  [338] = {.lex_state = 0},
+  [339] = {.lex_state = 0},
+  [340] = {.lex_state = 0},
+  [341] = {.lex_state = 0},
+  [342] = {.lex_state = 0},
+  [343] = {.lex_state = 0},
+  [344] = {.lex_state = 0},
+  [345] = {.lex_state = 0},
+  [346] = {.lex_state = 0},
+  [347] = {.lex_state = 0},
+  [348] = {.lex_state = 0},
+  [349] = {.lex_state = 0},
+  [350] = {.lex_state = 0},
+  [351] = {.lex_state = 0},
+  [352] = {.lex_state = 0},
+  [353] = {.lex_state = 0},
+  [354] = {.lex_state = 0},
+  [355] = {.lex_state = 0},
+  [356] = {.lex_state = 0},
+  [357] = {.lex_state = 0},
+  [358] = {.lex_state = 0},
+  [359] = {.lex_state = 0},
+  [360] = {.lex_state = 0},
+  [361] = {.lex_state = 0},
+  [362] = {.lex_state = 0},
+  [363] = {.lex_state = 0},
+  [364] = {.lex_state = 0},
+  [365] = {.lex_state = 0},
+  [366] = {.lex_state = 0},
+  [367] = {.lex_state = 0},
+  [368] = {.lex_state = 0},
+  [369] = {.lex_state = 0},
+  [370] = {.lex_state = 0},
+  [371] = {.lex_state = 0},
+  [372] = {.lex_state = 0},
+  [373] = {.lex_state = 0},
+  [374] = {.lex_state = 0},
+  [375] = {.lex_state = 0},
+  [376] = {.lex_state = 0},
+  [377] = {.lex_state = 0},
+  [378] = {.lex_state = 0},
+  [379] = {.lex_state = 0},
+  [380] = {.lex_state = 0},
+  [381] = {.lex_state = 0},
+  [382] = {.lex_state = 0},
+  [383] = {.lex_state = 0},
+  [384] = {.lex_state = 0},
+  [385] = {.lex_state = 0},
+  [386] = {.lex_state = 0},
+  [387] = {.lex_state = 0},
+  [388] = {.lex_state = 0},
+  [389] = {.lex_state = 0},
+  [390] = {.lex_state = 0},
+  [391] = {.lex_state = 0},
+  [392] = {.lex_state = 0},
+  [393] = {.lex_state = 0},
+  [394] = {.lex_state = 0},
+  [395] = {.lex_state = 0},
+  [396] = {.lex_state = 0},
+  [397] = {.lex_state = 0},
+  [398] = {.lex_state = 0},
+  [399] = {.lex_state = 0},
+  [400] = {.lex_state = 0},
+  [401] = {.lex_state = 0},
+  [402] = {.lex_state = 0},
+  [403] = {.lex_state = 0},
+  [404] = {.lex_state = 0},
+  [405] = {.lex_state = 2},
+  [406] = {.lex_state = 0},
+  [407] = {.lex_state = 26},
+  [408] = {.lex_state = 0},
+  [409] = {.lex_state = 0},
+  [410] = {.lex_state = 0},
+  [411] = {.lex_state = 0},
+  [412] = {.lex_state = 0},
+  [413] = {.lex_state = 0},
+  [414] = {.lex_state = 0},
+  [415] = {.lex_state = 30},
+  [416] = {.lex_state = 0},
+  [417] = {.lex_state = 0},
+  [418] = {.lex_state = 0},
+  [419] = {.lex_state = 0},
+  [420] = {.lex_state = 2},
+  [421] = {.lex_state = 0},
+  [422] = {.lex_state = 0},
+  [423] = {.lex_state = 0},
+  [424] = {.lex_state = 0},
+  [425] = {.lex_state = 0},
+  [426] = {.lex_state = 0},
+  [427] = {.lex_state = 0},
+  [428] = {.lex_state = 0},
+  [429] = {.lex_state = 0},
+  [430] = {.lex_state = 26},
+  [431] = {.lex_state = 30},
+  [432] = {.lex_state = 0},
+  [433] = {.lex_state = 0},
+  [434] = {.lex_state = 0},
+};
+
+static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
+  [0] = {
+    [ts_builtin_sym_end] = ACTIONS(1),
+    [sym__name] = ACTIONS(1),
+    [anon_sym_schema] = ACTIONS(1),
+    [anon_sym_LBRACE] = ACTIONS(1),
+    [anon_sym_RBRACE] = ACTIONS(1),
+    [anon_sym_extend] = ACTIONS(1),
+    [anon_sym_scalar] = ACTIONS(1),
+    [anon_sym_type] = ACTIONS(1),
+    [anon_sym_interface] = ACTIONS(1),
+    [anon_sym_union] = ACTIONS(1),
+    [anon_sym_enum] = ACTIONS(1),
+    [anon_sym_input] = ACTIONS(1),
+    [anon_sym_AMP] = ACTIONS(1),
+    [anon_sym_implements] = ACTIONS(1),
+    [anon_sym_COLON] = ACTIONS(1),
+    [anon_sym_LPAREN] = ACTIONS(1),
+    [anon_sym_RPAREN] = ACTIONS(1),
+    [anon_sym_EQ] = ACTIONS(1),
+    [anon_sym_PIPE] = ACTIONS(1),
+    [anon_sym_query] = ACTIONS(1),
+    [anon_sym_mutation] = ACTIONS(1),
+    [anon_sym_subscription] = ACTIONS(1),
+    [anon_sym_DOLLAR] = ACTIONS(1),
+    [anon_sym_DQUOTE_DQUOTE_DQUOTE] = ACTIONS(1),
+    [anon_sym_DQUOTE] = ACTIONS(1),
+    [sym_int_value] = ACTIONS(1),
+    [sym_float_value] = ACTIONS(1),
+    [anon_sym_true] = ACTIONS(1),
+    [anon_sym_false] = ACTIONS(1),
+    [sym_null_value] = ACTIONS(1),
+    [anon_sym_LBRACK] = ACTIONS(1),
+    [anon_sym_RBRACK] = ACTIONS(1),
+    [anon_sym_DOT_DOT_DOT] = ACTIONS(1),
+    [anon_sym_fragment] = ACTIONS(1),
+    [anon_sym_on] = ACTIONS(1),
+    [anon_sym_AT] = ACTIONS(1),
+    [anon_sym_directive] = ACTIONS(1),
+    [anon_sym_repeatable] = ACTIONS(1),
+    [anon_sym_QUERY] = ACTIONS(1),
+    [anon_sym_MUTATION] = ACTIONS(1),
+    [anon_sym_SUBSCRIPTION] = ACTIONS(1),
+    [anon_sym_FIELD] = ACTIONS(1),
+    [anon_sym_FRAGMENT_DEFINITION] = ACTIONS(1),
+    [anon_sym_FRAGMENT_SPREAD] = ACTIONS(1),
+    [anon_sym_INLINE_FRAGMENT] = ACTIONS(1),
+    [anon_sym_VARIABLE_DEFINITION] = ACTIONS(1),
+    [anon_sym_SCHEMA] = ACTIONS(1),
+    [anon_sym_SCALAR] = ACTIONS(1),
+    [anon_sym_OBJECT] = ACTIONS(1),
+    [anon_sym_FIELD_DEFINITION] = ACTIONS(1),
+    [anon_sym_ARGUMENT_DEFINITION] = ACTIONS(1),
+    [anon_sym_INTERFACE] = ACTIONS(1),
+    [anon_sym_UNION] = ACTIONS(1),
+    [anon_sym_ENUM] = ACTIONS(1),
+    [anon_sym_ENUM_VALUE] = ACTIONS(1),
+    [anon_sym_INPUT_OBJECT] = ACTIONS(1),
+    [anon_sym_INPUT_FIELD_DEFINITION] = ACTIONS(1),
+    [anon_sym_BANG] = ACTIONS(1),
+    [sym_comment] = ACTIONS(3),
+    [sym_comma] = ACTIONS(3),
+    [sym_grit_metavariable] = ACTIONS(1),
+  },
+  [1] = {
+    [sym_source_file] = STATE(411),
+    [sym_document] = STATE(425),
+    [sym_definition] = STATE(158),
+    [sym_executable_definition] = STATE(159),
+    [sym_type_system_definition] = STATE(159),
+    [sym_type_system_extension] = STATE(159),
+    [sym_schema_definition] = STATE(160),
+    [sym_schema_extension] = STATE(161),
+    [sym_type_extension] = STATE(161),
+    [sym_scalar_type_extension] = STATE(165),
+    [sym_object_type_extension] = STATE(165),
+    [sym_interface_type_extension] = STATE(165),
+    [sym_union_type_extension] = STATE(165),
+    [sym_enum_type_extension] = STATE(165),
+    [sym_input_object_type_extension] = STATE(165),
+    [sym_operation_definition] = STATE(171),
+    [sym_operation_type] = STATE(188),
+    [sym_type_definition] = STATE(160),
+    [sym_scalar_type_definition] = STATE(123),
+    [sym_object_type_definition] = STATE(123),
+    [sym_interface_type_definition] = STATE(123),
+    [sym_union_type_definition] = STATE(123),
+    [sym_enum_type_definition] = STATE(123),
+    [sym_input_object_type_definition] = STATE(123),
+    [sym_selection_set] = STATE(124),
+    [sym_string_value] = STATE(206),
+    [sym_fragment_definition] = STATE(171),
+    [sym_directive_definition] = STATE(160),
+    [sym_description] = STATE(237),
+    [aux_sym_document_repeat1] = STATE(3),
+    [anon_sym_schema] = ACTIONS(5),
+    [anon_sym_LBRACE] = ACTIONS(7),
+    [anon_sym_extend] = ACTIONS(9),
+    [anon_sym_scalar] = ACTIONS(11),
+    [anon_sym_type] = ACTIONS(13),
+    [anon_sym_interface] = ACTIONS(15),
+    [anon_sym_union] = ACTIONS(17),
+    [anon_sym_enum] = ACTIONS(19),
+    [anon_sym_input] = ACTIONS(21),
+    [anon_sym_query] = ACTIONS(23),
+    [anon_sym_mutation] = ACTIONS(23),
+    [anon_sym_subscription] = ACTIONS(23),
+    [anon_sym_DQUOTE_DQUOTE_DQUOTE] = ACTIONS(25),
+    [anon_sym_DQUOTE] = ACTIONS(27),
+    [anon_sym_fragment] = ACTIONS(29),
+    [anon_sym_directive] = ACTIONS(31),
+    [sym_comment] = ACTIONS(3),
+    [sym_comma] = ACTIONS(3),
+  },
+};
+
+static const uint16_t ts_small_parse_table[] = {
+  [0] = 28,
+    ACTIONS(33), 1,
+      ts_builtin_sym_end,
+    ACTIONS(35), 1,
+      anon_sym_schema,
+    ACTIONS(38), 1,
+      anon_sym_LBRACE,
+    ACTIONS(41), 1,
+      anon_sym_extend,
+    ACTIONS(44), 1,
+      anon_sym_scalar,
+    ACTIONS(47), 1,
+      anon_sym_type,
+    ACTIONS(50), 1,
+      anon_sym_interface,
+    ACTIONS(53), 1,
+      anon_sym_union,
+    ACTIONS(56), 1,
+      anon_sym_enum,
+    ACTIONS(59), 1,
+      anon_sym_input,
+    ACTIONS(65), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(68), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_fragment,
+    ACTIONS(74), 1,
+      anon_sym_directive,
+    STATE(2), 1,
+      aux_sym_document_repeat1,
+    STATE(124), 1,
+      sym_selection_set,
+    STATE(158), 1,
+      sym_definition,
+    STATE(188), 1,
+      sym_operation_type,
+    STATE(206), 1,
+      sym_string_value,
+    STATE(237), 1,
+      sym_description,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    STATE(161), 2,
+      sym_schema_extension,
+      sym_type_extension,
+    STATE(171), 2,
+      sym_operation_definition,
+      sym_fragment_definition,
+    ACTIONS(62), 3,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+    STATE(159), 3,
+      sym_executable_definition,
+      sym_type_system_definition,
+      sym_type_system_extension,
+    STATE(160), 3,
+      sym_schema_definition,
+      sym_type_definition,
+      sym_directive_definition,
+    STATE(123), 6,
+      sym_scalar_type_definition,
+      sym_object_type_definition,
+      sym_interface_type_definition,
+      sym_union_type_definition,
+      sym_enum_type_definition,
+      sym_input_object_type_definition,
+    STATE(165), 6,
+      sym_scalar_type_extension,
+      sym_object_type_extension,
+      sym_interface_type_extension,
+      sym_union_type_extension,
+      sym_enum_type_extension,
+      sym_input_object_type_extension,
+  [104] = 28,
+    ACTIONS(5), 1,
+      anon_sym_schema,
+    ACTIONS(7), 1,
+      anon_sym_LBRACE,
+    ACTIONS(9), 1,
+      anon_sym_extend,
+    ACTIONS(11), 1,
+      anon_sym_scalar,
+    ACTIONS(13), 1,
+      anon_sym_type,
+    ACTIONS(15), 1,
+      anon_sym_interface,
+    ACTIONS(17), 1,
+      anon_sym_union,
+    ACTIONS(19), 1,
+      anon_sym_enum,
+    ACTIONS(21), 1,
+      anon_sym_input,
+    ACTIONS(25), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(27), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(29), 1,
+      anon_sym_fragment,
+    ACTIONS(31), 1,
+      anon_sym_directive,
+    ACTIONS(77), 1,
+      ts_builtin_sym_end,
+    STATE(2), 1,
+      aux_sym_document_repeat1,
+    STATE(124), 1,
+      sym_selection_set,
+    STATE(158), 1,
+      sym_definition,
+    STATE(188), 1,
+      sym_operation_type,
+    STATE(206), 1,
+      sym_string_value,
+    STATE(237), 1,
+      sym_description,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    STATE(161), 2,
+      sym_schema_extension,
+      sym_type_extension,
+    STATE(171), 2,
+      sym_operation_definition,
+      sym_fragment_definition,
+    ACTIONS(23), 3,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+    STATE(159), 3,
+      sym_executable_definition,
+      sym_type_system_definition,
+      sym_type_system_extension,
+    STATE(160), 3,
+      sym_schema_definition,
+      sym_type_definition,
+      sym_directive_definition,
+    STATE(123), 6,
+      sym_scalar_type_definition,
+      sym_object_type_definition,
+      sym_interface_type_definition,
+      sym_union_type_definition,
+      sym_enum_type_definition,
+      sym_input_object_type_definition,
+    STATE(165), 6,
+      sym_scalar_type_extension,
+      sym_object_type_extension,
+      sym_interface_type_extension,
+      sym_union_type_extension,
+      sym_enum_type_extension,
+      sym_input_object_type_extension,
+  [208] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(79), 11,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE,
+      sym_int_value,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null_value,
+      anon_sym_on,
+      anon_sym_repeatable,
+      sym__name,
+    ACTIONS(81), 15,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_EQ,
+      anon_sym_DOLLAR,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym_float_value,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_AT,
+      anon_sym_BANG,
+      sym_grit_metavariable,
+  [243] = 11,
+    ACTIONS(85), 1,
+      anon_sym_LBRACE,
+    ACTIONS(87), 1,
+      anon_sym_implements,
+    ACTIONS(89), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(91), 1,
+      anon_sym_AT,
+    STATE(28), 1,
+      sym_implements_interfaces,
+    STATE(45), 1,
+      aux_sym_directives_repeat1,
+    STATE(64), 1,
+      sym_directive,
+    STATE(77), 1,
+      sym_directives,
+    STATE(136), 1,
+      sym_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(83), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [292] = 11,
+    ACTIONS(85), 1,
+      anon_sym_LBRACE,
+    ACTIONS(87), 1,
+      anon_sym_implements,
+    ACTIONS(91), 1,
+      anon_sym_AT,
+    ACTIONS(95), 1,
+      anon_sym_DQUOTE,
+    STATE(21), 1,
+      sym_implements_interfaces,
+    STATE(45), 1,
+      aux_sym_directives_repeat1,
+    STATE(64), 1,
+      sym_directive,
+    STATE(97), 1,
+      sym_directives,
+    STATE(134), 1,
+      sym_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(93), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [341] = 11,
+    ACTIONS(85), 1,
+      anon_sym_LBRACE,
+    ACTIONS(87), 1,
+      anon_sym_implements,
+    ACTIONS(91), 1,
+      anon_sym_AT,
+    ACTIONS(99), 1,
+      anon_sym_DQUOTE,
+    STATE(20), 1,
+      sym_implements_interfaces,
+    STATE(45), 1,
+      aux_sym_directives_repeat1,
+    STATE(64), 1,
+      sym_directive,
+    STATE(76), 1,
+      sym_directives,
+    STATE(168), 1,
+      sym_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(97), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [390] = 11,
+    ACTIONS(85), 1,
+      anon_sym_LBRACE,
+    ACTIONS(87), 1,
+      anon_sym_implements,
+    ACTIONS(91), 1,
+      anon_sym_AT,
+    ACTIONS(103), 1,
+      anon_sym_DQUOTE,
+    STATE(25), 1,
+      sym_implements_interfaces,
+    STATE(45), 1,
+      aux_sym_directives_repeat1,
+    STATE(64), 1,
+      sym_directive,
+    STATE(71), 1,
+      sym_directives,
+    STATE(170), 1,
+      sym_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(101), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [439] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(107), 10,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym_float_value,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_AT,
+      sym_grit_metavariable,
+    ACTIONS(105), 14,
+      anon_sym_schema,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_DQUOTE,
+      sym_int_value,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null_value,
+      anon_sym_directive,
+      sym__name,
+  [472] = 11,
+    ACTIONS(85), 1,
+      anon_sym_LBRACE,
+    ACTIONS(87), 1,
+      anon_sym_implements,
+    ACTIONS(91), 1,
+      anon_sym_AT,
+    ACTIONS(111), 1,
+      anon_sym_DQUOTE,
+    STATE(27), 1,
+      sym_implements_interfaces,
+    STATE(45), 1,
+      aux_sym_directives_repeat1,
+    STATE(64), 1,
+      sym_directive,
+    STATE(96), 1,
+      sym_directives,
+    STATE(121), 1,
+      sym_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(109), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [521] = 11,
+    ACTIONS(85), 1,
+      anon_sym_LBRACE,
+    ACTIONS(87), 1,
+      anon_sym_implements,
+    ACTIONS(91), 1,
+      anon_sym_AT,
+    ACTIONS(115), 1,
+      anon_sym_DQUOTE,
+    STATE(24), 1,
+      sym_implements_interfaces,
+    STATE(45), 1,
+      aux_sym_directives_repeat1,
+    STATE(64), 1,
+      sym_directive,
+    STATE(98), 1,
+      sym_directives,
+    STATE(122), 1,
+      sym_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(113), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [570] = 9,
+    ACTIONS(117), 1,
+      anon_sym_PIPE,
+    ACTIONS(121), 1,
+      anon_sym_FIELD,
+    ACTIONS(125), 1,
+      anon_sym_ENUM,
+    STATE(68), 1,
+      sym_directive_locations,
+    STATE(69), 1,
+      sym_directive_location,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    STATE(70), 2,
+      sym_executable_directive_location,
+      sym_type_system_directive_location,
+    ACTIONS(119), 7,
+      anon_sym_QUERY,
+      anon_sym_MUTATION,
+      anon_sym_SUBSCRIPTION,
+      anon_sym_FRAGMENT_DEFINITION,
+      anon_sym_FRAGMENT_SPREAD,
+      anon_sym_INLINE_FRAGMENT,
+      anon_sym_VARIABLE_DEFINITION,
+    ACTIONS(123), 10,
+      anon_sym_SCHEMA,
+      anon_sym_SCALAR,
+      anon_sym_OBJECT,
+      anon_sym_FIELD_DEFINITION,
+      anon_sym_ARGUMENT_DEFINITION,
+      anon_sym_INTERFACE,
+      anon_sym_UNION,
+      anon_sym_ENUM_VALUE,
+      anon_sym_INPUT_OBJECT,
+      anon_sym_INPUT_FIELD_DEFINITION,
+  [615] = 9,
+    ACTIONS(117), 1,
+      anon_sym_PIPE,
+    ACTIONS(121), 1,
+      anon_sym_FIELD,
+    ACTIONS(125), 1,
+      anon_sym_ENUM,
+    STATE(69), 1,
+      sym_directive_location,
+    STATE(80), 1,
+      sym_directive_locations,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    STATE(70), 2,
+      sym_executable_directive_location,
+      sym_type_system_directive_location,
+    ACTIONS(119), 7,
+      anon_sym_QUERY,
+      anon_sym_MUTATION,
+      anon_sym_SUBSCRIPTION,
+      anon_sym_FRAGMENT_DEFINITION,
+      anon_sym_FRAGMENT_SPREAD,
+      anon_sym_INLINE_FRAGMENT,
+      anon_sym_VARIABLE_DEFINITION,
+    ACTIONS(123), 10,
+      anon_sym_SCHEMA,
+      anon_sym_SCALAR,
+      anon_sym_OBJECT,
+      anon_sym_FIELD_DEFINITION,
+      anon_sym_ARGUMENT_DEFINITION,
+      anon_sym_INTERFACE,
+      anon_sym_UNION,
+      anon_sym_ENUM_VALUE,
+      anon_sym_INPUT_OBJECT,
+      anon_sym_INPUT_FIELD_DEFINITION,
+  [660] = 9,
+    ACTIONS(117), 1,
+      anon_sym_PIPE,
+    ACTIONS(121), 1,
+      anon_sym_FIELD,
+    ACTIONS(125), 1,
+      anon_sym_ENUM,
+    STATE(69), 1,
+      sym_directive_location,
+    STATE(81), 1,
+      sym_directive_locations,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    STATE(70), 2,
+      sym_executable_directive_location,
+      sym_type_system_directive_location,
+    ACTIONS(119), 7,
+      anon_sym_QUERY,
+      anon_sym_MUTATION,
+      anon_sym_SUBSCRIPTION,
+      anon_sym_FRAGMENT_DEFINITION,
+      anon_sym_FRAGMENT_SPREAD,
+      anon_sym_INLINE_FRAGMENT,
+      anon_sym_VARIABLE_DEFINITION,
+    ACTIONS(123), 10,
+      anon_sym_SCHEMA,
+      anon_sym_SCALAR,
+      anon_sym_OBJECT,
+      anon_sym_FIELD_DEFINITION,
+      anon_sym_ARGUMENT_DEFINITION,
+      anon_sym_INTERFACE,
+      anon_sym_UNION,
+      anon_sym_ENUM_VALUE,
+      anon_sym_INPUT_OBJECT,
+      anon_sym_INPUT_FIELD_DEFINITION,
+  [705] = 9,
+    ACTIONS(117), 1,
+      anon_sym_PIPE,
+    ACTIONS(121), 1,
+      anon_sym_FIELD,
+    ACTIONS(125), 1,
+      anon_sym_ENUM,
+    STATE(69), 1,
+      sym_directive_location,
+    STATE(84), 1,
+      sym_directive_locations,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    STATE(70), 2,
+      sym_executable_directive_location,
+      sym_type_system_directive_location,
+    ACTIONS(119), 7,
+      anon_sym_QUERY,
+      anon_sym_MUTATION,
+      anon_sym_SUBSCRIPTION,
+      anon_sym_FRAGMENT_DEFINITION,
+      anon_sym_FRAGMENT_SPREAD,
+      anon_sym_INLINE_FRAGMENT,
+      anon_sym_VARIABLE_DEFINITION,
+    ACTIONS(123), 10,
+      anon_sym_SCHEMA,
+      anon_sym_SCALAR,
+      anon_sym_OBJECT,
+      anon_sym_FIELD_DEFINITION,
+      anon_sym_ARGUMENT_DEFINITION,
+      anon_sym_INTERFACE,
+      anon_sym_UNION,
+      anon_sym_ENUM_VALUE,
+      anon_sym_INPUT_OBJECT,
+      anon_sym_INPUT_FIELD_DEFINITION,
+  [750] = 9,
+    ACTIONS(117), 1,
+      anon_sym_PIPE,
+    ACTIONS(121), 1,
+      anon_sym_FIELD,
+    ACTIONS(125), 1,
+      anon_sym_ENUM,
+    STATE(69), 1,
+      sym_directive_location,
+    STATE(89), 1,
+      sym_directive_locations,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    STATE(70), 2,
+      sym_executable_directive_location,
+      sym_type_system_directive_location,
+    ACTIONS(119), 7,
+      anon_sym_QUERY,
+      anon_sym_MUTATION,
+      anon_sym_SUBSCRIPTION,
+      anon_sym_FRAGMENT_DEFINITION,
+      anon_sym_FRAGMENT_SPREAD,
+      anon_sym_INLINE_FRAGMENT,
+      anon_sym_VARIABLE_DEFINITION,
+    ACTIONS(123), 10,
+      anon_sym_SCHEMA,
+      anon_sym_SCALAR,
+      anon_sym_OBJECT,
+      anon_sym_FIELD_DEFINITION,
+      anon_sym_ARGUMENT_DEFINITION,
+      anon_sym_INTERFACE,
+      anon_sym_UNION,
+      anon_sym_ENUM_VALUE,
+      anon_sym_INPUT_OBJECT,
+      anon_sym_INPUT_FIELD_DEFINITION,
+  [795] = 9,
+    ACTIONS(117), 1,
+      anon_sym_PIPE,
+    ACTIONS(121), 1,
+      anon_sym_FIELD,
+    ACTIONS(125), 1,
+      anon_sym_ENUM,
+    STATE(69), 1,
+      sym_directive_location,
+    STATE(90), 1,
+      sym_directive_locations,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    STATE(70), 2,
+      sym_executable_directive_location,
+      sym_type_system_directive_location,
+    ACTIONS(119), 7,
+      anon_sym_QUERY,
+      anon_sym_MUTATION,
+      anon_sym_SUBSCRIPTION,
+      anon_sym_FRAGMENT_DEFINITION,
+      anon_sym_FRAGMENT_SPREAD,
+      anon_sym_INLINE_FRAGMENT,
+      anon_sym_VARIABLE_DEFINITION,
+    ACTIONS(123), 10,
+      anon_sym_SCHEMA,
+      anon_sym_SCALAR,
+      anon_sym_OBJECT,
+      anon_sym_FIELD_DEFINITION,
+      anon_sym_ARGUMENT_DEFINITION,
+      anon_sym_INTERFACE,
+      anon_sym_UNION,
+      anon_sym_ENUM_VALUE,
+      anon_sym_INPUT_OBJECT,
+      anon_sym_INPUT_FIELD_DEFINITION,
+  [840] = 9,
+    ACTIONS(117), 1,
+      anon_sym_PIPE,
+    ACTIONS(121), 1,
+      anon_sym_FIELD,
+    ACTIONS(125), 1,
+      anon_sym_ENUM,
+    STATE(69), 1,
+      sym_directive_location,
+    STATE(91), 1,
+      sym_directive_locations,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    STATE(70), 2,
+      sym_executable_directive_location,
+      sym_type_system_directive_location,
+    ACTIONS(119), 7,
+      anon_sym_QUERY,
+      anon_sym_MUTATION,
+      anon_sym_SUBSCRIPTION,
+      anon_sym_FRAGMENT_DEFINITION,
+      anon_sym_FRAGMENT_SPREAD,
+      anon_sym_INLINE_FRAGMENT,
+      anon_sym_VARIABLE_DEFINITION,
+    ACTIONS(123), 10,
+      anon_sym_SCHEMA,
+      anon_sym_SCALAR,
+      anon_sym_OBJECT,
+      anon_sym_FIELD_DEFINITION,
+      anon_sym_ARGUMENT_DEFINITION,
+      anon_sym_INTERFACE,
+      anon_sym_UNION,
+      anon_sym_ENUM_VALUE,
+      anon_sym_INPUT_OBJECT,
+      anon_sym_INPUT_FIELD_DEFINITION,
+  [885] = 9,
+    ACTIONS(117), 1,
+      anon_sym_PIPE,
+    ACTIONS(121), 1,
+      anon_sym_FIELD,
+    ACTIONS(125), 1,
+      anon_sym_ENUM,
+    STATE(69), 1,
+      sym_directive_location,
+    STATE(95), 1,
+      sym_directive_locations,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    STATE(70), 2,
+      sym_executable_directive_location,
+      sym_type_system_directive_location,
+    ACTIONS(119), 7,
+      anon_sym_QUERY,
+      anon_sym_MUTATION,
+      anon_sym_SUBSCRIPTION,
+      anon_sym_FRAGMENT_DEFINITION,
+      anon_sym_FRAGMENT_SPREAD,
+      anon_sym_INLINE_FRAGMENT,
+      anon_sym_VARIABLE_DEFINITION,
+    ACTIONS(123), 10,
+      anon_sym_SCHEMA,
+      anon_sym_SCALAR,
+      anon_sym_OBJECT,
+      anon_sym_FIELD_DEFINITION,
+      anon_sym_ARGUMENT_DEFINITION,
+      anon_sym_INTERFACE,
+      anon_sym_UNION,
+      anon_sym_ENUM_VALUE,
+      anon_sym_INPUT_OBJECT,
+      anon_sym_INPUT_FIELD_DEFINITION,
+  [930] = 10,
+    ACTIONS(85), 1,
+      anon_sym_LBRACE,
+    ACTIONS(91), 1,
+      anon_sym_AT,
+    ACTIONS(129), 1,
+      anon_sym_AMP,
+    ACTIONS(131), 1,
+      anon_sym_DQUOTE,
+    STATE(45), 1,
+      aux_sym_directives_repeat1,
+    STATE(64), 1,
+      sym_directive,
+    STATE(105), 1,
+      sym_directives,
+    STATE(130), 1,
+      sym_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(127), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [976] = 10,
+    ACTIONS(85), 1,
+      anon_sym_LBRACE,
+    ACTIONS(91), 1,
+      anon_sym_AT,
+    ACTIONS(129), 1,
+      anon_sym_AMP,
+    ACTIONS(135), 1,
+      anon_sym_DQUOTE,
+    STATE(45), 1,
+      aux_sym_directives_repeat1,
+    STATE(64), 1,
+      sym_directive,
+    STATE(85), 1,
+      sym_directives,
+    STATE(108), 1,
+      sym_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(133), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [1022] = 10,
+    ACTIONS(91), 1,
+      anon_sym_AT,
+    ACTIONS(139), 1,
+      anon_sym_LBRACE,
+    ACTIONS(141), 1,
+      anon_sym_DQUOTE,
+    STATE(45), 1,
+      aux_sym_directives_repeat1,
+    STATE(61), 1,
+      sym_directives,
+    STATE(62), 1,
+      aux_sym_input_object_type_extension_repeat1,
+    STATE(64), 1,
+      sym_directive,
+    STATE(144), 1,
+      sym_input_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(137), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [1068] = 3,
+    ACTIONS(145), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(143), 22,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_AMP,
+      anon_sym_PIPE,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_RBRACK,
+      anon_sym_fragment,
+      anon_sym_AT,
+      anon_sym_directive,
+      anon_sym_BANG,
+  [1100] = 10,
+    ACTIONS(85), 1,
+      anon_sym_LBRACE,
+    ACTIONS(91), 1,
+      anon_sym_AT,
+    ACTIONS(129), 1,
+      anon_sym_AMP,
+    ACTIONS(149), 1,
+      anon_sym_DQUOTE,
+    STATE(45), 1,
+      aux_sym_directives_repeat1,
+    STATE(64), 1,
+      sym_directive,
+    STATE(74), 1,
+      sym_directives,
+    STATE(148), 1,
+      sym_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(147), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [1146] = 10,
+    ACTIONS(85), 1,
+      anon_sym_LBRACE,
+    ACTIONS(91), 1,
+      anon_sym_AT,
+    ACTIONS(129), 1,
+      anon_sym_AMP,
+    ACTIONS(153), 1,
+      anon_sym_DQUOTE,
+    STATE(45), 1,
+      aux_sym_directives_repeat1,
+    STATE(64), 1,
+      sym_directive,
+    STATE(101), 1,
+      sym_directives,
+    STATE(132), 1,
+      sym_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(151), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [1192] = 9,
+    ACTIONS(91), 1,
+      anon_sym_AT,
+    ACTIONS(157), 1,
+      anon_sym_EQ,
+    ACTIONS(159), 1,
+      anon_sym_DQUOTE,
+    STATE(45), 1,
+      aux_sym_directives_repeat1,
+    STATE(60), 1,
+      sym_directives,
+    STATE(64), 1,
+      sym_directive,
+    STATE(72), 1,
+      sym_union_member_types,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(155), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [1236] = 10,
+    ACTIONS(85), 1,
+      anon_sym_LBRACE,
+    ACTIONS(91), 1,
+      anon_sym_AT,
+    ACTIONS(129), 1,
+      anon_sym_AMP,
+    ACTIONS(163), 1,
+      anon_sym_DQUOTE,
+    STATE(45), 1,
+      aux_sym_directives_repeat1,
+    STATE(64), 1,
+      sym_directive,
+    STATE(73), 1,
+      sym_directives,
+    STATE(146), 1,
+      sym_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(161), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [1282] = 10,
+    ACTIONS(85), 1,
+      anon_sym_LBRACE,
+    ACTIONS(91), 1,
+      anon_sym_AT,
+    ACTIONS(129), 1,
+      anon_sym_AMP,
+    ACTIONS(167), 1,
+      anon_sym_DQUOTE,
+    STATE(45), 1,
+      aux_sym_directives_repeat1,
+    STATE(64), 1,
+      sym_directive,
+    STATE(86), 1,
+      sym_directives,
+    STATE(110), 1,
+      sym_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(165), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [1328] = 9,
+    ACTIONS(91), 1,
+      anon_sym_AT,
+    ACTIONS(157), 1,
+      anon_sym_EQ,
+    ACTIONS(171), 1,
+      anon_sym_DQUOTE,
+    STATE(45), 1,
+      aux_sym_directives_repeat1,
+    STATE(59), 1,
+      sym_directives,
+    STATE(64), 1,
+      sym_directive,
+    STATE(82), 1,
+      sym_union_member_types,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(169), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [1372] = 9,
+    ACTIONS(91), 1,
+      anon_sym_AT,
+    ACTIONS(157), 1,
+      anon_sym_EQ,
+    ACTIONS(175), 1,
+      anon_sym_DQUOTE,
+    STATE(45), 1,
+      aux_sym_directives_repeat1,
+    STATE(64), 1,
+      sym_directive,
+    STATE(65), 1,
+      sym_directives,
+    STATE(100), 1,
+      sym_union_member_types,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(173), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [1416] = 3,
+    ACTIONS(79), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(81), 22,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_AMP,
+      anon_sym_implements,
+      anon_sym_LPAREN,
+      anon_sym_EQ,
+      anon_sym_PIPE,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_AT,
+      anon_sym_directive,
+  [1448] = 9,
+    ACTIONS(91), 1,
+      anon_sym_AT,
+    ACTIONS(179), 1,
+      anon_sym_LBRACE,
+    ACTIONS(181), 1,
+      anon_sym_DQUOTE,
+    STATE(45), 1,
+      aux_sym_directives_repeat1,
+    STATE(64), 1,
+      sym_directive,
+    STATE(93), 1,
+      sym_directives,
+    STATE(142), 1,
+      sym_enum_values_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(177), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [1491] = 9,
+    ACTIONS(91), 1,
+      anon_sym_AT,
+    ACTIONS(179), 1,
+      anon_sym_LBRACE,
+    ACTIONS(185), 1,
+      anon_sym_DQUOTE,
+    STATE(45), 1,
+      aux_sym_directives_repeat1,
+    STATE(64), 1,
+      sym_directive,
+    STATE(66), 1,
+      sym_directives,
+    STATE(107), 1,
+      sym_enum_values_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(183), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [1534] = 9,
+    ACTIONS(91), 1,
+      anon_sym_AT,
+    ACTIONS(139), 1,
+      anon_sym_LBRACE,
+    ACTIONS(189), 1,
+      anon_sym_DQUOTE,
+    STATE(45), 1,
+      aux_sym_directives_repeat1,
+    STATE(64), 1,
+      sym_directive,
+    STATE(104), 1,
+      sym_directives,
+    STATE(126), 1,
+      sym_input_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(187), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [1577] = 9,
+    ACTIONS(91), 1,
+      anon_sym_AT,
+    ACTIONS(179), 1,
+      anon_sym_LBRACE,
+    ACTIONS(193), 1,
+      anon_sym_DQUOTE,
+    STATE(45), 1,
+      aux_sym_directives_repeat1,
+    STATE(64), 1,
+      sym_directive,
+    STATE(102), 1,
+      sym_directives,
+    STATE(125), 1,
+      sym_enum_values_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(191), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [1620] = 7,
+    ACTIONS(121), 1,
+      anon_sym_FIELD,
+    ACTIONS(125), 1,
+      anon_sym_ENUM,
+    STATE(78), 1,
+      sym_directive_location,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    STATE(70), 2,
+      sym_executable_directive_location,
+      sym_type_system_directive_location,
+    ACTIONS(119), 7,
+      anon_sym_QUERY,
+      anon_sym_MUTATION,
+      anon_sym_SUBSCRIPTION,
+      anon_sym_FRAGMENT_DEFINITION,
+      anon_sym_FRAGMENT_SPREAD,
+      anon_sym_INLINE_FRAGMENT,
+      anon_sym_VARIABLE_DEFINITION,
+    ACTIONS(123), 10,
+      anon_sym_SCHEMA,
+      anon_sym_SCALAR,
+      anon_sym_OBJECT,
+      anon_sym_FIELD_DEFINITION,
+      anon_sym_ARGUMENT_DEFINITION,
+      anon_sym_INTERFACE,
+      anon_sym_UNION,
+      anon_sym_ENUM_VALUE,
+      anon_sym_INPUT_OBJECT,
+      anon_sym_INPUT_FIELD_DEFINITION,
+  [1659] = 9,
+    ACTIONS(91), 1,
+      anon_sym_AT,
+    ACTIONS(139), 1,
+      anon_sym_LBRACE,
+    ACTIONS(197), 1,
+      anon_sym_DQUOTE,
+    STATE(45), 1,
+      aux_sym_directives_repeat1,
+    STATE(64), 1,
+      sym_directive,
+    STATE(94), 1,
+      sym_directives,
+    STATE(143), 1,
+      sym_input_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(195), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [1702] = 16,
+    ACTIONS(25), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(27), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(199), 1,
+      sym__name,
+    ACTIONS(201), 1,
+      anon_sym_LBRACE,
+    ACTIONS(203), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(207), 1,
+      sym_float_value,
+    ACTIONS(211), 1,
+      anon_sym_LBRACK,
+    ACTIONS(213), 1,
+      anon_sym_RBRACK,
+    ACTIONS(215), 1,
+      sym_grit_metavariable,
+    STATE(41), 1,
+      aux_sym_list_value_repeat1,
+    STATE(179), 1,
+      sym_name,
+    STATE(181), 1,
+      sym_value,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(205), 2,
+      sym_int_value,
+      sym_null_value,
+    ACTIONS(209), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(173), 6,
+      sym_variable,
+      sym_string_value,
+      sym_boolean_value,
+      sym_enum_value,
+      sym_list_value,
+      sym_object_value,
+  [1759] = 16,
+    ACTIONS(25), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(27), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(199), 1,
+      sym__name,
+    ACTIONS(201), 1,
+      anon_sym_LBRACE,
+    ACTIONS(203), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(207), 1,
+      sym_float_value,
+    ACTIONS(211), 1,
+      anon_sym_LBRACK,
+    ACTIONS(215), 1,
+      sym_grit_metavariable,
+    ACTIONS(217), 1,
+      anon_sym_RBRACK,
+    STATE(42), 1,
+      aux_sym_list_value_repeat1,
+    STATE(179), 1,
+      sym_name,
+    STATE(181), 1,
+      sym_value,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(205), 2,
+      sym_int_value,
+      sym_null_value,
+    ACTIONS(209), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(173), 6,
+      sym_variable,
+      sym_string_value,
+      sym_boolean_value,
+      sym_enum_value,
+      sym_list_value,
+      sym_object_value,
+  [1816] = 7,
+    ACTIONS(121), 1,
+      anon_sym_FIELD,
+    ACTIONS(125), 1,
+      anon_sym_ENUM,
+    STATE(88), 1,
+      sym_directive_location,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    STATE(70), 2,
+      sym_executable_directive_location,
+      sym_type_system_directive_location,
+    ACTIONS(119), 7,
+      anon_sym_QUERY,
+      anon_sym_MUTATION,
+      anon_sym_SUBSCRIPTION,
+      anon_sym_FRAGMENT_DEFINITION,
+      anon_sym_FRAGMENT_SPREAD,
+      anon_sym_INLINE_FRAGMENT,
+      anon_sym_VARIABLE_DEFINITION,
+    ACTIONS(123), 10,
+      anon_sym_SCHEMA,
+      anon_sym_SCALAR,
+      anon_sym_OBJECT,
+      anon_sym_FIELD_DEFINITION,
+      anon_sym_ARGUMENT_DEFINITION,
+      anon_sym_INTERFACE,
+      anon_sym_UNION,
+      anon_sym_ENUM_VALUE,
+      anon_sym_INPUT_OBJECT,
+      anon_sym_INPUT_FIELD_DEFINITION,
+  [1855] = 16,
+    ACTIONS(25), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(27), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(199), 1,
+      sym__name,
+    ACTIONS(201), 1,
+      anon_sym_LBRACE,
+    ACTIONS(203), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(207), 1,
+      sym_float_value,
+    ACTIONS(211), 1,
+      anon_sym_LBRACK,
+    ACTIONS(215), 1,
+      sym_grit_metavariable,
+    ACTIONS(219), 1,
+      anon_sym_RBRACK,
+    STATE(42), 1,
+      aux_sym_list_value_repeat1,
+    STATE(179), 1,
+      sym_name,
+    STATE(181), 1,
+      sym_value,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(205), 2,
+      sym_int_value,
+      sym_null_value,
+    ACTIONS(209), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(173), 6,
+      sym_variable,
+      sym_string_value,
+      sym_boolean_value,
+      sym_enum_value,
+      sym_list_value,
+      sym_object_value,
+  [1912] = 16,
+    ACTIONS(221), 1,
+      sym__name,
+    ACTIONS(224), 1,
+      anon_sym_LBRACE,
+    ACTIONS(227), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(230), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(233), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(239), 1,
+      sym_float_value,
+    ACTIONS(245), 1,
+      anon_sym_LBRACK,
+    ACTIONS(248), 1,
+      anon_sym_RBRACK,
+    ACTIONS(250), 1,
+      sym_grit_metavariable,
+    STATE(42), 1,
+      aux_sym_list_value_repeat1,
+    STATE(179), 1,
+      sym_name,
+    STATE(181), 1,
+      sym_value,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(236), 2,
+      sym_int_value,
+      sym_null_value,
+    ACTIONS(242), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(173), 6,
+      sym_variable,
+      sym_string_value,
+      sym_boolean_value,
+      sym_enum_value,
+      sym_list_value,
+      sym_object_value,
+  [1969] = 16,
+    ACTIONS(25), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(27), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(199), 1,
+      sym__name,
+    ACTIONS(201), 1,
+      anon_sym_LBRACE,
+    ACTIONS(203), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(207), 1,
+      sym_float_value,
+    ACTIONS(211), 1,
+      anon_sym_LBRACK,
+    ACTIONS(215), 1,
+      sym_grit_metavariable,
+    ACTIONS(253), 1,
+      anon_sym_RBRACK,
+    STATE(39), 1,
+      aux_sym_list_value_repeat1,
+    STATE(179), 1,
+      sym_name,
+    STATE(181), 1,
+      sym_value,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(205), 2,
+      sym_int_value,
+      sym_null_value,
+    ACTIONS(209), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(173), 6,
+      sym_variable,
+      sym_string_value,
+      sym_boolean_value,
+      sym_enum_value,
+      sym_list_value,
+      sym_object_value,
+  [2026] = 7,
+    ACTIONS(91), 1,
+      anon_sym_AT,
+    ACTIONS(257), 1,
+      anon_sym_DQUOTE,
+    STATE(45), 1,
+      aux_sym_directives_repeat1,
+    STATE(64), 1,
+      sym_directive,
+    STATE(128), 1,
+      sym_directives,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(255), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [2064] = 6,
+    ACTIONS(91), 1,
+      anon_sym_AT,
+    ACTIONS(261), 1,
+      anon_sym_DQUOTE,
+    STATE(48), 1,
+      aux_sym_directives_repeat1,
+    STATE(64), 1,
+      sym_directive,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(259), 17,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_EQ,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [2100] = 7,
+    ACTIONS(91), 1,
+      anon_sym_AT,
+    ACTIONS(265), 1,
+      anon_sym_DQUOTE,
+    STATE(45), 1,
+      aux_sym_directives_repeat1,
+    STATE(64), 1,
+      sym_directive,
+    STATE(119), 1,
+      sym_directives,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(263), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [2138] = 5,
+    ACTIONS(269), 1,
+      anon_sym_LPAREN,
+    ACTIONS(271), 1,
+      anon_sym_DQUOTE,
+    STATE(54), 1,
+      sym_arguments,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(267), 18,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_EQ,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_AT,
+      anon_sym_directive,
+  [2172] = 6,
+    ACTIONS(275), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(277), 1,
+      anon_sym_AT,
+    STATE(48), 1,
+      aux_sym_directives_repeat1,
+    STATE(64), 1,
+      sym_directive,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(273), 17,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_EQ,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [2208] = 14,
+    ACTIONS(25), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(27), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(199), 1,
+      sym__name,
+    ACTIONS(201), 1,
+      anon_sym_LBRACE,
+    ACTIONS(203), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(207), 1,
+      sym_float_value,
+    ACTIONS(211), 1,
+      anon_sym_LBRACK,
+    ACTIONS(215), 1,
+      sym_grit_metavariable,
+    STATE(179), 1,
+      sym_name,
+    STATE(360), 1,
+      sym_value,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(205), 2,
+      sym_int_value,
+      sym_null_value,
+    ACTIONS(209), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(173), 6,
+      sym_variable,
+      sym_string_value,
+      sym_boolean_value,
+      sym_enum_value,
+      sym_list_value,
+      sym_object_value,
+  [2259] = 14,
+    ACTIONS(280), 1,
+      sym__name,
+    ACTIONS(282), 1,
+      anon_sym_LBRACE,
+    ACTIONS(284), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(286), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(288), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(292), 1,
+      sym_float_value,
+    ACTIONS(296), 1,
+      anon_sym_LBRACK,
+    ACTIONS(298), 1,
+      sym_grit_metavariable,
+    STATE(265), 1,
+      sym_name,
+    STATE(349), 1,
+      sym_value,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(290), 2,
+      sym_int_value,
+      sym_null_value,
+    ACTIONS(294), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(268), 6,
+      sym_variable,
+      sym_string_value,
+      sym_boolean_value,
+      sym_enum_value,
+      sym_list_value,
+      sym_object_value,
+  [2310] = 14,
+    ACTIONS(280), 1,
+      sym__name,
+    ACTIONS(282), 1,
+      anon_sym_LBRACE,
+    ACTIONS(284), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(286), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(288), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(292), 1,
+      sym_float_value,
+    ACTIONS(296), 1,
+      anon_sym_LBRACK,
+    ACTIONS(298), 1,
+      sym_grit_metavariable,
+    STATE(265), 1,
+      sym_name,
+    STATE(362), 1,
+      sym_value,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(290), 2,
+      sym_int_value,
+      sym_null_value,
+    ACTIONS(294), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(268), 6,
+      sym_variable,
+      sym_string_value,
+      sym_boolean_value,
+      sym_enum_value,
+      sym_list_value,
+      sym_object_value,
+  [2361] = 14,
+    ACTIONS(25), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(27), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(199), 1,
+      sym__name,
+    ACTIONS(201), 1,
+      anon_sym_LBRACE,
+    ACTIONS(203), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(207), 1,
+      sym_float_value,
+    ACTIONS(211), 1,
+      anon_sym_LBRACK,
+    ACTIONS(215), 1,
+      sym_grit_metavariable,
+    STATE(179), 1,
+      sym_name,
+    STATE(246), 1,
+      sym_value,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(205), 2,
+      sym_int_value,
+      sym_null_value,
+    ACTIONS(209), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(173), 6,
+      sym_variable,
+      sym_string_value,
+      sym_boolean_value,
+      sym_enum_value,
+      sym_list_value,
+      sym_object_value,
+  [2412] = 3,
+    ACTIONS(302), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(300), 18,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_EQ,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_AT,
+      anon_sym_directive,
+  [2440] = 3,
+    ACTIONS(306), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(304), 18,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_EQ,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_AT,
+      anon_sym_directive,
+  [2468] = 6,
+    ACTIONS(139), 1,
+      anon_sym_LBRACE,
+    ACTIONS(310), 1,
+      anon_sym_DQUOTE,
+    STATE(56), 1,
+      aux_sym_input_object_type_extension_repeat1,
+    STATE(144), 1,
+      sym_input_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(308), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [2502] = 6,
+    ACTIONS(314), 1,
+      anon_sym_LBRACE,
+    ACTIONS(317), 1,
+      anon_sym_DQUOTE,
+    STATE(56), 1,
+      aux_sym_input_object_type_extension_repeat1,
+    STATE(144), 1,
+      sym_input_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(312), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [2536] = 3,
+    ACTIONS(321), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(319), 18,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_AMP,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_AT,
+      anon_sym_directive,
+  [2564] = 3,
+    ACTIONS(325), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(323), 18,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_AMP,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_AT,
+      anon_sym_directive,
+  [2592] = 5,
+    ACTIONS(157), 1,
+      anon_sym_EQ,
+    ACTIONS(329), 1,
+      anon_sym_DQUOTE,
+    STATE(92), 1,
+      sym_union_member_types,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(327), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [2624] = 5,
+    ACTIONS(157), 1,
+      anon_sym_EQ,
+    ACTIONS(333), 1,
+      anon_sym_DQUOTE,
+    STATE(79), 1,
+      sym_union_member_types,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(331), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [2656] = 6,
+    ACTIONS(139), 1,
+      anon_sym_LBRACE,
+    ACTIONS(337), 1,
+      anon_sym_DQUOTE,
+    STATE(55), 1,
+      aux_sym_input_object_type_extension_repeat1,
+    STATE(144), 1,
+      sym_input_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(335), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [2690] = 6,
+    ACTIONS(139), 1,
+      anon_sym_LBRACE,
+    ACTIONS(341), 1,
+      anon_sym_DQUOTE,
+    STATE(56), 1,
+      aux_sym_input_object_type_extension_repeat1,
+    STATE(144), 1,
+      sym_input_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(339), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [2724] = 3,
+    ACTIONS(345), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(343), 18,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_AMP,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_AT,
+      anon_sym_directive,
+  [2752] = 3,
+    ACTIONS(349), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(347), 18,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_EQ,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_AT,
+      anon_sym_directive,
+  [2780] = 5,
+    ACTIONS(157), 1,
+      anon_sym_EQ,
+    ACTIONS(353), 1,
+      anon_sym_DQUOTE,
+    STATE(75), 1,
+      sym_union_member_types,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(351), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [2812] = 5,
+    ACTIONS(179), 1,
+      anon_sym_LBRACE,
+    ACTIONS(357), 1,
+      anon_sym_DQUOTE,
+    STATE(135), 1,
+      sym_enum_values_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(355), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [2843] = 3,
+    ACTIONS(361), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(359), 17,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_PIPE,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [2870] = 4,
+    ACTIONS(365), 1,
+      anon_sym_PIPE,
+    ACTIONS(367), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(363), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [2899] = 3,
+    ACTIONS(371), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(369), 17,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_PIPE,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [2926] = 3,
+    ACTIONS(375), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(373), 17,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_PIPE,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [2953] = 5,
+    ACTIONS(85), 1,
+      anon_sym_LBRACE,
+    ACTIONS(379), 1,
+      anon_sym_DQUOTE,
+    STATE(133), 1,
+      sym_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(377), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [2984] = 4,
+    ACTIONS(383), 1,
+      anon_sym_PIPE,
+    ACTIONS(385), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(381), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3013] = 5,
+    ACTIONS(85), 1,
+      anon_sym_LBRACE,
+    ACTIONS(389), 1,
+      anon_sym_DQUOTE,
+    STATE(163), 1,
+      sym_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(387), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3044] = 5,
+    ACTIONS(85), 1,
+      anon_sym_LBRACE,
+    ACTIONS(393), 1,
+      anon_sym_DQUOTE,
+    STATE(164), 1,
+      sym_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(391), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3075] = 4,
+    ACTIONS(383), 1,
+      anon_sym_PIPE,
+    ACTIONS(397), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(395), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3104] = 5,
+    ACTIONS(85), 1,
+      anon_sym_LBRACE,
+    ACTIONS(401), 1,
+      anon_sym_DQUOTE,
+    STATE(131), 1,
+      sym_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(399), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3135] = 5,
+    ACTIONS(85), 1,
+      anon_sym_LBRACE,
+    ACTIONS(405), 1,
+      anon_sym_DQUOTE,
+    STATE(111), 1,
+      sym_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(403), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3166] = 3,
+    ACTIONS(409), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(407), 17,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_PIPE,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3193] = 4,
+    ACTIONS(383), 1,
+      anon_sym_PIPE,
+    ACTIONS(413), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(411), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3222] = 4,
+    ACTIONS(365), 1,
+      anon_sym_PIPE,
+    ACTIONS(417), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(415), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3251] = 4,
+    ACTIONS(365), 1,
+      anon_sym_PIPE,
+    ACTIONS(421), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(419), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3280] = 4,
+    ACTIONS(383), 1,
+      anon_sym_PIPE,
+    ACTIONS(425), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(423), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3309] = 3,
+    ACTIONS(429), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(427), 17,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_PIPE,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3336] = 4,
+    ACTIONS(365), 1,
+      anon_sym_PIPE,
+    ACTIONS(433), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(431), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3365] = 5,
+    ACTIONS(85), 1,
+      anon_sym_LBRACE,
+    ACTIONS(437), 1,
+      anon_sym_DQUOTE,
+    STATE(137), 1,
+      sym_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(435), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3396] = 5,
+    ACTIONS(85), 1,
+      anon_sym_LBRACE,
+    ACTIONS(441), 1,
+      anon_sym_DQUOTE,
+    STATE(138), 1,
+      sym_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(439), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3427] = 3,
+    ACTIONS(445), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(443), 17,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_PIPE,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3454] = 3,
+    ACTIONS(449), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(447), 17,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_PIPE,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3481] = 4,
+    ACTIONS(365), 1,
+      anon_sym_PIPE,
+    ACTIONS(453), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(451), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3510] = 4,
+    ACTIONS(365), 1,
+      anon_sym_PIPE,
+    ACTIONS(457), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(455), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3539] = 4,
+    ACTIONS(365), 1,
+      anon_sym_PIPE,
+    ACTIONS(461), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(459), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3568] = 4,
+    ACTIONS(383), 1,
+      anon_sym_PIPE,
+    ACTIONS(465), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(463), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3597] = 5,
+    ACTIONS(179), 1,
+      anon_sym_LBRACE,
+    ACTIONS(469), 1,
+      anon_sym_DQUOTE,
+    STATE(112), 1,
+      sym_enum_values_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(467), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3628] = 5,
+    ACTIONS(139), 1,
+      anon_sym_LBRACE,
+    ACTIONS(473), 1,
+      anon_sym_DQUOTE,
+    STATE(113), 1,
+      sym_input_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(471), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3659] = 4,
+    ACTIONS(365), 1,
+      anon_sym_PIPE,
+    ACTIONS(477), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(475), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3688] = 5,
+    ACTIONS(85), 1,
+      anon_sym_LBRACE,
+    ACTIONS(481), 1,
+      anon_sym_DQUOTE,
+    STATE(147), 1,
+      sym_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(479), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3719] = 5,
+    ACTIONS(85), 1,
+      anon_sym_LBRACE,
+    ACTIONS(485), 1,
+      anon_sym_DQUOTE,
+    STATE(109), 1,
+      sym_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(483), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3750] = 5,
+    ACTIONS(85), 1,
+      anon_sym_LBRACE,
+    ACTIONS(489), 1,
+      anon_sym_DQUOTE,
+    STATE(150), 1,
+      sym_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(487), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3781] = 3,
+    ACTIONS(493), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(491), 17,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_PIPE,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3808] = 4,
+    ACTIONS(383), 1,
+      anon_sym_PIPE,
+    ACTIONS(497), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(495), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3837] = 5,
+    ACTIONS(85), 1,
+      anon_sym_LBRACE,
+    ACTIONS(501), 1,
+      anon_sym_DQUOTE,
+    STATE(157), 1,
+      sym_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(499), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3868] = 5,
+    ACTIONS(179), 1,
+      anon_sym_LBRACE,
+    ACTIONS(505), 1,
+      anon_sym_DQUOTE,
+    STATE(152), 1,
+      sym_enum_values_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(503), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3899] = 3,
+    ACTIONS(509), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(507), 17,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_PIPE,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3926] = 5,
+    ACTIONS(139), 1,
+      anon_sym_LBRACE,
+    ACTIONS(513), 1,
+      anon_sym_DQUOTE,
+    STATE(153), 1,
+      sym_input_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(511), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3957] = 5,
+    ACTIONS(85), 1,
+      anon_sym_LBRACE,
+    ACTIONS(517), 1,
+      anon_sym_DQUOTE,
+    STATE(156), 1,
+      sym_fields_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(515), 15,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [3988] = 3,
+    ACTIONS(521), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(519), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4014] = 3,
+    ACTIONS(525), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(523), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4040] = 3,
+    ACTIONS(529), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(527), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4066] = 3,
+    ACTIONS(533), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(531), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4092] = 3,
+    ACTIONS(537), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(535), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4118] = 3,
+    ACTIONS(541), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(539), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4144] = 3,
+    ACTIONS(545), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(543), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4170] = 3,
+    ACTIONS(549), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(547), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4196] = 3,
+    ACTIONS(553), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(551), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4222] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(555), 6,
+      anon_sym_DQUOTE,
+      sym_int_value,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null_value,
+      sym__name,
+    ACTIONS(557), 11,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym_float_value,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_AT,
+      sym_grit_metavariable,
+  [4248] = 3,
+    ACTIONS(561), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(559), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4274] = 3,
+    ACTIONS(565), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(563), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4300] = 3,
+    ACTIONS(569), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(567), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4326] = 3,
+    ACTIONS(573), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(571), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4352] = 3,
+    ACTIONS(577), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(575), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4378] = 3,
+    ACTIONS(581), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(579), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4404] = 3,
+    ACTIONS(585), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(583), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4430] = 3,
+    ACTIONS(589), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(587), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4456] = 3,
+    ACTIONS(593), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(591), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4482] = 3,
+    ACTIONS(597), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(595), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4508] = 3,
+    ACTIONS(601), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(599), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4534] = 3,
+    ACTIONS(605), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(603), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4560] = 3,
+    ACTIONS(609), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(607), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4586] = 3,
+    ACTIONS(613), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(611), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4612] = 3,
+    ACTIONS(617), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(615), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4638] = 3,
+    ACTIONS(621), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(619), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4664] = 3,
+    ACTIONS(625), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(623), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4690] = 3,
+    ACTIONS(629), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(627), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4716] = 3,
+    ACTIONS(633), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(631), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4742] = 3,
+    ACTIONS(637), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(635), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4768] = 3,
+    ACTIONS(641), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(639), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4794] = 3,
+    ACTIONS(645), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(643), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4820] = 3,
+    ACTIONS(649), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(647), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4846] = 3,
+    ACTIONS(653), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(651), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4872] = 3,
+    ACTIONS(657), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(655), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4898] = 3,
+    ACTIONS(661), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(659), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4924] = 3,
+    ACTIONS(665), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(663), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4950] = 3,
+    ACTIONS(669), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(667), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [4976] = 3,
+    ACTIONS(673), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(671), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5002] = 3,
+    ACTIONS(677), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(675), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5028] = 3,
+    ACTIONS(681), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(679), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5054] = 3,
+    ACTIONS(685), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(683), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5080] = 3,
+    ACTIONS(689), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(687), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5106] = 3,
+    ACTIONS(693), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(691), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5132] = 3,
+    ACTIONS(697), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(695), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5158] = 3,
+    ACTIONS(701), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(699), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5184] = 3,
+    ACTIONS(705), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(703), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5210] = 3,
+    ACTIONS(709), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(707), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5236] = 3,
+    ACTIONS(713), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(711), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5262] = 3,
+    ACTIONS(717), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(715), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5288] = 3,
+    ACTIONS(721), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(719), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5314] = 3,
+    ACTIONS(725), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(723), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5340] = 3,
+    ACTIONS(729), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(727), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5366] = 3,
+    ACTIONS(733), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(731), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5392] = 3,
+    ACTIONS(737), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(735), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5418] = 3,
+    ACTIONS(741), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(739), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5444] = 3,
+    ACTIONS(745), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(743), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5470] = 3,
+    ACTIONS(749), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(747), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5496] = 3,
+    ACTIONS(753), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(751), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5522] = 3,
+    ACTIONS(757), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(755), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5548] = 3,
+    ACTIONS(761), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(759), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5574] = 3,
+    ACTIONS(765), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(763), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5600] = 3,
+    ACTIONS(769), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(767), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5626] = 3,
+    ACTIONS(773), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(771), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5652] = 3,
+    ACTIONS(777), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(775), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5678] = 3,
+    ACTIONS(781), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(779), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5704] = 3,
+    ACTIONS(785), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(783), 16,
+      ts_builtin_sym_end,
+      anon_sym_schema,
+      anon_sym_LBRACE,
+      anon_sym_extend,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_fragment,
+      anon_sym_directive,
+  [5730] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(787), 6,
+      anon_sym_DQUOTE,
+      sym_int_value,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null_value,
+      sym__name,
+    ACTIONS(789), 10,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym_float_value,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_AT,
+      sym_grit_metavariable,
+  [5755] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(791), 6,
+      anon_sym_DQUOTE,
+      sym_int_value,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null_value,
+      sym__name,
+    ACTIONS(793), 10,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym_float_value,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_AT,
+      sym_grit_metavariable,
+  [5780] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(795), 6,
+      anon_sym_DQUOTE,
+      sym_int_value,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null_value,
+      sym__name,
+    ACTIONS(797), 10,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym_float_value,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_AT,
+      sym_grit_metavariable,
+  [5805] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(799), 6,
+      anon_sym_DQUOTE,
+      sym_int_value,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null_value,
+      sym__name,
+    ACTIONS(801), 10,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym_float_value,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_AT,
+      sym_grit_metavariable,
+  [5830] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(803), 6,
+      anon_sym_DQUOTE,
+      sym_int_value,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null_value,
+      sym__name,
+    ACTIONS(805), 10,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym_float_value,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_AT,
+      sym_grit_metavariable,
+  [5855] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(807), 6,
+      anon_sym_DQUOTE,
+      sym_int_value,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null_value,
+      sym__name,
+    ACTIONS(809), 10,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym_float_value,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_AT,
+      sym_grit_metavariable,
+  [5880] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(811), 6,
+      anon_sym_DQUOTE,
+      sym_int_value,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null_value,
+      sym__name,
+    ACTIONS(813), 10,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym_float_value,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_AT,
+      sym_grit_metavariable,
+  [5905] = 11,
+    ACTIONS(817), 1,
+      anon_sym_LBRACE,
+    ACTIONS(819), 1,
+      anon_sym_COLON,
+    ACTIONS(821), 1,
+      anon_sym_LPAREN,
+    ACTIONS(823), 1,
+      anon_sym_AT,
+    STATE(187), 1,
+      aux_sym_directives_repeat1,
+    STATE(199), 1,
+      sym_arguments,
+    STATE(213), 1,
+      sym_directive,
+    STATE(251), 1,
+      sym_directives,
+    STATE(314), 1,
+      sym_selection_set,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(815), 4,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT_DOT,
+      sym__name,
+      sym_grit_metavariable,
+  [5943] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(825), 6,
+      anon_sym_DQUOTE,
+      sym_int_value,
+      anon_sym_true,
+      anon_sym_false,
+      sym_null_value,
+      sym__name,
+    ACTIONS(827), 7,
+      anon_sym_LBRACE,
+      anon_sym_DOLLAR,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym_float_value,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [5965] = 9,
+    ACTIONS(823), 1,
+      anon_sym_AT,
+    ACTIONS(831), 1,
+      anon_sym_EQ,
+    ACTIONS(833), 1,
+      anon_sym_DQUOTE,
+    STATE(187), 1,
+      aux_sym_directives_repeat1,
+    STATE(208), 1,
+      sym_default_value,
+    STATE(213), 1,
+      sym_directive,
+    STATE(261), 1,
+      sym_directives,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(829), 5,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym__name,
+      sym_grit_metavariable,
+  [5998] = 13,
+    ACTIONS(199), 1,
+      sym__name,
+    ACTIONS(215), 1,
+      sym_grit_metavariable,
+    ACTIONS(817), 1,
+      anon_sym_LBRACE,
+    ACTIONS(823), 1,
+      anon_sym_AT,
+    ACTIONS(835), 1,
+      anon_sym_on,
+    STATE(187), 1,
+      aux_sym_directives_repeat1,
+    STATE(213), 1,
+      sym_directive,
+    STATE(239), 1,
+      sym_fragment_name,
+    STATE(253), 1,
+      sym_name,
+    STATE(270), 1,
+      sym_type_condition,
+    STATE(342), 1,
+      sym_selection_set,
+    STATE(401), 1,
+      sym_directives,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [6039] = 10,
+    ACTIONS(817), 1,
+      anon_sym_LBRACE,
+    ACTIONS(821), 1,
+      anon_sym_LPAREN,
+    ACTIONS(823), 1,
+      anon_sym_AT,
+    STATE(187), 1,
+      aux_sym_directives_repeat1,
+    STATE(204), 1,
+      sym_arguments,
+    STATE(213), 1,
+      sym_directive,
+    STATE(255), 1,
+      sym_directives,
+    STATE(319), 1,
+      sym_selection_set,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(837), 4,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT_DOT,
+      sym__name,
+      sym_grit_metavariable,
+  [6074] = 9,
+    ACTIONS(823), 1,
+      anon_sym_AT,
+    ACTIONS(831), 1,
+      anon_sym_EQ,
+    ACTIONS(841), 1,
+      anon_sym_DQUOTE,
+    STATE(187), 1,
+      aux_sym_directives_repeat1,
+    STATE(203), 1,
+      sym_default_value,
+    STATE(213), 1,
+      sym_directive,
+    STATE(250), 1,
+      sym_directives,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(839), 5,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym__name,
+      sym_grit_metavariable,
+  [6107] = 6,
+    ACTIONS(275), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(843), 1,
+      anon_sym_AT,
+    STATE(186), 1,
+      aux_sym_directives_repeat1,
+    STATE(213), 1,
+      sym_directive,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(273), 7,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_DOT_DOT_DOT,
+      sym__name,
+      sym_grit_metavariable,
+  [6133] = 6,
+    ACTIONS(261), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(823), 1,
+      anon_sym_AT,
+    STATE(186), 1,
+      aux_sym_directives_repeat1,
+    STATE(213), 1,
+      sym_directive,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(259), 7,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_DOT_DOT_DOT,
+      sym__name,
+      sym_grit_metavariable,
+  [6159] = 11,
+    ACTIONS(7), 1,
+      anon_sym_LBRACE,
+    ACTIONS(823), 1,
+      anon_sym_AT,
+    ACTIONS(846), 1,
+      anon_sym_LPAREN,
+    STATE(169), 1,
+      sym_selection_set,
+    STATE(187), 1,
+      aux_sym_directives_repeat1,
+    STATE(213), 1,
+      sym_directive,
+    STATE(235), 1,
+      sym_name,
+    STATE(263), 1,
+      sym_variable_definitions,
+    STATE(385), 1,
+      sym_directives,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [6195] = 11,
+    ACTIONS(25), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(27), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(848), 1,
+      anon_sym_RBRACE,
+    STATE(179), 1,
+      sym_name,
+    STATE(191), 1,
+      aux_sym_enum_values_definition_repeat1,
+    STATE(206), 1,
+      sym_string_value,
+    STATE(211), 1,
+      sym_enum_value,
+    STATE(299), 1,
+      sym_enum_value_definition,
+    STATE(339), 1,
+      sym_description,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [6231] = 9,
+    ACTIONS(850), 1,
+      anon_sym_RBRACE,
+    ACTIONS(852), 1,
+      anon_sym_DOT_DOT_DOT,
+    STATE(180), 1,
+      sym_name,
+    STATE(192), 1,
+      aux_sym_selection_set_repeat1,
+    STATE(332), 1,
+      sym_selection,
+    STATE(373), 1,
+      sym_alias,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+    STATE(338), 3,
+      sym_field,
+      sym_fragment_spread,
+      sym_inline_fragment,
+  [6263] = 11,
+    ACTIONS(857), 1,
+      anon_sym_RBRACE,
+    ACTIONS(859), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(862), 1,
+      anon_sym_DQUOTE,
+    STATE(179), 1,
+      sym_name,
+    STATE(191), 1,
+      aux_sym_enum_values_definition_repeat1,
+    STATE(206), 1,
+      sym_string_value,
+    STATE(211), 1,
+      sym_enum_value,
+    STATE(299), 1,
+      sym_enum_value_definition,
+    STATE(339), 1,
+      sym_description,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(854), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [6299] = 9,
+    ACTIONS(868), 1,
+      anon_sym_RBRACE,
+    ACTIONS(870), 1,
+      anon_sym_DOT_DOT_DOT,
+    STATE(180), 1,
+      sym_name,
+    STATE(192), 1,
+      aux_sym_selection_set_repeat1,
+    STATE(332), 1,
+      sym_selection,
+    STATE(373), 1,
+      sym_alias,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(865), 2,
+      sym__name,
+      sym_grit_metavariable,
+    STATE(338), 3,
+      sym_field,
+      sym_fragment_spread,
+      sym_inline_fragment,
+  [6331] = 9,
+    ACTIONS(852), 1,
+      anon_sym_DOT_DOT_DOT,
+    ACTIONS(873), 1,
+      anon_sym_RBRACE,
+    STATE(180), 1,
+      sym_name,
+    STATE(192), 1,
+      aux_sym_selection_set_repeat1,
+    STATE(332), 1,
+      sym_selection,
+    STATE(373), 1,
+      sym_alias,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+    STATE(338), 3,
+      sym_field,
+      sym_fragment_spread,
+      sym_inline_fragment,
+  [6363] = 5,
+    ACTIONS(271), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(821), 1,
+      anon_sym_LPAREN,
+    STATE(218), 1,
+      sym_arguments,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(267), 8,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_AT,
+      sym__name,
+      sym_grit_metavariable,
+  [6387] = 10,
+    ACTIONS(25), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(27), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(875), 1,
+      anon_sym_RBRACE,
+    STATE(205), 1,
+      aux_sym_input_fields_definition_repeat1,
+    STATE(206), 1,
+      sym_string_value,
+    STATE(302), 1,
+      sym_input_value_definition,
+    STATE(375), 1,
+      sym_description,
+    STATE(432), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [6420] = 8,
+    ACTIONS(852), 1,
+      anon_sym_DOT_DOT_DOT,
+    STATE(180), 1,
+      sym_name,
+    STATE(193), 1,
+      aux_sym_selection_set_repeat1,
+    STATE(332), 1,
+      sym_selection,
+    STATE(373), 1,
+      sym_alias,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+    STATE(338), 3,
+      sym_field,
+      sym_fragment_spread,
+      sym_inline_fragment,
+  [6449] = 10,
+    ACTIONS(880), 1,
+      anon_sym_RBRACE,
+    ACTIONS(882), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(885), 1,
+      anon_sym_DQUOTE,
+    STATE(197), 1,
+      aux_sym_fields_definition_repeat1,
+    STATE(206), 1,
+      sym_string_value,
+    STATE(287), 1,
+      sym_field_definition,
+    STATE(356), 1,
+      sym_name,
+    STATE(357), 1,
+      sym_description,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(877), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [6482] = 10,
+    ACTIONS(891), 1,
+      anon_sym_RPAREN,
+    ACTIONS(893), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(896), 1,
+      anon_sym_DQUOTE,
+    STATE(198), 1,
+      aux_sym_arguments_definition_repeat1,
+    STATE(206), 1,
+      sym_string_value,
+    STATE(285), 1,
+      sym_input_value_definition,
+    STATE(375), 1,
+      sym_description,
+    STATE(432), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(888), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [6515] = 8,
+    ACTIONS(817), 1,
+      anon_sym_LBRACE,
+    ACTIONS(823), 1,
+      anon_sym_AT,
+    STATE(187), 1,
+      aux_sym_directives_repeat1,
+    STATE(213), 1,
+      sym_directive,
+    STATE(247), 1,
+      sym_directives,
+    STATE(326), 1,
+      sym_selection_set,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(899), 4,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT_DOT,
+      sym__name,
+      sym_grit_metavariable,
+  [6544] = 10,
+    ACTIONS(25), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(27), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(901), 1,
+      anon_sym_RBRACE,
+    STATE(197), 1,
+      aux_sym_fields_definition_repeat1,
+    STATE(206), 1,
+      sym_string_value,
+    STATE(287), 1,
+      sym_field_definition,
+    STATE(356), 1,
+      sym_name,
+    STATE(357), 1,
+      sym_description,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [6577] = 8,
+    ACTIONS(852), 1,
+      anon_sym_DOT_DOT_DOT,
+    STATE(180), 1,
+      sym_name,
+    STATE(190), 1,
+      aux_sym_selection_set_repeat1,
+    STATE(332), 1,
+      sym_selection,
+    STATE(373), 1,
+      sym_alias,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+    STATE(338), 3,
+      sym_field,
+      sym_fragment_spread,
+      sym_inline_fragment,
+  [6606] = 3,
+    ACTIONS(905), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(903), 9,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_EQ,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_RBRACK,
+      anon_sym_AT,
+      anon_sym_BANG,
+      sym__name,
+      sym_grit_metavariable,
+  [6625] = 7,
+    ACTIONS(823), 1,
+      anon_sym_AT,
+    ACTIONS(909), 1,
+      anon_sym_DQUOTE,
+    STATE(187), 1,
+      aux_sym_directives_repeat1,
+    STATE(213), 1,
+      sym_directive,
+    STATE(260), 1,
+      sym_directives,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(907), 5,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym__name,
+      sym_grit_metavariable,
+  [6652] = 8,
+    ACTIONS(817), 1,
+      anon_sym_LBRACE,
+    ACTIONS(823), 1,
+      anon_sym_AT,
+    STATE(187), 1,
+      aux_sym_directives_repeat1,
+    STATE(213), 1,
+      sym_directive,
+    STATE(281), 1,
+      sym_directives,
+    STATE(324), 1,
+      sym_selection_set,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(911), 4,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT_DOT,
+      sym__name,
+      sym_grit_metavariable,
+  [6681] = 10,
+    ACTIONS(916), 1,
+      anon_sym_RBRACE,
+    ACTIONS(918), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(921), 1,
+      anon_sym_DQUOTE,
+    STATE(205), 1,
+      aux_sym_input_fields_definition_repeat1,
+    STATE(206), 1,
+      sym_string_value,
+    STATE(302), 1,
+      sym_input_value_definition,
+    STATE(375), 1,
+      sym_description,
+    STATE(432), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(913), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [6714] = 3,
+    ACTIONS(926), 1,
+      sym_grit_metavariable,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(924), 9,
+      anon_sym_schema,
+      anon_sym_scalar,
+      anon_sym_type,
+      anon_sym_interface,
+      anon_sym_union,
+      anon_sym_enum,
+      anon_sym_input,
+      anon_sym_directive,
+      sym__name,
+  [6733] = 10,
+    ACTIONS(25), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(27), 1,
+      anon_sym_DQUOTE,
+    STATE(179), 1,
+      sym_name,
+    STATE(189), 1,
+      aux_sym_enum_values_definition_repeat1,
+    STATE(206), 1,
+      sym_string_value,
+    STATE(211), 1,
+      sym_enum_value,
+    STATE(299), 1,
+      sym_enum_value_definition,
+    STATE(339), 1,
+      sym_description,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [6766] = 7,
+    ACTIONS(823), 1,
+      anon_sym_AT,
+    ACTIONS(930), 1,
+      anon_sym_DQUOTE,
+    STATE(187), 1,
+      aux_sym_directives_repeat1,
+    STATE(213), 1,
+      sym_directive,
+    STATE(262), 1,
+      sym_directives,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(928), 5,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym__name,
+      sym_grit_metavariable,
+  [6793] = 10,
+    ACTIONS(25), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(27), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(932), 1,
+      anon_sym_RPAREN,
+    STATE(198), 1,
+      aux_sym_arguments_definition_repeat1,
+    STATE(206), 1,
+      sym_string_value,
+    STATE(285), 1,
+      sym_input_value_definition,
+    STATE(375), 1,
+      sym_description,
+    STATE(432), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [6826] = 4,
+    ACTIONS(936), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(938), 1,
+      anon_sym_BANG,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(934), 8,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_EQ,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_RBRACK,
+      anon_sym_AT,
+      sym__name,
+      sym_grit_metavariable,
+  [6847] = 7,
+    ACTIONS(823), 1,
+      anon_sym_AT,
+    ACTIONS(942), 1,
+      anon_sym_DQUOTE,
+    STATE(187), 1,
+      aux_sym_directives_repeat1,
+    STATE(213), 1,
+      sym_directive,
+    STATE(290), 1,
+      sym_directives,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(940), 4,
+      anon_sym_RBRACE,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym__name,
+      sym_grit_metavariable,
+  [6873] = 7,
+    ACTIONS(823), 1,
+      anon_sym_AT,
+    ACTIONS(946), 1,
+      anon_sym_DQUOTE,
+    STATE(187), 1,
+      aux_sym_directives_repeat1,
+    STATE(213), 1,
+      sym_directive,
+    STATE(304), 1,
+      sym_directives,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(944), 4,
+      anon_sym_RBRACE,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym__name,
+      sym_grit_metavariable,
+  [6899] = 3,
+    ACTIONS(349), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(347), 8,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_AT,
+      sym__name,
+      sym_grit_metavariable,
+  [6917] = 3,
+    ACTIONS(302), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(300), 8,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_AT,
+      sym__name,
+      sym_grit_metavariable,
+  [6935] = 9,
+    ACTIONS(25), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(27), 1,
+      anon_sym_DQUOTE,
+    STATE(200), 1,
+      aux_sym_fields_definition_repeat1,
+    STATE(206), 1,
+      sym_string_value,
+    STATE(287), 1,
+      sym_field_definition,
+    STATE(356), 1,
+      sym_name,
+    STATE(357), 1,
+      sym_description,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [6965] = 7,
+    ACTIONS(823), 1,
+      anon_sym_AT,
+    ACTIONS(950), 1,
+      anon_sym_DQUOTE,
+    STATE(187), 1,
+      aux_sym_directives_repeat1,
+    STATE(213), 1,
+      sym_directive,
+    STATE(310), 1,
+      sym_directives,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(948), 4,
+      anon_sym_RBRACE,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym__name,
+      sym_grit_metavariable,
+  [6991] = 9,
+    ACTIONS(25), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(27), 1,
+      anon_sym_DQUOTE,
+    STATE(206), 1,
+      sym_string_value,
+    STATE(209), 1,
+      aux_sym_arguments_definition_repeat1,
+    STATE(285), 1,
+      sym_input_value_definition,
+    STATE(375), 1,
+      sym_description,
+    STATE(432), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [7021] = 3,
+    ACTIONS(306), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(304), 8,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_AT,
+      sym__name,
+      sym_grit_metavariable,
+  [7039] = 7,
+    ACTIONS(823), 1,
+      anon_sym_AT,
+    ACTIONS(954), 1,
+      anon_sym_DQUOTE,
+    STATE(187), 1,
+      aux_sym_directives_repeat1,
+    STATE(213), 1,
+      sym_directive,
+    STATE(291), 1,
+      sym_directives,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(952), 4,
+      anon_sym_RBRACE,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym__name,
+      sym_grit_metavariable,
+  [7065] = 7,
+    ACTIONS(823), 1,
+      anon_sym_AT,
+    ACTIONS(958), 1,
+      anon_sym_DQUOTE,
+    STATE(187), 1,
+      aux_sym_directives_repeat1,
+    STATE(213), 1,
+      sym_directive,
+    STATE(286), 1,
+      sym_directives,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(956), 4,
+      anon_sym_RBRACE,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym__name,
+      sym_grit_metavariable,
+  [7091] = 3,
+    ACTIONS(936), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(934), 8,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_EQ,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_RBRACK,
+      anon_sym_AT,
+      sym__name,
+      sym_grit_metavariable,
+  [7109] = 3,
+    ACTIONS(962), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(960), 8,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_EQ,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_RBRACK,
+      anon_sym_AT,
+      sym__name,
+      sym_grit_metavariable,
+  [7127] = 7,
+    ACTIONS(823), 1,
+      anon_sym_AT,
+    ACTIONS(966), 1,
+      anon_sym_DQUOTE,
+    STATE(187), 1,
+      aux_sym_directives_repeat1,
+    STATE(213), 1,
+      sym_directive,
+    STATE(296), 1,
+      sym_directives,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(964), 4,
+      anon_sym_RBRACE,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym__name,
+      sym_grit_metavariable,
+  [7153] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(81), 10,
+      anon_sym_RBRACE,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_EQ,
+      anon_sym_DOLLAR,
+      anon_sym_AT,
+      anon_sym_BANG,
+      sym__name,
+      sym_comma,
+      sym_grit_metavariable,
+  [7169] = 3,
+    ACTIONS(145), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(143), 8,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_EQ,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_AT,
+      anon_sym_BANG,
+      sym__name,
+      sym_grit_metavariable,
+  [7187] = 9,
+    ACTIONS(25), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(27), 1,
+      anon_sym_DQUOTE,
+    STATE(195), 1,
+      aux_sym_input_fields_definition_repeat1,
+    STATE(206), 1,
+      sym_string_value,
+    STATE(302), 1,
+      sym_input_value_definition,
+    STATE(375), 1,
+      sym_description,
+    STATE(432), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [7217] = 7,
+    ACTIONS(968), 1,
+      anon_sym_LBRACK,
+    STATE(23), 1,
+      sym_name,
+    STATE(221), 1,
+      sym_non_null_type,
+    STATE(424), 1,
+      sym_type,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+    STATE(210), 2,
+      sym_named_type,
+      sym_list_type,
+  [7242] = 7,
+    ACTIONS(968), 1,
+      anon_sym_LBRACK,
+    STATE(23), 1,
+      sym_name,
+    STATE(221), 1,
+      sym_non_null_type,
+    STATE(422), 1,
+      sym_type,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+    STATE(210), 2,
+      sym_named_type,
+      sym_list_type,
+  [7267] = 7,
+    ACTIONS(968), 1,
+      anon_sym_LBRACK,
+    STATE(182), 1,
+      sym_type,
+    STATE(221), 1,
+      sym_non_null_type,
+    STATE(225), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+    STATE(210), 2,
+      sym_named_type,
+      sym_list_type,
+  [7292] = 7,
+    ACTIONS(968), 1,
+      anon_sym_LBRACK,
+    STATE(185), 1,
+      sym_type,
+    STATE(221), 1,
+      sym_non_null_type,
+    STATE(225), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+    STATE(210), 2,
+      sym_named_type,
+      sym_list_type,
+  [7317] = 7,
+    ACTIONS(968), 1,
+      anon_sym_LBRACK,
+    STATE(221), 1,
+      sym_non_null_type,
+    STATE(223), 1,
+      sym_type,
+    STATE(225), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+    STATE(210), 2,
+      sym_named_type,
+      sym_list_type,
+  [7342] = 7,
+    ACTIONS(968), 1,
+      anon_sym_LBRACK,
+    STATE(219), 1,
+      sym_type,
+    STATE(221), 1,
+      sym_non_null_type,
+    STATE(225), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+    STATE(210), 2,
+      sym_named_type,
+      sym_list_type,
+  [7367] = 7,
+    ACTIONS(968), 1,
+      anon_sym_LBRACK,
+    STATE(220), 1,
+      sym_type,
+    STATE(221), 1,
+      sym_non_null_type,
+    STATE(225), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+    STATE(210), 2,
+      sym_named_type,
+      sym_list_type,
+  [7392] = 7,
+    ACTIONS(968), 1,
+      anon_sym_LBRACK,
+    STATE(212), 1,
+      sym_type,
+    STATE(221), 1,
+      sym_non_null_type,
+    STATE(225), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+    STATE(210), 2,
+      sym_named_type,
+      sym_list_type,
+  [7417] = 9,
+    ACTIONS(7), 1,
+      anon_sym_LBRACE,
+    ACTIONS(823), 1,
+      anon_sym_AT,
+    ACTIONS(846), 1,
+      anon_sym_LPAREN,
+    STATE(154), 1,
+      sym_selection_set,
+    STATE(187), 1,
+      aux_sym_directives_repeat1,
+    STATE(213), 1,
+      sym_directive,
+    STATE(249), 1,
+      sym_variable_definitions,
+    STATE(395), 1,
+      sym_directives,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [7446] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(972), 1,
+      anon_sym_EQ,
+    ACTIONS(974), 1,
+      anon_sym_AT,
+    ACTIONS(976), 1,
+      sym_comma,
+    STATE(267), 1,
+      sym_default_value,
+    STATE(289), 1,
+      aux_sym_directives_repeat1,
+    STATE(365), 1,
+      sym_directive,
+    STATE(386), 1,
+      sym_directives,
+    ACTIONS(970), 2,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+  [7475] = 9,
+    ACTIONS(978), 1,
+      anon_sym_schema,
+    ACTIONS(980), 1,
+      anon_sym_scalar,
+    ACTIONS(982), 1,
+      anon_sym_type,
+    ACTIONS(984), 1,
+      anon_sym_interface,
+    ACTIONS(986), 1,
+      anon_sym_union,
+    ACTIONS(988), 1,
+      anon_sym_enum,
+    ACTIONS(990), 1,
+      anon_sym_input,
+    ACTIONS(992), 1,
+      anon_sym_directive,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [7504] = 7,
+    ACTIONS(994), 1,
+      anon_sym_LBRACK,
+    STATE(236), 1,
+      sym_type,
+    STATE(298), 1,
+      sym_name,
+    STATE(340), 1,
+      sym_non_null_type,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(298), 2,
+      sym__name,
+      sym_grit_metavariable,
+    STATE(301), 2,
+      sym_named_type,
+      sym_list_type,
+  [7529] = 6,
+    ACTIONS(823), 1,
+      anon_sym_AT,
+    STATE(187), 1,
+      aux_sym_directives_repeat1,
+    STATE(213), 1,
+      sym_directive,
+    STATE(316), 1,
+      sym_directives,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(996), 4,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT_DOT,
+      sym__name,
+      sym_grit_metavariable,
+  [7552] = 8,
+    ACTIONS(998), 1,
+      anon_sym_schema,
+    ACTIONS(1000), 1,
+      anon_sym_scalar,
+    ACTIONS(1002), 1,
+      anon_sym_type,
+    ACTIONS(1004), 1,
+      anon_sym_interface,
+    ACTIONS(1006), 1,
+      anon_sym_union,
+    ACTIONS(1008), 1,
+      anon_sym_enum,
+    ACTIONS(1010), 1,
+      anon_sym_input,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [7578] = 6,
+    ACTIONS(1012), 1,
+      anon_sym_RBRACE,
+    STATE(243), 1,
+      aux_sym_schema_definition_repeat1,
+    STATE(333), 1,
+      sym_root_operation_type_definition,
+    STATE(433), 1,
+      sym_operation_type,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(23), 3,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+  [7600] = 6,
+    ACTIONS(1014), 1,
+      anon_sym_RBRACE,
+    STATE(243), 1,
+      aux_sym_schema_definition_repeat1,
+    STATE(333), 1,
+      sym_root_operation_type_definition,
+    STATE(433), 1,
+      sym_operation_type,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(23), 3,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+  [7622] = 6,
+    ACTIONS(1016), 1,
+      anon_sym_RBRACE,
+    STATE(243), 1,
+      aux_sym_schema_definition_repeat1,
+    STATE(333), 1,
+      sym_root_operation_type_definition,
+    STATE(433), 1,
+      sym_operation_type,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1018), 3,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+  [7644] = 6,
+    ACTIONS(1021), 1,
+      anon_sym_RBRACE,
+    STATE(243), 1,
+      aux_sym_schema_definition_repeat1,
+    STATE(333), 1,
+      sym_root_operation_type_definition,
+    STATE(433), 1,
+      sym_operation_type,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(23), 3,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+  [7666] = 6,
+    ACTIONS(1023), 1,
+      anon_sym_RBRACE,
+    STATE(243), 1,
+      aux_sym_schema_definition_repeat1,
+    STATE(333), 1,
+      sym_root_operation_type_definition,
+    STATE(433), 1,
+      sym_operation_type,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(23), 3,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+  [7688] = 3,
+    ACTIONS(1027), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1025), 6,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      anon_sym_AT,
+      sym__name,
+      sym_grit_metavariable,
+  [7704] = 4,
+    ACTIONS(817), 1,
+      anon_sym_LBRACE,
+    STATE(328), 1,
+      sym_selection_set,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1029), 4,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT_DOT,
+      sym__name,
+      sym_grit_metavariable,
+  [7721] = 6,
+    ACTIONS(1034), 1,
+      anon_sym_RPAREN,
+    STATE(248), 1,
+      aux_sym_arguments_repeat1,
+    STATE(368), 1,
+      sym_argument,
+    STATE(414), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1031), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [7742] = 7,
+    ACTIONS(7), 1,
+      anon_sym_LBRACE,
+    ACTIONS(823), 1,
+      anon_sym_AT,
+    STATE(117), 1,
+      sym_selection_set,
+    STATE(187), 1,
+      aux_sym_directives_repeat1,
+    STATE(213), 1,
+      sym_directive,
+    STATE(403), 1,
+      sym_directives,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [7765] = 3,
+    ACTIONS(1038), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1036), 5,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym__name,
+      sym_grit_metavariable,
+  [7780] = 4,
+    ACTIONS(817), 1,
+      anon_sym_LBRACE,
+    STATE(327), 1,
+      sym_selection_set,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1040), 4,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT_DOT,
+      sym__name,
+      sym_grit_metavariable,
+  [7797] = 6,
+    ACTIONS(1042), 1,
+      anon_sym_RBRACE,
+    STATE(277), 1,
+      aux_sym_object_value_repeat1,
+    STATE(379), 1,
+      sym_object_field,
+    STATE(429), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [7818] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1044), 2,
+      anon_sym_on,
+      sym__name,
+    ACTIONS(1046), 4,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT_DOT,
+      anon_sym_AT,
+      sym_grit_metavariable,
+  [7833] = 5,
+    STATE(241), 1,
+      aux_sym_schema_definition_repeat1,
+    STATE(333), 1,
+      sym_root_operation_type_definition,
+    STATE(433), 1,
+      sym_operation_type,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(23), 3,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+  [7852] = 4,
+    ACTIONS(817), 1,
+      anon_sym_LBRACE,
+    STATE(312), 1,
+      sym_selection_set,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1048), 4,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT_DOT,
+      sym__name,
+      sym_grit_metavariable,
+  [7869] = 6,
+    ACTIONS(1050), 1,
+      anon_sym_RPAREN,
+    STATE(248), 1,
+      aux_sym_arguments_repeat1,
+    STATE(368), 1,
+      sym_argument,
+    STATE(414), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [7890] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1052), 6,
+      anon_sym_LBRACE,
+      anon_sym_COLON,
+      anon_sym_LPAREN,
+      anon_sym_AT,
+      sym__name,
+      sym_grit_metavariable,
+  [7903] = 6,
+    ACTIONS(1057), 1,
+      anon_sym_RBRACE,
+    STATE(258), 1,
+      aux_sym_object_value_repeat1,
+    STATE(379), 1,
+      sym_object_field,
+    STATE(429), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1054), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [7924] = 5,
+    STATE(242), 1,
+      aux_sym_schema_definition_repeat1,
+    STATE(333), 1,
+      sym_root_operation_type_definition,
+    STATE(433), 1,
+      sym_operation_type,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(23), 3,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+  [7943] = 3,
+    ACTIONS(1061), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1059), 5,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym__name,
+      sym_grit_metavariable,
+  [7958] = 3,
+    ACTIONS(1065), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1063), 5,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym__name,
+      sym_grit_metavariable,
+  [7973] = 3,
+    ACTIONS(1069), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1067), 5,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym__name,
+      sym_grit_metavariable,
+  [7988] = 7,
+    ACTIONS(7), 1,
+      anon_sym_LBRACE,
+    ACTIONS(823), 1,
+      anon_sym_AT,
+    STATE(149), 1,
+      sym_selection_set,
+    STATE(187), 1,
+      aux_sym_directives_repeat1,
+    STATE(213), 1,
+      sym_directive,
+    STATE(391), 1,
+      sym_directives,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [8011] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(107), 7,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+      anon_sym_AT,
+      sym__name,
+      sym_comma,
+      sym_grit_metavariable,
+  [8024] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(813), 7,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+      anon_sym_AT,
+      sym__name,
+      sym_comma,
+      sym_grit_metavariable,
+  [8037] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(557), 7,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+      anon_sym_AT,
+      sym__name,
+      sym_comma,
+      sym_grit_metavariable,
+  [8050] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(974), 1,
+      anon_sym_AT,
+    ACTIONS(1073), 1,
+      sym_comma,
+    STATE(289), 1,
+      aux_sym_directives_repeat1,
+    STATE(365), 1,
+      sym_directive,
+    STATE(392), 1,
+      sym_directives,
+    ACTIONS(1071), 2,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+  [8073] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(789), 7,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+      anon_sym_AT,
+      sym__name,
+      sym_comma,
+      sym_grit_metavariable,
+  [8086] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(797), 7,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+      anon_sym_AT,
+      sym__name,
+      sym_comma,
+      sym_grit_metavariable,
+  [8099] = 7,
+    ACTIONS(817), 1,
+      anon_sym_LBRACE,
+    ACTIONS(823), 1,
+      anon_sym_AT,
+    STATE(187), 1,
+      aux_sym_directives_repeat1,
+    STATE(213), 1,
+      sym_directive,
+    STATE(317), 1,
+      sym_selection_set,
+    STATE(388), 1,
+      sym_directives,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [8122] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(793), 7,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+      anon_sym_AT,
+      sym__name,
+      sym_comma,
+      sym_grit_metavariable,
+  [8135] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(801), 7,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+      anon_sym_AT,
+      sym__name,
+      sym_comma,
+      sym_grit_metavariable,
+  [8148] = 5,
+    STATE(245), 1,
+      aux_sym_schema_definition_repeat1,
+    STATE(333), 1,
+      sym_root_operation_type_definition,
+    STATE(433), 1,
+      sym_operation_type,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(23), 3,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+  [8167] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(809), 7,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+      anon_sym_AT,
+      sym__name,
+      sym_comma,
+      sym_grit_metavariable,
+  [8180] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(805), 7,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+      anon_sym_AT,
+      sym__name,
+      sym_comma,
+      sym_grit_metavariable,
+  [8193] = 5,
+    STATE(244), 1,
+      aux_sym_schema_definition_repeat1,
+    STATE(333), 1,
+      sym_root_operation_type_definition,
+    STATE(433), 1,
+      sym_operation_type,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(23), 3,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+  [8212] = 6,
+    ACTIONS(1075), 1,
+      anon_sym_RBRACE,
+    STATE(258), 1,
+      aux_sym_object_value_repeat1,
+    STATE(379), 1,
+      sym_object_field,
+    STATE(429), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [8233] = 6,
+    ACTIONS(1077), 1,
+      anon_sym_RPAREN,
+    STATE(248), 1,
+      aux_sym_arguments_repeat1,
+    STATE(368), 1,
+      sym_argument,
+    STATE(414), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [8254] = 6,
+    ACTIONS(1079), 1,
+      anon_sym_RBRACE,
+    STATE(280), 1,
+      aux_sym_object_value_repeat1,
+    STATE(379), 1,
+      sym_object_field,
+    STATE(429), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [8275] = 6,
+    ACTIONS(1081), 1,
+      anon_sym_RBRACE,
+    STATE(258), 1,
+      aux_sym_object_value_repeat1,
+    STATE(379), 1,
+      sym_object_field,
+    STATE(429), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [8296] = 4,
+    ACTIONS(817), 1,
+      anon_sym_LBRACE,
+    STATE(343), 1,
+      sym_selection_set,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1083), 4,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT_DOT,
+      sym__name,
+      sym_grit_metavariable,
+  [8313] = 6,
+    ACTIONS(1085), 1,
+      anon_sym_RPAREN,
+    STATE(248), 1,
+      aux_sym_arguments_repeat1,
+    STATE(368), 1,
+      sym_argument,
+    STATE(414), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [8334] = 7,
+    ACTIONS(7), 1,
+      anon_sym_LBRACE,
+    ACTIONS(823), 1,
+      anon_sym_AT,
+    STATE(114), 1,
+      sym_selection_set,
+    STATE(187), 1,
+      aux_sym_directives_repeat1,
+    STATE(213), 1,
+      sym_directive,
+    STATE(396), 1,
+      sym_directives,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [8357] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(903), 6,
+      anon_sym_RPAREN,
+      anon_sym_EQ,
+      anon_sym_DOLLAR,
+      anon_sym_AT,
+      anon_sym_BANG,
+      sym_comma,
+  [8369] = 3,
+    ACTIONS(1089), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1087), 4,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym__name,
+      sym_grit_metavariable,
+  [8383] = 3,
+    ACTIONS(1093), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1091), 4,
+      anon_sym_RBRACE,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym__name,
+      sym_grit_metavariable,
+  [8397] = 3,
+    ACTIONS(1097), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1095), 4,
+      anon_sym_RBRACE,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym__name,
+      sym_grit_metavariable,
+  [8411] = 4,
+    STATE(417), 1,
+      sym_root_operation_type_definition,
+    STATE(433), 1,
+      sym_operation_type,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(23), 3,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+  [8427] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(974), 1,
+      anon_sym_AT,
+    STATE(293), 1,
+      aux_sym_directives_repeat1,
+    STATE(365), 1,
+      sym_directive,
+    ACTIONS(259), 3,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+      sym_comma,
+  [8445] = 3,
+    ACTIONS(1101), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1099), 4,
+      anon_sym_RBRACE,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym__name,
+      sym_grit_metavariable,
+  [8459] = 3,
+    ACTIONS(1105), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1103), 4,
+      anon_sym_RBRACE,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym__name,
+      sym_grit_metavariable,
+  [8473] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1107), 1,
+      anon_sym_LPAREN,
+    STATE(370), 1,
+      sym_arguments,
+    ACTIONS(267), 4,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+      anon_sym_AT,
+      sym_comma,
+  [8489] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1109), 1,
+      anon_sym_AT,
+    STATE(293), 1,
+      aux_sym_directives_repeat1,
+    STATE(365), 1,
+      sym_directive,
+    ACTIONS(273), 3,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+      sym_comma,
+  [8507] = 6,
+    ACTIONS(1112), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1114), 1,
+      anon_sym_DOLLAR,
+    STATE(294), 1,
+      aux_sym_variable_definitions_repeat1,
+    STATE(389), 1,
+      sym_variable_definition,
+    STATE(410), 1,
+      sym_variable,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [8527] = 5,
+    STATE(256), 1,
+      aux_sym_arguments_repeat1,
+    STATE(368), 1,
+      sym_argument,
+    STATE(414), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [8545] = 3,
+    ACTIONS(1119), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1117), 4,
+      anon_sym_RBRACE,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym__name,
+      sym_grit_metavariable,
+  [8559] = 6,
+    ACTIONS(823), 1,
+      anon_sym_AT,
+    ACTIONS(1121), 1,
+      anon_sym_LBRACE,
+    STATE(187), 1,
+      aux_sym_directives_repeat1,
+    STATE(213), 1,
+      sym_directive,
+    STATE(412), 1,
+      sym_directives,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [8579] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(143), 6,
+      anon_sym_RPAREN,
+      anon_sym_EQ,
+      anon_sym_DOLLAR,
+      anon_sym_AT,
+      anon_sym_BANG,
+      sym_comma,
+  [8591] = 3,
+    ACTIONS(1125), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1123), 4,
+      anon_sym_RBRACE,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym__name,
+      sym_grit_metavariable,
+  [8605] = 5,
+    ACTIONS(1129), 1,
+      anon_sym_AMP,
+    STATE(23), 1,
+      sym_name,
+    STATE(63), 1,
+      sym_named_type,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1127), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [8623] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1131), 1,
+      anon_sym_BANG,
+    ACTIONS(934), 5,
+      anon_sym_RPAREN,
+      anon_sym_EQ,
+      anon_sym_DOLLAR,
+      anon_sym_AT,
+      sym_comma,
+  [8637] = 3,
+    ACTIONS(1135), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1133), 4,
+      anon_sym_RBRACE,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym__name,
+      sym_grit_metavariable,
+  [8651] = 6,
+    ACTIONS(823), 1,
+      anon_sym_AT,
+    ACTIONS(1137), 1,
+      anon_sym_LBRACE,
+    STATE(187), 1,
+      aux_sym_directives_repeat1,
+    STATE(213), 1,
+      sym_directive,
+    STATE(406), 1,
+      sym_directives,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [8671] = 3,
+    ACTIONS(1141), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1139), 4,
+      anon_sym_RBRACE,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym__name,
+      sym_grit_metavariable,
+  [8685] = 4,
+    STATE(418), 1,
+      sym_root_operation_type_definition,
+    STATE(433), 1,
+      sym_operation_type,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(23), 3,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+  [8701] = 5,
+    ACTIONS(1143), 1,
+      anon_sym_PIPE,
+    STATE(23), 1,
+      sym_name,
+    STATE(87), 1,
+      sym_named_type,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1127), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [8719] = 6,
+    ACTIONS(823), 1,
+      anon_sym_AT,
+    ACTIONS(1145), 1,
+      anon_sym_LBRACE,
+    STATE(187), 1,
+      aux_sym_directives_repeat1,
+    STATE(213), 1,
+      sym_directive,
+    STATE(409), 1,
+      sym_directives,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [8739] = 6,
+    ACTIONS(203), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(1147), 1,
+      anon_sym_RPAREN,
+    STATE(294), 1,
+      aux_sym_variable_definitions_repeat1,
+    STATE(389), 1,
+      sym_variable_definition,
+    STATE(410), 1,
+      sym_variable,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [8759] = 5,
+    STATE(278), 1,
+      aux_sym_arguments_repeat1,
+    STATE(368), 1,
+      sym_argument,
+    STATE(414), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [8777] = 3,
+    ACTIONS(1151), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1149), 4,
+      anon_sym_RBRACE,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+      sym__name,
+      sym_grit_metavariable,
+  [8791] = 5,
+    STATE(282), 1,
+      aux_sym_arguments_repeat1,
+    STATE(368), 1,
+      sym_argument,
+    STATE(414), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [8809] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1153), 4,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT_DOT,
+      sym__name,
+      sym_grit_metavariable,
+  [8820] = 4,
+    STATE(23), 1,
+      sym_name,
+    STATE(321), 1,
+      sym_named_type,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [8835] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1155), 4,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT_DOT,
+      sym__name,
+      sym_grit_metavariable,
+  [8846] = 4,
+    STATE(253), 1,
+      sym_name,
+    STATE(387), 1,
+      sym_fragment_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [8861] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1157), 4,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT_DOT,
+      sym__name,
+      sym_grit_metavariable,
+  [8872] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1159), 4,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT_DOT,
+      sym__name,
+      sym_grit_metavariable,
+  [8883] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1161), 4,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT_DOT,
+      sym__name,
+      sym_grit_metavariable,
+  [8894] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1163), 4,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT_DOT,
+      sym__name,
+      sym_grit_metavariable,
+  [8905] = 5,
+    ACTIONS(1165), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1167), 1,
+      anon_sym_on,
+    ACTIONS(1169), 1,
+      anon_sym_repeatable,
+    STATE(402), 1,
+      sym_arguments_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [8922] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1171), 4,
+      anon_sym_RBRACE,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+  [8933] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(960), 5,
+      anon_sym_RPAREN,
+      anon_sym_EQ,
+      anon_sym_DOLLAR,
+      anon_sym_AT,
+      sym_comma,
+  [8944] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1173), 4,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT_DOT,
+      sym__name,
+      sym_grit_metavariable,
+  [8955] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1175), 4,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT_DOT,
+      sym__name,
+      sym_grit_metavariable,
+  [8966] = 5,
+    ACTIONS(91), 1,
+      anon_sym_AT,
+    STATE(45), 1,
+      aux_sym_directives_repeat1,
+    STATE(64), 1,
+      sym_directive,
+    STATE(167), 1,
+      sym_directives,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [8983] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1177), 4,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT_DOT,
+      sym__name,
+      sym_grit_metavariable,
+  [8994] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1179), 4,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT_DOT,
+      sym__name,
+      sym_grit_metavariable,
+  [9005] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1181), 4,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT_DOT,
+      sym__name,
+      sym_grit_metavariable,
+  [9016] = 5,
+    ACTIONS(203), 1,
+      anon_sym_DOLLAR,
+    STATE(308), 1,
+      aux_sym_variable_definitions_repeat1,
+    STATE(389), 1,
+      sym_variable_definition,
+    STATE(410), 1,
+      sym_variable,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [9033] = 4,
+    STATE(23), 1,
+      sym_name,
+    STATE(57), 1,
+      sym_named_type,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1127), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9048] = 4,
+    STATE(23), 1,
+      sym_name,
+    STATE(58), 1,
+      sym_named_type,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1127), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9063] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1183), 4,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT_DOT,
+      sym__name,
+      sym_grit_metavariable,
+  [9074] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1185), 4,
+      anon_sym_RBRACE,
+      anon_sym_query,
+      anon_sym_mutation,
+      anon_sym_subscription,
+  [9085] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(575), 4,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT_DOT,
+      sym__name,
+      sym_grit_metavariable,
+  [9096] = 4,
+    STATE(23), 1,
+      sym_name,
+    STATE(83), 1,
+      sym_named_type,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1127), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9111] = 5,
+    ACTIONS(1165), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1187), 1,
+      anon_sym_on,
+    ACTIONS(1189), 1,
+      anon_sym_repeatable,
+    STATE(397), 1,
+      sym_arguments_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [9128] = 4,
+    STATE(23), 1,
+      sym_name,
+    STATE(99), 1,
+      sym_named_type,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1127), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9143] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1191), 4,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT_DOT,
+      sym__name,
+      sym_grit_metavariable,
+  [9154] = 4,
+    STATE(179), 1,
+      sym_name,
+    STATE(216), 1,
+      sym_enum_value,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9169] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(934), 5,
+      anon_sym_RPAREN,
+      anon_sym_EQ,
+      anon_sym_DOLLAR,
+      anon_sym_AT,
+      sym_comma,
+  [9180] = 4,
+    STATE(23), 1,
+      sym_name,
+    STATE(393), 1,
+      sym_named_type,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9195] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1193), 4,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT_DOT,
+      sym__name,
+      sym_grit_metavariable,
+  [9206] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1195), 4,
+      anon_sym_RBRACE,
+      anon_sym_DOT_DOT_DOT,
+      sym__name,
+      sym_grit_metavariable,
+  [9217] = 3,
+    STATE(320), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9229] = 3,
+    STATE(6), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1127), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9241] = 3,
+    STATE(194), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9253] = 3,
+    STATE(336), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9265] = 3,
+    STATE(5), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1127), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9277] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1025), 4,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+      anon_sym_AT,
+      sym_comma,
+  [9287] = 3,
+    STATE(29), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1127), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9299] = 4,
+    ACTIONS(1165), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1197), 1,
+      anon_sym_COLON,
+    STATE(416), 1,
+      sym_arguments_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [9313] = 3,
+    STATE(32), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1127), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9325] = 3,
+    STATE(37), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1127), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9337] = 3,
+    STATE(46), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1127), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9349] = 3,
+    STATE(10), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1127), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9361] = 4,
+    ACTIONS(1165), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1199), 1,
+      anon_sym_COLON,
+    STATE(426), 1,
+      sym_arguments_definition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [9375] = 3,
+    STATE(351), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9387] = 3,
+    STATE(11), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1127), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9399] = 3,
+    STATE(26), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1127), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9411] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1201), 3,
+      anon_sym_RPAREN,
+      sym__name,
+      sym_grit_metavariable,
+  [9421] = 3,
+    STATE(35), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1127), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9433] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1205), 1,
+      sym_comma,
+    ACTIONS(1203), 3,
+      anon_sym_RBRACE,
+      sym__name,
+      sym_grit_metavariable,
+  [9445] = 3,
+    STATE(34), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1127), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9457] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1207), 3,
+      anon_sym_RBRACE,
+      sym__name,
+      sym_grit_metavariable,
+  [9467] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(347), 4,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+      anon_sym_AT,
+      sym_comma,
+  [9477] = 3,
+    STATE(33), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1127), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9489] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1209), 3,
+      anon_sym_COLON,
+      anon_sym_on,
+      anon_sym_repeatable,
+  [9499] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1211), 3,
+      anon_sym_RPAREN,
+      sym__name,
+      sym_grit_metavariable,
+  [9509] = 3,
+    STATE(266), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(298), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9521] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(304), 4,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+      anon_sym_AT,
+      sym_comma,
+  [9531] = 3,
+    STATE(22), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1127), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9543] = 3,
+    STATE(115), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9555] = 3,
+    STATE(184), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9567] = 3,
+    STATE(44), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1127), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9579] = 3,
+    STATE(404), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9591] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(300), 4,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+      anon_sym_AT,
+      sym_comma,
+  [9601] = 3,
+    STATE(47), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1127), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9613] = 3,
+    STATE(325), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9625] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1213), 3,
+      anon_sym_RBRACE,
+      sym__name,
+      sym_grit_metavariable,
+  [9635] = 3,
+    STATE(292), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(298), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9647] = 3,
+    STATE(7), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1127), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9659] = 3,
+    STATE(8), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1127), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9671] = 3,
+    STATE(30), 1,
+      sym_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1127), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9683] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1215), 2,
+      sym__name,
+      sym_grit_metavariable,
+  [9692] = 3,
+    ACTIONS(7), 1,
+      anon_sym_LBRACE,
+    STATE(151), 1,
+      sym_selection_set,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [9703] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1219), 1,
+      sym_comma,
+    ACTIONS(1217), 2,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+  [9714] = 3,
+    ACTIONS(1221), 1,
+      anon_sym_on,
+    STATE(283), 1,
+      sym_type_condition,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [9725] = 3,
+    ACTIONS(817), 1,
+      anon_sym_LBRACE,
+    STATE(323), 1,
+      sym_selection_set,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [9736] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1223), 2,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+  [9745] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1225), 2,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+  [9754] = 3,
+    ACTIONS(7), 1,
+      anon_sym_LBRACE,
+    STATE(116), 1,
+      sym_selection_set,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [9765] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1229), 1,
+      sym_comma,
+    ACTIONS(1227), 2,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+  [9776] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1231), 2,
+      anon_sym_LBRACE,
+      anon_sym_AT,
+  [9785] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1233), 2,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+  [9794] = 3,
+    ACTIONS(7), 1,
+      anon_sym_LBRACE,
+    STATE(118), 1,
+      sym_selection_set,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [9805] = 3,
+    ACTIONS(7), 1,
+      anon_sym_LBRACE,
+    STATE(141), 1,
+      sym_selection_set,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [9816] = 3,
+    ACTIONS(1235), 1,
+      anon_sym_on,
+    ACTIONS(1237), 1,
+      anon_sym_repeatable,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [9827] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1239), 2,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+  [9836] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1241), 2,
+      anon_sym_RPAREN,
+      anon_sym_DOLLAR,
+  [9845] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+    ACTIONS(1243), 2,
+      anon_sym_LBRACE,
+      anon_sym_AT,
+  [9854] = 3,
+    ACTIONS(817), 1,
+      anon_sym_LBRACE,
+    STATE(318), 1,
+      sym_selection_set,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [9865] = 3,
+    ACTIONS(1245), 1,
+      anon_sym_on,
+    ACTIONS(1247), 1,
+      anon_sym_repeatable,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [9876] = 3,
+    ACTIONS(7), 1,
+      anon_sym_LBRACE,
+    STATE(172), 1,
+      sym_selection_set,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [9887] = 2,
+    ACTIONS(1249), 1,
+      anon_sym_COLON,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [9895] = 2,
+    ACTIONS(1251), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [9903] = 2,
+    ACTIONS(1253), 1,
+      anon_sym_LBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [9911] = 2,
+    ACTIONS(1255), 1,
+      aux_sym_string_value_token1,
+    ACTIONS(1257), 2,
+      sym_comment,
+      sym_comma,
+  [9919] = 2,
+    ACTIONS(1259), 1,
+      anon_sym_on,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [9927] = 2,
+    ACTIONS(1261), 1,
+      anon_sym_LBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [9935] = 2,
+    ACTIONS(1263), 1,
+      anon_sym_COLON,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [9943] = 2,
+    ACTIONS(1265), 1,
+      ts_builtin_sym_end,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [9951] = 2,
+    ACTIONS(1267), 1,
+      anon_sym_LBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [9959] = 2,
+    ACTIONS(1269), 1,
+      anon_sym_on,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [9967] = 2,
+    ACTIONS(1271), 1,
+      anon_sym_COLON,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [9975] = 2,
+    ACTIONS(1273), 1,
+      aux_sym_string_value_token2,
+    ACTIONS(1257), 2,
+      sym_comment,
+      sym_comma,
+  [9983] = 2,
+    ACTIONS(1275), 1,
+      anon_sym_COLON,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [9991] = 2,
+    ACTIONS(1277), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [9999] = 2,
+    ACTIONS(1279), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [10007] = 2,
+    ACTIONS(1281), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [10015] = 2,
+    ACTIONS(1281), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [10023] = 2,
+    ACTIONS(1283), 1,
+      anon_sym_on,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [10031] = 2,
+    ACTIONS(1285), 1,
+      anon_sym_RBRACK,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [10039] = 2,
+    ACTIONS(1287), 1,
+      anon_sym_on,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [10047] = 2,
+    ACTIONS(1289), 1,
+      anon_sym_RBRACK,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [10055] = 2,
+    ACTIONS(1291), 1,
+      ts_builtin_sym_end,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [10063] = 2,
+    ACTIONS(1293), 1,
+      anon_sym_COLON,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [10071] = 2,
+    ACTIONS(1295), 1,
+      anon_sym_AT,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [10079] = 2,
+    ACTIONS(1251), 1,
+      anon_sym_DQUOTE_DQUOTE_DQUOTE,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [10087] = 2,
+    ACTIONS(1297), 1,
+      anon_sym_COLON,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [10095] = 2,
+    ACTIONS(1299), 1,
+      aux_sym_string_value_token1,
+    ACTIONS(1257), 2,
+      sym_comment,
+      sym_comma,
+  [10103] = 2,
+    ACTIONS(1301), 1,
+      aux_sym_string_value_token2,
+    ACTIONS(1257), 2,
+      sym_comment,
+      sym_comma,
+  [10111] = 2,
+    ACTIONS(1303), 1,
+      anon_sym_COLON,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [10119] = 2,
+    ACTIONS(1305), 1,
+      anon_sym_COLON,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+  [10127] = 2,
+    ACTIONS(1307), 1,
+      anon_sym_AT,
+    ACTIONS(3), 2,
+      sym_comment,
+      sym_comma,
+};
+
+static const uint32_t ts_small_parse_table_map[] = {
+  [SMALL_STATE(2)] = 0,
+  [SMALL_STATE(3)] = 104,
+  [SMALL_STATE(4)] = 208,
+  [SMALL_STATE(5)] = 243,
+  [SMALL_STATE(6)] = 292,
+  [SMALL_STATE(7)] = 341,
+  [SMALL_STATE(8)] = 390,
+  [SMALL_STATE(9)] = 439,
+  [SMALL_STATE(10)] = 472,
+  [SMALL_STATE(11)] = 521,
+  [SMALL_STATE(12)] = 570,
+  [SMALL_STATE(13)] = 615,
+  [SMALL_STATE(14)] = 660,
+  [SMALL_STATE(15)] = 705,
+  [SMALL_STATE(16)] = 750,
+  [SMALL_STATE(17)] = 795,
+  [SMALL_STATE(18)] = 840,
+  [SMALL_STATE(19)] = 885,
+  [SMALL_STATE(20)] = 930,
+  [SMALL_STATE(21)] = 976,
+  [SMALL_STATE(22)] = 1022,
+  [SMALL_STATE(23)] = 1068,
+  [SMALL_STATE(24)] = 1100,
+  [SMALL_STATE(25)] = 1146,
+  [SMALL_STATE(26)] = 1192,
+  [SMALL_STATE(27)] = 1236,
+  [SMALL_STATE(28)] = 1282,
+  [SMALL_STATE(29)] = 1328,
+  [SMALL_STATE(30)] = 1372,
+  [SMALL_STATE(31)] = 1416,
+  [SMALL_STATE(32)] = 1448,
+  [SMALL_STATE(33)] = 1491,
+  [SMALL_STATE(34)] = 1534,
+  [SMALL_STATE(35)] = 1577,
+  [SMALL_STATE(36)] = 1620,
+  [SMALL_STATE(37)] = 1659,
+  [SMALL_STATE(38)] = 1702,
+  [SMALL_STATE(39)] = 1759,
+  [SMALL_STATE(40)] = 1816,
+  [SMALL_STATE(41)] = 1855,
+  [SMALL_STATE(42)] = 1912,
+  [SMALL_STATE(43)] = 1969,
+  [SMALL_STATE(44)] = 2026,
+  [SMALL_STATE(45)] = 2064,
+  [SMALL_STATE(46)] = 2100,
+  [SMALL_STATE(47)] = 2138,
+  [SMALL_STATE(48)] = 2172,
+  [SMALL_STATE(49)] = 2208,
+  [SMALL_STATE(50)] = 2259,
+  [SMALL_STATE(51)] = 2310,
+  [SMALL_STATE(52)] = 2361,
+  [SMALL_STATE(53)] = 2412,
+  [SMALL_STATE(54)] = 2440,
+  [SMALL_STATE(55)] = 2468,
+  [SMALL_STATE(56)] = 2502,
+  [SMALL_STATE(57)] = 2536,
+  [SMALL_STATE(58)] = 2564,
+  [SMALL_STATE(59)] = 2592,
+  [SMALL_STATE(60)] = 2624,
+  [SMALL_STATE(61)] = 2656,
+  [SMALL_STATE(62)] = 2690,
+  [SMALL_STATE(63)] = 2724,
+  [SMALL_STATE(64)] = 2752,
+  [SMALL_STATE(65)] = 2780,
+  [SMALL_STATE(66)] = 2812,
+  [SMALL_STATE(67)] = 2843,
+  [SMALL_STATE(68)] = 2870,
+  [SMALL_STATE(69)] = 2899,
+  [SMALL_STATE(70)] = 2926,
+  [SMALL_STATE(71)] = 2953,
+  [SMALL_STATE(72)] = 2984,
+  [SMALL_STATE(73)] = 3013,
+  [SMALL_STATE(74)] = 3044,
+  [SMALL_STATE(75)] = 3075,
+  [SMALL_STATE(76)] = 3104,
+  [SMALL_STATE(77)] = 3135,
+  [SMALL_STATE(78)] = 3166,
+  [SMALL_STATE(79)] = 3193,
+  [SMALL_STATE(80)] = 3222,
+  [SMALL_STATE(81)] = 3251,
+  [SMALL_STATE(82)] = 3280,
+  [SMALL_STATE(83)] = 3309,
+  [SMALL_STATE(84)] = 3336,
+  [SMALL_STATE(85)] = 3365,
+  [SMALL_STATE(86)] = 3396,
+  [SMALL_STATE(87)] = 3427,
+  [SMALL_STATE(88)] = 3454,
+  [SMALL_STATE(89)] = 3481,
+  [SMALL_STATE(90)] = 3510,
+  [SMALL_STATE(91)] = 3539,
+  [SMALL_STATE(92)] = 3568,
+  [SMALL_STATE(93)] = 3597,
+  [SMALL_STATE(94)] = 3628,
+  [SMALL_STATE(95)] = 3659,
+  [SMALL_STATE(96)] = 3688,
+  [SMALL_STATE(97)] = 3719,
+  [SMALL_STATE(98)] = 3750,
+  [SMALL_STATE(99)] = 3781,
+  [SMALL_STATE(100)] = 3808,
+  [SMALL_STATE(101)] = 3837,
+  [SMALL_STATE(102)] = 3868,
+  [SMALL_STATE(103)] = 3899,
+  [SMALL_STATE(104)] = 3926,
+  [SMALL_STATE(105)] = 3957,
+  [SMALL_STATE(106)] = 3988,
+  [SMALL_STATE(107)] = 4014,
+  [SMALL_STATE(108)] = 4040,
+  [SMALL_STATE(109)] = 4066,
+  [SMALL_STATE(110)] = 4092,
+  [SMALL_STATE(111)] = 4118,
+  [SMALL_STATE(112)] = 4144,
+  [SMALL_STATE(113)] = 4170,
+  [SMALL_STATE(114)] = 4196,
+  [SMALL_STATE(115)] = 4222,
+  [SMALL_STATE(116)] = 4248,
+  [SMALL_STATE(117)] = 4274,
+  [SMALL_STATE(118)] = 4300,
+  [SMALL_STATE(119)] = 4326,
+  [SMALL_STATE(120)] = 4352,
+  [SMALL_STATE(121)] = 4378,
+  [SMALL_STATE(122)] = 4404,
+  [SMALL_STATE(123)] = 4430,
+  [SMALL_STATE(124)] = 4456,
+  [SMALL_STATE(125)] = 4482,
+  [SMALL_STATE(126)] = 4508,
+  [SMALL_STATE(127)] = 4534,
+  [SMALL_STATE(128)] = 4560,
+  [SMALL_STATE(129)] = 4586,
+  [SMALL_STATE(130)] = 4612,
+  [SMALL_STATE(131)] = 4638,
+  [SMALL_STATE(132)] = 4664,
+  [SMALL_STATE(133)] = 4690,
+  [SMALL_STATE(134)] = 4716,
+  [SMALL_STATE(135)] = 4742,
+  [SMALL_STATE(136)] = 4768,
+  [SMALL_STATE(137)] = 4794,
+  [SMALL_STATE(138)] = 4820,
+  [SMALL_STATE(139)] = 4846,
+  [SMALL_STATE(140)] = 4872,
+  [SMALL_STATE(141)] = 4898,
+  [SMALL_STATE(142)] = 4924,
+  [SMALL_STATE(143)] = 4950,
+  [SMALL_STATE(144)] = 4976,
+  [SMALL_STATE(145)] = 5002,
+  [SMALL_STATE(146)] = 5028,
+  [SMALL_STATE(147)] = 5054,
+  [SMALL_STATE(148)] = 5080,
+  [SMALL_STATE(149)] = 5106,
+  [SMALL_STATE(150)] = 5132,
+  [SMALL_STATE(151)] = 5158,
+  [SMALL_STATE(152)] = 5184,
+  [SMALL_STATE(153)] = 5210,
+  [SMALL_STATE(154)] = 5236,
+  [SMALL_STATE(155)] = 5262,
+  [SMALL_STATE(156)] = 5288,
+  [SMALL_STATE(157)] = 5314,
+  [SMALL_STATE(158)] = 5340,
+  [SMALL_STATE(159)] = 5366,
+  [SMALL_STATE(160)] = 5392,
+  [SMALL_STATE(161)] = 5418,
+  [SMALL_STATE(162)] = 5444,
+  [SMALL_STATE(163)] = 5470,
+  [SMALL_STATE(164)] = 5496,
+  [SMALL_STATE(165)] = 5522,
+  [SMALL_STATE(166)] = 5548,
+  [SMALL_STATE(167)] = 5574,
+  [SMALL_STATE(168)] = 5600,
+  [SMALL_STATE(169)] = 5626,
+  [SMALL_STATE(170)] = 5652,
+  [SMALL_STATE(171)] = 5678,
+  [SMALL_STATE(172)] = 5704,
+  [SMALL_STATE(173)] = 5730,
+  [SMALL_STATE(174)] = 5755,
+  [SMALL_STATE(175)] = 5780,
+  [SMALL_STATE(176)] = 5805,
+  [SMALL_STATE(177)] = 5830,
+  [SMALL_STATE(178)] = 5855,
+  [SMALL_STATE(179)] = 5880,
+  [SMALL_STATE(180)] = 5905,
+  [SMALL_STATE(181)] = 5943,
+  [SMALL_STATE(182)] = 5965,
+  [SMALL_STATE(183)] = 5998,
+  [SMALL_STATE(184)] = 6039,
+  [SMALL_STATE(185)] = 6074,
+  [SMALL_STATE(186)] = 6107,
+  [SMALL_STATE(187)] = 6133,
+  [SMALL_STATE(188)] = 6159,
+  [SMALL_STATE(189)] = 6195,
+  [SMALL_STATE(190)] = 6231,
+  [SMALL_STATE(191)] = 6263,
+  [SMALL_STATE(192)] = 6299,
+  [SMALL_STATE(193)] = 6331,
+  [SMALL_STATE(194)] = 6363,
+  [SMALL_STATE(195)] = 6387,
+  [SMALL_STATE(196)] = 6420,
+  [SMALL_STATE(197)] = 6449,
+  [SMALL_STATE(198)] = 6482,
+  [SMALL_STATE(199)] = 6515,
+  [SMALL_STATE(200)] = 6544,
+  [SMALL_STATE(201)] = 6577,
+  [SMALL_STATE(202)] = 6606,
+  [SMALL_STATE(203)] = 6625,
+  [SMALL_STATE(204)] = 6652,
+  [SMALL_STATE(205)] = 6681,
+  [SMALL_STATE(206)] = 6714,
+  [SMALL_STATE(207)] = 6733,
+  [SMALL_STATE(208)] = 6766,
+  [SMALL_STATE(209)] = 6793,
+  [SMALL_STATE(210)] = 6826,
+  [SMALL_STATE(211)] = 6847,
+  [SMALL_STATE(212)] = 6873,
+  [SMALL_STATE(213)] = 6899,
+  [SMALL_STATE(214)] = 6917,
+  [SMALL_STATE(215)] = 6935,
+  [SMALL_STATE(216)] = 6965,
+  [SMALL_STATE(217)] = 6991,
+  [SMALL_STATE(218)] = 7021,
+  [SMALL_STATE(219)] = 7039,
+  [SMALL_STATE(220)] = 7065,
+  [SMALL_STATE(221)] = 7091,
+  [SMALL_STATE(222)] = 7109,
+  [SMALL_STATE(223)] = 7127,
+  [SMALL_STATE(224)] = 7153,
+  [SMALL_STATE(225)] = 7169,
+  [SMALL_STATE(226)] = 7187,
+  [SMALL_STATE(227)] = 7217,
+  [SMALL_STATE(228)] = 7242,
+  [SMALL_STATE(229)] = 7267,
+  [SMALL_STATE(230)] = 7292,
+  [SMALL_STATE(231)] = 7317,
+  [SMALL_STATE(232)] = 7342,
+  [SMALL_STATE(233)] = 7367,
+  [SMALL_STATE(234)] = 7392,
+  [SMALL_STATE(235)] = 7417,
+  [SMALL_STATE(236)] = 7446,
+  [SMALL_STATE(237)] = 7475,
+  [SMALL_STATE(238)] = 7504,
+  [SMALL_STATE(239)] = 7529,
+  [SMALL_STATE(240)] = 7552,
+  [SMALL_STATE(241)] = 7578,
+  [SMALL_STATE(242)] = 7600,
+  [SMALL_STATE(243)] = 7622,
+  [SMALL_STATE(244)] = 7644,
+  [SMALL_STATE(245)] = 7666,
+  [SMALL_STATE(246)] = 7688,
+  [SMALL_STATE(247)] = 7704,
+  [SMALL_STATE(248)] = 7721,
+  [SMALL_STATE(249)] = 7742,
+  [SMALL_STATE(250)] = 7765,
+  [SMALL_STATE(251)] = 7780,
+  [SMALL_STATE(252)] = 7797,
+  [SMALL_STATE(253)] = 7818,
+  [SMALL_STATE(254)] = 7833,
+  [SMALL_STATE(255)] = 7852,
+  [SMALL_STATE(256)] = 7869,
+  [SMALL_STATE(257)] = 7890,
+  [SMALL_STATE(258)] = 7903,
+  [SMALL_STATE(259)] = 7924,
+  [SMALL_STATE(260)] = 7943,
+  [SMALL_STATE(261)] = 7958,
+  [SMALL_STATE(262)] = 7973,
+  [SMALL_STATE(263)] = 7988,
+  [SMALL_STATE(264)] = 8011,
+  [SMALL_STATE(265)] = 8024,
+  [SMALL_STATE(266)] = 8037,
+  [SMALL_STATE(267)] = 8050,
+  [SMALL_STATE(268)] = 8073,
+  [SMALL_STATE(269)] = 8086,
+  [SMALL_STATE(270)] = 8099,
+  [SMALL_STATE(271)] = 8122,
+  [SMALL_STATE(272)] = 8135,
+  [SMALL_STATE(273)] = 8148,
+  [SMALL_STATE(274)] = 8167,
+  [SMALL_STATE(275)] = 8180,
+  [SMALL_STATE(276)] = 8193,
+  [SMALL_STATE(277)] = 8212,
+  [SMALL_STATE(278)] = 8233,
+  [SMALL_STATE(279)] = 8254,
+  [SMALL_STATE(280)] = 8275,
+  [SMALL_STATE(281)] = 8296,
+  [SMALL_STATE(282)] = 8313,
+  [SMALL_STATE(283)] = 8334,
+  [SMALL_STATE(284)] = 8357,
+  [SMALL_STATE(285)] = 8369,
+  [SMALL_STATE(286)] = 8383,
+  [SMALL_STATE(287)] = 8397,
+  [SMALL_STATE(288)] = 8411,
+  [SMALL_STATE(289)] = 8427,
+  [SMALL_STATE(290)] = 8445,
+  [SMALL_STATE(291)] = 8459,
+  [SMALL_STATE(292)] = 8473,
+  [SMALL_STATE(293)] = 8489,
+  [SMALL_STATE(294)] = 8507,
+  [SMALL_STATE(295)] = 8527,
+  [SMALL_STATE(296)] = 8545,
+  [SMALL_STATE(297)] = 8559,
+  [SMALL_STATE(298)] = 8579,
+  [SMALL_STATE(299)] = 8591,
+  [SMALL_STATE(300)] = 8605,
+  [SMALL_STATE(301)] = 8623,
+  [SMALL_STATE(302)] = 8637,
+  [SMALL_STATE(303)] = 8651,
+  [SMALL_STATE(304)] = 8671,
+  [SMALL_STATE(305)] = 8685,
+  [SMALL_STATE(306)] = 8701,
+  [SMALL_STATE(307)] = 8719,
+  [SMALL_STATE(308)] = 8739,
+  [SMALL_STATE(309)] = 8759,
+  [SMALL_STATE(310)] = 8777,
+  [SMALL_STATE(311)] = 8791,
+  [SMALL_STATE(312)] = 8809,
+  [SMALL_STATE(313)] = 8820,
+  [SMALL_STATE(314)] = 8835,
+  [SMALL_STATE(315)] = 8846,
+  [SMALL_STATE(316)] = 8861,
+  [SMALL_STATE(317)] = 8872,
+  [SMALL_STATE(318)] = 8883,
+  [SMALL_STATE(319)] = 8894,
+  [SMALL_STATE(320)] = 8905,
+  [SMALL_STATE(321)] = 8922,
+  [SMALL_STATE(322)] = 8933,
+  [SMALL_STATE(323)] = 8944,
+  [SMALL_STATE(324)] = 8955,
+  [SMALL_STATE(325)] = 8966,
+  [SMALL_STATE(326)] = 8983,
+  [SMALL_STATE(327)] = 8994,
+  [SMALL_STATE(328)] = 9005,
+  [SMALL_STATE(329)] = 9016,
+  [SMALL_STATE(330)] = 9033,
+  [SMALL_STATE(331)] = 9048,
+  [SMALL_STATE(332)] = 9063,
+  [SMALL_STATE(333)] = 9074,
+  [SMALL_STATE(334)] = 9085,
+  [SMALL_STATE(335)] = 9096,
+  [SMALL_STATE(336)] = 9111,
+  [SMALL_STATE(337)] = 9128,
+  [SMALL_STATE(338)] = 9143,
+  [SMALL_STATE(339)] = 9154,
+  [SMALL_STATE(340)] = 9169,
+  [SMALL_STATE(341)] = 9180,
+  [SMALL_STATE(342)] = 9195,
+  [SMALL_STATE(343)] = 9206,
+  [SMALL_STATE(344)] = 9217,
+  [SMALL_STATE(345)] = 9229,
+  [SMALL_STATE(346)] = 9241,
+  [SMALL_STATE(347)] = 9253,
+  [SMALL_STATE(348)] = 9265,
+  [SMALL_STATE(349)] = 9277,
+  [SMALL_STATE(350)] = 9287,
+  [SMALL_STATE(351)] = 9299,
+  [SMALL_STATE(352)] = 9313,
+  [SMALL_STATE(353)] = 9325,
+  [SMALL_STATE(354)] = 9337,
+  [SMALL_STATE(355)] = 9349,
+  [SMALL_STATE(356)] = 9361,
+  [SMALL_STATE(357)] = 9375,
+  [SMALL_STATE(358)] = 9387,
+  [SMALL_STATE(359)] = 9399,
+  [SMALL_STATE(360)] = 9411,
+  [SMALL_STATE(361)] = 9421,
+  [SMALL_STATE(362)] = 9433,
+  [SMALL_STATE(363)] = 9445,
+  [SMALL_STATE(364)] = 9457,
+  [SMALL_STATE(365)] = 9467,
+  [SMALL_STATE(366)] = 9477,
+  [SMALL_STATE(367)] = 9489,
+  [SMALL_STATE(368)] = 9499,
+  [SMALL_STATE(369)] = 9509,
+  [SMALL_STATE(370)] = 9521,
+  [SMALL_STATE(371)] = 9531,
+  [SMALL_STATE(372)] = 9543,
+  [SMALL_STATE(373)] = 9555,
+  [SMALL_STATE(374)] = 9567,
+  [SMALL_STATE(375)] = 9579,
+  [SMALL_STATE(376)] = 9591,
+  [SMALL_STATE(377)] = 9601,
+  [SMALL_STATE(378)] = 9613,
+  [SMALL_STATE(379)] = 9625,
+  [SMALL_STATE(380)] = 9635,
+  [SMALL_STATE(381)] = 9647,
+  [SMALL_STATE(382)] = 9659,
+  [SMALL_STATE(383)] = 9671,
+  [SMALL_STATE(384)] = 9683,
+  [SMALL_STATE(385)] = 9692,
+  [SMALL_STATE(386)] = 9703,
+  [SMALL_STATE(387)] = 9714,
+  [SMALL_STATE(388)] = 9725,
+  [SMALL_STATE(389)] = 9736,
+  [SMALL_STATE(390)] = 9745,
+  [SMALL_STATE(391)] = 9754,
+  [SMALL_STATE(392)] = 9765,
+  [SMALL_STATE(393)] = 9776,
+  [SMALL_STATE(394)] = 9785,
+  [SMALL_STATE(395)] = 9794,
+  [SMALL_STATE(396)] = 9805,
+  [SMALL_STATE(397)] = 9816,
+  [SMALL_STATE(398)] = 9827,
+  [SMALL_STATE(399)] = 9836,
+  [SMALL_STATE(400)] = 9845,
+  [SMALL_STATE(401)] = 9854,
+  [SMALL_STATE(402)] = 9865,
+  [SMALL_STATE(403)] = 9876,
+  [SMALL_STATE(404)] = 9887,
+  [SMALL_STATE(405)] = 9895,
+  [SMALL_STATE(406)] = 9903,
+  [SMALL_STATE(407)] = 9911,
+  [SMALL_STATE(408)] = 9919,
+  [SMALL_STATE(409)] = 9927,
+  [SMALL_STATE(410)] = 9935,
+  [SMALL_STATE(411)] = 9943,
+  [SMALL_STATE(412)] = 9951,
+  [SMALL_STATE(413)] = 9959,
+  [SMALL_STATE(414)] = 9967,
+  [SMALL_STATE(415)] = 9975,
+  [SMALL_STATE(416)] = 9983,
+  [SMALL_STATE(417)] = 9991,
+  [SMALL_STATE(418)] = 9999,
+  [SMALL_STATE(419)] = 10007,
+  [SMALL_STATE(420)] = 10015,
+  [SMALL_STATE(421)] = 10023,
+  [SMALL_STATE(422)] = 10031,
+  [SMALL_STATE(423)] = 10039,
+  [SMALL_STATE(424)] = 10047,
+  [SMALL_STATE(425)] = 10055,
+  [SMALL_STATE(426)] = 10063,
+  [SMALL_STATE(427)] = 10071,
+  [SMALL_STATE(428)] = 10079,
+  [SMALL_STATE(429)] = 10087,
+  [SMALL_STATE(430)] = 10095,
+  [SMALL_STATE(431)] = 10103,
+  [SMALL_STATE(432)] = 10111,
+  [SMALL_STATE(433)] = 10119,
+  [SMALL_STATE(434)] = 10127,
+};
+
+static const TSParseActionEntry ts_parse_actions[] = {
+  [0] = {.entry = {.count = 0, .reusable = false}},
+  [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
+  [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT_EXTRA(),
+  [5] = {.entry = {.count = 1, .reusable = true}}, SHIFT(307),
+  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(201),
+  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(240),
+  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(374),
+  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(345),
+  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(348),
+  [17] = {.entry = {.count = 1, .reusable = true}}, SHIFT(350),
+  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(352),
+  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(353),
+  [23] = {.entry = {.count = 1, .reusable = true}}, SHIFT(257),
+  [25] = {.entry = {.count = 1, .reusable = true}}, SHIFT(407),
+  [27] = {.entry = {.count = 1, .reusable = false}}, SHIFT(415),
+  [29] = {.entry = {.count = 1, .reusable = true}}, SHIFT(315),
+  [31] = {.entry = {.count = 1, .reusable = true}}, SHIFT(427),
+  [33] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 12),
+  [35] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 12), SHIFT_REPEAT(307),
+  [38] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 12), SHIFT_REPEAT(201),
+  [41] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 12), SHIFT_REPEAT(240),
+  [44] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 12), SHIFT_REPEAT(374),
+  [47] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 12), SHIFT_REPEAT(345),
+  [50] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 12), SHIFT_REPEAT(348),
+  [53] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 12), SHIFT_REPEAT(350),
+  [56] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 12), SHIFT_REPEAT(352),
+  [59] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 12), SHIFT_REPEAT(353),
+  [62] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 12), SHIFT_REPEAT(257),
+  [65] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 12), SHIFT_REPEAT(407),
+  [68] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_document_repeat1, 2, 0, 12), SHIFT_REPEAT(415),
+  [71] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 12), SHIFT_REPEAT(315),
+  [74] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 12), SHIFT_REPEAT(427),
+  [77] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_document, 1, 0, 5),
+  [79] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_name, 1, 0, 0),
+  [81] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_name, 1, 0, 0),
+  [83] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_type_definition, 2, 0, 10),
+  [85] = {.entry = {.count = 1, .reusable = true}}, SHIFT(215),
+  [87] = {.entry = {.count = 1, .reusable = true}}, SHIFT(300),
+  [89] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface_type_definition, 2, 0, 10),
+  [91] = {.entry = {.count = 1, .reusable = true}}, SHIFT(377),
+  [93] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_type_definition, 2, 0, 10),
+  [95] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object_type_definition, 2, 0, 10),
+  [97] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_type_extension, 3, 0, 22),
+  [99] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object_type_extension, 3, 0, 22),
+  [101] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_type_extension, 3, 0, 22),
+  [103] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface_type_extension, 3, 0, 22),
+  [105] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string_value, 3, 0, 0),
+  [107] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string_value, 3, 0, 0),
+  [109] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_type_definition, 3, 0, 32),
+  [111] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object_type_definition, 3, 0, 32),
+  [113] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_type_definition, 3, 0, 32),
+  [115] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface_type_definition, 3, 0, 32),
+  [117] = {.entry = {.count = 1, .reusable = true}}, SHIFT(36),
+  [119] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
+  [121] = {.entry = {.count = 1, .reusable = false}}, SHIFT(103),
+  [123] = {.entry = {.count = 1, .reusable = true}}, SHIFT(67),
+  [125] = {.entry = {.count = 1, .reusable = false}}, SHIFT(67),
+  [127] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_type_extension, 4, 0, 47),
+  [129] = {.entry = {.count = 1, .reusable = true}}, SHIFT(331),
+  [131] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object_type_extension, 4, 0, 47),
+  [133] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_type_definition, 3, 0, 24),
+  [135] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object_type_definition, 3, 0, 24),
+  [137] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_input_object_type_extension, 3, 0, 22),
+  [139] = {.entry = {.count = 1, .reusable = true}}, SHIFT(226),
+  [141] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_input_object_type_extension, 3, 0, 22),
+  [143] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_named_type, 1, 0, 0),
+  [145] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_named_type, 1, 0, 0),
+  [147] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_type_definition, 4, 0, 69),
+  [149] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface_type_definition, 4, 0, 69),
+  [151] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_type_extension, 4, 0, 47),
+  [153] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface_type_extension, 4, 0, 47),
+  [155] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_union_type_extension, 3, 0, 22),
+  [157] = {.entry = {.count = 1, .reusable = true}}, SHIFT(306),
+  [159] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_union_type_extension, 3, 0, 22),
+  [161] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_type_definition, 4, 0, 69),
+  [163] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object_type_definition, 4, 0, 69),
+  [165] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_type_definition, 3, 0, 24),
+  [167] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface_type_definition, 3, 0, 24),
+  [169] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_union_type_definition, 2, 0, 10),
+  [171] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_union_type_definition, 2, 0, 10),
+  [173] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_union_type_definition, 3, 0, 32),
+  [175] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_union_type_definition, 3, 0, 32),
+  [177] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_type_definition, 2, 0, 10),
+  [179] = {.entry = {.count = 1, .reusable = true}}, SHIFT(207),
+  [181] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum_type_definition, 2, 0, 10),
+  [183] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_type_extension, 3, 0, 22),
+  [185] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum_type_extension, 3, 0, 22),
+  [187] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_input_object_type_definition, 3, 0, 32),
+  [189] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_input_object_type_definition, 3, 0, 32),
+  [191] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_type_definition, 3, 0, 32),
+  [193] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum_type_definition, 3, 0, 32),
+  [195] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_input_object_type_definition, 2, 0, 10),
+  [197] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_input_object_type_definition, 2, 0, 10),
+  [199] = {.entry = {.count = 1, .reusable = false}}, SHIFT(4),
+  [201] = {.entry = {.count = 1, .reusable = true}}, SHIFT(252),
+  [203] = {.entry = {.count = 1, .reusable = true}}, SHIFT(372),
+  [205] = {.entry = {.count = 1, .reusable = false}}, SHIFT(173),
+  [207] = {.entry = {.count = 1, .reusable = true}}, SHIFT(173),
+  [209] = {.entry = {.count = 1, .reusable = false}}, SHIFT(175),
+  [211] = {.entry = {.count = 1, .reusable = true}}, SHIFT(38),
+  [213] = {.entry = {.count = 1, .reusable = true}}, SHIFT(176),
+  [215] = {.entry = {.count = 1, .reusable = true}}, SHIFT(4),
+  [217] = {.entry = {.count = 1, .reusable = true}}, SHIFT(275),
+  [219] = {.entry = {.count = 1, .reusable = true}}, SHIFT(177),
+  [221] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_list_value_repeat1, 2, 0, 100), SHIFT_REPEAT(4),
+  [224] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_list_value_repeat1, 2, 0, 100), SHIFT_REPEAT(252),
+  [227] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_list_value_repeat1, 2, 0, 100), SHIFT_REPEAT(372),
+  [230] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_list_value_repeat1, 2, 0, 100), SHIFT_REPEAT(407),
+  [233] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_list_value_repeat1, 2, 0, 100), SHIFT_REPEAT(415),
+  [236] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_list_value_repeat1, 2, 0, 100), SHIFT_REPEAT(173),
+  [239] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_list_value_repeat1, 2, 0, 100), SHIFT_REPEAT(173),
+  [242] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_list_value_repeat1, 2, 0, 100), SHIFT_REPEAT(175),
+  [245] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_list_value_repeat1, 2, 0, 100), SHIFT_REPEAT(38),
+  [248] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_list_value_repeat1, 2, 0, 100),
+  [250] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_list_value_repeat1, 2, 0, 100), SHIFT_REPEAT(4),
+  [253] = {.entry = {.count = 1, .reusable = true}}, SHIFT(272),
+  [255] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_scalar_type_definition, 2, 0, 10),
+  [257] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_scalar_type_definition, 2, 0, 10),
+  [259] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_directives, 1, 0, 7),
+  [261] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_directives, 1, 0, 7),
+  [263] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_scalar_type_definition, 3, 0, 32),
+  [265] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_scalar_type_definition, 3, 0, 32),
+  [267] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_directive, 2, 0, 10),
+  [269] = {.entry = {.count = 1, .reusable = true}}, SHIFT(309),
+  [271] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_directive, 2, 0, 10),
+  [273] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_directives_repeat1, 2, 0, 14),
+  [275] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_directives_repeat1, 2, 0, 14),
+  [277] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_directives_repeat1, 2, 0, 14), SHIFT_REPEAT(377),
+  [280] = {.entry = {.count = 1, .reusable = false}}, SHIFT(224),
+  [282] = {.entry = {.count = 1, .reusable = true}}, SHIFT(279),
+  [284] = {.entry = {.count = 1, .reusable = true}}, SHIFT(369),
+  [286] = {.entry = {.count = 1, .reusable = true}}, SHIFT(430),
+  [288] = {.entry = {.count = 1, .reusable = false}}, SHIFT(431),
+  [290] = {.entry = {.count = 1, .reusable = false}}, SHIFT(268),
+  [292] = {.entry = {.count = 1, .reusable = true}}, SHIFT(268),
+  [294] = {.entry = {.count = 1, .reusable = false}}, SHIFT(269),
+  [296] = {.entry = {.count = 1, .reusable = true}}, SHIFT(43),
+  [298] = {.entry = {.count = 1, .reusable = true}}, SHIFT(224),
+  [300] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arguments, 3, 0, 79),
+  [302] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arguments, 3, 0, 79),
+  [304] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_directive, 3, 0, 35),
+  [306] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_directive, 3, 0, 35),
+  [308] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_input_object_type_extension, 5, 0, 88),
+  [310] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_input_object_type_extension, 5, 0, 88),
+  [312] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_input_object_type_extension_repeat1, 2, 0, 89),
+  [314] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_input_object_type_extension_repeat1, 2, 0, 89), SHIFT_REPEAT(226),
+  [317] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_input_object_type_extension_repeat1, 2, 0, 89),
+  [319] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_implements_interfaces, 3, 0, 92),
+  [321] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_implements_interfaces, 3, 0, 92),
+  [323] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_implements_interfaces, 3, 0, 93),
+  [325] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_implements_interfaces, 3, 0, 93),
+  [327] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_union_type_definition, 3, 0, 23),
+  [329] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_union_type_definition, 3, 0, 23),
+  [331] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_union_type_extension, 4, 0, 46),
+  [333] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_union_type_extension, 4, 0, 46),
+  [335] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_input_object_type_extension, 4, 0, 46),
+  [337] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_input_object_type_extension, 4, 0, 46),
+  [339] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_input_object_type_extension, 4, 0, 52),
+  [341] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_input_object_type_extension, 4, 0, 52),
+  [343] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_implements_interfaces, 2, 0, 54),
+  [345] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_implements_interfaces, 2, 0, 54),
+  [347] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_directives_repeat1, 1, 0, 6),
+  [349] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_directives_repeat1, 1, 0, 6),
+  [351] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_union_type_definition, 4, 0, 68),
+  [353] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_union_type_definition, 4, 0, 68),
+  [355] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_type_extension, 4, 0, 46),
+  [357] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum_type_extension, 4, 0, 46),
+  [359] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_system_directive_location, 1, 0, 0),
+  [361] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_system_directive_location, 1, 0, 0),
+  [363] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_directive_definition, 5, 0, 102),
+  [365] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
+  [367] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_directive_definition, 5, 0, 102),
+  [369] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_directive_locations, 1, 0, 103),
+  [371] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_directive_locations, 1, 0, 103),
+  [373] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_directive_location, 1, 0, 0),
+  [375] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_directive_location, 1, 0, 0),
+  [377] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_type_extension, 4, 0, 46),
+  [379] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface_type_extension, 4, 0, 46),
+  [381] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_union_type_extension, 4, 0, 49),
+  [383] = {.entry = {.count = 1, .reusable = true}}, SHIFT(337),
+  [385] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_union_type_extension, 4, 0, 49),
+  [387] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_type_definition, 5, 0, 109),
+  [389] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object_type_definition, 5, 0, 109),
+  [391] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_type_definition, 5, 0, 109),
+  [393] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface_type_definition, 5, 0, 109),
+  [395] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_union_type_definition, 5, 0, 111),
+  [397] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_union_type_definition, 5, 0, 111),
+  [399] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_type_extension, 4, 0, 46),
+  [401] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object_type_extension, 4, 0, 46),
+  [403] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_type_definition, 3, 0, 23),
+  [405] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface_type_definition, 3, 0, 23),
+  [407] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_directive_locations, 2, 0, 119),
+  [409] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_directive_locations, 2, 0, 119),
+  [411] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_union_type_extension, 5, 0, 86),
+  [413] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_union_type_extension, 5, 0, 86),
+  [415] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_directive_definition, 6, 0, 120),
+  [417] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_directive_definition, 6, 0, 120),
+  [419] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_directive_definition, 6, 0, 121),
+  [421] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_directive_definition, 6, 0, 121),
+  [423] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_union_type_definition, 3, 0, 26),
+  [425] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_union_type_definition, 3, 0, 26),
+  [427] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_union_member_types, 3, 0, 95),
+  [429] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_union_member_types, 3, 0, 95),
+  [431] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_directive_definition, 6, 0, 126),
+  [433] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_directive_definition, 6, 0, 126),
+  [435] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_type_definition, 4, 0, 56),
+  [437] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object_type_definition, 4, 0, 56),
+  [439] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_type_definition, 4, 0, 56),
+  [441] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface_type_definition, 4, 0, 56),
+  [443] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_union_member_types, 2, 0, 58),
+  [445] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_union_member_types, 2, 0, 58),
+  [447] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_directive_locations, 3, 0, 131),
+  [449] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_directive_locations, 3, 0, 131),
+  [451] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_directive_definition, 7, 0, 132),
+  [453] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_directive_definition, 7, 0, 132),
+  [455] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_directive_definition, 7, 0, 135),
+  [457] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_directive_definition, 7, 0, 135),
+  [459] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_directive_definition, 7, 0, 136),
+  [461] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_directive_definition, 7, 0, 136),
+  [463] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_union_type_definition, 4, 0, 59),
+  [465] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_union_type_definition, 4, 0, 59),
+  [467] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_type_definition, 3, 0, 23),
+  [469] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum_type_definition, 3, 0, 23),
+  [471] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_input_object_type_definition, 3, 0, 23),
+  [473] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_input_object_type_definition, 3, 0, 23),
+  [475] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_directive_definition, 8, 0, 142),
+  [477] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_directive_definition, 8, 0, 142),
+  [479] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_type_definition, 4, 0, 68),
+  [481] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object_type_definition, 4, 0, 68),
+  [483] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_type_definition, 3, 0, 23),
+  [485] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object_type_definition, 3, 0, 23),
+  [487] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_type_definition, 4, 0, 68),
+  [489] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface_type_definition, 4, 0, 68),
+  [491] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_union_member_types, 3, 0, 96),
+  [493] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_union_member_types, 3, 0, 96),
+  [495] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_union_type_definition, 4, 0, 71),
+  [497] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_union_type_definition, 4, 0, 71),
+  [499] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_type_extension, 5, 0, 84),
+  [501] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface_type_extension, 5, 0, 84),
+  [503] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_type_definition, 4, 0, 68),
+  [505] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum_type_definition, 4, 0, 68),
+  [507] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_executable_directive_location, 1, 0, 0),
+  [509] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_executable_directive_location, 1, 0, 0),
+  [511] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_input_object_type_definition, 4, 0, 68),
+  [513] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_input_object_type_definition, 4, 0, 68),
+  [515] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_type_extension, 5, 0, 84),
+  [517] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object_type_extension, 5, 0, 84),
+  [519] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fields_definition, 3, 0, 90),
+  [521] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fields_definition, 3, 0, 90),
+  [523] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_type_extension, 4, 0, 50),
+  [525] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum_type_extension, 4, 0, 50),
+  [527] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_type_definition, 4, 0, 55),
+  [529] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object_type_definition, 4, 0, 55),
+  [531] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_type_definition, 4, 0, 57),
+  [533] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object_type_definition, 4, 0, 57),
+  [535] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_type_definition, 4, 0, 55),
+  [537] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface_type_definition, 4, 0, 55),
+  [539] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_type_definition, 4, 0, 57),
+  [541] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface_type_definition, 4, 0, 57),
+  [543] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_type_definition, 4, 0, 61),
+  [545] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum_type_definition, 4, 0, 61),
+  [547] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_input_object_type_definition, 4, 0, 57),
+  [549] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_input_object_type_definition, 4, 0, 57),
+  [551] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fragment_definition, 4, 0, 62),
+  [553] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fragment_definition, 4, 0, 62),
+  [555] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variable, 2, 0, 10),
+  [557] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable, 2, 0, 10),
+  [559] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_operation_definition, 4, 0, 65),
+  [561] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_operation_definition, 4, 0, 65),
+  [563] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_operation_definition, 4, 0, 66),
+  [565] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_operation_definition, 4, 0, 66),
+  [567] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_operation_definition, 4, 0, 67),
+  [569] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_operation_definition, 4, 0, 67),
+  [571] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_scalar_type_definition, 4, 0, 68),
+  [573] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_scalar_type_definition, 4, 0, 68),
+  [575] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_selection_set, 3, 0, 20),
+  [577] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_selection_set, 3, 0, 20),
+  [579] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_type_definition, 4, 0, 70),
+  [581] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object_type_definition, 4, 0, 70),
+  [583] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_type_definition, 4, 0, 70),
+  [585] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface_type_definition, 4, 0, 70),
+  [587] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_definition, 1, 0, 2),
+  [589] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_definition, 1, 0, 2),
+  [591] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_operation_definition, 1, 0, 4),
+  [593] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_operation_definition, 1, 0, 4),
+  [595] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_type_definition, 4, 0, 72),
+  [597] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum_type_definition, 4, 0, 72),
+  [599] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_input_object_type_definition, 4, 0, 70),
+  [601] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_input_object_type_definition, 4, 0, 70),
+  [603] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_schema_definition, 5, 0, 74),
+  [605] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_schema_definition, 5, 0, 74),
+  [607] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_scalar_type_definition, 3, 0, 23),
+  [609] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_scalar_type_definition, 3, 0, 23),
+  [611] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_schema_extension, 5, 0, 82),
+  [613] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_schema_extension, 5, 0, 82),
+  [615] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_type_extension, 5, 0, 83),
+  [617] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object_type_extension, 5, 0, 83),
+  [619] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_type_extension, 5, 0, 85),
+  [621] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object_type_extension, 5, 0, 85),
+  [623] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_type_extension, 5, 0, 83),
+  [625] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface_type_extension, 5, 0, 83),
+  [627] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_type_extension, 5, 0, 85),
+  [629] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface_type_extension, 5, 0, 85),
+  [631] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_type_definition, 3, 0, 25),
+  [633] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object_type_definition, 3, 0, 25),
+  [635] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_type_extension, 5, 0, 87),
+  [637] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum_type_extension, 5, 0, 87),
+  [639] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_type_definition, 3, 0, 25),
+  [641] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface_type_definition, 3, 0, 25),
+  [643] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_type_definition, 5, 0, 94),
+  [645] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object_type_definition, 5, 0, 94),
+  [647] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_type_definition, 5, 0, 94),
+  [649] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface_type_definition, 5, 0, 94),
+  [651] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_values_definition, 3, 0, 99),
+  [653] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum_values_definition, 3, 0, 99),
+  [655] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_input_fields_definition, 3, 0, 90),
+  [657] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_input_fields_definition, 3, 0, 90),
+  [659] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fragment_definition, 5, 0, 101),
+  [661] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fragment_definition, 5, 0, 101),
+  [663] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_type_definition, 3, 0, 27),
+  [665] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum_type_definition, 3, 0, 27),
+  [667] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_input_object_type_definition, 3, 0, 25),
+  [669] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_input_object_type_definition, 3, 0, 25),
+  [671] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_input_object_type_extension_repeat1, 1, 0, 51),
+  [673] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_input_object_type_extension_repeat1, 1, 0, 51),
+  [675] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_schema_definition, 5, 0, 107),
+  [677] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_schema_definition, 5, 0, 107),
+  [679] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_type_definition, 5, 0, 108),
+  [681] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object_type_definition, 5, 0, 108),
+  [683] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_type_definition, 5, 0, 110),
+  [685] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object_type_definition, 5, 0, 110),
+  [687] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_type_definition, 5, 0, 108),
+  [689] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface_type_definition, 5, 0, 108),
+  [691] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_operation_definition, 3, 0, 29),
+  [693] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_operation_definition, 3, 0, 29),
+  [695] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_type_definition, 5, 0, 110),
+  [697] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface_type_definition, 5, 0, 110),
+  [699] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_operation_definition, 3, 0, 30),
+  [701] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_operation_definition, 3, 0, 30),
+  [703] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_type_definition, 5, 0, 112),
+  [705] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum_type_definition, 5, 0, 112),
+  [707] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_input_object_type_definition, 5, 0, 110),
+  [709] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_input_object_type_definition, 5, 0, 110),
+  [711] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_operation_definition, 3, 0, 31),
+  [713] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_operation_definition, 3, 0, 31),
+  [715] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_schema_extension, 6, 0, 115),
+  [717] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_schema_extension, 6, 0, 115),
+  [719] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_type_extension, 6, 0, 116),
+  [721] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object_type_extension, 6, 0, 116),
+  [723] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_type_extension, 6, 0, 116),
+  [725] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface_type_extension, 6, 0, 116),
+  [727] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 1, 0, 2),
+  [729] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_document_repeat1, 1, 0, 2),
+  [731] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_definition, 1, 0, 2),
+  [733] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_definition, 1, 0, 2),
+  [735] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_system_definition, 1, 0, 2),
+  [737] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_system_definition, 1, 0, 2),
+  [739] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_system_extension, 1, 0, 3),
+  [741] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_system_extension, 1, 0, 3),
+  [743] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_schema_definition, 6, 0, 124),
+  [745] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_schema_definition, 6, 0, 124),
+  [747] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_type_definition, 6, 0, 125),
+  [749] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object_type_definition, 6, 0, 125),
+  [751] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_type_definition, 6, 0, 125),
+  [753] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface_type_definition, 6, 0, 125),
+  [755] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_extension, 1, 0, 3),
+  [757] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type_extension, 1, 0, 3),
+  [759] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_schema_definition, 4, 0, 33),
+  [761] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_schema_definition, 4, 0, 33),
+  [763] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_scalar_type_extension, 4, 0, 46),
+  [765] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_scalar_type_extension, 4, 0, 46),
+  [767] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_type_extension, 4, 0, 48),
+  [769] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object_type_extension, 4, 0, 48),
+  [771] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_operation_definition, 2, 0, 11),
+  [773] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_operation_definition, 2, 0, 11),
+  [775] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interface_type_extension, 4, 0, 48),
+  [777] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interface_type_extension, 4, 0, 48),
+  [779] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_executable_definition, 1, 0, 2),
+  [781] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_executable_definition, 1, 0, 2),
+  [783] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_operation_definition, 5, 0, 106),
+  [785] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_operation_definition, 5, 0, 106),
+  [787] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_value, 1, 0, 60),
+  [789] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_value, 1, 0, 60),
+  [791] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object_value, 2, 0, 0),
+  [793] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_value, 2, 0, 0),
+  [795] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_boolean_value, 1, 0, 0),
+  [797] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_boolean_value, 1, 0, 0),
+  [799] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_list_value, 2, 0, 0),
+  [801] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list_value, 2, 0, 0),
+  [803] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_list_value, 3, 0, 99),
+  [805] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list_value, 3, 0, 99),
+  [807] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object_value, 3, 0, 90),
+  [809] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_value, 3, 0, 90),
+  [811] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum_value, 1, 0, 0),
+  [813] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_value, 1, 0, 0),
+  [815] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field, 1, 0, 9),
+  [817] = {.entry = {.count = 1, .reusable = true}}, SHIFT(196),
+  [819] = {.entry = {.count = 1, .reusable = true}}, SHIFT(384),
+  [821] = {.entry = {.count = 1, .reusable = true}}, SHIFT(295),
+  [823] = {.entry = {.count = 1, .reusable = true}}, SHIFT(346),
+  [825] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_list_value_repeat1, 1, 0, 60),
+  [827] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_list_value_repeat1, 1, 0, 60),
+  [829] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_input_value_definition, 4, 0, 129),
+  [831] = {.entry = {.count = 1, .reusable = true}}, SHIFT(52),
+  [833] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_input_value_definition, 4, 0, 129),
+  [835] = {.entry = {.count = 1, .reusable = false}}, SHIFT(341),
+  [837] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field, 2, 0, 16),
+  [839] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_input_value_definition, 3, 0, 117),
+  [841] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_input_value_definition, 3, 0, 117),
+  [843] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_directives_repeat1, 2, 0, 14), SHIFT_REPEAT(346),
+  [846] = {.entry = {.count = 1, .reusable = true}}, SHIFT(329),
+  [848] = {.entry = {.count = 1, .reusable = true}}, SHIFT(139),
+  [850] = {.entry = {.count = 1, .reusable = true}}, SHIFT(120),
+  [852] = {.entry = {.count = 1, .reusable = true}}, SHIFT(183),
+  [854] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_enum_values_definition_repeat1, 2, 0, 100), SHIFT_REPEAT(4),
+  [857] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_enum_values_definition_repeat1, 2, 0, 100),
+  [859] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_enum_values_definition_repeat1, 2, 0, 100), SHIFT_REPEAT(407),
+  [862] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_enum_values_definition_repeat1, 2, 0, 100), SHIFT_REPEAT(415),
+  [865] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_selection_set_repeat1, 2, 0, 21), SHIFT_REPEAT(4),
+  [868] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_selection_set_repeat1, 2, 0, 21),
+  [870] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_selection_set_repeat1, 2, 0, 21), SHIFT_REPEAT(183),
+  [873] = {.entry = {.count = 1, .reusable = true}}, SHIFT(334),
+  [875] = {.entry = {.count = 1, .reusable = true}}, SHIFT(140),
+  [877] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_fields_definition_repeat1, 2, 0, 91), SHIFT_REPEAT(4),
+  [880] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_fields_definition_repeat1, 2, 0, 91),
+  [882] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_fields_definition_repeat1, 2, 0, 91), SHIFT_REPEAT(407),
+  [885] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fields_definition_repeat1, 2, 0, 91), SHIFT_REPEAT(415),
+  [888] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_arguments_definition_repeat1, 2, 0, 80), SHIFT_REPEAT(4),
+  [891] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_arguments_definition_repeat1, 2, 0, 80),
+  [893] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_arguments_definition_repeat1, 2, 0, 80), SHIFT_REPEAT(407),
+  [896] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_arguments_definition_repeat1, 2, 0, 80), SHIFT_REPEAT(415),
+  [899] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field, 2, 0, 18),
+  [901] = {.entry = {.count = 1, .reusable = true}}, SHIFT(106),
+  [903] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list_type, 3, 0, 36),
+  [905] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_list_type, 3, 0, 36),
+  [907] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_input_value_definition, 4, 0, 130),
+  [909] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_input_value_definition, 4, 0, 130),
+  [911] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field, 3, 0, 40),
+  [913] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_input_fields_definition_repeat1, 2, 0, 91), SHIFT_REPEAT(4),
+  [916] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_input_fields_definition_repeat1, 2, 0, 91),
+  [918] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_input_fields_definition_repeat1, 2, 0, 91), SHIFT_REPEAT(407),
+  [921] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_input_fields_definition_repeat1, 2, 0, 91), SHIFT_REPEAT(415),
+  [924] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_description, 1, 0, 0),
+  [926] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_description, 1, 0, 0),
+  [928] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_input_value_definition, 5, 0, 141),
+  [930] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_input_value_definition, 5, 0, 141),
+  [932] = {.entry = {.count = 1, .reusable = true}}, SHIFT(367),
+  [934] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type, 1, 0, 105),
+  [936] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type, 1, 0, 105),
+  [938] = {.entry = {.count = 1, .reusable = true}}, SHIFT(222),
+  [940] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_value_definition, 1, 0, 60),
+  [942] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum_value_definition, 1, 0, 60),
+  [944] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field_definition, 3, 0, 117),
+  [946] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_field_definition, 3, 0, 117),
+  [948] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_value_definition, 2, 0, 98),
+  [950] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum_value_definition, 2, 0, 98),
+  [952] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field_definition, 4, 0, 128),
+  [954] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_field_definition, 4, 0, 128),
+  [956] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field_definition, 5, 0, 139),
+  [958] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_field_definition, 5, 0, 139),
+  [960] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_non_null_type, 2, 0, 105),
+  [962] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_non_null_type, 2, 0, 105),
+  [964] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field_definition, 4, 0, 129),
+  [966] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_field_definition, 4, 0, 129),
+  [968] = {.entry = {.count = 1, .reusable = true}}, SHIFT(227),
+  [970] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable_definition, 3, 0, 104),
+  [972] = {.entry = {.count = 1, .reusable = true}}, SHIFT(50),
+  [974] = {.entry = {.count = 1, .reusable = true}}, SHIFT(380),
+  [976] = {.entry = {.count = 1, .reusable = true}}, SHIFT(394),
+  [978] = {.entry = {.count = 1, .reusable = true}}, SHIFT(297),
+  [980] = {.entry = {.count = 1, .reusable = true}}, SHIFT(354),
+  [982] = {.entry = {.count = 1, .reusable = true}}, SHIFT(355),
+  [984] = {.entry = {.count = 1, .reusable = true}}, SHIFT(358),
+  [986] = {.entry = {.count = 1, .reusable = true}}, SHIFT(383),
+  [988] = {.entry = {.count = 1, .reusable = true}}, SHIFT(361),
+  [990] = {.entry = {.count = 1, .reusable = true}}, SHIFT(363),
+  [992] = {.entry = {.count = 1, .reusable = true}}, SHIFT(434),
+  [994] = {.entry = {.count = 1, .reusable = true}}, SHIFT(228),
+  [996] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fragment_spread, 2, 0, 10),
+  [998] = {.entry = {.count = 1, .reusable = true}}, SHIFT(303),
+  [1000] = {.entry = {.count = 1, .reusable = true}}, SHIFT(378),
+  [1002] = {.entry = {.count = 1, .reusable = true}}, SHIFT(381),
+  [1004] = {.entry = {.count = 1, .reusable = true}}, SHIFT(382),
+  [1006] = {.entry = {.count = 1, .reusable = true}}, SHIFT(359),
+  [1008] = {.entry = {.count = 1, .reusable = true}}, SHIFT(366),
+  [1010] = {.entry = {.count = 1, .reusable = true}}, SHIFT(371),
+  [1012] = {.entry = {.count = 1, .reusable = true}}, SHIFT(145),
+  [1014] = {.entry = {.count = 1, .reusable = true}}, SHIFT(162),
+  [1016] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_schema_definition_repeat1, 2, 0, 34),
+  [1018] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_schema_definition_repeat1, 2, 0, 34), SHIFT_REPEAT(257),
+  [1021] = {.entry = {.count = 1, .reusable = true}}, SHIFT(127),
+  [1023] = {.entry = {.count = 1, .reusable = true}}, SHIFT(166),
+  [1025] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_default_value, 2, 0, 133),
+  [1027] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_default_value, 2, 0, 133),
+  [1029] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field, 3, 0, 44),
+  [1031] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_arguments_repeat1, 2, 0, 80), SHIFT_REPEAT(4),
+  [1034] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_arguments_repeat1, 2, 0, 80),
+  [1036] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_input_value_definition, 4, 0, 127),
+  [1038] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_input_value_definition, 4, 0, 127),
+  [1040] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field, 2, 0, 19),
+  [1042] = {.entry = {.count = 1, .reusable = true}}, SHIFT(174),
+  [1044] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fragment_name, 1, 0, 0),
+  [1046] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fragment_name, 1, 0, 0),
+  [1048] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field, 3, 0, 41),
+  [1050] = {.entry = {.count = 1, .reusable = true}}, SHIFT(214),
+  [1052] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_operation_type, 1, 0, 0),
+  [1054] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_object_value_repeat1, 2, 0, 91), SHIFT_REPEAT(4),
+  [1057] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_object_value_repeat1, 2, 0, 91),
+  [1059] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_input_value_definition, 5, 0, 140),
+  [1061] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_input_value_definition, 5, 0, 140),
+  [1063] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_input_value_definition, 5, 0, 138),
+  [1065] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_input_value_definition, 5, 0, 138),
+  [1067] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_input_value_definition, 6, 0, 144),
+  [1069] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_input_value_definition, 6, 0, 144),
+  [1071] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable_definition, 4, 0, 122),
+  [1073] = {.entry = {.count = 1, .reusable = true}}, SHIFT(390),
+  [1075] = {.entry = {.count = 1, .reusable = true}}, SHIFT(178),
+  [1077] = {.entry = {.count = 1, .reusable = true}}, SHIFT(53),
+  [1079] = {.entry = {.count = 1, .reusable = true}}, SHIFT(271),
+  [1081] = {.entry = {.count = 1, .reusable = true}}, SHIFT(274),
+  [1083] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field, 4, 0, 77),
+  [1085] = {.entry = {.count = 1, .reusable = true}}, SHIFT(376),
+  [1087] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_arguments_definition_repeat1, 1, 0, 42),
+  [1089] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_arguments_definition_repeat1, 1, 0, 42),
+  [1091] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field_definition, 6, 0, 143),
+  [1093] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_field_definition, 6, 0, 143),
+  [1095] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_fields_definition_repeat1, 1, 0, 53),
+  [1097] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_fields_definition_repeat1, 1, 0, 53),
+  [1099] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_value_definition, 2, 0, 97),
+  [1101] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum_value_definition, 2, 0, 97),
+  [1103] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field_definition, 5, 0, 137),
+  [1105] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_field_definition, 5, 0, 137),
+  [1107] = {.entry = {.count = 1, .reusable = true}}, SHIFT(311),
+  [1109] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_directives_repeat1, 2, 0, 14), SHIFT_REPEAT(380),
+  [1112] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_variable_definitions_repeat1, 2, 0, 64),
+  [1114] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_variable_definitions_repeat1, 2, 0, 64), SHIFT_REPEAT(372),
+  [1117] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field_definition, 5, 0, 138),
+  [1119] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_field_definition, 5, 0, 138),
+  [1121] = {.entry = {.count = 1, .reusable = true}}, SHIFT(254),
+  [1123] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_enum_values_definition_repeat1, 1, 0, 60),
+  [1125] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_enum_values_definition_repeat1, 1, 0, 60),
+  [1127] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
+  [1129] = {.entry = {.count = 1, .reusable = true}}, SHIFT(330),
+  [1131] = {.entry = {.count = 1, .reusable = true}}, SHIFT(322),
+  [1133] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_input_fields_definition_repeat1, 1, 0, 53),
+  [1135] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_input_fields_definition_repeat1, 1, 0, 53),
+  [1137] = {.entry = {.count = 1, .reusable = true}}, SHIFT(305),
+  [1139] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field_definition, 4, 0, 127),
+  [1141] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_field_definition, 4, 0, 127),
+  [1143] = {.entry = {.count = 1, .reusable = true}}, SHIFT(335),
+  [1145] = {.entry = {.count = 1, .reusable = true}}, SHIFT(273),
+  [1147] = {.entry = {.count = 1, .reusable = true}}, SHIFT(400),
+  [1149] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_value_definition, 3, 0, 118),
+  [1151] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum_value_definition, 3, 0, 118),
+  [1153] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field, 4, 0, 78),
+  [1155] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field, 2, 0, 17),
+  [1157] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fragment_spread, 3, 0, 23),
+  [1159] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_inline_fragment, 3, 0, 37),
+  [1161] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_inline_fragment, 3, 0, 38),
+  [1163] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field, 3, 0, 39),
+  [1165] = {.entry = {.count = 1, .reusable = true}}, SHIFT(217),
+  [1167] = {.entry = {.count = 1, .reusable = true}}, SHIFT(15),
+  [1169] = {.entry = {.count = 1, .reusable = true}}, SHIFT(423),
+  [1171] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_root_operation_type_definition, 3, 0, 73),
+  [1173] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_inline_fragment, 4, 0, 75),
+  [1175] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field, 4, 0, 76),
+  [1177] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field, 3, 0, 43),
+  [1179] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field, 3, 0, 45),
+  [1181] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field, 4, 0, 81),
+  [1183] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_selection_set_repeat1, 1, 0, 8),
+  [1185] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_schema_definition_repeat1, 1, 0, 13),
+  [1187] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
+  [1189] = {.entry = {.count = 1, .reusable = true}}, SHIFT(413),
+  [1191] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_selection, 1, 0, 8),
+  [1193] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_inline_fragment, 2, 0, 15),
+  [1195] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field, 5, 0, 113),
+  [1197] = {.entry = {.count = 1, .reusable = true}}, SHIFT(231),
+  [1199] = {.entry = {.count = 1, .reusable = true}}, SHIFT(234),
+  [1201] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument, 3, 0, 114),
+  [1203] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_field, 3, 0, 114),
+  [1205] = {.entry = {.count = 1, .reusable = true}}, SHIFT(364),
+  [1207] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object_field, 4, 0, 114),
+  [1209] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arguments_definition, 3, 0, 79),
+  [1211] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_arguments_repeat1, 1, 0, 42),
+  [1213] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_object_value_repeat1, 1, 0, 53),
+  [1215] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_alias, 2, 0, 9),
+  [1217] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable_definition, 4, 0, 123),
+  [1219] = {.entry = {.count = 1, .reusable = true}}, SHIFT(399),
+  [1221] = {.entry = {.count = 1, .reusable = true}}, SHIFT(341),
+  [1223] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_variable_definitions_repeat1, 1, 0, 28),
+  [1225] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable_definition, 5, 0, 122),
+  [1227] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable_definition, 5, 0, 134),
+  [1229] = {.entry = {.count = 1, .reusable = true}}, SHIFT(398),
+  [1231] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_condition, 2, 0, 36),
+  [1233] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable_definition, 4, 0, 104),
+  [1235] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
+  [1237] = {.entry = {.count = 1, .reusable = true}}, SHIFT(421),
+  [1239] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable_definition, 6, 0, 134),
+  [1241] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable_definition, 5, 0, 123),
+  [1243] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable_definitions, 3, 0, 63),
+  [1245] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
+  [1247] = {.entry = {.count = 1, .reusable = true}}, SHIFT(408),
+  [1249] = {.entry = {.count = 1, .reusable = true}}, SHIFT(229),
+  [1251] = {.entry = {.count = 1, .reusable = true}}, SHIFT(9),
+  [1253] = {.entry = {.count = 1, .reusable = true}}, SHIFT(288),
+  [1255] = {.entry = {.count = 1, .reusable = false}}, SHIFT(428),
+  [1257] = {.entry = {.count = 1, .reusable = false}}, SHIFT_EXTRA(),
+  [1259] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
+  [1261] = {.entry = {.count = 1, .reusable = true}}, SHIFT(276),
+  [1263] = {.entry = {.count = 1, .reusable = true}}, SHIFT(238),
+  [1265] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [1267] = {.entry = {.count = 1, .reusable = true}}, SHIFT(259),
+  [1269] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
+  [1271] = {.entry = {.count = 1, .reusable = true}}, SHIFT(49),
+  [1273] = {.entry = {.count = 1, .reusable = false}}, SHIFT(405),
+  [1275] = {.entry = {.count = 1, .reusable = true}}, SHIFT(233),
+  [1277] = {.entry = {.count = 1, .reusable = true}}, SHIFT(155),
+  [1279] = {.entry = {.count = 1, .reusable = true}}, SHIFT(129),
+  [1281] = {.entry = {.count = 1, .reusable = true}}, SHIFT(264),
+  [1283] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
+  [1285] = {.entry = {.count = 1, .reusable = true}}, SHIFT(284),
+  [1287] = {.entry = {.count = 1, .reusable = true}}, SHIFT(17),
+  [1289] = {.entry = {.count = 1, .reusable = true}}, SHIFT(202),
+  [1291] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1, 0, 1),
+  [1293] = {.entry = {.count = 1, .reusable = true}}, SHIFT(232),
+  [1295] = {.entry = {.count = 1, .reusable = true}}, SHIFT(347),
+  [1297] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
+  [1299] = {.entry = {.count = 1, .reusable = false}}, SHIFT(419),
+  [1301] = {.entry = {.count = 1, .reusable = false}}, SHIFT(420),
+  [1303] = {.entry = {.count = 1, .reusable = true}}, SHIFT(230),
+  [1305] = {.entry = {.count = 1, .reusable = true}}, SHIFT(313),
+  [1307] = {.entry = {.count = 1, .reusable = true}}, SHIFT(344),
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+#ifdef TREE_SITTER_HIDE_SYMBOLS
+#define TS_PUBLIC
+#elif defined(_WIN32)
+#define TS_PUBLIC __declspec(dllexport)
+#else
+#define TS_PUBLIC __attribute__((visibility("default")))
+#endif
+
+TS_PUBLIC const TSLanguage *tree_sitter_graphql(void) {
+  static const TSLanguage language = {
+    .version = LANGUAGE_VERSION,
+    .symbol_count = SYMBOL_COUNT,
+    .alias_count = ALIAS_COUNT,
+    .token_count = TOKEN_COUNT,
+    .external_token_count = EXTERNAL_TOKEN_COUNT,
+    .state_count = STATE_COUNT,
+    .large_state_count = LARGE_STATE_COUNT,
+    .production_id_count = PRODUCTION_ID_COUNT,
+    .field_count = FIELD_COUNT,
+    .max_alias_sequence_length = MAX_ALIAS_SEQUENCE_LENGTH,
+    .parse_table = &ts_parse_table[0][0],
+    .small_parse_table = ts_small_parse_table,
+    .small_parse_table_map = ts_small_parse_table_map,
+    .parse_actions = ts_parse_actions,
+    .symbol_names = ts_symbol_names,
+    .field_names = ts_field_names,
+    .field_map_slices = ts_field_map_slices,
+    .field_map_entries = ts_field_map_entries,
+    .symbol_metadata = ts_symbol_metadata,
+    .public_symbol_map = ts_symbol_map,
+    .alias_map = ts_non_terminal_alias_map,
+    .alias_sequences = &ts_alias_sequences[0][0],
+    .lex_modes = ts_lex_modes,
+    .lex_fn = ts_lex,
+    .keyword_lex_fn = ts_lex_keywords,
+    .keyword_capture_token = sym__name,
+    .primary_state_ids = ts_primary_state_ids,
+  };
+  return &language;
+}
+#ifdef __cplusplus
+}
+#endif
@@ -0,0 +1,288 @@
+/**
+ * @file LESS grammar for tree-sitter
+ * @author jimliang <769925821@qq.com>
+ * @license MIT
+ */
+
+/// <reference types="tree-sitter-cli/dsl" />
+// @ts-check
+
+// Extends the CSS metavariable grammar rather than upstream tree-sitter-css, so
+// that selectors, declarations and values keep the same node kinds and fields
+// as in CSS and CSS snippets can be reused against Less files.
+const CSS = require('../tree-sitter-css/grammar');
+
+module.exports = grammar(CSS, {
+  name: 'less',
+
+  externals: ($, original) => original.concat([
+    $._pseudo_class_selector_colon,
+    $.__error_recovery,
+    $._concat,
+  ]),
+
+  conflicts: ($, original) => original.concat([
+    [$.id_selector, $._mixin_name],
+    [$.arguments, $.parameter],
+  ]),
+
+  rules: {
+    _top_level_item: ($, original) => choice(
+      original,
+      $.plugin_statement,
+      $.mixin_definition,
+      $.each_statement,
+    ),
+
+    _block_item: ($, original) => choice(
+      original,
+      $.plugin_statement,
+      $.mixin_statement,
+      $.mixin_definition,
+      $.extend_statement,
+      $.each_statement,
+    ),
+
+    // Selectors
+
+    _selector: ($, original) => choice(
+      original,
+      alias($._concatenated_identifier, $.tag_name),
+    ),
+
+    class_selector: $ => prec(1, seq(
+      optional(field('selector', $._selector)),
+      choice('.', $.nesting_selector),
+      field('class', alias(choice($.identifier, $._concatenated_identifier), $.class_name)),
+    )),
+
+    // Declarations
+
+    declaration: $ => prec(1, seq(
+      field('name', alias(
+        choice($.identifier, $.variable, $._concatenated_identifier, $.at_keyword),
+        $.property_name,
+      )),
+      optional(field('merge', $.merge_identifier)),
+      ':',
+      field('values', $._value),
+      repeat(seq(optional(','), field('values', $._value))),
+      optional(field('important', $.important)),
+      ';',
+    )),
+
+    last_declaration: $ => prec(1, seq(
+      field('name', alias(
+        choice($.identifier, $.variable, $._concatenated_identifier, $.at_keyword),
+        $.property_name,
+      )),
+      optional(field('merge', $.merge_identifier)),
+      ':',
+      field('values', $._value),
+      repeat(seq(optional(','), field('values', $._value))),
+      optional(field('important', $.important)),
+    )),
+
+    mixin_definition: $ => seq(
+      $._mixin_name,
+      field('parameters', $.parameters),
+      optional(field('guard', $.when_condition)),
+      field('body', $.block),
+    ),
+
+    // Media queries
+
+    _query: ($, original) => choice(
+      original,
+      prec(-1, choice($.interpolation, $.variable)),
+    ),
+
+    // Property Values
+
+    _value: ($, original) => choice(
+      original,
+      $.value_value,
+      $.property_value,
+      $.escaped_value,
+      prec(-1, choice(
+        $.nesting_selector,
+        $._concatenated_identifier,
+        $.list_value,
+      )),
+      $.variable,
+    ),
+
+    parameters: $ => seq(
+      token.immediate('('),
+      sep(choice(',', ';'), field('parameter', choice($.parameter, $.rest_parameter))),
+      ')',
+    ),
+
+    parameter: $ => seq(
+      field('name', $._value),
+      optional(seq(':', field('default', $._value))),
+    ),
+
+    rest_parameter: $ => seq(optional(field('name', $.variable)), '...'),
+
+    each_statement: $ => seq(
+      'each',
+      '(',
+      field('collection', $._value),
+      ',',
+      field('body', $.block),
+      ')',
+      ';',
+    ),
+
+    mixin_statement: $ => seq(
+      field('name', alias($._mixin_name, $.function_name)),
+      optional(field('arguments', $.arguments)),
+      optional(field('important', $.important)),
+      ';',
+    ),
+
+    extend_statement: $ => seq(
+      field('selector', $._selector),
+      ':extend',
+      field('arguments', alias($._extend_arguments, $.arguments)),
+      ';',
+    ),
+
+    plugin_statement: $ => seq('@plugin', field('value', $._value), ';'),
+
+    import_statement: $ => seq(
+      '@import',
+      optional(field('options', $._value)),
+      field('value', $._value),
+      sep(',', field('from', $._query)),
+      ';',
+    ),
+
+    call_expression: $ => seq(
+      field('name', alias(choice($.identifier, $.plain_value), $.function_name)),
+      field('arguments', $.arguments),
+    ),
+
+    binary_expression: $ => prec.left(seq(
+      field('left', $._value),
+      field('operator', choice(
+        $.plus,
+        $.minus,
+        $.times,
+        $.divide,
+        alias('==', $.equal),
+        alias('!=', $.not_equal),
+        alias('<', $.less_than),
+        alias('>', $.greater_than),
+        alias('<=', $.less_than_or_equal),
+        alias('>=', $.greater_than_or_equal),
+      )),
+      field('right', $._value),
+    )),
+
+    when_condition: $ => seq(
+      'when',
+      '(',
+      field('condition', $._value),
+      ')',
+    ),
+
+    list_value: $ => seq(
+      '(',
+      sep2(',', field('values', $._value)),
+      ')',
+    ),
+
+    interpolation: $ => seq('@{', field('value', $._value), '}'),
+
+    _concatenated_identifier: $ => choice(
+      seq(
+        $.identifier,
+        repeat1(seq(
+          $._concat,
+          choice($.interpolation, $.identifier, alias(token.immediate('-'), $.identifier)),
+        )),
+      ),
+      seq(
+        $.interpolation,
+        repeat(seq(
+          $._concat,
+          choice($.interpolation, $.identifier, alias(token.immediate('-'), $.identifier)),
+        )),
+      ),
+    ),
+
+    _extend_arguments: $ => seq(
+      '(',
+      field('selector', $._selector),
+      ')',
+    ),
+
+    _mixin_name: $ => seq(
+      optional(seq('#', field('namespace', alias($.identifier, $.id_name)))),
+      optional('>'),
+      '.',
+      field('class', choice(alias($.identifier, $.class_name), $.grit_metavariable)),
+    ),
+
+    property_value: $ => choice(
+      seq('$', field('name', alias($.identifier, $.property_name))),
+      seq('${', field('name', alias($.identifier, $.property_name)), '}'),
+    ),
+
+    escaped_value: $ => seq('~', field('value', $.string_value)),
+
+    value_value: $ => seq('@@', field('name', alias($.identifier, $.property_name))),
+
+    variable: _ => /([a-zA-Z_]+\.)?@[a-zA-Z-_][a-zA-Z0-9-_]*/,
+
+    merge_identifier: _ => choice('+', '+_'),
+
+    comment: _ => token(choice(
+      seq('//', /.*/),
+      seq('/*', /[^*]*\*+([^/*][^*]*\*+)*/, '/'),
+    )),
+  },
+});
+
+/**
+ * Creates a rule to optionally match one or more of the rules separated by `separator`
+ *
+ * @param {RuleOrLiteral} separator
+ *
+ * @param {RuleOrLiteral} rule
+ *
+ * @return {ChoiceRule}
+ *
+ */
+function sep(separator, rule) {
+  return optional(sep1(separator, rule));
+}
+
+/**
+ * Creates a rule to match one or more of the rules separated by `separator`
+ *
+ * @param {RuleOrLiteral} separator
+ *
+ * @param {RuleOrLiteral} rule
+ *
+ * @return {SeqRule}
+ *
+ */
+function sep1(separator, rule) {
+  return seq(rule, repeat(seq(separator, rule)));
+}
+
+/**
+ * Creates a rule to match two or more of the rules separated by `separator`
+ *
+ * @param {RuleOrLiteral} separator
+ *
+ * @param {RuleOrLiteral} rules
+ *
+ * @return {SeqRule}
+ */
+function sep2(separator, rules) {
+  return seq(rules, repeat1(seq(separator, rules)));
+}
@@ -0,0 +1,33 @@
+[
+  "@import"
+  "@namespace"
+  "@charset"
+] @keyword
+
+(js_comment) @comment @spell
+
+(function_name) @function
+
+[
+  ">="
+  "<="
+] @operator
+
+(plain_value) @string
+
+(keyword_query) @function
+
+(identifier) @variable
+
+(variable) @variable
+
+(arguments
+  (variable) @variable.parameter)
+
+[
+  "["
+  "]"
+] @punctuation.bracket
+
+(import_statement
+  (identifier) @function)
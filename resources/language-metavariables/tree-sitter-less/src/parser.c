@@ -0,0 +1,25621 @@
+#include "tree_sitter/parser.h"
+
+#if defined(__GNUC__) || defined(__clang__)
+#pragma GCC diagnostic ignored "-Wmissing-field-initializers"
+#endif
+
+#ifdef _MSC_VER
+#pragma optimize("", off)
+#elif defined(__clang__)
+#pragma clang optimize off
+#elif defined(__GNUC__)
+#pragma GCC optimize ("O0")
+#endif
+
+#define LANGUAGE_VERSION 14
+#define STATE_COUNT 681
+#define LARGE_STATE_COUNT 2
+#define SYMBOL_COUNT 149
+#define ALIAS_COUNT 11
+#define TOKEN_COUNT 73
+#define EXTERNAL_TOKEN_COUNT 4
+#define FIELD_COUNT 44
+#define MAX_ALIAS_SEQUENCE_LENGTH 7
+#define PRODUCTION_ID_COUNT 104
+
+enum ts_symbol_identifiers {
+  anon_sym_ATimport = 1,
+  anon_sym_COMMA = 2,
+  anon_sym_SEMI = 3,
+  anon_sym_ATmedia = 4,
+  anon_sym_ATcharset = 5,
+  anon_sym_ATnamespace = 6,
+  anon_sym_ATkeyframes = 7,
+  aux_sym_keyframes_statement_token1 = 8,
+  anon_sym_LBRACE = 9,
+  anon_sym_RBRACE = 10,
+  sym_from = 11,
+  sym_to = 12,
+  anon_sym_ATsupports = 13,
+  sym_nesting_selector = 14,
+  anon_sym_STAR = 15,
+  anon_sym_DOT = 16,
+  anon_sym_COLON = 17,
+  anon_sym_COLON_COLON = 18,
+  anon_sym_POUND = 19,
+  sym_equal = 20,
+  sym_contains_word_equal = 21,
+  sym_starts_with_equal = 22,
+  sym_dash_equal = 23,
+  sym_contains_equal = 24,
+  sym_ends_equal = 25,
+  anon_sym_LBRACK = 26,
+  anon_sym_RBRACK = 27,
+  anon_sym_GT = 28,
+  anon_sym_TILDE = 29,
+  anon_sym_PLUS = 30,
+  anon_sym_LPAREN = 31,
+  anon_sym_RPAREN = 32,
+  sym_important = 33,
+  anon_sym_LPAREN2 = 34,
+  sym_and = 35,
+  sym_or = 36,
+  sym_not = 37,
+  sym_only = 38,
+  anon_sym_selector = 39,
+  aux_sym_color_value_token1 = 40,
+  sym_string_value = 41,
+  aux_sym_integer_value_token1 = 42,
+  aux_sym_float_value_token1 = 43,
+  sym_unit = 44,
+  sym_minus = 45,
+  sym_divide = 46,
+  anon_sym_EQ_EQ = 47,
+  anon_sym_BANG_EQ = 48,
+  anon_sym_LT = 49,
+  anon_sym_LT_EQ = 50,
+  anon_sym_GT_EQ = 51,
+  sym_identifier = 52,
+  sym_at_keyword = 53,
+  sym_comment = 54,
+  sym_plain_value = 55,
+  sym_grit_metavariable = 56,
+  anon_sym_DOT_DOT_DOT = 57,
+  anon_sym_each = 58,
+  anon_sym_COLONextend = 59,
+  anon_sym_ATplugin = 60,
+  anon_sym_when = 61,
+  anon_sym_AT_LBRACE = 62,
+  anon_sym_DASH = 63,
+  anon_sym_DOLLAR = 64,
+  anon_sym_DOLLAR_LBRACE = 65,
+  anon_sym_AT_AT = 66,
+  sym_variable = 67,
+  anon_sym_PLUS_ = 68,
+  sym__descendant_operator = 69,
+  sym__pseudo_class_selector_colon = 70,
+  sym___error_recovery = 71,
+  sym__concat = 72,
+  sym_stylesheet = 73,
+  sym_import_statement = 74,
+  sym_media_statement = 75,
+  sym_charset_statement = 76,
+  sym_namespace_statement = 77,
+  sym_keyframes_statement = 78,
+  sym_keyframe_block_list = 79,
+  sym_keyframe_block = 80,
+  sym_supports_statement = 81,
+  sym_at_rule = 82,
+  sym_rule_set = 83,
+  sym_selectors = 84,
+  sym_block = 85,
+  sym__selector = 86,
+  sym_universal_selector = 87,
+  sym_class_selector = 88,
+  sym_pseudo_class_selector = 89,
+  sym_pseudo_element_selector = 90,
+  sym_id_selector = 91,
+  sym_attribute_selector = 92,
+  sym_child_selector = 93,
+  sym_descendant_selector = 94,
+  sym_sibling_selector = 95,
+  sym_adjacent_sibling_selector = 96,
+  sym_pseudo_class_arguments = 97,
+  sym_pseudo_element_arguments = 98,
+  sym_declaration = 99,
+  sym_last_declaration = 100,
+  sym__query = 101,
+  sym_feature_query = 102,
+  sym_parenthesized_query = 103,
+  sym_binary_query = 104,
+  sym_unary_query = 105,
+  sym_selector_query = 106,
+  sym__value = 107,
+  sym_parenthesized_value = 108,
+  sym_color_value = 109,
+  sym_integer_value = 110,
+  sym_float_value = 111,
+  sym_call_expression = 112,
+  sym_plus = 113,
+  sym_times = 114,
+  sym_binary_expression = 115,
+  sym_arguments = 116,
+  sym_mixin_definition = 117,
+  sym_parameters = 118,
+  sym_parameter = 119,
+  sym_rest_parameter = 120,
+  sym_each_statement = 121,
+  sym_mixin_statement = 122,
+  sym_extend_statement = 123,
+  sym_plugin_statement = 124,
+  sym_when_condition = 125,
+  sym_list_value = 126,
+  sym_interpolation = 127,
+  sym__concatenated_identifier = 128,
+  sym__extend_arguments = 129,
+  sym__mixin_name = 130,
+  sym_property_value = 131,
+  sym_escaped_value = 132,
+  sym_value_value = 133,
+  sym_merge_identifier = 134,
+  aux_sym_stylesheet_repeat1 = 135,
+  aux_sym_import_statement_repeat1 = 136,
+  aux_sym_media_statement_repeat1 = 137,
+  aux_sym_keyframe_block_list_repeat1 = 138,
+  aux_sym_at_rule_repeat1 = 139,
+  aux_sym_selectors_repeat1 = 140,
+  aux_sym_block_repeat1 = 141,
+  aux_sym_pseudo_class_arguments_repeat1 = 142,
+  aux_sym_pseudo_class_arguments_repeat2 = 143,
+  aux_sym_declaration_repeat1 = 144,
+  aux_sym_arguments_repeat1 = 145,
+  aux_sym_parameters_repeat1 = 146,
+  aux_sym_list_value_repeat1 = 147,
+  aux_sym__concatenated_identifier_repeat1 = 148,
+  alias_sym_attribute_name = 149,
+  alias_sym_class_name = 150,
+  alias_sym_feature_name = 151,
+  alias_sym_function_name = 152,
+  alias_sym_greater_than = 153,
+  alias_sym_id_name = 154,
+  alias_sym_keyframes_name = 155,
+  alias_sym_keyword_query = 156,
+  alias_sym_namespace_name = 157,
+  alias_sym_property_name = 158,
+  alias_sym_tag_name = 159,
+};
+
+static const char * const ts_symbol_names[] = {
+  [ts_builtin_sym_end] = "end",
+  [anon_sym_ATimport] = "@import",
+  [anon_sym_COMMA] = ",",
+  [anon_sym_SEMI] = ";",
+  [anon_sym_ATmedia] = "@media",
+  [anon_sym_ATcharset] = "@charset",
+  [anon_sym_ATnamespace] = "@namespace",
+  [anon_sym_ATkeyframes] = "@keyframes",
+  [aux_sym_keyframes_statement_token1] = "at_keyword",
+  [anon_sym_LBRACE] = "{",
+  [anon_sym_RBRACE] = "}",
+  [sym_from] = "from",
+  [sym_to] = "to",
+  [anon_sym_ATsupports] = "@supports",
+  [sym_nesting_selector] = "nesting_selector",
+  [anon_sym_STAR] = "*",
+  [anon_sym_DOT] = ".",
+  [anon_sym_COLON] = ":",
+  [anon_sym_COLON_COLON] = "::",
+  [anon_sym_POUND] = "#",
+  [sym_equal] = "equal",
+  [sym_contains_word_equal] = "contains_word_equal",
+  [sym_starts_with_equal] = "starts_with_equal",
+  [sym_dash_equal] = "dash_equal",
+  [sym_contains_equal] = "contains_equal",
+  [sym_ends_equal] = "ends_equal",
+  [anon_sym_LBRACK] = "[",
+  [anon_sym_RBRACK] = "]",
+  [anon_sym_GT] = ">",
+  [anon_sym_TILDE] = "~",
+  [anon_sym_PLUS] = "+",
+  [anon_sym_LPAREN] = "(",
+  [anon_sym_RPAREN] = ")",
+  [sym_important] = "important",
+  [anon_sym_LPAREN2] = "(",
+  [sym_and] = "and",
+  [sym_or] = "or",
+  [sym_not] = "not",
+  [sym_only] = "only",
+  [anon_sym_selector] = "selector",
+  [aux_sym_color_value_token1] = "color_value_token1",
+  [sym_string_value] = "string_value",
+  [aux_sym_integer_value_token1] = "integer_value_token1",
+  [aux_sym_float_value_token1] = "float_value_token1",
+  [sym_unit] = "unit",
+  [sym_minus] = "minus",
+  [sym_divide] = "divide",
+  [anon_sym_EQ_EQ] = "equal",
+  [anon_sym_BANG_EQ] = "not_equal",
+  [anon_sym_LT] = "less_than",
+  [anon_sym_LT_EQ] = "less_than_or_equal",
+  [anon_sym_GT_EQ] = "greater_than_or_equal",
+  [sym_identifier] = "identifier",
+  [sym_at_keyword] = "at_keyword",
+  [sym_comment] = "comment",
+  [sym_plain_value] = "plain_value",
+  [sym_grit_metavariable] = "grit_metavariable",
+  [anon_sym_DOT_DOT_DOT] = "...",
+  [anon_sym_each] = "each",
+  [anon_sym_COLONextend] = ":extend",
+  [anon_sym_ATplugin] = "@plugin",
+  [anon_sym_when] = "when",
+  [anon_sym_AT_LBRACE] = "@{",
+  [anon_sym_DASH] = "identifier",
+  [anon_sym_DOLLAR] = "$",
+  [anon_sym_DOLLAR_LBRACE] = "${",
+  [anon_sym_AT_AT] = "@@",
+  [sym_variable] = "variable",
+  [anon_sym_PLUS_] = "+_",
+  [sym__descendant_operator] = "_descendant_operator",
+  [sym__pseudo_class_selector_colon] = "_pseudo_class_selector_colon",
+  [sym___error_recovery] = "__error_recovery",
+  [sym__concat] = "_concat",
+  [sym_stylesheet] = "stylesheet",
+  [sym_import_statement] = "import_statement",
+  [sym_media_statement] = "media_statement",
+  [sym_charset_statement] = "charset_statement",
+  [sym_namespace_statement] = "namespace_statement",
+  [sym_keyframes_statement] = "keyframes_statement",
+  [sym_keyframe_block_list] = "keyframe_block_list",
+  [sym_keyframe_block] = "keyframe_block",
+  [sym_supports_statement] = "supports_statement",
+  [sym_at_rule] = "at_rule",
+  [sym_rule_set] = "rule_set",
+  [sym_selectors] = "selectors",
+  [sym_block] = "block",
+  [sym__selector] = "_selector",
+  [sym_universal_selector] = "universal_selector",
+  [sym_class_selector] = "class_selector",
+  [sym_pseudo_class_selector] = "pseudo_class_selector",
+  [sym_pseudo_element_selector] = "pseudo_element_selector",
+  [sym_id_selector] = "id_selector",
+  [sym_attribute_selector] = "attribute_selector",
+  [sym_child_selector] = "child_selector",
+  [sym_descendant_selector] = "descendant_selector",
+  [sym_sibling_selector] = "sibling_selector",
+  [sym_adjacent_sibling_selector] = "adjacent_sibling_selector",
+  [sym_pseudo_class_arguments] = "arguments",
+  [sym_pseudo_element_arguments] = "arguments",
+  [sym_declaration] = "declaration",
+  [sym_last_declaration] = "declaration",
+  [sym__query] = "_query",
+  [sym_feature_query] = "feature_query",
+  [sym_parenthesized_query] = "parenthesized_query",
+  [sym_binary_query] = "binary_query",
+  [sym_unary_query] = "unary_query",
+  [sym_selector_query] = "selector_query",
+  [sym__value] = "_value",
+  [sym_parenthesized_value] = "parenthesized_value",
+  [sym_color_value] = "color_value",
+  [sym_integer_value] = "integer_value",
+  [sym_float_value] = "float_value",
+  [sym_call_expression] = "call_expression",
+  [sym_plus] = "plus",
+  [sym_times] = "times",
+  [sym_binary_expression] = "binary_expression",
+  [sym_arguments] = "arguments",
+  [sym_mixin_definition] = "mixin_definition",
+  [sym_parameters] = "parameters",
+  [sym_parameter] = "parameter",
+  [sym_rest_parameter] = "rest_parameter",
+  [sym_each_statement] = "each_statement",
+  [sym_mixin_statement] = "mixin_statement",
+  [sym_extend_statement] = "extend_statement",
+  [sym_plugin_statement] = "plugin_statement",
+  [sym_when_condition] = "when_condition",
+  [sym_list_value] = "list_value",
+  [sym_interpolation] = "interpolation",
+  [sym__concatenated_identifier] = "_concatenated_identifier",
+  [sym__extend_arguments] = "arguments",
+  [sym__mixin_name] = "_mixin_name",
+  [sym_property_value] = "property_value",
+  [sym_escaped_value] = "escaped_value",
+  [sym_value_value] = "value_value",
+  [sym_merge_identifier] = "merge_identifier",
+  [aux_sym_stylesheet_repeat1] = "stylesheet_repeat1",
+  [aux_sym_import_statement_repeat1] = "import_statement_repeat1",
+  [aux_sym_media_statement_repeat1] = "media_statement_repeat1",
+  [aux_sym_keyframe_block_list_repeat1] = "keyframe_block_list_repeat1",
+  [aux_sym_at_rule_repeat1] = "at_rule_repeat1",
+  [aux_sym_selectors_repeat1] = "selectors_repeat1",
+  [aux_sym_block_repeat1] = "block_repeat1",
+  [aux_sym_pseudo_class_arguments_repeat1] = "pseudo_class_arguments_repeat1",
+  [aux_sym_pseudo_class_arguments_repeat2] = "pseudo_class_arguments_repeat2",
+  [aux_sym_declaration_repeat1] = "declaration_repeat1",
+  [aux_sym_arguments_repeat1] = "arguments_repeat1",
+  [aux_sym_parameters_repeat1] = "parameters_repeat1",
+  [aux_sym_list_value_repeat1] = "list_value_repeat1",
+  [aux_sym__concatenated_identifier_repeat1] = "_concatenated_identifier_repeat1",
+  [alias_sym_attribute_name] = "attribute_name",
+  [alias_sym_class_name] = "class_name",
+  [alias_sym_feature_name] = "feature_name",
+  [alias_sym_function_name] = "function_name",
+  [alias_sym_greater_than] = "greater_than",
+  [alias_sym_id_name] = "id_name",
+  [alias_sym_keyframes_name] = "keyframes_name",
+  [alias_sym_keyword_query] = "keyword_query",
+  [alias_sym_namespace_name] = "namespace_name",
+  [alias_sym_property_name] = "property_name",
+  [alias_sym_tag_name] = "tag_name",
+};
+
+static const TSSymbol ts_symbol_map[] = {
+  [ts_builtin_sym_end] = ts_builtin_sym_end,
+  [anon_sym_ATimport] = anon_sym_ATimport,
+  [anon_sym_COMMA] = anon_sym_COMMA,
+  [anon_sym_SEMI] = anon_sym_SEMI,
+  [anon_sym_ATmedia] = anon_sym_ATmedia,
+  [anon_sym_ATcharset] = anon_sym_ATcharset,
+  [anon_sym_ATnamespace] = anon_sym_ATnamespace,
+  [anon_sym_ATkeyframes] = anon_sym_ATkeyframes,
+  [aux_sym_keyframes_statement_token1] = sym_at_keyword,
+  [anon_sym_LBRACE] = anon_sym_LBRACE,
+  [anon_sym_RBRACE] = anon_sym_RBRACE,
+  [sym_from] = sym_from,
+  [sym_to] = sym_to,
+  [anon_sym_ATsupports] = anon_sym_ATsupports,
+  [sym_nesting_selector] = sym_nesting_selector,
+  [anon_sym_STAR] = anon_sym_STAR,
+  [anon_sym_DOT] = anon_sym_DOT,
+  [anon_sym_COLON] = anon_sym_COLON,
+  [anon_sym_COLON_COLON] = anon_sym_COLON_COLON,
+  [anon_sym_POUND] = anon_sym_POUND,
+  [sym_equal] = sym_equal,
+  [sym_contains_word_equal] = sym_contains_word_equal,
+  [sym_starts_with_equal] = sym_starts_with_equal,
+  [sym_dash_equal] = sym_dash_equal,
+  [sym_contains_equal] = sym_contains_equal,
+  [sym_ends_equal] = sym_ends_equal,
+  [anon_sym_LBRACK] = anon_sym_LBRACK,
+  [anon_sym_RBRACK] = anon_sym_RBRACK,
+  [anon_sym_GT] = anon_sym_GT,
+  [anon_sym_TILDE] = anon_sym_TILDE,
+  [anon_sym_PLUS] = anon_sym_PLUS,
+  [anon_sym_LPAREN] = anon_sym_LPAREN,
+  [anon_sym_RPAREN] = anon_sym_RPAREN,
+  [sym_important] = sym_important,
+  [anon_sym_LPAREN2] = anon_sym_LPAREN,
+  [sym_and] = sym_and,
+  [sym_or] = sym_or,
+  [sym_not] = sym_not,
+  [sym_only] = sym_only,
+  [anon_sym_selector] = anon_sym_selector,
+  [aux_sym_color_value_token1] = aux_sym_color_value_token1,
+  [sym_string_value] = sym_string_value,
+  [aux_sym_integer_value_token1] = aux_sym_integer_value_token1,
+  [aux_sym_float_value_token1] = aux_sym_float_value_token1,
+  [sym_unit] = sym_unit,
+  [sym_minus] = sym_minus,
+  [sym_divide] = sym_divide,
+  [anon_sym_EQ_EQ] = sym_equal,
+  [anon_sym_BANG_EQ] = anon_sym_BANG_EQ,
+  [anon_sym_LT] = anon_sym_LT,
+  [anon_sym_LT_EQ] = anon_sym_LT_EQ,
+  [anon_sym_GT_EQ] = anon_sym_GT_EQ,
+  [sym_identifier] = sym_identifier,
+  [sym_at_keyword] = sym_at_keyword,
+  [sym_comment] = sym_comment,
+  [sym_plain_value] = sym_plain_value,
+  [sym_grit_metavariable] = sym_grit_metavariable,
+  [anon_sym_DOT_DOT_DOT] = anon_sym_DOT_DOT_DOT,
+  [anon_sym_each] = anon_sym_each,
+  [anon_sym_COLONextend] = anon_sym_COLONextend,
+  [anon_sym_ATplugin] = anon_sym_ATplugin,
+  [anon_sym_when] = anon_sym_when,
+  [anon_sym_AT_LBRACE] = anon_sym_AT_LBRACE,
+  [anon_sym_DASH] = sym_identifier,
+  [anon_sym_DOLLAR] = anon_sym_DOLLAR,
+  [anon_sym_DOLLAR_LBRACE] = anon_sym_DOLLAR_LBRACE,
+  [anon_sym_AT_AT] = anon_sym_AT_AT,
+  [sym_variable] = sym_variable,
+  [anon_sym_PLUS_] = anon_sym_PLUS_,
+  [sym__descendant_operator] = sym__descendant_operator,
+  [sym__pseudo_class_selector_colon] = sym__pseudo_class_selector_colon,
+  [sym___error_recovery] = sym___error_recovery,
+  [sym__concat] = sym__concat,
+  [sym_stylesheet] = sym_stylesheet,
+  [sym_import_statement] = sym_import_statement,
+  [sym_media_statement] = sym_media_statement,
+  [sym_charset_statement] = sym_charset_statement,
+  [sym_namespace_statement] = sym_namespace_statement,
+  [sym_keyframes_statement] = sym_keyframes_statement,
+  [sym_keyframe_block_list] = sym_keyframe_block_list,
+  [sym_keyframe_block] = sym_keyframe_block,
+  [sym_supports_statement] = sym_supports_statement,
+  [sym_at_rule] = sym_at_rule,
+  [sym_rule_set] = sym_rule_set,
+  [sym_selectors] = sym_selectors,
+  [sym_block] = sym_block,
+  [sym__selector] = sym__selector,
+  [sym_universal_selector] = sym_universal_selector,
+  [sym_class_selector] = sym_class_selector,
+  [sym_pseudo_class_selector] = sym_pseudo_class_selector,
+  [sym_pseudo_element_selector] = sym_pseudo_element_selector,
+  [sym_id_selector] = sym_id_selector,
+  [sym_attribute_selector] = sym_attribute_selector,
+  [sym_child_selector] = sym_child_selector,
+  [sym_descendant_selector] = sym_descendant_selector,
+  [sym_sibling_selector] = sym_sibling_selector,
+  [sym_adjacent_sibling_selector] = sym_adjacent_sibling_selector,
+  [sym_pseudo_class_arguments] = sym_arguments,
+  [sym_pseudo_element_arguments] = sym_arguments,
+  [sym_declaration] = sym_declaration,
+  [sym_last_declaration] = sym_declaration,
+  [sym__query] = sym__query,
+  [sym_feature_query] = sym_feature_query,
+  [sym_parenthesized_query] = sym_parenthesized_query,
+  [sym_binary_query] = sym_binary_query,
+  [sym_unary_query] = sym_unary_query,
+  [sym_selector_query] = sym_selector_query,
+  [sym__value] = sym__value,
+  [sym_parenthesized_value] = sym_parenthesized_value,
+  [sym_color_value] = sym_color_value,
+  [sym_integer_value] = sym_integer_value,
+  [sym_float_value] = sym_float_value,
+  [sym_call_expression] = sym_call_expression,
+  [sym_plus] = sym_plus,
+  [sym_times] = sym_times,
+  [sym_binary_expression] = sym_binary_expression,
+  [sym_arguments] = sym_arguments,
+  [sym_mixin_definition] = sym_mixin_definition,
+  [sym_parameters] = sym_parameters,
+  [sym_parameter] = sym_parameter,
+  [sym_rest_parameter] = sym_rest_parameter,
+  [sym_each_statement] = sym_each_statement,
+  [sym_mixin_statement] = sym_mixin_statement,
+  [sym_extend_statement] = sym_extend_statement,
+  [sym_plugin_statement] = sym_plugin_statement,
+  [sym_when_condition] = sym_when_condition,
+  [sym_list_value] = sym_list_value,
+  [sym_interpolation] = sym_interpolation,
+  [sym__concatenated_identifier] = sym__concatenated_identifier,
+  [sym__extend_arguments] = sym_arguments,
+  [sym__mixin_name] = sym__mixin_name,
+  [sym_property_value] = sym_property_value,
+  [sym_escaped_value] = sym_escaped_value,
+  [sym_value_value] = sym_value_value,
+  [sym_merge_identifier] = sym_merge_identifier,
+  [aux_sym_stylesheet_repeat1] = aux_sym_stylesheet_repeat1,
+  [aux_sym_import_statement_repeat1] = aux_sym_import_statement_repeat1,
+  [aux_sym_media_statement_repeat1] = aux_sym_media_statement_repeat1,
+  [aux_sym_keyframe_block_list_repeat1] = aux_sym_keyframe_block_list_repeat1,
+  [aux_sym_at_rule_repeat1] = aux_sym_at_rule_repeat1,
+  [aux_sym_selectors_repeat1] = aux_sym_selectors_repeat1,
+  [aux_sym_block_repeat1] = aux_sym_block_repeat1,
+  [aux_sym_pseudo_class_arguments_repeat1] = aux_sym_pseudo_class_arguments_repeat1,
+  [aux_sym_pseudo_class_arguments_repeat2] = aux_sym_pseudo_class_arguments_repeat2,
+  [aux_sym_declaration_repeat1] = aux_sym_declaration_repeat1,
+  [aux_sym_arguments_repeat1] = aux_sym_arguments_repeat1,
+  [aux_sym_parameters_repeat1] = aux_sym_parameters_repeat1,
+  [aux_sym_list_value_repeat1] = aux_sym_list_value_repeat1,
+  [aux_sym__concatenated_identifier_repeat1] = aux_sym__concatenated_identifier_repeat1,
+  [alias_sym_attribute_name] = alias_sym_attribute_name,
+  [alias_sym_class_name] = alias_sym_class_name,
+  [alias_sym_feature_name] = alias_sym_feature_name,
+  [alias_sym_function_name] = alias_sym_function_name,
+  [alias_sym_greater_than] = alias_sym_greater_than,
+  [alias_sym_id_name] = alias_sym_id_name,
+  [alias_sym_keyframes_name] = alias_sym_keyframes_name,
+  [alias_sym_keyword_query] = alias_sym_keyword_query,
+  [alias_sym_namespace_name] = alias_sym_namespace_name,
+  [alias_sym_property_name] = alias_sym_property_name,
+  [alias_sym_tag_name] = alias_sym_tag_name,
+};
+
+static const TSSymbolMetadata ts_symbol_metadata[] = {
+  [ts_builtin_sym_end] = {
+    .visible = false,
+    .named = true,
+  },
+  [anon_sym_ATimport] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_COMMA] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_SEMI] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ATmedia] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ATcharset] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ATnamespace] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ATkeyframes] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym_keyframes_statement_token1] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_LBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_from] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_to] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_ATsupports] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_nesting_selector] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_STAR] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_COLON] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_COLON_COLON] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_POUND] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_equal] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_contains_word_equal] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_starts_with_equal] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_dash_equal] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_contains_equal] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_ends_equal] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_LBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_GT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_TILDE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_PLUS] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LPAREN] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RPAREN] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_important] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_LPAREN2] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_and] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_or] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_not] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_only] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_selector] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym_color_value_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [sym_string_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [aux_sym_integer_value_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_float_value_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [sym_unit] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_minus] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_divide] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_EQ_EQ] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_BANG_EQ] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_LT] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_LT_EQ] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_GT_EQ] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_identifier] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_at_keyword] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_comment] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_plain_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_grit_metavariable] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_DOT_DOT_DOT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_each] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_COLONextend] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ATplugin] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_when] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_AT_LBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DASH] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_DOLLAR] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOLLAR_LBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_AT_AT] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_variable] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_PLUS_] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym__descendant_operator] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym__pseudo_class_selector_colon] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym___error_recovery] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym__concat] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_stylesheet] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_import_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_media_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_charset_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_namespace_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyframes_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyframe_block_list] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyframe_block] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_supports_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_at_rule] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_rule_set] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_selectors] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_block] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__selector] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_universal_selector] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_class_selector] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_pseudo_class_selector] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_pseudo_element_selector] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_id_selector] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_attribute_selector] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_child_selector] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_descendant_selector] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_sibling_selector] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_adjacent_sibling_selector] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_pseudo_class_arguments] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_pseudo_element_arguments] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_declaration] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_last_declaration] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__query] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_feature_query] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_parenthesized_query] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_binary_query] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_unary_query] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_selector_query] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__value] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_parenthesized_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_color_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_integer_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_float_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_call_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_plus] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_times] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_binary_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_arguments] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_mixin_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_parameters] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_parameter] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_rest_parameter] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_each_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_mixin_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_extend_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_plugin_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_when_condition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_list_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_interpolation] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__concatenated_identifier] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym__extend_arguments] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__mixin_name] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_property_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_escaped_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_value_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_merge_identifier] = {
+    .visible = true,
+    .named = true,
+  },
+  [aux_sym_stylesheet_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_import_statement_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_media_statement_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_keyframe_block_list_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_at_rule_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_selectors_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_block_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_pseudo_class_arguments_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_pseudo_class_arguments_repeat2] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_declaration_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_arguments_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_parameters_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_list_value_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym__concatenated_identifier_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [alias_sym_attribute_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [alias_sym_class_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [alias_sym_feature_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [alias_sym_function_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [alias_sym_greater_than] = {
+    .visible = true,
+    .named = true,
+  },
+  [alias_sym_id_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [alias_sym_keyframes_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [alias_sym_keyword_query] = {
+    .visible = true,
+    .named = true,
+  },
+  [alias_sym_namespace_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [alias_sym_property_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [alias_sym_tag_name] = {
+    .visible = true,
+    .named = true,
+  },
+};
+
+enum ts_field_identifiers {
+  field_ancestor = 1,
+  field_annotation = 2,
+  field_arguments = 3,
+  field_attribute = 4,
+  field_blocks = 5,
+  field_body = 6,
+  field_charset = 7,
+  field_child = 8,
+  field_class = 9,
+  field_collection = 10,
+  field_condition = 11,
+  field_declaration = 12,
+  field_default = 13,
+  field_descendant = 14,
+  field_feature = 15,
+  field_first = 16,
+  field_from = 17,
+  field_guard = 18,
+  field_important = 19,
+  field_item = 20,
+  field_items = 21,
+  field_keyframes = 22,
+  field_left = 23,
+  field_media_type = 24,
+  field_merge = 25,
+  field_name = 26,
+  field_namespace = 27,
+  field_offset = 28,
+  field_operator = 29,
+  field_options = 30,
+  field_parameter = 31,
+  field_parameters = 32,
+  field_parent = 33,
+  field_primary = 34,
+  field_query = 35,
+  field_right = 36,
+  field_rule = 37,
+  field_second = 38,
+  field_selector = 39,
+  field_selector_type = 40,
+  field_selectors = 41,
+  field_sibling = 42,
+  field_value = 43,
+  field_values = 44,
+};
+
+static const char * const ts_field_names[] = {
+  [0] = NULL,
+  [field_ancestor] = "ancestor",
+  [field_annotation] = "annotation",
+  [field_arguments] = "arguments",
+  [field_attribute] = "attribute",
+  [field_blocks] = "blocks",
+  [field_body] = "body",
+  [field_charset] = "charset",
+  [field_child] = "child",
+  [field_class] = "class",
+  [field_collection] = "collection",
+  [field_condition] = "condition",
+  [field_declaration] = "declaration",
+  [field_default] = "default",
+  [field_descendant] = "descendant",
+  [field_feature] = "feature",
+  [field_first] = "first",
+  [field_from] = "from",
+  [field_guard] = "guard",
+  [field_important] = "important",
+  [field_item] = "item",
+  [field_items] = "items",
+  [field_keyframes] = "keyframes",
+  [field_left] = "left",
+  [field_media_type] = "media_type",
+  [field_merge] = "merge",
+  [field_name] = "name",
+  [field_namespace] = "namespace",
+  [field_offset] = "offset",
+  [field_operator] = "operator",
+  [field_options] = "options",
+  [field_parameter] = "parameter",
+  [field_parameters] = "parameters",
+  [field_parent] = "parent",
+  [field_primary] = "primary",
+  [field_query] = "query",
+  [field_right] = "right",
+  [field_rule] = "rule",
+  [field_second] = "second",
+  [field_selector] = "selector",
+  [field_selector_type] = "selector_type",
+  [field_selectors] = "selectors",
+  [field_sibling] = "sibling",
+  [field_value] = "value",
+  [field_values] = "values",
+};
+
+static const TSFieldMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
+  [2] = {.index = 0, .length = 1},
+  [3] = {.index = 1, .length = 1},
+  [4] = {.index = 2, .length = 1},
+  [7] = {.index = 3, .length = 1},
+  [8] = {.index = 3, .length = 1},
+  [9] = {.index = 4, .length = 1},
+  [10] = {.index = 4, .length = 1},
+  [11] = {.index = 5, .length = 1},
+  [12] = {.index = 6, .length = 2},
+  [13] = {.index = 8, .length = 2},
+  [14] = {.index = 10, .length = 2},
+  [15] = {.index = 12, .length = 2},
+  [16] = {.index = 14, .length = 1},
+  [17] = {.index = 15, .length = 2},
+  [18] = {.index = 4, .length = 1},
+  [19] = {.index = 17, .length = 2},
+  [20] = {.index = 19, .length = 2},
+  [21] = {.index = 21, .length = 1},
+  [22] = {.index = 22, .length = 3},
+  [23] = {.index = 25, .length = 2},
+  [24] = {.index = 27, .length = 2},
+  [25] = {.index = 29, .length = 2},
+  [26] = {.index = 31, .length = 1},
+  [27] = {.index = 32, .length = 1},
+  [28] = {.index = 32, .length = 1},
+  [29] = {.index = 33, .length = 2},
+  [30] = {.index = 35, .length = 3},
+  [31] = {.index = 38, .length = 2},
+  [32] = {.index = 40, .length = 1},
+  [33] = {.index = 41, .length = 2},
+  [34] = {.index = 43, .length = 2},
+  [35] = {.index = 43, .length = 2},
+  [36] = {.index = 45, .length = 2},
+  [37] = {.index = 47, .length = 2},
+  [38] = {.index = 49, .length = 2},
+  [39] = {.index = 51, .length = 2},
+  [40] = {.index = 53, .length = 1},
+  [41] = {.index = 54, .length = 4},
+  [42] = {.index = 58, .length = 3},
+  [43] = {.index = 58, .length = 3},
+  [44] = {.index = 61, .length = 2},
+  [45] = {.index = 63, .length = 2},
+  [46] = {.index = 65, .length = 1},
+  [47] = {.index = 66, .length = 1},
+  [48] = {.index = 67, .length = 2},
+  [49] = {.index = 69, .length = 3},
+  [50] = {.index = 72, .length = 2},
+  [51] = {.index = 74, .length = 2},
+  [52] = {.index = 76, .length = 2},
+  [53] = {.index = 76, .length = 2},
+  [54] = {.index = 78, .length = 2},
+  [55] = {.index = 80, .length = 1},
+  [56] = {.index = 81, .length = 1},
+  [57] = {.index = 82, .length = 3},
+  [58] = {.index = 85, .length = 1},
+  [59] = {.index = 86, .length = 3},
+  [60] = {.index = 89, .length = 4},
+  [61] = {.index = 93, .length = 2},
+  [62] = {.index = 95, .length = 3},
+  [63] = {.index = 98, .length = 3},
+  [64] = {.index = 101, .length = 2},
+  [65] = {.index = 103, .length = 1},
+  [66] = {.index = 104, .length = 5},
+  [67] = {.index = 109, .length = 1},
+  [68] = {.index = 110, .length = 2},
+  [69] = {.index = 112, .length = 2},
+  [70] = {.index = 114, .length = 1},
+  [71] = {.index = 115, .length = 3},
+  [72] = {.index = 118, .length = 2},
+  [73] = {.index = 120, .length = 3},
+  [74] = {.index = 123, .length = 1},
+  [75] = {.index = 124, .length = 2},
+  [76] = {.index = 126, .length = 1},
+  [77] = {.index = 127, .length = 1},
+  [78] = {.index = 128, .length = 2},
+  [79] = {.index = 128, .length = 2},
+  [80] = {.index = 130, .length = 3},
+  [81] = {.index = 133, .length = 3},
+  [82] = {.index = 136, .length = 3},
+  [83] = {.index = 139, .length = 3},
+  [84] = {.index = 142, .length = 4},
+  [85] = {.index = 146, .length = 4},
+  [86] = {.index = 150, .length = 2},
+  [87] = {.index = 152, .length = 2},
+  [88] = {.index = 154, .length = 2},
+  [89] = {.index = 156, .length = 2},
+  [90] = {.index = 158, .length = 4},
+  [91] = {.index = 162, .length = 2},
+  [92] = {.index = 164, .length = 2},
+  [93] = {.index = 166, .length = 2},
+  [94] = {.index = 168, .length = 4},
+  [95] = {.index = 172, .length = 4},
+  [96] = {.index = 176, .length = 4},
+  [97] = {.index = 180, .length = 3},
+  [98] = {.index = 183, .length = 5},
+  [99] = {.index = 188, .length = 4},
+  [100] = {.index = 192, .length = 1},
+  [101] = {.index = 193, .length = 5},
+  [102] = {.index = 198, .length = 1},
+  [103] = {.index = 199, .length = 2},
+};
+
+static const TSFieldMapEntry ts_field_map_entries[] = {
+  [0] =
+    {field_items, 0},
+  [1] =
+    {field_selectors, 0},
+  [2] =
+    {field_items, 0, .inherited = true},
+  [3] =
+    {field_class, 1},
+  [4] =
+    {field_name, 1},
+  [5] =
+    {field_rule, 0},
+  [6] =
+    {field_body, 1},
+    {field_rule, 0},
+  [8] =
+    {field_body, 1},
+    {field_selectors, 0},
+  [10] =
+    {field_selectors, 0},
+    {field_selectors, 1, .inherited = true},
+  [12] =
+    {field_items, 0, .inherited = true},
+    {field_items, 1, .inherited = true},
+  [14] =
+    {field_value, 1},
+  [15] =
+    {field_arguments, 1},
+    {field_name, 0},
+  [17] =
+    {field_operator, 0},
+    {field_query, 1},
+  [19] =
+    {field_body, 2},
+    {field_media_type, 1},
+  [21] =
+    {field_charset, 1},
+  [22] =
+    {field_annotation, 0},
+    {field_blocks, 2},
+    {field_name, 1},
+  [25] =
+    {field_body, 2},
+    {field_feature, 1},
+  [27] =
+    {field_arguments, 2},
+    {field_class, 1},
+  [29] =
+    {field_arguments, 2},
+    {field_name, 1},
+  [31] =
+    {field_attribute, 1},
+  [32] =
+    {field_class, 2},
+  [33] =
+    {field_query, 1},
+    {field_rule, 0},
+  [35] =
+    {field_body, 2},
+    {field_query, 1},
+    {field_rule, 0},
+  [38] =
+    {field_ancestor, 0},
+    {field_descendant, 2},
+  [40] =
+    {field_selectors, 1},
+  [41] =
+    {field_class, 2},
+    {field_selector, 0},
+  [43] =
+    {field_name, 2},
+    {field_selector, 0},
+  [45] =
+    {field_child, 2},
+    {field_parent, 0},
+  [47] =
+    {field_primary, 2},
+    {field_sibling, 0},
+  [49] =
+    {field_first, 0},
+    {field_second, 2},
+  [51] =
+    {field_selectors, 0, .inherited = true},
+    {field_selectors, 1, .inherited = true},
+  [53] =
+    {field_name, 0},
+  [54] =
+    {field_body, 2},
+    {field_class, 0, .inherited = true},
+    {field_namespace, 0, .inherited = true},
+    {field_parameters, 1},
+  [58] =
+    {field_left, 0},
+    {field_operator, 1},
+    {field_right, 2},
+  [61] =
+    {field_from, 2},
+    {field_value, 1},
+  [63] =
+    {field_options, 1},
+    {field_value, 2},
+  [65] =
+    {field_query, 1},
+  [66] =
+    {field_media_type, 1},
+  [67] =
+    {field_operator, 1},
+    {field_query, 2},
+  [69] =
+    {field_body, 3},
+    {field_media_type, 1},
+    {field_media_type, 2, .inherited = true},
+  [72] =
+    {field_media_type, 0, .inherited = true},
+    {field_media_type, 1, .inherited = true},
+  [74] =
+    {field_namespace, 1},
+    {field_value, 2},
+  [76] =
+    {field_class, 3},
+    {field_namespace, 1},
+  [78] =
+    {field_name, 0},
+    {field_values, 2},
+  [80] =
+    {field_values, 0},
+  [81] =
+    {field_declaration, 1},
+  [82] =
+    {field_class, 0, .inherited = true},
+    {field_name, 0},
+    {field_namespace, 0, .inherited = true},
+  [85] =
+    {field_item, 1},
+  [86] =
+    {field_query, 1},
+    {field_query, 2, .inherited = true},
+    {field_rule, 0},
+  [89] =
+    {field_body, 3},
+    {field_query, 1},
+    {field_query, 2, .inherited = true},
+    {field_rule, 0},
+  [93] =
+    {field_query, 0, .inherited = true},
+    {field_query, 1, .inherited = true},
+  [95] =
+    {field_arguments, 3},
+    {field_class, 2},
+    {field_selector, 0},
+  [98] =
+    {field_arguments, 3},
+    {field_name, 2},
+    {field_selector, 0},
+  [101] =
+    {field_attribute, 2},
+    {field_selector, 0},
+  [103] =
+    {field_parameter, 1},
+  [104] =
+    {field_body, 3},
+    {field_class, 0, .inherited = true},
+    {field_guard, 2},
+    {field_namespace, 0, .inherited = true},
+    {field_parameters, 1},
+  [109] =
+    {field_values, 1},
+  [110] =
+    {field_values, 1},
+    {field_values, 2, .inherited = true},
+  [112] =
+    {field_values, 0, .inherited = true},
+    {field_values, 1, .inherited = true},
+  [114] =
+    {field_from, 1},
+  [115] =
+    {field_from, 2},
+    {field_from, 3, .inherited = true},
+    {field_value, 1},
+  [118] =
+    {field_from, 0, .inherited = true},
+    {field_from, 1, .inherited = true},
+  [120] =
+    {field_from, 3},
+    {field_options, 1},
+    {field_value, 2},
+  [123] =
+    {field_selector, 2},
+  [124] =
+    {field_body, 1},
+    {field_offset, 0},
+  [126] =
+    {field_keyframes, 1},
+  [127] =
+    {field_arguments, 1},
+  [128] =
+    {field_class, 4},
+    {field_namespace, 1},
+  [130] =
+    {field_attribute, 1},
+    {field_selector_type, 2},
+    {field_value, 3},
+  [133] =
+    {field_important, 3},
+    {field_name, 0},
+    {field_values, 2},
+  [136] =
+    {field_name, 0},
+    {field_values, 2},
+    {field_values, 3, .inherited = true},
+  [139] =
+    {field_merge, 1},
+    {field_name, 0},
+    {field_values, 3},
+  [142] =
+    {field_class, 0, .inherited = true},
+    {field_important, 1},
+    {field_name, 0},
+    {field_namespace, 0, .inherited = true},
+  [146] =
+    {field_arguments, 1},
+    {field_class, 0, .inherited = true},
+    {field_name, 0},
+    {field_namespace, 0, .inherited = true},
+  [150] =
+    {field_declaration, 2},
+    {field_item, 1},
+  [152] =
+    {field_default, 2},
+    {field_name, 0},
+  [154] =
+    {field_parameter, 1},
+    {field_parameter, 2, .inherited = true},
+  [156] =
+    {field_parameter, 0, .inherited = true},
+    {field_parameter, 1, .inherited = true},
+  [158] =
+    {field_from, 3},
+    {field_from, 4, .inherited = true},
+    {field_options, 1},
+    {field_value, 2},
+  [162] =
+    {field_name, 1},
+    {field_value, 3},
+  [164] =
+    {field_arguments, 1},
+    {field_arguments, 2, .inherited = true},
+  [166] =
+    {field_arguments, 0, .inherited = true},
+    {field_arguments, 1, .inherited = true},
+  [168] =
+    {field_important, 4},
+    {field_name, 0},
+    {field_values, 2},
+    {field_values, 3, .inherited = true},
+  [172] =
+    {field_important, 4},
+    {field_merge, 1},
+    {field_name, 0},
+    {field_values, 3},
+  [176] =
+    {field_merge, 1},
+    {field_name, 0},
+    {field_values, 3},
+    {field_values, 4, .inherited = true},
+  [180] =
+    {field_arguments, 2},
+    {field_selector, 0},
+    {field_selector, 2, .inherited = true},
+  [183] =
+    {field_arguments, 1},
+    {field_class, 0, .inherited = true},
+    {field_important, 2},
+    {field_name, 0},
+    {field_namespace, 0, .inherited = true},
+  [188] =
+    {field_attribute, 2},
+    {field_selector, 0},
+    {field_selector_type, 3},
+    {field_value, 4},
+  [192] =
+    {field_condition, 2},
+  [193] =
+    {field_important, 5},
+    {field_merge, 1},
+    {field_name, 0},
+    {field_values, 3},
+    {field_values, 4, .inherited = true},
+  [198] =
+    {field_selector, 1},
+  [199] =
+    {field_body, 4},
+    {field_collection, 2},
+};
+
+static const TSSymbol ts_alias_sequences[PRODUCTION_ID_COUNT][MAX_ALIAS_SEQUENCE_LENGTH] = {
+  [0] = {0},
+  [1] = {
+    [0] = alias_sym_tag_name,
+  },
+  [5] = {
+    [0] = sym_plain_value,
+  },
+  [6] = {
+    [0] = alias_sym_keyword_query,
+  },
+  [7] = {
+    [1] = alias_sym_class_name,
+  },
+  [9] = {
+    [1] = alias_sym_tag_name,
+  },
+  [10] = {
+    [1] = alias_sym_id_name,
+  },
+  [17] = {
+    [0] = alias_sym_function_name,
+  },
+  [18] = {
+    [1] = alias_sym_property_name,
+  },
+  [22] = {
+    [1] = alias_sym_keyframes_name,
+  },
+  [24] = {
+    [1] = alias_sym_class_name,
+  },
+  [25] = {
+    [1] = alias_sym_tag_name,
+  },
+  [26] = {
+    [1] = alias_sym_attribute_name,
+  },
+  [27] = {
+    [2] = alias_sym_class_name,
+  },
+  [33] = {
+    [2] = alias_sym_class_name,
+  },
+  [34] = {
+    [2] = alias_sym_tag_name,
+  },
+  [35] = {
+    [2] = alias_sym_id_name,
+  },
+  [42] = {
+    [1] = alias_sym_greater_than,
+  },
+  [51] = {
+    [1] = alias_sym_namespace_name,
+  },
+  [52] = {
+    [1] = alias_sym_id_name,
+    [3] = alias_sym_class_name,
+  },
+  [53] = {
+    [1] = alias_sym_id_name,
+  },
+  [54] = {
+    [0] = alias_sym_property_name,
+  },
+  [57] = {
+    [0] = alias_sym_function_name,
+  },
+  [62] = {
+    [2] = alias_sym_class_name,
+  },
+  [63] = {
+    [2] = alias_sym_tag_name,
+  },
+  [64] = {
+    [2] = alias_sym_attribute_name,
+  },
+  [78] = {
+    [1] = alias_sym_id_name,
+    [4] = alias_sym_class_name,
+  },
+  [79] = {
+    [1] = alias_sym_id_name,
+  },
+  [80] = {
+    [1] = alias_sym_attribute_name,
+  },
+  [81] = {
+    [0] = alias_sym_property_name,
+  },
+  [82] = {
+    [0] = alias_sym_property_name,
+  },
+  [83] = {
+    [0] = alias_sym_property_name,
+  },
+  [84] = {
+    [0] = alias_sym_function_name,
+  },
+  [85] = {
+    [0] = alias_sym_function_name,
+  },
+  [91] = {
+    [1] = alias_sym_feature_name,
+  },
+  [94] = {
+    [0] = alias_sym_property_name,
+  },
+  [95] = {
+    [0] = alias_sym_property_name,
+  },
+  [96] = {
+    [0] = alias_sym_property_name,
+  },
+  [98] = {
+    [0] = alias_sym_function_name,
+  },
+  [99] = {
+    [2] = alias_sym_attribute_name,
+  },
+  [101] = {
+    [0] = alias_sym_property_name,
+  },
+};
+
+static const uint16_t ts_non_terminal_alias_map[] = {
+  sym__concatenated_identifier, 4,
+    sym__concatenated_identifier,
+    alias_sym_class_name,
+    alias_sym_property_name,
+    alias_sym_tag_name,
+  sym__mixin_name, 2,
+    sym__mixin_name,
+    alias_sym_function_name,
+  0,
+};
+
+static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
+  [0] = 0,
+  [1] = 1,
+  [2] = 2,
+  [3] = 3,
+  [4] = 2,
+  [5] = 3,
+  [6] = 2,
+  [7] = 3,
+  [8] = 8,
+  [9] = 9,
+  [10] = 10,
+  [11] = 11,
+  [12] = 11,
+  [13] = 13,
+  [14] = 14,
+  [15] = 14,
+  [16] = 13,
+  [17] = 17,
+  [18] = 18,
+  [19] = 19,
+  [20] = 20,
+  [21] = 21,
+  [22] = 20,
+  [23] = 21,
+  [24] = 24,
+  [25] = 24,
+  [26] = 26,
+  [27] = 27,
+  [28] = 28,
+  [29] = 29,
+  [30] = 30,
+  [31] = 31,
+  [32] = 32,
+  [33] = 33,
+  [34] = 34,
+  [35] = 35,
+  [36] = 36,
+  [37] = 37,
+  [38] = 38,
+  [39] = 39,
+  [40] = 28,
+  [41] = 29,
+  [42] = 42,
+  [43] = 43,
+  [44] = 31,
+  [45] = 35,
+  [46] = 27,
+  [47] = 35,
+  [48] = 35,
+  [49] = 49,
+  [50] = 50,
+  [51] = 49,
+  [52] = 52,
+  [53] = 53,
+  [54] = 54,
+  [55] = 42,
+  [56] = 56,
+  [57] = 57,
+  [58] = 58,
+  [59] = 59,
+  [60] = 60,
+  [61] = 58,
+  [62] = 62,
+  [63] = 59,
+  [64] = 43,
+  [65] = 62,
+  [66] = 66,
+  [67] = 37,
+  [68] = 68,
+  [69] = 69,
+  [70] = 70,
+  [71] = 71,
+  [72] = 72,
+  [73] = 73,
+  [74] = 38,
+  [75] = 39,
+  [76] = 76,
+  [77] = 77,
+  [78] = 78,
+  [79] = 79,
+  [80] = 80,
+  [81] = 81,
+  [82] = 28,
+  [83] = 29,
+  [84] = 84,
+  [85] = 85,
+  [86] = 31,
+  [87] = 87,
+  [88] = 88,
+  [89] = 89,
+  [90] = 57,
+  [91] = 43,
+  [92] = 92,
+  [93] = 93,
+  [94] = 71,
+  [95] = 84,
+  [96] = 96,
+  [97] = 53,
+  [98] = 93,
+  [99] = 99,
+  [100] = 85,
+  [101] = 101,
+  [102] = 93,
+  [103] = 93,
+  [104] = 42,
+  [105] = 69,
+  [106] = 70,
+  [107] = 72,
+  [108] = 108,
+  [109] = 73,
+  [110] = 76,
+  [111] = 77,
+  [112] = 66,
+  [113] = 113,
+  [114] = 80,
+  [115] = 79,
+  [116] = 78,
+  [117] = 117,
+  [118] = 118,
+  [119] = 119,
+  [120] = 120,
+  [121] = 121,
+  [122] = 118,
+  [123] = 118,
+  [124] = 124,
+  [125] = 125,
+  [126] = 126,
+  [127] = 120,
+  [128] = 128,
+  [129] = 129,
+  [130] = 130,
+  [131] = 131,
+  [132] = 132,
+  [133] = 133,
+  [134] = 120,
+  [135] = 135,
+  [136] = 136,
+  [137] = 118,
+  [138] = 120,
+  [139] = 139,
+  [140] = 128,
+  [141] = 129,
+  [142] = 142,
+  [143] = 131,
+  [144] = 135,
+  [145] = 130,
+  [146] = 121,
+  [147] = 142,
+  [148] = 124,
+  [149] = 126,
+  [150] = 150,
+  [151] = 135,
+  [152] = 130,
+  [153] = 135,
+  [154] = 130,
+  [155] = 135,
+  [156] = 135,
+  [157] = 135,
+  [158] = 135,
+  [159] = 135,
+  [160] = 135,
+  [161] = 135,
+  [162] = 125,
+  [163] = 150,
+  [164] = 164,
+  [165] = 165,
+  [166] = 166,
+  [167] = 167,
+  [168] = 168,
+  [169] = 169,
+  [170] = 170,
+  [171] = 171,
+  [172] = 172,
+  [173] = 173,
+  [174] = 174,
+  [175] = 175,
+  [176] = 176,
+  [177] = 177,
+  [178] = 178,
+  [179] = 179,
+  [180] = 180,
+  [181] = 181,
+  [182] = 182,
+  [183] = 183,
+  [184] = 184,
+  [185] = 185,
+  [186] = 186,
+  [187] = 187,
+  [188] = 188,
+  [189] = 189,
+  [190] = 190,
+  [191] = 191,
+  [192] = 192,
+  [193] = 193,
+  [194] = 194,
+  [195] = 195,
+  [196] = 196,
+  [197] = 197,
+  [198] = 198,
+  [199] = 199,
+  [200] = 200,
+  [201] = 201,
+  [202] = 202,
+  [203] = 203,
+  [204] = 204,
+  [205] = 205,
+  [206] = 181,
+  [207] = 183,
+  [208] = 187,
+  [209] = 169,
+  [210] = 195,
+  [211] = 196,
+  [212] = 198,
+  [213] = 213,
+  [214] = 214,
+  [215] = 215,
+  [216] = 216,
+  [217] = 217,
+  [218] = 218,
+  [219] = 166,
+  [220] = 220,
+  [221] = 221,
+  [222] = 170,
+  [223] = 223,
+  [224] = 171,
+  [225] = 172,
+  [226] = 173,
+  [227] = 174,
+  [228] = 175,
+  [229] = 176,
+  [230] = 177,
+  [231] = 167,
+  [232] = 165,
+  [233] = 179,
+  [234] = 180,
+  [235] = 182,
+  [236] = 185,
+  [237] = 186,
+  [238] = 188,
+  [239] = 189,
+  [240] = 191,
+  [241] = 168,
+  [242] = 197,
+  [243] = 199,
+  [244] = 200,
+  [245] = 201,
+  [246] = 204,
+  [247] = 205,
+  [248] = 213,
+  [249] = 249,
+  [250] = 250,
+  [251] = 214,
+  [252] = 215,
+  [253] = 216,
+  [254] = 217,
+  [255] = 218,
+  [256] = 220,
+  [257] = 221,
+  [258] = 184,
+  [259] = 27,
+  [260] = 43,
+  [261] = 261,
+  [262] = 261,
+  [263] = 263,
+  [264] = 264,
+  [265] = 265,
+  [266] = 31,
+  [267] = 37,
+  [268] = 38,
+  [269] = 39,
+  [270] = 29,
+  [271] = 28,
+  [272] = 27,
+  [273] = 57,
+  [274] = 42,
+  [275] = 43,
+  [276] = 53,
+  [277] = 72,
+  [278] = 69,
+  [279] = 70,
+  [280] = 71,
+  [281] = 76,
+  [282] = 73,
+  [283] = 77,
+  [284] = 78,
+  [285] = 79,
+  [286] = 80,
+  [287] = 85,
+  [288] = 43,
+  [289] = 289,
+  [290] = 66,
+  [291] = 84,
+  [292] = 29,
+  [293] = 31,
+  [294] = 38,
+  [295] = 295,
+  [296] = 37,
+  [297] = 39,
+  [298] = 28,
+  [299] = 299,
+  [300] = 57,
+  [301] = 42,
+  [302] = 53,
+  [303] = 303,
+  [304] = 304,
+  [305] = 304,
+  [306] = 303,
+  [307] = 66,
+  [308] = 84,
+  [309] = 80,
+  [310] = 69,
+  [311] = 70,
+  [312] = 71,
+  [313] = 72,
+  [314] = 85,
+  [315] = 73,
+  [316] = 249,
+  [317] = 28,
+  [318] = 77,
+  [319] = 29,
+  [320] = 31,
+  [321] = 321,
+  [322] = 78,
+  [323] = 79,
+  [324] = 76,
+  [325] = 325,
+  [326] = 326,
+  [327] = 31,
+  [328] = 328,
+  [329] = 329,
+  [330] = 330,
+  [331] = 331,
+  [332] = 295,
+  [333] = 333,
+  [334] = 29,
+  [335] = 335,
+  [336] = 336,
+  [337] = 28,
+  [338] = 333,
+  [339] = 339,
+  [340] = 28,
+  [341] = 341,
+  [342] = 42,
+  [343] = 343,
+  [344] = 344,
+  [345] = 345,
+  [346] = 346,
+  [347] = 347,
+  [348] = 43,
+  [349] = 349,
+  [350] = 29,
+  [351] = 31,
+  [352] = 29,
+  [353] = 329,
+  [354] = 325,
+  [355] = 31,
+  [356] = 92,
+  [357] = 357,
+  [358] = 325,
+  [359] = 359,
+  [360] = 325,
+  [361] = 361,
+  [362] = 341,
+  [363] = 345,
+  [364] = 346,
+  [365] = 339,
+  [366] = 366,
+  [367] = 366,
+  [368] = 335,
+  [369] = 349,
+  [370] = 28,
+  [371] = 371,
+  [372] = 372,
+  [373] = 344,
+  [374] = 42,
+  [375] = 43,
+  [376] = 42,
+  [377] = 43,
+  [378] = 42,
+  [379] = 43,
+  [380] = 263,
+  [381] = 264,
+  [382] = 382,
+  [383] = 383,
+  [384] = 384,
+  [385] = 385,
+  [386] = 386,
+  [387] = 387,
+  [388] = 388,
+  [389] = 389,
+  [390] = 390,
+  [391] = 391,
+  [392] = 392,
+  [393] = 393,
+  [394] = 394,
+  [395] = 395,
+  [396] = 387,
+  [397] = 390,
+  [398] = 395,
+  [399] = 399,
+  [400] = 400,
+  [401] = 401,
+  [402] = 402,
+  [403] = 403,
+  [404] = 388,
+  [405] = 405,
+  [406] = 406,
+  [407] = 407,
+  [408] = 408,
+  [409] = 409,
+  [410] = 399,
+  [411] = 400,
+  [412] = 401,
+  [413] = 402,
+  [414] = 389,
+  [415] = 415,
+  [416] = 416,
+  [417] = 417,
+  [418] = 403,
+  [419] = 385,
+  [420] = 420,
+  [421] = 421,
+  [422] = 422,
+  [423] = 409,
+  [424] = 416,
+  [425] = 425,
+  [426] = 417,
+  [427] = 427,
+  [428] = 420,
+  [429] = 427,
+  [430] = 386,
+  [431] = 431,
+  [432] = 421,
+  [433] = 433,
+  [434] = 408,
+  [435] = 415,
+  [436] = 382,
+  [437] = 406,
+  [438] = 386,
+  [439] = 386,
+  [440] = 386,
+  [441] = 386,
+  [442] = 386,
+  [443] = 386,
+  [444] = 386,
+  [445] = 386,
+  [446] = 386,
+  [447] = 391,
+  [448] = 392,
+  [449] = 393,
+  [450] = 394,
+  [451] = 451,
+  [452] = 452,
+  [453] = 433,
+  [454] = 452,
+  [455] = 455,
+  [456] = 455,
+  [457] = 422,
+  [458] = 383,
+  [459] = 384,
+  [460] = 451,
+  [461] = 431,
+  [462] = 425,
+  [463] = 463,
+  [464] = 464,
+  [465] = 465,
+  [466] = 466,
+  [467] = 467,
+  [468] = 468,
+  [469] = 468,
+  [470] = 470,
+  [471] = 471,
+  [472] = 467,
+  [473] = 470,
+  [474] = 474,
+  [475] = 474,
+  [476] = 471,
+  [477] = 477,
+  [478] = 478,
+  [479] = 479,
+  [480] = 480,
+  [481] = 481,
+  [482] = 482,
+  [483] = 483,
+  [484] = 479,
+  [485] = 485,
+  [486] = 43,
+  [487] = 487,
+  [488] = 478,
+  [489] = 489,
+  [490] = 489,
+  [491] = 491,
+  [492] = 491,
+  [493] = 493,
+  [494] = 494,
+  [495] = 493,
+  [496] = 496,
+  [497] = 497,
+  [498] = 496,
+  [499] = 499,
+  [500] = 500,
+  [501] = 501,
+  [502] = 502,
+  [503] = 503,
+  [504] = 504,
+  [505] = 505,
+  [506] = 503,
+  [507] = 507,
+  [508] = 508,
+  [509] = 504,
+  [510] = 500,
+  [511] = 511,
+  [512] = 501,
+  [513] = 513,
+  [514] = 514,
+  [515] = 503,
+  [516] = 516,
+  [517] = 514,
+  [518] = 518,
+  [519] = 514,
+  [520] = 514,
+  [521] = 503,
+  [522] = 503,
+  [523] = 503,
+  [524] = 524,
+  [525] = 503,
+  [526] = 503,
+  [527] = 505,
+  [528] = 528,
+  [529] = 529,
+  [530] = 166,
+  [531] = 531,
+  [532] = 513,
+  [533] = 529,
+  [534] = 534,
+  [535] = 167,
+  [536] = 165,
+  [537] = 168,
+  [538] = 538,
+  [539] = 538,
+  [540] = 503,
+  [541] = 541,
+  [542] = 542,
+  [543] = 541,
+  [544] = 544,
+  [545] = 545,
+  [546] = 546,
+  [547] = 547,
+  [548] = 548,
+  [549] = 549,
+  [550] = 549,
+  [551] = 551,
+  [552] = 551,
+  [553] = 553,
+  [554] = 554,
+  [555] = 555,
+  [556] = 556,
+  [557] = 541,
+  [558] = 558,
+  [559] = 548,
+  [560] = 560,
+  [561] = 561,
+  [562] = 562,
+  [563] = 545,
+  [564] = 564,
+  [565] = 553,
+  [566] = 556,
+  [567] = 567,
+  [568] = 541,
+  [569] = 569,
+  [570] = 570,
+  [571] = 571,
+  [572] = 572,
+  [573] = 573,
+  [574] = 574,
+  [575] = 575,
+  [576] = 53,
+  [577] = 577,
+  [578] = 578,
+  [579] = 575,
+  [580] = 580,
+  [581] = 581,
+  [582] = 582,
+  [583] = 583,
+  [584] = 584,
+  [585] = 585,
+  [586] = 586,
+  [587] = 587,
+  [588] = 583,
+  [589] = 589,
+  [590] = 590,
+  [591] = 591,
+  [592] = 592,
+  [593] = 593,
+  [594] = 594,
+  [595] = 582,
+  [596] = 596,
+  [597] = 584,
+  [598] = 598,
+  [599] = 599,
+  [600] = 600,
+  [601] = 601,
+  [602] = 602,
+  [603] = 603,
+  [604] = 604,
+  [605] = 605,
+  [606] = 606,
+  [607] = 607,
+  [608] = 608,
+  [609] = 609,
+  [610] = 609,
+  [611] = 611,
+  [612] = 612,
+  [613] = 612,
+  [614] = 614,
+  [615] = 602,
+  [616] = 616,
+  [617] = 603,
+  [618] = 602,
+  [619] = 603,
+  [620] = 604,
+  [621] = 605,
+  [622] = 604,
+  [623] = 623,
+  [624] = 604,
+  [625] = 601,
+  [626] = 611,
+  [627] = 605,
+  [628] = 605,
+  [629] = 629,
+  [630] = 630,
+  [631] = 631,
+  [632] = 632,
+  [633] = 603,
+  [634] = 608,
+  [635] = 635,
+  [636] = 631,
+  [637] = 637,
+  [638] = 638,
+  [639] = 616,
+  [640] = 607,
+  [641] = 641,
+  [642] = 611,
+  [643] = 643,
+  [644] = 644,
+  [645] = 645,
+  [646] = 646,
+  [647] = 647,
+  [648] = 648,
+  [649] = 648,
+  [650] = 650,
+  [651] = 651,
+  [652] = 652,
+  [653] = 653,
+  [654] = 632,
+  [655] = 623,
+  [656] = 616,
+  [657] = 657,
+  [658] = 658,
+  [659] = 611,
+  [660] = 629,
+  [661] = 646,
+  [662] = 600,
+  [663] = 652,
+  [664] = 664,
+  [665] = 665,
+  [666] = 652,
+  [667] = 637,
+  [668] = 651,
+  [669] = 669,
+  [670] = 644,
+  [671] = 602,
+  [672] = 612,
+  [673] = 645,
+  [674] = 635,
+  [675] = 647,
+  [676] = 653,
+  [677] = 658,
+  [678] = 652,
+  [679] = 641,
+  [680] = 680,
+};
+
+static TSCharacterRange sym_plain_value_character_set_1[] = {
+  {0, 0x08}, {0x0e, 0x1f}, {'"', '\''}, {'+', '+'}, {'-', ':'}, {'<', 'Z'}, {'\\', '\\'}, {'^', 'z'},
+  {'|', '|'}, {'~', 0x10ffff},
+};
+
+static TSCharacterRange sym_plain_value_character_set_2[] = {
+  {0, 0x08}, {0x0e, 0x1f}, {'"', '\''}, {'*', '+'}, {'-', ':'}, {'<', 'Z'}, {'\\', '\\'}, {'^', 'z'},
+  {'|', '|'}, {'~', 0x10ffff},
+};
+
+static bool ts_lex(TSLexer *lexer, TSStateId state) {
+  START_LEXER();
+  eof = lexer->eof(lexer);
+  switch (state) {
+    case 0:
+      if (eof) ADVANCE(99);
+      ADVANCE_MAP(
+        '!', 50,
+        '"', 27,
+        '#', 125,
+        '$', 355,
+        '&', 115,
+        '\'', 31,
+        '(', 142,
+        ')', 143,
+        '*', 117,
+        '+', 141,
+        ',', 101,
+        '-', 354,
+        '.', 119,
+        '/', 221,
+        ':', 123,
+        ';', 102,
+        '<', 225,
+        '=', 127,
+        '>', 136,
+        '@', 57,
+        '[', 133,
+        ']', 134,
+        '^', 54,
+        'a', 230,
+        'e', 228,
+        'f', 231,
+        'n', 253,
+        'o', 250,
+        's', 241,
+        't', 254,
+        'w', 245,
+        '{', 108,
+        '|', 55,
+        '}', 109,
+        '~', 138,
+        0xb5, 44,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(97);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('b' <= lookahead && lookahead <= 'd')) ADVANCE(232);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(92);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 1:
+      ADVANCE_MAP(
+        '!', 50,
+        '"', 27,
+        '#', 125,
+        '$', 356,
+        '%', 217,
+        '&', 115,
+        '\'', 31,
+        '(', 145,
+        ')', 143,
+        '*', 116,
+        '+', 140,
+        ',', 101,
+        '-', 219,
+        '.', 88,
+        '/', 222,
+        ':', 121,
+        ';', 102,
+        '<', 225,
+        '=', 53,
+        '>', 136,
+        '@', 58,
+        '_', 234,
+        '}', 109,
+        '~', 137,
+        0xb5, 44,
+        'E', 202,
+        'e', 202,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(3);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(184);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(215);
+      END_STATE();
+    case 2:
+      ADVANCE_MAP(
+        '!', 50,
+        '"', 27,
+        '#', 125,
+        '$', 356,
+        '&', 115,
+        '\'', 31,
+        '(', 142,
+        ')', 143,
+        '*', 116,
+        '+', 140,
+        ',', 101,
+        '-', 219,
+        '.', 120,
+        '/', 222,
+        ':', 122,
+        ';', 102,
+        '<', 225,
+        '=', 53,
+        '>', 136,
+        '@', 58,
+        '[', 133,
+        '_', 234,
+        '}', 109,
+        '~', 137,
+        0xb5, 44,
+        'E', 235,
+        'e', 235,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(4);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(184);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(275);
+      END_STATE();
+    case 3:
+      ADVANCE_MAP(
+        '!', 50,
+        '"', 27,
+        '#', 125,
+        '$', 356,
+        '&', 115,
+        '\'', 31,
+        '(', 145,
+        ')', 143,
+        '*', 116,
+        '+', 140,
+        ',', 101,
+        '-', 219,
+        '.', 88,
+        '/', 222,
+        ':', 121,
+        ';', 102,
+        '<', 225,
+        '=', 53,
+        '>', 136,
+        '@', 58,
+        '_', 234,
+        '}', 109,
+        '~', 137,
+        0xb5, 44,
+        'E', 235,
+        'e', 235,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(3);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(184);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(275);
+      END_STATE();
+    case 4:
+      ADVANCE_MAP(
+        '!', 50,
+        '"', 27,
+        '#', 125,
+        '$', 356,
+        '&', 115,
+        '\'', 31,
+        '(', 145,
+        ')', 143,
+        '*', 116,
+        '+', 140,
+        ',', 101,
+        '-', 219,
+        '.', 120,
+        '/', 222,
+        ':', 122,
+        ';', 102,
+        '<', 225,
+        '=', 53,
+        '>', 136,
+        '@', 58,
+        '[', 133,
+        '_', 234,
+        '}', 109,
+        '~', 137,
+        0xb5, 44,
+        'E', 235,
+        'e', 235,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(4);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(184);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(275);
+      END_STATE();
+    case 5:
+      ADVANCE_MAP(
+        '!', 50,
+        '"', 27,
+        '#', 125,
+        '$', 356,
+        '&', 115,
+        '\'', 31,
+        '(', 145,
+        '*', 116,
+        '+', 140,
+        '-', 219,
+        '.', 88,
+        '/', 222,
+        ';', 102,
+        '<', 225,
+        '=', 53,
+        '>', 136,
+        '@', 58,
+        '_', 234,
+        'n', 269,
+        'o', 268,
+        's', 265,
+        '{', 108,
+        '~', 137,
+        0xb5, 44,
+        'E', 235,
+        'e', 235,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(5);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(184);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(275);
+      END_STATE();
+    case 6:
+      ADVANCE_MAP(
+        '!', 50,
+        '(', 142,
+        ')', 143,
+        '*', 116,
+        '+', 139,
+        ',', 101,
+        '-', 218,
+        '.', 40,
+        '/', 221,
+        ':', 121,
+        ';', 102,
+        '<', 225,
+        '=', 53,
+        '>', 136,
+        ']', 134,
+        'a', 73,
+        'o', 79,
+        'w', 66,
+        '{', 108,
+        '}', 109,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(7);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('b' <= lookahead && lookahead <= 'f')) ADVANCE(92);
+      END_STATE();
+    case 7:
+      ADVANCE_MAP(
+        '!', 50,
+        ')', 143,
+        '*', 116,
+        '+', 139,
+        ',', 101,
+        '-', 218,
+        '.', 40,
+        '/', 221,
+        ':', 121,
+        ';', 102,
+        '<', 225,
+        '=', 53,
+        '>', 136,
+        ']', 134,
+        'a', 72,
+        'o', 79,
+        'w', 66,
+        '{', 108,
+        '}', 109,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(7);
+      END_STATE();
+    case 8:
+      ADVANCE_MAP(
+        '!', 67,
+        '"', 27,
+        '#', 125,
+        '&', 115,
+        '\'', 31,
+        '(', 142,
+        '*', 116,
+        '+', 139,
+        ',', 101,
+        '-', 93,
+        '.', 118,
+        '/', 33,
+        ':', 123,
+        ';', 102,
+        '>', 135,
+        '@', 61,
+        '[', 133,
+        'e', 237,
+        '{', 108,
+        '}', 109,
+        '~', 137,
+        0xb5, 44,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(9);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 9:
+      ADVANCE_MAP(
+        '!', 67,
+        '"', 27,
+        '#', 125,
+        '&', 115,
+        '\'', 31,
+        '*', 116,
+        '+', 139,
+        ',', 101,
+        '-', 93,
+        '.', 118,
+        '/', 33,
+        ':', 123,
+        ';', 102,
+        '>', 135,
+        '@', 61,
+        '[', 133,
+        'e', 237,
+        '{', 108,
+        '}', 109,
+        '~', 137,
+        0xb5, 44,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(9);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 10:
+      ADVANCE_MAP(
+        '!', 49,
+        '"', 27,
+        '#', 125,
+        '$', 356,
+        '%', 217,
+        '&', 115,
+        '\'', 31,
+        '(', 145,
+        '*', 116,
+        '+', 140,
+        '-', 219,
+        '.', 88,
+        '/', 222,
+        ';', 102,
+        '<', 225,
+        '=', 53,
+        '>', 136,
+        '@', 58,
+        '_', 234,
+        'n', 209,
+        'o', 208,
+        's', 205,
+        '~', 137,
+        0xb5, 44,
+        'E', 202,
+        'e', 202,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(13);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(184);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(215);
+      END_STATE();
+    case 11:
+      ADVANCE_MAP(
+        '!', 49,
+        '"', 27,
+        '#', 125,
+        '$', 356,
+        '&', 115,
+        '\'', 31,
+        '(', 142,
+        '*', 116,
+        '+', 140,
+        '-', 219,
+        '.', 88,
+        '/', 222,
+        ';', 102,
+        '<', 225,
+        '=', 53,
+        '>', 136,
+        '@', 58,
+        '_', 234,
+        'n', 269,
+        'o', 268,
+        's', 265,
+        '~', 137,
+        0xb5, 44,
+        'E', 235,
+        'e', 235,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(13);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(184);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(275);
+      END_STATE();
+    case 12:
+      ADVANCE_MAP(
+        '!', 49,
+        '"', 27,
+        '#', 125,
+        '$', 356,
+        '&', 115,
+        '\'', 31,
+        '(', 145,
+        ')', 143,
+        '*', 116,
+        '+', 140,
+        ',', 101,
+        '-', 219,
+        '.', 41,
+        '/', 222,
+        ':', 121,
+        ';', 102,
+        '<', 225,
+        '=', 53,
+        '>', 136,
+        '@', 58,
+        '_', 234,
+        '~', 137,
+        0xb5, 44,
+        'E', 235,
+        'e', 235,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(12);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(184);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(275);
+      END_STATE();
+    case 13:
+      ADVANCE_MAP(
+        '!', 49,
+        '"', 27,
+        '#', 125,
+        '$', 356,
+        '&', 115,
+        '\'', 31,
+        '(', 145,
+        '*', 116,
+        '+', 140,
+        '-', 219,
+        '.', 88,
+        '/', 222,
+        ';', 102,
+        '<', 225,
+        '=', 53,
+        '>', 136,
+        '@', 58,
+        '_', 234,
+        'n', 269,
+        'o', 268,
+        's', 265,
+        '~', 137,
+        0xb5, 44,
+        'E', 235,
+        'e', 235,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(13);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(184);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(275);
+      END_STATE();
+    case 14:
+      ADVANCE_MAP(
+        '!', 49,
+        '#', 125,
+        '&', 115,
+        '(', 142,
+        ')', 143,
+        '*', 116,
+        '+', 139,
+        ',', 101,
+        '-', 220,
+        '.', 118,
+        '/', 221,
+        ':', 122,
+        ';', 102,
+        '<', 225,
+        '=', 53,
+        '>', 136,
+        '@', 87,
+        '[', 133,
+        'a', 249,
+        'n', 253,
+        'o', 250,
+        's', 241,
+        '{', 108,
+        '~', 137,
+        0xb5, 44,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(15);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 15:
+      ADVANCE_MAP(
+        '!', 49,
+        '#', 125,
+        '&', 115,
+        '(', 145,
+        ')', 143,
+        '*', 116,
+        '+', 139,
+        ',', 101,
+        '-', 220,
+        '.', 118,
+        '/', 221,
+        ':', 122,
+        ';', 102,
+        '<', 225,
+        '=', 53,
+        '>', 136,
+        '@', 87,
+        '[', 133,
+        'a', 249,
+        'n', 253,
+        'o', 250,
+        's', 241,
+        '{', 108,
+        '~', 137,
+        0xb5, 44,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(15);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 16:
+      ADVANCE_MAP(
+        '!', 49,
+        '#', 125,
+        '&', 115,
+        '(', 145,
+        ')', 143,
+        '*', 116,
+        '+', 139,
+        ',', 101,
+        '-', 220,
+        '.', 118,
+        '/', 221,
+        ':', 122,
+        ';', 102,
+        '<', 225,
+        '=', 53,
+        '>', 136,
+        '@', 87,
+        '[', 133,
+        'n', 253,
+        'o', 251,
+        's', 241,
+        '{', 108,
+        '~', 137,
+        0xb5, 44,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(16);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 17:
+      ADVANCE_MAP(
+        '!', 49,
+        '%', 217,
+        '(', 145,
+        '*', 116,
+        '+', 139,
+        '-', 220,
+        '/', 221,
+        ';', 102,
+        '<', 225,
+        '=', 53,
+        '>', 136,
+        '@', 87,
+        '_', 262,
+        'n', 195,
+        'o', 194,
+        's', 191,
+        0xb5, 44,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(20);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(201);
+      END_STATE();
+    case 18:
+      ADVANCE_MAP(
+        '!', 49,
+        '(', 142,
+        '*', 116,
+        '+', 139,
+        '-', 220,
+        '/', 221,
+        ';', 102,
+        '<', 225,
+        '=', 53,
+        '>', 136,
+        '@', 87,
+        'n', 253,
+        'o', 251,
+        's', 241,
+        0xb5, 44,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(20);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 19:
+      ADVANCE_MAP(
+        '!', 49,
+        '(', 145,
+        '*', 116,
+        '+', 139,
+        ',', 101,
+        '-', 220,
+        '/', 221,
+        ';', 102,
+        '<', 225,
+        '=', 53,
+        '>', 136,
+        '@', 87,
+        'a', 249,
+        'n', 253,
+        'o', 250,
+        's', 241,
+        0xb5, 44,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(19);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 20:
+      ADVANCE_MAP(
+        '!', 49,
+        '(', 145,
+        '*', 116,
+        '+', 139,
+        '-', 220,
+        '/', 221,
+        ';', 102,
+        '<', 225,
+        '=', 53,
+        '>', 136,
+        '@', 87,
+        'n', 253,
+        'o', 251,
+        's', 241,
+        0xb5, 44,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(20);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 21:
+      ADVANCE_MAP(
+        '!', 49,
+        ')', 143,
+        '*', 116,
+        '+', 139,
+        ',', 101,
+        '-', 218,
+        '/', 221,
+        ':', 121,
+        ';', 102,
+        '<', 225,
+        '=', 53,
+        '>', 136,
+        ']', 134,
+        '{', 108,
+        '}', 109,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(21);
+      END_STATE();
+    case 22:
+      ADVANCE_MAP(
+        '!', 49,
+        ')', 143,
+        '*', 116,
+        '+', 139,
+        ',', 101,
+        '-', 218,
+        '/', 221,
+        ':', 121,
+        ';', 102,
+        '<', 225,
+        '=', 53,
+        '>', 136,
+        ']', 134,
+        '{', 108,
+        '}', 109,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(21);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(217);
+      END_STATE();
+    case 23:
+      ADVANCE_MAP(
+        '"', 27,
+        '#', 125,
+        '&', 115,
+        '\'', 31,
+        '(', 142,
+        '+', 141,
+        ',', 101,
+        '-', 47,
+        '.', 118,
+        '/', 34,
+        ':', 123,
+        '>', 135,
+        '[', 133,
+        '_', 276,
+        '{', 108,
+        '~', 137,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(26);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(278);
+      END_STATE();
+    case 24:
+      ADVANCE_MAP(
+        '"', 27,
+        '#', 125,
+        '&', 115,
+        '\'', 31,
+        ')', 143,
+        '*', 116,
+        '+', 139,
+        ',', 101,
+        '-', 354,
+        '.', 118,
+        '/', 33,
+        ':', 122,
+        '>', 135,
+        '@', 86,
+        '[', 133,
+        '{', 108,
+        '~', 137,
+        0xb5, 44,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(25);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(281);
+      END_STATE();
+    case 25:
+      ADVANCE_MAP(
+        '"', 27,
+        '#', 125,
+        '&', 115,
+        '\'', 31,
+        ')', 143,
+        '*', 116,
+        '+', 139,
+        ',', 101,
+        '-', 93,
+        '.', 118,
+        '/', 33,
+        ':', 122,
+        '>', 135,
+        '@', 86,
+        '[', 133,
+        '{', 108,
+        '~', 137,
+        0xb5, 44,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(25);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(281);
+      END_STATE();
+    case 26:
+      ADVANCE_MAP(
+        '"', 27,
+        '#', 125,
+        '&', 115,
+        '\'', 31,
+        '+', 141,
+        ',', 101,
+        '-', 47,
+        '.', 118,
+        '/', 34,
+        ':', 123,
+        '>', 135,
+        '[', 133,
+        '_', 276,
+        '{', 108,
+        '~', 137,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(26);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(278);
+      END_STATE();
+    case 27:
+      if (lookahead == '"') ADVANCE(181);
+      if (lookahead == '\\') ADVANCE(28);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(27);
+      END_STATE();
+    case 28:
+      if (lookahead == '"') ADVANCE(182);
+      if (lookahead == '\\') ADVANCE(28);
+      if (lookahead != 0) ADVANCE(27);
+      END_STATE();
+    case 29:
+      ADVANCE_MAP(
+        '#', 125,
+        '&', 115,
+        '+', 139,
+        ',', 101,
+        '-', 93,
+        '.', 118,
+        '/', 33,
+        ':', 123,
+        '>', 135,
+        '@', 86,
+        '[', 133,
+        '{', 108,
+        '~', 137,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(29);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(281);
+      END_STATE();
+    case 30:
+      ADVANCE_MAP(
+        '$', 51,
+        '(', 145,
+        '*', 52,
+        '+', 141,
+        '-', 93,
+        '/', 33,
+        ':', 121,
+        ';', 102,
+        '=', 126,
+        '@', 87,
+        ']', 134,
+        '^', 54,
+        'n', 253,
+        'o', 251,
+        's', 241,
+        '{', 108,
+        '|', 55,
+        '~', 56,
+        0xb5, 44,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(30);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 31:
+      if (lookahead == '\'') ADVANCE(181);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(31);
+      END_STATE();
+    case 32:
+      if (lookahead == '\'') ADVANCE(183);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead != 0) ADVANCE(31);
+      END_STATE();
+    case 33:
+      if (lookahead == '*') ADVANCE(36);
+      if (lookahead == '/') ADVANCE(340);
+      END_STATE();
+    case 34:
+      if (lookahead == '*') ADVANCE(36);
+      if (lookahead == '/') ADVANCE(336);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(48);
+      END_STATE();
+    case 35:
+      if (lookahead == '*') ADVANCE(35);
+      if (lookahead == '/') ADVANCE(335);
+      if (lookahead != 0) ADVANCE(36);
+      END_STATE();
+    case 36:
+      if (lookahead == '*') ADVANCE(35);
+      if (lookahead != 0) ADVANCE(36);
+      END_STATE();
+    case 37:
+      if (lookahead == '-') ADVANCE(93);
+      if (lookahead == '/') ADVANCE(33);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(38);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(92);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(279);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(281);
+      END_STATE();
+    case 38:
+      if (lookahead == '-') ADVANCE(93);
+      if (lookahead == '/') ADVANCE(33);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(38);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(281);
+      END_STATE();
+    case 39:
+      if (lookahead == '-') ADVANCE(89);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(188);
+      END_STATE();
+    case 40:
+      if (lookahead == '.') ADVANCE(42);
+      END_STATE();
+    case 41:
+      if (lookahead == '.') ADVANCE(42);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(187);
+      END_STATE();
+    case 42:
+      if (lookahead == '.') ADVANCE(347);
+      END_STATE();
+    case 43:
+      if (lookahead == '.') ADVANCE(345);
+      END_STATE();
+    case 44:
+      if (lookahead == '.') ADVANCE(45);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(346);
+      END_STATE();
+    case 45:
+      if (lookahead == '.') ADVANCE(43);
+      END_STATE();
+    case 46:
+      if (lookahead == '/') ADVANCE(33);
+      if (lookahead == 'f') ADVANCE(81);
+      if (lookahead == 't') ADVANCE(76);
+      if (lookahead == '}') ADVANCE(109);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(90);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(46);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(185);
+      END_STATE();
+    case 47:
+      if (lookahead == '/') ADVANCE(95);
+      if (lookahead == '-' ||
+          lookahead == '_') ADVANCE(276);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(278);
+      END_STATE();
+    case 48:
+      if (lookahead == '/') ADVANCE(95);
+      if (lookahead == '-' ||
+          lookahead == '_') ADVANCE(48);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(344);
+      END_STATE();
+    case 49:
+      if (lookahead == '=') ADVANCE(224);
+      END_STATE();
+    case 50:
+      if (lookahead == '=') ADVANCE(224);
+      if (lookahead == 'i') ADVANCE(68);
+      END_STATE();
+    case 51:
+      if (lookahead == '=') ADVANCE(132);
+      END_STATE();
+    case 52:
+      if (lookahead == '=') ADVANCE(131);
+      END_STATE();
+    case 53:
+      if (lookahead == '=') ADVANCE(223);
+      END_STATE();
+    case 54:
+      if (lookahead == '=') ADVANCE(129);
+      END_STATE();
+    case 55:
+      if (lookahead == '=') ADVANCE(130);
+      END_STATE();
+    case 56:
+      if (lookahead == '=') ADVANCE(128);
+      END_STATE();
+    case 57:
+      ADVANCE_MAP(
+        '@', 358,
+        'c', 301,
+        'i', 305,
+        'k', 290,
+        'm', 291,
+        'n', 285,
+        'p', 304,
+        's', 330,
+        '{', 353,
+      );
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 58:
+      if (lookahead == '@') ADVANCE(358);
+      if (lookahead == '{') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(359);
+      END_STATE();
+    case 59:
+      if (lookahead == '@') ADVANCE(94);
+      END_STATE();
+    case 60:
+      if (lookahead == 'a') ADVANCE(74);
+      END_STATE();
+    case 61:
+      ADVANCE_MAP(
+        'c', 301,
+        'i', 305,
+        'k', 290,
+        'm', 291,
+        'n', 285,
+        'p', 304,
+        's', 330,
+        '{', 353,
+      );
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 62:
+      if (lookahead == 'd') ADVANCE(349);
+      END_STATE();
+    case 63:
+      if (lookahead == 'd') ADVANCE(146);
+      END_STATE();
+    case 64:
+      if (lookahead == 'e') ADVANCE(70);
+      END_STATE();
+    case 65:
+      if (lookahead == 'e') ADVANCE(71);
+      END_STATE();
+    case 66:
+      if (lookahead == 'h') ADVANCE(65);
+      END_STATE();
+    case 67:
+      if (lookahead == 'i') ADVANCE(68);
+      END_STATE();
+    case 68:
+      if (lookahead == 'm') ADVANCE(78);
+      END_STATE();
+    case 69:
+      if (lookahead == 'm') ADVANCE(110);
+      END_STATE();
+    case 70:
+      if (lookahead == 'n') ADVANCE(62);
+      END_STATE();
+    case 71:
+      if (lookahead == 'n') ADVANCE(351);
+      END_STATE();
+    case 72:
+      if (lookahead == 'n') ADVANCE(63);
+      END_STATE();
+    case 73:
+      if (lookahead == 'n') ADVANCE(63);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(91);
+      END_STATE();
+    case 74:
+      if (lookahead == 'n') ADVANCE(84);
+      END_STATE();
+    case 75:
+      if (lookahead == 'o') ADVANCE(80);
+      END_STATE();
+    case 76:
+      if (lookahead == 'o') ADVANCE(112);
+      END_STATE();
+    case 77:
+      if (lookahead == 'o') ADVANCE(69);
+      END_STATE();
+    case 78:
+      if (lookahead == 'p') ADVANCE(75);
+      END_STATE();
+    case 79:
+      if (lookahead == 'r') ADVANCE(148);
+      END_STATE();
+    case 80:
+      if (lookahead == 'r') ADVANCE(83);
+      END_STATE();
+    case 81:
+      if (lookahead == 'r') ADVANCE(77);
+      END_STATE();
+    case 82:
+      if (lookahead == 't') ADVANCE(64);
+      END_STATE();
+    case 83:
+      if (lookahead == 't') ADVANCE(60);
+      END_STATE();
+    case 84:
+      if (lookahead == 't') ADVANCE(144);
+      END_STATE();
+    case 85:
+      if (lookahead == 'x') ADVANCE(82);
+      END_STATE();
+    case 86:
+      if (lookahead == '{') ADVANCE(353);
+      END_STATE();
+    case 87:
+      if (lookahead == '{') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(359);
+      END_STATE();
+    case 88:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(187);
+      END_STATE();
+    case 89:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(188);
+      END_STATE();
+    case 90:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(185);
+      END_STATE();
+    case 91:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(179);
+      END_STATE();
+    case 92:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(91);
+      END_STATE();
+    case 93:
+      if (lookahead == '-' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(281);
+      END_STATE();
+    case 94:
+      if (lookahead == '-' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(359);
+      END_STATE();
+    case 95:
+      if ((!eof && set_contains(sym_plain_value_character_set_1, 10, lookahead))) ADVANCE(48);
+      END_STATE();
+    case 96:
+      if ((!eof && set_contains(sym_plain_value_character_set_1, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 97:
+      if (eof) ADVANCE(99);
+      ADVANCE_MAP(
+        '!', 50,
+        '"', 27,
+        '#', 125,
+        '$', 355,
+        '&', 115,
+        '\'', 31,
+        '(', 145,
+        ')', 143,
+        '*', 117,
+        '+', 141,
+        ',', 101,
+        '-', 220,
+        '.', 119,
+        '/', 221,
+        ':', 123,
+        ';', 102,
+        '<', 225,
+        '=', 127,
+        '>', 136,
+        '@', 57,
+        '[', 133,
+        ']', 134,
+        '^', 54,
+        'a', 249,
+        'e', 237,
+        'f', 258,
+        'n', 253,
+        'o', 250,
+        's', 241,
+        't', 254,
+        'w', 245,
+        '{', 108,
+        '|', 55,
+        '}', 109,
+        '~', 138,
+        0xb5, 44,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(97);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 98:
+      if (eof) ADVANCE(99);
+      ADVANCE_MAP(
+        '"', 27,
+        '#', 125,
+        '&', 115,
+        '\'', 31,
+        '(', 145,
+        ')', 143,
+        '*', 116,
+        '+', 141,
+        ',', 101,
+        '-', 93,
+        '.', 118,
+        '/', 33,
+        ':', 122,
+        '>', 135,
+        '@', 61,
+        '[', 133,
+        'e', 237,
+        '{', 108,
+        '}', 109,
+        '~', 137,
+        0xb5, 44,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(98);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 99:
+      ACCEPT_TOKEN(ts_builtin_sym_end);
+      END_STATE();
+    case 100:
+      ACCEPT_TOKEN(anon_sym_ATimport);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 101:
+      ACCEPT_TOKEN(anon_sym_COMMA);
+      END_STATE();
+    case 102:
+      ACCEPT_TOKEN(anon_sym_SEMI);
+      END_STATE();
+    case 103:
+      ACCEPT_TOKEN(anon_sym_ATmedia);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 104:
+      ACCEPT_TOKEN(anon_sym_ATcharset);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 105:
+      ACCEPT_TOKEN(anon_sym_ATnamespace);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 106:
+      ACCEPT_TOKEN(anon_sym_ATkeyframes);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 107:
+      ACCEPT_TOKEN(aux_sym_keyframes_statement_token1);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 108:
+      ACCEPT_TOKEN(anon_sym_LBRACE);
+      END_STATE();
+    case 109:
+      ACCEPT_TOKEN(anon_sym_RBRACE);
+      END_STATE();
+    case 110:
+      ACCEPT_TOKEN(sym_from);
+      END_STATE();
+    case 111:
+      ACCEPT_TOKEN(sym_from);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 112:
+      ACCEPT_TOKEN(sym_to);
+      END_STATE();
+    case 113:
+      ACCEPT_TOKEN(sym_to);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 114:
+      ACCEPT_TOKEN(anon_sym_ATsupports);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 115:
+      ACCEPT_TOKEN(sym_nesting_selector);
+      END_STATE();
+    case 116:
+      ACCEPT_TOKEN(anon_sym_STAR);
+      END_STATE();
+    case 117:
+      ACCEPT_TOKEN(anon_sym_STAR);
+      if (lookahead == '=') ADVANCE(131);
+      END_STATE();
+    case 118:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      END_STATE();
+    case 119:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      if (lookahead == '.') ADVANCE(42);
+      END_STATE();
+    case 120:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(187);
+      END_STATE();
+    case 121:
+      ACCEPT_TOKEN(anon_sym_COLON);
+      END_STATE();
+    case 122:
+      ACCEPT_TOKEN(anon_sym_COLON);
+      if (lookahead == ':') ADVANCE(124);
+      END_STATE();
+    case 123:
+      ACCEPT_TOKEN(anon_sym_COLON);
+      if (lookahead == ':') ADVANCE(124);
+      if (lookahead == 'e') ADVANCE(85);
+      END_STATE();
+    case 124:
+      ACCEPT_TOKEN(anon_sym_COLON_COLON);
+      END_STATE();
+    case 125:
+      ACCEPT_TOKEN(anon_sym_POUND);
+      END_STATE();
+    case 126:
+      ACCEPT_TOKEN(sym_equal);
+      END_STATE();
+    case 127:
+      ACCEPT_TOKEN(sym_equal);
+      if (lookahead == '=') ADVANCE(223);
+      END_STATE();
+    case 128:
+      ACCEPT_TOKEN(sym_contains_word_equal);
+      END_STATE();
+    case 129:
+      ACCEPT_TOKEN(sym_starts_with_equal);
+      END_STATE();
+    case 130:
+      ACCEPT_TOKEN(sym_dash_equal);
+      END_STATE();
+    case 131:
+      ACCEPT_TOKEN(sym_contains_equal);
+      END_STATE();
+    case 132:
+      ACCEPT_TOKEN(sym_ends_equal);
+      END_STATE();
+    case 133:
+      ACCEPT_TOKEN(anon_sym_LBRACK);
+      END_STATE();
+    case 134:
+      ACCEPT_TOKEN(anon_sym_RBRACK);
+      END_STATE();
+    case 135:
+      ACCEPT_TOKEN(anon_sym_GT);
+      END_STATE();
+    case 136:
+      ACCEPT_TOKEN(anon_sym_GT);
+      if (lookahead == '=') ADVANCE(227);
+      END_STATE();
+    case 137:
+      ACCEPT_TOKEN(anon_sym_TILDE);
+      END_STATE();
+    case 138:
+      ACCEPT_TOKEN(anon_sym_TILDE);
+      if (lookahead == '=') ADVANCE(128);
+      END_STATE();
+    case 139:
+      ACCEPT_TOKEN(anon_sym_PLUS);
+      END_STATE();
+    case 140:
+      ACCEPT_TOKEN(anon_sym_PLUS);
+      if (lookahead == '.') ADVANCE(88);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(39);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(184);
+      END_STATE();
+    case 141:
+      ACCEPT_TOKEN(anon_sym_PLUS);
+      if (lookahead == '_') ADVANCE(360);
+      END_STATE();
+    case 142:
+      ACCEPT_TOKEN(anon_sym_LPAREN);
+      END_STATE();
+    case 143:
+      ACCEPT_TOKEN(anon_sym_RPAREN);
+      END_STATE();
+    case 144:
+      ACCEPT_TOKEN(sym_important);
+      END_STATE();
+    case 145:
+      ACCEPT_TOKEN(anon_sym_LPAREN2);
+      END_STATE();
+    case 146:
+      ACCEPT_TOKEN(sym_and);
+      END_STATE();
+    case 147:
+      ACCEPT_TOKEN(sym_and);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 148:
+      ACCEPT_TOKEN(sym_or);
+      END_STATE();
+    case 149:
+      ACCEPT_TOKEN(sym_or);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 150:
+      ACCEPT_TOKEN(sym_not);
+      if (lookahead == '%') ADVANCE(217);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '_') ADVANCE(262);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(201);
+      END_STATE();
+    case 151:
+      ACCEPT_TOKEN(sym_not);
+      if (lookahead == '%') ADVANCE(216);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == '_') ADVANCE(275);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(215);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 152:
+      ACCEPT_TOKEN(sym_not);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 153:
+      ACCEPT_TOKEN(sym_not);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(275);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 154:
+      ACCEPT_TOKEN(sym_only);
+      if (lookahead == '%') ADVANCE(217);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '_') ADVANCE(262);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(201);
+      END_STATE();
+    case 155:
+      ACCEPT_TOKEN(sym_only);
+      if (lookahead == '%') ADVANCE(216);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == '_') ADVANCE(275);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(215);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 156:
+      ACCEPT_TOKEN(sym_only);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 157:
+      ACCEPT_TOKEN(sym_only);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(275);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 158:
+      ACCEPT_TOKEN(anon_sym_selector);
+      if (lookahead == '%') ADVANCE(217);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '_') ADVANCE(262);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(201);
+      END_STATE();
+    case 159:
+      ACCEPT_TOKEN(anon_sym_selector);
+      if (lookahead == '%') ADVANCE(216);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == '_') ADVANCE(275);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(215);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 160:
+      ACCEPT_TOKEN(anon_sym_selector);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 161:
+      ACCEPT_TOKEN(anon_sym_selector);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(275);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 162:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      END_STATE();
+    case 163:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (lookahead == '-') ADVANCE(281);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'h') ADVANCE(348);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(176);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(167);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 164:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (lookahead == '-') ADVANCE(281);
+      if (lookahead == '.') ADVANCE(59);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(180);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(169);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 165:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (lookahead == '-') ADVANCE(281);
+      if (lookahead == '.') ADVANCE(59);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(170);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(164);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 166:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (lookahead == '-') ADVANCE(281);
+      if (lookahead == '.') ADVANCE(59);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(172);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(165);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 167:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (lookahead == '-') ADVANCE(281);
+      if (lookahead == '.') ADVANCE(59);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(174);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(166);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 168:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (lookahead == '-') ADVANCE(281);
+      if (lookahead == '.') ADVANCE(59);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(176);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(167);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 169:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 170:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(180);
+      if (lookahead == '-' ||
+          ('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(281);
+      END_STATE();
+    case 171:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(162);
+      END_STATE();
+    case 172:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(170);
+      if (lookahead == '-' ||
+          ('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(281);
+      END_STATE();
+    case 173:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(171);
+      END_STATE();
+    case 174:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(172);
+      if (lookahead == '-' ||
+          ('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(281);
+      END_STATE();
+    case 175:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(173);
+      END_STATE();
+    case 176:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(174);
+      if (lookahead == '-' ||
+          ('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(281);
+      END_STATE();
+    case 177:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(175);
+      END_STATE();
+    case 178:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(176);
+      if (lookahead == '-' ||
+          ('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(281);
+      END_STATE();
+    case 179:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(177);
+      END_STATE();
+    case 180:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(281);
+      END_STATE();
+    case 181:
+      ACCEPT_TOKEN(sym_string_value);
+      END_STATE();
+    case 182:
+      ACCEPT_TOKEN(sym_string_value);
+      if (lookahead == '"') ADVANCE(181);
+      if (lookahead == '\\') ADVANCE(28);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(27);
+      END_STATE();
+    case 183:
+      ACCEPT_TOKEN(sym_string_value);
+      if (lookahead == '\'') ADVANCE(181);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(31);
+      END_STATE();
+    case 184:
+      ACCEPT_TOKEN(aux_sym_integer_value_token1);
+      if (lookahead == '.') ADVANCE(88);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(39);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(184);
+      END_STATE();
+    case 185:
+      ACCEPT_TOKEN(aux_sym_integer_value_token1);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(185);
+      END_STATE();
+    case 186:
+      ACCEPT_TOKEN(aux_sym_float_value_token1);
+      if (lookahead == '/') ADVANCE(96);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(186);
+      if (lookahead == '-' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(278);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 187:
+      ACCEPT_TOKEN(aux_sym_float_value_token1);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(39);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(187);
+      END_STATE();
+    case 188:
+      ACCEPT_TOKEN(aux_sym_float_value_token1);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(188);
+      END_STATE();
+    case 189:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(217);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '_') ADVANCE(262);
+      if (lookahead == 'c') ADVANCE(199);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(201);
+      END_STATE();
+    case 190:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(217);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '_') ADVANCE(262);
+      if (lookahead == 'e') ADVANCE(189);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(201);
+      END_STATE();
+    case 191:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(217);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '_') ADVANCE(262);
+      if (lookahead == 'e') ADVANCE(193);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(201);
+      END_STATE();
+    case 192:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(217);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '_') ADVANCE(262);
+      if (lookahead == 'l') ADVANCE(200);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(201);
+      END_STATE();
+    case 193:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(217);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '_') ADVANCE(262);
+      if (lookahead == 'l') ADVANCE(190);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(201);
+      END_STATE();
+    case 194:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(217);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '_') ADVANCE(262);
+      if (lookahead == 'n') ADVANCE(192);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(201);
+      END_STATE();
+    case 195:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(217);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '_') ADVANCE(262);
+      if (lookahead == 'o') ADVANCE(198);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(201);
+      END_STATE();
+    case 196:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(217);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '_') ADVANCE(262);
+      if (lookahead == 'o') ADVANCE(197);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(201);
+      END_STATE();
+    case 197:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(217);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '_') ADVANCE(262);
+      if (lookahead == 'r') ADVANCE(158);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(201);
+      END_STATE();
+    case 198:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(217);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '_') ADVANCE(262);
+      if (lookahead == 't') ADVANCE(150);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(201);
+      END_STATE();
+    case 199:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(217);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '_') ADVANCE(262);
+      if (lookahead == 't') ADVANCE(196);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(201);
+      END_STATE();
+    case 200:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(217);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '_') ADVANCE(262);
+      if (lookahead == 'y') ADVANCE(154);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(201);
+      END_STATE();
+    case 201:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(217);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '_') ADVANCE(262);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(201);
+      END_STATE();
+    case 202:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(216);
+      if (lookahead == '-') ADVANCE(277);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == '_') ADVANCE(275);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(186);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(215);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 203:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(216);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == '_') ADVANCE(275);
+      if (lookahead == 'c') ADVANCE(213);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(215);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 204:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(216);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == '_') ADVANCE(275);
+      if (lookahead == 'e') ADVANCE(203);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(215);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 205:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(216);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == '_') ADVANCE(275);
+      if (lookahead == 'e') ADVANCE(207);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(215);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 206:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(216);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == '_') ADVANCE(275);
+      if (lookahead == 'l') ADVANCE(214);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(215);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 207:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(216);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == '_') ADVANCE(275);
+      if (lookahead == 'l') ADVANCE(204);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(215);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 208:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(216);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == '_') ADVANCE(275);
+      if (lookahead == 'n') ADVANCE(206);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(215);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 209:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(216);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == '_') ADVANCE(275);
+      if (lookahead == 'o') ADVANCE(212);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(215);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 210:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(216);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == '_') ADVANCE(275);
+      if (lookahead == 'o') ADVANCE(211);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(215);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 211:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(216);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == '_') ADVANCE(275);
+      if (lookahead == 'r') ADVANCE(159);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(215);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 212:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(216);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == '_') ADVANCE(275);
+      if (lookahead == 't') ADVANCE(151);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(215);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 213:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(216);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == '_') ADVANCE(275);
+      if (lookahead == 't') ADVANCE(210);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(215);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 214:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(216);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == '_') ADVANCE(275);
+      if (lookahead == 'y') ADVANCE(155);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(215);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 215:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(216);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == '_') ADVANCE(275);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(215);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 216:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(216);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 217:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(217);
+      END_STATE();
+    case 218:
+      ACCEPT_TOKEN(sym_minus);
+      END_STATE();
+    case 219:
+      ACCEPT_TOKEN(sym_minus);
+      if (lookahead == '.') ADVANCE(88);
+      if (lookahead == '/') ADVANCE(95);
+      if (lookahead == '-' ||
+          lookahead == '_') ADVANCE(276);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(236);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(184);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(278);
+      END_STATE();
+    case 220:
+      ACCEPT_TOKEN(sym_minus);
+      if (lookahead == '-' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(281);
+      END_STATE();
+    case 221:
+      ACCEPT_TOKEN(sym_divide);
+      if (lookahead == '*') ADVANCE(36);
+      if (lookahead == '/') ADVANCE(340);
+      END_STATE();
+    case 222:
+      ACCEPT_TOKEN(sym_divide);
+      if (lookahead == '*') ADVANCE(36);
+      if (lookahead == '/') ADVANCE(336);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(48);
+      END_STATE();
+    case 223:
+      ACCEPT_TOKEN(anon_sym_EQ_EQ);
+      END_STATE();
+    case 224:
+      ACCEPT_TOKEN(anon_sym_BANG_EQ);
+      END_STATE();
+    case 225:
+      ACCEPT_TOKEN(anon_sym_LT);
+      if (lookahead == '=') ADVANCE(226);
+      END_STATE();
+    case 226:
+      ACCEPT_TOKEN(anon_sym_LT_EQ);
+      END_STATE();
+    case 227:
+      ACCEPT_TOKEN(anon_sym_GT_EQ);
+      END_STATE();
+    case 228:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '-') ADVANCE(281);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'a') ADVANCE(229);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(280);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('b' <= lookahead && lookahead <= 'f')) ADVANCE(233);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 229:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '-') ADVANCE(281);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'c') ADVANCE(163);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(178);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(168);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 230:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '-') ADVANCE(281);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'n') ADVANCE(240);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(280);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(233);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 231:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '-') ADVANCE(281);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'r') ADVANCE(255);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(280);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(233);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 232:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '-') ADVANCE(281);
+      if (lookahead == '.') ADVANCE(59);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(280);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(233);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 233:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '-') ADVANCE(281);
+      if (lookahead == '.') ADVANCE(59);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(178);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(168);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 234:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '-') ADVANCE(276);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '/') ADVANCE(95);
+      if (lookahead == '_') ADVANCE(234);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(275);
+      END_STATE();
+    case 235:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '-') ADVANCE(277);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(186);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(275);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 236:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '-') ADVANCE(277);
+      if (lookahead == '/') ADVANCE(96);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(186);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(278);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 237:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'a') ADVANCE(238);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 238:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'c') ADVANCE(244);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 239:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'c') ADVANCE(260);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 240:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'd') ADVANCE(147);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 241:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'e') ADVANCE(247);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 242:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'e') ADVANCE(252);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 243:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'e') ADVANCE(239);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 244:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'h') ADVANCE(348);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 245:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'h') ADVANCE(242);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 246:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'l') ADVANCE(261);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 247:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'l') ADVANCE(243);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 248:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'm') ADVANCE(111);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 249:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'n') ADVANCE(240);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 250:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'n') ADVANCE(246);
+      if (lookahead == 'r') ADVANCE(149);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 251:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'n') ADVANCE(246);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 252:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'n') ADVANCE(352);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 253:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'o') ADVANCE(259);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 254:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'o') ADVANCE(113);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 255:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'o') ADVANCE(248);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 256:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'o') ADVANCE(257);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 257:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'r') ADVANCE(160);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 258:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'r') ADVANCE(255);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 259:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 't') ADVANCE(152);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 260:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 't') ADVANCE(256);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 261:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == 'y') ADVANCE(156);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 262:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 263:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == 'c') ADVANCE(273);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(275);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 264:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == 'e') ADVANCE(263);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(275);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 265:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == 'e') ADVANCE(267);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(275);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 266:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == 'l') ADVANCE(274);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(275);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 267:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == 'l') ADVANCE(264);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(275);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 268:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == 'n') ADVANCE(266);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(275);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 269:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == 'o') ADVANCE(272);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(275);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 270:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == 'o') ADVANCE(271);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(275);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 271:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == 'r') ADVANCE(161);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(275);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 272:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == 't') ADVANCE(153);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(275);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 273:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == 't') ADVANCE(270);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(275);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 274:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == 'y') ADVANCE(157);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(275);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 275:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(341);
+      if (lookahead == '/') ADVANCE(96);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(278);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(275);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 276:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '/') ADVANCE(95);
+      if (lookahead == '-' ||
+          lookahead == '_') ADVANCE(276);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(278);
+      END_STATE();
+    case 277:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '/') ADVANCE(96);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(186);
+      if (lookahead == '-' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(278);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 278:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(278);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 279:
+      ACCEPT_TOKEN(sym_identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(280);
+      if (lookahead == '-' ||
+          ('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(281);
+      END_STATE();
+    case 280:
+      ACCEPT_TOKEN(sym_identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(178);
+      if (lookahead == '-' ||
+          ('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(281);
+      END_STATE();
+    case 281:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(281);
+      END_STATE();
+    case 282:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'a') ADVANCE(316);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 283:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'a') ADVANCE(103);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 284:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'a') ADVANCE(288);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 285:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'a') ADVANCE(306);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 286:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'a') ADVANCE(307);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 287:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'a') ADVANCE(308);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 288:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'c') ADVANCE(292);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 289:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'd') ADVANCE(303);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 290:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'e') ADVANCE(331);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 291:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'e') ADVANCE(289);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 292:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'e') ADVANCE(105);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 293:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'e') ADVANCE(324);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 294:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'e') ADVANCE(327);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 295:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'e') ADVANCE(322);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 296:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'e') ADVANCE(323);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 297:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'e') ADVANCE(332);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 298:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'f') ADVANCE(319);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 299:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'f') ADVANCE(320);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 300:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'g') ADVANCE(302);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 301:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'h') ADVANCE(282);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 302:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'i') ADVANCE(309);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 303:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'i') ADVANCE(283);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 304:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 'l') ADVANCE(329);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 305:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 'm') ADVANCE(312);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 306:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 'm') ADVANCE(293);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 307:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 'm') ADVANCE(295);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 308:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 'm') ADVANCE(296);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 309:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 'n') ADVANCE(350);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 310:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 'o') ADVANCE(317);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 311:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 'o') ADVANCE(318);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 312:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 'p') ADVANCE(310);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 313:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 'p') ADVANCE(284);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 314:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 'p') ADVANCE(311);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 315:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 'p') ADVANCE(314);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 316:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 'r') ADVANCE(325);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 317:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 'r') ADVANCE(326);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 318:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 'r') ADVANCE(328);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 319:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 'r') ADVANCE(286);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 320:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 'r') ADVANCE(287);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 321:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 's') ADVANCE(114);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 322:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 's') ADVANCE(106);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 323:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 's') ADVANCE(107);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 324:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 's') ADVANCE(313);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 325:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 's') ADVANCE(294);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 326:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 't') ADVANCE(100);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 327:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 't') ADVANCE(104);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 328:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 't') ADVANCE(321);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 329:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 'u') ADVANCE(300);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 330:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 'u') ADVANCE(315);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 331:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 'y') ADVANCE(298);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 332:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (lookahead == 'y') ADVANCE(299);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 333:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 334:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(334);
+      END_STATE();
+    case 335:
+      ACCEPT_TOKEN(sym_comment);
+      END_STATE();
+    case 336:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead == '/') ADVANCE(338);
+      if (lookahead == '-' ||
+          lookahead == '_') ADVANCE(336);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(337);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(340);
+      END_STATE();
+    case 337:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead == '/') ADVANCE(339);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ' ||
+          lookahead == '!' ||
+          lookahead == '(' ||
+          lookahead == ')' ||
+          lookahead == ',' ||
+          lookahead == ';' ||
+          lookahead == '[' ||
+          lookahead == ']' ||
+          lookahead == '{' ||
+          lookahead == '}') ADVANCE(340);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead)) ADVANCE(337);
+      END_STATE();
+    case 338:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ' ||
+          lookahead == '!' ||
+          ('(' <= lookahead && lookahead <= '*') ||
+          lookahead == ',' ||
+          lookahead == ';' ||
+          lookahead == '[' ||
+          lookahead == ']' ||
+          lookahead == '{' ||
+          lookahead == '}') ADVANCE(340);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead)) ADVANCE(336);
+      END_STATE();
+    case 339:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ' ||
+          lookahead == '!' ||
+          ('(' <= lookahead && lookahead <= '*') ||
+          lookahead == ',' ||
+          lookahead == ';' ||
+          lookahead == '[' ||
+          lookahead == ']' ||
+          lookahead == '{' ||
+          lookahead == '}') ADVANCE(340);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead)) ADVANCE(337);
+      END_STATE();
+    case 340:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(340);
+      END_STATE();
+    case 341:
+      ACCEPT_TOKEN(sym_plain_value);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == '@') ADVANCE(342);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 342:
+      ACCEPT_TOKEN(sym_plain_value);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == '-' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(343);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 343:
+      ACCEPT_TOKEN(sym_plain_value);
+      if (lookahead == '/') ADVANCE(96);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(343);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 344:
+      ACCEPT_TOKEN(sym_plain_value);
+      if (lookahead == '/') ADVANCE(96);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(344);
+      END_STATE();
+    case 345:
+      ACCEPT_TOKEN(sym_grit_metavariable);
+      END_STATE();
+    case 346:
+      ACCEPT_TOKEN(sym_grit_metavariable);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(346);
+      END_STATE();
+    case 347:
+      ACCEPT_TOKEN(anon_sym_DOT_DOT_DOT);
+      END_STATE();
+    case 348:
+      ACCEPT_TOKEN(anon_sym_each);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 349:
+      ACCEPT_TOKEN(anon_sym_COLONextend);
+      END_STATE();
+    case 350:
+      ACCEPT_TOKEN(anon_sym_ATplugin);
+      if (lookahead == 'k') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(359);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(333);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(334);
+      END_STATE();
+    case 351:
+      ACCEPT_TOKEN(anon_sym_when);
+      END_STATE();
+    case 352:
+      ACCEPT_TOKEN(anon_sym_when);
+      if (lookahead == '.') ADVANCE(59);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(281);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(262);
+      END_STATE();
+    case 353:
+      ACCEPT_TOKEN(anon_sym_AT_LBRACE);
+      END_STATE();
+    case 354:
+      ACCEPT_TOKEN(anon_sym_DASH);
+      if (lookahead == '-' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(281);
+      END_STATE();
+    case 355:
+      ACCEPT_TOKEN(anon_sym_DOLLAR);
+      if (lookahead == '=') ADVANCE(132);
+      if (lookahead == '{') ADVANCE(357);
+      END_STATE();
+    case 356:
+      ACCEPT_TOKEN(anon_sym_DOLLAR);
+      if (lookahead == '{') ADVANCE(357);
+      END_STATE();
+    case 357:
+      ACCEPT_TOKEN(anon_sym_DOLLAR_LBRACE);
+      END_STATE();
+    case 358:
+      ACCEPT_TOKEN(anon_sym_AT_AT);
+      END_STATE();
+    case 359:
+      ACCEPT_TOKEN(sym_variable);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(359);
+      END_STATE();
+    case 360:
+      ACCEPT_TOKEN(anon_sym_PLUS_);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static const TSLexMode ts_lex_modes[STATE_COUNT] = {
+  [0] = {.lex_state = 0, .external_lex_state = 1},
+  [1] = {.lex_state = 98},
+  [2] = {.lex_state = 98},
+  [3] = {.lex_state = 98},
+  [4] = {.lex_state = 98},
+  [5] = {.lex_state = 98},
+  [6] = {.lex_state = 98},
+  [7] = {.lex_state = 98},
+  [8] = {.lex_state = 98},
+  [9] = {.lex_state = 98},
+  [10] = {.lex_state = 98},
+  [11] = {.lex_state = 5},
+  [12] = {.lex_state = 5},
+  [13] = {.lex_state = 4},
+  [14] = {.lex_state = 4},
+  [15] = {.lex_state = 4},
+  [16] = {.lex_state = 4},
+  [17] = {.lex_state = 4},
+  [18] = {.lex_state = 4},
+  [19] = {.lex_state = 4},
+  [20] = {.lex_state = 4},
+  [21] = {.lex_state = 4},
+  [22] = {.lex_state = 4},
+  [23] = {.lex_state = 4},
+  [24] = {.lex_state = 5},
+  [25] = {.lex_state = 5},
+  [26] = {.lex_state = 2, .external_lex_state = 2},
+  [27] = {.lex_state = 2, .external_lex_state = 3},
+  [28] = {.lex_state = 4, .external_lex_state = 2},
+  [29] = {.lex_state = 4, .external_lex_state = 2},
+  [30] = {.lex_state = 4, .external_lex_state = 4},
+  [31] = {.lex_state = 4, .external_lex_state = 2},
+  [32] = {.lex_state = 12},
+  [33] = {.lex_state = 4},
+  [34] = {.lex_state = 4},
+  [35] = {.lex_state = 4},
+  [36] = {.lex_state = 4},
+  [37] = {.lex_state = 2},
+  [38] = {.lex_state = 4},
+  [39] = {.lex_state = 4},
+  [40] = {.lex_state = 4, .external_lex_state = 3},
+  [41] = {.lex_state = 4, .external_lex_state = 3},
+  [42] = {.lex_state = 4, .external_lex_state = 2},
+  [43] = {.lex_state = 4, .external_lex_state = 2},
+  [44] = {.lex_state = 4, .external_lex_state = 3},
+  [45] = {.lex_state = 4},
+  [46] = {.lex_state = 11, .external_lex_state = 3},
+  [47] = {.lex_state = 4},
+  [48] = {.lex_state = 4},
+  [49] = {.lex_state = 4},
+  [50] = {.lex_state = 4},
+  [51] = {.lex_state = 4},
+  [52] = {.lex_state = 4},
+  [53] = {.lex_state = 1},
+  [54] = {.lex_state = 4, .external_lex_state = 4},
+  [55] = {.lex_state = 4, .external_lex_state = 3},
+  [56] = {.lex_state = 4, .external_lex_state = 4},
+  [57] = {.lex_state = 1},
+  [58] = {.lex_state = 4},
+  [59] = {.lex_state = 4},
+  [60] = {.lex_state = 12},
+  [61] = {.lex_state = 4},
+  [62] = {.lex_state = 4},
+  [63] = {.lex_state = 4},
+  [64] = {.lex_state = 4, .external_lex_state = 3},
+  [65] = {.lex_state = 4},
+  [66] = {.lex_state = 4},
+  [67] = {.lex_state = 11},
+  [68] = {.lex_state = 4},
+  [69] = {.lex_state = 4},
+  [70] = {.lex_state = 4},
+  [71] = {.lex_state = 4},
+  [72] = {.lex_state = 4},
+  [73] = {.lex_state = 4},
+  [74] = {.lex_state = 5},
+  [75] = {.lex_state = 5},
+  [76] = {.lex_state = 4},
+  [77] = {.lex_state = 4},
+  [78] = {.lex_state = 4},
+  [79] = {.lex_state = 4},
+  [80] = {.lex_state = 4},
+  [81] = {.lex_state = 4},
+  [82] = {.lex_state = 5, .external_lex_state = 3},
+  [83] = {.lex_state = 5, .external_lex_state = 3},
+  [84] = {.lex_state = 4},
+  [85] = {.lex_state = 4},
+  [86] = {.lex_state = 5, .external_lex_state = 3},
+  [87] = {.lex_state = 4},
+  [88] = {.lex_state = 12},
+  [89] = {.lex_state = 4},
+  [90] = {.lex_state = 10},
+  [91] = {.lex_state = 5, .external_lex_state = 3},
+  [92] = {.lex_state = 12},
+  [93] = {.lex_state = 4},
+  [94] = {.lex_state = 5},
+  [95] = {.lex_state = 5},
+  [96] = {.lex_state = 4},
+  [97] = {.lex_state = 10},
+  [98] = {.lex_state = 4},
+  [99] = {.lex_state = 8, .external_lex_state = 4},
+  [100] = {.lex_state = 5},
+  [101] = {.lex_state = 4},
+  [102] = {.lex_state = 4},
+  [103] = {.lex_state = 4},
+  [104] = {.lex_state = 5, .external_lex_state = 3},
+  [105] = {.lex_state = 5},
+  [106] = {.lex_state = 5},
+  [107] = {.lex_state = 5},
+  [108] = {.lex_state = 4},
+  [109] = {.lex_state = 5},
+  [110] = {.lex_state = 5},
+  [111] = {.lex_state = 5},
+  [112] = {.lex_state = 5},
+  [113] = {.lex_state = 4},
+  [114] = {.lex_state = 5},
+  [115] = {.lex_state = 5},
+  [116] = {.lex_state = 5},
+  [117] = {.lex_state = 4},
+  [118] = {.lex_state = 4},
+  [119] = {.lex_state = 4},
+  [120] = {.lex_state = 4},
+  [121] = {.lex_state = 4},
+  [122] = {.lex_state = 4},
+  [123] = {.lex_state = 4},
+  [124] = {.lex_state = 4},
+  [125] = {.lex_state = 4},
+  [126] = {.lex_state = 4},
+  [127] = {.lex_state = 4},
+  [128] = {.lex_state = 16},
+  [129] = {.lex_state = 4},
+  [130] = {.lex_state = 4},
+  [131] = {.lex_state = 4},
+  [132] = {.lex_state = 4},
+  [133] = {.lex_state = 4},
+  [134] = {.lex_state = 4},
+  [135] = {.lex_state = 4},
+  [136] = {.lex_state = 4},
+  [137] = {.lex_state = 4},
+  [138] = {.lex_state = 4},
+  [139] = {.lex_state = 4},
+  [140] = {.lex_state = 16},
+  [141] = {.lex_state = 4},
+  [142] = {.lex_state = 4},
+  [143] = {.lex_state = 4},
+  [144] = {.lex_state = 4},
+  [145] = {.lex_state = 4},
+  [146] = {.lex_state = 4},
+  [147] = {.lex_state = 4},
+  [148] = {.lex_state = 4},
+  [149] = {.lex_state = 4},
+  [150] = {.lex_state = 4},
+  [151] = {.lex_state = 4},
+  [152] = {.lex_state = 4},
+  [153] = {.lex_state = 4},
+  [154] = {.lex_state = 4},
+  [155] = {.lex_state = 4},
+  [156] = {.lex_state = 4},
+  [157] = {.lex_state = 4},
+  [158] = {.lex_state = 4},
+  [159] = {.lex_state = 4},
+  [160] = {.lex_state = 4},
+  [161] = {.lex_state = 4},
+  [162] = {.lex_state = 4},
+  [163] = {.lex_state = 4},
+  [164] = {.lex_state = 14, .external_lex_state = 3},
+  [165] = {.lex_state = 98},
+  [166] = {.lex_state = 98},
+  [167] = {.lex_state = 98},
+  [168] = {.lex_state = 98},
+  [169] = {.lex_state = 24},
+  [170] = {.lex_state = 98},
+  [171] = {.lex_state = 98},
+  [172] = {.lex_state = 98},
+  [173] = {.lex_state = 98},
+  [174] = {.lex_state = 98},
+  [175] = {.lex_state = 98},
+  [176] = {.lex_state = 98},
+  [177] = {.lex_state = 98},
+  [178] = {.lex_state = 98},
+  [179] = {.lex_state = 98},
+  [180] = {.lex_state = 98},
+  [181] = {.lex_state = 98},
+  [182] = {.lex_state = 98},
+  [183] = {.lex_state = 98},
+  [184] = {.lex_state = 98},
+  [185] = {.lex_state = 98},
+  [186] = {.lex_state = 98},
+  [187] = {.lex_state = 98},
+  [188] = {.lex_state = 98},
+  [189] = {.lex_state = 98},
+  [190] = {.lex_state = 24},
+  [191] = {.lex_state = 98},
+  [192] = {.lex_state = 24},
+  [193] = {.lex_state = 98},
+  [194] = {.lex_state = 98},
+  [195] = {.lex_state = 24},
+  [196] = {.lex_state = 24},
+  [197] = {.lex_state = 98},
+  [198] = {.lex_state = 24},
+  [199] = {.lex_state = 98},
+  [200] = {.lex_state = 98},
+  [201] = {.lex_state = 98},
+  [202] = {.lex_state = 98},
+  [203] = {.lex_state = 98},
+  [204] = {.lex_state = 98},
+  [205] = {.lex_state = 98},
+  [206] = {.lex_state = 98},
+  [207] = {.lex_state = 98},
+  [208] = {.lex_state = 98},
+  [209] = {.lex_state = 24},
+  [210] = {.lex_state = 24},
+  [211] = {.lex_state = 24},
+  [212] = {.lex_state = 24},
+  [213] = {.lex_state = 98},
+  [214] = {.lex_state = 98},
+  [215] = {.lex_state = 98},
+  [216] = {.lex_state = 98},
+  [217] = {.lex_state = 98},
+  [218] = {.lex_state = 98},
+  [219] = {.lex_state = 98},
+  [220] = {.lex_state = 98},
+  [221] = {.lex_state = 98},
+  [222] = {.lex_state = 98},
+  [223] = {.lex_state = 98},
+  [224] = {.lex_state = 98},
+  [225] = {.lex_state = 98},
+  [226] = {.lex_state = 98},
+  [227] = {.lex_state = 98},
+  [228] = {.lex_state = 98},
+  [229] = {.lex_state = 98},
+  [230] = {.lex_state = 98},
+  [231] = {.lex_state = 98},
+  [232] = {.lex_state = 98},
+  [233] = {.lex_state = 98},
+  [234] = {.lex_state = 98},
+  [235] = {.lex_state = 98},
+  [236] = {.lex_state = 98},
+  [237] = {.lex_state = 98},
+  [238] = {.lex_state = 98},
+  [239] = {.lex_state = 98},
+  [240] = {.lex_state = 98},
+  [241] = {.lex_state = 98},
+  [242] = {.lex_state = 98},
+  [243] = {.lex_state = 98},
+  [244] = {.lex_state = 98},
+  [245] = {.lex_state = 98},
+  [246] = {.lex_state = 98},
+  [247] = {.lex_state = 98},
+  [248] = {.lex_state = 98},
+  [249] = {.lex_state = 19, .external_lex_state = 3},
+  [250] = {.lex_state = 24},
+  [251] = {.lex_state = 98},
+  [252] = {.lex_state = 98},
+  [253] = {.lex_state = 98},
+  [254] = {.lex_state = 98},
+  [255] = {.lex_state = 98},
+  [256] = {.lex_state = 98},
+  [257] = {.lex_state = 98},
+  [258] = {.lex_state = 98},
+  [259] = {.lex_state = 18, .external_lex_state = 3},
+  [260] = {.lex_state = 19, .external_lex_state = 3},
+  [261] = {.lex_state = 30},
+  [262] = {.lex_state = 30},
+  [263] = {.lex_state = 19},
+  [264] = {.lex_state = 19},
+  [265] = {.lex_state = 30},
+  [266] = {.lex_state = 16, .external_lex_state = 3},
+  [267] = {.lex_state = 18},
+  [268] = {.lex_state = 16},
+  [269] = {.lex_state = 16},
+  [270] = {.lex_state = 16, .external_lex_state = 3},
+  [271] = {.lex_state = 16, .external_lex_state = 3},
+  [272] = {.lex_state = 6, .external_lex_state = 3},
+  [273] = {.lex_state = 17},
+  [274] = {.lex_state = 16, .external_lex_state = 3},
+  [275] = {.lex_state = 16, .external_lex_state = 3},
+  [276] = {.lex_state = 17},
+  [277] = {.lex_state = 16},
+  [278] = {.lex_state = 16},
+  [279] = {.lex_state = 16},
+  [280] = {.lex_state = 16},
+  [281] = {.lex_state = 16},
+  [282] = {.lex_state = 16},
+  [283] = {.lex_state = 16},
+  [284] = {.lex_state = 16},
+  [285] = {.lex_state = 16},
+  [286] = {.lex_state = 16},
+  [287] = {.lex_state = 16},
+  [288] = {.lex_state = 6, .external_lex_state = 3},
+  [289] = {.lex_state = 6, .external_lex_state = 3},
+  [290] = {.lex_state = 16},
+  [291] = {.lex_state = 16},
+  [292] = {.lex_state = 6, .external_lex_state = 3},
+  [293] = {.lex_state = 6, .external_lex_state = 3},
+  [294] = {.lex_state = 6},
+  [295] = {.lex_state = 8, .external_lex_state = 2},
+  [296] = {.lex_state = 6},
+  [297] = {.lex_state = 6},
+  [298] = {.lex_state = 6, .external_lex_state = 3},
+  [299] = {.lex_state = 23, .external_lex_state = 2},
+  [300] = {.lex_state = 22},
+  [301] = {.lex_state = 6, .external_lex_state = 3},
+  [302] = {.lex_state = 22},
+  [303] = {.lex_state = 23, .external_lex_state = 2},
+  [304] = {.lex_state = 24, .external_lex_state = 4},
+  [305] = {.lex_state = 29, .external_lex_state = 4},
+  [306] = {.lex_state = 98, .external_lex_state = 2},
+  [307] = {.lex_state = 6},
+  [308] = {.lex_state = 6},
+  [309] = {.lex_state = 6},
+  [310] = {.lex_state = 6},
+  [311] = {.lex_state = 6},
+  [312] = {.lex_state = 6},
+  [313] = {.lex_state = 6},
+  [314] = {.lex_state = 6},
+  [315] = {.lex_state = 6},
+  [316] = {.lex_state = 6, .external_lex_state = 3},
+  [317] = {.lex_state = 23, .external_lex_state = 2},
+  [318] = {.lex_state = 6},
+  [319] = {.lex_state = 23, .external_lex_state = 2},
+  [320] = {.lex_state = 23, .external_lex_state = 2},
+  [321] = {.lex_state = 6},
+  [322] = {.lex_state = 6},
+  [323] = {.lex_state = 6},
+  [324] = {.lex_state = 6},
+  [325] = {.lex_state = 6},
+  [326] = {.lex_state = 16},
+  [327] = {.lex_state = 16, .external_lex_state = 2},
+  [328] = {.lex_state = 16},
+  [329] = {.lex_state = 16, .external_lex_state = 2},
+  [330] = {.lex_state = 16},
+  [331] = {.lex_state = 16},
+  [332] = {.lex_state = 14, .external_lex_state = 2},
+  [333] = {.lex_state = 14, .external_lex_state = 4},
+  [334] = {.lex_state = 98, .external_lex_state = 2},
+  [335] = {.lex_state = 8, .external_lex_state = 2},
+  [336] = {.lex_state = 16},
+  [337] = {.lex_state = 98, .external_lex_state = 2},
+  [338] = {.lex_state = 8, .external_lex_state = 4},
+  [339] = {.lex_state = 8, .external_lex_state = 4},
+  [340] = {.lex_state = 16, .external_lex_state = 2},
+  [341] = {.lex_state = 16},
+  [342] = {.lex_state = 23, .external_lex_state = 2},
+  [343] = {.lex_state = 4},
+  [344] = {.lex_state = 23, .external_lex_state = 4},
+  [345] = {.lex_state = 8, .external_lex_state = 4},
+  [346] = {.lex_state = 16},
+  [347] = {.lex_state = 6},
+  [348] = {.lex_state = 23, .external_lex_state = 2},
+  [349] = {.lex_state = 8, .external_lex_state = 4},
+  [350] = {.lex_state = 8, .external_lex_state = 2},
+  [351] = {.lex_state = 8, .external_lex_state = 2},
+  [352] = {.lex_state = 16, .external_lex_state = 2},
+  [353] = {.lex_state = 8, .external_lex_state = 2},
+  [354] = {.lex_state = 6},
+  [355] = {.lex_state = 98, .external_lex_state = 2},
+  [356] = {.lex_state = 6},
+  [357] = {.lex_state = 4},
+  [358] = {.lex_state = 6},
+  [359] = {.lex_state = 23, .external_lex_state = 4},
+  [360] = {.lex_state = 6},
+  [361] = {.lex_state = 16},
+  [362] = {.lex_state = 16},
+  [363] = {.lex_state = 14, .external_lex_state = 4},
+  [364] = {.lex_state = 16},
+  [365] = {.lex_state = 14, .external_lex_state = 4},
+  [366] = {.lex_state = 8, .external_lex_state = 2},
+  [367] = {.lex_state = 16, .external_lex_state = 2},
+  [368] = {.lex_state = 16, .external_lex_state = 2},
+  [369] = {.lex_state = 14, .external_lex_state = 4},
+  [370] = {.lex_state = 8, .external_lex_state = 2},
+  [371] = {.lex_state = 8, .external_lex_state = 4},
+  [372] = {.lex_state = 6},
+  [373] = {.lex_state = 98, .external_lex_state = 4},
+  [374] = {.lex_state = 16, .external_lex_state = 2},
+  [375] = {.lex_state = 16, .external_lex_state = 2},
+  [376] = {.lex_state = 8, .external_lex_state = 2},
+  [377] = {.lex_state = 8, .external_lex_state = 2},
+  [378] = {.lex_state = 98, .external_lex_state = 2},
+  [379] = {.lex_state = 98, .external_lex_state = 2},
+  [380] = {.lex_state = 6},
+  [381] = {.lex_state = 6},
+  [382] = {.lex_state = 6},
+  [383] = {.lex_state = 8, .external_lex_state = 4},
+  [384] = {.lex_state = 8, .external_lex_state = 4},
+  [385] = {.lex_state = 8, .external_lex_state = 4},
+  [386] = {.lex_state = 6},
+  [387] = {.lex_state = 16, .external_lex_state = 4},
+  [388] = {.lex_state = 8, .external_lex_state = 4},
+  [389] = {.lex_state = 8, .external_lex_state = 4},
+  [390] = {.lex_state = 16, .external_lex_state = 4},
+  [391] = {.lex_state = 8, .external_lex_state = 4},
+  [392] = {.lex_state = 8, .external_lex_state = 4},
+  [393] = {.lex_state = 8, .external_lex_state = 4},
+  [394] = {.lex_state = 8, .external_lex_state = 4},
+  [395] = {.lex_state = 16, .external_lex_state = 4},
+  [396] = {.lex_state = 8, .external_lex_state = 4},
+  [397] = {.lex_state = 8, .external_lex_state = 4},
+  [398] = {.lex_state = 8, .external_lex_state = 4},
+  [399] = {.lex_state = 8, .external_lex_state = 4},
+  [400] = {.lex_state = 8, .external_lex_state = 4},
+  [401] = {.lex_state = 8, .external_lex_state = 4},
+  [402] = {.lex_state = 8, .external_lex_state = 4},
+  [403] = {.lex_state = 8, .external_lex_state = 4},
+  [404] = {.lex_state = 16, .external_lex_state = 4},
+  [405] = {.lex_state = 16, .external_lex_state = 4},
+  [406] = {.lex_state = 6},
+  [407] = {.lex_state = 6},
+  [408] = {.lex_state = 16, .external_lex_state = 4},
+  [409] = {.lex_state = 16, .external_lex_state = 4},
+  [410] = {.lex_state = 16, .external_lex_state = 4},
+  [411] = {.lex_state = 16, .external_lex_state = 4},
+  [412] = {.lex_state = 16, .external_lex_state = 4},
+  [413] = {.lex_state = 16, .external_lex_state = 4},
+  [414] = {.lex_state = 16, .external_lex_state = 4},
+  [415] = {.lex_state = 16, .external_lex_state = 4},
+  [416] = {.lex_state = 8, .external_lex_state = 4},
+  [417] = {.lex_state = 8, .external_lex_state = 4},
+  [418] = {.lex_state = 16, .external_lex_state = 4},
+  [419] = {.lex_state = 16, .external_lex_state = 4},
+  [420] = {.lex_state = 6},
+  [421] = {.lex_state = 8, .external_lex_state = 4},
+  [422] = {.lex_state = 8, .external_lex_state = 4},
+  [423] = {.lex_state = 8, .external_lex_state = 4},
+  [424] = {.lex_state = 16, .external_lex_state = 4},
+  [425] = {.lex_state = 8, .external_lex_state = 4},
+  [426] = {.lex_state = 16, .external_lex_state = 4},
+  [427] = {.lex_state = 6},
+  [428] = {.lex_state = 6},
+  [429] = {.lex_state = 6},
+  [430] = {.lex_state = 6},
+  [431] = {.lex_state = 16, .external_lex_state = 4},
+  [432] = {.lex_state = 16, .external_lex_state = 4},
+  [433] = {.lex_state = 8, .external_lex_state = 4},
+  [434] = {.lex_state = 16, .external_lex_state = 4},
+  [435] = {.lex_state = 16, .external_lex_state = 4},
+  [436] = {.lex_state = 6},
+  [437] = {.lex_state = 6},
+  [438] = {.lex_state = 6},
+  [439] = {.lex_state = 6},
+  [440] = {.lex_state = 6},
+  [441] = {.lex_state = 6},
+  [442] = {.lex_state = 6},
+  [443] = {.lex_state = 6},
+  [444] = {.lex_state = 6},
+  [445] = {.lex_state = 6},
+  [446] = {.lex_state = 6},
+  [447] = {.lex_state = 16, .external_lex_state = 4},
+  [448] = {.lex_state = 16, .external_lex_state = 4},
+  [449] = {.lex_state = 16, .external_lex_state = 4},
+  [450] = {.lex_state = 16, .external_lex_state = 4},
+  [451] = {.lex_state = 6},
+  [452] = {.lex_state = 8, .external_lex_state = 4},
+  [453] = {.lex_state = 16, .external_lex_state = 4},
+  [454] = {.lex_state = 16, .external_lex_state = 4},
+  [455] = {.lex_state = 16, .external_lex_state = 4},
+  [456] = {.lex_state = 8, .external_lex_state = 4},
+  [457] = {.lex_state = 16, .external_lex_state = 4},
+  [458] = {.lex_state = 16, .external_lex_state = 4},
+  [459] = {.lex_state = 16, .external_lex_state = 4},
+  [460] = {.lex_state = 6},
+  [461] = {.lex_state = 8, .external_lex_state = 4},
+  [462] = {.lex_state = 16, .external_lex_state = 4},
+  [463] = {.lex_state = 16, .external_lex_state = 4},
+  [464] = {.lex_state = 16, .external_lex_state = 4},
+  [465] = {.lex_state = 16, .external_lex_state = 4},
+  [466] = {.lex_state = 16, .external_lex_state = 4},
+  [467] = {.lex_state = 46},
+  [468] = {.lex_state = 30},
+  [469] = {.lex_state = 30},
+  [470] = {.lex_state = 6},
+  [471] = {.lex_state = 46},
+  [472] = {.lex_state = 46},
+  [473] = {.lex_state = 6},
+  [474] = {.lex_state = 30},
+  [475] = {.lex_state = 30},
+  [476] = {.lex_state = 46},
+  [477] = {.lex_state = 46},
+  [478] = {.lex_state = 23},
+  [479] = {.lex_state = 6},
+  [480] = {.lex_state = 6},
+  [481] = {.lex_state = 6},
+  [482] = {.lex_state = 6},
+  [483] = {.lex_state = 6},
+  [484] = {.lex_state = 6},
+  [485] = {.lex_state = 6},
+  [486] = {.lex_state = 6},
+  [487] = {.lex_state = 6},
+  [488] = {.lex_state = 23},
+  [489] = {.lex_state = 6},
+  [490] = {.lex_state = 6},
+  [491] = {.lex_state = 24},
+  [492] = {.lex_state = 24},
+  [493] = {.lex_state = 0},
+  [494] = {.lex_state = 6},
+  [495] = {.lex_state = 0},
+  [496] = {.lex_state = 6},
+  [497] = {.lex_state = 0},
+  [498] = {.lex_state = 6},
+  [499] = {.lex_state = 0},
+  [500] = {.lex_state = 6},
+  [501] = {.lex_state = 0},
+  [502] = {.lex_state = 6},
+  [503] = {.lex_state = 24},
+  [504] = {.lex_state = 24},
+  [505] = {.lex_state = 6},
+  [506] = {.lex_state = 24},
+  [507] = {.lex_state = 0},
+  [508] = {.lex_state = 46},
+  [509] = {.lex_state = 24},
+  [510] = {.lex_state = 6},
+  [511] = {.lex_state = 6},
+  [512] = {.lex_state = 0},
+  [513] = {.lex_state = 24},
+  [514] = {.lex_state = 0},
+  [515] = {.lex_state = 24},
+  [516] = {.lex_state = 98},
+  [517] = {.lex_state = 0},
+  [518] = {.lex_state = 0},
+  [519] = {.lex_state = 0},
+  [520] = {.lex_state = 0},
+  [521] = {.lex_state = 24},
+  [522] = {.lex_state = 24},
+  [523] = {.lex_state = 24},
+  [524] = {.lex_state = 0},
+  [525] = {.lex_state = 24},
+  [526] = {.lex_state = 24},
+  [527] = {.lex_state = 6},
+  [528] = {.lex_state = 0},
+  [529] = {.lex_state = 23},
+  [530] = {.lex_state = 46},
+  [531] = {.lex_state = 6},
+  [532] = {.lex_state = 24},
+  [533] = {.lex_state = 23},
+  [534] = {.lex_state = 6},
+  [535] = {.lex_state = 46},
+  [536] = {.lex_state = 46},
+  [537] = {.lex_state = 46},
+  [538] = {.lex_state = 98},
+  [539] = {.lex_state = 98},
+  [540] = {.lex_state = 24},
+  [541] = {.lex_state = 0},
+  [542] = {.lex_state = 0},
+  [543] = {.lex_state = 0},
+  [544] = {.lex_state = 0},
+  [545] = {.lex_state = 0},
+  [546] = {.lex_state = 0},
+  [547] = {.lex_state = 0},
+  [548] = {.lex_state = 0},
+  [549] = {.lex_state = 0},
+  [550] = {.lex_state = 0},
+  [551] = {.lex_state = 0},
+  [552] = {.lex_state = 0},
+  [553] = {.lex_state = 0},
+  [554] = {.lex_state = 0},
+  [555] = {.lex_state = 0},
+  [556] = {.lex_state = 0},
+  [557] = {.lex_state = 0},
+  [558] = {.lex_state = 0},
+  [559] = {.lex_state = 0},
+  [560] = {.lex_state = 0},
+  [561] = {.lex_state = 0},
+  [562] = {.lex_state = 6},
+  [563] = {.lex_state = 0},
+  [564] = {.lex_state = 0},
+  [565] = {.lex_state = 0},
+  [566] = {.lex_state = 0},
+  [567] = {.lex_state = 0},
+  [568] = {.lex_state = 0},
+  [569] = {.lex_state = 0},
+  [570] = {.lex_state = 0},
+  [571] = {.lex_state = 0},
+  [572] = {.lex_state = 0},
+  [573] = {.lex_state = 0},
+  [574] = {.lex_state = 0},
+  [575] = {.lex_state = 0},
+  [576] = {.lex_state = 22},
+  [577] = {.lex_state = 24},
+  [578] = {.lex_state = 0},
+  [579] = {.lex_state = 0},
+  [580] = {.lex_state = 0},
+  [581] = {.lex_state = 0},
+  [582] = {.lex_state = 0},
+  [583] = {.lex_state = 0},
+  [584] = {.lex_state = 0},
+  [585] = {.lex_state = 24},
+  [586] = {.lex_state = 0},
+  [587] = {.lex_state = 6},
+  [588] = {.lex_state = 0},
+  [589] = {.lex_state = 0},
+  [590] = {.lex_state = 0},
+  [591] = {.lex_state = 6},
+  [592] = {.lex_state = 6},
+  [593] = {.lex_state = 0},
+  [594] = {.lex_state = 98},
+  [595] = {.lex_state = 0},
+  [596] = {.lex_state = 24},
+  [597] = {.lex_state = 0},
+  [598] = {.lex_state = 37},
+  [599] = {.lex_state = 98},
+  [600] = {.lex_state = 0},
+  [601] = {.lex_state = 0},
+  [602] = {.lex_state = 6},
+  [603] = {.lex_state = 0},
+  [604] = {.lex_state = 24},
+  [605] = {.lex_state = 24},
+  [606] = {.lex_state = 98},
+  [607] = {.lex_state = 24},
+  [608] = {.lex_state = 24},
+  [609] = {.lex_state = 24},
+  [610] = {.lex_state = 24},
+  [611] = {.lex_state = 0},
+  [612] = {.lex_state = 0},
+  [613] = {.lex_state = 0},
+  [614] = {.lex_state = 98},
+  [615] = {.lex_state = 6},
+  [616] = {.lex_state = 0},
+  [617] = {.lex_state = 0},
+  [618] = {.lex_state = 6},
+  [619] = {.lex_state = 0},
+  [620] = {.lex_state = 24},
+  [621] = {.lex_state = 24},
+  [622] = {.lex_state = 24},
+  [623] = {.lex_state = 24},
+  [624] = {.lex_state = 24},
+  [625] = {.lex_state = 0},
+  [626] = {.lex_state = 0},
+  [627] = {.lex_state = 24},
+  [628] = {.lex_state = 24},
+  [629] = {.lex_state = 0},
+  [630] = {.lex_state = 98},
+  [631] = {.lex_state = 0},
+  [632] = {.lex_state = 0},
+  [633] = {.lex_state = 0},
+  [634] = {.lex_state = 24},
+  [635] = {.lex_state = 0},
+  [636] = {.lex_state = 0},
+  [637] = {.lex_state = 0},
+  [638] = {.lex_state = 98},
+  [639] = {.lex_state = 0},
+  [640] = {.lex_state = 24},
+  [641] = {.lex_state = 98},
+  [642] = {.lex_state = 0},
+  [643] = {.lex_state = 0},
+  [644] = {.lex_state = 24},
+  [645] = {.lex_state = 24},
+  [646] = {.lex_state = 24},
+  [647] = {.lex_state = 98},
+  [648] = {.lex_state = 24},
+  [649] = {.lex_state = 24},
+  [650] = {.lex_state = 0},
+  [651] = {.lex_state = 24},
+  [652] = {.lex_state = 24},
+  [653] = {.lex_state = 0},
+  [654] = {.lex_state = 0},
+  [655] = {.lex_state = 24},
+  [656] = {.lex_state = 0},
+  [657] = {.lex_state = 0},
+  [658] = {.lex_state = 24},
+  [659] = {.lex_state = 0},
+  [660] = {.lex_state = 0},
+  [661] = {.lex_state = 24},
+  [662] = {.lex_state = 0},
+  [663] = {.lex_state = 24},
+  [664] = {.lex_state = 98},
+  [665] = {.lex_state = 0},
+  [666] = {.lex_state = 24},
+  [667] = {.lex_state = 0},
+  [668] = {.lex_state = 24},
+  [669] = {.lex_state = 0},
+  [670] = {.lex_state = 24},
+  [671] = {.lex_state = 6},
+  [672] = {.lex_state = 0},
+  [673] = {.lex_state = 24},
+  [674] = {.lex_state = 0},
+  [675] = {.lex_state = 98},
+  [676] = {.lex_state = 0},
+  [677] = {.lex_state = 24},
+  [678] = {.lex_state = 24},
+  [679] = {.lex_state = 98},
+  [680] = {.lex_state = 0},
+};
+
+static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
+  [0] = {
+    [ts_builtin_sym_end] = ACTIONS(1),
+    [anon_sym_ATimport] = ACTIONS(1),
+    [anon_sym_COMMA] = ACTIONS(1),
+    [anon_sym_SEMI] = ACTIONS(1),
+    [anon_sym_ATmedia] = ACTIONS(1),
+    [anon_sym_ATcharset] = ACTIONS(1),
+    [anon_sym_ATnamespace] = ACTIONS(1),
+    [anon_sym_ATkeyframes] = ACTIONS(1),
+    [aux_sym_keyframes_statement_token1] = ACTIONS(1),
+    [anon_sym_LBRACE] = ACTIONS(1),
+    [anon_sym_RBRACE] = ACTIONS(1),
+    [sym_from] = ACTIONS(1),
+    [sym_to] = ACTIONS(1),
+    [anon_sym_ATsupports] = ACTIONS(1),
+    [sym_nesting_selector] = ACTIONS(1),
+    [anon_sym_STAR] = ACTIONS(1),
+    [anon_sym_DOT] = ACTIONS(1),
+    [anon_sym_COLON] = ACTIONS(1),
+    [anon_sym_COLON_COLON] = ACTIONS(1),
+    [anon_sym_POUND] = ACTIONS(1),
+    [sym_equal] = ACTIONS(1),
+    [sym_contains_word_equal] = ACTIONS(1),
+    [sym_starts_with_equal] = ACTIONS(1),
+    [sym_dash_equal] = ACTIONS(1),
+    [sym_contains_equal] = ACTIONS(1),
+    [sym_ends_equal] = ACTIONS(1),
+    [anon_sym_LBRACK] = ACTIONS(1),
+    [anon_sym_RBRACK] = ACTIONS(1),
+    [anon_sym_GT] = ACTIONS(1),
+    [anon_sym_TILDE] = ACTIONS(1),
+    [anon_sym_PLUS] = ACTIONS(1),
+    [anon_sym_LPAREN] = ACTIONS(1),
+    [anon_sym_RPAREN] = ACTIONS(1),
+    [sym_important] = ACTIONS(1),
+    [anon_sym_LPAREN2] = ACTIONS(1),
+    [sym_and] = ACTIONS(1),
+    [sym_or] = ACTIONS(1),
+    [sym_not] = ACTIONS(1),
+    [sym_only] = ACTIONS(1),
+    [anon_sym_selector] = ACTIONS(1),
+    [aux_sym_color_value_token1] = ACTIONS(1),
+    [sym_string_value] = ACTIONS(1),
+    [sym_minus] = ACTIONS(1),
+    [sym_divide] = ACTIONS(1),
+    [anon_sym_EQ_EQ] = ACTIONS(1),
+    [anon_sym_BANG_EQ] = ACTIONS(1),
+    [anon_sym_LT] = ACTIONS(1),
+    [anon_sym_LT_EQ] = ACTIONS(1),
+    [anon_sym_GT_EQ] = ACTIONS(1),
+    [sym_identifier] = ACTIONS(1),
+    [sym_at_keyword] = ACTIONS(1),
+    [sym_comment] = ACTIONS(3),
+    [sym_grit_metavariable] = ACTIONS(1),
+    [anon_sym_DOT_DOT_DOT] = ACTIONS(1),
+    [anon_sym_each] = ACTIONS(1),
+    [anon_sym_COLONextend] = ACTIONS(1),
+    [anon_sym_ATplugin] = ACTIONS(1),
+    [anon_sym_when] = ACTIONS(1),
+    [anon_sym_AT_LBRACE] = ACTIONS(1),
+    [anon_sym_DASH] = ACTIONS(1),
+    [anon_sym_DOLLAR] = ACTIONS(1),
+    [anon_sym_DOLLAR_LBRACE] = ACTIONS(1),
+    [anon_sym_AT_AT] = ACTIONS(1),
+    [sym_variable] = ACTIONS(1),
+    [anon_sym_PLUS_] = ACTIONS(1),
+    [sym__descendant_operator] = ACTIONS(1),
+    [sym__pseudo_class_selector_colon] = ACTIONS(1),
+    [sym___error_recovery] = ACTIONS(1),
+    [sym__concat] = ACTIONS(1),
+  },
+  [1] = {
+    [sym_stylesheet] = STATE(665),
+    [sym_import_statement] = STATE(223),
+    [sym_media_statement] = STATE(223),
+    [sym_charset_statement] = STATE(223),
+    [sym_namespace_statement] = STATE(223),
+    [sym_keyframes_statement] = STATE(223),
+    [sym_supports_statement] = STATE(223),
+    [sym_at_rule] = STATE(223),
+    [sym_rule_set] = STATE(223),
+    [sym_selectors] = STATE(595),
+    [sym__selector] = STATE(405),
+    [sym_universal_selector] = STATE(405),
+    [sym_class_selector] = STATE(405),
+    [sym_pseudo_class_selector] = STATE(405),
+    [sym_pseudo_element_selector] = STATE(405),
+    [sym_id_selector] = STATE(405),
+    [sym_attribute_selector] = STATE(405),
+    [sym_child_selector] = STATE(405),
+    [sym_descendant_selector] = STATE(405),
+    [sym_sibling_selector] = STATE(405),
+    [sym_adjacent_sibling_selector] = STATE(405),
+    [sym_declaration] = STATE(223),
+    [sym_mixin_definition] = STATE(223),
+    [sym_each_statement] = STATE(223),
+    [sym_plugin_statement] = STATE(223),
+    [sym_interpolation] = STATE(337),
+    [sym__concatenated_identifier] = STATE(373),
+    [sym__mixin_name] = STATE(590),
+    [aux_sym_stylesheet_repeat1] = STATE(9),
+    [ts_builtin_sym_end] = ACTIONS(5),
+    [anon_sym_ATimport] = ACTIONS(7),
+    [anon_sym_ATmedia] = ACTIONS(9),
+    [anon_sym_ATcharset] = ACTIONS(11),
+    [anon_sym_ATnamespace] = ACTIONS(13),
+    [anon_sym_ATkeyframes] = ACTIONS(15),
+    [aux_sym_keyframes_statement_token1] = ACTIONS(15),
+    [anon_sym_ATsupports] = ACTIONS(17),
+    [sym_nesting_selector] = ACTIONS(19),
+    [anon_sym_STAR] = ACTIONS(21),
+    [anon_sym_DOT] = ACTIONS(23),
+    [anon_sym_COLON] = ACTIONS(25),
+    [anon_sym_COLON_COLON] = ACTIONS(27),
+    [anon_sym_POUND] = ACTIONS(29),
+    [anon_sym_LBRACK] = ACTIONS(31),
+    [anon_sym_GT] = ACTIONS(33),
+    [sym_string_value] = ACTIONS(35),
+    [sym_identifier] = ACTIONS(37),
+    [sym_at_keyword] = ACTIONS(39),
+    [sym_comment] = ACTIONS(3),
+    [sym_grit_metavariable] = ACTIONS(35),
+    [anon_sym_each] = ACTIONS(41),
+    [anon_sym_ATplugin] = ACTIONS(43),
+    [anon_sym_AT_LBRACE] = ACTIONS(45),
+    [sym_variable] = ACTIONS(47),
+  },
+};
+
+static const uint16_t ts_small_parse_table[] = {
+  [0] = 31,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      anon_sym_GT,
+    ACTIONS(49), 1,
+      anon_sym_ATimport,
+    ACTIONS(51), 1,
+      anon_sym_ATmedia,
+    ACTIONS(53), 1,
+      anon_sym_ATcharset,
+    ACTIONS(55), 1,
+      anon_sym_ATnamespace,
+    ACTIONS(59), 1,
+      anon_sym_RBRACE,
+    ACTIONS(61), 1,
+      anon_sym_ATsupports,
+    ACTIONS(63), 1,
+      sym_nesting_selector,
+    ACTIONS(65), 1,
+      anon_sym_STAR,
+    ACTIONS(67), 1,
+      anon_sym_DOT,
+    ACTIONS(69), 1,
+      anon_sym_COLON,
+    ACTIONS(71), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(73), 1,
+      anon_sym_POUND,
+    ACTIONS(75), 1,
+      anon_sym_LBRACK,
+    ACTIONS(77), 1,
+      sym_string_value,
+    ACTIONS(79), 1,
+      sym_identifier,
+    ACTIONS(81), 1,
+      sym_at_keyword,
+    ACTIONS(83), 1,
+      sym_grit_metavariable,
+    ACTIONS(85), 1,
+      anon_sym_each,
+    ACTIONS(87), 1,
+      anon_sym_ATplugin,
+    ACTIONS(89), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(91), 1,
+      sym_variable,
+    STATE(317), 1,
+      sym_interpolation,
+    STATE(359), 1,
+      sym__concatenated_identifier,
+    STATE(497), 1,
+      sym__mixin_name,
+    STATE(582), 1,
+      sym_selectors,
+    STATE(613), 1,
+      sym_last_declaration,
+    ACTIONS(57), 2,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+    STATE(371), 11,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+    STATE(3), 15,
+      sym_import_statement,
+      sym_media_statement,
+      sym_charset_statement,
+      sym_namespace_statement,
+      sym_keyframes_statement,
+      sym_supports_statement,
+      sym_at_rule,
+      sym_rule_set,
+      sym_declaration,
+      sym_mixin_definition,
+      sym_each_statement,
+      sym_mixin_statement,
+      sym_extend_statement,
+      sym_plugin_statement,
+      aux_sym_block_repeat1,
+  [119] = 31,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      anon_sym_GT,
+    ACTIONS(49), 1,
+      anon_sym_ATimport,
+    ACTIONS(51), 1,
+      anon_sym_ATmedia,
+    ACTIONS(53), 1,
+      anon_sym_ATcharset,
+    ACTIONS(55), 1,
+      anon_sym_ATnamespace,
+    ACTIONS(61), 1,
+      anon_sym_ATsupports,
+    ACTIONS(63), 1,
+      sym_nesting_selector,
+    ACTIONS(65), 1,
+      anon_sym_STAR,
+    ACTIONS(67), 1,
+      anon_sym_DOT,
+    ACTIONS(69), 1,
+      anon_sym_COLON,
+    ACTIONS(71), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(73), 1,
+      anon_sym_POUND,
+    ACTIONS(75), 1,
+      anon_sym_LBRACK,
+    ACTIONS(77), 1,
+      sym_string_value,
+    ACTIONS(79), 1,
+      sym_identifier,
+    ACTIONS(81), 1,
+      sym_at_keyword,
+    ACTIONS(83), 1,
+      sym_grit_metavariable,
+    ACTIONS(85), 1,
+      anon_sym_each,
+    ACTIONS(87), 1,
+      anon_sym_ATplugin,
+    ACTIONS(89), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(91), 1,
+      sym_variable,
+    ACTIONS(93), 1,
+      anon_sym_RBRACE,
+    STATE(317), 1,
+      sym_interpolation,
+    STATE(359), 1,
+      sym__concatenated_identifier,
+    STATE(497), 1,
+      sym__mixin_name,
+    STATE(582), 1,
+      sym_selectors,
+    STATE(656), 1,
+      sym_last_declaration,
+    ACTIONS(57), 2,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+    STATE(371), 11,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+    STATE(8), 15,
+      sym_import_statement,
+      sym_media_statement,
+      sym_charset_statement,
+      sym_namespace_statement,
+      sym_keyframes_statement,
+      sym_supports_statement,
+      sym_at_rule,
+      sym_rule_set,
+      sym_declaration,
+      sym_mixin_definition,
+      sym_each_statement,
+      sym_mixin_statement,
+      sym_extend_statement,
+      sym_plugin_statement,
+      aux_sym_block_repeat1,
+  [238] = 31,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      anon_sym_GT,
+    ACTIONS(49), 1,
+      anon_sym_ATimport,
+    ACTIONS(51), 1,
+      anon_sym_ATmedia,
+    ACTIONS(53), 1,
+      anon_sym_ATcharset,
+    ACTIONS(55), 1,
+      anon_sym_ATnamespace,
+    ACTIONS(61), 1,
+      anon_sym_ATsupports,
+    ACTIONS(63), 1,
+      sym_nesting_selector,
+    ACTIONS(65), 1,
+      anon_sym_STAR,
+    ACTIONS(67), 1,
+      anon_sym_DOT,
+    ACTIONS(69), 1,
+      anon_sym_COLON,
+    ACTIONS(71), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(73), 1,
+      anon_sym_POUND,
+    ACTIONS(75), 1,
+      anon_sym_LBRACK,
+    ACTIONS(77), 1,
+      sym_string_value,
+    ACTIONS(79), 1,
+      sym_identifier,
+    ACTIONS(81), 1,
+      sym_at_keyword,
+    ACTIONS(83), 1,
+      sym_grit_metavariable,
+    ACTIONS(85), 1,
+      anon_sym_each,
+    ACTIONS(87), 1,
+      anon_sym_ATplugin,
+    ACTIONS(89), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(91), 1,
+      sym_variable,
+    ACTIONS(95), 1,
+      anon_sym_RBRACE,
+    STATE(317), 1,
+      sym_interpolation,
+    STATE(359), 1,
+      sym__concatenated_identifier,
+    STATE(497), 1,
+      sym__mixin_name,
+    STATE(582), 1,
+      sym_selectors,
+    STATE(672), 1,
+      sym_last_declaration,
+    ACTIONS(57), 2,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+    STATE(371), 11,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+    STATE(5), 15,
+      sym_import_statement,
+      sym_media_statement,
+      sym_charset_statement,
+      sym_namespace_statement,
+      sym_keyframes_statement,
+      sym_supports_statement,
+      sym_at_rule,
+      sym_rule_set,
+      sym_declaration,
+      sym_mixin_definition,
+      sym_each_statement,
+      sym_mixin_statement,
+      sym_extend_statement,
+      sym_plugin_statement,
+      aux_sym_block_repeat1,
+  [357] = 31,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      anon_sym_GT,
+    ACTIONS(49), 1,
+      anon_sym_ATimport,
+    ACTIONS(51), 1,
+      anon_sym_ATmedia,
+    ACTIONS(53), 1,
+      anon_sym_ATcharset,
+    ACTIONS(55), 1,
+      anon_sym_ATnamespace,
+    ACTIONS(61), 1,
+      anon_sym_ATsupports,
+    ACTIONS(63), 1,
+      sym_nesting_selector,
+    ACTIONS(65), 1,
+      anon_sym_STAR,
+    ACTIONS(67), 1,
+      anon_sym_DOT,
+    ACTIONS(69), 1,
+      anon_sym_COLON,
+    ACTIONS(71), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(73), 1,
+      anon_sym_POUND,
+    ACTIONS(75), 1,
+      anon_sym_LBRACK,
+    ACTIONS(77), 1,
+      sym_string_value,
+    ACTIONS(79), 1,
+      sym_identifier,
+    ACTIONS(81), 1,
+      sym_at_keyword,
+    ACTIONS(83), 1,
+      sym_grit_metavariable,
+    ACTIONS(85), 1,
+      anon_sym_each,
+    ACTIONS(87), 1,
+      anon_sym_ATplugin,
+    ACTIONS(89), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(91), 1,
+      sym_variable,
+    ACTIONS(97), 1,
+      anon_sym_RBRACE,
+    STATE(317), 1,
+      sym_interpolation,
+    STATE(359), 1,
+      sym__concatenated_identifier,
+    STATE(497), 1,
+      sym__mixin_name,
+    STATE(582), 1,
+      sym_selectors,
+    STATE(639), 1,
+      sym_last_declaration,
+    ACTIONS(57), 2,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+    STATE(371), 11,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+    STATE(8), 15,
+      sym_import_statement,
+      sym_media_statement,
+      sym_charset_statement,
+      sym_namespace_statement,
+      sym_keyframes_statement,
+      sym_supports_statement,
+      sym_at_rule,
+      sym_rule_set,
+      sym_declaration,
+      sym_mixin_definition,
+      sym_each_statement,
+      sym_mixin_statement,
+      sym_extend_statement,
+      sym_plugin_statement,
+      aux_sym_block_repeat1,
+  [476] = 31,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      anon_sym_GT,
+    ACTIONS(49), 1,
+      anon_sym_ATimport,
+    ACTIONS(51), 1,
+      anon_sym_ATmedia,
+    ACTIONS(53), 1,
+      anon_sym_ATcharset,
+    ACTIONS(55), 1,
+      anon_sym_ATnamespace,
+    ACTIONS(61), 1,
+      anon_sym_ATsupports,
+    ACTIONS(63), 1,
+      sym_nesting_selector,
+    ACTIONS(65), 1,
+      anon_sym_STAR,
+    ACTIONS(67), 1,
+      anon_sym_DOT,
+    ACTIONS(69), 1,
+      anon_sym_COLON,
+    ACTIONS(71), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(73), 1,
+      anon_sym_POUND,
+    ACTIONS(75), 1,
+      anon_sym_LBRACK,
+    ACTIONS(77), 1,
+      sym_string_value,
+    ACTIONS(79), 1,
+      sym_identifier,
+    ACTIONS(81), 1,
+      sym_at_keyword,
+    ACTIONS(83), 1,
+      sym_grit_metavariable,
+    ACTIONS(85), 1,
+      anon_sym_each,
+    ACTIONS(87), 1,
+      anon_sym_ATplugin,
+    ACTIONS(89), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(91), 1,
+      sym_variable,
+    ACTIONS(99), 1,
+      anon_sym_RBRACE,
+    STATE(317), 1,
+      sym_interpolation,
+    STATE(359), 1,
+      sym__concatenated_identifier,
+    STATE(497), 1,
+      sym__mixin_name,
+    STATE(582), 1,
+      sym_selectors,
+    STATE(612), 1,
+      sym_last_declaration,
+    ACTIONS(57), 2,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+    STATE(371), 11,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+    STATE(7), 15,
+      sym_import_statement,
+      sym_media_statement,
+      sym_charset_statement,
+      sym_namespace_statement,
+      sym_keyframes_statement,
+      sym_supports_statement,
+      sym_at_rule,
+      sym_rule_set,
+      sym_declaration,
+      sym_mixin_definition,
+      sym_each_statement,
+      sym_mixin_statement,
+      sym_extend_statement,
+      sym_plugin_statement,
+      aux_sym_block_repeat1,
+  [595] = 31,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(33), 1,
+      anon_sym_GT,
+    ACTIONS(49), 1,
+      anon_sym_ATimport,
+    ACTIONS(51), 1,
+      anon_sym_ATmedia,
+    ACTIONS(53), 1,
+      anon_sym_ATcharset,
+    ACTIONS(55), 1,
+      anon_sym_ATnamespace,
+    ACTIONS(61), 1,
+      anon_sym_ATsupports,
+    ACTIONS(63), 1,
+      sym_nesting_selector,
+    ACTIONS(65), 1,
+      anon_sym_STAR,
+    ACTIONS(67), 1,
+      anon_sym_DOT,
+    ACTIONS(69), 1,
+      anon_sym_COLON,
+    ACTIONS(71), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(73), 1,
+      anon_sym_POUND,
+    ACTIONS(75), 1,
+      anon_sym_LBRACK,
+    ACTIONS(77), 1,
+      sym_string_value,
+    ACTIONS(79), 1,
+      sym_identifier,
+    ACTIONS(81), 1,
+      sym_at_keyword,
+    ACTIONS(83), 1,
+      sym_grit_metavariable,
+    ACTIONS(85), 1,
+      anon_sym_each,
+    ACTIONS(87), 1,
+      anon_sym_ATplugin,
+    ACTIONS(89), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(91), 1,
+      sym_variable,
+    ACTIONS(101), 1,
+      anon_sym_RBRACE,
+    STATE(317), 1,
+      sym_interpolation,
+    STATE(359), 1,
+      sym__concatenated_identifier,
+    STATE(497), 1,
+      sym__mixin_name,
+    STATE(582), 1,
+      sym_selectors,
+    STATE(616), 1,
+      sym_last_declaration,
+    ACTIONS(57), 2,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+    STATE(371), 11,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+    STATE(8), 15,
+      sym_import_statement,
+      sym_media_statement,
+      sym_charset_statement,
+      sym_namespace_statement,
+      sym_keyframes_statement,
+      sym_supports_statement,
+      sym_at_rule,
+      sym_rule_set,
+      sym_declaration,
+      sym_mixin_definition,
+      sym_each_statement,
+      sym_mixin_statement,
+      sym_extend_statement,
+      sym_plugin_statement,
+      aux_sym_block_repeat1,
+  [714] = 30,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(103), 1,
+      anon_sym_ATimport,
+    ACTIONS(106), 1,
+      anon_sym_ATmedia,
+    ACTIONS(109), 1,
+      anon_sym_ATcharset,
+    ACTIONS(112), 1,
+      anon_sym_ATnamespace,
+    ACTIONS(118), 1,
+      anon_sym_RBRACE,
+    ACTIONS(120), 1,
+      anon_sym_ATsupports,
+    ACTIONS(123), 1,
+      sym_nesting_selector,
+    ACTIONS(126), 1,
+      anon_sym_STAR,
+    ACTIONS(129), 1,
+      anon_sym_DOT,
+    ACTIONS(132), 1,
+      anon_sym_COLON,
+    ACTIONS(135), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(138), 1,
+      anon_sym_POUND,
+    ACTIONS(141), 1,
+      anon_sym_LBRACK,
+    ACTIONS(144), 1,
+      anon_sym_GT,
+    ACTIONS(147), 1,
+      sym_string_value,
+    ACTIONS(150), 1,
+      sym_identifier,
+    ACTIONS(153), 1,
+      sym_at_keyword,
+    ACTIONS(156), 1,
+      sym_grit_metavariable,
+    ACTIONS(159), 1,
+      anon_sym_each,
+    ACTIONS(162), 1,
+      anon_sym_ATplugin,
+    ACTIONS(165), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(168), 1,
+      sym_variable,
+    STATE(317), 1,
+      sym_interpolation,
+    STATE(344), 1,
+      sym__concatenated_identifier,
+    STATE(497), 1,
+      sym__mixin_name,
+    STATE(582), 1,
+      sym_selectors,
+    ACTIONS(115), 2,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+    STATE(371), 11,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+    STATE(8), 15,
+      sym_import_statement,
+      sym_media_statement,
+      sym_charset_statement,
+      sym_namespace_statement,
+      sym_keyframes_statement,
+      sym_supports_statement,
+      sym_at_rule,
+      sym_rule_set,
+      sym_declaration,
+      sym_mixin_definition,
+      sym_each_statement,
+      sym_mixin_statement,
+      sym_extend_statement,
+      sym_plugin_statement,
+      aux_sym_block_repeat1,
+  [830] = 30,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      anon_sym_ATimport,
+    ACTIONS(9), 1,
+      anon_sym_ATmedia,
+    ACTIONS(11), 1,
+      anon_sym_ATcharset,
+    ACTIONS(13), 1,
+      anon_sym_ATnamespace,
+    ACTIONS(17), 1,
+      anon_sym_ATsupports,
+    ACTIONS(19), 1,
+      sym_nesting_selector,
+    ACTIONS(21), 1,
+      anon_sym_STAR,
+    ACTIONS(23), 1,
+      anon_sym_DOT,
+    ACTIONS(25), 1,
+      anon_sym_COLON,
+    ACTIONS(27), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(29), 1,
+      anon_sym_POUND,
+    ACTIONS(31), 1,
+      anon_sym_LBRACK,
+    ACTIONS(33), 1,
+      anon_sym_GT,
+    ACTIONS(37), 1,
+      sym_identifier,
+    ACTIONS(39), 1,
+      sym_at_keyword,
+    ACTIONS(41), 1,
+      anon_sym_each,
+    ACTIONS(43), 1,
+      anon_sym_ATplugin,
+    ACTIONS(45), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(47), 1,
+      sym_variable,
+    ACTIONS(171), 1,
+      ts_builtin_sym_end,
+    STATE(10), 1,
+      aux_sym_stylesheet_repeat1,
+    STATE(337), 1,
+      sym_interpolation,
+    STATE(373), 1,
+      sym__concatenated_identifier,
+    STATE(590), 1,
+      sym__mixin_name,
+    STATE(595), 1,
+      sym_selectors,
+    ACTIONS(15), 2,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+    ACTIONS(35), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(405), 11,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+    STATE(223), 12,
+      sym_import_statement,
+      sym_media_statement,
+      sym_charset_statement,
+      sym_namespace_statement,
+      sym_keyframes_statement,
+      sym_supports_statement,
+      sym_at_rule,
+      sym_rule_set,
+      sym_declaration,
+      sym_mixin_definition,
+      sym_each_statement,
+      sym_plugin_statement,
+  [944] = 30,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(173), 1,
+      ts_builtin_sym_end,
+    ACTIONS(175), 1,
+      anon_sym_ATimport,
+    ACTIONS(178), 1,
+      anon_sym_ATmedia,
+    ACTIONS(181), 1,
+      anon_sym_ATcharset,
+    ACTIONS(184), 1,
+      anon_sym_ATnamespace,
+    ACTIONS(190), 1,
+      anon_sym_ATsupports,
+    ACTIONS(193), 1,
+      sym_nesting_selector,
+    ACTIONS(196), 1,
+      anon_sym_STAR,
+    ACTIONS(199), 1,
+      anon_sym_DOT,
+    ACTIONS(202), 1,
+      anon_sym_COLON,
+    ACTIONS(205), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(208), 1,
+      anon_sym_POUND,
+    ACTIONS(211), 1,
+      anon_sym_LBRACK,
+    ACTIONS(214), 1,
+      anon_sym_GT,
+    ACTIONS(220), 1,
+      sym_identifier,
+    ACTIONS(223), 1,
+      sym_at_keyword,
+    ACTIONS(226), 1,
+      anon_sym_each,
+    ACTIONS(229), 1,
+      anon_sym_ATplugin,
+    ACTIONS(232), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(235), 1,
+      sym_variable,
+    STATE(10), 1,
+      aux_sym_stylesheet_repeat1,
+    STATE(337), 1,
+      sym_interpolation,
+    STATE(373), 1,
+      sym__concatenated_identifier,
+    STATE(590), 1,
+      sym__mixin_name,
+    STATE(595), 1,
+      sym_selectors,
+    ACTIONS(187), 2,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+    ACTIONS(217), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(405), 11,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+    STATE(223), 12,
+      sym_import_statement,
+      sym_media_statement,
+      sym_charset_statement,
+      sym_namespace_statement,
+      sym_keyframes_statement,
+      sym_supports_statement,
+      sym_at_rule,
+      sym_rule_set,
+      sym_declaration,
+      sym_mixin_definition,
+      sym_each_statement,
+      sym_plugin_statement,
+  [1058] = 27,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(238), 1,
+      anon_sym_SEMI,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(244), 1,
+      anon_sym_POUND,
+    ACTIONS(246), 1,
+      anon_sym_GT,
+    ACTIONS(248), 1,
+      anon_sym_TILDE,
+    ACTIONS(250), 1,
+      anon_sym_PLUS,
+    ACTIONS(252), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(256), 1,
+      anon_sym_selector,
+    ACTIONS(258), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(260), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(266), 1,
+      sym_identifier,
+    ACTIONS(268), 1,
+      sym_plain_value,
+    ACTIONS(270), 1,
+      sym_grit_metavariable,
+    ACTIONS(272), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(274), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(276), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(278), 1,
+      anon_sym_AT_AT,
+    ACTIONS(280), 1,
+      sym_variable,
+    STATE(249), 1,
+      sym_interpolation,
+    ACTIONS(240), 2,
+      sym_nesting_selector,
+      sym_string_value,
+    ACTIONS(254), 2,
+      sym_not,
+      sym_only,
+    STATE(134), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(262), 3,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(264), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    STATE(489), 6,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+    STATE(140), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [1164] = 27,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(244), 1,
+      anon_sym_POUND,
+    ACTIONS(246), 1,
+      anon_sym_GT,
+    ACTIONS(248), 1,
+      anon_sym_TILDE,
+    ACTIONS(250), 1,
+      anon_sym_PLUS,
+    ACTIONS(252), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(256), 1,
+      anon_sym_selector,
+    ACTIONS(258), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(260), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(266), 1,
+      sym_identifier,
+    ACTIONS(268), 1,
+      sym_plain_value,
+    ACTIONS(270), 1,
+      sym_grit_metavariable,
+    ACTIONS(272), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(274), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(276), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(278), 1,
+      anon_sym_AT_AT,
+    ACTIONS(280), 1,
+      sym_variable,
+    ACTIONS(282), 1,
+      anon_sym_SEMI,
+    STATE(249), 1,
+      sym_interpolation,
+    ACTIONS(254), 2,
+      sym_not,
+      sym_only,
+    ACTIONS(284), 2,
+      sym_nesting_selector,
+      sym_string_value,
+    STATE(134), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(262), 3,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(264), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    STATE(490), 6,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+    STATE(128), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [1270] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(21), 1,
+      anon_sym_STAR,
+    ACTIONS(25), 1,
+      anon_sym_COLON,
+    ACTIONS(27), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(31), 1,
+      anon_sym_LBRACK,
+    ACTIONS(286), 1,
+      sym_nesting_selector,
+    ACTIONS(288), 1,
+      anon_sym_DOT,
+    ACTIONS(290), 1,
+      anon_sym_POUND,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(294), 1,
+      anon_sym_RPAREN,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(304), 1,
+      sym_identifier,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(308), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(316), 1,
+      sym_variable,
+    STATE(28), 1,
+      sym_interpolation,
+    STATE(56), 1,
+      sym__concatenated_identifier,
+    STATE(61), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    ACTIONS(298), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(101), 11,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+    STATE(408), 11,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+  [1370] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(21), 1,
+      anon_sym_STAR,
+    ACTIONS(25), 1,
+      anon_sym_COLON,
+    ACTIONS(27), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(31), 1,
+      anon_sym_LBRACK,
+    ACTIONS(286), 1,
+      sym_nesting_selector,
+    ACTIONS(288), 1,
+      anon_sym_DOT,
+    ACTIONS(290), 1,
+      anon_sym_POUND,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(304), 1,
+      sym_identifier,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(308), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(316), 1,
+      sym_variable,
+    ACTIONS(318), 1,
+      anon_sym_RPAREN,
+    STATE(28), 1,
+      sym_interpolation,
+    STATE(56), 1,
+      sym__concatenated_identifier,
+    STATE(63), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    ACTIONS(298), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(101), 11,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+    STATE(415), 11,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+  [1470] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(21), 1,
+      anon_sym_STAR,
+    ACTIONS(25), 1,
+      anon_sym_COLON,
+    ACTIONS(27), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(31), 1,
+      anon_sym_LBRACK,
+    ACTIONS(286), 1,
+      sym_nesting_selector,
+    ACTIONS(288), 1,
+      anon_sym_DOT,
+    ACTIONS(290), 1,
+      anon_sym_POUND,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(304), 1,
+      sym_identifier,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(308), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(316), 1,
+      sym_variable,
+    ACTIONS(320), 1,
+      anon_sym_RPAREN,
+    STATE(28), 1,
+      sym_interpolation,
+    STATE(56), 1,
+      sym__concatenated_identifier,
+    STATE(59), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    ACTIONS(298), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(101), 11,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+    STATE(435), 11,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+  [1570] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(21), 1,
+      anon_sym_STAR,
+    ACTIONS(25), 1,
+      anon_sym_COLON,
+    ACTIONS(27), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(31), 1,
+      anon_sym_LBRACK,
+    ACTIONS(286), 1,
+      sym_nesting_selector,
+    ACTIONS(288), 1,
+      anon_sym_DOT,
+    ACTIONS(290), 1,
+      anon_sym_POUND,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(304), 1,
+      sym_identifier,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(308), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(316), 1,
+      sym_variable,
+    ACTIONS(322), 1,
+      anon_sym_RPAREN,
+    STATE(28), 1,
+      sym_interpolation,
+    STATE(56), 1,
+      sym__concatenated_identifier,
+    STATE(58), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    ACTIONS(298), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(101), 11,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+    STATE(434), 11,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+  [1670] = 27,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(250), 1,
+      anon_sym_PLUS,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(324), 1,
+      anon_sym_COMMA,
+    ACTIONS(326), 1,
+      anon_sym_SEMI,
+    ACTIONS(328), 1,
+      anon_sym_RBRACE,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(334), 1,
+      anon_sym_GT,
+    ACTIONS(336), 1,
+      sym_important,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(346), 1,
+      sym_variable,
+    STATE(33), 1,
+      aux_sym_declaration_repeat1,
+    STATE(40), 1,
+      sym_interpolation,
+    STATE(127), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(330), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    ACTIONS(338), 3,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(340), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    STATE(87), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [1771] = 27,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(250), 1,
+      anon_sym_PLUS,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(324), 1,
+      anon_sym_COMMA,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(334), 1,
+      anon_sym_GT,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(346), 1,
+      sym_variable,
+    ACTIONS(348), 1,
+      anon_sym_SEMI,
+    ACTIONS(350), 1,
+      anon_sym_RBRACE,
+    ACTIONS(352), 1,
+      sym_important,
+    STATE(36), 1,
+      aux_sym_declaration_repeat1,
+    STATE(40), 1,
+      sym_interpolation,
+    STATE(127), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(330), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    ACTIONS(338), 3,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(340), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    STATE(87), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [1872] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(21), 1,
+      anon_sym_STAR,
+    ACTIONS(25), 1,
+      anon_sym_COLON,
+    ACTIONS(27), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(31), 1,
+      anon_sym_LBRACK,
+    ACTIONS(286), 1,
+      sym_nesting_selector,
+    ACTIONS(288), 1,
+      anon_sym_DOT,
+    ACTIONS(290), 1,
+      anon_sym_POUND,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(304), 1,
+      sym_identifier,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(308), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(316), 1,
+      sym_variable,
+    STATE(28), 1,
+      sym_interpolation,
+    STATE(56), 1,
+      sym__concatenated_identifier,
+    STATE(89), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    ACTIONS(298), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(101), 11,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+    STATE(463), 11,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+  [1969] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(250), 1,
+      anon_sym_PLUS,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(324), 1,
+      anon_sym_COMMA,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(334), 1,
+      anon_sym_GT,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(346), 1,
+      sym_variable,
+    ACTIONS(354), 1,
+      anon_sym_SEMI,
+    ACTIONS(356), 1,
+      sym_important,
+    STATE(40), 1,
+      sym_interpolation,
+    STATE(51), 1,
+      aux_sym_declaration_repeat1,
+    STATE(127), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(330), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    ACTIONS(338), 3,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(340), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    STATE(87), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [2067] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(250), 1,
+      anon_sym_PLUS,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(324), 1,
+      anon_sym_COMMA,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(334), 1,
+      anon_sym_GT,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(346), 1,
+      sym_variable,
+    ACTIONS(358), 1,
+      anon_sym_SEMI,
+    ACTIONS(360), 1,
+      sym_important,
+    STATE(40), 1,
+      sym_interpolation,
+    STATE(65), 1,
+      aux_sym_declaration_repeat1,
+    STATE(127), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(330), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    ACTIONS(338), 3,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(340), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    STATE(87), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [2165] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(250), 1,
+      anon_sym_PLUS,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(324), 1,
+      anon_sym_COMMA,
+    ACTIONS(326), 1,
+      anon_sym_SEMI,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(334), 1,
+      anon_sym_GT,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(346), 1,
+      sym_variable,
+    ACTIONS(362), 1,
+      sym_important,
+    STATE(40), 1,
+      sym_interpolation,
+    STATE(49), 1,
+      aux_sym_declaration_repeat1,
+    STATE(127), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(330), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    ACTIONS(338), 3,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(340), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    STATE(87), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [2263] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(250), 1,
+      anon_sym_PLUS,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(324), 1,
+      anon_sym_COMMA,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(334), 1,
+      anon_sym_GT,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(346), 1,
+      sym_variable,
+    ACTIONS(348), 1,
+      anon_sym_SEMI,
+    ACTIONS(364), 1,
+      sym_important,
+    STATE(40), 1,
+      sym_interpolation,
+    STATE(62), 1,
+      aux_sym_declaration_repeat1,
+    STATE(127), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(330), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    ACTIONS(338), 3,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(340), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    STATE(87), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [2361] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(256), 1,
+      anon_sym_selector,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(372), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(378), 1,
+      sym_identifier,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(382), 1,
+      sym_grit_metavariable,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(392), 1,
+      sym_variable,
+    STATE(316), 1,
+      sym_interpolation,
+    ACTIONS(254), 2,
+      sym_not,
+      sym_only,
+    ACTIONS(366), 2,
+      sym_nesting_selector,
+      sym_string_value,
+    STATE(562), 6,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+    STATE(354), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [2440] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(256), 1,
+      anon_sym_selector,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(372), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(378), 1,
+      sym_identifier,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(382), 1,
+      sym_grit_metavariable,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(392), 1,
+      sym_variable,
+    STATE(316), 1,
+      sym_interpolation,
+    ACTIONS(254), 2,
+      sym_not,
+      sym_only,
+    ACTIONS(394), 2,
+      sym_nesting_selector,
+      sym_string_value,
+    STATE(562), 6,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+    STATE(358), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [2519] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(402), 1,
+      anon_sym_LPAREN,
+    ACTIONS(406), 1,
+      sym__concat,
+    STATE(29), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    STATE(73), 1,
+      sym_arguments,
+    ACTIONS(400), 4,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_GT,
+      anon_sym_PLUS,
+    ACTIONS(396), 8,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      sym_nesting_selector,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+    ACTIONS(398), 10,
+      anon_sym_STAR,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+    ACTIONS(404), 10,
+      anon_sym_LPAREN2,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+  [2575] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(402), 1,
+      anon_sym_LPAREN,
+    ACTIONS(408), 1,
+      sym__concat,
+    STATE(41), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    STATE(73), 1,
+      sym_arguments,
+    ACTIONS(404), 12,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(398), 19,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      sym_important,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [2626] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(406), 1,
+      sym__concat,
+    STATE(29), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(412), 13,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(410), 19,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [2672] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(406), 1,
+      sym__concat,
+    STATE(31), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(416), 13,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(414), 19,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [2718] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(426), 1,
+      sym_identifier,
+    ACTIONS(428), 1,
+      anon_sym_AT_LBRACE,
+    STATE(340), 1,
+      sym_interpolation,
+    STATE(409), 1,
+      sym__concatenated_identifier,
+    ACTIONS(422), 4,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_GT,
+      anon_sym_PLUS,
+    ACTIONS(418), 8,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      sym_nesting_selector,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+    ACTIONS(424), 8,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(420), 10,
+      anon_sym_STAR,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [2772] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(434), 1,
+      sym__concat,
+    STATE(31), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(432), 13,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(430), 19,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [2818] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(439), 1,
+      anon_sym_RPAREN,
+    ACTIONS(441), 1,
+      anon_sym_DOT_DOT_DOT,
+    ACTIONS(443), 1,
+      sym_variable,
+    STATE(35), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    STATE(40), 1,
+      sym_interpolation,
+    STATE(499), 2,
+      sym_parameter,
+      sym_rest_parameter,
+    ACTIONS(437), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(81), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [2893] = 21,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(324), 1,
+      anon_sym_COMMA,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(346), 1,
+      sym_variable,
+    ACTIONS(445), 1,
+      anon_sym_SEMI,
+    ACTIONS(447), 1,
+      anon_sym_RBRACE,
+    ACTIONS(449), 1,
+      sym_important,
+    STATE(34), 1,
+      aux_sym_declaration_repeat1,
+    STATE(40), 1,
+      sym_interpolation,
+    ACTIONS(330), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(87), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [2970] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(451), 1,
+      anon_sym_COMMA,
+    ACTIONS(459), 1,
+      anon_sym_POUND,
+    ACTIONS(462), 1,
+      anon_sym_TILDE,
+    ACTIONS(465), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(468), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(471), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(474), 1,
+      sym_identifier,
+    ACTIONS(477), 1,
+      sym_plain_value,
+    ACTIONS(480), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(483), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(486), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(489), 1,
+      anon_sym_AT_AT,
+    ACTIONS(492), 1,
+      sym_variable,
+    STATE(34), 1,
+      aux_sym_declaration_repeat1,
+    STATE(40), 1,
+      sym_interpolation,
+    ACTIONS(454), 3,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_important,
+    ACTIONS(456), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(87), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [3043] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(316), 1,
+      sym_variable,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(499), 1,
+      anon_sym_RPAREN,
+    STATE(40), 1,
+      sym_interpolation,
+    STATE(50), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    STATE(517), 1,
+      aux_sym_arguments_repeat1,
+    ACTIONS(495), 2,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+    ACTIONS(497), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(101), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [3118] = 21,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(324), 1,
+      anon_sym_COMMA,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(346), 1,
+      sym_variable,
+    ACTIONS(501), 1,
+      anon_sym_SEMI,
+    ACTIONS(503), 1,
+      anon_sym_RBRACE,
+    ACTIONS(505), 1,
+      sym_important,
+    STATE(34), 1,
+      aux_sym_declaration_repeat1,
+    STATE(40), 1,
+      sym_interpolation,
+    ACTIONS(330), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(87), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [3195] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(402), 1,
+      anon_sym_LPAREN,
+    STATE(73), 1,
+      sym_arguments,
+    ACTIONS(424), 12,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(420), 19,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      sym_important,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [3240] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(127), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(509), 11,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(507), 20,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [3283] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(127), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(513), 11,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(511), 20,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [3326] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(408), 1,
+      sym__concat,
+    STATE(41), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(412), 11,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(410), 20,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [3371] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(408), 1,
+      sym__concat,
+    STATE(44), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(416), 11,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(414), 20,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [3416] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(432), 13,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(430), 20,
+      sym__descendant_operator,
+      sym__concat,
+      anon_sym_COMMA,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [3457] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(517), 13,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(515), 20,
+      sym__descendant_operator,
+      sym__concat,
+      anon_sym_COMMA,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [3498] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(519), 1,
+      sym__concat,
+    STATE(44), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(432), 11,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(430), 20,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [3543] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(316), 1,
+      sym_variable,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(522), 1,
+      anon_sym_RPAREN,
+    STATE(40), 1,
+      sym_interpolation,
+    STATE(50), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    STATE(514), 1,
+      aux_sym_arguments_repeat1,
+    ACTIONS(495), 2,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+    ACTIONS(497), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(101), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [3618] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(524), 1,
+      anon_sym_LPAREN,
+    ACTIONS(526), 1,
+      sym__concat,
+    STATE(83), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    STATE(109), 1,
+      sym_arguments,
+    ACTIONS(398), 14,
+      anon_sym_SEMI,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+    ACTIONS(404), 15,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+  [3667] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(316), 1,
+      sym_variable,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(528), 1,
+      anon_sym_RPAREN,
+    STATE(40), 1,
+      sym_interpolation,
+    STATE(50), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    STATE(519), 1,
+      aux_sym_arguments_repeat1,
+    ACTIONS(495), 2,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+    ACTIONS(497), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(101), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [3742] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(316), 1,
+      sym_variable,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(530), 1,
+      anon_sym_RPAREN,
+    STATE(40), 1,
+      sym_interpolation,
+    STATE(50), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    STATE(520), 1,
+      aux_sym_arguments_repeat1,
+    ACTIONS(495), 2,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+    ACTIONS(497), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(101), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [3817] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(324), 1,
+      anon_sym_COMMA,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(346), 1,
+      sym_variable,
+    ACTIONS(445), 1,
+      anon_sym_SEMI,
+    ACTIONS(532), 1,
+      sym_important,
+    STATE(34), 1,
+      aux_sym_declaration_repeat1,
+    STATE(40), 1,
+      sym_interpolation,
+    ACTIONS(330), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(87), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [3891] = 18,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(539), 1,
+      anon_sym_POUND,
+    ACTIONS(542), 1,
+      anon_sym_TILDE,
+    ACTIONS(545), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(548), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(551), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(554), 1,
+      sym_identifier,
+    ACTIONS(557), 1,
+      sym_plain_value,
+    ACTIONS(560), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(563), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(566), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(569), 1,
+      anon_sym_AT_AT,
+    ACTIONS(572), 1,
+      sym_variable,
+    STATE(40), 1,
+      sym_interpolation,
+    STATE(50), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    ACTIONS(534), 3,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
+    ACTIONS(536), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(101), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [3961] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(324), 1,
+      anon_sym_COMMA,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(346), 1,
+      sym_variable,
+    ACTIONS(575), 1,
+      anon_sym_SEMI,
+    ACTIONS(577), 1,
+      sym_important,
+    STATE(34), 1,
+      aux_sym_declaration_repeat1,
+    STATE(40), 1,
+      sym_interpolation,
+    ACTIONS(330), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(87), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [4035] = 18,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(316), 1,
+      sym_variable,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    STATE(40), 1,
+      sym_interpolation,
+    STATE(50), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    ACTIONS(497), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    ACTIONS(579), 3,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
+    STATE(101), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [4105] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(585), 1,
+      sym_unit,
+    ACTIONS(583), 11,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(581), 20,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [4147] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(422), 4,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_GT,
+      anon_sym_PLUS,
+    ACTIONS(418), 8,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      sym_nesting_selector,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+    ACTIONS(424), 9,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(420), 11,
+      anon_sym_STAR,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [4191] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(432), 11,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(430), 21,
+      sym__concat,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [4231] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(400), 4,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_GT,
+      anon_sym_PLUS,
+    ACTIONS(396), 8,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      sym_nesting_selector,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+    ACTIONS(424), 9,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(420), 11,
+      anon_sym_STAR,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [4275] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(591), 1,
+      sym_unit,
+    ACTIONS(589), 11,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(587), 20,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [4317] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(316), 1,
+      sym_variable,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(593), 1,
+      anon_sym_COMMA,
+    ACTIONS(595), 1,
+      anon_sym_RPAREN,
+    STATE(40), 1,
+      sym_interpolation,
+    STATE(50), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    STATE(551), 1,
+      aux_sym_pseudo_class_arguments_repeat2,
+    ACTIONS(497), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(101), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [4391] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(316), 1,
+      sym_variable,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(593), 1,
+      anon_sym_COMMA,
+    ACTIONS(597), 1,
+      anon_sym_RPAREN,
+    STATE(40), 1,
+      sym_interpolation,
+    STATE(50), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    STATE(556), 1,
+      aux_sym_pseudo_class_arguments_repeat2,
+    ACTIONS(497), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(101), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [4465] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(441), 1,
+      anon_sym_DOT_DOT_DOT,
+    ACTIONS(601), 1,
+      anon_sym_RPAREN,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(607), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    STATE(499), 2,
+      sym_parameter,
+      sym_rest_parameter,
+    ACTIONS(599), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(321), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [4537] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(316), 1,
+      sym_variable,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(593), 1,
+      anon_sym_COMMA,
+    ACTIONS(609), 1,
+      anon_sym_RPAREN,
+    STATE(40), 1,
+      sym_interpolation,
+    STATE(50), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    STATE(552), 1,
+      aux_sym_pseudo_class_arguments_repeat2,
+    ACTIONS(497), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(101), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [4611] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(324), 1,
+      anon_sym_COMMA,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(346), 1,
+      sym_variable,
+    ACTIONS(501), 1,
+      anon_sym_SEMI,
+    ACTIONS(611), 1,
+      sym_important,
+    STATE(34), 1,
+      aux_sym_declaration_repeat1,
+    STATE(40), 1,
+      sym_interpolation,
+    ACTIONS(330), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(87), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [4685] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(316), 1,
+      sym_variable,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(593), 1,
+      anon_sym_COMMA,
+    ACTIONS(613), 1,
+      anon_sym_RPAREN,
+    STATE(40), 1,
+      sym_interpolation,
+    STATE(50), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    STATE(566), 1,
+      aux_sym_pseudo_class_arguments_repeat2,
+    ACTIONS(497), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(101), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [4759] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(517), 11,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(515), 21,
+      sym__concat,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [4799] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(324), 1,
+      anon_sym_COMMA,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(346), 1,
+      sym_variable,
+    ACTIONS(615), 1,
+      anon_sym_SEMI,
+    ACTIONS(617), 1,
+      sym_important,
+    STATE(34), 1,
+      aux_sym_declaration_repeat1,
+    STATE(40), 1,
+      sym_interpolation,
+    ACTIONS(330), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(87), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [4873] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(621), 11,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(619), 20,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [4912] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(524), 1,
+      anon_sym_LPAREN,
+    STATE(109), 1,
+      sym_arguments,
+    ACTIONS(420), 14,
+      anon_sym_SEMI,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+    ACTIONS(424), 15,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+  [4955] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(250), 1,
+      anon_sym_PLUS,
+    ACTIONS(334), 1,
+      anon_sym_GT,
+    STATE(127), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(338), 3,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(340), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(625), 6,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(623), 13,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [5006] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(629), 11,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(627), 20,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [5045] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(633), 11,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(631), 20,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [5084] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(637), 11,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(635), 20,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [5123] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(641), 11,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(639), 20,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [5162] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(645), 11,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(643), 20,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [5201] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(134), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(509), 14,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(507), 15,
+      anon_sym_SEMI,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [5242] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(134), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(513), 14,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(511), 15,
+      anon_sym_SEMI,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [5283] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(649), 11,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(647), 20,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [5322] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(653), 11,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(651), 20,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [5361] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(657), 11,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(655), 20,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [5400] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(661), 11,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(659), 20,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [5439] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(665), 11,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(663), 20,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [5478] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(250), 1,
+      anon_sym_PLUS,
+    ACTIONS(334), 1,
+      anon_sym_GT,
+    ACTIONS(672), 1,
+      anon_sym_COLON,
+    STATE(127), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(338), 3,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(667), 3,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
+    ACTIONS(340), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(674), 6,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(670), 9,
+      sym_nesting_selector,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [5533] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(526), 1,
+      sym__concat,
+    STATE(83), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(412), 14,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(410), 15,
+      anon_sym_SEMI,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [5576] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(526), 1,
+      sym__concat,
+    STATE(86), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(416), 14,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(414), 15,
+      anon_sym_SEMI,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [5619] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(678), 11,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(676), 20,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [5658] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(682), 11,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(680), 20,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [5697] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(684), 1,
+      sym__concat,
+    STATE(86), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(432), 14,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(430), 15,
+      anon_sym_SEMI,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [5740] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(250), 1,
+      anon_sym_PLUS,
+    ACTIONS(334), 1,
+      anon_sym_GT,
+    STATE(127), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(338), 3,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(340), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(689), 6,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(687), 13,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [5791] = 18,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(441), 1,
+      anon_sym_DOT_DOT_DOT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(607), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    STATE(544), 2,
+      sym_parameter,
+      sym_rest_parameter,
+    ACTIONS(599), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(321), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [5860] = 18,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(316), 1,
+      sym_variable,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    STATE(40), 1,
+      sym_interpolation,
+    STATE(50), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    ACTIONS(691), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+    ACTIONS(497), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(101), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [5929] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(693), 1,
+      sym_unit,
+    ACTIONS(589), 14,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(587), 15,
+      anon_sym_SEMI,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [5969] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(517), 14,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(515), 16,
+      sym__concat,
+      anon_sym_SEMI,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [6007] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(695), 1,
+      anon_sym_DOT_DOT_DOT,
+    ACTIONS(424), 11,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(420), 18,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [6047] = 18,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(316), 1,
+      sym_variable,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(697), 1,
+      anon_sym_RPAREN,
+    STATE(35), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    STATE(40), 1,
+      sym_interpolation,
+    ACTIONS(497), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(101), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [6115] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(637), 14,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(635), 16,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [6153] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(678), 14,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(676), 16,
+      anon_sym_SEMI,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [6191] = 18,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(316), 1,
+      sym_variable,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(699), 1,
+      anon_sym_RPAREN,
+    STATE(40), 1,
+      sym_interpolation,
+    STATE(50), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    ACTIONS(497), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(101), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [6259] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(701), 1,
+      sym_unit,
+    ACTIONS(583), 14,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(581), 15,
+      anon_sym_SEMI,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [6299] = 18,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(316), 1,
+      sym_variable,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(703), 1,
+      anon_sym_RPAREN,
+    STATE(40), 1,
+      sym_interpolation,
+    STATE(45), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    ACTIONS(497), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(101), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [6367] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(712), 1,
+      anon_sym_COLON,
+    ACTIONS(707), 5,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(418), 6,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+    ACTIONS(709), 6,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+    ACTIONS(705), 12,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [6411] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(682), 14,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(680), 16,
+      anon_sym_SEMI,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [6449] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(250), 1,
+      anon_sym_PLUS,
+    ACTIONS(334), 1,
+      anon_sym_GT,
+    STATE(127), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(338), 3,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(340), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(674), 6,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(670), 12,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      sym_nesting_selector,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [6499] = 18,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(316), 1,
+      sym_variable,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(715), 1,
+      anon_sym_RPAREN,
+    STATE(40), 1,
+      sym_interpolation,
+    STATE(47), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    ACTIONS(497), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(101), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [6567] = 18,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(316), 1,
+      sym_variable,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(717), 1,
+      anon_sym_RPAREN,
+    STATE(40), 1,
+      sym_interpolation,
+    STATE(48), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    ACTIONS(497), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(101), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [6635] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(432), 14,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(430), 16,
+      sym__concat,
+      anon_sym_SEMI,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [6673] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(629), 14,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(627), 15,
+      anon_sym_SEMI,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [6710] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(633), 14,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(631), 15,
+      anon_sym_SEMI,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [6747] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(641), 14,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(639), 15,
+      anon_sym_SEMI,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [6784] = 17,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(316), 1,
+      sym_variable,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    STATE(40), 1,
+      sym_interpolation,
+    STATE(96), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    ACTIONS(497), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(101), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [6849] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(645), 14,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(643), 15,
+      anon_sym_SEMI,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [6886] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(649), 14,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(647), 15,
+      anon_sym_SEMI,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [6923] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(653), 14,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(651), 15,
+      anon_sym_SEMI,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [6960] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(621), 14,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(619), 15,
+      anon_sym_SEMI,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [6997] = 17,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(316), 1,
+      sym_variable,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    STATE(40), 1,
+      sym_interpolation,
+    STATE(52), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    ACTIONS(497), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(101), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [7062] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(665), 14,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(663), 15,
+      anon_sym_SEMI,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [7099] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(661), 14,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(659), 15,
+      anon_sym_SEMI,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [7136] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(657), 14,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(655), 15,
+      anon_sym_SEMI,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [7173] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(721), 1,
+      sym_variable,
+    STATE(40), 1,
+      sym_interpolation,
+    ACTIONS(719), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(18), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [7235] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(244), 1,
+      anon_sym_POUND,
+    ACTIONS(248), 1,
+      anon_sym_TILDE,
+    ACTIONS(258), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(260), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(268), 1,
+      sym_plain_value,
+    ACTIONS(274), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(276), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(278), 1,
+      anon_sym_AT_AT,
+    ACTIONS(725), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(727), 1,
+      sym_identifier,
+    ACTIONS(729), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(731), 1,
+      sym_variable,
+    STATE(271), 1,
+      sym_interpolation,
+    ACTIONS(723), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(268), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [7297] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(735), 1,
+      sym_variable,
+    STATE(40), 1,
+      sym_interpolation,
+    ACTIONS(733), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(68), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [7359] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(244), 1,
+      anon_sym_POUND,
+    ACTIONS(248), 1,
+      anon_sym_TILDE,
+    ACTIONS(258), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(260), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(268), 1,
+      sym_plain_value,
+    ACTIONS(274), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(276), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(278), 1,
+      anon_sym_AT_AT,
+    ACTIONS(725), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(727), 1,
+      sym_identifier,
+    ACTIONS(729), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(739), 1,
+      sym_variable,
+    STATE(271), 1,
+      sym_interpolation,
+    ACTIONS(737), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(269), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [7421] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(743), 1,
+      sym_variable,
+    STATE(40), 1,
+      sym_interpolation,
+    ACTIONS(741), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(20), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [7483] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(747), 1,
+      anon_sym_POUND,
+    ACTIONS(749), 1,
+      anon_sym_TILDE,
+    ACTIONS(751), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(753), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(755), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(757), 1,
+      sym_identifier,
+    ACTIONS(759), 1,
+      sym_plain_value,
+    ACTIONS(761), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(763), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(765), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(767), 1,
+      anon_sym_AT_AT,
+    ACTIONS(769), 1,
+      sym_variable,
+    STATE(82), 1,
+      sym_interpolation,
+    ACTIONS(745), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(74), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [7545] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(773), 1,
+      sym_variable,
+    STATE(40), 1,
+      sym_interpolation,
+    ACTIONS(771), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(38), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [7607] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(777), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(775), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(382), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [7669] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(781), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(779), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(451), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [7731] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(785), 1,
+      sym_variable,
+    STATE(40), 1,
+      sym_interpolation,
+    ACTIONS(783), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(21), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [7793] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(789), 1,
+      sym_variable,
+    STATE(40), 1,
+      sym_interpolation,
+    ACTIONS(787), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(39), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [7855] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(256), 1,
+      anon_sym_selector,
+    ACTIONS(791), 1,
+      anon_sym_SEMI,
+    ACTIONS(793), 1,
+      anon_sym_GT,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(797), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(803), 1,
+      sym_identifier,
+    ACTIONS(807), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(254), 2,
+      sym_not,
+      sym_only,
+    ACTIONS(805), 2,
+      sym_grit_metavariable,
+      sym_variable,
+    STATE(120), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(799), 3,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(801), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    STATE(496), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [7915] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(747), 1,
+      anon_sym_POUND,
+    ACTIONS(749), 1,
+      anon_sym_TILDE,
+    ACTIONS(751), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(753), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(755), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(757), 1,
+      sym_identifier,
+    ACTIONS(759), 1,
+      sym_plain_value,
+    ACTIONS(761), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(763), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(765), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(767), 1,
+      anon_sym_AT_AT,
+    ACTIONS(811), 1,
+      sym_variable,
+    STATE(82), 1,
+      sym_interpolation,
+    ACTIONS(809), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(11), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [7977] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(815), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(813), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(325), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [8039] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(819), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(817), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(427), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [8101] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(823), 1,
+      sym_variable,
+    STATE(40), 1,
+      sym_interpolation,
+    ACTIONS(821), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(17), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [8163] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(827), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(825), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(372), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [8225] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(747), 1,
+      anon_sym_POUND,
+    ACTIONS(749), 1,
+      anon_sym_TILDE,
+    ACTIONS(751), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(753), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(755), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(757), 1,
+      sym_identifier,
+    ACTIONS(759), 1,
+      sym_plain_value,
+    ACTIONS(761), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(763), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(765), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(767), 1,
+      anon_sym_AT_AT,
+    ACTIONS(831), 1,
+      sym_variable,
+    STATE(82), 1,
+      sym_interpolation,
+    ACTIONS(829), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(75), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [8287] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(835), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(833), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(386), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [8349] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(839), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(837), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(407), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [8411] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(843), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(841), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(294), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [8473] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(847), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(845), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(297), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [8535] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(851), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(849), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(347), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [8597] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(256), 1,
+      anon_sym_selector,
+    ACTIONS(793), 1,
+      anon_sym_GT,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(797), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(803), 1,
+      sym_identifier,
+    ACTIONS(807), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(853), 1,
+      anon_sym_SEMI,
+    ACTIONS(254), 2,
+      sym_not,
+      sym_only,
+    ACTIONS(855), 2,
+      sym_grit_metavariable,
+      sym_variable,
+    STATE(120), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(799), 3,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(801), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    STATE(498), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [8657] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(747), 1,
+      anon_sym_POUND,
+    ACTIONS(749), 1,
+      anon_sym_TILDE,
+    ACTIONS(751), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(753), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(755), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(757), 1,
+      sym_identifier,
+    ACTIONS(759), 1,
+      sym_plain_value,
+    ACTIONS(761), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(763), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(765), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(767), 1,
+      anon_sym_AT_AT,
+    ACTIONS(859), 1,
+      sym_variable,
+    STATE(82), 1,
+      sym_interpolation,
+    ACTIONS(857), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(12), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [8719] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(863), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(861), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(428), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [8781] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(867), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(865), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(429), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [8843] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(871), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(869), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(430), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [8905] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(873), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(366), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(354), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [8967] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(877), 1,
+      sym_variable,
+    STATE(40), 1,
+      sym_interpolation,
+    ACTIONS(875), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(22), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [9029] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(881), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(879), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(420), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [9091] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(885), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(883), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(436), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [9153] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(292), 1,
+      anon_sym_TILDE,
+    ACTIONS(296), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(300), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(302), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(306), 1,
+      sym_plain_value,
+    ACTIONS(310), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(312), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(314), 1,
+      anon_sym_AT_AT,
+    ACTIONS(332), 1,
+      anon_sym_POUND,
+    ACTIONS(342), 1,
+      sym_identifier,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(889), 1,
+      sym_variable,
+    STATE(40), 1,
+      sym_interpolation,
+    ACTIONS(887), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(23), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [9215] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(893), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(891), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(437), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [9277] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(897), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(895), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(438), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [9339] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(901), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(899), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(360), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [9401] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(905), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(903), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(439), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [9463] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(907), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(394), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(358), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [9525] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(911), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(909), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(440), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [9587] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(915), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(913), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(441), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [9649] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(919), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(917), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(442), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [9711] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(923), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(921), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(443), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [9773] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(927), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(925), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(444), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [9835] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(931), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(929), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(445), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [9897] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(935), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(933), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(446), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [9959] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(939), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(937), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(460), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [10021] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      anon_sym_POUND,
+    ACTIONS(370), 1,
+      anon_sym_TILDE,
+    ACTIONS(374), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(376), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_plain_value,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(386), 1,
+      anon_sym_DOLLAR,
+    ACTIONS(388), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(390), 1,
+      anon_sym_AT_AT,
+    ACTIONS(603), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(605), 1,
+      sym_identifier,
+    ACTIONS(943), 1,
+      sym_variable,
+    STATE(298), 1,
+      sym_interpolation,
+    ACTIONS(941), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(406), 12,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym__concatenated_identifier,
+      sym_property_value,
+      sym_escaped_value,
+      sym_value_value,
+  [10083] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(947), 1,
+      anon_sym_LPAREN,
+    ACTIONS(951), 1,
+      sym__concat,
+    STATE(270), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    STATE(282), 1,
+      sym_arguments,
+    ACTIONS(945), 2,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+    ACTIONS(949), 2,
+      sym_and,
+      sym_or,
+    ACTIONS(398), 9,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+    ACTIONS(404), 9,
+      anon_sym_GT,
+      anon_sym_LPAREN2,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+  [10129] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(953), 12,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_RPAREN,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(955), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [10162] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(957), 12,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_RPAREN,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(959), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [10195] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(961), 12,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_RPAREN,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(963), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [10228] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(965), 12,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_RPAREN,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(967), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [10261] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(19), 1,
+      sym_nesting_selector,
+    ACTIONS(21), 1,
+      anon_sym_STAR,
+    ACTIONS(25), 1,
+      anon_sym_COLON,
+    ACTIONS(27), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(31), 1,
+      anon_sym_LBRACK,
+    ACTIONS(428), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(969), 1,
+      anon_sym_DOT,
+    ACTIONS(971), 1,
+      anon_sym_POUND,
+    ACTIONS(975), 1,
+      sym_identifier,
+    STATE(340), 1,
+      sym_interpolation,
+    STATE(454), 1,
+      sym__concatenated_identifier,
+    ACTIONS(973), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(453), 11,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+  [10315] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(977), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(979), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [10347] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(981), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(983), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [10379] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(985), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(987), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [10411] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(989), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(991), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [10443] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(993), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(995), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [10475] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(997), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(999), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [10507] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1001), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1003), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [10539] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1005), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1007), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [10571] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1011), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1009), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [10603] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1013), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1015), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [10635] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1017), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1019), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [10667] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1021), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1023), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [10699] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1025), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1027), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [10731] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1029), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1031), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [10763] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1033), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1035), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [10795] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1037), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1039), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [10827] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1041), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1043), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [10859] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1045), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1047), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [10891] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1049), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1051), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [10923] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1053), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1055), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [10955] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(19), 1,
+      sym_nesting_selector,
+    ACTIONS(21), 1,
+      anon_sym_STAR,
+    ACTIONS(25), 1,
+      anon_sym_COLON,
+    ACTIONS(27), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(31), 1,
+      anon_sym_LBRACK,
+    ACTIONS(428), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(969), 1,
+      anon_sym_DOT,
+    ACTIONS(971), 1,
+      anon_sym_POUND,
+    ACTIONS(975), 1,
+      sym_identifier,
+    STATE(340), 1,
+      sym_interpolation,
+    STATE(454), 1,
+      sym__concatenated_identifier,
+    ACTIONS(1057), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(464), 11,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+  [11009] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1059), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1061), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [11041] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(19), 1,
+      sym_nesting_selector,
+    ACTIONS(21), 1,
+      anon_sym_STAR,
+    ACTIONS(25), 1,
+      anon_sym_COLON,
+    ACTIONS(27), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(31), 1,
+      anon_sym_LBRACK,
+    ACTIONS(428), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(969), 1,
+      anon_sym_DOT,
+    ACTIONS(971), 1,
+      anon_sym_POUND,
+    ACTIONS(975), 1,
+      sym_identifier,
+    STATE(340), 1,
+      sym_interpolation,
+    STATE(454), 1,
+      sym__concatenated_identifier,
+    ACTIONS(1063), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(466), 11,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+  [11095] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1067), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1065), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [11127] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1071), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1069), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [11159] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(19), 1,
+      sym_nesting_selector,
+    ACTIONS(21), 1,
+      anon_sym_STAR,
+    ACTIONS(25), 1,
+      anon_sym_COLON,
+    ACTIONS(27), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(31), 1,
+      anon_sym_LBRACK,
+    ACTIONS(428), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(969), 1,
+      anon_sym_DOT,
+    ACTIONS(971), 1,
+      anon_sym_POUND,
+    ACTIONS(975), 1,
+      sym_identifier,
+    STATE(340), 1,
+      sym_interpolation,
+    STATE(454), 1,
+      sym__concatenated_identifier,
+    ACTIONS(1073), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(458), 11,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+  [11213] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(19), 1,
+      sym_nesting_selector,
+    ACTIONS(21), 1,
+      anon_sym_STAR,
+    ACTIONS(25), 1,
+      anon_sym_COLON,
+    ACTIONS(27), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(31), 1,
+      anon_sym_LBRACK,
+    ACTIONS(428), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(969), 1,
+      anon_sym_DOT,
+    ACTIONS(971), 1,
+      anon_sym_POUND,
+    ACTIONS(975), 1,
+      sym_identifier,
+    STATE(340), 1,
+      sym_interpolation,
+    STATE(454), 1,
+      sym__concatenated_identifier,
+    ACTIONS(1075), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(459), 11,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+  [11267] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1077), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1079), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [11299] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(19), 1,
+      sym_nesting_selector,
+    ACTIONS(21), 1,
+      anon_sym_STAR,
+    ACTIONS(25), 1,
+      anon_sym_COLON,
+    ACTIONS(27), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(31), 1,
+      anon_sym_LBRACK,
+    ACTIONS(428), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(969), 1,
+      anon_sym_DOT,
+    ACTIONS(971), 1,
+      anon_sym_POUND,
+    ACTIONS(975), 1,
+      sym_identifier,
+    STATE(340), 1,
+      sym_interpolation,
+    STATE(454), 1,
+      sym__concatenated_identifier,
+    ACTIONS(1081), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(419), 11,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+  [11353] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1083), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1085), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [11385] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1087), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1089), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [11417] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1091), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1093), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [11449] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1097), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1095), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [11481] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1101), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1099), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [11513] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1103), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1105), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [11545] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1107), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1109), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [11577] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1021), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1023), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [11609] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1029), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1031), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [11641] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1045), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1047), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [11673] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(63), 1,
+      sym_nesting_selector,
+    ACTIONS(65), 1,
+      anon_sym_STAR,
+    ACTIONS(69), 1,
+      anon_sym_COLON,
+    ACTIONS(71), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(75), 1,
+      anon_sym_LBRACK,
+    ACTIONS(1111), 1,
+      anon_sym_DOT,
+    ACTIONS(1113), 1,
+      anon_sym_POUND,
+    ACTIONS(1117), 1,
+      sym_identifier,
+    ACTIONS(1119), 1,
+      anon_sym_AT_LBRACE,
+    STATE(370), 1,
+      sym_interpolation,
+    STATE(452), 1,
+      sym__concatenated_identifier,
+    ACTIONS(1115), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(433), 11,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+  [11727] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(63), 1,
+      sym_nesting_selector,
+    ACTIONS(65), 1,
+      anon_sym_STAR,
+    ACTIONS(69), 1,
+      anon_sym_COLON,
+    ACTIONS(71), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(75), 1,
+      anon_sym_LBRACK,
+    ACTIONS(1111), 1,
+      anon_sym_DOT,
+    ACTIONS(1113), 1,
+      anon_sym_POUND,
+    ACTIONS(1117), 1,
+      sym_identifier,
+    ACTIONS(1119), 1,
+      anon_sym_AT_LBRACE,
+    STATE(370), 1,
+      sym_interpolation,
+    STATE(452), 1,
+      sym__concatenated_identifier,
+    ACTIONS(1121), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(383), 11,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+  [11781] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(63), 1,
+      sym_nesting_selector,
+    ACTIONS(65), 1,
+      anon_sym_STAR,
+    ACTIONS(69), 1,
+      anon_sym_COLON,
+    ACTIONS(71), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(75), 1,
+      anon_sym_LBRACK,
+    ACTIONS(1111), 1,
+      anon_sym_DOT,
+    ACTIONS(1113), 1,
+      anon_sym_POUND,
+    ACTIONS(1117), 1,
+      sym_identifier,
+    ACTIONS(1119), 1,
+      anon_sym_AT_LBRACE,
+    STATE(370), 1,
+      sym_interpolation,
+    STATE(452), 1,
+      sym__concatenated_identifier,
+    ACTIONS(1123), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(384), 11,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+  [11835] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(63), 1,
+      sym_nesting_selector,
+    ACTIONS(65), 1,
+      anon_sym_STAR,
+    ACTIONS(69), 1,
+      anon_sym_COLON,
+    ACTIONS(71), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(75), 1,
+      anon_sym_LBRACK,
+    ACTIONS(1111), 1,
+      anon_sym_DOT,
+    ACTIONS(1113), 1,
+      anon_sym_POUND,
+    ACTIONS(1117), 1,
+      sym_identifier,
+    ACTIONS(1119), 1,
+      anon_sym_AT_LBRACE,
+    STATE(370), 1,
+      sym_interpolation,
+    STATE(452), 1,
+      sym__concatenated_identifier,
+    ACTIONS(1125), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(385), 11,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+  [11889] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1129), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1127), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [11921] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1133), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1131), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [11953] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1137), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1135), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [11985] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1141), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1139), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12017] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1145), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1143), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12049] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1149), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1147), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12081] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(957), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(959), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12113] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1153), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1151), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12145] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1157), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1155), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12177] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(977), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(979), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12209] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1159), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1161), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12241] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(981), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(983), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12273] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(985), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(987), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12305] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(989), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(991), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12337] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(993), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(995), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12369] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(997), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(999), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12401] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1001), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1003), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12433] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1005), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1007), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12465] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(961), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(963), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12497] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(953), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(955), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12529] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1013), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1015), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12561] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1017), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1019), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12593] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1025), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1027), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12625] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1037), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1039), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12657] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1041), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1043), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12689] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1049), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1051), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12721] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1053), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1055), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12753] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1059), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1061), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12785] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(965), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(967), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12817] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1077), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1079), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12849] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1083), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1085), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12881] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1087), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1089), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12913] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1091), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1093), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12945] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1103), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1105), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [12977] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1107), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1109), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [13009] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1129), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1127), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [13041] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(951), 1,
+      sym__concat,
+    ACTIONS(1163), 1,
+      anon_sym_COMMA,
+    STATE(270), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(1165), 2,
+      sym_and,
+      sym_or,
+    ACTIONS(412), 8,
+      anon_sym_GT,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(410), 11,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [13081] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(19), 1,
+      sym_nesting_selector,
+    ACTIONS(21), 1,
+      anon_sym_STAR,
+    ACTIONS(25), 1,
+      anon_sym_COLON,
+    ACTIONS(27), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(31), 1,
+      anon_sym_LBRACK,
+    ACTIONS(428), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(969), 1,
+      anon_sym_DOT,
+    ACTIONS(971), 1,
+      anon_sym_POUND,
+    ACTIONS(975), 1,
+      sym_identifier,
+    STATE(340), 1,
+      sym_interpolation,
+    STATE(454), 1,
+      sym__concatenated_identifier,
+    ACTIONS(1167), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(465), 11,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+  [13135] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1133), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1131), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [13167] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1137), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1135), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [13199] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1141), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1139), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [13231] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1145), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1143), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [13263] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1149), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1147), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [13295] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1153), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1151), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [13327] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1157), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1155), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [13359] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1033), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1035), 13,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_each,
+      anon_sym_ATplugin,
+      sym_variable,
+  [13391] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(947), 1,
+      anon_sym_LPAREN,
+    ACTIONS(951), 1,
+      sym__concat,
+    STATE(270), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    STATE(282), 1,
+      sym_arguments,
+    ACTIONS(404), 9,
+      anon_sym_GT,
+      anon_sym_LPAREN2,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(398), 10,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [13430] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(517), 10,
+      anon_sym_GT,
+      sym_and,
+      sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(515), 13,
+      sym__concat,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [13461] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(256), 1,
+      anon_sym_selector,
+    ACTIONS(797), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(803), 1,
+      sym_identifier,
+    ACTIONS(807), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1169), 1,
+      anon_sym_SEMI,
+    ACTIONS(1171), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1173), 1,
+      anon_sym_COLON,
+    ACTIONS(1175), 1,
+      anon_sym_PLUS,
+    ACTIONS(1179), 1,
+      anon_sym_PLUS_,
+    STATE(207), 1,
+      sym_block,
+    STATE(675), 1,
+      sym_merge_identifier,
+    ACTIONS(254), 2,
+      sym_not,
+      sym_only,
+    ACTIONS(1177), 2,
+      sym_grit_metavariable,
+      sym_variable,
+    STATE(470), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [13515] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(256), 1,
+      anon_sym_selector,
+    ACTIONS(797), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(803), 1,
+      sym_identifier,
+    ACTIONS(807), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1175), 1,
+      anon_sym_PLUS,
+    ACTIONS(1179), 1,
+      anon_sym_PLUS_,
+    ACTIONS(1181), 1,
+      anon_sym_SEMI,
+    ACTIONS(1183), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1185), 1,
+      anon_sym_COLON,
+    STATE(183), 1,
+      sym_block,
+    STATE(647), 1,
+      sym_merge_identifier,
+    ACTIONS(254), 2,
+      sym_not,
+      sym_only,
+    ACTIONS(1187), 2,
+      sym_grit_metavariable,
+      sym_variable,
+    STATE(473), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [13569] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1163), 2,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+    ACTIONS(1165), 2,
+      sym_and,
+      sym_or,
+    ACTIONS(424), 8,
+      anon_sym_GT,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(420), 10,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [13603] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1163), 1,
+      anon_sym_COMMA,
+    ACTIONS(1165), 2,
+      sym_and,
+      sym_or,
+    ACTIONS(424), 8,
+      anon_sym_GT,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(420), 11,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [13637] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(256), 1,
+      anon_sym_selector,
+    ACTIONS(797), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(803), 1,
+      sym_identifier,
+    ACTIONS(807), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1169), 1,
+      anon_sym_SEMI,
+    ACTIONS(1171), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1175), 1,
+      anon_sym_PLUS,
+    ACTIONS(1179), 1,
+      anon_sym_PLUS_,
+    ACTIONS(1189), 1,
+      anon_sym_COLON,
+    STATE(207), 1,
+      sym_block,
+    STATE(599), 1,
+      sym_merge_identifier,
+    ACTIONS(254), 2,
+      sym_not,
+      sym_only,
+    ACTIONS(1177), 2,
+      sym_grit_metavariable,
+      sym_variable,
+    STATE(470), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [13691] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1191), 1,
+      sym__concat,
+    STATE(266), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(432), 8,
+      anon_sym_GT,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(430), 11,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [13724] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(947), 1,
+      anon_sym_LPAREN,
+    STATE(282), 1,
+      sym_arguments,
+    ACTIONS(424), 9,
+      anon_sym_GT,
+      anon_sym_LPAREN2,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(420), 10,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [13757] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(120), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(509), 8,
+      anon_sym_GT,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(507), 11,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [13788] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(120), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(513), 8,
+      anon_sym_GT,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(511), 11,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [13819] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(951), 1,
+      sym__concat,
+    STATE(266), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(416), 8,
+      anon_sym_GT,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(414), 11,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [13852] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(951), 1,
+      sym__concat,
+    STATE(270), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(412), 8,
+      anon_sym_GT,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(410), 11,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [13885] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1194), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1196), 1,
+      sym__concat,
+    STATE(292), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    STATE(315), 1,
+      sym_arguments,
+    ACTIONS(404), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(398), 13,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [13921] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1198), 1,
+      sym_unit,
+    ACTIONS(589), 8,
+      anon_sym_GT,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(587), 11,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [13951] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(432), 8,
+      anon_sym_GT,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(430), 12,
+      sym__concat,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [13979] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(517), 8,
+      anon_sym_GT,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(515), 12,
+      sym__concat,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [14007] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1200), 1,
+      sym_unit,
+    ACTIONS(583), 8,
+      anon_sym_GT,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(581), 11,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [14037] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(641), 8,
+      anon_sym_GT,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(639), 11,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [14064] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(629), 8,
+      anon_sym_GT,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(627), 11,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [14091] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(633), 8,
+      anon_sym_GT,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(631), 11,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [14118] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(637), 8,
+      anon_sym_GT,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(635), 11,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [14145] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(649), 8,
+      anon_sym_GT,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(647), 11,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [14172] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(645), 8,
+      anon_sym_GT,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(643), 11,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [14199] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(653), 8,
+      anon_sym_GT,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(651), 11,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [14226] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(657), 8,
+      anon_sym_GT,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(655), 11,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [14253] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(661), 8,
+      anon_sym_GT,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(659), 11,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [14280] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(665), 8,
+      anon_sym_GT,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(663), 11,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [14307] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(682), 8,
+      anon_sym_GT,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(680), 11,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [14334] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(517), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(515), 16,
+      sym__concat,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_and,
+      sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [14361] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1194), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1196), 1,
+      sym__concat,
+    ACTIONS(1202), 1,
+      anon_sym_COLON,
+    STATE(292), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    STATE(315), 1,
+      sym_arguments,
+    ACTIONS(404), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(945), 3,
+      anon_sym_RPAREN,
+      sym_and,
+      sym_or,
+    ACTIONS(398), 8,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [14400] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(621), 8,
+      anon_sym_GT,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(619), 11,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [14427] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(678), 8,
+      anon_sym_GT,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(676), 11,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      sym_variable,
+  [14454] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1196), 1,
+      sym__concat,
+    STATE(293), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(416), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(414), 13,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [14484] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1204), 1,
+      sym__concat,
+    STATE(293), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(432), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(430), 13,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [14514] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(509), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(507), 13,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [14542] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1211), 1,
+      anon_sym_COLON,
+    ACTIONS(1213), 1,
+      sym__concat,
+    STATE(350), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(1209), 3,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      sym_important,
+    ACTIONS(1207), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [14574] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1194), 1,
+      anon_sym_LPAREN,
+    STATE(315), 1,
+      sym_arguments,
+    ACTIONS(424), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(420), 13,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [14604] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(513), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(511), 13,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [14632] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1196), 1,
+      sym__concat,
+    STATE(292), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(412), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(410), 13,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [14662] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1175), 1,
+      anon_sym_PLUS,
+    ACTIONS(1179), 1,
+      anon_sym_PLUS_,
+    ACTIONS(1215), 1,
+      anon_sym_COLON,
+    ACTIONS(1217), 1,
+      sym__concat,
+    STATE(319), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    STATE(599), 1,
+      sym_merge_identifier,
+    ACTIONS(396), 11,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_COLONextend,
+  [14697] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1219), 1,
+      sym_unit,
+    ACTIONS(589), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(587), 13,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [14724] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(432), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(430), 14,
+      sym__concat,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [14749] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1221), 1,
+      sym_unit,
+    ACTIONS(583), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(581), 13,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [14776] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1175), 1,
+      anon_sym_PLUS,
+    ACTIONS(1179), 1,
+      anon_sym_PLUS_,
+    ACTIONS(1217), 1,
+      sym__concat,
+    ACTIONS(1223), 1,
+      anon_sym_COLON,
+    STATE(319), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    STATE(675), 1,
+      sym_merge_identifier,
+    ACTIONS(396), 11,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_COLONextend,
+  [14811] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(422), 1,
+      anon_sym_COLON,
+    ACTIONS(428), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1225), 1,
+      sym_identifier,
+    STATE(340), 1,
+      sym_interpolation,
+    STATE(409), 1,
+      sym__concatenated_identifier,
+    ACTIONS(418), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [14844] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(422), 1,
+      anon_sym_COLON,
+    ACTIONS(1119), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1227), 1,
+      sym_identifier,
+    STATE(370), 1,
+      sym_interpolation,
+    STATE(423), 1,
+      sym__concatenated_identifier,
+    ACTIONS(418), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [14877] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1175), 1,
+      anon_sym_PLUS,
+    ACTIONS(1179), 1,
+      anon_sym_PLUS_,
+    ACTIONS(1229), 1,
+      anon_sym_COLON,
+    ACTIONS(1231), 1,
+      sym__concat,
+    STATE(334), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    STATE(647), 1,
+      sym_merge_identifier,
+    ACTIONS(396), 10,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+  [14911] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(621), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(619), 13,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [14935] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(678), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(676), 13,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [14959] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(665), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(663), 13,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [14983] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(629), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(627), 13,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [15007] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(633), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(631), 13,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [15031] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(637), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(635), 13,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [15055] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(641), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(639), 13,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [15079] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(682), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(680), 13,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [15103] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(645), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(643), 13,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [15127] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1196), 1,
+      sym__concat,
+    STATE(292), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(1163), 2,
+      sym_and,
+      sym_or,
+    ACTIONS(412), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(410), 9,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [15157] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1217), 1,
+      sym__concat,
+    STATE(319), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(412), 2,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+    ACTIONS(410), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_COLONextend,
+      anon_sym_PLUS_,
+  [15185] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(653), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(651), 13,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [15209] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1217), 1,
+      sym__concat,
+    STATE(320), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(416), 2,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+    ACTIONS(414), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_COLONextend,
+      anon_sym_PLUS_,
+  [15237] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1233), 1,
+      sym__concat,
+    STATE(320), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(432), 2,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+    ACTIONS(430), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_COLONextend,
+      anon_sym_PLUS_,
+  [15265] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(672), 1,
+      anon_sym_COLON,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1236), 3,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [15301] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(657), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(655), 13,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [15325] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(661), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(659), 13,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [15349] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(649), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(647), 13,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [15373] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1244), 1,
+      anon_sym_COMMA,
+    ACTIONS(1246), 1,
+      anon_sym_RPAREN,
+    STATE(541), 1,
+      aux_sym_list_value_repeat1,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [15410] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(256), 1,
+      anon_sym_selector,
+    ACTIONS(797), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(803), 1,
+      sym_identifier,
+    ACTIONS(807), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(254), 2,
+      sym_not,
+      sym_only,
+    ACTIONS(1248), 2,
+      sym_grit_metavariable,
+      sym_variable,
+    STATE(534), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [15443] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(432), 1,
+      anon_sym_COLON,
+    ACTIONS(1250), 1,
+      sym__concat,
+    STATE(327), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(430), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [15470] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(256), 1,
+      anon_sym_selector,
+    ACTIONS(797), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(803), 1,
+      sym_identifier,
+    ACTIONS(807), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(254), 2,
+      sym_not,
+      sym_only,
+    ACTIONS(1253), 2,
+      sym_grit_metavariable,
+      sym_variable,
+    STATE(480), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [15503] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1211), 1,
+      anon_sym_COLON,
+    ACTIONS(1255), 1,
+      sym__concat,
+    STATE(352), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(1207), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [15530] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(256), 1,
+      anon_sym_selector,
+    ACTIONS(797), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(803), 1,
+      sym_identifier,
+    ACTIONS(807), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(254), 2,
+      sym_not,
+      sym_only,
+    ACTIONS(1257), 2,
+      sym_grit_metavariable,
+      sym_variable,
+    STATE(502), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [15563] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(256), 1,
+      anon_sym_selector,
+    ACTIONS(797), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(803), 1,
+      sym_identifier,
+    ACTIONS(807), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(254), 2,
+      sym_not,
+      sym_only,
+    ACTIONS(1259), 2,
+      sym_grit_metavariable,
+      sym_variable,
+    STATE(483), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [15596] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1209), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1211), 1,
+      anon_sym_COLON,
+    ACTIONS(1255), 1,
+      sym__concat,
+    STATE(352), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(1207), 11,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+  [15625] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1263), 1,
+      anon_sym_COLON,
+    ACTIONS(1265), 1,
+      anon_sym_LPAREN,
+    STATE(426), 1,
+      sym_pseudo_element_arguments,
+    ACTIONS(1261), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [15652] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1231), 1,
+      sym__concat,
+    STATE(355), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(416), 2,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+    ACTIONS(414), 11,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS_,
+  [15679] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1213), 1,
+      sym__concat,
+    ACTIONS(1269), 1,
+      anon_sym_COLON,
+    STATE(350), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(1267), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [15706] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(256), 1,
+      anon_sym_selector,
+    ACTIONS(797), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(807), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1271), 1,
+      sym_identifier,
+    ACTIONS(254), 2,
+      sym_not,
+      sym_only,
+    ACTIONS(1273), 2,
+      sym_grit_metavariable,
+      sym_variable,
+    STATE(562), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [15739] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1231), 1,
+      sym__concat,
+    STATE(334), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(412), 2,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+    ACTIONS(410), 11,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS_,
+  [15766] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1263), 1,
+      anon_sym_COLON,
+    ACTIONS(1275), 1,
+      anon_sym_LPAREN,
+    STATE(417), 1,
+      sym_pseudo_element_arguments,
+    ACTIONS(1261), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [15793] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1275), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1279), 1,
+      anon_sym_COLON,
+    STATE(393), 1,
+      sym_pseudo_element_arguments,
+    ACTIONS(1277), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [15820] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(412), 1,
+      anon_sym_COLON,
+    ACTIONS(1255), 1,
+      sym__concat,
+    STATE(352), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(410), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [15847] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(256), 1,
+      anon_sym_selector,
+    ACTIONS(797), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(803), 1,
+      sym_identifier,
+    ACTIONS(807), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(254), 2,
+      sym_not,
+      sym_only,
+    ACTIONS(1281), 2,
+      sym_grit_metavariable,
+      sym_variable,
+    STATE(479), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [15880] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(432), 2,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+    ACTIONS(430), 13,
+      sym__descendant_operator,
+      sym__concat,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_COLONextend,
+      anon_sym_PLUS_,
+  [15903] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1285), 6,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(1283), 9,
+      sym_nesting_selector,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [15926] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1175), 1,
+      anon_sym_PLUS,
+    ACTIONS(1179), 1,
+      anon_sym_PLUS_,
+    ACTIONS(1223), 1,
+      anon_sym_COLON,
+    STATE(675), 1,
+      sym_merge_identifier,
+    ACTIONS(396), 11,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_COLONextend,
+  [15955] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1289), 1,
+      anon_sym_COLON,
+    ACTIONS(1291), 1,
+      anon_sym_LPAREN,
+    STATE(416), 1,
+      sym_pseudo_class_arguments,
+    ACTIONS(1287), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [15982] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(256), 1,
+      anon_sym_selector,
+    ACTIONS(797), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(803), 1,
+      sym_identifier,
+    ACTIONS(807), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(254), 2,
+      sym_not,
+      sym_only,
+    ACTIONS(1293), 2,
+      sym_grit_metavariable,
+      sym_variable,
+    STATE(527), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [16015] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1295), 3,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [16048] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(517), 2,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+    ACTIONS(515), 13,
+      sym__descendant_operator,
+      sym__concat,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_COLONextend,
+      anon_sym_PLUS_,
+  [16071] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1291), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1299), 1,
+      anon_sym_COLON,
+    STATE(392), 1,
+      sym_pseudo_class_arguments,
+    ACTIONS(1297), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [16098] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(416), 1,
+      anon_sym_COLON,
+    ACTIONS(1213), 1,
+      sym__concat,
+    STATE(351), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(414), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [16125] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(432), 1,
+      anon_sym_COLON,
+    ACTIONS(1301), 1,
+      sym__concat,
+    STATE(351), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(430), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [16152] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(416), 1,
+      anon_sym_COLON,
+    ACTIONS(1255), 1,
+      sym__concat,
+    STATE(327), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(414), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [16179] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1211), 1,
+      anon_sym_COLON,
+    ACTIONS(1213), 1,
+      sym__concat,
+    STATE(350), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(1207), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [16206] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1244), 1,
+      anon_sym_COMMA,
+    ACTIONS(1304), 1,
+      anon_sym_RPAREN,
+    STATE(543), 1,
+      aux_sym_list_value_repeat1,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [16243] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1306), 1,
+      sym__concat,
+    STATE(355), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(432), 2,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+    ACTIONS(430), 11,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS_,
+  [16270] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(695), 1,
+      anon_sym_DOT_DOT_DOT,
+    ACTIONS(424), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(420), 11,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [16295] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1311), 6,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_identifier,
+      sym_plain_value,
+      anon_sym_DOLLAR,
+      sym_variable,
+    ACTIONS(1309), 9,
+      sym_nesting_selector,
+      anon_sym_POUND,
+      anon_sym_TILDE,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_AT_LBRACE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_AT_AT,
+  [16318] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1244), 1,
+      anon_sym_COMMA,
+    ACTIONS(1313), 1,
+      anon_sym_RPAREN,
+    STATE(568), 1,
+      aux_sym_list_value_repeat1,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [16355] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1175), 1,
+      anon_sym_PLUS,
+    ACTIONS(1179), 1,
+      anon_sym_PLUS_,
+    ACTIONS(1215), 1,
+      anon_sym_COLON,
+    STATE(599), 1,
+      sym_merge_identifier,
+    ACTIONS(396), 11,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_COLONextend,
+  [16384] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1244), 1,
+      anon_sym_COMMA,
+    ACTIONS(1315), 1,
+      anon_sym_RPAREN,
+    STATE(557), 1,
+      aux_sym_list_value_repeat1,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [16421] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(256), 1,
+      anon_sym_selector,
+    ACTIONS(797), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(803), 1,
+      sym_identifier,
+    ACTIONS(807), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(254), 2,
+      sym_not,
+      sym_only,
+    ACTIONS(1317), 2,
+      sym_grit_metavariable,
+      sym_variable,
+    STATE(494), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [16454] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(256), 1,
+      anon_sym_selector,
+    ACTIONS(797), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(803), 1,
+      sym_identifier,
+    ACTIONS(807), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(254), 2,
+      sym_not,
+      sym_only,
+    ACTIONS(1319), 2,
+      sym_grit_metavariable,
+      sym_variable,
+    STATE(484), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [16487] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1289), 1,
+      anon_sym_COLON,
+    ACTIONS(1321), 1,
+      anon_sym_LPAREN,
+    STATE(424), 1,
+      sym_pseudo_class_arguments,
+    ACTIONS(1287), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [16514] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(256), 1,
+      anon_sym_selector,
+    ACTIONS(797), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(803), 1,
+      sym_identifier,
+    ACTIONS(807), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(254), 2,
+      sym_not,
+      sym_only,
+    ACTIONS(1323), 2,
+      sym_grit_metavariable,
+      sym_variable,
+    STATE(505), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [16547] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1265), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1279), 1,
+      anon_sym_COLON,
+    STATE(449), 1,
+      sym_pseudo_element_arguments,
+    ACTIONS(1277), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [16574] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(400), 1,
+      anon_sym_COLON,
+    ACTIONS(1213), 1,
+      sym__concat,
+    STATE(350), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(396), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [16601] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(400), 1,
+      anon_sym_COLON,
+    ACTIONS(1255), 1,
+      sym__concat,
+    STATE(352), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(396), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [16628] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1255), 1,
+      sym__concat,
+    ACTIONS(1269), 1,
+      anon_sym_COLON,
+    STATE(352), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(1267), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [16655] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1299), 1,
+      anon_sym_COLON,
+    ACTIONS(1321), 1,
+      anon_sym_LPAREN,
+    STATE(448), 1,
+      sym_pseudo_class_arguments,
+    ACTIONS(1297), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [16682] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(412), 1,
+      anon_sym_COLON,
+    ACTIONS(1213), 1,
+      sym__concat,
+    STATE(350), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(410), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [16709] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1325), 1,
+      anon_sym_COMMA,
+    ACTIONS(1327), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1331), 1,
+      anon_sym_COLON,
+    ACTIONS(1333), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(1335), 1,
+      anon_sym_POUND,
+    ACTIONS(1337), 1,
+      anon_sym_LBRACK,
+    ACTIONS(1339), 1,
+      anon_sym_GT,
+    ACTIONS(1341), 1,
+      anon_sym_TILDE,
+    ACTIONS(1343), 1,
+      anon_sym_PLUS,
+    ACTIONS(1345), 1,
+      anon_sym_COLONextend,
+    ACTIONS(1347), 1,
+      sym__descendant_operator,
+    STATE(554), 1,
+      aux_sym_selectors_repeat1,
+    ACTIONS(1329), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+  [16753] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(1349), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [16785] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1175), 1,
+      anon_sym_PLUS,
+    ACTIONS(1179), 1,
+      anon_sym_PLUS_,
+    ACTIONS(1229), 1,
+      anon_sym_COLON,
+    STATE(647), 1,
+      sym_merge_identifier,
+    ACTIONS(396), 10,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+  [16813] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(432), 1,
+      anon_sym_COLON,
+    ACTIONS(430), 13,
+      sym__descendant_operator,
+      sym__concat,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [16835] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(517), 1,
+      anon_sym_COLON,
+    ACTIONS(515), 13,
+      sym__descendant_operator,
+      sym__concat,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [16857] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(432), 1,
+      anon_sym_COLON,
+    ACTIONS(430), 13,
+      sym__descendant_operator,
+      sym__concat,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [16879] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(517), 1,
+      anon_sym_COLON,
+    ACTIONS(515), 13,
+      sym__descendant_operator,
+      sym__concat,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [16901] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(432), 2,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+    ACTIONS(430), 12,
+      sym__descendant_operator,
+      sym__concat,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS_,
+  [16923] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(517), 2,
+      anon_sym_COLON,
+      anon_sym_PLUS,
+    ACTIONS(515), 12,
+      sym__descendant_operator,
+      sym__concat,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS_,
+  [16945] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(424), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(1163), 3,
+      anon_sym_RPAREN,
+      sym_and,
+      sym_or,
+    ACTIONS(420), 8,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [16969] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1163), 2,
+      sym_and,
+      sym_or,
+    ACTIONS(424), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(420), 9,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [16993] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1351), 1,
+      anon_sym_RBRACK,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [17024] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1355), 1,
+      anon_sym_COLON,
+    ACTIONS(1329), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+    ACTIONS(1353), 10,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [17047] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1359), 1,
+      anon_sym_COLON,
+    ACTIONS(1329), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+    ACTIONS(1357), 10,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [17070] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1363), 1,
+      anon_sym_COLON,
+    ACTIONS(1329), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+    ACTIONS(1361), 10,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [17093] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1365), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [17124] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1369), 1,
+      anon_sym_COLON,
+    ACTIONS(1367), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [17145] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1373), 1,
+      anon_sym_COLON,
+    ACTIONS(1371), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [17166] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1377), 1,
+      anon_sym_COLON,
+    ACTIONS(1375), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [17187] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1381), 1,
+      anon_sym_COLON,
+    ACTIONS(1379), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [17208] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1385), 1,
+      anon_sym_COLON,
+    ACTIONS(1383), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [17229] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1389), 1,
+      anon_sym_COLON,
+    ACTIONS(1387), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [17250] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1393), 1,
+      anon_sym_COLON,
+    ACTIONS(1391), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [17271] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1397), 1,
+      anon_sym_COLON,
+    ACTIONS(1395), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [17292] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1401), 1,
+      anon_sym_COLON,
+    ACTIONS(1399), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [17313] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1369), 1,
+      anon_sym_COLON,
+    ACTIONS(1367), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [17334] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1381), 1,
+      anon_sym_COLON,
+    ACTIONS(1379), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [17355] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1401), 1,
+      anon_sym_COLON,
+    ACTIONS(1399), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [17376] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1405), 1,
+      anon_sym_COLON,
+    ACTIONS(1403), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [17397] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1405), 1,
+      anon_sym_COLON,
+    ACTIONS(1403), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [17418] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1409), 1,
+      anon_sym_COLON,
+    ACTIONS(1407), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [17439] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1409), 1,
+      anon_sym_COLON,
+    ACTIONS(1407), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [17460] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1413), 1,
+      anon_sym_COLON,
+    ACTIONS(1411), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [17481] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1373), 1,
+      anon_sym_COLON,
+    ACTIONS(1371), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [17502] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1325), 1,
+      anon_sym_COMMA,
+    ACTIONS(1327), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1417), 1,
+      anon_sym_COLON,
+    ACTIONS(1419), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(1421), 1,
+      anon_sym_POUND,
+    ACTIONS(1423), 1,
+      anon_sym_LBRACK,
+    ACTIONS(1425), 1,
+      anon_sym_GT,
+    ACTIONS(1427), 1,
+      anon_sym_TILDE,
+    ACTIONS(1429), 1,
+      anon_sym_PLUS,
+    ACTIONS(1431), 1,
+      sym__descendant_operator,
+    STATE(554), 1,
+      aux_sym_selectors_repeat1,
+    ACTIONS(1415), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+  [17543] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1433), 1,
+      anon_sym_RBRACK,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [17574] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1435), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [17605] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(593), 1,
+      anon_sym_COMMA,
+    ACTIONS(609), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1417), 1,
+      anon_sym_COLON,
+    ACTIONS(1419), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(1421), 1,
+      anon_sym_POUND,
+    ACTIONS(1423), 1,
+      anon_sym_LBRACK,
+    ACTIONS(1425), 1,
+      anon_sym_GT,
+    ACTIONS(1427), 1,
+      anon_sym_TILDE,
+    ACTIONS(1429), 1,
+      anon_sym_PLUS,
+    ACTIONS(1431), 1,
+      sym__descendant_operator,
+    STATE(549), 1,
+      aux_sym_pseudo_class_arguments_repeat2,
+    ACTIONS(1415), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+  [17646] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1211), 1,
+      anon_sym_COLON,
+    ACTIONS(1207), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [17667] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1405), 1,
+      anon_sym_COLON,
+    ACTIONS(1403), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [17688] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1405), 1,
+      anon_sym_COLON,
+    ACTIONS(1403), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [17709] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1409), 1,
+      anon_sym_COLON,
+    ACTIONS(1407), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [17730] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1409), 1,
+      anon_sym_COLON,
+    ACTIONS(1407), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [17751] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1377), 1,
+      anon_sym_COLON,
+    ACTIONS(1375), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [17772] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(593), 1,
+      anon_sym_COMMA,
+    ACTIONS(613), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1417), 1,
+      anon_sym_COLON,
+    ACTIONS(1419), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(1421), 1,
+      anon_sym_POUND,
+    ACTIONS(1423), 1,
+      anon_sym_LBRACK,
+    ACTIONS(1425), 1,
+      anon_sym_GT,
+    ACTIONS(1427), 1,
+      anon_sym_TILDE,
+    ACTIONS(1429), 1,
+      anon_sym_PLUS,
+    ACTIONS(1431), 1,
+      sym__descendant_operator,
+    STATE(565), 1,
+      aux_sym_pseudo_class_arguments_repeat2,
+    ACTIONS(1415), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+  [17813] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1439), 1,
+      anon_sym_COLON,
+    ACTIONS(1437), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [17834] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1443), 1,
+      anon_sym_COLON,
+    ACTIONS(1441), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [17855] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1413), 1,
+      anon_sym_COLON,
+    ACTIONS(1411), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [17876] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1363), 1,
+      anon_sym_COLON,
+    ACTIONS(1415), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+    ACTIONS(1361), 10,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [17899] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1445), 1,
+      anon_sym_SEMI,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [17930] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1449), 1,
+      anon_sym_COLON,
+    ACTIONS(1447), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [17951] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1453), 1,
+      anon_sym_COLON,
+    ACTIONS(1451), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [17972] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1211), 1,
+      anon_sym_COLON,
+    ACTIONS(1207), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [17993] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1439), 1,
+      anon_sym_COLON,
+    ACTIONS(1437), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [18014] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1385), 1,
+      anon_sym_COLON,
+    ACTIONS(1455), 1,
+      anon_sym_DOT,
+    ACTIONS(1458), 1,
+      anon_sym_GT,
+    ACTIONS(1383), 10,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [18039] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1443), 1,
+      anon_sym_COLON,
+    ACTIONS(1441), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [18060] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1461), 1,
+      anon_sym_SEMI,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18091] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1463), 1,
+      anon_sym_SEMI,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18122] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1465), 1,
+      anon_sym_SEMI,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18153] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1467), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18184] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1471), 1,
+      anon_sym_COLON,
+    ACTIONS(1469), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [18205] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1449), 1,
+      anon_sym_COLON,
+    ACTIONS(1447), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [18226] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1475), 1,
+      anon_sym_COLON,
+    ACTIONS(1329), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+    ACTIONS(1473), 10,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [18249] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(593), 1,
+      anon_sym_COMMA,
+    ACTIONS(595), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1417), 1,
+      anon_sym_COLON,
+    ACTIONS(1419), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(1421), 1,
+      anon_sym_POUND,
+    ACTIONS(1423), 1,
+      anon_sym_LBRACK,
+    ACTIONS(1425), 1,
+      anon_sym_GT,
+    ACTIONS(1427), 1,
+      anon_sym_TILDE,
+    ACTIONS(1429), 1,
+      anon_sym_PLUS,
+    ACTIONS(1431), 1,
+      sym__descendant_operator,
+    STATE(550), 1,
+      aux_sym_pseudo_class_arguments_repeat2,
+    ACTIONS(1415), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+  [18290] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(593), 1,
+      anon_sym_COMMA,
+    ACTIONS(597), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1417), 1,
+      anon_sym_COLON,
+    ACTIONS(1419), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(1421), 1,
+      anon_sym_POUND,
+    ACTIONS(1423), 1,
+      anon_sym_LBRACK,
+    ACTIONS(1425), 1,
+      anon_sym_GT,
+    ACTIONS(1427), 1,
+      anon_sym_TILDE,
+    ACTIONS(1429), 1,
+      anon_sym_PLUS,
+    ACTIONS(1431), 1,
+      sym__descendant_operator,
+    STATE(553), 1,
+      aux_sym_pseudo_class_arguments_repeat2,
+    ACTIONS(1415), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+  [18331] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1477), 1,
+      anon_sym_RBRACK,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18362] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1479), 1,
+      anon_sym_RBRACK,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18393] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1481), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18424] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1483), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18455] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1485), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18486] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1487), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18517] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1489), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18548] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1491), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18579] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1493), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18610] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1495), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18641] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1497), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18672] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1385), 1,
+      anon_sym_COLON,
+    ACTIONS(1383), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [18693] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1389), 1,
+      anon_sym_COLON,
+    ACTIONS(1387), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [18714] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1393), 1,
+      anon_sym_COLON,
+    ACTIONS(1391), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [18735] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1397), 1,
+      anon_sym_COLON,
+    ACTIONS(1395), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [18756] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1499), 1,
+      anon_sym_COMMA,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18787] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(400), 1,
+      anon_sym_COLON,
+    ACTIONS(396), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [18808] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1475), 1,
+      anon_sym_COLON,
+    ACTIONS(1415), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+    ACTIONS(1473), 10,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [18831] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(400), 1,
+      anon_sym_COLON,
+    ACTIONS(396), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [18852] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1269), 1,
+      anon_sym_COLON,
+    ACTIONS(1267), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [18873] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1269), 1,
+      anon_sym_COLON,
+    ACTIONS(1267), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [18894] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1453), 1,
+      anon_sym_COLON,
+    ACTIONS(1451), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [18915] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1355), 1,
+      anon_sym_COLON,
+    ACTIONS(1415), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+    ACTIONS(1353), 10,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [18938] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1359), 1,
+      anon_sym_COLON,
+    ACTIONS(1415), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+    ACTIONS(1357), 10,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [18961] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 1,
+      anon_sym_STAR,
+    ACTIONS(795), 1,
+      anon_sym_PLUS,
+    ACTIONS(1238), 1,
+      anon_sym_GT,
+    ACTIONS(1501), 1,
+      anon_sym_COMMA,
+    ACTIONS(1242), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(138), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1240), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18992] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1471), 1,
+      anon_sym_COLON,
+    ACTIONS(1469), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_COLONextend,
+  [19013] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1385), 1,
+      anon_sym_COLON,
+    ACTIONS(1455), 1,
+      anon_sym_DOT,
+    ACTIONS(1458), 1,
+      anon_sym_GT,
+    ACTIONS(1383), 9,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+  [19037] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1417), 1,
+      anon_sym_COLON,
+    ACTIONS(1419), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(1421), 1,
+      anon_sym_POUND,
+    ACTIONS(1423), 1,
+      anon_sym_LBRACK,
+    ACTIONS(1425), 1,
+      anon_sym_GT,
+    ACTIONS(1427), 1,
+      anon_sym_TILDE,
+    ACTIONS(1429), 1,
+      anon_sym_PLUS,
+    ACTIONS(1431), 1,
+      sym__descendant_operator,
+    ACTIONS(691), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+    ACTIONS(1415), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+  [19073] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1417), 1,
+      anon_sym_COLON,
+    ACTIONS(1419), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(1421), 1,
+      anon_sym_POUND,
+    ACTIONS(1423), 1,
+      anon_sym_LBRACK,
+    ACTIONS(1425), 1,
+      anon_sym_GT,
+    ACTIONS(1427), 1,
+      anon_sym_TILDE,
+    ACTIONS(1429), 1,
+      anon_sym_PLUS,
+    ACTIONS(1431), 1,
+      sym__descendant_operator,
+    ACTIONS(1415), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+    ACTIONS(1503), 2,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+  [19109] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1417), 1,
+      anon_sym_COLON,
+    ACTIONS(1419), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(1421), 1,
+      anon_sym_POUND,
+    ACTIONS(1423), 1,
+      anon_sym_LBRACK,
+    ACTIONS(1425), 1,
+      anon_sym_GT,
+    ACTIONS(1427), 1,
+      anon_sym_TILDE,
+    ACTIONS(1429), 1,
+      anon_sym_PLUS,
+    ACTIONS(1431), 1,
+      sym__descendant_operator,
+    ACTIONS(1505), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1415), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+  [19144] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1417), 1,
+      anon_sym_COLON,
+    ACTIONS(1419), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(1421), 1,
+      anon_sym_POUND,
+    ACTIONS(1423), 1,
+      anon_sym_LBRACK,
+    ACTIONS(1425), 1,
+      anon_sym_GT,
+    ACTIONS(1427), 1,
+      anon_sym_TILDE,
+    ACTIONS(1429), 1,
+      anon_sym_PLUS,
+    ACTIONS(1431), 1,
+      sym__descendant_operator,
+    ACTIONS(1507), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1415), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+  [19179] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1509), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1513), 1,
+      aux_sym_integer_value_token1,
+    STATE(589), 1,
+      sym_integer_value,
+    ACTIONS(1511), 2,
+      sym_from,
+      sym_to,
+    STATE(471), 2,
+      sym_keyframe_block,
+      aux_sym_keyframe_block_list_repeat1,
+  [19200] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1517), 1,
+      anon_sym_RBRACK,
+    ACTIONS(1515), 6,
+      sym_equal,
+      sym_contains_word_equal,
+      sym_starts_with_equal,
+      sym_dash_equal,
+      sym_contains_equal,
+      sym_ends_equal,
+  [19215] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1521), 1,
+      anon_sym_RBRACK,
+    ACTIONS(1519), 6,
+      sym_equal,
+      sym_contains_word_equal,
+      sym_starts_with_equal,
+      sym_dash_equal,
+      sym_contains_equal,
+      sym_ends_equal,
+  [19230] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1171), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1523), 1,
+      anon_sym_COMMA,
+    ACTIONS(1525), 1,
+      anon_sym_SEMI,
+    STATE(221), 1,
+      sym_block,
+    STATE(493), 1,
+      aux_sym_at_rule_repeat1,
+    ACTIONS(1527), 2,
+      sym_and,
+      sym_or,
+  [19253] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1513), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1529), 1,
+      anon_sym_RBRACE,
+    STATE(589), 1,
+      sym_integer_value,
+    ACTIONS(1511), 2,
+      sym_from,
+      sym_to,
+    STATE(477), 2,
+      sym_keyframe_block,
+      aux_sym_keyframe_block_list_repeat1,
+  [19274] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1513), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1531), 1,
+      anon_sym_RBRACE,
+    STATE(589), 1,
+      sym_integer_value,
+    ACTIONS(1511), 2,
+      sym_from,
+      sym_to,
+    STATE(476), 2,
+      sym_keyframe_block,
+      aux_sym_keyframe_block_list_repeat1,
+  [19295] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1183), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1523), 1,
+      anon_sym_COMMA,
+    ACTIONS(1533), 1,
+      anon_sym_SEMI,
+    STATE(257), 1,
+      sym_block,
+    STATE(495), 1,
+      aux_sym_at_rule_repeat1,
+    ACTIONS(1527), 2,
+      sym_and,
+      sym_or,
+  [19318] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1537), 1,
+      anon_sym_RBRACK,
+    ACTIONS(1535), 6,
+      sym_equal,
+      sym_contains_word_equal,
+      sym_starts_with_equal,
+      sym_dash_equal,
+      sym_contains_equal,
+      sym_ends_equal,
+  [19333] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1541), 1,
+      anon_sym_RBRACK,
+    ACTIONS(1539), 6,
+      sym_equal,
+      sym_contains_word_equal,
+      sym_starts_with_equal,
+      sym_dash_equal,
+      sym_contains_equal,
+      sym_ends_equal,
+  [19348] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1513), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1543), 1,
+      anon_sym_RBRACE,
+    STATE(589), 1,
+      sym_integer_value,
+    ACTIONS(1511), 2,
+      sym_from,
+      sym_to,
+    STATE(477), 2,
+      sym_keyframe_block,
+      aux_sym_keyframe_block_list_repeat1,
+  [19369] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1545), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1550), 1,
+      aux_sym_integer_value_token1,
+    STATE(589), 1,
+      sym_integer_value,
+    ACTIONS(1547), 2,
+      sym_from,
+      sym_to,
+    STATE(477), 2,
+      sym_keyframe_block,
+      aux_sym_keyframe_block_list_repeat1,
+  [19390] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(524), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1553), 1,
+      sym_string_value,
+    STATE(109), 1,
+      sym_arguments,
+    STATE(667), 1,
+      sym_call_expression,
+    ACTIONS(1555), 2,
+      sym_identifier,
+      sym_plain_value,
+  [19410] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1183), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1557), 1,
+      anon_sym_COMMA,
+    STATE(251), 1,
+      sym_block,
+    STATE(501), 1,
+      aux_sym_media_statement_repeat1,
+    ACTIONS(1527), 2,
+      sym_and,
+      sym_or,
+  [19430] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1559), 6,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RPAREN,
+      sym_and,
+      sym_or,
+  [19442] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1561), 6,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RPAREN,
+      sym_and,
+      sym_or,
+  [19454] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1563), 6,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RPAREN,
+      sym_and,
+      sym_or,
+  [19466] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1565), 6,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RPAREN,
+      sym_and,
+      sym_or,
+  [19478] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1171), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1557), 1,
+      anon_sym_COMMA,
+    STATE(214), 1,
+      sym_block,
+    STATE(512), 1,
+      aux_sym_media_statement_repeat1,
+    ACTIONS(1527), 2,
+      sym_and,
+      sym_or,
+  [19498] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1567), 6,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RPAREN,
+      sym_and,
+      sym_or,
+  [19510] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(515), 6,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RPAREN,
+      sym_and,
+      sym_or,
+  [19522] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(945), 6,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RPAREN,
+      sym_and,
+      sym_or,
+  [19534] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(524), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1569), 1,
+      sym_string_value,
+    STATE(109), 1,
+      sym_arguments,
+    STATE(637), 1,
+      sym_call_expression,
+    ACTIONS(1555), 2,
+      sym_identifier,
+      sym_plain_value,
+  [19554] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1571), 1,
+      anon_sym_COMMA,
+    ACTIONS(1573), 1,
+      anon_sym_SEMI,
+    STATE(563), 1,
+      aux_sym_import_statement_repeat1,
+    ACTIONS(1527), 2,
+      sym_and,
+      sym_or,
+  [19571] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1571), 1,
+      anon_sym_COMMA,
+    ACTIONS(1575), 1,
+      anon_sym_SEMI,
+    STATE(545), 1,
+      aux_sym_import_statement_repeat1,
+    ACTIONS(1527), 2,
+      sym_and,
+      sym_or,
+  [19588] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1119), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1577), 1,
+      sym_identifier,
+    ACTIONS(1579), 1,
+      sym_grit_metavariable,
+    STATE(370), 1,
+      sym_interpolation,
+    STATE(423), 1,
+      sym__concatenated_identifier,
+  [19607] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(428), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1579), 1,
+      sym_grit_metavariable,
+    ACTIONS(1581), 1,
+      sym_identifier,
+    STATE(340), 1,
+      sym_interpolation,
+    STATE(409), 1,
+      sym__concatenated_identifier,
+  [19626] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1171), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1523), 1,
+      anon_sym_COMMA,
+    ACTIONS(1583), 1,
+      anon_sym_SEMI,
+    STATE(234), 1,
+      sym_block,
+    STATE(518), 1,
+      aux_sym_at_rule_repeat1,
+  [19645] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1527), 2,
+      sym_and,
+      sym_or,
+    ACTIONS(1585), 3,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+  [19658] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1183), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1523), 1,
+      anon_sym_COMMA,
+    ACTIONS(1587), 1,
+      anon_sym_SEMI,
+    STATE(180), 1,
+      sym_block,
+    STATE(518), 1,
+      aux_sym_at_rule_repeat1,
+  [19677] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1571), 1,
+      anon_sym_COMMA,
+    ACTIONS(1589), 1,
+      anon_sym_SEMI,
+    STATE(548), 1,
+      aux_sym_import_statement_repeat1,
+    ACTIONS(1527), 2,
+      sym_and,
+      sym_or,
+  [19694] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1591), 1,
+      anon_sym_SEMI,
+    ACTIONS(1593), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1595), 1,
+      sym_important,
+    STATE(510), 1,
+      sym_parameters,
+    STATE(581), 1,
+      sym_arguments,
+  [19713] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1571), 1,
+      anon_sym_COMMA,
+    ACTIONS(1597), 1,
+      anon_sym_SEMI,
+    STATE(559), 1,
+      aux_sym_import_statement_repeat1,
+    ACTIONS(1527), 2,
+      sym_and,
+      sym_or,
+  [19730] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1601), 1,
+      anon_sym_RPAREN,
+    STATE(507), 1,
+      aux_sym_parameters_repeat1,
+    ACTIONS(1599), 2,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+  [19744] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1183), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1603), 1,
+      anon_sym_when,
+    STATE(171), 1,
+      sym_block,
+    STATE(579), 1,
+      sym_when_condition,
+  [19760] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1183), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1557), 1,
+      anon_sym_COMMA,
+    STATE(174), 1,
+      sym_block,
+    STATE(573), 1,
+      aux_sym_media_statement_repeat1,
+  [19776] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1527), 2,
+      sym_and,
+      sym_or,
+    ACTIONS(1605), 2,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+  [19788] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(45), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1607), 1,
+      sym_identifier,
+    ACTIONS(1609), 1,
+      anon_sym_DASH,
+    STATE(378), 1,
+      sym_interpolation,
+  [19804] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(428), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1611), 1,
+      sym_identifier,
+    STATE(340), 1,
+      sym_interpolation,
+    STATE(455), 1,
+      sym__concatenated_identifier,
+  [19820] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1171), 1,
+      anon_sym_LBRACE,
+    STATE(218), 1,
+      sym_block,
+    ACTIONS(1527), 2,
+      sym_and,
+      sym_or,
+  [19834] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(761), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1613), 1,
+      sym_identifier,
+    ACTIONS(1615), 1,
+      anon_sym_DASH,
+    STATE(104), 1,
+      sym_interpolation,
+  [19850] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1617), 1,
+      anon_sym_RPAREN,
+    STATE(524), 1,
+      aux_sym_parameters_repeat1,
+    ACTIONS(1599), 2,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+  [19864] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1619), 4,
+      anon_sym_RBRACE,
+      sym_from,
+      sym_to,
+      aux_sym_integer_value_token1,
+  [19874] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1119), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1621), 1,
+      sym_identifier,
+    STATE(370), 1,
+      sym_interpolation,
+    STATE(456), 1,
+      sym__concatenated_identifier,
+  [19890] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1171), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1603), 1,
+      anon_sym_when,
+    STATE(224), 1,
+      sym_block,
+    STATE(575), 1,
+      sym_when_condition,
+  [19906] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(659), 2,
+      anon_sym_SEMI,
+      sym_important,
+    ACTIONS(1623), 2,
+      anon_sym_LBRACE,
+      anon_sym_when,
+  [19918] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1171), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1557), 1,
+      anon_sym_COMMA,
+    STATE(227), 1,
+      sym_block,
+    STATE(573), 1,
+      aux_sym_media_statement_repeat1,
+  [19934] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1119), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1227), 1,
+      sym_identifier,
+    STATE(370), 1,
+      sym_interpolation,
+    STATE(423), 1,
+      sym__concatenated_identifier,
+  [19950] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1625), 1,
+      anon_sym_RPAREN,
+    STATE(528), 1,
+      aux_sym_arguments_repeat1,
+    ACTIONS(495), 2,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+  [19964] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(384), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1627), 1,
+      sym_identifier,
+    ACTIONS(1629), 1,
+      anon_sym_DASH,
+    STATE(301), 1,
+      sym_interpolation,
+  [19980] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1175), 1,
+      anon_sym_PLUS,
+    ACTIONS(1179), 1,
+      anon_sym_PLUS_,
+    ACTIONS(1189), 1,
+      anon_sym_COLON,
+    STATE(599), 1,
+      sym_merge_identifier,
+  [19996] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1631), 1,
+      anon_sym_RPAREN,
+    STATE(528), 1,
+      aux_sym_arguments_repeat1,
+    ACTIONS(495), 2,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+  [20010] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1633), 1,
+      anon_sym_COMMA,
+    STATE(518), 1,
+      aux_sym_at_rule_repeat1,
+    ACTIONS(1636), 2,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+  [20024] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1638), 1,
+      anon_sym_RPAREN,
+    STATE(528), 1,
+      aux_sym_arguments_repeat1,
+    ACTIONS(495), 2,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+  [20038] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1640), 1,
+      anon_sym_RPAREN,
+    STATE(528), 1,
+      aux_sym_arguments_repeat1,
+    ACTIONS(495), 2,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+  [20052] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(729), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1642), 1,
+      sym_identifier,
+    ACTIONS(1644), 1,
+      anon_sym_DASH,
+    STATE(274), 1,
+      sym_interpolation,
+  [20068] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(344), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1646), 1,
+      sym_identifier,
+    ACTIONS(1648), 1,
+      anon_sym_DASH,
+    STATE(55), 1,
+      sym_interpolation,
+  [20084] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(89), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1650), 1,
+      sym_identifier,
+    ACTIONS(1652), 1,
+      anon_sym_DASH,
+    STATE(342), 1,
+      sym_interpolation,
+  [20100] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1657), 1,
+      anon_sym_RPAREN,
+    STATE(524), 1,
+      aux_sym_parameters_repeat1,
+    ACTIONS(1654), 2,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+  [20114] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(308), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1659), 1,
+      sym_identifier,
+    ACTIONS(1661), 1,
+      anon_sym_DASH,
+    STATE(42), 1,
+      sym_interpolation,
+  [20130] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1119), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1663), 1,
+      sym_identifier,
+    ACTIONS(1665), 1,
+      anon_sym_DASH,
+    STATE(376), 1,
+      sym_interpolation,
+  [20146] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1183), 1,
+      anon_sym_LBRACE,
+    STATE(255), 1,
+      sym_block,
+    ACTIONS(1527), 2,
+      sym_and,
+      sym_or,
+  [20160] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1670), 1,
+      anon_sym_RPAREN,
+    STATE(528), 1,
+      aux_sym_arguments_repeat1,
+    ACTIONS(1667), 2,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+  [20174] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1555), 1,
+      sym_plain_value,
+    ACTIONS(1672), 1,
+      sym_string_value,
+    ACTIONS(1674), 1,
+      sym_identifier,
+    STATE(632), 1,
+      sym_call_expression,
+  [20190] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(957), 4,
+      anon_sym_RBRACE,
+      sym_from,
+      sym_to,
+      aux_sym_integer_value_token1,
+  [20200] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1202), 1,
+      anon_sym_COLON,
+    ACTIONS(945), 3,
+      anon_sym_RPAREN,
+      sym_and,
+      sym_or,
+  [20212] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(428), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1225), 1,
+      sym_identifier,
+    STATE(340), 1,
+      sym_interpolation,
+    STATE(409), 1,
+      sym__concatenated_identifier,
+  [20228] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1555), 1,
+      sym_plain_value,
+    ACTIONS(1676), 1,
+      sym_string_value,
+    ACTIONS(1678), 1,
+      sym_identifier,
+    STATE(654), 1,
+      sym_call_expression,
+  [20244] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1527), 2,
+      sym_and,
+      sym_or,
+    ACTIONS(1680), 2,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+  [20256] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(961), 4,
+      anon_sym_RBRACE,
+      sym_from,
+      sym_to,
+      aux_sym_integer_value_token1,
+  [20266] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(953), 4,
+      anon_sym_RBRACE,
+      sym_from,
+      sym_to,
+      aux_sym_integer_value_token1,
+  [20276] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(965), 4,
+      anon_sym_RBRACE,
+      sym_from,
+      sym_to,
+      aux_sym_integer_value_token1,
+  [20286] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1173), 1,
+      anon_sym_COLON,
+    ACTIONS(1175), 1,
+      anon_sym_PLUS,
+    ACTIONS(1179), 1,
+      anon_sym_PLUS_,
+    STATE(675), 1,
+      sym_merge_identifier,
+  [20302] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1175), 1,
+      anon_sym_PLUS,
+    ACTIONS(1179), 1,
+      anon_sym_PLUS_,
+    ACTIONS(1185), 1,
+      anon_sym_COLON,
+    STATE(647), 1,
+      sym_merge_identifier,
+  [20318] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(428), 1,
+      anon_sym_AT_LBRACE,
+    ACTIONS(1682), 1,
+      sym_identifier,
+    ACTIONS(1684), 1,
+      anon_sym_DASH,
+    STATE(374), 1,
+      sym_interpolation,
+  [20334] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1244), 1,
+      anon_sym_COMMA,
+    ACTIONS(1686), 1,
+      anon_sym_RPAREN,
+    STATE(555), 1,
+      aux_sym_list_value_repeat1,
+  [20347] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1688), 3,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      sym_important,
+  [20356] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1244), 1,
+      anon_sym_COMMA,
+    ACTIONS(1690), 1,
+      anon_sym_RPAREN,
+    STATE(555), 1,
+      aux_sym_list_value_repeat1,
+  [20369] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1692), 3,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
+  [20378] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1571), 1,
+      anon_sym_COMMA,
+    ACTIONS(1694), 1,
+      anon_sym_SEMI,
+    STATE(546), 1,
+      aux_sym_import_statement_repeat1,
+  [20391] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1696), 1,
+      anon_sym_COMMA,
+    ACTIONS(1699), 1,
+      anon_sym_SEMI,
+    STATE(546), 1,
+      aux_sym_import_statement_repeat1,
+  [20404] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1701), 3,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
+  [20413] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1571), 1,
+      anon_sym_COMMA,
+    ACTIONS(1703), 1,
+      anon_sym_SEMI,
+    STATE(546), 1,
+      aux_sym_import_statement_repeat1,
+  [20426] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(593), 1,
+      anon_sym_COMMA,
+    ACTIONS(1705), 1,
+      anon_sym_RPAREN,
+    STATE(564), 1,
+      aux_sym_pseudo_class_arguments_repeat2,
+  [20439] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(593), 1,
+      anon_sym_COMMA,
+    ACTIONS(1707), 1,
+      anon_sym_RPAREN,
+    STATE(564), 1,
+      aux_sym_pseudo_class_arguments_repeat2,
+  [20452] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(593), 1,
+      anon_sym_COMMA,
+    ACTIONS(1709), 1,
+      anon_sym_RPAREN,
+    STATE(564), 1,
+      aux_sym_pseudo_class_arguments_repeat2,
+  [20465] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(593), 1,
+      anon_sym_COMMA,
+    ACTIONS(1711), 1,
+      anon_sym_RPAREN,
+    STATE(564), 1,
+      aux_sym_pseudo_class_arguments_repeat2,
+  [20478] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(593), 1,
+      anon_sym_COMMA,
+    ACTIONS(1713), 1,
+      anon_sym_RPAREN,
+    STATE(564), 1,
+      aux_sym_pseudo_class_arguments_repeat2,
+  [20491] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1325), 1,
+      anon_sym_COMMA,
+    ACTIONS(1715), 1,
+      anon_sym_LBRACE,
+    STATE(567), 1,
+      aux_sym_selectors_repeat1,
+  [20504] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1717), 1,
+      anon_sym_COMMA,
+    ACTIONS(1720), 1,
+      anon_sym_RPAREN,
+    STATE(555), 1,
+      aux_sym_list_value_repeat1,
+  [20517] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(593), 1,
+      anon_sym_COMMA,
+    ACTIONS(1722), 1,
+      anon_sym_RPAREN,
+    STATE(564), 1,
+      aux_sym_pseudo_class_arguments_repeat2,
+  [20530] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1244), 1,
+      anon_sym_COMMA,
+    ACTIONS(1724), 1,
+      anon_sym_RPAREN,
+    STATE(555), 1,
+      aux_sym_list_value_repeat1,
+  [20543] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1726), 3,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
+  [20552] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1571), 1,
+      anon_sym_COMMA,
+    ACTIONS(1728), 1,
+      anon_sym_SEMI,
+    STATE(546), 1,
+      aux_sym_import_statement_repeat1,
+  [20565] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1730), 3,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      sym_important,
+  [20574] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1732), 3,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      sym_important,
+  [20583] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1734), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1527), 2,
+      sym_and,
+      sym_or,
+  [20594] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1571), 1,
+      anon_sym_COMMA,
+    ACTIONS(1736), 1,
+      anon_sym_SEMI,
+    STATE(546), 1,
+      aux_sym_import_statement_repeat1,
+  [20607] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1738), 1,
+      anon_sym_COMMA,
+    ACTIONS(1741), 1,
+      anon_sym_RPAREN,
+    STATE(564), 1,
+      aux_sym_pseudo_class_arguments_repeat2,
+  [20620] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(593), 1,
+      anon_sym_COMMA,
+    ACTIONS(1743), 1,
+      anon_sym_RPAREN,
+    STATE(564), 1,
+      aux_sym_pseudo_class_arguments_repeat2,
+  [20633] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(593), 1,
+      anon_sym_COMMA,
+    ACTIONS(1745), 1,
+      anon_sym_RPAREN,
+    STATE(564), 1,
+      aux_sym_pseudo_class_arguments_repeat2,
+  [20646] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1747), 1,
+      anon_sym_COMMA,
+    ACTIONS(1750), 1,
+      anon_sym_LBRACE,
+    STATE(567), 1,
+      aux_sym_selectors_repeat1,
+  [20659] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1244), 1,
+      anon_sym_COMMA,
+    ACTIONS(1752), 1,
+      anon_sym_RPAREN,
+    STATE(555), 1,
+      aux_sym_list_value_repeat1,
+  [20672] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1754), 3,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      sym_important,
+  [20681] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1756), 3,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      sym_important,
+  [20690] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1758), 3,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      sym_important,
+  [20699] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1760), 3,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      sym_important,
+  [20708] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1762), 1,
+      anon_sym_COMMA,
+    ACTIONS(1765), 1,
+      anon_sym_LBRACE,
+    STATE(573), 1,
+      aux_sym_media_statement_repeat1,
+  [20721] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1767), 1,
+      anon_sym_SEMI,
+    ACTIONS(1769), 1,
+      anon_sym_RBRACE,
+  [20731] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1171), 1,
+      anon_sym_LBRACE,
+    STATE(235), 1,
+      sym_block,
+  [20741] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(581), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1771), 1,
+      sym_unit,
+  [20751] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1773), 1,
+      sym_identifier,
+    ACTIONS(1775), 1,
+      sym_grit_metavariable,
+  [20761] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1777), 1,
+      anon_sym_SEMI,
+    ACTIONS(1779), 1,
+      anon_sym_RBRACE,
+  [20771] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1183), 1,
+      anon_sym_LBRACE,
+    STATE(182), 1,
+      sym_block,
+  [20781] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1781), 1,
+      anon_sym_SEMI,
+    ACTIONS(1783), 1,
+      anon_sym_RBRACE,
+  [20791] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1785), 1,
+      anon_sym_SEMI,
+    ACTIONS(1787), 1,
+      sym_important,
+  [20801] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1171), 1,
+      anon_sym_LBRACE,
+    STATE(208), 1,
+      sym_block,
+  [20811] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1789), 1,
+      anon_sym_LBRACE,
+    STATE(254), 1,
+      sym_keyframe_block_list,
+  [20821] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1183), 1,
+      anon_sym_LBRACE,
+    STATE(629), 1,
+      sym_block,
+  [20831] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1791), 1,
+      sym_identifier,
+    ACTIONS(1793), 1,
+      sym_grit_metavariable,
+  [20841] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(524), 1,
+      anon_sym_LPAREN,
+    STATE(109), 1,
+      sym_arguments,
+  [20851] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1795), 2,
+      anon_sym_LBRACE,
+      anon_sym_when,
+  [20859] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1797), 1,
+      anon_sym_LBRACE,
+    STATE(217), 1,
+      sym_keyframe_block_list,
+  [20869] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1799), 1,
+      anon_sym_LBRACE,
+    STATE(508), 1,
+      sym_block,
+  [20879] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1801), 1,
+      anon_sym_LPAREN,
+    STATE(500), 1,
+      sym_parameters,
+  [20889] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1623), 2,
+      anon_sym_LBRACE,
+      anon_sym_when,
+  [20897] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1803), 2,
+      anon_sym_LBRACE,
+      anon_sym_when,
+  [20905] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1805), 1,
+      anon_sym_SEMI,
+    ACTIONS(1807), 1,
+      anon_sym_RBRACE,
+  [20915] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1809), 1,
+      anon_sym_LPAREN2,
+    STATE(657), 1,
+      sym__extend_arguments,
+  [20925] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1183), 1,
+      anon_sym_LBRACE,
+    STATE(187), 1,
+      sym_block,
+  [20935] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1811), 1,
+      sym_identifier,
+    ACTIONS(1813), 1,
+      sym_grit_metavariable,
+  [20945] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1183), 1,
+      anon_sym_LBRACE,
+    STATE(660), 1,
+      sym_block,
+  [20955] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1815), 1,
+      aux_sym_color_value_token1,
+    ACTIONS(1817), 1,
+      sym_identifier,
+  [20965] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1819), 1,
+      anon_sym_COLON,
+  [20972] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1767), 1,
+      anon_sym_SEMI,
+  [20979] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1821), 1,
+      anon_sym_SEMI,
+  [20986] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1823), 1,
+      aux_sym_color_value_token1,
+  [20993] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1825), 1,
+      sym_string_value,
+  [21000] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1827), 1,
+      sym_identifier,
+  [21007] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1829), 1,
+      sym_identifier,
+  [21014] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1831), 1,
+      anon_sym_LPAREN2,
+  [21021] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1833), 1,
+      sym_identifier,
+  [21028] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1835), 1,
+      sym_identifier,
+  [21035] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1837), 1,
+      sym_identifier,
+  [21042] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1839), 1,
+      sym_identifier,
+  [21049] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1841), 1,
+      anon_sym_RBRACE,
+  [21056] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1843), 1,
+      anon_sym_RBRACE,
+  [21063] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1845), 1,
+      anon_sym_RBRACE,
+  [21070] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1847), 1,
+      anon_sym_COLON,
+  [21077] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1849), 1,
+      aux_sym_color_value_token1,
+  [21084] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1851), 1,
+      anon_sym_RBRACE,
+  [21091] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1853), 1,
+      sym_string_value,
+  [21098] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1855), 1,
+      aux_sym_color_value_token1,
+  [21105] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1857), 1,
+      sym_string_value,
+  [21112] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1859), 1,
+      sym_identifier,
+  [21119] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1861), 1,
+      sym_identifier,
+  [21126] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1863), 1,
+      sym_identifier,
+  [21133] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1865), 1,
+      sym_identifier,
+  [21140] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1867), 1,
+      sym_identifier,
+  [21147] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1869), 1,
+      anon_sym_SEMI,
+  [21154] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1871), 1,
+      anon_sym_RBRACE,
+  [21161] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1873), 1,
+      sym_identifier,
+  [21168] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1875), 1,
+      sym_identifier,
+  [21175] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1877), 1,
+      anon_sym_RPAREN,
+  [21182] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1879), 1,
+      anon_sym_LPAREN2,
+  [21189] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1777), 1,
+      anon_sym_SEMI,
+  [21196] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1881), 1,
+      anon_sym_SEMI,
+  [21203] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1883), 1,
+      sym_string_value,
+  [21210] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1885), 1,
+      sym_identifier,
+  [21217] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1887), 1,
+      anon_sym_SEMI,
+  [21224] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1889), 1,
+      anon_sym_SEMI,
+  [21231] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1891), 1,
+      anon_sym_SEMI,
+  [21238] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1893), 1,
+      anon_sym_DOT,
+  [21245] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1895), 1,
+      anon_sym_RBRACE,
+  [21252] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1897), 1,
+      sym_identifier,
+  [21259] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1899), 1,
+      anon_sym_LPAREN2,
+  [21266] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1901), 1,
+      anon_sym_RBRACE,
+  [21273] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1903), 1,
+      anon_sym_SEMI,
+  [21280] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1905), 1,
+      sym_identifier,
+  [21287] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1907), 1,
+      sym_identifier,
+  [21294] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1909), 1,
+      sym_identifier,
+  [21301] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1911), 1,
+      anon_sym_COLON,
+  [21308] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1913), 1,
+      sym_identifier,
+  [21315] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1915), 1,
+      sym_identifier,
+  [21322] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1917), 1,
+      anon_sym_LBRACE,
+  [21329] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1919), 1,
+      sym_identifier,
+  [21336] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1921), 1,
+      sym_identifier,
+  [21343] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1923), 1,
+      anon_sym_SEMI,
+  [21350] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1925), 1,
+      anon_sym_SEMI,
+  [21357] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1927), 1,
+      sym_identifier,
+  [21364] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1929), 1,
+      anon_sym_RBRACE,
+  [21371] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1931), 1,
+      anon_sym_SEMI,
+  [21378] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1933), 1,
+      sym_identifier,
+  [21385] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1935), 1,
+      anon_sym_RBRACE,
+  [21392] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1937), 1,
+      anon_sym_RPAREN,
+  [21399] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1939), 1,
+      sym_identifier,
+  [21406] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1941), 1,
+      anon_sym_SEMI,
+  [21413] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1943), 1,
+      sym_identifier,
+  [21420] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1945), 1,
+      anon_sym_DOT,
+  [21427] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1947), 1,
+      ts_builtin_sym_end,
+  [21434] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1949), 1,
+      sym_identifier,
+  [21441] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1951), 1,
+      anon_sym_SEMI,
+  [21448] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1953), 1,
+      sym_identifier,
+  [21455] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1955), 1,
+      anon_sym_SEMI,
+  [21462] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1957), 1,
+      sym_identifier,
+  [21469] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1959), 1,
+      aux_sym_color_value_token1,
+  [21476] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1961), 1,
+      anon_sym_RBRACE,
+  [21483] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1963), 1,
+      sym_identifier,
+  [21490] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1805), 1,
+      anon_sym_SEMI,
+  [21497] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1965), 1,
+      anon_sym_COLON,
+  [21504] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1781), 1,
+      anon_sym_SEMI,
+  [21511] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1967), 1,
+      sym_identifier,
+  [21518] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1969), 1,
+      sym_identifier,
+  [21525] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1971), 1,
+      anon_sym_LPAREN2,
+  [21532] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1973), 1,
+      anon_sym_SEMI,
+};
+
+static const uint32_t ts_small_parse_table_map[] = {
+  [SMALL_STATE(2)] = 0,
+  [SMALL_STATE(3)] = 119,
+  [SMALL_STATE(4)] = 238,
+  [SMALL_STATE(5)] = 357,
+  [SMALL_STATE(6)] = 476,
+  [SMALL_STATE(7)] = 595,
+  [SMALL_STATE(8)] = 714,
+  [SMALL_STATE(9)] = 830,
+  [SMALL_STATE(10)] = 944,
+  [SMALL_STATE(11)] = 1058,
+  [SMALL_STATE(12)] = 1164,
+  [SMALL_STATE(13)] = 1270,
+  [SMALL_STATE(14)] = 1370,
+  [SMALL_STATE(15)] = 1470,
+  [SMALL_STATE(16)] = 1570,
+  [SMALL_STATE(17)] = 1670,
+  [SMALL_STATE(18)] = 1771,
+  [SMALL_STATE(19)] = 1872,
+  [SMALL_STATE(20)] = 1969,
+  [SMALL_STATE(21)] = 2067,
+  [SMALL_STATE(22)] = 2165,
+  [SMALL_STATE(23)] = 2263,
+  [SMALL_STATE(24)] = 2361,
+  [SMALL_STATE(25)] = 2440,
+  [SMALL_STATE(26)] = 2519,
+  [SMALL_STATE(27)] = 2575,
+  [SMALL_STATE(28)] = 2626,
+  [SMALL_STATE(29)] = 2672,
+  [SMALL_STATE(30)] = 2718,
+  [SMALL_STATE(31)] = 2772,
+  [SMALL_STATE(32)] = 2818,
+  [SMALL_STATE(33)] = 2893,
+  [SMALL_STATE(34)] = 2970,
+  [SMALL_STATE(35)] = 3043,
+  [SMALL_STATE(36)] = 3118,
+  [SMALL_STATE(37)] = 3195,
+  [SMALL_STATE(38)] = 3240,
+  [SMALL_STATE(39)] = 3283,
+  [SMALL_STATE(40)] = 3326,
+  [SMALL_STATE(41)] = 3371,
+  [SMALL_STATE(42)] = 3416,
+  [SMALL_STATE(43)] = 3457,
+  [SMALL_STATE(44)] = 3498,
+  [SMALL_STATE(45)] = 3543,
+  [SMALL_STATE(46)] = 3618,
+  [SMALL_STATE(47)] = 3667,
+  [SMALL_STATE(48)] = 3742,
+  [SMALL_STATE(49)] = 3817,
+  [SMALL_STATE(50)] = 3891,
+  [SMALL_STATE(51)] = 3961,
+  [SMALL_STATE(52)] = 4035,
+  [SMALL_STATE(53)] = 4105,
+  [SMALL_STATE(54)] = 4147,
+  [SMALL_STATE(55)] = 4191,
+  [SMALL_STATE(56)] = 4231,
+  [SMALL_STATE(57)] = 4275,
+  [SMALL_STATE(58)] = 4317,
+  [SMALL_STATE(59)] = 4391,
+  [SMALL_STATE(60)] = 4465,
+  [SMALL_STATE(61)] = 4537,
+  [SMALL_STATE(62)] = 4611,
+  [SMALL_STATE(63)] = 4685,
+  [SMALL_STATE(64)] = 4759,
+  [SMALL_STATE(65)] = 4799,
+  [SMALL_STATE(66)] = 4873,
+  [SMALL_STATE(67)] = 4912,
+  [SMALL_STATE(68)] = 4955,
+  [SMALL_STATE(69)] = 5006,
+  [SMALL_STATE(70)] = 5045,
+  [SMALL_STATE(71)] = 5084,
+  [SMALL_STATE(72)] = 5123,
+  [SMALL_STATE(73)] = 5162,
+  [SMALL_STATE(74)] = 5201,
+  [SMALL_STATE(75)] = 5242,
+  [SMALL_STATE(76)] = 5283,
+  [SMALL_STATE(77)] = 5322,
+  [SMALL_STATE(78)] = 5361,
+  [SMALL_STATE(79)] = 5400,
+  [SMALL_STATE(80)] = 5439,
+  [SMALL_STATE(81)] = 5478,
+  [SMALL_STATE(82)] = 5533,
+  [SMALL_STATE(83)] = 5576,
+  [SMALL_STATE(84)] = 5619,
+  [SMALL_STATE(85)] = 5658,
+  [SMALL_STATE(86)] = 5697,
+  [SMALL_STATE(87)] = 5740,
+  [SMALL_STATE(88)] = 5791,
+  [SMALL_STATE(89)] = 5860,
+  [SMALL_STATE(90)] = 5929,
+  [SMALL_STATE(91)] = 5969,
+  [SMALL_STATE(92)] = 6007,
+  [SMALL_STATE(93)] = 6047,
+  [SMALL_STATE(94)] = 6115,
+  [SMALL_STATE(95)] = 6153,
+  [SMALL_STATE(96)] = 6191,
+  [SMALL_STATE(97)] = 6259,
+  [SMALL_STATE(98)] = 6299,
+  [SMALL_STATE(99)] = 6367,
+  [SMALL_STATE(100)] = 6411,
+  [SMALL_STATE(101)] = 6449,
+  [SMALL_STATE(102)] = 6499,
+  [SMALL_STATE(103)] = 6567,
+  [SMALL_STATE(104)] = 6635,
+  [SMALL_STATE(105)] = 6673,
+  [SMALL_STATE(106)] = 6710,
+  [SMALL_STATE(107)] = 6747,
+  [SMALL_STATE(108)] = 6784,
+  [SMALL_STATE(109)] = 6849,
+  [SMALL_STATE(110)] = 6886,
+  [SMALL_STATE(111)] = 6923,
+  [SMALL_STATE(112)] = 6960,
+  [SMALL_STATE(113)] = 6997,
+  [SMALL_STATE(114)] = 7062,
+  [SMALL_STATE(115)] = 7099,
+  [SMALL_STATE(116)] = 7136,
+  [SMALL_STATE(117)] = 7173,
+  [SMALL_STATE(118)] = 7235,
+  [SMALL_STATE(119)] = 7297,
+  [SMALL_STATE(120)] = 7359,
+  [SMALL_STATE(121)] = 7421,
+  [SMALL_STATE(122)] = 7483,
+  [SMALL_STATE(123)] = 7545,
+  [SMALL_STATE(124)] = 7607,
+  [SMALL_STATE(125)] = 7669,
+  [SMALL_STATE(126)] = 7731,
+  [SMALL_STATE(127)] = 7793,
+  [SMALL_STATE(128)] = 7855,
+  [SMALL_STATE(129)] = 7915,
+  [SMALL_STATE(130)] = 7977,
+  [SMALL_STATE(131)] = 8039,
+  [SMALL_STATE(132)] = 8101,
+  [SMALL_STATE(133)] = 8163,
+  [SMALL_STATE(134)] = 8225,
+  [SMALL_STATE(135)] = 8287,
+  [SMALL_STATE(136)] = 8349,
+  [SMALL_STATE(137)] = 8411,
+  [SMALL_STATE(138)] = 8473,
+  [SMALL_STATE(139)] = 8535,
+  [SMALL_STATE(140)] = 8597,
+  [SMALL_STATE(141)] = 8657,
+  [SMALL_STATE(142)] = 8719,
+  [SMALL_STATE(143)] = 8781,
+  [SMALL_STATE(144)] = 8843,
+  [SMALL_STATE(145)] = 8905,
+  [SMALL_STATE(146)] = 8967,
+  [SMALL_STATE(147)] = 9029,
+  [SMALL_STATE(148)] = 9091,
+  [SMALL_STATE(149)] = 9153,
+  [SMALL_STATE(150)] = 9215,
+  [SMALL_STATE(151)] = 9277,
+  [SMALL_STATE(152)] = 9339,
+  [SMALL_STATE(153)] = 9401,
+  [SMALL_STATE(154)] = 9463,
+  [SMALL_STATE(155)] = 9525,
+  [SMALL_STATE(156)] = 9587,
+  [SMALL_STATE(157)] = 9649,
+  [SMALL_STATE(158)] = 9711,
+  [SMALL_STATE(159)] = 9773,
+  [SMALL_STATE(160)] = 9835,
+  [SMALL_STATE(161)] = 9897,
+  [SMALL_STATE(162)] = 9959,
+  [SMALL_STATE(163)] = 10021,
+  [SMALL_STATE(164)] = 10083,
+  [SMALL_STATE(165)] = 10129,
+  [SMALL_STATE(166)] = 10162,
+  [SMALL_STATE(167)] = 10195,
+  [SMALL_STATE(168)] = 10228,
+  [SMALL_STATE(169)] = 10261,
+  [SMALL_STATE(170)] = 10315,
+  [SMALL_STATE(171)] = 10347,
+  [SMALL_STATE(172)] = 10379,
+  [SMALL_STATE(173)] = 10411,
+  [SMALL_STATE(174)] = 10443,
+  [SMALL_STATE(175)] = 10475,
+  [SMALL_STATE(176)] = 10507,
+  [SMALL_STATE(177)] = 10539,
+  [SMALL_STATE(178)] = 10571,
+  [SMALL_STATE(179)] = 10603,
+  [SMALL_STATE(180)] = 10635,
+  [SMALL_STATE(181)] = 10667,
+  [SMALL_STATE(182)] = 10699,
+  [SMALL_STATE(183)] = 10731,
+  [SMALL_STATE(184)] = 10763,
+  [SMALL_STATE(185)] = 10795,
+  [SMALL_STATE(186)] = 10827,
+  [SMALL_STATE(187)] = 10859,
+  [SMALL_STATE(188)] = 10891,
+  [SMALL_STATE(189)] = 10923,
+  [SMALL_STATE(190)] = 10955,
+  [SMALL_STATE(191)] = 11009,
+  [SMALL_STATE(192)] = 11041,
+  [SMALL_STATE(193)] = 11095,
+  [SMALL_STATE(194)] = 11127,
+  [SMALL_STATE(195)] = 11159,
+  [SMALL_STATE(196)] = 11213,
+  [SMALL_STATE(197)] = 11267,
+  [SMALL_STATE(198)] = 11299,
+  [SMALL_STATE(199)] = 11353,
+  [SMALL_STATE(200)] = 11385,
+  [SMALL_STATE(201)] = 11417,
+  [SMALL_STATE(202)] = 11449,
+  [SMALL_STATE(203)] = 11481,
+  [SMALL_STATE(204)] = 11513,
+  [SMALL_STATE(205)] = 11545,
+  [SMALL_STATE(206)] = 11577,
+  [SMALL_STATE(207)] = 11609,
+  [SMALL_STATE(208)] = 11641,
+  [SMALL_STATE(209)] = 11673,
+  [SMALL_STATE(210)] = 11727,
+  [SMALL_STATE(211)] = 11781,
+  [SMALL_STATE(212)] = 11835,
+  [SMALL_STATE(213)] = 11889,
+  [SMALL_STATE(214)] = 11921,
+  [SMALL_STATE(215)] = 11953,
+  [SMALL_STATE(216)] = 11985,
+  [SMALL_STATE(217)] = 12017,
+  [SMALL_STATE(218)] = 12049,
+  [SMALL_STATE(219)] = 12081,
+  [SMALL_STATE(220)] = 12113,
+  [SMALL_STATE(221)] = 12145,
+  [SMALL_STATE(222)] = 12177,
+  [SMALL_STATE(223)] = 12209,
+  [SMALL_STATE(224)] = 12241,
+  [SMALL_STATE(225)] = 12273,
+  [SMALL_STATE(226)] = 12305,
+  [SMALL_STATE(227)] = 12337,
+  [SMALL_STATE(228)] = 12369,
+  [SMALL_STATE(229)] = 12401,
+  [SMALL_STATE(230)] = 12433,
+  [SMALL_STATE(231)] = 12465,
+  [SMALL_STATE(232)] = 12497,
+  [SMALL_STATE(233)] = 12529,
+  [SMALL_STATE(234)] = 12561,
+  [SMALL_STATE(235)] = 12593,
+  [SMALL_STATE(236)] = 12625,
+  [SMALL_STATE(237)] = 12657,
+  [SMALL_STATE(238)] = 12689,
+  [SMALL_STATE(239)] = 12721,
+  [SMALL_STATE(240)] = 12753,
+  [SMALL_STATE(241)] = 12785,
+  [SMALL_STATE(242)] = 12817,
+  [SMALL_STATE(243)] = 12849,
+  [SMALL_STATE(244)] = 12881,
+  [SMALL_STATE(245)] = 12913,
+  [SMALL_STATE(246)] = 12945,
+  [SMALL_STATE(247)] = 12977,
+  [SMALL_STATE(248)] = 13009,
+  [SMALL_STATE(249)] = 13041,
+  [SMALL_STATE(250)] = 13081,
+  [SMALL_STATE(251)] = 13135,
+  [SMALL_STATE(252)] = 13167,
+  [SMALL_STATE(253)] = 13199,
+  [SMALL_STATE(254)] = 13231,
+  [SMALL_STATE(255)] = 13263,
+  [SMALL_STATE(256)] = 13295,
+  [SMALL_STATE(257)] = 13327,
+  [SMALL_STATE(258)] = 13359,
+  [SMALL_STATE(259)] = 13391,
+  [SMALL_STATE(260)] = 13430,
+  [SMALL_STATE(261)] = 13461,
+  [SMALL_STATE(262)] = 13515,
+  [SMALL_STATE(263)] = 13569,
+  [SMALL_STATE(264)] = 13603,
+  [SMALL_STATE(265)] = 13637,
+  [SMALL_STATE(266)] = 13691,
+  [SMALL_STATE(267)] = 13724,
+  [SMALL_STATE(268)] = 13757,
+  [SMALL_STATE(269)] = 13788,
+  [SMALL_STATE(270)] = 13819,
+  [SMALL_STATE(271)] = 13852,
+  [SMALL_STATE(272)] = 13885,
+  [SMALL_STATE(273)] = 13921,
+  [SMALL_STATE(274)] = 13951,
+  [SMALL_STATE(275)] = 13979,
+  [SMALL_STATE(276)] = 14007,
+  [SMALL_STATE(277)] = 14037,
+  [SMALL_STATE(278)] = 14064,
+  [SMALL_STATE(279)] = 14091,
+  [SMALL_STATE(280)] = 14118,
+  [SMALL_STATE(281)] = 14145,
+  [SMALL_STATE(282)] = 14172,
+  [SMALL_STATE(283)] = 14199,
+  [SMALL_STATE(284)] = 14226,
+  [SMALL_STATE(285)] = 14253,
+  [SMALL_STATE(286)] = 14280,
+  [SMALL_STATE(287)] = 14307,
+  [SMALL_STATE(288)] = 14334,
+  [SMALL_STATE(289)] = 14361,
+  [SMALL_STATE(290)] = 14400,
+  [SMALL_STATE(291)] = 14427,
+  [SMALL_STATE(292)] = 14454,
+  [SMALL_STATE(293)] = 14484,
+  [SMALL_STATE(294)] = 14514,
+  [SMALL_STATE(295)] = 14542,
+  [SMALL_STATE(296)] = 14574,
+  [SMALL_STATE(297)] = 14604,
+  [SMALL_STATE(298)] = 14632,
+  [SMALL_STATE(299)] = 14662,
+  [SMALL_STATE(300)] = 14697,
+  [SMALL_STATE(301)] = 14724,
+  [SMALL_STATE(302)] = 14749,
+  [SMALL_STATE(303)] = 14776,
+  [SMALL_STATE(304)] = 14811,
+  [SMALL_STATE(305)] = 14844,
+  [SMALL_STATE(306)] = 14877,
+  [SMALL_STATE(307)] = 14911,
+  [SMALL_STATE(308)] = 14935,
+  [SMALL_STATE(309)] = 14959,
+  [SMALL_STATE(310)] = 14983,
+  [SMALL_STATE(311)] = 15007,
+  [SMALL_STATE(312)] = 15031,
+  [SMALL_STATE(313)] = 15055,
+  [SMALL_STATE(314)] = 15079,
+  [SMALL_STATE(315)] = 15103,
+  [SMALL_STATE(316)] = 15127,
+  [SMALL_STATE(317)] = 15157,
+  [SMALL_STATE(318)] = 15185,
+  [SMALL_STATE(319)] = 15209,
+  [SMALL_STATE(320)] = 15237,
+  [SMALL_STATE(321)] = 15265,
+  [SMALL_STATE(322)] = 15301,
+  [SMALL_STATE(323)] = 15325,
+  [SMALL_STATE(324)] = 15349,
+  [SMALL_STATE(325)] = 15373,
+  [SMALL_STATE(326)] = 15410,
+  [SMALL_STATE(327)] = 15443,
+  [SMALL_STATE(328)] = 15470,
+  [SMALL_STATE(329)] = 15503,
+  [SMALL_STATE(330)] = 15530,
+  [SMALL_STATE(331)] = 15563,
+  [SMALL_STATE(332)] = 15596,
+  [SMALL_STATE(333)] = 15625,
+  [SMALL_STATE(334)] = 15652,
+  [SMALL_STATE(335)] = 15679,
+  [SMALL_STATE(336)] = 15706,
+  [SMALL_STATE(337)] = 15739,
+  [SMALL_STATE(338)] = 15766,
+  [SMALL_STATE(339)] = 15793,
+  [SMALL_STATE(340)] = 15820,
+  [SMALL_STATE(341)] = 15847,
+  [SMALL_STATE(342)] = 15880,
+  [SMALL_STATE(343)] = 15903,
+  [SMALL_STATE(344)] = 15926,
+  [SMALL_STATE(345)] = 15955,
+  [SMALL_STATE(346)] = 15982,
+  [SMALL_STATE(347)] = 16015,
+  [SMALL_STATE(348)] = 16048,
+  [SMALL_STATE(349)] = 16071,
+  [SMALL_STATE(350)] = 16098,
+  [SMALL_STATE(351)] = 16125,
+  [SMALL_STATE(352)] = 16152,
+  [SMALL_STATE(353)] = 16179,
+  [SMALL_STATE(354)] = 16206,
+  [SMALL_STATE(355)] = 16243,
+  [SMALL_STATE(356)] = 16270,
+  [SMALL_STATE(357)] = 16295,
+  [SMALL_STATE(358)] = 16318,
+  [SMALL_STATE(359)] = 16355,
+  [SMALL_STATE(360)] = 16384,
+  [SMALL_STATE(361)] = 16421,
+  [SMALL_STATE(362)] = 16454,
+  [SMALL_STATE(363)] = 16487,
+  [SMALL_STATE(364)] = 16514,
+  [SMALL_STATE(365)] = 16547,
+  [SMALL_STATE(366)] = 16574,
+  [SMALL_STATE(367)] = 16601,
+  [SMALL_STATE(368)] = 16628,
+  [SMALL_STATE(369)] = 16655,
+  [SMALL_STATE(370)] = 16682,
+  [SMALL_STATE(371)] = 16709,
+  [SMALL_STATE(372)] = 16753,
+  [SMALL_STATE(373)] = 16785,
+  [SMALL_STATE(374)] = 16813,
+  [SMALL_STATE(375)] = 16835,
+  [SMALL_STATE(376)] = 16857,
+  [SMALL_STATE(377)] = 16879,
+  [SMALL_STATE(378)] = 16901,
+  [SMALL_STATE(379)] = 16923,
+  [SMALL_STATE(380)] = 16945,
+  [SMALL_STATE(381)] = 16969,
+  [SMALL_STATE(382)] = 16993,
+  [SMALL_STATE(383)] = 17024,
+  [SMALL_STATE(384)] = 17047,
+  [SMALL_STATE(385)] = 17070,
+  [SMALL_STATE(386)] = 17093,
+  [SMALL_STATE(387)] = 17124,
+  [SMALL_STATE(388)] = 17145,
+  [SMALL_STATE(389)] = 17166,
+  [SMALL_STATE(390)] = 17187,
+  [SMALL_STATE(391)] = 17208,
+  [SMALL_STATE(392)] = 17229,
+  [SMALL_STATE(393)] = 17250,
+  [SMALL_STATE(394)] = 17271,
+  [SMALL_STATE(395)] = 17292,
+  [SMALL_STATE(396)] = 17313,
+  [SMALL_STATE(397)] = 17334,
+  [SMALL_STATE(398)] = 17355,
+  [SMALL_STATE(399)] = 17376,
+  [SMALL_STATE(400)] = 17397,
+  [SMALL_STATE(401)] = 17418,
+  [SMALL_STATE(402)] = 17439,
+  [SMALL_STATE(403)] = 17460,
+  [SMALL_STATE(404)] = 17481,
+  [SMALL_STATE(405)] = 17502,
+  [SMALL_STATE(406)] = 17543,
+  [SMALL_STATE(407)] = 17574,
+  [SMALL_STATE(408)] = 17605,
+  [SMALL_STATE(409)] = 17646,
+  [SMALL_STATE(410)] = 17667,
+  [SMALL_STATE(411)] = 17688,
+  [SMALL_STATE(412)] = 17709,
+  [SMALL_STATE(413)] = 17730,
+  [SMALL_STATE(414)] = 17751,
+  [SMALL_STATE(415)] = 17772,
+  [SMALL_STATE(416)] = 17813,
+  [SMALL_STATE(417)] = 17834,
+  [SMALL_STATE(418)] = 17855,
+  [SMALL_STATE(419)] = 17876,
+  [SMALL_STATE(420)] = 17899,
+  [SMALL_STATE(421)] = 17930,
+  [SMALL_STATE(422)] = 17951,
+  [SMALL_STATE(423)] = 17972,
+  [SMALL_STATE(424)] = 17993,
+  [SMALL_STATE(425)] = 18014,
+  [SMALL_STATE(426)] = 18039,
+  [SMALL_STATE(427)] = 18060,
+  [SMALL_STATE(428)] = 18091,
+  [SMALL_STATE(429)] = 18122,
+  [SMALL_STATE(430)] = 18153,
+  [SMALL_STATE(431)] = 18184,
+  [SMALL_STATE(432)] = 18205,
+  [SMALL_STATE(433)] = 18226,
+  [SMALL_STATE(434)] = 18249,
+  [SMALL_STATE(435)] = 18290,
+  [SMALL_STATE(436)] = 18331,
+  [SMALL_STATE(437)] = 18362,
+  [SMALL_STATE(438)] = 18393,
+  [SMALL_STATE(439)] = 18424,
+  [SMALL_STATE(440)] = 18455,
+  [SMALL_STATE(441)] = 18486,
+  [SMALL_STATE(442)] = 18517,
+  [SMALL_STATE(443)] = 18548,
+  [SMALL_STATE(444)] = 18579,
+  [SMALL_STATE(445)] = 18610,
+  [SMALL_STATE(446)] = 18641,
+  [SMALL_STATE(447)] = 18672,
+  [SMALL_STATE(448)] = 18693,
+  [SMALL_STATE(449)] = 18714,
+  [SMALL_STATE(450)] = 18735,
+  [SMALL_STATE(451)] = 18756,
+  [SMALL_STATE(452)] = 18787,
+  [SMALL_STATE(453)] = 18808,
+  [SMALL_STATE(454)] = 18831,
+  [SMALL_STATE(455)] = 18852,
+  [SMALL_STATE(456)] = 18873,
+  [SMALL_STATE(457)] = 18894,
+  [SMALL_STATE(458)] = 18915,
+  [SMALL_STATE(459)] = 18938,
+  [SMALL_STATE(460)] = 18961,
+  [SMALL_STATE(461)] = 18992,
+  [SMALL_STATE(462)] = 19013,
+  [SMALL_STATE(463)] = 19037,
+  [SMALL_STATE(464)] = 19073,
+  [SMALL_STATE(465)] = 19109,
+  [SMALL_STATE(466)] = 19144,
+  [SMALL_STATE(467)] = 19179,
+  [SMALL_STATE(468)] = 19200,
+  [SMALL_STATE(469)] = 19215,
+  [SMALL_STATE(470)] = 19230,
+  [SMALL_STATE(471)] = 19253,
+  [SMALL_STATE(472)] = 19274,
+  [SMALL_STATE(473)] = 19295,
+  [SMALL_STATE(474)] = 19318,
+  [SMALL_STATE(475)] = 19333,
+  [SMALL_STATE(476)] = 19348,
+  [SMALL_STATE(477)] = 19369,
+  [SMALL_STATE(478)] = 19390,
+  [SMALL_STATE(479)] = 19410,
+  [SMALL_STATE(480)] = 19430,
+  [SMALL_STATE(481)] = 19442,
+  [SMALL_STATE(482)] = 19454,
+  [SMALL_STATE(483)] = 19466,
+  [SMALL_STATE(484)] = 19478,
+  [SMALL_STATE(485)] = 19498,
+  [SMALL_STATE(486)] = 19510,
+  [SMALL_STATE(487)] = 19522,
+  [SMALL_STATE(488)] = 19534,
+  [SMALL_STATE(489)] = 19554,
+  [SMALL_STATE(490)] = 19571,
+  [SMALL_STATE(491)] = 19588,
+  [SMALL_STATE(492)] = 19607,
+  [SMALL_STATE(493)] = 19626,
+  [SMALL_STATE(494)] = 19645,
+  [SMALL_STATE(495)] = 19658,
+  [SMALL_STATE(496)] = 19677,
+  [SMALL_STATE(497)] = 19694,
+  [SMALL_STATE(498)] = 19713,
+  [SMALL_STATE(499)] = 19730,
+  [SMALL_STATE(500)] = 19744,
+  [SMALL_STATE(501)] = 19760,
+  [SMALL_STATE(502)] = 19776,
+  [SMALL_STATE(503)] = 19788,
+  [SMALL_STATE(504)] = 19804,
+  [SMALL_STATE(505)] = 19820,
+  [SMALL_STATE(506)] = 19834,
+  [SMALL_STATE(507)] = 19850,
+  [SMALL_STATE(508)] = 19864,
+  [SMALL_STATE(509)] = 19874,
+  [SMALL_STATE(510)] = 19890,
+  [SMALL_STATE(511)] = 19906,
+  [SMALL_STATE(512)] = 19918,
+  [SMALL_STATE(513)] = 19934,
+  [SMALL_STATE(514)] = 19950,
+  [SMALL_STATE(515)] = 19964,
+  [SMALL_STATE(516)] = 19980,
+  [SMALL_STATE(517)] = 19996,
+  [SMALL_STATE(518)] = 20010,
+  [SMALL_STATE(519)] = 20024,
+  [SMALL_STATE(520)] = 20038,
+  [SMALL_STATE(521)] = 20052,
+  [SMALL_STATE(522)] = 20068,
+  [SMALL_STATE(523)] = 20084,
+  [SMALL_STATE(524)] = 20100,
+  [SMALL_STATE(525)] = 20114,
+  [SMALL_STATE(526)] = 20130,
+  [SMALL_STATE(527)] = 20146,
+  [SMALL_STATE(528)] = 20160,
+  [SMALL_STATE(529)] = 20174,
+  [SMALL_STATE(530)] = 20190,
+  [SMALL_STATE(531)] = 20200,
+  [SMALL_STATE(532)] = 20212,
+  [SMALL_STATE(533)] = 20228,
+  [SMALL_STATE(534)] = 20244,
+  [SMALL_STATE(535)] = 20256,
+  [SMALL_STATE(536)] = 20266,
+  [SMALL_STATE(537)] = 20276,
+  [SMALL_STATE(538)] = 20286,
+  [SMALL_STATE(539)] = 20302,
+  [SMALL_STATE(540)] = 20318,
+  [SMALL_STATE(541)] = 20334,
+  [SMALL_STATE(542)] = 20347,
+  [SMALL_STATE(543)] = 20356,
+  [SMALL_STATE(544)] = 20369,
+  [SMALL_STATE(545)] = 20378,
+  [SMALL_STATE(546)] = 20391,
+  [SMALL_STATE(547)] = 20404,
+  [SMALL_STATE(548)] = 20413,
+  [SMALL_STATE(549)] = 20426,
+  [SMALL_STATE(550)] = 20439,
+  [SMALL_STATE(551)] = 20452,
+  [SMALL_STATE(552)] = 20465,
+  [SMALL_STATE(553)] = 20478,
+  [SMALL_STATE(554)] = 20491,
+  [SMALL_STATE(555)] = 20504,
+  [SMALL_STATE(556)] = 20517,
+  [SMALL_STATE(557)] = 20530,
+  [SMALL_STATE(558)] = 20543,
+  [SMALL_STATE(559)] = 20552,
+  [SMALL_STATE(560)] = 20565,
+  [SMALL_STATE(561)] = 20574,
+  [SMALL_STATE(562)] = 20583,
+  [SMALL_STATE(563)] = 20594,
+  [SMALL_STATE(564)] = 20607,
+  [SMALL_STATE(565)] = 20620,
+  [SMALL_STATE(566)] = 20633,
+  [SMALL_STATE(567)] = 20646,
+  [SMALL_STATE(568)] = 20659,
+  [SMALL_STATE(569)] = 20672,
+  [SMALL_STATE(570)] = 20681,
+  [SMALL_STATE(571)] = 20690,
+  [SMALL_STATE(572)] = 20699,
+  [SMALL_STATE(573)] = 20708,
+  [SMALL_STATE(574)] = 20721,
+  [SMALL_STATE(575)] = 20731,
+  [SMALL_STATE(576)] = 20741,
+  [SMALL_STATE(577)] = 20751,
+  [SMALL_STATE(578)] = 20761,
+  [SMALL_STATE(579)] = 20771,
+  [SMALL_STATE(580)] = 20781,
+  [SMALL_STATE(581)] = 20791,
+  [SMALL_STATE(582)] = 20801,
+  [SMALL_STATE(583)] = 20811,
+  [SMALL_STATE(584)] = 20821,
+  [SMALL_STATE(585)] = 20831,
+  [SMALL_STATE(586)] = 20841,
+  [SMALL_STATE(587)] = 20851,
+  [SMALL_STATE(588)] = 20859,
+  [SMALL_STATE(589)] = 20869,
+  [SMALL_STATE(590)] = 20879,
+  [SMALL_STATE(591)] = 20889,
+  [SMALL_STATE(592)] = 20897,
+  [SMALL_STATE(593)] = 20905,
+  [SMALL_STATE(594)] = 20915,
+  [SMALL_STATE(595)] = 20925,
+  [SMALL_STATE(596)] = 20935,
+  [SMALL_STATE(597)] = 20945,
+  [SMALL_STATE(598)] = 20955,
+  [SMALL_STATE(599)] = 20965,
+  [SMALL_STATE(600)] = 20972,
+  [SMALL_STATE(601)] = 20979,
+  [SMALL_STATE(602)] = 20986,
+  [SMALL_STATE(603)] = 20993,
+  [SMALL_STATE(604)] = 21000,
+  [SMALL_STATE(605)] = 21007,
+  [SMALL_STATE(606)] = 21014,
+  [SMALL_STATE(607)] = 21021,
+  [SMALL_STATE(608)] = 21028,
+  [SMALL_STATE(609)] = 21035,
+  [SMALL_STATE(610)] = 21042,
+  [SMALL_STATE(611)] = 21049,
+  [SMALL_STATE(612)] = 21056,
+  [SMALL_STATE(613)] = 21063,
+  [SMALL_STATE(614)] = 21070,
+  [SMALL_STATE(615)] = 21077,
+  [SMALL_STATE(616)] = 21084,
+  [SMALL_STATE(617)] = 21091,
+  [SMALL_STATE(618)] = 21098,
+  [SMALL_STATE(619)] = 21105,
+  [SMALL_STATE(620)] = 21112,
+  [SMALL_STATE(621)] = 21119,
+  [SMALL_STATE(622)] = 21126,
+  [SMALL_STATE(623)] = 21133,
+  [SMALL_STATE(624)] = 21140,
+  [SMALL_STATE(625)] = 21147,
+  [SMALL_STATE(626)] = 21154,
+  [SMALL_STATE(627)] = 21161,
+  [SMALL_STATE(628)] = 21168,
+  [SMALL_STATE(629)] = 21175,
+  [SMALL_STATE(630)] = 21182,
+  [SMALL_STATE(631)] = 21189,
+  [SMALL_STATE(632)] = 21196,
+  [SMALL_STATE(633)] = 21203,
+  [SMALL_STATE(634)] = 21210,
+  [SMALL_STATE(635)] = 21217,
+  [SMALL_STATE(636)] = 21224,
+  [SMALL_STATE(637)] = 21231,
+  [SMALL_STATE(638)] = 21238,
+  [SMALL_STATE(639)] = 21245,
+  [SMALL_STATE(640)] = 21252,
+  [SMALL_STATE(641)] = 21259,
+  [SMALL_STATE(642)] = 21266,
+  [SMALL_STATE(643)] = 21273,
+  [SMALL_STATE(644)] = 21280,
+  [SMALL_STATE(645)] = 21287,
+  [SMALL_STATE(646)] = 21294,
+  [SMALL_STATE(647)] = 21301,
+  [SMALL_STATE(648)] = 21308,
+  [SMALL_STATE(649)] = 21315,
+  [SMALL_STATE(650)] = 21322,
+  [SMALL_STATE(651)] = 21329,
+  [SMALL_STATE(652)] = 21336,
+  [SMALL_STATE(653)] = 21343,
+  [SMALL_STATE(654)] = 21350,
+  [SMALL_STATE(655)] = 21357,
+  [SMALL_STATE(656)] = 21364,
+  [SMALL_STATE(657)] = 21371,
+  [SMALL_STATE(658)] = 21378,
+  [SMALL_STATE(659)] = 21385,
+  [SMALL_STATE(660)] = 21392,
+  [SMALL_STATE(661)] = 21399,
+  [SMALL_STATE(662)] = 21406,
+  [SMALL_STATE(663)] = 21413,
+  [SMALL_STATE(664)] = 21420,
+  [SMALL_STATE(665)] = 21427,
+  [SMALL_STATE(666)] = 21434,
+  [SMALL_STATE(667)] = 21441,
+  [SMALL_STATE(668)] = 21448,
+  [SMALL_STATE(669)] = 21455,
+  [SMALL_STATE(670)] = 21462,
+  [SMALL_STATE(671)] = 21469,
+  [SMALL_STATE(672)] = 21476,
+  [SMALL_STATE(673)] = 21483,
+  [SMALL_STATE(674)] = 21490,
+  [SMALL_STATE(675)] = 21497,
+  [SMALL_STATE(676)] = 21504,
+  [SMALL_STATE(677)] = 21511,
+  [SMALL_STATE(678)] = 21518,
+  [SMALL_STATE(679)] = 21525,
+  [SMALL_STATE(680)] = 21532,
+};
+
+static const TSParseActionEntry ts_parse_actions[] = {
+  [0] = {.entry = {.count = 0, .reusable = false}},
+  [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
+  [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT_EXTRA(),
+  [5] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_stylesheet, 0, 0, 0),
+  [7] = {.entry = {.count = 1, .reusable = false}}, SHIFT(129),
+  [9] = {.entry = {.count = 1, .reusable = false}}, SHIFT(341),
+  [11] = {.entry = {.count = 1, .reusable = false}}, SHIFT(147),
+  [13] = {.entry = {.count = 1, .reusable = false}}, SHIFT(533),
+  [15] = {.entry = {.count = 1, .reusable = false}}, SHIFT(661),
+  [17] = {.entry = {.count = 1, .reusable = false}}, SHIFT(346),
+  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(304),
+  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(431),
+  [23] = {.entry = {.count = 1, .reusable = true}}, SHIFT(492),
+  [25] = {.entry = {.count = 1, .reusable = false}}, SHIFT(640),
+  [27] = {.entry = {.count = 1, .reusable = true}}, SHIFT(634),
+  [29] = {.entry = {.count = 1, .reusable = true}}, SHIFT(610),
+  [31] = {.entry = {.count = 1, .reusable = true}}, SHIFT(649),
+  [33] = {.entry = {.count = 1, .reusable = true}}, SHIFT(664),
+  [35] = {.entry = {.count = 1, .reusable = true}}, SHIFT(405),
+  [37] = {.entry = {.count = 1, .reusable = false}}, SHIFT(306),
+  [39] = {.entry = {.count = 1, .reusable = false}}, SHIFT(262),
+  [41] = {.entry = {.count = 1, .reusable = false}}, SHIFT(641),
+  [43] = {.entry = {.count = 1, .reusable = false}}, SHIFT(131),
+  [45] = {.entry = {.count = 1, .reusable = true}}, SHIFT(135),
+  [47] = {.entry = {.count = 1, .reusable = false}}, SHIFT(539),
+  [49] = {.entry = {.count = 1, .reusable = false}}, SHIFT(141),
+  [51] = {.entry = {.count = 1, .reusable = false}}, SHIFT(362),
+  [53] = {.entry = {.count = 1, .reusable = false}}, SHIFT(142),
+  [55] = {.entry = {.count = 1, .reusable = false}}, SHIFT(529),
+  [57] = {.entry = {.count = 1, .reusable = false}}, SHIFT(646),
+  [59] = {.entry = {.count = 1, .reusable = true}}, SHIFT(166),
+  [61] = {.entry = {.count = 1, .reusable = false}}, SHIFT(364),
+  [63] = {.entry = {.count = 1, .reusable = true}}, SHIFT(305),
+  [65] = {.entry = {.count = 1, .reusable = true}}, SHIFT(461),
+  [67] = {.entry = {.count = 1, .reusable = true}}, SHIFT(491),
+  [69] = {.entry = {.count = 1, .reusable = false}}, SHIFT(607),
+  [71] = {.entry = {.count = 1, .reusable = true}}, SHIFT(608),
+  [73] = {.entry = {.count = 1, .reusable = true}}, SHIFT(609),
+  [75] = {.entry = {.count = 1, .reusable = true}}, SHIFT(648),
+  [77] = {.entry = {.count = 1, .reusable = true}}, SHIFT(371),
+  [79] = {.entry = {.count = 1, .reusable = false}}, SHIFT(299),
+  [81] = {.entry = {.count = 1, .reusable = false}}, SHIFT(265),
+  [83] = {.entry = {.count = 1, .reusable = true}}, SHIFT(99),
+  [85] = {.entry = {.count = 1, .reusable = false}}, SHIFT(679),
+  [87] = {.entry = {.count = 1, .reusable = false}}, SHIFT(143),
+  [89] = {.entry = {.count = 1, .reusable = true}}, SHIFT(158),
+  [91] = {.entry = {.count = 1, .reusable = false}}, SHIFT(516),
+  [93] = {.entry = {.count = 1, .reusable = true}}, SHIFT(165),
+  [95] = {.entry = {.count = 1, .reusable = true}}, SHIFT(219),
+  [97] = {.entry = {.count = 1, .reusable = true}}, SHIFT(232),
+  [99] = {.entry = {.count = 1, .reusable = true}}, SHIFT(530),
+  [101] = {.entry = {.count = 1, .reusable = true}}, SHIFT(536),
+  [103] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(141),
+  [106] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(362),
+  [109] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(142),
+  [112] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(529),
+  [115] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(646),
+  [118] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0),
+  [120] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(364),
+  [123] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(305),
+  [126] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(461),
+  [129] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(491),
+  [132] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(607),
+  [135] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(608),
+  [138] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(609),
+  [141] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(648),
+  [144] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(664),
+  [147] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(371),
+  [150] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(303),
+  [153] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(261),
+  [156] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(99),
+  [159] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(679),
+  [162] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(143),
+  [165] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(158),
+  [168] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(538),
+  [171] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_stylesheet, 1, 0, 4),
+  [173] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 15),
+  [175] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 15), SHIFT_REPEAT(129),
+  [178] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 15), SHIFT_REPEAT(341),
+  [181] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 15), SHIFT_REPEAT(147),
+  [184] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 15), SHIFT_REPEAT(533),
+  [187] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 15), SHIFT_REPEAT(661),
+  [190] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 15), SHIFT_REPEAT(346),
+  [193] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 15), SHIFT_REPEAT(304),
+  [196] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 15), SHIFT_REPEAT(431),
+  [199] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 15), SHIFT_REPEAT(492),
+  [202] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 15), SHIFT_REPEAT(640),
+  [205] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 15), SHIFT_REPEAT(634),
+  [208] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 15), SHIFT_REPEAT(610),
+  [211] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 15), SHIFT_REPEAT(649),
+  [214] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 15), SHIFT_REPEAT(664),
+  [217] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 15), SHIFT_REPEAT(405),
+  [220] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 15), SHIFT_REPEAT(306),
+  [223] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 15), SHIFT_REPEAT(262),
+  [226] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 15), SHIFT_REPEAT(641),
+  [229] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 15), SHIFT_REPEAT(131),
+  [232] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 15), SHIFT_REPEAT(135),
+  [235] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 15), SHIFT_REPEAT(539),
+  [238] = {.entry = {.count = 1, .reusable = true}}, SHIFT(248),
+  [240] = {.entry = {.count = 1, .reusable = true}}, SHIFT(140),
+  [242] = {.entry = {.count = 1, .reusable = true}}, SHIFT(343),
+  [244] = {.entry = {.count = 1, .reusable = true}}, SHIFT(602),
+  [246] = {.entry = {.count = 1, .reusable = false}}, SHIFT(122),
+  [248] = {.entry = {.count = 1, .reusable = true}}, SHIFT(603),
+  [250] = {.entry = {.count = 1, .reusable = false}}, SHIFT(357),
+  [252] = {.entry = {.count = 1, .reusable = true}}, SHIFT(25),
+  [254] = {.entry = {.count = 1, .reusable = false}}, SHIFT(328),
+  [256] = {.entry = {.count = 1, .reusable = false}}, SHIFT(630),
+  [258] = {.entry = {.count = 1, .reusable = false}}, SHIFT(276),
+  [260] = {.entry = {.count = 1, .reusable = false}}, SHIFT(273),
+  [262] = {.entry = {.count = 1, .reusable = false}}, SHIFT(134),
+  [264] = {.entry = {.count = 1, .reusable = true}}, SHIFT(134),
+  [266] = {.entry = {.count = 1, .reusable = false}}, SHIFT(164),
+  [268] = {.entry = {.count = 1, .reusable = false}}, SHIFT(267),
+  [270] = {.entry = {.count = 1, .reusable = true}}, SHIFT(263),
+  [272] = {.entry = {.count = 1, .reusable = true}}, SHIFT(156),
+  [274] = {.entry = {.count = 1, .reusable = false}}, SHIFT(604),
+  [276] = {.entry = {.count = 1, .reusable = true}}, SHIFT(663),
+  [278] = {.entry = {.count = 1, .reusable = true}}, SHIFT(605),
+  [280] = {.entry = {.count = 1, .reusable = false}}, SHIFT(264),
+  [282] = {.entry = {.count = 1, .reusable = true}}, SHIFT(213),
+  [284] = {.entry = {.count = 1, .reusable = true}}, SHIFT(128),
+  [286] = {.entry = {.count = 1, .reusable = true}}, SHIFT(30),
+  [288] = {.entry = {.count = 1, .reusable = false}}, SHIFT(532),
+  [290] = {.entry = {.count = 1, .reusable = true}}, SHIFT(598),
+  [292] = {.entry = {.count = 1, .reusable = true}}, SHIFT(619),
+  [294] = {.entry = {.count = 1, .reusable = true}}, SHIFT(404),
+  [296] = {.entry = {.count = 1, .reusable = true}}, SHIFT(152),
+  [298] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
+  [300] = {.entry = {.count = 1, .reusable = false}}, SHIFT(53),
+  [302] = {.entry = {.count = 1, .reusable = false}}, SHIFT(57),
+  [304] = {.entry = {.count = 1, .reusable = false}}, SHIFT(26),
+  [306] = {.entry = {.count = 1, .reusable = false}}, SHIFT(37),
+  [308] = {.entry = {.count = 1, .reusable = true}}, SHIFT(159),
+  [310] = {.entry = {.count = 1, .reusable = false}}, SHIFT(620),
+  [312] = {.entry = {.count = 1, .reusable = true}}, SHIFT(666),
+  [314] = {.entry = {.count = 1, .reusable = true}}, SHIFT(621),
+  [316] = {.entry = {.count = 1, .reusable = false}}, SHIFT(101),
+  [318] = {.entry = {.count = 1, .reusable = true}}, SHIFT(414),
+  [320] = {.entry = {.count = 1, .reusable = true}}, SHIFT(389),
+  [322] = {.entry = {.count = 1, .reusable = true}}, SHIFT(388),
+  [324] = {.entry = {.count = 1, .reusable = true}}, SHIFT(119),
+  [326] = {.entry = {.count = 1, .reusable = true}}, SHIFT(230),
+  [328] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_last_declaration, 3, 0, 54),
+  [330] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
+  [332] = {.entry = {.count = 1, .reusable = true}}, SHIFT(618),
+  [334] = {.entry = {.count = 1, .reusable = false}}, SHIFT(123),
+  [336] = {.entry = {.count = 1, .reusable = true}}, SHIFT(578),
+  [338] = {.entry = {.count = 1, .reusable = false}}, SHIFT(127),
+  [340] = {.entry = {.count = 1, .reusable = true}}, SHIFT(127),
+  [342] = {.entry = {.count = 1, .reusable = false}}, SHIFT(27),
+  [344] = {.entry = {.count = 1, .reusable = true}}, SHIFT(157),
+  [346] = {.entry = {.count = 1, .reusable = false}}, SHIFT(87),
+  [348] = {.entry = {.count = 1, .reusable = true}}, SHIFT(240),
+  [350] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_last_declaration, 4, 0, 83),
+  [352] = {.entry = {.count = 1, .reusable = true}}, SHIFT(580),
+  [354] = {.entry = {.count = 1, .reusable = true}}, SHIFT(177),
+  [356] = {.entry = {.count = 1, .reusable = true}}, SHIFT(636),
+  [358] = {.entry = {.count = 1, .reusable = true}}, SHIFT(191),
+  [360] = {.entry = {.count = 1, .reusable = true}}, SHIFT(653),
+  [362] = {.entry = {.count = 1, .reusable = true}}, SHIFT(631),
+  [364] = {.entry = {.count = 1, .reusable = true}}, SHIFT(676),
+  [366] = {.entry = {.count = 1, .reusable = true}}, SHIFT(354),
+  [368] = {.entry = {.count = 1, .reusable = true}}, SHIFT(615),
+  [370] = {.entry = {.count = 1, .reusable = true}}, SHIFT(617),
+  [372] = {.entry = {.count = 1, .reusable = true}}, SHIFT(24),
+  [374] = {.entry = {.count = 1, .reusable = false}}, SHIFT(302),
+  [376] = {.entry = {.count = 1, .reusable = false}}, SHIFT(300),
+  [378] = {.entry = {.count = 1, .reusable = false}}, SHIFT(289),
+  [380] = {.entry = {.count = 1, .reusable = false}}, SHIFT(296),
+  [382] = {.entry = {.count = 1, .reusable = true}}, SHIFT(380),
+  [384] = {.entry = {.count = 1, .reusable = true}}, SHIFT(153),
+  [386] = {.entry = {.count = 1, .reusable = false}}, SHIFT(622),
+  [388] = {.entry = {.count = 1, .reusable = true}}, SHIFT(652),
+  [390] = {.entry = {.count = 1, .reusable = true}}, SHIFT(627),
+  [392] = {.entry = {.count = 1, .reusable = false}}, SHIFT(381),
+  [394] = {.entry = {.count = 1, .reusable = true}}, SHIFT(358),
+  [396] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__selector, 1, 0, 1),
+  [398] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__value, 1, 0, 5),
+  [400] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__selector, 1, 0, 1),
+  [402] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
+  [404] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__value, 1, 0, 5),
+  [406] = {.entry = {.count = 1, .reusable = true}}, SHIFT(525),
+  [408] = {.entry = {.count = 1, .reusable = true}}, SHIFT(522),
+  [410] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__concatenated_identifier, 1, 0, 0),
+  [412] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__concatenated_identifier, 1, 0, 0),
+  [414] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__concatenated_identifier, 2, 0, 0),
+  [416] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__concatenated_identifier, 2, 0, 0),
+  [418] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__selector, 1, 0, 0),
+  [420] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__value, 1, 0, 0),
+  [422] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__selector, 1, 0, 0),
+  [424] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__value, 1, 0, 0),
+  [426] = {.entry = {.count = 1, .reusable = false}}, SHIFT(329),
+  [428] = {.entry = {.count = 1, .reusable = true}}, SHIFT(155),
+  [430] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__concatenated_identifier_repeat1, 2, 0, 0),
+  [432] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym__concatenated_identifier_repeat1, 2, 0, 0),
+  [434] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__concatenated_identifier_repeat1, 2, 0, 0), SHIFT_REPEAT(525),
+  [437] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
+  [439] = {.entry = {.count = 1, .reusable = true}}, SHIFT(511),
+  [441] = {.entry = {.count = 1, .reusable = true}}, SHIFT(547),
+  [443] = {.entry = {.count = 1, .reusable = false}}, SHIFT(92),
+  [445] = {.entry = {.count = 1, .reusable = true}}, SHIFT(239),
+  [447] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_last_declaration, 4, 0, 82),
+  [449] = {.entry = {.count = 1, .reusable = true}}, SHIFT(593),
+  [451] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 69), SHIFT_REPEAT(119),
+  [454] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 69),
+  [456] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 69), SHIFT_REPEAT(87),
+  [459] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 69), SHIFT_REPEAT(618),
+  [462] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 69), SHIFT_REPEAT(619),
+  [465] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 69), SHIFT_REPEAT(152),
+  [468] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 69), SHIFT_REPEAT(53),
+  [471] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 69), SHIFT_REPEAT(57),
+  [474] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 69), SHIFT_REPEAT(27),
+  [477] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 69), SHIFT_REPEAT(37),
+  [480] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 69), SHIFT_REPEAT(157),
+  [483] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 69), SHIFT_REPEAT(620),
+  [486] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 69), SHIFT_REPEAT(666),
+  [489] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 69), SHIFT_REPEAT(621),
+  [492] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 69), SHIFT_REPEAT(87),
+  [495] = {.entry = {.count = 1, .reusable = true}}, SHIFT(113),
+  [497] = {.entry = {.count = 1, .reusable = true}}, SHIFT(101),
+  [499] = {.entry = {.count = 1, .reusable = true}}, SHIFT(95),
+  [501] = {.entry = {.count = 1, .reusable = true}}, SHIFT(245),
+  [503] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_last_declaration, 5, 0, 96),
+  [505] = {.entry = {.count = 1, .reusable = true}}, SHIFT(574),
+  [507] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binary_expression, 3, 0, 42),
+  [509] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_binary_expression, 3, 0, 42),
+  [511] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binary_expression, 3, 0, 43),
+  [513] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_binary_expression, 3, 0, 43),
+  [515] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interpolation, 3, 0, 16),
+  [517] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interpolation, 3, 0, 16),
+  [519] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__concatenated_identifier_repeat1, 2, 0, 0), SHIFT_REPEAT(522),
+  [522] = {.entry = {.count = 1, .reusable = true}}, SHIFT(308),
+  [524] = {.entry = {.count = 1, .reusable = true}}, SHIFT(93),
+  [526] = {.entry = {.count = 1, .reusable = true}}, SHIFT(506),
+  [528] = {.entry = {.count = 1, .reusable = true}}, SHIFT(291),
+  [530] = {.entry = {.count = 1, .reusable = true}}, SHIFT(84),
+  [532] = {.entry = {.count = 1, .reusable = true}}, SHIFT(674),
+  [534] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 2, 0, 0),
+  [536] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 2, 0, 0), SHIFT_REPEAT(101),
+  [539] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 2, 0, 0), SHIFT_REPEAT(618),
+  [542] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 2, 0, 0), SHIFT_REPEAT(619),
+  [545] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 2, 0, 0), SHIFT_REPEAT(152),
+  [548] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 2, 0, 0), SHIFT_REPEAT(53),
+  [551] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 2, 0, 0), SHIFT_REPEAT(57),
+  [554] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 2, 0, 0), SHIFT_REPEAT(27),
+  [557] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 2, 0, 0), SHIFT_REPEAT(37),
+  [560] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 2, 0, 0), SHIFT_REPEAT(157),
+  [563] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 2, 0, 0), SHIFT_REPEAT(620),
+  [566] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 2, 0, 0), SHIFT_REPEAT(666),
+  [569] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 2, 0, 0), SHIFT_REPEAT(621),
+  [572] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 2, 0, 0), SHIFT_REPEAT(101),
+  [575] = {.entry = {.count = 1, .reusable = true}}, SHIFT(189),
+  [577] = {.entry = {.count = 1, .reusable = true}}, SHIFT(635),
+  [579] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_arguments_repeat1, 2, 0, 67),
+  [581] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_integer_value, 1, 0, 0),
+  [583] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_integer_value, 1, 0, 0),
+  [585] = {.entry = {.count = 1, .reusable = false}}, SHIFT(71),
+  [587] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_float_value, 1, 0, 0),
+  [589] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_float_value, 1, 0, 0),
+  [591] = {.entry = {.count = 1, .reusable = false}}, SHIFT(72),
+  [593] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
+  [595] = {.entry = {.count = 1, .reusable = true}}, SHIFT(396),
+  [597] = {.entry = {.count = 1, .reusable = true}}, SHIFT(397),
+  [599] = {.entry = {.count = 1, .reusable = true}}, SHIFT(321),
+  [601] = {.entry = {.count = 1, .reusable = true}}, SHIFT(591),
+  [603] = {.entry = {.count = 1, .reusable = true}}, SHIFT(145),
+  [605] = {.entry = {.count = 1, .reusable = false}}, SHIFT(272),
+  [607] = {.entry = {.count = 1, .reusable = false}}, SHIFT(356),
+  [609] = {.entry = {.count = 1, .reusable = true}}, SHIFT(387),
+  [611] = {.entry = {.count = 1, .reusable = true}}, SHIFT(600),
+  [613] = {.entry = {.count = 1, .reusable = true}}, SHIFT(390),
+  [615] = {.entry = {.count = 1, .reusable = true}}, SHIFT(201),
+  [617] = {.entry = {.count = 1, .reusable = true}}, SHIFT(662),
+  [619] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list_value, 4, 0, 68),
+  [621] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_list_value, 4, 0, 68),
+  [623] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 67),
+  [625] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 67),
+  [627] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_color_value, 2, 0, 0),
+  [629] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_color_value, 2, 0, 0),
+  [631] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_escaped_value, 2, 0, 16),
+  [633] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_escaped_value, 2, 0, 16),
+  [635] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_integer_value, 2, 0, 0),
+  [637] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_integer_value, 2, 0, 0),
+  [639] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_float_value, 2, 0, 0),
+  [641] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_float_value, 2, 0, 0),
+  [643] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_call_expression, 2, 0, 17),
+  [645] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_call_expression, 2, 0, 17),
+  [647] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_value, 2, 0, 18),
+  [649] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_property_value, 2, 0, 18),
+  [651] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_value_value, 2, 0, 18),
+  [653] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_value_value, 2, 0, 18),
+  [655] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesized_value, 3, 0, 16),
+  [657] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parenthesized_value, 3, 0, 16),
+  [659] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arguments, 2, 0, 0),
+  [661] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arguments, 2, 0, 0),
+  [663] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_value, 3, 0, 18),
+  [665] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_property_value, 3, 0, 18),
+  [667] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_parameter, 1, 0, 40), REDUCE(aux_sym_pseudo_class_arguments_repeat1, 1, 0, 0),
+  [670] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 1, 0, 0),
+  [672] = {.entry = {.count = 1, .reusable = true}}, SHIFT(139),
+  [674] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 1, 0, 0),
+  [676] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arguments, 3, 0, 67),
+  [678] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arguments, 3, 0, 67),
+  [680] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arguments, 4, 0, 68),
+  [682] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arguments, 4, 0, 68),
+  [684] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__concatenated_identifier_repeat1, 2, 0, 0), SHIFT_REPEAT(506),
+  [687] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_declaration_repeat1, 1, 0, 55),
+  [689] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_declaration_repeat1, 1, 0, 55),
+  [691] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_pseudo_class_arguments_repeat2, 2, 0, 77),
+  [693] = {.entry = {.count = 1, .reusable = false}}, SHIFT(107),
+  [695] = {.entry = {.count = 1, .reusable = true}}, SHIFT(558),
+  [697] = {.entry = {.count = 1, .reusable = true}}, SHIFT(115),
+  [699] = {.entry = {.count = 1, .reusable = true}}, SHIFT(485),
+  [701] = {.entry = {.count = 1, .reusable = false}}, SHIFT(94),
+  [703] = {.entry = {.count = 1, .reusable = true}}, SHIFT(323),
+  [705] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 1, 0, 0),
+  [707] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 1, 0, 0),
+  [709] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym__selector, 1, 0, 0), REDUCE(aux_sym_block_repeat1, 1, 0, 0),
+  [712] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym__selector, 1, 0, 0), REDUCE(aux_sym_block_repeat1, 1, 0, 0),
+  [715] = {.entry = {.count = 1, .reusable = true}}, SHIFT(285),
+  [717] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
+  [719] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
+  [721] = {.entry = {.count = 1, .reusable = false}}, SHIFT(18),
+  [723] = {.entry = {.count = 1, .reusable = true}}, SHIFT(268),
+  [725] = {.entry = {.count = 1, .reusable = true}}, SHIFT(154),
+  [727] = {.entry = {.count = 1, .reusable = false}}, SHIFT(259),
+  [729] = {.entry = {.count = 1, .reusable = true}}, SHIFT(161),
+  [731] = {.entry = {.count = 1, .reusable = false}}, SHIFT(268),
+  [733] = {.entry = {.count = 1, .reusable = true}}, SHIFT(68),
+  [735] = {.entry = {.count = 1, .reusable = false}}, SHIFT(68),
+  [737] = {.entry = {.count = 1, .reusable = true}}, SHIFT(269),
+  [739] = {.entry = {.count = 1, .reusable = false}}, SHIFT(269),
+  [741] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
+  [743] = {.entry = {.count = 1, .reusable = false}}, SHIFT(20),
+  [745] = {.entry = {.count = 1, .reusable = true}}, SHIFT(74),
+  [747] = {.entry = {.count = 1, .reusable = true}}, SHIFT(671),
+  [749] = {.entry = {.count = 1, .reusable = true}}, SHIFT(633),
+  [751] = {.entry = {.count = 1, .reusable = true}}, SHIFT(130),
+  [753] = {.entry = {.count = 1, .reusable = false}}, SHIFT(97),
+  [755] = {.entry = {.count = 1, .reusable = false}}, SHIFT(90),
+  [757] = {.entry = {.count = 1, .reusable = false}}, SHIFT(46),
+  [759] = {.entry = {.count = 1, .reusable = false}}, SHIFT(67),
+  [761] = {.entry = {.count = 1, .reusable = true}}, SHIFT(144),
+  [763] = {.entry = {.count = 1, .reusable = false}}, SHIFT(624),
+  [765] = {.entry = {.count = 1, .reusable = true}}, SHIFT(678),
+  [767] = {.entry = {.count = 1, .reusable = true}}, SHIFT(628),
+  [769] = {.entry = {.count = 1, .reusable = false}}, SHIFT(74),
+  [771] = {.entry = {.count = 1, .reusable = true}}, SHIFT(38),
+  [773] = {.entry = {.count = 1, .reusable = false}}, SHIFT(38),
+  [775] = {.entry = {.count = 1, .reusable = true}}, SHIFT(382),
+  [777] = {.entry = {.count = 1, .reusable = false}}, SHIFT(382),
+  [779] = {.entry = {.count = 1, .reusable = true}}, SHIFT(451),
+  [781] = {.entry = {.count = 1, .reusable = false}}, SHIFT(451),
+  [783] = {.entry = {.count = 1, .reusable = true}}, SHIFT(21),
+  [785] = {.entry = {.count = 1, .reusable = false}}, SHIFT(21),
+  [787] = {.entry = {.count = 1, .reusable = true}}, SHIFT(39),
+  [789] = {.entry = {.count = 1, .reusable = false}}, SHIFT(39),
+  [791] = {.entry = {.count = 1, .reusable = true}}, SHIFT(226),
+  [793] = {.entry = {.count = 1, .reusable = false}}, SHIFT(118),
+  [795] = {.entry = {.count = 1, .reusable = true}}, SHIFT(357),
+  [797] = {.entry = {.count = 1, .reusable = true}}, SHIFT(336),
+  [799] = {.entry = {.count = 1, .reusable = false}}, SHIFT(120),
+  [801] = {.entry = {.count = 1, .reusable = true}}, SHIFT(120),
+  [803] = {.entry = {.count = 1, .reusable = false}}, SHIFT(487),
+  [805] = {.entry = {.count = 1, .reusable = true}}, SHIFT(496),
+  [807] = {.entry = {.count = 1, .reusable = true}}, SHIFT(151),
+  [809] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
+  [811] = {.entry = {.count = 1, .reusable = false}}, SHIFT(11),
+  [813] = {.entry = {.count = 1, .reusable = true}}, SHIFT(325),
+  [815] = {.entry = {.count = 1, .reusable = false}}, SHIFT(325),
+  [817] = {.entry = {.count = 1, .reusable = true}}, SHIFT(427),
+  [819] = {.entry = {.count = 1, .reusable = false}}, SHIFT(427),
+  [821] = {.entry = {.count = 1, .reusable = true}}, SHIFT(17),
+  [823] = {.entry = {.count = 1, .reusable = false}}, SHIFT(17),
+  [825] = {.entry = {.count = 1, .reusable = true}}, SHIFT(372),
+  [827] = {.entry = {.count = 1, .reusable = false}}, SHIFT(372),
+  [829] = {.entry = {.count = 1, .reusable = true}}, SHIFT(75),
+  [831] = {.entry = {.count = 1, .reusable = false}}, SHIFT(75),
+  [833] = {.entry = {.count = 1, .reusable = true}}, SHIFT(386),
+  [835] = {.entry = {.count = 1, .reusable = false}}, SHIFT(386),
+  [837] = {.entry = {.count = 1, .reusable = true}}, SHIFT(407),
+  [839] = {.entry = {.count = 1, .reusable = false}}, SHIFT(407),
+  [841] = {.entry = {.count = 1, .reusable = true}}, SHIFT(294),
+  [843] = {.entry = {.count = 1, .reusable = false}}, SHIFT(294),
+  [845] = {.entry = {.count = 1, .reusable = true}}, SHIFT(297),
+  [847] = {.entry = {.count = 1, .reusable = false}}, SHIFT(297),
+  [849] = {.entry = {.count = 1, .reusable = true}}, SHIFT(347),
+  [851] = {.entry = {.count = 1, .reusable = false}}, SHIFT(347),
+  [853] = {.entry = {.count = 1, .reusable = true}}, SHIFT(173),
+  [855] = {.entry = {.count = 1, .reusable = true}}, SHIFT(498),
+  [857] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
+  [859] = {.entry = {.count = 1, .reusable = false}}, SHIFT(12),
+  [861] = {.entry = {.count = 1, .reusable = true}}, SHIFT(428),
+  [863] = {.entry = {.count = 1, .reusable = false}}, SHIFT(428),
+  [865] = {.entry = {.count = 1, .reusable = true}}, SHIFT(429),
+  [867] = {.entry = {.count = 1, .reusable = false}}, SHIFT(429),
+  [869] = {.entry = {.count = 1, .reusable = true}}, SHIFT(430),
+  [871] = {.entry = {.count = 1, .reusable = false}}, SHIFT(430),
+  [873] = {.entry = {.count = 1, .reusable = false}}, SHIFT(354),
+  [875] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
+  [877] = {.entry = {.count = 1, .reusable = false}}, SHIFT(22),
+  [879] = {.entry = {.count = 1, .reusable = true}}, SHIFT(420),
+  [881] = {.entry = {.count = 1, .reusable = false}}, SHIFT(420),
+  [883] = {.entry = {.count = 1, .reusable = true}}, SHIFT(436),
+  [885] = {.entry = {.count = 1, .reusable = false}}, SHIFT(436),
+  [887] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
+  [889] = {.entry = {.count = 1, .reusable = false}}, SHIFT(23),
+  [891] = {.entry = {.count = 1, .reusable = true}}, SHIFT(437),
+  [893] = {.entry = {.count = 1, .reusable = false}}, SHIFT(437),
+  [895] = {.entry = {.count = 1, .reusable = true}}, SHIFT(438),
+  [897] = {.entry = {.count = 1, .reusable = false}}, SHIFT(438),
+  [899] = {.entry = {.count = 1, .reusable = true}}, SHIFT(360),
+  [901] = {.entry = {.count = 1, .reusable = false}}, SHIFT(360),
+  [903] = {.entry = {.count = 1, .reusable = true}}, SHIFT(439),
+  [905] = {.entry = {.count = 1, .reusable = false}}, SHIFT(439),
+  [907] = {.entry = {.count = 1, .reusable = false}}, SHIFT(358),
+  [909] = {.entry = {.count = 1, .reusable = true}}, SHIFT(440),
+  [911] = {.entry = {.count = 1, .reusable = false}}, SHIFT(440),
+  [913] = {.entry = {.count = 1, .reusable = true}}, SHIFT(441),
+  [915] = {.entry = {.count = 1, .reusable = false}}, SHIFT(441),
+  [917] = {.entry = {.count = 1, .reusable = true}}, SHIFT(442),
+  [919] = {.entry = {.count = 1, .reusable = false}}, SHIFT(442),
+  [921] = {.entry = {.count = 1, .reusable = true}}, SHIFT(443),
+  [923] = {.entry = {.count = 1, .reusable = false}}, SHIFT(443),
+  [925] = {.entry = {.count = 1, .reusable = true}}, SHIFT(444),
+  [927] = {.entry = {.count = 1, .reusable = false}}, SHIFT(444),
+  [929] = {.entry = {.count = 1, .reusable = true}}, SHIFT(445),
+  [931] = {.entry = {.count = 1, .reusable = false}}, SHIFT(445),
+  [933] = {.entry = {.count = 1, .reusable = true}}, SHIFT(446),
+  [935] = {.entry = {.count = 1, .reusable = false}}, SHIFT(446),
+  [937] = {.entry = {.count = 1, .reusable = true}}, SHIFT(460),
+  [939] = {.entry = {.count = 1, .reusable = false}}, SHIFT(460),
+  [941] = {.entry = {.count = 1, .reusable = true}}, SHIFT(406),
+  [943] = {.entry = {.count = 1, .reusable = false}}, SHIFT(406),
+  [945] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__query, 1, 0, 6),
+  [947] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
+  [949] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__query, 1, 0, 6),
+  [951] = {.entry = {.count = 1, .reusable = true}}, SHIFT(521),
+  [953] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 3, 0, 58),
+  [955] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block, 3, 0, 58),
+  [957] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 2, 0, 0),
+  [959] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block, 2, 0, 0),
+  [961] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 3, 0, 56),
+  [963] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block, 3, 0, 56),
+  [965] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 4, 0, 86),
+  [967] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block, 4, 0, 86),
+  [969] = {.entry = {.count = 1, .reusable = true}}, SHIFT(532),
+  [971] = {.entry = {.count = 1, .reusable = true}}, SHIFT(658),
+  [973] = {.entry = {.count = 1, .reusable = true}}, SHIFT(453),
+  [975] = {.entry = {.count = 1, .reusable = true}}, SHIFT(367),
+  [977] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_plugin_statement, 3, 0, 16),
+  [979] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_plugin_statement, 3, 0, 16),
+  [981] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_mixin_definition, 3, 0, 41),
+  [983] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_mixin_definition, 3, 0, 41),
+  [985] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_statement, 4, 0, 44),
+  [987] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_import_statement, 4, 0, 44),
+  [989] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_statement, 4, 0, 45),
+  [991] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_import_statement, 4, 0, 45),
+  [993] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_media_statement, 4, 0, 49),
+  [995] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_media_statement, 4, 0, 49),
+  [997] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_namespace_statement, 4, 0, 51),
+  [999] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_namespace_statement, 4, 0, 51),
+  [1001] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_keyframe_block_list, 2, 0, 0),
+  [1003] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_keyframe_block_list, 2, 0, 0),
+  [1005] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 4, 0, 54),
+  [1007] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_declaration, 4, 0, 54),
+  [1009] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_mixin_statement, 2, 0, 57),
+  [1011] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_mixin_statement, 2, 0, 57),
+  [1013] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_at_rule, 4, 0, 59),
+  [1015] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_at_rule, 4, 0, 59),
+  [1017] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_at_rule, 4, 0, 60),
+  [1019] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_at_rule, 4, 0, 60),
+  [1021] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_at_rule, 2, 0, 11),
+  [1023] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_at_rule, 2, 0, 11),
+  [1025] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_mixin_definition, 4, 0, 66),
+  [1027] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_mixin_definition, 4, 0, 66),
+  [1029] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_at_rule, 2, 0, 12),
+  [1031] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_at_rule, 2, 0, 12),
+  [1033] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_statement, 5, 0, 71),
+  [1035] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_import_statement, 5, 0, 71),
+  [1037] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_statement, 5, 0, 73),
+  [1039] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_import_statement, 5, 0, 73),
+  [1041] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_keyframe_block_list, 3, 0, 76),
+  [1043] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_keyframe_block_list, 3, 0, 76),
+  [1045] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_rule_set, 2, 0, 13),
+  [1047] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_rule_set, 2, 0, 13),
+  [1049] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 5, 0, 81),
+  [1051] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_declaration, 5, 0, 81),
+  [1053] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 5, 0, 82),
+  [1055] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_declaration, 5, 0, 82),
+  [1057] = {.entry = {.count = 1, .reusable = true}}, SHIFT(464),
+  [1059] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 5, 0, 83),
+  [1061] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_declaration, 5, 0, 83),
+  [1063] = {.entry = {.count = 1, .reusable = true}}, SHIFT(466),
+  [1065] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_mixin_statement, 3, 0, 84),
+  [1067] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_mixin_statement, 3, 0, 84),
+  [1069] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_mixin_statement, 3, 0, 85),
+  [1071] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_mixin_statement, 3, 0, 85),
+  [1073] = {.entry = {.count = 1, .reusable = true}}, SHIFT(458),
+  [1075] = {.entry = {.count = 1, .reusable = true}}, SHIFT(459),
+  [1077] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_statement, 6, 0, 90),
+  [1079] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_import_statement, 6, 0, 90),
+  [1081] = {.entry = {.count = 1, .reusable = true}}, SHIFT(419),
+  [1083] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 6, 0, 94),
+  [1085] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_declaration, 6, 0, 94),
+  [1087] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 6, 0, 95),
+  [1089] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_declaration, 6, 0, 95),
+  [1091] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 6, 0, 96),
+  [1093] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_declaration, 6, 0, 96),
+  [1095] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extend_statement, 4, 0, 97),
+  [1097] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extend_statement, 4, 0, 97),
+  [1099] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_mixin_statement, 4, 0, 98),
+  [1101] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_mixin_statement, 4, 0, 98),
+  [1103] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 7, 0, 101),
+  [1105] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_declaration, 7, 0, 101),
+  [1107] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_statement, 7, 0, 103),
+  [1109] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_statement, 7, 0, 103),
+  [1111] = {.entry = {.count = 1, .reusable = true}}, SHIFT(513),
+  [1113] = {.entry = {.count = 1, .reusable = true}}, SHIFT(677),
+  [1115] = {.entry = {.count = 1, .reusable = true}}, SHIFT(433),
+  [1117] = {.entry = {.count = 1, .reusable = true}}, SHIFT(366),
+  [1119] = {.entry = {.count = 1, .reusable = true}}, SHIFT(160),
+  [1121] = {.entry = {.count = 1, .reusable = true}}, SHIFT(383),
+  [1123] = {.entry = {.count = 1, .reusable = true}}, SHIFT(384),
+  [1125] = {.entry = {.count = 1, .reusable = true}}, SHIFT(385),
+  [1127] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_import_statement, 3, 0, 16),
+  [1129] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_statement, 3, 0, 16),
+  [1131] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_media_statement, 3, 0, 20),
+  [1133] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_media_statement, 3, 0, 20),
+  [1135] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_charset_statement, 3, 0, 21),
+  [1137] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_charset_statement, 3, 0, 21),
+  [1139] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_namespace_statement, 3, 0, 16),
+  [1141] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_namespace_statement, 3, 0, 16),
+  [1143] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_keyframes_statement, 3, 0, 22),
+  [1145] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_keyframes_statement, 3, 0, 22),
+  [1147] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_supports_statement, 3, 0, 23),
+  [1149] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_supports_statement, 3, 0, 23),
+  [1151] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_at_rule, 3, 0, 29),
+  [1153] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_at_rule, 3, 0, 29),
+  [1155] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_at_rule, 3, 0, 30),
+  [1157] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_at_rule, 3, 0, 30),
+  [1159] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_stylesheet_repeat1, 1, 0, 2),
+  [1161] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 1, 0, 2),
+  [1163] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__query, 1, 0, 0),
+  [1165] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__query, 1, 0, 0),
+  [1167] = {.entry = {.count = 1, .reusable = true}}, SHIFT(465),
+  [1169] = {.entry = {.count = 1, .reusable = true}}, SHIFT(206),
+  [1171] = {.entry = {.count = 1, .reusable = true}}, SHIFT(4),
+  [1173] = {.entry = {.count = 1, .reusable = true}}, SHIFT(146),
+  [1175] = {.entry = {.count = 1, .reusable = false}}, SHIFT(614),
+  [1177] = {.entry = {.count = 1, .reusable = true}}, SHIFT(470),
+  [1179] = {.entry = {.count = 1, .reusable = true}}, SHIFT(614),
+  [1181] = {.entry = {.count = 1, .reusable = true}}, SHIFT(181),
+  [1183] = {.entry = {.count = 1, .reusable = true}}, SHIFT(2),
+  [1185] = {.entry = {.count = 1, .reusable = true}}, SHIFT(121),
+  [1187] = {.entry = {.count = 1, .reusable = true}}, SHIFT(473),
+  [1189] = {.entry = {.count = 1, .reusable = true}}, SHIFT(132),
+  [1191] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__concatenated_identifier_repeat1, 2, 0, 0), SHIFT_REPEAT(521),
+  [1194] = {.entry = {.count = 1, .reusable = true}}, SHIFT(98),
+  [1196] = {.entry = {.count = 1, .reusable = true}}, SHIFT(515),
+  [1198] = {.entry = {.count = 1, .reusable = false}}, SHIFT(277),
+  [1200] = {.entry = {.count = 1, .reusable = false}}, SHIFT(280),
+  [1202] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
+  [1204] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__concatenated_identifier_repeat1, 2, 0, 0), SHIFT_REPEAT(515),
+  [1207] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_class_selector, 2, 0, 7),
+  [1209] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__mixin_name, 2, 0, 7),
+  [1211] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_class_selector, 2, 0, 7),
+  [1213] = {.entry = {.count = 1, .reusable = true}}, SHIFT(526),
+  [1215] = {.entry = {.count = 1, .reusable = false}}, SHIFT(132),
+  [1217] = {.entry = {.count = 1, .reusable = true}}, SHIFT(523),
+  [1219] = {.entry = {.count = 1, .reusable = true}}, SHIFT(313),
+  [1221] = {.entry = {.count = 1, .reusable = true}}, SHIFT(312),
+  [1223] = {.entry = {.count = 1, .reusable = false}}, SHIFT(146),
+  [1225] = {.entry = {.count = 1, .reusable = true}}, SHIFT(329),
+  [1227] = {.entry = {.count = 1, .reusable = true}}, SHIFT(353),
+  [1229] = {.entry = {.count = 1, .reusable = false}}, SHIFT(121),
+  [1231] = {.entry = {.count = 1, .reusable = true}}, SHIFT(503),
+  [1233] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__concatenated_identifier_repeat1, 2, 0, 0), SHIFT_REPEAT(523),
+  [1236] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameter, 1, 0, 40),
+  [1238] = {.entry = {.count = 1, .reusable = false}}, SHIFT(137),
+  [1240] = {.entry = {.count = 1, .reusable = true}}, SHIFT(138),
+  [1242] = {.entry = {.count = 1, .reusable = false}}, SHIFT(138),
+  [1244] = {.entry = {.count = 1, .reusable = true}}, SHIFT(133),
+  [1246] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
+  [1248] = {.entry = {.count = 1, .reusable = true}}, SHIFT(534),
+  [1250] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__concatenated_identifier_repeat1, 2, 0, 0), SHIFT_REPEAT(540),
+  [1253] = {.entry = {.count = 1, .reusable = true}}, SHIFT(480),
+  [1255] = {.entry = {.count = 1, .reusable = true}}, SHIFT(540),
+  [1257] = {.entry = {.count = 1, .reusable = true}}, SHIFT(502),
+  [1259] = {.entry = {.count = 1, .reusable = true}}, SHIFT(483),
+  [1261] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_element_selector, 2, 0, 9),
+  [1263] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_element_selector, 2, 0, 9),
+  [1265] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
+  [1267] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_class_selector, 3, 0, 33),
+  [1269] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_class_selector, 3, 0, 33),
+  [1271] = {.entry = {.count = 1, .reusable = false}}, SHIFT(531),
+  [1273] = {.entry = {.count = 1, .reusable = true}}, SHIFT(562),
+  [1275] = {.entry = {.count = 1, .reusable = true}}, SHIFT(15),
+  [1277] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_element_selector, 3, 0, 34),
+  [1279] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_element_selector, 3, 0, 34),
+  [1281] = {.entry = {.count = 1, .reusable = true}}, SHIFT(479),
+  [1283] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_times, 1, 0, 0),
+  [1285] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_times, 1, 0, 0),
+  [1287] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_class_selector, 2, 0, 7),
+  [1289] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_class_selector, 2, 0, 7),
+  [1291] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
+  [1293] = {.entry = {.count = 1, .reusable = true}}, SHIFT(527),
+  [1295] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameter, 3, 0, 87),
+  [1297] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_class_selector, 3, 0, 33),
+  [1299] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_class_selector, 3, 0, 33),
+  [1301] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__concatenated_identifier_repeat1, 2, 0, 0), SHIFT_REPEAT(526),
+  [1304] = {.entry = {.count = 1, .reusable = true}}, SHIFT(322),
+  [1306] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__concatenated_identifier_repeat1, 2, 0, 0), SHIFT_REPEAT(503),
+  [1309] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_plus, 1, 0, 0),
+  [1311] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_plus, 1, 0, 0),
+  [1313] = {.entry = {.count = 1, .reusable = true}}, SHIFT(284),
+  [1315] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
+  [1317] = {.entry = {.count = 1, .reusable = true}}, SHIFT(494),
+  [1319] = {.entry = {.count = 1, .reusable = true}}, SHIFT(484),
+  [1321] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
+  [1323] = {.entry = {.count = 1, .reusable = true}}, SHIFT(505),
+  [1325] = {.entry = {.count = 1, .reusable = true}}, SHIFT(190),
+  [1327] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_selectors, 1, 0, 3),
+  [1329] = {.entry = {.count = 1, .reusable = true}}, SHIFT(509),
+  [1331] = {.entry = {.count = 1, .reusable = false}}, SHIFT(644),
+  [1333] = {.entry = {.count = 1, .reusable = true}}, SHIFT(645),
+  [1335] = {.entry = {.count = 1, .reusable = true}}, SHIFT(651),
+  [1337] = {.entry = {.count = 1, .reusable = true}}, SHIFT(655),
+  [1339] = {.entry = {.count = 1, .reusable = true}}, SHIFT(210),
+  [1341] = {.entry = {.count = 1, .reusable = true}}, SHIFT(211),
+  [1343] = {.entry = {.count = 1, .reusable = true}}, SHIFT(212),
+  [1345] = {.entry = {.count = 1, .reusable = true}}, SHIFT(594),
+  [1347] = {.entry = {.count = 1, .reusable = true}}, SHIFT(209),
+  [1349] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_list_value_repeat1, 2, 0, 67),
+  [1351] = {.entry = {.count = 1, .reusable = true}}, SHIFT(395),
+  [1353] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_child_selector, 3, 0, 36),
+  [1355] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_child_selector, 3, 0, 36),
+  [1357] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_sibling_selector, 3, 0, 37),
+  [1359] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_sibling_selector, 3, 0, 37),
+  [1361] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_adjacent_sibling_selector, 3, 0, 38),
+  [1363] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_adjacent_sibling_selector, 3, 0, 38),
+  [1365] = {.entry = {.count = 1, .reusable = true}}, SHIFT(379),
+  [1367] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_class_arguments, 3, 0, 77),
+  [1369] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_class_arguments, 3, 0, 77),
+  [1371] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_class_arguments, 2, 0, 0),
+  [1373] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_class_arguments, 2, 0, 0),
+  [1375] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_element_arguments, 2, 0, 0),
+  [1377] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_element_arguments, 2, 0, 0),
+  [1379] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_element_arguments, 3, 0, 77),
+  [1381] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_element_arguments, 3, 0, 77),
+  [1383] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_id_selector, 2, 0, 10),
+  [1385] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_id_selector, 2, 0, 10),
+  [1387] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_class_selector, 4, 0, 62),
+  [1389] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_class_selector, 4, 0, 62),
+  [1391] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_element_selector, 4, 0, 63),
+  [1393] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_element_selector, 4, 0, 63),
+  [1395] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute_selector, 4, 0, 64),
+  [1397] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute_selector, 4, 0, 64),
+  [1399] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute_selector, 5, 0, 80),
+  [1401] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute_selector, 5, 0, 80),
+  [1403] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_class_arguments, 4, 0, 92),
+  [1405] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_class_arguments, 4, 0, 92),
+  [1407] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_element_arguments, 4, 0, 92),
+  [1409] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_element_arguments, 4, 0, 92),
+  [1411] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute_selector, 6, 0, 99),
+  [1413] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute_selector, 6, 0, 99),
+  [1415] = {.entry = {.count = 1, .reusable = true}}, SHIFT(504),
+  [1417] = {.entry = {.count = 1, .reusable = false}}, SHIFT(670),
+  [1419] = {.entry = {.count = 1, .reusable = true}}, SHIFT(673),
+  [1421] = {.entry = {.count = 1, .reusable = true}}, SHIFT(668),
+  [1423] = {.entry = {.count = 1, .reusable = true}}, SHIFT(623),
+  [1425] = {.entry = {.count = 1, .reusable = true}}, SHIFT(195),
+  [1427] = {.entry = {.count = 1, .reusable = true}}, SHIFT(196),
+  [1429] = {.entry = {.count = 1, .reusable = true}}, SHIFT(198),
+  [1431] = {.entry = {.count = 1, .reusable = true}}, SHIFT(169),
+  [1433] = {.entry = {.count = 1, .reusable = true}}, SHIFT(418),
+  [1435] = {.entry = {.count = 1, .reusable = true}}, SHIFT(650),
+  [1437] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_class_selector, 3, 0, 24),
+  [1439] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_class_selector, 3, 0, 24),
+  [1441] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_element_selector, 3, 0, 25),
+  [1443] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_element_selector, 3, 0, 25),
+  [1445] = {.entry = {.count = 1, .reusable = true}}, SHIFT(252),
+  [1447] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute_selector, 3, 0, 26),
+  [1449] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute_selector, 3, 0, 26),
+  [1451] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_id_selector, 3, 0, 35),
+  [1453] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_id_selector, 3, 0, 35),
+  [1455] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_id_selector, 2, 0, 10), SHIFT(585),
+  [1458] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_id_selector, 2, 0, 10), SHIFT(638),
+  [1461] = {.entry = {.count = 1, .reusable = true}}, SHIFT(170),
+  [1463] = {.entry = {.count = 1, .reusable = true}}, SHIFT(215),
+  [1465] = {.entry = {.count = 1, .reusable = true}}, SHIFT(222),
+  [1467] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
+  [1469] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_universal_selector, 1, 0, 0),
+  [1471] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_universal_selector, 1, 0, 0),
+  [1473] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_descendant_selector, 3, 0, 31),
+  [1475] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_descendant_selector, 3, 0, 31),
+  [1477] = {.entry = {.count = 1, .reusable = true}}, SHIFT(398),
+  [1479] = {.entry = {.count = 1, .reusable = true}}, SHIFT(403),
+  [1481] = {.entry = {.count = 1, .reusable = true}}, SHIFT(486),
+  [1483] = {.entry = {.count = 1, .reusable = true}}, SHIFT(288),
+  [1485] = {.entry = {.count = 1, .reusable = true}}, SHIFT(375),
+  [1487] = {.entry = {.count = 1, .reusable = true}}, SHIFT(260),
+  [1489] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
+  [1491] = {.entry = {.count = 1, .reusable = true}}, SHIFT(348),
+  [1493] = {.entry = {.count = 1, .reusable = true}}, SHIFT(43),
+  [1495] = {.entry = {.count = 1, .reusable = true}}, SHIFT(377),
+  [1497] = {.entry = {.count = 1, .reusable = true}}, SHIFT(275),
+  [1499] = {.entry = {.count = 1, .reusable = true}}, SHIFT(584),
+  [1501] = {.entry = {.count = 1, .reusable = true}}, SHIFT(597),
+  [1503] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_selectors_repeat1, 2, 0, 32),
+  [1505] = {.entry = {.count = 1, .reusable = true}}, SHIFT(482),
+  [1507] = {.entry = {.count = 1, .reusable = true}}, SHIFT(680),
+  [1509] = {.entry = {.count = 1, .reusable = true}}, SHIFT(176),
+  [1511] = {.entry = {.count = 1, .reusable = true}}, SHIFT(589),
+  [1513] = {.entry = {.count = 1, .reusable = true}}, SHIFT(576),
+  [1515] = {.entry = {.count = 1, .reusable = true}}, SHIFT(148),
+  [1517] = {.entry = {.count = 1, .reusable = true}}, SHIFT(421),
+  [1519] = {.entry = {.count = 1, .reusable = true}}, SHIFT(124),
+  [1521] = {.entry = {.count = 1, .reusable = true}}, SHIFT(432),
+  [1523] = {.entry = {.count = 1, .reusable = true}}, SHIFT(361),
+  [1525] = {.entry = {.count = 1, .reusable = true}}, SHIFT(220),
+  [1527] = {.entry = {.count = 1, .reusable = true}}, SHIFT(331),
+  [1529] = {.entry = {.count = 1, .reusable = true}}, SHIFT(186),
+  [1531] = {.entry = {.count = 1, .reusable = true}}, SHIFT(229),
+  [1533] = {.entry = {.count = 1, .reusable = true}}, SHIFT(256),
+  [1535] = {.entry = {.count = 1, .reusable = true}}, SHIFT(163),
+  [1537] = {.entry = {.count = 1, .reusable = true}}, SHIFT(450),
+  [1539] = {.entry = {.count = 1, .reusable = true}}, SHIFT(150),
+  [1541] = {.entry = {.count = 1, .reusable = true}}, SHIFT(394),
+  [1543] = {.entry = {.count = 1, .reusable = true}}, SHIFT(237),
+  [1545] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_keyframe_block_list_repeat1, 2, 0, 0),
+  [1547] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_keyframe_block_list_repeat1, 2, 0, 0), SHIFT_REPEAT(589),
+  [1550] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_keyframe_block_list_repeat1, 2, 0, 0), SHIFT_REPEAT(576),
+  [1553] = {.entry = {.count = 1, .reusable = true}}, SHIFT(667),
+  [1555] = {.entry = {.count = 1, .reusable = false}}, SHIFT(586),
+  [1557] = {.entry = {.count = 1, .reusable = true}}, SHIFT(330),
+  [1559] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unary_query, 2, 0, 19),
+  [1561] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesized_query, 3, 0, 46),
+  [1563] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_selector_query, 4, 0, 74),
+  [1565] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binary_query, 3, 0, 48),
+  [1567] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_feature_query, 5, 0, 91),
+  [1569] = {.entry = {.count = 1, .reusable = true}}, SHIFT(637),
+  [1571] = {.entry = {.count = 1, .reusable = true}}, SHIFT(326),
+  [1573] = {.entry = {.count = 1, .reusable = true}}, SHIFT(172),
+  [1575] = {.entry = {.count = 1, .reusable = true}}, SHIFT(225),
+  [1577] = {.entry = {.count = 1, .reusable = true}}, SHIFT(295),
+  [1579] = {.entry = {.count = 1, .reusable = true}}, SHIFT(560),
+  [1581] = {.entry = {.count = 1, .reusable = true}}, SHIFT(332),
+  [1583] = {.entry = {.count = 1, .reusable = true}}, SHIFT(233),
+  [1585] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_at_rule_repeat1, 2, 0, 46),
+  [1587] = {.entry = {.count = 1, .reusable = true}}, SHIFT(179),
+  [1589] = {.entry = {.count = 1, .reusable = true}}, SHIFT(236),
+  [1591] = {.entry = {.count = 1, .reusable = true}}, SHIFT(178),
+  [1593] = {.entry = {.count = 1, .reusable = true}}, SHIFT(32),
+  [1595] = {.entry = {.count = 1, .reusable = true}}, SHIFT(669),
+  [1597] = {.entry = {.count = 1, .reusable = true}}, SHIFT(185),
+  [1599] = {.entry = {.count = 1, .reusable = true}}, SHIFT(88),
+  [1601] = {.entry = {.count = 1, .reusable = true}}, SHIFT(592),
+  [1603] = {.entry = {.count = 1, .reusable = true}}, SHIFT(606),
+  [1605] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_media_statement_repeat1, 2, 0, 47),
+  [1607] = {.entry = {.count = 1, .reusable = true}}, SHIFT(378),
+  [1609] = {.entry = {.count = 1, .reusable = false}}, SHIFT(378),
+  [1611] = {.entry = {.count = 1, .reusable = true}}, SHIFT(368),
+  [1613] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
+  [1615] = {.entry = {.count = 1, .reusable = false}}, SHIFT(104),
+  [1617] = {.entry = {.count = 1, .reusable = true}}, SHIFT(587),
+  [1619] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_keyframe_block, 2, 0, 75),
+  [1621] = {.entry = {.count = 1, .reusable = true}}, SHIFT(335),
+  [1623] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameters, 2, 0, 0),
+  [1625] = {.entry = {.count = 1, .reusable = true}}, SHIFT(314),
+  [1627] = {.entry = {.count = 1, .reusable = true}}, SHIFT(301),
+  [1629] = {.entry = {.count = 1, .reusable = false}}, SHIFT(301),
+  [1631] = {.entry = {.count = 1, .reusable = true}}, SHIFT(100),
+  [1633] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_at_rule_repeat1, 2, 0, 61), SHIFT_REPEAT(361),
+  [1636] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_at_rule_repeat1, 2, 0, 61),
+  [1638] = {.entry = {.count = 1, .reusable = true}}, SHIFT(287),
+  [1640] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
+  [1642] = {.entry = {.count = 1, .reusable = true}}, SHIFT(274),
+  [1644] = {.entry = {.count = 1, .reusable = false}}, SHIFT(274),
+  [1646] = {.entry = {.count = 1, .reusable = true}}, SHIFT(55),
+  [1648] = {.entry = {.count = 1, .reusable = false}}, SHIFT(55),
+  [1650] = {.entry = {.count = 1, .reusable = true}}, SHIFT(342),
+  [1652] = {.entry = {.count = 1, .reusable = false}}, SHIFT(342),
+  [1654] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_parameters_repeat1, 2, 0, 89), SHIFT_REPEAT(88),
+  [1657] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_parameters_repeat1, 2, 0, 89),
+  [1659] = {.entry = {.count = 1, .reusable = true}}, SHIFT(42),
+  [1661] = {.entry = {.count = 1, .reusable = false}}, SHIFT(42),
+  [1663] = {.entry = {.count = 1, .reusable = true}}, SHIFT(376),
+  [1665] = {.entry = {.count = 1, .reusable = false}}, SHIFT(376),
+  [1667] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_arguments_repeat1, 2, 0, 69), SHIFT_REPEAT(113),
+  [1670] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_arguments_repeat1, 2, 0, 69),
+  [1672] = {.entry = {.count = 1, .reusable = true}}, SHIFT(632),
+  [1674] = {.entry = {.count = 1, .reusable = false}}, SHIFT(478),
+  [1676] = {.entry = {.count = 1, .reusable = true}}, SHIFT(654),
+  [1678] = {.entry = {.count = 1, .reusable = false}}, SHIFT(488),
+  [1680] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_import_statement_repeat1, 2, 0, 70),
+  [1682] = {.entry = {.count = 1, .reusable = true}}, SHIFT(374),
+  [1684] = {.entry = {.count = 1, .reusable = false}}, SHIFT(374),
+  [1686] = {.entry = {.count = 1, .reusable = true}}, SHIFT(112),
+  [1688] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__mixin_name, 4, 0, 53),
+  [1690] = {.entry = {.count = 1, .reusable = true}}, SHIFT(307),
+  [1692] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_parameters_repeat1, 2, 0, 65),
+  [1694] = {.entry = {.count = 1, .reusable = true}}, SHIFT(258),
+  [1696] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_import_statement_repeat1, 2, 0, 72), SHIFT_REPEAT(326),
+  [1699] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_import_statement_repeat1, 2, 0, 72),
+  [1701] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_rest_parameter, 1, 0, 0),
+  [1703] = {.entry = {.count = 1, .reusable = true}}, SHIFT(242),
+  [1705] = {.entry = {.count = 1, .reusable = true}}, SHIFT(410),
+  [1707] = {.entry = {.count = 1, .reusable = true}}, SHIFT(399),
+  [1709] = {.entry = {.count = 1, .reusable = true}}, SHIFT(400),
+  [1711] = {.entry = {.count = 1, .reusable = true}}, SHIFT(411),
+  [1713] = {.entry = {.count = 1, .reusable = true}}, SHIFT(401),
+  [1715] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_selectors, 2, 0, 14),
+  [1717] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_list_value_repeat1, 2, 0, 69), SHIFT_REPEAT(133),
+  [1720] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_list_value_repeat1, 2, 0, 69),
+  [1722] = {.entry = {.count = 1, .reusable = true}}, SHIFT(402),
+  [1724] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
+  [1726] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_rest_parameter, 2, 0, 40),
+  [1728] = {.entry = {.count = 1, .reusable = true}}, SHIFT(197),
+  [1730] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__mixin_name, 2, 0, 8),
+  [1732] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__mixin_name, 4, 0, 52),
+  [1734] = {.entry = {.count = 1, .reusable = true}}, SHIFT(481),
+  [1736] = {.entry = {.count = 1, .reusable = true}}, SHIFT(184),
+  [1738] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_pseudo_class_arguments_repeat2, 2, 0, 93), SHIFT_REPEAT(19),
+  [1741] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_pseudo_class_arguments_repeat2, 2, 0, 93),
+  [1743] = {.entry = {.count = 1, .reusable = true}}, SHIFT(412),
+  [1745] = {.entry = {.count = 1, .reusable = true}}, SHIFT(413),
+  [1747] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_selectors_repeat1, 2, 0, 39), SHIFT_REPEAT(190),
+  [1750] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_selectors_repeat1, 2, 0, 39),
+  [1752] = {.entry = {.count = 1, .reusable = true}}, SHIFT(290),
+  [1754] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__mixin_name, 5, 0, 78),
+  [1756] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__mixin_name, 5, 0, 79),
+  [1758] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__mixin_name, 3, 0, 27),
+  [1760] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__mixin_name, 3, 0, 28),
+  [1762] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_media_statement_repeat1, 2, 0, 50), SHIFT_REPEAT(330),
+  [1765] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_media_statement_repeat1, 2, 0, 50),
+  [1767] = {.entry = {.count = 1, .reusable = true}}, SHIFT(246),
+  [1769] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_last_declaration, 6, 0, 101),
+  [1771] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
+  [1773] = {.entry = {.count = 1, .reusable = true}}, SHIFT(571),
+  [1775] = {.entry = {.count = 1, .reusable = true}}, SHIFT(572),
+  [1777] = {.entry = {.count = 1, .reusable = true}}, SHIFT(238),
+  [1779] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_last_declaration, 4, 0, 81),
+  [1781] = {.entry = {.count = 1, .reusable = true}}, SHIFT(244),
+  [1783] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_last_declaration, 5, 0, 95),
+  [1785] = {.entry = {.count = 1, .reusable = true}}, SHIFT(194),
+  [1787] = {.entry = {.count = 1, .reusable = true}}, SHIFT(643),
+  [1789] = {.entry = {.count = 1, .reusable = true}}, SHIFT(467),
+  [1791] = {.entry = {.count = 1, .reusable = true}}, SHIFT(561),
+  [1793] = {.entry = {.count = 1, .reusable = true}}, SHIFT(542),
+  [1795] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameters, 4, 0, 88),
+  [1797] = {.entry = {.count = 1, .reusable = true}}, SHIFT(472),
+  [1799] = {.entry = {.count = 1, .reusable = true}}, SHIFT(6),
+  [1801] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
+  [1803] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameters, 3, 0, 65),
+  [1805] = {.entry = {.count = 1, .reusable = true}}, SHIFT(243),
+  [1807] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_last_declaration, 5, 0, 94),
+  [1809] = {.entry = {.count = 1, .reusable = true}}, SHIFT(192),
+  [1811] = {.entry = {.count = 1, .reusable = true}}, SHIFT(569),
+  [1813] = {.entry = {.count = 1, .reusable = true}}, SHIFT(570),
+  [1815] = {.entry = {.count = 1, .reusable = false}}, SHIFT(69),
+  [1817] = {.entry = {.count = 1, .reusable = false}}, SHIFT(447),
+  [1819] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
+  [1821] = {.entry = {.count = 1, .reusable = true}}, SHIFT(247),
+  [1823] = {.entry = {.count = 1, .reusable = true}}, SHIFT(278),
+  [1825] = {.entry = {.count = 1, .reusable = true}}, SHIFT(279),
+  [1827] = {.entry = {.count = 1, .reusable = true}}, SHIFT(281),
+  [1829] = {.entry = {.count = 1, .reusable = true}}, SHIFT(283),
+  [1831] = {.entry = {.count = 1, .reusable = true}}, SHIFT(136),
+  [1833] = {.entry = {.count = 1, .reusable = true}}, SHIFT(345),
+  [1835] = {.entry = {.count = 1, .reusable = true}}, SHIFT(338),
+  [1837] = {.entry = {.count = 1, .reusable = true}}, SHIFT(425),
+  [1839] = {.entry = {.count = 1, .reusable = true}}, SHIFT(462),
+  [1841] = {.entry = {.count = 1, .reusable = true}}, SHIFT(286),
+  [1843] = {.entry = {.count = 1, .reusable = true}}, SHIFT(535),
+  [1845] = {.entry = {.count = 1, .reusable = true}}, SHIFT(167),
+  [1847] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_merge_identifier, 1, 0, 0),
+  [1849] = {.entry = {.count = 1, .reusable = true}}, SHIFT(310),
+  [1851] = {.entry = {.count = 1, .reusable = true}}, SHIFT(537),
+  [1853] = {.entry = {.count = 1, .reusable = true}}, SHIFT(311),
+  [1855] = {.entry = {.count = 1, .reusable = true}}, SHIFT(69),
+  [1857] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
+  [1859] = {.entry = {.count = 1, .reusable = true}}, SHIFT(76),
+  [1861] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
+  [1863] = {.entry = {.count = 1, .reusable = true}}, SHIFT(324),
+  [1865] = {.entry = {.count = 1, .reusable = true}}, SHIFT(474),
+  [1867] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
+  [1869] = {.entry = {.count = 1, .reusable = true}}, SHIFT(205),
+  [1871] = {.entry = {.count = 1, .reusable = true}}, SHIFT(80),
+  [1873] = {.entry = {.count = 1, .reusable = true}}, SHIFT(318),
+  [1875] = {.entry = {.count = 1, .reusable = true}}, SHIFT(111),
+  [1877] = {.entry = {.count = 1, .reusable = true}}, SHIFT(625),
+  [1879] = {.entry = {.count = 1, .reusable = true}}, SHIFT(250),
+  [1881] = {.entry = {.count = 1, .reusable = true}}, SHIFT(216),
+  [1883] = {.entry = {.count = 1, .reusable = true}}, SHIFT(106),
+  [1885] = {.entry = {.count = 1, .reusable = true}}, SHIFT(333),
+  [1887] = {.entry = {.count = 1, .reusable = true}}, SHIFT(199),
+  [1889] = {.entry = {.count = 1, .reusable = true}}, SHIFT(188),
+  [1891] = {.entry = {.count = 1, .reusable = true}}, SHIFT(175),
+  [1893] = {.entry = {.count = 1, .reusable = true}}, SHIFT(596),
+  [1895] = {.entry = {.count = 1, .reusable = true}}, SHIFT(241),
+  [1897] = {.entry = {.count = 1, .reusable = true}}, SHIFT(363),
+  [1899] = {.entry = {.count = 1, .reusable = true}}, SHIFT(125),
+  [1901] = {.entry = {.count = 1, .reusable = true}}, SHIFT(114),
+  [1903] = {.entry = {.count = 1, .reusable = true}}, SHIFT(203),
+  [1905] = {.entry = {.count = 1, .reusable = true}}, SHIFT(349),
+  [1907] = {.entry = {.count = 1, .reusable = true}}, SHIFT(339),
+  [1909] = {.entry = {.count = 1, .reusable = true}}, SHIFT(588),
+  [1911] = {.entry = {.count = 1, .reusable = true}}, SHIFT(126),
+  [1913] = {.entry = {.count = 1, .reusable = true}}, SHIFT(468),
+  [1915] = {.entry = {.count = 1, .reusable = true}}, SHIFT(469),
+  [1917] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_when_condition, 4, 0, 100),
+  [1919] = {.entry = {.count = 1, .reusable = true}}, SHIFT(422),
+  [1921] = {.entry = {.count = 1, .reusable = true}}, SHIFT(659),
+  [1923] = {.entry = {.count = 1, .reusable = true}}, SHIFT(200),
+  [1925] = {.entry = {.count = 1, .reusable = true}}, SHIFT(253),
+  [1927] = {.entry = {.count = 1, .reusable = true}}, SHIFT(475),
+  [1929] = {.entry = {.count = 1, .reusable = true}}, SHIFT(168),
+  [1931] = {.entry = {.count = 1, .reusable = true}}, SHIFT(202),
+  [1933] = {.entry = {.count = 1, .reusable = true}}, SHIFT(447),
+  [1935] = {.entry = {.count = 1, .reusable = true}}, SHIFT(309),
+  [1937] = {.entry = {.count = 1, .reusable = true}}, SHIFT(601),
+  [1939] = {.entry = {.count = 1, .reusable = true}}, SHIFT(583),
+  [1941] = {.entry = {.count = 1, .reusable = true}}, SHIFT(204),
+  [1943] = {.entry = {.count = 1, .reusable = true}}, SHIFT(611),
+  [1945] = {.entry = {.count = 1, .reusable = true}}, SHIFT(577),
+  [1947] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [1949] = {.entry = {.count = 1, .reusable = true}}, SHIFT(626),
+  [1951] = {.entry = {.count = 1, .reusable = true}}, SHIFT(228),
+  [1953] = {.entry = {.count = 1, .reusable = true}}, SHIFT(457),
+  [1955] = {.entry = {.count = 1, .reusable = true}}, SHIFT(193),
+  [1957] = {.entry = {.count = 1, .reusable = true}}, SHIFT(369),
+  [1959] = {.entry = {.count = 1, .reusable = true}}, SHIFT(105),
+  [1961] = {.entry = {.count = 1, .reusable = true}}, SHIFT(231),
+  [1963] = {.entry = {.count = 1, .reusable = true}}, SHIFT(365),
+  [1965] = {.entry = {.count = 1, .reusable = true}}, SHIFT(149),
+  [1967] = {.entry = {.count = 1, .reusable = true}}, SHIFT(391),
+  [1969] = {.entry = {.count = 1, .reusable = true}}, SHIFT(642),
+  [1971] = {.entry = {.count = 1, .reusable = true}}, SHIFT(162),
+  [1973] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__extend_arguments, 3, 0, 102),
+};
+
+enum ts_external_scanner_symbol_identifiers {
+  ts_external_token__descendant_operator = 0,
+  ts_external_token__pseudo_class_selector_colon = 1,
+  ts_external_token___error_recovery = 2,
+  ts_external_token__concat = 3,
+};
+
+static const TSSymbol ts_external_scanner_symbol_map[EXTERNAL_TOKEN_COUNT] = {
+  [ts_external_token__descendant_operator] = sym__descendant_operator,
+  [ts_external_token__pseudo_class_selector_colon] = sym__pseudo_class_selector_colon,
+  [ts_external_token___error_recovery] = sym___error_recovery,
+  [ts_external_token__concat] = sym__concat,
+};
+
+static const bool ts_external_scanner_states[5][EXTERNAL_TOKEN_COUNT] = {
+  [1] = {
+    [ts_external_token__descendant_operator] = true,
+    [ts_external_token__pseudo_class_selector_colon] = true,
+    [ts_external_token___error_recovery] = true,
+    [ts_external_token__concat] = true,
+  },
+  [2] = {
+    [ts_external_token__descendant_operator] = true,
+    [ts_external_token__concat] = true,
+  },
+  [3] = {
+    [ts_external_token__concat] = true,
+  },
+  [4] = {
+    [ts_external_token__descendant_operator] = true,
+  },
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+void *tree_sitter_less_external_scanner_create(void);
+void tree_sitter_less_external_scanner_destroy(void *);
+bool tree_sitter_less_external_scanner_scan(void *, TSLexer *, const bool *);
+unsigned tree_sitter_less_external_scanner_serialize(void *, char *);
+void tree_sitter_less_external_scanner_deserialize(void *, const char *, unsigned);
+
+#ifdef TREE_SITTER_HIDE_SYMBOLS
+#define TS_PUBLIC
+#elif defined(_WIN32)
+#define TS_PUBLIC __declspec(dllexport)
+#else
+#define TS_PUBLIC __attribute__((visibility("default")))
+#endif
+
+TS_PUBLIC const TSLanguage *tree_sitter_less(void) {
+  static const TSLanguage language = {
+    .version = LANGUAGE_VERSION,
+    .symbol_count = SYMBOL_COUNT,
+    .alias_count = ALIAS_COUNT,
+    .token_count = TOKEN_COUNT,
+    .external_token_count = EXTERNAL_TOKEN_COUNT,
+    .state_count = STATE_COUNT,
+    .large_state_count = LARGE_STATE_COUNT,
+    .production_id_count = PRODUCTION_ID_COUNT,
+    .field_count = FIELD_COUNT,
+    .max_alias_sequence_length = MAX_ALIAS_SEQUENCE_LENGTH,
+    .parse_table = &ts_parse_table[0][0],
+    .small_parse_table = ts_small_parse_table,
+    .small_parse_table_map = ts_small_parse_table_map,
+    .parse_actions = ts_parse_actions,
+    .symbol_names = ts_symbol_names,
+    .field_names = ts_field_names,
+    .field_map_slices = ts_field_map_slices,
+    .field_map_entries = ts_field_map_entries,
+    .symbol_metadata = ts_symbol_metadata,
+    .public_symbol_map = ts_symbol_map,
+    .alias_map = ts_non_terminal_alias_map,
+    .alias_sequences = &ts_alias_sequences[0][0],
+    .lex_modes = ts_lex_modes,
+    .lex_fn = ts_lex,
+    .external_scanner = {
+      &ts_external_scanner_states[0][0],
+      ts_external_scanner_symbol_map,
+      tree_sitter_less_external_scanner_create,
+      tree_sitter_less_external_scanner_destroy,
+      tree_sitter_less_external_scanner_scan,
+      tree_sitter_less_external_scanner_serialize,
+      tree_sitter_less_external_scanner_deserialize,
+    },
+    .primary_state_ids = ts_primary_state_ids,
+  };
+  return &language;
+}
+#ifdef __cplusplus
+}
+#endif
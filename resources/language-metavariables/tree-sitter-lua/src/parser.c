@@ -0,0 +1,13642 @@
+#include "tree_sitter/parser.h"
+
+#if defined(__GNUC__) || defined(__clang__)
+#pragma GCC diagnostic ignored "-Wmissing-field-initializers"
+#endif
+
+#define LANGUAGE_VERSION 14
+#define STATE_COUNT 272
+#define LARGE_STATE_COUNT 2
+#define SYMBOL_COUNT 139
+#define ALIAS_COUNT 0
+#define TOKEN_COUNT 73
+#define EXTERNAL_TOKEN_COUNT 6
+#define FIELD_COUNT 32
+#define MAX_ALIAS_SEQUENCE_LENGTH 7
+#define PRODUCTION_ID_COUNT 83
+
+enum ts_symbol_identifiers {
+  sym__identifier = 1,
+  sym_hash_bang_line = 2,
+  anon_sym_return = 3,
+  anon_sym_SEMI = 4,
+  anon_sym_EQ = 5,
+  anon_sym_COMMA = 6,
+  anon_sym_COLON_COLON = 7,
+  sym_break_statement = 8,
+  anon_sym_goto = 9,
+  anon_sym_do = 10,
+  anon_sym_end = 11,
+  anon_sym_while = 12,
+  anon_sym_repeat = 13,
+  anon_sym_until = 14,
+  anon_sym_if = 15,
+  anon_sym_then = 16,
+  anon_sym_elseif = 17,
+  anon_sym_else = 18,
+  anon_sym_for = 19,
+  anon_sym_in = 20,
+  anon_sym_function = 21,
+  anon_sym_local = 22,
+  anon_sym_DOT = 23,
+  anon_sym_COLON = 24,
+  anon_sym_LT = 25,
+  anon_sym_GT = 26,
+  sym_nil = 27,
+  sym_false = 28,
+  sym_true = 29,
+  sym_number = 30,
+  anon_sym_DQUOTE = 31,
+  anon_sym_SQUOTE = 32,
+  aux_sym__doublequote_string_content_token1 = 33,
+  aux_sym__singlequote_string_content_token1 = 34,
+  sym_escape_sequence = 35,
+  sym_vararg_expression = 36,
+  anon_sym_LPAREN = 37,
+  anon_sym_RPAREN = 38,
+  anon_sym_LBRACK = 39,
+  anon_sym_RBRACK = 40,
+  anon_sym_LBRACE = 41,
+  anon_sym_RBRACE = 42,
+  anon_sym_or = 43,
+  anon_sym_and = 44,
+  anon_sym_LT_EQ = 45,
+  anon_sym_EQ_EQ = 46,
+  anon_sym_TILDE_EQ = 47,
+  anon_sym_GT_EQ = 48,
+  anon_sym_PIPE = 49,
+  anon_sym_TILDE = 50,
+  anon_sym_AMP = 51,
+  anon_sym_LT_LT = 52,
+  anon_sym_GT_GT = 53,
+  anon_sym_PLUS = 54,
+  anon_sym_DASH = 55,
+  anon_sym_STAR = 56,
+  anon_sym_SLASH = 57,
+  anon_sym_SLASH_SLASH = 58,
+  anon_sym_PERCENT = 59,
+  anon_sym_DOT_DOT = 60,
+  anon_sym_CARET = 61,
+  anon_sym_not = 62,
+  anon_sym_POUND = 63,
+  sym_grit_metavariable = 64,
+  anon_sym_DASH_DASH = 65,
+  aux_sym_comment_token1 = 66,
+  sym__block_comment_start = 67,
+  sym__block_comment_content = 68,
+  sym__block_comment_end = 69,
+  sym__block_string_start = 70,
+  sym__block_string_content = 71,
+  sym__block_string_end = 72,
+  sym_chunk = 73,
+  sym__block = 74,
+  sym_statement = 75,
+  sym_return_statement = 76,
+  sym_empty_statement = 77,
+  sym_assignment_statement = 78,
+  sym__variable_assignment_varlist = 79,
+  sym__variable_assignment_explist = 80,
+  sym_label_statement = 81,
+  sym_goto_statement = 82,
+  sym_do_statement = 83,
+  sym_while_statement = 84,
+  sym_repeat_statement = 85,
+  sym_if_statement = 86,
+  sym_elseif_statement = 87,
+  sym_else_statement = 88,
+  sym_for_statement = 89,
+  sym_for_generic_clause = 90,
+  sym_for_numeric_clause = 91,
+  sym_declaration = 92,
+  sym_function_declaration = 93,
+  sym__local_function_declaration = 94,
+  sym__function_name = 95,
+  sym__function_name_prefix_expression = 96,
+  sym__function_name_dot_index_expression = 97,
+  sym__function_name_method_index_expression = 98,
+  sym_variable_declaration = 99,
+  sym__local_variable_assignment = 100,
+  sym__name_list = 101,
+  sym__att_name_list = 102,
+  sym__attrib = 103,
+  sym__expression_list = 104,
+  sym_expression = 105,
+  sym_string = 106,
+  sym__quote_string = 107,
+  aux_sym__doublequote_string_content = 108,
+  aux_sym__singlequote_string_content = 109,
+  sym__block_string = 110,
+  sym_function_definition = 111,
+  sym__function_body = 112,
+  sym_parameters = 113,
+  sym__parameter_list = 114,
+  sym__prefix_expression = 115,
+  sym_variable = 116,
+  sym_bracket_index_expression = 117,
+  sym_dot_index_expression = 118,
+  sym_function_call = 119,
+  sym_method_index_expression = 120,
+  sym_arguments = 121,
+  sym_parenthesized_expression = 122,
+  sym_table_constructor = 123,
+  sym__field_list = 124,
+  sym__field_sep = 125,
+  sym_field = 126,
+  sym_binary_expression = 127,
+  sym_unary_expression = 128,
+  sym_identifier = 129,
+  sym_comment = 130,
+  aux_sym_chunk_repeat1 = 131,
+  aux_sym__variable_assignment_varlist_repeat1 = 132,
+  aux_sym__variable_assignment_explist_repeat1 = 133,
+  aux_sym_if_statement_repeat1 = 134,
+  aux_sym__name_list_repeat1 = 135,
+  aux_sym__att_name_list_repeat1 = 136,
+  aux_sym_arguments_repeat1 = 137,
+  aux_sym__field_list_repeat1 = 138,
+};
+
+static const char * const ts_symbol_names[] = {
+  [ts_builtin_sym_end] = "end",
+  [sym__identifier] = "_identifier",
+  [sym_hash_bang_line] = "hash_bang_line",
+  [anon_sym_return] = "return",
+  [anon_sym_SEMI] = ";",
+  [anon_sym_EQ] = "=",
+  [anon_sym_COMMA] = ",",
+  [anon_sym_COLON_COLON] = "::",
+  [sym_break_statement] = "break_statement",
+  [anon_sym_goto] = "goto",
+  [anon_sym_do] = "do",
+  [anon_sym_end] = "end",
+  [anon_sym_while] = "while",
+  [anon_sym_repeat] = "repeat",
+  [anon_sym_until] = "until",
+  [anon_sym_if] = "if",
+  [anon_sym_then] = "then",
+  [anon_sym_elseif] = "elseif",
+  [anon_sym_else] = "else",
+  [anon_sym_for] = "for",
+  [anon_sym_in] = "in",
+  [anon_sym_function] = "function",
+  [anon_sym_local] = "local",
+  [anon_sym_DOT] = ".",
+  [anon_sym_COLON] = ":",
+  [anon_sym_LT] = "<",
+  [anon_sym_GT] = ">",
+  [sym_nil] = "nil",
+  [sym_false] = "false",
+  [sym_true] = "true",
+  [sym_number] = "number",
+  [anon_sym_DQUOTE] = "\"",
+  [anon_sym_SQUOTE] = "'",
+  [aux_sym__doublequote_string_content_token1] = "_doublequote_string_content_token1",
+  [aux_sym__singlequote_string_content_token1] = "_singlequote_string_content_token1",
+  [sym_escape_sequence] = "escape_sequence",
+  [sym_vararg_expression] = "vararg_expression",
+  [anon_sym_LPAREN] = "(",
+  [anon_sym_RPAREN] = ")",
+  [anon_sym_LBRACK] = "[",
+  [anon_sym_RBRACK] = "]",
+  [anon_sym_LBRACE] = "{",
+  [anon_sym_RBRACE] = "}",
+  [anon_sym_or] = "operator",
+  [anon_sym_and] = "operator",
+  [anon_sym_LT_EQ] = "operator",
+  [anon_sym_EQ_EQ] = "operator",
+  [anon_sym_TILDE_EQ] = "operator",
+  [anon_sym_GT_EQ] = "operator",
+  [anon_sym_PIPE] = "operator",
+  [anon_sym_TILDE] = "operator",
+  [anon_sym_AMP] = "operator",
+  [anon_sym_LT_LT] = "operator",
+  [anon_sym_GT_GT] = "operator",
+  [anon_sym_PLUS] = "operator",
+  [anon_sym_DASH] = "operator",
+  [anon_sym_STAR] = "operator",
+  [anon_sym_SLASH] = "operator",
+  [anon_sym_SLASH_SLASH] = "operator",
+  [anon_sym_PERCENT] = "operator",
+  [anon_sym_DOT_DOT] = "operator",
+  [anon_sym_CARET] = "operator",
+  [anon_sym_not] = "operator",
+  [anon_sym_POUND] = "operator",
+  [sym_grit_metavariable] = "grit_metavariable",
+  [anon_sym_DASH_DASH] = "--",
+  [aux_sym_comment_token1] = "comment_content",
+  [sym__block_comment_start] = "[[",
+  [sym__block_comment_content] = "comment_content",
+  [sym__block_comment_end] = "]]",
+  [sym__block_string_start] = "[[",
+  [sym__block_string_content] = "string_content",
+  [sym__block_string_end] = "]]",
+  [sym_chunk] = "chunk",
+  [sym__block] = "block",
+  [sym_statement] = "statement",
+  [sym_return_statement] = "return_statement",
+  [sym_empty_statement] = "empty_statement",
+  [sym_assignment_statement] = "assignment_statement",
+  [sym__variable_assignment_varlist] = "variable_list",
+  [sym__variable_assignment_explist] = "expression_list",
+  [sym_label_statement] = "label_statement",
+  [sym_goto_statement] = "goto_statement",
+  [sym_do_statement] = "do_statement",
+  [sym_while_statement] = "while_statement",
+  [sym_repeat_statement] = "repeat_statement",
+  [sym_if_statement] = "if_statement",
+  [sym_elseif_statement] = "elseif_statement",
+  [sym_else_statement] = "else_statement",
+  [sym_for_statement] = "for_statement",
+  [sym_for_generic_clause] = "for_generic_clause",
+  [sym_for_numeric_clause] = "for_numeric_clause",
+  [sym_declaration] = "declaration",
+  [sym_function_declaration] = "function_declaration",
+  [sym__local_function_declaration] = "function_declaration",
+  [sym__function_name] = "_function_name",
+  [sym__function_name_prefix_expression] = "_function_name_prefix_expression",
+  [sym__function_name_dot_index_expression] = "dot_index_expression",
+  [sym__function_name_method_index_expression] = "method_index_expression",
+  [sym_variable_declaration] = "variable_declaration",
+  [sym__local_variable_assignment] = "assignment_statement",
+  [sym__name_list] = "variable_list",
+  [sym__att_name_list] = "variable_list",
+  [sym__attrib] = "attribute",
+  [sym__expression_list] = "expression_list",
+  [sym_expression] = "expression",
+  [sym_string] = "string",
+  [sym__quote_string] = "_quote_string",
+  [aux_sym__doublequote_string_content] = "_doublequote_string_content",
+  [aux_sym__singlequote_string_content] = "_singlequote_string_content",
+  [sym__block_string] = "_block_string",
+  [sym_function_definition] = "function_definition",
+  [sym__function_body] = "_function_body",
+  [sym_parameters] = "parameters",
+  [sym__parameter_list] = "_parameter_list",
+  [sym__prefix_expression] = "_prefix_expression",
+  [sym_variable] = "variable",
+  [sym_bracket_index_expression] = "bracket_index_expression",
+  [sym_dot_index_expression] = "dot_index_expression",
+  [sym_function_call] = "function_call",
+  [sym_method_index_expression] = "method_index_expression",
+  [sym_arguments] = "arguments",
+  [sym_parenthesized_expression] = "parenthesized_expression",
+  [sym_table_constructor] = "table_constructor",
+  [sym__field_list] = "_field_list",
+  [sym__field_sep] = "_field_sep",
+  [sym_field] = "field",
+  [sym_binary_expression] = "binary_expression",
+  [sym_unary_expression] = "unary_expression",
+  [sym_identifier] = "identifier",
+  [sym_comment] = "comment",
+  [aux_sym_chunk_repeat1] = "chunk_repeat1",
+  [aux_sym__variable_assignment_varlist_repeat1] = "_variable_assignment_varlist_repeat1",
+  [aux_sym__variable_assignment_explist_repeat1] = "_variable_assignment_explist_repeat1",
+  [aux_sym_if_statement_repeat1] = "if_statement_repeat1",
+  [aux_sym__name_list_repeat1] = "_name_list_repeat1",
+  [aux_sym__att_name_list_repeat1] = "_att_name_list_repeat1",
+  [aux_sym_arguments_repeat1] = "arguments_repeat1",
+  [aux_sym__field_list_repeat1] = "_field_list_repeat1",
+};
+
+static const TSSymbol ts_symbol_map[] = {
+  [ts_builtin_sym_end] = ts_builtin_sym_end,
+  [sym__identifier] = sym__identifier,
+  [sym_hash_bang_line] = sym_hash_bang_line,
+  [anon_sym_return] = anon_sym_return,
+  [anon_sym_SEMI] = anon_sym_SEMI,
+  [anon_sym_EQ] = anon_sym_EQ,
+  [anon_sym_COMMA] = anon_sym_COMMA,
+  [anon_sym_COLON_COLON] = anon_sym_COLON_COLON,
+  [sym_break_statement] = sym_break_statement,
+  [anon_sym_goto] = anon_sym_goto,
+  [anon_sym_do] = anon_sym_do,
+  [anon_sym_end] = anon_sym_end,
+  [anon_sym_while] = anon_sym_while,
+  [anon_sym_repeat] = anon_sym_repeat,
+  [anon_sym_until] = anon_sym_until,
+  [anon_sym_if] = anon_sym_if,
+  [anon_sym_then] = anon_sym_then,
+  [anon_sym_elseif] = anon_sym_elseif,
+  [anon_sym_else] = anon_sym_else,
+  [anon_sym_for] = anon_sym_for,
+  [anon_sym_in] = anon_sym_in,
+  [anon_sym_function] = anon_sym_function,
+  [anon_sym_local] = anon_sym_local,
+  [anon_sym_DOT] = anon_sym_DOT,
+  [anon_sym_COLON] = anon_sym_COLON,
+  [anon_sym_LT] = anon_sym_LT,
+  [anon_sym_GT] = anon_sym_GT,
+  [sym_nil] = sym_nil,
+  [sym_false] = sym_false,
+  [sym_true] = sym_true,
+  [sym_number] = sym_number,
+  [anon_sym_DQUOTE] = anon_sym_DQUOTE,
+  [anon_sym_SQUOTE] = anon_sym_SQUOTE,
+  [aux_sym__doublequote_string_content_token1] = aux_sym__doublequote_string_content_token1,
+  [aux_sym__singlequote_string_content_token1] = aux_sym__singlequote_string_content_token1,
+  [sym_escape_sequence] = sym_escape_sequence,
+  [sym_vararg_expression] = sym_vararg_expression,
+  [anon_sym_LPAREN] = anon_sym_LPAREN,
+  [anon_sym_RPAREN] = anon_sym_RPAREN,
+  [anon_sym_LBRACK] = anon_sym_LBRACK,
+  [anon_sym_RBRACK] = anon_sym_RBRACK,
+  [anon_sym_LBRACE] = anon_sym_LBRACE,
+  [anon_sym_RBRACE] = anon_sym_RBRACE,
+  [anon_sym_or] = anon_sym_or,
+  [anon_sym_and] = anon_sym_or,
+  [anon_sym_LT_EQ] = anon_sym_or,
+  [anon_sym_EQ_EQ] = anon_sym_or,
+  [anon_sym_TILDE_EQ] = anon_sym_or,
+  [anon_sym_GT_EQ] = anon_sym_or,
+  [anon_sym_PIPE] = anon_sym_or,
+  [anon_sym_TILDE] = anon_sym_or,
+  [anon_sym_AMP] = anon_sym_or,
+  [anon_sym_LT_LT] = anon_sym_or,
+  [anon_sym_GT_GT] = anon_sym_or,
+  [anon_sym_PLUS] = anon_sym_or,
+  [anon_sym_DASH] = anon_sym_or,
+  [anon_sym_STAR] = anon_sym_or,
+  [anon_sym_SLASH] = anon_sym_or,
+  [anon_sym_SLASH_SLASH] = anon_sym_or,
+  [anon_sym_PERCENT] = anon_sym_or,
+  [anon_sym_DOT_DOT] = anon_sym_or,
+  [anon_sym_CARET] = anon_sym_or,
+  [anon_sym_not] = anon_sym_or,
+  [anon_sym_POUND] = anon_sym_or,
+  [sym_grit_metavariable] = sym_grit_metavariable,
+  [anon_sym_DASH_DASH] = anon_sym_DASH_DASH,
+  [aux_sym_comment_token1] = sym__block_comment_content,
+  [sym__block_comment_start] = sym__block_comment_start,
+  [sym__block_comment_content] = sym__block_comment_content,
+  [sym__block_comment_end] = sym__block_comment_end,
+  [sym__block_string_start] = sym__block_comment_start,
+  [sym__block_string_content] = sym__block_string_content,
+  [sym__block_string_end] = sym__block_comment_end,
+  [sym_chunk] = sym_chunk,
+  [sym__block] = sym__block,
+  [sym_statement] = sym_statement,
+  [sym_return_statement] = sym_return_statement,
+  [sym_empty_statement] = sym_empty_statement,
+  [sym_assignment_statement] = sym_assignment_statement,
+  [sym__variable_assignment_varlist] = sym__variable_assignment_varlist,
+  [sym__variable_assignment_explist] = sym__variable_assignment_explist,
+  [sym_label_statement] = sym_label_statement,
+  [sym_goto_statement] = sym_goto_statement,
+  [sym_do_statement] = sym_do_statement,
+  [sym_while_statement] = sym_while_statement,
+  [sym_repeat_statement] = sym_repeat_statement,
+  [sym_if_statement] = sym_if_statement,
+  [sym_elseif_statement] = sym_elseif_statement,
+  [sym_else_statement] = sym_else_statement,
+  [sym_for_statement] = sym_for_statement,
+  [sym_for_generic_clause] = sym_for_generic_clause,
+  [sym_for_numeric_clause] = sym_for_numeric_clause,
+  [sym_declaration] = sym_declaration,
+  [sym_function_declaration] = sym_function_declaration,
+  [sym__local_function_declaration] = sym_function_declaration,
+  [sym__function_name] = sym__function_name,
+  [sym__function_name_prefix_expression] = sym__function_name_prefix_expression,
+  [sym__function_name_dot_index_expression] = sym_dot_index_expression,
+  [sym__function_name_method_index_expression] = sym_method_index_expression,
+  [sym_variable_declaration] = sym_variable_declaration,
+  [sym__local_variable_assignment] = sym_assignment_statement,
+  [sym__name_list] = sym__variable_assignment_varlist,
+  [sym__att_name_list] = sym__variable_assignment_varlist,
+  [sym__attrib] = sym__attrib,
+  [sym__expression_list] = sym__variable_assignment_explist,
+  [sym_expression] = sym_expression,
+  [sym_string] = sym_string,
+  [sym__quote_string] = sym__quote_string,
+  [aux_sym__doublequote_string_content] = aux_sym__doublequote_string_content,
+  [aux_sym__singlequote_string_content] = aux_sym__singlequote_string_content,
+  [sym__block_string] = sym__block_string,
+  [sym_function_definition] = sym_function_definition,
+  [sym__function_body] = sym__function_body,
+  [sym_parameters] = sym_parameters,
+  [sym__parameter_list] = sym__parameter_list,
+  [sym__prefix_expression] = sym__prefix_expression,
+  [sym_variable] = sym_variable,
+  [sym_bracket_index_expression] = sym_bracket_index_expression,
+  [sym_dot_index_expression] = sym_dot_index_expression,
+  [sym_function_call] = sym_function_call,
+  [sym_method_index_expression] = sym_method_index_expression,
+  [sym_arguments] = sym_arguments,
+  [sym_parenthesized_expression] = sym_parenthesized_expression,
+  [sym_table_constructor] = sym_table_constructor,
+  [sym__field_list] = sym__field_list,
+  [sym__field_sep] = sym__field_sep,
+  [sym_field] = sym_field,
+  [sym_binary_expression] = sym_binary_expression,
+  [sym_unary_expression] = sym_unary_expression,
+  [sym_identifier] = sym_identifier,
+  [sym_comment] = sym_comment,
+  [aux_sym_chunk_repeat1] = aux_sym_chunk_repeat1,
+  [aux_sym__variable_assignment_varlist_repeat1] = aux_sym__variable_assignment_varlist_repeat1,
+  [aux_sym__variable_assignment_explist_repeat1] = aux_sym__variable_assignment_explist_repeat1,
+  [aux_sym_if_statement_repeat1] = aux_sym_if_statement_repeat1,
+  [aux_sym__name_list_repeat1] = aux_sym__name_list_repeat1,
+  [aux_sym__att_name_list_repeat1] = aux_sym__att_name_list_repeat1,
+  [aux_sym_arguments_repeat1] = aux_sym_arguments_repeat1,
+  [aux_sym__field_list_repeat1] = aux_sym__field_list_repeat1,
+};
+
+static const TSSymbolMetadata ts_symbol_metadata[] = {
+  [ts_builtin_sym_end] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym__identifier] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_hash_bang_line] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_return] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_SEMI] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_COMMA] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_COLON_COLON] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_break_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_goto] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_do] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_end] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_while] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_repeat] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_until] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_if] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_then] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_elseif] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_else] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_for] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_in] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_function] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_local] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_COLON] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_GT] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_nil] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_false] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_true] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_number] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_DQUOTE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_SQUOTE] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym__doublequote_string_content_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym__singlequote_string_content_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [sym_escape_sequence] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_vararg_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_LPAREN] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RPAREN] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_or] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_and] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_LT_EQ] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_EQ_EQ] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_TILDE_EQ] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_GT_EQ] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_PIPE] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_TILDE] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_AMP] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_LT_LT] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_GT_GT] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_PLUS] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_DASH] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_STAR] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_SLASH] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_SLASH_SLASH] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_PERCENT] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_DOT_DOT] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_CARET] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_not] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_POUND] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_grit_metavariable] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_DASH_DASH] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym_comment_token1] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__block_comment_start] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym__block_comment_content] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__block_comment_end] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym__block_string_start] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym__block_string_content] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__block_string_end] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_chunk] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__block] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_statement] = {
+    .visible = false,
+    .named = true,
+    .supertype = true,
+  },
+  [sym_return_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_empty_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_assignment_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__variable_assignment_varlist] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__variable_assignment_explist] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_label_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_goto_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_do_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_while_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_repeat_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_if_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_elseif_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_else_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_for_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_for_generic_clause] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_for_numeric_clause] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_declaration] = {
+    .visible = false,
+    .named = true,
+    .supertype = true,
+  },
+  [sym_function_declaration] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__local_function_declaration] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__function_name] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym__function_name_prefix_expression] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym__function_name_dot_index_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__function_name_method_index_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_variable_declaration] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__local_variable_assignment] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__name_list] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__att_name_list] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__attrib] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__expression_list] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_expression] = {
+    .visible = false,
+    .named = true,
+    .supertype = true,
+  },
+  [sym_string] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__quote_string] = {
+    .visible = false,
+    .named = true,
+  },
+  [aux_sym__doublequote_string_content] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym__singlequote_string_content] = {
+    .visible = false,
+    .named = false,
+  },
+  [sym__block_string] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_function_definition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__function_body] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_parameters] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__parameter_list] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym__prefix_expression] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_variable] = {
+    .visible = false,
+    .named = true,
+    .supertype = true,
+  },
+  [sym_bracket_index_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_dot_index_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_function_call] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_method_index_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_arguments] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_parenthesized_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_table_constructor] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__field_list] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym__field_sep] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_field] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_binary_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_unary_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_identifier] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_comment] = {
+    .visible = true,
+    .named = true,
+  },
+  [aux_sym_chunk_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym__variable_assignment_varlist_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym__variable_assignment_explist_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_if_statement_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym__name_list_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym__att_name_list_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_arguments_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym__field_list_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+};
+
+enum ts_field_identifiers {
+  field_alternative = 1,
+  field_argument = 2,
+  field_arguments = 3,
+  field_attribute = 4,
+  field_body = 5,
+  field_clause = 6,
+  field_condition = 7,
+  field_consequence = 8,
+  field_content = 9,
+  field_declaration = 10,
+  field_end = 11,
+  field_expression = 12,
+  field_field = 13,
+  field_hash_bang = 14,
+  field_label = 15,
+  field_left = 16,
+  field_local_declaration = 17,
+  field_method = 18,
+  field_name = 19,
+  field_names = 20,
+  field_operand = 21,
+  field_operator = 22,
+  field_parameters = 23,
+  field_right = 24,
+  field_start = 25,
+  field_statement = 26,
+  field_step = 27,
+  field_table = 28,
+  field_value = 29,
+  field_values = 30,
+  field_vararg = 31,
+  field_variables = 32,
+};
+
+static const char * const ts_field_names[] = {
+  [0] = NULL,
+  [field_alternative] = "alternative",
+  [field_argument] = "argument",
+  [field_arguments] = "arguments",
+  [field_attribute] = "attribute",
+  [field_body] = "body",
+  [field_clause] = "clause",
+  [field_condition] = "condition",
+  [field_consequence] = "consequence",
+  [field_content] = "content",
+  [field_declaration] = "declaration",
+  [field_end] = "end",
+  [field_expression] = "expression",
+  [field_field] = "field",
+  [field_hash_bang] = "hash_bang",
+  [field_label] = "label",
+  [field_left] = "left",
+  [field_local_declaration] = "local_declaration",
+  [field_method] = "method",
+  [field_name] = "name",
+  [field_names] = "names",
+  [field_operand] = "operand",
+  [field_operator] = "operator",
+  [field_parameters] = "parameters",
+  [field_right] = "right",
+  [field_start] = "start",
+  [field_statement] = "statement",
+  [field_step] = "step",
+  [field_table] = "table",
+  [field_value] = "value",
+  [field_values] = "values",
+  [field_vararg] = "vararg",
+  [field_variables] = "variables",
+};
+
+static const TSFieldMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
+  [1] = {.index = 0, .length = 1},
+  [2] = {.index = 1, .length = 1},
+  [3] = {.index = 2, .length = 1},
+  [4] = {.index = 3, .length = 4},
+  [5] = {.index = 7, .length = 1},
+  [6] = {.index = 8, .length = 1},
+  [7] = {.index = 9, .length = 1},
+  [8] = {.index = 10, .length = 2},
+  [9] = {.index = 12, .length = 2},
+  [10] = {.index = 14, .length = 2},
+  [11] = {.index = 16, .length = 2},
+  [12] = {.index = 18, .length = 1},
+  [13] = {.index = 19, .length = 3},
+  [14] = {.index = 22, .length = 1},
+  [15] = {.index = 23, .length = 2},
+  [16] = {.index = 25, .length = 2},
+  [17] = {.index = 27, .length = 3},
+  [18] = {.index = 30, .length = 3},
+  [19] = {.index = 33, .length = 1},
+  [20] = {.index = 34, .length = 2},
+  [21] = {.index = 36, .length = 2},
+  [22] = {.index = 38, .length = 2},
+  [23] = {.index = 40, .length = 2},
+  [24] = {.index = 42, .length = 3},
+  [25] = {.index = 45, .length = 3},
+  [26] = {.index = 48, .length = 2},
+  [27] = {.index = 50, .length = 2},
+  [28] = {.index = 52, .length = 1},
+  [29] = {.index = 53, .length = 2},
+  [30] = {.index = 55, .length = 2},
+  [31] = {.index = 57, .length = 1},
+  [32] = {.index = 58, .length = 2},
+  [33] = {.index = 60, .length = 1},
+  [34] = {.index = 61, .length = 3},
+  [35] = {.index = 64, .length = 2},
+  [36] = {.index = 66, .length = 3},
+  [37] = {.index = 69, .length = 1},
+  [38] = {.index = 70, .length = 4},
+  [39] = {.index = 74, .length = 2},
+  [40] = {.index = 76, .length = 2},
+  [41] = {.index = 78, .length = 2},
+  [42] = {.index = 80, .length = 1},
+  [43] = {.index = 81, .length = 1},
+  [44] = {.index = 42, .length = 3},
+  [45] = {.index = 82, .length = 1},
+  [46] = {.index = 83, .length = 2},
+  [47] = {.index = 85, .length = 1},
+  [48] = {.index = 86, .length = 3},
+  [49] = {.index = 89, .length = 2},
+  [50] = {.index = 91, .length = 1},
+  [51] = {.index = 92, .length = 3},
+  [52] = {.index = 95, .length = 1},
+  [53] = {.index = 96, .length = 1},
+  [54] = {.index = 97, .length = 4},
+  [55] = {.index = 101, .length = 3},
+  [56] = {.index = 104, .length = 5},
+  [57] = {.index = 109, .length = 4},
+  [58] = {.index = 113, .length = 4},
+  [59] = {.index = 117, .length = 1},
+  [60] = {.index = 118, .length = 2},
+  [61] = {.index = 120, .length = 3},
+  [62] = {.index = 123, .length = 1},
+  [63] = {.index = 124, .length = 2},
+  [64] = {.index = 126, .length = 2},
+  [65] = {.index = 128, .length = 3},
+  [66] = {.index = 131, .length = 3},
+  [67] = {.index = 134, .length = 2},
+  [68] = {.index = 136, .length = 2},
+  [69] = {.index = 138, .length = 2},
+  [70] = {.index = 140, .length = 3},
+  [71] = {.index = 143, .length = 2},
+  [72] = {.index = 145, .length = 2},
+  [73] = {.index = 147, .length = 2},
+  [74] = {.index = 149, .length = 2},
+  [75] = {.index = 151, .length = 4},
+  [76] = {.index = 155, .length = 4},
+  [77] = {.index = 159, .length = 3},
+  [78] = {.index = 162, .length = 3},
+  [79] = {.index = 165, .length = 3},
+  [80] = {.index = 168, .length = 2},
+  [81] = {.index = 170, .length = 5},
+  [82] = {.index = 175, .length = 4},
+};
+
+static const TSFieldMapEntry ts_field_map_entries[] = {
+  [0] =
+    {field_hash_bang, 0},
+  [1] =
+    {field_statement, 0},
+  [2] =
+    {field_local_declaration, 0, .inherited = true},
+  [3] =
+    {field_body, 0, .inherited = true},
+    {field_local_declaration, 0},
+    {field_name, 0, .inherited = true},
+    {field_parameters, 0, .inherited = true},
+  [7] =
+    {field_local_declaration, 0},
+  [8] =
+    {field_name, 0},
+  [9] =
+    {field_statement, 0, .inherited = true},
+  [10] =
+    {field_content, 1},
+    {field_start, 0},
+  [12] =
+    {field_hash_bang, 0},
+    {field_statement, 1},
+  [14] =
+    {field_hash_bang, 0},
+    {field_statement, 1, .inherited = true},
+  [16] =
+    {field_value, 1, .inherited = true},
+    {field_values, 1},
+  [18] =
+    {field_value, 0},
+  [19] =
+    {field_content, 0, .inherited = true},
+    {field_end, 0, .inherited = true},
+    {field_start, 0, .inherited = true},
+  [22] =
+    {field_label, 1},
+  [23] =
+    {field_field, 0, .inherited = true},
+    {field_table, 0, .inherited = true},
+  [25] =
+    {field_method, 0, .inherited = true},
+    {field_table, 0, .inherited = true},
+  [27] =
+    {field_declaration, 1},
+    {field_values, 1, .inherited = true},
+    {field_variables, 1, .inherited = true},
+  [30] =
+    {field_attribute, 1, .inherited = true},
+    {field_declaration, 1},
+    {field_name, 1, .inherited = true},
+  [33] =
+    {field_argument, 0},
+  [34] =
+    {field_arguments, 1},
+    {field_name, 0},
+  [36] =
+    {field_name, 0},
+    {field_name, 1, .inherited = true},
+  [38] =
+    {field_statement, 0, .inherited = true},
+    {field_statement, 1},
+  [40] =
+    {field_statement, 0, .inherited = true},
+    {field_statement, 1, .inherited = true},
+  [42] =
+    {field_content, 1},
+    {field_end, 2},
+    {field_start, 0},
+  [45] =
+    {field_hash_bang, 0},
+    {field_statement, 1, .inherited = true},
+    {field_statement, 2},
+  [48] =
+    {field_body, 1, .inherited = true},
+    {field_parameters, 1, .inherited = true},
+  [50] =
+    {field_end, 1},
+    {field_start, 0},
+  [52] =
+    {field_field, 0},
+  [53] =
+    {field_operand, 1},
+    {field_operator, 0},
+  [55] =
+    {field_value, 0},
+    {field_value, 1, .inherited = true},
+  [57] =
+    {field_name, 1},
+  [58] =
+    {field_body, 1},
+    {field_statement, 1, .inherited = true},
+  [60] =
+    {field_condition, 2},
+  [61] =
+    {field_body, 2, .inherited = true},
+    {field_name, 1},
+    {field_parameters, 2, .inherited = true},
+  [64] =
+    {field_attribute, 1},
+    {field_name, 0},
+  [66] =
+    {field_attribute, 1, .inherited = true},
+    {field_name, 0},
+    {field_name, 1, .inherited = true},
+  [69] =
+    {field_expression, 1},
+  [70] =
+    {field_name, 0, .inherited = true},
+    {field_value, 2, .inherited = true},
+    {field_values, 2},
+    {field_variables, 0},
+  [74] =
+    {field_field, 2},
+    {field_table, 0},
+  [76] =
+    {field_method, 2},
+    {field_table, 0},
+  [78] =
+    {field_name, 0, .inherited = true},
+    {field_name, 1, .inherited = true},
+  [80] =
+    {field_vararg, 0},
+  [81] =
+    {field_parameters, 0},
+  [82] =
+    {field_field, 1, .inherited = true},
+  [83] =
+    {field_field, 0},
+    {field_field, 1, .inherited = true},
+  [85] =
+    {field_value, 1},
+  [86] =
+    {field_left, 0},
+    {field_operator, 1},
+    {field_right, 2},
+  [89] =
+    {field_value, 0, .inherited = true},
+    {field_value, 1, .inherited = true},
+  [91] =
+    {field_condition, 1},
+  [92] =
+    {field_body, 1},
+    {field_condition, 3},
+    {field_statement, 1, .inherited = true},
+  [95] =
+    {field_alternative, 0},
+  [96] =
+    {field_clause, 1},
+  [97] =
+    {field_name, 0, .inherited = true},
+    {field_names, 0},
+    {field_value, 2, .inherited = true},
+    {field_values, 2},
+  [101] =
+    {field_body, 3, .inherited = true},
+    {field_name, 2},
+    {field_parameters, 3, .inherited = true},
+  [104] =
+    {field_attribute, 0, .inherited = true},
+    {field_name, 0, .inherited = true},
+    {field_value, 2, .inherited = true},
+    {field_values, 2},
+    {field_variables, 0},
+  [109] =
+    {field_attribute, 1},
+    {field_attribute, 2, .inherited = true},
+    {field_name, 0},
+    {field_name, 2, .inherited = true},
+  [113] =
+    {field_attribute, 0, .inherited = true},
+    {field_attribute, 1, .inherited = true},
+    {field_name, 0, .inherited = true},
+    {field_name, 1, .inherited = true},
+  [117] =
+    {field_argument, 1},
+  [118] =
+    {field_name, 1, .inherited = true},
+    {field_vararg, 1, .inherited = true},
+  [120] =
+    {field_body, 1},
+    {field_parameters, 0},
+    {field_statement, 1, .inherited = true},
+  [123] =
+    {field_field, 1},
+  [124] =
+    {field_field, 0, .inherited = true},
+    {field_field, 1, .inherited = true},
+  [126] =
+    {field_name, 0},
+    {field_value, 2},
+  [128] =
+    {field_body, 3},
+    {field_condition, 1},
+    {field_statement, 3, .inherited = true},
+  [131] =
+    {field_condition, 1},
+    {field_consequence, 3},
+    {field_statement, 3, .inherited = true},
+  [134] =
+    {field_alternative, 3},
+    {field_condition, 1},
+  [136] =
+    {field_alternative, 3, .inherited = true},
+    {field_condition, 1},
+  [138] =
+    {field_alternative, 0, .inherited = true},
+    {field_alternative, 1, .inherited = true},
+  [140] =
+    {field_body, 3},
+    {field_clause, 1},
+    {field_statement, 3, .inherited = true},
+  [143] =
+    {field_attribute, 2},
+    {field_name, 1},
+  [145] =
+    {field_argument, 1},
+    {field_argument, 2, .inherited = true},
+  [147] =
+    {field_argument, 0, .inherited = true},
+    {field_argument, 1, .inherited = true},
+  [149] =
+    {field_name, 0},
+    {field_vararg, 2},
+  [151] =
+    {field_alternative, 4},
+    {field_condition, 1},
+    {field_consequence, 3},
+    {field_statement, 3, .inherited = true},
+  [155] =
+    {field_alternative, 4, .inherited = true},
+    {field_condition, 1},
+    {field_consequence, 3},
+    {field_statement, 3, .inherited = true},
+  [159] =
+    {field_alternative, 3, .inherited = true},
+    {field_alternative, 4},
+    {field_condition, 1},
+  [162] =
+    {field_end, 4},
+    {field_name, 0},
+    {field_start, 2},
+  [165] =
+    {field_name, 0},
+    {field_name, 1, .inherited = true},
+    {field_vararg, 3},
+  [168] =
+    {field_name, 1},
+    {field_value, 4},
+  [170] =
+    {field_alternative, 4, .inherited = true},
+    {field_alternative, 5},
+    {field_condition, 1},
+    {field_consequence, 3},
+    {field_statement, 3, .inherited = true},
+  [175] =
+    {field_end, 4},
+    {field_name, 0},
+    {field_start, 2},
+    {field_step, 6},
+};
+
+static const TSSymbol ts_alias_sequences[PRODUCTION_ID_COUNT][MAX_ALIAS_SEQUENCE_LENGTH] = {
+  [0] = {0},
+  [44] = {
+    [1] = sym__block_string_content,
+  },
+  [48] = {
+    [1] = anon_sym_or,
+  },
+};
+
+static const uint16_t ts_non_terminal_alias_map[] = {
+  aux_sym__doublequote_string_content, 2,
+    aux_sym__doublequote_string_content,
+    sym__block_string_content,
+  aux_sym__singlequote_string_content, 2,
+    aux_sym__singlequote_string_content,
+    sym__block_string_content,
+  0,
+};
+
+static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
+  [0] = 0,
+  [1] = 1,
+  [2] = 2,
+  [3] = 3,
+  [4] = 4,
+  [5] = 5,
+  [6] = 6,
+  [7] = 7,
+  [8] = 8,
+  [9] = 9,
+  [10] = 10,
+  [11] = 11,
+  [12] = 12,
+  [13] = 13,
+  [14] = 14,
+  [15] = 15,
+  [16] = 16,
+  [17] = 17,
+  [18] = 18,
+  [19] = 19,
+  [20] = 20,
+  [21] = 21,
+  [22] = 22,
+  [23] = 23,
+  [24] = 24,
+  [25] = 15,
+  [26] = 14,
+  [27] = 18,
+  [28] = 28,
+  [29] = 19,
+  [30] = 20,
+  [31] = 6,
+  [32] = 32,
+  [33] = 33,
+  [34] = 34,
+  [35] = 35,
+  [36] = 36,
+  [37] = 37,
+  [38] = 38,
+  [39] = 39,
+  [40] = 40,
+  [41] = 41,
+  [42] = 42,
+  [43] = 43,
+  [44] = 16,
+  [45] = 17,
+  [46] = 46,
+  [47] = 47,
+  [48] = 48,
+  [49] = 49,
+  [50] = 50,
+  [51] = 51,
+  [52] = 52,
+  [53] = 53,
+  [54] = 54,
+  [55] = 55,
+  [56] = 56,
+  [57] = 57,
+  [58] = 58,
+  [59] = 59,
+  [60] = 60,
+  [61] = 61,
+  [62] = 62,
+  [63] = 63,
+  [64] = 63,
+  [65] = 65,
+  [66] = 66,
+  [67] = 62,
+  [68] = 68,
+  [69] = 69,
+  [70] = 70,
+  [71] = 71,
+  [72] = 72,
+  [73] = 73,
+  [74] = 74,
+  [75] = 75,
+  [76] = 76,
+  [77] = 77,
+  [78] = 78,
+  [79] = 79,
+  [80] = 80,
+  [81] = 81,
+  [82] = 82,
+  [83] = 83,
+  [84] = 84,
+  [85] = 85,
+  [86] = 86,
+  [87] = 87,
+  [88] = 88,
+  [89] = 89,
+  [90] = 90,
+  [91] = 91,
+  [92] = 92,
+  [93] = 93,
+  [94] = 94,
+  [95] = 95,
+  [96] = 96,
+  [97] = 97,
+  [98] = 98,
+  [99] = 99,
+  [100] = 100,
+  [101] = 101,
+  [102] = 102,
+  [103] = 3,
+  [104] = 104,
+  [105] = 105,
+  [106] = 106,
+  [107] = 107,
+  [108] = 108,
+  [109] = 109,
+  [110] = 110,
+  [111] = 111,
+  [112] = 112,
+  [113] = 113,
+  [114] = 114,
+  [115] = 115,
+  [116] = 116,
+  [117] = 117,
+  [118] = 118,
+  [119] = 119,
+  [120] = 120,
+  [121] = 121,
+  [122] = 122,
+  [123] = 123,
+  [124] = 124,
+  [125] = 125,
+  [126] = 126,
+  [127] = 127,
+  [128] = 128,
+  [129] = 129,
+  [130] = 130,
+  [131] = 131,
+  [132] = 132,
+  [133] = 133,
+  [134] = 134,
+  [135] = 135,
+  [136] = 136,
+  [137] = 137,
+  [138] = 138,
+  [139] = 139,
+  [140] = 140,
+  [141] = 141,
+  [142] = 142,
+  [143] = 143,
+  [144] = 144,
+  [145] = 145,
+  [146] = 146,
+  [147] = 147,
+  [148] = 148,
+  [149] = 149,
+  [150] = 150,
+  [151] = 151,
+  [152] = 152,
+  [153] = 153,
+  [154] = 154,
+  [155] = 155,
+  [156] = 156,
+  [157] = 157,
+  [158] = 158,
+  [159] = 159,
+  [160] = 160,
+  [161] = 161,
+  [162] = 162,
+  [163] = 163,
+  [164] = 164,
+  [165] = 165,
+  [166] = 166,
+  [167] = 167,
+  [168] = 168,
+  [169] = 169,
+  [170] = 170,
+  [171] = 171,
+  [172] = 172,
+  [173] = 173,
+  [174] = 174,
+  [175] = 175,
+  [176] = 176,
+  [177] = 177,
+  [178] = 178,
+  [179] = 179,
+  [180] = 180,
+  [181] = 181,
+  [182] = 182,
+  [183] = 183,
+  [184] = 184,
+  [185] = 179,
+  [186] = 182,
+  [187] = 187,
+  [188] = 188,
+  [189] = 189,
+  [190] = 190,
+  [191] = 191,
+  [192] = 188,
+  [193] = 193,
+  [194] = 194,
+  [195] = 195,
+  [196] = 196,
+  [197] = 187,
+  [198] = 198,
+  [199] = 199,
+  [200] = 200,
+  [201] = 201,
+  [202] = 202,
+  [203] = 203,
+  [204] = 204,
+  [205] = 205,
+  [206] = 206,
+  [207] = 207,
+  [208] = 208,
+  [209] = 209,
+  [210] = 210,
+  [211] = 211,
+  [212] = 212,
+  [213] = 213,
+  [214] = 214,
+  [215] = 215,
+  [216] = 216,
+  [217] = 217,
+  [218] = 218,
+  [219] = 219,
+  [220] = 220,
+  [221] = 221,
+  [222] = 222,
+  [223] = 223,
+  [224] = 224,
+  [225] = 225,
+  [226] = 226,
+  [227] = 227,
+  [228] = 228,
+  [229] = 229,
+  [230] = 230,
+  [231] = 231,
+  [232] = 232,
+  [233] = 233,
+  [234] = 234,
+  [235] = 235,
+  [236] = 236,
+  [237] = 237,
+  [238] = 238,
+  [239] = 239,
+  [240] = 240,
+  [241] = 241,
+  [242] = 242,
+  [243] = 243,
+  [244] = 244,
+  [245] = 245,
+  [246] = 246,
+  [247] = 247,
+  [248] = 248,
+  [249] = 249,
+  [250] = 250,
+  [251] = 251,
+  [252] = 252,
+  [253] = 253,
+  [254] = 254,
+  [255] = 255,
+  [256] = 256,
+  [257] = 257,
+  [258] = 258,
+  [259] = 259,
+  [260] = 260,
+  [261] = 261,
+  [262] = 261,
+  [263] = 230,
+  [264] = 264,
+  [265] = 234,
+  [266] = 266,
+  [267] = 240,
+  [268] = 233,
+  [269] = 269,
+  [270] = 270,
+  [271] = 271,
+};
+
+static TSCharacterRange sym_escape_sequence_character_set_1[] = {
+  {'\n', '\n'}, {'"', '"'}, {'\'', '\''}, {'0', '9'}, {'\\', '\\'}, {'a', 'b'}, {'f', 'f'}, {'n', 'n'},
+  {'r', 'r'}, {'t', 'v'}, {'x', 'x'}, {'z', 'z'},
+};
+
+static bool ts_lex(TSLexer *lexer, TSStateId state) {
+  START_LEXER();
+  eof = lexer->eof(lexer);
+  switch (state) {
+    case 0:
+      if (eof) ADVANCE(27);
+      ADVANCE_MAP(
+        '"', 50,
+        '#', 95,
+        '%', 92,
+        '&', 84,
+        '\'', 51,
+        '(', 71,
+        ')', 72,
+        '*', 89,
+        '+', 87,
+        ',', 32,
+        '-', 88,
+        '.', 36,
+        '/', 90,
+        '0', 44,
+        ':', 38,
+        ';', 29,
+        '<', 40,
+        '=', 31,
+        '>', 42,
+        '[', 73,
+        '\\', 11,
+        ']', 74,
+        '^', 94,
+        '{', 75,
+        '|', 81,
+        '}', 76,
+        '~', 83,
+        0xb5, 96,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(22);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(45);
+      if (lookahead > ' ' &&
+          (lookahead < '{' || 0x9f < lookahead)) ADVANCE(97);
+      END_STATE();
+    case 1:
+      if (lookahead == '"') ADVANCE(50);
+      if (lookahead == '-') ADVANCE(54);
+      if (lookahead == '\\') ADVANCE(11);
+      if (lookahead == 0xb5) ADVANCE(56);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(52);
+      if (lookahead != 0) ADVANCE(58);
+      END_STATE();
+    case 2:
+      if (lookahead == '"') ADVANCE(50);
+      if (lookahead == '-') ADVANCE(54);
+      if (lookahead == '\\') ADVANCE(11);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(53);
+      if (lookahead != 0) ADVANCE(58);
+      END_STATE();
+    case 3:
+      if (lookahead == '\'') ADVANCE(51);
+      if (lookahead == '-') ADVANCE(61);
+      if (lookahead == '\\') ADVANCE(11);
+      if (lookahead == 0xb5) ADVANCE(63);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(59);
+      if (lookahead != 0) ADVANCE(65);
+      END_STATE();
+    case 4:
+      if (lookahead == '\'') ADVANCE(51);
+      if (lookahead == '-') ADVANCE(61);
+      if (lookahead == '\\') ADVANCE(11);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(60);
+      if (lookahead != 0) ADVANCE(65);
+      END_STATE();
+    case 5:
+      if (lookahead == '-') ADVANCE(100);
+      END_STATE();
+    case 6:
+      if (lookahead == '.') ADVANCE(98);
+      END_STATE();
+    case 7:
+      if (lookahead == '.') ADVANCE(70);
+      END_STATE();
+    case 8:
+      if (lookahead == '.') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(46);
+      END_STATE();
+    case 9:
+      if (lookahead == '.') ADVANCE(6);
+      END_STATE();
+    case 10:
+      if (lookahead == '.') ADVANCE(7);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(47);
+      END_STATE();
+    case 11:
+      if (lookahead == 'u') ADVANCE(12);
+      if (lookahead == 'x') ADVANCE(21);
+      if (lookahead == 'z') ADVANCE(67);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(69);
+      if (set_contains(sym_escape_sequence_character_set_1, 12, lookahead)) ADVANCE(66);
+      END_STATE();
+    case 12:
+      if (lookahead == '{') ADVANCE(20);
+      END_STATE();
+    case 13:
+      if (lookahead == '}') ADVANCE(66);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(13);
+      END_STATE();
+    case 14:
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(17);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(49);
+      END_STATE();
+    case 15:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(43);
+      END_STATE();
+    case 16:
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(15);
+      END_STATE();
+    case 17:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(49);
+      END_STATE();
+    case 18:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(66);
+      END_STATE();
+    case 19:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(48);
+      END_STATE();
+    case 20:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(13);
+      END_STATE();
+    case 21:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(18);
+      END_STATE();
+    case 22:
+      if (eof) ADVANCE(27);
+      ADVANCE_MAP(
+        '"', 50,
+        '#', 95,
+        '%', 92,
+        '&', 84,
+        '\'', 51,
+        '(', 71,
+        ')', 72,
+        '*', 89,
+        '+', 87,
+        ',', 32,
+        '-', 88,
+        '.', 36,
+        '/', 90,
+        '0', 44,
+        ':', 38,
+        ';', 29,
+        '<', 40,
+        '=', 31,
+        '>', 42,
+        '[', 73,
+        ']', 74,
+        '^', 94,
+        '{', 75,
+        '|', 81,
+        '}', 76,
+        '~', 83,
+        0xb5, 96,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(22);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(45);
+      if (lookahead > ' ' &&
+          (lookahead < '[' || '^' < lookahead) &&
+          (lookahead < '{' || 0x9f < lookahead)) ADVANCE(97);
+      END_STATE();
+    case 23:
+      if (eof) ADVANCE(27);
+      ADVANCE_MAP(
+        '"', 50,
+        '#', 95,
+        '\'', 51,
+        '(', 71,
+        ')', 72,
+        '-', 88,
+        '.', 10,
+        '0', 44,
+        ';', 29,
+        '>', 41,
+        '[', 73,
+        '{', 75,
+        '}', 76,
+        '~', 82,
+        0xb5, 96,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(23);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(45);
+      if (lookahead > ' ' &&
+          (lookahead < '%' || '>' < lookahead) &&
+          (lookahead < '[' || '^' < lookahead) &&
+          (lookahead < '{' || 0x9f < lookahead)) ADVANCE(97);
+      END_STATE();
+    case 24:
+      if (eof) ADVANCE(27);
+      ADVANCE_MAP(
+        '"', 50,
+        '#', 28,
+        '%', 92,
+        '&', 84,
+        '\'', 51,
+        '(', 71,
+        ')', 72,
+        '*', 89,
+        '+', 87,
+        ',', 32,
+        '-', 88,
+        '.', 35,
+        '/', 90,
+        ':', 38,
+        ';', 29,
+        '<', 40,
+        '=', 31,
+        '>', 42,
+        '[', 73,
+        ']', 74,
+        '^', 94,
+        '{', 75,
+        '|', 81,
+        '}', 76,
+        '~', 83,
+        0xb5, 96,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(24);
+      if (lookahead > ' ' &&
+          (lookahead < '%' || '>' < lookahead) &&
+          (lookahead < '[' || '^' < lookahead) &&
+          (lookahead < '{' || 0x9f < lookahead)) ADVANCE(97);
+      END_STATE();
+    case 25:
+      if (eof) ADVANCE(27);
+      ADVANCE_MAP(
+        '"', 50,
+        '%', 92,
+        '&', 84,
+        '\'', 51,
+        '(', 71,
+        ')', 72,
+        '*', 89,
+        '+', 87,
+        ',', 32,
+        '-', 88,
+        '.', 35,
+        '/', 90,
+        ':', 37,
+        ';', 29,
+        '<', 40,
+        '=', 31,
+        '>', 42,
+        '[', 73,
+        ']', 74,
+        '^', 94,
+        '{', 75,
+        '|', 81,
+        '}', 76,
+        '~', 83,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(25);
+      if (lookahead > ' ' &&
+          lookahead != '"' &&
+          lookahead != '#' &&
+          (lookahead < '%' || '>' < lookahead) &&
+          (lookahead < '[' || '^' < lookahead) &&
+          (lookahead < '{' || 0x9f < lookahead)) ADVANCE(97);
+      END_STATE();
+    case 26:
+      if (eof) ADVANCE(27);
+      ADVANCE_MAP(
+        '(', 71,
+        ')', 72,
+        ',', 32,
+        '-', 5,
+        '.', 34,
+        ':', 38,
+        ';', 29,
+        '<', 39,
+        '=', 30,
+        '>', 41,
+        0xb5, 96,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(26);
+      if (lookahead > ' ' &&
+          lookahead != '"' &&
+          lookahead != '#' &&
+          (lookahead < '%' || '>' < lookahead) &&
+          (lookahead < '[' || '^' < lookahead) &&
+          (lookahead < '{' || 0x9f < lookahead)) ADVANCE(97);
+      END_STATE();
+    case 27:
+      ACCEPT_TOKEN(ts_builtin_sym_end);
+      END_STATE();
+    case 28:
+      ACCEPT_TOKEN(sym_hash_bang_line);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(28);
+      END_STATE();
+    case 29:
+      ACCEPT_TOKEN(anon_sym_SEMI);
+      END_STATE();
+    case 30:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      END_STATE();
+    case 31:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      if (lookahead == '=') ADVANCE(78);
+      END_STATE();
+    case 32:
+      ACCEPT_TOKEN(anon_sym_COMMA);
+      END_STATE();
+    case 33:
+      ACCEPT_TOKEN(anon_sym_COLON_COLON);
+      END_STATE();
+    case 34:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      END_STATE();
+    case 35:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      if (lookahead == '.') ADVANCE(93);
+      END_STATE();
+    case 36:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      if (lookahead == '.') ADVANCE(93);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(47);
+      END_STATE();
+    case 37:
+      ACCEPT_TOKEN(anon_sym_COLON);
+      END_STATE();
+    case 38:
+      ACCEPT_TOKEN(anon_sym_COLON);
+      if (lookahead == ':') ADVANCE(33);
+      END_STATE();
+    case 39:
+      ACCEPT_TOKEN(anon_sym_LT);
+      END_STATE();
+    case 40:
+      ACCEPT_TOKEN(anon_sym_LT);
+      if (lookahead == '<') ADVANCE(85);
+      if (lookahead == '=') ADVANCE(77);
+      END_STATE();
+    case 41:
+      ACCEPT_TOKEN(anon_sym_GT);
+      END_STATE();
+    case 42:
+      ACCEPT_TOKEN(anon_sym_GT);
+      if (lookahead == '=') ADVANCE(80);
+      if (lookahead == '>') ADVANCE(86);
+      END_STATE();
+    case 43:
+      ACCEPT_TOKEN(sym_number);
+      END_STATE();
+    case 44:
+      ACCEPT_TOKEN(sym_number);
+      ADVANCE_MAP(
+        '.', 47,
+        'E', 14,
+        'e', 14,
+        'I', 43,
+        'i', 43,
+        'L', 15,
+        'l', 15,
+        'U', 16,
+        'u', 16,
+        'X', 8,
+        'x', 8,
+      );
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(45);
+      END_STATE();
+    case 45:
+      ACCEPT_TOKEN(sym_number);
+      ADVANCE_MAP(
+        '.', 47,
+        'E', 14,
+        'e', 14,
+        'I', 43,
+        'i', 43,
+        'L', 15,
+        'l', 15,
+        'U', 16,
+        'u', 16,
+      );
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(45);
+      END_STATE();
+    case 46:
+      ACCEPT_TOKEN(sym_number);
+      ADVANCE_MAP(
+        '.', 48,
+        'I', 43,
+        'i', 43,
+        'L', 15,
+        'l', 15,
+        'P', 14,
+        'p', 14,
+        'U', 16,
+        'u', 16,
+      );
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(46);
+      END_STATE();
+    case 47:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(14);
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(43);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(47);
+      END_STATE();
+    case 48:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(43);
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(14);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(48);
+      END_STATE();
+    case 49:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == 'I' ||
+          lookahead == 'i') ADVANCE(43);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(49);
+      END_STATE();
+    case 50:
+      ACCEPT_TOKEN(anon_sym_DQUOTE);
+      END_STATE();
+    case 51:
+      ACCEPT_TOKEN(anon_sym_SQUOTE);
+      END_STATE();
+    case 52:
+      ACCEPT_TOKEN(aux_sym__doublequote_string_content_token1);
+      if (lookahead == '-') ADVANCE(54);
+      if (lookahead == 0xb5) ADVANCE(56);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(52);
+      if (lookahead != 0 &&
+          lookahead != '"' &&
+          lookahead != '\\') ADVANCE(58);
+      END_STATE();
+    case 53:
+      ACCEPT_TOKEN(aux_sym__doublequote_string_content_token1);
+      if (lookahead == '-') ADVANCE(54);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(53);
+      if (lookahead != 0 &&
+          lookahead != '"' &&
+          lookahead != '\\') ADVANCE(58);
+      END_STATE();
+    case 54:
+      ACCEPT_TOKEN(aux_sym__doublequote_string_content_token1);
+      if (lookahead == '-') ADVANCE(58);
+      if (lookahead != 0 &&
+          lookahead != '"' &&
+          lookahead != '\\') ADVANCE(58);
+      END_STATE();
+    case 55:
+      ACCEPT_TOKEN(aux_sym__doublequote_string_content_token1);
+      if (lookahead == '.') ADVANCE(98);
+      if (lookahead != 0 &&
+          lookahead != '"' &&
+          lookahead != '\\') ADVANCE(58);
+      END_STATE();
+    case 56:
+      ACCEPT_TOKEN(aux_sym__doublequote_string_content_token1);
+      if (lookahead == '.') ADVANCE(57);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      if (lookahead != 0 &&
+          lookahead != '"' &&
+          lookahead != '\\') ADVANCE(58);
+      END_STATE();
+    case 57:
+      ACCEPT_TOKEN(aux_sym__doublequote_string_content_token1);
+      if (lookahead == '.') ADVANCE(55);
+      if (lookahead != 0 &&
+          lookahead != '"' &&
+          lookahead != '\\') ADVANCE(58);
+      END_STATE();
+    case 58:
+      ACCEPT_TOKEN(aux_sym__doublequote_string_content_token1);
+      if (lookahead != 0 &&
+          lookahead != '"' &&
+          lookahead != '\\') ADVANCE(58);
+      END_STATE();
+    case 59:
+      ACCEPT_TOKEN(aux_sym__singlequote_string_content_token1);
+      if (lookahead == '-') ADVANCE(61);
+      if (lookahead == 0xb5) ADVANCE(63);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(59);
+      if (lookahead != 0 &&
+          lookahead != '\'' &&
+          lookahead != '\\') ADVANCE(65);
+      END_STATE();
+    case 60:
+      ACCEPT_TOKEN(aux_sym__singlequote_string_content_token1);
+      if (lookahead == '-') ADVANCE(61);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(60);
+      if (lookahead != 0 &&
+          lookahead != '\'' &&
+          lookahead != '\\') ADVANCE(65);
+      END_STATE();
+    case 61:
+      ACCEPT_TOKEN(aux_sym__singlequote_string_content_token1);
+      if (lookahead == '-') ADVANCE(65);
+      if (lookahead != 0 &&
+          lookahead != '\'' &&
+          lookahead != '\\') ADVANCE(65);
+      END_STATE();
+    case 62:
+      ACCEPT_TOKEN(aux_sym__singlequote_string_content_token1);
+      if (lookahead == '.') ADVANCE(98);
+      if (lookahead != 0 &&
+          lookahead != '\'' &&
+          lookahead != '\\') ADVANCE(65);
+      END_STATE();
+    case 63:
+      ACCEPT_TOKEN(aux_sym__singlequote_string_content_token1);
+      if (lookahead == '.') ADVANCE(64);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      if (lookahead != 0 &&
+          lookahead != '\'' &&
+          lookahead != '\\') ADVANCE(65);
+      END_STATE();
+    case 64:
+      ACCEPT_TOKEN(aux_sym__singlequote_string_content_token1);
+      if (lookahead == '.') ADVANCE(62);
+      if (lookahead != 0 &&
+          lookahead != '\'' &&
+          lookahead != '\\') ADVANCE(65);
+      END_STATE();
+    case 65:
+      ACCEPT_TOKEN(aux_sym__singlequote_string_content_token1);
+      if (lookahead != 0 &&
+          lookahead != '\'' &&
+          lookahead != '\\') ADVANCE(65);
+      END_STATE();
+    case 66:
+      ACCEPT_TOKEN(sym_escape_sequence);
+      END_STATE();
+    case 67:
+      ACCEPT_TOKEN(sym_escape_sequence);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(67);
+      END_STATE();
+    case 68:
+      ACCEPT_TOKEN(sym_escape_sequence);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(66);
+      END_STATE();
+    case 69:
+      ACCEPT_TOKEN(sym_escape_sequence);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(68);
+      END_STATE();
+    case 70:
+      ACCEPT_TOKEN(sym_vararg_expression);
+      END_STATE();
+    case 71:
+      ACCEPT_TOKEN(anon_sym_LPAREN);
+      END_STATE();
+    case 72:
+      ACCEPT_TOKEN(anon_sym_RPAREN);
+      END_STATE();
+    case 73:
+      ACCEPT_TOKEN(anon_sym_LBRACK);
+      END_STATE();
+    case 74:
+      ACCEPT_TOKEN(anon_sym_RBRACK);
+      END_STATE();
+    case 75:
+      ACCEPT_TOKEN(anon_sym_LBRACE);
+      END_STATE();
+    case 76:
+      ACCEPT_TOKEN(anon_sym_RBRACE);
+      END_STATE();
+    case 77:
+      ACCEPT_TOKEN(anon_sym_LT_EQ);
+      END_STATE();
+    case 78:
+      ACCEPT_TOKEN(anon_sym_EQ_EQ);
+      END_STATE();
+    case 79:
+      ACCEPT_TOKEN(anon_sym_TILDE_EQ);
+      END_STATE();
+    case 80:
+      ACCEPT_TOKEN(anon_sym_GT_EQ);
+      END_STATE();
+    case 81:
+      ACCEPT_TOKEN(anon_sym_PIPE);
+      END_STATE();
+    case 82:
+      ACCEPT_TOKEN(anon_sym_TILDE);
+      END_STATE();
+    case 83:
+      ACCEPT_TOKEN(anon_sym_TILDE);
+      if (lookahead == '=') ADVANCE(79);
+      END_STATE();
+    case 84:
+      ACCEPT_TOKEN(anon_sym_AMP);
+      END_STATE();
+    case 85:
+      ACCEPT_TOKEN(anon_sym_LT_LT);
+      END_STATE();
+    case 86:
+      ACCEPT_TOKEN(anon_sym_GT_GT);
+      END_STATE();
+    case 87:
+      ACCEPT_TOKEN(anon_sym_PLUS);
+      END_STATE();
+    case 88:
+      ACCEPT_TOKEN(anon_sym_DASH);
+      if (lookahead == '-') ADVANCE(100);
+      END_STATE();
+    case 89:
+      ACCEPT_TOKEN(anon_sym_STAR);
+      END_STATE();
+    case 90:
+      ACCEPT_TOKEN(anon_sym_SLASH);
+      if (lookahead == '/') ADVANCE(91);
+      END_STATE();
+    case 91:
+      ACCEPT_TOKEN(anon_sym_SLASH_SLASH);
+      END_STATE();
+    case 92:
+      ACCEPT_TOKEN(anon_sym_PERCENT);
+      END_STATE();
+    case 93:
+      ACCEPT_TOKEN(anon_sym_DOT_DOT);
+      END_STATE();
+    case 94:
+      ACCEPT_TOKEN(anon_sym_CARET);
+      END_STATE();
+    case 95:
+      ACCEPT_TOKEN(anon_sym_POUND);
+      END_STATE();
+    case 96:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == '.') ADVANCE(9);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      if (lookahead > ' ' &&
+          lookahead != '"' &&
+          lookahead != '#' &&
+          (lookahead < '%' || '/' < lookahead) &&
+          (lookahead < ':' || '>' < lookahead) &&
+          (lookahead < 'A' || '_' < lookahead) &&
+          (lookahead < 'a' || 0x9f < lookahead)) ADVANCE(97);
+      END_STATE();
+    case 97:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead > ' ' &&
+          lookahead != '"' &&
+          lookahead != '#' &&
+          (lookahead < '%' || '/' < lookahead) &&
+          (lookahead < ':' || '>' < lookahead) &&
+          (lookahead < '[' || '^' < lookahead) &&
+          (lookahead < '{' || 0x9f < lookahead)) ADVANCE(97);
+      END_STATE();
+    case 98:
+      ACCEPT_TOKEN(sym_grit_metavariable);
+      END_STATE();
+    case 99:
+      ACCEPT_TOKEN(sym_grit_metavariable);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      END_STATE();
+    case 100:
+      ACCEPT_TOKEN(anon_sym_DASH_DASH);
+      END_STATE();
+    case 101:
+      ACCEPT_TOKEN(anon_sym_DASH_DASH);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\r') ADVANCE(104);
+      END_STATE();
+    case 102:
+      ACCEPT_TOKEN(aux_sym_comment_token1);
+      if (lookahead == '-') ADVANCE(103);
+      if (lookahead == '\t' ||
+          lookahead == 0x0b ||
+          lookahead == '\f' ||
+          lookahead == ' ') ADVANCE(102);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead)) ADVANCE(104);
+      END_STATE();
+    case 103:
+      ACCEPT_TOKEN(aux_sym_comment_token1);
+      if (lookahead == '-') ADVANCE(101);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\r') ADVANCE(104);
+      END_STATE();
+    case 104:
+      ACCEPT_TOKEN(aux_sym_comment_token1);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\r') ADVANCE(104);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static bool ts_lex_keywords(TSLexer *lexer, TSStateId state) {
+  START_LEXER();
+  eof = lexer->eof(lexer);
+  switch (state) {
+    case 0:
+      ADVANCE_MAP(
+        'a', 1,
+        'b', 2,
+        'd', 3,
+        'e', 4,
+        'f', 5,
+        'g', 6,
+        'i', 7,
+        'l', 8,
+        'n', 9,
+        'o', 10,
+        'r', 11,
+        't', 12,
+        'u', 13,
+        'w', 14,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(0);
+      END_STATE();
+    case 1:
+      if (lookahead == 'n') ADVANCE(15);
+      END_STATE();
+    case 2:
+      if (lookahead == 'r') ADVANCE(16);
+      END_STATE();
+    case 3:
+      if (lookahead == 'o') ADVANCE(17);
+      END_STATE();
+    case 4:
+      if (lookahead == 'l') ADVANCE(18);
+      if (lookahead == 'n') ADVANCE(19);
+      END_STATE();
+    case 5:
+      if (lookahead == 'a') ADVANCE(20);
+      if (lookahead == 'o') ADVANCE(21);
+      if (lookahead == 'u') ADVANCE(22);
+      END_STATE();
+    case 6:
+      if (lookahead == 'o') ADVANCE(23);
+      END_STATE();
+    case 7:
+      if (lookahead == 'f') ADVANCE(24);
+      if (lookahead == 'n') ADVANCE(25);
+      END_STATE();
+    case 8:
+      if (lookahead == 'o') ADVANCE(26);
+      END_STATE();
+    case 9:
+      if (lookahead == 'i') ADVANCE(27);
+      if (lookahead == 'o') ADVANCE(28);
+      END_STATE();
+    case 10:
+      if (lookahead == 'r') ADVANCE(29);
+      END_STATE();
+    case 11:
+      if (lookahead == 'e') ADVANCE(30);
+      END_STATE();
+    case 12:
+      if (lookahead == 'h') ADVANCE(31);
+      if (lookahead == 'r') ADVANCE(32);
+      END_STATE();
+    case 13:
+      if (lookahead == 'n') ADVANCE(33);
+      END_STATE();
+    case 14:
+      if (lookahead == 'h') ADVANCE(34);
+      END_STATE();
+    case 15:
+      if (lookahead == 'd') ADVANCE(35);
+      END_STATE();
+    case 16:
+      if (lookahead == 'e') ADVANCE(36);
+      END_STATE();
+    case 17:
+      ACCEPT_TOKEN(anon_sym_do);
+      END_STATE();
+    case 18:
+      if (lookahead == 's') ADVANCE(37);
+      END_STATE();
+    case 19:
+      if (lookahead == 'd') ADVANCE(38);
+      END_STATE();
+    case 20:
+      if (lookahead == 'l') ADVANCE(39);
+      END_STATE();
+    case 21:
+      if (lookahead == 'r') ADVANCE(40);
+      END_STATE();
+    case 22:
+      if (lookahead == 'n') ADVANCE(41);
+      END_STATE();
+    case 23:
+      if (lookahead == 't') ADVANCE(42);
+      END_STATE();
+    case 24:
+      ACCEPT_TOKEN(anon_sym_if);
+      END_STATE();
+    case 25:
+      ACCEPT_TOKEN(anon_sym_in);
+      END_STATE();
+    case 26:
+      if (lookahead == 'c') ADVANCE(43);
+      END_STATE();
+    case 27:
+      if (lookahead == 'l') ADVANCE(44);
+      END_STATE();
+    case 28:
+      if (lookahead == 't') ADVANCE(45);
+      END_STATE();
+    case 29:
+      ACCEPT_TOKEN(anon_sym_or);
+      END_STATE();
+    case 30:
+      if (lookahead == 'p') ADVANCE(46);
+      if (lookahead == 't') ADVANCE(47);
+      END_STATE();
+    case 31:
+      if (lookahead == 'e') ADVANCE(48);
+      END_STATE();
+    case 32:
+      if (lookahead == 'u') ADVANCE(49);
+      END_STATE();
+    case 33:
+      if (lookahead == 't') ADVANCE(50);
+      END_STATE();
+    case 34:
+      if (lookahead == 'i') ADVANCE(51);
+      END_STATE();
+    case 35:
+      ACCEPT_TOKEN(anon_sym_and);
+      END_STATE();
+    case 36:
+      if (lookahead == 'a') ADVANCE(52);
+      END_STATE();
+    case 37:
+      if (lookahead == 'e') ADVANCE(53);
+      END_STATE();
+    case 38:
+      ACCEPT_TOKEN(anon_sym_end);
+      END_STATE();
+    case 39:
+      if (lookahead == 's') ADVANCE(54);
+      END_STATE();
+    case 40:
+      ACCEPT_TOKEN(anon_sym_for);
+      END_STATE();
+    case 41:
+      if (lookahead == 'c') ADVANCE(55);
+      END_STATE();
+    case 42:
+      if (lookahead == 'o') ADVANCE(56);
+      END_STATE();
+    case 43:
+      if (lookahead == 'a') ADVANCE(57);
+      END_STATE();
+    case 44:
+      ACCEPT_TOKEN(sym_nil);
+      END_STATE();
+    case 45:
+      ACCEPT_TOKEN(anon_sym_not);
+      END_STATE();
+    case 46:
+      if (lookahead == 'e') ADVANCE(58);
+      END_STATE();
+    case 47:
+      if (lookahead == 'u') ADVANCE(59);
+      END_STATE();
+    case 48:
+      if (lookahead == 'n') ADVANCE(60);
+      END_STATE();
+    case 49:
+      if (lookahead == 'e') ADVANCE(61);
+      END_STATE();
+    case 50:
+      if (lookahead == 'i') ADVANCE(62);
+      END_STATE();
+    case 51:
+      if (lookahead == 'l') ADVANCE(63);
+      END_STATE();
+    case 52:
+      if (lookahead == 'k') ADVANCE(64);
+      END_STATE();
+    case 53:
+      ACCEPT_TOKEN(anon_sym_else);
+      if (lookahead == 'i') ADVANCE(65);
+      END_STATE();
+    case 54:
+      if (lookahead == 'e') ADVANCE(66);
+      END_STATE();
+    case 55:
+      if (lookahead == 't') ADVANCE(67);
+      END_STATE();
+    case 56:
+      ACCEPT_TOKEN(anon_sym_goto);
+      END_STATE();
+    case 57:
+      if (lookahead == 'l') ADVANCE(68);
+      END_STATE();
+    case 58:
+      if (lookahead == 'a') ADVANCE(69);
+      END_STATE();
+    case 59:
+      if (lookahead == 'r') ADVANCE(70);
+      END_STATE();
+    case 60:
+      ACCEPT_TOKEN(anon_sym_then);
+      END_STATE();
+    case 61:
+      ACCEPT_TOKEN(sym_true);
+      END_STATE();
+    case 62:
+      if (lookahead == 'l') ADVANCE(71);
+      END_STATE();
+    case 63:
+      if (lookahead == 'e') ADVANCE(72);
+      END_STATE();
+    case 64:
+      ACCEPT_TOKEN(sym_break_statement);
+      END_STATE();
+    case 65:
+      if (lookahead == 'f') ADVANCE(73);
+      END_STATE();
+    case 66:
+      ACCEPT_TOKEN(sym_false);
+      END_STATE();
+    case 67:
+      if (lookahead == 'i') ADVANCE(74);
+      END_STATE();
+    case 68:
+      ACCEPT_TOKEN(anon_sym_local);
+      END_STATE();
+    case 69:
+      if (lookahead == 't') ADVANCE(75);
+      END_STATE();
+    case 70:
+      if (lookahead == 'n') ADVANCE(76);
+      END_STATE();
+    case 71:
+      ACCEPT_TOKEN(anon_sym_until);
+      END_STATE();
+    case 72:
+      ACCEPT_TOKEN(anon_sym_while);
+      END_STATE();
+    case 73:
+      ACCEPT_TOKEN(anon_sym_elseif);
+      END_STATE();
+    case 74:
+      if (lookahead == 'o') ADVANCE(77);
+      END_STATE();
+    case 75:
+      ACCEPT_TOKEN(anon_sym_repeat);
+      END_STATE();
+    case 76:
+      ACCEPT_TOKEN(anon_sym_return);
+      END_STATE();
+    case 77:
+      if (lookahead == 'n') ADVANCE(78);
+      END_STATE();
+    case 78:
+      ACCEPT_TOKEN(anon_sym_function);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static const TSLexMode ts_lex_modes[STATE_COUNT] = {
+  [0] = {.lex_state = 0, .external_lex_state = 1},
+  [1] = {.lex_state = 24, .external_lex_state = 2},
+  [2] = {.lex_state = 24, .external_lex_state = 3},
+  [3] = {.lex_state = 24, .external_lex_state = 3},
+  [4] = {.lex_state = 24, .external_lex_state = 3},
+  [5] = {.lex_state = 24, .external_lex_state = 3},
+  [6] = {.lex_state = 24, .external_lex_state = 3},
+  [7] = {.lex_state = 24, .external_lex_state = 3},
+  [8] = {.lex_state = 24, .external_lex_state = 3},
+  [9] = {.lex_state = 24, .external_lex_state = 3},
+  [10] = {.lex_state = 24, .external_lex_state = 3},
+  [11] = {.lex_state = 24, .external_lex_state = 3},
+  [12] = {.lex_state = 24, .external_lex_state = 3},
+  [13] = {.lex_state = 24, .external_lex_state = 3},
+  [14] = {.lex_state = 24, .external_lex_state = 3},
+  [15] = {.lex_state = 24, .external_lex_state = 3},
+  [16] = {.lex_state = 24, .external_lex_state = 3},
+  [17] = {.lex_state = 24, .external_lex_state = 3},
+  [18] = {.lex_state = 24, .external_lex_state = 3},
+  [19] = {.lex_state = 24, .external_lex_state = 3},
+  [20] = {.lex_state = 24, .external_lex_state = 3},
+  [21] = {.lex_state = 0, .external_lex_state = 2},
+  [22] = {.lex_state = 0, .external_lex_state = 2},
+  [23] = {.lex_state = 0, .external_lex_state = 2},
+  [24] = {.lex_state = 0, .external_lex_state = 2},
+  [25] = {.lex_state = 0, .external_lex_state = 2},
+  [26] = {.lex_state = 0, .external_lex_state = 2},
+  [27] = {.lex_state = 0, .external_lex_state = 2},
+  [28] = {.lex_state = 0, .external_lex_state = 2},
+  [29] = {.lex_state = 0, .external_lex_state = 2},
+  [30] = {.lex_state = 0, .external_lex_state = 2},
+  [31] = {.lex_state = 0, .external_lex_state = 2},
+  [32] = {.lex_state = 0, .external_lex_state = 2},
+  [33] = {.lex_state = 0, .external_lex_state = 2},
+  [34] = {.lex_state = 0, .external_lex_state = 2},
+  [35] = {.lex_state = 0, .external_lex_state = 2},
+  [36] = {.lex_state = 0, .external_lex_state = 2},
+  [37] = {.lex_state = 0, .external_lex_state = 2},
+  [38] = {.lex_state = 0, .external_lex_state = 2},
+  [39] = {.lex_state = 0, .external_lex_state = 2},
+  [40] = {.lex_state = 0, .external_lex_state = 2},
+  [41] = {.lex_state = 0, .external_lex_state = 2},
+  [42] = {.lex_state = 0, .external_lex_state = 2},
+  [43] = {.lex_state = 0, .external_lex_state = 2},
+  [44] = {.lex_state = 0, .external_lex_state = 2},
+  [45] = {.lex_state = 0, .external_lex_state = 2},
+  [46] = {.lex_state = 0, .external_lex_state = 2},
+  [47] = {.lex_state = 0, .external_lex_state = 2},
+  [48] = {.lex_state = 0, .external_lex_state = 2},
+  [49] = {.lex_state = 0, .external_lex_state = 2},
+  [50] = {.lex_state = 0, .external_lex_state = 2},
+  [51] = {.lex_state = 0, .external_lex_state = 2},
+  [52] = {.lex_state = 0, .external_lex_state = 2},
+  [53] = {.lex_state = 0, .external_lex_state = 2},
+  [54] = {.lex_state = 0, .external_lex_state = 2},
+  [55] = {.lex_state = 0, .external_lex_state = 2},
+  [56] = {.lex_state = 0, .external_lex_state = 2},
+  [57] = {.lex_state = 0, .external_lex_state = 2},
+  [58] = {.lex_state = 0, .external_lex_state = 2},
+  [59] = {.lex_state = 0, .external_lex_state = 2},
+  [60] = {.lex_state = 0, .external_lex_state = 2},
+  [61] = {.lex_state = 0, .external_lex_state = 2},
+  [62] = {.lex_state = 23, .external_lex_state = 3},
+  [63] = {.lex_state = 23, .external_lex_state = 3},
+  [64] = {.lex_state = 23, .external_lex_state = 3},
+  [65] = {.lex_state = 23, .external_lex_state = 3},
+  [66] = {.lex_state = 23, .external_lex_state = 3},
+  [67] = {.lex_state = 23, .external_lex_state = 3},
+  [68] = {.lex_state = 23, .external_lex_state = 3},
+  [69] = {.lex_state = 23, .external_lex_state = 3},
+  [70] = {.lex_state = 23, .external_lex_state = 3},
+  [71] = {.lex_state = 23, .external_lex_state = 3},
+  [72] = {.lex_state = 23, .external_lex_state = 3},
+  [73] = {.lex_state = 23, .external_lex_state = 3},
+  [74] = {.lex_state = 23, .external_lex_state = 3},
+  [75] = {.lex_state = 23, .external_lex_state = 3},
+  [76] = {.lex_state = 23, .external_lex_state = 3},
+  [77] = {.lex_state = 23, .external_lex_state = 3},
+  [78] = {.lex_state = 23, .external_lex_state = 3},
+  [79] = {.lex_state = 23, .external_lex_state = 3},
+  [80] = {.lex_state = 23, .external_lex_state = 3},
+  [81] = {.lex_state = 25, .external_lex_state = 3},
+  [82] = {.lex_state = 23, .external_lex_state = 3},
+  [83] = {.lex_state = 23, .external_lex_state = 3},
+  [84] = {.lex_state = 23, .external_lex_state = 3},
+  [85] = {.lex_state = 23, .external_lex_state = 3},
+  [86] = {.lex_state = 23, .external_lex_state = 3},
+  [87] = {.lex_state = 23, .external_lex_state = 3},
+  [88] = {.lex_state = 23, .external_lex_state = 3},
+  [89] = {.lex_state = 23, .external_lex_state = 3},
+  [90] = {.lex_state = 23, .external_lex_state = 3},
+  [91] = {.lex_state = 23, .external_lex_state = 3},
+  [92] = {.lex_state = 23, .external_lex_state = 3},
+  [93] = {.lex_state = 23, .external_lex_state = 3},
+  [94] = {.lex_state = 23, .external_lex_state = 3},
+  [95] = {.lex_state = 23, .external_lex_state = 3},
+  [96] = {.lex_state = 23, .external_lex_state = 3},
+  [97] = {.lex_state = 23, .external_lex_state = 3},
+  [98] = {.lex_state = 23, .external_lex_state = 3},
+  [99] = {.lex_state = 23, .external_lex_state = 3},
+  [100] = {.lex_state = 23, .external_lex_state = 3},
+  [101] = {.lex_state = 25, .external_lex_state = 2},
+  [102] = {.lex_state = 24, .external_lex_state = 3},
+  [103] = {.lex_state = 26, .external_lex_state = 2},
+  [104] = {.lex_state = 24, .external_lex_state = 3},
+  [105] = {.lex_state = 0, .external_lex_state = 2},
+  [106] = {.lex_state = 25, .external_lex_state = 2},
+  [107] = {.lex_state = 25, .external_lex_state = 2},
+  [108] = {.lex_state = 0, .external_lex_state = 2},
+  [109] = {.lex_state = 25, .external_lex_state = 2},
+  [110] = {.lex_state = 25, .external_lex_state = 2},
+  [111] = {.lex_state = 0, .external_lex_state = 2},
+  [112] = {.lex_state = 0, .external_lex_state = 2},
+  [113] = {.lex_state = 0, .external_lex_state = 2},
+  [114] = {.lex_state = 25, .external_lex_state = 2},
+  [115] = {.lex_state = 0, .external_lex_state = 2},
+  [116] = {.lex_state = 25, .external_lex_state = 2},
+  [117] = {.lex_state = 0, .external_lex_state = 2},
+  [118] = {.lex_state = 25, .external_lex_state = 2},
+  [119] = {.lex_state = 25, .external_lex_state = 2},
+  [120] = {.lex_state = 25, .external_lex_state = 2},
+  [121] = {.lex_state = 25, .external_lex_state = 2},
+  [122] = {.lex_state = 25, .external_lex_state = 2},
+  [123] = {.lex_state = 25, .external_lex_state = 2},
+  [124] = {.lex_state = 0, .external_lex_state = 2},
+  [125] = {.lex_state = 25, .external_lex_state = 2},
+  [126] = {.lex_state = 0, .external_lex_state = 2},
+  [127] = {.lex_state = 0, .external_lex_state = 2},
+  [128] = {.lex_state = 25, .external_lex_state = 2},
+  [129] = {.lex_state = 0, .external_lex_state = 2},
+  [130] = {.lex_state = 0, .external_lex_state = 2},
+  [131] = {.lex_state = 0, .external_lex_state = 2},
+  [132] = {.lex_state = 0, .external_lex_state = 2},
+  [133] = {.lex_state = 0, .external_lex_state = 2},
+  [134] = {.lex_state = 0, .external_lex_state = 2},
+  [135] = {.lex_state = 0, .external_lex_state = 2},
+  [136] = {.lex_state = 0, .external_lex_state = 2},
+  [137] = {.lex_state = 0, .external_lex_state = 2},
+  [138] = {.lex_state = 0, .external_lex_state = 2},
+  [139] = {.lex_state = 0, .external_lex_state = 2},
+  [140] = {.lex_state = 0, .external_lex_state = 2},
+  [141] = {.lex_state = 0, .external_lex_state = 2},
+  [142] = {.lex_state = 0, .external_lex_state = 2},
+  [143] = {.lex_state = 0, .external_lex_state = 2},
+  [144] = {.lex_state = 0, .external_lex_state = 2},
+  [145] = {.lex_state = 0, .external_lex_state = 2},
+  [146] = {.lex_state = 0, .external_lex_state = 2},
+  [147] = {.lex_state = 0, .external_lex_state = 2},
+  [148] = {.lex_state = 0, .external_lex_state = 2},
+  [149] = {.lex_state = 0, .external_lex_state = 2},
+  [150] = {.lex_state = 0, .external_lex_state = 2},
+  [151] = {.lex_state = 0, .external_lex_state = 2},
+  [152] = {.lex_state = 0, .external_lex_state = 2},
+  [153] = {.lex_state = 0, .external_lex_state = 2},
+  [154] = {.lex_state = 0, .external_lex_state = 2},
+  [155] = {.lex_state = 0, .external_lex_state = 2},
+  [156] = {.lex_state = 0, .external_lex_state = 2},
+  [157] = {.lex_state = 0, .external_lex_state = 2},
+  [158] = {.lex_state = 23, .external_lex_state = 3},
+  [159] = {.lex_state = 0, .external_lex_state = 2},
+  [160] = {.lex_state = 0, .external_lex_state = 2},
+  [161] = {.lex_state = 24, .external_lex_state = 3},
+  [162] = {.lex_state = 24, .external_lex_state = 3},
+  [163] = {.lex_state = 0, .external_lex_state = 2},
+  [164] = {.lex_state = 0, .external_lex_state = 3},
+  [165] = {.lex_state = 24, .external_lex_state = 3},
+  [166] = {.lex_state = 25, .external_lex_state = 2},
+  [167] = {.lex_state = 24, .external_lex_state = 3},
+  [168] = {.lex_state = 0, .external_lex_state = 2},
+  [169] = {.lex_state = 0, .external_lex_state = 2},
+  [170] = {.lex_state = 25, .external_lex_state = 2},
+  [171] = {.lex_state = 25, .external_lex_state = 2},
+  [172] = {.lex_state = 25, .external_lex_state = 2},
+  [173] = {.lex_state = 0, .external_lex_state = 2},
+  [174] = {.lex_state = 23, .external_lex_state = 2},
+  [175] = {.lex_state = 25, .external_lex_state = 2},
+  [176] = {.lex_state = 0, .external_lex_state = 2},
+  [177] = {.lex_state = 25, .external_lex_state = 2},
+  [178] = {.lex_state = 0, .external_lex_state = 2},
+  [179] = {.lex_state = 3, .external_lex_state = 2},
+  [180] = {.lex_state = 25, .external_lex_state = 2},
+  [181] = {.lex_state = 0, .external_lex_state = 2},
+  [182] = {.lex_state = 1, .external_lex_state = 2},
+  [183] = {.lex_state = 0, .external_lex_state = 3},
+  [184] = {.lex_state = 25, .external_lex_state = 2},
+  [185] = {.lex_state = 3, .external_lex_state = 2},
+  [186] = {.lex_state = 1, .external_lex_state = 2},
+  [187] = {.lex_state = 2, .external_lex_state = 2},
+  [188] = {.lex_state = 4, .external_lex_state = 2},
+  [189] = {.lex_state = 25, .external_lex_state = 2},
+  [190] = {.lex_state = 25, .external_lex_state = 2},
+  [191] = {.lex_state = 23, .external_lex_state = 2},
+  [192] = {.lex_state = 4, .external_lex_state = 2},
+  [193] = {.lex_state = 23, .external_lex_state = 2},
+  [194] = {.lex_state = 2, .external_lex_state = 2},
+  [195] = {.lex_state = 25, .external_lex_state = 2},
+  [196] = {.lex_state = 25, .external_lex_state = 2},
+  [197] = {.lex_state = 2, .external_lex_state = 2},
+  [198] = {.lex_state = 4, .external_lex_state = 2},
+  [199] = {.lex_state = 0, .external_lex_state = 2},
+  [200] = {.lex_state = 0, .external_lex_state = 2},
+  [201] = {.lex_state = 0, .external_lex_state = 2},
+  [202] = {.lex_state = 25, .external_lex_state = 2},
+  [203] = {.lex_state = 0, .external_lex_state = 2},
+  [204] = {.lex_state = 0, .external_lex_state = 2},
+  [205] = {.lex_state = 0, .external_lex_state = 2},
+  [206] = {.lex_state = 0, .external_lex_state = 2},
+  [207] = {.lex_state = 0, .external_lex_state = 2},
+  [208] = {.lex_state = 24, .external_lex_state = 2},
+  [209] = {.lex_state = 0, .external_lex_state = 2},
+  [210] = {.lex_state = 0, .external_lex_state = 2},
+  [211] = {.lex_state = 0, .external_lex_state = 2},
+  [212] = {.lex_state = 0, .external_lex_state = 2},
+  [213] = {.lex_state = 2, .external_lex_state = 2},
+  [214] = {.lex_state = 0, .external_lex_state = 2},
+  [215] = {.lex_state = 0, .external_lex_state = 2},
+  [216] = {.lex_state = 25, .external_lex_state = 2},
+  [217] = {.lex_state = 25, .external_lex_state = 2},
+  [218] = {.lex_state = 4, .external_lex_state = 2},
+  [219] = {.lex_state = 0, .external_lex_state = 2},
+  [220] = {.lex_state = 24, .external_lex_state = 2},
+  [221] = {.lex_state = 0, .external_lex_state = 2},
+  [222] = {.lex_state = 24, .external_lex_state = 2},
+  [223] = {.lex_state = 0, .external_lex_state = 2},
+  [224] = {.lex_state = 0, .external_lex_state = 2},
+  [225] = {.lex_state = 0, .external_lex_state = 2},
+  [226] = {.lex_state = 24, .external_lex_state = 2},
+  [227] = {.lex_state = 0, .external_lex_state = 2},
+  [228] = {.lex_state = 25, .external_lex_state = 2},
+  [229] = {.lex_state = 0, .external_lex_state = 2},
+  [230] = {.lex_state = 0, .external_lex_state = 2},
+  [231] = {.lex_state = 0, .external_lex_state = 2},
+  [232] = {.lex_state = 25, .external_lex_state = 2},
+  [233] = {.lex_state = 0, .external_lex_state = 4},
+  [234] = {.lex_state = 0, .external_lex_state = 2},
+  [235] = {.lex_state = 0, .external_lex_state = 2},
+  [236] = {.lex_state = 0, .external_lex_state = 5},
+  [237] = {.lex_state = 25, .external_lex_state = 2},
+  [238] = {.lex_state = 25, .external_lex_state = 2},
+  [239] = {.lex_state = 25, .external_lex_state = 2},
+  [240] = {.lex_state = 0, .external_lex_state = 2},
+  [241] = {.lex_state = 0, .external_lex_state = 2},
+  [242] = {.lex_state = 25, .external_lex_state = 2},
+  [243] = {.lex_state = 25, .external_lex_state = 2},
+  [244] = {.lex_state = 0, .external_lex_state = 2},
+  [245] = {.lex_state = 0, .external_lex_state = 2},
+  [246] = {.lex_state = 0, .external_lex_state = 2},
+  [247] = {.lex_state = 0, .external_lex_state = 2},
+  [248] = {.lex_state = 0, .external_lex_state = 2},
+  [249] = {.lex_state = 102, .external_lex_state = 2},
+  [250] = {.lex_state = 0, .external_lex_state = 6},
+  [251] = {.lex_state = 0, .external_lex_state = 2},
+  [252] = {.lex_state = 25, .external_lex_state = 2},
+  [253] = {.lex_state = 25, .external_lex_state = 2},
+  [254] = {.lex_state = 0, .external_lex_state = 2},
+  [255] = {.lex_state = 0, .external_lex_state = 2},
+  [256] = {.lex_state = 0, .external_lex_state = 2},
+  [257] = {.lex_state = 0, .external_lex_state = 2},
+  [258] = {.lex_state = 25, .external_lex_state = 2},
+  [259] = {.lex_state = 25, .external_lex_state = 2},
+  [260] = {.lex_state = 25, .external_lex_state = 2},
+  [261] = {.lex_state = 0, .external_lex_state = 7},
+  [262] = {.lex_state = 0, .external_lex_state = 7},
+  [263] = {.lex_state = 0, .external_lex_state = 2},
+  [264] = {.lex_state = 23, .external_lex_state = 2},
+  [265] = {.lex_state = 0, .external_lex_state = 2},
+  [266] = {.lex_state = 25, .external_lex_state = 2},
+  [267] = {.lex_state = 0, .external_lex_state = 2},
+  [268] = {.lex_state = 0, .external_lex_state = 4},
+  [269] = {.lex_state = 25, .external_lex_state = 2},
+  [270] = {(TSStateId)(-1)},
+  [271] = {(TSStateId)(-1)},
+};
+
+static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
+  [0] = {
+    [sym_comment] = STATE(0),
+    [ts_builtin_sym_end] = ACTIONS(1),
+    [sym__identifier] = ACTIONS(1),
+    [anon_sym_return] = ACTIONS(1),
+    [anon_sym_SEMI] = ACTIONS(1),
+    [anon_sym_EQ] = ACTIONS(1),
+    [anon_sym_COMMA] = ACTIONS(1),
+    [anon_sym_COLON_COLON] = ACTIONS(1),
+    [sym_break_statement] = ACTIONS(1),
+    [anon_sym_goto] = ACTIONS(1),
+    [anon_sym_do] = ACTIONS(1),
+    [anon_sym_end] = ACTIONS(1),
+    [anon_sym_while] = ACTIONS(1),
+    [anon_sym_repeat] = ACTIONS(1),
+    [anon_sym_until] = ACTIONS(1),
+    [anon_sym_if] = ACTIONS(1),
+    [anon_sym_then] = ACTIONS(1),
+    [anon_sym_elseif] = ACTIONS(1),
+    [anon_sym_else] = ACTIONS(1),
+    [anon_sym_for] = ACTIONS(1),
+    [anon_sym_in] = ACTIONS(1),
+    [anon_sym_function] = ACTIONS(1),
+    [anon_sym_local] = ACTIONS(1),
+    [anon_sym_DOT] = ACTIONS(1),
+    [anon_sym_COLON] = ACTIONS(1),
+    [anon_sym_LT] = ACTIONS(1),
+    [anon_sym_GT] = ACTIONS(1),
+    [sym_nil] = ACTIONS(1),
+    [sym_false] = ACTIONS(1),
+    [sym_true] = ACTIONS(1),
+    [sym_number] = ACTIONS(1),
+    [anon_sym_DQUOTE] = ACTIONS(1),
+    [anon_sym_SQUOTE] = ACTIONS(1),
+    [sym_escape_sequence] = ACTIONS(1),
+    [anon_sym_LPAREN] = ACTIONS(1),
+    [anon_sym_RPAREN] = ACTIONS(1),
+    [anon_sym_LBRACK] = ACTIONS(1),
+    [anon_sym_RBRACK] = ACTIONS(1),
+    [anon_sym_LBRACE] = ACTIONS(1),
+    [anon_sym_RBRACE] = ACTIONS(1),
+    [anon_sym_or] = ACTIONS(1),
+    [anon_sym_and] = ACTIONS(1),
+    [anon_sym_LT_EQ] = ACTIONS(1),
+    [anon_sym_EQ_EQ] = ACTIONS(1),
+    [anon_sym_TILDE_EQ] = ACTIONS(1),
+    [anon_sym_GT_EQ] = ACTIONS(1),
+    [anon_sym_PIPE] = ACTIONS(1),
+    [anon_sym_TILDE] = ACTIONS(1),
+    [anon_sym_AMP] = ACTIONS(1),
+    [anon_sym_LT_LT] = ACTIONS(1),
+    [anon_sym_GT_GT] = ACTIONS(1),
+    [anon_sym_PLUS] = ACTIONS(1),
+    [anon_sym_DASH] = ACTIONS(1),
+    [anon_sym_STAR] = ACTIONS(1),
+    [anon_sym_SLASH] = ACTIONS(1),
+    [anon_sym_SLASH_SLASH] = ACTIONS(1),
+    [anon_sym_PERCENT] = ACTIONS(1),
+    [anon_sym_DOT_DOT] = ACTIONS(1),
+    [anon_sym_CARET] = ACTIONS(1),
+    [anon_sym_not] = ACTIONS(1),
+    [anon_sym_POUND] = ACTIONS(1),
+    [sym_grit_metavariable] = ACTIONS(1),
+    [anon_sym_DASH_DASH] = ACTIONS(3),
+    [sym__block_comment_start] = ACTIONS(5),
+    [sym__block_comment_content] = ACTIONS(1),
+    [sym__block_comment_end] = ACTIONS(1),
+    [sym__block_string_start] = ACTIONS(1),
+    [sym__block_string_content] = ACTIONS(1),
+    [sym__block_string_end] = ACTIONS(1),
+  },
+  [1] = {
+    [sym_chunk] = STATE(255),
+    [sym_statement] = STATE(136),
+    [sym_return_statement] = STATE(244),
+    [sym_empty_statement] = STATE(142),
+    [sym_assignment_statement] = STATE(142),
+    [sym__variable_assignment_varlist] = STATE(247),
+    [sym_label_statement] = STATE(142),
+    [sym_goto_statement] = STATE(142),
+    [sym_do_statement] = STATE(142),
+    [sym_while_statement] = STATE(142),
+    [sym_repeat_statement] = STATE(142),
+    [sym_if_statement] = STATE(142),
+    [sym_for_statement] = STATE(142),
+    [sym_declaration] = STATE(150),
+    [sym_function_declaration] = STATE(151),
+    [sym__local_function_declaration] = STATE(155),
+    [sym_variable_declaration] = STATE(134),
+    [sym__prefix_expression] = STATE(161),
+    [sym_variable] = STATE(162),
+    [sym_bracket_index_expression] = STATE(2),
+    [sym_dot_index_expression] = STATE(2),
+    [sym_function_call] = STATE(104),
+    [sym_method_index_expression] = STATE(164),
+    [sym_parenthesized_expression] = STATE(167),
+    [sym_identifier] = STATE(2),
+    [sym_comment] = STATE(1),
+    [aux_sym_chunk_repeat1] = STATE(60),
+    [ts_builtin_sym_end] = ACTIONS(7),
+    [sym__identifier] = ACTIONS(9),
+    [sym_hash_bang_line] = ACTIONS(11),
+    [anon_sym_return] = ACTIONS(13),
+    [anon_sym_SEMI] = ACTIONS(15),
+    [anon_sym_COLON_COLON] = ACTIONS(17),
+    [sym_break_statement] = ACTIONS(19),
+    [anon_sym_goto] = ACTIONS(21),
+    [anon_sym_do] = ACTIONS(23),
+    [anon_sym_while] = ACTIONS(25),
+    [anon_sym_repeat] = ACTIONS(27),
+    [anon_sym_if] = ACTIONS(29),
+    [anon_sym_for] = ACTIONS(31),
+    [anon_sym_function] = ACTIONS(33),
+    [anon_sym_local] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [sym_grit_metavariable] = ACTIONS(39),
+    [anon_sym_DASH_DASH] = ACTIONS(3),
+    [sym__block_comment_start] = ACTIONS(5),
+  },
+};
+
+static const uint16_t ts_small_parse_table[] = {
+  [0] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(2), 1,
+      sym_comment,
+    ACTIONS(43), 26,
+      anon_sym_return,
+      anon_sym_EQ,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+    ACTIONS(41), 28,
+      sym__block_string_start,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+  [68] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(3), 1,
+      sym_comment,
+    ACTIONS(47), 26,
+      anon_sym_return,
+      anon_sym_EQ,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+    ACTIONS(45), 28,
+      sym__block_string_start,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+  [136] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(4), 1,
+      sym_comment,
+    ACTIONS(51), 26,
+      anon_sym_return,
+      anon_sym_EQ,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+    ACTIONS(49), 28,
+      sym__block_string_start,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+  [204] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(5), 1,
+      sym_comment,
+    ACTIONS(55), 26,
+      anon_sym_return,
+      anon_sym_EQ,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+    ACTIONS(53), 28,
+      sym__block_string_start,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+  [272] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(6), 1,
+      sym_comment,
+    ACTIONS(59), 25,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+    ACTIONS(57), 28,
+      sym__block_string_start,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+  [339] = 7,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(7), 1,
+      sym_comment,
+    ACTIONS(65), 2,
+      anon_sym_DOT,
+      anon_sym_COLON,
+    ACTIONS(67), 6,
+      sym__block_string_start,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_LBRACE,
+    ACTIONS(61), 22,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+    ACTIONS(63), 23,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+  [410] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(8), 1,
+      sym_comment,
+    ACTIONS(71), 25,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+    ACTIONS(69), 28,
+      sym__block_string_start,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+  [477] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(9), 1,
+      sym_comment,
+    ACTIONS(75), 25,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+    ACTIONS(73), 28,
+      sym__block_string_start,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+  [544] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(10), 1,
+      sym_comment,
+    ACTIONS(79), 25,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+    ACTIONS(77), 28,
+      sym__block_string_start,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+  [611] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(11), 1,
+      sym_comment,
+    ACTIONS(83), 25,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+    ACTIONS(81), 28,
+      sym__block_string_start,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+  [678] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(12), 1,
+      sym_comment,
+    ACTIONS(87), 25,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+    ACTIONS(85), 28,
+      sym__block_string_start,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+  [745] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(13), 1,
+      sym_comment,
+    ACTIONS(91), 25,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+    ACTIONS(89), 28,
+      sym__block_string_start,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+  [812] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(14), 1,
+      sym_comment,
+    ACTIONS(95), 25,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+    ACTIONS(93), 28,
+      sym__block_string_start,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+  [879] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(15), 1,
+      sym_comment,
+    ACTIONS(95), 25,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+    ACTIONS(93), 28,
+      sym__block_string_start,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+  [946] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(16), 1,
+      sym_comment,
+    ACTIONS(99), 25,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+    ACTIONS(97), 28,
+      sym__block_string_start,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+  [1013] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(17), 1,
+      sym_comment,
+    ACTIONS(103), 25,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+    ACTIONS(101), 28,
+      sym__block_string_start,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+  [1080] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(18), 1,
+      sym_comment,
+    ACTIONS(107), 25,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+    ACTIONS(105), 28,
+      sym__block_string_start,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+  [1147] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(19), 1,
+      sym_comment,
+    ACTIONS(111), 25,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+    ACTIONS(109), 28,
+      sym__block_string_start,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+  [1214] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(20), 1,
+      sym_comment,
+    ACTIONS(115), 25,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+    ACTIONS(113), 28,
+      sym__block_string_start,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+  [1281] = 40,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(15), 1,
+      anon_sym_SEMI,
+    ACTIONS(17), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(19), 1,
+      sym_break_statement,
+    ACTIONS(21), 1,
+      anon_sym_goto,
+    ACTIONS(23), 1,
+      anon_sym_do,
+    ACTIONS(25), 1,
+      anon_sym_while,
+    ACTIONS(27), 1,
+      anon_sym_repeat,
+    ACTIONS(29), 1,
+      anon_sym_if,
+    ACTIONS(31), 1,
+      anon_sym_for,
+    ACTIONS(33), 1,
+      anon_sym_function,
+    ACTIONS(35), 1,
+      anon_sym_local,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(117), 1,
+      anon_sym_return,
+    ACTIONS(119), 1,
+      anon_sym_end,
+    ACTIONS(121), 1,
+      anon_sym_elseif,
+    ACTIONS(123), 1,
+      anon_sym_else,
+    STATE(21), 1,
+      sym_comment,
+    STATE(47), 1,
+      aux_sym_chunk_repeat1,
+    STATE(104), 1,
+      sym_function_call,
+    STATE(134), 1,
+      sym_variable_declaration,
+    STATE(136), 1,
+      sym_statement,
+    STATE(150), 1,
+      sym_declaration,
+    STATE(151), 1,
+      sym_function_declaration,
+    STATE(155), 1,
+      sym__local_function_declaration,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(162), 1,
+      sym_variable,
+    STATE(164), 1,
+      sym_method_index_expression,
+    STATE(167), 1,
+      sym_parenthesized_expression,
+    STATE(170), 1,
+      sym__block,
+    STATE(171), 1,
+      aux_sym_if_statement_repeat1,
+    STATE(190), 1,
+      sym_return_statement,
+    STATE(228), 1,
+      sym_elseif_statement,
+    STATE(232), 1,
+      sym_else_statement,
+    STATE(247), 1,
+      sym__variable_assignment_varlist,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(142), 9,
+      sym_empty_statement,
+      sym_assignment_statement,
+      sym_label_statement,
+      sym_goto_statement,
+      sym_do_statement,
+      sym_while_statement,
+      sym_repeat_statement,
+      sym_if_statement,
+      sym_for_statement,
+  [1412] = 15,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(129), 1,
+      anon_sym_PIPE,
+    ACTIONS(131), 1,
+      anon_sym_TILDE,
+    ACTIONS(133), 1,
+      anon_sym_AMP,
+    ACTIONS(137), 1,
+      anon_sym_PLUS,
+    ACTIONS(139), 1,
+      anon_sym_DASH,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(145), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    STATE(22), 1,
+      sym_comment,
+    ACTIONS(135), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(125), 13,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+    ACTIONS(127), 20,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      sym__identifier,
+  [1492] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    STATE(23), 1,
+      sym_comment,
+    ACTIONS(149), 22,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      sym_grit_metavariable,
+    ACTIONS(151), 23,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+  [1554] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(24), 1,
+      sym_comment,
+    ACTIONS(61), 23,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+    ACTIONS(63), 23,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+  [1614] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(25), 1,
+      sym_comment,
+    ACTIONS(93), 23,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+    ACTIONS(95), 23,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+  [1674] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(26), 1,
+      sym_comment,
+    ACTIONS(93), 23,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+    ACTIONS(95), 23,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+  [1734] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(27), 1,
+      sym_comment,
+    ACTIONS(105), 23,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+    ACTIONS(107), 23,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+  [1794] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(28), 1,
+      sym_comment,
+    ACTIONS(153), 23,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+    ACTIONS(155), 23,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+  [1854] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(29), 1,
+      sym_comment,
+    ACTIONS(109), 23,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+    ACTIONS(111), 23,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+  [1914] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(30), 1,
+      sym_comment,
+    ACTIONS(113), 23,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+    ACTIONS(115), 23,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+  [1974] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(31), 1,
+      sym_comment,
+    ACTIONS(57), 23,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+    ACTIONS(59), 23,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+  [2034] = 18,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(129), 1,
+      anon_sym_PIPE,
+    ACTIONS(131), 1,
+      anon_sym_TILDE,
+    ACTIONS(133), 1,
+      anon_sym_AMP,
+    ACTIONS(137), 1,
+      anon_sym_PLUS,
+    ACTIONS(139), 1,
+      anon_sym_DASH,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(145), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    ACTIONS(159), 1,
+      anon_sym_and,
+    STATE(32), 1,
+      sym_comment,
+    ACTIONS(135), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(157), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(161), 4,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(125), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(127), 17,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_or,
+      sym__identifier,
+  [2120] = 14,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(131), 1,
+      anon_sym_TILDE,
+    ACTIONS(133), 1,
+      anon_sym_AMP,
+    ACTIONS(137), 1,
+      anon_sym_PLUS,
+    ACTIONS(139), 1,
+      anon_sym_DASH,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(145), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    STATE(33), 1,
+      sym_comment,
+    ACTIONS(135), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(125), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      sym_grit_metavariable,
+    ACTIONS(127), 20,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      sym__identifier,
+  [2198] = 17,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(129), 1,
+      anon_sym_PIPE,
+    ACTIONS(131), 1,
+      anon_sym_TILDE,
+    ACTIONS(133), 1,
+      anon_sym_AMP,
+    ACTIONS(137), 1,
+      anon_sym_PLUS,
+    ACTIONS(139), 1,
+      anon_sym_DASH,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(145), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    STATE(34), 1,
+      sym_comment,
+    ACTIONS(135), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(157), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(161), 4,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(125), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(127), 18,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_or,
+      anon_sym_and,
+      sym__identifier,
+  [2282] = 13,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(133), 1,
+      anon_sym_AMP,
+    ACTIONS(137), 1,
+      anon_sym_PLUS,
+    ACTIONS(139), 1,
+      anon_sym_DASH,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(145), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    STATE(35), 1,
+      sym_comment,
+    ACTIONS(135), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(125), 14,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      sym_grit_metavariable,
+    ACTIONS(127), 21,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      sym__identifier,
+  [2358] = 12,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(137), 1,
+      anon_sym_PLUS,
+    ACTIONS(139), 1,
+      anon_sym_DASH,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(145), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    STATE(36), 1,
+      sym_comment,
+    ACTIONS(135), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(125), 15,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      sym_grit_metavariable,
+    ACTIONS(127), 21,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      sym__identifier,
+  [2432] = 11,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(137), 1,
+      anon_sym_PLUS,
+    ACTIONS(139), 1,
+      anon_sym_DASH,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(145), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    STATE(37), 1,
+      sym_comment,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(125), 17,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      sym_grit_metavariable,
+    ACTIONS(127), 21,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      sym__identifier,
+  [2504] = 8,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    STATE(38), 1,
+      sym_comment,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(125), 19,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_DOT_DOT,
+      sym_grit_metavariable,
+    ACTIONS(127), 22,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      sym__identifier,
+  [2570] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    STATE(39), 1,
+      sym_comment,
+    ACTIONS(125), 22,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      sym_grit_metavariable,
+    ACTIONS(127), 23,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+  [2632] = 11,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(137), 1,
+      anon_sym_PLUS,
+    ACTIONS(139), 1,
+      anon_sym_DASH,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(145), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    STATE(40), 1,
+      sym_comment,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(125), 17,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      sym_grit_metavariable,
+    ACTIONS(127), 21,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      sym__identifier,
+  [2704] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    STATE(41), 1,
+      sym_comment,
+    ACTIONS(125), 22,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      sym_grit_metavariable,
+    ACTIONS(127), 23,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+  [2766] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(42), 1,
+      sym_comment,
+    ACTIONS(163), 23,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+    ACTIONS(165), 23,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+  [2826] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(43), 1,
+      sym_comment,
+    ACTIONS(167), 23,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+    ACTIONS(169), 23,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+  [2886] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(44), 1,
+      sym_comment,
+    ACTIONS(97), 23,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+    ACTIONS(99), 23,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+  [2946] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(45), 1,
+      sym_comment,
+    ACTIONS(101), 23,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_RBRACK,
+      anon_sym_RBRACE,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+      sym_grit_metavariable,
+    ACTIONS(103), 23,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_then,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+      sym__identifier,
+  [3006] = 35,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(15), 1,
+      anon_sym_SEMI,
+    ACTIONS(17), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(19), 1,
+      sym_break_statement,
+    ACTIONS(21), 1,
+      anon_sym_goto,
+    ACTIONS(23), 1,
+      anon_sym_do,
+    ACTIONS(25), 1,
+      anon_sym_while,
+    ACTIONS(27), 1,
+      anon_sym_repeat,
+    ACTIONS(29), 1,
+      anon_sym_if,
+    ACTIONS(31), 1,
+      anon_sym_for,
+    ACTIONS(33), 1,
+      anon_sym_function,
+    ACTIONS(35), 1,
+      anon_sym_local,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(117), 1,
+      anon_sym_return,
+    STATE(46), 1,
+      sym_comment,
+    STATE(47), 1,
+      aux_sym_chunk_repeat1,
+    STATE(104), 1,
+      sym_function_call,
+    STATE(134), 1,
+      sym_variable_declaration,
+    STATE(136), 1,
+      sym_statement,
+    STATE(150), 1,
+      sym_declaration,
+    STATE(151), 1,
+      sym_function_declaration,
+    STATE(155), 1,
+      sym__local_function_declaration,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(162), 1,
+      sym_variable,
+    STATE(164), 1,
+      sym_method_index_expression,
+    STATE(167), 1,
+      sym_parenthesized_expression,
+    STATE(190), 1,
+      sym_return_statement,
+    STATE(216), 1,
+      sym__block,
+    STATE(247), 1,
+      sym__variable_assignment_varlist,
+    ACTIONS(171), 3,
+      anon_sym_end,
+      anon_sym_elseif,
+      anon_sym_else,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(142), 9,
+      sym_empty_statement,
+      sym_assignment_statement,
+      sym_label_statement,
+      sym_goto_statement,
+      sym_do_statement,
+      sym_while_statement,
+      sym_repeat_statement,
+      sym_if_statement,
+      sym_for_statement,
+  [3124] = 34,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(15), 1,
+      anon_sym_SEMI,
+    ACTIONS(17), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(19), 1,
+      sym_break_statement,
+    ACTIONS(21), 1,
+      anon_sym_goto,
+    ACTIONS(23), 1,
+      anon_sym_do,
+    ACTIONS(25), 1,
+      anon_sym_while,
+    ACTIONS(27), 1,
+      anon_sym_repeat,
+    ACTIONS(29), 1,
+      anon_sym_if,
+    ACTIONS(31), 1,
+      anon_sym_for,
+    ACTIONS(33), 1,
+      anon_sym_function,
+    ACTIONS(35), 1,
+      anon_sym_local,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(117), 1,
+      anon_sym_return,
+    STATE(47), 1,
+      sym_comment,
+    STATE(48), 1,
+      aux_sym_chunk_repeat1,
+    STATE(104), 1,
+      sym_function_call,
+    STATE(134), 1,
+      sym_variable_declaration,
+    STATE(136), 1,
+      sym_statement,
+    STATE(150), 1,
+      sym_declaration,
+    STATE(151), 1,
+      sym_function_declaration,
+    STATE(155), 1,
+      sym__local_function_declaration,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(162), 1,
+      sym_variable,
+    STATE(164), 1,
+      sym_method_index_expression,
+    STATE(167), 1,
+      sym_parenthesized_expression,
+    STATE(189), 1,
+      sym_return_statement,
+    STATE(247), 1,
+      sym__variable_assignment_varlist,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    ACTIONS(173), 4,
+      anon_sym_end,
+      anon_sym_until,
+      anon_sym_elseif,
+      anon_sym_else,
+    STATE(142), 9,
+      sym_empty_statement,
+      sym_assignment_statement,
+      sym_label_statement,
+      sym_goto_statement,
+      sym_do_statement,
+      sym_while_statement,
+      sym_repeat_statement,
+      sym_if_statement,
+      sym_for_statement,
+  [3240] = 32,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(175), 1,
+      ts_builtin_sym_end,
+    ACTIONS(177), 1,
+      sym__identifier,
+    ACTIONS(182), 1,
+      anon_sym_SEMI,
+    ACTIONS(185), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(188), 1,
+      sym_break_statement,
+    ACTIONS(191), 1,
+      anon_sym_goto,
+    ACTIONS(194), 1,
+      anon_sym_do,
+    ACTIONS(197), 1,
+      anon_sym_while,
+    ACTIONS(200), 1,
+      anon_sym_repeat,
+    ACTIONS(203), 1,
+      anon_sym_if,
+    ACTIONS(206), 1,
+      anon_sym_for,
+    ACTIONS(209), 1,
+      anon_sym_function,
+    ACTIONS(212), 1,
+      anon_sym_local,
+    ACTIONS(215), 1,
+      anon_sym_LPAREN,
+    ACTIONS(218), 1,
+      sym_grit_metavariable,
+    STATE(104), 1,
+      sym_function_call,
+    STATE(134), 1,
+      sym_variable_declaration,
+    STATE(136), 1,
+      sym_statement,
+    STATE(150), 1,
+      sym_declaration,
+    STATE(151), 1,
+      sym_function_declaration,
+    STATE(155), 1,
+      sym__local_function_declaration,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(162), 1,
+      sym_variable,
+    STATE(164), 1,
+      sym_method_index_expression,
+    STATE(167), 1,
+      sym_parenthesized_expression,
+    STATE(247), 1,
+      sym__variable_assignment_varlist,
+    STATE(48), 2,
+      sym_comment,
+      aux_sym_chunk_repeat1,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    ACTIONS(180), 5,
+      anon_sym_return,
+      anon_sym_end,
+      anon_sym_until,
+      anon_sym_elseif,
+      anon_sym_else,
+    STATE(142), 9,
+      sym_empty_statement,
+      sym_assignment_statement,
+      sym_label_statement,
+      sym_goto_statement,
+      sym_do_statement,
+      sym_while_statement,
+      sym_repeat_statement,
+      sym_if_statement,
+      sym_for_statement,
+  [3352] = 21,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(129), 1,
+      anon_sym_PIPE,
+    ACTIONS(131), 1,
+      anon_sym_TILDE,
+    ACTIONS(133), 1,
+      anon_sym_AMP,
+    ACTIONS(137), 1,
+      anon_sym_PLUS,
+    ACTIONS(139), 1,
+      anon_sym_DASH,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(145), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    ACTIONS(159), 1,
+      anon_sym_and,
+    ACTIONS(225), 1,
+      anon_sym_COMMA,
+    ACTIONS(227), 1,
+      anon_sym_or,
+    STATE(49), 1,
+      sym_comment,
+    STATE(127), 1,
+      aux_sym__variable_assignment_explist_repeat1,
+    ACTIONS(135), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(157), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(161), 4,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(221), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(223), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [3441] = 35,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(15), 1,
+      anon_sym_SEMI,
+    ACTIONS(17), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(19), 1,
+      sym_break_statement,
+    ACTIONS(21), 1,
+      anon_sym_goto,
+    ACTIONS(23), 1,
+      anon_sym_do,
+    ACTIONS(25), 1,
+      anon_sym_while,
+    ACTIONS(27), 1,
+      anon_sym_repeat,
+    ACTIONS(29), 1,
+      anon_sym_if,
+    ACTIONS(31), 1,
+      anon_sym_for,
+    ACTIONS(33), 1,
+      anon_sym_function,
+    ACTIONS(35), 1,
+      anon_sym_local,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(117), 1,
+      anon_sym_return,
+    ACTIONS(229), 1,
+      anon_sym_end,
+    STATE(47), 1,
+      aux_sym_chunk_repeat1,
+    STATE(50), 1,
+      sym_comment,
+    STATE(104), 1,
+      sym_function_call,
+    STATE(134), 1,
+      sym_variable_declaration,
+    STATE(136), 1,
+      sym_statement,
+    STATE(150), 1,
+      sym_declaration,
+    STATE(151), 1,
+      sym_function_declaration,
+    STATE(155), 1,
+      sym__local_function_declaration,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(162), 1,
+      sym_variable,
+    STATE(164), 1,
+      sym_method_index_expression,
+    STATE(167), 1,
+      sym_parenthesized_expression,
+    STATE(190), 1,
+      sym_return_statement,
+    STATE(243), 1,
+      sym__block,
+    STATE(247), 1,
+      sym__variable_assignment_varlist,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(142), 9,
+      sym_empty_statement,
+      sym_assignment_statement,
+      sym_label_statement,
+      sym_goto_statement,
+      sym_do_statement,
+      sym_while_statement,
+      sym_repeat_statement,
+      sym_if_statement,
+      sym_for_statement,
+  [3557] = 35,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(15), 1,
+      anon_sym_SEMI,
+    ACTIONS(17), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(19), 1,
+      sym_break_statement,
+    ACTIONS(21), 1,
+      anon_sym_goto,
+    ACTIONS(23), 1,
+      anon_sym_do,
+    ACTIONS(25), 1,
+      anon_sym_while,
+    ACTIONS(27), 1,
+      anon_sym_repeat,
+    ACTIONS(29), 1,
+      anon_sym_if,
+    ACTIONS(31), 1,
+      anon_sym_for,
+    ACTIONS(33), 1,
+      anon_sym_function,
+    ACTIONS(35), 1,
+      anon_sym_local,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(117), 1,
+      anon_sym_return,
+    ACTIONS(231), 1,
+      anon_sym_end,
+    STATE(47), 1,
+      aux_sym_chunk_repeat1,
+    STATE(51), 1,
+      sym_comment,
+    STATE(104), 1,
+      sym_function_call,
+    STATE(134), 1,
+      sym_variable_declaration,
+    STATE(136), 1,
+      sym_statement,
+    STATE(150), 1,
+      sym_declaration,
+    STATE(151), 1,
+      sym_function_declaration,
+    STATE(155), 1,
+      sym__local_function_declaration,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(162), 1,
+      sym_variable,
+    STATE(164), 1,
+      sym_method_index_expression,
+    STATE(167), 1,
+      sym_parenthesized_expression,
+    STATE(190), 1,
+      sym_return_statement,
+    STATE(237), 1,
+      sym__block,
+    STATE(247), 1,
+      sym__variable_assignment_varlist,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(142), 9,
+      sym_empty_statement,
+      sym_assignment_statement,
+      sym_label_statement,
+      sym_goto_statement,
+      sym_do_statement,
+      sym_while_statement,
+      sym_repeat_statement,
+      sym_if_statement,
+      sym_for_statement,
+  [3673] = 19,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(129), 1,
+      anon_sym_PIPE,
+    ACTIONS(131), 1,
+      anon_sym_TILDE,
+    ACTIONS(133), 1,
+      anon_sym_AMP,
+    ACTIONS(137), 1,
+      anon_sym_PLUS,
+    ACTIONS(139), 1,
+      anon_sym_DASH,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(145), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    ACTIONS(159), 1,
+      anon_sym_and,
+    ACTIONS(227), 1,
+      anon_sym_or,
+    STATE(52), 1,
+      sym_comment,
+    ACTIONS(135), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(157), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(161), 4,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(233), 6,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(235), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [3757] = 35,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(15), 1,
+      anon_sym_SEMI,
+    ACTIONS(17), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(19), 1,
+      sym_break_statement,
+    ACTIONS(21), 1,
+      anon_sym_goto,
+    ACTIONS(23), 1,
+      anon_sym_do,
+    ACTIONS(25), 1,
+      anon_sym_while,
+    ACTIONS(27), 1,
+      anon_sym_repeat,
+    ACTIONS(29), 1,
+      anon_sym_if,
+    ACTIONS(31), 1,
+      anon_sym_for,
+    ACTIONS(33), 1,
+      anon_sym_function,
+    ACTIONS(35), 1,
+      anon_sym_local,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(117), 1,
+      anon_sym_return,
+    ACTIONS(237), 1,
+      anon_sym_until,
+    STATE(47), 1,
+      aux_sym_chunk_repeat1,
+    STATE(53), 1,
+      sym_comment,
+    STATE(104), 1,
+      sym_function_call,
+    STATE(134), 1,
+      sym_variable_declaration,
+    STATE(136), 1,
+      sym_statement,
+    STATE(150), 1,
+      sym_declaration,
+    STATE(151), 1,
+      sym_function_declaration,
+    STATE(155), 1,
+      sym__local_function_declaration,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(162), 1,
+      sym_variable,
+    STATE(164), 1,
+      sym_method_index_expression,
+    STATE(167), 1,
+      sym_parenthesized_expression,
+    STATE(190), 1,
+      sym_return_statement,
+    STATE(247), 1,
+      sym__variable_assignment_varlist,
+    STATE(269), 1,
+      sym__block,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(142), 9,
+      sym_empty_statement,
+      sym_assignment_statement,
+      sym_label_statement,
+      sym_goto_statement,
+      sym_do_statement,
+      sym_while_statement,
+      sym_repeat_statement,
+      sym_if_statement,
+      sym_for_statement,
+  [3873] = 35,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(15), 1,
+      anon_sym_SEMI,
+    ACTIONS(17), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(19), 1,
+      sym_break_statement,
+    ACTIONS(21), 1,
+      anon_sym_goto,
+    ACTIONS(23), 1,
+      anon_sym_do,
+    ACTIONS(25), 1,
+      anon_sym_while,
+    ACTIONS(27), 1,
+      anon_sym_repeat,
+    ACTIONS(29), 1,
+      anon_sym_if,
+    ACTIONS(31), 1,
+      anon_sym_for,
+    ACTIONS(33), 1,
+      anon_sym_function,
+    ACTIONS(35), 1,
+      anon_sym_local,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(117), 1,
+      anon_sym_return,
+    ACTIONS(239), 1,
+      anon_sym_end,
+    STATE(47), 1,
+      aux_sym_chunk_repeat1,
+    STATE(54), 1,
+      sym_comment,
+    STATE(104), 1,
+      sym_function_call,
+    STATE(134), 1,
+      sym_variable_declaration,
+    STATE(136), 1,
+      sym_statement,
+    STATE(150), 1,
+      sym_declaration,
+    STATE(151), 1,
+      sym_function_declaration,
+    STATE(155), 1,
+      sym__local_function_declaration,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(162), 1,
+      sym_variable,
+    STATE(164), 1,
+      sym_method_index_expression,
+    STATE(167), 1,
+      sym_parenthesized_expression,
+    STATE(190), 1,
+      sym_return_statement,
+    STATE(247), 1,
+      sym__variable_assignment_varlist,
+    STATE(252), 1,
+      sym__block,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(142), 9,
+      sym_empty_statement,
+      sym_assignment_statement,
+      sym_label_statement,
+      sym_goto_statement,
+      sym_do_statement,
+      sym_while_statement,
+      sym_repeat_statement,
+      sym_if_statement,
+      sym_for_statement,
+  [3989] = 35,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(15), 1,
+      anon_sym_SEMI,
+    ACTIONS(17), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(19), 1,
+      sym_break_statement,
+    ACTIONS(21), 1,
+      anon_sym_goto,
+    ACTIONS(23), 1,
+      anon_sym_do,
+    ACTIONS(25), 1,
+      anon_sym_while,
+    ACTIONS(27), 1,
+      anon_sym_repeat,
+    ACTIONS(29), 1,
+      anon_sym_if,
+    ACTIONS(31), 1,
+      anon_sym_for,
+    ACTIONS(33), 1,
+      anon_sym_function,
+    ACTIONS(35), 1,
+      anon_sym_local,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(117), 1,
+      anon_sym_return,
+    ACTIONS(241), 1,
+      anon_sym_end,
+    STATE(47), 1,
+      aux_sym_chunk_repeat1,
+    STATE(55), 1,
+      sym_comment,
+    STATE(104), 1,
+      sym_function_call,
+    STATE(134), 1,
+      sym_variable_declaration,
+    STATE(136), 1,
+      sym_statement,
+    STATE(150), 1,
+      sym_declaration,
+    STATE(151), 1,
+      sym_function_declaration,
+    STATE(155), 1,
+      sym__local_function_declaration,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(162), 1,
+      sym_variable,
+    STATE(164), 1,
+      sym_method_index_expression,
+    STATE(167), 1,
+      sym_parenthesized_expression,
+    STATE(190), 1,
+      sym_return_statement,
+    STATE(247), 1,
+      sym__variable_assignment_varlist,
+    STATE(258), 1,
+      sym__block,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(142), 9,
+      sym_empty_statement,
+      sym_assignment_statement,
+      sym_label_statement,
+      sym_goto_statement,
+      sym_do_statement,
+      sym_while_statement,
+      sym_repeat_statement,
+      sym_if_statement,
+      sym_for_statement,
+  [4105] = 35,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(15), 1,
+      anon_sym_SEMI,
+    ACTIONS(17), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(19), 1,
+      sym_break_statement,
+    ACTIONS(21), 1,
+      anon_sym_goto,
+    ACTIONS(23), 1,
+      anon_sym_do,
+    ACTIONS(25), 1,
+      anon_sym_while,
+    ACTIONS(27), 1,
+      anon_sym_repeat,
+    ACTIONS(29), 1,
+      anon_sym_if,
+    ACTIONS(31), 1,
+      anon_sym_for,
+    ACTIONS(33), 1,
+      anon_sym_function,
+    ACTIONS(35), 1,
+      anon_sym_local,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(117), 1,
+      anon_sym_return,
+    ACTIONS(243), 1,
+      anon_sym_end,
+    STATE(47), 1,
+      aux_sym_chunk_repeat1,
+    STATE(56), 1,
+      sym_comment,
+    STATE(104), 1,
+      sym_function_call,
+    STATE(134), 1,
+      sym_variable_declaration,
+    STATE(136), 1,
+      sym_statement,
+    STATE(150), 1,
+      sym_declaration,
+    STATE(151), 1,
+      sym_function_declaration,
+    STATE(155), 1,
+      sym__local_function_declaration,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(162), 1,
+      sym_variable,
+    STATE(164), 1,
+      sym_method_index_expression,
+    STATE(167), 1,
+      sym_parenthesized_expression,
+    STATE(190), 1,
+      sym_return_statement,
+    STATE(239), 1,
+      sym__block,
+    STATE(247), 1,
+      sym__variable_assignment_varlist,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(142), 9,
+      sym_empty_statement,
+      sym_assignment_statement,
+      sym_label_statement,
+      sym_goto_statement,
+      sym_do_statement,
+      sym_while_statement,
+      sym_repeat_statement,
+      sym_if_statement,
+      sym_for_statement,
+  [4221] = 34,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(13), 1,
+      anon_sym_return,
+    ACTIONS(15), 1,
+      anon_sym_SEMI,
+    ACTIONS(17), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(19), 1,
+      sym_break_statement,
+    ACTIONS(21), 1,
+      anon_sym_goto,
+    ACTIONS(23), 1,
+      anon_sym_do,
+    ACTIONS(25), 1,
+      anon_sym_while,
+    ACTIONS(27), 1,
+      anon_sym_repeat,
+    ACTIONS(29), 1,
+      anon_sym_if,
+    ACTIONS(31), 1,
+      anon_sym_for,
+    ACTIONS(33), 1,
+      anon_sym_function,
+    ACTIONS(35), 1,
+      anon_sym_local,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(245), 1,
+      ts_builtin_sym_end,
+    STATE(48), 1,
+      aux_sym_chunk_repeat1,
+    STATE(57), 1,
+      sym_comment,
+    STATE(104), 1,
+      sym_function_call,
+    STATE(134), 1,
+      sym_variable_declaration,
+    STATE(136), 1,
+      sym_statement,
+    STATE(150), 1,
+      sym_declaration,
+    STATE(151), 1,
+      sym_function_declaration,
+    STATE(155), 1,
+      sym__local_function_declaration,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(162), 1,
+      sym_variable,
+    STATE(164), 1,
+      sym_method_index_expression,
+    STATE(167), 1,
+      sym_parenthesized_expression,
+    STATE(229), 1,
+      sym_return_statement,
+    STATE(247), 1,
+      sym__variable_assignment_varlist,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(142), 9,
+      sym_empty_statement,
+      sym_assignment_statement,
+      sym_label_statement,
+      sym_goto_statement,
+      sym_do_statement,
+      sym_while_statement,
+      sym_repeat_statement,
+      sym_if_statement,
+      sym_for_statement,
+  [4334] = 34,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(13), 1,
+      anon_sym_return,
+    ACTIONS(15), 1,
+      anon_sym_SEMI,
+    ACTIONS(17), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(19), 1,
+      sym_break_statement,
+    ACTIONS(21), 1,
+      anon_sym_goto,
+    ACTIONS(23), 1,
+      anon_sym_do,
+    ACTIONS(25), 1,
+      anon_sym_while,
+    ACTIONS(27), 1,
+      anon_sym_repeat,
+    ACTIONS(29), 1,
+      anon_sym_if,
+    ACTIONS(31), 1,
+      anon_sym_for,
+    ACTIONS(33), 1,
+      anon_sym_function,
+    ACTIONS(35), 1,
+      anon_sym_local,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(247), 1,
+      ts_builtin_sym_end,
+    STATE(57), 1,
+      aux_sym_chunk_repeat1,
+    STATE(58), 1,
+      sym_comment,
+    STATE(104), 1,
+      sym_function_call,
+    STATE(134), 1,
+      sym_variable_declaration,
+    STATE(136), 1,
+      sym_statement,
+    STATE(150), 1,
+      sym_declaration,
+    STATE(151), 1,
+      sym_function_declaration,
+    STATE(155), 1,
+      sym__local_function_declaration,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(162), 1,
+      sym_variable,
+    STATE(164), 1,
+      sym_method_index_expression,
+    STATE(167), 1,
+      sym_parenthesized_expression,
+    STATE(247), 1,
+      sym__variable_assignment_varlist,
+    STATE(257), 1,
+      sym_return_statement,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(142), 9,
+      sym_empty_statement,
+      sym_assignment_statement,
+      sym_label_statement,
+      sym_goto_statement,
+      sym_do_statement,
+      sym_while_statement,
+      sym_repeat_statement,
+      sym_if_statement,
+      sym_for_statement,
+  [4447] = 19,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(129), 1,
+      anon_sym_PIPE,
+    ACTIONS(131), 1,
+      anon_sym_TILDE,
+    ACTIONS(133), 1,
+      anon_sym_AMP,
+    ACTIONS(137), 1,
+      anon_sym_PLUS,
+    ACTIONS(139), 1,
+      anon_sym_DASH,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(145), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    ACTIONS(159), 1,
+      anon_sym_and,
+    ACTIONS(227), 1,
+      anon_sym_or,
+    STATE(59), 1,
+      sym_comment,
+    ACTIONS(135), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(157), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(161), 4,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(249), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(251), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [4530] = 34,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(13), 1,
+      anon_sym_return,
+    ACTIONS(15), 1,
+      anon_sym_SEMI,
+    ACTIONS(17), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(19), 1,
+      sym_break_statement,
+    ACTIONS(21), 1,
+      anon_sym_goto,
+    ACTIONS(23), 1,
+      anon_sym_do,
+    ACTIONS(25), 1,
+      anon_sym_while,
+    ACTIONS(27), 1,
+      anon_sym_repeat,
+    ACTIONS(29), 1,
+      anon_sym_if,
+    ACTIONS(31), 1,
+      anon_sym_for,
+    ACTIONS(33), 1,
+      anon_sym_function,
+    ACTIONS(35), 1,
+      anon_sym_local,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(253), 1,
+      ts_builtin_sym_end,
+    STATE(48), 1,
+      aux_sym_chunk_repeat1,
+    STATE(60), 1,
+      sym_comment,
+    STATE(104), 1,
+      sym_function_call,
+    STATE(134), 1,
+      sym_variable_declaration,
+    STATE(136), 1,
+      sym_statement,
+    STATE(150), 1,
+      sym_declaration,
+    STATE(151), 1,
+      sym_function_declaration,
+    STATE(155), 1,
+      sym__local_function_declaration,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(162), 1,
+      sym_variable,
+    STATE(164), 1,
+      sym_method_index_expression,
+    STATE(167), 1,
+      sym_parenthesized_expression,
+    STATE(247), 1,
+      sym__variable_assignment_varlist,
+    STATE(251), 1,
+      sym_return_statement,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(142), 9,
+      sym_empty_statement,
+      sym_assignment_statement,
+      sym_label_statement,
+      sym_goto_statement,
+      sym_do_statement,
+      sym_while_statement,
+      sym_repeat_statement,
+      sym_if_statement,
+      sym_for_statement,
+  [4643] = 19,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(129), 1,
+      anon_sym_PIPE,
+    ACTIONS(131), 1,
+      anon_sym_TILDE,
+    ACTIONS(133), 1,
+      anon_sym_AMP,
+    ACTIONS(137), 1,
+      anon_sym_PLUS,
+    ACTIONS(139), 1,
+      anon_sym_DASH,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(145), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    ACTIONS(159), 1,
+      anon_sym_and,
+    ACTIONS(227), 1,
+      anon_sym_or,
+    STATE(61), 1,
+      sym_comment,
+    ACTIONS(135), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(157), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(161), 4,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(255), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(257), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [4726] = 26,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(259), 1,
+      anon_sym_SEMI,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(62), 1,
+      sym_comment,
+    STATE(101), 1,
+      sym_expression,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    STATE(172), 1,
+      sym__expression_list,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    ACTIONS(261), 4,
+      anon_sym_end,
+      anon_sym_until,
+      anon_sym_elseif,
+      anon_sym_else,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [4821] = 28,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    ACTIONS(283), 1,
+      anon_sym_LBRACK,
+    ACTIONS(285), 1,
+      anon_sym_RBRACE,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(63), 1,
+      sym_comment,
+    STATE(81), 1,
+      sym_identifier,
+    STATE(107), 1,
+      sym_expression,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    STATE(176), 1,
+      sym_field,
+    STATE(240), 1,
+      sym__field_list,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    STATE(2), 2,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [4918] = 28,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    ACTIONS(283), 1,
+      anon_sym_LBRACK,
+    ACTIONS(287), 1,
+      anon_sym_RBRACE,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(64), 1,
+      sym_comment,
+    STATE(81), 1,
+      sym_identifier,
+    STATE(107), 1,
+      sym_expression,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    STATE(176), 1,
+      sym_field,
+    STATE(267), 1,
+      sym__field_list,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    STATE(2), 2,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [5015] = 27,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    ACTIONS(283), 1,
+      anon_sym_LBRACK,
+    ACTIONS(289), 1,
+      anon_sym_RBRACE,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(65), 1,
+      sym_comment,
+    STATE(81), 1,
+      sym_identifier,
+    STATE(107), 1,
+      sym_expression,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    STATE(215), 1,
+      sym_field,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    STATE(2), 2,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [5109] = 27,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    ACTIONS(283), 1,
+      anon_sym_LBRACK,
+    ACTIONS(291), 1,
+      anon_sym_RBRACE,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(66), 1,
+      sym_comment,
+    STATE(81), 1,
+      sym_identifier,
+    STATE(107), 1,
+      sym_expression,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    STATE(215), 1,
+      sym_field,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    STATE(2), 2,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [5203] = 26,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(259), 1,
+      anon_sym_SEMI,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    ACTIONS(293), 1,
+      ts_builtin_sym_end,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(67), 1,
+      sym_comment,
+    STATE(101), 1,
+      sym_expression,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    STATE(172), 1,
+      sym__expression_list,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [5295] = 26,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    ACTIONS(283), 1,
+      anon_sym_LBRACK,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(68), 1,
+      sym_comment,
+    STATE(81), 1,
+      sym_identifier,
+    STATE(107), 1,
+      sym_expression,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    STATE(215), 1,
+      sym_field,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    STATE(2), 2,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [5386] = 24,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(49), 1,
+      sym_expression,
+    STATE(69), 1,
+      sym_comment,
+    STATE(132), 1,
+      sym__variable_assignment_explist,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [5472] = 24,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(49), 1,
+      sym_expression,
+    STATE(70), 1,
+      sym_comment,
+    STATE(146), 1,
+      sym__variable_assignment_explist,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [5558] = 24,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    ACTIONS(295), 1,
+      anon_sym_RPAREN,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(71), 1,
+      sym_comment,
+    STATE(110), 1,
+      sym_expression,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [5644] = 24,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(72), 1,
+      sym_comment,
+    STATE(101), 1,
+      sym_expression,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    STATE(238), 1,
+      sym__expression_list,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [5730] = 23,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(73), 1,
+      sym_comment,
+    STATE(118), 1,
+      sym_expression,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [5813] = 23,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(61), 1,
+      sym_expression,
+    STATE(74), 1,
+      sym_comment,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [5896] = 23,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(75), 1,
+      sym_comment,
+    STATE(119), 1,
+      sym_expression,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [5979] = 23,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(76), 1,
+      sym_comment,
+    STATE(106), 1,
+      sym_expression,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [6062] = 23,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(77), 1,
+      sym_comment,
+    STATE(122), 1,
+      sym_expression,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [6145] = 23,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(78), 1,
+      sym_comment,
+    STATE(121), 1,
+      sym_expression,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [6228] = 23,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(23), 1,
+      sym_expression,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(79), 1,
+      sym_comment,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [6311] = 23,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(80), 1,
+      sym_comment,
+    STATE(128), 1,
+      sym_expression,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [6394] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(297), 1,
+      anon_sym_EQ,
+    STATE(81), 1,
+      sym_comment,
+    ACTIONS(43), 6,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_DASH,
+      anon_sym_SLASH,
+    ACTIONS(41), 26,
+      sym__block_string_start,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_COLON,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_or,
+      anon_sym_and,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_PIPE,
+      anon_sym_AMP,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_PLUS,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+      anon_sym_DOT_DOT,
+      anon_sym_CARET,
+  [6443] = 23,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(52), 1,
+      sym_expression,
+    STATE(82), 1,
+      sym_comment,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [6526] = 23,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(22), 1,
+      sym_expression,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(83), 1,
+      sym_comment,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [6609] = 23,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(32), 1,
+      sym_expression,
+    STATE(84), 1,
+      sym_comment,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [6692] = 23,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(34), 1,
+      sym_expression,
+    STATE(85), 1,
+      sym_comment,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [6775] = 23,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(86), 1,
+      sym_comment,
+    STATE(123), 1,
+      sym_expression,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [6858] = 23,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(87), 1,
+      sym_comment,
+    STATE(116), 1,
+      sym_expression,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [6941] = 23,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(35), 1,
+      sym_expression,
+    STATE(88), 1,
+      sym_comment,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [7024] = 23,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(36), 1,
+      sym_expression,
+    STATE(89), 1,
+      sym_comment,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [7107] = 23,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(37), 1,
+      sym_expression,
+    STATE(90), 1,
+      sym_comment,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [7190] = 23,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(38), 1,
+      sym_expression,
+    STATE(91), 1,
+      sym_comment,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [7273] = 23,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(92), 1,
+      sym_comment,
+    STATE(114), 1,
+      sym_expression,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [7356] = 23,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(39), 1,
+      sym_expression,
+    STATE(93), 1,
+      sym_comment,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [7439] = 23,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(94), 1,
+      sym_comment,
+    STATE(109), 1,
+      sym_expression,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [7522] = 23,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(40), 1,
+      sym_expression,
+    STATE(95), 1,
+      sym_comment,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [7605] = 23,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(41), 1,
+      sym_expression,
+    STATE(96), 1,
+      sym_comment,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [7688] = 23,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(97), 1,
+      sym_comment,
+    STATE(125), 1,
+      sym_expression,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [7771] = 23,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(59), 1,
+      sym_expression,
+    STATE(98), 1,
+      sym_comment,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [7854] = 23,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(99), 1,
+      sym_comment,
+    STATE(120), 1,
+      sym_expression,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [7937] = 23,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(263), 1,
+      anon_sym_function,
+    ACTIONS(269), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(271), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(273), 1,
+      anon_sym_LBRACE,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    ACTIONS(281), 1,
+      sym__block_string_start,
+    STATE(25), 1,
+      sym__block_string,
+    STATE(26), 1,
+      sym__quote_string,
+    STATE(33), 1,
+      sym_expression,
+    STATE(100), 1,
+      sym_comment,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(164), 1,
+      sym_method_index_expression,
+    ACTIONS(267), 2,
+      sym_number,
+      sym_vararg_expression,
+    ACTIONS(275), 2,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+    ACTIONS(277), 2,
+      anon_sym_DASH,
+      anon_sym_not,
+    ACTIONS(265), 3,
+      sym_nil,
+      sym_false,
+      sym_true,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+    STATE(7), 3,
+      sym_variable,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(24), 5,
+      sym_string,
+      sym_function_definition,
+      sym_table_constructor,
+      sym_binary_expression,
+      sym_unary_expression,
+  [8020] = 21,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(129), 1,
+      anon_sym_PIPE,
+    ACTIONS(131), 1,
+      anon_sym_TILDE,
+    ACTIONS(133), 1,
+      anon_sym_AMP,
+    ACTIONS(137), 1,
+      anon_sym_PLUS,
+    ACTIONS(139), 1,
+      anon_sym_DASH,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(145), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    ACTIONS(225), 1,
+      anon_sym_COMMA,
+    ACTIONS(301), 1,
+      anon_sym_else,
+    ACTIONS(303), 1,
+      anon_sym_or,
+    ACTIONS(305), 1,
+      anon_sym_and,
+    STATE(101), 1,
+      sym_comment,
+    STATE(166), 1,
+      aux_sym__variable_assignment_explist_repeat1,
+    ACTIONS(135), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(157), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(161), 4,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(299), 6,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_until,
+      anon_sym_elseif,
+  [8096] = 7,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(47), 1,
+      anon_sym_COLON,
+    STATE(102), 1,
+      sym_comment,
+    ACTIONS(307), 4,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      sym_grit_metavariable,
+    ACTIONS(45), 9,
+      sym__block_string_start,
+      anon_sym_EQ,
+      anon_sym_COMMA,
+      anon_sym_DOT,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_LBRACE,
+    ACTIONS(309), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [8143] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(103), 1,
+      sym_comment,
+    ACTIONS(45), 11,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_EQ,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_DOT,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      sym_grit_metavariable,
+    ACTIONS(47), 17,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_in,
+      anon_sym_function,
+      anon_sym_local,
+      anon_sym_COLON,
+      sym__identifier,
+  [8185] = 7,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(65), 1,
+      anon_sym_COLON,
+    STATE(104), 1,
+      sym_comment,
+    ACTIONS(307), 4,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      sym_grit_metavariable,
+    ACTIONS(67), 7,
+      sym__block_string_start,
+      anon_sym_DOT,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_LBRACE,
+    ACTIONS(309), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [8230] = 9,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(315), 1,
+      anon_sym_COMMA,
+    ACTIONS(317), 1,
+      anon_sym_LT,
+    STATE(105), 1,
+      sym_comment,
+    STATE(111), 1,
+      aux_sym__att_name_list_repeat1,
+    STATE(112), 1,
+      sym__attrib,
+    ACTIONS(311), 6,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_EQ,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(313), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [8277] = 18,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(129), 1,
+      anon_sym_PIPE,
+    ACTIONS(131), 1,
+      anon_sym_TILDE,
+    ACTIONS(133), 1,
+      anon_sym_AMP,
+    ACTIONS(137), 1,
+      anon_sym_PLUS,
+    ACTIONS(139), 1,
+      anon_sym_DASH,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(145), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    ACTIONS(303), 1,
+      anon_sym_or,
+    ACTIONS(305), 1,
+      anon_sym_and,
+    STATE(106), 1,
+      sym_comment,
+    ACTIONS(135), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(157), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(319), 3,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+    ACTIONS(161), 4,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+  [8341] = 18,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(129), 1,
+      anon_sym_PIPE,
+    ACTIONS(131), 1,
+      anon_sym_TILDE,
+    ACTIONS(133), 1,
+      anon_sym_AMP,
+    ACTIONS(137), 1,
+      anon_sym_PLUS,
+    ACTIONS(139), 1,
+      anon_sym_DASH,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(145), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    ACTIONS(303), 1,
+      anon_sym_or,
+    ACTIONS(305), 1,
+      anon_sym_and,
+    STATE(107), 1,
+      sym_comment,
+    ACTIONS(135), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(157), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(321), 3,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+    ACTIONS(161), 4,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+  [8405] = 7,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(317), 1,
+      anon_sym_LT,
+    STATE(108), 1,
+      sym_comment,
+    STATE(126), 1,
+      sym__attrib,
+    ACTIONS(323), 7,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_EQ,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(325), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [8447] = 18,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(129), 1,
+      anon_sym_PIPE,
+    ACTIONS(131), 1,
+      anon_sym_TILDE,
+    ACTIONS(133), 1,
+      anon_sym_AMP,
+    ACTIONS(137), 1,
+      anon_sym_PLUS,
+    ACTIONS(139), 1,
+      anon_sym_DASH,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(145), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    ACTIONS(303), 1,
+      anon_sym_or,
+    ACTIONS(305), 1,
+      anon_sym_and,
+    STATE(109), 1,
+      sym_comment,
+    ACTIONS(135), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(157), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(327), 3,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+    ACTIONS(161), 4,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+  [8511] = 20,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(129), 1,
+      anon_sym_PIPE,
+    ACTIONS(131), 1,
+      anon_sym_TILDE,
+    ACTIONS(133), 1,
+      anon_sym_AMP,
+    ACTIONS(137), 1,
+      anon_sym_PLUS,
+    ACTIONS(139), 1,
+      anon_sym_DASH,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(145), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    ACTIONS(303), 1,
+      anon_sym_or,
+    ACTIONS(305), 1,
+      anon_sym_and,
+    ACTIONS(329), 1,
+      anon_sym_COMMA,
+    ACTIONS(331), 1,
+      anon_sym_RPAREN,
+    STATE(110), 1,
+      sym_comment,
+    STATE(199), 1,
+      aux_sym_arguments_repeat1,
+    ACTIONS(135), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(157), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(161), 4,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+  [8579] = 7,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(315), 1,
+      anon_sym_COMMA,
+    STATE(111), 1,
+      sym_comment,
+    STATE(113), 1,
+      aux_sym__att_name_list_repeat1,
+    ACTIONS(333), 6,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_EQ,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(335), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [8620] = 7,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(315), 1,
+      anon_sym_COMMA,
+    STATE(112), 1,
+      sym_comment,
+    STATE(115), 1,
+      aux_sym__att_name_list_repeat1,
+    ACTIONS(337), 6,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_EQ,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(339), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [8661] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(345), 1,
+      anon_sym_COMMA,
+    STATE(113), 2,
+      sym_comment,
+      aux_sym__att_name_list_repeat1,
+    ACTIONS(341), 6,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_EQ,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(343), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [8700] = 19,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(129), 1,
+      anon_sym_PIPE,
+    ACTIONS(131), 1,
+      anon_sym_TILDE,
+    ACTIONS(133), 1,
+      anon_sym_AMP,
+    ACTIONS(137), 1,
+      anon_sym_PLUS,
+    ACTIONS(139), 1,
+      anon_sym_DASH,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(145), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    ACTIONS(303), 1,
+      anon_sym_or,
+    ACTIONS(305), 1,
+      anon_sym_and,
+    ACTIONS(348), 1,
+      anon_sym_COMMA,
+    ACTIONS(350), 1,
+      anon_sym_do,
+    STATE(114), 1,
+      sym_comment,
+    ACTIONS(135), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(157), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(161), 4,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+  [8765] = 7,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(315), 1,
+      anon_sym_COMMA,
+    STATE(113), 1,
+      aux_sym__att_name_list_repeat1,
+    STATE(115), 1,
+      sym_comment,
+    ACTIONS(352), 6,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_EQ,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(354), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [8806] = 18,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(129), 1,
+      anon_sym_PIPE,
+    ACTIONS(131), 1,
+      anon_sym_TILDE,
+    ACTIONS(133), 1,
+      anon_sym_AMP,
+    ACTIONS(137), 1,
+      anon_sym_PLUS,
+    ACTIONS(139), 1,
+      anon_sym_DASH,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(145), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    ACTIONS(303), 1,
+      anon_sym_or,
+    ACTIONS(305), 1,
+      anon_sym_and,
+    STATE(116), 1,
+      sym_comment,
+    ACTIONS(135), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(157), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(356), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(161), 4,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+  [8869] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(117), 1,
+      sym_comment,
+    ACTIONS(358), 7,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_EQ,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(360), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [8905] = 18,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(129), 1,
+      anon_sym_PIPE,
+    ACTIONS(131), 1,
+      anon_sym_TILDE,
+    ACTIONS(133), 1,
+      anon_sym_AMP,
+    ACTIONS(137), 1,
+      anon_sym_PLUS,
+    ACTIONS(139), 1,
+      anon_sym_DASH,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(145), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    ACTIONS(303), 1,
+      anon_sym_or,
+    ACTIONS(305), 1,
+      anon_sym_and,
+    ACTIONS(362), 1,
+      anon_sym_then,
+    STATE(118), 1,
+      sym_comment,
+    ACTIONS(135), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(157), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(161), 4,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+  [8967] = 18,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(129), 1,
+      anon_sym_PIPE,
+    ACTIONS(131), 1,
+      anon_sym_TILDE,
+    ACTIONS(133), 1,
+      anon_sym_AMP,
+    ACTIONS(137), 1,
+      anon_sym_PLUS,
+    ACTIONS(139), 1,
+      anon_sym_DASH,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(145), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    ACTIONS(303), 1,
+      anon_sym_or,
+    ACTIONS(305), 1,
+      anon_sym_and,
+    ACTIONS(364), 1,
+      anon_sym_RBRACK,
+    STATE(119), 1,
+      sym_comment,
+    ACTIONS(135), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(157), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(161), 4,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+  [9029] = 18,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(129), 1,
+      anon_sym_PIPE,
+    ACTIONS(131), 1,
+      anon_sym_TILDE,
+    ACTIONS(133), 1,
+      anon_sym_AMP,
+    ACTIONS(137), 1,
+      anon_sym_PLUS,
+    ACTIONS(139), 1,
+      anon_sym_DASH,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(145), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    ACTIONS(303), 1,
+      anon_sym_or,
+    ACTIONS(305), 1,
+      anon_sym_and,
+    ACTIONS(366), 1,
+      anon_sym_COMMA,
+    STATE(120), 1,
+      sym_comment,
+    ACTIONS(135), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(157), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(161), 4,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+  [9091] = 18,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(129), 1,
+      anon_sym_PIPE,
+    ACTIONS(131), 1,
+      anon_sym_TILDE,
+    ACTIONS(133), 1,
+      anon_sym_AMP,
+    ACTIONS(137), 1,
+      anon_sym_PLUS,
+    ACTIONS(139), 1,
+      anon_sym_DASH,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(145), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    ACTIONS(303), 1,
+      anon_sym_or,
+    ACTIONS(305), 1,
+      anon_sym_and,
+    ACTIONS(368), 1,
+      anon_sym_do,
+    STATE(121), 1,
+      sym_comment,
+    ACTIONS(135), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(157), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(161), 4,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+  [9153] = 18,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(129), 1,
+      anon_sym_PIPE,
+    ACTIONS(131), 1,
+      anon_sym_TILDE,
+    ACTIONS(133), 1,
+      anon_sym_AMP,
+    ACTIONS(137), 1,
+      anon_sym_PLUS,
+    ACTIONS(139), 1,
+      anon_sym_DASH,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(145), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    ACTIONS(303), 1,
+      anon_sym_or,
+    ACTIONS(305), 1,
+      anon_sym_and,
+    ACTIONS(370), 1,
+      anon_sym_RPAREN,
+    STATE(122), 1,
+      sym_comment,
+    ACTIONS(135), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(157), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(161), 4,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+  [9215] = 18,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(129), 1,
+      anon_sym_PIPE,
+    ACTIONS(131), 1,
+      anon_sym_TILDE,
+    ACTIONS(133), 1,
+      anon_sym_AMP,
+    ACTIONS(137), 1,
+      anon_sym_PLUS,
+    ACTIONS(139), 1,
+      anon_sym_DASH,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(145), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    ACTIONS(303), 1,
+      anon_sym_or,
+    ACTIONS(305), 1,
+      anon_sym_and,
+    ACTIONS(372), 1,
+      anon_sym_then,
+    STATE(123), 1,
+      sym_comment,
+    ACTIONS(135), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(157), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(161), 4,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+  [9277] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(378), 1,
+      anon_sym_COMMA,
+    STATE(124), 2,
+      sym_comment,
+      aux_sym__variable_assignment_explist_repeat1,
+    ACTIONS(374), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(376), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [9315] = 18,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(129), 1,
+      anon_sym_PIPE,
+    ACTIONS(131), 1,
+      anon_sym_TILDE,
+    ACTIONS(133), 1,
+      anon_sym_AMP,
+    ACTIONS(137), 1,
+      anon_sym_PLUS,
+    ACTIONS(139), 1,
+      anon_sym_DASH,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(145), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    ACTIONS(303), 1,
+      anon_sym_or,
+    ACTIONS(305), 1,
+      anon_sym_and,
+    ACTIONS(381), 1,
+      anon_sym_do,
+    STATE(125), 1,
+      sym_comment,
+    ACTIONS(135), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(157), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(161), 4,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+  [9377] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(126), 1,
+      sym_comment,
+    ACTIONS(383), 7,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_EQ,
+      anon_sym_COMMA,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(385), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [9413] = 7,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(225), 1,
+      anon_sym_COMMA,
+    STATE(124), 1,
+      aux_sym__variable_assignment_explist_repeat1,
+    STATE(127), 1,
+      sym_comment,
+    ACTIONS(387), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(389), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [9453] = 18,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(129), 1,
+      anon_sym_PIPE,
+    ACTIONS(131), 1,
+      anon_sym_TILDE,
+    ACTIONS(133), 1,
+      anon_sym_AMP,
+    ACTIONS(137), 1,
+      anon_sym_PLUS,
+    ACTIONS(139), 1,
+      anon_sym_DASH,
+    ACTIONS(143), 1,
+      anon_sym_SLASH,
+    ACTIONS(145), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(147), 1,
+      anon_sym_CARET,
+    ACTIONS(303), 1,
+      anon_sym_or,
+    ACTIONS(305), 1,
+      anon_sym_and,
+    ACTIONS(391), 1,
+      anon_sym_RBRACK,
+    STATE(128), 1,
+      sym_comment,
+    ACTIONS(135), 2,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(157), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(141), 3,
+      anon_sym_STAR,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PERCENT,
+    ACTIONS(161), 4,
+      anon_sym_LT_EQ,
+      anon_sym_EQ_EQ,
+      anon_sym_TILDE_EQ,
+      anon_sym_GT_EQ,
+  [9515] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(397), 1,
+      anon_sym_EQ,
+    STATE(129), 1,
+      sym_comment,
+    ACTIONS(393), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(395), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [9552] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(130), 1,
+      sym_comment,
+    ACTIONS(399), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(401), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [9586] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(131), 1,
+      sym_comment,
+    ACTIONS(403), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(405), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [9620] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(132), 1,
+      sym_comment,
+    ACTIONS(407), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(409), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [9654] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(133), 1,
+      sym_comment,
+    ACTIONS(411), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(413), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [9688] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(134), 1,
+      sym_comment,
+    ACTIONS(415), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(417), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [9722] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(135), 1,
+      sym_comment,
+    ACTIONS(419), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(421), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [9756] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(136), 1,
+      sym_comment,
+    ACTIONS(423), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(425), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [9790] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(137), 1,
+      sym_comment,
+    ACTIONS(427), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(429), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [9824] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(138), 1,
+      sym_comment,
+    ACTIONS(431), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(433), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [9858] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(139), 1,
+      sym_comment,
+    ACTIONS(435), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(437), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [9892] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(140), 1,
+      sym_comment,
+    ACTIONS(439), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(441), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [9926] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(141), 1,
+      sym_comment,
+    ACTIONS(443), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(445), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [9960] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(142), 1,
+      sym_comment,
+    ACTIONS(307), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(309), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [9994] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(143), 1,
+      sym_comment,
+    ACTIONS(447), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(449), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [10028] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(144), 1,
+      sym_comment,
+    ACTIONS(451), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(453), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [10062] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(145), 1,
+      sym_comment,
+    ACTIONS(455), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(457), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [10096] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(146), 1,
+      sym_comment,
+    ACTIONS(459), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(461), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [10130] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(147), 1,
+      sym_comment,
+    ACTIONS(463), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(465), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [10164] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(148), 1,
+      sym_comment,
+    ACTIONS(467), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(469), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [10198] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(149), 1,
+      sym_comment,
+    ACTIONS(471), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(473), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [10232] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(150), 1,
+      sym_comment,
+    ACTIONS(475), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(477), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [10266] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(151), 1,
+      sym_comment,
+    ACTIONS(479), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(481), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [10300] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(152), 1,
+      sym_comment,
+    ACTIONS(483), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(485), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [10334] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(153), 1,
+      sym_comment,
+    ACTIONS(487), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(489), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [10368] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(154), 1,
+      sym_comment,
+    ACTIONS(491), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(493), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [10402] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(155), 1,
+      sym_comment,
+    ACTIONS(495), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(497), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [10436] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(156), 1,
+      sym_comment,
+    ACTIONS(499), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(501), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [10470] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(157), 1,
+      sym_comment,
+    ACTIONS(503), 5,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(505), 15,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_until,
+      anon_sym_if,
+      anon_sym_elseif,
+      anon_sym_else,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [10504] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(158), 1,
+      sym_comment,
+    ACTIONS(507), 7,
+      anon_sym_function,
+      sym_nil,
+      sym_false,
+      sym_true,
+      anon_sym_DASH,
+      anon_sym_not,
+      sym__identifier,
+    ACTIONS(509), 12,
+      sym__block_string_start,
+      sym_number,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      sym_vararg_expression,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_TILDE,
+      anon_sym_POUND,
+      sym_grit_metavariable,
+  [10537] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(159), 1,
+      sym_comment,
+    ACTIONS(513), 4,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(511), 12,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_if,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [10567] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(160), 1,
+      sym_comment,
+    ACTIONS(517), 4,
+      anon_sym_SEMI,
+      anon_sym_COLON_COLON,
+      anon_sym_LPAREN,
+      sym_grit_metavariable,
+    ACTIONS(515), 12,
+      anon_sym_return,
+      sym_break_statement,
+      anon_sym_goto,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_if,
+      anon_sym_for,
+      anon_sym_function,
+      anon_sym_local,
+      sym__identifier,
+  [10597] = 15,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(519), 1,
+      anon_sym_DOT,
+    ACTIONS(521), 1,
+      anon_sym_COLON,
+    ACTIONS(523), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(525), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(527), 1,
+      anon_sym_LPAREN,
+    ACTIONS(529), 1,
+      anon_sym_LBRACK,
+    ACTIONS(531), 1,
+      anon_sym_LBRACE,
+    ACTIONS(533), 1,
+      sym__block_string_start,
+    STATE(9), 1,
+      sym_arguments,
+    STATE(14), 1,
+      sym__quote_string,
+    STATE(15), 1,
+      sym__block_string,
+    STATE(161), 1,
+      sym_comment,
+    STATE(8), 2,
+      sym_string,
+      sym_table_constructor,
+  [10644] = 7,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(535), 1,
+      anon_sym_EQ,
+    ACTIONS(537), 1,
+      anon_sym_COMMA,
+    STATE(162), 1,
+      sym_comment,
+    STATE(219), 1,
+      aux_sym__variable_assignment_varlist_repeat1,
+    ACTIONS(67), 8,
+      sym__block_string_start,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_LBRACE,
+  [10673] = 11,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(37), 1,
+      anon_sym_LPAREN,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    STATE(161), 1,
+      sym__prefix_expression,
+    STATE(163), 1,
+      sym_comment,
+    STATE(164), 1,
+      sym_method_index_expression,
+    STATE(165), 1,
+      sym_variable,
+    STATE(167), 2,
+      sym_function_call,
+      sym_parenthesized_expression,
+    STATE(2), 3,
+      sym_bracket_index_expression,
+      sym_dot_index_expression,
+      sym_identifier,
+  [10710] = 12,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(523), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(525), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(527), 1,
+      anon_sym_LPAREN,
+    ACTIONS(531), 1,
+      anon_sym_LBRACE,
+    ACTIONS(533), 1,
+      sym__block_string_start,
+    STATE(9), 1,
+      sym_arguments,
+    STATE(14), 1,
+      sym__quote_string,
+    STATE(15), 1,
+      sym__block_string,
+    STATE(164), 1,
+      sym_comment,
+    STATE(8), 2,
+      sym_string,
+      sym_table_constructor,
+  [10748] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(165), 1,
+      sym_comment,
+    ACTIONS(539), 2,
+      anon_sym_EQ,
+      anon_sym_COMMA,
+    ACTIONS(67), 8,
+      sym__block_string_start,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_LBRACE,
+  [10772] = 7,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(225), 1,
+      anon_sym_COMMA,
+    ACTIONS(543), 1,
+      anon_sym_else,
+    STATE(124), 1,
+      aux_sym__variable_assignment_explist_repeat1,
+    STATE(166), 1,
+      sym_comment,
+    ACTIONS(541), 6,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_do,
+      anon_sym_end,
+      anon_sym_until,
+      anon_sym_elseif,
+  [10799] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(167), 1,
+      sym_comment,
+    ACTIONS(67), 8,
+      sym__block_string_start,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+      anon_sym_LBRACE,
+  [10819] = 10,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(545), 1,
+      sym__identifier,
+    ACTIONS(547), 1,
+      sym_grit_metavariable,
+    STATE(168), 1,
+      sym_comment,
+    STATE(208), 1,
+      sym__function_name_dot_index_expression,
+    STATE(222), 1,
+      sym_identifier,
+    STATE(225), 1,
+      sym__function_name,
+    STATE(226), 1,
+      sym__function_name_prefix_expression,
+    STATE(246), 1,
+      sym__function_name_method_index_expression,
+  [10850] = 8,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(545), 1,
+      sym__identifier,
+    ACTIONS(547), 1,
+      sym_grit_metavariable,
+    STATE(169), 1,
+      sym_comment,
+    STATE(196), 1,
+      sym_identifier,
+    STATE(266), 1,
+      sym__name_list,
+    STATE(259), 2,
+      sym_for_generic_clause,
+      sym_for_numeric_clause,
+  [10876] = 9,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(123), 1,
+      anon_sym_else,
+    ACTIONS(549), 1,
+      anon_sym_end,
+    ACTIONS(551), 1,
+      anon_sym_elseif,
+    STATE(170), 1,
+      sym_comment,
+    STATE(175), 1,
+      aux_sym_if_statement_repeat1,
+    STATE(228), 1,
+      sym_elseif_statement,
+    STATE(253), 1,
+      sym_else_statement,
+  [10904] = 9,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(123), 1,
+      anon_sym_else,
+    ACTIONS(551), 1,
+      anon_sym_elseif,
+    ACTIONS(553), 1,
+      anon_sym_end,
+    STATE(171), 1,
+      sym_comment,
+    STATE(180), 1,
+      aux_sym_if_statement_repeat1,
+    STATE(228), 1,
+      sym_elseif_statement,
+    STATE(260), 1,
+      sym_else_statement,
+  [10932] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(557), 1,
+      anon_sym_SEMI,
+    ACTIONS(559), 1,
+      anon_sym_else,
+    STATE(172), 1,
+      sym_comment,
+    ACTIONS(555), 4,
+      ts_builtin_sym_end,
+      anon_sym_end,
+      anon_sym_until,
+      anon_sym_elseif,
+  [10954] = 9,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(545), 1,
+      sym__identifier,
+    ACTIONS(547), 1,
+      sym_grit_metavariable,
+    ACTIONS(561), 1,
+      anon_sym_function,
+    STATE(105), 1,
+      sym_identifier,
+    STATE(129), 1,
+      sym__att_name_list,
+    STATE(131), 1,
+      sym__local_variable_assignment,
+    STATE(173), 1,
+      sym_comment,
+  [10982] = 9,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(545), 1,
+      sym__identifier,
+    ACTIONS(547), 1,
+      sym_grit_metavariable,
+    ACTIONS(563), 1,
+      sym_vararg_expression,
+    ACTIONS(565), 1,
+      anon_sym_RPAREN,
+    STATE(174), 1,
+      sym_comment,
+    STATE(224), 1,
+      sym_identifier,
+    STATE(256), 1,
+      sym__parameter_list,
+  [11010] = 9,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(123), 1,
+      anon_sym_else,
+    ACTIONS(551), 1,
+      anon_sym_elseif,
+    ACTIONS(567), 1,
+      anon_sym_end,
+    STATE(175), 1,
+      sym_comment,
+    STATE(180), 1,
+      aux_sym_if_statement_repeat1,
+    STATE(228), 1,
+      sym_elseif_statement,
+    STATE(242), 1,
+      sym_else_statement,
+  [11038] = 7,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(571), 1,
+      anon_sym_RBRACE,
+    STATE(65), 1,
+      sym__field_sep,
+    STATE(176), 1,
+      sym_comment,
+    STATE(178), 1,
+      aux_sym__field_list_repeat1,
+    ACTIONS(569), 2,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+  [11061] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(575), 1,
+      anon_sym_else,
+    STATE(177), 1,
+      sym_comment,
+    ACTIONS(573), 4,
+      ts_builtin_sym_end,
+      anon_sym_end,
+      anon_sym_until,
+      anon_sym_elseif,
+  [11080] = 7,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(577), 1,
+      anon_sym_RBRACE,
+    STATE(66), 1,
+      sym__field_sep,
+    STATE(178), 1,
+      sym_comment,
+    STATE(181), 1,
+      aux_sym__field_list_repeat1,
+    ACTIONS(569), 2,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+  [11103] = 8,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(579), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(581), 1,
+      aux_sym__singlequote_string_content_token1,
+    ACTIONS(583), 1,
+      sym_escape_sequence,
+    ACTIONS(585), 1,
+      sym_grit_metavariable,
+    ACTIONS(587), 1,
+      anon_sym_DASH_DASH,
+    STATE(179), 1,
+      sym_comment,
+    STATE(188), 1,
+      aux_sym__singlequote_string_content,
+  [11128] = 7,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(589), 1,
+      anon_sym_end,
+    ACTIONS(591), 1,
+      anon_sym_elseif,
+    ACTIONS(594), 1,
+      anon_sym_else,
+    STATE(228), 1,
+      sym_elseif_statement,
+    STATE(180), 2,
+      sym_comment,
+      aux_sym_if_statement_repeat1,
+  [11151] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(599), 1,
+      anon_sym_RBRACE,
+    STATE(68), 1,
+      sym__field_sep,
+    ACTIONS(596), 2,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+    STATE(181), 2,
+      sym_comment,
+      aux_sym__field_list_repeat1,
+  [11172] = 8,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(587), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(601), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(603), 1,
+      aux_sym__doublequote_string_content_token1,
+    ACTIONS(605), 1,
+      sym_escape_sequence,
+    ACTIONS(607), 1,
+      sym_grit_metavariable,
+    STATE(182), 1,
+      sym_comment,
+    STATE(187), 1,
+      aux_sym__doublequote_string_content,
+  [11197] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(183), 1,
+      sym_comment,
+    ACTIONS(609), 5,
+      sym__block_string_start,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      anon_sym_LPAREN,
+      anon_sym_LBRACE,
+  [11214] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(613), 1,
+      anon_sym_else,
+    STATE(184), 1,
+      sym_comment,
+    ACTIONS(611), 4,
+      ts_builtin_sym_end,
+      anon_sym_end,
+      anon_sym_until,
+      anon_sym_elseif,
+  [11233] = 8,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(581), 1,
+      aux_sym__singlequote_string_content_token1,
+    ACTIONS(583), 1,
+      sym_escape_sequence,
+    ACTIONS(587), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(601), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(615), 1,
+      sym_grit_metavariable,
+    STATE(185), 1,
+      sym_comment,
+    STATE(192), 1,
+      aux_sym__singlequote_string_content,
+  [11258] = 8,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(579), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(587), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(603), 1,
+      aux_sym__doublequote_string_content_token1,
+    ACTIONS(605), 1,
+      sym_escape_sequence,
+    ACTIONS(617), 1,
+      sym_grit_metavariable,
+    STATE(186), 1,
+      sym_comment,
+    STATE(197), 1,
+      aux_sym__doublequote_string_content,
+  [11283] = 6,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(587), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(619), 1,
+      anon_sym_DQUOTE,
+    STATE(187), 1,
+      sym_comment,
+    STATE(194), 1,
+      aux_sym__doublequote_string_content,
+    ACTIONS(605), 2,
+      aux_sym__doublequote_string_content_token1,
+      sym_escape_sequence,
+  [11303] = 6,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(587), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(621), 1,
+      anon_sym_SQUOTE,
+    STATE(188), 1,
+      sym_comment,
+    STATE(198), 1,
+      aux_sym__singlequote_string_content,
+    ACTIONS(583), 2,
+      aux_sym__singlequote_string_content_token1,
+      sym_escape_sequence,
+  [11323] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(625), 1,
+      anon_sym_else,
+    STATE(189), 1,
+      sym_comment,
+    ACTIONS(623), 3,
+      anon_sym_end,
+      anon_sym_until,
+      anon_sym_elseif,
+  [11341] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(629), 1,
+      anon_sym_else,
+    STATE(190), 1,
+      sym_comment,
+    ACTIONS(627), 3,
+      anon_sym_end,
+      anon_sym_until,
+      anon_sym_elseif,
+  [11359] = 7,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(545), 1,
+      sym__identifier,
+    ACTIONS(547), 1,
+      sym_grit_metavariable,
+    ACTIONS(631), 1,
+      sym_vararg_expression,
+    STATE(191), 1,
+      sym_comment,
+    STATE(217), 1,
+      sym_identifier,
+  [11381] = 6,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(587), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(619), 1,
+      anon_sym_SQUOTE,
+    STATE(192), 1,
+      sym_comment,
+    STATE(198), 1,
+      aux_sym__singlequote_string_content,
+    ACTIONS(583), 2,
+      aux_sym__singlequote_string_content_token1,
+      sym_escape_sequence,
+  [11401] = 7,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(545), 1,
+      sym__identifier,
+    ACTIONS(547), 1,
+      sym_grit_metavariable,
+    ACTIONS(633), 1,
+      sym_vararg_expression,
+    STATE(193), 1,
+      sym_comment,
+    STATE(217), 1,
+      sym_identifier,
+  [11423] = 5,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(587), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(635), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(637), 2,
+      aux_sym__doublequote_string_content_token1,
+      sym_escape_sequence,
+    STATE(194), 2,
+      aux_sym__doublequote_string_content,
+      sym_comment,
+  [11441] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(640), 1,
+      anon_sym_COMMA,
+    ACTIONS(643), 2,
+      anon_sym_in,
+      anon_sym_RPAREN,
+    STATE(195), 2,
+      sym_comment,
+      aux_sym__name_list_repeat1,
+  [11459] = 7,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(645), 1,
+      anon_sym_EQ,
+    ACTIONS(647), 1,
+      anon_sym_COMMA,
+    ACTIONS(649), 1,
+      anon_sym_in,
+    STATE(196), 1,
+      sym_comment,
+    STATE(202), 1,
+      aux_sym__name_list_repeat1,
+  [11481] = 6,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(587), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(621), 1,
+      anon_sym_DQUOTE,
+    STATE(194), 1,
+      aux_sym__doublequote_string_content,
+    STATE(197), 1,
+      sym_comment,
+    ACTIONS(605), 2,
+      aux_sym__doublequote_string_content_token1,
+      sym_escape_sequence,
+  [11501] = 5,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(587), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(651), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(653), 2,
+      aux_sym__singlequote_string_content_token1,
+      sym_escape_sequence,
+    STATE(198), 2,
+      aux_sym__singlequote_string_content,
+      sym_comment,
+  [11519] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(329), 1,
+      anon_sym_COMMA,
+    ACTIONS(656), 1,
+      anon_sym_RPAREN,
+    STATE(199), 1,
+      sym_comment,
+    STATE(207), 1,
+      aux_sym_arguments_repeat1,
+  [11538] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(545), 1,
+      sym__identifier,
+    ACTIONS(547), 1,
+      sym_grit_metavariable,
+    STATE(200), 1,
+      sym_comment,
+    STATE(217), 1,
+      sym_identifier,
+  [11557] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(658), 1,
+      anon_sym_COMMA,
+    ACTIONS(660), 1,
+      anon_sym_RPAREN,
+    STATE(195), 1,
+      aux_sym__name_list_repeat1,
+    STATE(201), 1,
+      sym_comment,
+  [11576] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(647), 1,
+      anon_sym_COMMA,
+    ACTIONS(662), 1,
+      anon_sym_in,
+    STATE(195), 1,
+      aux_sym__name_list_repeat1,
+    STATE(202), 1,
+      sym_comment,
+  [11595] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(545), 1,
+      sym__identifier,
+    ACTIONS(547), 1,
+      sym_grit_metavariable,
+    STATE(153), 1,
+      sym_identifier,
+    STATE(203), 1,
+      sym_comment,
+  [11614] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(545), 1,
+      sym__identifier,
+    ACTIONS(547), 1,
+      sym_grit_metavariable,
+    STATE(204), 1,
+      sym_comment,
+    STATE(220), 1,
+      sym_identifier,
+  [11633] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(545), 1,
+      sym__identifier,
+    ACTIONS(547), 1,
+      sym_grit_metavariable,
+    STATE(205), 1,
+      sym_comment,
+    STATE(248), 1,
+      sym_identifier,
+  [11652] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(664), 1,
+      anon_sym_LPAREN,
+    STATE(55), 1,
+      sym_parameters,
+    STATE(143), 1,
+      sym__function_body,
+    STATE(206), 1,
+      sym_comment,
+  [11671] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(666), 1,
+      anon_sym_COMMA,
+    ACTIONS(669), 1,
+      anon_sym_RPAREN,
+    STATE(207), 2,
+      sym_comment,
+      aux_sym_arguments_repeat1,
+  [11688] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(208), 1,
+      sym_comment,
+    ACTIONS(671), 3,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_LPAREN,
+  [11703] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(545), 1,
+      sym__identifier,
+    ACTIONS(547), 1,
+      sym_grit_metavariable,
+    STATE(108), 1,
+      sym_identifier,
+    STATE(209), 1,
+      sym_comment,
+  [11722] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(545), 1,
+      sym__identifier,
+    ACTIONS(547), 1,
+      sym_grit_metavariable,
+    STATE(210), 1,
+      sym_comment,
+    STATE(264), 1,
+      sym_identifier,
+  [11741] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    STATE(4), 1,
+      sym_identifier,
+    STATE(211), 1,
+      sym_comment,
+  [11760] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(9), 1,
+      sym__identifier,
+    ACTIONS(279), 1,
+      sym_grit_metavariable,
+    STATE(183), 1,
+      sym_identifier,
+    STATE(212), 1,
+      sym_comment,
+  [11779] = 5,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(587), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(673), 1,
+      anon_sym_DQUOTE,
+    STATE(213), 1,
+      sym_comment,
+    ACTIONS(675), 2,
+      aux_sym__doublequote_string_content_token1,
+      sym_escape_sequence,
+  [11796] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(664), 1,
+      anon_sym_LPAREN,
+    STATE(43), 1,
+      sym__function_body,
+    STATE(55), 1,
+      sym_parameters,
+    STATE(214), 1,
+      sym_comment,
+  [11815] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(215), 1,
+      sym_comment,
+    ACTIONS(677), 3,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RBRACE,
+  [11830] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(681), 1,
+      anon_sym_else,
+    STATE(216), 1,
+      sym_comment,
+    ACTIONS(679), 2,
+      anon_sym_end,
+      anon_sym_elseif,
+  [11847] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(217), 1,
+      sym_comment,
+    ACTIONS(683), 3,
+      anon_sym_COMMA,
+      anon_sym_in,
+      anon_sym_RPAREN,
+  [11862] = 5,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(587), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(685), 1,
+      anon_sym_SQUOTE,
+    STATE(218), 1,
+      sym_comment,
+    ACTIONS(687), 2,
+      aux_sym__singlequote_string_content_token1,
+      sym_escape_sequence,
+  [11879] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(537), 1,
+      anon_sym_COMMA,
+    ACTIONS(689), 1,
+      anon_sym_EQ,
+    STATE(219), 1,
+      sym_comment,
+    STATE(221), 1,
+      aux_sym__variable_assignment_varlist_repeat1,
+  [11898] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(220), 1,
+      sym_comment,
+    ACTIONS(691), 3,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_LPAREN,
+  [11913] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(693), 1,
+      anon_sym_EQ,
+    ACTIONS(695), 1,
+      anon_sym_COMMA,
+    STATE(221), 2,
+      sym_comment,
+      aux_sym__variable_assignment_varlist_repeat1,
+  [11930] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    STATE(222), 1,
+      sym_comment,
+    ACTIONS(698), 3,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_LPAREN,
+  [11945] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(545), 1,
+      sym__identifier,
+    ACTIONS(547), 1,
+      sym_grit_metavariable,
+    STATE(223), 1,
+      sym_comment,
+    STATE(231), 1,
+      sym_identifier,
+  [11964] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(700), 1,
+      anon_sym_COMMA,
+    ACTIONS(702), 1,
+      anon_sym_RPAREN,
+    STATE(201), 1,
+      aux_sym__name_list_repeat1,
+    STATE(224), 1,
+      sym_comment,
+  [11983] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(664), 1,
+      anon_sym_LPAREN,
+    STATE(55), 1,
+      sym_parameters,
+    STATE(157), 1,
+      sym__function_body,
+    STATE(225), 1,
+      sym_comment,
+  [12002] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(704), 1,
+      anon_sym_DOT,
+    ACTIONS(706), 1,
+      anon_sym_COLON,
+    ACTIONS(708), 1,
+      anon_sym_LPAREN,
+    STATE(226), 1,
+      sym_comment,
+  [12021] = 6,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(545), 1,
+      sym__identifier,
+    ACTIONS(547), 1,
+      sym_grit_metavariable,
+    STATE(206), 1,
+      sym_identifier,
+    STATE(227), 1,
+      sym_comment,
+  [12040] = 5,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(712), 1,
+      anon_sym_else,
+    STATE(228), 1,
+      sym_comment,
+    ACTIONS(710), 2,
+      anon_sym_end,
+      anon_sym_elseif,
+  [12057] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(714), 1,
+      ts_builtin_sym_end,
+    STATE(229), 1,
+      sym_comment,
+  [12070] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(716), 1,
+      anon_sym_DQUOTE,
+    STATE(230), 1,
+      sym_comment,
+  [12083] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(718), 1,
+      anon_sym_COLON_COLON,
+    STATE(231), 1,
+      sym_comment,
+  [12096] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(720), 1,
+      anon_sym_end,
+    STATE(232), 1,
+      sym_comment,
+  [12109] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(722), 1,
+      sym__block_string_content,
+    STATE(233), 1,
+      sym_comment,
+  [12122] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(716), 1,
+      anon_sym_SQUOTE,
+    STATE(234), 1,
+      sym_comment,
+  [12135] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(724), 1,
+      anon_sym_RPAREN,
+    STATE(235), 1,
+      sym_comment,
+  [12148] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(726), 1,
+      sym__block_comment_content,
+    STATE(236), 1,
+      sym_comment,
+  [12161] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(728), 1,
+      anon_sym_end,
+    STATE(237), 1,
+      sym_comment,
+  [12174] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(730), 1,
+      anon_sym_do,
+    STATE(238), 1,
+      sym_comment,
+  [12187] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(732), 1,
+      anon_sym_end,
+    STATE(239), 1,
+      sym_comment,
+  [12200] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(734), 1,
+      anon_sym_RBRACE,
+    STATE(240), 1,
+      sym_comment,
+  [12213] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(736), 1,
+      anon_sym_EQ,
+    STATE(241), 1,
+      sym_comment,
+  [12226] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(738), 1,
+      anon_sym_end,
+    STATE(242), 1,
+      sym_comment,
+  [12239] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(740), 1,
+      anon_sym_end,
+    STATE(243), 1,
+      sym_comment,
+  [12252] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(742), 1,
+      ts_builtin_sym_end,
+    STATE(244), 1,
+      sym_comment,
+  [12265] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(744), 1,
+      anon_sym_RPAREN,
+    STATE(245), 1,
+      sym_comment,
+  [12278] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(746), 1,
+      anon_sym_LPAREN,
+    STATE(246), 1,
+      sym_comment,
+  [12291] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(748), 1,
+      anon_sym_EQ,
+    STATE(247), 1,
+      sym_comment,
+  [12304] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(750), 1,
+      anon_sym_LPAREN,
+    STATE(248), 1,
+      sym_comment,
+  [12317] = 4,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(587), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(752), 1,
+      aux_sym_comment_token1,
+    STATE(249), 1,
+      sym_comment,
+  [12330] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(754), 1,
+      sym__block_comment_end,
+    STATE(250), 1,
+      sym_comment,
+  [12343] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(756), 1,
+      ts_builtin_sym_end,
+    STATE(251), 1,
+      sym_comment,
+  [12356] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(758), 1,
+      anon_sym_end,
+    STATE(252), 1,
+      sym_comment,
+  [12369] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(760), 1,
+      anon_sym_end,
+    STATE(253), 1,
+      sym_comment,
+  [12382] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(762), 1,
+      anon_sym_RPAREN,
+    STATE(254), 1,
+      sym_comment,
+  [12395] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(764), 1,
+      ts_builtin_sym_end,
+    STATE(255), 1,
+      sym_comment,
+  [12408] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(766), 1,
+      anon_sym_RPAREN,
+    STATE(256), 1,
+      sym_comment,
+  [12421] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(768), 1,
+      ts_builtin_sym_end,
+    STATE(257), 1,
+      sym_comment,
+  [12434] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(770), 1,
+      anon_sym_end,
+    STATE(258), 1,
+      sym_comment,
+  [12447] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(772), 1,
+      anon_sym_do,
+    STATE(259), 1,
+      sym_comment,
+  [12460] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(774), 1,
+      anon_sym_end,
+    STATE(260), 1,
+      sym_comment,
+  [12473] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(776), 1,
+      sym__block_string_end,
+    STATE(261), 1,
+      sym_comment,
+  [12486] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(778), 1,
+      sym__block_string_end,
+    STATE(262), 1,
+      sym_comment,
+  [12499] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(780), 1,
+      anon_sym_DQUOTE,
+    STATE(263), 1,
+      sym_comment,
+  [12512] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(782), 1,
+      anon_sym_GT,
+    STATE(264), 1,
+      sym_comment,
+  [12525] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(780), 1,
+      anon_sym_SQUOTE,
+    STATE(265), 1,
+      sym_comment,
+  [12538] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(784), 1,
+      anon_sym_in,
+    STATE(266), 1,
+      sym_comment,
+  [12551] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(786), 1,
+      anon_sym_RBRACE,
+    STATE(267), 1,
+      sym_comment,
+  [12564] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(788), 1,
+      sym__block_string_content,
+    STATE(268), 1,
+      sym_comment,
+  [12577] = 4,
+    ACTIONS(3), 1,
+      anon_sym_DASH_DASH,
+    ACTIONS(5), 1,
+      sym__block_comment_start,
+    ACTIONS(790), 1,
+      anon_sym_until,
+    STATE(269), 1,
+      sym_comment,
+  [12590] = 1,
+    ACTIONS(792), 1,
+      ts_builtin_sym_end,
+  [12594] = 1,
+    ACTIONS(794), 1,
+      ts_builtin_sym_end,
+};
+
+static const uint32_t ts_small_parse_table_map[] = {
+  [SMALL_STATE(2)] = 0,
+  [SMALL_STATE(3)] = 68,
+  [SMALL_STATE(4)] = 136,
+  [SMALL_STATE(5)] = 204,
+  [SMALL_STATE(6)] = 272,
+  [SMALL_STATE(7)] = 339,
+  [SMALL_STATE(8)] = 410,
+  [SMALL_STATE(9)] = 477,
+  [SMALL_STATE(10)] = 544,
+  [SMALL_STATE(11)] = 611,
+  [SMALL_STATE(12)] = 678,
+  [SMALL_STATE(13)] = 745,
+  [SMALL_STATE(14)] = 812,
+  [SMALL_STATE(15)] = 879,
+  [SMALL_STATE(16)] = 946,
+  [SMALL_STATE(17)] = 1013,
+  [SMALL_STATE(18)] = 1080,
+  [SMALL_STATE(19)] = 1147,
+  [SMALL_STATE(20)] = 1214,
+  [SMALL_STATE(21)] = 1281,
+  [SMALL_STATE(22)] = 1412,
+  [SMALL_STATE(23)] = 1492,
+  [SMALL_STATE(24)] = 1554,
+  [SMALL_STATE(25)] = 1614,
+  [SMALL_STATE(26)] = 1674,
+  [SMALL_STATE(27)] = 1734,
+  [SMALL_STATE(28)] = 1794,
+  [SMALL_STATE(29)] = 1854,
+  [SMALL_STATE(30)] = 1914,
+  [SMALL_STATE(31)] = 1974,
+  [SMALL_STATE(32)] = 2034,
+  [SMALL_STATE(33)] = 2120,
+  [SMALL_STATE(34)] = 2198,
+  [SMALL_STATE(35)] = 2282,
+  [SMALL_STATE(36)] = 2358,
+  [SMALL_STATE(37)] = 2432,
+  [SMALL_STATE(38)] = 2504,
+  [SMALL_STATE(39)] = 2570,
+  [SMALL_STATE(40)] = 2632,
+  [SMALL_STATE(41)] = 2704,
+  [SMALL_STATE(42)] = 2766,
+  [SMALL_STATE(43)] = 2826,
+  [SMALL_STATE(44)] = 2886,
+  [SMALL_STATE(45)] = 2946,
+  [SMALL_STATE(46)] = 3006,
+  [SMALL_STATE(47)] = 3124,
+  [SMALL_STATE(48)] = 3240,
+  [SMALL_STATE(49)] = 3352,
+  [SMALL_STATE(50)] = 3441,
+  [SMALL_STATE(51)] = 3557,
+  [SMALL_STATE(52)] = 3673,
+  [SMALL_STATE(53)] = 3757,
+  [SMALL_STATE(54)] = 3873,
+  [SMALL_STATE(55)] = 3989,
+  [SMALL_STATE(56)] = 4105,
+  [SMALL_STATE(57)] = 4221,
+  [SMALL_STATE(58)] = 4334,
+  [SMALL_STATE(59)] = 4447,
+  [SMALL_STATE(60)] = 4530,
+  [SMALL_STATE(61)] = 4643,
+  [SMALL_STATE(62)] = 4726,
+  [SMALL_STATE(63)] = 4821,
+  [SMALL_STATE(64)] = 4918,
+  [SMALL_STATE(65)] = 5015,
+  [SMALL_STATE(66)] = 5109,
+  [SMALL_STATE(67)] = 5203,
+  [SMALL_STATE(68)] = 5295,
+  [SMALL_STATE(69)] = 5386,
+  [SMALL_STATE(70)] = 5472,
+  [SMALL_STATE(71)] = 5558,
+  [SMALL_STATE(72)] = 5644,
+  [SMALL_STATE(73)] = 5730,
+  [SMALL_STATE(74)] = 5813,
+  [SMALL_STATE(75)] = 5896,
+  [SMALL_STATE(76)] = 5979,
+  [SMALL_STATE(77)] = 6062,
+  [SMALL_STATE(78)] = 6145,
+  [SMALL_STATE(79)] = 6228,
+  [SMALL_STATE(80)] = 6311,
+  [SMALL_STATE(81)] = 6394,
+  [SMALL_STATE(82)] = 6443,
+  [SMALL_STATE(83)] = 6526,
+  [SMALL_STATE(84)] = 6609,
+  [SMALL_STATE(85)] = 6692,
+  [SMALL_STATE(86)] = 6775,
+  [SMALL_STATE(87)] = 6858,
+  [SMALL_STATE(88)] = 6941,
+  [SMALL_STATE(89)] = 7024,
+  [SMALL_STATE(90)] = 7107,
+  [SMALL_STATE(91)] = 7190,
+  [SMALL_STATE(92)] = 7273,
+  [SMALL_STATE(93)] = 7356,
+  [SMALL_STATE(94)] = 7439,
+  [SMALL_STATE(95)] = 7522,
+  [SMALL_STATE(96)] = 7605,
+  [SMALL_STATE(97)] = 7688,
+  [SMALL_STATE(98)] = 7771,
+  [SMALL_STATE(99)] = 7854,
+  [SMALL_STATE(100)] = 7937,
+  [SMALL_STATE(101)] = 8020,
+  [SMALL_STATE(102)] = 8096,
+  [SMALL_STATE(103)] = 8143,
+  [SMALL_STATE(104)] = 8185,
+  [SMALL_STATE(105)] = 8230,
+  [SMALL_STATE(106)] = 8277,
+  [SMALL_STATE(107)] = 8341,
+  [SMALL_STATE(108)] = 8405,
+  [SMALL_STATE(109)] = 8447,
+  [SMALL_STATE(110)] = 8511,
+  [SMALL_STATE(111)] = 8579,
+  [SMALL_STATE(112)] = 8620,
+  [SMALL_STATE(113)] = 8661,
+  [SMALL_STATE(114)] = 8700,
+  [SMALL_STATE(115)] = 8765,
+  [SMALL_STATE(116)] = 8806,
+  [SMALL_STATE(117)] = 8869,
+  [SMALL_STATE(118)] = 8905,
+  [SMALL_STATE(119)] = 8967,
+  [SMALL_STATE(120)] = 9029,
+  [SMALL_STATE(121)] = 9091,
+  [SMALL_STATE(122)] = 9153,
+  [SMALL_STATE(123)] = 9215,
+  [SMALL_STATE(124)] = 9277,
+  [SMALL_STATE(125)] = 9315,
+  [SMALL_STATE(126)] = 9377,
+  [SMALL_STATE(127)] = 9413,
+  [SMALL_STATE(128)] = 9453,
+  [SMALL_STATE(129)] = 9515,
+  [SMALL_STATE(130)] = 9552,
+  [SMALL_STATE(131)] = 9586,
+  [SMALL_STATE(132)] = 9620,
+  [SMALL_STATE(133)] = 9654,
+  [SMALL_STATE(134)] = 9688,
+  [SMALL_STATE(135)] = 9722,
+  [SMALL_STATE(136)] = 9756,
+  [SMALL_STATE(137)] = 9790,
+  [SMALL_STATE(138)] = 9824,
+  [SMALL_STATE(139)] = 9858,
+  [SMALL_STATE(140)] = 9892,
+  [SMALL_STATE(141)] = 9926,
+  [SMALL_STATE(142)] = 9960,
+  [SMALL_STATE(143)] = 9994,
+  [SMALL_STATE(144)] = 10028,
+  [SMALL_STATE(145)] = 10062,
+  [SMALL_STATE(146)] = 10096,
+  [SMALL_STATE(147)] = 10130,
+  [SMALL_STATE(148)] = 10164,
+  [SMALL_STATE(149)] = 10198,
+  [SMALL_STATE(150)] = 10232,
+  [SMALL_STATE(151)] = 10266,
+  [SMALL_STATE(152)] = 10300,
+  [SMALL_STATE(153)] = 10334,
+  [SMALL_STATE(154)] = 10368,
+  [SMALL_STATE(155)] = 10402,
+  [SMALL_STATE(156)] = 10436,
+  [SMALL_STATE(157)] = 10470,
+  [SMALL_STATE(158)] = 10504,
+  [SMALL_STATE(159)] = 10537,
+  [SMALL_STATE(160)] = 10567,
+  [SMALL_STATE(161)] = 10597,
+  [SMALL_STATE(162)] = 10644,
+  [SMALL_STATE(163)] = 10673,
+  [SMALL_STATE(164)] = 10710,
+  [SMALL_STATE(165)] = 10748,
+  [SMALL_STATE(166)] = 10772,
+  [SMALL_STATE(167)] = 10799,
+  [SMALL_STATE(168)] = 10819,
+  [SMALL_STATE(169)] = 10850,
+  [SMALL_STATE(170)] = 10876,
+  [SMALL_STATE(171)] = 10904,
+  [SMALL_STATE(172)] = 10932,
+  [SMALL_STATE(173)] = 10954,
+  [SMALL_STATE(174)] = 10982,
+  [SMALL_STATE(175)] = 11010,
+  [SMALL_STATE(176)] = 11038,
+  [SMALL_STATE(177)] = 11061,
+  [SMALL_STATE(178)] = 11080,
+  [SMALL_STATE(179)] = 11103,
+  [SMALL_STATE(180)] = 11128,
+  [SMALL_STATE(181)] = 11151,
+  [SMALL_STATE(182)] = 11172,
+  [SMALL_STATE(183)] = 11197,
+  [SMALL_STATE(184)] = 11214,
+  [SMALL_STATE(185)] = 11233,
+  [SMALL_STATE(186)] = 11258,
+  [SMALL_STATE(187)] = 11283,
+  [SMALL_STATE(188)] = 11303,
+  [SMALL_STATE(189)] = 11323,
+  [SMALL_STATE(190)] = 11341,
+  [SMALL_STATE(191)] = 11359,
+  [SMALL_STATE(192)] = 11381,
+  [SMALL_STATE(193)] = 11401,
+  [SMALL_STATE(194)] = 11423,
+  [SMALL_STATE(195)] = 11441,
+  [SMALL_STATE(196)] = 11459,
+  [SMALL_STATE(197)] = 11481,
+  [SMALL_STATE(198)] = 11501,
+  [SMALL_STATE(199)] = 11519,
+  [SMALL_STATE(200)] = 11538,
+  [SMALL_STATE(201)] = 11557,
+  [SMALL_STATE(202)] = 11576,
+  [SMALL_STATE(203)] = 11595,
+  [SMALL_STATE(204)] = 11614,
+  [SMALL_STATE(205)] = 11633,
+  [SMALL_STATE(206)] = 11652,
+  [SMALL_STATE(207)] = 11671,
+  [SMALL_STATE(208)] = 11688,
+  [SMALL_STATE(209)] = 11703,
+  [SMALL_STATE(210)] = 11722,
+  [SMALL_STATE(211)] = 11741,
+  [SMALL_STATE(212)] = 11760,
+  [SMALL_STATE(213)] = 11779,
+  [SMALL_STATE(214)] = 11796,
+  [SMALL_STATE(215)] = 11815,
+  [SMALL_STATE(216)] = 11830,
+  [SMALL_STATE(217)] = 11847,
+  [SMALL_STATE(218)] = 11862,
+  [SMALL_STATE(219)] = 11879,
+  [SMALL_STATE(220)] = 11898,
+  [SMALL_STATE(221)] = 11913,
+  [SMALL_STATE(222)] = 11930,
+  [SMALL_STATE(223)] = 11945,
+  [SMALL_STATE(224)] = 11964,
+  [SMALL_STATE(225)] = 11983,
+  [SMALL_STATE(226)] = 12002,
+  [SMALL_STATE(227)] = 12021,
+  [SMALL_STATE(228)] = 12040,
+  [SMALL_STATE(229)] = 12057,
+  [SMALL_STATE(230)] = 12070,
+  [SMALL_STATE(231)] = 12083,
+  [SMALL_STATE(232)] = 12096,
+  [SMALL_STATE(233)] = 12109,
+  [SMALL_STATE(234)] = 12122,
+  [SMALL_STATE(235)] = 12135,
+  [SMALL_STATE(236)] = 12148,
+  [SMALL_STATE(237)] = 12161,
+  [SMALL_STATE(238)] = 12174,
+  [SMALL_STATE(239)] = 12187,
+  [SMALL_STATE(240)] = 12200,
+  [SMALL_STATE(241)] = 12213,
+  [SMALL_STATE(242)] = 12226,
+  [SMALL_STATE(243)] = 12239,
+  [SMALL_STATE(244)] = 12252,
+  [SMALL_STATE(245)] = 12265,
+  [SMALL_STATE(246)] = 12278,
+  [SMALL_STATE(247)] = 12291,
+  [SMALL_STATE(248)] = 12304,
+  [SMALL_STATE(249)] = 12317,
+  [SMALL_STATE(250)] = 12330,
+  [SMALL_STATE(251)] = 12343,
+  [SMALL_STATE(252)] = 12356,
+  [SMALL_STATE(253)] = 12369,
+  [SMALL_STATE(254)] = 12382,
+  [SMALL_STATE(255)] = 12395,
+  [SMALL_STATE(256)] = 12408,
+  [SMALL_STATE(257)] = 12421,
+  [SMALL_STATE(258)] = 12434,
+  [SMALL_STATE(259)] = 12447,
+  [SMALL_STATE(260)] = 12460,
+  [SMALL_STATE(261)] = 12473,
+  [SMALL_STATE(262)] = 12486,
+  [SMALL_STATE(263)] = 12499,
+  [SMALL_STATE(264)] = 12512,
+  [SMALL_STATE(265)] = 12525,
+  [SMALL_STATE(266)] = 12538,
+  [SMALL_STATE(267)] = 12551,
+  [SMALL_STATE(268)] = 12564,
+  [SMALL_STATE(269)] = 12577,
+  [SMALL_STATE(270)] = 12590,
+  [SMALL_STATE(271)] = 12594,
+};
+
+static const TSParseActionEntry ts_parse_actions[] = {
+  [0] = {.entry = {.count = 0, .reusable = false}},
+  [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
+  [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT(249),
+  [5] = {.entry = {.count = 1, .reusable = true}}, SHIFT(236),
+  [7] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chunk, 0, 0, 0),
+  [9] = {.entry = {.count = 1, .reusable = false}}, SHIFT(3),
+  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(58),
+  [13] = {.entry = {.count = 1, .reusable = false}}, SHIFT(67),
+  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(137),
+  [17] = {.entry = {.count = 1, .reusable = true}}, SHIFT(223),
+  [19] = {.entry = {.count = 1, .reusable = false}}, SHIFT(142),
+  [21] = {.entry = {.count = 1, .reusable = false}}, SHIFT(203),
+  [23] = {.entry = {.count = 1, .reusable = false}}, SHIFT(50),
+  [25] = {.entry = {.count = 1, .reusable = false}}, SHIFT(78),
+  [27] = {.entry = {.count = 1, .reusable = false}}, SHIFT(53),
+  [29] = {.entry = {.count = 1, .reusable = false}}, SHIFT(73),
+  [31] = {.entry = {.count = 1, .reusable = false}}, SHIFT(169),
+  [33] = {.entry = {.count = 1, .reusable = false}}, SHIFT(168),
+  [35] = {.entry = {.count = 1, .reusable = false}}, SHIFT(173),
+  [37] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
+  [39] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
+  [41] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable, 1, 0, 0),
+  [43] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variable, 1, 0, 0),
+  [45] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_identifier, 1, 0, 0),
+  [47] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_identifier, 1, 0, 0),
+  [49] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_dot_index_expression, 3, 0, 39),
+  [51] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_dot_index_expression, 3, 0, 39),
+  [53] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_bracket_index_expression, 4, 0, 39),
+  [55] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_bracket_index_expression, 4, 0, 39),
+  [57] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_table_constructor, 3, 0, 45),
+  [59] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_table_constructor, 3, 0, 45),
+  [61] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1, 0, 0),
+  [63] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1, 0, 0),
+  [65] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__prefix_expression, 1, 0, 0),
+  [67] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__prefix_expression, 1, 0, 0),
+  [69] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arguments, 1, 0, 19),
+  [71] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arguments, 1, 0, 19),
+  [73] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_call, 2, 0, 20),
+  [75] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_function_call, 2, 0, 20),
+  [77] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesized_expression, 3, 0, 37),
+  [79] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parenthesized_expression, 3, 0, 37),
+  [81] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arguments, 2, 0, 0),
+  [83] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arguments, 2, 0, 0),
+  [85] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arguments, 3, 0, 59),
+  [87] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arguments, 3, 0, 59),
+  [89] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arguments, 4, 0, 72),
+  [91] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arguments, 4, 0, 72),
+  [93] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string, 1, 0, 13),
+  [95] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string, 1, 0, 13),
+  [97] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__quote_string, 2, 0, 27),
+  [99] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__quote_string, 2, 0, 27),
+  [101] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_table_constructor, 2, 0, 0),
+  [103] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_table_constructor, 2, 0, 0),
+  [105] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__block_string, 3, 0, 24),
+  [107] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__block_string, 3, 0, 24),
+  [109] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__quote_string, 3, 0, 24),
+  [111] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__quote_string, 3, 0, 24),
+  [113] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__quote_string, 3, 0, 44),
+  [115] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__quote_string, 3, 0, 44),
+  [117] = {.entry = {.count = 1, .reusable = false}}, SHIFT(62),
+  [119] = {.entry = {.count = 1, .reusable = false}}, SHIFT(135),
+  [121] = {.entry = {.count = 1, .reusable = false}}, SHIFT(86),
+  [123] = {.entry = {.count = 1, .reusable = false}}, SHIFT(54),
+  [125] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binary_expression, 3, 0, 48),
+  [127] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_binary_expression, 3, 0, 48),
+  [129] = {.entry = {.count = 1, .reusable = true}}, SHIFT(100),
+  [131] = {.entry = {.count = 1, .reusable = false}}, SHIFT(88),
+  [133] = {.entry = {.count = 1, .reusable = true}}, SHIFT(89),
+  [135] = {.entry = {.count = 1, .reusable = true}}, SHIFT(90),
+  [137] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
+  [139] = {.entry = {.count = 1, .reusable = false}}, SHIFT(91),
+  [141] = {.entry = {.count = 1, .reusable = true}}, SHIFT(93),
+  [143] = {.entry = {.count = 1, .reusable = false}}, SHIFT(93),
+  [145] = {.entry = {.count = 1, .reusable = true}}, SHIFT(95),
+  [147] = {.entry = {.count = 1, .reusable = true}}, SHIFT(96),
+  [149] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unary_expression, 2, 0, 29),
+  [151] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unary_expression, 2, 0, 29),
+  [153] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__function_body, 2, 0, 43),
+  [155] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__function_body, 2, 0, 43),
+  [157] = {.entry = {.count = 1, .reusable = false}}, SHIFT(83),
+  [159] = {.entry = {.count = 1, .reusable = false}}, SHIFT(85),
+  [161] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
+  [163] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__function_body, 3, 0, 61),
+  [165] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__function_body, 3, 0, 61),
+  [167] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_definition, 2, 0, 26),
+  [169] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_function_definition, 2, 0, 26),
+  [171] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_elseif_statement, 3, 0, 50),
+  [173] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__block, 1, 0, 7),
+  [175] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_chunk_repeat1, 2, 0, 23),
+  [177] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_chunk_repeat1, 2, 0, 23), SHIFT_REPEAT(3),
+  [180] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_chunk_repeat1, 2, 0, 23),
+  [182] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_chunk_repeat1, 2, 0, 23), SHIFT_REPEAT(137),
+  [185] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_chunk_repeat1, 2, 0, 23), SHIFT_REPEAT(223),
+  [188] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_chunk_repeat1, 2, 0, 23), SHIFT_REPEAT(142),
+  [191] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_chunk_repeat1, 2, 0, 23), SHIFT_REPEAT(203),
+  [194] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_chunk_repeat1, 2, 0, 23), SHIFT_REPEAT(50),
+  [197] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_chunk_repeat1, 2, 0, 23), SHIFT_REPEAT(78),
+  [200] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_chunk_repeat1, 2, 0, 23), SHIFT_REPEAT(53),
+  [203] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_chunk_repeat1, 2, 0, 23), SHIFT_REPEAT(73),
+  [206] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_chunk_repeat1, 2, 0, 23), SHIFT_REPEAT(169),
+  [209] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_chunk_repeat1, 2, 0, 23), SHIFT_REPEAT(168),
+  [212] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_chunk_repeat1, 2, 0, 23), SHIFT_REPEAT(173),
+  [215] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_chunk_repeat1, 2, 0, 23), SHIFT_REPEAT(77),
+  [218] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_chunk_repeat1, 2, 0, 23), SHIFT_REPEAT(102),
+  [221] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__variable_assignment_explist, 1, 0, 12),
+  [223] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__variable_assignment_explist, 1, 0, 12),
+  [225] = {.entry = {.count = 1, .reusable = true}}, SHIFT(82),
+  [227] = {.entry = {.count = 1, .reusable = false}}, SHIFT(84),
+  [229] = {.entry = {.count = 1, .reusable = false}}, SHIFT(154),
+  [231] = {.entry = {.count = 1, .reusable = false}}, SHIFT(138),
+  [233] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__variable_assignment_explist_repeat1, 2, 0, 47),
+  [235] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym__variable_assignment_explist_repeat1, 2, 0, 47),
+  [237] = {.entry = {.count = 1, .reusable = false}}, SHIFT(74),
+  [239] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_else_statement, 1, 0, 0),
+  [241] = {.entry = {.count = 1, .reusable = false}}, SHIFT(28),
+  [243] = {.entry = {.count = 1, .reusable = false}}, SHIFT(133),
+  [245] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chunk, 2, 0, 10),
+  [247] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chunk, 1, 0, 1),
+  [249] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_repeat_statement, 4, 0, 51),
+  [251] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_repeat_statement, 4, 0, 51),
+  [253] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chunk, 1, 0, 7),
+  [255] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_repeat_statement, 3, 0, 33),
+  [257] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_repeat_statement, 3, 0, 33),
+  [259] = {.entry = {.count = 1, .reusable = true}}, SHIFT(184),
+  [261] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_return_statement, 1, 0, 0),
+  [263] = {.entry = {.count = 1, .reusable = false}}, SHIFT(214),
+  [265] = {.entry = {.count = 1, .reusable = false}}, SHIFT(24),
+  [267] = {.entry = {.count = 1, .reusable = true}}, SHIFT(24),
+  [269] = {.entry = {.count = 1, .reusable = true}}, SHIFT(182),
+  [271] = {.entry = {.count = 1, .reusable = true}}, SHIFT(185),
+  [273] = {.entry = {.count = 1, .reusable = true}}, SHIFT(63),
+  [275] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
+  [277] = {.entry = {.count = 1, .reusable = false}}, SHIFT(79),
+  [279] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
+  [281] = {.entry = {.count = 1, .reusable = true}}, SHIFT(233),
+  [283] = {.entry = {.count = 1, .reusable = true}}, SHIFT(80),
+  [285] = {.entry = {.count = 1, .reusable = true}}, SHIFT(45),
+  [287] = {.entry = {.count = 1, .reusable = true}}, SHIFT(17),
+  [289] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__field_list, 2, 0, 28),
+  [291] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__field_list, 3, 0, 46),
+  [293] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_return_statement, 1, 0, 0),
+  [295] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
+  [297] = {.entry = {.count = 1, .reusable = false}}, SHIFT(76),
+  [299] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__expression_list, 1, 0, 12),
+  [301] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__expression_list, 1, 0, 12),
+  [303] = {.entry = {.count = 1, .reusable = true}}, SHIFT(84),
+  [305] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
+  [307] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_statement, 1, 0, 0),
+  [309] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_statement, 1, 0, 0),
+  [311] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__att_name_list, 1, 0, 6),
+  [313] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__att_name_list, 1, 0, 6),
+  [315] = {.entry = {.count = 1, .reusable = true}}, SHIFT(209),
+  [317] = {.entry = {.count = 1, .reusable = true}}, SHIFT(210),
+  [319] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field, 3, 0, 64),
+  [321] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field, 1, 0, 12),
+  [323] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__att_name_list_repeat1, 2, 0, 31),
+  [325] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym__att_name_list_repeat1, 2, 0, 31),
+  [327] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field, 5, 0, 80),
+  [329] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
+  [331] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
+  [333] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__att_name_list, 2, 0, 36),
+  [335] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__att_name_list, 2, 0, 36),
+  [337] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__att_name_list, 2, 0, 35),
+  [339] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__att_name_list, 2, 0, 35),
+  [341] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__att_name_list_repeat1, 2, 0, 58),
+  [343] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym__att_name_list_repeat1, 2, 0, 58),
+  [345] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__att_name_list_repeat1, 2, 0, 58), SHIFT_REPEAT(209),
+  [348] = {.entry = {.count = 1, .reusable = true}}, SHIFT(97),
+  [350] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_numeric_clause, 5, 0, 78),
+  [352] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__att_name_list, 3, 0, 57),
+  [354] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__att_name_list, 3, 0, 57),
+  [356] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_arguments_repeat1, 2, 0, 59),
+  [358] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__attrib, 3, 0, 0),
+  [360] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__attrib, 3, 0, 0),
+  [362] = {.entry = {.count = 1, .reusable = true}}, SHIFT(21),
+  [364] = {.entry = {.count = 1, .reusable = true}}, SHIFT(5),
+  [366] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
+  [368] = {.entry = {.count = 1, .reusable = true}}, SHIFT(56),
+  [370] = {.entry = {.count = 1, .reusable = true}}, SHIFT(10),
+  [372] = {.entry = {.count = 1, .reusable = true}}, SHIFT(46),
+  [374] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__variable_assignment_explist_repeat1, 2, 0, 49),
+  [376] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym__variable_assignment_explist_repeat1, 2, 0, 49),
+  [378] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__variable_assignment_explist_repeat1, 2, 0, 49), SHIFT_REPEAT(82),
+  [381] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_numeric_clause, 7, 0, 82),
+  [383] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__att_name_list_repeat1, 3, 0, 71),
+  [385] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym__att_name_list_repeat1, 3, 0, 71),
+  [387] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__variable_assignment_explist, 2, 0, 30),
+  [389] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__variable_assignment_explist, 2, 0, 30),
+  [391] = {.entry = {.count = 1, .reusable = true}}, SHIFT(241),
+  [393] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable_declaration, 2, 0, 18),
+  [395] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variable_declaration, 2, 0, 18),
+  [397] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
+  [399] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_statement, 5, 0, 70),
+  [401] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_for_statement, 5, 0, 70),
+  [403] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable_declaration, 2, 0, 17),
+  [405] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variable_declaration, 2, 0, 17),
+  [407] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_assignment_statement, 3, 0, 38),
+  [409] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_assignment_statement, 3, 0, 38),
+  [411] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_while_statement, 4, 0, 50),
+  [413] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_while_statement, 4, 0, 50),
+  [415] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 1, 0, 5),
+  [417] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_declaration, 1, 0, 5),
+  [419] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 4, 0, 50),
+  [421] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_statement, 4, 0, 50),
+  [423] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_chunk_repeat1, 1, 0, 2),
+  [425] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_chunk_repeat1, 1, 0, 2),
+  [427] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_empty_statement, 1, 0, 0),
+  [429] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_empty_statement, 1, 0, 0),
+  [431] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_statement, 4, 0, 53),
+  [433] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_for_statement, 4, 0, 53),
+  [435] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 6, 0, 75),
+  [437] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_statement, 6, 0, 75),
+  [439] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 6, 0, 76),
+  [441] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_statement, 6, 0, 76),
+  [443] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 6, 0, 77),
+  [445] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_statement, 6, 0, 77),
+  [447] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__local_function_declaration, 4, 0, 55),
+  [449] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__local_function_declaration, 4, 0, 55),
+  [451] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 7, 0, 81),
+  [453] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_statement, 7, 0, 81),
+  [455] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_while_statement, 5, 0, 65),
+  [457] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_while_statement, 5, 0, 65),
+  [459] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__local_variable_assignment, 3, 0, 56),
+  [461] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__local_variable_assignment, 3, 0, 56),
+  [463] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_label_statement, 3, 0, 31),
+  [465] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_label_statement, 3, 0, 31),
+  [467] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 5, 0, 66),
+  [469] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_statement, 5, 0, 66),
+  [471] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_do_statement, 3, 0, 32),
+  [473] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_do_statement, 3, 0, 32),
+  [475] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_statement, 1, 0, 3),
+  [477] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_statement, 1, 0, 3),
+  [479] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 1, 0, 0),
+  [481] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_declaration, 1, 0, 0),
+  [483] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 5, 0, 67),
+  [485] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_statement, 5, 0, 67),
+  [487] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_goto_statement, 2, 0, 14),
+  [489] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_goto_statement, 2, 0, 14),
+  [491] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_do_statement, 2, 0, 0),
+  [493] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_do_statement, 2, 0, 0),
+  [495] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 1, 0, 4),
+  [497] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_declaration, 1, 0, 4),
+  [499] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 5, 0, 68),
+  [501] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_statement, 5, 0, 68),
+  [503] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_declaration, 3, 0, 34),
+  [505] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_function_declaration, 3, 0, 34),
+  [507] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__field_sep, 1, 0, 0),
+  [509] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__field_sep, 1, 0, 0),
+  [511] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parameters, 2, 0, 0),
+  [513] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameters, 2, 0, 0),
+  [515] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parameters, 3, 0, 60),
+  [517] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameters, 3, 0, 60),
+  [519] = {.entry = {.count = 1, .reusable = true}}, SHIFT(211),
+  [521] = {.entry = {.count = 1, .reusable = true}}, SHIFT(212),
+  [523] = {.entry = {.count = 1, .reusable = true}}, SHIFT(186),
+  [525] = {.entry = {.count = 1, .reusable = true}}, SHIFT(179),
+  [527] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
+  [529] = {.entry = {.count = 1, .reusable = true}}, SHIFT(75),
+  [531] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
+  [533] = {.entry = {.count = 1, .reusable = true}}, SHIFT(268),
+  [535] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__variable_assignment_varlist, 1, 0, 6),
+  [537] = {.entry = {.count = 1, .reusable = true}}, SHIFT(163),
+  [539] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__variable_assignment_varlist_repeat1, 2, 0, 31),
+  [541] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__expression_list, 2, 0, 30),
+  [543] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__expression_list, 2, 0, 30),
+  [545] = {.entry = {.count = 1, .reusable = false}}, SHIFT(103),
+  [547] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
+  [549] = {.entry = {.count = 1, .reusable = true}}, SHIFT(148),
+  [551] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
+  [553] = {.entry = {.count = 1, .reusable = true}}, SHIFT(156),
+  [555] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_return_statement, 2, 0, 11),
+  [557] = {.entry = {.count = 1, .reusable = true}}, SHIFT(177),
+  [559] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_return_statement, 2, 0, 11),
+  [561] = {.entry = {.count = 1, .reusable = false}}, SHIFT(227),
+  [563] = {.entry = {.count = 1, .reusable = true}}, SHIFT(254),
+  [565] = {.entry = {.count = 1, .reusable = true}}, SHIFT(159),
+  [567] = {.entry = {.count = 1, .reusable = true}}, SHIFT(140),
+  [569] = {.entry = {.count = 1, .reusable = true}}, SHIFT(158),
+  [571] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__field_list, 1, 0, 28),
+  [573] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_return_statement, 3, 0, 11),
+  [575] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_return_statement, 3, 0, 11),
+  [577] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__field_list, 2, 0, 46),
+  [579] = {.entry = {.count = 1, .reusable = false}}, SHIFT(16),
+  [581] = {.entry = {.count = 1, .reusable = false}}, SHIFT(218),
+  [583] = {.entry = {.count = 1, .reusable = true}}, SHIFT(218),
+  [585] = {.entry = {.count = 1, .reusable = false}}, SHIFT(265),
+  [587] = {.entry = {.count = 1, .reusable = false}}, SHIFT(249),
+  [589] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_if_statement_repeat1, 2, 0, 69),
+  [591] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_if_statement_repeat1, 2, 0, 69), SHIFT_REPEAT(86),
+  [594] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_if_statement_repeat1, 2, 0, 69),
+  [596] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__field_list_repeat1, 2, 0, 63), SHIFT_REPEAT(158),
+  [599] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__field_list_repeat1, 2, 0, 63),
+  [601] = {.entry = {.count = 1, .reusable = false}}, SHIFT(44),
+  [603] = {.entry = {.count = 1, .reusable = false}}, SHIFT(213),
+  [605] = {.entry = {.count = 1, .reusable = true}}, SHIFT(213),
+  [607] = {.entry = {.count = 1, .reusable = false}}, SHIFT(230),
+  [609] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_method_index_expression, 3, 0, 40),
+  [611] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_return_statement, 2, 0, 0),
+  [613] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_return_statement, 2, 0, 0),
+  [615] = {.entry = {.count = 1, .reusable = false}}, SHIFT(234),
+  [617] = {.entry = {.count = 1, .reusable = false}}, SHIFT(263),
+  [619] = {.entry = {.count = 1, .reusable = false}}, SHIFT(30),
+  [621] = {.entry = {.count = 1, .reusable = false}}, SHIFT(20),
+  [623] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__block, 2, 0, 22),
+  [625] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__block, 2, 0, 22),
+  [627] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__block, 1, 0, 2),
+  [629] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__block, 1, 0, 2),
+  [631] = {.entry = {.count = 1, .reusable = true}}, SHIFT(235),
+  [633] = {.entry = {.count = 1, .reusable = true}}, SHIFT(245),
+  [635] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym__doublequote_string_content, 2, 0, 0),
+  [637] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__doublequote_string_content, 2, 0, 0), SHIFT_REPEAT(213),
+  [640] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__name_list_repeat1, 2, 0, 41), SHIFT_REPEAT(200),
+  [643] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__name_list_repeat1, 2, 0, 41),
+  [645] = {.entry = {.count = 1, .reusable = true}}, SHIFT(99),
+  [647] = {.entry = {.count = 1, .reusable = true}}, SHIFT(200),
+  [649] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__name_list, 1, 0, 6),
+  [651] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym__singlequote_string_content, 2, 0, 0),
+  [653] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__singlequote_string_content, 2, 0, 0), SHIFT_REPEAT(218),
+  [656] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
+  [658] = {.entry = {.count = 1, .reusable = true}}, SHIFT(193),
+  [660] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__parameter_list, 2, 0, 21),
+  [662] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__name_list, 2, 0, 21),
+  [664] = {.entry = {.count = 1, .reusable = true}}, SHIFT(174),
+  [666] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_arguments_repeat1, 2, 0, 73), SHIFT_REPEAT(87),
+  [669] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_arguments_repeat1, 2, 0, 73),
+  [671] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__function_name_prefix_expression, 1, 0, 15),
+  [673] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym__doublequote_string_content, 1, 0, 0),
+  [675] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__doublequote_string_content, 1, 0, 0),
+  [677] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__field_list_repeat1, 2, 0, 62),
+  [679] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_elseif_statement, 4, 0, 66),
+  [681] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_elseif_statement, 4, 0, 66),
+  [683] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__name_list_repeat1, 2, 0, 31),
+  [685] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym__singlequote_string_content, 1, 0, 0),
+  [687] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__singlequote_string_content, 1, 0, 0),
+  [689] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__variable_assignment_varlist, 2, 0, 21),
+  [691] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__function_name_dot_index_expression, 3, 0, 39),
+  [693] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__variable_assignment_varlist_repeat1, 2, 0, 41),
+  [695] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__variable_assignment_varlist_repeat1, 2, 0, 41), SHIFT_REPEAT(163),
+  [698] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__function_name_prefix_expression, 1, 0, 0),
+  [700] = {.entry = {.count = 1, .reusable = true}}, SHIFT(191),
+  [702] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__parameter_list, 1, 0, 6),
+  [704] = {.entry = {.count = 1, .reusable = true}}, SHIFT(204),
+  [706] = {.entry = {.count = 1, .reusable = true}}, SHIFT(205),
+  [708] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__function_name, 1, 0, 0),
+  [710] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_if_statement_repeat1, 1, 0, 52),
+  [712] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_if_statement_repeat1, 1, 0, 52),
+  [714] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chunk, 3, 0, 25),
+  [716] = {.entry = {.count = 1, .reusable = true}}, SHIFT(29),
+  [718] = {.entry = {.count = 1, .reusable = true}}, SHIFT(147),
+  [720] = {.entry = {.count = 1, .reusable = true}}, SHIFT(152),
+  [722] = {.entry = {.count = 1, .reusable = true}}, SHIFT(261),
+  [724] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__parameter_list, 3, 0, 74),
+  [726] = {.entry = {.count = 1, .reusable = true}}, SHIFT(250),
+  [728] = {.entry = {.count = 1, .reusable = true}}, SHIFT(130),
+  [730] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_generic_clause, 3, 0, 54),
+  [732] = {.entry = {.count = 1, .reusable = true}}, SHIFT(145),
+  [734] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
+  [736] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
+  [738] = {.entry = {.count = 1, .reusable = true}}, SHIFT(144),
+  [740] = {.entry = {.count = 1, .reusable = true}}, SHIFT(149),
+  [742] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chunk, 1, 0, 2),
+  [744] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__parameter_list, 4, 0, 79),
+  [746] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__function_name, 1, 0, 16),
+  [748] = {.entry = {.count = 1, .reusable = true}}, SHIFT(69),
+  [750] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__function_name_method_index_expression, 3, 0, 40),
+  [752] = {.entry = {.count = 1, .reusable = false}}, SHIFT(271),
+  [754] = {.entry = {.count = 1, .reusable = true}}, SHIFT(270),
+  [756] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chunk, 2, 0, 22),
+  [758] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_else_statement, 2, 0, 32),
+  [760] = {.entry = {.count = 1, .reusable = true}}, SHIFT(139),
+  [762] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__parameter_list, 1, 0, 42),
+  [764] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [766] = {.entry = {.count = 1, .reusable = true}}, SHIFT(160),
+  [768] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chunk, 2, 0, 9),
+  [770] = {.entry = {.count = 1, .reusable = true}}, SHIFT(42),
+  [772] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
+  [774] = {.entry = {.count = 1, .reusable = true}}, SHIFT(141),
+  [776] = {.entry = {.count = 1, .reusable = true}}, SHIFT(27),
+  [778] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
+  [780] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
+  [782] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
+  [784] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
+  [786] = {.entry = {.count = 1, .reusable = true}}, SHIFT(6),
+  [788] = {.entry = {.count = 1, .reusable = true}}, SHIFT(262),
+  [790] = {.entry = {.count = 1, .reusable = true}}, SHIFT(98),
+  [792] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comment, 3, 0, 24),
+  [794] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comment, 2, 0, 8),
+};
+
+enum ts_external_scanner_symbol_identifiers {
+  ts_external_token__block_comment_start = 0,
+  ts_external_token__block_comment_content = 1,
+  ts_external_token__block_comment_end = 2,
+  ts_external_token__block_string_start = 3,
+  ts_external_token__block_string_content = 4,
+  ts_external_token__block_string_end = 5,
+};
+
+static const TSSymbol ts_external_scanner_symbol_map[EXTERNAL_TOKEN_COUNT] = {
+  [ts_external_token__block_comment_start] = sym__block_comment_start,
+  [ts_external_token__block_comment_content] = sym__block_comment_content,
+  [ts_external_token__block_comment_end] = sym__block_comment_end,
+  [ts_external_token__block_string_start] = sym__block_string_start,
+  [ts_external_token__block_string_content] = sym__block_string_content,
+  [ts_external_token__block_string_end] = sym__block_string_end,
+};
+
+static const bool ts_external_scanner_states[8][EXTERNAL_TOKEN_COUNT] = {
+  [1] = {
+    [ts_external_token__block_comment_start] = true,
+    [ts_external_token__block_comment_content] = true,
+    [ts_external_token__block_comment_end] = true,
+    [ts_external_token__block_string_start] = true,
+    [ts_external_token__block_string_content] = true,
+    [ts_external_token__block_string_end] = true,
+  },
+  [2] = {
+    [ts_external_token__block_comment_start] = true,
+  },
+  [3] = {
+    [ts_external_token__block_comment_start] = true,
+    [ts_external_token__block_string_start] = true,
+  },
+  [4] = {
+    [ts_external_token__block_comment_start] = true,
+    [ts_external_token__block_string_content] = true,
+  },
+  [5] = {
+    [ts_external_token__block_comment_start] = true,
+    [ts_external_token__block_comment_content] = true,
+  },
+  [6] = {
+    [ts_external_token__block_comment_start] = true,
+    [ts_external_token__block_comment_end] = true,
+  },
+  [7] = {
+    [ts_external_token__block_comment_start] = true,
+    [ts_external_token__block_string_end] = true,
+  },
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+void *tree_sitter_lua_external_scanner_create(void);
+void tree_sitter_lua_external_scanner_destroy(void *);
+bool tree_sitter_lua_external_scanner_scan(void *, TSLexer *, const bool *);
+unsigned tree_sitter_lua_external_scanner_serialize(void *, char *);
+void tree_sitter_lua_external_scanner_deserialize(void *, const char *, unsigned);
+
+#ifdef TREE_SITTER_HIDE_SYMBOLS
+#define TS_PUBLIC
+#elif defined(_WIN32)
+#define TS_PUBLIC __declspec(dllexport)
+#else
+#define TS_PUBLIC __attribute__((visibility("default")))
+#endif
+
+TS_PUBLIC const TSLanguage *tree_sitter_lua(void) {
+  static const TSLanguage language = {
+    .version = LANGUAGE_VERSION,
+    .symbol_count = SYMBOL_COUNT,
+    .alias_count = ALIAS_COUNT,
+    .token_count = TOKEN_COUNT,
+    .external_token_count = EXTERNAL_TOKEN_COUNT,
+    .state_count = STATE_COUNT,
+    .large_state_count = LARGE_STATE_COUNT,
+    .production_id_count = PRODUCTION_ID_COUNT,
+    .field_count = FIELD_COUNT,
+    .max_alias_sequence_length = MAX_ALIAS_SEQUENCE_LENGTH,
+    .parse_table = &ts_parse_table[0][0],
+    .small_parse_table = ts_small_parse_table,
+    .small_parse_table_map = ts_small_parse_table_map,
+    .parse_actions = ts_parse_actions,
+    .symbol_names = ts_symbol_names,
+    .field_names = ts_field_names,
+    .field_map_slices = ts_field_map_slices,
+    .field_map_entries = ts_field_map_entries,
+    .symbol_metadata = ts_symbol_metadata,
+    .public_symbol_map = ts_symbol_map,
+    .alias_map = ts_non_terminal_alias_map,
+    .alias_sequences = &ts_alias_sequences[0][0],
+    .lex_modes = ts_lex_modes,
+    .lex_fn = ts_lex,
+    .keyword_lex_fn = ts_lex_keywords,
+    .keyword_capture_token = sym__identifier,
+    .external_scanner = {
+      &ts_external_scanner_states[0][0],
+      ts_external_scanner_symbol_map,
+      tree_sitter_lua_external_scanner_create,
+      tree_sitter_lua_external_scanner_destroy,
+      tree_sitter_lua_external_scanner_scan,
+      tree_sitter_lua_external_scanner_serialize,
+      tree_sitter_lua_external_scanner_deserialize,
+    },
+    .primary_state_ids = ts_primary_state_ids,
+  };
+  return &language;
+}
+#ifdef __cplusplus
+}
+#endif
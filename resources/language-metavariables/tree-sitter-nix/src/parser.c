@@ -0,0 +1,24357 @@
+#include "tree_sitter/parser.h"
+
+#if defined(__GNUC__) || defined(__clang__)
+#pragma GCC diagnostic ignored "-Wmissing-field-initializers"
+#endif
+
+#define LANGUAGE_VERSION 14
+#define STATE_COUNT 554
+#define LARGE_STATE_COUNT 2
+#define SYMBOL_COUNT 110
+#define ALIAS_COUNT 0
+#define TOKEN_COUNT 63
+#define EXTERNAL_TOKEN_COUNT 6
+#define FIELD_COUNT 24
+#define MAX_ALIAS_SEQUENCE_LENGTH 6
+#define PRODUCTION_ID_COUNT 47
+
+enum ts_symbol_identifiers {
+  sym_keyword = 1,
+  aux_sym_identifier_token1 = 2,
+  sym_integer_expression = 3,
+  sym_float_expression = 4,
+  sym__hpath_start = 5,
+  sym_spath_expression = 6,
+  sym_uri_expression = 7,
+  anon_sym_COLON = 8,
+  anon_sym_AT = 9,
+  anon_sym_LBRACE = 10,
+  anon_sym_RBRACE = 11,
+  anon_sym_COMMA = 12,
+  anon_sym_QMARK = 13,
+  sym_ellipses = 14,
+  anon_sym_assert = 15,
+  anon_sym_SEMI = 16,
+  anon_sym_with = 17,
+  anon_sym_let = 18,
+  anon_sym_in = 19,
+  anon_sym_if = 20,
+  anon_sym_then = 21,
+  anon_sym_else = 22,
+  anon_sym_BANG = 23,
+  anon_sym_DASH = 24,
+  anon_sym_EQ_EQ = 25,
+  anon_sym_BANG_EQ = 26,
+  anon_sym_LT = 27,
+  anon_sym_LT_EQ = 28,
+  anon_sym_GT = 29,
+  anon_sym_GT_EQ = 30,
+  anon_sym_AMP_AMP = 31,
+  anon_sym_PIPE_PIPE = 32,
+  anon_sym_PLUS = 33,
+  anon_sym_STAR = 34,
+  anon_sym_SLASH = 35,
+  anon_sym_DASH_GT = 36,
+  anon_sym_SLASH_SLASH = 37,
+  anon_sym_PLUS_PLUS = 38,
+  anon_sym_DOT = 39,
+  anon_sym_or = 40,
+  anon_sym_LPAREN = 41,
+  anon_sym_RPAREN = 42,
+  anon_sym_rec = 43,
+  anon_sym_DQUOTE = 44,
+  anon_sym_DOLLAR = 45,
+  sym_escape_sequence = 46,
+  anon_sym_SQUOTE_SQUOTE = 47,
+  sym__indented_escape_sequence = 48,
+  anon_sym_EQ = 49,
+  anon_sym_inherit = 50,
+  anon_sym_DOLLAR_LBRACE = 51,
+  anon_sym_DOLLAR_LBRACE2 = 52,
+  anon_sym_LBRACK = 53,
+  anon_sym_RBRACK = 54,
+  sym_comment = 55,
+  sym_grit_metavariable = 56,
+  sym_string_fragment = 57,
+  sym__indented_string_fragment = 58,
+  sym__path_start = 59,
+  sym_path_fragment = 60,
+  sym_dollar_escape = 61,
+  sym__indented_dollar_escape = 62,
+  sym_source_code = 63,
+  sym__expression = 64,
+  sym_identifier = 65,
+  sym_variable_expression = 66,
+  sym_path_expression = 67,
+  sym_hpath_expression = 68,
+  sym__expr_function_expression = 69,
+  sym_function_expression = 70,
+  sym_formals = 71,
+  sym_formal = 72,
+  sym_assert_expression = 73,
+  sym_with_expression = 74,
+  sym_let_expression = 75,
+  sym__expr_if = 76,
+  sym_if_expression = 77,
+  sym__expr_op = 78,
+  sym_has_attr_expression = 79,
+  sym_unary_expression = 80,
+  sym_binary_expression = 81,
+  sym__expr_apply_expression = 82,
+  sym_apply_expression = 83,
+  sym__expr_select_expression = 84,
+  sym_select_expression = 85,
+  sym__expr_simple = 86,
+  sym_parenthesized_expression = 87,
+  sym_attrset_expression = 88,
+  sym_let_attrset_expression = 89,
+  sym_rec_attrset_expression = 90,
+  sym_string_expression = 91,
+  sym_indented_string_expression = 92,
+  sym_binding_set = 93,
+  sym_binding = 94,
+  sym_inherit = 95,
+  sym_inherit_from = 96,
+  sym_attrpath = 97,
+  sym_inherited_attrs = 98,
+  sym__immediate_interpolation = 99,
+  sym_interpolation = 100,
+  sym_list_expression = 101,
+  aux_sym_path_expression_repeat1 = 102,
+  aux_sym_formals_repeat1 = 103,
+  aux_sym_string_expression_repeat1 = 104,
+  aux_sym_indented_string_expression_repeat1 = 105,
+  aux_sym_binding_set_repeat1 = 106,
+  aux_sym_attrpath_repeat1 = 107,
+  aux_sym_inherited_attrs_repeat1 = 108,
+  aux_sym_list_expression_repeat1 = 109,
+};
+
+static const char * const ts_symbol_names[] = {
+  [ts_builtin_sym_end] = "end",
+  [sym_keyword] = "keyword",
+  [aux_sym_identifier_token1] = "identifier_token1",
+  [sym_integer_expression] = "integer_expression",
+  [sym_float_expression] = "float_expression",
+  [sym__hpath_start] = "path_fragment",
+  [sym_spath_expression] = "spath_expression",
+  [sym_uri_expression] = "uri_expression",
+  [anon_sym_COLON] = ":",
+  [anon_sym_AT] = "@",
+  [anon_sym_LBRACE] = "{",
+  [anon_sym_RBRACE] = "}",
+  [anon_sym_COMMA] = ",",
+  [anon_sym_QMARK] = "\?",
+  [sym_ellipses] = "ellipses",
+  [anon_sym_assert] = "assert",
+  [anon_sym_SEMI] = ";",
+  [anon_sym_with] = "with",
+  [anon_sym_let] = "let",
+  [anon_sym_in] = "in",
+  [anon_sym_if] = "if",
+  [anon_sym_then] = "then",
+  [anon_sym_else] = "else",
+  [anon_sym_BANG] = "operator",
+  [anon_sym_DASH] = "operator",
+  [anon_sym_EQ_EQ] = "operator",
+  [anon_sym_BANG_EQ] = "operator",
+  [anon_sym_LT] = "operator",
+  [anon_sym_LT_EQ] = "operator",
+  [anon_sym_GT] = "operator",
+  [anon_sym_GT_EQ] = "operator",
+  [anon_sym_AMP_AMP] = "operator",
+  [anon_sym_PIPE_PIPE] = "operator",
+  [anon_sym_PLUS] = "operator",
+  [anon_sym_STAR] = "operator",
+  [anon_sym_SLASH] = "operator",
+  [anon_sym_DASH_GT] = "operator",
+  [anon_sym_SLASH_SLASH] = "operator",
+  [anon_sym_PLUS_PLUS] = "operator",
+  [anon_sym_DOT] = ".",
+  [anon_sym_or] = "or",
+  [anon_sym_LPAREN] = "(",
+  [anon_sym_RPAREN] = ")",
+  [anon_sym_rec] = "rec",
+  [anon_sym_DQUOTE] = "\"",
+  [anon_sym_DOLLAR] = "string_fragment",
+  [sym_escape_sequence] = "escape_sequence",
+  [anon_sym_SQUOTE_SQUOTE] = "''",
+  [sym__indented_escape_sequence] = "escape_sequence",
+  [anon_sym_EQ] = "=",
+  [anon_sym_inherit] = "inherit",
+  [anon_sym_DOLLAR_LBRACE] = "${",
+  [anon_sym_DOLLAR_LBRACE2] = "${",
+  [anon_sym_LBRACK] = "[",
+  [anon_sym_RBRACK] = "]",
+  [sym_comment] = "comment",
+  [sym_grit_metavariable] = "grit_metavariable",
+  [sym_string_fragment] = "string_fragment",
+  [sym__indented_string_fragment] = "string_fragment",
+  [sym__path_start] = "path_fragment",
+  [sym_path_fragment] = "path_fragment",
+  [sym_dollar_escape] = "dollar_escape",
+  [sym__indented_dollar_escape] = "dollar_escape",
+  [sym_source_code] = "source_code",
+  [sym__expression] = "_expression",
+  [sym_identifier] = "identifier",
+  [sym_variable_expression] = "variable_expression",
+  [sym_path_expression] = "path_expression",
+  [sym_hpath_expression] = "hpath_expression",
+  [sym__expr_function_expression] = "_expr_function_expression",
+  [sym_function_expression] = "function_expression",
+  [sym_formals] = "formals",
+  [sym_formal] = "formal",
+  [sym_assert_expression] = "assert_expression",
+  [sym_with_expression] = "with_expression",
+  [sym_let_expression] = "let_expression",
+  [sym__expr_if] = "_expr_if",
+  [sym_if_expression] = "if_expression",
+  [sym__expr_op] = "_expr_op",
+  [sym_has_attr_expression] = "has_attr_expression",
+  [sym_unary_expression] = "unary_expression",
+  [sym_binary_expression] = "binary_expression",
+  [sym__expr_apply_expression] = "_expr_apply_expression",
+  [sym_apply_expression] = "apply_expression",
+  [sym__expr_select_expression] = "_expr_select_expression",
+  [sym_select_expression] = "select_expression",
+  [sym__expr_simple] = "_expr_simple",
+  [sym_parenthesized_expression] = "parenthesized_expression",
+  [sym_attrset_expression] = "attrset_expression",
+  [sym_let_attrset_expression] = "let_attrset_expression",
+  [sym_rec_attrset_expression] = "rec_attrset_expression",
+  [sym_string_expression] = "string_expression",
+  [sym_indented_string_expression] = "indented_string_expression",
+  [sym_binding_set] = "binding_set",
+  [sym_binding] = "binding",
+  [sym_inherit] = "inherit",
+  [sym_inherit_from] = "inherit_from",
+  [sym_attrpath] = "attrpath",
+  [sym_inherited_attrs] = "inherited_attrs",
+  [sym__immediate_interpolation] = "interpolation",
+  [sym_interpolation] = "interpolation",
+  [sym_list_expression] = "list_expression",
+  [aux_sym_path_expression_repeat1] = "path_expression_repeat1",
+  [aux_sym_formals_repeat1] = "formals_repeat1",
+  [aux_sym_string_expression_repeat1] = "string_expression_repeat1",
+  [aux_sym_indented_string_expression_repeat1] = "indented_string_expression_repeat1",
+  [aux_sym_binding_set_repeat1] = "binding_set_repeat1",
+  [aux_sym_attrpath_repeat1] = "attrpath_repeat1",
+  [aux_sym_inherited_attrs_repeat1] = "inherited_attrs_repeat1",
+  [aux_sym_list_expression_repeat1] = "list_expression_repeat1",
+};
+
+static const TSSymbol ts_symbol_map[] = {
+  [ts_builtin_sym_end] = ts_builtin_sym_end,
+  [sym_keyword] = sym_keyword,
+  [aux_sym_identifier_token1] = aux_sym_identifier_token1,
+  [sym_integer_expression] = sym_integer_expression,
+  [sym_float_expression] = sym_float_expression,
+  [sym__hpath_start] = sym_path_fragment,
+  [sym_spath_expression] = sym_spath_expression,
+  [sym_uri_expression] = sym_uri_expression,
+  [anon_sym_COLON] = anon_sym_COLON,
+  [anon_sym_AT] = anon_sym_AT,
+  [anon_sym_LBRACE] = anon_sym_LBRACE,
+  [anon_sym_RBRACE] = anon_sym_RBRACE,
+  [anon_sym_COMMA] = anon_sym_COMMA,
+  [anon_sym_QMARK] = anon_sym_QMARK,
+  [sym_ellipses] = sym_ellipses,
+  [anon_sym_assert] = anon_sym_assert,
+  [anon_sym_SEMI] = anon_sym_SEMI,
+  [anon_sym_with] = anon_sym_with,
+  [anon_sym_let] = anon_sym_let,
+  [anon_sym_in] = anon_sym_in,
+  [anon_sym_if] = anon_sym_if,
+  [anon_sym_then] = anon_sym_then,
+  [anon_sym_else] = anon_sym_else,
+  [anon_sym_BANG] = anon_sym_BANG,
+  [anon_sym_DASH] = anon_sym_BANG,
+  [anon_sym_EQ_EQ] = anon_sym_BANG,
+  [anon_sym_BANG_EQ] = anon_sym_BANG,
+  [anon_sym_LT] = anon_sym_BANG,
+  [anon_sym_LT_EQ] = anon_sym_BANG,
+  [anon_sym_GT] = anon_sym_BANG,
+  [anon_sym_GT_EQ] = anon_sym_BANG,
+  [anon_sym_AMP_AMP] = anon_sym_BANG,
+  [anon_sym_PIPE_PIPE] = anon_sym_BANG,
+  [anon_sym_PLUS] = anon_sym_BANG,
+  [anon_sym_STAR] = anon_sym_BANG,
+  [anon_sym_SLASH] = anon_sym_BANG,
+  [anon_sym_DASH_GT] = anon_sym_BANG,
+  [anon_sym_SLASH_SLASH] = anon_sym_BANG,
+  [anon_sym_PLUS_PLUS] = anon_sym_BANG,
+  [anon_sym_DOT] = anon_sym_DOT,
+  [anon_sym_or] = anon_sym_or,
+  [anon_sym_LPAREN] = anon_sym_LPAREN,
+  [anon_sym_RPAREN] = anon_sym_RPAREN,
+  [anon_sym_rec] = anon_sym_rec,
+  [anon_sym_DQUOTE] = anon_sym_DQUOTE,
+  [anon_sym_DOLLAR] = sym_string_fragment,
+  [sym_escape_sequence] = sym_escape_sequence,
+  [anon_sym_SQUOTE_SQUOTE] = anon_sym_SQUOTE_SQUOTE,
+  [sym__indented_escape_sequence] = sym_escape_sequence,
+  [anon_sym_EQ] = anon_sym_EQ,
+  [anon_sym_inherit] = anon_sym_inherit,
+  [anon_sym_DOLLAR_LBRACE] = anon_sym_DOLLAR_LBRACE,
+  [anon_sym_DOLLAR_LBRACE2] = anon_sym_DOLLAR_LBRACE,
+  [anon_sym_LBRACK] = anon_sym_LBRACK,
+  [anon_sym_RBRACK] = anon_sym_RBRACK,
+  [sym_comment] = sym_comment,
+  [sym_grit_metavariable] = sym_grit_metavariable,
+  [sym_string_fragment] = sym_string_fragment,
+  [sym__indented_string_fragment] = sym_string_fragment,
+  [sym__path_start] = sym_path_fragment,
+  [sym_path_fragment] = sym_path_fragment,
+  [sym_dollar_escape] = sym_dollar_escape,
+  [sym__indented_dollar_escape] = sym_dollar_escape,
+  [sym_source_code] = sym_source_code,
+  [sym__expression] = sym__expression,
+  [sym_identifier] = sym_identifier,
+  [sym_variable_expression] = sym_variable_expression,
+  [sym_path_expression] = sym_path_expression,
+  [sym_hpath_expression] = sym_hpath_expression,
+  [sym__expr_function_expression] = sym__expr_function_expression,
+  [sym_function_expression] = sym_function_expression,
+  [sym_formals] = sym_formals,
+  [sym_formal] = sym_formal,
+  [sym_assert_expression] = sym_assert_expression,
+  [sym_with_expression] = sym_with_expression,
+  [sym_let_expression] = sym_let_expression,
+  [sym__expr_if] = sym__expr_if,
+  [sym_if_expression] = sym_if_expression,
+  [sym__expr_op] = sym__expr_op,
+  [sym_has_attr_expression] = sym_has_attr_expression,
+  [sym_unary_expression] = sym_unary_expression,
+  [sym_binary_expression] = sym_binary_expression,
+  [sym__expr_apply_expression] = sym__expr_apply_expression,
+  [sym_apply_expression] = sym_apply_expression,
+  [sym__expr_select_expression] = sym__expr_select_expression,
+  [sym_select_expression] = sym_select_expression,
+  [sym__expr_simple] = sym__expr_simple,
+  [sym_parenthesized_expression] = sym_parenthesized_expression,
+  [sym_attrset_expression] = sym_attrset_expression,
+  [sym_let_attrset_expression] = sym_let_attrset_expression,
+  [sym_rec_attrset_expression] = sym_rec_attrset_expression,
+  [sym_string_expression] = sym_string_expression,
+  [sym_indented_string_expression] = sym_indented_string_expression,
+  [sym_binding_set] = sym_binding_set,
+  [sym_binding] = sym_binding,
+  [sym_inherit] = sym_inherit,
+  [sym_inherit_from] = sym_inherit_from,
+  [sym_attrpath] = sym_attrpath,
+  [sym_inherited_attrs] = sym_inherited_attrs,
+  [sym__immediate_interpolation] = sym_interpolation,
+  [sym_interpolation] = sym_interpolation,
+  [sym_list_expression] = sym_list_expression,
+  [aux_sym_path_expression_repeat1] = aux_sym_path_expression_repeat1,
+  [aux_sym_formals_repeat1] = aux_sym_formals_repeat1,
+  [aux_sym_string_expression_repeat1] = aux_sym_string_expression_repeat1,
+  [aux_sym_indented_string_expression_repeat1] = aux_sym_indented_string_expression_repeat1,
+  [aux_sym_binding_set_repeat1] = aux_sym_binding_set_repeat1,
+  [aux_sym_attrpath_repeat1] = aux_sym_attrpath_repeat1,
+  [aux_sym_inherited_attrs_repeat1] = aux_sym_inherited_attrs_repeat1,
+  [aux_sym_list_expression_repeat1] = aux_sym_list_expression_repeat1,
+};
+
+static const TSSymbolMetadata ts_symbol_metadata[] = {
+  [ts_builtin_sym_end] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_keyword] = {
+    .visible = true,
+    .named = true,
+  },
+  [aux_sym_identifier_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [sym_integer_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_float_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__hpath_start] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_spath_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_uri_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_COLON] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_AT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_COMMA] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_QMARK] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_ellipses] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_assert] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_SEMI] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_with] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_let] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_in] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_if] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_then] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_else] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_BANG] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_DASH] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_EQ_EQ] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_BANG_EQ] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_LT] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_LT_EQ] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_GT] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_GT_EQ] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_AMP_AMP] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_PIPE_PIPE] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_PLUS] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_STAR] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_SLASH] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_DASH_GT] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_SLASH_SLASH] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_PLUS_PLUS] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_DOT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_or] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LPAREN] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RPAREN] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_rec] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DQUOTE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOLLAR] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_escape_sequence] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_SQUOTE_SQUOTE] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym__indented_escape_sequence] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_inherit] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOLLAR_LBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOLLAR_LBRACE2] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_comment] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_grit_metavariable] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_string_fragment] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__indented_string_fragment] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__path_start] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_path_fragment] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_dollar_escape] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__indented_dollar_escape] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_source_code] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__expression] = {
+    .visible = false,
+    .named = true,
+    .supertype = true,
+  },
+  [sym_identifier] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_variable_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_path_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_hpath_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__expr_function_expression] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_function_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_formals] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_formal] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_assert_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_with_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_let_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__expr_if] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_if_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__expr_op] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_has_attr_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_unary_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_binary_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__expr_apply_expression] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_apply_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__expr_select_expression] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_select_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__expr_simple] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_parenthesized_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_attrset_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_let_attrset_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_rec_attrset_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_string_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_indented_string_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_binding_set] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_binding] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_inherit] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_inherit_from] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_attrpath] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_inherited_attrs] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__immediate_interpolation] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_interpolation] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_list_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [aux_sym_path_expression_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_formals_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_string_expression_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_indented_string_expression_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_binding_set_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_attrpath_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_inherited_attrs_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_list_expression_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+};
+
+enum ts_field_identifiers {
+  field_alternative = 1,
+  field_argument = 2,
+  field_attr = 3,
+  field_attrpath = 4,
+  field_attrs = 5,
+  field_binding = 6,
+  field_bindings = 7,
+  field_body = 8,
+  field_condition = 9,
+  field_consequence = 10,
+  field_default = 11,
+  field_element = 12,
+  field_ellipses = 13,
+  field_environment = 14,
+  field_expression = 15,
+  field_formal = 16,
+  field_formals = 17,
+  field_fragment = 18,
+  field_function = 19,
+  field_left = 20,
+  field_name = 21,
+  field_operator = 22,
+  field_right = 23,
+  field_universal = 24,
+};
+
+static const char * const ts_field_names[] = {
+  [0] = NULL,
+  [field_alternative] = "alternative",
+  [field_argument] = "argument",
+  [field_attr] = "attr",
+  [field_attrpath] = "attrpath",
+  [field_attrs] = "attrs",
+  [field_binding] = "binding",
+  [field_bindings] = "bindings",
+  [field_body] = "body",
+  [field_condition] = "condition",
+  [field_consequence] = "consequence",
+  [field_default] = "default",
+  [field_element] = "element",
+  [field_ellipses] = "ellipses",
+  [field_environment] = "environment",
+  [field_expression] = "expression",
+  [field_formal] = "formal",
+  [field_formals] = "formals",
+  [field_fragment] = "fragment",
+  [field_function] = "function",
+  [field_left] = "left",
+  [field_name] = "name",
+  [field_operator] = "operator",
+  [field_right] = "right",
+  [field_universal] = "universal",
+};
+
+static const TSFieldMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
+  [1] = {.index = 0, .length = 1},
+  [2] = {.index = 1, .length = 1},
+  [3] = {.index = 2, .length = 1},
+  [4] = {.index = 3, .length = 1},
+  [5] = {.index = 4, .length = 1},
+  [6] = {.index = 5, .length = 1},
+  [7] = {.index = 6, .length = 2},
+  [8] = {.index = 8, .length = 1},
+  [9] = {.index = 9, .length = 1},
+  [10] = {.index = 10, .length = 2},
+  [11] = {.index = 12, .length = 1},
+  [12] = {.index = 13, .length = 1},
+  [13] = {.index = 14, .length = 2},
+  [14] = {.index = 16, .length = 1},
+  [15] = {.index = 17, .length = 1},
+  [16] = {.index = 18, .length = 2},
+  [17] = {.index = 20, .length = 1},
+  [18] = {.index = 21, .length = 1},
+  [19] = {.index = 22, .length = 2},
+  [20] = {.index = 24, .length = 1},
+  [21] = {.index = 25, .length = 2},
+  [22] = {.index = 27, .length = 1},
+  [23] = {.index = 28, .length = 2},
+  [24] = {.index = 30, .length = 2},
+  [25] = {.index = 32, .length = 2},
+  [26] = {.index = 34, .length = 3},
+  [27] = {.index = 37, .length = 3},
+  [28] = {.index = 40, .length = 2},
+  [29] = {.index = 42, .length = 1},
+  [30] = {.index = 43, .length = 2},
+  [31] = {.index = 45, .length = 2},
+  [32] = {.index = 47, .length = 1},
+  [33] = {.index = 48, .length = 2},
+  [34] = {.index = 50, .length = 2},
+  [35] = {.index = 52, .length = 2},
+  [36] = {.index = 54, .length = 2},
+  [37] = {.index = 56, .length = 1},
+  [38] = {.index = 57, .length = 2},
+  [39] = {.index = 59, .length = 2},
+  [40] = {.index = 61, .length = 2},
+  [41] = {.index = 63, .length = 3},
+  [42] = {.index = 66, .length = 3},
+  [43] = {.index = 69, .length = 3},
+  [44] = {.index = 72, .length = 3},
+  [45] = {.index = 75, .length = 3},
+  [46] = {.index = 78, .length = 2},
+};
+
+static const TSFieldMapEntry ts_field_map_entries[] = {
+  [0] =
+    {field_expression, 0},
+  [1] =
+    {field_name, 0},
+  [2] =
+    {field_expression, 0, .inherited = true},
+  [3] =
+    {field_binding, 0},
+  [4] =
+    {field_attr, 0},
+  [5] =
+    {field_binding, 0, .inherited = true},
+  [6] =
+    {field_argument, 1},
+    {field_operator, 0},
+  [8] =
+    {field_fragment, 0},
+  [9] =
+    {field_element, 0},
+  [10] =
+    {field_argument, 1},
+    {field_function, 0},
+  [12] =
+    {field_ellipses, 1},
+  [13] =
+    {field_attr, 0, .inherited = true},
+  [14] =
+    {field_attr, 0},
+    {field_attr, 1, .inherited = true},
+  [16] =
+    {field_formal, 1},
+  [17] =
+    {field_bindings, 1},
+  [18] =
+    {field_binding, 0, .inherited = true},
+    {field_binding, 1, .inherited = true},
+  [20] =
+    {field_body, 2},
+  [21] =
+    {field_expression, 1},
+  [22] =
+    {field_fragment, 0},
+    {field_fragment, 1},
+  [24] =
+    {field_fragment, 1, .inherited = true},
+  [25] =
+    {field_fragment, 0, .inherited = true},
+    {field_fragment, 1, .inherited = true},
+  [27] =
+    {field_element, 1, .inherited = true},
+  [28] =
+    {field_element, 0, .inherited = true},
+    {field_element, 1, .inherited = true},
+  [30] =
+    {field_body, 2},
+    {field_universal, 0},
+  [32] =
+    {field_body, 2},
+    {field_formals, 0},
+  [34] =
+    {field_attrpath, 2},
+    {field_expression, 0},
+    {field_operator, 1},
+  [37] =
+    {field_left, 0},
+    {field_operator, 1},
+    {field_right, 2},
+  [40] =
+    {field_attrpath, 2},
+    {field_expression, 0},
+  [42] =
+    {field_attrs, 1},
+  [43] =
+    {field_attr, 0, .inherited = true},
+    {field_attr, 1, .inherited = true},
+  [45] =
+    {field_default, 2},
+    {field_name, 0},
+  [47] =
+    {field_attr, 1},
+  [48] =
+    {field_formal, 1},
+    {field_formal, 2, .inherited = true},
+  [50] =
+    {field_formal, 0, .inherited = true},
+    {field_formal, 1, .inherited = true},
+  [52] =
+    {field_body, 3},
+    {field_condition, 1},
+  [54] =
+    {field_body, 3},
+    {field_environment, 1},
+  [56] =
+    {field_bindings, 2},
+  [57] =
+    {field_bindings, 1},
+    {field_body, 3},
+  [59] =
+    {field_ellipses, 3},
+    {field_formal, 1},
+  [61] =
+    {field_attrpath, 0},
+    {field_expression, 2},
+  [63] =
+    {field_body, 4},
+    {field_formals, 2},
+    {field_universal, 0},
+  [66] =
+    {field_body, 4},
+    {field_formals, 0},
+    {field_universal, 2},
+  [69] =
+    {field_attrpath, 2},
+    {field_default, 4},
+    {field_expression, 0},
+  [72] =
+    {field_ellipses, 4},
+    {field_formal, 1},
+    {field_formal, 2, .inherited = true},
+  [75] =
+    {field_alternative, 5},
+    {field_condition, 1},
+    {field_consequence, 3},
+  [78] =
+    {field_attrs, 4},
+    {field_expression, 2},
+};
+
+static const TSSymbol ts_alias_sequences[PRODUCTION_ID_COUNT][MAX_ALIAS_SEQUENCE_LENGTH] = {
+  [0] = {0},
+  [26] = {
+    [1] = anon_sym_BANG,
+  },
+};
+
+static const uint16_t ts_non_terminal_alias_map[] = {
+  0,
+};
+
+static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
+  [0] = 0,
+  [1] = 1,
+  [2] = 2,
+  [3] = 3,
+  [4] = 4,
+  [5] = 5,
+  [6] = 6,
+  [7] = 7,
+  [8] = 8,
+  [9] = 9,
+  [10] = 10,
+  [11] = 11,
+  [12] = 12,
+  [13] = 11,
+  [14] = 11,
+  [15] = 11,
+  [16] = 16,
+  [17] = 3,
+  [18] = 5,
+  [19] = 2,
+  [20] = 6,
+  [21] = 9,
+  [22] = 16,
+  [23] = 16,
+  [24] = 3,
+  [25] = 5,
+  [26] = 2,
+  [27] = 6,
+  [28] = 10,
+  [29] = 16,
+  [30] = 3,
+  [31] = 5,
+  [32] = 2,
+  [33] = 6,
+  [34] = 10,
+  [35] = 6,
+  [36] = 6,
+  [37] = 6,
+  [38] = 6,
+  [39] = 4,
+  [40] = 7,
+  [41] = 4,
+  [42] = 4,
+  [43] = 10,
+  [44] = 44,
+  [45] = 45,
+  [46] = 46,
+  [47] = 44,
+  [48] = 48,
+  [49] = 49,
+  [50] = 50,
+  [51] = 51,
+  [52] = 52,
+  [53] = 45,
+  [54] = 46,
+  [55] = 48,
+  [56] = 49,
+  [57] = 50,
+  [58] = 52,
+  [59] = 45,
+  [60] = 46,
+  [61] = 44,
+  [62] = 48,
+  [63] = 49,
+  [64] = 50,
+  [65] = 51,
+  [66] = 52,
+  [67] = 45,
+  [68] = 46,
+  [69] = 44,
+  [70] = 48,
+  [71] = 49,
+  [72] = 50,
+  [73] = 51,
+  [74] = 52,
+  [75] = 51,
+  [76] = 12,
+  [77] = 12,
+  [78] = 12,
+  [79] = 79,
+  [80] = 80,
+  [81] = 81,
+  [82] = 82,
+  [83] = 83,
+  [84] = 84,
+  [85] = 85,
+  [86] = 86,
+  [87] = 87,
+  [88] = 88,
+  [89] = 89,
+  [90] = 90,
+  [91] = 91,
+  [92] = 92,
+  [93] = 88,
+  [94] = 94,
+  [95] = 95,
+  [96] = 96,
+  [97] = 97,
+  [98] = 97,
+  [99] = 79,
+  [100] = 82,
+  [101] = 101,
+  [102] = 102,
+  [103] = 94,
+  [104] = 83,
+  [105] = 105,
+  [106] = 106,
+  [107] = 107,
+  [108] = 97,
+  [109] = 101,
+  [110] = 102,
+  [111] = 105,
+  [112] = 112,
+  [113] = 113,
+  [114] = 114,
+  [115] = 81,
+  [116] = 112,
+  [117] = 113,
+  [118] = 114,
+  [119] = 96,
+  [120] = 79,
+  [121] = 121,
+  [122] = 122,
+  [123] = 83,
+  [124] = 80,
+  [125] = 125,
+  [126] = 126,
+  [127] = 127,
+  [128] = 79,
+  [129] = 82,
+  [130] = 130,
+  [131] = 131,
+  [132] = 132,
+  [133] = 106,
+  [134] = 107,
+  [135] = 97,
+  [136] = 101,
+  [137] = 102,
+  [138] = 105,
+  [139] = 112,
+  [140] = 113,
+  [141] = 114,
+  [142] = 126,
+  [143] = 143,
+  [144] = 144,
+  [145] = 145,
+  [146] = 146,
+  [147] = 147,
+  [148] = 126,
+  [149] = 149,
+  [150] = 150,
+  [151] = 151,
+  [152] = 80,
+  [153] = 106,
+  [154] = 106,
+  [155] = 107,
+  [156] = 125,
+  [157] = 101,
+  [158] = 102,
+  [159] = 105,
+  [160] = 112,
+  [161] = 113,
+  [162] = 114,
+  [163] = 81,
+  [164] = 125,
+  [165] = 82,
+  [166] = 83,
+  [167] = 107,
+  [168] = 80,
+  [169] = 125,
+  [170] = 126,
+  [171] = 81,
+  [172] = 172,
+  [173] = 173,
+  [174] = 89,
+  [175] = 91,
+  [176] = 88,
+  [177] = 84,
+  [178] = 88,
+  [179] = 84,
+  [180] = 91,
+  [181] = 87,
+  [182] = 87,
+  [183] = 90,
+  [184] = 86,
+  [185] = 89,
+  [186] = 85,
+  [187] = 85,
+  [188] = 91,
+  [189] = 90,
+  [190] = 84,
+  [191] = 88,
+  [192] = 86,
+  [193] = 87,
+  [194] = 85,
+  [195] = 86,
+  [196] = 90,
+  [197] = 89,
+  [198] = 92,
+  [199] = 88,
+  [200] = 94,
+  [201] = 96,
+  [202] = 88,
+  [203] = 95,
+  [204] = 88,
+  [205] = 94,
+  [206] = 95,
+  [207] = 94,
+  [208] = 96,
+  [209] = 96,
+  [210] = 92,
+  [211] = 95,
+  [212] = 92,
+  [213] = 94,
+  [214] = 149,
+  [215] = 121,
+  [216] = 149,
+  [217] = 130,
+  [218] = 132,
+  [219] = 122,
+  [220] = 143,
+  [221] = 127,
+  [222] = 131,
+  [223] = 144,
+  [224] = 96,
+  [225] = 145,
+  [226] = 146,
+  [227] = 121,
+  [228] = 130,
+  [229] = 132,
+  [230] = 143,
+  [231] = 144,
+  [232] = 145,
+  [233] = 146,
+  [234] = 147,
+  [235] = 150,
+  [236] = 151,
+  [237] = 147,
+  [238] = 122,
+  [239] = 94,
+  [240] = 127,
+  [241] = 131,
+  [242] = 150,
+  [243] = 151,
+  [244] = 121,
+  [245] = 130,
+  [246] = 132,
+  [247] = 143,
+  [248] = 144,
+  [249] = 96,
+  [250] = 145,
+  [251] = 146,
+  [252] = 147,
+  [253] = 150,
+  [254] = 151,
+  [255] = 122,
+  [256] = 94,
+  [257] = 96,
+  [258] = 127,
+  [259] = 131,
+  [260] = 149,
+  [261] = 173,
+  [262] = 172,
+  [263] = 173,
+  [264] = 172,
+  [265] = 173,
+  [266] = 172,
+  [267] = 267,
+  [268] = 268,
+  [269] = 267,
+  [270] = 268,
+  [271] = 267,
+  [272] = 268,
+  [273] = 267,
+  [274] = 274,
+  [275] = 268,
+  [276] = 276,
+  [277] = 276,
+  [278] = 276,
+  [279] = 276,
+  [280] = 88,
+  [281] = 96,
+  [282] = 92,
+  [283] = 94,
+  [284] = 95,
+  [285] = 285,
+  [286] = 286,
+  [287] = 89,
+  [288] = 87,
+  [289] = 84,
+  [290] = 84,
+  [291] = 87,
+  [292] = 89,
+  [293] = 88,
+  [294] = 294,
+  [295] = 295,
+  [296] = 296,
+  [297] = 297,
+  [298] = 92,
+  [299] = 299,
+  [300] = 94,
+  [301] = 96,
+  [302] = 302,
+  [303] = 303,
+  [304] = 304,
+  [305] = 305,
+  [306] = 306,
+  [307] = 307,
+  [308] = 297,
+  [309] = 305,
+  [310] = 294,
+  [311] = 311,
+  [312] = 296,
+  [313] = 295,
+  [314] = 304,
+  [315] = 305,
+  [316] = 307,
+  [317] = 294,
+  [318] = 295,
+  [319] = 305,
+  [320] = 311,
+  [321] = 307,
+  [322] = 296,
+  [323] = 297,
+  [324] = 299,
+  [325] = 296,
+  [326] = 303,
+  [327] = 306,
+  [328] = 311,
+  [329] = 297,
+  [330] = 302,
+  [331] = 304,
+  [332] = 295,
+  [333] = 307,
+  [334] = 311,
+  [335] = 299,
+  [336] = 302,
+  [337] = 304,
+  [338] = 299,
+  [339] = 294,
+  [340] = 303,
+  [341] = 306,
+  [342] = 303,
+  [343] = 302,
+  [344] = 306,
+  [345] = 345,
+  [346] = 346,
+  [347] = 345,
+  [348] = 345,
+  [349] = 345,
+  [350] = 350,
+  [351] = 351,
+  [352] = 351,
+  [353] = 353,
+  [354] = 350,
+  [355] = 353,
+  [356] = 351,
+  [357] = 353,
+  [358] = 351,
+  [359] = 350,
+  [360] = 353,
+  [361] = 350,
+  [362] = 362,
+  [363] = 362,
+  [364] = 364,
+  [365] = 364,
+  [366] = 366,
+  [367] = 367,
+  [368] = 367,
+  [369] = 369,
+  [370] = 369,
+  [371] = 371,
+  [372] = 372,
+  [373] = 373,
+  [374] = 366,
+  [375] = 375,
+  [376] = 376,
+  [377] = 366,
+  [378] = 378,
+  [379] = 379,
+  [380] = 380,
+  [381] = 379,
+  [382] = 375,
+  [383] = 376,
+  [384] = 378,
+  [385] = 373,
+  [386] = 379,
+  [387] = 387,
+  [388] = 375,
+  [389] = 376,
+  [390] = 390,
+  [391] = 378,
+  [392] = 373,
+  [393] = 379,
+  [394] = 375,
+  [395] = 378,
+  [396] = 375,
+  [397] = 378,
+  [398] = 375,
+  [399] = 378,
+  [400] = 375,
+  [401] = 378,
+  [402] = 375,
+  [403] = 378,
+  [404] = 375,
+  [405] = 378,
+  [406] = 378,
+  [407] = 373,
+  [408] = 380,
+  [409] = 375,
+  [410] = 376,
+  [411] = 411,
+  [412] = 412,
+  [413] = 413,
+  [414] = 414,
+  [415] = 413,
+  [416] = 416,
+  [417] = 413,
+  [418] = 413,
+  [419] = 413,
+  [420] = 413,
+  [421] = 421,
+  [422] = 422,
+  [423] = 423,
+  [424] = 424,
+  [425] = 425,
+  [426] = 426,
+  [427] = 427,
+  [428] = 428,
+  [429] = 92,
+  [430] = 430,
+  [431] = 427,
+  [432] = 432,
+  [433] = 433,
+  [434] = 434,
+  [435] = 435,
+  [436] = 436,
+  [437] = 434,
+  [438] = 438,
+  [439] = 436,
+  [440] = 92,
+  [441] = 441,
+  [442] = 428,
+  [443] = 443,
+  [444] = 444,
+  [445] = 445,
+  [446] = 446,
+  [447] = 447,
+  [448] = 84,
+  [449] = 449,
+  [450] = 450,
+  [451] = 451,
+  [452] = 452,
+  [453] = 451,
+  [454] = 451,
+  [455] = 451,
+  [456] = 456,
+  [457] = 457,
+  [458] = 458,
+  [459] = 458,
+  [460] = 460,
+  [461] = 461,
+  [462] = 462,
+  [463] = 463,
+  [464] = 464,
+  [465] = 465,
+  [466] = 466,
+  [467] = 458,
+  [468] = 465,
+  [469] = 465,
+  [470] = 458,
+  [471] = 465,
+  [472] = 472,
+  [473] = 473,
+  [474] = 474,
+  [475] = 475,
+  [476] = 476,
+  [477] = 477,
+  [478] = 477,
+  [479] = 477,
+  [480] = 480,
+  [481] = 481,
+  [482] = 474,
+  [483] = 483,
+  [484] = 475,
+  [485] = 485,
+  [486] = 486,
+  [487] = 473,
+  [488] = 488,
+  [489] = 472,
+  [490] = 490,
+  [491] = 491,
+  [492] = 492,
+  [493] = 475,
+  [494] = 485,
+  [495] = 495,
+  [496] = 485,
+  [497] = 475,
+  [498] = 485,
+  [499] = 499,
+  [500] = 473,
+  [501] = 488,
+  [502] = 485,
+  [503] = 476,
+  [504] = 490,
+  [505] = 485,
+  [506] = 485,
+  [507] = 507,
+  [508] = 491,
+  [509] = 509,
+  [510] = 472,
+  [511] = 511,
+  [512] = 485,
+  [513] = 513,
+  [514] = 514,
+  [515] = 515,
+  [516] = 516,
+  [517] = 488,
+  [518] = 472,
+  [519] = 519,
+  [520] = 476,
+  [521] = 490,
+  [522] = 491,
+  [523] = 499,
+  [524] = 507,
+  [525] = 513,
+  [526] = 526,
+  [527] = 511,
+  [528] = 477,
+  [529] = 474,
+  [530] = 491,
+  [531] = 514,
+  [532] = 495,
+  [533] = 499,
+  [534] = 513,
+  [535] = 511,
+  [536] = 514,
+  [537] = 476,
+  [538] = 514,
+  [539] = 513,
+  [540] = 474,
+  [541] = 488,
+  [542] = 490,
+  [543] = 499,
+  [544] = 480,
+  [545] = 486,
+  [546] = 526,
+  [547] = 486,
+  [548] = 486,
+  [549] = 549,
+  [550] = 550,
+  [551] = 509,
+  [552] = 511,
+  [553] = 473,
+};
+
+static bool ts_lex(TSLexer *lexer, TSStateId state) {
+  START_LEXER();
+  eof = lexer->eof(lexer);
+  switch (state) {
+    case 0:
+      if (eof) ADVANCE(62);
+      ADVANCE_MAP(
+        '!', 132,
+        '"', 157,
+        '#', 172,
+        '$', 159,
+        '&', 6,
+        '\'', 7,
+        '(', 155,
+        ')', 156,
+        '*', 145,
+        '+', 144,
+        ',', 127,
+        '-', 134,
+        '.', 151,
+        '/', 146,
+        '0', 116,
+        ':', 123,
+        ';', 130,
+        '<', 138,
+        '=', 166,
+        '>', 140,
+        '?', 128,
+        '@', 124,
+        '[', 169,
+        '\\', 52,
+        ']', 170,
+        'a', 109,
+        'e', 104,
+        'i', 99,
+        'l', 94,
+        'o', 107,
+        'r', 95,
+        't', 101,
+        'w', 103,
+        '{', 125,
+        '|', 45,
+        '}', 126,
+        '~', 17,
+        0xb5, 15,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(54);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(115);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 1:
+      ADVANCE_MAP(
+        '!', 20,
+        '"', 157,
+        '#', 172,
+        '$', 44,
+        '&', 6,
+        '\'', 7,
+        '(', 155,
+        ')', 156,
+        '*', 145,
+        '+', 144,
+        ',', 127,
+        '-', 134,
+        '.', 150,
+        '/', 146,
+        ':', 123,
+        ';', 130,
+        '<', 137,
+        '=', 166,
+        '>', 140,
+        '?', 128,
+        '|', 45,
+        '}', 126,
+        0xb5, 15,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(2);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 2:
+      ADVANCE_MAP(
+        '!', 20,
+        '"', 157,
+        '#', 172,
+        '$', 44,
+        '&', 6,
+        '\'', 8,
+        '(', 155,
+        ')', 156,
+        '*', 145,
+        '+', 144,
+        ',', 127,
+        '-', 134,
+        '.', 150,
+        '/', 146,
+        ':', 123,
+        ';', 130,
+        '<', 137,
+        '=', 166,
+        '>', 140,
+        '?', 128,
+        '|', 45,
+        '}', 126,
+        0xb5, 15,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(2);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 3:
+      ADVANCE_MAP(
+        '"', 157,
+        '#', 172,
+        '$', 44,
+        ',', 127,
+        '.', 150,
+        '/', 9,
+        '=', 165,
+        '?', 128,
+        'a', 109,
+        'e', 104,
+        'i', 99,
+        'l', 94,
+        'r', 95,
+        't', 101,
+        'w', 103,
+        '}', 126,
+        0xb5, 15,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(3);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 4:
+      ADVANCE_MAP(
+        '"', 157,
+        '#', 172,
+        '$', 44,
+        '.', 12,
+        '/', 9,
+        'a', 109,
+        'e', 104,
+        'i', 99,
+        'l', 94,
+        'r', 95,
+        't', 101,
+        'w', 103,
+        '{', 125,
+        '}', 126,
+        0xb5, 15,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(4);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 5:
+      if (lookahead == '#') ADVANCE(172);
+      if (lookahead == '.') ADVANCE(12);
+      if (lookahead == '/') ADVANCE(9);
+      if (lookahead == '}') ADVANCE(126);
+      if (lookahead == 0xb5) ADVANCE(15);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(5);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 6:
+      if (lookahead == '&') ADVANCE(142);
+      END_STATE();
+    case 7:
+      if (lookahead == '\'') ADVANCE(163);
+      END_STATE();
+    case 8:
+      if (lookahead == '\'') ADVANCE(162);
+      END_STATE();
+    case 9:
+      if (lookahead == '*') ADVANCE(11);
+      END_STATE();
+    case 10:
+      if (lookahead == '*') ADVANCE(10);
+      if (lookahead == '/') ADVANCE(171);
+      if (lookahead != 0) ADVANCE(11);
+      END_STATE();
+    case 11:
+      if (lookahead == '*') ADVANCE(10);
+      if (lookahead != 0) ADVANCE(11);
+      END_STATE();
+    case 12:
+      if (lookahead == '.') ADVANCE(13);
+      END_STATE();
+    case 13:
+      if (lookahead == '.') ADVANCE(129);
+      END_STATE();
+    case 14:
+      if (lookahead == '.') ADVANCE(173);
+      END_STATE();
+    case 15:
+      if (lookahead == '.') ADVANCE(16);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(174);
+      END_STATE();
+    case 16:
+      if (lookahead == '.') ADVANCE(14);
+      END_STATE();
+    case 17:
+      if (lookahead == '/') ADVANCE(50);
+      END_STATE();
+    case 18:
+      if (lookahead == '/') ADVANCE(49);
+      if (lookahead == '>') ADVANCE(121);
+      if (lookahead == '+' ||
+          ('-' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(18);
+      END_STATE();
+    case 19:
+      if (lookahead == ':') ADVANCE(51);
+      if (lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '.' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(19);
+      END_STATE();
+    case 20:
+      if (lookahead == '=') ADVANCE(136);
+      END_STATE();
+    case 21:
+      if (lookahead == '=') ADVANCE(135);
+      END_STATE();
+    case 22:
+      if (lookahead == 'c') ADVANCE(63);
+      END_STATE();
+    case 23:
+      if (lookahead == 'e') ADVANCE(63);
+      END_STATE();
+    case 24:
+      if (lookahead == 'e') ADVANCE(41);
+      END_STATE();
+    case 25:
+      if (lookahead == 'e') ADVANCE(22);
+      END_STATE();
+    case 26:
+      if (lookahead == 'e') ADVANCE(35);
+      END_STATE();
+    case 27:
+      if (lookahead == 'e') ADVANCE(36);
+      END_STATE();
+    case 28:
+      if (lookahead == 'e') ADVANCE(37);
+      END_STATE();
+    case 29:
+      if (lookahead == 'f') ADVANCE(63);
+      if (lookahead == 'n') ADVANCE(67);
+      END_STATE();
+    case 30:
+      if (lookahead == 'h') ADVANCE(63);
+      END_STATE();
+    case 31:
+      if (lookahead == 'h') ADVANCE(26);
+      END_STATE();
+    case 32:
+      if (lookahead == 'i') ADVANCE(41);
+      END_STATE();
+    case 33:
+      if (lookahead == 'i') ADVANCE(42);
+      END_STATE();
+    case 34:
+      if (lookahead == 'l') ADVANCE(40);
+      END_STATE();
+    case 35:
+      if (lookahead == 'n') ADVANCE(63);
+      END_STATE();
+    case 36:
+      if (lookahead == 'r') ADVANCE(41);
+      END_STATE();
+    case 37:
+      if (lookahead == 'r') ADVANCE(32);
+      END_STATE();
+    case 38:
+      if (lookahead == 's') ADVANCE(39);
+      END_STATE();
+    case 39:
+      if (lookahead == 's') ADVANCE(27);
+      END_STATE();
+    case 40:
+      if (lookahead == 's') ADVANCE(23);
+      END_STATE();
+    case 41:
+      if (lookahead == 't') ADVANCE(63);
+      END_STATE();
+    case 42:
+      if (lookahead == 't') ADVANCE(30);
+      END_STATE();
+    case 43:
+      if (lookahead == '{') ADVANCE(167);
+      END_STATE();
+    case 44:
+      if (lookahead == '{') ADVANCE(168);
+      END_STATE();
+    case 45:
+      if (lookahead == '|') ADVANCE(143);
+      END_STATE();
+    case 46:
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(48);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(119);
+      END_STATE();
+    case 47:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(118);
+      END_STATE();
+    case 48:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(119);
+      END_STATE();
+    case 49:
+      if (lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '.' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(18);
+      END_STATE();
+    case 50:
+      if (lookahead == '+' ||
+          ('-' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(120);
+      END_STATE();
+    case 51:
+      if (lookahead == '!' ||
+          ('$' <= lookahead && lookahead <= '\'') ||
+          ('*' <= lookahead && lookahead <= ':') ||
+          lookahead == '=' ||
+          ('?' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z') ||
+          lookahead == '~') ADVANCE(122);
+      END_STATE();
+    case 52:
+      if (lookahead != 0 &&
+          lookahead != '$') ADVANCE(161);
+      END_STATE();
+    case 53:
+      if (lookahead != 0 &&
+          lookahead != '$') ADVANCE(164);
+      END_STATE();
+    case 54:
+      if (eof) ADVANCE(62);
+      ADVANCE_MAP(
+        '!', 132,
+        '"', 157,
+        '#', 172,
+        '$', 160,
+        '&', 6,
+        '\'', 8,
+        '(', 155,
+        ')', 156,
+        '*', 145,
+        '+', 144,
+        ',', 127,
+        '-', 134,
+        '.', 151,
+        '/', 146,
+        '0', 116,
+        ':', 123,
+        ';', 130,
+        '<', 138,
+        '=', 166,
+        '>', 140,
+        '?', 128,
+        '@', 124,
+        '[', 169,
+        ']', 170,
+        'a', 109,
+        'e', 104,
+        'i', 99,
+        'l', 94,
+        'o', 107,
+        'r', 95,
+        't', 101,
+        'w', 103,
+        '{', 125,
+        '|', 45,
+        '}', 126,
+        '~', 17,
+        0xb5, 15,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(54);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(115);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 55:
+      if (eof) ADVANCE(62);
+      ADVANCE_MAP(
+        '!', 131,
+        '"', 157,
+        '#', 172,
+        '$', 44,
+        '\'', 8,
+        '(', 155,
+        ',', 127,
+        '-', 133,
+        '.', 152,
+        '/', 9,
+        '0', 116,
+        '<', 49,
+        '=', 165,
+        '?', 128,
+        '[', 169,
+        '\\', 52,
+        ']', 170,
+        '_', 114,
+        'a', 86,
+        'e', 81,
+        'i', 76,
+        'l', 71,
+        'r', 72,
+        't', 78,
+        'w', 80,
+        '{', 125,
+        '}', 126,
+        '~', 17,
+        0xb5, 15,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(56);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(115);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 56:
+      if (eof) ADVANCE(62);
+      ADVANCE_MAP(
+        '!', 131,
+        '"', 157,
+        '#', 172,
+        '$', 44,
+        '\'', 8,
+        '(', 155,
+        ',', 127,
+        '-', 133,
+        '.', 152,
+        '/', 9,
+        '0', 116,
+        '<', 49,
+        '=', 165,
+        '?', 128,
+        '[', 169,
+        ']', 170,
+        '_', 114,
+        'a', 86,
+        'e', 81,
+        'i', 76,
+        'l', 71,
+        'r', 72,
+        't', 78,
+        'w', 80,
+        '{', 125,
+        '}', 126,
+        '~', 17,
+        0xb5, 15,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(56);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(115);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 57:
+      if (eof) ADVANCE(62);
+      ADVANCE_MAP(
+        '!', 20,
+        '"', 157,
+        '#', 172,
+        '$', 158,
+        '&', 6,
+        '\'', 8,
+        '(', 155,
+        ')', 156,
+        '*', 145,
+        '+', 144,
+        ',', 127,
+        '-', 134,
+        '.', 152,
+        '/', 146,
+        '0', 116,
+        ';', 130,
+        '<', 138,
+        '=', 21,
+        '>', 140,
+        '?', 128,
+        '[', 169,
+        ']', 170,
+        '_', 114,
+        'a', 86,
+        'e', 81,
+        'i', 76,
+        'l', 71,
+        'o', 84,
+        'r', 72,
+        't', 78,
+        'w', 80,
+        '{', 125,
+        '|', 45,
+        '}', 126,
+        '~', 17,
+        0xb5, 15,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(57);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(115);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 58:
+      if (eof) ADVANCE(62);
+      ADVANCE_MAP(
+        '!', 20,
+        '"', 157,
+        '#', 172,
+        '$', 43,
+        '&', 6,
+        '\'', 8,
+        '(', 155,
+        ')', 156,
+        '*', 145,
+        '+', 144,
+        ',', 127,
+        '-', 134,
+        '.', 152,
+        '/', 146,
+        '0', 116,
+        ':', 123,
+        ';', 130,
+        '<', 138,
+        '=', 21,
+        '>', 140,
+        '?', 128,
+        '@', 124,
+        '[', 169,
+        ']', 170,
+        '_', 114,
+        'a', 86,
+        'e', 81,
+        'i', 76,
+        'l', 71,
+        'r', 72,
+        't', 78,
+        'w', 80,
+        '{', 125,
+        '|', 45,
+        '}', 126,
+        '~', 17,
+        0xb5, 15,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(59);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(115);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 59:
+      if (eof) ADVANCE(62);
+      ADVANCE_MAP(
+        '!', 20,
+        '"', 157,
+        '#', 172,
+        '&', 6,
+        '\'', 8,
+        '(', 155,
+        ')', 156,
+        '*', 145,
+        '+', 144,
+        ',', 127,
+        '-', 134,
+        '.', 152,
+        '/', 146,
+        '0', 116,
+        ':', 123,
+        ';', 130,
+        '<', 138,
+        '=', 21,
+        '>', 140,
+        '?', 128,
+        '@', 124,
+        '[', 169,
+        ']', 170,
+        '_', 114,
+        'a', 86,
+        'e', 81,
+        'i', 76,
+        'l', 71,
+        'r', 72,
+        't', 78,
+        'w', 80,
+        '{', 125,
+        '|', 45,
+        '}', 126,
+        '~', 17,
+        0xb5, 15,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(59);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(115);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 60:
+      if (eof) ADVANCE(62);
+      ADVANCE_MAP(
+        '!', 20,
+        '#', 172,
+        '&', 6,
+        ')', 156,
+        '*', 145,
+        '+', 144,
+        ',', 127,
+        '-', 134,
+        '.', 150,
+        '/', 146,
+        ';', 130,
+        '<', 137,
+        '=', 166,
+        '>', 140,
+        '?', 128,
+        'a', 38,
+        'e', 34,
+        'i', 29,
+        'l', 24,
+        'r', 25,
+        't', 31,
+        'w', 33,
+        '|', 45,
+        '}', 126,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(60);
+      END_STATE();
+    case 61:
+      if (eof) ADVANCE(62);
+      ADVANCE_MAP(
+        '!', 20,
+        '#', 172,
+        '&', 6,
+        ')', 156,
+        '*', 145,
+        '+', 144,
+        ',', 127,
+        '-', 134,
+        '.', 150,
+        '/', 146,
+        ';', 130,
+        '<', 137,
+        '=', 21,
+        '>', 140,
+        '?', 128,
+        'a', 38,
+        'e', 34,
+        'i', 29,
+        'l', 24,
+        'r', 25,
+        't', 31,
+        'w', 33,
+        '|', 45,
+        '}', 126,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(61);
+      END_STATE();
+    case 62:
+      ACCEPT_TOKEN(ts_builtin_sym_end);
+      END_STATE();
+    case 63:
+      ACCEPT_TOKEN(sym_keyword);
+      END_STATE();
+    case 64:
+      ACCEPT_TOKEN(sym_keyword);
+      if (lookahead == ':') ADVANCE(51);
+      if (lookahead == 'h') ADVANCE(75);
+      if (lookahead == '\'' ||
+          lookahead == '_') ADVANCE(114);
+      if (lookahead == '+' ||
+          lookahead == '.') ADVANCE(19);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 65:
+      ACCEPT_TOKEN(sym_keyword);
+      if (lookahead == ':') ADVANCE(51);
+      if (lookahead == '\'' ||
+          lookahead == '_') ADVANCE(114);
+      if (lookahead == '+' ||
+          lookahead == '.') ADVANCE(19);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 66:
+      ACCEPT_TOKEN(sym_keyword);
+      if (lookahead == 'h') ADVANCE(98);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 67:
+      ACCEPT_TOKEN(sym_keyword);
+      if (lookahead == 'h') ADVANCE(28);
+      END_STATE();
+    case 68:
+      ACCEPT_TOKEN(sym_keyword);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 69:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == ':') ADVANCE(51);
+      if (lookahead == 'c') ADVANCE(65);
+      if (lookahead == '\'' ||
+          lookahead == '_') ADVANCE(114);
+      if (lookahead == '+' ||
+          lookahead == '.') ADVANCE(19);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 70:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == ':') ADVANCE(51);
+      if (lookahead == 'e') ADVANCE(65);
+      if (lookahead == '\'' ||
+          lookahead == '_') ADVANCE(114);
+      if (lookahead == '+' ||
+          lookahead == '.') ADVANCE(19);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 71:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == ':') ADVANCE(51);
+      if (lookahead == 'e') ADVANCE(89);
+      if (lookahead == '\'' ||
+          lookahead == '_') ADVANCE(114);
+      if (lookahead == '+' ||
+          lookahead == '.') ADVANCE(19);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 72:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == ':') ADVANCE(51);
+      if (lookahead == 'e') ADVANCE(69);
+      if (lookahead == '\'' ||
+          lookahead == '_') ADVANCE(114);
+      if (lookahead == '+' ||
+          lookahead == '.') ADVANCE(19);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 73:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == ':') ADVANCE(51);
+      if (lookahead == 'e') ADVANCE(82);
+      if (lookahead == '\'' ||
+          lookahead == '_') ADVANCE(114);
+      if (lookahead == '+' ||
+          lookahead == '.') ADVANCE(19);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 74:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == ':') ADVANCE(51);
+      if (lookahead == 'e') ADVANCE(83);
+      if (lookahead == '\'' ||
+          lookahead == '_') ADVANCE(114);
+      if (lookahead == '+' ||
+          lookahead == '.') ADVANCE(19);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 75:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == ':') ADVANCE(51);
+      if (lookahead == 'e') ADVANCE(85);
+      if (lookahead == '\'' ||
+          lookahead == '_') ADVANCE(114);
+      if (lookahead == '+' ||
+          lookahead == '.') ADVANCE(19);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 76:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == ':') ADVANCE(51);
+      if (lookahead == 'f') ADVANCE(65);
+      if (lookahead == 'n') ADVANCE(64);
+      if (lookahead == '\'' ||
+          lookahead == '_') ADVANCE(114);
+      if (lookahead == '+' ||
+          lookahead == '.') ADVANCE(19);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 77:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == ':') ADVANCE(51);
+      if (lookahead == 'h') ADVANCE(65);
+      if (lookahead == '\'' ||
+          lookahead == '_') ADVANCE(114);
+      if (lookahead == '+' ||
+          lookahead == '.') ADVANCE(19);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 78:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == ':') ADVANCE(51);
+      if (lookahead == 'h') ADVANCE(73);
+      if (lookahead == '\'' ||
+          lookahead == '_') ADVANCE(114);
+      if (lookahead == '+' ||
+          lookahead == '.') ADVANCE(19);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 79:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == ':') ADVANCE(51);
+      if (lookahead == 'i') ADVANCE(89);
+      if (lookahead == '\'' ||
+          lookahead == '_') ADVANCE(114);
+      if (lookahead == '+' ||
+          lookahead == '.') ADVANCE(19);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 80:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == ':') ADVANCE(51);
+      if (lookahead == 'i') ADVANCE(90);
+      if (lookahead == '\'' ||
+          lookahead == '_') ADVANCE(114);
+      if (lookahead == '+' ||
+          lookahead == '.') ADVANCE(19);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 81:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == ':') ADVANCE(51);
+      if (lookahead == 'l') ADVANCE(88);
+      if (lookahead == '\'' ||
+          lookahead == '_') ADVANCE(114);
+      if (lookahead == '+' ||
+          lookahead == '.') ADVANCE(19);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 82:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == ':') ADVANCE(51);
+      if (lookahead == 'n') ADVANCE(65);
+      if (lookahead == '\'' ||
+          lookahead == '_') ADVANCE(114);
+      if (lookahead == '+' ||
+          lookahead == '.') ADVANCE(19);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 83:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == ':') ADVANCE(51);
+      if (lookahead == 'r') ADVANCE(89);
+      if (lookahead == '\'' ||
+          lookahead == '_') ADVANCE(114);
+      if (lookahead == '+' ||
+          lookahead == '.') ADVANCE(19);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 84:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == ':') ADVANCE(51);
+      if (lookahead == 'r') ADVANCE(153);
+      if (lookahead == '\'' ||
+          lookahead == '_') ADVANCE(114);
+      if (lookahead == '+' ||
+          lookahead == '.') ADVANCE(19);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 85:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == ':') ADVANCE(51);
+      if (lookahead == 'r') ADVANCE(79);
+      if (lookahead == '\'' ||
+          lookahead == '_') ADVANCE(114);
+      if (lookahead == '+' ||
+          lookahead == '.') ADVANCE(19);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 86:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == ':') ADVANCE(51);
+      if (lookahead == 's') ADVANCE(87);
+      if (lookahead == '\'' ||
+          lookahead == '_') ADVANCE(114);
+      if (lookahead == '+' ||
+          lookahead == '.') ADVANCE(19);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 87:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == ':') ADVANCE(51);
+      if (lookahead == 's') ADVANCE(74);
+      if (lookahead == '\'' ||
+          lookahead == '_') ADVANCE(114);
+      if (lookahead == '+' ||
+          lookahead == '.') ADVANCE(19);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 88:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == ':') ADVANCE(51);
+      if (lookahead == 's') ADVANCE(70);
+      if (lookahead == '\'' ||
+          lookahead == '_') ADVANCE(114);
+      if (lookahead == '+' ||
+          lookahead == '.') ADVANCE(19);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 89:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == ':') ADVANCE(51);
+      if (lookahead == 't') ADVANCE(65);
+      if (lookahead == '\'' ||
+          lookahead == '_') ADVANCE(114);
+      if (lookahead == '+' ||
+          lookahead == '.') ADVANCE(19);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 90:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == ':') ADVANCE(51);
+      if (lookahead == 't') ADVANCE(77);
+      if (lookahead == '\'' ||
+          lookahead == '_') ADVANCE(114);
+      if (lookahead == '+' ||
+          lookahead == '.') ADVANCE(19);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 91:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == ':') ADVANCE(51);
+      if (lookahead == '\'' ||
+          lookahead == '_') ADVANCE(114);
+      if (lookahead == '+' ||
+          lookahead == '.') ADVANCE(19);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 92:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == 'c') ADVANCE(68);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 93:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == 'e') ADVANCE(68);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 94:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == 'e') ADVANCE(112);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 95:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == 'e') ADVANCE(92);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 96:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == 'e') ADVANCE(105);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 97:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == 'e') ADVANCE(106);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 98:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == 'e') ADVANCE(108);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 99:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == 'f') ADVANCE(68);
+      if (lookahead == 'n') ADVANCE(66);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 100:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == 'h') ADVANCE(68);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 101:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == 'h') ADVANCE(96);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 102:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == 'i') ADVANCE(112);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 103:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == 'i') ADVANCE(113);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 104:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == 'l') ADVANCE(111);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 105:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == 'n') ADVANCE(68);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 106:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == 'r') ADVANCE(112);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 107:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == 'r') ADVANCE(154);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 108:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == 'r') ADVANCE(102);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 109:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == 's') ADVANCE(110);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 110:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == 's') ADVANCE(97);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 111:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == 's') ADVANCE(93);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 112:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == 't') ADVANCE(68);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 113:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == 't') ADVANCE(100);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 114:
+      ACCEPT_TOKEN(aux_sym_identifier_token1);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 115:
+      ACCEPT_TOKEN(sym_integer_expression);
+      if (lookahead == '.') ADVANCE(118);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(115);
+      END_STATE();
+    case 116:
+      ACCEPT_TOKEN(sym_integer_expression);
+      if (lookahead == '.') ADVANCE(47);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(117);
+      END_STATE();
+    case 117:
+      ACCEPT_TOKEN(sym_integer_expression);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(117);
+      END_STATE();
+    case 118:
+      ACCEPT_TOKEN(sym_float_expression);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(46);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(118);
+      END_STATE();
+    case 119:
+      ACCEPT_TOKEN(sym_float_expression);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(119);
+      END_STATE();
+    case 120:
+      ACCEPT_TOKEN(sym__hpath_start);
+      if (lookahead == '+' ||
+          ('-' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(120);
+      END_STATE();
+    case 121:
+      ACCEPT_TOKEN(sym_spath_expression);
+      END_STATE();
+    case 122:
+      ACCEPT_TOKEN(sym_uri_expression);
+      if (lookahead == '!' ||
+          ('$' <= lookahead && lookahead <= '\'') ||
+          ('*' <= lookahead && lookahead <= ':') ||
+          lookahead == '=' ||
+          ('?' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z') ||
+          lookahead == '~') ADVANCE(122);
+      END_STATE();
+    case 123:
+      ACCEPT_TOKEN(anon_sym_COLON);
+      END_STATE();
+    case 124:
+      ACCEPT_TOKEN(anon_sym_AT);
+      END_STATE();
+    case 125:
+      ACCEPT_TOKEN(anon_sym_LBRACE);
+      END_STATE();
+    case 126:
+      ACCEPT_TOKEN(anon_sym_RBRACE);
+      END_STATE();
+    case 127:
+      ACCEPT_TOKEN(anon_sym_COMMA);
+      END_STATE();
+    case 128:
+      ACCEPT_TOKEN(anon_sym_QMARK);
+      END_STATE();
+    case 129:
+      ACCEPT_TOKEN(sym_ellipses);
+      END_STATE();
+    case 130:
+      ACCEPT_TOKEN(anon_sym_SEMI);
+      END_STATE();
+    case 131:
+      ACCEPT_TOKEN(anon_sym_BANG);
+      END_STATE();
+    case 132:
+      ACCEPT_TOKEN(anon_sym_BANG);
+      if (lookahead == '=') ADVANCE(136);
+      END_STATE();
+    case 133:
+      ACCEPT_TOKEN(anon_sym_DASH);
+      END_STATE();
+    case 134:
+      ACCEPT_TOKEN(anon_sym_DASH);
+      if (lookahead == '>') ADVANCE(147);
+      END_STATE();
+    case 135:
+      ACCEPT_TOKEN(anon_sym_EQ_EQ);
+      END_STATE();
+    case 136:
+      ACCEPT_TOKEN(anon_sym_BANG_EQ);
+      END_STATE();
+    case 137:
+      ACCEPT_TOKEN(anon_sym_LT);
+      if (lookahead == '=') ADVANCE(139);
+      END_STATE();
+    case 138:
+      ACCEPT_TOKEN(anon_sym_LT);
+      if (lookahead == '=') ADVANCE(139);
+      if (lookahead == '+' ||
+          lookahead == '-' ||
+          lookahead == '.' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(18);
+      END_STATE();
+    case 139:
+      ACCEPT_TOKEN(anon_sym_LT_EQ);
+      END_STATE();
+    case 140:
+      ACCEPT_TOKEN(anon_sym_GT);
+      if (lookahead == '=') ADVANCE(141);
+      END_STATE();
+    case 141:
+      ACCEPT_TOKEN(anon_sym_GT_EQ);
+      END_STATE();
+    case 142:
+      ACCEPT_TOKEN(anon_sym_AMP_AMP);
+      END_STATE();
+    case 143:
+      ACCEPT_TOKEN(anon_sym_PIPE_PIPE);
+      END_STATE();
+    case 144:
+      ACCEPT_TOKEN(anon_sym_PLUS);
+      if (lookahead == '+') ADVANCE(149);
+      END_STATE();
+    case 145:
+      ACCEPT_TOKEN(anon_sym_STAR);
+      END_STATE();
+    case 146:
+      ACCEPT_TOKEN(anon_sym_SLASH);
+      if (lookahead == '*') ADVANCE(11);
+      if (lookahead == '/') ADVANCE(148);
+      END_STATE();
+    case 147:
+      ACCEPT_TOKEN(anon_sym_DASH_GT);
+      END_STATE();
+    case 148:
+      ACCEPT_TOKEN(anon_sym_SLASH_SLASH);
+      END_STATE();
+    case 149:
+      ACCEPT_TOKEN(anon_sym_PLUS_PLUS);
+      END_STATE();
+    case 150:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      END_STATE();
+    case 151:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      if (lookahead == '.') ADVANCE(13);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(118);
+      END_STATE();
+    case 152:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(118);
+      END_STATE();
+    case 153:
+      ACCEPT_TOKEN(anon_sym_or);
+      if (lookahead == ':') ADVANCE(51);
+      if (lookahead == '\'' ||
+          lookahead == '_') ADVANCE(114);
+      if (lookahead == '+' ||
+          lookahead == '.') ADVANCE(19);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(91);
+      END_STATE();
+    case 154:
+      ACCEPT_TOKEN(anon_sym_or);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 155:
+      ACCEPT_TOKEN(anon_sym_LPAREN);
+      END_STATE();
+    case 156:
+      ACCEPT_TOKEN(anon_sym_RPAREN);
+      END_STATE();
+    case 157:
+      ACCEPT_TOKEN(anon_sym_DQUOTE);
+      END_STATE();
+    case 158:
+      ACCEPT_TOKEN(anon_sym_DOLLAR);
+      END_STATE();
+    case 159:
+      ACCEPT_TOKEN(anon_sym_DOLLAR);
+      if (lookahead == '{') ADVANCE(167);
+      END_STATE();
+    case 160:
+      ACCEPT_TOKEN(anon_sym_DOLLAR);
+      if (lookahead == '{') ADVANCE(168);
+      END_STATE();
+    case 161:
+      ACCEPT_TOKEN(sym_escape_sequence);
+      END_STATE();
+    case 162:
+      ACCEPT_TOKEN(anon_sym_SQUOTE_SQUOTE);
+      END_STATE();
+    case 163:
+      ACCEPT_TOKEN(anon_sym_SQUOTE_SQUOTE);
+      if (lookahead == '\'') ADVANCE(164);
+      if (lookahead == '\\') ADVANCE(53);
+      END_STATE();
+    case 164:
+      ACCEPT_TOKEN(sym__indented_escape_sequence);
+      END_STATE();
+    case 165:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      END_STATE();
+    case 166:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      if (lookahead == '=') ADVANCE(135);
+      END_STATE();
+    case 167:
+      ACCEPT_TOKEN(anon_sym_DOLLAR_LBRACE);
+      END_STATE();
+    case 168:
+      ACCEPT_TOKEN(anon_sym_DOLLAR_LBRACE2);
+      END_STATE();
+    case 169:
+      ACCEPT_TOKEN(anon_sym_LBRACK);
+      END_STATE();
+    case 170:
+      ACCEPT_TOKEN(anon_sym_RBRACK);
+      END_STATE();
+    case 171:
+      ACCEPT_TOKEN(sym_comment);
+      END_STATE();
+    case 172:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(172);
+      END_STATE();
+    case 173:
+      ACCEPT_TOKEN(sym_grit_metavariable);
+      END_STATE();
+    case 174:
+      ACCEPT_TOKEN(sym_grit_metavariable);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(174);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static bool ts_lex_keywords(TSLexer *lexer, TSStateId state) {
+  START_LEXER();
+  eof = lexer->eof(lexer);
+  switch (state) {
+    case 0:
+      if (lookahead == 'a') ADVANCE(1);
+      if (lookahead == 'e') ADVANCE(2);
+      if (lookahead == 'i') ADVANCE(3);
+      if (lookahead == 'l') ADVANCE(4);
+      if (lookahead == 'r') ADVANCE(5);
+      if (lookahead == 't') ADVANCE(6);
+      if (lookahead == 'w') ADVANCE(7);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(0);
+      END_STATE();
+    case 1:
+      if (lookahead == 's') ADVANCE(8);
+      END_STATE();
+    case 2:
+      if (lookahead == 'l') ADVANCE(9);
+      END_STATE();
+    case 3:
+      if (lookahead == 'f') ADVANCE(10);
+      if (lookahead == 'n') ADVANCE(11);
+      END_STATE();
+    case 4:
+      if (lookahead == 'e') ADVANCE(12);
+      END_STATE();
+    case 5:
+      if (lookahead == 'e') ADVANCE(13);
+      END_STATE();
+    case 6:
+      if (lookahead == 'h') ADVANCE(14);
+      END_STATE();
+    case 7:
+      if (lookahead == 'i') ADVANCE(15);
+      END_STATE();
+    case 8:
+      if (lookahead == 's') ADVANCE(16);
+      END_STATE();
+    case 9:
+      if (lookahead == 's') ADVANCE(17);
+      END_STATE();
+    case 10:
+      ACCEPT_TOKEN(anon_sym_if);
+      END_STATE();
+    case 11:
+      ACCEPT_TOKEN(anon_sym_in);
+      if (lookahead == 'h') ADVANCE(18);
+      END_STATE();
+    case 12:
+      if (lookahead == 't') ADVANCE(19);
+      END_STATE();
+    case 13:
+      if (lookahead == 'c') ADVANCE(20);
+      END_STATE();
+    case 14:
+      if (lookahead == 'e') ADVANCE(21);
+      END_STATE();
+    case 15:
+      if (lookahead == 't') ADVANCE(22);
+      END_STATE();
+    case 16:
+      if (lookahead == 'e') ADVANCE(23);
+      END_STATE();
+    case 17:
+      if (lookahead == 'e') ADVANCE(24);
+      END_STATE();
+    case 18:
+      if (lookahead == 'e') ADVANCE(25);
+      END_STATE();
+    case 19:
+      ACCEPT_TOKEN(anon_sym_let);
+      END_STATE();
+    case 20:
+      ACCEPT_TOKEN(anon_sym_rec);
+      END_STATE();
+    case 21:
+      if (lookahead == 'n') ADVANCE(26);
+      END_STATE();
+    case 22:
+      if (lookahead == 'h') ADVANCE(27);
+      END_STATE();
+    case 23:
+      if (lookahead == 'r') ADVANCE(28);
+      END_STATE();
+    case 24:
+      ACCEPT_TOKEN(anon_sym_else);
+      END_STATE();
+    case 25:
+      if (lookahead == 'r') ADVANCE(29);
+      END_STATE();
+    case 26:
+      ACCEPT_TOKEN(anon_sym_then);
+      END_STATE();
+    case 27:
+      ACCEPT_TOKEN(anon_sym_with);
+      END_STATE();
+    case 28:
+      if (lookahead == 't') ADVANCE(30);
+      END_STATE();
+    case 29:
+      if (lookahead == 'i') ADVANCE(31);
+      END_STATE();
+    case 30:
+      ACCEPT_TOKEN(anon_sym_assert);
+      END_STATE();
+    case 31:
+      if (lookahead == 't') ADVANCE(32);
+      END_STATE();
+    case 32:
+      ACCEPT_TOKEN(anon_sym_inherit);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static const TSLexMode ts_lex_modes[STATE_COUNT] = {
+  [0] = {.lex_state = 0, .external_lex_state = 1},
+  [1] = {.lex_state = 55, .external_lex_state = 2},
+  [2] = {.lex_state = 55, .external_lex_state = 2},
+  [3] = {.lex_state = 55, .external_lex_state = 2},
+  [4] = {.lex_state = 55, .external_lex_state = 2},
+  [5] = {.lex_state = 55, .external_lex_state = 2},
+  [6] = {.lex_state = 55, .external_lex_state = 2},
+  [7] = {.lex_state = 55, .external_lex_state = 2},
+  [8] = {.lex_state = 55, .external_lex_state = 2},
+  [9] = {.lex_state = 55, .external_lex_state = 2},
+  [10] = {.lex_state = 55, .external_lex_state = 2},
+  [11] = {.lex_state = 55, .external_lex_state = 2},
+  [12] = {.lex_state = 58, .external_lex_state = 2},
+  [13] = {.lex_state = 55, .external_lex_state = 2},
+  [14] = {.lex_state = 55, .external_lex_state = 2},
+  [15] = {.lex_state = 55, .external_lex_state = 2},
+  [16] = {.lex_state = 55, .external_lex_state = 2},
+  [17] = {.lex_state = 55, .external_lex_state = 2},
+  [18] = {.lex_state = 55, .external_lex_state = 2},
+  [19] = {.lex_state = 55, .external_lex_state = 2},
+  [20] = {.lex_state = 55, .external_lex_state = 2},
+  [21] = {.lex_state = 55, .external_lex_state = 2},
+  [22] = {.lex_state = 55, .external_lex_state = 2},
+  [23] = {.lex_state = 55, .external_lex_state = 2},
+  [24] = {.lex_state = 55, .external_lex_state = 2},
+  [25] = {.lex_state = 55, .external_lex_state = 2},
+  [26] = {.lex_state = 55, .external_lex_state = 2},
+  [27] = {.lex_state = 55, .external_lex_state = 2},
+  [28] = {.lex_state = 55, .external_lex_state = 2},
+  [29] = {.lex_state = 55, .external_lex_state = 2},
+  [30] = {.lex_state = 55, .external_lex_state = 2},
+  [31] = {.lex_state = 55, .external_lex_state = 2},
+  [32] = {.lex_state = 55, .external_lex_state = 2},
+  [33] = {.lex_state = 55, .external_lex_state = 2},
+  [34] = {.lex_state = 55, .external_lex_state = 2},
+  [35] = {.lex_state = 55, .external_lex_state = 2},
+  [36] = {.lex_state = 55, .external_lex_state = 2},
+  [37] = {.lex_state = 55, .external_lex_state = 2},
+  [38] = {.lex_state = 55, .external_lex_state = 2},
+  [39] = {.lex_state = 55, .external_lex_state = 2},
+  [40] = {.lex_state = 55, .external_lex_state = 2},
+  [41] = {.lex_state = 55, .external_lex_state = 2},
+  [42] = {.lex_state = 55, .external_lex_state = 2},
+  [43] = {.lex_state = 55, .external_lex_state = 2},
+  [44] = {.lex_state = 55, .external_lex_state = 2},
+  [45] = {.lex_state = 55, .external_lex_state = 2},
+  [46] = {.lex_state = 55, .external_lex_state = 2},
+  [47] = {.lex_state = 55, .external_lex_state = 2},
+  [48] = {.lex_state = 55, .external_lex_state = 2},
+  [49] = {.lex_state = 55, .external_lex_state = 2},
+  [50] = {.lex_state = 55, .external_lex_state = 2},
+  [51] = {.lex_state = 55, .external_lex_state = 2},
+  [52] = {.lex_state = 55, .external_lex_state = 2},
+  [53] = {.lex_state = 55, .external_lex_state = 2},
+  [54] = {.lex_state = 55, .external_lex_state = 2},
+  [55] = {.lex_state = 55, .external_lex_state = 2},
+  [56] = {.lex_state = 55, .external_lex_state = 2},
+  [57] = {.lex_state = 55, .external_lex_state = 2},
+  [58] = {.lex_state = 55, .external_lex_state = 2},
+  [59] = {.lex_state = 55, .external_lex_state = 2},
+  [60] = {.lex_state = 55, .external_lex_state = 2},
+  [61] = {.lex_state = 55, .external_lex_state = 2},
+  [62] = {.lex_state = 55, .external_lex_state = 2},
+  [63] = {.lex_state = 55, .external_lex_state = 2},
+  [64] = {.lex_state = 55, .external_lex_state = 2},
+  [65] = {.lex_state = 55, .external_lex_state = 2},
+  [66] = {.lex_state = 55, .external_lex_state = 2},
+  [67] = {.lex_state = 55, .external_lex_state = 2},
+  [68] = {.lex_state = 55, .external_lex_state = 2},
+  [69] = {.lex_state = 55, .external_lex_state = 2},
+  [70] = {.lex_state = 55, .external_lex_state = 2},
+  [71] = {.lex_state = 55, .external_lex_state = 2},
+  [72] = {.lex_state = 55, .external_lex_state = 2},
+  [73] = {.lex_state = 55, .external_lex_state = 2},
+  [74] = {.lex_state = 55, .external_lex_state = 2},
+  [75] = {.lex_state = 55, .external_lex_state = 2},
+  [76] = {.lex_state = 58, .external_lex_state = 2},
+  [77] = {.lex_state = 58, .external_lex_state = 2},
+  [78] = {.lex_state = 58, .external_lex_state = 2},
+  [79] = {.lex_state = 58, .external_lex_state = 3},
+  [80] = {.lex_state = 58, .external_lex_state = 3},
+  [81] = {.lex_state = 58, .external_lex_state = 3},
+  [82] = {.lex_state = 58, .external_lex_state = 3},
+  [83] = {.lex_state = 58, .external_lex_state = 3},
+  [84] = {.lex_state = 57, .external_lex_state = 2},
+  [85] = {.lex_state = 58, .external_lex_state = 3},
+  [86] = {.lex_state = 58, .external_lex_state = 3},
+  [87] = {.lex_state = 57, .external_lex_state = 2},
+  [88] = {.lex_state = 58, .external_lex_state = 2},
+  [89] = {.lex_state = 57, .external_lex_state = 2},
+  [90] = {.lex_state = 58, .external_lex_state = 2},
+  [91] = {.lex_state = 58, .external_lex_state = 2},
+  [92] = {.lex_state = 57, .external_lex_state = 2},
+  [93] = {.lex_state = 57, .external_lex_state = 2},
+  [94] = {.lex_state = 57, .external_lex_state = 2},
+  [95] = {.lex_state = 57, .external_lex_state = 2},
+  [96] = {.lex_state = 57, .external_lex_state = 2},
+  [97] = {.lex_state = 55, .external_lex_state = 2},
+  [98] = {.lex_state = 55, .external_lex_state = 2},
+  [99] = {.lex_state = 58, .external_lex_state = 3},
+  [100] = {.lex_state = 58, .external_lex_state = 3},
+  [101] = {.lex_state = 55, .external_lex_state = 2},
+  [102] = {.lex_state = 55, .external_lex_state = 2},
+  [103] = {.lex_state = 58, .external_lex_state = 2},
+  [104] = {.lex_state = 58, .external_lex_state = 3},
+  [105] = {.lex_state = 55, .external_lex_state = 2},
+  [106] = {.lex_state = 55, .external_lex_state = 2},
+  [107] = {.lex_state = 55, .external_lex_state = 2},
+  [108] = {.lex_state = 55, .external_lex_state = 2},
+  [109] = {.lex_state = 55, .external_lex_state = 2},
+  [110] = {.lex_state = 55, .external_lex_state = 2},
+  [111] = {.lex_state = 55, .external_lex_state = 2},
+  [112] = {.lex_state = 55, .external_lex_state = 2},
+  [113] = {.lex_state = 55, .external_lex_state = 2},
+  [114] = {.lex_state = 55, .external_lex_state = 2},
+  [115] = {.lex_state = 58, .external_lex_state = 3},
+  [116] = {.lex_state = 55, .external_lex_state = 2},
+  [117] = {.lex_state = 55, .external_lex_state = 2},
+  [118] = {.lex_state = 55, .external_lex_state = 2},
+  [119] = {.lex_state = 58, .external_lex_state = 2},
+  [120] = {.lex_state = 58, .external_lex_state = 3},
+  [121] = {.lex_state = 58, .external_lex_state = 2},
+  [122] = {.lex_state = 58, .external_lex_state = 2},
+  [123] = {.lex_state = 58, .external_lex_state = 3},
+  [124] = {.lex_state = 58, .external_lex_state = 3},
+  [125] = {.lex_state = 55, .external_lex_state = 2},
+  [126] = {.lex_state = 55, .external_lex_state = 2},
+  [127] = {.lex_state = 58, .external_lex_state = 2},
+  [128] = {.lex_state = 58, .external_lex_state = 3},
+  [129] = {.lex_state = 58, .external_lex_state = 3},
+  [130] = {.lex_state = 58, .external_lex_state = 2},
+  [131] = {.lex_state = 58, .external_lex_state = 2},
+  [132] = {.lex_state = 58, .external_lex_state = 2},
+  [133] = {.lex_state = 55, .external_lex_state = 2},
+  [134] = {.lex_state = 55, .external_lex_state = 2},
+  [135] = {.lex_state = 55, .external_lex_state = 2},
+  [136] = {.lex_state = 55, .external_lex_state = 2},
+  [137] = {.lex_state = 55, .external_lex_state = 2},
+  [138] = {.lex_state = 55, .external_lex_state = 2},
+  [139] = {.lex_state = 55, .external_lex_state = 2},
+  [140] = {.lex_state = 55, .external_lex_state = 2},
+  [141] = {.lex_state = 55, .external_lex_state = 2},
+  [142] = {.lex_state = 55, .external_lex_state = 2},
+  [143] = {.lex_state = 58, .external_lex_state = 2},
+  [144] = {.lex_state = 58, .external_lex_state = 2},
+  [145] = {.lex_state = 58, .external_lex_state = 2},
+  [146] = {.lex_state = 58, .external_lex_state = 2},
+  [147] = {.lex_state = 57, .external_lex_state = 2},
+  [148] = {.lex_state = 55, .external_lex_state = 2},
+  [149] = {.lex_state = 58, .external_lex_state = 2},
+  [150] = {.lex_state = 58, .external_lex_state = 2},
+  [151] = {.lex_state = 58, .external_lex_state = 2},
+  [152] = {.lex_state = 58, .external_lex_state = 3},
+  [153] = {.lex_state = 55, .external_lex_state = 2},
+  [154] = {.lex_state = 55, .external_lex_state = 2},
+  [155] = {.lex_state = 55, .external_lex_state = 2},
+  [156] = {.lex_state = 55, .external_lex_state = 2},
+  [157] = {.lex_state = 55, .external_lex_state = 2},
+  [158] = {.lex_state = 55, .external_lex_state = 2},
+  [159] = {.lex_state = 55, .external_lex_state = 2},
+  [160] = {.lex_state = 55, .external_lex_state = 2},
+  [161] = {.lex_state = 55, .external_lex_state = 2},
+  [162] = {.lex_state = 55, .external_lex_state = 2},
+  [163] = {.lex_state = 58, .external_lex_state = 3},
+  [164] = {.lex_state = 55, .external_lex_state = 2},
+  [165] = {.lex_state = 58, .external_lex_state = 3},
+  [166] = {.lex_state = 58, .external_lex_state = 3},
+  [167] = {.lex_state = 55, .external_lex_state = 2},
+  [168] = {.lex_state = 58, .external_lex_state = 3},
+  [169] = {.lex_state = 55, .external_lex_state = 2},
+  [170] = {.lex_state = 55, .external_lex_state = 2},
+  [171] = {.lex_state = 58, .external_lex_state = 3},
+  [172] = {.lex_state = 58, .external_lex_state = 2},
+  [173] = {.lex_state = 58, .external_lex_state = 2},
+  [174] = {.lex_state = 57, .external_lex_state = 2},
+  [175] = {.lex_state = 58, .external_lex_state = 2},
+  [176] = {.lex_state = 58, .external_lex_state = 2},
+  [177] = {.lex_state = 57, .external_lex_state = 2},
+  [178] = {.lex_state = 58, .external_lex_state = 2},
+  [179] = {.lex_state = 57, .external_lex_state = 2},
+  [180] = {.lex_state = 58, .external_lex_state = 2},
+  [181] = {.lex_state = 57, .external_lex_state = 2},
+  [182] = {.lex_state = 57, .external_lex_state = 2},
+  [183] = {.lex_state = 58, .external_lex_state = 2},
+  [184] = {.lex_state = 58, .external_lex_state = 3},
+  [185] = {.lex_state = 57, .external_lex_state = 2},
+  [186] = {.lex_state = 58, .external_lex_state = 3},
+  [187] = {.lex_state = 58, .external_lex_state = 3},
+  [188] = {.lex_state = 58, .external_lex_state = 2},
+  [189] = {.lex_state = 58, .external_lex_state = 2},
+  [190] = {.lex_state = 57, .external_lex_state = 2},
+  [191] = {.lex_state = 58, .external_lex_state = 2},
+  [192] = {.lex_state = 58, .external_lex_state = 3},
+  [193] = {.lex_state = 57, .external_lex_state = 2},
+  [194] = {.lex_state = 58, .external_lex_state = 3},
+  [195] = {.lex_state = 58, .external_lex_state = 3},
+  [196] = {.lex_state = 58, .external_lex_state = 2},
+  [197] = {.lex_state = 57, .external_lex_state = 2},
+  [198] = {.lex_state = 57, .external_lex_state = 2},
+  [199] = {.lex_state = 57, .external_lex_state = 2},
+  [200] = {.lex_state = 57, .external_lex_state = 2},
+  [201] = {.lex_state = 57, .external_lex_state = 2},
+  [202] = {.lex_state = 57, .external_lex_state = 2},
+  [203] = {.lex_state = 57, .external_lex_state = 2},
+  [204] = {.lex_state = 57, .external_lex_state = 2},
+  [205] = {.lex_state = 57, .external_lex_state = 2},
+  [206] = {.lex_state = 57, .external_lex_state = 2},
+  [207] = {.lex_state = 57, .external_lex_state = 2},
+  [208] = {.lex_state = 57, .external_lex_state = 2},
+  [209] = {.lex_state = 57, .external_lex_state = 2},
+  [210] = {.lex_state = 57, .external_lex_state = 2},
+  [211] = {.lex_state = 57, .external_lex_state = 2},
+  [212] = {.lex_state = 57, .external_lex_state = 2},
+  [213] = {.lex_state = 58, .external_lex_state = 2},
+  [214] = {.lex_state = 58, .external_lex_state = 2},
+  [215] = {.lex_state = 58, .external_lex_state = 2},
+  [216] = {.lex_state = 58, .external_lex_state = 2},
+  [217] = {.lex_state = 58, .external_lex_state = 2},
+  [218] = {.lex_state = 58, .external_lex_state = 2},
+  [219] = {.lex_state = 58, .external_lex_state = 2},
+  [220] = {.lex_state = 58, .external_lex_state = 2},
+  [221] = {.lex_state = 58, .external_lex_state = 2},
+  [222] = {.lex_state = 58, .external_lex_state = 2},
+  [223] = {.lex_state = 58, .external_lex_state = 2},
+  [224] = {.lex_state = 58, .external_lex_state = 2},
+  [225] = {.lex_state = 58, .external_lex_state = 2},
+  [226] = {.lex_state = 58, .external_lex_state = 2},
+  [227] = {.lex_state = 58, .external_lex_state = 2},
+  [228] = {.lex_state = 58, .external_lex_state = 2},
+  [229] = {.lex_state = 58, .external_lex_state = 2},
+  [230] = {.lex_state = 58, .external_lex_state = 2},
+  [231] = {.lex_state = 58, .external_lex_state = 2},
+  [232] = {.lex_state = 58, .external_lex_state = 2},
+  [233] = {.lex_state = 58, .external_lex_state = 2},
+  [234] = {.lex_state = 57, .external_lex_state = 2},
+  [235] = {.lex_state = 58, .external_lex_state = 2},
+  [236] = {.lex_state = 58, .external_lex_state = 2},
+  [237] = {.lex_state = 57, .external_lex_state = 2},
+  [238] = {.lex_state = 58, .external_lex_state = 2},
+  [239] = {.lex_state = 58, .external_lex_state = 2},
+  [240] = {.lex_state = 58, .external_lex_state = 2},
+  [241] = {.lex_state = 58, .external_lex_state = 2},
+  [242] = {.lex_state = 58, .external_lex_state = 2},
+  [243] = {.lex_state = 58, .external_lex_state = 2},
+  [244] = {.lex_state = 58, .external_lex_state = 2},
+  [245] = {.lex_state = 58, .external_lex_state = 2},
+  [246] = {.lex_state = 58, .external_lex_state = 2},
+  [247] = {.lex_state = 58, .external_lex_state = 2},
+  [248] = {.lex_state = 58, .external_lex_state = 2},
+  [249] = {.lex_state = 58, .external_lex_state = 2},
+  [250] = {.lex_state = 58, .external_lex_state = 2},
+  [251] = {.lex_state = 58, .external_lex_state = 2},
+  [252] = {.lex_state = 57, .external_lex_state = 2},
+  [253] = {.lex_state = 58, .external_lex_state = 2},
+  [254] = {.lex_state = 58, .external_lex_state = 2},
+  [255] = {.lex_state = 58, .external_lex_state = 2},
+  [256] = {.lex_state = 58, .external_lex_state = 2},
+  [257] = {.lex_state = 58, .external_lex_state = 2},
+  [258] = {.lex_state = 58, .external_lex_state = 2},
+  [259] = {.lex_state = 58, .external_lex_state = 2},
+  [260] = {.lex_state = 58, .external_lex_state = 2},
+  [261] = {.lex_state = 58, .external_lex_state = 2},
+  [262] = {.lex_state = 58, .external_lex_state = 2},
+  [263] = {.lex_state = 58, .external_lex_state = 2},
+  [264] = {.lex_state = 58, .external_lex_state = 2},
+  [265] = {.lex_state = 58, .external_lex_state = 2},
+  [266] = {.lex_state = 58, .external_lex_state = 2},
+  [267] = {.lex_state = 55, .external_lex_state = 2},
+  [268] = {.lex_state = 55, .external_lex_state = 2},
+  [269] = {.lex_state = 55, .external_lex_state = 2},
+  [270] = {.lex_state = 55, .external_lex_state = 2},
+  [271] = {.lex_state = 55, .external_lex_state = 2},
+  [272] = {.lex_state = 55, .external_lex_state = 2},
+  [273] = {.lex_state = 55, .external_lex_state = 2},
+  [274] = {.lex_state = 55, .external_lex_state = 2},
+  [275] = {.lex_state = 55, .external_lex_state = 2},
+  [276] = {.lex_state = 55, .external_lex_state = 2},
+  [277] = {.lex_state = 55, .external_lex_state = 2},
+  [278] = {.lex_state = 55, .external_lex_state = 2},
+  [279] = {.lex_state = 55, .external_lex_state = 2},
+  [280] = {.lex_state = 1},
+  [281] = {.lex_state = 1},
+  [282] = {.lex_state = 1},
+  [283] = {.lex_state = 1},
+  [284] = {.lex_state = 60},
+  [285] = {.lex_state = 61},
+  [286] = {.lex_state = 61},
+  [287] = {.lex_state = 1},
+  [288] = {.lex_state = 1},
+  [289] = {.lex_state = 61},
+  [290] = {.lex_state = 61},
+  [291] = {.lex_state = 61},
+  [292] = {.lex_state = 61},
+  [293] = {.lex_state = 61},
+  [294] = {.lex_state = 61},
+  [295] = {.lex_state = 61},
+  [296] = {.lex_state = 61},
+  [297] = {.lex_state = 61},
+  [298] = {.lex_state = 61},
+  [299] = {.lex_state = 61},
+  [300] = {.lex_state = 61},
+  [301] = {.lex_state = 61},
+  [302] = {.lex_state = 61},
+  [303] = {.lex_state = 61},
+  [304] = {.lex_state = 61},
+  [305] = {.lex_state = 61},
+  [306] = {.lex_state = 61},
+  [307] = {.lex_state = 61},
+  [308] = {.lex_state = 61},
+  [309] = {.lex_state = 61},
+  [310] = {.lex_state = 61},
+  [311] = {.lex_state = 4},
+  [312] = {.lex_state = 61},
+  [313] = {.lex_state = 61},
+  [314] = {.lex_state = 61},
+  [315] = {.lex_state = 61},
+  [316] = {.lex_state = 61},
+  [317] = {.lex_state = 61},
+  [318] = {.lex_state = 61},
+  [319] = {.lex_state = 61},
+  [320] = {.lex_state = 4},
+  [321] = {.lex_state = 61},
+  [322] = {.lex_state = 61},
+  [323] = {.lex_state = 61},
+  [324] = {.lex_state = 61},
+  [325] = {.lex_state = 61},
+  [326] = {.lex_state = 61},
+  [327] = {.lex_state = 61},
+  [328] = {.lex_state = 4},
+  [329] = {.lex_state = 61},
+  [330] = {.lex_state = 61},
+  [331] = {.lex_state = 61},
+  [332] = {.lex_state = 61},
+  [333] = {.lex_state = 61},
+  [334] = {.lex_state = 4},
+  [335] = {.lex_state = 61},
+  [336] = {.lex_state = 61},
+  [337] = {.lex_state = 61},
+  [338] = {.lex_state = 61},
+  [339] = {.lex_state = 61},
+  [340] = {.lex_state = 61},
+  [341] = {.lex_state = 61},
+  [342] = {.lex_state = 61},
+  [343] = {.lex_state = 61},
+  [344] = {.lex_state = 61},
+  [345] = {.lex_state = 4},
+  [346] = {.lex_state = 55, .external_lex_state = 2},
+  [347] = {.lex_state = 4},
+  [348] = {.lex_state = 4},
+  [349] = {.lex_state = 4},
+  [350] = {.lex_state = 4},
+  [351] = {.lex_state = 4},
+  [352] = {.lex_state = 4},
+  [353] = {.lex_state = 4},
+  [354] = {.lex_state = 4},
+  [355] = {.lex_state = 4},
+  [356] = {.lex_state = 4},
+  [357] = {.lex_state = 4},
+  [358] = {.lex_state = 4},
+  [359] = {.lex_state = 4},
+  [360] = {.lex_state = 4},
+  [361] = {.lex_state = 4},
+  [362] = {.lex_state = 4},
+  [363] = {.lex_state = 4},
+  [364] = {.lex_state = 4},
+  [365] = {.lex_state = 4},
+  [366] = {.lex_state = 3},
+  [367] = {.lex_state = 1},
+  [368] = {.lex_state = 1},
+  [369] = {.lex_state = 1},
+  [370] = {.lex_state = 1},
+  [371] = {.lex_state = 1},
+  [372] = {.lex_state = 1},
+  [373] = {.lex_state = 1, .external_lex_state = 4},
+  [374] = {.lex_state = 3},
+  [375] = {.lex_state = 55, .external_lex_state = 5},
+  [376] = {.lex_state = 1, .external_lex_state = 4},
+  [377] = {.lex_state = 3},
+  [378] = {.lex_state = 55, .external_lex_state = 5},
+  [379] = {.lex_state = 1},
+  [380] = {.lex_state = 1},
+  [381] = {.lex_state = 1},
+  [382] = {.lex_state = 55, .external_lex_state = 5},
+  [383] = {.lex_state = 1, .external_lex_state = 4},
+  [384] = {.lex_state = 55, .external_lex_state = 5},
+  [385] = {.lex_state = 1, .external_lex_state = 4},
+  [386] = {.lex_state = 1},
+  [387] = {.lex_state = 55, .external_lex_state = 5},
+  [388] = {.lex_state = 55, .external_lex_state = 5},
+  [389] = {.lex_state = 1, .external_lex_state = 4},
+  [390] = {.lex_state = 1, .external_lex_state = 4},
+  [391] = {.lex_state = 55, .external_lex_state = 5},
+  [392] = {.lex_state = 1, .external_lex_state = 4},
+  [393] = {.lex_state = 1},
+  [394] = {.lex_state = 55, .external_lex_state = 5},
+  [395] = {.lex_state = 55, .external_lex_state = 5},
+  [396] = {.lex_state = 55, .external_lex_state = 5},
+  [397] = {.lex_state = 55, .external_lex_state = 5},
+  [398] = {.lex_state = 55, .external_lex_state = 5},
+  [399] = {.lex_state = 55, .external_lex_state = 5},
+  [400] = {.lex_state = 55, .external_lex_state = 5},
+  [401] = {.lex_state = 55, .external_lex_state = 5},
+  [402] = {.lex_state = 55, .external_lex_state = 5},
+  [403] = {.lex_state = 55, .external_lex_state = 5},
+  [404] = {.lex_state = 55, .external_lex_state = 5},
+  [405] = {.lex_state = 55, .external_lex_state = 5},
+  [406] = {.lex_state = 55, .external_lex_state = 5},
+  [407] = {.lex_state = 1, .external_lex_state = 4},
+  [408] = {.lex_state = 1},
+  [409] = {.lex_state = 55, .external_lex_state = 5},
+  [410] = {.lex_state = 1, .external_lex_state = 4},
+  [411] = {.lex_state = 60},
+  [412] = {.lex_state = 60},
+  [413] = {.lex_state = 1},
+  [414] = {.lex_state = 60},
+  [415] = {.lex_state = 1},
+  [416] = {.lex_state = 60},
+  [417] = {.lex_state = 1},
+  [418] = {.lex_state = 1},
+  [419] = {.lex_state = 1},
+  [420] = {.lex_state = 1},
+  [421] = {.lex_state = 60},
+  [422] = {.lex_state = 60},
+  [423] = {.lex_state = 60},
+  [424] = {.lex_state = 60},
+  [425] = {.lex_state = 60},
+  [426] = {.lex_state = 60},
+  [427] = {.lex_state = 4},
+  [428] = {.lex_state = 4},
+  [429] = {.lex_state = 55, .external_lex_state = 5},
+  [430] = {.lex_state = 55},
+  [431] = {.lex_state = 4},
+  [432] = {.lex_state = 1, .external_lex_state = 4},
+  [433] = {.lex_state = 1, .external_lex_state = 4},
+  [434] = {.lex_state = 4},
+  [435] = {.lex_state = 55, .external_lex_state = 5},
+  [436] = {.lex_state = 4},
+  [437] = {.lex_state = 4},
+  [438] = {.lex_state = 5},
+  [439] = {.lex_state = 4},
+  [440] = {.lex_state = 1, .external_lex_state = 4},
+  [441] = {.lex_state = 55, .external_lex_state = 5},
+  [442] = {.lex_state = 4},
+  [443] = {.lex_state = 1},
+  [444] = {.lex_state = 5},
+  [445] = {.lex_state = 5},
+  [446] = {.lex_state = 1},
+  [447] = {.lex_state = 0},
+  [448] = {.lex_state = 55},
+  [449] = {.lex_state = 0},
+  [450] = {.lex_state = 55},
+  [451] = {.lex_state = 1},
+  [452] = {.lex_state = 0},
+  [453] = {.lex_state = 1},
+  [454] = {.lex_state = 1},
+  [455] = {.lex_state = 1},
+  [456] = {.lex_state = 0},
+  [457] = {.lex_state = 0},
+  [458] = {.lex_state = 0},
+  [459] = {.lex_state = 0},
+  [460] = {.lex_state = 0},
+  [461] = {.lex_state = 0},
+  [462] = {.lex_state = 0},
+  [463] = {.lex_state = 0},
+  [464] = {.lex_state = 0},
+  [465] = {.lex_state = 0},
+  [466] = {.lex_state = 0},
+  [467] = {.lex_state = 0},
+  [468] = {.lex_state = 0},
+  [469] = {.lex_state = 0},
+  [470] = {.lex_state = 0},
+  [471] = {.lex_state = 0},
+  [472] = {.lex_state = 0},
+  [473] = {.lex_state = 0},
+  [474] = {.lex_state = 60},
+  [475] = {.lex_state = 0},
+  [476] = {.lex_state = 0},
+  [477] = {.lex_state = 0},
+  [478] = {.lex_state = 0},
+  [479] = {.lex_state = 0},
+  [480] = {.lex_state = 55},
+  [481] = {.lex_state = 0},
+  [482] = {.lex_state = 60},
+  [483] = {.lex_state = 0},
+  [484] = {.lex_state = 0},
+  [485] = {.lex_state = 0},
+  [486] = {.lex_state = 60},
+  [487] = {.lex_state = 0},
+  [488] = {.lex_state = 0},
+  [489] = {.lex_state = 0},
+  [490] = {.lex_state = 0},
+  [491] = {.lex_state = 60},
+  [492] = {.lex_state = 57},
+  [493] = {.lex_state = 0},
+  [494] = {.lex_state = 0},
+  [495] = {.lex_state = 0},
+  [496] = {.lex_state = 0},
+  [497] = {.lex_state = 0},
+  [498] = {.lex_state = 0},
+  [499] = {.lex_state = 0},
+  [500] = {.lex_state = 0},
+  [501] = {.lex_state = 0},
+  [502] = {.lex_state = 0},
+  [503] = {.lex_state = 0},
+  [504] = {.lex_state = 0},
+  [505] = {.lex_state = 0},
+  [506] = {.lex_state = 0},
+  [507] = {.lex_state = 0},
+  [508] = {.lex_state = 60},
+  [509] = {.lex_state = 0},
+  [510] = {.lex_state = 0},
+  [511] = {.lex_state = 0},
+  [512] = {.lex_state = 0},
+  [513] = {.lex_state = 0},
+  [514] = {.lex_state = 0},
+  [515] = {.lex_state = 0},
+  [516] = {.lex_state = 0},
+  [517] = {.lex_state = 0},
+  [518] = {.lex_state = 0},
+  [519] = {.lex_state = 57},
+  [520] = {.lex_state = 0},
+  [521] = {.lex_state = 0},
+  [522] = {.lex_state = 60},
+  [523] = {.lex_state = 0},
+  [524] = {.lex_state = 0},
+  [525] = {.lex_state = 0},
+  [526] = {.lex_state = 0},
+  [527] = {.lex_state = 0},
+  [528] = {.lex_state = 0},
+  [529] = {.lex_state = 60},
+  [530] = {.lex_state = 60},
+  [531] = {.lex_state = 0},
+  [532] = {.lex_state = 0},
+  [533] = {.lex_state = 0},
+  [534] = {.lex_state = 0},
+  [535] = {.lex_state = 0},
+  [536] = {.lex_state = 0},
+  [537] = {.lex_state = 0},
+  [538] = {.lex_state = 0},
+  [539] = {.lex_state = 0},
+  [540] = {.lex_state = 60},
+  [541] = {.lex_state = 0},
+  [542] = {.lex_state = 0},
+  [543] = {.lex_state = 0},
+  [544] = {.lex_state = 55},
+  [545] = {.lex_state = 60},
+  [546] = {.lex_state = 0},
+  [547] = {.lex_state = 60},
+  [548] = {.lex_state = 60},
+  [549] = {.lex_state = 0},
+  [550] = {.lex_state = 0},
+  [551] = {.lex_state = 0},
+  [552] = {.lex_state = 0},
+  [553] = {.lex_state = 0},
+};
+
+static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
+  [0] = {
+    [ts_builtin_sym_end] = ACTIONS(1),
+    [sym_keyword] = ACTIONS(1),
+    [aux_sym_identifier_token1] = ACTIONS(1),
+    [sym_integer_expression] = ACTIONS(1),
+    [sym_float_expression] = ACTIONS(1),
+    [sym__hpath_start] = ACTIONS(1),
+    [sym_spath_expression] = ACTIONS(1),
+    [anon_sym_COLON] = ACTIONS(1),
+    [anon_sym_AT] = ACTIONS(1),
+    [anon_sym_LBRACE] = ACTIONS(1),
+    [anon_sym_RBRACE] = ACTIONS(1),
+    [anon_sym_COMMA] = ACTIONS(1),
+    [anon_sym_QMARK] = ACTIONS(1),
+    [sym_ellipses] = ACTIONS(1),
+    [anon_sym_assert] = ACTIONS(1),
+    [anon_sym_SEMI] = ACTIONS(1),
+    [anon_sym_with] = ACTIONS(1),
+    [anon_sym_let] = ACTIONS(1),
+    [anon_sym_in] = ACTIONS(1),
+    [anon_sym_if] = ACTIONS(1),
+    [anon_sym_then] = ACTIONS(1),
+    [anon_sym_else] = ACTIONS(1),
+    [anon_sym_BANG] = ACTIONS(1),
+    [anon_sym_DASH] = ACTIONS(1),
+    [anon_sym_EQ_EQ] = ACTIONS(1),
+    [anon_sym_BANG_EQ] = ACTIONS(1),
+    [anon_sym_LT] = ACTIONS(1),
+    [anon_sym_LT_EQ] = ACTIONS(1),
+    [anon_sym_GT] = ACTIONS(1),
+    [anon_sym_GT_EQ] = ACTIONS(1),
+    [anon_sym_AMP_AMP] = ACTIONS(1),
+    [anon_sym_PIPE_PIPE] = ACTIONS(1),
+    [anon_sym_PLUS] = ACTIONS(1),
+    [anon_sym_STAR] = ACTIONS(1),
+    [anon_sym_SLASH] = ACTIONS(1),
+    [anon_sym_DASH_GT] = ACTIONS(1),
+    [anon_sym_SLASH_SLASH] = ACTIONS(1),
+    [anon_sym_PLUS_PLUS] = ACTIONS(1),
+    [anon_sym_DOT] = ACTIONS(1),
+    [anon_sym_or] = ACTIONS(1),
+    [anon_sym_LPAREN] = ACTIONS(1),
+    [anon_sym_RPAREN] = ACTIONS(1),
+    [anon_sym_rec] = ACTIONS(1),
+    [anon_sym_DQUOTE] = ACTIONS(1),
+    [anon_sym_DOLLAR] = ACTIONS(1),
+    [sym_escape_sequence] = ACTIONS(1),
+    [anon_sym_SQUOTE_SQUOTE] = ACTIONS(1),
+    [sym__indented_escape_sequence] = ACTIONS(1),
+    [anon_sym_EQ] = ACTIONS(1),
+    [anon_sym_inherit] = ACTIONS(1),
+    [anon_sym_DOLLAR_LBRACE] = ACTIONS(1),
+    [anon_sym_DOLLAR_LBRACE2] = ACTIONS(1),
+    [anon_sym_LBRACK] = ACTIONS(1),
+    [anon_sym_RBRACK] = ACTIONS(1),
+    [sym_comment] = ACTIONS(3),
+    [sym_grit_metavariable] = ACTIONS(1),
+    [sym_string_fragment] = ACTIONS(1),
+    [sym__indented_string_fragment] = ACTIONS(1),
+    [sym__path_start] = ACTIONS(1),
+    [sym_path_fragment] = ACTIONS(1),
+    [sym_dollar_escape] = ACTIONS(1),
+    [sym__indented_dollar_escape] = ACTIONS(1),
+  },
+  [1] = {
+    [sym_source_code] = STATE(549),
+    [sym__expression] = STATE(481),
+    [sym_identifier] = STATE(189),
+    [sym_variable_expression] = STATE(214),
+    [sym_path_expression] = STATE(214),
+    [sym_hpath_expression] = STATE(214),
+    [sym__expr_function_expression] = STATE(421),
+    [sym_function_expression] = STATE(421),
+    [sym_formals] = STATE(470),
+    [sym_assert_expression] = STATE(421),
+    [sym_with_expression] = STATE(421),
+    [sym_let_expression] = STATE(421),
+    [sym__expr_if] = STATE(421),
+    [sym_if_expression] = STATE(421),
+    [sym__expr_op] = STATE(343),
+    [sym_has_attr_expression] = STATE(343),
+    [sym_unary_expression] = STATE(343),
+    [sym_binary_expression] = STATE(343),
+    [sym__expr_apply_expression] = STATE(76),
+    [sym_apply_expression] = STATE(76),
+    [sym__expr_select_expression] = STATE(76),
+    [sym_select_expression] = STATE(76),
+    [sym__expr_simple] = STATE(214),
+    [sym_parenthesized_expression] = STATE(214),
+    [sym_attrset_expression] = STATE(214),
+    [sym_let_attrset_expression] = STATE(214),
+    [sym_rec_attrset_expression] = STATE(214),
+    [sym_string_expression] = STATE(214),
+    [sym_indented_string_expression] = STATE(214),
+    [sym_list_expression] = STATE(214),
+    [ts_builtin_sym_end] = ACTIONS(5),
+    [aux_sym_identifier_token1] = ACTIONS(7),
+    [sym_integer_expression] = ACTIONS(9),
+    [sym_float_expression] = ACTIONS(11),
+    [sym__hpath_start] = ACTIONS(13),
+    [sym_spath_expression] = ACTIONS(11),
+    [sym_uri_expression] = ACTIONS(11),
+    [anon_sym_LBRACE] = ACTIONS(15),
+    [anon_sym_assert] = ACTIONS(17),
+    [anon_sym_with] = ACTIONS(19),
+    [anon_sym_let] = ACTIONS(21),
+    [anon_sym_if] = ACTIONS(23),
+    [anon_sym_BANG] = ACTIONS(25),
+    [anon_sym_DASH] = ACTIONS(27),
+    [anon_sym_LPAREN] = ACTIONS(29),
+    [anon_sym_rec] = ACTIONS(31),
+    [anon_sym_DQUOTE] = ACTIONS(33),
+    [anon_sym_SQUOTE_SQUOTE] = ACTIONS(35),
+    [anon_sym_LBRACK] = ACTIONS(37),
+    [sym_comment] = ACTIONS(3),
+    [sym_grit_metavariable] = ACTIONS(39),
+    [sym__path_start] = ACTIONS(41),
+  },
+};
+
+static const uint16_t ts_small_parse_table[] = {
+  [0] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(475), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [103] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(511), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [206] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(81), 1,
+      sym_integer_expression,
+    ACTIONS(85), 1,
+      sym__hpath_start,
+    ACTIONS(87), 1,
+      anon_sym_LBRACE,
+    ACTIONS(89), 1,
+      anon_sym_assert,
+    ACTIONS(91), 1,
+      anon_sym_with,
+    ACTIONS(93), 1,
+      anon_sym_let,
+    ACTIONS(95), 1,
+      anon_sym_if,
+    ACTIONS(97), 1,
+      anon_sym_BANG,
+    ACTIONS(99), 1,
+      anon_sym_DASH,
+    ACTIONS(101), 1,
+      anon_sym_LPAREN,
+    ACTIONS(103), 1,
+      anon_sym_rec,
+    ACTIONS(105), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(107), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(109), 1,
+      anon_sym_LBRACK,
+    ACTIONS(111), 1,
+      sym_grit_metavariable,
+    ACTIONS(113), 1,
+      sym__path_start,
+    STATE(196), 1,
+      sym_identifier,
+    STATE(467), 1,
+      sym_formals,
+    STATE(486), 1,
+      sym__expression,
+    ACTIONS(83), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(78), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(330), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(216), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [309] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(528), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [412] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(506), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [515] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(526), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [618] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(460), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [721] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(524), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [824] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(115), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(117), 1,
+      sym_integer_expression,
+    ACTIONS(121), 1,
+      sym__hpath_start,
+    ACTIONS(123), 1,
+      anon_sym_LBRACE,
+    ACTIONS(125), 1,
+      anon_sym_assert,
+    ACTIONS(127), 1,
+      anon_sym_with,
+    ACTIONS(129), 1,
+      anon_sym_let,
+    ACTIONS(131), 1,
+      anon_sym_if,
+    ACTIONS(133), 1,
+      anon_sym_BANG,
+    ACTIONS(135), 1,
+      anon_sym_DASH,
+    ACTIONS(137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(139), 1,
+      anon_sym_rec,
+    ACTIONS(141), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(143), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(145), 1,
+      anon_sym_LBRACK,
+    ACTIONS(147), 1,
+      sym_grit_metavariable,
+    ACTIONS(149), 1,
+      sym__path_start,
+    STATE(183), 1,
+      sym_identifier,
+    STATE(458), 1,
+      sym_formals,
+    STATE(530), 1,
+      sym__expression,
+    ACTIONS(119), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(77), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(336), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(260), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [927] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(9), 1,
+      sym_integer_expression,
+    ACTIONS(13), 1,
+      sym__hpath_start,
+    ACTIONS(15), 1,
+      anon_sym_LBRACE,
+    ACTIONS(17), 1,
+      anon_sym_assert,
+    ACTIONS(19), 1,
+      anon_sym_with,
+    ACTIONS(21), 1,
+      anon_sym_let,
+    ACTIONS(23), 1,
+      anon_sym_if,
+    ACTIONS(25), 1,
+      anon_sym_BANG,
+    ACTIONS(27), 1,
+      anon_sym_DASH,
+    ACTIONS(29), 1,
+      anon_sym_LPAREN,
+    ACTIONS(31), 1,
+      anon_sym_rec,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(41), 1,
+      sym__path_start,
+    STATE(189), 1,
+      sym_identifier,
+    STATE(423), 1,
+      sym__expression,
+    STATE(470), 1,
+      sym_formals,
+    ACTIONS(11), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(76), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(343), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(214), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [1030] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    ACTIONS(151), 1,
+      anon_sym_LBRACE,
+    ACTIONS(155), 1,
+      anon_sym_let,
+    STATE(122), 1,
+      sym_identifier,
+    STATE(173), 2,
+      sym__expr_select_expression,
+      sym_select_expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    ACTIONS(157), 5,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+    ACTIONS(153), 15,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_RPAREN,
+  [1119] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(423), 1,
+      sym__expression,
+    STATE(459), 1,
+      sym_formals,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [1222] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(81), 1,
+      sym_integer_expression,
+    ACTIONS(85), 1,
+      sym__hpath_start,
+    ACTIONS(87), 1,
+      anon_sym_LBRACE,
+    ACTIONS(89), 1,
+      anon_sym_assert,
+    ACTIONS(91), 1,
+      anon_sym_with,
+    ACTIONS(93), 1,
+      anon_sym_let,
+    ACTIONS(95), 1,
+      anon_sym_if,
+    ACTIONS(97), 1,
+      anon_sym_BANG,
+    ACTIONS(99), 1,
+      anon_sym_DASH,
+    ACTIONS(101), 1,
+      anon_sym_LPAREN,
+    ACTIONS(103), 1,
+      anon_sym_rec,
+    ACTIONS(105), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(107), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(109), 1,
+      anon_sym_LBRACK,
+    ACTIONS(111), 1,
+      sym_grit_metavariable,
+    ACTIONS(113), 1,
+      sym__path_start,
+    STATE(196), 1,
+      sym_identifier,
+    STATE(423), 1,
+      sym__expression,
+    STATE(467), 1,
+      sym_formals,
+    ACTIONS(83), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(78), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(330), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(216), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [1325] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(115), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(117), 1,
+      sym_integer_expression,
+    ACTIONS(121), 1,
+      sym__hpath_start,
+    ACTIONS(123), 1,
+      anon_sym_LBRACE,
+    ACTIONS(125), 1,
+      anon_sym_assert,
+    ACTIONS(127), 1,
+      anon_sym_with,
+    ACTIONS(129), 1,
+      anon_sym_let,
+    ACTIONS(131), 1,
+      anon_sym_if,
+    ACTIONS(133), 1,
+      anon_sym_BANG,
+    ACTIONS(135), 1,
+      anon_sym_DASH,
+    ACTIONS(137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(139), 1,
+      anon_sym_rec,
+    ACTIONS(141), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(143), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(145), 1,
+      anon_sym_LBRACK,
+    ACTIONS(147), 1,
+      sym_grit_metavariable,
+    ACTIONS(149), 1,
+      sym__path_start,
+    STATE(183), 1,
+      sym_identifier,
+    STATE(423), 1,
+      sym__expression,
+    STATE(458), 1,
+      sym_formals,
+    ACTIONS(119), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(77), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(336), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(260), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [1428] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(523), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [1531] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(527), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [1634] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(477), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [1737] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(493), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [1840] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(496), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [1943] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(507), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [2046] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(499), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [2149] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(533), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [2252] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(535), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [2355] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(479), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [2458] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(497), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [2561] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(512), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [2664] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(115), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(117), 1,
+      sym_integer_expression,
+    ACTIONS(121), 1,
+      sym__hpath_start,
+    ACTIONS(123), 1,
+      anon_sym_LBRACE,
+    ACTIONS(125), 1,
+      anon_sym_assert,
+    ACTIONS(127), 1,
+      anon_sym_with,
+    ACTIONS(129), 1,
+      anon_sym_let,
+    ACTIONS(131), 1,
+      anon_sym_if,
+    ACTIONS(133), 1,
+      anon_sym_BANG,
+    ACTIONS(135), 1,
+      anon_sym_DASH,
+    ACTIONS(137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(139), 1,
+      anon_sym_rec,
+    ACTIONS(141), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(143), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(145), 1,
+      anon_sym_LBRACK,
+    ACTIONS(147), 1,
+      sym_grit_metavariable,
+    ACTIONS(149), 1,
+      sym__path_start,
+    STATE(183), 1,
+      sym_identifier,
+    STATE(458), 1,
+      sym_formals,
+    STATE(522), 1,
+      sym__expression,
+    ACTIONS(119), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(77), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(336), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(260), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [2767] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(543), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [2870] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(552), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [2973] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(478), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [3076] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(484), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [3179] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(485), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [3282] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(115), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(117), 1,
+      sym_integer_expression,
+    ACTIONS(121), 1,
+      sym__hpath_start,
+    ACTIONS(123), 1,
+      anon_sym_LBRACE,
+    ACTIONS(125), 1,
+      anon_sym_assert,
+    ACTIONS(127), 1,
+      anon_sym_with,
+    ACTIONS(129), 1,
+      anon_sym_let,
+    ACTIONS(131), 1,
+      anon_sym_if,
+    ACTIONS(133), 1,
+      anon_sym_BANG,
+    ACTIONS(135), 1,
+      anon_sym_DASH,
+    ACTIONS(137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(139), 1,
+      anon_sym_rec,
+    ACTIONS(141), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(143), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(145), 1,
+      anon_sym_LBRACK,
+    ACTIONS(147), 1,
+      sym_grit_metavariable,
+    ACTIONS(149), 1,
+      sym__path_start,
+    STATE(183), 1,
+      sym_identifier,
+    STATE(458), 1,
+      sym_formals,
+    STATE(491), 1,
+      sym__expression,
+    ACTIONS(119), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(77), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(336), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(260), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [3385] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(494), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [3488] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(498), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [3591] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(502), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [3694] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(505), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [3797] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(81), 1,
+      sym_integer_expression,
+    ACTIONS(85), 1,
+      sym__hpath_start,
+    ACTIONS(87), 1,
+      anon_sym_LBRACE,
+    ACTIONS(89), 1,
+      anon_sym_assert,
+    ACTIONS(91), 1,
+      anon_sym_with,
+    ACTIONS(93), 1,
+      anon_sym_let,
+    ACTIONS(95), 1,
+      anon_sym_if,
+    ACTIONS(97), 1,
+      anon_sym_BANG,
+    ACTIONS(99), 1,
+      anon_sym_DASH,
+    ACTIONS(101), 1,
+      anon_sym_LPAREN,
+    ACTIONS(103), 1,
+      anon_sym_rec,
+    ACTIONS(105), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(107), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(109), 1,
+      anon_sym_LBRACK,
+    ACTIONS(111), 1,
+      sym_grit_metavariable,
+    ACTIONS(113), 1,
+      sym__path_start,
+    STATE(196), 1,
+      sym_identifier,
+    STATE(467), 1,
+      sym_formals,
+    STATE(545), 1,
+      sym__expression,
+    ACTIONS(83), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(78), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(330), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(216), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [3900] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    STATE(546), 1,
+      sym__expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [4003] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(81), 1,
+      sym_integer_expression,
+    ACTIONS(85), 1,
+      sym__hpath_start,
+    ACTIONS(87), 1,
+      anon_sym_LBRACE,
+    ACTIONS(89), 1,
+      anon_sym_assert,
+    ACTIONS(91), 1,
+      anon_sym_with,
+    ACTIONS(93), 1,
+      anon_sym_let,
+    ACTIONS(95), 1,
+      anon_sym_if,
+    ACTIONS(97), 1,
+      anon_sym_BANG,
+    ACTIONS(99), 1,
+      anon_sym_DASH,
+    ACTIONS(101), 1,
+      anon_sym_LPAREN,
+    ACTIONS(103), 1,
+      anon_sym_rec,
+    ACTIONS(105), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(107), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(109), 1,
+      anon_sym_LBRACK,
+    ACTIONS(111), 1,
+      sym_grit_metavariable,
+    ACTIONS(113), 1,
+      sym__path_start,
+    STATE(196), 1,
+      sym_identifier,
+    STATE(467), 1,
+      sym_formals,
+    STATE(547), 1,
+      sym__expression,
+    ACTIONS(83), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(78), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(330), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(216), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [4106] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(81), 1,
+      sym_integer_expression,
+    ACTIONS(85), 1,
+      sym__hpath_start,
+    ACTIONS(87), 1,
+      anon_sym_LBRACE,
+    ACTIONS(89), 1,
+      anon_sym_assert,
+    ACTIONS(91), 1,
+      anon_sym_with,
+    ACTIONS(93), 1,
+      anon_sym_let,
+    ACTIONS(95), 1,
+      anon_sym_if,
+    ACTIONS(97), 1,
+      anon_sym_BANG,
+    ACTIONS(99), 1,
+      anon_sym_DASH,
+    ACTIONS(101), 1,
+      anon_sym_LPAREN,
+    ACTIONS(103), 1,
+      anon_sym_rec,
+    ACTIONS(105), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(107), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(109), 1,
+      anon_sym_LBRACK,
+    ACTIONS(111), 1,
+      sym_grit_metavariable,
+    ACTIONS(113), 1,
+      sym__path_start,
+    STATE(196), 1,
+      sym_identifier,
+    STATE(467), 1,
+      sym_formals,
+    STATE(548), 1,
+      sym__expression,
+    ACTIONS(83), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(78), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(330), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(216), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [4209] = 26,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(115), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(117), 1,
+      sym_integer_expression,
+    ACTIONS(121), 1,
+      sym__hpath_start,
+    ACTIONS(123), 1,
+      anon_sym_LBRACE,
+    ACTIONS(125), 1,
+      anon_sym_assert,
+    ACTIONS(127), 1,
+      anon_sym_with,
+    ACTIONS(129), 1,
+      anon_sym_let,
+    ACTIONS(131), 1,
+      anon_sym_if,
+    ACTIONS(133), 1,
+      anon_sym_BANG,
+    ACTIONS(135), 1,
+      anon_sym_DASH,
+    ACTIONS(137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(139), 1,
+      anon_sym_rec,
+    ACTIONS(141), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(143), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(145), 1,
+      anon_sym_LBRACK,
+    ACTIONS(147), 1,
+      sym_grit_metavariable,
+    ACTIONS(149), 1,
+      sym__path_start,
+    STATE(183), 1,
+      sym_identifier,
+    STATE(458), 1,
+      sym_formals,
+    STATE(508), 1,
+      sym__expression,
+    ACTIONS(119), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(77), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(336), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(421), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(260), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [4312] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(81), 1,
+      sym_integer_expression,
+    ACTIONS(85), 1,
+      sym__hpath_start,
+    ACTIONS(87), 1,
+      anon_sym_LBRACE,
+    ACTIONS(89), 1,
+      anon_sym_assert,
+    ACTIONS(91), 1,
+      anon_sym_with,
+    ACTIONS(93), 1,
+      anon_sym_let,
+    ACTIONS(95), 1,
+      anon_sym_if,
+    ACTIONS(97), 1,
+      anon_sym_BANG,
+    ACTIONS(99), 1,
+      anon_sym_DASH,
+    ACTIONS(101), 1,
+      anon_sym_LPAREN,
+    ACTIONS(103), 1,
+      anon_sym_rec,
+    ACTIONS(105), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(107), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(109), 1,
+      anon_sym_LBRACK,
+    ACTIONS(111), 1,
+      sym_grit_metavariable,
+    ACTIONS(113), 1,
+      sym__path_start,
+    STATE(196), 1,
+      sym_identifier,
+    STATE(467), 1,
+      sym_formals,
+    ACTIONS(83), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(78), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(330), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(412), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(216), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [4412] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(9), 1,
+      sym_integer_expression,
+    ACTIONS(13), 1,
+      sym__hpath_start,
+    ACTIONS(15), 1,
+      anon_sym_LBRACE,
+    ACTIONS(17), 1,
+      anon_sym_assert,
+    ACTIONS(19), 1,
+      anon_sym_with,
+    ACTIONS(21), 1,
+      anon_sym_let,
+    ACTIONS(23), 1,
+      anon_sym_if,
+    ACTIONS(25), 1,
+      anon_sym_BANG,
+    ACTIONS(27), 1,
+      anon_sym_DASH,
+    ACTIONS(29), 1,
+      anon_sym_LPAREN,
+    ACTIONS(31), 1,
+      anon_sym_rec,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(41), 1,
+      sym__path_start,
+    STATE(189), 1,
+      sym_identifier,
+    STATE(470), 1,
+      sym_formals,
+    ACTIONS(11), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(76), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(343), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(424), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(214), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [4512] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(425), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [4612] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(412), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [4712] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(422), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [4812] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(414), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [4912] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(416), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [5012] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(426), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [5112] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(411), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [5212] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(51), 1,
+      anon_sym_LBRACE,
+    ACTIONS(53), 1,
+      anon_sym_assert,
+    ACTIONS(55), 1,
+      anon_sym_with,
+    ACTIONS(57), 1,
+      anon_sym_let,
+    ACTIONS(59), 1,
+      anon_sym_if,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    STATE(90), 1,
+      sym_identifier,
+    STATE(459), 1,
+      sym_formals,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(302), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(424), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [5312] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(81), 1,
+      sym_integer_expression,
+    ACTIONS(85), 1,
+      sym__hpath_start,
+    ACTIONS(87), 1,
+      anon_sym_LBRACE,
+    ACTIONS(89), 1,
+      anon_sym_assert,
+    ACTIONS(91), 1,
+      anon_sym_with,
+    ACTIONS(93), 1,
+      anon_sym_let,
+    ACTIONS(95), 1,
+      anon_sym_if,
+    ACTIONS(97), 1,
+      anon_sym_BANG,
+    ACTIONS(99), 1,
+      anon_sym_DASH,
+    ACTIONS(101), 1,
+      anon_sym_LPAREN,
+    ACTIONS(103), 1,
+      anon_sym_rec,
+    ACTIONS(105), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(107), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(109), 1,
+      anon_sym_LBRACK,
+    ACTIONS(111), 1,
+      sym_grit_metavariable,
+    ACTIONS(113), 1,
+      sym__path_start,
+    STATE(196), 1,
+      sym_identifier,
+    STATE(467), 1,
+      sym_formals,
+    ACTIONS(83), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(78), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(330), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(425), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(216), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [5412] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(81), 1,
+      sym_integer_expression,
+    ACTIONS(85), 1,
+      sym__hpath_start,
+    ACTIONS(87), 1,
+      anon_sym_LBRACE,
+    ACTIONS(89), 1,
+      anon_sym_assert,
+    ACTIONS(91), 1,
+      anon_sym_with,
+    ACTIONS(93), 1,
+      anon_sym_let,
+    ACTIONS(95), 1,
+      anon_sym_if,
+    ACTIONS(97), 1,
+      anon_sym_BANG,
+    ACTIONS(99), 1,
+      anon_sym_DASH,
+    ACTIONS(101), 1,
+      anon_sym_LPAREN,
+    ACTIONS(103), 1,
+      anon_sym_rec,
+    ACTIONS(105), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(107), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(109), 1,
+      anon_sym_LBRACK,
+    ACTIONS(111), 1,
+      sym_grit_metavariable,
+    ACTIONS(113), 1,
+      sym__path_start,
+    STATE(196), 1,
+      sym_identifier,
+    STATE(467), 1,
+      sym_formals,
+    ACTIONS(83), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(78), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(330), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(422), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(216), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [5512] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(81), 1,
+      sym_integer_expression,
+    ACTIONS(85), 1,
+      sym__hpath_start,
+    ACTIONS(87), 1,
+      anon_sym_LBRACE,
+    ACTIONS(89), 1,
+      anon_sym_assert,
+    ACTIONS(91), 1,
+      anon_sym_with,
+    ACTIONS(93), 1,
+      anon_sym_let,
+    ACTIONS(95), 1,
+      anon_sym_if,
+    ACTIONS(97), 1,
+      anon_sym_BANG,
+    ACTIONS(99), 1,
+      anon_sym_DASH,
+    ACTIONS(101), 1,
+      anon_sym_LPAREN,
+    ACTIONS(103), 1,
+      anon_sym_rec,
+    ACTIONS(105), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(107), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(109), 1,
+      anon_sym_LBRACK,
+    ACTIONS(111), 1,
+      sym_grit_metavariable,
+    ACTIONS(113), 1,
+      sym__path_start,
+    STATE(196), 1,
+      sym_identifier,
+    STATE(467), 1,
+      sym_formals,
+    ACTIONS(83), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(78), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(330), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(414), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(216), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [5612] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(81), 1,
+      sym_integer_expression,
+    ACTIONS(85), 1,
+      sym__hpath_start,
+    ACTIONS(87), 1,
+      anon_sym_LBRACE,
+    ACTIONS(89), 1,
+      anon_sym_assert,
+    ACTIONS(91), 1,
+      anon_sym_with,
+    ACTIONS(93), 1,
+      anon_sym_let,
+    ACTIONS(95), 1,
+      anon_sym_if,
+    ACTIONS(97), 1,
+      anon_sym_BANG,
+    ACTIONS(99), 1,
+      anon_sym_DASH,
+    ACTIONS(101), 1,
+      anon_sym_LPAREN,
+    ACTIONS(103), 1,
+      anon_sym_rec,
+    ACTIONS(105), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(107), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(109), 1,
+      anon_sym_LBRACK,
+    ACTIONS(111), 1,
+      sym_grit_metavariable,
+    ACTIONS(113), 1,
+      sym__path_start,
+    STATE(196), 1,
+      sym_identifier,
+    STATE(467), 1,
+      sym_formals,
+    ACTIONS(83), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(78), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(330), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(416), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(216), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [5712] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(81), 1,
+      sym_integer_expression,
+    ACTIONS(85), 1,
+      sym__hpath_start,
+    ACTIONS(87), 1,
+      anon_sym_LBRACE,
+    ACTIONS(89), 1,
+      anon_sym_assert,
+    ACTIONS(91), 1,
+      anon_sym_with,
+    ACTIONS(93), 1,
+      anon_sym_let,
+    ACTIONS(95), 1,
+      anon_sym_if,
+    ACTIONS(97), 1,
+      anon_sym_BANG,
+    ACTIONS(99), 1,
+      anon_sym_DASH,
+    ACTIONS(101), 1,
+      anon_sym_LPAREN,
+    ACTIONS(103), 1,
+      anon_sym_rec,
+    ACTIONS(105), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(107), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(109), 1,
+      anon_sym_LBRACK,
+    ACTIONS(111), 1,
+      sym_grit_metavariable,
+    ACTIONS(113), 1,
+      sym__path_start,
+    STATE(196), 1,
+      sym_identifier,
+    STATE(467), 1,
+      sym_formals,
+    ACTIONS(83), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(78), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(330), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(411), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(216), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [5812] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(81), 1,
+      sym_integer_expression,
+    ACTIONS(85), 1,
+      sym__hpath_start,
+    ACTIONS(87), 1,
+      anon_sym_LBRACE,
+    ACTIONS(89), 1,
+      anon_sym_assert,
+    ACTIONS(91), 1,
+      anon_sym_with,
+    ACTIONS(93), 1,
+      anon_sym_let,
+    ACTIONS(95), 1,
+      anon_sym_if,
+    ACTIONS(97), 1,
+      anon_sym_BANG,
+    ACTIONS(99), 1,
+      anon_sym_DASH,
+    ACTIONS(101), 1,
+      anon_sym_LPAREN,
+    ACTIONS(103), 1,
+      anon_sym_rec,
+    ACTIONS(105), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(107), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(109), 1,
+      anon_sym_LBRACK,
+    ACTIONS(111), 1,
+      sym_grit_metavariable,
+    ACTIONS(113), 1,
+      sym__path_start,
+    STATE(196), 1,
+      sym_identifier,
+    STATE(467), 1,
+      sym_formals,
+    ACTIONS(83), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(78), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(330), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(424), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(216), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [5912] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(115), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(117), 1,
+      sym_integer_expression,
+    ACTIONS(121), 1,
+      sym__hpath_start,
+    ACTIONS(123), 1,
+      anon_sym_LBRACE,
+    ACTIONS(125), 1,
+      anon_sym_assert,
+    ACTIONS(127), 1,
+      anon_sym_with,
+    ACTIONS(129), 1,
+      anon_sym_let,
+    ACTIONS(131), 1,
+      anon_sym_if,
+    ACTIONS(133), 1,
+      anon_sym_BANG,
+    ACTIONS(135), 1,
+      anon_sym_DASH,
+    ACTIONS(137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(139), 1,
+      anon_sym_rec,
+    ACTIONS(141), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(143), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(145), 1,
+      anon_sym_LBRACK,
+    ACTIONS(147), 1,
+      sym_grit_metavariable,
+    ACTIONS(149), 1,
+      sym__path_start,
+    STATE(183), 1,
+      sym_identifier,
+    STATE(458), 1,
+      sym_formals,
+    ACTIONS(119), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(77), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(336), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(425), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(260), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [6012] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(115), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(117), 1,
+      sym_integer_expression,
+    ACTIONS(121), 1,
+      sym__hpath_start,
+    ACTIONS(123), 1,
+      anon_sym_LBRACE,
+    ACTIONS(125), 1,
+      anon_sym_assert,
+    ACTIONS(127), 1,
+      anon_sym_with,
+    ACTIONS(129), 1,
+      anon_sym_let,
+    ACTIONS(131), 1,
+      anon_sym_if,
+    ACTIONS(133), 1,
+      anon_sym_BANG,
+    ACTIONS(135), 1,
+      anon_sym_DASH,
+    ACTIONS(137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(139), 1,
+      anon_sym_rec,
+    ACTIONS(141), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(143), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(145), 1,
+      anon_sym_LBRACK,
+    ACTIONS(147), 1,
+      sym_grit_metavariable,
+    ACTIONS(149), 1,
+      sym__path_start,
+    STATE(183), 1,
+      sym_identifier,
+    STATE(458), 1,
+      sym_formals,
+    ACTIONS(119), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(77), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(336), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(412), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(260), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [6112] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(115), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(117), 1,
+      sym_integer_expression,
+    ACTIONS(121), 1,
+      sym__hpath_start,
+    ACTIONS(123), 1,
+      anon_sym_LBRACE,
+    ACTIONS(125), 1,
+      anon_sym_assert,
+    ACTIONS(127), 1,
+      anon_sym_with,
+    ACTIONS(129), 1,
+      anon_sym_let,
+    ACTIONS(131), 1,
+      anon_sym_if,
+    ACTIONS(133), 1,
+      anon_sym_BANG,
+    ACTIONS(135), 1,
+      anon_sym_DASH,
+    ACTIONS(137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(139), 1,
+      anon_sym_rec,
+    ACTIONS(141), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(143), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(145), 1,
+      anon_sym_LBRACK,
+    ACTIONS(147), 1,
+      sym_grit_metavariable,
+    ACTIONS(149), 1,
+      sym__path_start,
+    STATE(183), 1,
+      sym_identifier,
+    STATE(458), 1,
+      sym_formals,
+    ACTIONS(119), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(77), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(336), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(422), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(260), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [6212] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(115), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(117), 1,
+      sym_integer_expression,
+    ACTIONS(121), 1,
+      sym__hpath_start,
+    ACTIONS(123), 1,
+      anon_sym_LBRACE,
+    ACTIONS(125), 1,
+      anon_sym_assert,
+    ACTIONS(127), 1,
+      anon_sym_with,
+    ACTIONS(129), 1,
+      anon_sym_let,
+    ACTIONS(131), 1,
+      anon_sym_if,
+    ACTIONS(133), 1,
+      anon_sym_BANG,
+    ACTIONS(135), 1,
+      anon_sym_DASH,
+    ACTIONS(137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(139), 1,
+      anon_sym_rec,
+    ACTIONS(141), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(143), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(145), 1,
+      anon_sym_LBRACK,
+    ACTIONS(147), 1,
+      sym_grit_metavariable,
+    ACTIONS(149), 1,
+      sym__path_start,
+    STATE(183), 1,
+      sym_identifier,
+    STATE(458), 1,
+      sym_formals,
+    ACTIONS(119), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(77), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(336), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(414), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(260), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [6312] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(115), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(117), 1,
+      sym_integer_expression,
+    ACTIONS(121), 1,
+      sym__hpath_start,
+    ACTIONS(123), 1,
+      anon_sym_LBRACE,
+    ACTIONS(125), 1,
+      anon_sym_assert,
+    ACTIONS(127), 1,
+      anon_sym_with,
+    ACTIONS(129), 1,
+      anon_sym_let,
+    ACTIONS(131), 1,
+      anon_sym_if,
+    ACTIONS(133), 1,
+      anon_sym_BANG,
+    ACTIONS(135), 1,
+      anon_sym_DASH,
+    ACTIONS(137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(139), 1,
+      anon_sym_rec,
+    ACTIONS(141), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(143), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(145), 1,
+      anon_sym_LBRACK,
+    ACTIONS(147), 1,
+      sym_grit_metavariable,
+    ACTIONS(149), 1,
+      sym__path_start,
+    STATE(183), 1,
+      sym_identifier,
+    STATE(458), 1,
+      sym_formals,
+    ACTIONS(119), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(77), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(336), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(416), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(260), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [6412] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(115), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(117), 1,
+      sym_integer_expression,
+    ACTIONS(121), 1,
+      sym__hpath_start,
+    ACTIONS(123), 1,
+      anon_sym_LBRACE,
+    ACTIONS(125), 1,
+      anon_sym_assert,
+    ACTIONS(127), 1,
+      anon_sym_with,
+    ACTIONS(129), 1,
+      anon_sym_let,
+    ACTIONS(131), 1,
+      anon_sym_if,
+    ACTIONS(133), 1,
+      anon_sym_BANG,
+    ACTIONS(135), 1,
+      anon_sym_DASH,
+    ACTIONS(137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(139), 1,
+      anon_sym_rec,
+    ACTIONS(141), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(143), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(145), 1,
+      anon_sym_LBRACK,
+    ACTIONS(147), 1,
+      sym_grit_metavariable,
+    ACTIONS(149), 1,
+      sym__path_start,
+    STATE(183), 1,
+      sym_identifier,
+    STATE(458), 1,
+      sym_formals,
+    ACTIONS(119), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(77), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(336), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(426), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(260), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [6512] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(115), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(117), 1,
+      sym_integer_expression,
+    ACTIONS(121), 1,
+      sym__hpath_start,
+    ACTIONS(123), 1,
+      anon_sym_LBRACE,
+    ACTIONS(125), 1,
+      anon_sym_assert,
+    ACTIONS(127), 1,
+      anon_sym_with,
+    ACTIONS(129), 1,
+      anon_sym_let,
+    ACTIONS(131), 1,
+      anon_sym_if,
+    ACTIONS(133), 1,
+      anon_sym_BANG,
+    ACTIONS(135), 1,
+      anon_sym_DASH,
+    ACTIONS(137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(139), 1,
+      anon_sym_rec,
+    ACTIONS(141), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(143), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(145), 1,
+      anon_sym_LBRACK,
+    ACTIONS(147), 1,
+      sym_grit_metavariable,
+    ACTIONS(149), 1,
+      sym__path_start,
+    STATE(183), 1,
+      sym_identifier,
+    STATE(458), 1,
+      sym_formals,
+    ACTIONS(119), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(77), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(336), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(411), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(260), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [6612] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(115), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(117), 1,
+      sym_integer_expression,
+    ACTIONS(121), 1,
+      sym__hpath_start,
+    ACTIONS(123), 1,
+      anon_sym_LBRACE,
+    ACTIONS(125), 1,
+      anon_sym_assert,
+    ACTIONS(127), 1,
+      anon_sym_with,
+    ACTIONS(129), 1,
+      anon_sym_let,
+    ACTIONS(131), 1,
+      anon_sym_if,
+    ACTIONS(133), 1,
+      anon_sym_BANG,
+    ACTIONS(135), 1,
+      anon_sym_DASH,
+    ACTIONS(137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(139), 1,
+      anon_sym_rec,
+    ACTIONS(141), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(143), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(145), 1,
+      anon_sym_LBRACK,
+    ACTIONS(147), 1,
+      sym_grit_metavariable,
+    ACTIONS(149), 1,
+      sym__path_start,
+    STATE(183), 1,
+      sym_identifier,
+    STATE(458), 1,
+      sym_formals,
+    ACTIONS(119), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(77), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(336), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(424), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(260), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [6712] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(9), 1,
+      sym_integer_expression,
+    ACTIONS(13), 1,
+      sym__hpath_start,
+    ACTIONS(15), 1,
+      anon_sym_LBRACE,
+    ACTIONS(17), 1,
+      anon_sym_assert,
+    ACTIONS(19), 1,
+      anon_sym_with,
+    ACTIONS(21), 1,
+      anon_sym_let,
+    ACTIONS(23), 1,
+      anon_sym_if,
+    ACTIONS(25), 1,
+      anon_sym_BANG,
+    ACTIONS(27), 1,
+      anon_sym_DASH,
+    ACTIONS(29), 1,
+      anon_sym_LPAREN,
+    ACTIONS(31), 1,
+      anon_sym_rec,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(41), 1,
+      sym__path_start,
+    STATE(189), 1,
+      sym_identifier,
+    STATE(470), 1,
+      sym_formals,
+    ACTIONS(11), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(76), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(343), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(425), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(214), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [6812] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(9), 1,
+      sym_integer_expression,
+    ACTIONS(13), 1,
+      sym__hpath_start,
+    ACTIONS(15), 1,
+      anon_sym_LBRACE,
+    ACTIONS(17), 1,
+      anon_sym_assert,
+    ACTIONS(19), 1,
+      anon_sym_with,
+    ACTIONS(21), 1,
+      anon_sym_let,
+    ACTIONS(23), 1,
+      anon_sym_if,
+    ACTIONS(25), 1,
+      anon_sym_BANG,
+    ACTIONS(27), 1,
+      anon_sym_DASH,
+    ACTIONS(29), 1,
+      anon_sym_LPAREN,
+    ACTIONS(31), 1,
+      anon_sym_rec,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(41), 1,
+      sym__path_start,
+    STATE(189), 1,
+      sym_identifier,
+    STATE(470), 1,
+      sym_formals,
+    ACTIONS(11), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(76), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(343), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(412), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(214), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [6912] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(9), 1,
+      sym_integer_expression,
+    ACTIONS(13), 1,
+      sym__hpath_start,
+    ACTIONS(15), 1,
+      anon_sym_LBRACE,
+    ACTIONS(17), 1,
+      anon_sym_assert,
+    ACTIONS(19), 1,
+      anon_sym_with,
+    ACTIONS(21), 1,
+      anon_sym_let,
+    ACTIONS(23), 1,
+      anon_sym_if,
+    ACTIONS(25), 1,
+      anon_sym_BANG,
+    ACTIONS(27), 1,
+      anon_sym_DASH,
+    ACTIONS(29), 1,
+      anon_sym_LPAREN,
+    ACTIONS(31), 1,
+      anon_sym_rec,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(41), 1,
+      sym__path_start,
+    STATE(189), 1,
+      sym_identifier,
+    STATE(470), 1,
+      sym_formals,
+    ACTIONS(11), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(76), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(343), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(422), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(214), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [7012] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(9), 1,
+      sym_integer_expression,
+    ACTIONS(13), 1,
+      sym__hpath_start,
+    ACTIONS(15), 1,
+      anon_sym_LBRACE,
+    ACTIONS(17), 1,
+      anon_sym_assert,
+    ACTIONS(19), 1,
+      anon_sym_with,
+    ACTIONS(21), 1,
+      anon_sym_let,
+    ACTIONS(23), 1,
+      anon_sym_if,
+    ACTIONS(25), 1,
+      anon_sym_BANG,
+    ACTIONS(27), 1,
+      anon_sym_DASH,
+    ACTIONS(29), 1,
+      anon_sym_LPAREN,
+    ACTIONS(31), 1,
+      anon_sym_rec,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(41), 1,
+      sym__path_start,
+    STATE(189), 1,
+      sym_identifier,
+    STATE(470), 1,
+      sym_formals,
+    ACTIONS(11), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(76), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(343), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(414), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(214), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [7112] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(9), 1,
+      sym_integer_expression,
+    ACTIONS(13), 1,
+      sym__hpath_start,
+    ACTIONS(15), 1,
+      anon_sym_LBRACE,
+    ACTIONS(17), 1,
+      anon_sym_assert,
+    ACTIONS(19), 1,
+      anon_sym_with,
+    ACTIONS(21), 1,
+      anon_sym_let,
+    ACTIONS(23), 1,
+      anon_sym_if,
+    ACTIONS(25), 1,
+      anon_sym_BANG,
+    ACTIONS(27), 1,
+      anon_sym_DASH,
+    ACTIONS(29), 1,
+      anon_sym_LPAREN,
+    ACTIONS(31), 1,
+      anon_sym_rec,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(41), 1,
+      sym__path_start,
+    STATE(189), 1,
+      sym_identifier,
+    STATE(470), 1,
+      sym_formals,
+    ACTIONS(11), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(76), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(343), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(416), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(214), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [7212] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(9), 1,
+      sym_integer_expression,
+    ACTIONS(13), 1,
+      sym__hpath_start,
+    ACTIONS(15), 1,
+      anon_sym_LBRACE,
+    ACTIONS(17), 1,
+      anon_sym_assert,
+    ACTIONS(19), 1,
+      anon_sym_with,
+    ACTIONS(21), 1,
+      anon_sym_let,
+    ACTIONS(23), 1,
+      anon_sym_if,
+    ACTIONS(25), 1,
+      anon_sym_BANG,
+    ACTIONS(27), 1,
+      anon_sym_DASH,
+    ACTIONS(29), 1,
+      anon_sym_LPAREN,
+    ACTIONS(31), 1,
+      anon_sym_rec,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(41), 1,
+      sym__path_start,
+    STATE(189), 1,
+      sym_identifier,
+    STATE(470), 1,
+      sym_formals,
+    ACTIONS(11), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(76), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(343), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(426), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(214), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [7312] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(9), 1,
+      sym_integer_expression,
+    ACTIONS(13), 1,
+      sym__hpath_start,
+    ACTIONS(15), 1,
+      anon_sym_LBRACE,
+    ACTIONS(17), 1,
+      anon_sym_assert,
+    ACTIONS(19), 1,
+      anon_sym_with,
+    ACTIONS(21), 1,
+      anon_sym_let,
+    ACTIONS(23), 1,
+      anon_sym_if,
+    ACTIONS(25), 1,
+      anon_sym_BANG,
+    ACTIONS(27), 1,
+      anon_sym_DASH,
+    ACTIONS(29), 1,
+      anon_sym_LPAREN,
+    ACTIONS(31), 1,
+      anon_sym_rec,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(41), 1,
+      sym__path_start,
+    STATE(189), 1,
+      sym_identifier,
+    STATE(470), 1,
+      sym_formals,
+    ACTIONS(11), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(76), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(343), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(411), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(214), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [7412] = 25,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(81), 1,
+      sym_integer_expression,
+    ACTIONS(85), 1,
+      sym__hpath_start,
+    ACTIONS(87), 1,
+      anon_sym_LBRACE,
+    ACTIONS(89), 1,
+      anon_sym_assert,
+    ACTIONS(91), 1,
+      anon_sym_with,
+    ACTIONS(93), 1,
+      anon_sym_let,
+    ACTIONS(95), 1,
+      anon_sym_if,
+    ACTIONS(97), 1,
+      anon_sym_BANG,
+    ACTIONS(99), 1,
+      anon_sym_DASH,
+    ACTIONS(101), 1,
+      anon_sym_LPAREN,
+    ACTIONS(103), 1,
+      anon_sym_rec,
+    ACTIONS(105), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(107), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(109), 1,
+      anon_sym_LBRACK,
+    ACTIONS(111), 1,
+      sym_grit_metavariable,
+    ACTIONS(113), 1,
+      sym__path_start,
+    STATE(196), 1,
+      sym_identifier,
+    STATE(467), 1,
+      sym_formals,
+    ACTIONS(83), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(78), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(330), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(426), 7,
+      sym__expr_function_expression,
+      sym_function_expression,
+      sym_assert_expression,
+      sym_with_expression,
+      sym_let_expression,
+      sym__expr_if,
+      sym_if_expression,
+    STATE(216), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [7512] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(9), 1,
+      sym_integer_expression,
+    ACTIONS(13), 1,
+      sym__hpath_start,
+    ACTIONS(29), 1,
+      anon_sym_LPAREN,
+    ACTIONS(31), 1,
+      anon_sym_rec,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(41), 1,
+      sym__path_start,
+    ACTIONS(159), 1,
+      anon_sym_LBRACE,
+    ACTIONS(161), 1,
+      anon_sym_let,
+    STATE(255), 1,
+      sym_identifier,
+    STATE(261), 2,
+      sym__expr_select_expression,
+      sym_select_expression,
+    ACTIONS(11), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    ACTIONS(157), 5,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+    STATE(214), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+    ACTIONS(153), 12,
+      ts_builtin_sym_end,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+  [7598] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(115), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(117), 1,
+      sym_integer_expression,
+    ACTIONS(121), 1,
+      sym__hpath_start,
+    ACTIONS(137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(139), 1,
+      anon_sym_rec,
+    ACTIONS(141), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(143), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(145), 1,
+      anon_sym_LBRACK,
+    ACTIONS(147), 1,
+      sym_grit_metavariable,
+    ACTIONS(149), 1,
+      sym__path_start,
+    ACTIONS(163), 1,
+      anon_sym_LBRACE,
+    ACTIONS(165), 1,
+      anon_sym_let,
+    STATE(238), 1,
+      sym_identifier,
+    STATE(265), 2,
+      sym__expr_select_expression,
+      sym_select_expression,
+    ACTIONS(119), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    ACTIONS(157), 6,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+    ACTIONS(153), 11,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+    STATE(260), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [7684] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(81), 1,
+      sym_integer_expression,
+    ACTIONS(85), 1,
+      sym__hpath_start,
+    ACTIONS(101), 1,
+      anon_sym_LPAREN,
+    ACTIONS(103), 1,
+      anon_sym_rec,
+    ACTIONS(105), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(107), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(109), 1,
+      anon_sym_LBRACK,
+    ACTIONS(111), 1,
+      sym_grit_metavariable,
+    ACTIONS(113), 1,
+      sym__path_start,
+    ACTIONS(167), 1,
+      anon_sym_LBRACE,
+    ACTIONS(169), 1,
+      anon_sym_let,
+    STATE(219), 1,
+      sym_identifier,
+    STATE(263), 2,
+      sym__expr_select_expression,
+      sym_select_expression,
+    ACTIONS(83), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    ACTIONS(157), 6,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+    ACTIONS(153), 11,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+    STATE(216), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [7770] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(175), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(177), 1,
+      sym_path_fragment,
+    STATE(81), 1,
+      aux_sym_path_expression_repeat1,
+    STATE(85), 1,
+      sym__immediate_interpolation,
+    ACTIONS(171), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(173), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [7827] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(175), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(183), 1,
+      sym_path_fragment,
+    STATE(82), 1,
+      aux_sym_path_expression_repeat1,
+    STATE(85), 1,
+      sym__immediate_interpolation,
+    ACTIONS(179), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(181), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [7884] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(189), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(192), 1,
+      sym_path_fragment,
+    STATE(81), 1,
+      aux_sym_path_expression_repeat1,
+    STATE(85), 1,
+      sym__immediate_interpolation,
+    ACTIONS(185), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(187), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [7941] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(175), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(177), 1,
+      sym_path_fragment,
+    STATE(81), 1,
+      aux_sym_path_expression_repeat1,
+    STATE(85), 1,
+      sym__immediate_interpolation,
+    ACTIONS(195), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(197), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [7998] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(175), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(203), 1,
+      sym_path_fragment,
+    STATE(79), 1,
+      aux_sym_path_expression_repeat1,
+    STATE(85), 1,
+      sym__immediate_interpolation,
+    ACTIONS(199), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(201), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [8055] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(209), 1,
+      anon_sym_DOT,
+    STATE(87), 1,
+      aux_sym_attrpath_repeat1,
+    ACTIONS(205), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(207), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [8106] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(211), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(213), 29,
+      sym__path_start,
+      sym_path_fragment,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [8153] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(215), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(217), 29,
+      sym__path_start,
+      sym_path_fragment,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [8200] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(209), 1,
+      anon_sym_DOT,
+    STATE(89), 1,
+      aux_sym_attrpath_repeat1,
+    ACTIONS(219), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(221), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [8251] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(223), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(225), 29,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_COLON,
+      anon_sym_AT,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [8298] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(231), 1,
+      anon_sym_DOT,
+    STATE(89), 1,
+      aux_sym_attrpath_repeat1,
+    ACTIONS(227), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(229), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [8349] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(238), 1,
+      anon_sym_COLON,
+    ACTIONS(240), 1,
+      anon_sym_AT,
+    ACTIONS(234), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(236), 26,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [8399] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(246), 2,
+      anon_sym_COLON,
+      anon_sym_AT,
+    ACTIONS(242), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(244), 26,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [8447] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(248), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(250), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [8493] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(223), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(225), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [8539] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(252), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(254), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [8585] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(256), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(258), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [8631] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(260), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(262), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [8677] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(9), 1,
+      sym_integer_expression,
+    ACTIONS(13), 1,
+      sym__hpath_start,
+    ACTIONS(25), 1,
+      anon_sym_BANG,
+    ACTIONS(27), 1,
+      anon_sym_DASH,
+    ACTIONS(29), 1,
+      anon_sym_LPAREN,
+    ACTIONS(31), 1,
+      anon_sym_rec,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(41), 1,
+      sym__path_start,
+    ACTIONS(159), 1,
+      anon_sym_LBRACE,
+    ACTIONS(161), 1,
+      anon_sym_let,
+    STATE(255), 1,
+      sym_identifier,
+    ACTIONS(11), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(76), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(313), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(214), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [8756] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    ACTIONS(151), 1,
+      anon_sym_LBRACE,
+    ACTIONS(155), 1,
+      anon_sym_let,
+    STATE(122), 1,
+      sym_identifier,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(295), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [8835] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(264), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(266), 1,
+      sym_path_fragment,
+    STATE(115), 1,
+      aux_sym_path_expression_repeat1,
+    STATE(186), 1,
+      sym__immediate_interpolation,
+    ACTIONS(171), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(173), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [8888] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(264), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(266), 1,
+      sym_path_fragment,
+    STATE(115), 1,
+      aux_sym_path_expression_repeat1,
+    STATE(186), 1,
+      sym__immediate_interpolation,
+    ACTIONS(195), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(197), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [8941] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    ACTIONS(151), 1,
+      anon_sym_LBRACE,
+    ACTIONS(155), 1,
+      anon_sym_let,
+    STATE(122), 1,
+      sym_identifier,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(305), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [9020] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    ACTIONS(151), 1,
+      anon_sym_LBRACE,
+    ACTIONS(155), 1,
+      anon_sym_let,
+    STATE(122), 1,
+      sym_identifier,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(296), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [9099] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(252), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(254), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [9144] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(268), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(270), 1,
+      sym_path_fragment,
+    STATE(120), 1,
+      aux_sym_path_expression_repeat1,
+    STATE(194), 1,
+      sym__immediate_interpolation,
+    ACTIONS(199), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(201), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [9197] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    ACTIONS(151), 1,
+      anon_sym_LBRACE,
+    ACTIONS(155), 1,
+      anon_sym_let,
+    STATE(122), 1,
+      sym_identifier,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(297), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [9276] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(81), 1,
+      sym_integer_expression,
+    ACTIONS(85), 1,
+      sym__hpath_start,
+    ACTIONS(97), 1,
+      anon_sym_BANG,
+    ACTIONS(99), 1,
+      anon_sym_DASH,
+    ACTIONS(101), 1,
+      anon_sym_LPAREN,
+    ACTIONS(103), 1,
+      anon_sym_rec,
+    ACTIONS(105), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(107), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(109), 1,
+      anon_sym_LBRACK,
+    ACTIONS(111), 1,
+      sym_grit_metavariable,
+    ACTIONS(113), 1,
+      sym__path_start,
+    ACTIONS(167), 1,
+      anon_sym_LBRACE,
+    ACTIONS(169), 1,
+      anon_sym_let,
+    STATE(219), 1,
+      sym_identifier,
+    ACTIONS(83), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(78), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(333), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(216), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [9355] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(81), 1,
+      sym_integer_expression,
+    ACTIONS(85), 1,
+      sym__hpath_start,
+    ACTIONS(97), 1,
+      anon_sym_BANG,
+    ACTIONS(99), 1,
+      anon_sym_DASH,
+    ACTIONS(101), 1,
+      anon_sym_LPAREN,
+    ACTIONS(103), 1,
+      anon_sym_rec,
+    ACTIONS(105), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(107), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(109), 1,
+      anon_sym_LBRACK,
+    ACTIONS(111), 1,
+      sym_grit_metavariable,
+    ACTIONS(113), 1,
+      sym__path_start,
+    ACTIONS(167), 1,
+      anon_sym_LBRACE,
+    ACTIONS(169), 1,
+      anon_sym_let,
+    STATE(219), 1,
+      sym_identifier,
+    ACTIONS(83), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(78), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(339), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(216), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [9434] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(81), 1,
+      sym_integer_expression,
+    ACTIONS(85), 1,
+      sym__hpath_start,
+    ACTIONS(97), 1,
+      anon_sym_BANG,
+    ACTIONS(99), 1,
+      anon_sym_DASH,
+    ACTIONS(101), 1,
+      anon_sym_LPAREN,
+    ACTIONS(103), 1,
+      anon_sym_rec,
+    ACTIONS(105), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(107), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(109), 1,
+      anon_sym_LBRACK,
+    ACTIONS(111), 1,
+      sym_grit_metavariable,
+    ACTIONS(113), 1,
+      sym__path_start,
+    ACTIONS(167), 1,
+      anon_sym_LBRACE,
+    ACTIONS(169), 1,
+      anon_sym_let,
+    STATE(219), 1,
+      sym_identifier,
+    ACTIONS(83), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(78), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(332), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(216), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [9513] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(81), 1,
+      sym_integer_expression,
+    ACTIONS(85), 1,
+      sym__hpath_start,
+    ACTIONS(97), 1,
+      anon_sym_BANG,
+    ACTIONS(99), 1,
+      anon_sym_DASH,
+    ACTIONS(101), 1,
+      anon_sym_LPAREN,
+    ACTIONS(103), 1,
+      anon_sym_rec,
+    ACTIONS(105), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(107), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(109), 1,
+      anon_sym_LBRACK,
+    ACTIONS(111), 1,
+      sym_grit_metavariable,
+    ACTIONS(113), 1,
+      sym__path_start,
+    ACTIONS(167), 1,
+      anon_sym_LBRACE,
+    ACTIONS(169), 1,
+      anon_sym_let,
+    STATE(219), 1,
+      sym_identifier,
+    ACTIONS(83), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(78), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(309), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(216), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [9592] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(81), 1,
+      sym_integer_expression,
+    ACTIONS(85), 1,
+      sym__hpath_start,
+    ACTIONS(97), 1,
+      anon_sym_BANG,
+    ACTIONS(99), 1,
+      anon_sym_DASH,
+    ACTIONS(101), 1,
+      anon_sym_LPAREN,
+    ACTIONS(103), 1,
+      anon_sym_rec,
+    ACTIONS(105), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(107), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(109), 1,
+      anon_sym_LBRACK,
+    ACTIONS(111), 1,
+      sym_grit_metavariable,
+    ACTIONS(113), 1,
+      sym__path_start,
+    ACTIONS(167), 1,
+      anon_sym_LBRACE,
+    ACTIONS(169), 1,
+      anon_sym_let,
+    STATE(219), 1,
+      sym_identifier,
+    ACTIONS(83), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(78), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(312), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(216), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [9671] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(81), 1,
+      sym_integer_expression,
+    ACTIONS(85), 1,
+      sym__hpath_start,
+    ACTIONS(97), 1,
+      anon_sym_BANG,
+    ACTIONS(99), 1,
+      anon_sym_DASH,
+    ACTIONS(101), 1,
+      anon_sym_LPAREN,
+    ACTIONS(103), 1,
+      anon_sym_rec,
+    ACTIONS(105), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(107), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(109), 1,
+      anon_sym_LBRACK,
+    ACTIONS(111), 1,
+      sym_grit_metavariable,
+    ACTIONS(113), 1,
+      sym__path_start,
+    ACTIONS(167), 1,
+      anon_sym_LBRACE,
+    ACTIONS(169), 1,
+      anon_sym_let,
+    STATE(219), 1,
+      sym_identifier,
+    ACTIONS(83), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(78), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(308), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(216), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [9750] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(81), 1,
+      sym_integer_expression,
+    ACTIONS(85), 1,
+      sym__hpath_start,
+    ACTIONS(97), 1,
+      anon_sym_BANG,
+    ACTIONS(99), 1,
+      anon_sym_DASH,
+    ACTIONS(101), 1,
+      anon_sym_LPAREN,
+    ACTIONS(103), 1,
+      anon_sym_rec,
+    ACTIONS(105), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(107), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(109), 1,
+      anon_sym_LBRACK,
+    ACTIONS(111), 1,
+      sym_grit_metavariable,
+    ACTIONS(113), 1,
+      sym__path_start,
+    ACTIONS(167), 1,
+      anon_sym_LBRACE,
+    ACTIONS(169), 1,
+      anon_sym_let,
+    STATE(219), 1,
+      sym_identifier,
+    ACTIONS(83), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(78), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(338), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(216), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [9829] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(81), 1,
+      sym_integer_expression,
+    ACTIONS(85), 1,
+      sym__hpath_start,
+    ACTIONS(97), 1,
+      anon_sym_BANG,
+    ACTIONS(99), 1,
+      anon_sym_DASH,
+    ACTIONS(101), 1,
+      anon_sym_LPAREN,
+    ACTIONS(103), 1,
+      anon_sym_rec,
+    ACTIONS(105), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(107), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(109), 1,
+      anon_sym_LBRACK,
+    ACTIONS(111), 1,
+      sym_grit_metavariable,
+    ACTIONS(113), 1,
+      sym__path_start,
+    ACTIONS(167), 1,
+      anon_sym_LBRACE,
+    ACTIONS(169), 1,
+      anon_sym_let,
+    STATE(219), 1,
+      sym_identifier,
+    ACTIONS(83), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(78), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(342), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(216), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [9908] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(81), 1,
+      sym_integer_expression,
+    ACTIONS(85), 1,
+      sym__hpath_start,
+    ACTIONS(97), 1,
+      anon_sym_BANG,
+    ACTIONS(99), 1,
+      anon_sym_DASH,
+    ACTIONS(101), 1,
+      anon_sym_LPAREN,
+    ACTIONS(103), 1,
+      anon_sym_rec,
+    ACTIONS(105), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(107), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(109), 1,
+      anon_sym_LBRACK,
+    ACTIONS(111), 1,
+      sym_grit_metavariable,
+    ACTIONS(113), 1,
+      sym__path_start,
+    ACTIONS(167), 1,
+      anon_sym_LBRACE,
+    ACTIONS(169), 1,
+      anon_sym_let,
+    STATE(219), 1,
+      sym_identifier,
+    ACTIONS(83), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(78), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(327), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(216), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [9987] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(272), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(275), 1,
+      sym_path_fragment,
+    STATE(115), 1,
+      aux_sym_path_expression_repeat1,
+    STATE(186), 1,
+      sym__immediate_interpolation,
+    ACTIONS(185), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(187), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [10040] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    ACTIONS(151), 1,
+      anon_sym_LBRACE,
+    ACTIONS(155), 1,
+      anon_sym_let,
+    STATE(122), 1,
+      sym_identifier,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(299), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [10119] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    ACTIONS(151), 1,
+      anon_sym_LBRACE,
+    ACTIONS(155), 1,
+      anon_sym_let,
+    STATE(122), 1,
+      sym_identifier,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(303), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [10198] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    ACTIONS(151), 1,
+      anon_sym_LBRACE,
+    ACTIONS(155), 1,
+      anon_sym_let,
+    STATE(122), 1,
+      sym_identifier,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(306), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [10277] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(260), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(262), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [10322] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(268), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(278), 1,
+      sym_path_fragment,
+    STATE(163), 1,
+      aux_sym_path_expression_repeat1,
+    STATE(194), 1,
+      sym__immediate_interpolation,
+    ACTIONS(171), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(173), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [10375] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(280), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(282), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [10420] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(234), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(236), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [10465] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(284), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(286), 1,
+      sym_path_fragment,
+    STATE(128), 1,
+      aux_sym_path_expression_repeat1,
+    STATE(187), 1,
+      sym__immediate_interpolation,
+    ACTIONS(199), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(201), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [10518] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(284), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(288), 1,
+      sym_path_fragment,
+    STATE(129), 1,
+      aux_sym_path_expression_repeat1,
+    STATE(187), 1,
+      sym__immediate_interpolation,
+    ACTIONS(179), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(181), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [10571] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(115), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(117), 1,
+      sym_integer_expression,
+    ACTIONS(121), 1,
+      sym__hpath_start,
+    ACTIONS(133), 1,
+      anon_sym_BANG,
+    ACTIONS(135), 1,
+      anon_sym_DASH,
+    ACTIONS(137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(139), 1,
+      anon_sym_rec,
+    ACTIONS(141), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(143), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(145), 1,
+      anon_sym_LBRACK,
+    ACTIONS(147), 1,
+      sym_grit_metavariable,
+    ACTIONS(149), 1,
+      sym__path_start,
+    ACTIONS(163), 1,
+      anon_sym_LBRACE,
+    ACTIONS(165), 1,
+      anon_sym_let,
+    STATE(238), 1,
+      sym_identifier,
+    ACTIONS(119), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(77), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(337), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(260), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [10650] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(115), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(117), 1,
+      sym_integer_expression,
+    ACTIONS(121), 1,
+      sym__hpath_start,
+    ACTIONS(133), 1,
+      anon_sym_BANG,
+    ACTIONS(135), 1,
+      anon_sym_DASH,
+    ACTIONS(137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(139), 1,
+      anon_sym_rec,
+    ACTIONS(141), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(143), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(145), 1,
+      anon_sym_LBRACK,
+    ACTIONS(147), 1,
+      sym_grit_metavariable,
+    ACTIONS(149), 1,
+      sym__path_start,
+    ACTIONS(163), 1,
+      anon_sym_LBRACE,
+    ACTIONS(165), 1,
+      anon_sym_let,
+    STATE(238), 1,
+      sym_identifier,
+    ACTIONS(119), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(77), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(286), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(260), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [10729] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(290), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(292), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [10774] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(284), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(294), 1,
+      sym_path_fragment,
+    STATE(171), 1,
+      aux_sym_path_expression_repeat1,
+    STATE(187), 1,
+      sym__immediate_interpolation,
+    ACTIONS(171), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(173), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [10827] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(284), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(294), 1,
+      sym_path_fragment,
+    STATE(171), 1,
+      aux_sym_path_expression_repeat1,
+    STATE(187), 1,
+      sym__immediate_interpolation,
+    ACTIONS(195), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(197), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [10880] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(296), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(298), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [10925] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(300), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(302), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [10970] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(244), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [11015] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(115), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(117), 1,
+      sym_integer_expression,
+    ACTIONS(121), 1,
+      sym__hpath_start,
+    ACTIONS(133), 1,
+      anon_sym_BANG,
+    ACTIONS(135), 1,
+      anon_sym_DASH,
+    ACTIONS(137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(139), 1,
+      anon_sym_rec,
+    ACTIONS(141), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(143), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(145), 1,
+      anon_sym_LBRACK,
+    ACTIONS(147), 1,
+      sym_grit_metavariable,
+    ACTIONS(149), 1,
+      sym__path_start,
+    ACTIONS(163), 1,
+      anon_sym_LBRACE,
+    ACTIONS(165), 1,
+      anon_sym_let,
+    STATE(238), 1,
+      sym_identifier,
+    ACTIONS(119), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(77), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(316), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(260), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [11094] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(115), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(117), 1,
+      sym_integer_expression,
+    ACTIONS(121), 1,
+      sym__hpath_start,
+    ACTIONS(133), 1,
+      anon_sym_BANG,
+    ACTIONS(135), 1,
+      anon_sym_DASH,
+    ACTIONS(137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(139), 1,
+      anon_sym_rec,
+    ACTIONS(141), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(143), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(145), 1,
+      anon_sym_LBRACK,
+    ACTIONS(147), 1,
+      sym_grit_metavariable,
+    ACTIONS(149), 1,
+      sym__path_start,
+    ACTIONS(163), 1,
+      anon_sym_LBRACE,
+    ACTIONS(165), 1,
+      anon_sym_let,
+    STATE(238), 1,
+      sym_identifier,
+    ACTIONS(119), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(77), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(317), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(260), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [11173] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(115), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(117), 1,
+      sym_integer_expression,
+    ACTIONS(121), 1,
+      sym__hpath_start,
+    ACTIONS(133), 1,
+      anon_sym_BANG,
+    ACTIONS(135), 1,
+      anon_sym_DASH,
+    ACTIONS(137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(139), 1,
+      anon_sym_rec,
+    ACTIONS(141), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(143), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(145), 1,
+      anon_sym_LBRACK,
+    ACTIONS(147), 1,
+      sym_grit_metavariable,
+    ACTIONS(149), 1,
+      sym__path_start,
+    ACTIONS(163), 1,
+      anon_sym_LBRACE,
+    ACTIONS(165), 1,
+      anon_sym_let,
+    STATE(238), 1,
+      sym_identifier,
+    ACTIONS(119), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(77), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(318), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(260), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [11252] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(115), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(117), 1,
+      sym_integer_expression,
+    ACTIONS(121), 1,
+      sym__hpath_start,
+    ACTIONS(133), 1,
+      anon_sym_BANG,
+    ACTIONS(135), 1,
+      anon_sym_DASH,
+    ACTIONS(137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(139), 1,
+      anon_sym_rec,
+    ACTIONS(141), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(143), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(145), 1,
+      anon_sym_LBRACK,
+    ACTIONS(147), 1,
+      sym_grit_metavariable,
+    ACTIONS(149), 1,
+      sym__path_start,
+    ACTIONS(163), 1,
+      anon_sym_LBRACE,
+    ACTIONS(165), 1,
+      anon_sym_let,
+    STATE(238), 1,
+      sym_identifier,
+    ACTIONS(119), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(77), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(319), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(260), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [11331] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(115), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(117), 1,
+      sym_integer_expression,
+    ACTIONS(121), 1,
+      sym__hpath_start,
+    ACTIONS(133), 1,
+      anon_sym_BANG,
+    ACTIONS(135), 1,
+      anon_sym_DASH,
+    ACTIONS(137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(139), 1,
+      anon_sym_rec,
+    ACTIONS(141), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(143), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(145), 1,
+      anon_sym_LBRACK,
+    ACTIONS(147), 1,
+      sym_grit_metavariable,
+    ACTIONS(149), 1,
+      sym__path_start,
+    ACTIONS(163), 1,
+      anon_sym_LBRACE,
+    ACTIONS(165), 1,
+      anon_sym_let,
+    STATE(238), 1,
+      sym_identifier,
+    ACTIONS(119), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(77), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(322), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(260), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [11410] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(115), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(117), 1,
+      sym_integer_expression,
+    ACTIONS(121), 1,
+      sym__hpath_start,
+    ACTIONS(133), 1,
+      anon_sym_BANG,
+    ACTIONS(135), 1,
+      anon_sym_DASH,
+    ACTIONS(137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(139), 1,
+      anon_sym_rec,
+    ACTIONS(141), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(143), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(145), 1,
+      anon_sym_LBRACK,
+    ACTIONS(147), 1,
+      sym_grit_metavariable,
+    ACTIONS(149), 1,
+      sym__path_start,
+    ACTIONS(163), 1,
+      anon_sym_LBRACE,
+    ACTIONS(165), 1,
+      anon_sym_let,
+    STATE(238), 1,
+      sym_identifier,
+    ACTIONS(119), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(77), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(323), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(260), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [11489] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(115), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(117), 1,
+      sym_integer_expression,
+    ACTIONS(121), 1,
+      sym__hpath_start,
+    ACTIONS(133), 1,
+      anon_sym_BANG,
+    ACTIONS(135), 1,
+      anon_sym_DASH,
+    ACTIONS(137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(139), 1,
+      anon_sym_rec,
+    ACTIONS(141), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(143), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(145), 1,
+      anon_sym_LBRACK,
+    ACTIONS(147), 1,
+      sym_grit_metavariable,
+    ACTIONS(149), 1,
+      sym__path_start,
+    ACTIONS(163), 1,
+      anon_sym_LBRACE,
+    ACTIONS(165), 1,
+      anon_sym_let,
+    STATE(238), 1,
+      sym_identifier,
+    ACTIONS(119), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(77), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(324), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(260), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [11568] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(115), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(117), 1,
+      sym_integer_expression,
+    ACTIONS(121), 1,
+      sym__hpath_start,
+    ACTIONS(133), 1,
+      anon_sym_BANG,
+    ACTIONS(135), 1,
+      anon_sym_DASH,
+    ACTIONS(137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(139), 1,
+      anon_sym_rec,
+    ACTIONS(141), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(143), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(145), 1,
+      anon_sym_LBRACK,
+    ACTIONS(147), 1,
+      sym_grit_metavariable,
+    ACTIONS(149), 1,
+      sym__path_start,
+    ACTIONS(163), 1,
+      anon_sym_LBRACE,
+    ACTIONS(165), 1,
+      anon_sym_let,
+    STATE(238), 1,
+      sym_identifier,
+    ACTIONS(119), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(77), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(326), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(260), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [11647] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(115), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(117), 1,
+      sym_integer_expression,
+    ACTIONS(121), 1,
+      sym__hpath_start,
+    ACTIONS(133), 1,
+      anon_sym_BANG,
+    ACTIONS(135), 1,
+      anon_sym_DASH,
+    ACTIONS(137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(139), 1,
+      anon_sym_rec,
+    ACTIONS(141), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(143), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(145), 1,
+      anon_sym_LBRACK,
+    ACTIONS(147), 1,
+      sym_grit_metavariable,
+    ACTIONS(149), 1,
+      sym__path_start,
+    ACTIONS(163), 1,
+      anon_sym_LBRACE,
+    ACTIONS(165), 1,
+      anon_sym_let,
+    STATE(238), 1,
+      sym_identifier,
+    ACTIONS(119), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(77), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(344), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(260), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [11726] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    ACTIONS(151), 1,
+      anon_sym_LBRACE,
+    ACTIONS(155), 1,
+      anon_sym_let,
+    STATE(122), 1,
+      sym_identifier,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(286), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [11805] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(304), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(306), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [11850] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(308), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(310), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [11895] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(312), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(314), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [11940] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(316), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(318), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [11985] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(324), 1,
+      anon_sym_or,
+    ACTIONS(320), 9,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_rec,
+    ACTIONS(322), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [12032] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(9), 1,
+      sym_integer_expression,
+    ACTIONS(13), 1,
+      sym__hpath_start,
+    ACTIONS(25), 1,
+      anon_sym_BANG,
+    ACTIONS(27), 1,
+      anon_sym_DASH,
+    ACTIONS(29), 1,
+      anon_sym_LPAREN,
+    ACTIONS(31), 1,
+      anon_sym_rec,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(41), 1,
+      sym__path_start,
+    ACTIONS(159), 1,
+      anon_sym_LBRACE,
+    ACTIONS(161), 1,
+      anon_sym_let,
+    STATE(255), 1,
+      sym_identifier,
+    ACTIONS(11), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(76), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(286), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(214), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [12111] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(330), 1,
+      anon_sym_DOT,
+    ACTIONS(326), 9,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_rec,
+    ACTIONS(328), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [12158] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(332), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(334), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [12203] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(336), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(338), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [12248] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(268), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(340), 1,
+      sym_path_fragment,
+    STATE(165), 1,
+      aux_sym_path_expression_repeat1,
+    STATE(194), 1,
+      sym__immediate_interpolation,
+    ACTIONS(179), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(181), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [12301] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    ACTIONS(151), 1,
+      anon_sym_LBRACE,
+    ACTIONS(155), 1,
+      anon_sym_let,
+    STATE(122), 1,
+      sym_identifier,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(307), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [12380] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(9), 1,
+      sym_integer_expression,
+    ACTIONS(13), 1,
+      sym__hpath_start,
+    ACTIONS(25), 1,
+      anon_sym_BANG,
+    ACTIONS(27), 1,
+      anon_sym_DASH,
+    ACTIONS(29), 1,
+      anon_sym_LPAREN,
+    ACTIONS(31), 1,
+      anon_sym_rec,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(41), 1,
+      sym__path_start,
+    ACTIONS(159), 1,
+      anon_sym_LBRACE,
+    ACTIONS(161), 1,
+      anon_sym_let,
+    STATE(255), 1,
+      sym_identifier,
+    ACTIONS(11), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(76), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(321), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(214), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [12459] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(9), 1,
+      sym_integer_expression,
+    ACTIONS(13), 1,
+      sym__hpath_start,
+    ACTIONS(25), 1,
+      anon_sym_BANG,
+    ACTIONS(27), 1,
+      anon_sym_DASH,
+    ACTIONS(29), 1,
+      anon_sym_LPAREN,
+    ACTIONS(31), 1,
+      anon_sym_rec,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(41), 1,
+      sym__path_start,
+    ACTIONS(159), 1,
+      anon_sym_LBRACE,
+    ACTIONS(161), 1,
+      anon_sym_let,
+    STATE(255), 1,
+      sym_identifier,
+    ACTIONS(11), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(76), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(310), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(214), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [12538] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    ACTIONS(151), 1,
+      anon_sym_LBRACE,
+    ACTIONS(155), 1,
+      anon_sym_let,
+    STATE(122), 1,
+      sym_identifier,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(304), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [12617] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(9), 1,
+      sym_integer_expression,
+    ACTIONS(13), 1,
+      sym__hpath_start,
+    ACTIONS(25), 1,
+      anon_sym_BANG,
+    ACTIONS(27), 1,
+      anon_sym_DASH,
+    ACTIONS(29), 1,
+      anon_sym_LPAREN,
+    ACTIONS(31), 1,
+      anon_sym_rec,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(41), 1,
+      sym__path_start,
+    ACTIONS(159), 1,
+      anon_sym_LBRACE,
+    ACTIONS(161), 1,
+      anon_sym_let,
+    STATE(255), 1,
+      sym_identifier,
+    ACTIONS(11), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(76), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(315), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(214), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [12696] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(9), 1,
+      sym_integer_expression,
+    ACTIONS(13), 1,
+      sym__hpath_start,
+    ACTIONS(25), 1,
+      anon_sym_BANG,
+    ACTIONS(27), 1,
+      anon_sym_DASH,
+    ACTIONS(29), 1,
+      anon_sym_LPAREN,
+    ACTIONS(31), 1,
+      anon_sym_rec,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(41), 1,
+      sym__path_start,
+    ACTIONS(159), 1,
+      anon_sym_LBRACE,
+    ACTIONS(161), 1,
+      anon_sym_let,
+    STATE(255), 1,
+      sym_identifier,
+    ACTIONS(11), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(76), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(325), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(214), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [12775] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(9), 1,
+      sym_integer_expression,
+    ACTIONS(13), 1,
+      sym__hpath_start,
+    ACTIONS(25), 1,
+      anon_sym_BANG,
+    ACTIONS(27), 1,
+      anon_sym_DASH,
+    ACTIONS(29), 1,
+      anon_sym_LPAREN,
+    ACTIONS(31), 1,
+      anon_sym_rec,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(41), 1,
+      sym__path_start,
+    ACTIONS(159), 1,
+      anon_sym_LBRACE,
+    ACTIONS(161), 1,
+      anon_sym_let,
+    STATE(255), 1,
+      sym_identifier,
+    ACTIONS(11), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(76), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(329), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(214), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [12854] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(9), 1,
+      sym_integer_expression,
+    ACTIONS(13), 1,
+      sym__hpath_start,
+    ACTIONS(25), 1,
+      anon_sym_BANG,
+    ACTIONS(27), 1,
+      anon_sym_DASH,
+    ACTIONS(29), 1,
+      anon_sym_LPAREN,
+    ACTIONS(31), 1,
+      anon_sym_rec,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(41), 1,
+      sym__path_start,
+    ACTIONS(159), 1,
+      anon_sym_LBRACE,
+    ACTIONS(161), 1,
+      anon_sym_let,
+    STATE(255), 1,
+      sym_identifier,
+    ACTIONS(11), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(76), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(335), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(214), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [12933] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(9), 1,
+      sym_integer_expression,
+    ACTIONS(13), 1,
+      sym__hpath_start,
+    ACTIONS(25), 1,
+      anon_sym_BANG,
+    ACTIONS(27), 1,
+      anon_sym_DASH,
+    ACTIONS(29), 1,
+      anon_sym_LPAREN,
+    ACTIONS(31), 1,
+      anon_sym_rec,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(41), 1,
+      sym__path_start,
+    ACTIONS(159), 1,
+      anon_sym_LBRACE,
+    ACTIONS(161), 1,
+      anon_sym_let,
+    STATE(255), 1,
+      sym_identifier,
+    ACTIONS(11), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(76), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(340), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(214), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [13012] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(9), 1,
+      sym_integer_expression,
+    ACTIONS(13), 1,
+      sym__hpath_start,
+    ACTIONS(25), 1,
+      anon_sym_BANG,
+    ACTIONS(27), 1,
+      anon_sym_DASH,
+    ACTIONS(29), 1,
+      anon_sym_LPAREN,
+    ACTIONS(31), 1,
+      anon_sym_rec,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(41), 1,
+      sym__path_start,
+    ACTIONS(159), 1,
+      anon_sym_LBRACE,
+    ACTIONS(161), 1,
+      anon_sym_let,
+    STATE(255), 1,
+      sym_identifier,
+    ACTIONS(11), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(76), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(341), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(214), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [13091] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(342), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(345), 1,
+      sym_path_fragment,
+    STATE(163), 1,
+      aux_sym_path_expression_repeat1,
+    STATE(194), 1,
+      sym__immediate_interpolation,
+    ACTIONS(185), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(187), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [13144] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(9), 1,
+      sym_integer_expression,
+    ACTIONS(13), 1,
+      sym__hpath_start,
+    ACTIONS(25), 1,
+      anon_sym_BANG,
+    ACTIONS(27), 1,
+      anon_sym_DASH,
+    ACTIONS(29), 1,
+      anon_sym_LPAREN,
+    ACTIONS(31), 1,
+      anon_sym_rec,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(41), 1,
+      sym__path_start,
+    ACTIONS(159), 1,
+      anon_sym_LBRACE,
+    ACTIONS(161), 1,
+      anon_sym_let,
+    STATE(255), 1,
+      sym_identifier,
+    ACTIONS(11), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(76), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(314), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(214), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [13223] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(268), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(278), 1,
+      sym_path_fragment,
+    STATE(163), 1,
+      aux_sym_path_expression_repeat1,
+    STATE(194), 1,
+      sym__immediate_interpolation,
+    ACTIONS(195), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(197), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [13276] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(264), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(348), 1,
+      sym_path_fragment,
+    STATE(99), 1,
+      aux_sym_path_expression_repeat1,
+    STATE(186), 1,
+      sym__immediate_interpolation,
+    ACTIONS(199), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(201), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [13329] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(61), 1,
+      anon_sym_BANG,
+    ACTIONS(63), 1,
+      anon_sym_DASH,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    ACTIONS(151), 1,
+      anon_sym_LBRACE,
+    ACTIONS(155), 1,
+      anon_sym_let,
+    STATE(122), 1,
+      sym_identifier,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(12), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(294), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [13408] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(264), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(350), 1,
+      sym_path_fragment,
+    STATE(100), 1,
+      aux_sym_path_expression_repeat1,
+    STATE(186), 1,
+      sym__immediate_interpolation,
+    ACTIONS(179), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(181), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [13461] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(81), 1,
+      sym_integer_expression,
+    ACTIONS(85), 1,
+      sym__hpath_start,
+    ACTIONS(97), 1,
+      anon_sym_BANG,
+    ACTIONS(99), 1,
+      anon_sym_DASH,
+    ACTIONS(101), 1,
+      anon_sym_LPAREN,
+    ACTIONS(103), 1,
+      anon_sym_rec,
+    ACTIONS(105), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(107), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(109), 1,
+      anon_sym_LBRACK,
+    ACTIONS(111), 1,
+      sym_grit_metavariable,
+    ACTIONS(113), 1,
+      sym__path_start,
+    ACTIONS(167), 1,
+      anon_sym_LBRACE,
+    ACTIONS(169), 1,
+      anon_sym_let,
+    STATE(219), 1,
+      sym_identifier,
+    ACTIONS(83), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(78), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(331), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(216), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [13540] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(81), 1,
+      sym_integer_expression,
+    ACTIONS(85), 1,
+      sym__hpath_start,
+    ACTIONS(97), 1,
+      anon_sym_BANG,
+    ACTIONS(99), 1,
+      anon_sym_DASH,
+    ACTIONS(101), 1,
+      anon_sym_LPAREN,
+    ACTIONS(103), 1,
+      anon_sym_rec,
+    ACTIONS(105), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(107), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(109), 1,
+      anon_sym_LBRACK,
+    ACTIONS(111), 1,
+      sym_grit_metavariable,
+    ACTIONS(113), 1,
+      sym__path_start,
+    ACTIONS(167), 1,
+      anon_sym_LBRACE,
+    ACTIONS(169), 1,
+      anon_sym_let,
+    STATE(219), 1,
+      sym_identifier,
+    ACTIONS(83), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(78), 4,
+      sym__expr_apply_expression,
+      sym_apply_expression,
+      sym__expr_select_expression,
+      sym_select_expression,
+    STATE(286), 4,
+      sym__expr_op,
+      sym_has_attr_expression,
+      sym_unary_expression,
+      sym_binary_expression,
+    STATE(216), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [13619] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(352), 1,
+      anon_sym_DOLLAR_LBRACE,
+    ACTIONS(355), 1,
+      sym_path_fragment,
+    STATE(171), 1,
+      aux_sym_path_expression_repeat1,
+    STATE(187), 1,
+      sym__immediate_interpolation,
+    ACTIONS(185), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(187), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [13672] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(358), 9,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_rec,
+    ACTIONS(360), 27,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [13716] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(362), 9,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_rec,
+    ACTIONS(364), 26,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [13759] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(366), 1,
+      anon_sym_DOT,
+    STATE(174), 1,
+      aux_sym_attrpath_repeat1,
+    ACTIONS(227), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(229), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [13806] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(246), 2,
+      anon_sym_COLON,
+      anon_sym_AT,
+    ACTIONS(242), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(244), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [13851] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(223), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(225), 24,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_COLON,
+      anon_sym_AT,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [13894] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(369), 1,
+      anon_sym_DOT,
+    STATE(181), 1,
+      aux_sym_attrpath_repeat1,
+    ACTIONS(205), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(207), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [13941] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(223), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(225), 24,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_COLON,
+      anon_sym_AT,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [13984] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(371), 1,
+      anon_sym_DOT,
+    STATE(182), 1,
+      aux_sym_attrpath_repeat1,
+    ACTIONS(205), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(207), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [14031] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(246), 2,
+      anon_sym_COLON,
+      anon_sym_AT,
+    ACTIONS(242), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(244), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [14076] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(369), 1,
+      anon_sym_DOT,
+    STATE(185), 1,
+      aux_sym_attrpath_repeat1,
+    ACTIONS(219), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(221), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [14123] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(371), 1,
+      anon_sym_DOT,
+    STATE(174), 1,
+      aux_sym_attrpath_repeat1,
+    ACTIONS(219), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(221), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [14170] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(373), 1,
+      anon_sym_COLON,
+    ACTIONS(375), 1,
+      anon_sym_AT,
+    ACTIONS(234), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(236), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [14217] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(215), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(217), 24,
+      sym__path_start,
+      sym_path_fragment,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [14260] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(377), 1,
+      anon_sym_DOT,
+    STATE(185), 1,
+      aux_sym_attrpath_repeat1,
+    ACTIONS(227), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(229), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [14307] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(211), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(213), 24,
+      sym__path_start,
+      sym_path_fragment,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [14350] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(211), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(213), 24,
+      sym__path_start,
+      sym_path_fragment,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [14393] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(246), 2,
+      anon_sym_COLON,
+      anon_sym_AT,
+    ACTIONS(242), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(244), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [14438] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(380), 1,
+      anon_sym_COLON,
+    ACTIONS(382), 1,
+      anon_sym_AT,
+    ACTIONS(234), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(236), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [14485] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(384), 1,
+      anon_sym_DOT,
+    STATE(193), 1,
+      aux_sym_attrpath_repeat1,
+    ACTIONS(205), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(207), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [14532] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(223), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(225), 25,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_COLON,
+      anon_sym_AT,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [14575] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(215), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(217), 25,
+      sym__path_start,
+      sym_path_fragment,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [14618] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(384), 1,
+      anon_sym_DOT,
+    STATE(197), 1,
+      aux_sym_attrpath_repeat1,
+    ACTIONS(219), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(221), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [14665] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(211), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(213), 25,
+      sym__path_start,
+      sym_path_fragment,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [14708] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(215), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(217), 24,
+      sym__path_start,
+      sym_path_fragment,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_DOLLAR_LBRACE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [14751] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(386), 1,
+      anon_sym_COLON,
+    ACTIONS(388), 1,
+      anon_sym_AT,
+    ACTIONS(234), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(236), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [14798] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(390), 1,
+      anon_sym_DOT,
+    STATE(197), 1,
+      aux_sym_attrpath_repeat1,
+    ACTIONS(227), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(229), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [14845] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(248), 12,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(250), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [14887] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(223), 12,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(225), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [14929] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(252), 12,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(254), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [14971] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(260), 12,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(262), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [15013] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(223), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(225), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [15055] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(256), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(258), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [15097] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(223), 12,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(225), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [15139] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(252), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(254), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [15181] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(256), 12,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(258), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [15223] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(252), 12,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(254), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [15265] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(260), 12,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(262), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [15307] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(260), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(262), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [15349] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(248), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(250), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [15391] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(256), 12,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(258), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [15433] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(248), 12,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_or,
+      anon_sym_rec,
+    ACTIONS(250), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [15475] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(252), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(254), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [15516] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(393), 1,
+      anon_sym_DOT,
+    ACTIONS(326), 9,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_rec,
+    ACTIONS(328), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [15559] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(280), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(282), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [15600] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(395), 1,
+      anon_sym_DOT,
+    ACTIONS(326), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_rec,
+    ACTIONS(328), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [15643] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(296), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(298), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [15684] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(244), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [15725] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(234), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(236), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [15766] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(304), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(306), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [15807] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(290), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(292), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [15848] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(300), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(302), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [15889] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(308), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(310), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [15930] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(260), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(262), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [15971] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(312), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(314), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [16012] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(316), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(318), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [16053] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(280), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(282), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [16094] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(296), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(298), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [16135] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(244), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [16176] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(304), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(306), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [16217] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(308), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(310), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [16258] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(312), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(314), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [16299] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(316), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(318), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [16340] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(397), 1,
+      anon_sym_or,
+    ACTIONS(320), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_rec,
+    ACTIONS(322), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [16383] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(332), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(334), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [16424] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(336), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(338), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [16465] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(399), 1,
+      anon_sym_or,
+    ACTIONS(320), 9,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_rec,
+    ACTIONS(322), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [16508] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(234), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(236), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [16549] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(252), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(254), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [16590] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(290), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(292), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [16631] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(300), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(302), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [16672] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(332), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(334), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [16713] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(336), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(338), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [16754] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(280), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(282), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [16795] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(296), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(298), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [16836] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(242), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(244), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [16877] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(304), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(306), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [16918] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(308), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(310), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [16959] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(260), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(262), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [17000] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(312), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(314), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [17041] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(316), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(318), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [17082] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(401), 1,
+      anon_sym_or,
+    ACTIONS(320), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_rec,
+    ACTIONS(322), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [17125] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(332), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(334), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [17166] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(336), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(338), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [17207] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(234), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(236), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [17248] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(252), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(254), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [17289] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(260), 11,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(262), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [17330] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(290), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(292), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [17371] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(300), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_DOT,
+      anon_sym_rec,
+    ACTIONS(302), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [17412] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(403), 1,
+      anon_sym_DOT,
+    ACTIONS(326), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_rec,
+    ACTIONS(328), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [17455] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(362), 9,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_rec,
+    ACTIONS(364), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [17495] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(358), 9,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_rec,
+    ACTIONS(360), 23,
+      sym__path_start,
+      ts_builtin_sym_end,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [17535] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(362), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_rec,
+    ACTIONS(364), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [17575] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(358), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_then,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_rec,
+    ACTIONS(360), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [17615] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(362), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_rec,
+    ACTIONS(364), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [17655] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(358), 10,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_else,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_rec,
+    ACTIONS(360), 22,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_QMARK,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+  [17695] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    ACTIONS(151), 1,
+      anon_sym_LBRACE,
+    ACTIONS(155), 1,
+      anon_sym_let,
+    ACTIONS(405), 1,
+      anon_sym_RBRACK,
+    STATE(122), 1,
+      sym_identifier,
+    STATE(275), 1,
+      aux_sym_list_expression_repeat1,
+    STATE(346), 2,
+      sym__expr_select_expression,
+      sym_select_expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [17766] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    ACTIONS(151), 1,
+      anon_sym_LBRACE,
+    ACTIONS(155), 1,
+      anon_sym_let,
+    ACTIONS(407), 1,
+      anon_sym_RBRACK,
+    STATE(122), 1,
+      sym_identifier,
+    STATE(274), 1,
+      aux_sym_list_expression_repeat1,
+    STATE(346), 2,
+      sym__expr_select_expression,
+      sym_select_expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [17837] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    ACTIONS(151), 1,
+      anon_sym_LBRACE,
+    ACTIONS(155), 1,
+      anon_sym_let,
+    ACTIONS(409), 1,
+      anon_sym_RBRACK,
+    STATE(122), 1,
+      sym_identifier,
+    STATE(270), 1,
+      aux_sym_list_expression_repeat1,
+    STATE(346), 2,
+      sym__expr_select_expression,
+      sym_select_expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [17908] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    ACTIONS(151), 1,
+      anon_sym_LBRACE,
+    ACTIONS(155), 1,
+      anon_sym_let,
+    ACTIONS(411), 1,
+      anon_sym_RBRACK,
+    STATE(122), 1,
+      sym_identifier,
+    STATE(274), 1,
+      aux_sym_list_expression_repeat1,
+    STATE(346), 2,
+      sym__expr_select_expression,
+      sym_select_expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [17979] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    ACTIONS(151), 1,
+      anon_sym_LBRACE,
+    ACTIONS(155), 1,
+      anon_sym_let,
+    ACTIONS(413), 1,
+      anon_sym_RBRACK,
+    STATE(122), 1,
+      sym_identifier,
+    STATE(272), 1,
+      aux_sym_list_expression_repeat1,
+    STATE(346), 2,
+      sym__expr_select_expression,
+      sym_select_expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [18050] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    ACTIONS(151), 1,
+      anon_sym_LBRACE,
+    ACTIONS(155), 1,
+      anon_sym_let,
+    ACTIONS(415), 1,
+      anon_sym_RBRACK,
+    STATE(122), 1,
+      sym_identifier,
+    STATE(274), 1,
+      aux_sym_list_expression_repeat1,
+    STATE(346), 2,
+      sym__expr_select_expression,
+      sym_select_expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [18121] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    ACTIONS(151), 1,
+      anon_sym_LBRACE,
+    ACTIONS(155), 1,
+      anon_sym_let,
+    ACTIONS(417), 1,
+      anon_sym_RBRACK,
+    STATE(122), 1,
+      sym_identifier,
+    STATE(268), 1,
+      aux_sym_list_expression_repeat1,
+    STATE(346), 2,
+      sym__expr_select_expression,
+      sym_select_expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [18192] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(419), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(422), 1,
+      sym_integer_expression,
+    ACTIONS(428), 1,
+      sym__hpath_start,
+    ACTIONS(431), 1,
+      anon_sym_LBRACE,
+    ACTIONS(434), 1,
+      anon_sym_let,
+    ACTIONS(437), 1,
+      anon_sym_LPAREN,
+    ACTIONS(440), 1,
+      anon_sym_rec,
+    ACTIONS(443), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(446), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(449), 1,
+      anon_sym_LBRACK,
+    ACTIONS(452), 1,
+      anon_sym_RBRACK,
+    ACTIONS(454), 1,
+      sym_grit_metavariable,
+    ACTIONS(457), 1,
+      sym__path_start,
+    STATE(122), 1,
+      sym_identifier,
+    STATE(274), 1,
+      aux_sym_list_expression_repeat1,
+    STATE(346), 2,
+      sym__expr_select_expression,
+      sym_select_expression,
+    ACTIONS(425), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [18263] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    ACTIONS(151), 1,
+      anon_sym_LBRACE,
+    ACTIONS(155), 1,
+      anon_sym_let,
+    ACTIONS(460), 1,
+      anon_sym_RBRACK,
+    STATE(122), 1,
+      sym_identifier,
+    STATE(274), 1,
+      aux_sym_list_expression_repeat1,
+    STATE(346), 2,
+      sym__expr_select_expression,
+      sym_select_expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [18334] = 17,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(45), 1,
+      sym_integer_expression,
+    ACTIONS(49), 1,
+      sym__hpath_start,
+    ACTIONS(65), 1,
+      anon_sym_LPAREN,
+    ACTIONS(67), 1,
+      anon_sym_rec,
+    ACTIONS(69), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(71), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(73), 1,
+      anon_sym_LBRACK,
+    ACTIONS(75), 1,
+      sym_grit_metavariable,
+    ACTIONS(77), 1,
+      sym__path_start,
+    ACTIONS(151), 1,
+      anon_sym_LBRACE,
+    ACTIONS(155), 1,
+      anon_sym_let,
+    STATE(122), 1,
+      sym_identifier,
+    STATE(172), 2,
+      sym__expr_select_expression,
+      sym_select_expression,
+    ACTIONS(47), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(149), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [18399] = 17,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(9), 1,
+      sym_integer_expression,
+    ACTIONS(13), 1,
+      sym__hpath_start,
+    ACTIONS(29), 1,
+      anon_sym_LPAREN,
+    ACTIONS(31), 1,
+      anon_sym_rec,
+    ACTIONS(33), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(35), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(37), 1,
+      anon_sym_LBRACK,
+    ACTIONS(39), 1,
+      sym_grit_metavariable,
+    ACTIONS(41), 1,
+      sym__path_start,
+    ACTIONS(159), 1,
+      anon_sym_LBRACE,
+    ACTIONS(161), 1,
+      anon_sym_let,
+    STATE(255), 1,
+      sym_identifier,
+    STATE(262), 2,
+      sym__expr_select_expression,
+      sym_select_expression,
+    ACTIONS(11), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(214), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [18464] = 17,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(115), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(117), 1,
+      sym_integer_expression,
+    ACTIONS(121), 1,
+      sym__hpath_start,
+    ACTIONS(137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(139), 1,
+      anon_sym_rec,
+    ACTIONS(141), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(143), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(145), 1,
+      anon_sym_LBRACK,
+    ACTIONS(147), 1,
+      sym_grit_metavariable,
+    ACTIONS(149), 1,
+      sym__path_start,
+    ACTIONS(163), 1,
+      anon_sym_LBRACE,
+    ACTIONS(165), 1,
+      anon_sym_let,
+    STATE(238), 1,
+      sym_identifier,
+    STATE(266), 2,
+      sym__expr_select_expression,
+      sym_select_expression,
+    ACTIONS(119), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(260), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [18529] = 17,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(79), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(81), 1,
+      sym_integer_expression,
+    ACTIONS(85), 1,
+      sym__hpath_start,
+    ACTIONS(101), 1,
+      anon_sym_LPAREN,
+    ACTIONS(103), 1,
+      anon_sym_rec,
+    ACTIONS(105), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(107), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(109), 1,
+      anon_sym_LBRACK,
+    ACTIONS(111), 1,
+      sym_grit_metavariable,
+    ACTIONS(113), 1,
+      sym__path_start,
+    ACTIONS(167), 1,
+      anon_sym_LBRACE,
+    ACTIONS(169), 1,
+      anon_sym_let,
+    STATE(219), 1,
+      sym_identifier,
+    STATE(264), 2,
+      sym__expr_select_expression,
+      sym_select_expression,
+    ACTIONS(83), 3,
+      sym_float_expression,
+      sym_spath_expression,
+      sym_uri_expression,
+    STATE(216), 11,
+      sym_variable_expression,
+      sym_path_expression,
+      sym_hpath_expression,
+      sym__expr_simple,
+      sym_parenthesized_expression,
+      sym_attrset_expression,
+      sym_let_attrset_expression,
+      sym_rec_attrset_expression,
+      sym_string_expression,
+      sym_indented_string_expression,
+      sym_list_expression,
+  [18594] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(223), 6,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_EQ,
+    ACTIONS(225), 21,
+      aux_sym_identifier_token1,
+      anon_sym_COLON,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_DOT,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_DOLLAR_LBRACE2,
+      sym_grit_metavariable,
+  [18629] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(260), 6,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_EQ,
+    ACTIONS(262), 20,
+      aux_sym_identifier_token1,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_DOT,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_DOLLAR_LBRACE2,
+      sym_grit_metavariable,
+  [18663] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(248), 6,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_EQ,
+    ACTIONS(250), 20,
+      aux_sym_identifier_token1,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_DOT,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_DOLLAR_LBRACE2,
+      sym_grit_metavariable,
+  [18697] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(252), 6,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_EQ,
+    ACTIONS(254), 20,
+      aux_sym_identifier_token1,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_DOT,
+      anon_sym_RPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_DOLLAR_LBRACE2,
+      sym_grit_metavariable,
+  [18731] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(256), 6,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_EQ,
+    ACTIONS(258), 19,
+      ts_builtin_sym_end,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_DOT,
+      anon_sym_RPAREN,
+  [18764] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(464), 5,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+    ACTIONS(462), 18,
+      ts_builtin_sym_end,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_RPAREN,
+  [18795] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(468), 5,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+    ACTIONS(466), 18,
+      ts_builtin_sym_end,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_RPAREN,
+  [18826] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(470), 1,
+      anon_sym_DOT,
+    STATE(287), 1,
+      aux_sym_attrpath_repeat1,
+    ACTIONS(227), 6,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_EQ,
+    ACTIONS(229), 15,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_RPAREN,
+  [18861] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(473), 1,
+      anon_sym_DOT,
+    STATE(287), 1,
+      aux_sym_attrpath_repeat1,
+    ACTIONS(219), 6,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+      anon_sym_EQ,
+    ACTIONS(221), 15,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_RPAREN,
+  [18896] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(473), 1,
+      anon_sym_DOT,
+    STATE(288), 1,
+      aux_sym_attrpath_repeat1,
+    ACTIONS(205), 5,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+    ACTIONS(207), 15,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_RPAREN,
+  [18930] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(475), 1,
+      anon_sym_DOT,
+    STATE(291), 1,
+      aux_sym_attrpath_repeat1,
+    ACTIONS(205), 5,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+    ACTIONS(207), 14,
+      ts_builtin_sym_end,
+      anon_sym_QMARK,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+  [18963] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(475), 1,
+      anon_sym_DOT,
+    STATE(292), 1,
+      aux_sym_attrpath_repeat1,
+    ACTIONS(219), 5,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+    ACTIONS(221), 14,
+      ts_builtin_sym_end,
+      anon_sym_QMARK,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+  [18996] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(477), 1,
+      anon_sym_DOT,
+    STATE(292), 1,
+      aux_sym_attrpath_repeat1,
+    ACTIONS(227), 5,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+    ACTIONS(229), 14,
+      ts_builtin_sym_end,
+      anon_sym_QMARK,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+  [19029] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(223), 5,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+    ACTIONS(225), 15,
+      ts_builtin_sym_end,
+      anon_sym_QMARK,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_DOT,
+  [19057] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(482), 1,
+      anon_sym_QMARK,
+    ACTIONS(490), 1,
+      anon_sym_STAR,
+    ACTIONS(492), 1,
+      anon_sym_SLASH,
+    ACTIONS(494), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(496), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(484), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(486), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(488), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(480), 9,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+      anon_sym_RPAREN,
+  [19099] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(482), 1,
+      anon_sym_QMARK,
+    ACTIONS(490), 1,
+      anon_sym_STAR,
+    ACTIONS(492), 1,
+      anon_sym_SLASH,
+    ACTIONS(494), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(496), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(484), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(498), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(480), 11,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+      anon_sym_RPAREN,
+  [19139] = 12,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(482), 1,
+      anon_sym_QMARK,
+    ACTIONS(490), 1,
+      anon_sym_STAR,
+    ACTIONS(492), 1,
+      anon_sym_SLASH,
+    ACTIONS(494), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(496), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(502), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(484), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(486), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(488), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(500), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(480), 6,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+      anon_sym_RPAREN,
+  [19185] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(482), 1,
+      anon_sym_QMARK,
+    ACTIONS(496), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(498), 5,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+    ACTIONS(480), 13,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_RPAREN,
+  [19217] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(248), 5,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+    ACTIONS(250), 15,
+      ts_builtin_sym_end,
+      anon_sym_QMARK,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_DOT,
+  [19245] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(482), 1,
+      anon_sym_QMARK,
+    ACTIONS(490), 1,
+      anon_sym_STAR,
+    ACTIONS(492), 1,
+      anon_sym_SLASH,
+    ACTIONS(494), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(496), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(502), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(504), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(506), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(484), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(486), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(488), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(500), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(480), 4,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
+  [19295] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(252), 5,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+    ACTIONS(254), 15,
+      ts_builtin_sym_end,
+      anon_sym_QMARK,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_DOT,
+  [19323] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(260), 5,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+    ACTIONS(262), 15,
+      ts_builtin_sym_end,
+      anon_sym_QMARK,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_PLUS_PLUS,
+      anon_sym_DOT,
+  [19351] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(482), 1,
+      anon_sym_QMARK,
+    ACTIONS(490), 1,
+      anon_sym_STAR,
+    ACTIONS(492), 1,
+      anon_sym_SLASH,
+    ACTIONS(494), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(496), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(502), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(504), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(506), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(484), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(486), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(488), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(500), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(508), 4,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
+  [19401] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(482), 1,
+      anon_sym_QMARK,
+    ACTIONS(490), 1,
+      anon_sym_STAR,
+    ACTIONS(492), 1,
+      anon_sym_SLASH,
+    ACTIONS(494), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(496), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(484), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(498), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(480), 11,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+      anon_sym_RPAREN,
+  [19441] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(482), 1,
+      anon_sym_QMARK,
+    ACTIONS(490), 1,
+      anon_sym_STAR,
+    ACTIONS(492), 1,
+      anon_sym_SLASH,
+    ACTIONS(496), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(468), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(484), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(466), 12,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_RPAREN,
+  [19479] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(482), 1,
+      anon_sym_QMARK,
+    ACTIONS(490), 1,
+      anon_sym_STAR,
+    ACTIONS(492), 1,
+      anon_sym_SLASH,
+    ACTIONS(494), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(496), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(484), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(486), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(488), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(500), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(480), 7,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+      anon_sym_RPAREN,
+  [19523] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(482), 1,
+      anon_sym_QMARK,
+    ACTIONS(496), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(498), 5,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+    ACTIONS(480), 13,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_RPAREN,
+  [19555] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(482), 1,
+      anon_sym_QMARK,
+    ACTIONS(490), 1,
+      anon_sym_STAR,
+    ACTIONS(492), 1,
+      anon_sym_SLASH,
+    ACTIONS(496), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(498), 4,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+    ACTIONS(480), 12,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+      anon_sym_RPAREN,
+  [19591] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(512), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(498), 5,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+    ACTIONS(480), 10,
+      anon_sym_then,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+  [19620] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(512), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(522), 1,
+      anon_sym_STAR,
+    ACTIONS(524), 1,
+      anon_sym_SLASH,
+    ACTIONS(526), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(514), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(516), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(518), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(520), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(480), 4,
+      anon_sym_then,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+  [19661] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(534), 1,
+      anon_sym_STAR,
+    ACTIONS(536), 1,
+      anon_sym_SLASH,
+    ACTIONS(538), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(540), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(528), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(530), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(532), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(480), 6,
+      ts_builtin_sym_end,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+  [19700] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(542), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(544), 1,
+      anon_sym_RBRACE,
+    ACTIONS(546), 1,
+      sym_ellipses,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(550), 1,
+      anon_sym_inherit,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(554), 1,
+      sym_grit_metavariable,
+    STATE(363), 1,
+      aux_sym_binding_set_repeat1,
+    STATE(430), 1,
+      sym_identifier,
+    STATE(456), 1,
+      sym_formal,
+    STATE(480), 1,
+      sym_attrpath,
+    STATE(536), 1,
+      sym_binding_set,
+    STATE(448), 2,
+      sym_string_expression,
+      sym_interpolation,
+    STATE(427), 3,
+      sym_binding,
+      sym_inherit,
+      sym_inherit_from,
+  [19749] = 12,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(512), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(522), 1,
+      anon_sym_STAR,
+    ACTIONS(524), 1,
+      anon_sym_SLASH,
+    ACTIONS(526), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(556), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(514), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(516), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(518), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(520), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(480), 3,
+      anon_sym_then,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+  [19792] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(534), 1,
+      anon_sym_STAR,
+    ACTIONS(536), 1,
+      anon_sym_SLASH,
+    ACTIONS(538), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(540), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(498), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(528), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(480), 8,
+      ts_builtin_sym_end,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+  [19829] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(534), 1,
+      anon_sym_STAR,
+    ACTIONS(536), 1,
+      anon_sym_SLASH,
+    ACTIONS(540), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(468), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(528), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(466), 9,
+      ts_builtin_sym_end,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+  [19864] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(534), 1,
+      anon_sym_STAR,
+    ACTIONS(536), 1,
+      anon_sym_SLASH,
+    ACTIONS(538), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(540), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(528), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(530), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(532), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(558), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(480), 4,
+      ts_builtin_sym_end,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+  [19905] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(560), 1,
+      anon_sym_STAR,
+    ACTIONS(562), 1,
+      anon_sym_SLASH,
+    ACTIONS(564), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(498), 4,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+    ACTIONS(480), 9,
+      anon_sym_else,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+  [19938] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(560), 1,
+      anon_sym_STAR,
+    ACTIONS(562), 1,
+      anon_sym_SLASH,
+    ACTIONS(564), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(572), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(566), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(568), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(570), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(480), 6,
+      anon_sym_else,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+  [19977] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(560), 1,
+      anon_sym_STAR,
+    ACTIONS(562), 1,
+      anon_sym_SLASH,
+    ACTIONS(564), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(572), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(498), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(566), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(480), 8,
+      anon_sym_else,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+  [20014] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(560), 1,
+      anon_sym_STAR,
+    ACTIONS(562), 1,
+      anon_sym_SLASH,
+    ACTIONS(564), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(572), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(566), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(568), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(570), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(574), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(480), 4,
+      anon_sym_else,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+  [20055] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(542), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(546), 1,
+      sym_ellipses,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(550), 1,
+      anon_sym_inherit,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(554), 1,
+      sym_grit_metavariable,
+    ACTIONS(576), 1,
+      anon_sym_RBRACE,
+    STATE(363), 1,
+      aux_sym_binding_set_repeat1,
+    STATE(430), 1,
+      sym_identifier,
+    STATE(456), 1,
+      sym_formal,
+    STATE(480), 1,
+      sym_attrpath,
+    STATE(514), 1,
+      sym_binding_set,
+    STATE(448), 2,
+      sym_string_expression,
+      sym_interpolation,
+    STATE(427), 3,
+      sym_binding,
+      sym_inherit,
+      sym_inherit_from,
+  [20104] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(534), 1,
+      anon_sym_STAR,
+    ACTIONS(536), 1,
+      anon_sym_SLASH,
+    ACTIONS(540), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(498), 4,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+    ACTIONS(480), 9,
+      ts_builtin_sym_end,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+  [20137] = 12,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(560), 1,
+      anon_sym_STAR,
+    ACTIONS(562), 1,
+      anon_sym_SLASH,
+    ACTIONS(564), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(572), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(578), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(566), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(568), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(570), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(574), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(480), 3,
+      anon_sym_else,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+  [20180] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(564), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(498), 5,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+    ACTIONS(480), 10,
+      anon_sym_else,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+  [20209] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(480), 1,
+      anon_sym_else,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(560), 1,
+      anon_sym_STAR,
+    ACTIONS(562), 1,
+      anon_sym_SLASH,
+    ACTIONS(564), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(572), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(578), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(580), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(582), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(566), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(568), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(570), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(574), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+  [20256] = 12,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(534), 1,
+      anon_sym_STAR,
+    ACTIONS(536), 1,
+      anon_sym_SLASH,
+    ACTIONS(538), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(540), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(584), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(528), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(530), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(532), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(558), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(480), 3,
+      ts_builtin_sym_end,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+  [20299] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(560), 1,
+      anon_sym_STAR,
+    ACTIONS(562), 1,
+      anon_sym_SLASH,
+    ACTIONS(564), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(572), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(498), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(566), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(480), 8,
+      anon_sym_else,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+  [20336] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(512), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(498), 5,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+    ACTIONS(480), 10,
+      anon_sym_then,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+  [20365] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(542), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(546), 1,
+      sym_ellipses,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(550), 1,
+      anon_sym_inherit,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(554), 1,
+      sym_grit_metavariable,
+    ACTIONS(586), 1,
+      anon_sym_RBRACE,
+    STATE(363), 1,
+      aux_sym_binding_set_repeat1,
+    STATE(430), 1,
+      sym_identifier,
+    STATE(456), 1,
+      sym_formal,
+    STATE(480), 1,
+      sym_attrpath,
+    STATE(531), 1,
+      sym_binding_set,
+    STATE(448), 2,
+      sym_string_expression,
+      sym_interpolation,
+    STATE(427), 3,
+      sym_binding,
+      sym_inherit,
+      sym_inherit_from,
+  [20414] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(540), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(498), 5,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+    ACTIONS(480), 10,
+      ts_builtin_sym_end,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+  [20443] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(508), 1,
+      anon_sym_then,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(512), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(522), 1,
+      anon_sym_STAR,
+    ACTIONS(524), 1,
+      anon_sym_SLASH,
+    ACTIONS(526), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(556), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(588), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(590), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(514), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(516), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(518), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(520), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [20490] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(512), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(522), 1,
+      anon_sym_STAR,
+    ACTIONS(524), 1,
+      anon_sym_SLASH,
+    ACTIONS(468), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(514), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(466), 9,
+      anon_sym_then,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+  [20525] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(512), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(522), 1,
+      anon_sym_STAR,
+    ACTIONS(524), 1,
+      anon_sym_SLASH,
+    ACTIONS(526), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(498), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(514), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(480), 8,
+      anon_sym_then,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+  [20562] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(512), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(522), 1,
+      anon_sym_STAR,
+    ACTIONS(524), 1,
+      anon_sym_SLASH,
+    ACTIONS(498), 4,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+    ACTIONS(480), 9,
+      anon_sym_then,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+  [20595] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(542), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(546), 1,
+      sym_ellipses,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(550), 1,
+      anon_sym_inherit,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(554), 1,
+      sym_grit_metavariable,
+    ACTIONS(592), 1,
+      anon_sym_RBRACE,
+    STATE(363), 1,
+      aux_sym_binding_set_repeat1,
+    STATE(430), 1,
+      sym_identifier,
+    STATE(456), 1,
+      sym_formal,
+    STATE(480), 1,
+      sym_attrpath,
+    STATE(538), 1,
+      sym_binding_set,
+    STATE(448), 2,
+      sym_string_expression,
+      sym_interpolation,
+    STATE(427), 3,
+      sym_binding,
+      sym_inherit,
+      sym_inherit_from,
+  [20644] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(480), 1,
+      ts_builtin_sym_end,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(534), 1,
+      anon_sym_STAR,
+    ACTIONS(536), 1,
+      anon_sym_SLASH,
+    ACTIONS(538), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(540), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(584), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(594), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(596), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(528), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(530), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(532), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(558), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+  [20691] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(508), 1,
+      anon_sym_else,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(560), 1,
+      anon_sym_STAR,
+    ACTIONS(562), 1,
+      anon_sym_SLASH,
+    ACTIONS(564), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(572), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(578), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(580), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(582), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(566), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(568), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(570), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(574), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+  [20738] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(560), 1,
+      anon_sym_STAR,
+    ACTIONS(562), 1,
+      anon_sym_SLASH,
+    ACTIONS(564), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(468), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(566), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(466), 9,
+      anon_sym_else,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+  [20773] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(480), 1,
+      anon_sym_then,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(512), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(522), 1,
+      anon_sym_STAR,
+    ACTIONS(524), 1,
+      anon_sym_SLASH,
+    ACTIONS(526), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(556), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(588), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(590), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(514), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(516), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+    ACTIONS(518), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(520), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [20820] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(512), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(522), 1,
+      anon_sym_STAR,
+    ACTIONS(524), 1,
+      anon_sym_SLASH,
+    ACTIONS(526), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(514), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(518), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(520), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(480), 6,
+      anon_sym_then,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+  [20859] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(534), 1,
+      anon_sym_STAR,
+    ACTIONS(536), 1,
+      anon_sym_SLASH,
+    ACTIONS(538), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(540), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(498), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(528), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(480), 8,
+      ts_builtin_sym_end,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+  [20896] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(540), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(498), 5,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+    ACTIONS(480), 10,
+      ts_builtin_sym_end,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+  [20925] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(512), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(522), 1,
+      anon_sym_STAR,
+    ACTIONS(524), 1,
+      anon_sym_SLASH,
+    ACTIONS(526), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(498), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(514), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(480), 8,
+      anon_sym_then,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_DASH_GT,
+  [20962] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(508), 1,
+      ts_builtin_sym_end,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(534), 1,
+      anon_sym_STAR,
+    ACTIONS(536), 1,
+      anon_sym_SLASH,
+    ACTIONS(538), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(540), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(584), 1,
+      anon_sym_AMP_AMP,
+    ACTIONS(594), 1,
+      anon_sym_PIPE_PIPE,
+    ACTIONS(596), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(528), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(530), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(532), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(558), 2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+  [21009] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(510), 1,
+      anon_sym_QMARK,
+    ACTIONS(564), 1,
+      anon_sym_PLUS_PLUS,
+    ACTIONS(498), 5,
+      anon_sym_DASH,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_SLASH,
+    ACTIONS(480), 10,
+      anon_sym_else,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP_AMP,
+      anon_sym_PIPE_PIPE,
+      anon_sym_STAR,
+      anon_sym_DASH_GT,
+      anon_sym_SLASH_SLASH,
+  [21038] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(542), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(598), 1,
+      anon_sym_LBRACE,
+    ACTIONS(600), 1,
+      anon_sym_in,
+    ACTIONS(602), 1,
+      anon_sym_inherit,
+    ACTIONS(604), 1,
+      sym_grit_metavariable,
+    STATE(362), 1,
+      aux_sym_binding_set_repeat1,
+    STATE(529), 1,
+      sym_binding_set,
+    STATE(544), 1,
+      sym_attrpath,
+    STATE(431), 3,
+      sym_binding,
+      sym_inherit,
+      sym_inherit_from,
+    STATE(448), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [21082] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(606), 4,
+      aux_sym_identifier_token1,
+      sym_integer_expression,
+      anon_sym_let,
+      anon_sym_rec,
+    ACTIONS(608), 12,
+      sym__path_start,
+      sym_float_expression,
+      sym__hpath_start,
+      sym_spath_expression,
+      sym_uri_expression,
+      anon_sym_LBRACE,
+      anon_sym_LPAREN,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE_SQUOTE,
+      anon_sym_LBRACK,
+      anon_sym_RBRACK,
+      sym_grit_metavariable,
+  [21106] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(542), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(602), 1,
+      anon_sym_inherit,
+    ACTIONS(604), 1,
+      sym_grit_metavariable,
+    ACTIONS(610), 1,
+      anon_sym_LBRACE,
+    ACTIONS(612), 1,
+      anon_sym_in,
+    STATE(362), 1,
+      aux_sym_binding_set_repeat1,
+    STATE(540), 1,
+      sym_binding_set,
+    STATE(544), 1,
+      sym_attrpath,
+    STATE(431), 3,
+      sym_binding,
+      sym_inherit,
+      sym_inherit_from,
+    STATE(448), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [21150] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(542), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(602), 1,
+      anon_sym_inherit,
+    ACTIONS(604), 1,
+      sym_grit_metavariable,
+    ACTIONS(614), 1,
+      anon_sym_LBRACE,
+    ACTIONS(616), 1,
+      anon_sym_in,
+    STATE(362), 1,
+      aux_sym_binding_set_repeat1,
+    STATE(474), 1,
+      sym_binding_set,
+    STATE(544), 1,
+      sym_attrpath,
+    STATE(431), 3,
+      sym_binding,
+      sym_inherit,
+      sym_inherit_from,
+    STATE(448), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [21194] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(542), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(602), 1,
+      anon_sym_inherit,
+    ACTIONS(604), 1,
+      sym_grit_metavariable,
+    ACTIONS(618), 1,
+      anon_sym_LBRACE,
+    ACTIONS(620), 1,
+      anon_sym_in,
+    STATE(362), 1,
+      aux_sym_binding_set_repeat1,
+    STATE(482), 1,
+      sym_binding_set,
+    STATE(544), 1,
+      sym_attrpath,
+    STATE(431), 3,
+      sym_binding,
+      sym_inherit,
+      sym_inherit_from,
+    STATE(448), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [21238] = 12,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(542), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(550), 1,
+      anon_sym_inherit,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(622), 1,
+      anon_sym_RBRACE,
+    ACTIONS(624), 1,
+      sym_grit_metavariable,
+    STATE(363), 1,
+      aux_sym_binding_set_repeat1,
+    STATE(480), 1,
+      sym_attrpath,
+    STATE(517), 1,
+      sym_binding_set,
+    STATE(427), 3,
+      sym_binding,
+      sym_inherit,
+      sym_inherit_from,
+    STATE(448), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [21279] = 12,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(542), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(550), 1,
+      anon_sym_inherit,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(624), 1,
+      sym_grit_metavariable,
+    ACTIONS(626), 1,
+      anon_sym_RBRACE,
+    STATE(363), 1,
+      aux_sym_binding_set_repeat1,
+    STATE(480), 1,
+      sym_attrpath,
+    STATE(487), 1,
+      sym_binding_set,
+    STATE(427), 3,
+      sym_binding,
+      sym_inherit,
+      sym_inherit_from,
+    STATE(448), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [21320] = 12,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(542), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(550), 1,
+      anon_sym_inherit,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(624), 1,
+      sym_grit_metavariable,
+    ACTIONS(628), 1,
+      anon_sym_RBRACE,
+    STATE(363), 1,
+      aux_sym_binding_set_repeat1,
+    STATE(473), 1,
+      sym_binding_set,
+    STATE(480), 1,
+      sym_attrpath,
+    STATE(427), 3,
+      sym_binding,
+      sym_inherit,
+      sym_inherit_from,
+    STATE(448), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [21361] = 12,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(542), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(550), 1,
+      anon_sym_inherit,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(624), 1,
+      sym_grit_metavariable,
+    ACTIONS(630), 1,
+      anon_sym_RBRACE,
+    STATE(363), 1,
+      aux_sym_binding_set_repeat1,
+    STATE(480), 1,
+      sym_attrpath,
+    STATE(536), 1,
+      sym_binding_set,
+    STATE(427), 3,
+      sym_binding,
+      sym_inherit,
+      sym_inherit_from,
+    STATE(448), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [21402] = 12,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(542), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(550), 1,
+      anon_sym_inherit,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(624), 1,
+      sym_grit_metavariable,
+    ACTIONS(632), 1,
+      anon_sym_RBRACE,
+    STATE(363), 1,
+      aux_sym_binding_set_repeat1,
+    STATE(480), 1,
+      sym_attrpath,
+    STATE(488), 1,
+      sym_binding_set,
+    STATE(427), 3,
+      sym_binding,
+      sym_inherit,
+      sym_inherit_from,
+    STATE(448), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [21443] = 12,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(542), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(550), 1,
+      anon_sym_inherit,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(624), 1,
+      sym_grit_metavariable,
+    ACTIONS(634), 1,
+      anon_sym_RBRACE,
+    STATE(363), 1,
+      aux_sym_binding_set_repeat1,
+    STATE(480), 1,
+      sym_attrpath,
+    STATE(538), 1,
+      sym_binding_set,
+    STATE(427), 3,
+      sym_binding,
+      sym_inherit,
+      sym_inherit_from,
+    STATE(448), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [21484] = 12,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(542), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(550), 1,
+      anon_sym_inherit,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(624), 1,
+      sym_grit_metavariable,
+    ACTIONS(636), 1,
+      anon_sym_RBRACE,
+    STATE(363), 1,
+      aux_sym_binding_set_repeat1,
+    STATE(480), 1,
+      sym_attrpath,
+    STATE(500), 1,
+      sym_binding_set,
+    STATE(427), 3,
+      sym_binding,
+      sym_inherit,
+      sym_inherit_from,
+    STATE(448), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [21525] = 12,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(542), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(550), 1,
+      anon_sym_inherit,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(624), 1,
+      sym_grit_metavariable,
+    ACTIONS(638), 1,
+      anon_sym_RBRACE,
+    STATE(363), 1,
+      aux_sym_binding_set_repeat1,
+    STATE(480), 1,
+      sym_attrpath,
+    STATE(514), 1,
+      sym_binding_set,
+    STATE(427), 3,
+      sym_binding,
+      sym_inherit,
+      sym_inherit_from,
+    STATE(448), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [21566] = 12,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(542), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(550), 1,
+      anon_sym_inherit,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(624), 1,
+      sym_grit_metavariable,
+    ACTIONS(640), 1,
+      anon_sym_RBRACE,
+    STATE(363), 1,
+      aux_sym_binding_set_repeat1,
+    STATE(480), 1,
+      sym_attrpath,
+    STATE(553), 1,
+      sym_binding_set,
+    STATE(427), 3,
+      sym_binding,
+      sym_inherit,
+      sym_inherit_from,
+    STATE(448), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [21607] = 12,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(542), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(550), 1,
+      anon_sym_inherit,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(624), 1,
+      sym_grit_metavariable,
+    ACTIONS(642), 1,
+      anon_sym_RBRACE,
+    STATE(363), 1,
+      aux_sym_binding_set_repeat1,
+    STATE(480), 1,
+      sym_attrpath,
+    STATE(501), 1,
+      sym_binding_set,
+    STATE(427), 3,
+      sym_binding,
+      sym_inherit,
+      sym_inherit_from,
+    STATE(448), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [21648] = 12,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(542), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(550), 1,
+      anon_sym_inherit,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(624), 1,
+      sym_grit_metavariable,
+    ACTIONS(644), 1,
+      anon_sym_RBRACE,
+    STATE(363), 1,
+      aux_sym_binding_set_repeat1,
+    STATE(480), 1,
+      sym_attrpath,
+    STATE(531), 1,
+      sym_binding_set,
+    STATE(427), 3,
+      sym_binding,
+      sym_inherit,
+      sym_inherit_from,
+    STATE(448), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [21689] = 12,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(542), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(550), 1,
+      anon_sym_inherit,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(624), 1,
+      sym_grit_metavariable,
+    ACTIONS(646), 1,
+      anon_sym_RBRACE,
+    STATE(363), 1,
+      aux_sym_binding_set_repeat1,
+    STATE(480), 1,
+      sym_attrpath,
+    STATE(541), 1,
+      sym_binding_set,
+    STATE(427), 3,
+      sym_binding,
+      sym_inherit,
+      sym_inherit_from,
+    STATE(448), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [21730] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(542), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(602), 1,
+      anon_sym_inherit,
+    ACTIONS(604), 1,
+      sym_grit_metavariable,
+    ACTIONS(648), 1,
+      anon_sym_in,
+    STATE(364), 1,
+      aux_sym_binding_set_repeat1,
+    STATE(544), 1,
+      sym_attrpath,
+    STATE(431), 3,
+      sym_binding,
+      sym_inherit,
+      sym_inherit_from,
+    STATE(448), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [21768] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(542), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(550), 1,
+      anon_sym_inherit,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(624), 1,
+      sym_grit_metavariable,
+    ACTIONS(650), 1,
+      anon_sym_RBRACE,
+    STATE(365), 1,
+      aux_sym_binding_set_repeat1,
+    STATE(480), 1,
+      sym_attrpath,
+    STATE(427), 3,
+      sym_binding,
+      sym_inherit,
+      sym_inherit_from,
+    STATE(448), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [21806] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(652), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(655), 1,
+      anon_sym_in,
+    ACTIONS(657), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(660), 1,
+      anon_sym_inherit,
+    ACTIONS(663), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(666), 1,
+      sym_grit_metavariable,
+    STATE(364), 1,
+      aux_sym_binding_set_repeat1,
+    STATE(544), 1,
+      sym_attrpath,
+    STATE(431), 3,
+      sym_binding,
+      sym_inherit,
+      sym_inherit_from,
+    STATE(448), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [21844] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(652), 1,
+      aux_sym_identifier_token1,
+    ACTIONS(657), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(663), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(669), 1,
+      anon_sym_RBRACE,
+    ACTIONS(671), 1,
+      anon_sym_inherit,
+    ACTIONS(674), 1,
+      sym_grit_metavariable,
+    STATE(365), 1,
+      aux_sym_binding_set_repeat1,
+    STATE(480), 1,
+      sym_attrpath,
+    STATE(427), 3,
+      sym_binding,
+      sym_inherit,
+      sym_inherit_from,
+    STATE(448), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [21882] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(679), 1,
+      anon_sym_RBRACE,
+    ACTIONS(677), 2,
+      aux_sym_identifier_token1,
+      anon_sym_inherit,
+    ACTIONS(682), 3,
+      anon_sym_DQUOTE,
+      anon_sym_DOLLAR_LBRACE2,
+      sym_grit_metavariable,
+    ACTIONS(225), 4,
+      anon_sym_COMMA,
+      anon_sym_QMARK,
+      anon_sym_DOT,
+      anon_sym_EQ,
+  [21904] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(686), 1,
+      anon_sym_LPAREN,
+    STATE(371), 1,
+      aux_sym_inherited_attrs_repeat1,
+    STATE(495), 1,
+      sym_inherited_attrs,
+    ACTIONS(684), 2,
+      aux_sym_identifier_token1,
+      sym_grit_metavariable,
+    STATE(443), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [21932] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(688), 1,
+      anon_sym_LPAREN,
+    STATE(371), 1,
+      aux_sym_inherited_attrs_repeat1,
+    STATE(532), 1,
+      sym_inherited_attrs,
+    ACTIONS(684), 2,
+      aux_sym_identifier_token1,
+      sym_grit_metavariable,
+    STATE(443), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [21960] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    STATE(371), 1,
+      aux_sym_inherited_attrs_repeat1,
+    STATE(551), 1,
+      sym_inherited_attrs,
+    ACTIONS(684), 2,
+      aux_sym_identifier_token1,
+      sym_grit_metavariable,
+    STATE(443), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [21985] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    STATE(371), 1,
+      aux_sym_inherited_attrs_repeat1,
+    STATE(509), 1,
+      sym_inherited_attrs,
+    ACTIONS(684), 2,
+      aux_sym_identifier_token1,
+      sym_grit_metavariable,
+    STATE(443), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [22010] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(690), 1,
+      anon_sym_SEMI,
+    STATE(372), 1,
+      aux_sym_inherited_attrs_repeat1,
+    ACTIONS(684), 2,
+      aux_sym_identifier_token1,
+      sym_grit_metavariable,
+    STATE(443), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [22035] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(695), 1,
+      anon_sym_SEMI,
+    ACTIONS(697), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(700), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    STATE(372), 1,
+      aux_sym_inherited_attrs_repeat1,
+    ACTIONS(692), 2,
+      aux_sym_identifier_token1,
+      sym_grit_metavariable,
+    STATE(443), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [22060] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(703), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(707), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(709), 1,
+      sym__indented_dollar_escape,
+    STATE(390), 1,
+      aux_sym_indented_string_expression_repeat1,
+    STATE(433), 1,
+      sym_interpolation,
+    ACTIONS(705), 3,
+      sym__indented_string_fragment,
+      sym__indented_escape_sequence,
+      sym_grit_metavariable,
+  [22084] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(225), 2,
+      anon_sym_DOT,
+      anon_sym_EQ,
+    ACTIONS(677), 3,
+      aux_sym_identifier_token1,
+      anon_sym_in,
+      anon_sym_inherit,
+    ACTIONS(682), 3,
+      anon_sym_DQUOTE,
+      anon_sym_DOLLAR_LBRACE2,
+      sym_grit_metavariable,
+  [22102] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(711), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(715), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(717), 1,
+      sym_dollar_escape,
+    STATE(378), 1,
+      aux_sym_string_expression_repeat1,
+    STATE(435), 1,
+      sym_interpolation,
+    ACTIONS(713), 3,
+      sym_string_fragment,
+      sym_escape_sequence,
+      sym_grit_metavariable,
+  [22126] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(707), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(709), 1,
+      sym__indented_dollar_escape,
+    ACTIONS(719), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    STATE(373), 1,
+      aux_sym_indented_string_expression_repeat1,
+    STATE(433), 1,
+      sym_interpolation,
+    ACTIONS(705), 3,
+      sym__indented_string_fragment,
+      sym__indented_escape_sequence,
+      sym_grit_metavariable,
+  [22150] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(225), 2,
+      anon_sym_DOT,
+      anon_sym_EQ,
+    ACTIONS(677), 2,
+      aux_sym_identifier_token1,
+      anon_sym_inherit,
+    ACTIONS(682), 4,
+      anon_sym_RBRACE,
+      anon_sym_DQUOTE,
+      anon_sym_DOLLAR_LBRACE2,
+      sym_grit_metavariable,
+  [22168] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(715), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(717), 1,
+      sym_dollar_escape,
+    ACTIONS(721), 1,
+      anon_sym_DQUOTE,
+    STATE(387), 1,
+      aux_sym_string_expression_repeat1,
+    STATE(435), 1,
+      sym_interpolation,
+    ACTIONS(713), 3,
+      sym_string_fragment,
+      sym_escape_sequence,
+      sym_grit_metavariable,
+  [22192] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(725), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(727), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    STATE(237), 1,
+      sym_attrpath,
+    ACTIONS(723), 2,
+      aux_sym_identifier_token1,
+      sym_grit_metavariable,
+    STATE(179), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [22214] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    STATE(285), 1,
+      sym_attrpath,
+    ACTIONS(684), 2,
+      aux_sym_identifier_token1,
+      sym_grit_metavariable,
+    STATE(289), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [22236] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(731), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(733), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    STATE(147), 1,
+      sym_attrpath,
+    ACTIONS(729), 2,
+      aux_sym_identifier_token1,
+      sym_grit_metavariable,
+    STATE(84), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [22258] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(715), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(717), 1,
+      sym_dollar_escape,
+    ACTIONS(735), 1,
+      anon_sym_DQUOTE,
+    STATE(384), 1,
+      aux_sym_string_expression_repeat1,
+    STATE(435), 1,
+      sym_interpolation,
+    ACTIONS(713), 3,
+      sym_string_fragment,
+      sym_escape_sequence,
+      sym_grit_metavariable,
+  [22282] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(707), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(709), 1,
+      sym__indented_dollar_escape,
+    ACTIONS(737), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    STATE(385), 1,
+      aux_sym_indented_string_expression_repeat1,
+    STATE(433), 1,
+      sym_interpolation,
+    ACTIONS(705), 3,
+      sym__indented_string_fragment,
+      sym__indented_escape_sequence,
+      sym_grit_metavariable,
+  [22306] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(715), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(717), 1,
+      sym_dollar_escape,
+    ACTIONS(739), 1,
+      anon_sym_DQUOTE,
+    STATE(387), 1,
+      aux_sym_string_expression_repeat1,
+    STATE(435), 1,
+      sym_interpolation,
+    ACTIONS(713), 3,
+      sym_string_fragment,
+      sym_escape_sequence,
+      sym_grit_metavariable,
+  [22330] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(707), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(709), 1,
+      sym__indented_dollar_escape,
+    ACTIONS(741), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    STATE(390), 1,
+      aux_sym_indented_string_expression_repeat1,
+    STATE(433), 1,
+      sym_interpolation,
+    ACTIONS(705), 3,
+      sym__indented_string_fragment,
+      sym__indented_escape_sequence,
+      sym_grit_metavariable,
+  [22354] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(745), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(747), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    STATE(234), 1,
+      sym_attrpath,
+    ACTIONS(743), 2,
+      aux_sym_identifier_token1,
+      sym_grit_metavariable,
+    STATE(177), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [22376] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(749), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(754), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(757), 1,
+      sym_dollar_escape,
+    STATE(387), 1,
+      aux_sym_string_expression_repeat1,
+    STATE(435), 1,
+      sym_interpolation,
+    ACTIONS(751), 3,
+      sym_string_fragment,
+      sym_escape_sequence,
+      sym_grit_metavariable,
+  [22400] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(715), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(717), 1,
+      sym_dollar_escape,
+    ACTIONS(760), 1,
+      anon_sym_DQUOTE,
+    STATE(391), 1,
+      aux_sym_string_expression_repeat1,
+    STATE(435), 1,
+      sym_interpolation,
+    ACTIONS(713), 3,
+      sym_string_fragment,
+      sym_escape_sequence,
+      sym_grit_metavariable,
+  [22424] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(707), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(709), 1,
+      sym__indented_dollar_escape,
+    ACTIONS(762), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    STATE(407), 1,
+      aux_sym_indented_string_expression_repeat1,
+    STATE(433), 1,
+      sym_interpolation,
+    ACTIONS(705), 3,
+      sym__indented_string_fragment,
+      sym__indented_escape_sequence,
+      sym_grit_metavariable,
+  [22448] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(764), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(769), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(772), 1,
+      sym__indented_dollar_escape,
+    STATE(390), 1,
+      aux_sym_indented_string_expression_repeat1,
+    STATE(433), 1,
+      sym_interpolation,
+    ACTIONS(766), 3,
+      sym__indented_string_fragment,
+      sym__indented_escape_sequence,
+      sym_grit_metavariable,
+  [22472] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(715), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(717), 1,
+      sym_dollar_escape,
+    ACTIONS(775), 1,
+      anon_sym_DQUOTE,
+    STATE(387), 1,
+      aux_sym_string_expression_repeat1,
+    STATE(435), 1,
+      sym_interpolation,
+    ACTIONS(713), 3,
+      sym_string_fragment,
+      sym_escape_sequence,
+      sym_grit_metavariable,
+  [22496] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(707), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(709), 1,
+      sym__indented_dollar_escape,
+    ACTIONS(777), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    STATE(390), 1,
+      aux_sym_indented_string_expression_repeat1,
+    STATE(433), 1,
+      sym_interpolation,
+    ACTIONS(705), 3,
+      sym__indented_string_fragment,
+      sym__indented_escape_sequence,
+      sym_grit_metavariable,
+  [22520] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(781), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(783), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    STATE(252), 1,
+      sym_attrpath,
+    ACTIONS(779), 2,
+      aux_sym_identifier_token1,
+      sym_grit_metavariable,
+    STATE(190), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [22542] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(715), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(717), 1,
+      sym_dollar_escape,
+    ACTIONS(785), 1,
+      anon_sym_DQUOTE,
+    STATE(395), 1,
+      aux_sym_string_expression_repeat1,
+    STATE(435), 1,
+      sym_interpolation,
+    ACTIONS(713), 3,
+      sym_string_fragment,
+      sym_escape_sequence,
+      sym_grit_metavariable,
+  [22566] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(715), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(717), 1,
+      sym_dollar_escape,
+    ACTIONS(787), 1,
+      anon_sym_DQUOTE,
+    STATE(387), 1,
+      aux_sym_string_expression_repeat1,
+    STATE(435), 1,
+      sym_interpolation,
+    ACTIONS(713), 3,
+      sym_string_fragment,
+      sym_escape_sequence,
+      sym_grit_metavariable,
+  [22590] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(715), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(717), 1,
+      sym_dollar_escape,
+    ACTIONS(789), 1,
+      anon_sym_DQUOTE,
+    STATE(397), 1,
+      aux_sym_string_expression_repeat1,
+    STATE(435), 1,
+      sym_interpolation,
+    ACTIONS(713), 3,
+      sym_string_fragment,
+      sym_escape_sequence,
+      sym_grit_metavariable,
+  [22614] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(715), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(717), 1,
+      sym_dollar_escape,
+    ACTIONS(791), 1,
+      anon_sym_DQUOTE,
+    STATE(387), 1,
+      aux_sym_string_expression_repeat1,
+    STATE(435), 1,
+      sym_interpolation,
+    ACTIONS(713), 3,
+      sym_string_fragment,
+      sym_escape_sequence,
+      sym_grit_metavariable,
+  [22638] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(715), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(717), 1,
+      sym_dollar_escape,
+    ACTIONS(793), 1,
+      anon_sym_DQUOTE,
+    STATE(399), 1,
+      aux_sym_string_expression_repeat1,
+    STATE(435), 1,
+      sym_interpolation,
+    ACTIONS(713), 3,
+      sym_string_fragment,
+      sym_escape_sequence,
+      sym_grit_metavariable,
+  [22662] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(715), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(717), 1,
+      sym_dollar_escape,
+    ACTIONS(795), 1,
+      anon_sym_DQUOTE,
+    STATE(387), 1,
+      aux_sym_string_expression_repeat1,
+    STATE(435), 1,
+      sym_interpolation,
+    ACTIONS(713), 3,
+      sym_string_fragment,
+      sym_escape_sequence,
+      sym_grit_metavariable,
+  [22686] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(715), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(717), 1,
+      sym_dollar_escape,
+    ACTIONS(797), 1,
+      anon_sym_DQUOTE,
+    STATE(401), 1,
+      aux_sym_string_expression_repeat1,
+    STATE(435), 1,
+      sym_interpolation,
+    ACTIONS(713), 3,
+      sym_string_fragment,
+      sym_escape_sequence,
+      sym_grit_metavariable,
+  [22710] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(715), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(717), 1,
+      sym_dollar_escape,
+    ACTIONS(799), 1,
+      anon_sym_DQUOTE,
+    STATE(387), 1,
+      aux_sym_string_expression_repeat1,
+    STATE(435), 1,
+      sym_interpolation,
+    ACTIONS(713), 3,
+      sym_string_fragment,
+      sym_escape_sequence,
+      sym_grit_metavariable,
+  [22734] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(715), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(717), 1,
+      sym_dollar_escape,
+    ACTIONS(801), 1,
+      anon_sym_DQUOTE,
+    STATE(403), 1,
+      aux_sym_string_expression_repeat1,
+    STATE(435), 1,
+      sym_interpolation,
+    ACTIONS(713), 3,
+      sym_string_fragment,
+      sym_escape_sequence,
+      sym_grit_metavariable,
+  [22758] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(715), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(717), 1,
+      sym_dollar_escape,
+    ACTIONS(803), 1,
+      anon_sym_DQUOTE,
+    STATE(387), 1,
+      aux_sym_string_expression_repeat1,
+    STATE(435), 1,
+      sym_interpolation,
+    ACTIONS(713), 3,
+      sym_string_fragment,
+      sym_escape_sequence,
+      sym_grit_metavariable,
+  [22782] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(715), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(717), 1,
+      sym_dollar_escape,
+    ACTIONS(805), 1,
+      anon_sym_DQUOTE,
+    STATE(405), 1,
+      aux_sym_string_expression_repeat1,
+    STATE(435), 1,
+      sym_interpolation,
+    ACTIONS(713), 3,
+      sym_string_fragment,
+      sym_escape_sequence,
+      sym_grit_metavariable,
+  [22806] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(715), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(717), 1,
+      sym_dollar_escape,
+    ACTIONS(807), 1,
+      anon_sym_DQUOTE,
+    STATE(387), 1,
+      aux_sym_string_expression_repeat1,
+    STATE(435), 1,
+      sym_interpolation,
+    ACTIONS(713), 3,
+      sym_string_fragment,
+      sym_escape_sequence,
+      sym_grit_metavariable,
+  [22830] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(715), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(717), 1,
+      sym_dollar_escape,
+    ACTIONS(809), 1,
+      anon_sym_DQUOTE,
+    STATE(387), 1,
+      aux_sym_string_expression_repeat1,
+    STATE(435), 1,
+      sym_interpolation,
+    ACTIONS(713), 3,
+      sym_string_fragment,
+      sym_escape_sequence,
+      sym_grit_metavariable,
+  [22854] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(707), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(709), 1,
+      sym__indented_dollar_escape,
+    ACTIONS(811), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    STATE(390), 1,
+      aux_sym_indented_string_expression_repeat1,
+    STATE(433), 1,
+      sym_interpolation,
+    ACTIONS(705), 3,
+      sym__indented_string_fragment,
+      sym__indented_escape_sequence,
+      sym_grit_metavariable,
+  [22878] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(815), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(817), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    STATE(285), 1,
+      sym_attrpath,
+    ACTIONS(813), 2,
+      aux_sym_identifier_token1,
+      sym_grit_metavariable,
+    STATE(290), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [22900] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(715), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(717), 1,
+      sym_dollar_escape,
+    ACTIONS(819), 1,
+      anon_sym_DQUOTE,
+    STATE(406), 1,
+      aux_sym_string_expression_repeat1,
+    STATE(435), 1,
+      sym_interpolation,
+    ACTIONS(713), 3,
+      sym_string_fragment,
+      sym_escape_sequence,
+      sym_grit_metavariable,
+  [22924] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(707), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(709), 1,
+      sym__indented_dollar_escape,
+    ACTIONS(821), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    STATE(392), 1,
+      aux_sym_indented_string_expression_repeat1,
+    STATE(433), 1,
+      sym_interpolation,
+    ACTIONS(705), 3,
+      sym__indented_string_fragment,
+      sym__indented_escape_sequence,
+      sym_grit_metavariable,
+  [22948] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(823), 7,
+      ts_builtin_sym_end,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_RPAREN,
+  [22961] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(825), 7,
+      ts_builtin_sym_end,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_RPAREN,
+  [22974] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(815), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(817), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(813), 2,
+      aux_sym_identifier_token1,
+      sym_grit_metavariable,
+    STATE(284), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [22993] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(827), 7,
+      ts_builtin_sym_end,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_RPAREN,
+  [23006] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(731), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(733), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(729), 2,
+      aux_sym_identifier_token1,
+      sym_grit_metavariable,
+    STATE(95), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [23025] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(829), 7,
+      ts_builtin_sym_end,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_RPAREN,
+  [23038] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(548), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(552), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(684), 2,
+      aux_sym_identifier_token1,
+      sym_grit_metavariable,
+    STATE(284), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [23057] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(745), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(747), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(743), 2,
+      aux_sym_identifier_token1,
+      sym_grit_metavariable,
+    STATE(211), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [23076] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(781), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(783), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(779), 2,
+      aux_sym_identifier_token1,
+      sym_grit_metavariable,
+    STATE(206), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [23095] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(725), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(727), 1,
+      anon_sym_DOLLAR_LBRACE2,
+    ACTIONS(723), 2,
+      aux_sym_identifier_token1,
+      sym_grit_metavariable,
+    STATE(203), 3,
+      sym_identifier,
+      sym_string_expression,
+      sym_interpolation,
+  [23114] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(831), 7,
+      ts_builtin_sym_end,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_RPAREN,
+  [23127] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(833), 7,
+      ts_builtin_sym_end,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_RPAREN,
+  [23140] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(835), 7,
+      ts_builtin_sym_end,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_RPAREN,
+  [23153] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(837), 7,
+      ts_builtin_sym_end,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_RPAREN,
+  [23166] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(839), 7,
+      ts_builtin_sym_end,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_RPAREN,
+  [23179] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(841), 7,
+      ts_builtin_sym_end,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_then,
+      anon_sym_else,
+      anon_sym_RPAREN,
+  [23192] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(677), 2,
+      aux_sym_identifier_token1,
+      anon_sym_inherit,
+    ACTIONS(682), 4,
+      anon_sym_RBRACE,
+      anon_sym_DQUOTE,
+      anon_sym_DOLLAR_LBRACE2,
+      sym_grit_metavariable,
+  [23206] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(843), 2,
+      aux_sym_identifier_token1,
+      anon_sym_inherit,
+    ACTIONS(845), 4,
+      anon_sym_RBRACE,
+      anon_sym_DQUOTE,
+      anon_sym_DOLLAR_LBRACE2,
+      sym_grit_metavariable,
+  [23220] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(250), 6,
+      sym_string_fragment,
+      sym_dollar_escape,
+      anon_sym_DQUOTE,
+      sym_escape_sequence,
+      anon_sym_DOLLAR_LBRACE2,
+      sym_grit_metavariable,
+  [23232] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(207), 1,
+      anon_sym_EQ,
+    ACTIONS(473), 1,
+      anon_sym_DOT,
+    ACTIONS(849), 1,
+      anon_sym_QMARK,
+    STATE(288), 1,
+      aux_sym_attrpath_repeat1,
+    ACTIONS(847), 2,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+  [23252] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(677), 3,
+      aux_sym_identifier_token1,
+      anon_sym_in,
+      anon_sym_inherit,
+    ACTIONS(682), 3,
+      anon_sym_DQUOTE,
+      anon_sym_DOLLAR_LBRACE2,
+      sym_grit_metavariable,
+  [23266] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(851), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(853), 5,
+      sym__indented_string_fragment,
+      sym__indented_dollar_escape,
+      sym__indented_escape_sequence,
+      anon_sym_DOLLAR_LBRACE2,
+      sym_grit_metavariable,
+  [23280] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(855), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(857), 5,
+      sym__indented_string_fragment,
+      sym__indented_dollar_escape,
+      sym__indented_escape_sequence,
+      anon_sym_DOLLAR_LBRACE2,
+      sym_grit_metavariable,
+  [23294] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(859), 2,
+      aux_sym_identifier_token1,
+      anon_sym_inherit,
+    ACTIONS(861), 4,
+      anon_sym_RBRACE,
+      anon_sym_DQUOTE,
+      anon_sym_DOLLAR_LBRACE2,
+      sym_grit_metavariable,
+  [23308] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(863), 6,
+      sym_string_fragment,
+      sym_dollar_escape,
+      anon_sym_DQUOTE,
+      sym_escape_sequence,
+      anon_sym_DOLLAR_LBRACE2,
+      sym_grit_metavariable,
+  [23320] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(865), 3,
+      aux_sym_identifier_token1,
+      anon_sym_in,
+      anon_sym_inherit,
+    ACTIONS(867), 3,
+      anon_sym_DQUOTE,
+      anon_sym_DOLLAR_LBRACE2,
+      sym_grit_metavariable,
+  [23334] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(859), 3,
+      aux_sym_identifier_token1,
+      anon_sym_in,
+      anon_sym_inherit,
+    ACTIONS(861), 3,
+      anon_sym_DQUOTE,
+      anon_sym_DOLLAR_LBRACE2,
+      sym_grit_metavariable,
+  [23348] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(546), 1,
+      sym_ellipses,
+    ACTIONS(869), 1,
+      anon_sym_RBRACE,
+    STATE(449), 1,
+      sym_identifier,
+    STATE(456), 1,
+      sym_formal,
+    ACTIONS(684), 2,
+      aux_sym_identifier_token1,
+      sym_grit_metavariable,
+  [23368] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(865), 2,
+      aux_sym_identifier_token1,
+      anon_sym_inherit,
+    ACTIONS(867), 4,
+      anon_sym_RBRACE,
+      anon_sym_DQUOTE,
+      anon_sym_DOLLAR_LBRACE2,
+      sym_grit_metavariable,
+  [23382] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(248), 1,
+      anon_sym_SQUOTE_SQUOTE,
+    ACTIONS(250), 5,
+      sym__indented_string_fragment,
+      sym__indented_dollar_escape,
+      sym__indented_escape_sequence,
+      anon_sym_DOLLAR_LBRACE2,
+      sym_grit_metavariable,
+  [23396] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(871), 6,
+      sym_string_fragment,
+      sym_dollar_escape,
+      anon_sym_DQUOTE,
+      sym_escape_sequence,
+      anon_sym_DOLLAR_LBRACE2,
+      sym_grit_metavariable,
+  [23408] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(843), 3,
+      aux_sym_identifier_token1,
+      anon_sym_in,
+      anon_sym_inherit,
+    ACTIONS(845), 3,
+      anon_sym_DQUOTE,
+      anon_sym_DOLLAR_LBRACE2,
+      sym_grit_metavariable,
+  [23422] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(873), 5,
+      aux_sym_identifier_token1,
+      anon_sym_SEMI,
+      anon_sym_DQUOTE,
+      anon_sym_DOLLAR_LBRACE2,
+      sym_grit_metavariable,
+  [23433] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(875), 1,
+      sym_ellipses,
+    STATE(449), 1,
+      sym_identifier,
+    STATE(462), 1,
+      sym_formal,
+    ACTIONS(684), 2,
+      aux_sym_identifier_token1,
+      sym_grit_metavariable,
+  [23450] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(877), 1,
+      sym_ellipses,
+    STATE(449), 1,
+      sym_identifier,
+    STATE(462), 1,
+      sym_formal,
+    ACTIONS(684), 2,
+      aux_sym_identifier_token1,
+      sym_grit_metavariable,
+  [23467] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(449), 1,
+      sym_identifier,
+    STATE(462), 1,
+      sym_formal,
+    ACTIONS(684), 2,
+      aux_sym_identifier_token1,
+      sym_grit_metavariable,
+  [23481] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(879), 1,
+      anon_sym_RBRACE,
+    ACTIONS(881), 1,
+      anon_sym_COMMA,
+    STATE(452), 1,
+      aux_sym_formals_repeat1,
+  [23494] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(207), 1,
+      anon_sym_EQ,
+    ACTIONS(473), 1,
+      anon_sym_DOT,
+    STATE(450), 1,
+      aux_sym_attrpath_repeat1,
+  [23507] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(849), 1,
+      anon_sym_QMARK,
+    ACTIONS(847), 2,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+  [23518] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(221), 1,
+      anon_sym_EQ,
+    ACTIONS(473), 1,
+      anon_sym_DOT,
+    STATE(287), 1,
+      aux_sym_attrpath_repeat1,
+  [23531] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(504), 1,
+      sym_identifier,
+    ACTIONS(684), 2,
+      aux_sym_identifier_token1,
+      sym_grit_metavariable,
+  [23542] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(883), 1,
+      anon_sym_RBRACE,
+    ACTIONS(885), 1,
+      anon_sym_COMMA,
+    STATE(452), 1,
+      aux_sym_formals_repeat1,
+  [23555] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(542), 1,
+      sym_identifier,
+    ACTIONS(684), 2,
+      aux_sym_identifier_token1,
+      sym_grit_metavariable,
+  [23566] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(521), 1,
+      sym_identifier,
+    ACTIONS(684), 2,
+      aux_sym_identifier_token1,
+      sym_grit_metavariable,
+  [23577] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(490), 1,
+      sym_identifier,
+    ACTIONS(684), 2,
+      aux_sym_identifier_token1,
+      sym_grit_metavariable,
+  [23588] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(888), 1,
+      anon_sym_RBRACE,
+    ACTIONS(890), 1,
+      anon_sym_COMMA,
+    STATE(447), 1,
+      aux_sym_formals_repeat1,
+  [23601] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(892), 2,
+      anon_sym_COLON,
+      anon_sym_AT,
+  [23609] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(894), 1,
+      anon_sym_COLON,
+    ACTIONS(896), 1,
+      anon_sym_AT,
+  [23619] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(898), 1,
+      anon_sym_COLON,
+    ACTIONS(900), 1,
+      anon_sym_AT,
+  [23629] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(902), 2,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+  [23637] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(904), 2,
+      anon_sym_COLON,
+      anon_sym_AT,
+  [23645] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(906), 2,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+  [23653] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(908), 2,
+      anon_sym_COLON,
+      anon_sym_AT,
+  [23661] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(910), 2,
+      anon_sym_COLON,
+      anon_sym_AT,
+  [23669] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(912), 1,
+      anon_sym_LBRACE,
+    STATE(472), 1,
+      sym_formals,
+  [23679] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(914), 2,
+      anon_sym_COLON,
+      anon_sym_AT,
+  [23687] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(916), 1,
+      anon_sym_COLON,
+    ACTIONS(918), 1,
+      anon_sym_AT,
+  [23697] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(912), 1,
+      anon_sym_LBRACE,
+    STATE(518), 1,
+      sym_formals,
+  [23707] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(912), 1,
+      anon_sym_LBRACE,
+    STATE(510), 1,
+      sym_formals,
+  [23717] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(920), 1,
+      anon_sym_COLON,
+    ACTIONS(922), 1,
+      anon_sym_AT,
+  [23727] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(912), 1,
+      anon_sym_LBRACE,
+    STATE(489), 1,
+      sym_formals,
+  [23737] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(924), 1,
+      anon_sym_COLON,
+  [23744] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(926), 1,
+      anon_sym_RBRACE,
+  [23751] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(928), 1,
+      anon_sym_in,
+  [23758] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(930), 1,
+      anon_sym_RBRACE,
+  [23765] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(614), 1,
+      anon_sym_LBRACE,
+  [23772] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(932), 1,
+      anon_sym_RPAREN,
+  [23779] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(934), 1,
+      anon_sym_RPAREN,
+  [23786] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(936), 1,
+      anon_sym_RPAREN,
+  [23793] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(938), 1,
+      anon_sym_EQ,
+  [23800] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(940), 1,
+      ts_builtin_sym_end,
+  [23807] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(942), 1,
+      anon_sym_in,
+  [23814] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(246), 1,
+      anon_sym_COLON,
+  [23821] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(944), 1,
+      anon_sym_RBRACE,
+  [23828] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(946), 1,
+      anon_sym_RBRACE,
+  [23835] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(948), 1,
+      anon_sym_then,
+  [23842] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(950), 1,
+      anon_sym_RBRACE,
+  [23849] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(952), 1,
+      anon_sym_RBRACE,
+  [23856] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(954), 1,
+      anon_sym_COLON,
+  [23863] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(956), 1,
+      anon_sym_COLON,
+  [23870] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(958), 1,
+      anon_sym_else,
+  [23877] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(960), 1,
+      anon_sym_DOLLAR,
+  [23884] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(962), 1,
+      anon_sym_RBRACE,
+  [23891] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(964), 1,
+      anon_sym_RBRACE,
+  [23898] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(966), 1,
+      anon_sym_SEMI,
+  [23905] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(968), 1,
+      anon_sym_RBRACE,
+  [23912] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(970), 1,
+      anon_sym_RBRACE,
+  [23919] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(972), 1,
+      anon_sym_RBRACE,
+  [23926] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(974), 1,
+      anon_sym_SEMI,
+  [23933] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(976), 1,
+      anon_sym_RBRACE,
+  [23940] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(978), 1,
+      anon_sym_RBRACE,
+  [23947] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(980), 1,
+      anon_sym_RBRACE,
+  [23954] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(610), 1,
+      anon_sym_LBRACE,
+  [23961] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(982), 1,
+      anon_sym_COLON,
+  [23968] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(984), 1,
+      anon_sym_RBRACE,
+  [23975] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(986), 1,
+      anon_sym_RBRACE,
+  [23982] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(988), 1,
+      anon_sym_SEMI,
+  [23989] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(990), 1,
+      anon_sym_else,
+  [23996] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(992), 1,
+      anon_sym_SEMI,
+  [24003] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(994), 1,
+      anon_sym_COLON,
+  [24010] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(996), 1,
+      anon_sym_SEMI,
+  [24017] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(998), 1,
+      anon_sym_RBRACE,
+  [24024] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1000), 1,
+      anon_sym_LBRACE,
+  [24031] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1002), 1,
+      anon_sym_RBRACE,
+  [24038] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1004), 1,
+      anon_sym_RBRACE,
+  [24045] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1006), 1,
+      anon_sym_RBRACE,
+  [24052] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1008), 1,
+      anon_sym_RBRACE,
+  [24059] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1010), 1,
+      anon_sym_COLON,
+  [24066] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1012), 1,
+      anon_sym_DOLLAR,
+  [24073] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(618), 1,
+      anon_sym_LBRACE,
+  [24080] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1014), 1,
+      anon_sym_COLON,
+  [24087] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1016), 1,
+      anon_sym_else,
+  [24094] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1018), 1,
+      anon_sym_SEMI,
+  [24101] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1020), 1,
+      anon_sym_SEMI,
+  [24108] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1022), 1,
+      anon_sym_LBRACE,
+  [24115] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1024), 1,
+      anon_sym_RPAREN,
+  [24122] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1026), 1,
+      anon_sym_SEMI,
+  [24129] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1028), 1,
+      anon_sym_RPAREN,
+  [24136] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1030), 1,
+      anon_sym_in,
+  [24143] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1032), 1,
+      anon_sym_else,
+  [24150] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1034), 1,
+      anon_sym_RBRACE,
+  [24157] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1036), 1,
+      anon_sym_SEMI,
+  [24164] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1038), 1,
+      anon_sym_SEMI,
+  [24171] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1040), 1,
+      anon_sym_LBRACE,
+  [24178] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1042), 1,
+      anon_sym_SEMI,
+  [24185] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1044), 1,
+      anon_sym_RBRACE,
+  [24192] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(598), 1,
+      anon_sym_LBRACE,
+  [24199] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1046), 1,
+      anon_sym_RBRACE,
+  [24206] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1048), 1,
+      anon_sym_LBRACE,
+  [24213] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1050), 1,
+      anon_sym_in,
+  [24220] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1052), 1,
+      anon_sym_RBRACE,
+  [24227] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1054), 1,
+      anon_sym_COLON,
+  [24234] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1056), 1,
+      anon_sym_SEMI,
+  [24241] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1058), 1,
+      anon_sym_EQ,
+  [24248] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1060), 1,
+      anon_sym_then,
+  [24255] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1062), 1,
+      anon_sym_RPAREN,
+  [24262] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1064), 1,
+      anon_sym_then,
+  [24269] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1066), 1,
+      anon_sym_then,
+  [24276] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1068), 1,
+      ts_builtin_sym_end,
+  [24283] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1070), 1,
+      anon_sym_RBRACE,
+  [24290] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1072), 1,
+      anon_sym_SEMI,
+  [24297] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1074), 1,
+      anon_sym_SEMI,
+  [24304] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1076), 1,
+      anon_sym_RBRACE,
+};
+
+static const uint32_t ts_small_parse_table_map[] = {
+  [SMALL_STATE(2)] = 0,
+  [SMALL_STATE(3)] = 103,
+  [SMALL_STATE(4)] = 206,
+  [SMALL_STATE(5)] = 309,
+  [SMALL_STATE(6)] = 412,
+  [SMALL_STATE(7)] = 515,
+  [SMALL_STATE(8)] = 618,
+  [SMALL_STATE(9)] = 721,
+  [SMALL_STATE(10)] = 824,
+  [SMALL_STATE(11)] = 927,
+  [SMALL_STATE(12)] = 1030,
+  [SMALL_STATE(13)] = 1119,
+  [SMALL_STATE(14)] = 1222,
+  [SMALL_STATE(15)] = 1325,
+  [SMALL_STATE(16)] = 1428,
+  [SMALL_STATE(17)] = 1531,
+  [SMALL_STATE(18)] = 1634,
+  [SMALL_STATE(19)] = 1737,
+  [SMALL_STATE(20)] = 1840,
+  [SMALL_STATE(21)] = 1943,
+  [SMALL_STATE(22)] = 2046,
+  [SMALL_STATE(23)] = 2149,
+  [SMALL_STATE(24)] = 2252,
+  [SMALL_STATE(25)] = 2355,
+  [SMALL_STATE(26)] = 2458,
+  [SMALL_STATE(27)] = 2561,
+  [SMALL_STATE(28)] = 2664,
+  [SMALL_STATE(29)] = 2767,
+  [SMALL_STATE(30)] = 2870,
+  [SMALL_STATE(31)] = 2973,
+  [SMALL_STATE(32)] = 3076,
+  [SMALL_STATE(33)] = 3179,
+  [SMALL_STATE(34)] = 3282,
+  [SMALL_STATE(35)] = 3385,
+  [SMALL_STATE(36)] = 3488,
+  [SMALL_STATE(37)] = 3591,
+  [SMALL_STATE(38)] = 3694,
+  [SMALL_STATE(39)] = 3797,
+  [SMALL_STATE(40)] = 3900,
+  [SMALL_STATE(41)] = 4003,
+  [SMALL_STATE(42)] = 4106,
+  [SMALL_STATE(43)] = 4209,
+  [SMALL_STATE(44)] = 4312,
+  [SMALL_STATE(45)] = 4412,
+  [SMALL_STATE(46)] = 4512,
+  [SMALL_STATE(47)] = 4612,
+  [SMALL_STATE(48)] = 4712,
+  [SMALL_STATE(49)] = 4812,
+  [SMALL_STATE(50)] = 4912,
+  [SMALL_STATE(51)] = 5012,
+  [SMALL_STATE(52)] = 5112,
+  [SMALL_STATE(53)] = 5212,
+  [SMALL_STATE(54)] = 5312,
+  [SMALL_STATE(55)] = 5412,
+  [SMALL_STATE(56)] = 5512,
+  [SMALL_STATE(57)] = 5612,
+  [SMALL_STATE(58)] = 5712,
+  [SMALL_STATE(59)] = 5812,
+  [SMALL_STATE(60)] = 5912,
+  [SMALL_STATE(61)] = 6012,
+  [SMALL_STATE(62)] = 6112,
+  [SMALL_STATE(63)] = 6212,
+  [SMALL_STATE(64)] = 6312,
+  [SMALL_STATE(65)] = 6412,
+  [SMALL_STATE(66)] = 6512,
+  [SMALL_STATE(67)] = 6612,
+  [SMALL_STATE(68)] = 6712,
+  [SMALL_STATE(69)] = 6812,
+  [SMALL_STATE(70)] = 6912,
+  [SMALL_STATE(71)] = 7012,
+  [SMALL_STATE(72)] = 7112,
+  [SMALL_STATE(73)] = 7212,
+  [SMALL_STATE(74)] = 7312,
+  [SMALL_STATE(75)] = 7412,
+  [SMALL_STATE(76)] = 7512,
+  [SMALL_STATE(77)] = 7598,
+  [SMALL_STATE(78)] = 7684,
+  [SMALL_STATE(79)] = 7770,
+  [SMALL_STATE(80)] = 7827,
+  [SMALL_STATE(81)] = 7884,
+  [SMALL_STATE(82)] = 7941,
+  [SMALL_STATE(83)] = 7998,
+  [SMALL_STATE(84)] = 8055,
+  [SMALL_STATE(85)] = 8106,
+  [SMALL_STATE(86)] = 8153,
+  [SMALL_STATE(87)] = 8200,
+  [SMALL_STATE(88)] = 8251,
+  [SMALL_STATE(89)] = 8298,
+  [SMALL_STATE(90)] = 8349,
+  [SMALL_STATE(91)] = 8399,
+  [SMALL_STATE(92)] = 8447,
+  [SMALL_STATE(93)] = 8493,
+  [SMALL_STATE(94)] = 8539,
+  [SMALL_STATE(95)] = 8585,
+  [SMALL_STATE(96)] = 8631,
+  [SMALL_STATE(97)] = 8677,
+  [SMALL_STATE(98)] = 8756,
+  [SMALL_STATE(99)] = 8835,
+  [SMALL_STATE(100)] = 8888,
+  [SMALL_STATE(101)] = 8941,
+  [SMALL_STATE(102)] = 9020,
+  [SMALL_STATE(103)] = 9099,
+  [SMALL_STATE(104)] = 9144,
+  [SMALL_STATE(105)] = 9197,
+  [SMALL_STATE(106)] = 9276,
+  [SMALL_STATE(107)] = 9355,
+  [SMALL_STATE(108)] = 9434,
+  [SMALL_STATE(109)] = 9513,
+  [SMALL_STATE(110)] = 9592,
+  [SMALL_STATE(111)] = 9671,
+  [SMALL_STATE(112)] = 9750,
+  [SMALL_STATE(113)] = 9829,
+  [SMALL_STATE(114)] = 9908,
+  [SMALL_STATE(115)] = 9987,
+  [SMALL_STATE(116)] = 10040,
+  [SMALL_STATE(117)] = 10119,
+  [SMALL_STATE(118)] = 10198,
+  [SMALL_STATE(119)] = 10277,
+  [SMALL_STATE(120)] = 10322,
+  [SMALL_STATE(121)] = 10375,
+  [SMALL_STATE(122)] = 10420,
+  [SMALL_STATE(123)] = 10465,
+  [SMALL_STATE(124)] = 10518,
+  [SMALL_STATE(125)] = 10571,
+  [SMALL_STATE(126)] = 10650,
+  [SMALL_STATE(127)] = 10729,
+  [SMALL_STATE(128)] = 10774,
+  [SMALL_STATE(129)] = 10827,
+  [SMALL_STATE(130)] = 10880,
+  [SMALL_STATE(131)] = 10925,
+  [SMALL_STATE(132)] = 10970,
+  [SMALL_STATE(133)] = 11015,
+  [SMALL_STATE(134)] = 11094,
+  [SMALL_STATE(135)] = 11173,
+  [SMALL_STATE(136)] = 11252,
+  [SMALL_STATE(137)] = 11331,
+  [SMALL_STATE(138)] = 11410,
+  [SMALL_STATE(139)] = 11489,
+  [SMALL_STATE(140)] = 11568,
+  [SMALL_STATE(141)] = 11647,
+  [SMALL_STATE(142)] = 11726,
+  [SMALL_STATE(143)] = 11805,
+  [SMALL_STATE(144)] = 11850,
+  [SMALL_STATE(145)] = 11895,
+  [SMALL_STATE(146)] = 11940,
+  [SMALL_STATE(147)] = 11985,
+  [SMALL_STATE(148)] = 12032,
+  [SMALL_STATE(149)] = 12111,
+  [SMALL_STATE(150)] = 12158,
+  [SMALL_STATE(151)] = 12203,
+  [SMALL_STATE(152)] = 12248,
+  [SMALL_STATE(153)] = 12301,
+  [SMALL_STATE(154)] = 12380,
+  [SMALL_STATE(155)] = 12459,
+  [SMALL_STATE(156)] = 12538,
+  [SMALL_STATE(157)] = 12617,
+  [SMALL_STATE(158)] = 12696,
+  [SMALL_STATE(159)] = 12775,
+  [SMALL_STATE(160)] = 12854,
+  [SMALL_STATE(161)] = 12933,
+  [SMALL_STATE(162)] = 13012,
+  [SMALL_STATE(163)] = 13091,
+  [SMALL_STATE(164)] = 13144,
+  [SMALL_STATE(165)] = 13223,
+  [SMALL_STATE(166)] = 13276,
+  [SMALL_STATE(167)] = 13329,
+  [SMALL_STATE(168)] = 13408,
+  [SMALL_STATE(169)] = 13461,
+  [SMALL_STATE(170)] = 13540,
+  [SMALL_STATE(171)] = 13619,
+  [SMALL_STATE(172)] = 13672,
+  [SMALL_STATE(173)] = 13716,
+  [SMALL_STATE(174)] = 13759,
+  [SMALL_STATE(175)] = 13806,
+  [SMALL_STATE(176)] = 13851,
+  [SMALL_STATE(177)] = 13894,
+  [SMALL_STATE(178)] = 13941,
+  [SMALL_STATE(179)] = 13984,
+  [SMALL_STATE(180)] = 14031,
+  [SMALL_STATE(181)] = 14076,
+  [SMALL_STATE(182)] = 14123,
+  [SMALL_STATE(183)] = 14170,
+  [SMALL_STATE(184)] = 14217,
+  [SMALL_STATE(185)] = 14260,
+  [SMALL_STATE(186)] = 14307,
+  [SMALL_STATE(187)] = 14350,
+  [SMALL_STATE(188)] = 14393,
+  [SMALL_STATE(189)] = 14438,
+  [SMALL_STATE(190)] = 14485,
+  [SMALL_STATE(191)] = 14532,
+  [SMALL_STATE(192)] = 14575,
+  [SMALL_STATE(193)] = 14618,
+  [SMALL_STATE(194)] = 14665,
+  [SMALL_STATE(195)] = 14708,
+  [SMALL_STATE(196)] = 14751,
+  [SMALL_STATE(197)] = 14798,
+  [SMALL_STATE(198)] = 14845,
+  [SMALL_STATE(199)] = 14887,
+  [SMALL_STATE(200)] = 14929,
+  [SMALL_STATE(201)] = 14971,
+  [SMALL_STATE(202)] = 15013,
+  [SMALL_STATE(203)] = 15055,
+  [SMALL_STATE(204)] = 15097,
+  [SMALL_STATE(205)] = 15139,
+  [SMALL_STATE(206)] = 15181,
+  [SMALL_STATE(207)] = 15223,
+  [SMALL_STATE(208)] = 15265,
+  [SMALL_STATE(209)] = 15307,
+  [SMALL_STATE(210)] = 15349,
+  [SMALL_STATE(211)] = 15391,
+  [SMALL_STATE(212)] = 15433,
+  [SMALL_STATE(213)] = 15475,
+  [SMALL_STATE(214)] = 15516,
+  [SMALL_STATE(215)] = 15559,
+  [SMALL_STATE(216)] = 15600,
+  [SMALL_STATE(217)] = 15643,
+  [SMALL_STATE(218)] = 15684,
+  [SMALL_STATE(219)] = 15725,
+  [SMALL_STATE(220)] = 15766,
+  [SMALL_STATE(221)] = 15807,
+  [SMALL_STATE(222)] = 15848,
+  [SMALL_STATE(223)] = 15889,
+  [SMALL_STATE(224)] = 15930,
+  [SMALL_STATE(225)] = 15971,
+  [SMALL_STATE(226)] = 16012,
+  [SMALL_STATE(227)] = 16053,
+  [SMALL_STATE(228)] = 16094,
+  [SMALL_STATE(229)] = 16135,
+  [SMALL_STATE(230)] = 16176,
+  [SMALL_STATE(231)] = 16217,
+  [SMALL_STATE(232)] = 16258,
+  [SMALL_STATE(233)] = 16299,
+  [SMALL_STATE(234)] = 16340,
+  [SMALL_STATE(235)] = 16383,
+  [SMALL_STATE(236)] = 16424,
+  [SMALL_STATE(237)] = 16465,
+  [SMALL_STATE(238)] = 16508,
+  [SMALL_STATE(239)] = 16549,
+  [SMALL_STATE(240)] = 16590,
+  [SMALL_STATE(241)] = 16631,
+  [SMALL_STATE(242)] = 16672,
+  [SMALL_STATE(243)] = 16713,
+  [SMALL_STATE(244)] = 16754,
+  [SMALL_STATE(245)] = 16795,
+  [SMALL_STATE(246)] = 16836,
+  [SMALL_STATE(247)] = 16877,
+  [SMALL_STATE(248)] = 16918,
+  [SMALL_STATE(249)] = 16959,
+  [SMALL_STATE(250)] = 17000,
+  [SMALL_STATE(251)] = 17041,
+  [SMALL_STATE(252)] = 17082,
+  [SMALL_STATE(253)] = 17125,
+  [SMALL_STATE(254)] = 17166,
+  [SMALL_STATE(255)] = 17207,
+  [SMALL_STATE(256)] = 17248,
+  [SMALL_STATE(257)] = 17289,
+  [SMALL_STATE(258)] = 17330,
+  [SMALL_STATE(259)] = 17371,
+  [SMALL_STATE(260)] = 17412,
+  [SMALL_STATE(261)] = 17455,
+  [SMALL_STATE(262)] = 17495,
+  [SMALL_STATE(263)] = 17535,
+  [SMALL_STATE(264)] = 17575,
+  [SMALL_STATE(265)] = 17615,
+  [SMALL_STATE(266)] = 17655,
+  [SMALL_STATE(267)] = 17695,
+  [SMALL_STATE(268)] = 17766,
+  [SMALL_STATE(269)] = 17837,
+  [SMALL_STATE(270)] = 17908,
+  [SMALL_STATE(271)] = 17979,
+  [SMALL_STATE(272)] = 18050,
+  [SMALL_STATE(273)] = 18121,
+  [SMALL_STATE(274)] = 18192,
+  [SMALL_STATE(275)] = 18263,
+  [SMALL_STATE(276)] = 18334,
+  [SMALL_STATE(277)] = 18399,
+  [SMALL_STATE(278)] = 18464,
+  [SMALL_STATE(279)] = 18529,
+  [SMALL_STATE(280)] = 18594,
+  [SMALL_STATE(281)] = 18629,
+  [SMALL_STATE(282)] = 18663,
+  [SMALL_STATE(283)] = 18697,
+  [SMALL_STATE(284)] = 18731,
+  [SMALL_STATE(285)] = 18764,
+  [SMALL_STATE(286)] = 18795,
+  [SMALL_STATE(287)] = 18826,
+  [SMALL_STATE(288)] = 18861,
+  [SMALL_STATE(289)] = 18896,
+  [SMALL_STATE(290)] = 18930,
+  [SMALL_STATE(291)] = 18963,
+  [SMALL_STATE(292)] = 18996,
+  [SMALL_STATE(293)] = 19029,
+  [SMALL_STATE(294)] = 19057,
+  [SMALL_STATE(295)] = 19099,
+  [SMALL_STATE(296)] = 19139,
+  [SMALL_STATE(297)] = 19185,
+  [SMALL_STATE(298)] = 19217,
+  [SMALL_STATE(299)] = 19245,
+  [SMALL_STATE(300)] = 19295,
+  [SMALL_STATE(301)] = 19323,
+  [SMALL_STATE(302)] = 19351,
+  [SMALL_STATE(303)] = 19401,
+  [SMALL_STATE(304)] = 19441,
+  [SMALL_STATE(305)] = 19479,
+  [SMALL_STATE(306)] = 19523,
+  [SMALL_STATE(307)] = 19555,
+  [SMALL_STATE(308)] = 19591,
+  [SMALL_STATE(309)] = 19620,
+  [SMALL_STATE(310)] = 19661,
+  [SMALL_STATE(311)] = 19700,
+  [SMALL_STATE(312)] = 19749,
+  [SMALL_STATE(313)] = 19792,
+  [SMALL_STATE(314)] = 19829,
+  [SMALL_STATE(315)] = 19864,
+  [SMALL_STATE(316)] = 19905,
+  [SMALL_STATE(317)] = 19938,
+  [SMALL_STATE(318)] = 19977,
+  [SMALL_STATE(319)] = 20014,
+  [SMALL_STATE(320)] = 20055,
+  [SMALL_STATE(321)] = 20104,
+  [SMALL_STATE(322)] = 20137,
+  [SMALL_STATE(323)] = 20180,
+  [SMALL_STATE(324)] = 20209,
+  [SMALL_STATE(325)] = 20256,
+  [SMALL_STATE(326)] = 20299,
+  [SMALL_STATE(327)] = 20336,
+  [SMALL_STATE(328)] = 20365,
+  [SMALL_STATE(329)] = 20414,
+  [SMALL_STATE(330)] = 20443,
+  [SMALL_STATE(331)] = 20490,
+  [SMALL_STATE(332)] = 20525,
+  [SMALL_STATE(333)] = 20562,
+  [SMALL_STATE(334)] = 20595,
+  [SMALL_STATE(335)] = 20644,
+  [SMALL_STATE(336)] = 20691,
+  [SMALL_STATE(337)] = 20738,
+  [SMALL_STATE(338)] = 20773,
+  [SMALL_STATE(339)] = 20820,
+  [SMALL_STATE(340)] = 20859,
+  [SMALL_STATE(341)] = 20896,
+  [SMALL_STATE(342)] = 20925,
+  [SMALL_STATE(343)] = 20962,
+  [SMALL_STATE(344)] = 21009,
+  [SMALL_STATE(345)] = 21038,
+  [SMALL_STATE(346)] = 21082,
+  [SMALL_STATE(347)] = 21106,
+  [SMALL_STATE(348)] = 21150,
+  [SMALL_STATE(349)] = 21194,
+  [SMALL_STATE(350)] = 21238,
+  [SMALL_STATE(351)] = 21279,
+  [SMALL_STATE(352)] = 21320,
+  [SMALL_STATE(353)] = 21361,
+  [SMALL_STATE(354)] = 21402,
+  [SMALL_STATE(355)] = 21443,
+  [SMALL_STATE(356)] = 21484,
+  [SMALL_STATE(357)] = 21525,
+  [SMALL_STATE(358)] = 21566,
+  [SMALL_STATE(359)] = 21607,
+  [SMALL_STATE(360)] = 21648,
+  [SMALL_STATE(361)] = 21689,
+  [SMALL_STATE(362)] = 21730,
+  [SMALL_STATE(363)] = 21768,
+  [SMALL_STATE(364)] = 21806,
+  [SMALL_STATE(365)] = 21844,
+  [SMALL_STATE(366)] = 21882,
+  [SMALL_STATE(367)] = 21904,
+  [SMALL_STATE(368)] = 21932,
+  [SMALL_STATE(369)] = 21960,
+  [SMALL_STATE(370)] = 21985,
+  [SMALL_STATE(371)] = 22010,
+  [SMALL_STATE(372)] = 22035,
+  [SMALL_STATE(373)] = 22060,
+  [SMALL_STATE(374)] = 22084,
+  [SMALL_STATE(375)] = 22102,
+  [SMALL_STATE(376)] = 22126,
+  [SMALL_STATE(377)] = 22150,
+  [SMALL_STATE(378)] = 22168,
+  [SMALL_STATE(379)] = 22192,
+  [SMALL_STATE(380)] = 22214,
+  [SMALL_STATE(381)] = 22236,
+  [SMALL_STATE(382)] = 22258,
+  [SMALL_STATE(383)] = 22282,
+  [SMALL_STATE(384)] = 22306,
+  [SMALL_STATE(385)] = 22330,
+  [SMALL_STATE(386)] = 22354,
+  [SMALL_STATE(387)] = 22376,
+  [SMALL_STATE(388)] = 22400,
+  [SMALL_STATE(389)] = 22424,
+  [SMALL_STATE(390)] = 22448,
+  [SMALL_STATE(391)] = 22472,
+  [SMALL_STATE(392)] = 22496,
+  [SMALL_STATE(393)] = 22520,
+  [SMALL_STATE(394)] = 22542,
+  [SMALL_STATE(395)] = 22566,
+  [SMALL_STATE(396)] = 22590,
+  [SMALL_STATE(397)] = 22614,
+  [SMALL_STATE(398)] = 22638,
+  [SMALL_STATE(399)] = 22662,
+  [SMALL_STATE(400)] = 22686,
+  [SMALL_STATE(401)] = 22710,
+  [SMALL_STATE(402)] = 22734,
+  [SMALL_STATE(403)] = 22758,
+  [SMALL_STATE(404)] = 22782,
+  [SMALL_STATE(405)] = 22806,
+  [SMALL_STATE(406)] = 22830,
+  [SMALL_STATE(407)] = 22854,
+  [SMALL_STATE(408)] = 22878,
+  [SMALL_STATE(409)] = 22900,
+  [SMALL_STATE(410)] = 22924,
+  [SMALL_STATE(411)] = 22948,
+  [SMALL_STATE(412)] = 22961,
+  [SMALL_STATE(413)] = 22974,
+  [SMALL_STATE(414)] = 22993,
+  [SMALL_STATE(415)] = 23006,
+  [SMALL_STATE(416)] = 23025,
+  [SMALL_STATE(417)] = 23038,
+  [SMALL_STATE(418)] = 23057,
+  [SMALL_STATE(419)] = 23076,
+  [SMALL_STATE(420)] = 23095,
+  [SMALL_STATE(421)] = 23114,
+  [SMALL_STATE(422)] = 23127,
+  [SMALL_STATE(423)] = 23140,
+  [SMALL_STATE(424)] = 23153,
+  [SMALL_STATE(425)] = 23166,
+  [SMALL_STATE(426)] = 23179,
+  [SMALL_STATE(427)] = 23192,
+  [SMALL_STATE(428)] = 23206,
+  [SMALL_STATE(429)] = 23220,
+  [SMALL_STATE(430)] = 23232,
+  [SMALL_STATE(431)] = 23252,
+  [SMALL_STATE(432)] = 23266,
+  [SMALL_STATE(433)] = 23280,
+  [SMALL_STATE(434)] = 23294,
+  [SMALL_STATE(435)] = 23308,
+  [SMALL_STATE(436)] = 23320,
+  [SMALL_STATE(437)] = 23334,
+  [SMALL_STATE(438)] = 23348,
+  [SMALL_STATE(439)] = 23368,
+  [SMALL_STATE(440)] = 23382,
+  [SMALL_STATE(441)] = 23396,
+  [SMALL_STATE(442)] = 23408,
+  [SMALL_STATE(443)] = 23422,
+  [SMALL_STATE(444)] = 23433,
+  [SMALL_STATE(445)] = 23450,
+  [SMALL_STATE(446)] = 23467,
+  [SMALL_STATE(447)] = 23481,
+  [SMALL_STATE(448)] = 23494,
+  [SMALL_STATE(449)] = 23507,
+  [SMALL_STATE(450)] = 23518,
+  [SMALL_STATE(451)] = 23531,
+  [SMALL_STATE(452)] = 23542,
+  [SMALL_STATE(453)] = 23555,
+  [SMALL_STATE(454)] = 23566,
+  [SMALL_STATE(455)] = 23577,
+  [SMALL_STATE(456)] = 23588,
+  [SMALL_STATE(457)] = 23601,
+  [SMALL_STATE(458)] = 23609,
+  [SMALL_STATE(459)] = 23619,
+  [SMALL_STATE(460)] = 23629,
+  [SMALL_STATE(461)] = 23637,
+  [SMALL_STATE(462)] = 23645,
+  [SMALL_STATE(463)] = 23653,
+  [SMALL_STATE(464)] = 23661,
+  [SMALL_STATE(465)] = 23669,
+  [SMALL_STATE(466)] = 23679,
+  [SMALL_STATE(467)] = 23687,
+  [SMALL_STATE(468)] = 23697,
+  [SMALL_STATE(469)] = 23707,
+  [SMALL_STATE(470)] = 23717,
+  [SMALL_STATE(471)] = 23727,
+  [SMALL_STATE(472)] = 23737,
+  [SMALL_STATE(473)] = 23744,
+  [SMALL_STATE(474)] = 23751,
+  [SMALL_STATE(475)] = 23758,
+  [SMALL_STATE(476)] = 23765,
+  [SMALL_STATE(477)] = 23772,
+  [SMALL_STATE(478)] = 23779,
+  [SMALL_STATE(479)] = 23786,
+  [SMALL_STATE(480)] = 23793,
+  [SMALL_STATE(481)] = 23800,
+  [SMALL_STATE(482)] = 23807,
+  [SMALL_STATE(483)] = 23814,
+  [SMALL_STATE(484)] = 23821,
+  [SMALL_STATE(485)] = 23828,
+  [SMALL_STATE(486)] = 23835,
+  [SMALL_STATE(487)] = 23842,
+  [SMALL_STATE(488)] = 23849,
+  [SMALL_STATE(489)] = 23856,
+  [SMALL_STATE(490)] = 23863,
+  [SMALL_STATE(491)] = 23870,
+  [SMALL_STATE(492)] = 23877,
+  [SMALL_STATE(493)] = 23884,
+  [SMALL_STATE(494)] = 23891,
+  [SMALL_STATE(495)] = 23898,
+  [SMALL_STATE(496)] = 23905,
+  [SMALL_STATE(497)] = 23912,
+  [SMALL_STATE(498)] = 23919,
+  [SMALL_STATE(499)] = 23926,
+  [SMALL_STATE(500)] = 23933,
+  [SMALL_STATE(501)] = 23940,
+  [SMALL_STATE(502)] = 23947,
+  [SMALL_STATE(503)] = 23954,
+  [SMALL_STATE(504)] = 23961,
+  [SMALL_STATE(505)] = 23968,
+  [SMALL_STATE(506)] = 23975,
+  [SMALL_STATE(507)] = 23982,
+  [SMALL_STATE(508)] = 23989,
+  [SMALL_STATE(509)] = 23996,
+  [SMALL_STATE(510)] = 24003,
+  [SMALL_STATE(511)] = 24010,
+  [SMALL_STATE(512)] = 24017,
+  [SMALL_STATE(513)] = 24024,
+  [SMALL_STATE(514)] = 24031,
+  [SMALL_STATE(515)] = 24038,
+  [SMALL_STATE(516)] = 24045,
+  [SMALL_STATE(517)] = 24052,
+  [SMALL_STATE(518)] = 24059,
+  [SMALL_STATE(519)] = 24066,
+  [SMALL_STATE(520)] = 24073,
+  [SMALL_STATE(521)] = 24080,
+  [SMALL_STATE(522)] = 24087,
+  [SMALL_STATE(523)] = 24094,
+  [SMALL_STATE(524)] = 24101,
+  [SMALL_STATE(525)] = 24108,
+  [SMALL_STATE(526)] = 24115,
+  [SMALL_STATE(527)] = 24122,
+  [SMALL_STATE(528)] = 24129,
+  [SMALL_STATE(529)] = 24136,
+  [SMALL_STATE(530)] = 24143,
+  [SMALL_STATE(531)] = 24150,
+  [SMALL_STATE(532)] = 24157,
+  [SMALL_STATE(533)] = 24164,
+  [SMALL_STATE(534)] = 24171,
+  [SMALL_STATE(535)] = 24178,
+  [SMALL_STATE(536)] = 24185,
+  [SMALL_STATE(537)] = 24192,
+  [SMALL_STATE(538)] = 24199,
+  [SMALL_STATE(539)] = 24206,
+  [SMALL_STATE(540)] = 24213,
+  [SMALL_STATE(541)] = 24220,
+  [SMALL_STATE(542)] = 24227,
+  [SMALL_STATE(543)] = 24234,
+  [SMALL_STATE(544)] = 24241,
+  [SMALL_STATE(545)] = 24248,
+  [SMALL_STATE(546)] = 24255,
+  [SMALL_STATE(547)] = 24262,
+  [SMALL_STATE(548)] = 24269,
+  [SMALL_STATE(549)] = 24276,
+  [SMALL_STATE(550)] = 24283,
+  [SMALL_STATE(551)] = 24290,
+  [SMALL_STATE(552)] = 24297,
+  [SMALL_STATE(553)] = 24304,
+};
+
+static const TSParseActionEntry ts_parse_actions[] = {
+  [0] = {.entry = {.count = 0, .reusable = false}},
+  [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
+  [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT_EXTRA(),
+  [5] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_code, 0, 0, 0),
+  [7] = {.entry = {.count = 1, .reusable = false}}, SHIFT(191),
+  [9] = {.entry = {.count = 1, .reusable = false}}, SHIFT(214),
+  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(214),
+  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(152),
+  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(311),
+  [17] = {.entry = {.count = 1, .reusable = false}}, SHIFT(22),
+  [19] = {.entry = {.count = 1, .reusable = false}}, SHIFT(3),
+  [21] = {.entry = {.count = 1, .reusable = false}}, SHIFT(349),
+  [23] = {.entry = {.count = 1, .reusable = false}}, SHIFT(4),
+  [25] = {.entry = {.count = 1, .reusable = true}}, SHIFT(164),
+  [27] = {.entry = {.count = 1, .reusable = true}}, SHIFT(148),
+  [29] = {.entry = {.count = 1, .reusable = true}}, SHIFT(5),
+  [31] = {.entry = {.count = 1, .reusable = false}}, SHIFT(539),
+  [33] = {.entry = {.count = 1, .reusable = true}}, SHIFT(409),
+  [35] = {.entry = {.count = 1, .reusable = true}}, SHIFT(389),
+  [37] = {.entry = {.count = 1, .reusable = true}}, SHIFT(267),
+  [39] = {.entry = {.count = 1, .reusable = true}}, SHIFT(191),
+  [41] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
+  [43] = {.entry = {.count = 1, .reusable = false}}, SHIFT(88),
+  [45] = {.entry = {.count = 1, .reusable = false}}, SHIFT(149),
+  [47] = {.entry = {.count = 1, .reusable = true}}, SHIFT(149),
+  [49] = {.entry = {.count = 1, .reusable = true}}, SHIFT(80),
+  [51] = {.entry = {.count = 1, .reusable = true}}, SHIFT(320),
+  [53] = {.entry = {.count = 1, .reusable = false}}, SHIFT(16),
+  [55] = {.entry = {.count = 1, .reusable = false}}, SHIFT(17),
+  [57] = {.entry = {.count = 1, .reusable = false}}, SHIFT(345),
+  [59] = {.entry = {.count = 1, .reusable = false}}, SHIFT(39),
+  [61] = {.entry = {.count = 1, .reusable = true}}, SHIFT(156),
+  [63] = {.entry = {.count = 1, .reusable = true}}, SHIFT(142),
+  [65] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
+  [67] = {.entry = {.count = 1, .reusable = false}}, SHIFT(513),
+  [69] = {.entry = {.count = 1, .reusable = true}}, SHIFT(382),
+  [71] = {.entry = {.count = 1, .reusable = true}}, SHIFT(376),
+  [73] = {.entry = {.count = 1, .reusable = true}}, SHIFT(273),
+  [75] = {.entry = {.count = 1, .reusable = true}}, SHIFT(88),
+  [77] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
+  [79] = {.entry = {.count = 1, .reusable = false}}, SHIFT(178),
+  [81] = {.entry = {.count = 1, .reusable = false}}, SHIFT(216),
+  [83] = {.entry = {.count = 1, .reusable = true}}, SHIFT(216),
+  [85] = {.entry = {.count = 1, .reusable = true}}, SHIFT(168),
+  [87] = {.entry = {.count = 1, .reusable = true}}, SHIFT(328),
+  [89] = {.entry = {.count = 1, .reusable = false}}, SHIFT(23),
+  [91] = {.entry = {.count = 1, .reusable = false}}, SHIFT(24),
+  [93] = {.entry = {.count = 1, .reusable = false}}, SHIFT(347),
+  [95] = {.entry = {.count = 1, .reusable = false}}, SHIFT(41),
+  [97] = {.entry = {.count = 1, .reusable = true}}, SHIFT(169),
+  [99] = {.entry = {.count = 1, .reusable = true}}, SHIFT(170),
+  [101] = {.entry = {.count = 1, .reusable = true}}, SHIFT(25),
+  [103] = {.entry = {.count = 1, .reusable = false}}, SHIFT(525),
+  [105] = {.entry = {.count = 1, .reusable = true}}, SHIFT(388),
+  [107] = {.entry = {.count = 1, .reusable = true}}, SHIFT(383),
+  [109] = {.entry = {.count = 1, .reusable = true}}, SHIFT(269),
+  [111] = {.entry = {.count = 1, .reusable = true}}, SHIFT(178),
+  [113] = {.entry = {.count = 1, .reusable = true}}, SHIFT(166),
+  [115] = {.entry = {.count = 1, .reusable = false}}, SHIFT(176),
+  [117] = {.entry = {.count = 1, .reusable = false}}, SHIFT(260),
+  [119] = {.entry = {.count = 1, .reusable = true}}, SHIFT(260),
+  [121] = {.entry = {.count = 1, .reusable = true}}, SHIFT(124),
+  [123] = {.entry = {.count = 1, .reusable = true}}, SHIFT(334),
+  [125] = {.entry = {.count = 1, .reusable = false}}, SHIFT(29),
+  [127] = {.entry = {.count = 1, .reusable = false}}, SHIFT(30),
+  [129] = {.entry = {.count = 1, .reusable = false}}, SHIFT(348),
+  [131] = {.entry = {.count = 1, .reusable = false}}, SHIFT(42),
+  [133] = {.entry = {.count = 1, .reusable = true}}, SHIFT(125),
+  [135] = {.entry = {.count = 1, .reusable = true}}, SHIFT(126),
+  [137] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
+  [139] = {.entry = {.count = 1, .reusable = false}}, SHIFT(534),
+  [141] = {.entry = {.count = 1, .reusable = true}}, SHIFT(400),
+  [143] = {.entry = {.count = 1, .reusable = true}}, SHIFT(410),
+  [145] = {.entry = {.count = 1, .reusable = true}}, SHIFT(271),
+  [147] = {.entry = {.count = 1, .reusable = true}}, SHIFT(176),
+  [149] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
+  [151] = {.entry = {.count = 1, .reusable = true}}, SHIFT(357),
+  [153] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__expr_op, 1, 0, 0),
+  [155] = {.entry = {.count = 1, .reusable = false}}, SHIFT(537),
+  [157] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__expr_op, 1, 0, 0),
+  [159] = {.entry = {.count = 1, .reusable = true}}, SHIFT(353),
+  [161] = {.entry = {.count = 1, .reusable = false}}, SHIFT(520),
+  [163] = {.entry = {.count = 1, .reusable = true}}, SHIFT(355),
+  [165] = {.entry = {.count = 1, .reusable = false}}, SHIFT(476),
+  [167] = {.entry = {.count = 1, .reusable = true}}, SHIFT(360),
+  [169] = {.entry = {.count = 1, .reusable = false}}, SHIFT(503),
+  [171] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_path_expression, 2, 0, 0),
+  [173] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_path_expression, 2, 0, 0),
+  [175] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
+  [177] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
+  [179] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_hpath_expression, 1, 0, 0),
+  [181] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_hpath_expression, 1, 0, 0),
+  [183] = {.entry = {.count = 1, .reusable = true}}, SHIFT(82),
+  [185] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_path_expression_repeat1, 2, 0, 0),
+  [187] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_path_expression_repeat1, 2, 0, 0),
+  [189] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_path_expression_repeat1, 2, 0, 0), SHIFT_REPEAT(19),
+  [192] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_path_expression_repeat1, 2, 0, 0), SHIFT_REPEAT(81),
+  [195] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_hpath_expression, 2, 0, 0),
+  [197] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_hpath_expression, 2, 0, 0),
+  [199] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_path_expression, 1, 0, 0),
+  [201] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_path_expression, 1, 0, 0),
+  [203] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
+  [205] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attrpath, 1, 0, 5),
+  [207] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attrpath, 1, 0, 5),
+  [209] = {.entry = {.count = 1, .reusable = false}}, SHIFT(415),
+  [211] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_path_expression_repeat1, 1, 0, 3),
+  [213] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_path_expression_repeat1, 1, 0, 3),
+  [215] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__immediate_interpolation, 3, 0, 18),
+  [217] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__immediate_interpolation, 3, 0, 18),
+  [219] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attrpath, 2, 0, 13),
+  [221] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attrpath, 2, 0, 13),
+  [223] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_identifier, 1, 0, 0),
+  [225] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_identifier, 1, 0, 0),
+  [227] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_attrpath_repeat1, 2, 0, 30),
+  [229] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_attrpath_repeat1, 2, 0, 30),
+  [231] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_attrpath_repeat1, 2, 0, 30), SHIFT_REPEAT(415),
+  [234] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variable_expression, 1, 0, 2),
+  [236] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable_expression, 1, 0, 2),
+  [238] = {.entry = {.count = 1, .reusable = true}}, SHIFT(47),
+  [240] = {.entry = {.count = 1, .reusable = true}}, SHIFT(465),
+  [242] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attrset_expression, 2, 0, 0),
+  [244] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attrset_expression, 2, 0, 0),
+  [246] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_formals, 2, 0, 0),
+  [248] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interpolation, 3, 0, 18),
+  [250] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interpolation, 3, 0, 18),
+  [252] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string_expression, 2, 0, 0),
+  [254] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string_expression, 2, 0, 0),
+  [256] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_attrpath_repeat1, 2, 0, 32),
+  [258] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_attrpath_repeat1, 2, 0, 32),
+  [260] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string_expression, 3, 0, 20),
+  [262] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string_expression, 3, 0, 20),
+  [264] = {.entry = {.count = 1, .reusable = true}}, SHIFT(26),
+  [266] = {.entry = {.count = 1, .reusable = true}}, SHIFT(115),
+  [268] = {.entry = {.count = 1, .reusable = true}}, SHIFT(2),
+  [270] = {.entry = {.count = 1, .reusable = true}}, SHIFT(120),
+  [272] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_path_expression_repeat1, 2, 0, 0), SHIFT_REPEAT(26),
+  [275] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_path_expression_repeat1, 2, 0, 0), SHIFT_REPEAT(115),
+  [278] = {.entry = {.count = 1, .reusable = true}}, SHIFT(163),
+  [280] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attrset_expression, 3, 0, 15),
+  [282] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attrset_expression, 3, 0, 15),
+  [284] = {.entry = {.count = 1, .reusable = true}}, SHIFT(32),
+  [286] = {.entry = {.count = 1, .reusable = true}}, SHIFT(128),
+  [288] = {.entry = {.count = 1, .reusable = true}}, SHIFT(129),
+  [290] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_indented_string_expression, 2, 0, 0),
+  [292] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_indented_string_expression, 2, 0, 0),
+  [294] = {.entry = {.count = 1, .reusable = true}}, SHIFT(171),
+  [296] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_let_attrset_expression, 3, 0, 0),
+  [298] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_let_attrset_expression, 3, 0, 0),
+  [300] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_list_expression, 2, 0, 0),
+  [302] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list_expression, 2, 0, 0),
+  [304] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parenthesized_expression, 3, 0, 18),
+  [306] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesized_expression, 3, 0, 18),
+  [308] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_rec_attrset_expression, 3, 0, 0),
+  [310] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_rec_attrset_expression, 3, 0, 0),
+  [312] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_indented_string_expression, 3, 0, 20),
+  [314] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_indented_string_expression, 3, 0, 20),
+  [316] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_list_expression, 3, 0, 22),
+  [318] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list_expression, 3, 0, 22),
+  [320] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_select_expression, 3, 0, 28),
+  [322] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_expression, 3, 0, 28),
+  [324] = {.entry = {.count = 1, .reusable = false}}, SHIFT(276),
+  [326] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__expr_select_expression, 1, 0, 0),
+  [328] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__expr_select_expression, 1, 0, 0),
+  [330] = {.entry = {.count = 1, .reusable = false}}, SHIFT(381),
+  [332] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_let_attrset_expression, 4, 0, 37),
+  [334] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_let_attrset_expression, 4, 0, 37),
+  [336] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_rec_attrset_expression, 4, 0, 37),
+  [338] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_rec_attrset_expression, 4, 0, 37),
+  [340] = {.entry = {.count = 1, .reusable = true}}, SHIFT(165),
+  [342] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_path_expression_repeat1, 2, 0, 0), SHIFT_REPEAT(2),
+  [345] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_path_expression_repeat1, 2, 0, 0), SHIFT_REPEAT(163),
+  [348] = {.entry = {.count = 1, .reusable = true}}, SHIFT(99),
+  [350] = {.entry = {.count = 1, .reusable = true}}, SHIFT(100),
+  [352] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_path_expression_repeat1, 2, 0, 0), SHIFT_REPEAT(32),
+  [355] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_path_expression_repeat1, 2, 0, 0), SHIFT_REPEAT(171),
+  [358] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_select_expression, 5, 0, 43),
+  [360] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_expression, 5, 0, 43),
+  [362] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_apply_expression, 2, 0, 10),
+  [364] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_apply_expression, 2, 0, 10),
+  [366] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_attrpath_repeat1, 2, 0, 30), SHIFT_REPEAT(420),
+  [369] = {.entry = {.count = 1, .reusable = false}}, SHIFT(418),
+  [371] = {.entry = {.count = 1, .reusable = false}}, SHIFT(420),
+  [373] = {.entry = {.count = 1, .reusable = true}}, SHIFT(61),
+  [375] = {.entry = {.count = 1, .reusable = true}}, SHIFT(471),
+  [377] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_attrpath_repeat1, 2, 0, 30), SHIFT_REPEAT(418),
+  [380] = {.entry = {.count = 1, .reusable = true}}, SHIFT(69),
+  [382] = {.entry = {.count = 1, .reusable = true}}, SHIFT(469),
+  [384] = {.entry = {.count = 1, .reusable = false}}, SHIFT(419),
+  [386] = {.entry = {.count = 1, .reusable = true}}, SHIFT(44),
+  [388] = {.entry = {.count = 1, .reusable = true}}, SHIFT(468),
+  [390] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_attrpath_repeat1, 2, 0, 30), SHIFT_REPEAT(419),
+  [393] = {.entry = {.count = 1, .reusable = false}}, SHIFT(379),
+  [395] = {.entry = {.count = 1, .reusable = false}}, SHIFT(386),
+  [397] = {.entry = {.count = 1, .reusable = false}}, SHIFT(279),
+  [399] = {.entry = {.count = 1, .reusable = false}}, SHIFT(277),
+  [401] = {.entry = {.count = 1, .reusable = false}}, SHIFT(278),
+  [403] = {.entry = {.count = 1, .reusable = false}}, SHIFT(393),
+  [405] = {.entry = {.count = 1, .reusable = true}}, SHIFT(259),
+  [407] = {.entry = {.count = 1, .reusable = true}}, SHIFT(146),
+  [409] = {.entry = {.count = 1, .reusable = true}}, SHIFT(222),
+  [411] = {.entry = {.count = 1, .reusable = true}}, SHIFT(233),
+  [413] = {.entry = {.count = 1, .reusable = true}}, SHIFT(241),
+  [415] = {.entry = {.count = 1, .reusable = true}}, SHIFT(251),
+  [417] = {.entry = {.count = 1, .reusable = true}}, SHIFT(131),
+  [419] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_list_expression_repeat1, 2, 0, 23), SHIFT_REPEAT(88),
+  [422] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_list_expression_repeat1, 2, 0, 23), SHIFT_REPEAT(149),
+  [425] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_list_expression_repeat1, 2, 0, 23), SHIFT_REPEAT(149),
+  [428] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_list_expression_repeat1, 2, 0, 23), SHIFT_REPEAT(80),
+  [431] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_list_expression_repeat1, 2, 0, 23), SHIFT_REPEAT(357),
+  [434] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_list_expression_repeat1, 2, 0, 23), SHIFT_REPEAT(537),
+  [437] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_list_expression_repeat1, 2, 0, 23), SHIFT_REPEAT(18),
+  [440] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_list_expression_repeat1, 2, 0, 23), SHIFT_REPEAT(513),
+  [443] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_list_expression_repeat1, 2, 0, 23), SHIFT_REPEAT(382),
+  [446] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_list_expression_repeat1, 2, 0, 23), SHIFT_REPEAT(376),
+  [449] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_list_expression_repeat1, 2, 0, 23), SHIFT_REPEAT(273),
+  [452] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_list_expression_repeat1, 2, 0, 23),
+  [454] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_list_expression_repeat1, 2, 0, 23), SHIFT_REPEAT(88),
+  [457] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_list_expression_repeat1, 2, 0, 23), SHIFT_REPEAT(83),
+  [460] = {.entry = {.count = 1, .reusable = true}}, SHIFT(226),
+  [462] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_has_attr_expression, 3, 0, 26),
+  [464] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_has_attr_expression, 3, 0, 26),
+  [466] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unary_expression, 2, 0, 7),
+  [468] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unary_expression, 2, 0, 7),
+  [470] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_attrpath_repeat1, 2, 0, 30), SHIFT_REPEAT(417),
+  [473] = {.entry = {.count = 1, .reusable = true}}, SHIFT(417),
+  [475] = {.entry = {.count = 1, .reusable = true}}, SHIFT(413),
+  [477] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_attrpath_repeat1, 2, 0, 30), SHIFT_REPEAT(413),
+  [480] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binary_expression, 3, 0, 27),
+  [482] = {.entry = {.count = 1, .reusable = true}}, SHIFT(380),
+  [484] = {.entry = {.count = 1, .reusable = false}}, SHIFT(153),
+  [486] = {.entry = {.count = 1, .reusable = false}}, SHIFT(98),
+  [488] = {.entry = {.count = 1, .reusable = true}}, SHIFT(98),
+  [490] = {.entry = {.count = 1, .reusable = true}}, SHIFT(105),
+  [492] = {.entry = {.count = 1, .reusable = false}}, SHIFT(105),
+  [494] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
+  [496] = {.entry = {.count = 1, .reusable = true}}, SHIFT(118),
+  [498] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_binary_expression, 3, 0, 27),
+  [500] = {.entry = {.count = 1, .reusable = true}}, SHIFT(167),
+  [502] = {.entry = {.count = 1, .reusable = true}}, SHIFT(101),
+  [504] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
+  [506] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
+  [508] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__expr_if, 1, 0, 0),
+  [510] = {.entry = {.count = 1, .reusable = true}}, SHIFT(408),
+  [512] = {.entry = {.count = 1, .reusable = true}}, SHIFT(114),
+  [514] = {.entry = {.count = 1, .reusable = false}}, SHIFT(106),
+  [516] = {.entry = {.count = 1, .reusable = true}}, SHIFT(107),
+  [518] = {.entry = {.count = 1, .reusable = false}}, SHIFT(108),
+  [520] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
+  [522] = {.entry = {.count = 1, .reusable = true}}, SHIFT(111),
+  [524] = {.entry = {.count = 1, .reusable = false}}, SHIFT(111),
+  [526] = {.entry = {.count = 1, .reusable = true}}, SHIFT(113),
+  [528] = {.entry = {.count = 1, .reusable = false}}, SHIFT(154),
+  [530] = {.entry = {.count = 1, .reusable = false}}, SHIFT(97),
+  [532] = {.entry = {.count = 1, .reusable = true}}, SHIFT(97),
+  [534] = {.entry = {.count = 1, .reusable = true}}, SHIFT(159),
+  [536] = {.entry = {.count = 1, .reusable = false}}, SHIFT(159),
+  [538] = {.entry = {.count = 1, .reusable = true}}, SHIFT(161),
+  [540] = {.entry = {.count = 1, .reusable = true}}, SHIFT(162),
+  [542] = {.entry = {.count = 1, .reusable = false}}, SHIFT(280),
+  [544] = {.entry = {.count = 1, .reusable = true}}, SHIFT(188),
+  [546] = {.entry = {.count = 1, .reusable = true}}, SHIFT(550),
+  [548] = {.entry = {.count = 1, .reusable = true}}, SHIFT(375),
+  [550] = {.entry = {.count = 1, .reusable = false}}, SHIFT(368),
+  [552] = {.entry = {.count = 1, .reusable = true}}, SHIFT(6),
+  [554] = {.entry = {.count = 1, .reusable = true}}, SHIFT(366),
+  [556] = {.entry = {.count = 1, .reusable = true}}, SHIFT(109),
+  [558] = {.entry = {.count = 1, .reusable = true}}, SHIFT(155),
+  [560] = {.entry = {.count = 1, .reusable = true}}, SHIFT(138),
+  [562] = {.entry = {.count = 1, .reusable = false}}, SHIFT(138),
+  [564] = {.entry = {.count = 1, .reusable = true}}, SHIFT(141),
+  [566] = {.entry = {.count = 1, .reusable = false}}, SHIFT(133),
+  [568] = {.entry = {.count = 1, .reusable = false}}, SHIFT(135),
+  [570] = {.entry = {.count = 1, .reusable = true}}, SHIFT(135),
+  [572] = {.entry = {.count = 1, .reusable = true}}, SHIFT(140),
+  [574] = {.entry = {.count = 1, .reusable = true}}, SHIFT(134),
+  [576] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
+  [578] = {.entry = {.count = 1, .reusable = true}}, SHIFT(136),
+  [580] = {.entry = {.count = 1, .reusable = true}}, SHIFT(137),
+  [582] = {.entry = {.count = 1, .reusable = true}}, SHIFT(139),
+  [584] = {.entry = {.count = 1, .reusable = true}}, SHIFT(157),
+  [586] = {.entry = {.count = 1, .reusable = true}}, SHIFT(180),
+  [588] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
+  [590] = {.entry = {.count = 1, .reusable = true}}, SHIFT(112),
+  [592] = {.entry = {.count = 1, .reusable = true}}, SHIFT(175),
+  [594] = {.entry = {.count = 1, .reusable = true}}, SHIFT(158),
+  [596] = {.entry = {.count = 1, .reusable = true}}, SHIFT(160),
+  [598] = {.entry = {.count = 1, .reusable = true}}, SHIFT(356),
+  [600] = {.entry = {.count = 1, .reusable = false}}, SHIFT(46),
+  [602] = {.entry = {.count = 1, .reusable = false}}, SHIFT(367),
+  [604] = {.entry = {.count = 1, .reusable = true}}, SHIFT(374),
+  [606] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_list_expression_repeat1, 1, 0, 9),
+  [608] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_list_expression_repeat1, 1, 0, 9),
+  [610] = {.entry = {.count = 1, .reusable = true}}, SHIFT(358),
+  [612] = {.entry = {.count = 1, .reusable = false}}, SHIFT(54),
+  [614] = {.entry = {.count = 1, .reusable = true}}, SHIFT(351),
+  [616] = {.entry = {.count = 1, .reusable = false}}, SHIFT(60),
+  [618] = {.entry = {.count = 1, .reusable = true}}, SHIFT(352),
+  [620] = {.entry = {.count = 1, .reusable = false}}, SHIFT(68),
+  [622] = {.entry = {.count = 1, .reusable = true}}, SHIFT(231),
+  [624] = {.entry = {.count = 1, .reusable = true}}, SHIFT(377),
+  [626] = {.entry = {.count = 1, .reusable = true}}, SHIFT(245),
+  [628] = {.entry = {.count = 1, .reusable = true}}, SHIFT(217),
+  [630] = {.entry = {.count = 1, .reusable = true}}, SHIFT(218),
+  [632] = {.entry = {.count = 1, .reusable = true}}, SHIFT(248),
+  [634] = {.entry = {.count = 1, .reusable = true}}, SHIFT(246),
+  [636] = {.entry = {.count = 1, .reusable = true}}, SHIFT(130),
+  [638] = {.entry = {.count = 1, .reusable = true}}, SHIFT(132),
+  [640] = {.entry = {.count = 1, .reusable = true}}, SHIFT(228),
+  [642] = {.entry = {.count = 1, .reusable = true}}, SHIFT(144),
+  [644] = {.entry = {.count = 1, .reusable = true}}, SHIFT(229),
+  [646] = {.entry = {.count = 1, .reusable = true}}, SHIFT(223),
+  [648] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_binding_set, 1, 0, 6),
+  [650] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binding_set, 1, 0, 6),
+  [652] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_binding_set_repeat1, 2, 0, 16), SHIFT_REPEAT(280),
+  [655] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_binding_set_repeat1, 2, 0, 16),
+  [657] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_binding_set_repeat1, 2, 0, 16), SHIFT_REPEAT(375),
+  [660] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_binding_set_repeat1, 2, 0, 16), SHIFT_REPEAT(367),
+  [663] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_binding_set_repeat1, 2, 0, 16), SHIFT_REPEAT(6),
+  [666] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_binding_set_repeat1, 2, 0, 16), SHIFT_REPEAT(374),
+  [669] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_binding_set_repeat1, 2, 0, 16),
+  [671] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_binding_set_repeat1, 2, 0, 16), SHIFT_REPEAT(368),
+  [674] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_binding_set_repeat1, 2, 0, 16), SHIFT_REPEAT(377),
+  [677] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_binding_set_repeat1, 1, 0, 4),
+  [679] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_identifier, 1, 0, 0), REDUCE(aux_sym_binding_set_repeat1, 1, 0, 4),
+  [682] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_binding_set_repeat1, 1, 0, 4),
+  [684] = {.entry = {.count = 1, .reusable = true}}, SHIFT(280),
+  [686] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
+  [688] = {.entry = {.count = 1, .reusable = true}}, SHIFT(7),
+  [690] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_inherited_attrs, 1, 0, 12),
+  [692] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_inherited_attrs_repeat1, 2, 0, 30), SHIFT_REPEAT(280),
+  [695] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_inherited_attrs_repeat1, 2, 0, 30),
+  [697] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_inherited_attrs_repeat1, 2, 0, 30), SHIFT_REPEAT(375),
+  [700] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_inherited_attrs_repeat1, 2, 0, 30), SHIFT_REPEAT(6),
+  [703] = {.entry = {.count = 1, .reusable = false}}, SHIFT(145),
+  [705] = {.entry = {.count = 1, .reusable = true}}, SHIFT(433),
+  [707] = {.entry = {.count = 1, .reusable = true}}, SHIFT(27),
+  [709] = {.entry = {.count = 1, .reusable = true}}, SHIFT(519),
+  [711] = {.entry = {.count = 1, .reusable = true}}, SHIFT(283),
+  [713] = {.entry = {.count = 1, .reusable = true}}, SHIFT(435),
+  [715] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
+  [717] = {.entry = {.count = 1, .reusable = true}}, SHIFT(492),
+  [719] = {.entry = {.count = 1, .reusable = false}}, SHIFT(127),
+  [721] = {.entry = {.count = 1, .reusable = true}}, SHIFT(281),
+  [723] = {.entry = {.count = 1, .reusable = true}}, SHIFT(202),
+  [725] = {.entry = {.count = 1, .reusable = true}}, SHIFT(396),
+  [727] = {.entry = {.count = 1, .reusable = true}}, SHIFT(35),
+  [729] = {.entry = {.count = 1, .reusable = true}}, SHIFT(93),
+  [731] = {.entry = {.count = 1, .reusable = true}}, SHIFT(398),
+  [733] = {.entry = {.count = 1, .reusable = true}}, SHIFT(36),
+  [735] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
+  [737] = {.entry = {.count = 1, .reusable = false}}, SHIFT(221),
+  [739] = {.entry = {.count = 1, .reusable = true}}, SHIFT(119),
+  [741] = {.entry = {.count = 1, .reusable = false}}, SHIFT(232),
+  [743] = {.entry = {.count = 1, .reusable = true}}, SHIFT(199),
+  [745] = {.entry = {.count = 1, .reusable = true}}, SHIFT(402),
+  [747] = {.entry = {.count = 1, .reusable = true}}, SHIFT(37),
+  [749] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_string_expression_repeat1, 2, 0, 21),
+  [751] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_string_expression_repeat1, 2, 0, 21), SHIFT_REPEAT(435),
+  [754] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_string_expression_repeat1, 2, 0, 21), SHIFT_REPEAT(20),
+  [757] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_string_expression_repeat1, 2, 0, 21), SHIFT_REPEAT(492),
+  [760] = {.entry = {.count = 1, .reusable = true}}, SHIFT(239),
+  [762] = {.entry = {.count = 1, .reusable = false}}, SHIFT(258),
+  [764] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_indented_string_expression_repeat1, 2, 0, 21),
+  [766] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_indented_string_expression_repeat1, 2, 0, 21), SHIFT_REPEAT(433),
+  [769] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_indented_string_expression_repeat1, 2, 0, 21), SHIFT_REPEAT(27),
+  [772] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_indented_string_expression_repeat1, 2, 0, 21), SHIFT_REPEAT(519),
+  [775] = {.entry = {.count = 1, .reusable = true}}, SHIFT(249),
+  [777] = {.entry = {.count = 1, .reusable = false}}, SHIFT(250),
+  [779] = {.entry = {.count = 1, .reusable = true}}, SHIFT(204),
+  [781] = {.entry = {.count = 1, .reusable = true}}, SHIFT(404),
+  [783] = {.entry = {.count = 1, .reusable = true}}, SHIFT(38),
+  [785] = {.entry = {.count = 1, .reusable = true}}, SHIFT(300),
+  [787] = {.entry = {.count = 1, .reusable = true}}, SHIFT(301),
+  [789] = {.entry = {.count = 1, .reusable = true}}, SHIFT(205),
+  [791] = {.entry = {.count = 1, .reusable = true}}, SHIFT(209),
+  [793] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
+  [795] = {.entry = {.count = 1, .reusable = true}}, SHIFT(96),
+  [797] = {.entry = {.count = 1, .reusable = true}}, SHIFT(256),
+  [799] = {.entry = {.count = 1, .reusable = true}}, SHIFT(257),
+  [801] = {.entry = {.count = 1, .reusable = true}}, SHIFT(200),
+  [803] = {.entry = {.count = 1, .reusable = true}}, SHIFT(201),
+  [805] = {.entry = {.count = 1, .reusable = true}}, SHIFT(207),
+  [807] = {.entry = {.count = 1, .reusable = true}}, SHIFT(208),
+  [809] = {.entry = {.count = 1, .reusable = true}}, SHIFT(224),
+  [811] = {.entry = {.count = 1, .reusable = false}}, SHIFT(225),
+  [813] = {.entry = {.count = 1, .reusable = true}}, SHIFT(293),
+  [815] = {.entry = {.count = 1, .reusable = true}}, SHIFT(394),
+  [817] = {.entry = {.count = 1, .reusable = true}}, SHIFT(33),
+  [819] = {.entry = {.count = 1, .reusable = true}}, SHIFT(213),
+  [821] = {.entry = {.count = 1, .reusable = false}}, SHIFT(240),
+  [823] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_expression, 5, 0, 41),
+  [825] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_expression, 3, 0, 24),
+  [827] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_assert_expression, 4, 0, 35),
+  [829] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_with_expression, 4, 0, 36),
+  [831] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__expression, 1, 0, 0),
+  [833] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_expression, 3, 0, 25),
+  [835] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_expression, 6, 0, 45),
+  [837] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_expression, 5, 0, 42),
+  [839] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_let_expression, 3, 0, 17),
+  [841] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_let_expression, 4, 0, 38),
+  [843] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_inherit_from, 6, 0, 46),
+  [845] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_inherit_from, 6, 0, 46),
+  [847] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_formal, 1, 0, 2),
+  [849] = {.entry = {.count = 1, .reusable = true}}, SHIFT(8),
+  [851] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_indented_string_expression_repeat1, 2, 0, 19),
+  [853] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_indented_string_expression_repeat1, 2, 0, 19),
+  [855] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_indented_string_expression_repeat1, 1, 0, 8),
+  [857] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_indented_string_expression_repeat1, 1, 0, 8),
+  [859] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_binding, 4, 0, 40),
+  [861] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binding, 4, 0, 40),
+  [863] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_string_expression_repeat1, 1, 0, 8),
+  [865] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_inherit, 3, 0, 29),
+  [867] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_inherit, 3, 0, 29),
+  [869] = {.entry = {.count = 1, .reusable = true}}, SHIFT(483),
+  [871] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_string_expression_repeat1, 2, 0, 19),
+  [873] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_inherited_attrs_repeat1, 1, 0, 5),
+  [875] = {.entry = {.count = 1, .reusable = true}}, SHIFT(515),
+  [877] = {.entry = {.count = 1, .reusable = true}}, SHIFT(516),
+  [879] = {.entry = {.count = 1, .reusable = true}}, SHIFT(464),
+  [881] = {.entry = {.count = 1, .reusable = true}}, SHIFT(444),
+  [883] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_formals_repeat1, 2, 0, 34),
+  [885] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_formals_repeat1, 2, 0, 34), SHIFT_REPEAT(446),
+  [888] = {.entry = {.count = 1, .reusable = true}}, SHIFT(457),
+  [890] = {.entry = {.count = 1, .reusable = true}}, SHIFT(445),
+  [892] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_formals, 3, 0, 14),
+  [894] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
+  [896] = {.entry = {.count = 1, .reusable = true}}, SHIFT(455),
+  [898] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
+  [900] = {.entry = {.count = 1, .reusable = true}}, SHIFT(451),
+  [902] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_formal, 3, 0, 31),
+  [904] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_formals, 6, 0, 44),
+  [906] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_formals_repeat1, 2, 0, 14),
+  [908] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_formals, 5, 0, 39),
+  [910] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_formals, 4, 0, 33),
+  [912] = {.entry = {.count = 1, .reusable = true}}, SHIFT(438),
+  [914] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_formals, 3, 0, 11),
+  [916] = {.entry = {.count = 1, .reusable = true}}, SHIFT(55),
+  [918] = {.entry = {.count = 1, .reusable = true}}, SHIFT(454),
+  [920] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
+  [922] = {.entry = {.count = 1, .reusable = true}}, SHIFT(453),
+  [924] = {.entry = {.count = 1, .reusable = true}}, SHIFT(52),
+  [926] = {.entry = {.count = 1, .reusable = true}}, SHIFT(242),
+  [928] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
+  [930] = {.entry = {.count = 1, .reusable = true}}, SHIFT(192),
+  [932] = {.entry = {.count = 1, .reusable = true}}, SHIFT(143),
+  [934] = {.entry = {.count = 1, .reusable = true}}, SHIFT(247),
+  [936] = {.entry = {.count = 1, .reusable = true}}, SHIFT(230),
+  [938] = {.entry = {.count = 1, .reusable = true}}, SHIFT(9),
+  [940] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_code, 1, 0, 1),
+  [942] = {.entry = {.count = 1, .reusable = true}}, SHIFT(73),
+  [944] = {.entry = {.count = 1, .reusable = true}}, SHIFT(184),
+  [946] = {.entry = {.count = 1, .reusable = true}}, SHIFT(298),
+  [948] = {.entry = {.count = 1, .reusable = true}}, SHIFT(10),
+  [950] = {.entry = {.count = 1, .reusable = true}}, SHIFT(253),
+  [952] = {.entry = {.count = 1, .reusable = true}}, SHIFT(254),
+  [954] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
+  [956] = {.entry = {.count = 1, .reusable = true}}, SHIFT(67),
+  [958] = {.entry = {.count = 1, .reusable = true}}, SHIFT(15),
+  [960] = {.entry = {.count = 1, .reusable = true}}, SHIFT(441),
+  [962] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
+  [964] = {.entry = {.count = 1, .reusable = true}}, SHIFT(210),
+  [966] = {.entry = {.count = 1, .reusable = true}}, SHIFT(436),
+  [968] = {.entry = {.count = 1, .reusable = true}}, SHIFT(429),
+  [970] = {.entry = {.count = 1, .reusable = true}}, SHIFT(195),
+  [972] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
+  [974] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
+  [976] = {.entry = {.count = 1, .reusable = true}}, SHIFT(150),
+  [978] = {.entry = {.count = 1, .reusable = true}}, SHIFT(151),
+  [980] = {.entry = {.count = 1, .reusable = true}}, SHIFT(212),
+  [982] = {.entry = {.count = 1, .reusable = true}}, SHIFT(53),
+  [984] = {.entry = {.count = 1, .reusable = true}}, SHIFT(198),
+  [986] = {.entry = {.count = 1, .reusable = true}}, SHIFT(282),
+  [988] = {.entry = {.count = 1, .reusable = true}}, SHIFT(437),
+  [990] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
+  [992] = {.entry = {.count = 1, .reusable = true}}, SHIFT(442),
+  [994] = {.entry = {.count = 1, .reusable = true}}, SHIFT(74),
+  [996] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
+  [998] = {.entry = {.count = 1, .reusable = true}}, SHIFT(440),
+  [1000] = {.entry = {.count = 1, .reusable = true}}, SHIFT(359),
+  [1002] = {.entry = {.count = 1, .reusable = true}}, SHIFT(121),
+  [1004] = {.entry = {.count = 1, .reusable = true}}, SHIFT(461),
+  [1006] = {.entry = {.count = 1, .reusable = true}}, SHIFT(463),
+  [1008] = {.entry = {.count = 1, .reusable = true}}, SHIFT(236),
+  [1010] = {.entry = {.count = 1, .reusable = true}}, SHIFT(58),
+  [1012] = {.entry = {.count = 1, .reusable = true}}, SHIFT(432),
+  [1014] = {.entry = {.count = 1, .reusable = true}}, SHIFT(59),
+  [1016] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
+  [1018] = {.entry = {.count = 1, .reusable = true}}, SHIFT(49),
+  [1020] = {.entry = {.count = 1, .reusable = true}}, SHIFT(434),
+  [1022] = {.entry = {.count = 1, .reusable = true}}, SHIFT(350),
+  [1024] = {.entry = {.count = 1, .reusable = true}}, SHIFT(369),
+  [1026] = {.entry = {.count = 1, .reusable = true}}, SHIFT(50),
+  [1028] = {.entry = {.count = 1, .reusable = true}}, SHIFT(220),
+  [1030] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
+  [1032] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
+  [1034] = {.entry = {.count = 1, .reusable = true}}, SHIFT(227),
+  [1036] = {.entry = {.count = 1, .reusable = true}}, SHIFT(439),
+  [1038] = {.entry = {.count = 1, .reusable = true}}, SHIFT(56),
+  [1040] = {.entry = {.count = 1, .reusable = true}}, SHIFT(354),
+  [1042] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
+  [1044] = {.entry = {.count = 1, .reusable = true}}, SHIFT(215),
+  [1046] = {.entry = {.count = 1, .reusable = true}}, SHIFT(244),
+  [1048] = {.entry = {.count = 1, .reusable = true}}, SHIFT(361),
+  [1050] = {.entry = {.count = 1, .reusable = true}}, SHIFT(75),
+  [1052] = {.entry = {.count = 1, .reusable = true}}, SHIFT(243),
+  [1054] = {.entry = {.count = 1, .reusable = true}}, SHIFT(45),
+  [1056] = {.entry = {.count = 1, .reusable = true}}, SHIFT(63),
+  [1058] = {.entry = {.count = 1, .reusable = true}}, SHIFT(21),
+  [1060] = {.entry = {.count = 1, .reusable = true}}, SHIFT(43),
+  [1062] = {.entry = {.count = 1, .reusable = true}}, SHIFT(370),
+  [1064] = {.entry = {.count = 1, .reusable = true}}, SHIFT(28),
+  [1066] = {.entry = {.count = 1, .reusable = true}}, SHIFT(34),
+  [1068] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [1070] = {.entry = {.count = 1, .reusable = true}}, SHIFT(466),
+  [1072] = {.entry = {.count = 1, .reusable = true}}, SHIFT(428),
+  [1074] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
+  [1076] = {.entry = {.count = 1, .reusable = true}}, SHIFT(235),
+};
+
+enum ts_external_scanner_symbol_identifiers {
+  ts_external_token_string_fragment = 0,
+  ts_external_token__indented_string_fragment = 1,
+  ts_external_token__path_start = 2,
+  ts_external_token_path_fragment = 3,
+  ts_external_token_dollar_escape = 4,
+  ts_external_token__indented_dollar_escape = 5,
+};
+
+static const TSSymbol ts_external_scanner_symbol_map[EXTERNAL_TOKEN_COUNT] = {
+  [ts_external_token_string_fragment] = sym_string_fragment,
+  [ts_external_token__indented_string_fragment] = sym__indented_string_fragment,
+  [ts_external_token__path_start] = sym__path_start,
+  [ts_external_token_path_fragment] = sym_path_fragment,
+  [ts_external_token_dollar_escape] = sym_dollar_escape,
+  [ts_external_token__indented_dollar_escape] = sym__indented_dollar_escape,
+};
+
+static const bool ts_external_scanner_states[6][EXTERNAL_TOKEN_COUNT] = {
+  [1] = {
+    [ts_external_token_string_fragment] = true,
+    [ts_external_token__indented_string_fragment] = true,
+    [ts_external_token__path_start] = true,
+    [ts_external_token_path_fragment] = true,
+    [ts_external_token_dollar_escape] = true,
+    [ts_external_token__indented_dollar_escape] = true,
+  },
+  [2] = {
+    [ts_external_token__path_start] = true,
+  },
+  [3] = {
+    [ts_external_token__path_start] = true,
+    [ts_external_token_path_fragment] = true,
+  },
+  [4] = {
+    [ts_external_token__indented_string_fragment] = true,
+    [ts_external_token__indented_dollar_escape] = true,
+  },
+  [5] = {
+    [ts_external_token_string_fragment] = true,
+    [ts_external_token_dollar_escape] = true,
+  },
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+void *tree_sitter_nix_external_scanner_create(void);
+void tree_sitter_nix_external_scanner_destroy(void *);
+bool tree_sitter_nix_external_scanner_scan(void *, TSLexer *, const bool *);
+unsigned tree_sitter_nix_external_scanner_serialize(void *, char *);
+void tree_sitter_nix_external_scanner_deserialize(void *, const char *, unsigned);
+
+#ifdef TREE_SITTER_HIDE_SYMBOLS
+#define TS_PUBLIC
+#elif defined(_WIN32)
+#define TS_PUBLIC __declspec(dllexport)
+#else
+#define TS_PUBLIC __attribute__((visibility("default")))
+#endif
+
+TS_PUBLIC const TSLanguage *tree_sitter_nix(void) {
+  static const TSLanguage language = {
+    .version = LANGUAGE_VERSION,
+    .symbol_count = SYMBOL_COUNT,
+    .alias_count = ALIAS_COUNT,
+    .token_count = TOKEN_COUNT,
+    .external_token_count = EXTERNAL_TOKEN_COUNT,
+    .state_count = STATE_COUNT,
+    .large_state_count = LARGE_STATE_COUNT,
+    .production_id_count = PRODUCTION_ID_COUNT,
+    .field_count = FIELD_COUNT,
+    .max_alias_sequence_length = MAX_ALIAS_SEQUENCE_LENGTH,
+    .parse_table = &ts_parse_table[0][0],
+    .small_parse_table = ts_small_parse_table,
+    .small_parse_table_map = ts_small_parse_table_map,
+    .parse_actions = ts_parse_actions,
+    .symbol_names = ts_symbol_names,
+    .field_names = ts_field_names,
+    .field_map_slices = ts_field_map_slices,
+    .field_map_entries = ts_field_map_entries,
+    .symbol_metadata = ts_symbol_metadata,
+    .public_symbol_map = ts_symbol_map,
+    .alias_map = ts_non_terminal_alias_map,
+    .alias_sequences = &ts_alias_sequences[0][0],
+    .lex_modes = ts_lex_modes,
+    .lex_fn = ts_lex,
+    .keyword_lex_fn = ts_lex_keywords,
+    .keyword_capture_token = sym_keyword,
+    .external_scanner = {
+      &ts_external_scanner_states[0][0],
+      ts_external_scanner_symbol_map,
+      tree_sitter_nix_external_scanner_create,
+      tree_sitter_nix_external_scanner_destroy,
+      tree_sitter_nix_external_scanner_scan,
+      tree_sitter_nix_external_scanner_serialize,
+      tree_sitter_nix_external_scanner_deserialize,
+    },
+    .primary_state_ids = ts_primary_state_ids,
+  };
+  return &language;
+}
+#ifdef __cplusplus
+}
+#endif
@@ -0,0 +1,49 @@
+[
+  "syntax"
+  "edition"
+  "package"
+  "option"
+  "import"
+  "service"
+  "rpc"
+  "returns"
+  "message"
+  "enum"
+  "oneof"
+  "repeated"
+  "reserved"
+  "to"
+] @keyword
+
+[
+  (key_type)
+  (type)
+  (message_name)
+  (enum_name)
+  (service_name)
+  (rpc_name)
+]@type
+
+(string) @string
+
+[
+  (int_lit)
+  (float_lit)
+] @number
+
+[
+  (true)
+  (false)
+] @constant.builtin
+
+(comment) @comment
+
+[
+  "("
+  ")"
+  "["
+  "]"
+  "{"
+  "}"
+]  @punctuation.bracket
+
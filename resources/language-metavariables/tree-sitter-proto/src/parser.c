@@ -0,0 +1,10185 @@
+#include "tree_sitter/parser.h"
+
+#if defined(__GNUC__) || defined(__clang__)
+#pragma GCC diagnostic ignored "-Wmissing-field-initializers"
+#endif
+
+#ifdef _MSC_VER
+#pragma optimize("", off)
+#elif defined(__clang__)
+#pragma clang optimize off
+#elif defined(__GNUC__)
+#pragma GCC optimize ("O0")
+#endif
+
+#define LANGUAGE_VERSION 14
+#define STATE_COUNT 330
+#define LARGE_STATE_COUNT 2
+#define SYMBOL_COUNT 134
+#define ALIAS_COUNT 0
+#define TOKEN_COUNT 71
+#define EXTERNAL_TOKEN_COUNT 0
+#define FIELD_COUNT 24
+#define MAX_ALIAS_SEQUENCE_LENGTH 14
+#define PRODUCTION_ID_COUNT 47
+
+enum ts_symbol_identifiers {
+  anon_sym_SEMI = 1,
+  anon_sym_edition = 2,
+  anon_sym_EQ = 3,
+  anon_sym_syntax = 4,
+  anon_sym_DQUOTEproto3_DQUOTE = 5,
+  anon_sym_DQUOTEproto2_DQUOTE = 6,
+  anon_sym_import = 7,
+  anon_sym_weak = 8,
+  anon_sym_public = 9,
+  anon_sym_package = 10,
+  anon_sym_option = 11,
+  anon_sym_LPAREN = 12,
+  anon_sym_RPAREN = 13,
+  anon_sym_DOT = 14,
+  anon_sym_enum = 15,
+  anon_sym_LBRACE = 16,
+  anon_sym_RBRACE = 17,
+  anon_sym_LBRACK = 18,
+  anon_sym_COMMA = 19,
+  anon_sym_RBRACK = 20,
+  anon_sym_DASH = 21,
+  anon_sym_message = 22,
+  anon_sym_extend = 23,
+  anon_sym_optional = 24,
+  anon_sym_required = 25,
+  anon_sym_repeated = 26,
+  anon_sym_oneof = 27,
+  anon_sym_map = 28,
+  anon_sym_LT = 29,
+  anon_sym_GT = 30,
+  anon_sym_int32 = 31,
+  anon_sym_int64 = 32,
+  anon_sym_uint32 = 33,
+  anon_sym_uint64 = 34,
+  anon_sym_sint32 = 35,
+  anon_sym_sint64 = 36,
+  anon_sym_fixed32 = 37,
+  anon_sym_fixed64 = 38,
+  anon_sym_sfixed32 = 39,
+  anon_sym_sfixed64 = 40,
+  anon_sym_bool = 41,
+  anon_sym_string = 42,
+  anon_sym_double = 43,
+  anon_sym_float = 44,
+  anon_sym_bytes = 45,
+  anon_sym_reserved = 46,
+  anon_sym_extensions = 47,
+  anon_sym_to = 48,
+  sym_max = 49,
+  anon_sym_service = 50,
+  anon_sym_rpc = 51,
+  anon_sym_returns = 52,
+  sym_stream = 53,
+  anon_sym_PLUS = 54,
+  anon_sym_COLON = 55,
+  sym__identifier = 56,
+  sym_reserved_identifier = 57,
+  sym_true = 58,
+  sym_false = 59,
+  sym_decimal_lit = 60,
+  sym_octal_lit = 61,
+  sym_hex_lit = 62,
+  sym_float_lit = 63,
+  anon_sym_DQUOTE = 64,
+  aux_sym_string_token1 = 65,
+  anon_sym_SQUOTE = 66,
+  aux_sym_string_token2 = 67,
+  sym_escape_sequence = 68,
+  sym_grit_metavariable = 69,
+  sym_comment = 70,
+  sym_source_file = 71,
+  sym_empty_statement = 72,
+  sym_edition = 73,
+  sym_syntax = 74,
+  sym_import = 75,
+  sym_import_modifier = 76,
+  sym_package = 77,
+  sym_option = 78,
+  sym_option_name = 79,
+  sym_enum = 80,
+  sym_enum_name = 81,
+  sym_enum_body = 82,
+  sym_enum_field = 83,
+  sym_signed_int_lit = 84,
+  sym_enum_value_option = 85,
+  sym_message = 86,
+  sym_message_body = 87,
+  sym_message_name = 88,
+  sym_extend = 89,
+  sym_field = 90,
+  sym_field_label = 91,
+  sym_field_options = 92,
+  sym_field_option = 93,
+  sym_oneof = 94,
+  sym_oneof_field = 95,
+  sym_map_field = 96,
+  sym_key_type = 97,
+  sym_type = 98,
+  sym_reserved = 99,
+  sym_extensions = 100,
+  sym_ranges = 101,
+  sym_range = 102,
+  sym_reserved_field_names = 103,
+  sym_message_or_enum_type = 104,
+  sym_field_number = 105,
+  sym_service = 106,
+  sym_service_name = 107,
+  sym_rpc = 108,
+  sym_rpc_name = 109,
+  sym_constant = 110,
+  sym_block_lit = 111,
+  sym_identifier = 112,
+  sym_full_ident = 113,
+  sym_bool = 114,
+  sym_int_lit = 115,
+  sym_string = 116,
+  aux_sym_source_file_repeat1 = 117,
+  aux_sym_option_name_repeat1 = 118,
+  aux_sym_enum_body_repeat1 = 119,
+  aux_sym_enum_field_repeat1 = 120,
+  aux_sym_message_body_repeat1 = 121,
+  aux_sym_field_options_repeat1 = 122,
+  aux_sym_oneof_repeat1 = 123,
+  aux_sym_ranges_repeat1 = 124,
+  aux_sym_reserved_field_names_repeat1 = 125,
+  aux_sym_message_or_enum_type_repeat1 = 126,
+  aux_sym_service_repeat1 = 127,
+  aux_sym_rpc_repeat1 = 128,
+  aux_sym_block_lit_repeat1 = 129,
+  aux_sym_block_lit_repeat2 = 130,
+  aux_sym_string_repeat1 = 131,
+  aux_sym_string_repeat2 = 132,
+  aux_sym_string_repeat3 = 133,
+};
+
+static const char * const ts_symbol_names[] = {
+  [ts_builtin_sym_end] = "end",
+  [anon_sym_SEMI] = ";",
+  [anon_sym_edition] = "edition",
+  [anon_sym_EQ] = "=",
+  [anon_sym_syntax] = "syntax",
+  [anon_sym_DQUOTEproto3_DQUOTE] = "\"proto3\"",
+  [anon_sym_DQUOTEproto2_DQUOTE] = "\"proto2\"",
+  [anon_sym_import] = "import",
+  [anon_sym_weak] = "weak",
+  [anon_sym_public] = "public",
+  [anon_sym_package] = "package",
+  [anon_sym_option] = "option",
+  [anon_sym_LPAREN] = "(",
+  [anon_sym_RPAREN] = ")",
+  [anon_sym_DOT] = ".",
+  [anon_sym_enum] = "enum",
+  [anon_sym_LBRACE] = "{",
+  [anon_sym_RBRACE] = "}",
+  [anon_sym_LBRACK] = "[",
+  [anon_sym_COMMA] = ",",
+  [anon_sym_RBRACK] = "]",
+  [anon_sym_DASH] = "-",
+  [anon_sym_message] = "message",
+  [anon_sym_extend] = "extend",
+  [anon_sym_optional] = "optional",
+  [anon_sym_required] = "required",
+  [anon_sym_repeated] = "repeated",
+  [anon_sym_oneof] = "oneof",
+  [anon_sym_map] = "map",
+  [anon_sym_LT] = "<",
+  [anon_sym_GT] = ">",
+  [anon_sym_int32] = "int32",
+  [anon_sym_int64] = "int64",
+  [anon_sym_uint32] = "uint32",
+  [anon_sym_uint64] = "uint64",
+  [anon_sym_sint32] = "sint32",
+  [anon_sym_sint64] = "sint64",
+  [anon_sym_fixed32] = "fixed32",
+  [anon_sym_fixed64] = "fixed64",
+  [anon_sym_sfixed32] = "sfixed32",
+  [anon_sym_sfixed64] = "sfixed64",
+  [anon_sym_bool] = "bool",
+  [anon_sym_string] = "string",
+  [anon_sym_double] = "double",
+  [anon_sym_float] = "float",
+  [anon_sym_bytes] = "bytes",
+  [anon_sym_reserved] = "reserved",
+  [anon_sym_extensions] = "extensions",
+  [anon_sym_to] = "to",
+  [sym_max] = "max",
+  [anon_sym_service] = "service",
+  [anon_sym_rpc] = "rpc",
+  [anon_sym_returns] = "returns",
+  [sym_stream] = "stream",
+  [anon_sym_PLUS] = "+",
+  [anon_sym_COLON] = ":",
+  [sym__identifier] = "_identifier",
+  [sym_reserved_identifier] = "reserved_identifier",
+  [sym_true] = "true",
+  [sym_false] = "false",
+  [sym_decimal_lit] = "decimal_lit",
+  [sym_octal_lit] = "octal_lit",
+  [sym_hex_lit] = "hex_lit",
+  [sym_float_lit] = "float_lit",
+  [anon_sym_DQUOTE] = "\"",
+  [aux_sym_string_token1] = "string_token1",
+  [anon_sym_SQUOTE] = "'",
+  [aux_sym_string_token2] = "string_token2",
+  [sym_escape_sequence] = "escape_sequence",
+  [sym_grit_metavariable] = "grit_metavariable",
+  [sym_comment] = "comment",
+  [sym_source_file] = "source_file",
+  [sym_empty_statement] = "empty_statement",
+  [sym_edition] = "edition",
+  [sym_syntax] = "syntax",
+  [sym_import] = "import",
+  [sym_import_modifier] = "import_modifier",
+  [sym_package] = "package",
+  [sym_option] = "option",
+  [sym_option_name] = "option_name",
+  [sym_enum] = "enum",
+  [sym_enum_name] = "enum_name",
+  [sym_enum_body] = "enum_body",
+  [sym_enum_field] = "enum_field",
+  [sym_signed_int_lit] = "signed_int_lit",
+  [sym_enum_value_option] = "enum_value_option",
+  [sym_message] = "message",
+  [sym_message_body] = "message_body",
+  [sym_message_name] = "message_name",
+  [sym_extend] = "extend",
+  [sym_field] = "field",
+  [sym_field_label] = "field_label",
+  [sym_field_options] = "field_options",
+  [sym_field_option] = "field_option",
+  [sym_oneof] = "oneof",
+  [sym_oneof_field] = "oneof_field",
+  [sym_map_field] = "map_field",
+  [sym_key_type] = "key_type",
+  [sym_type] = "type",
+  [sym_reserved] = "reserved",
+  [sym_extensions] = "extensions",
+  [sym_ranges] = "ranges",
+  [sym_range] = "range",
+  [sym_reserved_field_names] = "reserved_field_names",
+  [sym_message_or_enum_type] = "message_or_enum_type",
+  [sym_field_number] = "field_number",
+  [sym_service] = "service",
+  [sym_service_name] = "service_name",
+  [sym_rpc] = "rpc",
+  [sym_rpc_name] = "rpc_name",
+  [sym_constant] = "constant",
+  [sym_block_lit] = "block_lit",
+  [sym_identifier] = "identifier",
+  [sym_full_ident] = "full_ident",
+  [sym_bool] = "bool",
+  [sym_int_lit] = "int_lit",
+  [sym_string] = "string",
+  [aux_sym_source_file_repeat1] = "source_file_repeat1",
+  [aux_sym_option_name_repeat1] = "option_name_repeat1",
+  [aux_sym_enum_body_repeat1] = "enum_body_repeat1",
+  [aux_sym_enum_field_repeat1] = "enum_field_repeat1",
+  [aux_sym_message_body_repeat1] = "message_body_repeat1",
+  [aux_sym_field_options_repeat1] = "field_options_repeat1",
+  [aux_sym_oneof_repeat1] = "oneof_repeat1",
+  [aux_sym_ranges_repeat1] = "ranges_repeat1",
+  [aux_sym_reserved_field_names_repeat1] = "reserved_field_names_repeat1",
+  [aux_sym_message_or_enum_type_repeat1] = "message_or_enum_type_repeat1",
+  [aux_sym_service_repeat1] = "service_repeat1",
+  [aux_sym_rpc_repeat1] = "rpc_repeat1",
+  [aux_sym_block_lit_repeat1] = "block_lit_repeat1",
+  [aux_sym_block_lit_repeat2] = "block_lit_repeat2",
+  [aux_sym_string_repeat1] = "string_repeat1",
+  [aux_sym_string_repeat2] = "string_repeat2",
+  [aux_sym_string_repeat3] = "string_repeat3",
+};
+
+static const TSSymbol ts_symbol_map[] = {
+  [ts_builtin_sym_end] = ts_builtin_sym_end,
+  [anon_sym_SEMI] = anon_sym_SEMI,
+  [anon_sym_edition] = anon_sym_edition,
+  [anon_sym_EQ] = anon_sym_EQ,
+  [anon_sym_syntax] = anon_sym_syntax,
+  [anon_sym_DQUOTEproto3_DQUOTE] = anon_sym_DQUOTEproto3_DQUOTE,
+  [anon_sym_DQUOTEproto2_DQUOTE] = anon_sym_DQUOTEproto2_DQUOTE,
+  [anon_sym_import] = anon_sym_import,
+  [anon_sym_weak] = anon_sym_weak,
+  [anon_sym_public] = anon_sym_public,
+  [anon_sym_package] = anon_sym_package,
+  [anon_sym_option] = anon_sym_option,
+  [anon_sym_LPAREN] = anon_sym_LPAREN,
+  [anon_sym_RPAREN] = anon_sym_RPAREN,
+  [anon_sym_DOT] = anon_sym_DOT,
+  [anon_sym_enum] = anon_sym_enum,
+  [anon_sym_LBRACE] = anon_sym_LBRACE,
+  [anon_sym_RBRACE] = anon_sym_RBRACE,
+  [anon_sym_LBRACK] = anon_sym_LBRACK,
+  [anon_sym_COMMA] = anon_sym_COMMA,
+  [anon_sym_RBRACK] = anon_sym_RBRACK,
+  [anon_sym_DASH] = anon_sym_DASH,
+  [anon_sym_message] = anon_sym_message,
+  [anon_sym_extend] = anon_sym_extend,
+  [anon_sym_optional] = anon_sym_optional,
+  [anon_sym_required] = anon_sym_required,
+  [anon_sym_repeated] = anon_sym_repeated,
+  [anon_sym_oneof] = anon_sym_oneof,
+  [anon_sym_map] = anon_sym_map,
+  [anon_sym_LT] = anon_sym_LT,
+  [anon_sym_GT] = anon_sym_GT,
+  [anon_sym_int32] = anon_sym_int32,
+  [anon_sym_int64] = anon_sym_int64,
+  [anon_sym_uint32] = anon_sym_uint32,
+  [anon_sym_uint64] = anon_sym_uint64,
+  [anon_sym_sint32] = anon_sym_sint32,
+  [anon_sym_sint64] = anon_sym_sint64,
+  [anon_sym_fixed32] = anon_sym_fixed32,
+  [anon_sym_fixed64] = anon_sym_fixed64,
+  [anon_sym_sfixed32] = anon_sym_sfixed32,
+  [anon_sym_sfixed64] = anon_sym_sfixed64,
+  [anon_sym_bool] = anon_sym_bool,
+  [anon_sym_string] = anon_sym_string,
+  [anon_sym_double] = anon_sym_double,
+  [anon_sym_float] = anon_sym_float,
+  [anon_sym_bytes] = anon_sym_bytes,
+  [anon_sym_reserved] = anon_sym_reserved,
+  [anon_sym_extensions] = anon_sym_extensions,
+  [anon_sym_to] = anon_sym_to,
+  [sym_max] = sym_max,
+  [anon_sym_service] = anon_sym_service,
+  [anon_sym_rpc] = anon_sym_rpc,
+  [anon_sym_returns] = anon_sym_returns,
+  [sym_stream] = sym_stream,
+  [anon_sym_PLUS] = anon_sym_PLUS,
+  [anon_sym_COLON] = anon_sym_COLON,
+  [sym__identifier] = sym__identifier,
+  [sym_reserved_identifier] = sym_reserved_identifier,
+  [sym_true] = sym_true,
+  [sym_false] = sym_false,
+  [sym_decimal_lit] = sym_decimal_lit,
+  [sym_octal_lit] = sym_octal_lit,
+  [sym_hex_lit] = sym_hex_lit,
+  [sym_float_lit] = sym_float_lit,
+  [anon_sym_DQUOTE] = anon_sym_DQUOTE,
+  [aux_sym_string_token1] = aux_sym_string_token1,
+  [anon_sym_SQUOTE] = anon_sym_SQUOTE,
+  [aux_sym_string_token2] = aux_sym_string_token2,
+  [sym_escape_sequence] = sym_escape_sequence,
+  [sym_grit_metavariable] = sym_grit_metavariable,
+  [sym_comment] = sym_comment,
+  [sym_source_file] = sym_source_file,
+  [sym_empty_statement] = sym_empty_statement,
+  [sym_edition] = sym_edition,
+  [sym_syntax] = sym_syntax,
+  [sym_import] = sym_import,
+  [sym_import_modifier] = sym_import_modifier,
+  [sym_package] = sym_package,
+  [sym_option] = sym_option,
+  [sym_option_name] = sym_option_name,
+  [sym_enum] = sym_enum,
+  [sym_enum_name] = sym_enum_name,
+  [sym_enum_body] = sym_enum_body,
+  [sym_enum_field] = sym_enum_field,
+  [sym_signed_int_lit] = sym_signed_int_lit,
+  [sym_enum_value_option] = sym_enum_value_option,
+  [sym_message] = sym_message,
+  [sym_message_body] = sym_message_body,
+  [sym_message_name] = sym_message_name,
+  [sym_extend] = sym_extend,
+  [sym_field] = sym_field,
+  [sym_field_label] = sym_field_label,
+  [sym_field_options] = sym_field_options,
+  [sym_field_option] = sym_field_option,
+  [sym_oneof] = sym_oneof,
+  [sym_oneof_field] = sym_oneof_field,
+  [sym_map_field] = sym_map_field,
+  [sym_key_type] = sym_key_type,
+  [sym_type] = sym_type,
+  [sym_reserved] = sym_reserved,
+  [sym_extensions] = sym_extensions,
+  [sym_ranges] = sym_ranges,
+  [sym_range] = sym_range,
+  [sym_reserved_field_names] = sym_reserved_field_names,
+  [sym_message_or_enum_type] = sym_message_or_enum_type,
+  [sym_field_number] = sym_field_number,
+  [sym_service] = sym_service,
+  [sym_service_name] = sym_service_name,
+  [sym_rpc] = sym_rpc,
+  [sym_rpc_name] = sym_rpc_name,
+  [sym_constant] = sym_constant,
+  [sym_block_lit] = sym_block_lit,
+  [sym_identifier] = sym_identifier,
+  [sym_full_ident] = sym_full_ident,
+  [sym_bool] = sym_bool,
+  [sym_int_lit] = sym_int_lit,
+  [sym_string] = sym_string,
+  [aux_sym_source_file_repeat1] = aux_sym_source_file_repeat1,
+  [aux_sym_option_name_repeat1] = aux_sym_option_name_repeat1,
+  [aux_sym_enum_body_repeat1] = aux_sym_enum_body_repeat1,
+  [aux_sym_enum_field_repeat1] = aux_sym_enum_field_repeat1,
+  [aux_sym_message_body_repeat1] = aux_sym_message_body_repeat1,
+  [aux_sym_field_options_repeat1] = aux_sym_field_options_repeat1,
+  [aux_sym_oneof_repeat1] = aux_sym_oneof_repeat1,
+  [aux_sym_ranges_repeat1] = aux_sym_ranges_repeat1,
+  [aux_sym_reserved_field_names_repeat1] = aux_sym_reserved_field_names_repeat1,
+  [aux_sym_message_or_enum_type_repeat1] = aux_sym_message_or_enum_type_repeat1,
+  [aux_sym_service_repeat1] = aux_sym_service_repeat1,
+  [aux_sym_rpc_repeat1] = aux_sym_rpc_repeat1,
+  [aux_sym_block_lit_repeat1] = aux_sym_block_lit_repeat1,
+  [aux_sym_block_lit_repeat2] = aux_sym_block_lit_repeat2,
+  [aux_sym_string_repeat1] = aux_sym_string_repeat1,
+  [aux_sym_string_repeat2] = aux_sym_string_repeat2,
+  [aux_sym_string_repeat3] = aux_sym_string_repeat3,
+};
+
+static const TSSymbolMetadata ts_symbol_metadata[] = {
+  [ts_builtin_sym_end] = {
+    .visible = false,
+    .named = true,
+  },
+  [anon_sym_SEMI] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_edition] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_syntax] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DQUOTEproto3_DQUOTE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DQUOTEproto2_DQUOTE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_import] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_weak] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_public] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_package] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_option] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LPAREN] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RPAREN] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_enum] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_COMMA] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DASH] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_message] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_extend] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_optional] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_required] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_repeated] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_oneof] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_map] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_GT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_int32] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_int64] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_uint32] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_uint64] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_sint32] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_sint64] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_fixed32] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_fixed64] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_sfixed32] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_sfixed64] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_bool] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_string] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_double] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_float] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_bytes] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_reserved] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_extensions] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_to] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_max] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_service] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_rpc] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_returns] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_stream] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_PLUS] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_COLON] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym__identifier] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_reserved_identifier] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_true] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_false] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_decimal_lit] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_octal_lit] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_hex_lit] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_float_lit] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_DQUOTE] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym_string_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [anon_sym_SQUOTE] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym_string_token2] = {
+    .visible = false,
+    .named = false,
+  },
+  [sym_escape_sequence] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_grit_metavariable] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_comment] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_source_file] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_empty_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_edition] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_syntax] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_import] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_import_modifier] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_package] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_option] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_option_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_enum] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_enum_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_enum_body] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_enum_field] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_signed_int_lit] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_enum_value_option] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_message] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_message_body] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_message_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_extend] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_field] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_field_label] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_field_options] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_field_option] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_oneof] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_oneof_field] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_map_field] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_key_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_reserved] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_extensions] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_ranges] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_range] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_reserved_field_names] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_message_or_enum_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_field_number] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_service] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_service_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_rpc] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_rpc_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_constant] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_block_lit] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_identifier] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_full_ident] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_bool] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_int_lit] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_string] = {
+    .visible = true,
+    .named = true,
+  },
+  [aux_sym_source_file_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_option_name_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_enum_body_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_enum_field_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_message_body_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_field_options_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_oneof_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_ranges_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_reserved_field_names_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_message_or_enum_type_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_service_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_rpc_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_block_lit_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_block_lit_repeat2] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_string_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_string_repeat2] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_string_repeat3] = {
+    .visible = false,
+    .named = false,
+  },
+};
+
+enum ts_field_identifiers {
+  field_body = 1,
+  field_end = 2,
+  field_item = 3,
+  field_key_type = 4,
+  field_label = 5,
+  field_modifier = 6,
+  field_name = 7,
+  field_number = 8,
+  field_option = 9,
+  field_options = 10,
+  field_path = 11,
+  field_range = 12,
+  field_ranges = 13,
+  field_request = 14,
+  field_request_stream = 15,
+  field_reserved = 16,
+  field_response = 17,
+  field_response_stream = 18,
+  field_start = 19,
+  field_syntax = 20,
+  field_type = 21,
+  field_value = 22,
+  field_value_type = 23,
+  field_year = 24,
+};
+
+static const char * const ts_field_names[] = {
+  [0] = NULL,
+  [field_body] = "body",
+  [field_end] = "end",
+  [field_item] = "item",
+  [field_key_type] = "key_type",
+  [field_label] = "label",
+  [field_modifier] = "modifier",
+  [field_name] = "name",
+  [field_number] = "number",
+  [field_option] = "option",
+  [field_options] = "options",
+  [field_path] = "path",
+  [field_range] = "range",
+  [field_ranges] = "ranges",
+  [field_request] = "request",
+  [field_request_stream] = "request_stream",
+  [field_reserved] = "reserved",
+  [field_response] = "response",
+  [field_response_stream] = "response_stream",
+  [field_start] = "start",
+  [field_syntax] = "syntax",
+  [field_type] = "type",
+  [field_value] = "value",
+  [field_value_type] = "value_type",
+  [field_year] = "year",
+};
+
+static const TSFieldMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
+  [1] = {.index = 0, .length = 1},
+  [2] = {.index = 1, .length = 1},
+  [3] = {.index = 2, .length = 1},
+  [4] = {.index = 3, .length = 2},
+  [5] = {.index = 5, .length = 2},
+  [6] = {.index = 7, .length = 1},
+  [7] = {.index = 8, .length = 1},
+  [8] = {.index = 9, .length = 2},
+  [9] = {.index = 11, .length = 1},
+  [10] = {.index = 12, .length = 2},
+  [11] = {.index = 14, .length = 2},
+  [12] = {.index = 16, .length = 1},
+  [13] = {.index = 17, .length = 1},
+  [14] = {.index = 18, .length = 1},
+  [15] = {.index = 19, .length = 1},
+  [16] = {.index = 20, .length = 2},
+  [17] = {.index = 22, .length = 2},
+  [18] = {.index = 24, .length = 1},
+  [19] = {.index = 25, .length = 2},
+  [20] = {.index = 27, .length = 1},
+  [21] = {.index = 28, .length = 2},
+  [22] = {.index = 30, .length = 1},
+  [23] = {.index = 31, .length = 2},
+  [24] = {.index = 33, .length = 2},
+  [25] = {.index = 35, .length = 2},
+  [26] = {.index = 37, .length = 3},
+  [27] = {.index = 40, .length = 4},
+  [28] = {.index = 44, .length = 1},
+  [29] = {.index = 45, .length = 2},
+  [30] = {.index = 47, .length = 1},
+  [31] = {.index = 48, .length = 3},
+  [32] = {.index = 51, .length = 2},
+  [33] = {.index = 53, .length = 2},
+  [34] = {.index = 55, .length = 4},
+  [35] = {.index = 59, .length = 4},
+  [36] = {.index = 63, .length = 5},
+  [37] = {.index = 68, .length = 4},
+  [38] = {.index = 72, .length = 3},
+  [39] = {.index = 75, .length = 4},
+  [40] = {.index = 79, .length = 4},
+  [41] = {.index = 83, .length = 5},
+  [42] = {.index = 88, .length = 4},
+  [43] = {.index = 92, .length = 5},
+  [44] = {.index = 97, .length = 5},
+  [45] = {.index = 102, .length = 5},
+  [46] = {.index = 107, .length = 6},
+};
+
+static const TSFieldMapEntry ts_field_map_entries[] = {
+  [0] =
+    {field_item, 0},
+  [1] =
+    {field_syntax, 0},
+  [2] =
+    {field_item, 0, .inherited = true},
+  [3] =
+    {field_item, 1, .inherited = true},
+    {field_syntax, 0},
+  [5] =
+    {field_item, 0, .inherited = true},
+    {field_item, 1, .inherited = true},
+  [7] =
+    {field_path, 1},
+  [8] =
+    {field_name, 1},
+  [9] =
+    {field_body, 2},
+    {field_name, 1},
+  [11] =
+    {field_year, 2},
+  [12] =
+    {field_modifier, 1},
+    {field_path, 2},
+  [14] =
+    {field_name, 1},
+    {field_value, 3},
+  [16] =
+    {field_name, 0},
+  [17] =
+    {field_range, 0},
+  [18] =
+    {field_start, 0},
+  [19] =
+    {field_item, 1, .inherited = true},
+  [20] =
+    {field_item, 3, .inherited = true},
+    {field_name, 1},
+  [22] =
+    {field_name, 0},
+    {field_name, 1, .inherited = true},
+  [24] =
+    {field_reserved, 1},
+  [25] =
+    {field_range, 0},
+    {field_range, 1, .inherited = true},
+  [27] =
+    {field_ranges, 1},
+  [28] =
+    {field_name, 0, .inherited = true},
+    {field_name, 1, .inherited = true},
+  [30] =
+    {field_range, 1},
+  [31] =
+    {field_range, 0, .inherited = true},
+    {field_range, 1, .inherited = true},
+  [33] =
+    {field_end, 2},
+    {field_start, 0},
+  [35] =
+    {field_name, 0},
+    {field_number, 2},
+  [37] =
+    {field_name, 1},
+    {field_number, 3},
+    {field_type, 0},
+  [40] =
+    {field_label, 0},
+    {field_name, 2},
+    {field_number, 4},
+    {field_type, 1},
+  [44] =
+    {field_option, 0},
+  [45] =
+    {field_name, 0},
+    {field_value, 2},
+  [47] =
+    {field_option, 1},
+  [48] =
+    {field_name, 0},
+    {field_number, 2},
+    {field_option, 4},
+  [51] =
+    {field_option, 0, .inherited = true},
+    {field_option, 1, .inherited = true},
+  [53] =
+    {field_option, 0},
+    {field_option, 1, .inherited = true},
+  [55] =
+    {field_name, 0},
+    {field_number, 2},
+    {field_option, 4},
+    {field_option, 5, .inherited = true},
+  [59] =
+    {field_name, 1},
+    {field_number, 3},
+    {field_options, 5},
+    {field_type, 0},
+  [63] =
+    {field_label, 0},
+    {field_name, 2},
+    {field_number, 4},
+    {field_options, 6},
+    {field_type, 1},
+  [68] =
+    {field_key_type, 2},
+    {field_name, 6},
+    {field_number, 8},
+    {field_value_type, 4},
+  [72] =
+    {field_name, 1},
+    {field_request, 3},
+    {field_response, 7},
+  [75] =
+    {field_name, 1},
+    {field_request, 4},
+    {field_request_stream, 3},
+    {field_response, 8},
+  [79] =
+    {field_name, 1},
+    {field_request, 3},
+    {field_response, 8},
+    {field_response_stream, 7},
+  [83] =
+    {field_name, 1},
+    {field_request, 4},
+    {field_request_stream, 3},
+    {field_response, 9},
+    {field_response_stream, 8},
+  [88] =
+    {field_item, 10, .inherited = true},
+    {field_name, 1},
+    {field_request, 3},
+    {field_response, 7},
+  [92] =
+    {field_key_type, 2},
+    {field_name, 6},
+    {field_number, 8},
+    {field_options, 10},
+    {field_value_type, 4},
+  [97] =
+    {field_item, 11, .inherited = true},
+    {field_name, 1},
+    {field_request, 4},
+    {field_request_stream, 3},
+    {field_response, 8},
+  [102] =
+    {field_item, 11, .inherited = true},
+    {field_name, 1},
+    {field_request, 3},
+    {field_response, 8},
+    {field_response_stream, 7},
+  [107] =
+    {field_item, 12, .inherited = true},
+    {field_name, 1},
+    {field_request, 4},
+    {field_request_stream, 3},
+    {field_response, 9},
+    {field_response_stream, 8},
+};
+
+static const TSSymbol ts_alias_sequences[PRODUCTION_ID_COUNT][MAX_ALIAS_SEQUENCE_LENGTH] = {
+  [0] = {0},
+};
+
+static const uint16_t ts_non_terminal_alias_map[] = {
+  0,
+};
+
+static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
+  [0] = 0,
+  [1] = 1,
+  [2] = 2,
+  [3] = 3,
+  [4] = 2,
+  [5] = 5,
+  [6] = 3,
+  [7] = 7,
+  [8] = 8,
+  [9] = 9,
+  [10] = 10,
+  [11] = 11,
+  [12] = 12,
+  [13] = 13,
+  [14] = 14,
+  [15] = 15,
+  [16] = 16,
+  [17] = 17,
+  [18] = 18,
+  [19] = 19,
+  [20] = 20,
+  [21] = 21,
+  [22] = 22,
+  [23] = 23,
+  [24] = 24,
+  [25] = 25,
+  [26] = 26,
+  [27] = 27,
+  [28] = 28,
+  [29] = 29,
+  [30] = 30,
+  [31] = 31,
+  [32] = 32,
+  [33] = 33,
+  [34] = 34,
+  [35] = 35,
+  [36] = 36,
+  [37] = 37,
+  [38] = 38,
+  [39] = 39,
+  [40] = 40,
+  [41] = 41,
+  [42] = 42,
+  [43] = 43,
+  [44] = 44,
+  [45] = 45,
+  [46] = 46,
+  [47] = 47,
+  [48] = 47,
+  [49] = 47,
+  [50] = 26,
+  [51] = 8,
+  [52] = 52,
+  [53] = 47,
+  [54] = 54,
+  [55] = 55,
+  [56] = 56,
+  [57] = 57,
+  [58] = 58,
+  [59] = 59,
+  [60] = 60,
+  [61] = 60,
+  [62] = 62,
+  [63] = 59,
+  [64] = 64,
+  [65] = 65,
+  [66] = 64,
+  [67] = 67,
+  [68] = 68,
+  [69] = 67,
+  [70] = 26,
+  [71] = 71,
+  [72] = 72,
+  [73] = 8,
+  [74] = 74,
+  [75] = 75,
+  [76] = 76,
+  [77] = 77,
+  [78] = 78,
+  [79] = 79,
+  [80] = 80,
+  [81] = 81,
+  [82] = 77,
+  [83] = 83,
+  [84] = 84,
+  [85] = 21,
+  [86] = 22,
+  [87] = 87,
+  [88] = 88,
+  [89] = 89,
+  [90] = 23,
+  [91] = 24,
+  [92] = 7,
+  [93] = 93,
+  [94] = 19,
+  [95] = 20,
+  [96] = 96,
+  [97] = 97,
+  [98] = 98,
+  [99] = 99,
+  [100] = 100,
+  [101] = 101,
+  [102] = 102,
+  [103] = 103,
+  [104] = 104,
+  [105] = 105,
+  [106] = 106,
+  [107] = 107,
+  [108] = 108,
+  [109] = 109,
+  [110] = 110,
+  [111] = 111,
+  [112] = 112,
+  [113] = 113,
+  [114] = 114,
+  [115] = 115,
+  [116] = 116,
+  [117] = 117,
+  [118] = 118,
+  [119] = 119,
+  [120] = 120,
+  [121] = 121,
+  [122] = 122,
+  [123] = 123,
+  [124] = 124,
+  [125] = 125,
+  [126] = 126,
+  [127] = 127,
+  [128] = 128,
+  [129] = 129,
+  [130] = 130,
+  [131] = 131,
+  [132] = 132,
+  [133] = 133,
+  [134] = 26,
+  [135] = 25,
+  [136] = 136,
+  [137] = 137,
+  [138] = 138,
+  [139] = 42,
+  [140] = 140,
+  [141] = 141,
+  [142] = 8,
+  [143] = 143,
+  [144] = 144,
+  [145] = 145,
+  [146] = 146,
+  [147] = 147,
+  [148] = 148,
+  [149] = 149,
+  [150] = 150,
+  [151] = 151,
+  [152] = 152,
+  [153] = 153,
+  [154] = 154,
+  [155] = 148,
+  [156] = 156,
+  [157] = 157,
+  [158] = 158,
+  [159] = 159,
+  [160] = 160,
+  [161] = 161,
+  [162] = 162,
+  [163] = 42,
+  [164] = 160,
+  [165] = 160,
+  [166] = 160,
+  [167] = 167,
+  [168] = 168,
+  [169] = 169,
+  [170] = 170,
+  [171] = 171,
+  [172] = 172,
+  [173] = 173,
+  [174] = 168,
+  [175] = 175,
+  [176] = 176,
+  [177] = 177,
+  [178] = 178,
+  [179] = 179,
+  [180] = 180,
+  [181] = 181,
+  [182] = 182,
+  [183] = 183,
+  [184] = 184,
+  [185] = 185,
+  [186] = 186,
+  [187] = 187,
+  [188] = 188,
+  [189] = 189,
+  [190] = 190,
+  [191] = 191,
+  [192] = 192,
+  [193] = 193,
+  [194] = 194,
+  [195] = 195,
+  [196] = 196,
+  [197] = 197,
+  [198] = 198,
+  [199] = 199,
+  [200] = 200,
+  [201] = 201,
+  [202] = 202,
+  [203] = 203,
+  [204] = 204,
+  [205] = 200,
+  [206] = 169,
+  [207] = 207,
+  [208] = 208,
+  [209] = 209,
+  [210] = 210,
+  [211] = 211,
+  [212] = 212,
+  [213] = 213,
+  [214] = 214,
+  [215] = 215,
+  [216] = 216,
+  [217] = 217,
+  [218] = 218,
+  [219] = 219,
+  [220] = 220,
+  [221] = 221,
+  [222] = 222,
+  [223] = 223,
+  [224] = 224,
+  [225] = 225,
+  [226] = 226,
+  [227] = 227,
+  [228] = 228,
+  [229] = 229,
+  [230] = 230,
+  [231] = 231,
+  [232] = 232,
+  [233] = 233,
+  [234] = 234,
+  [235] = 235,
+  [236] = 236,
+  [237] = 237,
+  [238] = 238,
+  [239] = 239,
+  [240] = 240,
+  [241] = 241,
+  [242] = 242,
+  [243] = 243,
+  [244] = 244,
+  [245] = 245,
+  [246] = 246,
+  [247] = 247,
+  [248] = 248,
+  [249] = 249,
+  [250] = 250,
+  [251] = 251,
+  [252] = 252,
+  [253] = 253,
+  [254] = 254,
+  [255] = 255,
+  [256] = 256,
+  [257] = 257,
+  [258] = 258,
+  [259] = 259,
+  [260] = 246,
+  [261] = 250,
+  [262] = 255,
+  [263] = 263,
+  [264] = 264,
+  [265] = 265,
+  [266] = 266,
+  [267] = 267,
+  [268] = 268,
+  [269] = 269,
+  [270] = 270,
+  [271] = 271,
+  [272] = 272,
+  [273] = 273,
+  [274] = 274,
+  [275] = 275,
+  [276] = 276,
+  [277] = 277,
+  [278] = 278,
+  [279] = 279,
+  [280] = 280,
+  [281] = 281,
+  [282] = 282,
+  [283] = 283,
+  [284] = 284,
+  [285] = 285,
+  [286] = 286,
+  [287] = 287,
+  [288] = 288,
+  [289] = 289,
+  [290] = 290,
+  [291] = 291,
+  [292] = 292,
+  [293] = 293,
+  [294] = 294,
+  [295] = 295,
+  [296] = 296,
+  [297] = 297,
+  [298] = 298,
+  [299] = 299,
+  [300] = 300,
+  [301] = 301,
+  [302] = 302,
+  [303] = 303,
+  [304] = 304,
+  [305] = 305,
+  [306] = 306,
+  [307] = 307,
+  [308] = 308,
+  [309] = 309,
+  [310] = 288,
+  [311] = 311,
+  [312] = 312,
+  [313] = 313,
+  [314] = 288,
+  [315] = 288,
+  [316] = 316,
+  [317] = 317,
+  [318] = 318,
+  [319] = 319,
+  [320] = 320,
+  [321] = 321,
+  [322] = 322,
+  [323] = 285,
+  [324] = 285,
+  [325] = 285,
+  [326] = 313,
+  [327] = 327,
+  [328] = 328,
+  [329] = 329,
+};
+
+static bool ts_lex(TSLexer *lexer, TSStateId state) {
+  START_LEXER();
+  eof = lexer->eof(lexer);
+  switch (state) {
+    case 0:
+      if (eof) ADVANCE(203);
+      ADVANCE_MAP(
+        '"', 422,
+        '\'', 429,
+        '(', 218,
+        ')', 219,
+        '+', 287,
+        ',', 227,
+        '-', 229,
+        '.', 221,
+        '/', 10,
+        '0', 414,
+        ':', 288,
+        ';', 204,
+        '<', 244,
+        '=', 206,
+        '>', 245,
+        '[', 226,
+        '\\', 39,
+        ']', 228,
+        'b', 135,
+        'd', 131,
+        'e', 64,
+        'f', 40,
+        'i', 114,
+        'm', 41,
+        'n', 42,
+        'o', 122,
+        'p', 44,
+        'r', 68,
+        's', 69,
+        't', 132,
+        'u', 105,
+        'w', 78,
+        '{', 224,
+        '}', 225,
+        0xb5, 14,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(201);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(412);
+      END_STATE();
+    case 1:
+      ADVANCE_MAP(
+        '"', 422,
+        '\'', 429,
+        '(', 218,
+        ')', 219,
+        ',', 227,
+        '.', 220,
+        '/', 10,
+        ';', 204,
+        '=', 206,
+        '>', 245,
+        '[', 226,
+        ']', 228,
+        '{', 224,
+        '}', 225,
+        0xb5, 14,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(1);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 2:
+      ADVANCE_MAP(
+        '"', 422,
+        '\'', 429,
+        '+', 287,
+        '-', 229,
+        '.', 188,
+        '/', 10,
+        '0', 414,
+        ':', 288,
+        '[', 226,
+        ']', 228,
+        'f', 308,
+        'i', 361,
+        'n', 309,
+        't', 379,
+        '{', 224,
+        0xb5, 14,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(2);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(412);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 3:
+      if (lookahead == '"') ADVANCE(422);
+      if (lookahead == '/') ADVANCE(424);
+      if (lookahead == '\\') ADVANCE(39);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(427);
+      if (lookahead != 0) ADVANCE(428);
+      END_STATE();
+    case 4:
+      if (lookahead == '"') ADVANCE(209);
+      END_STATE();
+    case 5:
+      if (lookahead == '"') ADVANCE(208);
+      END_STATE();
+    case 6:
+      if (lookahead == '"') ADVANCE(199);
+      if (lookahead == '\'') ADVANCE(200);
+      if (lookahead == '/') ADVANCE(10);
+      if (lookahead == '0') ADVANCE(416);
+      if (lookahead == 0xb5) ADVANCE(14);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(6);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(413);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(407);
+      END_STATE();
+    case 7:
+      if (lookahead == '"') ADVANCE(406);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(7);
+      END_STATE();
+    case 8:
+      if (lookahead == '\'') ADVANCE(429);
+      if (lookahead == '/') ADVANCE(431);
+      if (lookahead == '\\') ADVANCE(39);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(434);
+      if (lookahead != 0) ADVANCE(435);
+      END_STATE();
+    case 9:
+      if (lookahead == '\'') ADVANCE(406);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(9);
+      END_STATE();
+    case 10:
+      if (lookahead == '*') ADVANCE(12);
+      if (lookahead == '/') ADVANCE(442);
+      END_STATE();
+    case 11:
+      if (lookahead == '*') ADVANCE(11);
+      if (lookahead == '/') ADVANCE(441);
+      if (lookahead != 0) ADVANCE(12);
+      END_STATE();
+    case 12:
+      if (lookahead == '*') ADVANCE(11);
+      if (lookahead != 0) ADVANCE(12);
+      END_STATE();
+    case 13:
+      if (lookahead == '.') ADVANCE(420);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(187);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(13);
+      END_STATE();
+    case 14:
+      if (lookahead == '.') ADVANCE(20);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(440);
+      END_STATE();
+    case 15:
+      if (lookahead == '.') ADVANCE(439);
+      END_STATE();
+    case 16:
+      ADVANCE_MAP(
+        '.', 220,
+        '/', 10,
+        ';', 204,
+        '[', 226,
+        'b', 370,
+        'd', 366,
+        'f', 340,
+        'i', 359,
+        'o', 376,
+        's', 337,
+        'u', 346,
+        '}', 225,
+        0xb5, 14,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(16);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 17:
+      ADVANCE_MAP(
+        '.', 220,
+        '/', 10,
+        ';', 204,
+        'b', 370,
+        'd', 366,
+        'e', 360,
+        'f', 340,
+        'i', 359,
+        'm', 304,
+        'o', 358,
+        'r', 318,
+        's', 337,
+        'u', 346,
+        '}', 225,
+        0xb5, 14,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(17);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 18:
+      ADVANCE_MAP(
+        '.', 220,
+        '/', 10,
+        'b', 370,
+        'd', 366,
+        'f', 340,
+        'i', 359,
+        's', 337,
+        'u', 346,
+        0xb5, 14,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(18);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 19:
+      if (lookahead == '.') ADVANCE(220);
+      if (lookahead == '/') ADVANCE(10);
+      if (lookahead == 's') ADVANCE(395);
+      if (lookahead == 0xb5) ADVANCE(14);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(19);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 20:
+      if (lookahead == '.') ADVANCE(15);
+      END_STATE();
+    case 21:
+      if (lookahead == '.') ADVANCE(188);
+      if (lookahead == '/') ADVANCE(10);
+      if (lookahead == '0') ADVANCE(414);
+      if (lookahead == 'i') ADVANCE(123);
+      if (lookahead == 'n') ADVANCE(42);
+      if (lookahead == 0xb5) ADVANCE(14);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(21);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(412);
+      END_STATE();
+    case 22:
+      if (lookahead == '/') ADVANCE(10);
+      if (lookahead == ';') ADVANCE(204);
+      if (lookahead == 'o') ADVANCE(376);
+      if (lookahead == 'r') ADVANCE(333);
+      if (lookahead == '}') ADVANCE(225);
+      if (lookahead == 0xb5) ADVANCE(14);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(22);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 23:
+      if (lookahead == '2') ADVANCE(246);
+      END_STATE();
+    case 24:
+      if (lookahead == '2') ADVANCE(254);
+      END_STATE();
+    case 25:
+      if (lookahead == '2') ADVANCE(250);
+      END_STATE();
+    case 26:
+      if (lookahead == '2') ADVANCE(258);
+      END_STATE();
+    case 27:
+      if (lookahead == '2') ADVANCE(262);
+      END_STATE();
+    case 28:
+      if (lookahead == '2') ADVANCE(4);
+      if (lookahead == '3') ADVANCE(5);
+      END_STATE();
+    case 29:
+      if (lookahead == '3') ADVANCE(23);
+      if (lookahead == '6') ADVANCE(34);
+      END_STATE();
+    case 30:
+      if (lookahead == '3') ADVANCE(24);
+      if (lookahead == '6') ADVANCE(35);
+      END_STATE();
+    case 31:
+      if (lookahead == '3') ADVANCE(25);
+      if (lookahead == '6') ADVANCE(36);
+      END_STATE();
+    case 32:
+      if (lookahead == '3') ADVANCE(26);
+      if (lookahead == '6') ADVANCE(37);
+      END_STATE();
+    case 33:
+      if (lookahead == '3') ADVANCE(27);
+      if (lookahead == '6') ADVANCE(38);
+      END_STATE();
+    case 34:
+      if (lookahead == '4') ADVANCE(248);
+      END_STATE();
+    case 35:
+      if (lookahead == '4') ADVANCE(256);
+      END_STATE();
+    case 36:
+      if (lookahead == '4') ADVANCE(252);
+      END_STATE();
+    case 37:
+      if (lookahead == '4') ADVANCE(260);
+      END_STATE();
+    case 38:
+      if (lookahead == '4') ADVANCE(264);
+      END_STATE();
+    case 39:
+      if (lookahead == 'U') ADVANCE(198);
+      if (lookahead == 'u') ADVANCE(194);
+      if (lookahead == 'x') ADVANCE(192);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(438);
+      if (lookahead != 0) ADVANCE(436);
+      END_STATE();
+    case 40:
+      if (lookahead == 'a') ADVANCE(110);
+      if (lookahead == 'i') ADVANCE(185);
+      if (lookahead == 'l') ADVANCE(137);
+      END_STATE();
+    case 41:
+      if (lookahead == 'a') ADVANCE(144);
+      if (lookahead == 'e') ADVANCE(160);
+      END_STATE();
+    case 42:
+      if (lookahead == 'a') ADVANCE(117);
+      END_STATE();
+    case 43:
+      if (lookahead == 'a') ADVANCE(55);
+      END_STATE();
+    case 44:
+      if (lookahead == 'a') ADVANCE(55);
+      if (lookahead == 'u') ADVANCE(53);
+      END_STATE();
+    case 45:
+      if (lookahead == 'a') ADVANCE(94);
+      END_STATE();
+    case 46:
+      if (lookahead == 'a') ADVANCE(184);
+      END_STATE();
+    case 47:
+      if (lookahead == 'a') ADVANCE(116);
+      END_STATE();
+    case 48:
+      if (lookahead == 'a') ADVANCE(106);
+      END_STATE();
+    case 49:
+      if (lookahead == 'a') ADVANCE(183);
+      if (lookahead == 'e') ADVANCE(160);
+      END_STATE();
+    case 50:
+      if (lookahead == 'a') ADVANCE(163);
+      END_STATE();
+    case 51:
+      if (lookahead == 'a') ADVANCE(95);
+      END_STATE();
+    case 52:
+      if (lookahead == 'a') ADVANCE(170);
+      END_STATE();
+    case 53:
+      if (lookahead == 'b') ADVANCE(111);
+      END_STATE();
+    case 54:
+      if (lookahead == 'b') ADVANCE(112);
+      END_STATE();
+    case 55:
+      if (lookahead == 'c') ADVANCE(107);
+      END_STATE();
+    case 56:
+      if (lookahead == 'c') ADVANCE(283);
+      END_STATE();
+    case 57:
+      if (lookahead == 'c') ADVANCE(212);
+      END_STATE();
+    case 58:
+      if (lookahead == 'c') ADVANCE(77);
+      END_STATE();
+    case 59:
+      if (lookahead == 'd') ADVANCE(232);
+      END_STATE();
+    case 60:
+      if (lookahead == 'd') ADVANCE(232);
+      if (lookahead == 's') ADVANCE(101);
+      END_STATE();
+    case 61:
+      if (lookahead == 'd') ADVANCE(238);
+      END_STATE();
+    case 62:
+      if (lookahead == 'd') ADVANCE(236);
+      END_STATE();
+    case 63:
+      if (lookahead == 'd') ADVANCE(276);
+      END_STATE();
+    case 64:
+      if (lookahead == 'd') ADVANCE(103);
+      if (lookahead == 'n') ADVANCE(176);
+      if (lookahead == 'x') ADVANCE(167);
+      END_STATE();
+    case 65:
+      if (lookahead == 'd') ADVANCE(103);
+      if (lookahead == 'n') ADVANCE(176);
+      if (lookahead == 'x') ADVANCE(171);
+      END_STATE();
+    case 66:
+      if (lookahead == 'd') ADVANCE(32);
+      END_STATE();
+    case 67:
+      if (lookahead == 'd') ADVANCE(33);
+      END_STATE();
+    case 68:
+      if (lookahead == 'e') ADVANCE(148);
+      if (lookahead == 'p') ADVANCE(56);
+      END_STATE();
+    case 69:
+      if (lookahead == 'e') ADVANCE(150);
+      if (lookahead == 'f') ADVANCE(104);
+      if (lookahead == 'i') ADVANCE(125);
+      if (lookahead == 't') ADVANCE(151);
+      if (lookahead == 'y') ADVANCE(126);
+      END_STATE();
+    case 70:
+      if (lookahead == 'e') ADVANCE(150);
+      if (lookahead == 'y') ADVANCE(126);
+      END_STATE();
+    case 71:
+      if (lookahead == 'e') ADVANCE(66);
+      END_STATE();
+    case 72:
+      if (lookahead == 'e') ADVANCE(408);
+      END_STATE();
+    case 73:
+      if (lookahead == 'e') ADVANCE(410);
+      END_STATE();
+    case 74:
+      if (lookahead == 'e') ADVANCE(270);
+      END_STATE();
+    case 75:
+      if (lookahead == 'e') ADVANCE(230);
+      END_STATE();
+    case 76:
+      if (lookahead == 'e') ADVANCE(213);
+      END_STATE();
+    case 77:
+      if (lookahead == 'e') ADVANCE(282);
+      END_STATE();
+    case 78:
+      if (lookahead == 'e') ADVANCE(48);
+      END_STATE();
+    case 79:
+      if (lookahead == 'e') ADVANCE(61);
+      END_STATE();
+    case 80:
+      if (lookahead == 'e') ADVANCE(118);
+      END_STATE();
+    case 81:
+      if (lookahead == 'e') ADVANCE(62);
+      END_STATE();
+    case 82:
+      if (lookahead == 'e') ADVANCE(157);
+      END_STATE();
+    case 83:
+      if (lookahead == 'e') ADVANCE(63);
+      END_STATE();
+    case 84:
+      if (lookahead == 'e') ADVANCE(133);
+      END_STATE();
+    case 85:
+      if (lookahead == 'e') ADVANCE(52);
+      END_STATE();
+    case 86:
+      if (lookahead == 'e') ADVANCE(47);
+      if (lookahead == 'i') ADVANCE(124);
+      END_STATE();
+    case 87:
+      if (lookahead == 'e') ADVANCE(128);
+      END_STATE();
+    case 88:
+      if (lookahead == 'e') ADVANCE(152);
+      END_STATE();
+    case 89:
+      if (lookahead == 'e') ADVANCE(67);
+      END_STATE();
+    case 90:
+      if (lookahead == 'f') ADVANCE(419);
+      END_STATE();
+    case 91:
+      if (lookahead == 'f') ADVANCE(419);
+      if (lookahead == 't') ADVANCE(29);
+      END_STATE();
+    case 92:
+      if (lookahead == 'f') ADVANCE(240);
+      END_STATE();
+    case 93:
+      if (lookahead == 'g') ADVANCE(268);
+      END_STATE();
+    case 94:
+      if (lookahead == 'g') ADVANCE(75);
+      END_STATE();
+    case 95:
+      if (lookahead == 'g') ADVANCE(76);
+      END_STATE();
+    case 96:
+      if (lookahead == 'i') ADVANCE(57);
+      END_STATE();
+    case 97:
+      if (lookahead == 'i') ADVANCE(58);
+      END_STATE();
+    case 98:
+      if (lookahead == 'i') ADVANCE(156);
+      END_STATE();
+    case 99:
+      if (lookahead == 'i') ADVANCE(139);
+      END_STATE();
+    case 100:
+      if (lookahead == 'i') ADVANCE(141);
+      END_STATE();
+    case 101:
+      if (lookahead == 'i') ADVANCE(142);
+      END_STATE();
+    case 102:
+      if (lookahead == 'i') ADVANCE(143);
+      END_STATE();
+    case 103:
+      if (lookahead == 'i') ADVANCE(173);
+      END_STATE();
+    case 104:
+      if (lookahead == 'i') ADVANCE(186);
+      END_STATE();
+    case 105:
+      if (lookahead == 'i') ADVANCE(130);
+      END_STATE();
+    case 106:
+      if (lookahead == 'k') ADVANCE(211);
+      END_STATE();
+    case 107:
+      if (lookahead == 'k') ADVANCE(51);
+      END_STATE();
+    case 108:
+      if (lookahead == 'l') ADVANCE(266);
+      END_STATE();
+    case 109:
+      if (lookahead == 'l') ADVANCE(234);
+      END_STATE();
+    case 110:
+      if (lookahead == 'l') ADVANCE(162);
+      END_STATE();
+    case 111:
+      if (lookahead == 'l') ADVANCE(96);
+      END_STATE();
+    case 112:
+      if (lookahead == 'l') ADVANCE(74);
+      END_STATE();
+    case 113:
+      if (lookahead == 'm') ADVANCE(146);
+      END_STATE();
+    case 114:
+      if (lookahead == 'm') ADVANCE(146);
+      if (lookahead == 'n') ADVANCE(91);
+      END_STATE();
+    case 115:
+      if (lookahead == 'm') ADVANCE(222);
+      END_STATE();
+    case 116:
+      if (lookahead == 'm') ADVANCE(285);
+      END_STATE();
+    case 117:
+      if (lookahead == 'n') ADVANCE(419);
+      END_STATE();
+    case 118:
+      if (lookahead == 'n') ADVANCE(60);
+      END_STATE();
+    case 119:
+      if (lookahead == 'n') ADVANCE(216);
+      END_STATE();
+    case 120:
+      if (lookahead == 'n') ADVANCE(205);
+      END_STATE();
+    case 121:
+      if (lookahead == 'n') ADVANCE(214);
+      END_STATE();
+    case 122:
+      if (lookahead == 'n') ADVANCE(84);
+      if (lookahead == 'p') ADVANCE(166);
+      END_STATE();
+    case 123:
+      if (lookahead == 'n') ADVANCE(90);
+      END_STATE();
+    case 124:
+      if (lookahead == 'n') ADVANCE(93);
+      END_STATE();
+    case 125:
+      if (lookahead == 'n') ADVANCE(172);
+      END_STATE();
+    case 126:
+      if (lookahead == 'n') ADVANCE(168);
+      END_STATE();
+    case 127:
+      if (lookahead == 'n') ADVANCE(158);
+      END_STATE();
+    case 128:
+      if (lookahead == 'n') ADVANCE(59);
+      END_STATE();
+    case 129:
+      if (lookahead == 'n') ADVANCE(159);
+      END_STATE();
+    case 130:
+      if (lookahead == 'n') ADVANCE(174);
+      END_STATE();
+    case 131:
+      if (lookahead == 'o') ADVANCE(180);
+      END_STATE();
+    case 132:
+      if (lookahead == 'o') ADVANCE(280);
+      if (lookahead == 'r') ADVANCE(179);
+      END_STATE();
+    case 133:
+      if (lookahead == 'o') ADVANCE(92);
+      END_STATE();
+    case 134:
+      if (lookahead == 'o') ADVANCE(28);
+      END_STATE();
+    case 135:
+      if (lookahead == 'o') ADVANCE(136);
+      if (lookahead == 'y') ADVANCE(165);
+      END_STATE();
+    case 136:
+      if (lookahead == 'o') ADVANCE(108);
+      END_STATE();
+    case 137:
+      if (lookahead == 'o') ADVANCE(50);
+      END_STATE();
+    case 138:
+      if (lookahead == 'o') ADVANCE(153);
+      END_STATE();
+    case 139:
+      if (lookahead == 'o') ADVANCE(119);
+      END_STATE();
+    case 140:
+      if (lookahead == 'o') ADVANCE(169);
+      END_STATE();
+    case 141:
+      if (lookahead == 'o') ADVANCE(120);
+      END_STATE();
+    case 142:
+      if (lookahead == 'o') ADVANCE(129);
+      END_STATE();
+    case 143:
+      if (lookahead == 'o') ADVANCE(121);
+      END_STATE();
+    case 144:
+      if (lookahead == 'p') ADVANCE(242);
+      if (lookahead == 'x') ADVANCE(281);
+      END_STATE();
+    case 145:
+      if (lookahead == 'p') ADVANCE(56);
+      END_STATE();
+    case 146:
+      if (lookahead == 'p') ADVANCE(138);
+      END_STATE();
+    case 147:
+      if (lookahead == 'p') ADVANCE(155);
+      END_STATE();
+    case 148:
+      if (lookahead == 'p') ADVANCE(85);
+      if (lookahead == 'q') ADVANCE(178);
+      if (lookahead == 's') ADVANCE(88);
+      if (lookahead == 't') ADVANCE(177);
+      END_STATE();
+    case 149:
+      if (lookahead == 'p') ADVANCE(175);
+      END_STATE();
+    case 150:
+      if (lookahead == 'r') ADVANCE(181);
+      END_STATE();
+    case 151:
+      if (lookahead == 'r') ADVANCE(86);
+      END_STATE();
+    case 152:
+      if (lookahead == 'r') ADVANCE(182);
+      END_STATE();
+    case 153:
+      if (lookahead == 'r') ADVANCE(164);
+      END_STATE();
+    case 154:
+      if (lookahead == 'r') ADVANCE(127);
+      END_STATE();
+    case 155:
+      if (lookahead == 'r') ADVANCE(140);
+      END_STATE();
+    case 156:
+      if (lookahead == 'r') ADVANCE(81);
+      END_STATE();
+    case 157:
+      if (lookahead == 's') ADVANCE(274);
+      END_STATE();
+    case 158:
+      if (lookahead == 's') ADVANCE(284);
+      END_STATE();
+    case 159:
+      if (lookahead == 's') ADVANCE(278);
+      END_STATE();
+    case 160:
+      if (lookahead == 's') ADVANCE(161);
+      END_STATE();
+    case 161:
+      if (lookahead == 's') ADVANCE(45);
+      END_STATE();
+    case 162:
+      if (lookahead == 's') ADVANCE(73);
+      END_STATE();
+    case 163:
+      if (lookahead == 't') ADVANCE(272);
+      END_STATE();
+    case 164:
+      if (lookahead == 't') ADVANCE(210);
+      END_STATE();
+    case 165:
+      if (lookahead == 't') ADVANCE(82);
+      END_STATE();
+    case 166:
+      if (lookahead == 't') ADVANCE(99);
+      END_STATE();
+    case 167:
+      if (lookahead == 't') ADVANCE(80);
+      END_STATE();
+    case 168:
+      if (lookahead == 't') ADVANCE(46);
+      END_STATE();
+    case 169:
+      if (lookahead == 't') ADVANCE(134);
+      END_STATE();
+    case 170:
+      if (lookahead == 't') ADVANCE(79);
+      END_STATE();
+    case 171:
+      if (lookahead == 't') ADVANCE(87);
+      END_STATE();
+    case 172:
+      if (lookahead == 't') ADVANCE(30);
+      END_STATE();
+    case 173:
+      if (lookahead == 't') ADVANCE(100);
+      END_STATE();
+    case 174:
+      if (lookahead == 't') ADVANCE(31);
+      END_STATE();
+    case 175:
+      if (lookahead == 't') ADVANCE(102);
+      END_STATE();
+    case 176:
+      if (lookahead == 'u') ADVANCE(115);
+      END_STATE();
+    case 177:
+      if (lookahead == 'u') ADVANCE(154);
+      END_STATE();
+    case 178:
+      if (lookahead == 'u') ADVANCE(98);
+      END_STATE();
+    case 179:
+      if (lookahead == 'u') ADVANCE(72);
+      END_STATE();
+    case 180:
+      if (lookahead == 'u') ADVANCE(54);
+      END_STATE();
+    case 181:
+      if (lookahead == 'v') ADVANCE(97);
+      END_STATE();
+    case 182:
+      if (lookahead == 'v') ADVANCE(83);
+      END_STATE();
+    case 183:
+      if (lookahead == 'x') ADVANCE(281);
+      END_STATE();
+    case 184:
+      if (lookahead == 'x') ADVANCE(207);
+      END_STATE();
+    case 185:
+      if (lookahead == 'x') ADVANCE(71);
+      END_STATE();
+    case 186:
+      if (lookahead == 'x') ADVANCE(89);
+      END_STATE();
+    case 187:
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(189);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(421);
+      END_STATE();
+    case 188:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(420);
+      END_STATE();
+    case 189:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(421);
+      END_STATE();
+    case 190:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(436);
+      END_STATE();
+    case 191:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(418);
+      END_STATE();
+    case 192:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(190);
+      END_STATE();
+    case 193:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(192);
+      END_STATE();
+    case 194:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(193);
+      END_STATE();
+    case 195:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(194);
+      END_STATE();
+    case 196:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(195);
+      END_STATE();
+    case 197:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(196);
+      END_STATE();
+    case 198:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(197);
+      END_STATE();
+    case 199:
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(7);
+      END_STATE();
+    case 200:
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(9);
+      END_STATE();
+    case 201:
+      if (eof) ADVANCE(203);
+      ADVANCE_MAP(
+        '"', 422,
+        '\'', 429,
+        '(', 218,
+        ')', 219,
+        '+', 287,
+        ',', 227,
+        '-', 229,
+        '.', 221,
+        '/', 10,
+        '0', 414,
+        ':', 288,
+        ';', 204,
+        '<', 244,
+        '=', 206,
+        '>', 245,
+        '[', 226,
+        ']', 228,
+        'b', 135,
+        'd', 131,
+        'e', 64,
+        'f', 40,
+        'i', 114,
+        'm', 41,
+        'n', 42,
+        'o', 122,
+        'p', 44,
+        'r', 68,
+        's', 69,
+        't', 132,
+        'u', 105,
+        'w', 78,
+        '{', 224,
+        '}', 225,
+        0xb5, 14,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(201);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(412);
+      END_STATE();
+    case 202:
+      if (eof) ADVANCE(203);
+      ADVANCE_MAP(
+        '"', 147,
+        '-', 229,
+        '.', 220,
+        '/', 10,
+        '0', 416,
+        ';', 204,
+        '=', 206,
+        'e', 65,
+        'i', 113,
+        'm', 49,
+        'o', 149,
+        'p', 43,
+        'r', 145,
+        's', 70,
+        '}', 225,
+        0xb5, 14,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(202);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(413);
+      END_STATE();
+    case 203:
+      ACCEPT_TOKEN(ts_builtin_sym_end);
+      END_STATE();
+    case 204:
+      ACCEPT_TOKEN(anon_sym_SEMI);
+      END_STATE();
+    case 205:
+      ACCEPT_TOKEN(anon_sym_edition);
+      END_STATE();
+    case 206:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      END_STATE();
+    case 207:
+      ACCEPT_TOKEN(anon_sym_syntax);
+      END_STATE();
+    case 208:
+      ACCEPT_TOKEN(anon_sym_DQUOTEproto3_DQUOTE);
+      END_STATE();
+    case 209:
+      ACCEPT_TOKEN(anon_sym_DQUOTEproto2_DQUOTE);
+      END_STATE();
+    case 210:
+      ACCEPT_TOKEN(anon_sym_import);
+      END_STATE();
+    case 211:
+      ACCEPT_TOKEN(anon_sym_weak);
+      END_STATE();
+    case 212:
+      ACCEPT_TOKEN(anon_sym_public);
+      END_STATE();
+    case 213:
+      ACCEPT_TOKEN(anon_sym_package);
+      END_STATE();
+    case 214:
+      ACCEPT_TOKEN(anon_sym_option);
+      END_STATE();
+    case 215:
+      ACCEPT_TOKEN(anon_sym_option);
+      if (lookahead == 'a') ADVANCE(349);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 216:
+      ACCEPT_TOKEN(anon_sym_option);
+      if (lookahead == 'a') ADVANCE(109);
+      END_STATE();
+    case 217:
+      ACCEPT_TOKEN(anon_sym_option);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 218:
+      ACCEPT_TOKEN(anon_sym_LPAREN);
+      END_STATE();
+    case 219:
+      ACCEPT_TOKEN(anon_sym_RPAREN);
+      END_STATE();
+    case 220:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      END_STATE();
+    case 221:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(420);
+      END_STATE();
+    case 222:
+      ACCEPT_TOKEN(anon_sym_enum);
+      END_STATE();
+    case 223:
+      ACCEPT_TOKEN(anon_sym_enum);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 224:
+      ACCEPT_TOKEN(anon_sym_LBRACE);
+      END_STATE();
+    case 225:
+      ACCEPT_TOKEN(anon_sym_RBRACE);
+      END_STATE();
+    case 226:
+      ACCEPT_TOKEN(anon_sym_LBRACK);
+      END_STATE();
+    case 227:
+      ACCEPT_TOKEN(anon_sym_COMMA);
+      END_STATE();
+    case 228:
+      ACCEPT_TOKEN(anon_sym_RBRACK);
+      END_STATE();
+    case 229:
+      ACCEPT_TOKEN(anon_sym_DASH);
+      END_STATE();
+    case 230:
+      ACCEPT_TOKEN(anon_sym_message);
+      END_STATE();
+    case 231:
+      ACCEPT_TOKEN(anon_sym_message);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 232:
+      ACCEPT_TOKEN(anon_sym_extend);
+      END_STATE();
+    case 233:
+      ACCEPT_TOKEN(anon_sym_extend);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 234:
+      ACCEPT_TOKEN(anon_sym_optional);
+      END_STATE();
+    case 235:
+      ACCEPT_TOKEN(anon_sym_optional);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 236:
+      ACCEPT_TOKEN(anon_sym_required);
+      END_STATE();
+    case 237:
+      ACCEPT_TOKEN(anon_sym_required);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 238:
+      ACCEPT_TOKEN(anon_sym_repeated);
+      END_STATE();
+    case 239:
+      ACCEPT_TOKEN(anon_sym_repeated);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 240:
+      ACCEPT_TOKEN(anon_sym_oneof);
+      END_STATE();
+    case 241:
+      ACCEPT_TOKEN(anon_sym_oneof);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 242:
+      ACCEPT_TOKEN(anon_sym_map);
+      END_STATE();
+    case 243:
+      ACCEPT_TOKEN(anon_sym_map);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 244:
+      ACCEPT_TOKEN(anon_sym_LT);
+      END_STATE();
+    case 245:
+      ACCEPT_TOKEN(anon_sym_GT);
+      END_STATE();
+    case 246:
+      ACCEPT_TOKEN(anon_sym_int32);
+      END_STATE();
+    case 247:
+      ACCEPT_TOKEN(anon_sym_int32);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 248:
+      ACCEPT_TOKEN(anon_sym_int64);
+      END_STATE();
+    case 249:
+      ACCEPT_TOKEN(anon_sym_int64);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 250:
+      ACCEPT_TOKEN(anon_sym_uint32);
+      END_STATE();
+    case 251:
+      ACCEPT_TOKEN(anon_sym_uint32);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 252:
+      ACCEPT_TOKEN(anon_sym_uint64);
+      END_STATE();
+    case 253:
+      ACCEPT_TOKEN(anon_sym_uint64);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 254:
+      ACCEPT_TOKEN(anon_sym_sint32);
+      END_STATE();
+    case 255:
+      ACCEPT_TOKEN(anon_sym_sint32);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 256:
+      ACCEPT_TOKEN(anon_sym_sint64);
+      END_STATE();
+    case 257:
+      ACCEPT_TOKEN(anon_sym_sint64);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 258:
+      ACCEPT_TOKEN(anon_sym_fixed32);
+      END_STATE();
+    case 259:
+      ACCEPT_TOKEN(anon_sym_fixed32);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 260:
+      ACCEPT_TOKEN(anon_sym_fixed64);
+      END_STATE();
+    case 261:
+      ACCEPT_TOKEN(anon_sym_fixed64);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 262:
+      ACCEPT_TOKEN(anon_sym_sfixed32);
+      END_STATE();
+    case 263:
+      ACCEPT_TOKEN(anon_sym_sfixed32);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 264:
+      ACCEPT_TOKEN(anon_sym_sfixed64);
+      END_STATE();
+    case 265:
+      ACCEPT_TOKEN(anon_sym_sfixed64);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 266:
+      ACCEPT_TOKEN(anon_sym_bool);
+      END_STATE();
+    case 267:
+      ACCEPT_TOKEN(anon_sym_bool);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 268:
+      ACCEPT_TOKEN(anon_sym_string);
+      END_STATE();
+    case 269:
+      ACCEPT_TOKEN(anon_sym_string);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 270:
+      ACCEPT_TOKEN(anon_sym_double);
+      END_STATE();
+    case 271:
+      ACCEPT_TOKEN(anon_sym_double);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 272:
+      ACCEPT_TOKEN(anon_sym_float);
+      END_STATE();
+    case 273:
+      ACCEPT_TOKEN(anon_sym_float);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 274:
+      ACCEPT_TOKEN(anon_sym_bytes);
+      END_STATE();
+    case 275:
+      ACCEPT_TOKEN(anon_sym_bytes);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 276:
+      ACCEPT_TOKEN(anon_sym_reserved);
+      END_STATE();
+    case 277:
+      ACCEPT_TOKEN(anon_sym_reserved);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 278:
+      ACCEPT_TOKEN(anon_sym_extensions);
+      END_STATE();
+    case 279:
+      ACCEPT_TOKEN(anon_sym_extensions);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 280:
+      ACCEPT_TOKEN(anon_sym_to);
+      END_STATE();
+    case 281:
+      ACCEPT_TOKEN(sym_max);
+      END_STATE();
+    case 282:
+      ACCEPT_TOKEN(anon_sym_service);
+      END_STATE();
+    case 283:
+      ACCEPT_TOKEN(anon_sym_rpc);
+      END_STATE();
+    case 284:
+      ACCEPT_TOKEN(anon_sym_returns);
+      END_STATE();
+    case 285:
+      ACCEPT_TOKEN(sym_stream);
+      END_STATE();
+    case 286:
+      ACCEPT_TOKEN(sym_stream);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 287:
+      ACCEPT_TOKEN(anon_sym_PLUS);
+      END_STATE();
+    case 288:
+      ACCEPT_TOKEN(anon_sym_COLON);
+      END_STATE();
+    case 289:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == '2') ADVANCE(247);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 290:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == '2') ADVANCE(255);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 291:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == '2') ADVANCE(251);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 292:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == '2') ADVANCE(259);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 293:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == '2') ADVANCE(263);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 294:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == '3') ADVANCE(289);
+      if (lookahead == '6') ADVANCE(299);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 295:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == '3') ADVANCE(290);
+      if (lookahead == '6') ADVANCE(300);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 296:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == '3') ADVANCE(291);
+      if (lookahead == '6') ADVANCE(301);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 297:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == '3') ADVANCE(292);
+      if (lookahead == '6') ADVANCE(302);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 298:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == '3') ADVANCE(293);
+      if (lookahead == '6') ADVANCE(303);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 299:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == '4') ADVANCE(249);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 300:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == '4') ADVANCE(257);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 301:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == '4') ADVANCE(253);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 302:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == '4') ADVANCE(261);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 303:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == '4') ADVANCE(265);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 304:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'a') ADVANCE(374);
+      if (lookahead == 'e') ADVANCE(384);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 305:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'a') ADVANCE(339);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 306:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'a') ADVANCE(353);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 307:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'a') ADVANCE(389);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 308:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'a') ADVANCE(350);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 309:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'a') ADVANCE(354);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 310:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'a') ADVANCE(393);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 311:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'b') ADVANCE(351);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 312:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'd') ADVANCE(233);
+      if (lookahead == 's') ADVANCE(344);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 313:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'd') ADVANCE(239);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 314:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'd') ADVANCE(237);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 315:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'd') ADVANCE(277);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 316:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'd') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 317:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'd') ADVANCE(298);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 318:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'e') ADVANCE(375);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 319:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'e') ADVANCE(316);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 320:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'e') ADVANCE(271);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 321:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'e') ADVANCE(231);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 322:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'e') ADVANCE(409);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 323:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'e') ADVANCE(411);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 324:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'e') ADVANCE(355);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 325:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'e') ADVANCE(377);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 326:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'e') ADVANCE(313);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 327:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'e') ADVANCE(382);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 328:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'e') ADVANCE(310);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 329:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'e') ADVANCE(314);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 330:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'e') ADVANCE(369);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 331:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'e') ADVANCE(315);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 332:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'e') ADVANCE(306);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 333:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'e') ADVANCE(386);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 334:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'e') ADVANCE(317);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 335:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'f') ADVANCE(405);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 336:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'f') ADVANCE(241);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 337:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'f') ADVANCE(347);
+      if (lookahead == 'i') ADVANCE(364);
+      if (lookahead == 't') ADVANCE(378);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 338:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'g') ADVANCE(269);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 339:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'g') ADVANCE(321);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 340:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'i') ADVANCE(403);
+      if (lookahead == 'l') ADVANCE(368);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 341:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'i') ADVANCE(362);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 342:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'i') ADVANCE(380);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 343:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'i') ADVANCE(371);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 344:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'i') ADVANCE(372);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 345:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'i') ADVANCE(373);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 346:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'i') ADVANCE(365);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 347:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'i') ADVANCE(404);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 348:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'l') ADVANCE(267);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 349:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'l') ADVANCE(235);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 350:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'l') ADVANCE(387);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 351:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'l') ADVANCE(320);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 352:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'm') ADVANCE(223);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 353:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'm') ADVANCE(286);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 354:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'n') ADVANCE(405);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 355:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'n') ADVANCE(312);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 356:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'n') ADVANCE(215);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 357:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'n') ADVANCE(217);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 358:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'n') ADVANCE(330);
+      if (lookahead == 'p') ADVANCE(391);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 359:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'n') ADVANCE(388);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 360:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'n') ADVANCE(399);
+      if (lookahead == 'x') ADVANCE(392);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 361:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'n') ADVANCE(335);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 362:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'n') ADVANCE(338);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 363:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'n') ADVANCE(383);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 364:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'n') ADVANCE(394);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 365:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'n') ADVANCE(396);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 366:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'o') ADVANCE(398);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 367:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'o') ADVANCE(348);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 368:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'o') ADVANCE(307);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 369:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'o') ADVANCE(336);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 370:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'o') ADVANCE(367);
+      if (lookahead == 'y') ADVANCE(390);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 371:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'o') ADVANCE(356);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 372:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'o') ADVANCE(363);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 373:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'o') ADVANCE(357);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 374:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'p') ADVANCE(243);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 375:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'p') ADVANCE(328);
+      if (lookahead == 'q') ADVANCE(400);
+      if (lookahead == 's') ADVANCE(325);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 376:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'p') ADVANCE(397);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 377:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'r') ADVANCE(402);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 378:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'r') ADVANCE(341);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 379:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'r') ADVANCE(401);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 380:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'r') ADVANCE(329);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 381:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'r') ADVANCE(332);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 382:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 's') ADVANCE(275);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 383:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 's') ADVANCE(279);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 384:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 's') ADVANCE(385);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 385:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 's') ADVANCE(305);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 386:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 's') ADVANCE(325);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 387:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 's') ADVANCE(323);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 388:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 't') ADVANCE(294);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 389:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 't') ADVANCE(273);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 390:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 't') ADVANCE(327);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 391:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 't') ADVANCE(343);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 392:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 't') ADVANCE(324);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 393:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 't') ADVANCE(326);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 394:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 't') ADVANCE(295);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 395:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 't') ADVANCE(381);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 396:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 't') ADVANCE(296);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 397:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 't') ADVANCE(345);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 398:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'u') ADVANCE(311);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 399:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'u') ADVANCE(352);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 400:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'u') ADVANCE(342);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 401:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'u') ADVANCE(322);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 402:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'v') ADVANCE(331);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 403:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'x') ADVANCE(319);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 404:
+      ACCEPT_TOKEN(sym__identifier);
+      if (lookahead == 'x') ADVANCE(334);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 405:
+      ACCEPT_TOKEN(sym__identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 406:
+      ACCEPT_TOKEN(sym_reserved_identifier);
+      END_STATE();
+    case 407:
+      ACCEPT_TOKEN(sym_reserved_identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(407);
+      END_STATE();
+    case 408:
+      ACCEPT_TOKEN(sym_true);
+      END_STATE();
+    case 409:
+      ACCEPT_TOKEN(sym_true);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 410:
+      ACCEPT_TOKEN(sym_false);
+      END_STATE();
+    case 411:
+      ACCEPT_TOKEN(sym_false);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(405);
+      END_STATE();
+    case 412:
+      ACCEPT_TOKEN(sym_decimal_lit);
+      if (lookahead == '.') ADVANCE(420);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(187);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(412);
+      END_STATE();
+    case 413:
+      ACCEPT_TOKEN(sym_decimal_lit);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(413);
+      END_STATE();
+    case 414:
+      ACCEPT_TOKEN(sym_octal_lit);
+      if (lookahead == '.') ADVANCE(420);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(187);
+      if (lookahead == 'X' ||
+          lookahead == 'x') ADVANCE(191);
+      if (lookahead == '8' ||
+          lookahead == '9') ADVANCE(13);
+      if (('0' <= lookahead && lookahead <= '7')) ADVANCE(415);
+      END_STATE();
+    case 415:
+      ACCEPT_TOKEN(sym_octal_lit);
+      if (lookahead == '.') ADVANCE(420);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(187);
+      if (lookahead == '8' ||
+          lookahead == '9') ADVANCE(13);
+      if (('0' <= lookahead && lookahead <= '7')) ADVANCE(415);
+      END_STATE();
+    case 416:
+      ACCEPT_TOKEN(sym_octal_lit);
+      if (lookahead == 'X' ||
+          lookahead == 'x') ADVANCE(191);
+      if (('0' <= lookahead && lookahead <= '7')) ADVANCE(417);
+      END_STATE();
+    case 417:
+      ACCEPT_TOKEN(sym_octal_lit);
+      if (('0' <= lookahead && lookahead <= '7')) ADVANCE(417);
+      END_STATE();
+    case 418:
+      ACCEPT_TOKEN(sym_hex_lit);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(418);
+      END_STATE();
+    case 419:
+      ACCEPT_TOKEN(sym_float_lit);
+      END_STATE();
+    case 420:
+      ACCEPT_TOKEN(sym_float_lit);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(187);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(420);
+      END_STATE();
+    case 421:
+      ACCEPT_TOKEN(sym_float_lit);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(421);
+      END_STATE();
+    case 422:
+      ACCEPT_TOKEN(anon_sym_DQUOTE);
+      END_STATE();
+    case 423:
+      ACCEPT_TOKEN(aux_sym_string_token1);
+      if (lookahead == '\n') ADVANCE(428);
+      if (lookahead != 0 &&
+          lookahead != '"' &&
+          lookahead != '\\') ADVANCE(423);
+      END_STATE();
+    case 424:
+      ACCEPT_TOKEN(aux_sym_string_token1);
+      if (lookahead == '*') ADVANCE(426);
+      if (lookahead == '/') ADVANCE(423);
+      if (lookahead != 0 &&
+          lookahead != '"' &&
+          lookahead != '\\') ADVANCE(428);
+      END_STATE();
+    case 425:
+      ACCEPT_TOKEN(aux_sym_string_token1);
+      if (lookahead == '*') ADVANCE(425);
+      if (lookahead == '/') ADVANCE(428);
+      if (lookahead != 0 &&
+          lookahead != '"' &&
+          lookahead != '\\') ADVANCE(426);
+      END_STATE();
+    case 426:
+      ACCEPT_TOKEN(aux_sym_string_token1);
+      if (lookahead == '*') ADVANCE(425);
+      if (lookahead != 0 &&
+          lookahead != '"' &&
+          lookahead != '\\') ADVANCE(426);
+      END_STATE();
+    case 427:
+      ACCEPT_TOKEN(aux_sym_string_token1);
+      if (lookahead == '/') ADVANCE(424);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(427);
+      if (lookahead != 0 &&
+          lookahead != '"' &&
+          lookahead != '\\') ADVANCE(428);
+      END_STATE();
+    case 428:
+      ACCEPT_TOKEN(aux_sym_string_token1);
+      if (lookahead != 0 &&
+          lookahead != '"' &&
+          lookahead != '\\') ADVANCE(428);
+      END_STATE();
+    case 429:
+      ACCEPT_TOKEN(anon_sym_SQUOTE);
+      END_STATE();
+    case 430:
+      ACCEPT_TOKEN(aux_sym_string_token2);
+      if (lookahead == '\n') ADVANCE(435);
+      if (lookahead != 0 &&
+          lookahead != '\'' &&
+          lookahead != '\\') ADVANCE(430);
+      END_STATE();
+    case 431:
+      ACCEPT_TOKEN(aux_sym_string_token2);
+      if (lookahead == '*') ADVANCE(433);
+      if (lookahead == '/') ADVANCE(430);
+      if (lookahead != 0 &&
+          lookahead != '\'' &&
+          lookahead != '\\') ADVANCE(435);
+      END_STATE();
+    case 432:
+      ACCEPT_TOKEN(aux_sym_string_token2);
+      if (lookahead == '*') ADVANCE(432);
+      if (lookahead == '/') ADVANCE(435);
+      if (lookahead != 0 &&
+          lookahead != '\'' &&
+          lookahead != '\\') ADVANCE(433);
+      END_STATE();
+    case 433:
+      ACCEPT_TOKEN(aux_sym_string_token2);
+      if (lookahead == '*') ADVANCE(432);
+      if (lookahead != 0 &&
+          lookahead != '\'' &&
+          lookahead != '\\') ADVANCE(433);
+      END_STATE();
+    case 434:
+      ACCEPT_TOKEN(aux_sym_string_token2);
+      if (lookahead == '/') ADVANCE(431);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(434);
+      if (lookahead != 0 &&
+          lookahead != '\'' &&
+          lookahead != '\\') ADVANCE(435);
+      END_STATE();
+    case 435:
+      ACCEPT_TOKEN(aux_sym_string_token2);
+      if (lookahead != 0 &&
+          lookahead != '\'' &&
+          lookahead != '\\') ADVANCE(435);
+      END_STATE();
+    case 436:
+      ACCEPT_TOKEN(sym_escape_sequence);
+      END_STATE();
+    case 437:
+      ACCEPT_TOKEN(sym_escape_sequence);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(436);
+      END_STATE();
+    case 438:
+      ACCEPT_TOKEN(sym_escape_sequence);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(437);
+      END_STATE();
+    case 439:
+      ACCEPT_TOKEN(sym_grit_metavariable);
+      END_STATE();
+    case 440:
+      ACCEPT_TOKEN(sym_grit_metavariable);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(440);
+      END_STATE();
+    case 441:
+      ACCEPT_TOKEN(sym_comment);
+      END_STATE();
+    case 442:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(442);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static const TSLexMode ts_lex_modes[STATE_COUNT] = {
+  [0] = {.lex_state = 0},
+  [1] = {.lex_state = 202},
+  [2] = {.lex_state = 17},
+  [3] = {.lex_state = 17},
+  [4] = {.lex_state = 17},
+  [5] = {.lex_state = 17},
+  [6] = {.lex_state = 17},
+  [7] = {.lex_state = 17},
+  [8] = {.lex_state = 17},
+  [9] = {.lex_state = 17},
+  [10] = {.lex_state = 17},
+  [11] = {.lex_state = 17},
+  [12] = {.lex_state = 17},
+  [13] = {.lex_state = 17},
+  [14] = {.lex_state = 17},
+  [15] = {.lex_state = 17},
+  [16] = {.lex_state = 17},
+  [17] = {.lex_state = 17},
+  [18] = {.lex_state = 17},
+  [19] = {.lex_state = 17},
+  [20] = {.lex_state = 17},
+  [21] = {.lex_state = 17},
+  [22] = {.lex_state = 17},
+  [23] = {.lex_state = 17},
+  [24] = {.lex_state = 17},
+  [25] = {.lex_state = 17},
+  [26] = {.lex_state = 17},
+  [27] = {.lex_state = 16},
+  [28] = {.lex_state = 16},
+  [29] = {.lex_state = 16},
+  [30] = {.lex_state = 2},
+  [31] = {.lex_state = 2},
+  [32] = {.lex_state = 2},
+  [33] = {.lex_state = 16},
+  [34] = {.lex_state = 2},
+  [35] = {.lex_state = 16},
+  [36] = {.lex_state = 2},
+  [37] = {.lex_state = 18},
+  [38] = {.lex_state = 2},
+  [39] = {.lex_state = 2},
+  [40] = {.lex_state = 2},
+  [41] = {.lex_state = 18},
+  [42] = {.lex_state = 16},
+  [43] = {.lex_state = 16},
+  [44] = {.lex_state = 2},
+  [45] = {.lex_state = 2},
+  [46] = {.lex_state = 2},
+  [47] = {.lex_state = 2},
+  [48] = {.lex_state = 2},
+  [49] = {.lex_state = 2},
+  [50] = {.lex_state = 16},
+  [51] = {.lex_state = 16},
+  [52] = {.lex_state = 16},
+  [53] = {.lex_state = 2},
+  [54] = {.lex_state = 202},
+  [55] = {.lex_state = 18},
+  [56] = {.lex_state = 202},
+  [57] = {.lex_state = 202},
+  [58] = {.lex_state = 202},
+  [59] = {.lex_state = 2},
+  [60] = {.lex_state = 2},
+  [61] = {.lex_state = 1},
+  [62] = {.lex_state = 0},
+  [63] = {.lex_state = 1},
+  [64] = {.lex_state = 22},
+  [65] = {.lex_state = 1},
+  [66] = {.lex_state = 22},
+  [67] = {.lex_state = 22},
+  [68] = {.lex_state = 22},
+  [69] = {.lex_state = 22},
+  [70] = {.lex_state = 202},
+  [71] = {.lex_state = 1},
+  [72] = {.lex_state = 1},
+  [73] = {.lex_state = 202},
+  [74] = {.lex_state = 1},
+  [75] = {.lex_state = 1},
+  [76] = {.lex_state = 1},
+  [77] = {.lex_state = 6},
+  [78] = {.lex_state = 202},
+  [79] = {.lex_state = 202},
+  [80] = {.lex_state = 1},
+  [81] = {.lex_state = 202},
+  [82] = {.lex_state = 6},
+  [83] = {.lex_state = 1},
+  [84] = {.lex_state = 202},
+  [85] = {.lex_state = 202},
+  [86] = {.lex_state = 202},
+  [87] = {.lex_state = 202},
+  [88] = {.lex_state = 202},
+  [89] = {.lex_state = 202},
+  [90] = {.lex_state = 202},
+  [91] = {.lex_state = 202},
+  [92] = {.lex_state = 202},
+  [93] = {.lex_state = 202},
+  [94] = {.lex_state = 202},
+  [95] = {.lex_state = 202},
+  [96] = {.lex_state = 1},
+  [97] = {.lex_state = 202},
+  [98] = {.lex_state = 202},
+  [99] = {.lex_state = 202},
+  [100] = {.lex_state = 0},
+  [101] = {.lex_state = 19},
+  [102] = {.lex_state = 1},
+  [103] = {.lex_state = 1},
+  [104] = {.lex_state = 202},
+  [105] = {.lex_state = 19},
+  [106] = {.lex_state = 1},
+  [107] = {.lex_state = 1},
+  [108] = {.lex_state = 1},
+  [109] = {.lex_state = 1},
+  [110] = {.lex_state = 202},
+  [111] = {.lex_state = 1},
+  [112] = {.lex_state = 1},
+  [113] = {.lex_state = 19},
+  [114] = {.lex_state = 1},
+  [115] = {.lex_state = 202},
+  [116] = {.lex_state = 202},
+  [117] = {.lex_state = 202},
+  [118] = {.lex_state = 1},
+  [119] = {.lex_state = 1},
+  [120] = {.lex_state = 202},
+  [121] = {.lex_state = 1},
+  [122] = {.lex_state = 202},
+  [123] = {.lex_state = 1},
+  [124] = {.lex_state = 202},
+  [125] = {.lex_state = 202},
+  [126] = {.lex_state = 202},
+  [127] = {.lex_state = 202},
+  [128] = {.lex_state = 202},
+  [129] = {.lex_state = 202},
+  [130] = {.lex_state = 202},
+  [131] = {.lex_state = 202},
+  [132] = {.lex_state = 1},
+  [133] = {.lex_state = 202},
+  [134] = {.lex_state = 22},
+  [135] = {.lex_state = 22},
+  [136] = {.lex_state = 22},
+  [137] = {.lex_state = 1},
+  [138] = {.lex_state = 1},
+  [139] = {.lex_state = 1},
+  [140] = {.lex_state = 1},
+  [141] = {.lex_state = 202},
+  [142] = {.lex_state = 22},
+  [143] = {.lex_state = 1},
+  [144] = {.lex_state = 1},
+  [145] = {.lex_state = 22},
+  [146] = {.lex_state = 1},
+  [147] = {.lex_state = 1},
+  [148] = {.lex_state = 21},
+  [149] = {.lex_state = 22},
+  [150] = {.lex_state = 1},
+  [151] = {.lex_state = 1},
+  [152] = {.lex_state = 22},
+  [153] = {.lex_state = 1},
+  [154] = {.lex_state = 1},
+  [155] = {.lex_state = 21},
+  [156] = {.lex_state = 0},
+  [157] = {.lex_state = 1},
+  [158] = {.lex_state = 0},
+  [159] = {.lex_state = 1},
+  [160] = {.lex_state = 1},
+  [161] = {.lex_state = 1},
+  [162] = {.lex_state = 202},
+  [163] = {.lex_state = 0},
+  [164] = {.lex_state = 1},
+  [165] = {.lex_state = 1},
+  [166] = {.lex_state = 1},
+  [167] = {.lex_state = 8},
+  [168] = {.lex_state = 1},
+  [169] = {.lex_state = 1},
+  [170] = {.lex_state = 1},
+  [171] = {.lex_state = 1},
+  [172] = {.lex_state = 1},
+  [173] = {.lex_state = 202},
+  [174] = {.lex_state = 1},
+  [175] = {.lex_state = 1},
+  [176] = {.lex_state = 1},
+  [177] = {.lex_state = 1},
+  [178] = {.lex_state = 1},
+  [179] = {.lex_state = 1},
+  [180] = {.lex_state = 1},
+  [181] = {.lex_state = 1},
+  [182] = {.lex_state = 1},
+  [183] = {.lex_state = 3},
+  [184] = {.lex_state = 1},
+  [185] = {.lex_state = 3},
+  [186] = {.lex_state = 1},
+  [187] = {.lex_state = 1},
+  [188] = {.lex_state = 202},
+  [189] = {.lex_state = 202},
+  [190] = {.lex_state = 202},
+  [191] = {.lex_state = 202},
+  [192] = {.lex_state = 202},
+  [193] = {.lex_state = 202},
+  [194] = {.lex_state = 202},
+  [195] = {.lex_state = 202},
+  [196] = {.lex_state = 202},
+  [197] = {.lex_state = 202},
+  [198] = {.lex_state = 202},
+  [199] = {.lex_state = 202},
+  [200] = {.lex_state = 1},
+  [201] = {.lex_state = 3},
+  [202] = {.lex_state = 1},
+  [203] = {.lex_state = 8},
+  [204] = {.lex_state = 1},
+  [205] = {.lex_state = 1},
+  [206] = {.lex_state = 1},
+  [207] = {.lex_state = 8},
+  [208] = {.lex_state = 0},
+  [209] = {.lex_state = 0},
+  [210] = {.lex_state = 0},
+  [211] = {.lex_state = 1},
+  [212] = {.lex_state = 0},
+  [213] = {.lex_state = 0},
+  [214] = {.lex_state = 202},
+  [215] = {.lex_state = 202},
+  [216] = {.lex_state = 202},
+  [217] = {.lex_state = 0},
+  [218] = {.lex_state = 0},
+  [219] = {.lex_state = 0},
+  [220] = {.lex_state = 202},
+  [221] = {.lex_state = 0},
+  [222] = {.lex_state = 0},
+  [223] = {.lex_state = 1},
+  [224] = {.lex_state = 0},
+  [225] = {.lex_state = 0},
+  [226] = {.lex_state = 0},
+  [227] = {.lex_state = 0},
+  [228] = {.lex_state = 0},
+  [229] = {.lex_state = 202},
+  [230] = {.lex_state = 1},
+  [231] = {.lex_state = 0},
+  [232] = {.lex_state = 0},
+  [233] = {.lex_state = 0},
+  [234] = {.lex_state = 0},
+  [235] = {.lex_state = 0},
+  [236] = {.lex_state = 0},
+  [237] = {.lex_state = 1},
+  [238] = {.lex_state = 1},
+  [239] = {.lex_state = 1},
+  [240] = {.lex_state = 0},
+  [241] = {.lex_state = 1},
+  [242] = {.lex_state = 0},
+  [243] = {.lex_state = 0},
+  [244] = {.lex_state = 1},
+  [245] = {.lex_state = 0},
+  [246] = {.lex_state = 0},
+  [247] = {.lex_state = 0},
+  [248] = {.lex_state = 0},
+  [249] = {.lex_state = 0},
+  [250] = {.lex_state = 0},
+  [251] = {.lex_state = 0},
+  [252] = {.lex_state = 0},
+  [253] = {.lex_state = 0},
+  [254] = {.lex_state = 0},
+  [255] = {.lex_state = 0},
+  [256] = {.lex_state = 0},
+  [257] = {.lex_state = 0},
+  [258] = {.lex_state = 202},
+  [259] = {.lex_state = 0},
+  [260] = {.lex_state = 0},
+  [261] = {.lex_state = 0},
+  [262] = {.lex_state = 0},
+  [263] = {.lex_state = 0},
+  [264] = {.lex_state = 0},
+  [265] = {.lex_state = 0},
+  [266] = {.lex_state = 0},
+  [267] = {.lex_state = 0},
+  [268] = {.lex_state = 0},
+  [269] = {.lex_state = 0},
+  [270] = {.lex_state = 0},
+  [271] = {.lex_state = 0},
+  [272] = {.lex_state = 0},
+  [273] = {.lex_state = 0},
+  [274] = {.lex_state = 0},
+  [275] = {.lex_state = 0},
+  [276] = {.lex_state = 0},
+  [277] = {.lex_state = 6},
+  [278] = {.lex_state = 0},
+  [279] = {.lex_state = 0},
+  [280] = {.lex_state = 0},
+  [281] = {.lex_state = 0},
+  [282] = {.lex_state = 0},
+  [283] = {.lex_state = 0},
+  [284] = {.lex_state = 0},
+  [285] = {.lex_state = 0},
+  [286] = {.lex_state = 0},
+  [287] = {.lex_state = 0},
+  [288] = {.lex_state = 0},
+  [289] = {.lex_state = 0},
+  [290] = {.lex_state = 0},
+  [291] = {.lex_state = 0},
+  [292] = {.lex_state = 0},
+  [293] = {.lex_state = 0},
+  [294] = {.lex_state = 0},
+  [295] = {.lex_state = 0},
+  [296] = {.lex_state = 0},
+  [297] = {.lex_state = 0},
+  [298] = {.lex_state = 202},
+  [299] = {.lex_state = 0},
+  [300] = {.lex_state = 0},
+  [301] = {.lex_state = 0},
+  [302] = {.lex_state = 0},
+  [303] = {.lex_state = 0},
+  [304] = {.lex_state = 0},
+  [305] = {.lex_state = 0},
+  [306] = {.lex_state = 0},
+  [307] = {.lex_state = 0},
+  [308] = {.lex_state = 0},
+  [309] = {.lex_state = 0},
+  [310] = {.lex_state = 0},
+  [311] = {.lex_state = 0},
+  [312] = {.lex_state = 0},
+  [313] = {.lex_state = 0},
+  [314] = {.lex_state = 0},
+  [315] = {.lex_state = 0},
+  [316] = {.lex_state = 0},
+  [317] = {.lex_state = 0},
+  [318] = {.lex_state = 0},
+  [319] = {.lex_state = 0},
+  [320] = {.lex_state = 0},
+  [321] = {.lex_state = 0},
+  [322] = {.lex_state = 0},
+  [323] = {.lex_state = 0},
+  [324] = {.lex_state = 0},
+  [325] = {.lex_state = 0},
+  [326] = {.lex_state = 0},
+  [327] = {.lex_state = 0},
+  [328] = {.lex_state = 0},
+  [329] = {.lex_state = 0},
+};
+
+static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
+  [0] = {
+    [ts_builtin_sym_end] = ACTIONS(1),
+    [anon_sym_SEMI] = ACTIONS(1),
+    [anon_sym_edition] = ACTIONS(1),
+    [anon_sym_EQ] = ACTIONS(1),
+    [anon_sym_syntax] = ACTIONS(1),
+    [anon_sym_import] = ACTIONS(1),
+    [anon_sym_weak] = ACTIONS(1),
+    [anon_sym_public] = ACTIONS(1),
+    [anon_sym_package] = ACTIONS(1),
+    [anon_sym_option] = ACTIONS(1),
+    [anon_sym_LPAREN] = ACTIONS(1),
+    [anon_sym_RPAREN] = ACTIONS(1),
+    [anon_sym_DOT] = ACTIONS(1),
+    [anon_sym_enum] = ACTIONS(1),
+    [anon_sym_LBRACE] = ACTIONS(1),
+    [anon_sym_RBRACE] = ACTIONS(1),
+    [anon_sym_LBRACK] = ACTIONS(1),
+    [anon_sym_COMMA] = ACTIONS(1),
+    [anon_sym_RBRACK] = ACTIONS(1),
+    [anon_sym_DASH] = ACTIONS(1),
+    [anon_sym_message] = ACTIONS(1),
+    [anon_sym_extend] = ACTIONS(1),
+    [anon_sym_optional] = ACTIONS(1),
+    [anon_sym_required] = ACTIONS(1),
+    [anon_sym_repeated] = ACTIONS(1),
+    [anon_sym_oneof] = ACTIONS(1),
+    [anon_sym_map] = ACTIONS(1),
+    [anon_sym_LT] = ACTIONS(1),
+    [anon_sym_GT] = ACTIONS(1),
+    [anon_sym_int32] = ACTIONS(1),
+    [anon_sym_int64] = ACTIONS(1),
+    [anon_sym_uint32] = ACTIONS(1),
+    [anon_sym_uint64] = ACTIONS(1),
+    [anon_sym_sint32] = ACTIONS(1),
+    [anon_sym_sint64] = ACTIONS(1),
+    [anon_sym_fixed32] = ACTIONS(1),
+    [anon_sym_fixed64] = ACTIONS(1),
+    [anon_sym_sfixed32] = ACTIONS(1),
+    [anon_sym_sfixed64] = ACTIONS(1),
+    [anon_sym_bool] = ACTIONS(1),
+    [anon_sym_string] = ACTIONS(1),
+    [anon_sym_double] = ACTIONS(1),
+    [anon_sym_float] = ACTIONS(1),
+    [anon_sym_bytes] = ACTIONS(1),
+    [anon_sym_reserved] = ACTIONS(1),
+    [anon_sym_extensions] = ACTIONS(1),
+    [anon_sym_to] = ACTIONS(1),
+    [sym_max] = ACTIONS(1),
+    [anon_sym_service] = ACTIONS(1),
+    [anon_sym_rpc] = ACTIONS(1),
+    [anon_sym_returns] = ACTIONS(1),
+    [sym_stream] = ACTIONS(1),
+    [anon_sym_PLUS] = ACTIONS(1),
+    [anon_sym_COLON] = ACTIONS(1),
+    [sym_true] = ACTIONS(1),
+    [sym_false] = ACTIONS(1),
+    [sym_decimal_lit] = ACTIONS(1),
+    [sym_octal_lit] = ACTIONS(1),
+    [sym_hex_lit] = ACTIONS(1),
+    [sym_float_lit] = ACTIONS(1),
+    [anon_sym_DQUOTE] = ACTIONS(1),
+    [anon_sym_SQUOTE] = ACTIONS(1),
+    [sym_escape_sequence] = ACTIONS(1),
+    [sym_grit_metavariable] = ACTIONS(1),
+    [sym_comment] = ACTIONS(3),
+  },
+  [1] = {
+    [sym_source_file] = STATE(286),
+    [sym_empty_statement] = STATE(89),
+    [sym_edition] = STATE(56),
+    [sym_syntax] = STATE(56),
+    [sym_import] = STATE(89),
+    [sym_package] = STATE(89),
+    [sym_option] = STATE(89),
+    [sym_enum] = STATE(89),
+    [sym_message] = STATE(89),
+    [sym_extend] = STATE(89),
+    [sym_service] = STATE(89),
+    [aux_sym_source_file_repeat1] = STATE(57),
+    [ts_builtin_sym_end] = ACTIONS(5),
+    [anon_sym_SEMI] = ACTIONS(7),
+    [anon_sym_edition] = ACTIONS(9),
+    [anon_sym_syntax] = ACTIONS(11),
+    [anon_sym_import] = ACTIONS(13),
+    [anon_sym_package] = ACTIONS(15),
+    [anon_sym_option] = ACTIONS(17),
+    [anon_sym_enum] = ACTIONS(19),
+    [anon_sym_message] = ACTIONS(21),
+    [anon_sym_extend] = ACTIONS(23),
+    [anon_sym_service] = ACTIONS(25),
+    [sym_comment] = ACTIONS(3),
+  },
+};
+
+static const uint16_t ts_small_parse_table[] = {
+  [0] = 23,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(27), 1,
+      anon_sym_SEMI,
+    ACTIONS(29), 1,
+      anon_sym_option,
+    ACTIONS(31), 1,
+      anon_sym_DOT,
+    ACTIONS(33), 1,
+      anon_sym_enum,
+    ACTIONS(35), 1,
+      anon_sym_RBRACE,
+    ACTIONS(37), 1,
+      anon_sym_message,
+    ACTIONS(39), 1,
+      anon_sym_extend,
+    ACTIONS(43), 1,
+      anon_sym_oneof,
+    ACTIONS(45), 1,
+      anon_sym_map,
+    ACTIONS(49), 1,
+      anon_sym_reserved,
+    ACTIONS(51), 1,
+      anon_sym_extensions,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    STATE(5), 1,
+      aux_sym_message_body_repeat1,
+    STATE(37), 1,
+      sym_field_label,
+    STATE(161), 1,
+      sym_identifier,
+    STATE(170), 1,
+      aux_sym_message_or_enum_type_repeat1,
+    STATE(223), 1,
+      sym_type,
+    STATE(239), 1,
+      sym_message_or_enum_type,
+    ACTIONS(41), 3,
+      anon_sym_optional,
+      anon_sym_required,
+      anon_sym_repeated,
+    STATE(9), 10,
+      sym_empty_statement,
+      sym_option,
+      sym_enum,
+      sym_message,
+      sym_extend,
+      sym_field,
+      sym_oneof,
+      sym_map_field,
+      sym_reserved,
+      sym_extensions,
+    ACTIONS(47), 15,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+  [95] = 23,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(27), 1,
+      anon_sym_SEMI,
+    ACTIONS(29), 1,
+      anon_sym_option,
+    ACTIONS(31), 1,
+      anon_sym_DOT,
+    ACTIONS(33), 1,
+      anon_sym_enum,
+    ACTIONS(37), 1,
+      anon_sym_message,
+    ACTIONS(39), 1,
+      anon_sym_extend,
+    ACTIONS(43), 1,
+      anon_sym_oneof,
+    ACTIONS(45), 1,
+      anon_sym_map,
+    ACTIONS(49), 1,
+      anon_sym_reserved,
+    ACTIONS(51), 1,
+      anon_sym_extensions,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(57), 1,
+      anon_sym_RBRACE,
+    STATE(4), 1,
+      aux_sym_message_body_repeat1,
+    STATE(37), 1,
+      sym_field_label,
+    STATE(161), 1,
+      sym_identifier,
+    STATE(170), 1,
+      aux_sym_message_or_enum_type_repeat1,
+    STATE(223), 1,
+      sym_type,
+    STATE(239), 1,
+      sym_message_or_enum_type,
+    ACTIONS(41), 3,
+      anon_sym_optional,
+      anon_sym_required,
+      anon_sym_repeated,
+    STATE(9), 10,
+      sym_empty_statement,
+      sym_option,
+      sym_enum,
+      sym_message,
+      sym_extend,
+      sym_field,
+      sym_oneof,
+      sym_map_field,
+      sym_reserved,
+      sym_extensions,
+    ACTIONS(47), 15,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+  [190] = 23,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(27), 1,
+      anon_sym_SEMI,
+    ACTIONS(29), 1,
+      anon_sym_option,
+    ACTIONS(31), 1,
+      anon_sym_DOT,
+    ACTIONS(33), 1,
+      anon_sym_enum,
+    ACTIONS(37), 1,
+      anon_sym_message,
+    ACTIONS(39), 1,
+      anon_sym_extend,
+    ACTIONS(43), 1,
+      anon_sym_oneof,
+    ACTIONS(45), 1,
+      anon_sym_map,
+    ACTIONS(49), 1,
+      anon_sym_reserved,
+    ACTIONS(51), 1,
+      anon_sym_extensions,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(59), 1,
+      anon_sym_RBRACE,
+    STATE(5), 1,
+      aux_sym_message_body_repeat1,
+    STATE(37), 1,
+      sym_field_label,
+    STATE(161), 1,
+      sym_identifier,
+    STATE(170), 1,
+      aux_sym_message_or_enum_type_repeat1,
+    STATE(223), 1,
+      sym_type,
+    STATE(239), 1,
+      sym_message_or_enum_type,
+    ACTIONS(41), 3,
+      anon_sym_optional,
+      anon_sym_required,
+      anon_sym_repeated,
+    STATE(9), 10,
+      sym_empty_statement,
+      sym_option,
+      sym_enum,
+      sym_message,
+      sym_extend,
+      sym_field,
+      sym_oneof,
+      sym_map_field,
+      sym_reserved,
+      sym_extensions,
+    ACTIONS(47), 15,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+  [285] = 23,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(61), 1,
+      anon_sym_SEMI,
+    ACTIONS(64), 1,
+      anon_sym_option,
+    ACTIONS(67), 1,
+      anon_sym_DOT,
+    ACTIONS(70), 1,
+      anon_sym_enum,
+    ACTIONS(73), 1,
+      anon_sym_RBRACE,
+    ACTIONS(75), 1,
+      anon_sym_message,
+    ACTIONS(78), 1,
+      anon_sym_extend,
+    ACTIONS(84), 1,
+      anon_sym_oneof,
+    ACTIONS(87), 1,
+      anon_sym_map,
+    ACTIONS(93), 1,
+      anon_sym_reserved,
+    ACTIONS(96), 1,
+      anon_sym_extensions,
+    ACTIONS(99), 1,
+      sym__identifier,
+    ACTIONS(102), 1,
+      sym_grit_metavariable,
+    STATE(5), 1,
+      aux_sym_message_body_repeat1,
+    STATE(37), 1,
+      sym_field_label,
+    STATE(161), 1,
+      sym_identifier,
+    STATE(170), 1,
+      aux_sym_message_or_enum_type_repeat1,
+    STATE(223), 1,
+      sym_type,
+    STATE(239), 1,
+      sym_message_or_enum_type,
+    ACTIONS(81), 3,
+      anon_sym_optional,
+      anon_sym_required,
+      anon_sym_repeated,
+    STATE(9), 10,
+      sym_empty_statement,
+      sym_option,
+      sym_enum,
+      sym_message,
+      sym_extend,
+      sym_field,
+      sym_oneof,
+      sym_map_field,
+      sym_reserved,
+      sym_extensions,
+    ACTIONS(90), 15,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+  [380] = 23,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(27), 1,
+      anon_sym_SEMI,
+    ACTIONS(29), 1,
+      anon_sym_option,
+    ACTIONS(31), 1,
+      anon_sym_DOT,
+    ACTIONS(33), 1,
+      anon_sym_enum,
+    ACTIONS(37), 1,
+      anon_sym_message,
+    ACTIONS(39), 1,
+      anon_sym_extend,
+    ACTIONS(43), 1,
+      anon_sym_oneof,
+    ACTIONS(45), 1,
+      anon_sym_map,
+    ACTIONS(49), 1,
+      anon_sym_reserved,
+    ACTIONS(51), 1,
+      anon_sym_extensions,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(105), 1,
+      anon_sym_RBRACE,
+    STATE(2), 1,
+      aux_sym_message_body_repeat1,
+    STATE(37), 1,
+      sym_field_label,
+    STATE(161), 1,
+      sym_identifier,
+    STATE(170), 1,
+      aux_sym_message_or_enum_type_repeat1,
+    STATE(223), 1,
+      sym_type,
+    STATE(239), 1,
+      sym_message_or_enum_type,
+    ACTIONS(41), 3,
+      anon_sym_optional,
+      anon_sym_required,
+      anon_sym_repeated,
+    STATE(9), 10,
+      sym_empty_statement,
+      sym_option,
+      sym_enum,
+      sym_message,
+      sym_extend,
+      sym_field,
+      sym_oneof,
+      sym_map_field,
+      sym_reserved,
+      sym_extensions,
+    ACTIONS(47), 15,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+  [475] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(107), 4,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(109), 27,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_optional,
+      anon_sym_required,
+      anon_sym_repeated,
+      anon_sym_oneof,
+      anon_sym_map,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      anon_sym_reserved,
+      anon_sym_extensions,
+      sym__identifier,
+  [514] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(111), 4,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(113), 27,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_optional,
+      anon_sym_required,
+      anon_sym_repeated,
+      anon_sym_oneof,
+      anon_sym_map,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      anon_sym_reserved,
+      anon_sym_extensions,
+      sym__identifier,
+  [553] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(115), 4,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(117), 27,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_optional,
+      anon_sym_required,
+      anon_sym_repeated,
+      anon_sym_oneof,
+      anon_sym_map,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      anon_sym_reserved,
+      anon_sym_extensions,
+      sym__identifier,
+  [592] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(119), 4,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(121), 27,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_optional,
+      anon_sym_required,
+      anon_sym_repeated,
+      anon_sym_oneof,
+      anon_sym_map,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      anon_sym_reserved,
+      anon_sym_extensions,
+      sym__identifier,
+  [631] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(123), 4,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(125), 27,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_optional,
+      anon_sym_required,
+      anon_sym_repeated,
+      anon_sym_oneof,
+      anon_sym_map,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      anon_sym_reserved,
+      anon_sym_extensions,
+      sym__identifier,
+  [670] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(127), 4,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(129), 27,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_optional,
+      anon_sym_required,
+      anon_sym_repeated,
+      anon_sym_oneof,
+      anon_sym_map,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      anon_sym_reserved,
+      anon_sym_extensions,
+      sym__identifier,
+  [709] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(131), 4,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(133), 27,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_optional,
+      anon_sym_required,
+      anon_sym_repeated,
+      anon_sym_oneof,
+      anon_sym_map,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      anon_sym_reserved,
+      anon_sym_extensions,
+      sym__identifier,
+  [748] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(135), 4,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(137), 27,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_optional,
+      anon_sym_required,
+      anon_sym_repeated,
+      anon_sym_oneof,
+      anon_sym_map,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      anon_sym_reserved,
+      anon_sym_extensions,
+      sym__identifier,
+  [787] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(139), 4,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(141), 27,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_optional,
+      anon_sym_required,
+      anon_sym_repeated,
+      anon_sym_oneof,
+      anon_sym_map,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      anon_sym_reserved,
+      anon_sym_extensions,
+      sym__identifier,
+  [826] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(143), 4,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(145), 27,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_optional,
+      anon_sym_required,
+      anon_sym_repeated,
+      anon_sym_oneof,
+      anon_sym_map,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      anon_sym_reserved,
+      anon_sym_extensions,
+      sym__identifier,
+  [865] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(147), 4,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(149), 27,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_optional,
+      anon_sym_required,
+      anon_sym_repeated,
+      anon_sym_oneof,
+      anon_sym_map,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      anon_sym_reserved,
+      anon_sym_extensions,
+      sym__identifier,
+  [904] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(151), 4,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(153), 27,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_optional,
+      anon_sym_required,
+      anon_sym_repeated,
+      anon_sym_oneof,
+      anon_sym_map,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      anon_sym_reserved,
+      anon_sym_extensions,
+      sym__identifier,
+  [943] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(155), 4,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(157), 27,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_optional,
+      anon_sym_required,
+      anon_sym_repeated,
+      anon_sym_oneof,
+      anon_sym_map,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      anon_sym_reserved,
+      anon_sym_extensions,
+      sym__identifier,
+  [982] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(159), 4,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(161), 27,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_optional,
+      anon_sym_required,
+      anon_sym_repeated,
+      anon_sym_oneof,
+      anon_sym_map,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      anon_sym_reserved,
+      anon_sym_extensions,
+      sym__identifier,
+  [1021] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(163), 4,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(165), 27,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_optional,
+      anon_sym_required,
+      anon_sym_repeated,
+      anon_sym_oneof,
+      anon_sym_map,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      anon_sym_reserved,
+      anon_sym_extensions,
+      sym__identifier,
+  [1060] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(167), 4,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(169), 27,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_optional,
+      anon_sym_required,
+      anon_sym_repeated,
+      anon_sym_oneof,
+      anon_sym_map,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      anon_sym_reserved,
+      anon_sym_extensions,
+      sym__identifier,
+  [1099] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(171), 4,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(173), 27,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_optional,
+      anon_sym_required,
+      anon_sym_repeated,
+      anon_sym_oneof,
+      anon_sym_map,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      anon_sym_reserved,
+      anon_sym_extensions,
+      sym__identifier,
+  [1138] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(175), 4,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(177), 27,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_optional,
+      anon_sym_required,
+      anon_sym_repeated,
+      anon_sym_oneof,
+      anon_sym_map,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      anon_sym_reserved,
+      anon_sym_extensions,
+      sym__identifier,
+  [1177] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(179), 4,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(181), 27,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_optional,
+      anon_sym_required,
+      anon_sym_repeated,
+      anon_sym_oneof,
+      anon_sym_map,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      anon_sym_reserved,
+      anon_sym_extensions,
+      sym__identifier,
+  [1216] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(183), 4,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(185), 27,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_optional,
+      anon_sym_required,
+      anon_sym_repeated,
+      anon_sym_oneof,
+      anon_sym_map,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      anon_sym_reserved,
+      anon_sym_extensions,
+      sym__identifier,
+  [1255] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(31), 1,
+      anon_sym_DOT,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(187), 1,
+      anon_sym_SEMI,
+    ACTIONS(189), 1,
+      anon_sym_option,
+    ACTIONS(191), 1,
+      anon_sym_RBRACE,
+    STATE(29), 1,
+      aux_sym_oneof_repeat1,
+    STATE(161), 1,
+      sym_identifier,
+    STATE(170), 1,
+      aux_sym_message_or_enum_type_repeat1,
+    STATE(239), 1,
+      sym_message_or_enum_type,
+    STATE(241), 1,
+      sym_type,
+    STATE(52), 3,
+      sym_empty_statement,
+      sym_option,
+      sym_oneof_field,
+    ACTIONS(47), 15,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+  [1314] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(31), 1,
+      anon_sym_DOT,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(187), 1,
+      anon_sym_SEMI,
+    ACTIONS(189), 1,
+      anon_sym_option,
+    ACTIONS(193), 1,
+      anon_sym_RBRACE,
+    STATE(27), 1,
+      aux_sym_oneof_repeat1,
+    STATE(161), 1,
+      sym_identifier,
+    STATE(170), 1,
+      aux_sym_message_or_enum_type_repeat1,
+    STATE(239), 1,
+      sym_message_or_enum_type,
+    STATE(241), 1,
+      sym_type,
+    STATE(52), 3,
+      sym_empty_statement,
+      sym_option,
+      sym_oneof_field,
+    ACTIONS(47), 15,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+  [1373] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(195), 1,
+      anon_sym_SEMI,
+    ACTIONS(198), 1,
+      anon_sym_option,
+    ACTIONS(201), 1,
+      anon_sym_DOT,
+    ACTIONS(204), 1,
+      anon_sym_RBRACE,
+    ACTIONS(209), 1,
+      sym__identifier,
+    ACTIONS(212), 1,
+      sym_grit_metavariable,
+    STATE(29), 1,
+      aux_sym_oneof_repeat1,
+    STATE(161), 1,
+      sym_identifier,
+    STATE(170), 1,
+      aux_sym_message_or_enum_type_repeat1,
+    STATE(239), 1,
+      sym_message_or_enum_type,
+    STATE(241), 1,
+      sym_type,
+    STATE(52), 3,
+      sym_empty_statement,
+      sym_option,
+      sym_oneof_field,
+    ACTIONS(206), 15,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+  [1432] = 17,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(215), 1,
+      anon_sym_LBRACE,
+    ACTIONS(217), 1,
+      anon_sym_LBRACK,
+    ACTIONS(221), 1,
+      anon_sym_COLON,
+    ACTIONS(227), 1,
+      sym_hex_lit,
+    ACTIONS(229), 1,
+      sym_float_lit,
+    ACTIONS(231), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(233), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(235), 1,
+      sym_grit_metavariable,
+    STATE(74), 1,
+      sym_identifier,
+    STATE(76), 1,
+      aux_sym_string_repeat3,
+    STATE(151), 1,
+      sym_constant,
+    ACTIONS(219), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(223), 2,
+      sym_true,
+      sym_false,
+    ACTIONS(225), 2,
+      sym_decimal_lit,
+      sym_octal_lit,
+    STATE(112), 5,
+      sym_block_lit,
+      sym_full_ident,
+      sym_bool,
+      sym_int_lit,
+      sym_string,
+  [1491] = 17,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(215), 1,
+      anon_sym_LBRACE,
+    ACTIONS(227), 1,
+      sym_hex_lit,
+    ACTIONS(229), 1,
+      sym_float_lit,
+    ACTIONS(231), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(233), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(235), 1,
+      sym_grit_metavariable,
+    ACTIONS(237), 1,
+      anon_sym_LBRACK,
+    ACTIONS(239), 1,
+      anon_sym_COLON,
+    STATE(74), 1,
+      sym_identifier,
+    STATE(76), 1,
+      aux_sym_string_repeat3,
+    STATE(144), 1,
+      sym_constant,
+    ACTIONS(219), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(223), 2,
+      sym_true,
+      sym_false,
+    ACTIONS(225), 2,
+      sym_decimal_lit,
+      sym_octal_lit,
+    STATE(112), 5,
+      sym_block_lit,
+      sym_full_ident,
+      sym_bool,
+      sym_int_lit,
+      sym_string,
+  [1550] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(215), 1,
+      anon_sym_LBRACE,
+    ACTIONS(227), 1,
+      sym_hex_lit,
+    ACTIONS(229), 1,
+      sym_float_lit,
+    ACTIONS(231), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(233), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(235), 1,
+      sym_grit_metavariable,
+    ACTIONS(241), 1,
+      anon_sym_LBRACK,
+    STATE(74), 1,
+      sym_identifier,
+    STATE(76), 1,
+      aux_sym_string_repeat3,
+    STATE(150), 1,
+      sym_constant,
+    ACTIONS(219), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(223), 2,
+      sym_true,
+      sym_false,
+    ACTIONS(225), 2,
+      sym_decimal_lit,
+      sym_octal_lit,
+    STATE(112), 5,
+      sym_block_lit,
+      sym_full_ident,
+      sym_bool,
+      sym_int_lit,
+      sym_string,
+  [1606] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(243), 5,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+    ACTIONS(245), 17,
+      anon_sym_option,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      sym__identifier,
+  [1636] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(215), 1,
+      anon_sym_LBRACE,
+    ACTIONS(229), 1,
+      sym_float_lit,
+    ACTIONS(231), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(233), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(235), 1,
+      sym_grit_metavariable,
+    ACTIONS(247), 1,
+      anon_sym_RBRACK,
+    ACTIONS(253), 1,
+      sym_hex_lit,
+    STATE(74), 1,
+      sym_identifier,
+    STATE(76), 1,
+      aux_sym_string_repeat3,
+    STATE(225), 1,
+      sym_constant,
+    ACTIONS(223), 2,
+      sym_true,
+      sym_false,
+    ACTIONS(249), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(251), 2,
+      sym_decimal_lit,
+      sym_octal_lit,
+    STATE(112), 5,
+      sym_block_lit,
+      sym_full_ident,
+      sym_bool,
+      sym_int_lit,
+      sym_string,
+  [1692] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(259), 1,
+      anon_sym_LBRACK,
+    ACTIONS(255), 4,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(257), 17,
+      anon_sym_option,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      sym__identifier,
+  [1724] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(215), 1,
+      anon_sym_LBRACE,
+    ACTIONS(229), 1,
+      sym_float_lit,
+    ACTIONS(231), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(233), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(235), 1,
+      sym_grit_metavariable,
+    ACTIONS(253), 1,
+      sym_hex_lit,
+    ACTIONS(261), 1,
+      anon_sym_RBRACK,
+    STATE(74), 1,
+      sym_identifier,
+    STATE(76), 1,
+      aux_sym_string_repeat3,
+    STATE(219), 1,
+      sym_constant,
+    ACTIONS(223), 2,
+      sym_true,
+      sym_false,
+    ACTIONS(249), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(251), 2,
+      sym_decimal_lit,
+      sym_octal_lit,
+    STATE(112), 5,
+      sym_block_lit,
+      sym_full_ident,
+      sym_bool,
+      sym_int_lit,
+      sym_string,
+  [1780] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(31), 1,
+      anon_sym_DOT,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    STATE(161), 1,
+      sym_identifier,
+    STATE(170), 1,
+      aux_sym_message_or_enum_type_repeat1,
+    STATE(230), 1,
+      sym_type,
+    STATE(239), 1,
+      sym_message_or_enum_type,
+    ACTIONS(47), 15,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+  [1822] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(215), 1,
+      anon_sym_LBRACE,
+    ACTIONS(229), 1,
+      sym_float_lit,
+    ACTIONS(231), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(233), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(235), 1,
+      sym_grit_metavariable,
+    ACTIONS(253), 1,
+      sym_hex_lit,
+    ACTIONS(263), 1,
+      anon_sym_RBRACK,
+    STATE(74), 1,
+      sym_identifier,
+    STATE(76), 1,
+      aux_sym_string_repeat3,
+    STATE(231), 1,
+      sym_constant,
+    ACTIONS(223), 2,
+      sym_true,
+      sym_false,
+    ACTIONS(249), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(251), 2,
+      sym_decimal_lit,
+      sym_octal_lit,
+    STATE(112), 5,
+      sym_block_lit,
+      sym_full_ident,
+      sym_bool,
+      sym_int_lit,
+      sym_string,
+  [1878] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(215), 1,
+      anon_sym_LBRACE,
+    ACTIONS(229), 1,
+      sym_float_lit,
+    ACTIONS(231), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(233), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(235), 1,
+      sym_grit_metavariable,
+    ACTIONS(253), 1,
+      sym_hex_lit,
+    ACTIONS(265), 1,
+      anon_sym_RBRACK,
+    STATE(74), 1,
+      sym_identifier,
+    STATE(76), 1,
+      aux_sym_string_repeat3,
+    STATE(208), 1,
+      sym_constant,
+    ACTIONS(223), 2,
+      sym_true,
+      sym_false,
+    ACTIONS(249), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(251), 2,
+      sym_decimal_lit,
+      sym_octal_lit,
+    STATE(112), 5,
+      sym_block_lit,
+      sym_full_ident,
+      sym_bool,
+      sym_int_lit,
+      sym_string,
+  [1934] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(215), 1,
+      anon_sym_LBRACE,
+    ACTIONS(227), 1,
+      sym_hex_lit,
+    ACTIONS(229), 1,
+      sym_float_lit,
+    ACTIONS(231), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(233), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(235), 1,
+      sym_grit_metavariable,
+    ACTIONS(267), 1,
+      anon_sym_LBRACK,
+    STATE(74), 1,
+      sym_identifier,
+    STATE(76), 1,
+      aux_sym_string_repeat3,
+    STATE(147), 1,
+      sym_constant,
+    ACTIONS(219), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(223), 2,
+      sym_true,
+      sym_false,
+    ACTIONS(225), 2,
+      sym_decimal_lit,
+      sym_octal_lit,
+    STATE(112), 5,
+      sym_block_lit,
+      sym_full_ident,
+      sym_bool,
+      sym_int_lit,
+      sym_string,
+  [1990] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(31), 1,
+      anon_sym_DOT,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    STATE(161), 1,
+      sym_identifier,
+    STATE(170), 1,
+      aux_sym_message_or_enum_type_repeat1,
+    STATE(239), 1,
+      sym_message_or_enum_type,
+    STATE(283), 1,
+      sym_type,
+    ACTIONS(47), 15,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+  [2032] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(269), 5,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      sym_grit_metavariable,
+    ACTIONS(271), 17,
+      anon_sym_option,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      sym__identifier,
+  [2062] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(273), 4,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(275), 17,
+      anon_sym_option,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      sym__identifier,
+  [2091] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(215), 1,
+      anon_sym_LBRACE,
+    ACTIONS(229), 1,
+      sym_float_lit,
+    ACTIONS(231), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(233), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(235), 1,
+      sym_grit_metavariable,
+    ACTIONS(253), 1,
+      sym_hex_lit,
+    STATE(74), 1,
+      sym_identifier,
+    STATE(76), 1,
+      aux_sym_string_repeat3,
+    STATE(254), 1,
+      sym_constant,
+    ACTIONS(223), 2,
+      sym_true,
+      sym_false,
+    ACTIONS(249), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(251), 2,
+      sym_decimal_lit,
+      sym_octal_lit,
+    STATE(112), 5,
+      sym_block_lit,
+      sym_full_ident,
+      sym_bool,
+      sym_int_lit,
+      sym_string,
+  [2144] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(215), 1,
+      anon_sym_LBRACE,
+    ACTIONS(229), 1,
+      sym_float_lit,
+    ACTIONS(231), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(233), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(235), 1,
+      sym_grit_metavariable,
+    ACTIONS(253), 1,
+      sym_hex_lit,
+    STATE(74), 1,
+      sym_identifier,
+    STATE(76), 1,
+      aux_sym_string_repeat3,
+    STATE(252), 1,
+      sym_constant,
+    ACTIONS(223), 2,
+      sym_true,
+      sym_false,
+    ACTIONS(249), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(251), 2,
+      sym_decimal_lit,
+      sym_octal_lit,
+    STATE(112), 5,
+      sym_block_lit,
+      sym_full_ident,
+      sym_bool,
+      sym_int_lit,
+      sym_string,
+  [2197] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(215), 1,
+      anon_sym_LBRACE,
+    ACTIONS(229), 1,
+      sym_float_lit,
+    ACTIONS(231), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(233), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(235), 1,
+      sym_grit_metavariable,
+    ACTIONS(253), 1,
+      sym_hex_lit,
+    STATE(74), 1,
+      sym_identifier,
+    STATE(76), 1,
+      aux_sym_string_repeat3,
+    STATE(265), 1,
+      sym_constant,
+    ACTIONS(223), 2,
+      sym_true,
+      sym_false,
+    ACTIONS(249), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(251), 2,
+      sym_decimal_lit,
+      sym_octal_lit,
+    STATE(112), 5,
+      sym_block_lit,
+      sym_full_ident,
+      sym_bool,
+      sym_int_lit,
+      sym_string,
+  [2250] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(215), 1,
+      anon_sym_LBRACE,
+    ACTIONS(229), 1,
+      sym_float_lit,
+    ACTIONS(231), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(233), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(235), 1,
+      sym_grit_metavariable,
+    ACTIONS(253), 1,
+      sym_hex_lit,
+    STATE(74), 1,
+      sym_identifier,
+    STATE(76), 1,
+      aux_sym_string_repeat3,
+    STATE(315), 1,
+      sym_constant,
+    ACTIONS(223), 2,
+      sym_true,
+      sym_false,
+    ACTIONS(249), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(251), 2,
+      sym_decimal_lit,
+      sym_octal_lit,
+    STATE(112), 5,
+      sym_block_lit,
+      sym_full_ident,
+      sym_bool,
+      sym_int_lit,
+      sym_string,
+  [2303] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(215), 1,
+      anon_sym_LBRACE,
+    ACTIONS(229), 1,
+      sym_float_lit,
+    ACTIONS(231), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(233), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(235), 1,
+      sym_grit_metavariable,
+    ACTIONS(253), 1,
+      sym_hex_lit,
+    STATE(74), 1,
+      sym_identifier,
+    STATE(76), 1,
+      aux_sym_string_repeat3,
+    STATE(310), 1,
+      sym_constant,
+    ACTIONS(223), 2,
+      sym_true,
+      sym_false,
+    ACTIONS(249), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(251), 2,
+      sym_decimal_lit,
+      sym_octal_lit,
+    STATE(112), 5,
+      sym_block_lit,
+      sym_full_ident,
+      sym_bool,
+      sym_int_lit,
+      sym_string,
+  [2356] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(215), 1,
+      anon_sym_LBRACE,
+    ACTIONS(229), 1,
+      sym_float_lit,
+    ACTIONS(231), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(233), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(235), 1,
+      sym_grit_metavariable,
+    ACTIONS(253), 1,
+      sym_hex_lit,
+    STATE(74), 1,
+      sym_identifier,
+    STATE(76), 1,
+      aux_sym_string_repeat3,
+    STATE(288), 1,
+      sym_constant,
+    ACTIONS(223), 2,
+      sym_true,
+      sym_false,
+    ACTIONS(249), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(251), 2,
+      sym_decimal_lit,
+      sym_octal_lit,
+    STATE(112), 5,
+      sym_block_lit,
+      sym_full_ident,
+      sym_bool,
+      sym_int_lit,
+      sym_string,
+  [2409] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(183), 4,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(185), 17,
+      anon_sym_option,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      sym__identifier,
+  [2438] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(111), 4,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(113), 17,
+      anon_sym_option,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      sym__identifier,
+  [2467] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(277), 4,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(279), 17,
+      anon_sym_option,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      sym__identifier,
+  [2496] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(215), 1,
+      anon_sym_LBRACE,
+    ACTIONS(229), 1,
+      sym_float_lit,
+    ACTIONS(231), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(233), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(235), 1,
+      sym_grit_metavariable,
+    ACTIONS(253), 1,
+      sym_hex_lit,
+    STATE(74), 1,
+      sym_identifier,
+    STATE(76), 1,
+      aux_sym_string_repeat3,
+    STATE(314), 1,
+      sym_constant,
+    ACTIONS(223), 2,
+      sym_true,
+      sym_false,
+    ACTIONS(249), 2,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+    ACTIONS(251), 2,
+      sym_decimal_lit,
+      sym_octal_lit,
+    STATE(112), 5,
+      sym_block_lit,
+      sym_full_ident,
+      sym_bool,
+      sym_int_lit,
+      sym_string,
+  [2549] = 12,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      anon_sym_SEMI,
+    ACTIONS(13), 1,
+      anon_sym_import,
+    ACTIONS(15), 1,
+      anon_sym_package,
+    ACTIONS(17), 1,
+      anon_sym_option,
+    ACTIONS(19), 1,
+      anon_sym_enum,
+    ACTIONS(21), 1,
+      anon_sym_message,
+    ACTIONS(23), 1,
+      anon_sym_extend,
+    ACTIONS(25), 1,
+      anon_sym_service,
+    ACTIONS(281), 1,
+      ts_builtin_sym_end,
+    STATE(58), 1,
+      aux_sym_source_file_repeat1,
+    STATE(89), 8,
+      sym_empty_statement,
+      sym_import,
+      sym_package,
+      sym_option,
+      sym_enum,
+      sym_message,
+      sym_extend,
+      sym_service,
+  [2593] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(283), 2,
+      anon_sym_DOT,
+      sym_grit_metavariable,
+    ACTIONS(285), 16,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_double,
+      anon_sym_float,
+      anon_sym_bytes,
+      sym__identifier,
+  [2619] = 12,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      anon_sym_SEMI,
+    ACTIONS(13), 1,
+      anon_sym_import,
+    ACTIONS(15), 1,
+      anon_sym_package,
+    ACTIONS(17), 1,
+      anon_sym_option,
+    ACTIONS(19), 1,
+      anon_sym_enum,
+    ACTIONS(21), 1,
+      anon_sym_message,
+    ACTIONS(23), 1,
+      anon_sym_extend,
+    ACTIONS(25), 1,
+      anon_sym_service,
+    ACTIONS(287), 1,
+      ts_builtin_sym_end,
+    STATE(54), 1,
+      aux_sym_source_file_repeat1,
+    STATE(89), 8,
+      sym_empty_statement,
+      sym_import,
+      sym_package,
+      sym_option,
+      sym_enum,
+      sym_message,
+      sym_extend,
+      sym_service,
+  [2663] = 12,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      anon_sym_SEMI,
+    ACTIONS(13), 1,
+      anon_sym_import,
+    ACTIONS(15), 1,
+      anon_sym_package,
+    ACTIONS(17), 1,
+      anon_sym_option,
+    ACTIONS(19), 1,
+      anon_sym_enum,
+    ACTIONS(21), 1,
+      anon_sym_message,
+    ACTIONS(23), 1,
+      anon_sym_extend,
+    ACTIONS(25), 1,
+      anon_sym_service,
+    ACTIONS(289), 1,
+      ts_builtin_sym_end,
+    STATE(58), 1,
+      aux_sym_source_file_repeat1,
+    STATE(89), 8,
+      sym_empty_statement,
+      sym_import,
+      sym_package,
+      sym_option,
+      sym_enum,
+      sym_message,
+      sym_extend,
+      sym_service,
+  [2707] = 12,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(291), 1,
+      ts_builtin_sym_end,
+    ACTIONS(293), 1,
+      anon_sym_SEMI,
+    ACTIONS(296), 1,
+      anon_sym_import,
+    ACTIONS(299), 1,
+      anon_sym_package,
+    ACTIONS(302), 1,
+      anon_sym_option,
+    ACTIONS(305), 1,
+      anon_sym_enum,
+    ACTIONS(308), 1,
+      anon_sym_message,
+    ACTIONS(311), 1,
+      anon_sym_extend,
+    ACTIONS(314), 1,
+      anon_sym_service,
+    STATE(58), 1,
+      aux_sym_source_file_repeat1,
+    STATE(89), 8,
+      sym_empty_statement,
+      sym_import,
+      sym_package,
+      sym_option,
+      sym_enum,
+      sym_message,
+      sym_extend,
+      sym_service,
+  [2751] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(319), 6,
+      sym__identifier,
+      sym_true,
+      sym_false,
+      sym_decimal_lit,
+      sym_octal_lit,
+      sym_float_lit,
+    ACTIONS(317), 9,
+      anon_sym_LBRACE,
+      anon_sym_LBRACK,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+      anon_sym_COLON,
+      sym_hex_lit,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      sym_grit_metavariable,
+  [2774] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(319), 6,
+      sym__identifier,
+      sym_true,
+      sym_false,
+      sym_decimal_lit,
+      sym_octal_lit,
+      sym_float_lit,
+    ACTIONS(317), 9,
+      anon_sym_LBRACE,
+      anon_sym_LBRACK,
+      anon_sym_DASH,
+      anon_sym_PLUS,
+      anon_sym_COLON,
+      sym_hex_lit,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      sym_grit_metavariable,
+  [2797] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(317), 13,
+      anon_sym_SEMI,
+      anon_sym_EQ,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DOT,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_GT,
+      sym__identifier,
+      sym_grit_metavariable,
+  [2816] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(294), 1,
+      sym_key_type,
+    ACTIONS(321), 12,
+      anon_sym_int32,
+      anon_sym_int64,
+      anon_sym_uint32,
+      anon_sym_uint64,
+      anon_sym_sint32,
+      anon_sym_sint64,
+      anon_sym_fixed32,
+      anon_sym_fixed64,
+      anon_sym_sfixed32,
+      anon_sym_sfixed64,
+      anon_sym_bool,
+      anon_sym_string,
+  [2837] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(317), 13,
+      anon_sym_SEMI,
+      anon_sym_EQ,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+      anon_sym_DOT,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_GT,
+      sym__identifier,
+      sym_grit_metavariable,
+  [2856] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(323), 1,
+      anon_sym_SEMI,
+    ACTIONS(325), 1,
+      anon_sym_option,
+    ACTIONS(327), 1,
+      anon_sym_RBRACE,
+    ACTIONS(329), 1,
+      anon_sym_reserved,
+    STATE(69), 1,
+      aux_sym_enum_body_repeat1,
+    STATE(329), 1,
+      sym_identifier,
+    STATE(152), 4,
+      sym_empty_statement,
+      sym_option,
+      sym_enum_field,
+      sym_reserved,
+  [2890] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(333), 1,
+      anon_sym_DOT,
+    STATE(65), 1,
+      aux_sym_option_name_repeat1,
+    ACTIONS(331), 10,
+      anon_sym_SEMI,
+      anon_sym_EQ,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      sym__identifier,
+      sym_grit_metavariable,
+  [2912] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(323), 1,
+      anon_sym_SEMI,
+    ACTIONS(325), 1,
+      anon_sym_option,
+    ACTIONS(329), 1,
+      anon_sym_reserved,
+    ACTIONS(336), 1,
+      anon_sym_RBRACE,
+    STATE(67), 1,
+      aux_sym_enum_body_repeat1,
+    STATE(329), 1,
+      sym_identifier,
+    STATE(152), 4,
+      sym_empty_statement,
+      sym_option,
+      sym_enum_field,
+      sym_reserved,
+  [2946] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(323), 1,
+      anon_sym_SEMI,
+    ACTIONS(325), 1,
+      anon_sym_option,
+    ACTIONS(329), 1,
+      anon_sym_reserved,
+    ACTIONS(338), 1,
+      anon_sym_RBRACE,
+    STATE(68), 1,
+      aux_sym_enum_body_repeat1,
+    STATE(329), 1,
+      sym_identifier,
+    STATE(152), 4,
+      sym_empty_statement,
+      sym_option,
+      sym_enum_field,
+      sym_reserved,
+  [2980] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(340), 1,
+      anon_sym_SEMI,
+    ACTIONS(343), 1,
+      anon_sym_option,
+    ACTIONS(346), 1,
+      anon_sym_RBRACE,
+    ACTIONS(348), 1,
+      anon_sym_reserved,
+    ACTIONS(351), 1,
+      sym__identifier,
+    ACTIONS(354), 1,
+      sym_grit_metavariable,
+    STATE(68), 1,
+      aux_sym_enum_body_repeat1,
+    STATE(329), 1,
+      sym_identifier,
+    STATE(152), 4,
+      sym_empty_statement,
+      sym_option,
+      sym_enum_field,
+      sym_reserved,
+  [3014] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(323), 1,
+      anon_sym_SEMI,
+    ACTIONS(325), 1,
+      anon_sym_option,
+    ACTIONS(329), 1,
+      anon_sym_reserved,
+    ACTIONS(357), 1,
+      anon_sym_RBRACE,
+    STATE(68), 1,
+      aux_sym_enum_body_repeat1,
+    STATE(329), 1,
+      sym_identifier,
+    STATE(152), 4,
+      sym_empty_statement,
+      sym_option,
+      sym_enum_field,
+      sym_reserved,
+  [3048] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(183), 11,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_import,
+      anon_sym_package,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_RBRACE,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_service,
+      anon_sym_rpc,
+  [3065] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(331), 11,
+      anon_sym_SEMI,
+      anon_sym_EQ,
+      anon_sym_RPAREN,
+      anon_sym_DOT,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      sym__identifier,
+      sym_grit_metavariable,
+  [3082] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(361), 1,
+      anon_sym_DOT,
+    STATE(65), 1,
+      aux_sym_option_name_repeat1,
+    ACTIONS(359), 9,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      sym__identifier,
+      sym_grit_metavariable,
+  [3103] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(111), 11,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_import,
+      anon_sym_package,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_RBRACE,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_service,
+      anon_sym_rpc,
+  [3120] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(361), 1,
+      anon_sym_DOT,
+    STATE(72), 1,
+      aux_sym_option_name_repeat1,
+    ACTIONS(363), 9,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      sym__identifier,
+      sym_grit_metavariable,
+  [3141] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(367), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(370), 1,
+      anon_sym_SQUOTE,
+    STATE(75), 1,
+      aux_sym_string_repeat3,
+    ACTIONS(365), 7,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      sym__identifier,
+      sym_grit_metavariable,
+  [3163] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(231), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(233), 1,
+      anon_sym_SQUOTE,
+    STATE(75), 1,
+      aux_sym_string_repeat3,
+    ACTIONS(373), 7,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      sym__identifier,
+      sym_grit_metavariable,
+  [3185] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(251), 1,
+      sym_octal_lit,
+    ACTIONS(375), 1,
+      sym_reserved_identifier,
+    STATE(212), 1,
+      sym_range,
+    STATE(217), 1,
+      sym_int_lit,
+    STATE(313), 2,
+      sym_ranges,
+      sym_reserved_field_names,
+    ACTIONS(253), 3,
+      sym_decimal_lit,
+      sym_hex_lit,
+      sym_grit_metavariable,
+  [3210] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(377), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_import,
+      anon_sym_package,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_service,
+  [3225] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(379), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_import,
+      anon_sym_package,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_service,
+  [3240] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(381), 9,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      sym__identifier,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      sym_grit_metavariable,
+  [3255] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(383), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_import,
+      anon_sym_package,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_service,
+  [3270] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(251), 1,
+      sym_octal_lit,
+    ACTIONS(375), 1,
+      sym_reserved_identifier,
+    STATE(212), 1,
+      sym_range,
+    STATE(217), 1,
+      sym_int_lit,
+    STATE(326), 2,
+      sym_ranges,
+      sym_reserved_field_names,
+    ACTIONS(253), 3,
+      sym_decimal_lit,
+      sym_hex_lit,
+      sym_grit_metavariable,
+  [3295] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(365), 9,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      sym__identifier,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      sym_grit_metavariable,
+  [3310] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(385), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_import,
+      anon_sym_package,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_service,
+  [3325] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(163), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_import,
+      anon_sym_package,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_service,
+  [3340] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(167), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_import,
+      anon_sym_package,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_service,
+  [3355] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(387), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_import,
+      anon_sym_package,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_service,
+  [3370] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(389), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_import,
+      anon_sym_package,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_service,
+  [3385] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(391), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_import,
+      anon_sym_package,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_service,
+  [3400] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(171), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_import,
+      anon_sym_package,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_service,
+  [3415] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(175), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_import,
+      anon_sym_package,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_service,
+  [3430] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(107), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_import,
+      anon_sym_package,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_service,
+  [3445] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(393), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_import,
+      anon_sym_package,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_service,
+  [3460] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(155), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_import,
+      anon_sym_package,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_service,
+  [3475] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(159), 9,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      anon_sym_import,
+      anon_sym_package,
+      anon_sym_option,
+      anon_sym_enum,
+      anon_sym_message,
+      anon_sym_extend,
+      anon_sym_service,
+  [3490] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(317), 8,
+      anon_sym_SEMI,
+      anon_sym_DOT,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      sym__identifier,
+      sym_grit_metavariable,
+  [3504] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      anon_sym_SEMI,
+    ACTIONS(17), 1,
+      anon_sym_option,
+    ACTIONS(395), 1,
+      anon_sym_RBRACE,
+    ACTIONS(397), 1,
+      anon_sym_rpc,
+    STATE(98), 1,
+      aux_sym_service_repeat1,
+    STATE(173), 3,
+      sym_empty_statement,
+      sym_option,
+      sym_rpc,
+  [3528] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      anon_sym_SEMI,
+    ACTIONS(17), 1,
+      anon_sym_option,
+    ACTIONS(397), 1,
+      anon_sym_rpc,
+    ACTIONS(399), 1,
+      anon_sym_RBRACE,
+    STATE(99), 1,
+      aux_sym_service_repeat1,
+    STATE(173), 3,
+      sym_empty_statement,
+      sym_option,
+      sym_rpc,
+  [3552] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(401), 1,
+      anon_sym_SEMI,
+    ACTIONS(404), 1,
+      anon_sym_option,
+    ACTIONS(407), 1,
+      anon_sym_RBRACE,
+    ACTIONS(409), 1,
+      anon_sym_rpc,
+    STATE(99), 1,
+      aux_sym_service_repeat1,
+    STATE(173), 3,
+      sym_empty_statement,
+      sym_option,
+      sym_rpc,
+  [3576] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(231), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(233), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(414), 1,
+      sym_grit_metavariable,
+    STATE(76), 1,
+      aux_sym_string_repeat3,
+    STATE(158), 1,
+      sym_import_modifier,
+    STATE(304), 1,
+      sym_string,
+    ACTIONS(412), 2,
+      anon_sym_weak,
+      anon_sym_public,
+  [3602] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(31), 1,
+      anon_sym_DOT,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(416), 1,
+      sym_stream,
+    STATE(161), 1,
+      sym_identifier,
+    STATE(170), 1,
+      aux_sym_message_or_enum_type_repeat1,
+    STATE(317), 1,
+      sym_message_or_enum_type,
+  [3627] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(418), 7,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      sym__identifier,
+      sym_grit_metavariable,
+  [3640] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(420), 1,
+      anon_sym_LPAREN,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(213), 1,
+      sym_field_option,
+    STATE(229), 1,
+      sym_identifier,
+    STATE(281), 1,
+      sym_field_options,
+    STATE(297), 1,
+      sym_option_name,
+  [3665] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(251), 1,
+      sym_octal_lit,
+    STATE(212), 1,
+      sym_range,
+    STATE(217), 1,
+      sym_int_lit,
+    STATE(309), 1,
+      sym_ranges,
+    ACTIONS(253), 3,
+      sym_decimal_lit,
+      sym_hex_lit,
+      sym_grit_metavariable,
+  [3686] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(31), 1,
+      anon_sym_DOT,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(424), 1,
+      sym_stream,
+    STATE(161), 1,
+      sym_identifier,
+    STATE(170), 1,
+      aux_sym_message_or_enum_type_repeat1,
+    STATE(287), 1,
+      sym_message_or_enum_type,
+  [3711] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(420), 1,
+      anon_sym_LPAREN,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(213), 1,
+      sym_field_option,
+    STATE(229), 1,
+      sym_identifier,
+    STATE(273), 1,
+      sym_field_options,
+    STATE(297), 1,
+      sym_option_name,
+  [3736] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(420), 1,
+      anon_sym_LPAREN,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(213), 1,
+      sym_field_option,
+    STATE(229), 1,
+      sym_identifier,
+    STATE(297), 1,
+      sym_option_name,
+    STATE(306), 1,
+      sym_field_options,
+  [3761] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(426), 7,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      sym__identifier,
+      sym_grit_metavariable,
+  [3774] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(428), 7,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      sym__identifier,
+      sym_grit_metavariable,
+  [3787] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(251), 1,
+      sym_octal_lit,
+    ACTIONS(430), 1,
+      anon_sym_DASH,
+    STATE(256), 1,
+      sym_signed_int_lit,
+    STATE(257), 1,
+      sym_int_lit,
+    ACTIONS(253), 3,
+      sym_decimal_lit,
+      sym_hex_lit,
+      sym_grit_metavariable,
+  [3808] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(432), 7,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      sym__identifier,
+      sym_grit_metavariable,
+  [3821] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(434), 7,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      sym__identifier,
+      sym_grit_metavariable,
+  [3834] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(31), 1,
+      anon_sym_DOT,
+    ACTIONS(53), 1,
+      sym__identifier,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(436), 1,
+      sym_stream,
+    STATE(161), 1,
+      sym_identifier,
+    STATE(170), 1,
+      aux_sym_message_or_enum_type_repeat1,
+    STATE(311), 1,
+      sym_message_or_enum_type,
+  [3859] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(420), 1,
+      anon_sym_LPAREN,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(213), 1,
+      sym_field_option,
+    STATE(229), 1,
+      sym_identifier,
+    STATE(297), 1,
+      sym_option_name,
+    STATE(321), 1,
+      sym_field_options,
+  [3884] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(251), 1,
+      sym_octal_lit,
+    STATE(33), 1,
+      sym_int_lit,
+    STATE(259), 1,
+      sym_field_number,
+    ACTIONS(253), 3,
+      sym_decimal_lit,
+      sym_hex_lit,
+      sym_grit_metavariable,
+  [3902] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(251), 1,
+      sym_octal_lit,
+    STATE(33), 1,
+      sym_int_lit,
+    STATE(243), 1,
+      sym_field_number,
+    ACTIONS(253), 3,
+      sym_decimal_lit,
+      sym_hex_lit,
+      sym_grit_metavariable,
+  [3920] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(251), 1,
+      sym_octal_lit,
+    STATE(217), 1,
+      sym_int_lit,
+    STATE(264), 1,
+      sym_range,
+    ACTIONS(253), 3,
+      sym_decimal_lit,
+      sym_hex_lit,
+      sym_grit_metavariable,
+  [3938] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(31), 1,
+      anon_sym_DOT,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(161), 1,
+      sym_identifier,
+    STATE(170), 1,
+      aux_sym_message_or_enum_type_repeat1,
+    STATE(320), 1,
+      sym_message_or_enum_type,
+  [3960] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(438), 2,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+    ACTIONS(440), 4,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      sym__identifier,
+      sym_grit_metavariable,
+  [3974] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(251), 1,
+      sym_octal_lit,
+    ACTIONS(442), 1,
+      sym_max,
+    STATE(266), 1,
+      sym_int_lit,
+    ACTIONS(253), 3,
+      sym_decimal_lit,
+      sym_hex_lit,
+      sym_grit_metavariable,
+  [3992] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(31), 1,
+      anon_sym_DOT,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(161), 1,
+      sym_identifier,
+    STATE(170), 1,
+      aux_sym_message_or_enum_type_repeat1,
+    STATE(290), 1,
+      sym_message_or_enum_type,
+  [4014] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(251), 1,
+      sym_octal_lit,
+    STATE(33), 1,
+      sym_int_lit,
+    STATE(251), 1,
+      sym_field_number,
+    ACTIONS(253), 3,
+      sym_decimal_lit,
+      sym_hex_lit,
+      sym_grit_metavariable,
+  [4032] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(444), 2,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+    ACTIONS(446), 4,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      sym__identifier,
+      sym_grit_metavariable,
+  [4046] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      anon_sym_SEMI,
+    ACTIONS(17), 1,
+      anon_sym_option,
+    ACTIONS(448), 1,
+      anon_sym_RBRACE,
+    STATE(127), 1,
+      aux_sym_rpc_repeat1,
+    STATE(216), 2,
+      sym_empty_statement,
+      sym_option,
+  [4066] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      anon_sym_SEMI,
+    ACTIONS(17), 1,
+      anon_sym_option,
+    ACTIONS(450), 1,
+      anon_sym_RBRACE,
+    STATE(129), 1,
+      aux_sym_rpc_repeat1,
+    STATE(216), 2,
+      sym_empty_statement,
+      sym_option,
+  [4086] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      anon_sym_SEMI,
+    ACTIONS(17), 1,
+      anon_sym_option,
+    ACTIONS(452), 1,
+      anon_sym_RBRACE,
+    STATE(130), 1,
+      aux_sym_rpc_repeat1,
+    STATE(216), 2,
+      sym_empty_statement,
+      sym_option,
+  [4106] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      anon_sym_SEMI,
+    ACTIONS(17), 1,
+      anon_sym_option,
+    ACTIONS(454), 1,
+      anon_sym_RBRACE,
+    STATE(131), 1,
+      aux_sym_rpc_repeat1,
+    STATE(216), 2,
+      sym_empty_statement,
+      sym_option,
+  [4126] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      anon_sym_SEMI,
+    ACTIONS(17), 1,
+      anon_sym_option,
+    ACTIONS(456), 1,
+      anon_sym_RBRACE,
+    STATE(133), 1,
+      aux_sym_rpc_repeat1,
+    STATE(216), 2,
+      sym_empty_statement,
+      sym_option,
+  [4146] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      anon_sym_SEMI,
+    ACTIONS(17), 1,
+      anon_sym_option,
+    ACTIONS(458), 1,
+      anon_sym_RBRACE,
+    STATE(131), 1,
+      aux_sym_rpc_repeat1,
+    STATE(216), 2,
+      sym_empty_statement,
+      sym_option,
+  [4166] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      anon_sym_SEMI,
+    ACTIONS(17), 1,
+      anon_sym_option,
+    ACTIONS(460), 1,
+      anon_sym_RBRACE,
+    STATE(131), 1,
+      aux_sym_rpc_repeat1,
+    STATE(216), 2,
+      sym_empty_statement,
+      sym_option,
+  [4186] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(462), 1,
+      anon_sym_SEMI,
+    ACTIONS(465), 1,
+      anon_sym_option,
+    ACTIONS(468), 1,
+      anon_sym_RBRACE,
+    STATE(131), 1,
+      aux_sym_rpc_repeat1,
+    STATE(216), 2,
+      sym_empty_statement,
+      sym_option,
+  [4206] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(470), 1,
+      anon_sym_RBRACE,
+    ACTIONS(472), 1,
+      anon_sym_LBRACK,
+    ACTIONS(474), 1,
+      sym__identifier,
+    ACTIONS(476), 1,
+      sym_grit_metavariable,
+    STATE(30), 1,
+      sym_identifier,
+    STATE(137), 1,
+      aux_sym_block_lit_repeat2,
+  [4228] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      anon_sym_SEMI,
+    ACTIONS(17), 1,
+      anon_sym_option,
+    ACTIONS(478), 1,
+      anon_sym_RBRACE,
+    STATE(131), 1,
+      aux_sym_rpc_repeat1,
+    STATE(216), 2,
+      sym_empty_statement,
+      sym_option,
+  [4248] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(183), 3,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(185), 3,
+      anon_sym_option,
+      anon_sym_reserved,
+      sym__identifier,
+  [4262] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(179), 3,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(181), 3,
+      anon_sym_option,
+      anon_sym_reserved,
+      sym__identifier,
+  [4276] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(480), 3,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(482), 3,
+      anon_sym_option,
+      anon_sym_reserved,
+      sym__identifier,
+  [4290] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(472), 1,
+      anon_sym_LBRACK,
+    ACTIONS(474), 1,
+      sym__identifier,
+    ACTIONS(476), 1,
+      sym_grit_metavariable,
+    ACTIONS(484), 1,
+      anon_sym_RBRACE,
+    STATE(30), 1,
+      sym_identifier,
+    STATE(154), 1,
+      aux_sym_block_lit_repeat2,
+  [4312] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(420), 1,
+      anon_sym_LPAREN,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(227), 1,
+      sym_enum_value_option,
+    STATE(229), 1,
+      sym_identifier,
+    STATE(312), 1,
+      sym_option_name,
+  [4334] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(269), 6,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      sym__identifier,
+      sym_grit_metavariable,
+  [4346] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(31), 1,
+      anon_sym_DOT,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(161), 1,
+      sym_identifier,
+    STATE(170), 1,
+      aux_sym_message_or_enum_type_repeat1,
+    STATE(328), 1,
+      sym_message_or_enum_type,
+  [4368] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(488), 1,
+      sym_octal_lit,
+    STATE(33), 1,
+      sym_int_lit,
+    STATE(35), 1,
+      sym_field_number,
+    ACTIONS(486), 3,
+      sym_decimal_lit,
+      sym_hex_lit,
+      sym_grit_metavariable,
+  [4386] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(111), 3,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(113), 3,
+      anon_sym_option,
+      anon_sym_reserved,
+      sym__identifier,
+  [4400] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(420), 1,
+      anon_sym_LPAREN,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(229), 1,
+      sym_identifier,
+    STATE(268), 1,
+      sym_field_option,
+    STATE(297), 1,
+      sym_option_name,
+  [4422] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(490), 2,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+    ACTIONS(492), 4,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      sym__identifier,
+      sym_grit_metavariable,
+  [4436] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(494), 3,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(496), 3,
+      anon_sym_option,
+      anon_sym_reserved,
+      sym__identifier,
+  [4450] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(498), 2,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+    ACTIONS(500), 4,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      sym__identifier,
+      sym_grit_metavariable,
+  [4464] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(502), 2,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+    ACTIONS(504), 4,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      sym__identifier,
+      sym_grit_metavariable,
+  [4478] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(506), 1,
+      sym_float_lit,
+    STATE(109), 1,
+      sym_int_lit,
+    ACTIONS(225), 2,
+      sym_decimal_lit,
+      sym_octal_lit,
+    ACTIONS(227), 2,
+      sym_hex_lit,
+      sym_grit_metavariable,
+  [4496] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(508), 3,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(510), 3,
+      anon_sym_option,
+      anon_sym_reserved,
+      sym__identifier,
+  [4510] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(512), 2,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+    ACTIONS(514), 4,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      sym__identifier,
+      sym_grit_metavariable,
+  [4524] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(516), 2,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+    ACTIONS(518), 4,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      sym__identifier,
+      sym_grit_metavariable,
+  [4538] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(520), 3,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_grit_metavariable,
+    ACTIONS(522), 3,
+      anon_sym_option,
+      anon_sym_reserved,
+      sym__identifier,
+  [4552] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(420), 1,
+      anon_sym_LPAREN,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(229), 1,
+      sym_identifier,
+    STATE(253), 1,
+      sym_enum_value_option,
+    STATE(312), 1,
+      sym_option_name,
+  [4574] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(518), 1,
+      anon_sym_RBRACE,
+    ACTIONS(524), 1,
+      anon_sym_LBRACK,
+    ACTIONS(527), 1,
+      sym__identifier,
+    ACTIONS(530), 1,
+      sym_grit_metavariable,
+    STATE(30), 1,
+      sym_identifier,
+    STATE(154), 1,
+      aux_sym_block_lit_repeat2,
+  [4596] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(506), 1,
+      sym_float_lit,
+    STATE(109), 1,
+      sym_int_lit,
+    ACTIONS(251), 2,
+      sym_decimal_lit,
+      sym_octal_lit,
+    ACTIONS(253), 2,
+      sym_hex_lit,
+      sym_grit_metavariable,
+  [4614] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(231), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(233), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(414), 1,
+      sym_grit_metavariable,
+    STATE(76), 1,
+      aux_sym_string_repeat3,
+    STATE(291), 1,
+      sym_string,
+  [4633] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(535), 1,
+      anon_sym_DOT,
+    ACTIONS(533), 4,
+      anon_sym_RPAREN,
+      anon_sym_GT,
+      sym__identifier,
+      sym_grit_metavariable,
+  [4646] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(231), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(233), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(414), 1,
+      sym_grit_metavariable,
+    STATE(76), 1,
+      aux_sym_string_repeat3,
+    STATE(303), 1,
+      sym_string,
+  [4665] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(535), 1,
+      anon_sym_DOT,
+    ACTIONS(537), 4,
+      anon_sym_RPAREN,
+      anon_sym_GT,
+      sym__identifier,
+      sym_grit_metavariable,
+  [4678] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(420), 1,
+      anon_sym_LPAREN,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(229), 1,
+      sym_identifier,
+    STATE(285), 1,
+      sym_option_name,
+  [4697] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(535), 1,
+      anon_sym_DOT,
+    ACTIONS(539), 4,
+      anon_sym_RPAREN,
+      anon_sym_GT,
+      sym__identifier,
+      sym_grit_metavariable,
+  [4710] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(251), 1,
+      sym_octal_lit,
+    STATE(267), 1,
+      sym_int_lit,
+    ACTIONS(253), 3,
+      sym_decimal_lit,
+      sym_hex_lit,
+      sym_grit_metavariable,
+  [4725] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(269), 5,
+      anon_sym_SEMI,
+      anon_sym_LBRACK,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+      anon_sym_to,
+  [4736] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(420), 1,
+      anon_sym_LPAREN,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(229), 1,
+      sym_identifier,
+    STATE(323), 1,
+      sym_option_name,
+  [4755] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(420), 1,
+      anon_sym_LPAREN,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(229), 1,
+      sym_identifier,
+    STATE(324), 1,
+      sym_option_name,
+  [4774] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(420), 1,
+      anon_sym_LPAREN,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(229), 1,
+      sym_identifier,
+    STATE(325), 1,
+      sym_option_name,
+  [4793] = 4,
+    ACTIONS(541), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(545), 1,
+      sym_comment,
+    STATE(203), 1,
+      aux_sym_string_repeat2,
+    ACTIONS(543), 2,
+      aux_sym_string_token2,
+      sym_escape_sequence,
+  [4807] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(74), 1,
+      sym_identifier,
+    STATE(262), 1,
+      sym_full_ident,
+  [4823] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(250), 1,
+      sym_message_name,
+    STATE(270), 1,
+      sym_identifier,
+  [4839] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(157), 1,
+      sym_identifier,
+    STATE(186), 1,
+      aux_sym_message_or_enum_type_repeat1,
+  [4855] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(272), 1,
+      sym_identifier,
+    STATE(308), 1,
+      sym_rpc_name,
+  [4871] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(492), 4,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      sym__identifier,
+      sym_grit_metavariable,
+  [4881] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(547), 4,
+      anon_sym_SEMI,
+      anon_sym_option,
+      anon_sym_RBRACE,
+      anon_sym_rpc,
+  [4891] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(74), 1,
+      sym_identifier,
+    STATE(255), 1,
+      sym_full_ident,
+  [4907] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(74), 1,
+      sym_identifier,
+    STATE(305), 1,
+      sym_full_ident,
+  [4923] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(275), 1,
+      sym_service_name,
+    STATE(280), 1,
+      sym_identifier,
+  [4939] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(514), 4,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      sym__identifier,
+      sym_grit_metavariable,
+  [4949] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(446), 4,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      sym__identifier,
+      sym_grit_metavariable,
+  [4959] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(74), 1,
+      sym_identifier,
+    STATE(278), 1,
+      sym_full_ident,
+  [4975] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(74), 1,
+      sym_identifier,
+    STATE(276), 1,
+      sym_full_ident,
+  [4991] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(159), 1,
+      sym_identifier,
+    STATE(186), 1,
+      aux_sym_message_or_enum_type_repeat1,
+  [5007] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(500), 4,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      sym__identifier,
+      sym_grit_metavariable,
+  [5017] = 4,
+    ACTIONS(545), 1,
+      sym_comment,
+    ACTIONS(549), 1,
+      anon_sym_DQUOTE,
+    STATE(183), 1,
+      aux_sym_string_repeat1,
+    ACTIONS(551), 2,
+      aux_sym_string_token1,
+      sym_escape_sequence,
+  [5031] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(440), 4,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      sym__identifier,
+      sym_grit_metavariable,
+  [5041] = 4,
+    ACTIONS(541), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(545), 1,
+      sym_comment,
+    STATE(201), 1,
+      aux_sym_string_repeat1,
+    ACTIONS(554), 2,
+      aux_sym_string_token1,
+      sym_escape_sequence,
+  [5055] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(556), 1,
+      sym__identifier,
+    ACTIONS(559), 1,
+      sym_grit_metavariable,
+    STATE(186), 1,
+      aux_sym_message_or_enum_type_repeat1,
+    STATE(298), 1,
+      sym_identifier,
+  [5071] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(562), 4,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      sym__identifier,
+      sym_grit_metavariable,
+  [5081] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(564), 4,
+      anon_sym_SEMI,
+      anon_sym_option,
+      anon_sym_RBRACE,
+      anon_sym_rpc,
+  [5091] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(566), 4,
+      anon_sym_SEMI,
+      anon_sym_option,
+      anon_sym_RBRACE,
+      anon_sym_rpc,
+  [5101] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(568), 4,
+      anon_sym_SEMI,
+      anon_sym_option,
+      anon_sym_RBRACE,
+      anon_sym_rpc,
+  [5111] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(570), 4,
+      anon_sym_SEMI,
+      anon_sym_option,
+      anon_sym_RBRACE,
+      anon_sym_rpc,
+  [5121] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(572), 4,
+      anon_sym_SEMI,
+      anon_sym_option,
+      anon_sym_RBRACE,
+      anon_sym_rpc,
+  [5131] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(574), 4,
+      anon_sym_SEMI,
+      anon_sym_option,
+      anon_sym_RBRACE,
+      anon_sym_rpc,
+  [5141] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(576), 4,
+      anon_sym_SEMI,
+      anon_sym_option,
+      anon_sym_RBRACE,
+      anon_sym_rpc,
+  [5151] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(578), 4,
+      anon_sym_SEMI,
+      anon_sym_option,
+      anon_sym_RBRACE,
+      anon_sym_rpc,
+  [5161] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(580), 4,
+      anon_sym_SEMI,
+      anon_sym_option,
+      anon_sym_RBRACE,
+      anon_sym_rpc,
+  [5171] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(582), 4,
+      anon_sym_SEMI,
+      anon_sym_option,
+      anon_sym_RBRACE,
+      anon_sym_rpc,
+  [5181] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(584), 4,
+      anon_sym_SEMI,
+      anon_sym_option,
+      anon_sym_RBRACE,
+      anon_sym_rpc,
+  [5191] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(586), 4,
+      anon_sym_SEMI,
+      anon_sym_option,
+      anon_sym_RBRACE,
+      anon_sym_rpc,
+  [5201] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(246), 1,
+      sym_enum_name,
+    STATE(289), 1,
+      sym_identifier,
+  [5217] = 4,
+    ACTIONS(545), 1,
+      sym_comment,
+    ACTIONS(588), 1,
+      anon_sym_DQUOTE,
+    STATE(183), 1,
+      aux_sym_string_repeat1,
+    ACTIONS(590), 2,
+      aux_sym_string_token1,
+      sym_escape_sequence,
+  [5231] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(157), 1,
+      sym_identifier,
+    STATE(181), 1,
+      aux_sym_message_or_enum_type_repeat1,
+  [5247] = 4,
+    ACTIONS(545), 1,
+      sym_comment,
+    ACTIONS(588), 1,
+      anon_sym_SQUOTE,
+    STATE(207), 1,
+      aux_sym_string_repeat2,
+    ACTIONS(592), 2,
+      aux_sym_string_token2,
+      sym_escape_sequence,
+  [5261] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(504), 4,
+      anon_sym_RBRACE,
+      anon_sym_LBRACK,
+      sym__identifier,
+      sym_grit_metavariable,
+  [5271] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(260), 1,
+      sym_enum_name,
+    STATE(289), 1,
+      sym_identifier,
+  [5287] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(261), 1,
+      sym_message_name,
+    STATE(270), 1,
+      sym_identifier,
+  [5303] = 4,
+    ACTIONS(545), 1,
+      sym_comment,
+    ACTIONS(594), 1,
+      anon_sym_SQUOTE,
+    STATE(207), 1,
+      aux_sym_string_repeat2,
+    ACTIONS(596), 2,
+      aux_sym_string_token2,
+      sym_escape_sequence,
+  [5317] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(247), 1,
+      anon_sym_RBRACK,
+    ACTIONS(599), 1,
+      anon_sym_COMMA,
+    STATE(224), 1,
+      aux_sym_block_lit_repeat1,
+  [5330] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(601), 1,
+      anon_sym_COMMA,
+    ACTIONS(603), 1,
+      anon_sym_RBRACK,
+    STATE(222), 1,
+      aux_sym_enum_field_repeat1,
+  [5343] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(605), 1,
+      anon_sym_SEMI,
+    ACTIONS(607), 1,
+      anon_sym_COMMA,
+    STATE(221), 1,
+      aux_sym_reserved_field_names_repeat1,
+  [5356] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(279), 1,
+      sym_identifier,
+  [5369] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(609), 1,
+      anon_sym_SEMI,
+    ACTIONS(611), 1,
+      anon_sym_COMMA,
+    STATE(226), 1,
+      aux_sym_ranges_repeat1,
+  [5382] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(613), 1,
+      anon_sym_COMMA,
+    ACTIONS(615), 1,
+      anon_sym_RBRACK,
+    STATE(242), 1,
+      aux_sym_field_options_repeat1,
+  [5395] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(361), 1,
+      anon_sym_DOT,
+    ACTIONS(617), 1,
+      anon_sym_EQ,
+    STATE(215), 1,
+      aux_sym_option_name_repeat1,
+  [5408] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(361), 1,
+      anon_sym_DOT,
+    ACTIONS(619), 1,
+      anon_sym_EQ,
+    STATE(65), 1,
+      aux_sym_option_name_repeat1,
+  [5421] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(621), 3,
+      anon_sym_SEMI,
+      anon_sym_option,
+      anon_sym_RBRACE,
+  [5430] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(625), 1,
+      anon_sym_to,
+    ACTIONS(623), 2,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+  [5441] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(599), 1,
+      anon_sym_COMMA,
+    ACTIONS(627), 1,
+      anon_sym_RBRACK,
+    STATE(236), 1,
+      aux_sym_block_lit_repeat1,
+  [5454] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(599), 1,
+      anon_sym_COMMA,
+    ACTIONS(627), 1,
+      anon_sym_RBRACK,
+    STATE(228), 1,
+      aux_sym_block_lit_repeat1,
+  [5467] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(361), 1,
+      anon_sym_DOT,
+    ACTIONS(629), 1,
+      anon_sym_EQ,
+    STATE(65), 1,
+      aux_sym_option_name_repeat1,
+  [5480] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(607), 1,
+      anon_sym_COMMA,
+    ACTIONS(631), 1,
+      anon_sym_SEMI,
+    STATE(233), 1,
+      aux_sym_reserved_field_names_repeat1,
+  [5493] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(633), 1,
+      anon_sym_COMMA,
+    ACTIONS(636), 1,
+      anon_sym_RBRACK,
+    STATE(222), 1,
+      aux_sym_enum_field_repeat1,
+  [5506] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(318), 1,
+      sym_identifier,
+  [5519] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(261), 1,
+      anon_sym_RBRACK,
+    ACTIONS(599), 1,
+      anon_sym_COMMA,
+    STATE(236), 1,
+      aux_sym_block_lit_repeat1,
+  [5532] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(261), 1,
+      anon_sym_RBRACK,
+    ACTIONS(599), 1,
+      anon_sym_COMMA,
+    STATE(218), 1,
+      aux_sym_block_lit_repeat1,
+  [5545] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(611), 1,
+      anon_sym_COMMA,
+    ACTIONS(638), 1,
+      anon_sym_SEMI,
+    STATE(234), 1,
+      aux_sym_ranges_repeat1,
+  [5558] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(601), 1,
+      anon_sym_COMMA,
+    ACTIONS(640), 1,
+      anon_sym_RBRACK,
+    STATE(209), 1,
+      aux_sym_enum_field_repeat1,
+  [5571] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(599), 1,
+      anon_sym_COMMA,
+    ACTIONS(642), 1,
+      anon_sym_RBRACK,
+    STATE(236), 1,
+      aux_sym_block_lit_repeat1,
+  [5584] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(361), 1,
+      anon_sym_DOT,
+    ACTIONS(644), 1,
+      anon_sym_EQ,
+    STATE(220), 1,
+      aux_sym_option_name_repeat1,
+  [5597] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(296), 1,
+      sym_identifier,
+  [5610] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(265), 1,
+      anon_sym_RBRACK,
+    ACTIONS(599), 1,
+      anon_sym_COMMA,
+    STATE(235), 1,
+      aux_sym_block_lit_repeat1,
+  [5623] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(646), 1,
+      anon_sym_COMMA,
+    ACTIONS(649), 1,
+      anon_sym_RBRACK,
+    STATE(232), 1,
+      aux_sym_field_options_repeat1,
+  [5636] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(651), 1,
+      anon_sym_SEMI,
+    ACTIONS(653), 1,
+      anon_sym_COMMA,
+    STATE(233), 1,
+      aux_sym_reserved_field_names_repeat1,
+  [5649] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(656), 1,
+      anon_sym_SEMI,
+    ACTIONS(658), 1,
+      anon_sym_COMMA,
+    STATE(234), 1,
+      aux_sym_ranges_repeat1,
+  [5662] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(247), 1,
+      anon_sym_RBRACK,
+    ACTIONS(599), 1,
+      anon_sym_COMMA,
+    STATE(236), 1,
+      aux_sym_block_lit_repeat1,
+  [5675] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(661), 1,
+      anon_sym_COMMA,
+    ACTIONS(664), 1,
+      anon_sym_RBRACK,
+    STATE(236), 1,
+      aux_sym_block_lit_repeat1,
+  [5688] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(71), 1,
+      sym_identifier,
+  [5701] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(301), 1,
+      sym_identifier,
+  [5714] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(666), 3,
+      anon_sym_GT,
+      sym__identifier,
+      sym_grit_metavariable,
+  [5723] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(668), 3,
+      anon_sym_DQUOTE,
+      anon_sym_SQUOTE,
+      sym_grit_metavariable,
+  [5732] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(55), 1,
+      sym_grit_metavariable,
+    ACTIONS(422), 1,
+      sym__identifier,
+    STATE(319), 1,
+      sym_identifier,
+  [5745] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(613), 1,
+      anon_sym_COMMA,
+    ACTIONS(670), 1,
+      anon_sym_RBRACK,
+    STATE(232), 1,
+      aux_sym_field_options_repeat1,
+  [5758] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(672), 1,
+      anon_sym_SEMI,
+    ACTIONS(674), 1,
+      anon_sym_LBRACK,
+  [5768] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(676), 2,
+      sym__identifier,
+      sym_grit_metavariable,
+  [5776] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(678), 1,
+      anon_sym_SEMI,
+    ACTIONS(680), 1,
+      anon_sym_LBRACE,
+  [5786] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(682), 1,
+      anon_sym_LBRACE,
+    STATE(92), 1,
+      sym_enum_body,
+  [5796] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(684), 1,
+      anon_sym_SEMI,
+    ACTIONS(686), 1,
+      anon_sym_LBRACE,
+  [5806] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(688), 1,
+      anon_sym_SEMI,
+    ACTIONS(690), 1,
+      anon_sym_LBRACE,
+  [5816] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(692), 1,
+      anon_sym_SEMI,
+    ACTIONS(694), 1,
+      anon_sym_LBRACE,
+  [5826] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(696), 1,
+      anon_sym_LBRACE,
+    STATE(94), 1,
+      sym_message_body,
+  [5836] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(698), 1,
+      anon_sym_SEMI,
+    ACTIONS(700), 1,
+      anon_sym_LBRACK,
+  [5846] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(702), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+  [5854] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(704), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+  [5862] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(664), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+  [5870] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(696), 1,
+      anon_sym_LBRACE,
+    STATE(95), 1,
+      sym_message_body,
+  [5880] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(706), 1,
+      anon_sym_SEMI,
+    ACTIONS(708), 1,
+      anon_sym_LBRACK,
+  [5890] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(710), 2,
+      anon_sym_SEMI,
+      anon_sym_LBRACK,
+  [5898] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(712), 2,
+      anon_sym_DQUOTEproto3_DQUOTE,
+      anon_sym_DQUOTEproto2_DQUOTE,
+  [5906] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(714), 1,
+      anon_sym_SEMI,
+    ACTIONS(716), 1,
+      anon_sym_LBRACK,
+  [5916] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(718), 1,
+      anon_sym_LBRACE,
+    STATE(7), 1,
+      sym_enum_body,
+  [5926] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(720), 1,
+      anon_sym_LBRACE,
+    STATE(19), 1,
+      sym_message_body,
+  [5936] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(720), 1,
+      anon_sym_LBRACE,
+    STATE(20), 1,
+      sym_message_body,
+  [5946] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(722), 2,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+  [5954] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(724), 2,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+  [5962] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(726), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+  [5970] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(728), 2,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+  [5978] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(730), 2,
+      anon_sym_SEMI,
+      anon_sym_LBRACK,
+  [5986] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(732), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+  [5994] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(734), 1,
+      anon_sym_LPAREN,
+  [6001] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(736), 1,
+      anon_sym_LBRACE,
+  [6008] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(738), 1,
+      anon_sym_SEMI,
+  [6015] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(740), 1,
+      anon_sym_LPAREN,
+  [6022] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(742), 1,
+      anon_sym_RBRACK,
+  [6029] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(744), 1,
+      anon_sym_SEMI,
+  [6036] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(746), 1,
+      anon_sym_LBRACE,
+  [6043] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(748), 1,
+      anon_sym_RPAREN,
+  [6050] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(750), 1,
+      sym_reserved_identifier,
+  [6057] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(752), 1,
+      anon_sym_SEMI,
+  [6064] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(754), 1,
+      anon_sym_EQ,
+  [6071] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(756), 1,
+      anon_sym_LBRACE,
+  [6078] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(758), 1,
+      anon_sym_RBRACK,
+  [6085] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(760), 1,
+      anon_sym_EQ,
+  [6092] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(762), 1,
+      anon_sym_GT,
+  [6099] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(764), 1,
+      anon_sym_SEMI,
+  [6106] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(766), 1,
+      anon_sym_EQ,
+  [6113] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(768), 1,
+      ts_builtin_sym_end,
+  [6120] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(770), 1,
+      anon_sym_RPAREN,
+  [6127] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(772), 1,
+      anon_sym_SEMI,
+  [6134] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(774), 1,
+      anon_sym_LBRACE,
+  [6141] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(776), 1,
+      anon_sym_RPAREN,
+  [6148] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(778), 1,
+      anon_sym_SEMI,
+  [6155] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(780), 1,
+      anon_sym_SEMI,
+  [6162] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(782), 1,
+      anon_sym_COMMA,
+  [6169] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(784), 1,
+      anon_sym_COMMA,
+  [6176] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(786), 1,
+      anon_sym_returns,
+  [6183] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(788), 1,
+      anon_sym_EQ,
+  [6190] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(790), 1,
+      anon_sym_EQ,
+  [6197] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(535), 1,
+      anon_sym_DOT,
+  [6204] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(792), 1,
+      anon_sym_EQ,
+  [6211] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(794), 1,
+      anon_sym_SEMI,
+  [6218] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(796), 1,
+      anon_sym_LBRACE,
+  [6225] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(798), 1,
+      anon_sym_returns,
+  [6232] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(800), 1,
+      anon_sym_SEMI,
+  [6239] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(802), 1,
+      anon_sym_SEMI,
+  [6246] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(804), 1,
+      anon_sym_RBRACK,
+  [6253] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(806), 1,
+      anon_sym_RBRACK,
+  [6260] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(373), 1,
+      anon_sym_SEMI,
+  [6267] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(808), 1,
+      anon_sym_LPAREN,
+  [6274] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(810), 1,
+      anon_sym_SEMI,
+  [6281] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(812), 1,
+      anon_sym_SEMI,
+  [6288] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(814), 1,
+      anon_sym_RPAREN,
+  [6295] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(816), 1,
+      anon_sym_EQ,
+  [6302] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(818), 1,
+      anon_sym_SEMI,
+  [6309] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(820), 1,
+      anon_sym_SEMI,
+  [6316] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(822), 1,
+      anon_sym_SEMI,
+  [6323] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(824), 1,
+      anon_sym_SEMI,
+  [6330] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(826), 1,
+      anon_sym_RPAREN,
+  [6337] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(828), 1,
+      anon_sym_EQ,
+  [6344] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(830), 1,
+      anon_sym_EQ,
+  [6351] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(832), 1,
+      anon_sym_RPAREN,
+  [6358] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(834), 1,
+      anon_sym_RBRACK,
+  [6365] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(836), 1,
+      anon_sym_LT,
+  [6372] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(838), 1,
+      anon_sym_EQ,
+  [6379] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(840), 1,
+      anon_sym_EQ,
+  [6386] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(842), 1,
+      anon_sym_EQ,
+  [6393] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(844), 1,
+      anon_sym_SEMI,
+  [6400] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(846), 1,
+      anon_sym_LPAREN,
+  [6407] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(848), 1,
+      anon_sym_RPAREN,
+  [6414] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(850), 1,
+      anon_sym_EQ,
+};
+
+static const uint32_t ts_small_parse_table_map[] = {
+  [SMALL_STATE(2)] = 0,
+  [SMALL_STATE(3)] = 95,
+  [SMALL_STATE(4)] = 190,
+  [SMALL_STATE(5)] = 285,
+  [SMALL_STATE(6)] = 380,
+  [SMALL_STATE(7)] = 475,
+  [SMALL_STATE(8)] = 514,
+  [SMALL_STATE(9)] = 553,
+  [SMALL_STATE(10)] = 592,
+  [SMALL_STATE(11)] = 631,
+  [SMALL_STATE(12)] = 670,
+  [SMALL_STATE(13)] = 709,
+  [SMALL_STATE(14)] = 748,
+  [SMALL_STATE(15)] = 787,
+  [SMALL_STATE(16)] = 826,
+  [SMALL_STATE(17)] = 865,
+  [SMALL_STATE(18)] = 904,
+  [SMALL_STATE(19)] = 943,
+  [SMALL_STATE(20)] = 982,
+  [SMALL_STATE(21)] = 1021,
+  [SMALL_STATE(22)] = 1060,
+  [SMALL_STATE(23)] = 1099,
+  [SMALL_STATE(24)] = 1138,
+  [SMALL_STATE(25)] = 1177,
+  [SMALL_STATE(26)] = 1216,
+  [SMALL_STATE(27)] = 1255,
+  [SMALL_STATE(28)] = 1314,
+  [SMALL_STATE(29)] = 1373,
+  [SMALL_STATE(30)] = 1432,
+  [SMALL_STATE(31)] = 1491,
+  [SMALL_STATE(32)] = 1550,
+  [SMALL_STATE(33)] = 1606,
+  [SMALL_STATE(34)] = 1636,
+  [SMALL_STATE(35)] = 1692,
+  [SMALL_STATE(36)] = 1724,
+  [SMALL_STATE(37)] = 1780,
+  [SMALL_STATE(38)] = 1822,
+  [SMALL_STATE(39)] = 1878,
+  [SMALL_STATE(40)] = 1934,
+  [SMALL_STATE(41)] = 1990,
+  [SMALL_STATE(42)] = 2032,
+  [SMALL_STATE(43)] = 2062,
+  [SMALL_STATE(44)] = 2091,
+  [SMALL_STATE(45)] = 2144,
+  [SMALL_STATE(46)] = 2197,
+  [SMALL_STATE(47)] = 2250,
+  [SMALL_STATE(48)] = 2303,
+  [SMALL_STATE(49)] = 2356,
+  [SMALL_STATE(50)] = 2409,
+  [SMALL_STATE(51)] = 2438,
+  [SMALL_STATE(52)] = 2467,
+  [SMALL_STATE(53)] = 2496,
+  [SMALL_STATE(54)] = 2549,
+  [SMALL_STATE(55)] = 2593,
+  [SMALL_STATE(56)] = 2619,
+  [SMALL_STATE(57)] = 2663,
+  [SMALL_STATE(58)] = 2707,
+  [SMALL_STATE(59)] = 2751,
+  [SMALL_STATE(60)] = 2774,
+  [SMALL_STATE(61)] = 2797,
+  [SMALL_STATE(62)] = 2816,
+  [SMALL_STATE(63)] = 2837,
+  [SMALL_STATE(64)] = 2856,
+  [SMALL_STATE(65)] = 2890,
+  [SMALL_STATE(66)] = 2912,
+  [SMALL_STATE(67)] = 2946,
+  [SMALL_STATE(68)] = 2980,
+  [SMALL_STATE(69)] = 3014,
+  [SMALL_STATE(70)] = 3048,
+  [SMALL_STATE(71)] = 3065,
+  [SMALL_STATE(72)] = 3082,
+  [SMALL_STATE(73)] = 3103,
+  [SMALL_STATE(74)] = 3120,
+  [SMALL_STATE(75)] = 3141,
+  [SMALL_STATE(76)] = 3163,
+  [SMALL_STATE(77)] = 3185,
+  [SMALL_STATE(78)] = 3210,
+  [SMALL_STATE(79)] = 3225,
+  [SMALL_STATE(80)] = 3240,
+  [SMALL_STATE(81)] = 3255,
+  [SMALL_STATE(82)] = 3270,
+  [SMALL_STATE(83)] = 3295,
+  [SMALL_STATE(84)] = 3310,
+  [SMALL_STATE(85)] = 3325,
+  [SMALL_STATE(86)] = 3340,
+  [SMALL_STATE(87)] = 3355,
+  [SMALL_STATE(88)] = 3370,
+  [SMALL_STATE(89)] = 3385,
+  [SMALL_STATE(90)] = 3400,
+  [SMALL_STATE(91)] = 3415,
+  [SMALL_STATE(92)] = 3430,
+  [SMALL_STATE(93)] = 3445,
+  [SMALL_STATE(94)] = 3460,
+  [SMALL_STATE(95)] = 3475,
+  [SMALL_STATE(96)] = 3490,
+  [SMALL_STATE(97)] = 3504,
+  [SMALL_STATE(98)] = 3528,
+  [SMALL_STATE(99)] = 3552,
+  [SMALL_STATE(100)] = 3576,
+  [SMALL_STATE(101)] = 3602,
+  [SMALL_STATE(102)] = 3627,
+  [SMALL_STATE(103)] = 3640,
+  [SMALL_STATE(104)] = 3665,
+  [SMALL_STATE(105)] = 3686,
+  [SMALL_STATE(106)] = 3711,
+  [SMALL_STATE(107)] = 3736,
+  [SMALL_STATE(108)] = 3761,
+  [SMALL_STATE(109)] = 3774,
+  [SMALL_STATE(110)] = 3787,
+  [SMALL_STATE(111)] = 3808,
+  [SMALL_STATE(112)] = 3821,
+  [SMALL_STATE(113)] = 3834,
+  [SMALL_STATE(114)] = 3859,
+  [SMALL_STATE(115)] = 3884,
+  [SMALL_STATE(116)] = 3902,
+  [SMALL_STATE(117)] = 3920,
+  [SMALL_STATE(118)] = 3938,
+  [SMALL_STATE(119)] = 3960,
+  [SMALL_STATE(120)] = 3974,
+  [SMALL_STATE(121)] = 3992,
+  [SMALL_STATE(122)] = 4014,
+  [SMALL_STATE(123)] = 4032,
+  [SMALL_STATE(124)] = 4046,
+  [SMALL_STATE(125)] = 4066,
+  [SMALL_STATE(126)] = 4086,
+  [SMALL_STATE(127)] = 4106,
+  [SMALL_STATE(128)] = 4126,
+  [SMALL_STATE(129)] = 4146,
+  [SMALL_STATE(130)] = 4166,
+  [SMALL_STATE(131)] = 4186,
+  [SMALL_STATE(132)] = 4206,
+  [SMALL_STATE(133)] = 4228,
+  [SMALL_STATE(134)] = 4248,
+  [SMALL_STATE(135)] = 4262,
+  [SMALL_STATE(136)] = 4276,
+  [SMALL_STATE(137)] = 4290,
+  [SMALL_STATE(138)] = 4312,
+  [SMALL_STATE(139)] = 4334,
+  [SMALL_STATE(140)] = 4346,
+  [SMALL_STATE(141)] = 4368,
+  [SMALL_STATE(142)] = 4386,
+  [SMALL_STATE(143)] = 4400,
+  [SMALL_STATE(144)] = 4422,
+  [SMALL_STATE(145)] = 4436,
+  [SMALL_STATE(146)] = 4450,
+  [SMALL_STATE(147)] = 4464,
+  [SMALL_STATE(148)] = 4478,
+  [SMALL_STATE(149)] = 4496,
+  [SMALL_STATE(150)] = 4510,
+  [SMALL_STATE(151)] = 4524,
+  [SMALL_STATE(152)] = 4538,
+  [SMALL_STATE(153)] = 4552,
+  [SMALL_STATE(154)] = 4574,
+  [SMALL_STATE(155)] = 4596,
+  [SMALL_STATE(156)] = 4614,
+  [SMALL_STATE(157)] = 4633,
+  [SMALL_STATE(158)] = 4646,
+  [SMALL_STATE(159)] = 4665,
+  [SMALL_STATE(160)] = 4678,
+  [SMALL_STATE(161)] = 4697,
+  [SMALL_STATE(162)] = 4710,
+  [SMALL_STATE(163)] = 4725,
+  [SMALL_STATE(164)] = 4736,
+  [SMALL_STATE(165)] = 4755,
+  [SMALL_STATE(166)] = 4774,
+  [SMALL_STATE(167)] = 4793,
+  [SMALL_STATE(168)] = 4807,
+  [SMALL_STATE(169)] = 4823,
+  [SMALL_STATE(170)] = 4839,
+  [SMALL_STATE(171)] = 4855,
+  [SMALL_STATE(172)] = 4871,
+  [SMALL_STATE(173)] = 4881,
+  [SMALL_STATE(174)] = 4891,
+  [SMALL_STATE(175)] = 4907,
+  [SMALL_STATE(176)] = 4923,
+  [SMALL_STATE(177)] = 4939,
+  [SMALL_STATE(178)] = 4949,
+  [SMALL_STATE(179)] = 4959,
+  [SMALL_STATE(180)] = 4975,
+  [SMALL_STATE(181)] = 4991,
+  [SMALL_STATE(182)] = 5007,
+  [SMALL_STATE(183)] = 5017,
+  [SMALL_STATE(184)] = 5031,
+  [SMALL_STATE(185)] = 5041,
+  [SMALL_STATE(186)] = 5055,
+  [SMALL_STATE(187)] = 5071,
+  [SMALL_STATE(188)] = 5081,
+  [SMALL_STATE(189)] = 5091,
+  [SMALL_STATE(190)] = 5101,
+  [SMALL_STATE(191)] = 5111,
+  [SMALL_STATE(192)] = 5121,
+  [SMALL_STATE(193)] = 5131,
+  [SMALL_STATE(194)] = 5141,
+  [SMALL_STATE(195)] = 5151,
+  [SMALL_STATE(196)] = 5161,
+  [SMALL_STATE(197)] = 5171,
+  [SMALL_STATE(198)] = 5181,
+  [SMALL_STATE(199)] = 5191,
+  [SMALL_STATE(200)] = 5201,
+  [SMALL_STATE(201)] = 5217,
+  [SMALL_STATE(202)] = 5231,
+  [SMALL_STATE(203)] = 5247,
+  [SMALL_STATE(204)] = 5261,
+  [SMALL_STATE(205)] = 5271,
+  [SMALL_STATE(206)] = 5287,
+  [SMALL_STATE(207)] = 5303,
+  [SMALL_STATE(208)] = 5317,
+  [SMALL_STATE(209)] = 5330,
+  [SMALL_STATE(210)] = 5343,
+  [SMALL_STATE(211)] = 5356,
+  [SMALL_STATE(212)] = 5369,
+  [SMALL_STATE(213)] = 5382,
+  [SMALL_STATE(214)] = 5395,
+  [SMALL_STATE(215)] = 5408,
+  [SMALL_STATE(216)] = 5421,
+  [SMALL_STATE(217)] = 5430,
+  [SMALL_STATE(218)] = 5441,
+  [SMALL_STATE(219)] = 5454,
+  [SMALL_STATE(220)] = 5467,
+  [SMALL_STATE(221)] = 5480,
+  [SMALL_STATE(222)] = 5493,
+  [SMALL_STATE(223)] = 5506,
+  [SMALL_STATE(224)] = 5519,
+  [SMALL_STATE(225)] = 5532,
+  [SMALL_STATE(226)] = 5545,
+  [SMALL_STATE(227)] = 5558,
+  [SMALL_STATE(228)] = 5571,
+  [SMALL_STATE(229)] = 5584,
+  [SMALL_STATE(230)] = 5597,
+  [SMALL_STATE(231)] = 5610,
+  [SMALL_STATE(232)] = 5623,
+  [SMALL_STATE(233)] = 5636,
+  [SMALL_STATE(234)] = 5649,
+  [SMALL_STATE(235)] = 5662,
+  [SMALL_STATE(236)] = 5675,
+  [SMALL_STATE(237)] = 5688,
+  [SMALL_STATE(238)] = 5701,
+  [SMALL_STATE(239)] = 5714,
+  [SMALL_STATE(240)] = 5723,
+  [SMALL_STATE(241)] = 5732,
+  [SMALL_STATE(242)] = 5745,
+  [SMALL_STATE(243)] = 5758,
+  [SMALL_STATE(244)] = 5768,
+  [SMALL_STATE(245)] = 5776,
+  [SMALL_STATE(246)] = 5786,
+  [SMALL_STATE(247)] = 5796,
+  [SMALL_STATE(248)] = 5806,
+  [SMALL_STATE(249)] = 5816,
+  [SMALL_STATE(250)] = 5826,
+  [SMALL_STATE(251)] = 5836,
+  [SMALL_STATE(252)] = 5846,
+  [SMALL_STATE(253)] = 5854,
+  [SMALL_STATE(254)] = 5862,
+  [SMALL_STATE(255)] = 5870,
+  [SMALL_STATE(256)] = 5880,
+  [SMALL_STATE(257)] = 5890,
+  [SMALL_STATE(258)] = 5898,
+  [SMALL_STATE(259)] = 5906,
+  [SMALL_STATE(260)] = 5916,
+  [SMALL_STATE(261)] = 5926,
+  [SMALL_STATE(262)] = 5936,
+  [SMALL_STATE(263)] = 5946,
+  [SMALL_STATE(264)] = 5954,
+  [SMALL_STATE(265)] = 5962,
+  [SMALL_STATE(266)] = 5970,
+  [SMALL_STATE(267)] = 5978,
+  [SMALL_STATE(268)] = 5986,
+  [SMALL_STATE(269)] = 5994,
+  [SMALL_STATE(270)] = 6001,
+  [SMALL_STATE(271)] = 6008,
+  [SMALL_STATE(272)] = 6015,
+  [SMALL_STATE(273)] = 6022,
+  [SMALL_STATE(274)] = 6029,
+  [SMALL_STATE(275)] = 6036,
+  [SMALL_STATE(276)] = 6043,
+  [SMALL_STATE(277)] = 6050,
+  [SMALL_STATE(278)] = 6057,
+  [SMALL_STATE(279)] = 6064,
+  [SMALL_STATE(280)] = 6071,
+  [SMALL_STATE(281)] = 6078,
+  [SMALL_STATE(282)] = 6085,
+  [SMALL_STATE(283)] = 6092,
+  [SMALL_STATE(284)] = 6099,
+  [SMALL_STATE(285)] = 6106,
+  [SMALL_STATE(286)] = 6113,
+  [SMALL_STATE(287)] = 6120,
+  [SMALL_STATE(288)] = 6127,
+  [SMALL_STATE(289)] = 6134,
+  [SMALL_STATE(290)] = 6141,
+  [SMALL_STATE(291)] = 6148,
+  [SMALL_STATE(292)] = 6155,
+  [SMALL_STATE(293)] = 6162,
+  [SMALL_STATE(294)] = 6169,
+  [SMALL_STATE(295)] = 6176,
+  [SMALL_STATE(296)] = 6183,
+  [SMALL_STATE(297)] = 6190,
+  [SMALL_STATE(298)] = 6197,
+  [SMALL_STATE(299)] = 6204,
+  [SMALL_STATE(300)] = 6211,
+  [SMALL_STATE(301)] = 6218,
+  [SMALL_STATE(302)] = 6225,
+  [SMALL_STATE(303)] = 6232,
+  [SMALL_STATE(304)] = 6239,
+  [SMALL_STATE(305)] = 6246,
+  [SMALL_STATE(306)] = 6253,
+  [SMALL_STATE(307)] = 6260,
+  [SMALL_STATE(308)] = 6267,
+  [SMALL_STATE(309)] = 6274,
+  [SMALL_STATE(310)] = 6281,
+  [SMALL_STATE(311)] = 6288,
+  [SMALL_STATE(312)] = 6295,
+  [SMALL_STATE(313)] = 6302,
+  [SMALL_STATE(314)] = 6309,
+  [SMALL_STATE(315)] = 6316,
+  [SMALL_STATE(316)] = 6323,
+  [SMALL_STATE(317)] = 6330,
+  [SMALL_STATE(318)] = 6337,
+  [SMALL_STATE(319)] = 6344,
+  [SMALL_STATE(320)] = 6351,
+  [SMALL_STATE(321)] = 6358,
+  [SMALL_STATE(322)] = 6365,
+  [SMALL_STATE(323)] = 6372,
+  [SMALL_STATE(324)] = 6379,
+  [SMALL_STATE(325)] = 6386,
+  [SMALL_STATE(326)] = 6393,
+  [SMALL_STATE(327)] = 6400,
+  [SMALL_STATE(328)] = 6407,
+  [SMALL_STATE(329)] = 6414,
+};
+
+static const TSParseActionEntry ts_parse_actions[] = {
+  [0] = {.entry = {.count = 0, .reusable = false}},
+  [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
+  [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT_EXTRA(),
+  [5] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 0, 0, 0),
+  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
+  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(299),
+  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(282),
+  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(100),
+  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(179),
+  [17] = {.entry = {.count = 1, .reusable = true}}, SHIFT(160),
+  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(200),
+  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(169),
+  [23] = {.entry = {.count = 1, .reusable = true}}, SHIFT(174),
+  [25] = {.entry = {.count = 1, .reusable = true}}, SHIFT(176),
+  [27] = {.entry = {.count = 1, .reusable = true}}, SHIFT(26),
+  [29] = {.entry = {.count = 1, .reusable = false}}, SHIFT(165),
+  [31] = {.entry = {.count = 1, .reusable = true}}, SHIFT(202),
+  [33] = {.entry = {.count = 1, .reusable = false}}, SHIFT(205),
+  [35] = {.entry = {.count = 1, .reusable = true}}, SHIFT(24),
+  [37] = {.entry = {.count = 1, .reusable = false}}, SHIFT(206),
+  [39] = {.entry = {.count = 1, .reusable = false}}, SHIFT(168),
+  [41] = {.entry = {.count = 1, .reusable = false}}, SHIFT(55),
+  [43] = {.entry = {.count = 1, .reusable = false}}, SHIFT(238),
+  [45] = {.entry = {.count = 1, .reusable = false}}, SHIFT(322),
+  [47] = {.entry = {.count = 1, .reusable = false}}, SHIFT(239),
+  [49] = {.entry = {.count = 1, .reusable = false}}, SHIFT(77),
+  [51] = {.entry = {.count = 1, .reusable = false}}, SHIFT(104),
+  [53] = {.entry = {.count = 1, .reusable = false}}, SHIFT(61),
+  [55] = {.entry = {.count = 1, .reusable = true}}, SHIFT(63),
+  [57] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
+  [59] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
+  [61] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_message_body_repeat1, 2, 0, 5), SHIFT_REPEAT(26),
+  [64] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_message_body_repeat1, 2, 0, 5), SHIFT_REPEAT(165),
+  [67] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_message_body_repeat1, 2, 0, 5), SHIFT_REPEAT(202),
+  [70] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_message_body_repeat1, 2, 0, 5), SHIFT_REPEAT(205),
+  [73] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_message_body_repeat1, 2, 0, 5),
+  [75] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_message_body_repeat1, 2, 0, 5), SHIFT_REPEAT(206),
+  [78] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_message_body_repeat1, 2, 0, 5), SHIFT_REPEAT(168),
+  [81] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_message_body_repeat1, 2, 0, 5), SHIFT_REPEAT(55),
+  [84] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_message_body_repeat1, 2, 0, 5), SHIFT_REPEAT(238),
+  [87] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_message_body_repeat1, 2, 0, 5), SHIFT_REPEAT(322),
+  [90] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_message_body_repeat1, 2, 0, 5), SHIFT_REPEAT(239),
+  [93] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_message_body_repeat1, 2, 0, 5), SHIFT_REPEAT(77),
+  [96] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_message_body_repeat1, 2, 0, 5), SHIFT_REPEAT(104),
+  [99] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_message_body_repeat1, 2, 0, 5), SHIFT_REPEAT(61),
+  [102] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_message_body_repeat1, 2, 0, 5), SHIFT_REPEAT(63),
+  [105] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
+  [107] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum, 3, 0, 8),
+  [109] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum, 3, 0, 8),
+  [111] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_option, 5, 0, 11),
+  [113] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_option, 5, 0, 11),
+  [115] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_message_body_repeat1, 1, 0, 1),
+  [117] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_message_body_repeat1, 1, 0, 1),
+  [119] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extensions, 3, 0, 20),
+  [121] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extensions, 3, 0, 20),
+  [123] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_oneof, 5, 0, 16),
+  [125] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_oneof, 5, 0, 16),
+  [127] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_oneof, 4, 0, 7),
+  [129] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_oneof, 4, 0, 7),
+  [131] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field, 5, 0, 26),
+  [133] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_field, 5, 0, 26),
+  [135] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field, 6, 0, 27),
+  [137] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_field, 6, 0, 27),
+  [139] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field, 8, 0, 35),
+  [141] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_field, 8, 0, 35),
+  [143] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field, 9, 0, 36),
+  [145] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_field, 9, 0, 36),
+  [147] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_map_field, 10, 0, 37),
+  [149] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_map_field, 10, 0, 37),
+  [151] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_map_field, 13, 0, 43),
+  [153] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_map_field, 13, 0, 43),
+  [155] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_message, 3, 0, 8),
+  [157] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_message, 3, 0, 8),
+  [159] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extend, 3, 0, 8),
+  [161] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extend, 3, 0, 8),
+  [163] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_body, 2, 0, 0),
+  [165] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum_body, 2, 0, 0),
+  [167] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_message_body, 2, 0, 0),
+  [169] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_message_body, 2, 0, 0),
+  [171] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_body, 3, 0, 15),
+  [173] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum_body, 3, 0, 15),
+  [175] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_message_body, 3, 0, 15),
+  [177] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_message_body, 3, 0, 15),
+  [179] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_reserved, 3, 0, 18),
+  [181] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_reserved, 3, 0, 18),
+  [183] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_empty_statement, 1, 0, 0),
+  [185] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_empty_statement, 1, 0, 0),
+  [187] = {.entry = {.count = 1, .reusable = true}}, SHIFT(50),
+  [189] = {.entry = {.count = 1, .reusable = false}}, SHIFT(166),
+  [191] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
+  [193] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
+  [195] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_oneof_repeat1, 2, 0, 5), SHIFT_REPEAT(50),
+  [198] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_oneof_repeat1, 2, 0, 5), SHIFT_REPEAT(166),
+  [201] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_oneof_repeat1, 2, 0, 5), SHIFT_REPEAT(202),
+  [204] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_oneof_repeat1, 2, 0, 5),
+  [206] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_oneof_repeat1, 2, 0, 5), SHIFT_REPEAT(239),
+  [209] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_oneof_repeat1, 2, 0, 5), SHIFT_REPEAT(61),
+  [212] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_oneof_repeat1, 2, 0, 5), SHIFT_REPEAT(63),
+  [215] = {.entry = {.count = 1, .reusable = true}}, SHIFT(132),
+  [217] = {.entry = {.count = 1, .reusable = true}}, SHIFT(38),
+  [219] = {.entry = {.count = 1, .reusable = true}}, SHIFT(148),
+  [221] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
+  [223] = {.entry = {.count = 1, .reusable = false}}, SHIFT(111),
+  [225] = {.entry = {.count = 1, .reusable = false}}, SHIFT(139),
+  [227] = {.entry = {.count = 1, .reusable = true}}, SHIFT(139),
+  [229] = {.entry = {.count = 1, .reusable = false}}, SHIFT(112),
+  [231] = {.entry = {.count = 1, .reusable = true}}, SHIFT(185),
+  [233] = {.entry = {.count = 1, .reusable = true}}, SHIFT(167),
+  [235] = {.entry = {.count = 1, .reusable = true}}, SHIFT(96),
+  [237] = {.entry = {.count = 1, .reusable = true}}, SHIFT(34),
+  [239] = {.entry = {.count = 1, .reusable = true}}, SHIFT(32),
+  [241] = {.entry = {.count = 1, .reusable = true}}, SHIFT(36),
+  [243] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field_number, 1, 0, 0),
+  [245] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_field_number, 1, 0, 0),
+  [247] = {.entry = {.count = 1, .reusable = true}}, SHIFT(150),
+  [249] = {.entry = {.count = 1, .reusable = true}}, SHIFT(155),
+  [251] = {.entry = {.count = 1, .reusable = false}}, SHIFT(163),
+  [253] = {.entry = {.count = 1, .reusable = true}}, SHIFT(163),
+  [255] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_oneof_field, 4, 0, 26),
+  [257] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_oneof_field, 4, 0, 26),
+  [259] = {.entry = {.count = 1, .reusable = true}}, SHIFT(106),
+  [261] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
+  [263] = {.entry = {.count = 1, .reusable = true}}, SHIFT(147),
+  [265] = {.entry = {.count = 1, .reusable = true}}, SHIFT(144),
+  [267] = {.entry = {.count = 1, .reusable = true}}, SHIFT(39),
+  [269] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_int_lit, 1, 0, 0),
+  [271] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_int_lit, 1, 0, 0),
+  [273] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_oneof_field, 7, 0, 35),
+  [275] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_oneof_field, 7, 0, 35),
+  [277] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_oneof_repeat1, 1, 0, 1),
+  [279] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_oneof_repeat1, 1, 0, 1),
+  [281] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2, 0, 4),
+  [283] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field_label, 1, 0, 0),
+  [285] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_field_label, 1, 0, 0),
+  [287] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1, 0, 2),
+  [289] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1, 0, 3),
+  [291] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 5),
+  [293] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 5), SHIFT_REPEAT(70),
+  [296] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 5), SHIFT_REPEAT(100),
+  [299] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 5), SHIFT_REPEAT(179),
+  [302] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 5), SHIFT_REPEAT(160),
+  [305] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 5), SHIFT_REPEAT(200),
+  [308] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 5), SHIFT_REPEAT(169),
+  [311] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 5), SHIFT_REPEAT(174),
+  [314] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 5), SHIFT_REPEAT(176),
+  [317] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_identifier, 1, 0, 0),
+  [319] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_identifier, 1, 0, 0),
+  [321] = {.entry = {.count = 1, .reusable = true}}, SHIFT(293),
+  [323] = {.entry = {.count = 1, .reusable = true}}, SHIFT(134),
+  [325] = {.entry = {.count = 1, .reusable = false}}, SHIFT(164),
+  [327] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
+  [329] = {.entry = {.count = 1, .reusable = false}}, SHIFT(82),
+  [331] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_option_name_repeat1, 2, 0, 0),
+  [333] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_option_name_repeat1, 2, 0, 0), SHIFT_REPEAT(237),
+  [336] = {.entry = {.count = 1, .reusable = true}}, SHIFT(21),
+  [338] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
+  [340] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_enum_body_repeat1, 2, 0, 5), SHIFT_REPEAT(134),
+  [343] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_enum_body_repeat1, 2, 0, 5), SHIFT_REPEAT(164),
+  [346] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_enum_body_repeat1, 2, 0, 5),
+  [348] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_enum_body_repeat1, 2, 0, 5), SHIFT_REPEAT(82),
+  [351] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_enum_body_repeat1, 2, 0, 5), SHIFT_REPEAT(61),
+  [354] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_enum_body_repeat1, 2, 0, 5), SHIFT_REPEAT(63),
+  [357] = {.entry = {.count = 1, .reusable = true}}, SHIFT(90),
+  [359] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_full_ident, 2, 0, 0),
+  [361] = {.entry = {.count = 1, .reusable = true}}, SHIFT(237),
+  [363] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_full_ident, 1, 0, 0),
+  [365] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_string_repeat3, 2, 0, 0),
+  [367] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_string_repeat3, 2, 0, 0), SHIFT_REPEAT(185),
+  [370] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_string_repeat3, 2, 0, 0), SHIFT_REPEAT(167),
+  [373] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string, 1, 0, 0),
+  [375] = {.entry = {.count = 1, .reusable = true}}, SHIFT(210),
+  [377] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_edition, 4, 0, 9),
+  [379] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_syntax, 4, 0, 0),
+  [381] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_string_repeat3, 3, 0, 0),
+  [383] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import, 4, 0, 10),
+  [385] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import, 3, 0, 6),
+  [387] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_service, 4, 0, 7),
+  [389] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_package, 3, 0, 7),
+  [391] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 1, 0, 1),
+  [393] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_service, 5, 0, 16),
+  [395] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
+  [397] = {.entry = {.count = 1, .reusable = true}}, SHIFT(171),
+  [399] = {.entry = {.count = 1, .reusable = true}}, SHIFT(93),
+  [401] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_service_repeat1, 2, 0, 5), SHIFT_REPEAT(70),
+  [404] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_service_repeat1, 2, 0, 5), SHIFT_REPEAT(160),
+  [407] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_service_repeat1, 2, 0, 5),
+  [409] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_service_repeat1, 2, 0, 5), SHIFT_REPEAT(171),
+  [412] = {.entry = {.count = 1, .reusable = true}}, SHIFT(240),
+  [414] = {.entry = {.count = 1, .reusable = true}}, SHIFT(307),
+  [416] = {.entry = {.count = 1, .reusable = false}}, SHIFT(121),
+  [418] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block_lit, 3, 0, 0),
+  [420] = {.entry = {.count = 1, .reusable = true}}, SHIFT(180),
+  [422] = {.entry = {.count = 1, .reusable = true}}, SHIFT(61),
+  [424] = {.entry = {.count = 1, .reusable = false}}, SHIFT(118),
+  [426] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block_lit, 2, 0, 0),
+  [428] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_constant, 2, 0, 0),
+  [430] = {.entry = {.count = 1, .reusable = true}}, SHIFT(162),
+  [432] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_bool, 1, 0, 0),
+  [434] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_constant, 1, 0, 0),
+  [436] = {.entry = {.count = 1, .reusable = false}}, SHIFT(140),
+  [438] = {.entry = {.count = 1, .reusable = true}}, SHIFT(187),
+  [440] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_block_lit_repeat2, 8, 0, 0),
+  [442] = {.entry = {.count = 1, .reusable = true}}, SHIFT(266),
+  [444] = {.entry = {.count = 1, .reusable = true}}, SHIFT(182),
+  [446] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_block_lit_repeat2, 6, 0, 0),
+  [448] = {.entry = {.count = 1, .reusable = true}}, SHIFT(191),
+  [450] = {.entry = {.count = 1, .reusable = true}}, SHIFT(193),
+  [452] = {.entry = {.count = 1, .reusable = true}}, SHIFT(194),
+  [454] = {.entry = {.count = 1, .reusable = true}}, SHIFT(195),
+  [456] = {.entry = {.count = 1, .reusable = true}}, SHIFT(196),
+  [458] = {.entry = {.count = 1, .reusable = true}}, SHIFT(197),
+  [460] = {.entry = {.count = 1, .reusable = true}}, SHIFT(198),
+  [462] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_rpc_repeat1, 2, 0, 5), SHIFT_REPEAT(70),
+  [465] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_rpc_repeat1, 2, 0, 5), SHIFT_REPEAT(160),
+  [468] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_rpc_repeat1, 2, 0, 5),
+  [470] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
+  [472] = {.entry = {.count = 1, .reusable = true}}, SHIFT(175),
+  [474] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
+  [476] = {.entry = {.count = 1, .reusable = true}}, SHIFT(59),
+  [478] = {.entry = {.count = 1, .reusable = true}}, SHIFT(199),
+  [480] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_field, 7, 0, 31),
+  [482] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum_field, 7, 0, 31),
+  [484] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
+  [486] = {.entry = {.count = 1, .reusable = true}}, SHIFT(42),
+  [488] = {.entry = {.count = 1, .reusable = false}}, SHIFT(42),
+  [490] = {.entry = {.count = 1, .reusable = true}}, SHIFT(177),
+  [492] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_block_lit_repeat2, 4, 0, 0),
+  [494] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_field, 4, 0, 25),
+  [496] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum_field, 4, 0, 25),
+  [498] = {.entry = {.count = 1, .reusable = true}}, SHIFT(184),
+  [500] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_block_lit_repeat2, 7, 0, 0),
+  [502] = {.entry = {.count = 1, .reusable = true}}, SHIFT(172),
+  [504] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_block_lit_repeat2, 3, 0, 0),
+  [506] = {.entry = {.count = 1, .reusable = true}}, SHIFT(109),
+  [508] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_field, 8, 0, 34),
+  [510] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enum_field, 8, 0, 34),
+  [512] = {.entry = {.count = 1, .reusable = true}}, SHIFT(178),
+  [514] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_block_lit_repeat2, 5, 0, 0),
+  [516] = {.entry = {.count = 1, .reusable = true}}, SHIFT(204),
+  [518] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_block_lit_repeat2, 2, 0, 0),
+  [520] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_enum_body_repeat1, 1, 0, 1),
+  [522] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_enum_body_repeat1, 1, 0, 1),
+  [524] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_lit_repeat2, 2, 0, 0), SHIFT_REPEAT(175),
+  [527] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_lit_repeat2, 2, 0, 0), SHIFT_REPEAT(60),
+  [530] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_lit_repeat2, 2, 0, 0), SHIFT_REPEAT(59),
+  [533] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_message_or_enum_type, 2, 0, 0),
+  [535] = {.entry = {.count = 1, .reusable = true}}, SHIFT(244),
+  [537] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_message_or_enum_type, 3, 0, 0),
+  [539] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_message_or_enum_type, 1, 0, 0),
+  [541] = {.entry = {.count = 1, .reusable = false}}, SHIFT(83),
+  [543] = {.entry = {.count = 1, .reusable = true}}, SHIFT(203),
+  [545] = {.entry = {.count = 1, .reusable = false}}, SHIFT_EXTRA(),
+  [547] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_service_repeat1, 1, 0, 1),
+  [549] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_string_repeat1, 2, 0, 0),
+  [551] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_string_repeat1, 2, 0, 0), SHIFT_REPEAT(183),
+  [554] = {.entry = {.count = 1, .reusable = true}}, SHIFT(201),
+  [556] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_message_or_enum_type_repeat1, 2, 0, 0), SHIFT_REPEAT(61),
+  [559] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_message_or_enum_type_repeat1, 2, 0, 0), SHIFT_REPEAT(63),
+  [562] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_block_lit_repeat2, 9, 0, 0),
+  [564] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_rpc, 10, 0, 38),
+  [566] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_rpc, 11, 0, 39),
+  [568] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_rpc, 11, 0, 40),
+  [570] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_rpc, 11, 0, 38),
+  [572] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_rpc, 12, 0, 41),
+  [574] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_rpc, 12, 0, 39),
+  [576] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_rpc, 12, 0, 40),
+  [578] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_rpc, 12, 0, 42),
+  [580] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_rpc, 13, 0, 41),
+  [582] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_rpc, 13, 0, 44),
+  [584] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_rpc, 13, 0, 45),
+  [586] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_rpc, 14, 0, 46),
+  [588] = {.entry = {.count = 1, .reusable = false}}, SHIFT(80),
+  [590] = {.entry = {.count = 1, .reusable = true}}, SHIFT(183),
+  [592] = {.entry = {.count = 1, .reusable = true}}, SHIFT(207),
+  [594] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_string_repeat2, 2, 0, 0),
+  [596] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_string_repeat2, 2, 0, 0), SHIFT_REPEAT(207),
+  [599] = {.entry = {.count = 1, .reusable = true}}, SHIFT(44),
+  [601] = {.entry = {.count = 1, .reusable = true}}, SHIFT(153),
+  [603] = {.entry = {.count = 1, .reusable = true}}, SHIFT(274),
+  [605] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_reserved_field_names, 1, 0, 12),
+  [607] = {.entry = {.count = 1, .reusable = true}}, SHIFT(277),
+  [609] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ranges, 1, 0, 13),
+  [611] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
+  [613] = {.entry = {.count = 1, .reusable = true}}, SHIFT(143),
+  [615] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field_options, 1, 0, 28),
+  [617] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_option_name, 3, 0, 0),
+  [619] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_option_name, 4, 0, 0),
+  [621] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_rpc_repeat1, 1, 0, 1),
+  [623] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_range, 1, 0, 14),
+  [625] = {.entry = {.count = 1, .reusable = true}}, SHIFT(120),
+  [627] = {.entry = {.count = 1, .reusable = true}}, SHIFT(146),
+  [629] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_option_name, 2, 0, 0),
+  [631] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_reserved_field_names, 2, 0, 17),
+  [633] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_enum_field_repeat1, 2, 0, 32), SHIFT_REPEAT(153),
+  [636] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_enum_field_repeat1, 2, 0, 32),
+  [638] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ranges, 2, 0, 19),
+  [640] = {.entry = {.count = 1, .reusable = true}}, SHIFT(300),
+  [642] = {.entry = {.count = 1, .reusable = true}}, SHIFT(119),
+  [644] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_option_name, 1, 0, 0),
+  [646] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_field_options_repeat1, 2, 0, 32), SHIFT_REPEAT(143),
+  [649] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_field_options_repeat1, 2, 0, 32),
+  [651] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_reserved_field_names_repeat1, 2, 0, 21),
+  [653] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_reserved_field_names_repeat1, 2, 0, 21), SHIFT_REPEAT(277),
+  [656] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_ranges_repeat1, 2, 0, 23),
+  [658] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_ranges_repeat1, 2, 0, 23), SHIFT_REPEAT(117),
+  [661] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_lit_repeat1, 2, 0, 0), SHIFT_REPEAT(44),
+  [664] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_block_lit_repeat1, 2, 0, 0),
+  [666] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type, 1, 0, 0),
+  [668] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_modifier, 1, 0, 0),
+  [670] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field_options, 2, 0, 33),
+  [672] = {.entry = {.count = 1, .reusable = true}}, SHIFT(17),
+  [674] = {.entry = {.count = 1, .reusable = true}}, SHIFT(107),
+  [676] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_message_or_enum_type_repeat1, 2, 0, 0),
+  [678] = {.entry = {.count = 1, .reusable = true}}, SHIFT(188),
+  [680] = {.entry = {.count = 1, .reusable = true}}, SHIFT(124),
+  [682] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
+  [684] = {.entry = {.count = 1, .reusable = true}}, SHIFT(189),
+  [686] = {.entry = {.count = 1, .reusable = true}}, SHIFT(125),
+  [688] = {.entry = {.count = 1, .reusable = true}}, SHIFT(190),
+  [690] = {.entry = {.count = 1, .reusable = true}}, SHIFT(126),
+  [692] = {.entry = {.count = 1, .reusable = true}}, SHIFT(192),
+  [694] = {.entry = {.count = 1, .reusable = true}}, SHIFT(128),
+  [696] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
+  [698] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
+  [700] = {.entry = {.count = 1, .reusable = true}}, SHIFT(114),
+  [702] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_value_option, 3, 0, 29),
+  [704] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_enum_field_repeat1, 2, 0, 30),
+  [706] = {.entry = {.count = 1, .reusable = true}}, SHIFT(145),
+  [708] = {.entry = {.count = 1, .reusable = true}}, SHIFT(138),
+  [710] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_signed_int_lit, 1, 0, 0),
+  [712] = {.entry = {.count = 1, .reusable = true}}, SHIFT(292),
+  [714] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
+  [716] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
+  [718] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
+  [720] = {.entry = {.count = 1, .reusable = true}}, SHIFT(6),
+  [722] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_reserved_field_names_repeat1, 2, 0, 7),
+  [724] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_ranges_repeat1, 2, 0, 22),
+  [726] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field_option, 3, 0, 29),
+  [728] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_range, 3, 0, 24),
+  [730] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_signed_int_lit, 2, 0, 0),
+  [732] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_field_options_repeat1, 2, 0, 30),
+  [734] = {.entry = {.count = 1, .reusable = true}}, SHIFT(101),
+  [736] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_message_name, 1, 0, 0),
+  [738] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
+  [740] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_rpc_name, 1, 0, 0),
+  [742] = {.entry = {.count = 1, .reusable = true}}, SHIFT(43),
+  [744] = {.entry = {.count = 1, .reusable = true}}, SHIFT(149),
+  [746] = {.entry = {.count = 1, .reusable = true}}, SHIFT(97),
+  [748] = {.entry = {.count = 1, .reusable = true}}, SHIFT(214),
+  [750] = {.entry = {.count = 1, .reusable = true}}, SHIFT(263),
+  [752] = {.entry = {.count = 1, .reusable = true}}, SHIFT(88),
+  [754] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
+  [756] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_service_name, 1, 0, 0),
+  [758] = {.entry = {.count = 1, .reusable = true}}, SHIFT(316),
+  [760] = {.entry = {.count = 1, .reusable = true}}, SHIFT(258),
+  [762] = {.entry = {.count = 1, .reusable = true}}, SHIFT(211),
+  [764] = {.entry = {.count = 1, .reusable = true}}, SHIFT(15),
+  [766] = {.entry = {.count = 1, .reusable = true}}, SHIFT(49),
+  [768] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [770] = {.entry = {.count = 1, .reusable = true}}, SHIFT(245),
+  [772] = {.entry = {.count = 1, .reusable = true}}, SHIFT(73),
+  [774] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enum_name, 1, 0, 0),
+  [776] = {.entry = {.count = 1, .reusable = true}}, SHIFT(249),
+  [778] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
+  [780] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
+  [782] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_key_type, 1, 0, 0),
+  [784] = {.entry = {.count = 1, .reusable = true}}, SHIFT(41),
+  [786] = {.entry = {.count = 1, .reusable = true}}, SHIFT(327),
+  [788] = {.entry = {.count = 1, .reusable = true}}, SHIFT(115),
+  [790] = {.entry = {.count = 1, .reusable = true}}, SHIFT(46),
+  [792] = {.entry = {.count = 1, .reusable = true}}, SHIFT(156),
+  [794] = {.entry = {.count = 1, .reusable = true}}, SHIFT(136),
+  [796] = {.entry = {.count = 1, .reusable = true}}, SHIFT(28),
+  [798] = {.entry = {.count = 1, .reusable = true}}, SHIFT(269),
+  [800] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
+  [802] = {.entry = {.count = 1, .reusable = true}}, SHIFT(84),
+  [804] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
+  [806] = {.entry = {.count = 1, .reusable = true}}, SHIFT(271),
+  [808] = {.entry = {.count = 1, .reusable = true}}, SHIFT(113),
+  [810] = {.entry = {.count = 1, .reusable = true}}, SHIFT(10),
+  [812] = {.entry = {.count = 1, .reusable = true}}, SHIFT(142),
+  [814] = {.entry = {.count = 1, .reusable = true}}, SHIFT(295),
+  [816] = {.entry = {.count = 1, .reusable = true}}, SHIFT(45),
+  [818] = {.entry = {.count = 1, .reusable = true}}, SHIFT(25),
+  [820] = {.entry = {.count = 1, .reusable = true}}, SHIFT(8),
+  [822] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
+  [824] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
+  [826] = {.entry = {.count = 1, .reusable = true}}, SHIFT(247),
+  [828] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
+  [830] = {.entry = {.count = 1, .reusable = true}}, SHIFT(141),
+  [832] = {.entry = {.count = 1, .reusable = true}}, SHIFT(248),
+  [834] = {.entry = {.count = 1, .reusable = true}}, SHIFT(284),
+  [836] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
+  [838] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
+  [840] = {.entry = {.count = 1, .reusable = true}}, SHIFT(53),
+  [842] = {.entry = {.count = 1, .reusable = true}}, SHIFT(47),
+  [844] = {.entry = {.count = 1, .reusable = true}}, SHIFT(135),
+  [846] = {.entry = {.count = 1, .reusable = true}}, SHIFT(105),
+  [848] = {.entry = {.count = 1, .reusable = true}}, SHIFT(302),
+  [850] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+#ifdef TREE_SITTER_HIDE_SYMBOLS
+#define TS_PUBLIC
+#elif defined(_WIN32)
+#define TS_PUBLIC __declspec(dllexport)
+#else
+#define TS_PUBLIC __attribute__((visibility("default")))
+#endif
+
+TS_PUBLIC const TSLanguage *tree_sitter_proto(void) {
+  static const TSLanguage language = {
+    .version = LANGUAGE_VERSION,
+    .symbol_count = SYMBOL_COUNT,
+    .alias_count = ALIAS_COUNT,
+    .token_count = TOKEN_COUNT,
+    .external_token_count = EXTERNAL_TOKEN_COUNT,
+    .state_count = STATE_COUNT,
+    .large_state_count = LARGE_STATE_COUNT,
+    .production_id_count = PRODUCTION_ID_COUNT,
+    .field_count = FIELD_COUNT,
+    .max_alias_sequence_length = MAX_ALIAS_SEQUENCE_LENGTH,
+    .parse_table = &ts_parse_table[0][0],
+    .small_parse_table = ts_small_parse_table,
+    .small_parse_table_map = ts_small_parse_table_map,
+    .parse_actions = ts_parse_actions,
+    .symbol_names = ts_symbol_names,
+    .field_names = ts_field_names,
+    .field_map_slices = ts_field_map_slices,
+    .field_map_entries = ts_field_map_entries,
+    .symbol_metadata = ts_symbol_metadata,
+    .public_symbol_map = ts_symbol_map,
+    .alias_map = ts_non_terminal_alias_map,
+    .alias_sequences = &ts_alias_sequences[0][0],
+    .lex_modes = ts_lex_modes,
+    .lex_fn = ts_lex,
+    .primary_state_ids = ts_primary_state_ids,
+  };
+  return &language;
+}
+#ifdef __cplusplus
+}
+#endif
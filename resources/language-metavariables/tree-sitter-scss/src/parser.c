@@ -0,0 +1,30384 @@
+#include "tree_sitter/parser.h"
+
+#if defined(__GNUC__) || defined(__clang__)
+#pragma GCC diagnostic ignored "-Wmissing-field-initializers"
+#endif
+
+#ifdef _MSC_VER
+#pragma optimize("", off)
+#elif defined(__clang__)
+#pragma clang optimize off
+#elif defined(__GNUC__)
+#pragma GCC optimize ("O0")
+#endif
+
+#define LANGUAGE_VERSION 14
+#define STATE_COUNT 746
+#define LARGE_STATE_COUNT 15
+#define SYMBOL_COUNT 188
+#define ALIAS_COUNT 11
+#define TOKEN_COUNT 93
+#define EXTERNAL_TOKEN_COUNT 4
+#define FIELD_COUNT 54
+#define MAX_ALIAS_SEQUENCE_LENGTH 11
+#define PRODUCTION_ID_COUNT 134
+
+enum ts_symbol_identifiers {
+  anon_sym_ATimport = 1,
+  anon_sym_COMMA = 2,
+  anon_sym_SEMI = 3,
+  anon_sym_ATmedia = 4,
+  anon_sym_ATcharset = 5,
+  anon_sym_ATnamespace = 6,
+  anon_sym_ATkeyframes = 7,
+  aux_sym_keyframes_statement_token1 = 8,
+  anon_sym_LBRACE = 9,
+  anon_sym_RBRACE = 10,
+  anon_sym_from = 11,
+  anon_sym_to = 12,
+  anon_sym_ATsupports = 13,
+  sym_nesting_selector = 14,
+  anon_sym_STAR = 15,
+  anon_sym_DOT = 16,
+  anon_sym_COLON = 17,
+  anon_sym_COLON_COLON = 18,
+  anon_sym_POUND = 19,
+  sym_equal = 20,
+  sym_contains_word_equal = 21,
+  sym_starts_with_equal = 22,
+  sym_dash_equal = 23,
+  sym_contains_equal = 24,
+  sym_ends_equal = 25,
+  anon_sym_LBRACK = 26,
+  anon_sym_RBRACK = 27,
+  anon_sym_GT = 28,
+  anon_sym_TILDE = 29,
+  anon_sym_PLUS = 30,
+  anon_sym_LPAREN = 31,
+  anon_sym_RPAREN = 32,
+  sym_important = 33,
+  anon_sym_LPAREN2 = 34,
+  anon_sym_and = 35,
+  anon_sym_or = 36,
+  sym_not = 37,
+  sym_only = 38,
+  anon_sym_selector = 39,
+  aux_sym_color_value_token1 = 40,
+  sym_string_value = 41,
+  aux_sym_integer_value_token1 = 42,
+  aux_sym_float_value_token1 = 43,
+  sym_unit = 44,
+  sym_minus = 45,
+  sym_divide = 46,
+  anon_sym_EQ_EQ = 47,
+  anon_sym_BANG_EQ = 48,
+  anon_sym_LT = 49,
+  anon_sym_LT_EQ = 50,
+  anon_sym_GT_EQ = 51,
+  sym_identifier = 52,
+  sym_at_keyword = 53,
+  sym_comment = 54,
+  sym_plain_value = 55,
+  sym_grit_metavariable = 56,
+  anon_sym_BANGdefault = 57,
+  anon_sym_BANGglobal = 58,
+  anon_sym_ATuse = 59,
+  anon_sym_as = 60,
+  anon_sym_with = 61,
+  anon_sym_ATforward = 62,
+  anon_sym_STAR2 = 63,
+  anon_sym_show = 64,
+  anon_sym_hide = 65,
+  anon_sym_ATmixin = 66,
+  anon_sym_ATinclude = 67,
+  anon_sym_ATfunction = 68,
+  anon_sym_ATreturn = 69,
+  anon_sym_ATextend = 70,
+  anon_sym_BANGoptional = 71,
+  anon_sym_ATerror = 72,
+  anon_sym_ATwarn = 73,
+  anon_sym_ATdebug = 74,
+  anon_sym_ATat_DASHroot = 75,
+  anon_sym_ATif = 76,
+  anon_sym_ATelse = 77,
+  anon_sym_if = 78,
+  anon_sym_ATeach = 79,
+  anon_sym_in = 80,
+  anon_sym_ATfor = 81,
+  anon_sym_through = 82,
+  anon_sym_ATwhile = 83,
+  anon_sym_POUND_LBRACE = 84,
+  anon_sym_PERCENT = 85,
+  sym_module_member = 86,
+  anon_sym_DASH = 87,
+  sym_variable = 88,
+  sym__descendant_operator = 89,
+  sym__pseudo_class_selector_colon = 90,
+  sym___error_recovery = 91,
+  sym__concat = 92,
+  sym_stylesheet = 93,
+  sym_import_statement = 94,
+  sym_media_statement = 95,
+  sym_charset_statement = 96,
+  sym_namespace_statement = 97,
+  sym_keyframes_statement = 98,
+  sym_keyframe_block_list = 99,
+  sym_keyframe_block = 100,
+  sym_from = 101,
+  sym_to = 102,
+  sym_supports_statement = 103,
+  sym_at_rule = 104,
+  sym_rule_set = 105,
+  sym_selectors = 106,
+  sym_block = 107,
+  sym__selector = 108,
+  sym_universal_selector = 109,
+  sym_class_selector = 110,
+  sym_pseudo_class_selector = 111,
+  sym_pseudo_element_selector = 112,
+  sym_id_selector = 113,
+  sym_attribute_selector = 114,
+  sym_child_selector = 115,
+  sym_descendant_selector = 116,
+  sym_sibling_selector = 117,
+  sym_adjacent_sibling_selector = 118,
+  sym_pseudo_class_arguments = 119,
+  sym_pseudo_element_arguments = 120,
+  sym_declaration = 121,
+  sym_last_declaration = 122,
+  sym__query = 123,
+  sym_feature_query = 124,
+  sym_parenthesized_query = 125,
+  sym_and = 126,
+  sym_or = 127,
+  sym_binary_query = 128,
+  sym_unary_query = 129,
+  sym_selector_query = 130,
+  sym__value = 131,
+  sym_parenthesized_value = 132,
+  sym_color_value = 133,
+  sym_integer_value = 134,
+  sym_float_value = 135,
+  sym_call_expression = 136,
+  sym_plus = 137,
+  sym_times = 138,
+  sym_binary_expression = 139,
+  sym_arguments = 140,
+  sym_default_flag = 141,
+  sym_use_statement = 142,
+  sym_forward_statement = 143,
+  sym_mixin_statement = 144,
+  sym_include_statement = 145,
+  sym__include_arguments = 146,
+  sym__include_argument = 147,
+  sym_function_statement = 148,
+  sym_parameters = 149,
+  sym_parameter = 150,
+  sym_return_statement = 151,
+  sym_extend_statement = 152,
+  sym_error_statement = 153,
+  sym_warn_statement = 154,
+  sym_debug_statement = 155,
+  sym_at_root_statement = 156,
+  sym_if_statement = 157,
+  sym_else_if_clause = 158,
+  sym_else_clause = 159,
+  sym_each_statement = 160,
+  sym_for_statement = 161,
+  sym_while_statement = 162,
+  sym_list_value = 163,
+  sym_map_value = 164,
+  sym_map_pair = 165,
+  sym_interpolation = 166,
+  sym_placeholder = 167,
+  sym__name = 168,
+  sym__concatenated_identifier = 169,
+  aux_sym_stylesheet_repeat1 = 170,
+  aux_sym_import_statement_repeat1 = 171,
+  aux_sym_media_statement_repeat1 = 172,
+  aux_sym_keyframe_block_list_repeat1 = 173,
+  aux_sym_at_rule_repeat1 = 174,
+  aux_sym_selectors_repeat1 = 175,
+  aux_sym_block_repeat1 = 176,
+  aux_sym_pseudo_class_arguments_repeat1 = 177,
+  aux_sym_pseudo_class_arguments_repeat2 = 178,
+  aux_sym_declaration_repeat1 = 179,
+  aux_sym_arguments_repeat1 = 180,
+  aux_sym_forward_statement_repeat1 = 181,
+  aux_sym__include_arguments_repeat1 = 182,
+  aux_sym_parameters_repeat1 = 183,
+  aux_sym_if_statement_repeat1 = 184,
+  aux_sym_list_value_repeat1 = 185,
+  aux_sym_map_value_repeat1 = 186,
+  aux_sym__concatenated_identifier_repeat1 = 187,
+  alias_sym_attribute_name = 188,
+  alias_sym_class_name = 189,
+  alias_sym_feature_name = 190,
+  alias_sym_greater_than = 191,
+  alias_sym_id_name = 192,
+  alias_sym_keyframes_name = 193,
+  alias_sym_keyword_query = 194,
+  alias_sym_namespace_name = 195,
+  alias_sym_property_name = 196,
+  alias_sym_tag_name = 197,
+  alias_sym_wildcard = 198,
+};
+
+static const char * const ts_symbol_names[] = {
+  [ts_builtin_sym_end] = "end",
+  [anon_sym_ATimport] = "@import",
+  [anon_sym_COMMA] = ",",
+  [anon_sym_SEMI] = ";",
+  [anon_sym_ATmedia] = "@media",
+  [anon_sym_ATcharset] = "@charset",
+  [anon_sym_ATnamespace] = "@namespace",
+  [anon_sym_ATkeyframes] = "@keyframes",
+  [aux_sym_keyframes_statement_token1] = "at_keyword",
+  [anon_sym_LBRACE] = "{",
+  [anon_sym_RBRACE] = "}",
+  [anon_sym_from] = "from",
+  [anon_sym_to] = "to",
+  [anon_sym_ATsupports] = "@supports",
+  [sym_nesting_selector] = "nesting_selector",
+  [anon_sym_STAR] = "*",
+  [anon_sym_DOT] = ".",
+  [anon_sym_COLON] = ":",
+  [anon_sym_COLON_COLON] = "::",
+  [anon_sym_POUND] = "#",
+  [sym_equal] = "equal",
+  [sym_contains_word_equal] = "contains_word_equal",
+  [sym_starts_with_equal] = "starts_with_equal",
+  [sym_dash_equal] = "dash_equal",
+  [sym_contains_equal] = "contains_equal",
+  [sym_ends_equal] = "ends_equal",
+  [anon_sym_LBRACK] = "[",
+  [anon_sym_RBRACK] = "]",
+  [anon_sym_GT] = ">",
+  [anon_sym_TILDE] = "~",
+  [anon_sym_PLUS] = "+",
+  [anon_sym_LPAREN] = "(",
+  [anon_sym_RPAREN] = ")",
+  [sym_important] = "important",
+  [anon_sym_LPAREN2] = "(",
+  [anon_sym_and] = "and",
+  [anon_sym_or] = "or",
+  [sym_not] = "not",
+  [sym_only] = "only",
+  [anon_sym_selector] = "selector",
+  [aux_sym_color_value_token1] = "color_value_token1",
+  [sym_string_value] = "string_value",
+  [aux_sym_integer_value_token1] = "integer_value_token1",
+  [aux_sym_float_value_token1] = "float_value_token1",
+  [sym_unit] = "unit",
+  [sym_minus] = "minus",
+  [sym_divide] = "divide",
+  [anon_sym_EQ_EQ] = "equal",
+  [anon_sym_BANG_EQ] = "not_equal",
+  [anon_sym_LT] = "less_than",
+  [anon_sym_LT_EQ] = "less_than_or_equal",
+  [anon_sym_GT_EQ] = "greater_than_or_equal",
+  [sym_identifier] = "identifier",
+  [sym_at_keyword] = "at_keyword",
+  [sym_comment] = "comment",
+  [sym_plain_value] = "plain_value",
+  [sym_grit_metavariable] = "grit_metavariable",
+  [anon_sym_BANGdefault] = "!default",
+  [anon_sym_BANGglobal] = "!global",
+  [anon_sym_ATuse] = "@use",
+  [anon_sym_as] = "as",
+  [anon_sym_with] = "with",
+  [anon_sym_ATforward] = "@forward",
+  [anon_sym_STAR2] = "*",
+  [anon_sym_show] = "show",
+  [anon_sym_hide] = "hide",
+  [anon_sym_ATmixin] = "@mixin",
+  [anon_sym_ATinclude] = "@include",
+  [anon_sym_ATfunction] = "@function",
+  [anon_sym_ATreturn] = "@return",
+  [anon_sym_ATextend] = "@extend",
+  [anon_sym_BANGoptional] = "optional_flag",
+  [anon_sym_ATerror] = "@error",
+  [anon_sym_ATwarn] = "@warn",
+  [anon_sym_ATdebug] = "@debug",
+  [anon_sym_ATat_DASHroot] = "@at-root",
+  [anon_sym_ATif] = "@if",
+  [anon_sym_ATelse] = "@else",
+  [anon_sym_if] = "if",
+  [anon_sym_ATeach] = "@each",
+  [anon_sym_in] = "in",
+  [anon_sym_ATfor] = "@for",
+  [anon_sym_through] = "through",
+  [anon_sym_ATwhile] = "@while",
+  [anon_sym_POUND_LBRACE] = "#{",
+  [anon_sym_PERCENT] = "%",
+  [sym_module_member] = "function_name",
+  [anon_sym_DASH] = "identifier",
+  [sym_variable] = "variable",
+  [sym__descendant_operator] = "_descendant_operator",
+  [sym__pseudo_class_selector_colon] = "_pseudo_class_selector_colon",
+  [sym___error_recovery] = "__error_recovery",
+  [sym__concat] = "_concat",
+  [sym_stylesheet] = "stylesheet",
+  [sym_import_statement] = "import_statement",
+  [sym_media_statement] = "media_statement",
+  [sym_charset_statement] = "charset_statement",
+  [sym_namespace_statement] = "namespace_statement",
+  [sym_keyframes_statement] = "keyframes_statement",
+  [sym_keyframe_block_list] = "keyframe_block_list",
+  [sym_keyframe_block] = "keyframe_block",
+  [sym_from] = "from",
+  [sym_to] = "to",
+  [sym_supports_statement] = "supports_statement",
+  [sym_at_rule] = "at_rule",
+  [sym_rule_set] = "rule_set",
+  [sym_selectors] = "selectors",
+  [sym_block] = "block",
+  [sym__selector] = "_selector",
+  [sym_universal_selector] = "universal_selector",
+  [sym_class_selector] = "class_selector",
+  [sym_pseudo_class_selector] = "pseudo_class_selector",
+  [sym_pseudo_element_selector] = "pseudo_element_selector",
+  [sym_id_selector] = "id_selector",
+  [sym_attribute_selector] = "attribute_selector",
+  [sym_child_selector] = "child_selector",
+  [sym_descendant_selector] = "descendant_selector",
+  [sym_sibling_selector] = "sibling_selector",
+  [sym_adjacent_sibling_selector] = "adjacent_sibling_selector",
+  [sym_pseudo_class_arguments] = "arguments",
+  [sym_pseudo_element_arguments] = "arguments",
+  [sym_declaration] = "declaration",
+  [sym_last_declaration] = "declaration",
+  [sym__query] = "_query",
+  [sym_feature_query] = "feature_query",
+  [sym_parenthesized_query] = "parenthesized_query",
+  [sym_and] = "and",
+  [sym_or] = "or",
+  [sym_binary_query] = "binary_query",
+  [sym_unary_query] = "unary_query",
+  [sym_selector_query] = "selector_query",
+  [sym__value] = "_value",
+  [sym_parenthesized_value] = "parenthesized_value",
+  [sym_color_value] = "color_value",
+  [sym_integer_value] = "integer_value",
+  [sym_float_value] = "float_value",
+  [sym_call_expression] = "call_expression",
+  [sym_plus] = "plus",
+  [sym_times] = "times",
+  [sym_binary_expression] = "binary_expression",
+  [sym_arguments] = "arguments",
+  [sym_default_flag] = "default_flag",
+  [sym_use_statement] = "use_statement",
+  [sym_forward_statement] = "forward_statement",
+  [sym_mixin_statement] = "mixin_statement",
+  [sym_include_statement] = "include_statement",
+  [sym__include_arguments] = "arguments",
+  [sym__include_argument] = "argument",
+  [sym_function_statement] = "function_statement",
+  [sym_parameters] = "parameters",
+  [sym_parameter] = "parameter",
+  [sym_return_statement] = "return_statement",
+  [sym_extend_statement] = "extend_statement",
+  [sym_error_statement] = "error_statement",
+  [sym_warn_statement] = "warn_statement",
+  [sym_debug_statement] = "debug_statement",
+  [sym_at_root_statement] = "at_root_statement",
+  [sym_if_statement] = "if_statement",
+  [sym_else_if_clause] = "else_if_clause",
+  [sym_else_clause] = "else_clause",
+  [sym_each_statement] = "each_statement",
+  [sym_for_statement] = "for_statement",
+  [sym_while_statement] = "while_statement",
+  [sym_list_value] = "list_value",
+  [sym_map_value] = "map_value",
+  [sym_map_pair] = "map_pair",
+  [sym_interpolation] = "interpolation",
+  [sym_placeholder] = "placeholder",
+  [sym__name] = "_name",
+  [sym__concatenated_identifier] = "_concatenated_identifier",
+  [aux_sym_stylesheet_repeat1] = "stylesheet_repeat1",
+  [aux_sym_import_statement_repeat1] = "import_statement_repeat1",
+  [aux_sym_media_statement_repeat1] = "media_statement_repeat1",
+  [aux_sym_keyframe_block_list_repeat1] = "keyframe_block_list_repeat1",
+  [aux_sym_at_rule_repeat1] = "at_rule_repeat1",
+  [aux_sym_selectors_repeat1] = "selectors_repeat1",
+  [aux_sym_block_repeat1] = "block_repeat1",
+  [aux_sym_pseudo_class_arguments_repeat1] = "pseudo_class_arguments_repeat1",
+  [aux_sym_pseudo_class_arguments_repeat2] = "pseudo_class_arguments_repeat2",
+  [aux_sym_declaration_repeat1] = "declaration_repeat1",
+  [aux_sym_arguments_repeat1] = "arguments_repeat1",
+  [aux_sym_forward_statement_repeat1] = "forward_statement_repeat1",
+  [aux_sym__include_arguments_repeat1] = "_include_arguments_repeat1",
+  [aux_sym_parameters_repeat1] = "parameters_repeat1",
+  [aux_sym_if_statement_repeat1] = "if_statement_repeat1",
+  [aux_sym_list_value_repeat1] = "list_value_repeat1",
+  [aux_sym_map_value_repeat1] = "map_value_repeat1",
+  [aux_sym__concatenated_identifier_repeat1] = "_concatenated_identifier_repeat1",
+  [alias_sym_attribute_name] = "attribute_name",
+  [alias_sym_class_name] = "class_name",
+  [alias_sym_feature_name] = "feature_name",
+  [alias_sym_greater_than] = "greater_than",
+  [alias_sym_id_name] = "id_name",
+  [alias_sym_keyframes_name] = "keyframes_name",
+  [alias_sym_keyword_query] = "keyword_query",
+  [alias_sym_namespace_name] = "namespace_name",
+  [alias_sym_property_name] = "property_name",
+  [alias_sym_tag_name] = "tag_name",
+  [alias_sym_wildcard] = "wildcard",
+};
+
+static const TSSymbol ts_symbol_map[] = {
+  [ts_builtin_sym_end] = ts_builtin_sym_end,
+  [anon_sym_ATimport] = anon_sym_ATimport,
+  [anon_sym_COMMA] = anon_sym_COMMA,
+  [anon_sym_SEMI] = anon_sym_SEMI,
+  [anon_sym_ATmedia] = anon_sym_ATmedia,
+  [anon_sym_ATcharset] = anon_sym_ATcharset,
+  [anon_sym_ATnamespace] = anon_sym_ATnamespace,
+  [anon_sym_ATkeyframes] = anon_sym_ATkeyframes,
+  [aux_sym_keyframes_statement_token1] = sym_at_keyword,
+  [anon_sym_LBRACE] = anon_sym_LBRACE,
+  [anon_sym_RBRACE] = anon_sym_RBRACE,
+  [anon_sym_from] = anon_sym_from,
+  [anon_sym_to] = anon_sym_to,
+  [anon_sym_ATsupports] = anon_sym_ATsupports,
+  [sym_nesting_selector] = sym_nesting_selector,
+  [anon_sym_STAR] = anon_sym_STAR,
+  [anon_sym_DOT] = anon_sym_DOT,
+  [anon_sym_COLON] = anon_sym_COLON,
+  [anon_sym_COLON_COLON] = anon_sym_COLON_COLON,
+  [anon_sym_POUND] = anon_sym_POUND,
+  [sym_equal] = sym_equal,
+  [sym_contains_word_equal] = sym_contains_word_equal,
+  [sym_starts_with_equal] = sym_starts_with_equal,
+  [sym_dash_equal] = sym_dash_equal,
+  [sym_contains_equal] = sym_contains_equal,
+  [sym_ends_equal] = sym_ends_equal,
+  [anon_sym_LBRACK] = anon_sym_LBRACK,
+  [anon_sym_RBRACK] = anon_sym_RBRACK,
+  [anon_sym_GT] = anon_sym_GT,
+  [anon_sym_TILDE] = anon_sym_TILDE,
+  [anon_sym_PLUS] = anon_sym_PLUS,
+  [anon_sym_LPAREN] = anon_sym_LPAREN,
+  [anon_sym_RPAREN] = anon_sym_RPAREN,
+  [sym_important] = sym_important,
+  [anon_sym_LPAREN2] = anon_sym_LPAREN,
+  [anon_sym_and] = anon_sym_and,
+  [anon_sym_or] = anon_sym_or,
+  [sym_not] = sym_not,
+  [sym_only] = sym_only,
+  [anon_sym_selector] = anon_sym_selector,
+  [aux_sym_color_value_token1] = aux_sym_color_value_token1,
+  [sym_string_value] = sym_string_value,
+  [aux_sym_integer_value_token1] = aux_sym_integer_value_token1,
+  [aux_sym_float_value_token1] = aux_sym_float_value_token1,
+  [sym_unit] = sym_unit,
+  [sym_minus] = sym_minus,
+  [sym_divide] = sym_divide,
+  [anon_sym_EQ_EQ] = sym_equal,
+  [anon_sym_BANG_EQ] = anon_sym_BANG_EQ,
+  [anon_sym_LT] = anon_sym_LT,
+  [anon_sym_LT_EQ] = anon_sym_LT_EQ,
+  [anon_sym_GT_EQ] = anon_sym_GT_EQ,
+  [sym_identifier] = sym_identifier,
+  [sym_at_keyword] = sym_at_keyword,
+  [sym_comment] = sym_comment,
+  [sym_plain_value] = sym_plain_value,
+  [sym_grit_metavariable] = sym_grit_metavariable,
+  [anon_sym_BANGdefault] = anon_sym_BANGdefault,
+  [anon_sym_BANGglobal] = anon_sym_BANGglobal,
+  [anon_sym_ATuse] = anon_sym_ATuse,
+  [anon_sym_as] = anon_sym_as,
+  [anon_sym_with] = anon_sym_with,
+  [anon_sym_ATforward] = anon_sym_ATforward,
+  [anon_sym_STAR2] = anon_sym_STAR,
+  [anon_sym_show] = anon_sym_show,
+  [anon_sym_hide] = anon_sym_hide,
+  [anon_sym_ATmixin] = anon_sym_ATmixin,
+  [anon_sym_ATinclude] = anon_sym_ATinclude,
+  [anon_sym_ATfunction] = anon_sym_ATfunction,
+  [anon_sym_ATreturn] = anon_sym_ATreturn,
+  [anon_sym_ATextend] = anon_sym_ATextend,
+  [anon_sym_BANGoptional] = anon_sym_BANGoptional,
+  [anon_sym_ATerror] = anon_sym_ATerror,
+  [anon_sym_ATwarn] = anon_sym_ATwarn,
+  [anon_sym_ATdebug] = anon_sym_ATdebug,
+  [anon_sym_ATat_DASHroot] = anon_sym_ATat_DASHroot,
+  [anon_sym_ATif] = anon_sym_ATif,
+  [anon_sym_ATelse] = anon_sym_ATelse,
+  [anon_sym_if] = anon_sym_if,
+  [anon_sym_ATeach] = anon_sym_ATeach,
+  [anon_sym_in] = anon_sym_in,
+  [anon_sym_ATfor] = anon_sym_ATfor,
+  [anon_sym_through] = anon_sym_through,
+  [anon_sym_ATwhile] = anon_sym_ATwhile,
+  [anon_sym_POUND_LBRACE] = anon_sym_POUND_LBRACE,
+  [anon_sym_PERCENT] = anon_sym_PERCENT,
+  [sym_module_member] = sym_module_member,
+  [anon_sym_DASH] = sym_identifier,
+  [sym_variable] = sym_variable,
+  [sym__descendant_operator] = sym__descendant_operator,
+  [sym__pseudo_class_selector_colon] = sym__pseudo_class_selector_colon,
+  [sym___error_recovery] = sym___error_recovery,
+  [sym__concat] = sym__concat,
+  [sym_stylesheet] = sym_stylesheet,
+  [sym_import_statement] = sym_import_statement,
+  [sym_media_statement] = sym_media_statement,
+  [sym_charset_statement] = sym_charset_statement,
+  [sym_namespace_statement] = sym_namespace_statement,
+  [sym_keyframes_statement] = sym_keyframes_statement,
+  [sym_keyframe_block_list] = sym_keyframe_block_list,
+  [sym_keyframe_block] = sym_keyframe_block,
+  [sym_from] = sym_from,
+  [sym_to] = sym_to,
+  [sym_supports_statement] = sym_supports_statement,
+  [sym_at_rule] = sym_at_rule,
+  [sym_rule_set] = sym_rule_set,
+  [sym_selectors] = sym_selectors,
+  [sym_block] = sym_block,
+  [sym__selector] = sym__selector,
+  [sym_universal_selector] = sym_universal_selector,
+  [sym_class_selector] = sym_class_selector,
+  [sym_pseudo_class_selector] = sym_pseudo_class_selector,
+  [sym_pseudo_element_selector] = sym_pseudo_element_selector,
+  [sym_id_selector] = sym_id_selector,
+  [sym_attribute_selector] = sym_attribute_selector,
+  [sym_child_selector] = sym_child_selector,
+  [sym_descendant_selector] = sym_descendant_selector,
+  [sym_sibling_selector] = sym_sibling_selector,
+  [sym_adjacent_sibling_selector] = sym_adjacent_sibling_selector,
+  [sym_pseudo_class_arguments] = sym_arguments,
+  [sym_pseudo_element_arguments] = sym_arguments,
+  [sym_declaration] = sym_declaration,
+  [sym_last_declaration] = sym_declaration,
+  [sym__query] = sym__query,
+  [sym_feature_query] = sym_feature_query,
+  [sym_parenthesized_query] = sym_parenthesized_query,
+  [sym_and] = sym_and,
+  [sym_or] = sym_or,
+  [sym_binary_query] = sym_binary_query,
+  [sym_unary_query] = sym_unary_query,
+  [sym_selector_query] = sym_selector_query,
+  [sym__value] = sym__value,
+  [sym_parenthesized_value] = sym_parenthesized_value,
+  [sym_color_value] = sym_color_value,
+  [sym_integer_value] = sym_integer_value,
+  [sym_float_value] = sym_float_value,
+  [sym_call_expression] = sym_call_expression,
+  [sym_plus] = sym_plus,
+  [sym_times] = sym_times,
+  [sym_binary_expression] = sym_binary_expression,
+  [sym_arguments] = sym_arguments,
+  [sym_default_flag] = sym_default_flag,
+  [sym_use_statement] = sym_use_statement,
+  [sym_forward_statement] = sym_forward_statement,
+  [sym_mixin_statement] = sym_mixin_statement,
+  [sym_include_statement] = sym_include_statement,
+  [sym__include_arguments] = sym_arguments,
+  [sym__include_argument] = sym__include_argument,
+  [sym_function_statement] = sym_function_statement,
+  [sym_parameters] = sym_parameters,
+  [sym_parameter] = sym_parameter,
+  [sym_return_statement] = sym_return_statement,
+  [sym_extend_statement] = sym_extend_statement,
+  [sym_error_statement] = sym_error_statement,
+  [sym_warn_statement] = sym_warn_statement,
+  [sym_debug_statement] = sym_debug_statement,
+  [sym_at_root_statement] = sym_at_root_statement,
+  [sym_if_statement] = sym_if_statement,
+  [sym_else_if_clause] = sym_else_if_clause,
+  [sym_else_clause] = sym_else_clause,
+  [sym_each_statement] = sym_each_statement,
+  [sym_for_statement] = sym_for_statement,
+  [sym_while_statement] = sym_while_statement,
+  [sym_list_value] = sym_list_value,
+  [sym_map_value] = sym_map_value,
+  [sym_map_pair] = sym_map_pair,
+  [sym_interpolation] = sym_interpolation,
+  [sym_placeholder] = sym_placeholder,
+  [sym__name] = sym__name,
+  [sym__concatenated_identifier] = sym__concatenated_identifier,
+  [aux_sym_stylesheet_repeat1] = aux_sym_stylesheet_repeat1,
+  [aux_sym_import_statement_repeat1] = aux_sym_import_statement_repeat1,
+  [aux_sym_media_statement_repeat1] = aux_sym_media_statement_repeat1,
+  [aux_sym_keyframe_block_list_repeat1] = aux_sym_keyframe_block_list_repeat1,
+  [aux_sym_at_rule_repeat1] = aux_sym_at_rule_repeat1,
+  [aux_sym_selectors_repeat1] = aux_sym_selectors_repeat1,
+  [aux_sym_block_repeat1] = aux_sym_block_repeat1,
+  [aux_sym_pseudo_class_arguments_repeat1] = aux_sym_pseudo_class_arguments_repeat1,
+  [aux_sym_pseudo_class_arguments_repeat2] = aux_sym_pseudo_class_arguments_repeat2,
+  [aux_sym_declaration_repeat1] = aux_sym_declaration_repeat1,
+  [aux_sym_arguments_repeat1] = aux_sym_arguments_repeat1,
+  [aux_sym_forward_statement_repeat1] = aux_sym_forward_statement_repeat1,
+  [aux_sym__include_arguments_repeat1] = aux_sym__include_arguments_repeat1,
+  [aux_sym_parameters_repeat1] = aux_sym_parameters_repeat1,
+  [aux_sym_if_statement_repeat1] = aux_sym_if_statement_repeat1,
+  [aux_sym_list_value_repeat1] = aux_sym_list_value_repeat1,
+  [aux_sym_map_value_repeat1] = aux_sym_map_value_repeat1,
+  [aux_sym__concatenated_identifier_repeat1] = aux_sym__concatenated_identifier_repeat1,
+  [alias_sym_attribute_name] = alias_sym_attribute_name,
+  [alias_sym_class_name] = alias_sym_class_name,
+  [alias_sym_feature_name] = alias_sym_feature_name,
+  [alias_sym_greater_than] = alias_sym_greater_than,
+  [alias_sym_id_name] = alias_sym_id_name,
+  [alias_sym_keyframes_name] = alias_sym_keyframes_name,
+  [alias_sym_keyword_query] = alias_sym_keyword_query,
+  [alias_sym_namespace_name] = alias_sym_namespace_name,
+  [alias_sym_property_name] = alias_sym_property_name,
+  [alias_sym_tag_name] = alias_sym_tag_name,
+  [alias_sym_wildcard] = alias_sym_wildcard,
+};
+
+static const TSSymbolMetadata ts_symbol_metadata[] = {
+  [ts_builtin_sym_end] = {
+    .visible = false,
+    .named = true,
+  },
+  [anon_sym_ATimport] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_COMMA] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_SEMI] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ATmedia] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ATcharset] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ATnamespace] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ATkeyframes] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym_keyframes_statement_token1] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_LBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_from] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_to] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ATsupports] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_nesting_selector] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_STAR] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_COLON] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_COLON_COLON] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_POUND] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_equal] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_contains_word_equal] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_starts_with_equal] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_dash_equal] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_contains_equal] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_ends_equal] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_LBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_GT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_TILDE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_PLUS] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LPAREN] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RPAREN] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_important] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_LPAREN2] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_and] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_or] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_not] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_only] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_selector] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym_color_value_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [sym_string_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [aux_sym_integer_value_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_float_value_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [sym_unit] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_minus] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_divide] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_EQ_EQ] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_BANG_EQ] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_LT] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_LT_EQ] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_GT_EQ] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_identifier] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_at_keyword] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_comment] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_plain_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_grit_metavariable] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_BANGdefault] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_BANGglobal] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ATuse] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_as] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_with] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ATforward] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_STAR2] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_show] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_hide] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_ATmixin] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ATinclude] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ATfunction] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ATreturn] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ATextend] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_BANGoptional] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_ATerror] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ATwarn] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ATdebug] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ATat_DASHroot] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ATif] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ATelse] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_if] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ATeach] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_in] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ATfor] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_through] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ATwhile] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_POUND_LBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_PERCENT] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_module_member] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_DASH] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_variable] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__descendant_operator] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym__pseudo_class_selector_colon] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym___error_recovery] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym__concat] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_stylesheet] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_import_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_media_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_charset_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_namespace_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyframes_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyframe_block_list] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_keyframe_block] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_from] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_to] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_supports_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_at_rule] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_rule_set] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_selectors] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_block] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__selector] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_universal_selector] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_class_selector] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_pseudo_class_selector] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_pseudo_element_selector] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_id_selector] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_attribute_selector] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_child_selector] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_descendant_selector] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_sibling_selector] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_adjacent_sibling_selector] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_pseudo_class_arguments] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_pseudo_element_arguments] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_declaration] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_last_declaration] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__query] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_feature_query] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_parenthesized_query] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_and] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_or] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_binary_query] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_unary_query] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_selector_query] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__value] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_parenthesized_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_color_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_integer_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_float_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_call_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_plus] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_times] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_binary_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_arguments] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_default_flag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_use_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_forward_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_mixin_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_include_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__include_arguments] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__include_argument] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_function_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_parameters] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_parameter] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_return_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_extend_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_error_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_warn_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_debug_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_at_root_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_if_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_else_if_clause] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_else_clause] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_each_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_for_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_while_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_list_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_map_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_map_pair] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_interpolation] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_placeholder] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__name] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym__concatenated_identifier] = {
+    .visible = false,
+    .named = true,
+  },
+  [aux_sym_stylesheet_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_import_statement_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_media_statement_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_keyframe_block_list_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_at_rule_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_selectors_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_block_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_pseudo_class_arguments_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_pseudo_class_arguments_repeat2] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_declaration_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_arguments_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_forward_statement_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym__include_arguments_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_parameters_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_if_statement_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_list_value_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_map_value_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym__concatenated_identifier_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [alias_sym_attribute_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [alias_sym_class_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [alias_sym_feature_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [alias_sym_greater_than] = {
+    .visible = true,
+    .named = true,
+  },
+  [alias_sym_id_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [alias_sym_keyframes_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [alias_sym_keyword_query] = {
+    .visible = true,
+    .named = true,
+  },
+  [alias_sym_namespace_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [alias_sym_property_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [alias_sym_tag_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [alias_sym_wildcard] = {
+    .visible = true,
+    .named = true,
+  },
+};
+
+enum ts_field_identifiers {
+  field_alternative = 1,
+  field_ancestor = 2,
+  field_annotation = 3,
+  field_argument = 4,
+  field_arguments = 5,
+  field_attribute = 6,
+  field_blocks = 7,
+  field_body = 8,
+  field_charset = 9,
+  field_child = 10,
+  field_class = 11,
+  field_collection = 12,
+  field_condition = 13,
+  field_configuration = 14,
+  field_consequence = 15,
+  field_declaration = 16,
+  field_default = 17,
+  field_descendant = 18,
+  field_feature = 19,
+  field_first = 20,
+  field_flag = 21,
+  field_from = 22,
+  field_important = 23,
+  field_item = 24,
+  field_items = 25,
+  field_key = 26,
+  field_keyframes = 27,
+  field_left = 28,
+  field_media_type = 29,
+  field_member = 30,
+  field_module = 31,
+  field_name = 32,
+  field_namespace = 33,
+  field_offset = 34,
+  field_operator = 35,
+  field_pair = 36,
+  field_parameter = 37,
+  field_parameters = 38,
+  field_parent = 39,
+  field_prefix = 40,
+  field_primary = 41,
+  field_query = 42,
+  field_right = 43,
+  field_rule = 44,
+  field_second = 45,
+  field_selector = 46,
+  field_selector_type = 47,
+  field_selectors = 48,
+  field_sibling = 49,
+  field_through = 50,
+  field_value = 51,
+  field_values = 52,
+  field_variable = 53,
+  field_visibility = 54,
+};
+
+static const char * const ts_field_names[] = {
+  [0] = NULL,
+  [field_alternative] = "alternative",
+  [field_ancestor] = "ancestor",
+  [field_annotation] = "annotation",
+  [field_argument] = "argument",
+  [field_arguments] = "arguments",
+  [field_attribute] = "attribute",
+  [field_blocks] = "blocks",
+  [field_body] = "body",
+  [field_charset] = "charset",
+  [field_child] = "child",
+  [field_class] = "class",
+  [field_collection] = "collection",
+  [field_condition] = "condition",
+  [field_configuration] = "configuration",
+  [field_consequence] = "consequence",
+  [field_declaration] = "declaration",
+  [field_default] = "default",
+  [field_descendant] = "descendant",
+  [field_feature] = "feature",
+  [field_first] = "first",
+  [field_flag] = "flag",
+  [field_from] = "from",
+  [field_important] = "important",
+  [field_item] = "item",
+  [field_items] = "items",
+  [field_key] = "key",
+  [field_keyframes] = "keyframes",
+  [field_left] = "left",
+  [field_media_type] = "media_type",
+  [field_member] = "member",
+  [field_module] = "module",
+  [field_name] = "name",
+  [field_namespace] = "namespace",
+  [field_offset] = "offset",
+  [field_operator] = "operator",
+  [field_pair] = "pair",
+  [field_parameter] = "parameter",
+  [field_parameters] = "parameters",
+  [field_parent] = "parent",
+  [field_prefix] = "prefix",
+  [field_primary] = "primary",
+  [field_query] = "query",
+  [field_right] = "right",
+  [field_rule] = "rule",
+  [field_second] = "second",
+  [field_selector] = "selector",
+  [field_selector_type] = "selector_type",
+  [field_selectors] = "selectors",
+  [field_sibling] = "sibling",
+  [field_through] = "through",
+  [field_value] = "value",
+  [field_values] = "values",
+  [field_variable] = "variable",
+  [field_visibility] = "visibility",
+};
+
+static const TSFieldMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
+  [2] = {.index = 0, .length = 1},
+  [3] = {.index = 1, .length = 1},
+  [4] = {.index = 2, .length = 1},
+  [7] = {.index = 3, .length = 1},
+  [8] = {.index = 4, .length = 1},
+  [9] = {.index = 4, .length = 1},
+  [10] = {.index = 5, .length = 1},
+  [11] = {.index = 6, .length = 2},
+  [12] = {.index = 8, .length = 1},
+  [13] = {.index = 4, .length = 1},
+  [14] = {.index = 9, .length = 2},
+  [15] = {.index = 11, .length = 2},
+  [16] = {.index = 13, .length = 2},
+  [17] = {.index = 15, .length = 2},
+  [18] = {.index = 17, .length = 1},
+  [19] = {.index = 18, .length = 2},
+  [20] = {.index = 20, .length = 2},
+  [21] = {.index = 22, .length = 1},
+  [22] = {.index = 23, .length = 3},
+  [23] = {.index = 26, .length = 2},
+  [24] = {.index = 28, .length = 2},
+  [25] = {.index = 30, .length = 2},
+  [26] = {.index = 32, .length = 1},
+  [27] = {.index = 33, .length = 2},
+  [28] = {.index = 35, .length = 3},
+  [29] = {.index = 38, .length = 1},
+  [30] = {.index = 39, .length = 2},
+  [31] = {.index = 41, .length = 1},
+  [32] = {.index = 42, .length = 2},
+  [33] = {.index = 44, .length = 2},
+  [34] = {.index = 46, .length = 2},
+  [35] = {.index = 48, .length = 2},
+  [36] = {.index = 50, .length = 1},
+  [37] = {.index = 51, .length = 2},
+  [38] = {.index = 53, .length = 2},
+  [39] = {.index = 53, .length = 2},
+  [40] = {.index = 55, .length = 2},
+  [41] = {.index = 57, .length = 2},
+  [42] = {.index = 59, .length = 2},
+  [43] = {.index = 61, .length = 2},
+  [44] = {.index = 63, .length = 1},
+  [45] = {.index = 64, .length = 3},
+  [46] = {.index = 64, .length = 3},
+  [47] = {.index = 64, .length = 3},
+  [48] = {.index = 64, .length = 3},
+  [49] = {.index = 67, .length = 2},
+  [50] = {.index = 69, .length = 1},
+  [51] = {.index = 70, .length = 1},
+  [52] = {.index = 71, .length = 2},
+  [53] = {.index = 73, .length = 3},
+  [54] = {.index = 76, .length = 2},
+  [55] = {.index = 78, .length = 2},
+  [56] = {.index = 80, .length = 2},
+  [57] = {.index = 82, .length = 1},
+  [58] = {.index = 83, .length = 1},
+  [59] = {.index = 84, .length = 1},
+  [60] = {.index = 85, .length = 3},
+  [61] = {.index = 88, .length = 4},
+  [62] = {.index = 92, .length = 2},
+  [63] = {.index = 94, .length = 1},
+  [64] = {.index = 95, .length = 3},
+  [65] = {.index = 98, .length = 1},
+  [66] = {.index = 99, .length = 3},
+  [67] = {.index = 102, .length = 4},
+  [68] = {.index = 106, .length = 2},
+  [69] = {.index = 108, .length = 1},
+  [70] = {.index = 109, .length = 3},
+  [71] = {.index = 112, .length = 3},
+  [72] = {.index = 115, .length = 3},
+  [73] = {.index = 118, .length = 3},
+  [74] = {.index = 121, .length = 2},
+  [75] = {.index = 123, .length = 1},
+  [76] = {.index = 124, .length = 2},
+  [77] = {.index = 126, .length = 2},
+  [78] = {.index = 128, .length = 2},
+  [79] = {.index = 130, .length = 2},
+  [80] = {.index = 132, .length = 2},
+  [81] = {.index = 134, .length = 1},
+  [82] = {.index = 135, .length = 3},
+  [83] = {.index = 138, .length = 2},
+  [84] = {.index = 140, .length = 1},
+  [85] = {.index = 141, .length = 2},
+  [86] = {.index = 143, .length = 1},
+  [87] = {.index = 144, .length = 1},
+  [88] = {.index = 145, .length = 3},
+  [89] = {.index = 148, .length = 3},
+  [90] = {.index = 151, .length = 3},
+  [91] = {.index = 154, .length = 3},
+  [92] = {.index = 157, .length = 2},
+  [93] = {.index = 159, .length = 2},
+  [94] = {.index = 159, .length = 2},
+  [95] = {.index = 161, .length = 2},
+  [96] = {.index = 163, .length = 3},
+  [97] = {.index = 166, .length = 1},
+  [98] = {.index = 167, .length = 1},
+  [99] = {.index = 168, .length = 3},
+  [100] = {.index = 171, .length = 4},
+  [101] = {.index = 175, .length = 2},
+  [102] = {.index = 177, .length = 3},
+  [103] = {.index = 180, .length = 2},
+  [104] = {.index = 182, .length = 2},
+  [105] = {.index = 184, .length = 2},
+  [106] = {.index = 186, .length = 4},
+  [107] = {.index = 190, .length = 4},
+  [108] = {.index = 194, .length = 4},
+  [109] = {.index = 198, .length = 2},
+  [110] = {.index = 200, .length = 1},
+  [111] = {.index = 201, .length = 4},
+  [112] = {.index = 205, .length = 2},
+  [113] = {.index = 207, .length = 2},
+  [114] = {.index = 209, .length = 2},
+  [115] = {.index = 211, .length = 2},
+  [116] = {.index = 213, .length = 2},
+  [117] = {.index = 215, .length = 2},
+  [118] = {.index = 217, .length = 2},
+  [119] = {.index = 219, .length = 4},
+  [120] = {.index = 223, .length = 4},
+  [121] = {.index = 227, .length = 5},
+  [122] = {.index = 232, .length = 3},
+  [123] = {.index = 232, .length = 3},
+  [124] = {.index = 235, .length = 4},
+  [125] = {.index = 239, .length = 2},
+  [126] = {.index = 241, .length = 4},
+  [127] = {.index = 245, .length = 4},
+  [128] = {.index = 249, .length = 3},
+  [129] = {.index = 252, .length = 4},
+  [130] = {.index = 256, .length = 5},
+  [131] = {.index = 261, .length = 5},
+  [132] = {.index = 266, .length = 5},
+  [133] = {.index = 271, .length = 6},
+};
+
+static const TSFieldMapEntry ts_field_map_entries[] = {
+  [0] =
+    {field_items, 0},
+  [1] =
+    {field_selectors, 0},
+  [2] =
+    {field_items, 0, .inherited = true},
+  [3] =
+    {field_class, 1},
+  [4] =
+    {field_name, 1},
+  [5] =
+    {field_rule, 0},
+  [6] =
+    {field_body, 1},
+    {field_rule, 0},
+  [8] =
+    {field_body, 1},
+  [9] =
+    {field_body, 1},
+    {field_selectors, 0},
+  [11] =
+    {field_selectors, 0},
+    {field_selectors, 1, .inherited = true},
+  [13] =
+    {field_items, 0, .inherited = true},
+    {field_items, 1, .inherited = true},
+  [15] =
+    {field_arguments, 1},
+    {field_name, 0},
+  [17] =
+    {field_value, 1},
+  [18] =
+    {field_operator, 0},
+    {field_query, 1},
+  [20] =
+    {field_body, 2},
+    {field_media_type, 1},
+  [22] =
+    {field_charset, 1},
+  [23] =
+    {field_annotation, 0},
+    {field_blocks, 2},
+    {field_name, 1},
+  [26] =
+    {field_body, 2},
+    {field_feature, 1},
+  [28] =
+    {field_arguments, 2},
+    {field_class, 1},
+  [30] =
+    {field_arguments, 2},
+    {field_name, 1},
+  [32] =
+    {field_attribute, 1},
+  [33] =
+    {field_query, 1},
+    {field_rule, 0},
+  [35] =
+    {field_body, 2},
+    {field_query, 1},
+    {field_rule, 0},
+  [38] =
+    {field_module, 1},
+  [39] =
+    {field_body, 2},
+    {field_name, 1},
+  [41] =
+    {field_selector, 1},
+  [42] =
+    {field_body, 2},
+    {field_selector, 1},
+  [44] =
+    {field_condition, 1},
+    {field_consequence, 2},
+  [46] =
+    {field_body, 2},
+    {field_condition, 1},
+  [48] =
+    {field_ancestor, 0},
+    {field_descendant, 2},
+  [50] =
+    {field_selectors, 1},
+  [51] =
+    {field_class, 2},
+    {field_selector, 0},
+  [53] =
+    {field_name, 2},
+    {field_selector, 0},
+  [55] =
+    {field_child, 2},
+    {field_parent, 0},
+  [57] =
+    {field_primary, 2},
+    {field_sibling, 0},
+  [59] =
+    {field_first, 0},
+    {field_second, 2},
+  [61] =
+    {field_selectors, 0, .inherited = true},
+    {field_selectors, 1, .inherited = true},
+  [63] =
+    {field_pair, 1},
+  [64] =
+    {field_left, 0},
+    {field_operator, 1},
+    {field_right, 2},
+  [67] =
+    {field_from, 2},
+    {field_value, 1},
+  [69] =
+    {field_query, 1},
+  [70] =
+    {field_media_type, 1},
+  [71] =
+    {field_operator, 1},
+    {field_query, 2},
+  [73] =
+    {field_body, 3},
+    {field_media_type, 1},
+    {field_media_type, 2, .inherited = true},
+  [76] =
+    {field_media_type, 0, .inherited = true},
+    {field_media_type, 1, .inherited = true},
+  [78] =
+    {field_namespace, 1},
+    {field_value, 2},
+  [80] =
+    {field_name, 0},
+    {field_values, 2},
+  [82] =
+    {field_values, 0},
+  [83] =
+    {field_declaration, 1},
+  [84] =
+    {field_item, 1},
+  [85] =
+    {field_query, 1},
+    {field_query, 2, .inherited = true},
+    {field_rule, 0},
+  [88] =
+    {field_body, 3},
+    {field_query, 1},
+    {field_query, 2, .inherited = true},
+    {field_rule, 0},
+  [92] =
+    {field_query, 0, .inherited = true},
+    {field_query, 1, .inherited = true},
+  [94] =
+    {field_name, 0},
+  [95] =
+    {field_body, 3},
+    {field_name, 1},
+    {field_parameters, 2},
+  [98] =
+    {field_value, 0},
+  [99] =
+    {field_argument, 2, .inherited = true},
+    {field_arguments, 2},
+    {field_name, 1},
+  [102] =
+    {field_argument, 2, .inherited = true},
+    {field_arguments, 2},
+    {field_body, 3},
+    {field_name, 1},
+  [106] =
+    {field_flag, 2},
+    {field_selector, 1},
+  [108] =
+    {field_alternative, 0},
+  [109] =
+    {field_alternative, 3},
+    {field_condition, 1},
+    {field_consequence, 2},
+  [112] =
+    {field_alternative, 3, .inherited = true},
+    {field_condition, 1},
+    {field_consequence, 2},
+  [115] =
+    {field_arguments, 3},
+    {field_class, 2},
+    {field_selector, 0},
+  [118] =
+    {field_arguments, 3},
+    {field_name, 2},
+    {field_selector, 0},
+  [121] =
+    {field_attribute, 2},
+    {field_selector, 0},
+  [123] =
+    {field_values, 1},
+  [124] =
+    {field_key, 0},
+    {field_value, 2},
+  [126] =
+    {field_values, 1},
+    {field_values, 2, .inherited = true},
+  [128] =
+    {field_values, 0, .inherited = true},
+    {field_values, 1, .inherited = true},
+  [130] =
+    {field_pair, 1},
+    {field_pair, 2, .inherited = true},
+  [132] =
+    {field_pair, 0, .inherited = true},
+    {field_pair, 1, .inherited = true},
+  [134] =
+    {field_from, 1},
+  [135] =
+    {field_from, 2},
+    {field_from, 3, .inherited = true},
+    {field_value, 1},
+  [138] =
+    {field_from, 0, .inherited = true},
+    {field_from, 1, .inherited = true},
+  [140] =
+    {field_selector, 2},
+  [141] =
+    {field_body, 1},
+    {field_offset, 0},
+  [143] =
+    {field_keyframes, 1},
+  [144] =
+    {field_arguments, 1},
+  [145] =
+    {field_attribute, 1},
+    {field_selector_type, 2},
+    {field_value, 3},
+  [148] =
+    {field_important, 3},
+    {field_name, 0},
+    {field_values, 2},
+  [151] =
+    {field_flag, 3},
+    {field_name, 0},
+    {field_values, 2},
+  [154] =
+    {field_name, 0},
+    {field_values, 2},
+    {field_values, 3, .inherited = true},
+  [157] =
+    {field_declaration, 2},
+    {field_item, 1},
+  [159] =
+    {field_module, 1},
+    {field_namespace, 3},
+  [161] =
+    {field_configuration, 3},
+    {field_module, 1},
+  [163] =
+    {field_member, 3},
+    {field_module, 1},
+    {field_visibility, 2},
+  [166] =
+    {field_parameter, 1},
+  [167] =
+    {field_argument, 1},
+  [168] =
+    {field_argument, 1},
+    {field_name, 1, .inherited = true},
+    {field_value, 1, .inherited = true},
+  [171] =
+    {field_alternative, 3, .inherited = true},
+    {field_alternative, 4},
+    {field_condition, 1},
+    {field_consequence, 2},
+  [175] =
+    {field_alternative, 0, .inherited = true},
+    {field_alternative, 1, .inherited = true},
+  [177] =
+    {field_body, 4},
+    {field_collection, 3},
+    {field_value, 1},
+  [180] =
+    {field_name, 1},
+    {field_value, 3},
+  [182] =
+    {field_arguments, 1},
+    {field_arguments, 2, .inherited = true},
+  [184] =
+    {field_arguments, 0, .inherited = true},
+    {field_arguments, 1, .inherited = true},
+  [186] =
+    {field_flag, 4},
+    {field_important, 3},
+    {field_name, 0},
+    {field_values, 2},
+  [190] =
+    {field_important, 4},
+    {field_name, 0},
+    {field_values, 2},
+    {field_values, 3, .inherited = true},
+  [194] =
+    {field_flag, 4},
+    {field_name, 0},
+    {field_values, 2},
+    {field_values, 3, .inherited = true},
+  [198] =
+    {field_module, 1},
+    {field_prefix, 3},
+  [200] =
+    {field_member, 1},
+  [201] =
+    {field_member, 3},
+    {field_member, 4, .inherited = true},
+    {field_module, 1},
+    {field_visibility, 2},
+  [205] =
+    {field_member, 0, .inherited = true},
+    {field_member, 1, .inherited = true},
+  [207] =
+    {field_parameter, 1},
+    {field_parameter, 2, .inherited = true},
+  [209] =
+    {field_parameter, 0, .inherited = true},
+    {field_parameter, 1, .inherited = true},
+  [211] =
+    {field_default, 2},
+    {field_name, 0},
+  [213] =
+    {field_argument, 1},
+    {field_argument, 2, .inherited = true},
+  [215] =
+    {field_argument, 0, .inherited = true},
+    {field_argument, 1, .inherited = true},
+  [217] =
+    {field_name, 0},
+    {field_value, 2},
+  [219] =
+    {field_argument, 1},
+    {field_argument, 2, .inherited = true},
+    {field_name, 1, .inherited = true},
+    {field_value, 1, .inherited = true},
+  [223] =
+    {field_attribute, 2},
+    {field_selector, 0},
+    {field_selector_type, 3},
+    {field_value, 4},
+  [227] =
+    {field_flag, 5},
+    {field_important, 4},
+    {field_name, 0},
+    {field_values, 2},
+    {field_values, 3, .inherited = true},
+  [232] =
+    {field_configuration, 5},
+    {field_module, 1},
+    {field_namespace, 3},
+  [235] =
+    {field_configuration, 5},
+    {field_member, 3},
+    {field_module, 1},
+    {field_visibility, 2},
+  [239] =
+    {field_condition, 2},
+    {field_consequence, 3},
+  [241] =
+    {field_body, 6},
+    {field_collection, 5},
+    {field_key, 1},
+    {field_value, 3},
+  [245] =
+    {field_body, 6},
+    {field_from, 3},
+    {field_through, 5},
+    {field_variable, 1},
+  [249] =
+    {field_configuration, 6},
+    {field_module, 1},
+    {field_prefix, 3},
+  [252] =
+    {field_member, 6},
+    {field_module, 1},
+    {field_prefix, 3},
+    {field_visibility, 5},
+  [256] =
+    {field_configuration, 6},
+    {field_member, 3},
+    {field_member, 4, .inherited = true},
+    {field_module, 1},
+    {field_visibility, 2},
+  [261] =
+    {field_member, 6},
+    {field_member, 7, .inherited = true},
+    {field_module, 1},
+    {field_prefix, 3},
+    {field_visibility, 5},
+  [266] =
+    {field_configuration, 8},
+    {field_member, 6},
+    {field_module, 1},
+    {field_prefix, 3},
+    {field_visibility, 5},
+  [271] =
+    {field_configuration, 9},
+    {field_member, 6},
+    {field_member, 7, .inherited = true},
+    {field_module, 1},
+    {field_prefix, 3},
+    {field_visibility, 5},
+};
+
+static const TSSymbol ts_alias_sequences[PRODUCTION_ID_COUNT][MAX_ALIAS_SEQUENCE_LENGTH] = {
+  [0] = {0},
+  [1] = {
+    [0] = alias_sym_tag_name,
+  },
+  [5] = {
+    [0] = sym_plain_value,
+  },
+  [6] = {
+    [0] = alias_sym_keyword_query,
+  },
+  [7] = {
+    [1] = alias_sym_class_name,
+  },
+  [8] = {
+    [1] = alias_sym_tag_name,
+  },
+  [9] = {
+    [1] = alias_sym_id_name,
+  },
+  [17] = {
+    [0] = sym_module_member,
+  },
+  [22] = {
+    [1] = alias_sym_keyframes_name,
+  },
+  [24] = {
+    [1] = alias_sym_class_name,
+  },
+  [25] = {
+    [1] = alias_sym_tag_name,
+  },
+  [26] = {
+    [1] = alias_sym_attribute_name,
+  },
+  [37] = {
+    [2] = alias_sym_class_name,
+  },
+  [38] = {
+    [2] = alias_sym_tag_name,
+  },
+  [39] = {
+    [2] = alias_sym_id_name,
+  },
+  [45] = {
+    [1] = alias_sym_greater_than,
+  },
+  [46] = {
+    [1] = sym_and,
+  },
+  [47] = {
+    [1] = sym_or,
+  },
+  [55] = {
+    [1] = alias_sym_namespace_name,
+  },
+  [56] = {
+    [0] = alias_sym_property_name,
+  },
+  [72] = {
+    [2] = alias_sym_class_name,
+  },
+  [73] = {
+    [2] = alias_sym_tag_name,
+  },
+  [74] = {
+    [2] = alias_sym_attribute_name,
+  },
+  [88] = {
+    [1] = alias_sym_attribute_name,
+  },
+  [89] = {
+    [0] = alias_sym_property_name,
+  },
+  [90] = {
+    [0] = alias_sym_property_name,
+  },
+  [91] = {
+    [0] = alias_sym_property_name,
+  },
+  [93] = {
+    [3] = alias_sym_wildcard,
+  },
+  [103] = {
+    [1] = alias_sym_feature_name,
+  },
+  [106] = {
+    [0] = alias_sym_property_name,
+  },
+  [107] = {
+    [0] = alias_sym_property_name,
+  },
+  [108] = {
+    [0] = alias_sym_property_name,
+  },
+  [120] = {
+    [2] = alias_sym_attribute_name,
+  },
+  [121] = {
+    [0] = alias_sym_property_name,
+  },
+  [122] = {
+    [3] = alias_sym_wildcard,
+  },
+};
+
+static const uint16_t ts_non_terminal_alias_map[] = {
+  sym__concatenated_identifier, 4,
+    sym__concatenated_identifier,
+    alias_sym_class_name,
+    alias_sym_property_name,
+    alias_sym_tag_name,
+  0,
+};
+
+static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
+  [0] = 0,
+  [1] = 1,
+  [2] = 2,
+  [3] = 3,
+  [4] = 4,
+  [5] = 5,
+  [6] = 4,
+  [7] = 5,
+  [8] = 5,
+  [9] = 5,
+  [10] = 4,
+  [11] = 4,
+  [12] = 4,
+  [13] = 5,
+  [14] = 14,
+  [15] = 15,
+  [16] = 16,
+  [17] = 16,
+  [18] = 18,
+  [19] = 19,
+  [20] = 20,
+  [21] = 21,
+  [22] = 22,
+  [23] = 23,
+  [24] = 24,
+  [25] = 24,
+  [26] = 26,
+  [27] = 23,
+  [28] = 21,
+  [29] = 29,
+  [30] = 26,
+  [31] = 31,
+  [32] = 32,
+  [33] = 33,
+  [34] = 34,
+  [35] = 35,
+  [36] = 36,
+  [37] = 37,
+  [38] = 38,
+  [39] = 39,
+  [40] = 40,
+  [41] = 34,
+  [42] = 35,
+  [43] = 43,
+  [44] = 44,
+  [45] = 45,
+  [46] = 46,
+  [47] = 47,
+  [48] = 48,
+  [49] = 49,
+  [50] = 50,
+  [51] = 51,
+  [52] = 52,
+  [53] = 53,
+  [54] = 54,
+  [55] = 55,
+  [56] = 56,
+  [57] = 57,
+  [58] = 58,
+  [59] = 59,
+  [60] = 60,
+  [61] = 29,
+  [62] = 62,
+  [63] = 63,
+  [64] = 64,
+  [65] = 65,
+  [66] = 66,
+  [67] = 67,
+  [68] = 68,
+  [69] = 69,
+  [70] = 70,
+  [71] = 71,
+  [72] = 72,
+  [73] = 73,
+  [74] = 74,
+  [75] = 75,
+  [76] = 76,
+  [77] = 77,
+  [78] = 78,
+  [79] = 79,
+  [80] = 80,
+  [81] = 81,
+  [82] = 82,
+  [83] = 83,
+  [84] = 84,
+  [85] = 85,
+  [86] = 86,
+  [87] = 87,
+  [88] = 88,
+  [89] = 89,
+  [90] = 90,
+  [91] = 91,
+  [92] = 92,
+  [93] = 93,
+  [94] = 33,
+  [95] = 95,
+  [96] = 96,
+  [97] = 97,
+  [98] = 98,
+  [99] = 99,
+  [100] = 100,
+  [101] = 101,
+  [102] = 102,
+  [103] = 103,
+  [104] = 104,
+  [105] = 105,
+  [106] = 106,
+  [107] = 107,
+  [108] = 108,
+  [109] = 34,
+  [110] = 35,
+  [111] = 29,
+  [112] = 31,
+  [113] = 113,
+  [114] = 32,
+  [115] = 33,
+  [116] = 91,
+  [117] = 95,
+  [118] = 69,
+  [119] = 89,
+  [120] = 97,
+  [121] = 92,
+  [122] = 43,
+  [123] = 53,
+  [124] = 56,
+  [125] = 60,
+  [126] = 98,
+  [127] = 99,
+  [128] = 102,
+  [129] = 103,
+  [130] = 104,
+  [131] = 105,
+  [132] = 106,
+  [133] = 107,
+  [134] = 40,
+  [135] = 51,
+  [136] = 80,
+  [137] = 36,
+  [138] = 93,
+  [139] = 96,
+  [140] = 29,
+  [141] = 38,
+  [142] = 39,
+  [143] = 108,
+  [144] = 44,
+  [145] = 45,
+  [146] = 46,
+  [147] = 47,
+  [148] = 48,
+  [149] = 49,
+  [150] = 50,
+  [151] = 52,
+  [152] = 54,
+  [153] = 55,
+  [154] = 57,
+  [155] = 58,
+  [156] = 59,
+  [157] = 67,
+  [158] = 68,
+  [159] = 70,
+  [160] = 71,
+  [161] = 72,
+  [162] = 73,
+  [163] = 76,
+  [164] = 81,
+  [165] = 82,
+  [166] = 166,
+  [167] = 33,
+  [168] = 34,
+  [169] = 35,
+  [170] = 37,
+  [171] = 171,
+  [172] = 172,
+  [173] = 173,
+  [174] = 174,
+  [175] = 175,
+  [176] = 176,
+  [177] = 177,
+  [178] = 178,
+  [179] = 179,
+  [180] = 180,
+  [181] = 172,
+  [182] = 173,
+  [183] = 183,
+  [184] = 174,
+  [185] = 185,
+  [186] = 186,
+  [187] = 187,
+  [188] = 188,
+  [189] = 183,
+  [190] = 190,
+  [191] = 175,
+  [192] = 192,
+  [193] = 193,
+  [194] = 194,
+  [195] = 195,
+  [196] = 196,
+  [197] = 197,
+  [198] = 198,
+  [199] = 199,
+  [200] = 200,
+  [201] = 201,
+  [202] = 202,
+  [203] = 203,
+  [204] = 204,
+  [205] = 205,
+  [206] = 206,
+  [207] = 207,
+  [208] = 208,
+  [209] = 209,
+  [210] = 166,
+  [211] = 209,
+  [212] = 212,
+  [213] = 213,
+  [214] = 212,
+  [215] = 174,
+  [216] = 173,
+  [217] = 217,
+  [218] = 172,
+  [219] = 217,
+  [220] = 176,
+  [221] = 217,
+  [222] = 174,
+  [223] = 183,
+  [224] = 173,
+  [225] = 172,
+  [226] = 226,
+  [227] = 227,
+  [228] = 228,
+  [229] = 229,
+  [230] = 175,
+  [231] = 231,
+  [232] = 200,
+  [233] = 233,
+  [234] = 234,
+  [235] = 202,
+  [236] = 206,
+  [237] = 207,
+  [238] = 195,
+  [239] = 239,
+  [240] = 196,
+  [241] = 197,
+  [242] = 175,
+  [243] = 243,
+  [244] = 183,
+  [245] = 203,
+  [246] = 246,
+  [247] = 205,
+  [248] = 193,
+  [249] = 201,
+  [250] = 198,
+  [251] = 251,
+  [252] = 171,
+  [253] = 113,
+  [254] = 199,
+  [255] = 204,
+  [256] = 256,
+  [257] = 257,
+  [258] = 258,
+  [259] = 256,
+  [260] = 260,
+  [261] = 261,
+  [262] = 261,
+  [263] = 258,
+  [264] = 261,
+  [265] = 256,
+  [266] = 258,
+  [267] = 267,
+  [268] = 257,
+  [269] = 269,
+  [270] = 270,
+  [271] = 271,
+  [272] = 272,
+  [273] = 273,
+  [274] = 271,
+  [275] = 271,
+  [276] = 276,
+  [277] = 277,
+  [278] = 269,
+  [279] = 166,
+  [280] = 269,
+  [281] = 281,
+  [282] = 282,
+  [283] = 283,
+  [284] = 284,
+  [285] = 285,
+  [286] = 286,
+  [287] = 287,
+  [288] = 288,
+  [289] = 289,
+  [290] = 281,
+  [291] = 291,
+  [292] = 292,
+  [293] = 293,
+  [294] = 294,
+  [295] = 295,
+  [296] = 296,
+  [297] = 297,
+  [298] = 298,
+  [299] = 299,
+  [300] = 300,
+  [301] = 301,
+  [302] = 302,
+  [303] = 294,
+  [304] = 295,
+  [305] = 296,
+  [306] = 297,
+  [307] = 307,
+  [308] = 308,
+  [309] = 309,
+  [310] = 310,
+  [311] = 311,
+  [312] = 294,
+  [313] = 295,
+  [314] = 296,
+  [315] = 297,
+  [316] = 316,
+  [317] = 294,
+  [318] = 295,
+  [319] = 296,
+  [320] = 297,
+  [321] = 321,
+  [322] = 294,
+  [323] = 295,
+  [324] = 296,
+  [325] = 297,
+  [326] = 326,
+  [327] = 294,
+  [328] = 295,
+  [329] = 296,
+  [330] = 297,
+  [331] = 291,
+  [332] = 307,
+  [333] = 288,
+  [334] = 292,
+  [335] = 293,
+  [336] = 298,
+  [337] = 299,
+  [338] = 300,
+  [339] = 301,
+  [340] = 316,
+  [341] = 308,
+  [342] = 287,
+  [343] = 289,
+  [344] = 301,
+  [345] = 301,
+  [346] = 301,
+  [347] = 301,
+  [348] = 309,
+  [349] = 186,
+  [350] = 173,
+  [351] = 187,
+  [352] = 174,
+  [353] = 178,
+  [354] = 179,
+  [355] = 177,
+  [356] = 180,
+  [357] = 176,
+  [358] = 172,
+  [359] = 178,
+  [360] = 179,
+  [361] = 177,
+  [362] = 180,
+  [363] = 190,
+  [364] = 198,
+  [365] = 188,
+  [366] = 183,
+  [367] = 175,
+  [368] = 190,
+  [369] = 188,
+  [370] = 201,
+  [371] = 196,
+  [372] = 207,
+  [373] = 195,
+  [374] = 205,
+  [375] = 193,
+  [376] = 202,
+  [377] = 197,
+  [378] = 203,
+  [379] = 204,
+  [380] = 206,
+  [381] = 200,
+  [382] = 199,
+  [383] = 180,
+  [384] = 384,
+  [385] = 177,
+  [386] = 179,
+  [387] = 178,
+  [388] = 188,
+  [389] = 190,
+  [390] = 390,
+  [391] = 390,
+  [392] = 390,
+  [393] = 393,
+  [394] = 394,
+  [395] = 395,
+  [396] = 396,
+  [397] = 394,
+  [398] = 177,
+  [399] = 179,
+  [400] = 178,
+  [401] = 180,
+  [402] = 402,
+  [403] = 403,
+  [404] = 404,
+  [405] = 405,
+  [406] = 406,
+  [407] = 190,
+  [408] = 408,
+  [409] = 409,
+  [410] = 408,
+  [411] = 411,
+  [412] = 188,
+  [413] = 404,
+  [414] = 405,
+  [415] = 411,
+  [416] = 416,
+  [417] = 417,
+  [418] = 418,
+  [419] = 419,
+  [420] = 419,
+  [421] = 403,
+  [422] = 178,
+  [423] = 179,
+  [424] = 177,
+  [425] = 180,
+  [426] = 406,
+  [427] = 427,
+  [428] = 428,
+  [429] = 429,
+  [430] = 409,
+  [431] = 428,
+  [432] = 432,
+  [433] = 433,
+  [434] = 188,
+  [435] = 435,
+  [436] = 190,
+  [437] = 437,
+  [438] = 438,
+  [439] = 439,
+  [440] = 440,
+  [441] = 441,
+  [442] = 442,
+  [443] = 443,
+  [444] = 440,
+  [445] = 445,
+  [446] = 446,
+  [447] = 447,
+  [448] = 443,
+  [449] = 449,
+  [450] = 450,
+  [451] = 438,
+  [452] = 443,
+  [453] = 453,
+  [454] = 432,
+  [455] = 441,
+  [456] = 443,
+  [457] = 443,
+  [458] = 443,
+  [459] = 459,
+  [460] = 460,
+  [461] = 437,
+  [462] = 462,
+  [463] = 463,
+  [464] = 464,
+  [465] = 465,
+  [466] = 466,
+  [467] = 466,
+  [468] = 468,
+  [469] = 464,
+  [470] = 470,
+  [471] = 471,
+  [472] = 472,
+  [473] = 473,
+  [474] = 474,
+  [475] = 475,
+  [476] = 470,
+  [477] = 477,
+  [478] = 478,
+  [479] = 479,
+  [480] = 480,
+  [481] = 481,
+  [482] = 482,
+  [483] = 483,
+  [484] = 484,
+  [485] = 485,
+  [486] = 486,
+  [487] = 487,
+  [488] = 488,
+  [489] = 489,
+  [490] = 490,
+  [491] = 491,
+  [492] = 492,
+  [493] = 493,
+  [494] = 494,
+  [495] = 495,
+  [496] = 496,
+  [497] = 497,
+  [498] = 498,
+  [499] = 499,
+  [500] = 500,
+  [501] = 501,
+  [502] = 502,
+  [503] = 503,
+  [504] = 504,
+  [505] = 505,
+  [506] = 506,
+  [507] = 507,
+  [508] = 508,
+  [509] = 509,
+  [510] = 510,
+  [511] = 504,
+  [512] = 509,
+  [513] = 513,
+  [514] = 514,
+  [515] = 515,
+  [516] = 516,
+  [517] = 517,
+  [518] = 517,
+  [519] = 516,
+  [520] = 515,
+  [521] = 521,
+  [522] = 522,
+  [523] = 523,
+  [524] = 524,
+  [525] = 524,
+  [526] = 526,
+  [527] = 527,
+  [528] = 528,
+  [529] = 529,
+  [530] = 530,
+  [531] = 531,
+  [532] = 526,
+  [533] = 527,
+  [534] = 534,
+  [535] = 535,
+  [536] = 536,
+  [537] = 537,
+  [538] = 175,
+  [539] = 539,
+  [540] = 537,
+  [541] = 541,
+  [542] = 542,
+  [543] = 543,
+  [544] = 544,
+  [545] = 545,
+  [546] = 544,
+  [547] = 547,
+  [548] = 548,
+  [549] = 549,
+  [550] = 547,
+  [551] = 551,
+  [552] = 549,
+  [553] = 553,
+  [554] = 554,
+  [555] = 34,
+  [556] = 556,
+  [557] = 35,
+  [558] = 558,
+  [559] = 559,
+  [560] = 560,
+  [561] = 561,
+  [562] = 562,
+  [563] = 563,
+  [564] = 564,
+  [565] = 565,
+  [566] = 562,
+  [567] = 553,
+  [568] = 561,
+  [569] = 569,
+  [570] = 570,
+  [571] = 553,
+  [572] = 561,
+  [573] = 558,
+  [574] = 574,
+  [575] = 563,
+  [576] = 576,
+  [577] = 577,
+  [578] = 553,
+  [579] = 579,
+  [580] = 570,
+  [581] = 33,
+  [582] = 582,
+  [583] = 583,
+  [584] = 556,
+  [585] = 585,
+  [586] = 586,
+  [587] = 553,
+  [588] = 29,
+  [589] = 589,
+  [590] = 590,
+  [591] = 591,
+  [592] = 592,
+  [593] = 593,
+  [594] = 594,
+  [595] = 595,
+  [596] = 596,
+  [597] = 597,
+  [598] = 598,
+  [599] = 599,
+  [600] = 600,
+  [601] = 601,
+  [602] = 602,
+  [603] = 593,
+  [604] = 596,
+  [605] = 605,
+  [606] = 606,
+  [607] = 607,
+  [608] = 608,
+  [609] = 609,
+  [610] = 610,
+  [611] = 601,
+  [612] = 593,
+  [613] = 613,
+  [614] = 596,
+  [615] = 615,
+  [616] = 616,
+  [617] = 601,
+  [618] = 618,
+  [619] = 619,
+  [620] = 620,
+  [621] = 619,
+  [622] = 622,
+  [623] = 623,
+  [624] = 606,
+  [625] = 589,
+  [626] = 605,
+  [627] = 622,
+  [628] = 628,
+  [629] = 629,
+  [630] = 630,
+  [631] = 623,
+  [632] = 632,
+  [633] = 633,
+  [634] = 634,
+  [635] = 635,
+  [636] = 636,
+  [637] = 637,
+  [638] = 638,
+  [639] = 639,
+  [640] = 640,
+  [641] = 641,
+  [642] = 642,
+  [643] = 643,
+  [644] = 644,
+  [645] = 645,
+  [646] = 646,
+  [647] = 647,
+  [648] = 648,
+  [649] = 649,
+  [650] = 650,
+  [651] = 651,
+  [652] = 652,
+  [653] = 653,
+  [654] = 632,
+  [655] = 655,
+  [656] = 652,
+  [657] = 649,
+  [658] = 658,
+  [659] = 659,
+  [660] = 660,
+  [661] = 661,
+  [662] = 662,
+  [663] = 652,
+  [664] = 653,
+  [665] = 651,
+  [666] = 666,
+  [667] = 655,
+  [668] = 668,
+  [669] = 190,
+  [670] = 670,
+  [671] = 671,
+  [672] = 672,
+  [673] = 635,
+  [674] = 674,
+  [675] = 675,
+  [676] = 676,
+  [677] = 677,
+  [678] = 678,
+  [679] = 679,
+  [680] = 680,
+  [681] = 681,
+  [682] = 682,
+  [683] = 683,
+  [684] = 684,
+  [685] = 685,
+  [686] = 686,
+  [687] = 686,
+  [688] = 688,
+  [689] = 689,
+  [690] = 690,
+  [691] = 691,
+  [692] = 679,
+  [693] = 693,
+  [694] = 684,
+  [695] = 679,
+  [696] = 696,
+  [697] = 697,
+  [698] = 698,
+  [699] = 699,
+  [700] = 690,
+  [701] = 701,
+  [702] = 679,
+  [703] = 703,
+  [704] = 704,
+  [705] = 705,
+  [706] = 706,
+  [707] = 707,
+  [708] = 680,
+  [709] = 688,
+  [710] = 704,
+  [711] = 696,
+  [712] = 712,
+  [713] = 713,
+  [714] = 714,
+  [715] = 690,
+  [716] = 716,
+  [717] = 690,
+  [718] = 684,
+  [719] = 719,
+  [720] = 720,
+  [721] = 721,
+  [722] = 722,
+  [723] = 723,
+  [724] = 724,
+  [725] = 679,
+  [726] = 726,
+  [727] = 727,
+  [728] = 701,
+  [729] = 729,
+  [730] = 730,
+  [731] = 731,
+  [732] = 732,
+  [733] = 721,
+  [734] = 729,
+  [735] = 681,
+  [736] = 690,
+  [737] = 705,
+  [738] = 676,
+  [739] = 739,
+  [740] = 706,
+  [741] = 731,
+  [742] = 742,
+  [743] = 732,
+  [744] = 727,
+  [745] = 745,
+};
+
+static TSCharacterRange sym_plain_value_character_set_1[] = {
+  {0, 0x08}, {0x0e, 0x1f}, {'"', '\''}, {'+', '+'}, {'-', ':'}, {'<', 'Z'}, {'\\', '\\'}, {'^', 'z'},
+  {'|', '|'}, {'~', 0x10ffff},
+};
+
+static TSCharacterRange sym_plain_value_character_set_2[] = {
+  {0, 0x08}, {0x0e, 0x1f}, {'"', '\''}, {'*', '+'}, {'-', ':'}, {'<', 'Z'}, {'\\', '\\'}, {'^', 'z'},
+  {'|', '|'}, {'~', 0x10ffff},
+};
+
+static TSCharacterRange sym_plain_value_character_set_3[] = {
+  {0, 0x08}, {0x0e, 0x1f}, {'"', '\''}, {'*', '+'}, {'-', '9'}, {'<', 'Z'}, {'\\', '\\'}, {'^', 'z'},
+  {'|', '|'}, {'~', 0x10ffff},
+};
+
+static bool ts_lex(TSLexer *lexer, TSStateId state) {
+  START_LEXER();
+  eof = lexer->eof(lexer);
+  switch (state) {
+    case 0:
+      if (eof) ADVANCE(148);
+      ADVANCE_MAP(
+        '!', 54,
+        '"', 27,
+        '#', 172,
+        '$', 56,
+        '%', 473,
+        '&', 163,
+        '\'', 32,
+        '(', 188,
+        ')', 189,
+        '*', 447,
+        '+', 186,
+        ',', 150,
+        '-', 475,
+        '.', 166,
+        '/', 278,
+        ':', 169,
+        ';', 151,
+        '<', 282,
+        '=', 174,
+        '>', 183,
+        '@', 62,
+        '[', 180,
+        ']', 181,
+        '^', 59,
+        'a', 99,
+        'f', 118,
+        'h', 86,
+        'i', 79,
+        'n', 104,
+        'o', 100,
+        's', 78,
+        't', 82,
+        'w', 87,
+        '{', 157,
+        '|', 60,
+        '}', 158,
+        '~', 185,
+        0xb5, 42,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(144);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('b' <= lookahead && lookahead <= 'e')) ADVANCE(137);
+      END_STATE();
+    case 1:
+      ADVANCE_MAP(
+        '!', 53,
+        '"', 27,
+        '#', 172,
+        '$', 139,
+        '%', 274,
+        '&', 163,
+        '\'', 32,
+        '(', 191,
+        ')', 189,
+        '*', 164,
+        '+', 187,
+        ',', 150,
+        '-', 276,
+        '.', 133,
+        '/', 279,
+        ';', 151,
+        '<', 282,
+        '=', 58,
+        '>', 183,
+        '_', 285,
+        'a', 251,
+        'o', 252,
+        '}', 158,
+        0xb5, 42,
+        'E', 249,
+        'e', 249,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(4);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(228);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(253);
+      END_STATE();
+    case 2:
+      ADVANCE_MAP(
+        '!', 53,
+        '"', 27,
+        '#', 172,
+        '$', 139,
+        '&', 163,
+        '\'', 32,
+        '(', 188,
+        ')', 189,
+        '*', 164,
+        '+', 187,
+        ',', 150,
+        '-', 276,
+        '.', 167,
+        '/', 279,
+        ':', 169,
+        ';', 151,
+        '<', 282,
+        '=', 58,
+        '>', 183,
+        '[', 180,
+        '_', 285,
+        'a', 290,
+        'o', 291,
+        '}', 158,
+        '~', 184,
+        0xb5, 42,
+        'E', 286,
+        'e', 286,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(3);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(228);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(292);
+      END_STATE();
+    case 3:
+      ADVANCE_MAP(
+        '!', 53,
+        '"', 27,
+        '#', 172,
+        '$', 139,
+        '&', 163,
+        '\'', 32,
+        '(', 191,
+        ')', 189,
+        '*', 164,
+        '+', 187,
+        ',', 150,
+        '-', 276,
+        '.', 167,
+        '/', 279,
+        ':', 169,
+        ';', 151,
+        '<', 282,
+        '=', 58,
+        '>', 183,
+        '[', 180,
+        '_', 285,
+        'a', 290,
+        'o', 291,
+        '}', 158,
+        '~', 184,
+        0xb5, 42,
+        'E', 286,
+        'e', 286,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(3);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(228);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(292);
+      END_STATE();
+    case 4:
+      ADVANCE_MAP(
+        '!', 53,
+        '"', 27,
+        '#', 172,
+        '$', 139,
+        '&', 163,
+        '\'', 32,
+        '(', 191,
+        ')', 189,
+        '*', 164,
+        '+', 187,
+        ',', 150,
+        '-', 276,
+        '.', 133,
+        '/', 279,
+        ';', 151,
+        '<', 282,
+        '=', 58,
+        '>', 183,
+        '_', 285,
+        'a', 290,
+        'o', 291,
+        '}', 158,
+        0xb5, 42,
+        'E', 286,
+        'e', 286,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(4);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(228);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(292);
+      END_STATE();
+    case 5:
+      ADVANCE_MAP(
+        '!', 72,
+        '"', 27,
+        '#', 172,
+        '$', 139,
+        '%', 473,
+        '&', 163,
+        '\'', 32,
+        '(', 191,
+        ')', 189,
+        '*', 164,
+        '+', 45,
+        ',', 150,
+        '-', 44,
+        '.', 167,
+        '/', 35,
+        ':', 169,
+        ';', 151,
+        '[', 180,
+        '_', 285,
+        '{', 157,
+        '}', 158,
+        0xb5, 42,
+        'E', 286,
+        'e', 286,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(5);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(228);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(292);
+      END_STATE();
+    case 6:
+      ADVANCE_MAP(
+        '!', 55,
+        '#', 172,
+        '&', 163,
+        '*', 164,
+        '+', 186,
+        '-', 277,
+        '.', 166,
+        '/', 278,
+        ':', 169,
+        ';', 151,
+        '<', 282,
+        '=', 58,
+        '>', 183,
+        '[', 180,
+        'a', 306,
+        'o', 311,
+        '~', 184,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(6);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 7:
+      ADVANCE_MAP(
+        '!', 55,
+        '#', 171,
+        '&', 163,
+        '(', 188,
+        ')', 189,
+        '*', 164,
+        '+', 186,
+        ',', 150,
+        '-', 275,
+        '.', 166,
+        '/', 278,
+        ':', 169,
+        ';', 151,
+        '<', 282,
+        '=', 58,
+        '>', 183,
+        '[', 180,
+        ']', 181,
+        'a', 98,
+        'h', 86,
+        'o', 115,
+        's', 85,
+        't', 82,
+        'w', 87,
+        '{', 157,
+        '}', 158,
+        '~', 184,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(8);
+      END_STATE();
+    case 8:
+      ADVANCE_MAP(
+        '!', 55,
+        '#', 171,
+        '&', 163,
+        ')', 189,
+        '*', 164,
+        '+', 186,
+        ',', 150,
+        '-', 275,
+        '.', 166,
+        '/', 278,
+        ':', 169,
+        ';', 151,
+        '<', 282,
+        '=', 58,
+        '>', 183,
+        '[', 180,
+        ']', 181,
+        'a', 98,
+        'h', 86,
+        'o', 115,
+        's', 85,
+        't', 82,
+        'w', 87,
+        '{', 157,
+        '}', 158,
+        '~', 184,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(8);
+      END_STATE();
+    case 9:
+      ADVANCE_MAP(
+        '!', 55,
+        ')', 189,
+        '*', 164,
+        '+', 186,
+        ',', 150,
+        '-', 275,
+        '/', 278,
+        ':', 168,
+        ';', 151,
+        '<', 282,
+        '=', 58,
+        '>', 183,
+        ']', 181,
+        'a', 266,
+        'o', 269,
+        '{', 157,
+        '}', 158,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(10);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 10:
+      ADVANCE_MAP(
+        '!', 55,
+        ')', 189,
+        '*', 164,
+        '+', 186,
+        ',', 150,
+        '-', 275,
+        '/', 278,
+        ':', 168,
+        ';', 151,
+        '<', 282,
+        '=', 58,
+        '>', 183,
+        ']', 181,
+        'a', 97,
+        'o', 115,
+        '{', 157,
+        '}', 158,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(10);
+      END_STATE();
+    case 11:
+      ADVANCE_MAP(
+        '!', 52,
+        '#', 132,
+        '%', 274,
+        '(', 191,
+        '*', 164,
+        '+', 186,
+        '-', 277,
+        '/', 278,
+        ';', 151,
+        '<', 282,
+        '=', 58,
+        '>', 183,
+        '_', 318,
+        'a', 240,
+        'n', 242,
+        'o', 241,
+        's', 237,
+        0xb5, 42,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(14);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(248);
+      END_STATE();
+    case 12:
+      ADVANCE_MAP(
+        '!', 52,
+        '#', 132,
+        '(', 188,
+        '*', 164,
+        '+', 186,
+        '-', 277,
+        '/', 278,
+        ';', 151,
+        '<', 282,
+        '=', 58,
+        '>', 183,
+        'a', 306,
+        'n', 309,
+        'o', 307,
+        's', 303,
+        0xb5, 42,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(14);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 13:
+      ADVANCE_MAP(
+        '!', 52,
+        '#', 132,
+        '(', 191,
+        '*', 164,
+        '+', 186,
+        '-', 277,
+        '/', 278,
+        ';', 151,
+        '<', 282,
+        '=', 58,
+        '>', 183,
+        'a', 306,
+        'n', 309,
+        'o', 307,
+        's', 303,
+        '{', 157,
+        0xb5, 42,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(13);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 14:
+      ADVANCE_MAP(
+        '!', 52,
+        '#', 132,
+        '(', 191,
+        '*', 164,
+        '+', 186,
+        '-', 277,
+        '/', 278,
+        ';', 151,
+        '<', 282,
+        '=', 58,
+        '>', 183,
+        'a', 306,
+        'n', 309,
+        'o', 307,
+        's', 303,
+        0xb5, 42,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(14);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 15:
+      ADVANCE_MAP(
+        '!', 52,
+        '*', 164,
+        '+', 186,
+        '-', 275,
+        '/', 278,
+        ';', 151,
+        '<', 282,
+        '=', 58,
+        '>', 183,
+        'a', 98,
+        'h', 86,
+        'o', 115,
+        's', 85,
+        'w', 87,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(15);
+      END_STATE();
+    case 16:
+      ADVANCE_MAP(
+        '!', 52,
+        '*', 164,
+        '+', 186,
+        '-', 275,
+        '/', 278,
+        ';', 151,
+        '<', 282,
+        '=', 58,
+        '>', 183,
+        'a', 98,
+        'o', 115,
+        'w', 87,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(16);
+      END_STATE();
+    case 17:
+      ADVANCE_MAP(
+        '!', 52,
+        '*', 164,
+        '+', 186,
+        '-', 275,
+        '/', 278,
+        ';', 151,
+        '<', 282,
+        '=', 58,
+        '>', 183,
+        'a', 265,
+        'h', 264,
+        'o', 269,
+        's', 259,
+        'w', 263,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(15);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 18:
+      ADVANCE_MAP(
+        '!', 52,
+        '*', 164,
+        '+', 186,
+        '-', 275,
+        '/', 278,
+        ';', 151,
+        '<', 282,
+        '=', 58,
+        '>', 183,
+        'a', 265,
+        'o', 269,
+        'w', 263,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(16);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 19:
+      ADVANCE_MAP(
+        '!', 52,
+        '*', 164,
+        '+', 186,
+        '-', 275,
+        '/', 278,
+        '<', 282,
+        '=', 58,
+        '>', 183,
+        'a', 266,
+        'o', 269,
+        't', 262,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(20);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 20:
+      ADVANCE_MAP(
+        '!', 52,
+        '*', 164,
+        '+', 186,
+        '-', 275,
+        '/', 278,
+        '<', 282,
+        '=', 58,
+        '>', 183,
+        'a', 97,
+        'o', 115,
+        't', 82,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(20);
+      END_STATE();
+    case 21:
+      ADVANCE_MAP(
+        '"', 27,
+        '#', 172,
+        '$', 139,
+        '%', 473,
+        '&', 163,
+        '\'', 32,
+        '(', 191,
+        '*', 164,
+        '+', 186,
+        ',', 150,
+        '-', 140,
+        '.', 166,
+        '/', 34,
+        ':', 169,
+        '>', 182,
+        '@', 65,
+        '[', 180,
+        '{', 157,
+        '}', 158,
+        '~', 184,
+        0xb5, 42,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(21);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(288);
+      END_STATE();
+    case 22:
+      ADVANCE_MAP(
+        '"', 27,
+        '#', 172,
+        '$', 139,
+        '%', 473,
+        '&', 163,
+        '\'', 32,
+        '*', 164,
+        '-', 140,
+        '.', 166,
+        '/', 34,
+        ':', 169,
+        '@', 63,
+        '[', 180,
+        '}', 158,
+        0xb5, 42,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(22);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(288);
+      END_STATE();
+    case 23:
+      ADVANCE_MAP(
+        '"', 27,
+        '#', 172,
+        '%', 473,
+        '&', 163,
+        '\'', 32,
+        ')', 189,
+        '*', 164,
+        '+', 186,
+        ',', 150,
+        '-', 476,
+        '.', 166,
+        '/', 34,
+        ':', 169,
+        '>', 182,
+        '[', 180,
+        '{', 157,
+        '~', 184,
+        0xb5, 42,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(24);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 24:
+      ADVANCE_MAP(
+        '"', 27,
+        '#', 172,
+        '%', 473,
+        '&', 163,
+        '\'', 32,
+        ')', 189,
+        '*', 164,
+        '+', 186,
+        ',', 150,
+        '-', 140,
+        '.', 166,
+        '/', 34,
+        ':', 169,
+        '>', 182,
+        '[', 180,
+        '{', 157,
+        '~', 184,
+        0xb5, 42,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(24);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 25:
+      if (lookahead == '"') ADVANCE(27);
+      if (lookahead == '\'') ADVANCE(32);
+      if (lookahead == '(') ADVANCE(188);
+      if (lookahead == '-') ADVANCE(50);
+      if (lookahead == '/') ADVANCE(35);
+      if (lookahead == '_') ADVANCE(293);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(26);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(296);
+      END_STATE();
+    case 26:
+      if (lookahead == '"') ADVANCE(27);
+      if (lookahead == '\'') ADVANCE(32);
+      if (lookahead == '-') ADVANCE(50);
+      if (lookahead == '/') ADVANCE(35);
+      if (lookahead == '_') ADVANCE(293);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(26);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(296);
+      END_STATE();
+    case 27:
+      if (lookahead == '"') ADVANCE(225);
+      if (lookahead == '\\') ADVANCE(28);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(27);
+      END_STATE();
+    case 28:
+      if (lookahead == '"') ADVANCE(226);
+      if (lookahead == '\\') ADVANCE(28);
+      if (lookahead != 0) ADVANCE(27);
+      END_STATE();
+    case 29:
+      ADVANCE_MAP(
+        '#', 132,
+        '$', 56,
+        '(', 191,
+        '*', 57,
+        '-', 140,
+        '/', 34,
+        ';', 151,
+        '=', 173,
+        ']', 181,
+        '^', 59,
+        'n', 309,
+        'o', 308,
+        's', 303,
+        '{', 157,
+        '|', 60,
+        '~', 61,
+        0xb5, 42,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(29);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 30:
+      if (lookahead == '$') ADVANCE(139);
+      END_STATE();
+    case 31:
+      if (lookahead == '$') ADVANCE(139);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(474);
+      END_STATE();
+    case 32:
+      if (lookahead == '\'') ADVANCE(225);
+      if (lookahead == '\\') ADVANCE(33);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(32);
+      END_STATE();
+    case 33:
+      if (lookahead == '\'') ADVANCE(227);
+      if (lookahead == '\\') ADVANCE(33);
+      if (lookahead != 0) ADVANCE(32);
+      END_STATE();
+    case 34:
+      if (lookahead == '*') ADVANCE(37);
+      if (lookahead == '/') ADVANCE(430);
+      END_STATE();
+    case 35:
+      if (lookahead == '*') ADVANCE(37);
+      if (lookahead == '/') ADVANCE(425);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(51);
+      END_STATE();
+    case 36:
+      if (lookahead == '*') ADVANCE(36);
+      if (lookahead == '/') ADVANCE(424);
+      if (lookahead != 0) ADVANCE(37);
+      END_STATE();
+    case 37:
+      if (lookahead == '*') ADVANCE(36);
+      if (lookahead != 0) ADVANCE(37);
+      END_STATE();
+    case 38:
+      if (lookahead == '*') ADVANCE(446);
+      if (lookahead == '/') ADVANCE(34);
+      if (lookahead == 'f') ADVANCE(117);
+      if (lookahead == 't') ADVANCE(105);
+      if (lookahead == '}') ADVANCE(158);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(135);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(47);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(229);
+      END_STATE();
+    case 39:
+      if (lookahead == '-') ADVANCE(140);
+      if (lookahead == '/') ADVANCE(34);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(40);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(137);
+      if (('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(317);
+      if (('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 40:
+      if (lookahead == '-') ADVANCE(140);
+      if (lookahead == '/') ADVANCE(34);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(40);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 41:
+      if (lookahead == '-') ADVANCE(134);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(233);
+      END_STATE();
+    case 42:
+      if (lookahead == '.') ADVANCE(46);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(437);
+      END_STATE();
+    case 43:
+      if (lookahead == '.') ADVANCE(436);
+      END_STATE();
+    case 44:
+      if (lookahead == '.') ADVANCE(133);
+      if (lookahead == '/') ADVANCE(141);
+      if (lookahead == '-' ||
+          lookahead == '_') ADVANCE(297);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(287);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(228);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(299);
+      END_STATE();
+    case 45:
+      if (lookahead == '.') ADVANCE(133);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(41);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(228);
+      END_STATE();
+    case 46:
+      if (lookahead == '.') ADVANCE(43);
+      END_STATE();
+    case 47:
+      if (lookahead == '/') ADVANCE(34);
+      if (lookahead == 'f') ADVANCE(117);
+      if (lookahead == 't') ADVANCE(105);
+      if (lookahead == '}') ADVANCE(158);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(135);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(47);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(229);
+      END_STATE();
+    case 48:
+      if (lookahead == '/') ADVANCE(34);
+      if (lookahead == '{') ADVANCE(157);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(48);
+      END_STATE();
+    case 49:
+      if (lookahead == '/') ADVANCE(34);
+      if (lookahead == '{') ADVANCE(157);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(48);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 50:
+      if (lookahead == '/') ADVANCE(141);
+      if (lookahead == '-' ||
+          lookahead == '_') ADVANCE(297);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(299);
+      END_STATE();
+    case 51:
+      if (lookahead == '/') ADVANCE(141);
+      if (lookahead == '-' ||
+          lookahead == '_') ADVANCE(51);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(435);
+      END_STATE();
+    case 52:
+      if (lookahead == '=') ADVANCE(281);
+      END_STATE();
+    case 53:
+      if (lookahead == '=') ADVANCE(281);
+      if (lookahead == 'd') ADVANCE(75);
+      if (lookahead == 'g') ADVANCE(93);
+      if (lookahead == 'i') ADVANCE(96);
+      END_STATE();
+    case 54:
+      if (lookahead == '=') ADVANCE(281);
+      if (lookahead == 'd') ADVANCE(75);
+      if (lookahead == 'g') ADVANCE(93);
+      if (lookahead == 'i') ADVANCE(96);
+      if (lookahead == 'o') ADVANCE(113);
+      END_STATE();
+    case 55:
+      if (lookahead == '=') ADVANCE(281);
+      if (lookahead == 'o') ADVANCE(113);
+      END_STATE();
+    case 56:
+      if (lookahead == '=') ADVANCE(179);
+      END_STATE();
+    case 57:
+      if (lookahead == '=') ADVANCE(178);
+      END_STATE();
+    case 58:
+      if (lookahead == '=') ADVANCE(280);
+      END_STATE();
+    case 59:
+      if (lookahead == '=') ADVANCE(176);
+      END_STATE();
+    case 60:
+      if (lookahead == '=') ADVANCE(177);
+      END_STATE();
+    case 61:
+      if (lookahead == '=') ADVANCE(175);
+      END_STATE();
+    case 62:
+      ADVANCE_MAP(
+        'a', 407,
+        'c', 359,
+        'd', 339,
+        'e', 320,
+        'f', 375,
+        'i', 354,
+        'k', 340,
+        'm', 341,
+        'n', 322,
+        'r', 348,
+        's', 416,
+        'u', 404,
+        'w', 324,
+      );
+      if (lookahead == '-' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 63:
+      ADVANCE_MAP(
+        'a', 407,
+        'c', 359,
+        'd', 339,
+        'e', 320,
+        'f', 382,
+        'i', 354,
+        'k', 340,
+        'm', 341,
+        'n', 322,
+        'r', 348,
+        's', 416,
+        'w', 324,
+      );
+      if (lookahead == '-' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 64:
+      ADVANCE_MAP(
+        'a', 407,
+        'c', 359,
+        'd', 339,
+        'e', 321,
+        'f', 375,
+        'i', 354,
+        'k', 340,
+        'm', 341,
+        'n', 322,
+        'r', 348,
+        's', 416,
+        'u', 404,
+        'w', 324,
+      );
+      if (lookahead == '-' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 65:
+      ADVANCE_MAP(
+        'a', 407,
+        'c', 359,
+        'd', 339,
+        'e', 321,
+        'f', 382,
+        'i', 354,
+        'k', 340,
+        'm', 341,
+        'n', 322,
+        'r', 348,
+        's', 416,
+        'w', 324,
+      );
+      if (lookahead == '-' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 66:
+      if (lookahead == 'a') ADVANCE(129);
+      END_STATE();
+    case 67:
+      if (lookahead == 'a') ADVANCE(101);
+      END_STATE();
+    case 68:
+      if (lookahead == 'a') ADVANCE(90);
+      END_STATE();
+    case 69:
+      if (lookahead == 'a') ADVANCE(91);
+      END_STATE();
+    case 70:
+      if (lookahead == 'b') ADVANCE(68);
+      END_STATE();
+    case 71:
+      if (lookahead == 'c') ADVANCE(127);
+      END_STATE();
+    case 72:
+      if (lookahead == 'd') ADVANCE(75);
+      if (lookahead == 'g') ADVANCE(93);
+      if (lookahead == 'i') ADVANCE(96);
+      END_STATE();
+    case 73:
+      if (lookahead == 'd') ADVANCE(192);
+      END_STATE();
+    case 74:
+      if (lookahead == 'd') ADVANCE(76);
+      END_STATE();
+    case 75:
+      if (lookahead == 'e') ADVANCE(80);
+      END_STATE();
+    case 76:
+      if (lookahead == 'e') ADVANCE(450);
+      END_STATE();
+    case 77:
+      if (lookahead == 'e') ADVANCE(71);
+      END_STATE();
+    case 78:
+      if (lookahead == 'e') ADVANCE(92);
+      if (lookahead == 'h') ADVANCE(106);
+      END_STATE();
+    case 79:
+      if (lookahead == 'f') ADVANCE(464);
+      if (lookahead == 'n') ADVANCE(466);
+      END_STATE();
+    case 80:
+      if (lookahead == 'f') ADVANCE(66);
+      END_STATE();
+    case 81:
+      if (lookahead == 'g') ADVANCE(84);
+      END_STATE();
+    case 82:
+      if (lookahead == 'h') ADVANCE(119);
+      if (lookahead == 'o') ADVANCE(160);
+      END_STATE();
+    case 83:
+      if (lookahead == 'h') ADVANCE(443);
+      END_STATE();
+    case 84:
+      if (lookahead == 'h') ADVANCE(469);
+      END_STATE();
+    case 85:
+      if (lookahead == 'h') ADVANCE(106);
+      END_STATE();
+    case 86:
+      if (lookahead == 'i') ADVANCE(74);
+      END_STATE();
+    case 87:
+      if (lookahead == 'i') ADVANCE(122);
+      END_STATE();
+    case 88:
+      if (lookahead == 'i') ADVANCE(109);
+      END_STATE();
+    case 89:
+      if (lookahead == 'l') ADVANCE(131);
+      END_STATE();
+    case 90:
+      if (lookahead == 'l') ADVANCE(439);
+      END_STATE();
+    case 91:
+      if (lookahead == 'l') ADVANCE(457);
+      END_STATE();
+    case 92:
+      if (lookahead == 'l') ADVANCE(77);
+      END_STATE();
+    case 93:
+      if (lookahead == 'l') ADVANCE(107);
+      END_STATE();
+    case 94:
+      if (lookahead == 'l') ADVANCE(123);
+      END_STATE();
+    case 95:
+      if (lookahead == 'm') ADVANCE(159);
+      END_STATE();
+    case 96:
+      if (lookahead == 'm') ADVANCE(114);
+      END_STATE();
+    case 97:
+      if (lookahead == 'n') ADVANCE(73);
+      END_STATE();
+    case 98:
+      if (lookahead == 'n') ADVANCE(73);
+      if (lookahead == 's') ADVANCE(441);
+      END_STATE();
+    case 99:
+      if (lookahead == 'n') ADVANCE(73);
+      if (lookahead == 's') ADVANCE(441);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(136);
+      END_STATE();
+    case 100:
+      if (lookahead == 'n') ADVANCE(89);
+      if (lookahead == 'r') ADVANCE(198);
+      END_STATE();
+    case 101:
+      if (lookahead == 'n') ADVANCE(124);
+      END_STATE();
+    case 102:
+      if (lookahead == 'n') ADVANCE(69);
+      END_STATE();
+    case 103:
+      if (lookahead == 'o') ADVANCE(113);
+      END_STATE();
+    case 104:
+      if (lookahead == 'o') ADVANCE(121);
+      END_STATE();
+    case 105:
+      if (lookahead == 'o') ADVANCE(160);
+      END_STATE();
+    case 106:
+      if (lookahead == 'o') ADVANCE(130);
+      END_STATE();
+    case 107:
+      if (lookahead == 'o') ADVANCE(70);
+      END_STATE();
+    case 108:
+      if (lookahead == 'o') ADVANCE(128);
+      END_STATE();
+    case 109:
+      if (lookahead == 'o') ADVANCE(102);
+      END_STATE();
+    case 110:
+      if (lookahead == 'o') ADVANCE(95);
+      END_STATE();
+    case 111:
+      if (lookahead == 'o') ADVANCE(120);
+      END_STATE();
+    case 112:
+      if (lookahead == 'o') ADVANCE(116);
+      END_STATE();
+    case 113:
+      if (lookahead == 'p') ADVANCE(125);
+      END_STATE();
+    case 114:
+      if (lookahead == 'p') ADVANCE(111);
+      END_STATE();
+    case 115:
+      if (lookahead == 'r') ADVANCE(198);
+      END_STATE();
+    case 116:
+      if (lookahead == 'r') ADVANCE(210);
+      END_STATE();
+    case 117:
+      if (lookahead == 'r') ADVANCE(110);
+      END_STATE();
+    case 118:
+      if (lookahead == 'r') ADVANCE(110);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(136);
+      END_STATE();
+    case 119:
+      if (lookahead == 'r') ADVANCE(108);
+      END_STATE();
+    case 120:
+      if (lookahead == 'r') ADVANCE(126);
+      END_STATE();
+    case 121:
+      if (lookahead == 't') ADVANCE(204);
+      END_STATE();
+    case 122:
+      if (lookahead == 't') ADVANCE(83);
+      END_STATE();
+    case 123:
+      if (lookahead == 't') ADVANCE(438);
+      END_STATE();
+    case 124:
+      if (lookahead == 't') ADVANCE(190);
+      END_STATE();
+    case 125:
+      if (lookahead == 't') ADVANCE(88);
+      END_STATE();
+    case 126:
+      if (lookahead == 't') ADVANCE(67);
+      END_STATE();
+    case 127:
+      if (lookahead == 't') ADVANCE(112);
+      END_STATE();
+    case 128:
+      if (lookahead == 'u') ADVANCE(81);
+      END_STATE();
+    case 129:
+      if (lookahead == 'u') ADVANCE(94);
+      END_STATE();
+    case 130:
+      if (lookahead == 'w') ADVANCE(448);
+      END_STATE();
+    case 131:
+      if (lookahead == 'y') ADVANCE(207);
+      END_STATE();
+    case 132:
+      if (lookahead == '{') ADVANCE(472);
+      END_STATE();
+    case 133:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(232);
+      END_STATE();
+    case 134:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(233);
+      END_STATE();
+    case 135:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(229);
+      END_STATE();
+    case 136:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(222);
+      END_STATE();
+    case 137:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(136);
+      END_STATE();
+    case 138:
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(474);
+      END_STATE();
+    case 139:
+      if (lookahead == '-' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(477);
+      END_STATE();
+    case 140:
+      if (lookahead == '-' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 141:
+      if ((!eof && set_contains(sym_plain_value_character_set_1, 10, lookahead))) ADVANCE(51);
+      END_STATE();
+    case 142:
+      if ((!eof && set_contains(sym_plain_value_character_set_1, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 143:
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 144:
+      if (eof) ADVANCE(148);
+      ADVANCE_MAP(
+        '!', 54,
+        '"', 27,
+        '#', 172,
+        '$', 56,
+        '%', 473,
+        '&', 163,
+        '\'', 32,
+        '(', 191,
+        ')', 189,
+        '*', 165,
+        '+', 186,
+        ',', 150,
+        '-', 275,
+        '.', 166,
+        '/', 278,
+        ':', 169,
+        ';', 151,
+        '<', 282,
+        '=', 174,
+        '>', 183,
+        '@', 62,
+        '[', 180,
+        ']', 181,
+        '^', 59,
+        'a', 98,
+        'f', 117,
+        'h', 86,
+        'i', 79,
+        'n', 104,
+        'o', 100,
+        's', 78,
+        't', 82,
+        'w', 87,
+        '{', 157,
+        '|', 60,
+        '}', 158,
+        '~', 185,
+        0xb5, 42,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(144);
+      END_STATE();
+    case 145:
+      if (eof) ADVANCE(148);
+      ADVANCE_MAP(
+        '!', 103,
+        '"', 27,
+        '#', 172,
+        '$', 139,
+        '%', 473,
+        '&', 163,
+        '\'', 32,
+        '(', 188,
+        ')', 189,
+        '*', 164,
+        '+', 186,
+        ',', 150,
+        '-', 140,
+        '.', 166,
+        '/', 34,
+        ':', 169,
+        ';', 151,
+        '>', 182,
+        '@', 64,
+        '[', 180,
+        '{', 157,
+        '~', 184,
+        0xb5, 42,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(146);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(288);
+      END_STATE();
+    case 146:
+      if (eof) ADVANCE(148);
+      ADVANCE_MAP(
+        '!', 103,
+        '"', 27,
+        '#', 172,
+        '$', 139,
+        '%', 473,
+        '&', 163,
+        '\'', 32,
+        ')', 189,
+        '*', 164,
+        '+', 186,
+        ',', 150,
+        '-', 140,
+        '.', 166,
+        '/', 34,
+        ':', 169,
+        ';', 151,
+        '>', 182,
+        '@', 64,
+        '[', 180,
+        '{', 157,
+        '~', 184,
+        0xb5, 42,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(146);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(288);
+      END_STATE();
+    case 147:
+      if (eof) ADVANCE(148);
+      ADVANCE_MAP(
+        '"', 27,
+        '#', 172,
+        '$', 139,
+        '%', 473,
+        '&', 163,
+        '\'', 32,
+        '*', 164,
+        '-', 140,
+        '.', 166,
+        '/', 34,
+        ':', 169,
+        '@', 62,
+        '[', 180,
+        0xb5, 42,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(147);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(288);
+      END_STATE();
+    case 148:
+      ACCEPT_TOKEN(ts_builtin_sym_end);
+      END_STATE();
+    case 149:
+      ACCEPT_TOKEN(anon_sym_ATimport);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 150:
+      ACCEPT_TOKEN(anon_sym_COMMA);
+      END_STATE();
+    case 151:
+      ACCEPT_TOKEN(anon_sym_SEMI);
+      END_STATE();
+    case 152:
+      ACCEPT_TOKEN(anon_sym_ATmedia);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 153:
+      ACCEPT_TOKEN(anon_sym_ATcharset);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 154:
+      ACCEPT_TOKEN(anon_sym_ATnamespace);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 155:
+      ACCEPT_TOKEN(anon_sym_ATkeyframes);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 156:
+      ACCEPT_TOKEN(aux_sym_keyframes_statement_token1);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 157:
+      ACCEPT_TOKEN(anon_sym_LBRACE);
+      END_STATE();
+    case 158:
+      ACCEPT_TOKEN(anon_sym_RBRACE);
+      END_STATE();
+    case 159:
+      ACCEPT_TOKEN(anon_sym_from);
+      END_STATE();
+    case 160:
+      ACCEPT_TOKEN(anon_sym_to);
+      END_STATE();
+    case 161:
+      ACCEPT_TOKEN(anon_sym_to);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 162:
+      ACCEPT_TOKEN(anon_sym_ATsupports);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 163:
+      ACCEPT_TOKEN(sym_nesting_selector);
+      END_STATE();
+    case 164:
+      ACCEPT_TOKEN(anon_sym_STAR);
+      END_STATE();
+    case 165:
+      ACCEPT_TOKEN(anon_sym_STAR);
+      if (lookahead == '=') ADVANCE(178);
+      END_STATE();
+    case 166:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      END_STATE();
+    case 167:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(232);
+      END_STATE();
+    case 168:
+      ACCEPT_TOKEN(anon_sym_COLON);
+      END_STATE();
+    case 169:
+      ACCEPT_TOKEN(anon_sym_COLON);
+      if (lookahead == ':') ADVANCE(170);
+      END_STATE();
+    case 170:
+      ACCEPT_TOKEN(anon_sym_COLON_COLON);
+      END_STATE();
+    case 171:
+      ACCEPT_TOKEN(anon_sym_POUND);
+      END_STATE();
+    case 172:
+      ACCEPT_TOKEN(anon_sym_POUND);
+      if (lookahead == '{') ADVANCE(472);
+      END_STATE();
+    case 173:
+      ACCEPT_TOKEN(sym_equal);
+      END_STATE();
+    case 174:
+      ACCEPT_TOKEN(sym_equal);
+      if (lookahead == '=') ADVANCE(280);
+      END_STATE();
+    case 175:
+      ACCEPT_TOKEN(sym_contains_word_equal);
+      END_STATE();
+    case 176:
+      ACCEPT_TOKEN(sym_starts_with_equal);
+      END_STATE();
+    case 177:
+      ACCEPT_TOKEN(sym_dash_equal);
+      END_STATE();
+    case 178:
+      ACCEPT_TOKEN(sym_contains_equal);
+      END_STATE();
+    case 179:
+      ACCEPT_TOKEN(sym_ends_equal);
+      END_STATE();
+    case 180:
+      ACCEPT_TOKEN(anon_sym_LBRACK);
+      END_STATE();
+    case 181:
+      ACCEPT_TOKEN(anon_sym_RBRACK);
+      END_STATE();
+    case 182:
+      ACCEPT_TOKEN(anon_sym_GT);
+      END_STATE();
+    case 183:
+      ACCEPT_TOKEN(anon_sym_GT);
+      if (lookahead == '=') ADVANCE(284);
+      END_STATE();
+    case 184:
+      ACCEPT_TOKEN(anon_sym_TILDE);
+      END_STATE();
+    case 185:
+      ACCEPT_TOKEN(anon_sym_TILDE);
+      if (lookahead == '=') ADVANCE(175);
+      END_STATE();
+    case 186:
+      ACCEPT_TOKEN(anon_sym_PLUS);
+      END_STATE();
+    case 187:
+      ACCEPT_TOKEN(anon_sym_PLUS);
+      if (lookahead == '.') ADVANCE(133);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(41);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(228);
+      END_STATE();
+    case 188:
+      ACCEPT_TOKEN(anon_sym_LPAREN);
+      END_STATE();
+    case 189:
+      ACCEPT_TOKEN(anon_sym_RPAREN);
+      END_STATE();
+    case 190:
+      ACCEPT_TOKEN(sym_important);
+      END_STATE();
+    case 191:
+      ACCEPT_TOKEN(anon_sym_LPAREN2);
+      END_STATE();
+    case 192:
+      ACCEPT_TOKEN(anon_sym_and);
+      END_STATE();
+    case 193:
+      ACCEPT_TOKEN(anon_sym_and);
+      if (lookahead == '%') ADVANCE(274);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          lookahead == '_') ADVANCE(318);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(248);
+      END_STATE();
+    case 194:
+      ACCEPT_TOKEN(anon_sym_and);
+      if (lookahead == '%') ADVANCE(254);
+      if (lookahead == '.') ADVANCE(431);
+      if (lookahead == '/') ADVANCE(142);
+      if (lookahead == ':') ADVANCE(143);
+      if (lookahead == '_') ADVANCE(292);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(296);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(253);
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 195:
+      ACCEPT_TOKEN(anon_sym_and);
+      if (lookahead == '.') ADVANCE(431);
+      if (lookahead == '/') ADVANCE(142);
+      if (lookahead == ':') ADVANCE(143);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(296);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(292);
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 196:
+      ACCEPT_TOKEN(anon_sym_and);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 197:
+      ACCEPT_TOKEN(anon_sym_and);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 198:
+      ACCEPT_TOKEN(anon_sym_or);
+      END_STATE();
+    case 199:
+      ACCEPT_TOKEN(anon_sym_or);
+      if (lookahead == '%') ADVANCE(274);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          lookahead == '_') ADVANCE(318);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(248);
+      END_STATE();
+    case 200:
+      ACCEPT_TOKEN(anon_sym_or);
+      if (lookahead == '%') ADVANCE(254);
+      if (lookahead == '.') ADVANCE(431);
+      if (lookahead == '/') ADVANCE(142);
+      if (lookahead == ':') ADVANCE(143);
+      if (lookahead == '_') ADVANCE(292);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(296);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(253);
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 201:
+      ACCEPT_TOKEN(anon_sym_or);
+      if (lookahead == '.') ADVANCE(431);
+      if (lookahead == '/') ADVANCE(142);
+      if (lookahead == ':') ADVANCE(143);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(296);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(292);
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 202:
+      ACCEPT_TOKEN(anon_sym_or);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 203:
+      ACCEPT_TOKEN(anon_sym_or);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 204:
+      ACCEPT_TOKEN(sym_not);
+      END_STATE();
+    case 205:
+      ACCEPT_TOKEN(sym_not);
+      if (lookahead == '%') ADVANCE(274);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          lookahead == '_') ADVANCE(318);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(248);
+      END_STATE();
+    case 206:
+      ACCEPT_TOKEN(sym_not);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 207:
+      ACCEPT_TOKEN(sym_only);
+      END_STATE();
+    case 208:
+      ACCEPT_TOKEN(sym_only);
+      if (lookahead == '%') ADVANCE(274);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          lookahead == '_') ADVANCE(318);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(248);
+      END_STATE();
+    case 209:
+      ACCEPT_TOKEN(sym_only);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 210:
+      ACCEPT_TOKEN(anon_sym_selector);
+      END_STATE();
+    case 211:
+      ACCEPT_TOKEN(anon_sym_selector);
+      if (lookahead == '%') ADVANCE(274);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          lookahead == '_') ADVANCE(318);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(248);
+      END_STATE();
+    case 212:
+      ACCEPT_TOKEN(anon_sym_selector);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 213:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      END_STATE();
+    case 214:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(213);
+      END_STATE();
+    case 215:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(224);
+      if (lookahead == '-' ||
+          ('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 216:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(214);
+      END_STATE();
+    case 217:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(215);
+      if (lookahead == '-' ||
+          ('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 218:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(216);
+      END_STATE();
+    case 219:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(217);
+      if (lookahead == '-' ||
+          ('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 220:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(218);
+      END_STATE();
+    case 221:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(219);
+      if (lookahead == '-' ||
+          ('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 222:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(220);
+      END_STATE();
+    case 223:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(221);
+      if (lookahead == '-' ||
+          ('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 224:
+      ACCEPT_TOKEN(aux_sym_color_value_token1);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 225:
+      ACCEPT_TOKEN(sym_string_value);
+      END_STATE();
+    case 226:
+      ACCEPT_TOKEN(sym_string_value);
+      if (lookahead == '"') ADVANCE(225);
+      if (lookahead == '\\') ADVANCE(28);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(27);
+      END_STATE();
+    case 227:
+      ACCEPT_TOKEN(sym_string_value);
+      if (lookahead == '\'') ADVANCE(225);
+      if (lookahead == '\\') ADVANCE(33);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(32);
+      END_STATE();
+    case 228:
+      ACCEPT_TOKEN(aux_sym_integer_value_token1);
+      if (lookahead == '.') ADVANCE(133);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(41);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(228);
+      END_STATE();
+    case 229:
+      ACCEPT_TOKEN(aux_sym_integer_value_token1);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(229);
+      END_STATE();
+    case 230:
+      ACCEPT_TOKEN(aux_sym_float_value_token1);
+      if (lookahead == '.') ADVANCE(432);
+      if (lookahead == '/') ADVANCE(142);
+      if (lookahead == ':') ADVANCE(143);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(230);
+      if (lookahead == '-' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(296);
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 231:
+      ACCEPT_TOKEN(aux_sym_float_value_token1);
+      if (lookahead == '/') ADVANCE(142);
+      if (lookahead == ':') ADVANCE(143);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(231);
+      if (lookahead == '-' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(299);
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 232:
+      ACCEPT_TOKEN(aux_sym_float_value_token1);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(41);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(232);
+      END_STATE();
+    case 233:
+      ACCEPT_TOKEN(aux_sym_float_value_token1);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(233);
+      END_STATE();
+    case 234:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(274);
+      if (lookahead == 'c') ADVANCE(246);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          lookahead == '_') ADVANCE(318);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(248);
+      END_STATE();
+    case 235:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(274);
+      if (lookahead == 'd') ADVANCE(193);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          lookahead == '_') ADVANCE(318);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(248);
+      END_STATE();
+    case 236:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(274);
+      if (lookahead == 'e') ADVANCE(234);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          lookahead == '_') ADVANCE(318);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(248);
+      END_STATE();
+    case 237:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(274);
+      if (lookahead == 'e') ADVANCE(239);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          lookahead == '_') ADVANCE(318);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(248);
+      END_STATE();
+    case 238:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(274);
+      if (lookahead == 'l') ADVANCE(247);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          lookahead == '_') ADVANCE(318);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(248);
+      END_STATE();
+    case 239:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(274);
+      if (lookahead == 'l') ADVANCE(236);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          lookahead == '_') ADVANCE(318);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(248);
+      END_STATE();
+    case 240:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(274);
+      if (lookahead == 'n') ADVANCE(235);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          lookahead == '_') ADVANCE(318);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(248);
+      END_STATE();
+    case 241:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(274);
+      if (lookahead == 'n') ADVANCE(238);
+      if (lookahead == 'r') ADVANCE(199);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          lookahead == '_') ADVANCE(318);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(248);
+      END_STATE();
+    case 242:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(274);
+      if (lookahead == 'o') ADVANCE(245);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          lookahead == '_') ADVANCE(318);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(248);
+      END_STATE();
+    case 243:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(274);
+      if (lookahead == 'o') ADVANCE(244);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          lookahead == '_') ADVANCE(318);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(248);
+      END_STATE();
+    case 244:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(274);
+      if (lookahead == 'r') ADVANCE(211);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          lookahead == '_') ADVANCE(318);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(248);
+      END_STATE();
+    case 245:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(274);
+      if (lookahead == 't') ADVANCE(205);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          lookahead == '_') ADVANCE(318);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(248);
+      END_STATE();
+    case 246:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(274);
+      if (lookahead == 't') ADVANCE(243);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          lookahead == '_') ADVANCE(318);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(248);
+      END_STATE();
+    case 247:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(274);
+      if (lookahead == 'y') ADVANCE(208);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          lookahead == '_') ADVANCE(318);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(248);
+      END_STATE();
+    case 248:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(274);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          lookahead == '_') ADVANCE(318);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(248);
+      END_STATE();
+    case 249:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(254);
+      if (lookahead == '-') ADVANCE(295);
+      if (lookahead == '.') ADVANCE(431);
+      if (lookahead == '/') ADVANCE(142);
+      if (lookahead == ':') ADVANCE(143);
+      if (lookahead == '_') ADVANCE(292);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(230);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(253);
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 250:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(254);
+      if (lookahead == '.') ADVANCE(431);
+      if (lookahead == '/') ADVANCE(142);
+      if (lookahead == ':') ADVANCE(143);
+      if (lookahead == '_') ADVANCE(292);
+      if (lookahead == 'd') ADVANCE(194);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(296);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(253);
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 251:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(254);
+      if (lookahead == '.') ADVANCE(431);
+      if (lookahead == '/') ADVANCE(142);
+      if (lookahead == ':') ADVANCE(143);
+      if (lookahead == '_') ADVANCE(292);
+      if (lookahead == 'n') ADVANCE(250);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(296);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(253);
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 252:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(254);
+      if (lookahead == '.') ADVANCE(431);
+      if (lookahead == '/') ADVANCE(142);
+      if (lookahead == ':') ADVANCE(143);
+      if (lookahead == '_') ADVANCE(292);
+      if (lookahead == 'r') ADVANCE(200);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(296);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(253);
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 253:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%') ADVANCE(254);
+      if (lookahead == '.') ADVANCE(431);
+      if (lookahead == '/') ADVANCE(142);
+      if (lookahead == ':') ADVANCE(143);
+      if (lookahead == '_') ADVANCE(292);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(296);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(253);
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 254:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '/') ADVANCE(142);
+      if (lookahead == ':') ADVANCE(143);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(254);
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 255:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == 'd') ADVANCE(196);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 256:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == 'd') ADVANCE(257);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 257:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == 'e') ADVANCE(451);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 258:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == 'g') ADVANCE(261);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 259:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == 'h') ADVANCE(267);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 260:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == 'h') ADVANCE(444);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 261:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == 'h') ADVANCE(470);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 262:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == 'h') ADVANCE(270);
+      if (lookahead == 'o') ADVANCE(161);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 263:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == 'i') ADVANCE(271);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 264:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == 'i') ADVANCE(256);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 265:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == 'n') ADVANCE(255);
+      if (lookahead == 's') ADVANCE(442);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 266:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == 'n') ADVANCE(255);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 267:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == 'o') ADVANCE(273);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 268:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == 'o') ADVANCE(272);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 269:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == 'r') ADVANCE(202);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 270:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == 'r') ADVANCE(268);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 271:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == 't') ADVANCE(260);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 272:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == 'u') ADVANCE(258);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 273:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == 'w') ADVANCE(449);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 274:
+      ACCEPT_TOKEN(sym_unit);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 275:
+      ACCEPT_TOKEN(sym_minus);
+      END_STATE();
+    case 276:
+      ACCEPT_TOKEN(sym_minus);
+      if (lookahead == '.') ADVANCE(133);
+      if (lookahead == '/') ADVANCE(141);
+      if (lookahead == '-' ||
+          lookahead == '_') ADVANCE(297);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(287);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(228);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(299);
+      END_STATE();
+    case 277:
+      ACCEPT_TOKEN(sym_minus);
+      if (lookahead == '-' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 278:
+      ACCEPT_TOKEN(sym_divide);
+      if (lookahead == '*') ADVANCE(37);
+      if (lookahead == '/') ADVANCE(430);
+      END_STATE();
+    case 279:
+      ACCEPT_TOKEN(sym_divide);
+      if (lookahead == '*') ADVANCE(37);
+      if (lookahead == '/') ADVANCE(425);
+      if ((!eof && set_contains(sym_plain_value_character_set_2, 10, lookahead))) ADVANCE(51);
+      END_STATE();
+    case 280:
+      ACCEPT_TOKEN(anon_sym_EQ_EQ);
+      END_STATE();
+    case 281:
+      ACCEPT_TOKEN(anon_sym_BANG_EQ);
+      END_STATE();
+    case 282:
+      ACCEPT_TOKEN(anon_sym_LT);
+      if (lookahead == '=') ADVANCE(283);
+      END_STATE();
+    case 283:
+      ACCEPT_TOKEN(anon_sym_LT_EQ);
+      END_STATE();
+    case 284:
+      ACCEPT_TOKEN(anon_sym_GT_EQ);
+      END_STATE();
+    case 285:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '-') ADVANCE(293);
+      if (lookahead == '.') ADVANCE(31);
+      if (lookahead == '/') ADVANCE(141);
+      if (lookahead == '_') ADVANCE(285);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(294);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(292);
+      END_STATE();
+    case 286:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '-') ADVANCE(295);
+      if (lookahead == '.') ADVANCE(431);
+      if (lookahead == '/') ADVANCE(142);
+      if (lookahead == ':') ADVANCE(143);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(230);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(292);
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 287:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '-') ADVANCE(298);
+      if (lookahead == '/') ADVANCE(142);
+      if (lookahead == ':') ADVANCE(143);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(231);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(299);
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 288:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(30);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(318);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(288);
+      END_STATE();
+    case 289:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(431);
+      if (lookahead == '/') ADVANCE(142);
+      if (lookahead == ':') ADVANCE(143);
+      if (lookahead == 'd') ADVANCE(195);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(296);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(292);
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 290:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(431);
+      if (lookahead == '/') ADVANCE(142);
+      if (lookahead == ':') ADVANCE(143);
+      if (lookahead == 'n') ADVANCE(289);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(296);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(292);
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 291:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(431);
+      if (lookahead == '/') ADVANCE(142);
+      if (lookahead == ':') ADVANCE(143);
+      if (lookahead == 'r') ADVANCE(201);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(296);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(292);
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 292:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(431);
+      if (lookahead == '/') ADVANCE(142);
+      if (lookahead == ':') ADVANCE(143);
+      if (('-' <= lookahead && lookahead <= '9')) ADVANCE(296);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(292);
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 293:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(138);
+      if (lookahead == '/') ADVANCE(141);
+      if (lookahead == '-' ||
+          lookahead == '_') ADVANCE(293);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(294);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(296);
+      END_STATE();
+    case 294:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(138);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(294);
+      END_STATE();
+    case 295:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(432);
+      if (lookahead == '/') ADVANCE(142);
+      if (lookahead == ':') ADVANCE(143);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(230);
+      if (lookahead == '-' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(296);
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 296:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '.') ADVANCE(432);
+      if (lookahead == '/') ADVANCE(142);
+      if (lookahead == ':') ADVANCE(143);
+      if (('-' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(296);
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 297:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '/') ADVANCE(141);
+      if (lookahead == '-' ||
+          lookahead == '_') ADVANCE(297);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(318);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(299);
+      END_STATE();
+    case 298:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '/') ADVANCE(142);
+      if (lookahead == ':') ADVANCE(143);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(231);
+      if (lookahead == '-' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(299);
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 299:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '/') ADVANCE(142);
+      if (lookahead == ':') ADVANCE(143);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(299);
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 300:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'c') ADVANCE(314);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 301:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'd') ADVANCE(197);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 302:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(300);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 303:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(305);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 304:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'l') ADVANCE(315);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 305:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'l') ADVANCE(302);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 306:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'n') ADVANCE(301);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 307:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'n') ADVANCE(304);
+      if (lookahead == 'r') ADVANCE(203);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 308:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'n') ADVANCE(304);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 309:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'o') ADVANCE(313);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 310:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'o') ADVANCE(312);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 311:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'r') ADVANCE(203);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 312:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'r') ADVANCE(212);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 313:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(206);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 314:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(310);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 315:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'y') ADVANCE(209);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 316:
+      ACCEPT_TOKEN(sym_identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(223);
+      if (lookahead == '-' ||
+          ('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 317:
+      ACCEPT_TOKEN(sym_identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(316);
+      if (lookahead == '-' ||
+          ('G' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('g' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 318:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 319:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == '-') ADVANCE(392);
+      if (lookahead == 'k') ADVANCE(353);
+      if (('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 320:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'a') ADVANCE(332);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'l') ADVANCE(405);
+      if (lookahead == 'r') ADVANCE(387);
+      if (lookahead == 'x') ADVANCE(414);
+      if (lookahead == '-' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 321:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'a') ADVANCE(332);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'r') ADVANCE(387);
+      if (lookahead == 'x') ADVANCE(414);
+      if (lookahead == '-' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 322:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'a') ADVANCE(366);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 323:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'a') ADVANCE(152);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 324:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'a') ADVANCE(391);
+      if (lookahead == 'h') ADVANCE(360);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 325:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'a') ADVANCE(334);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 326:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'a') ADVANCE(397);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 327:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'a') ADVANCE(393);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 328:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'a') ADVANCE(367);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 329:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'a') ADVANCE(368);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 330:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'b') ADVANCE(415);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 331:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'c') ADVANCE(364);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 332:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'c') ADVANCE(358);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 333:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'c') ADVANCE(413);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 334:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'c') ADVANCE(346);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 335:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'd') ADVANCE(456);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 336:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'd') ADVANCE(445);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 337:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'd') ADVANCE(361);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 338:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'd') ADVANCE(345);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 339:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'e') ADVANCE(330);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 340:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'e') ADVANCE(420);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 341:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'e') ADVANCE(337);
+      if (lookahead == 'i') ADVANCE(419);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 342:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'e') ADVANCE(440);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 343:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'e') ADVANCE(463);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 344:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'e') ADVANCE(471);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 345:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'e') ADVANCE(453);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 346:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'e') ADVANCE(154);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 347:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'e') ADVANCE(403);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 348:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'e') ADVANCE(411);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 349:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'e') ADVANCE(373);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 350:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'e') ADVANCE(401);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 351:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'e') ADVANCE(402);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 352:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'e') ADVANCE(410);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 353:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'e') ADVANCE(421);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 354:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'f') ADVANCE(462);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'm') ADVANCE(384);
+      if (lookahead == 'n') ADVANCE(331);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 355:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'f') ADVANCE(398);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 356:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'f') ADVANCE(399);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 357:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'g') ADVANCE(460);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 358:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'h') ADVANCE(465);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 359:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'h') ADVANCE(326);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 360:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'i') ADVANCE(365);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 361:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'i') ADVANCE(323);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 362:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'i') ADVANCE(370);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 363:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'i') ADVANCE(380);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 364:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'l') ADVANCE(417);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 365:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'l') ADVANCE(344);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 366:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'm') ADVANCE(347);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 367:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'm') ADVANCE(350);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 368:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'm') ADVANCE(351);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 369:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'n') ADVANCE(459);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 370:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'n') ADVANCE(452);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 371:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'n') ADVANCE(455);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 372:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'n') ADVANCE(454);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 373:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'n') ADVANCE(335);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 374:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'n') ADVANCE(333);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 375:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'o') ADVANCE(388);
+      if (lookahead == 'u') ADVANCE(374);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 376:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'o') ADVANCE(378);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 377:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'o') ADVANCE(389);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 378:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'o') ADVANCE(409);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 379:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'o') ADVANCE(394);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 380:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'o') ADVANCE(372);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 381:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'o') ADVANCE(396);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 382:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'o') ADVANCE(390);
+      if (lookahead == 'u') ADVANCE(374);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 383:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'p') ADVANCE(325);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 384:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'p') ADVANCE(379);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 385:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'p') ADVANCE(381);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 386:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'p') ADVANCE(385);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 387:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'r') ADVANCE(377);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 388:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'r') ADVANCE(467);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 389:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'r') ADVANCE(458);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 390:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'r') ADVANCE(468);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 391:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'r') ADVANCE(369);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 392:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'r') ADVANCE(376);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 393:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'r') ADVANCE(336);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 394:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'r') ADVANCE(408);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 395:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'r') ADVANCE(371);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 396:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'r') ADVANCE(412);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 397:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'r') ADVANCE(406);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 398:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'r') ADVANCE(328);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 399:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'r') ADVANCE(329);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 400:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 's') ADVANCE(162);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 401:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 's') ADVANCE(155);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 402:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 's') ADVANCE(156);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 403:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 's') ADVANCE(383);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 404:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 's') ADVANCE(342);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 405:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 's') ADVANCE(343);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 406:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 's') ADVANCE(352);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 407:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 't') ADVANCE(319);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 408:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 't') ADVANCE(149);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 409:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 't') ADVANCE(461);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 410:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 't') ADVANCE(153);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 411:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 't') ADVANCE(418);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 412:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 't') ADVANCE(400);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 413:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 't') ADVANCE(363);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 414:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 't') ADVANCE(349);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 415:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'u') ADVANCE(357);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 416:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'u') ADVANCE(386);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 417:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'u') ADVANCE(338);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 418:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'u') ADVANCE(395);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 419:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'x') ADVANCE(362);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 420:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'y') ADVANCE(355);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 421:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'y') ADVANCE(356);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 422:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 423:
+      ACCEPT_TOKEN(sym_at_keyword);
+      if (lookahead == '-' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(423);
+      END_STATE();
+    case 424:
+      ACCEPT_TOKEN(sym_comment);
+      END_STATE();
+    case 425:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead == '/') ADVANCE(428);
+      if (lookahead == '-' ||
+          lookahead == '_') ADVANCE(425);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(426);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(430);
+      END_STATE();
+    case 426:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead == '/') ADVANCE(429);
+      if (lookahead == ':') ADVANCE(427);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ' ||
+          lookahead == '!' ||
+          lookahead == '(' ||
+          lookahead == ')' ||
+          lookahead == ',' ||
+          lookahead == ';' ||
+          lookahead == '[' ||
+          lookahead == ']' ||
+          lookahead == '{' ||
+          lookahead == '}') ADVANCE(430);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead)) ADVANCE(426);
+      END_STATE();
+    case 427:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ' ||
+          lookahead == '!' ||
+          lookahead == '(' ||
+          lookahead == ')' ||
+          lookahead == ',' ||
+          lookahead == ':' ||
+          lookahead == ';' ||
+          lookahead == '[' ||
+          lookahead == ']' ||
+          lookahead == '{' ||
+          lookahead == '}') ADVANCE(430);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead)) ADVANCE(426);
+      END_STATE();
+    case 428:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ' ||
+          lookahead == '!' ||
+          ('(' <= lookahead && lookahead <= '*') ||
+          lookahead == ',' ||
+          lookahead == ';' ||
+          lookahead == '[' ||
+          lookahead == ']' ||
+          lookahead == '{' ||
+          lookahead == '}') ADVANCE(430);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead)) ADVANCE(425);
+      END_STATE();
+    case 429:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ' ||
+          lookahead == '!' ||
+          ('(' <= lookahead && lookahead <= '*') ||
+          lookahead == ',' ||
+          lookahead == ';' ||
+          lookahead == '[' ||
+          lookahead == ']' ||
+          lookahead == '{' ||
+          lookahead == '}') ADVANCE(430);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead)) ADVANCE(426);
+      END_STATE();
+    case 430:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(430);
+      END_STATE();
+    case 431:
+      ACCEPT_TOKEN(sym_plain_value);
+      if (lookahead == '$') ADVANCE(433);
+      if (lookahead == '/') ADVANCE(142);
+      if (lookahead == ':') ADVANCE(143);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(434);
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 432:
+      ACCEPT_TOKEN(sym_plain_value);
+      if (lookahead == '/') ADVANCE(142);
+      if (lookahead == ':') ADVANCE(143);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(434);
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 433:
+      ACCEPT_TOKEN(sym_plain_value);
+      if (lookahead == '/') ADVANCE(142);
+      if (lookahead == ':') ADVANCE(143);
+      if (lookahead == '-' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(434);
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 434:
+      ACCEPT_TOKEN(sym_plain_value);
+      if (lookahead == '/') ADVANCE(142);
+      if (lookahead == ':') ADVANCE(143);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(434);
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 435:
+      ACCEPT_TOKEN(sym_plain_value);
+      if (lookahead == '/') ADVANCE(142);
+      if (lookahead == ':') ADVANCE(143);
+      if ((!eof && set_contains(sym_plain_value_character_set_3, 10, lookahead))) ADVANCE(435);
+      END_STATE();
+    case 436:
+      ACCEPT_TOKEN(sym_grit_metavariable);
+      END_STATE();
+    case 437:
+      ACCEPT_TOKEN(sym_grit_metavariable);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(437);
+      END_STATE();
+    case 438:
+      ACCEPT_TOKEN(anon_sym_BANGdefault);
+      END_STATE();
+    case 439:
+      ACCEPT_TOKEN(anon_sym_BANGglobal);
+      END_STATE();
+    case 440:
+      ACCEPT_TOKEN(anon_sym_ATuse);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 441:
+      ACCEPT_TOKEN(anon_sym_as);
+      END_STATE();
+    case 442:
+      ACCEPT_TOKEN(anon_sym_as);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 443:
+      ACCEPT_TOKEN(anon_sym_with);
+      END_STATE();
+    case 444:
+      ACCEPT_TOKEN(anon_sym_with);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 445:
+      ACCEPT_TOKEN(anon_sym_ATforward);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 446:
+      ACCEPT_TOKEN(anon_sym_STAR2);
+      END_STATE();
+    case 447:
+      ACCEPT_TOKEN(anon_sym_STAR2);
+      if (lookahead == '=') ADVANCE(178);
+      END_STATE();
+    case 448:
+      ACCEPT_TOKEN(anon_sym_show);
+      END_STATE();
+    case 449:
+      ACCEPT_TOKEN(anon_sym_show);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 450:
+      ACCEPT_TOKEN(anon_sym_hide);
+      END_STATE();
+    case 451:
+      ACCEPT_TOKEN(anon_sym_hide);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 452:
+      ACCEPT_TOKEN(anon_sym_ATmixin);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 453:
+      ACCEPT_TOKEN(anon_sym_ATinclude);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 454:
+      ACCEPT_TOKEN(anon_sym_ATfunction);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 455:
+      ACCEPT_TOKEN(anon_sym_ATreturn);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 456:
+      ACCEPT_TOKEN(anon_sym_ATextend);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 457:
+      ACCEPT_TOKEN(anon_sym_BANGoptional);
+      END_STATE();
+    case 458:
+      ACCEPT_TOKEN(anon_sym_ATerror);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 459:
+      ACCEPT_TOKEN(anon_sym_ATwarn);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 460:
+      ACCEPT_TOKEN(anon_sym_ATdebug);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 461:
+      ACCEPT_TOKEN(anon_sym_ATat_DASHroot);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 462:
+      ACCEPT_TOKEN(anon_sym_ATif);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 463:
+      ACCEPT_TOKEN(anon_sym_ATelse);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 464:
+      ACCEPT_TOKEN(anon_sym_if);
+      END_STATE();
+    case 465:
+      ACCEPT_TOKEN(anon_sym_ATeach);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 466:
+      ACCEPT_TOKEN(anon_sym_in);
+      END_STATE();
+    case 467:
+      ACCEPT_TOKEN(anon_sym_ATfor);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == 'w') ADVANCE(327);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 468:
+      ACCEPT_TOKEN(anon_sym_ATfor);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 469:
+      ACCEPT_TOKEN(anon_sym_through);
+      END_STATE();
+    case 470:
+      ACCEPT_TOKEN(anon_sym_through);
+      if (lookahead == '%' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(274);
+      END_STATE();
+    case 471:
+      ACCEPT_TOKEN(anon_sym_ATwhile);
+      if (lookahead == 'k') ADVANCE(353);
+      if (lookahead == '-' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(422);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_') ADVANCE(423);
+      END_STATE();
+    case 472:
+      ACCEPT_TOKEN(anon_sym_POUND_LBRACE);
+      END_STATE();
+    case 473:
+      ACCEPT_TOKEN(anon_sym_PERCENT);
+      END_STATE();
+    case 474:
+      ACCEPT_TOKEN(sym_module_member);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(474);
+      END_STATE();
+    case 475:
+      ACCEPT_TOKEN(anon_sym_DASH);
+      END_STATE();
+    case 476:
+      ACCEPT_TOKEN(anon_sym_DASH);
+      if (lookahead == '-' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(318);
+      END_STATE();
+    case 477:
+      ACCEPT_TOKEN(sym_variable);
+      if (lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(477);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static const TSLexMode ts_lex_modes[STATE_COUNT] = {
+  [0] = {.lex_state = 0, .external_lex_state = 1},
+  [1] = {.lex_state = 145},
+  [2] = {.lex_state = 145},
+  [3] = {.lex_state = 145},
+  [4] = {.lex_state = 21},
+  [5] = {.lex_state = 21},
+  [6] = {.lex_state = 21},
+  [7] = {.lex_state = 21},
+  [8] = {.lex_state = 21},
+  [9] = {.lex_state = 21},
+  [10] = {.lex_state = 21},
+  [11] = {.lex_state = 21},
+  [12] = {.lex_state = 21},
+  [13] = {.lex_state = 21},
+  [14] = {.lex_state = 21},
+  [15] = {.lex_state = 3},
+  [16] = {.lex_state = 3},
+  [17] = {.lex_state = 3},
+  [18] = {.lex_state = 5},
+  [19] = {.lex_state = 5},
+  [20] = {.lex_state = 5},
+  [21] = {.lex_state = 147},
+  [22] = {.lex_state = 21, .external_lex_state = 2},
+  [23] = {.lex_state = 147},
+  [24] = {.lex_state = 5},
+  [25] = {.lex_state = 5},
+  [26] = {.lex_state = 147},
+  [27] = {.lex_state = 22},
+  [28] = {.lex_state = 22},
+  [29] = {.lex_state = 147},
+  [30] = {.lex_state = 22},
+  [31] = {.lex_state = 147},
+  [32] = {.lex_state = 147},
+  [33] = {.lex_state = 147},
+  [34] = {.lex_state = 147},
+  [35] = {.lex_state = 147},
+  [36] = {.lex_state = 145},
+  [37] = {.lex_state = 145},
+  [38] = {.lex_state = 145},
+  [39] = {.lex_state = 145},
+  [40] = {.lex_state = 145},
+  [41] = {.lex_state = 145},
+  [42] = {.lex_state = 145},
+  [43] = {.lex_state = 145},
+  [44] = {.lex_state = 145},
+  [45] = {.lex_state = 145},
+  [46] = {.lex_state = 145},
+  [47] = {.lex_state = 145},
+  [48] = {.lex_state = 145},
+  [49] = {.lex_state = 145},
+  [50] = {.lex_state = 145},
+  [51] = {.lex_state = 145},
+  [52] = {.lex_state = 145},
+  [53] = {.lex_state = 145},
+  [54] = {.lex_state = 145},
+  [55] = {.lex_state = 145},
+  [56] = {.lex_state = 145},
+  [57] = {.lex_state = 145},
+  [58] = {.lex_state = 145},
+  [59] = {.lex_state = 145},
+  [60] = {.lex_state = 145},
+  [61] = {.lex_state = 145},
+  [62] = {.lex_state = 145},
+  [63] = {.lex_state = 145},
+  [64] = {.lex_state = 145},
+  [65] = {.lex_state = 145},
+  [66] = {.lex_state = 145},
+  [67] = {.lex_state = 145},
+  [68] = {.lex_state = 145},
+  [69] = {.lex_state = 145},
+  [70] = {.lex_state = 145},
+  [71] = {.lex_state = 145},
+  [72] = {.lex_state = 145},
+  [73] = {.lex_state = 145},
+  [74] = {.lex_state = 145},
+  [75] = {.lex_state = 145},
+  [76] = {.lex_state = 145},
+  [77] = {.lex_state = 145},
+  [78] = {.lex_state = 145},
+  [79] = {.lex_state = 145},
+  [80] = {.lex_state = 145},
+  [81] = {.lex_state = 145},
+  [82] = {.lex_state = 145},
+  [83] = {.lex_state = 145},
+  [84] = {.lex_state = 145},
+  [85] = {.lex_state = 145},
+  [86] = {.lex_state = 145},
+  [87] = {.lex_state = 145},
+  [88] = {.lex_state = 145},
+  [89] = {.lex_state = 145},
+  [90] = {.lex_state = 145},
+  [91] = {.lex_state = 145},
+  [92] = {.lex_state = 145},
+  [93] = {.lex_state = 145},
+  [94] = {.lex_state = 145},
+  [95] = {.lex_state = 145},
+  [96] = {.lex_state = 145},
+  [97] = {.lex_state = 145},
+  [98] = {.lex_state = 145},
+  [99] = {.lex_state = 145},
+  [100] = {.lex_state = 145},
+  [101] = {.lex_state = 145},
+  [102] = {.lex_state = 145},
+  [103] = {.lex_state = 145},
+  [104] = {.lex_state = 145},
+  [105] = {.lex_state = 145},
+  [106] = {.lex_state = 145},
+  [107] = {.lex_state = 145},
+  [108] = {.lex_state = 145},
+  [109] = {.lex_state = 22},
+  [110] = {.lex_state = 22},
+  [111] = {.lex_state = 22},
+  [112] = {.lex_state = 22},
+  [113] = {.lex_state = 2, .external_lex_state = 3},
+  [114] = {.lex_state = 22},
+  [115] = {.lex_state = 22},
+  [116] = {.lex_state = 21},
+  [117] = {.lex_state = 21},
+  [118] = {.lex_state = 21},
+  [119] = {.lex_state = 21},
+  [120] = {.lex_state = 21},
+  [121] = {.lex_state = 21},
+  [122] = {.lex_state = 21},
+  [123] = {.lex_state = 21},
+  [124] = {.lex_state = 21},
+  [125] = {.lex_state = 21},
+  [126] = {.lex_state = 21},
+  [127] = {.lex_state = 21},
+  [128] = {.lex_state = 21},
+  [129] = {.lex_state = 21},
+  [130] = {.lex_state = 21},
+  [131] = {.lex_state = 21},
+  [132] = {.lex_state = 21},
+  [133] = {.lex_state = 21},
+  [134] = {.lex_state = 21},
+  [135] = {.lex_state = 21},
+  [136] = {.lex_state = 21},
+  [137] = {.lex_state = 21},
+  [138] = {.lex_state = 21},
+  [139] = {.lex_state = 21},
+  [140] = {.lex_state = 21},
+  [141] = {.lex_state = 21},
+  [142] = {.lex_state = 21},
+  [143] = {.lex_state = 21},
+  [144] = {.lex_state = 21},
+  [145] = {.lex_state = 21},
+  [146] = {.lex_state = 21},
+  [147] = {.lex_state = 21},
+  [148] = {.lex_state = 21},
+  [149] = {.lex_state = 21},
+  [150] = {.lex_state = 21},
+  [151] = {.lex_state = 21},
+  [152] = {.lex_state = 21},
+  [153] = {.lex_state = 21},
+  [154] = {.lex_state = 21},
+  [155] = {.lex_state = 21},
+  [156] = {.lex_state = 21},
+  [157] = {.lex_state = 21},
+  [158] = {.lex_state = 21},
+  [159] = {.lex_state = 21},
+  [160] = {.lex_state = 21},
+  [161] = {.lex_state = 21},
+  [162] = {.lex_state = 21},
+  [163] = {.lex_state = 21},
+  [164] = {.lex_state = 21},
+  [165] = {.lex_state = 21},
+  [166] = {.lex_state = 2, .external_lex_state = 4},
+  [167] = {.lex_state = 21},
+  [168] = {.lex_state = 21},
+  [169] = {.lex_state = 21},
+  [170] = {.lex_state = 21},
+  [171] = {.lex_state = 3, .external_lex_state = 2},
+  [172] = {.lex_state = 3, .external_lex_state = 3},
+  [173] = {.lex_state = 3, .external_lex_state = 3},
+  [174] = {.lex_state = 3, .external_lex_state = 3},
+  [175] = {.lex_state = 3, .external_lex_state = 3},
+  [176] = {.lex_state = 2},
+  [177] = {.lex_state = 3},
+  [178] = {.lex_state = 3},
+  [179] = {.lex_state = 3},
+  [180] = {.lex_state = 3},
+  [181] = {.lex_state = 3, .external_lex_state = 4},
+  [182] = {.lex_state = 3, .external_lex_state = 4},
+  [183] = {.lex_state = 3, .external_lex_state = 3},
+  [184] = {.lex_state = 3, .external_lex_state = 4},
+  [185] = {.lex_state = 3},
+  [186] = {.lex_state = 3, .external_lex_state = 2},
+  [187] = {.lex_state = 3, .external_lex_state = 2},
+  [188] = {.lex_state = 1},
+  [189] = {.lex_state = 3, .external_lex_state = 4},
+  [190] = {.lex_state = 1},
+  [191] = {.lex_state = 3, .external_lex_state = 4},
+  [192] = {.lex_state = 3},
+  [193] = {.lex_state = 3},
+  [194] = {.lex_state = 5},
+  [195] = {.lex_state = 3},
+  [196] = {.lex_state = 3},
+  [197] = {.lex_state = 3},
+  [198] = {.lex_state = 3},
+  [199] = {.lex_state = 3},
+  [200] = {.lex_state = 3},
+  [201] = {.lex_state = 3},
+  [202] = {.lex_state = 3},
+  [203] = {.lex_state = 3},
+  [204] = {.lex_state = 3},
+  [205] = {.lex_state = 3},
+  [206] = {.lex_state = 3},
+  [207] = {.lex_state = 3},
+  [208] = {.lex_state = 5},
+  [209] = {.lex_state = 5},
+  [210] = {.lex_state = 7, .external_lex_state = 4},
+  [211] = {.lex_state = 5},
+  [212] = {.lex_state = 13},
+  [213] = {.lex_state = 3},
+  [214] = {.lex_state = 13},
+  [215] = {.lex_state = 7, .external_lex_state = 4},
+  [216] = {.lex_state = 7, .external_lex_state = 4},
+  [217] = {.lex_state = 5},
+  [218] = {.lex_state = 7, .external_lex_state = 4},
+  [219] = {.lex_state = 5},
+  [220] = {.lex_state = 7},
+  [221] = {.lex_state = 5},
+  [222] = {.lex_state = 7, .external_lex_state = 3},
+  [223] = {.lex_state = 7, .external_lex_state = 4},
+  [224] = {.lex_state = 7, .external_lex_state = 3},
+  [225] = {.lex_state = 7, .external_lex_state = 3},
+  [226] = {.lex_state = 5},
+  [227] = {.lex_state = 5},
+  [228] = {.lex_state = 5},
+  [229] = {.lex_state = 5},
+  [230] = {.lex_state = 7, .external_lex_state = 4},
+  [231] = {.lex_state = 23},
+  [232] = {.lex_state = 7},
+  [233] = {.lex_state = 23},
+  [234] = {.lex_state = 23},
+  [235] = {.lex_state = 7},
+  [236] = {.lex_state = 7},
+  [237] = {.lex_state = 7},
+  [238] = {.lex_state = 7},
+  [239] = {.lex_state = 23},
+  [240] = {.lex_state = 7},
+  [241] = {.lex_state = 7},
+  [242] = {.lex_state = 7, .external_lex_state = 3},
+  [243] = {.lex_state = 23},
+  [244] = {.lex_state = 7, .external_lex_state = 3},
+  [245] = {.lex_state = 7},
+  [246] = {.lex_state = 23},
+  [247] = {.lex_state = 7},
+  [248] = {.lex_state = 7},
+  [249] = {.lex_state = 7},
+  [250] = {.lex_state = 7},
+  [251] = {.lex_state = 5},
+  [252] = {.lex_state = 6, .external_lex_state = 2},
+  [253] = {.lex_state = 7, .external_lex_state = 3},
+  [254] = {.lex_state = 7},
+  [255] = {.lex_state = 7},
+  [256] = {.lex_state = 5},
+  [257] = {.lex_state = 5},
+  [258] = {.lex_state = 5},
+  [259] = {.lex_state = 5},
+  [260] = {.lex_state = 5},
+  [261] = {.lex_state = 5},
+  [262] = {.lex_state = 5},
+  [263] = {.lex_state = 5},
+  [264] = {.lex_state = 5},
+  [265] = {.lex_state = 5},
+  [266] = {.lex_state = 5},
+  [267] = {.lex_state = 5},
+  [268] = {.lex_state = 5},
+  [269] = {.lex_state = 5},
+  [270] = {.lex_state = 5},
+  [271] = {.lex_state = 5},
+  [272] = {.lex_state = 5},
+  [273] = {.lex_state = 5},
+  [274] = {.lex_state = 5},
+  [275] = {.lex_state = 5},
+  [276] = {.lex_state = 5},
+  [277] = {.lex_state = 5},
+  [278] = {.lex_state = 5},
+  [279] = {.lex_state = 12, .external_lex_state = 4},
+  [280] = {.lex_state = 5},
+  [281] = {.lex_state = 5},
+  [282] = {.lex_state = 5},
+  [283] = {.lex_state = 5},
+  [284] = {.lex_state = 5},
+  [285] = {.lex_state = 5},
+  [286] = {.lex_state = 5},
+  [287] = {.lex_state = 5},
+  [288] = {.lex_state = 5},
+  [289] = {.lex_state = 5},
+  [290] = {.lex_state = 5},
+  [291] = {.lex_state = 5},
+  [292] = {.lex_state = 5},
+  [293] = {.lex_state = 5},
+  [294] = {.lex_state = 5},
+  [295] = {.lex_state = 5},
+  [296] = {.lex_state = 5},
+  [297] = {.lex_state = 5},
+  [298] = {.lex_state = 5},
+  [299] = {.lex_state = 5},
+  [300] = {.lex_state = 5},
+  [301] = {.lex_state = 5},
+  [302] = {.lex_state = 5},
+  [303] = {.lex_state = 5},
+  [304] = {.lex_state = 5},
+  [305] = {.lex_state = 5},
+  [306] = {.lex_state = 5},
+  [307] = {.lex_state = 5},
+  [308] = {.lex_state = 5},
+  [309] = {.lex_state = 5},
+  [310] = {.lex_state = 5},
+  [311] = {.lex_state = 5},
+  [312] = {.lex_state = 5},
+  [313] = {.lex_state = 5},
+  [314] = {.lex_state = 5},
+  [315] = {.lex_state = 5},
+  [316] = {.lex_state = 5},
+  [317] = {.lex_state = 5},
+  [318] = {.lex_state = 5},
+  [319] = {.lex_state = 5},
+  [320] = {.lex_state = 5},
+  [321] = {.lex_state = 5},
+  [322] = {.lex_state = 5},
+  [323] = {.lex_state = 5},
+  [324] = {.lex_state = 5},
+  [325] = {.lex_state = 5},
+  [326] = {.lex_state = 5},
+  [327] = {.lex_state = 5},
+  [328] = {.lex_state = 5},
+  [329] = {.lex_state = 5},
+  [330] = {.lex_state = 5},
+  [331] = {.lex_state = 5},
+  [332] = {.lex_state = 5},
+  [333] = {.lex_state = 5},
+  [334] = {.lex_state = 5},
+  [335] = {.lex_state = 5},
+  [336] = {.lex_state = 5},
+  [337] = {.lex_state = 5},
+  [338] = {.lex_state = 5},
+  [339] = {.lex_state = 5},
+  [340] = {.lex_state = 5},
+  [341] = {.lex_state = 5},
+  [342] = {.lex_state = 5},
+  [343] = {.lex_state = 5},
+  [344] = {.lex_state = 5},
+  [345] = {.lex_state = 5},
+  [346] = {.lex_state = 5},
+  [347] = {.lex_state = 5},
+  [348] = {.lex_state = 5},
+  [349] = {.lex_state = 7, .external_lex_state = 2},
+  [350] = {.lex_state = 13, .external_lex_state = 4},
+  [351] = {.lex_state = 7, .external_lex_state = 2},
+  [352] = {.lex_state = 13, .external_lex_state = 4},
+  [353] = {.lex_state = 7},
+  [354] = {.lex_state = 7},
+  [355] = {.lex_state = 7},
+  [356] = {.lex_state = 7},
+  [357] = {.lex_state = 12},
+  [358] = {.lex_state = 13, .external_lex_state = 4},
+  [359] = {.lex_state = 13},
+  [360] = {.lex_state = 13},
+  [361] = {.lex_state = 13},
+  [362] = {.lex_state = 13},
+  [363] = {.lex_state = 11},
+  [364] = {.lex_state = 13},
+  [365] = {.lex_state = 11},
+  [366] = {.lex_state = 13, .external_lex_state = 4},
+  [367] = {.lex_state = 13, .external_lex_state = 4},
+  [368] = {.lex_state = 9},
+  [369] = {.lex_state = 9},
+  [370] = {.lex_state = 13},
+  [371] = {.lex_state = 13},
+  [372] = {.lex_state = 13},
+  [373] = {.lex_state = 13},
+  [374] = {.lex_state = 13},
+  [375] = {.lex_state = 13},
+  [376] = {.lex_state = 13},
+  [377] = {.lex_state = 13},
+  [378] = {.lex_state = 13},
+  [379] = {.lex_state = 13},
+  [380] = {.lex_state = 13},
+  [381] = {.lex_state = 13},
+  [382] = {.lex_state = 13},
+  [383] = {.lex_state = 7},
+  [384] = {.lex_state = 7},
+  [385] = {.lex_state = 7},
+  [386] = {.lex_state = 7},
+  [387] = {.lex_state = 7},
+  [388] = {.lex_state = 17},
+  [389] = {.lex_state = 17},
+  [390] = {.lex_state = 7},
+  [391] = {.lex_state = 7},
+  [392] = {.lex_state = 7},
+  [393] = {.lex_state = 145, .external_lex_state = 3},
+  [394] = {.lex_state = 29},
+  [395] = {.lex_state = 7},
+  [396] = {.lex_state = 145, .external_lex_state = 3},
+  [397] = {.lex_state = 29},
+  [398] = {.lex_state = 7},
+  [399] = {.lex_state = 7},
+  [400] = {.lex_state = 7},
+  [401] = {.lex_state = 7},
+  [402] = {.lex_state = 23, .external_lex_state = 2},
+  [403] = {.lex_state = 7},
+  [404] = {.lex_state = 7},
+  [405] = {.lex_state = 7},
+  [406] = {.lex_state = 7},
+  [407] = {.lex_state = 18},
+  [408] = {.lex_state = 7},
+  [409] = {.lex_state = 7},
+  [410] = {.lex_state = 7},
+  [411] = {.lex_state = 7},
+  [412] = {.lex_state = 18},
+  [413] = {.lex_state = 7},
+  [414] = {.lex_state = 7},
+  [415] = {.lex_state = 7},
+  [416] = {.lex_state = 7},
+  [417] = {.lex_state = 7},
+  [418] = {.lex_state = 7},
+  [419] = {.lex_state = 7},
+  [420] = {.lex_state = 7},
+  [421] = {.lex_state = 7},
+  [422] = {.lex_state = 7},
+  [423] = {.lex_state = 7},
+  [424] = {.lex_state = 7},
+  [425] = {.lex_state = 7},
+  [426] = {.lex_state = 7},
+  [427] = {.lex_state = 7},
+  [428] = {.lex_state = 7},
+  [429] = {.lex_state = 7},
+  [430] = {.lex_state = 7},
+  [431] = {.lex_state = 7},
+  [432] = {.lex_state = 7},
+  [433] = {.lex_state = 145, .external_lex_state = 2},
+  [434] = {.lex_state = 19},
+  [435] = {.lex_state = 145, .external_lex_state = 2},
+  [436] = {.lex_state = 19},
+  [437] = {.lex_state = 7},
+  [438] = {.lex_state = 7},
+  [439] = {.lex_state = 145, .external_lex_state = 2},
+  [440] = {.lex_state = 7},
+  [441] = {.lex_state = 7},
+  [442] = {.lex_state = 7},
+  [443] = {.lex_state = 7},
+  [444] = {.lex_state = 7},
+  [445] = {.lex_state = 145, .external_lex_state = 2},
+  [446] = {.lex_state = 145, .external_lex_state = 2},
+  [447] = {.lex_state = 145, .external_lex_state = 2},
+  [448] = {.lex_state = 7},
+  [449] = {.lex_state = 145, .external_lex_state = 2},
+  [450] = {.lex_state = 7},
+  [451] = {.lex_state = 7},
+  [452] = {.lex_state = 7},
+  [453] = {.lex_state = 7},
+  [454] = {.lex_state = 7},
+  [455] = {.lex_state = 7},
+  [456] = {.lex_state = 7},
+  [457] = {.lex_state = 7},
+  [458] = {.lex_state = 7},
+  [459] = {.lex_state = 7},
+  [460] = {.lex_state = 145, .external_lex_state = 3},
+  [461] = {.lex_state = 7},
+  [462] = {.lex_state = 7},
+  [463] = {.lex_state = 29},
+  [464] = {.lex_state = 29},
+  [465] = {.lex_state = 29},
+  [466] = {.lex_state = 29},
+  [467] = {.lex_state = 29},
+  [468] = {.lex_state = 29},
+  [469] = {.lex_state = 29},
+  [470] = {.lex_state = 145, .external_lex_state = 3},
+  [471] = {.lex_state = 7},
+  [472] = {.lex_state = 29},
+  [473] = {.lex_state = 29},
+  [474] = {.lex_state = 29},
+  [475] = {.lex_state = 145, .external_lex_state = 3},
+  [476] = {.lex_state = 145, .external_lex_state = 3},
+  [477] = {.lex_state = 145, .external_lex_state = 2},
+  [478] = {.lex_state = 145, .external_lex_state = 2},
+  [479] = {.lex_state = 145, .external_lex_state = 2},
+  [480] = {.lex_state = 145, .external_lex_state = 2},
+  [481] = {.lex_state = 145, .external_lex_state = 2},
+  [482] = {.lex_state = 145, .external_lex_state = 2},
+  [483] = {.lex_state = 145, .external_lex_state = 2},
+  [484] = {.lex_state = 145, .external_lex_state = 2},
+  [485] = {.lex_state = 145, .external_lex_state = 2},
+  [486] = {.lex_state = 145, .external_lex_state = 2},
+  [487] = {.lex_state = 145, .external_lex_state = 2},
+  [488] = {.lex_state = 145, .external_lex_state = 2},
+  [489] = {.lex_state = 145, .external_lex_state = 2},
+  [490] = {.lex_state = 145, .external_lex_state = 2},
+  [491] = {.lex_state = 145, .external_lex_state = 2},
+  [492] = {.lex_state = 145, .external_lex_state = 2},
+  [493] = {.lex_state = 145, .external_lex_state = 2},
+  [494] = {.lex_state = 145, .external_lex_state = 2},
+  [495] = {.lex_state = 145, .external_lex_state = 2},
+  [496] = {.lex_state = 145, .external_lex_state = 2},
+  [497] = {.lex_state = 145, .external_lex_state = 2},
+  [498] = {.lex_state = 145, .external_lex_state = 2},
+  [499] = {.lex_state = 145, .external_lex_state = 2},
+  [500] = {.lex_state = 145, .external_lex_state = 2},
+  [501] = {.lex_state = 145, .external_lex_state = 2},
+  [502] = {.lex_state = 145, .external_lex_state = 2},
+  [503] = {.lex_state = 145, .external_lex_state = 2},
+  [504] = {.lex_state = 145, .external_lex_state = 2},
+  [505] = {.lex_state = 145, .external_lex_state = 2},
+  [506] = {.lex_state = 5},
+  [507] = {.lex_state = 5},
+  [508] = {.lex_state = 145, .external_lex_state = 2},
+  [509] = {.lex_state = 145, .external_lex_state = 2},
+  [510] = {.lex_state = 145, .external_lex_state = 2},
+  [511] = {.lex_state = 145, .external_lex_state = 2},
+  [512] = {.lex_state = 145, .external_lex_state = 2},
+  [513] = {.lex_state = 145, .external_lex_state = 2},
+  [514] = {.lex_state = 145, .external_lex_state = 2},
+  [515] = {.lex_state = 38},
+  [516] = {.lex_state = 38},
+  [517] = {.lex_state = 0},
+  [518] = {.lex_state = 0},
+  [519] = {.lex_state = 38},
+  [520] = {.lex_state = 38},
+  [521] = {.lex_state = 38},
+  [522] = {.lex_state = 0},
+  [523] = {.lex_state = 0},
+  [524] = {.lex_state = 0},
+  [525] = {.lex_state = 0},
+  [526] = {.lex_state = 25},
+  [527] = {.lex_state = 0},
+  [528] = {.lex_state = 29},
+  [529] = {.lex_state = 29},
+  [530] = {.lex_state = 29},
+  [531] = {.lex_state = 0},
+  [532] = {.lex_state = 25},
+  [533] = {.lex_state = 0},
+  [534] = {.lex_state = 29},
+  [535] = {.lex_state = 0},
+  [536] = {.lex_state = 0},
+  [537] = {.lex_state = 0},
+  [538] = {.lex_state = 0},
+  [539] = {.lex_state = 0},
+  [540] = {.lex_state = 0},
+  [541] = {.lex_state = 0},
+  [542] = {.lex_state = 0},
+  [543] = {.lex_state = 0},
+  [544] = {.lex_state = 0},
+  [545] = {.lex_state = 0},
+  [546] = {.lex_state = 0},
+  [547] = {.lex_state = 25},
+  [548] = {.lex_state = 0},
+  [549] = {.lex_state = 0},
+  [550] = {.lex_state = 25},
+  [551] = {.lex_state = 0},
+  [552] = {.lex_state = 0},
+  [553] = {.lex_state = 23},
+  [554] = {.lex_state = 0},
+  [555] = {.lex_state = 38},
+  [556] = {.lex_state = 0},
+  [557] = {.lex_state = 38},
+  [558] = {.lex_state = 0},
+  [559] = {.lex_state = 0},
+  [560] = {.lex_state = 0},
+  [561] = {.lex_state = 0},
+  [562] = {.lex_state = 0},
+  [563] = {.lex_state = 21},
+  [564] = {.lex_state = 0},
+  [565] = {.lex_state = 0},
+  [566] = {.lex_state = 0},
+  [567] = {.lex_state = 23},
+  [568] = {.lex_state = 0},
+  [569] = {.lex_state = 0},
+  [570] = {.lex_state = 21},
+  [571] = {.lex_state = 23},
+  [572] = {.lex_state = 0},
+  [573] = {.lex_state = 0},
+  [574] = {.lex_state = 23},
+  [575] = {.lex_state = 21},
+  [576] = {.lex_state = 38},
+  [577] = {.lex_state = 145},
+  [578] = {.lex_state = 23},
+  [579] = {.lex_state = 0},
+  [580] = {.lex_state = 21},
+  [581] = {.lex_state = 38},
+  [582] = {.lex_state = 23},
+  [583] = {.lex_state = 23},
+  [584] = {.lex_state = 0},
+  [585] = {.lex_state = 0},
+  [586] = {.lex_state = 0},
+  [587] = {.lex_state = 23},
+  [588] = {.lex_state = 38},
+  [589] = {.lex_state = 0},
+  [590] = {.lex_state = 0},
+  [591] = {.lex_state = 0},
+  [592] = {.lex_state = 0},
+  [593] = {.lex_state = 0},
+  [594] = {.lex_state = 0},
+  [595] = {.lex_state = 0},
+  [596] = {.lex_state = 0},
+  [597] = {.lex_state = 23},
+  [598] = {.lex_state = 0},
+  [599] = {.lex_state = 0},
+  [600] = {.lex_state = 0},
+  [601] = {.lex_state = 0},
+  [602] = {.lex_state = 0},
+  [603] = {.lex_state = 0},
+  [604] = {.lex_state = 0},
+  [605] = {.lex_state = 0},
+  [606] = {.lex_state = 0},
+  [607] = {.lex_state = 0},
+  [608] = {.lex_state = 0},
+  [609] = {.lex_state = 0},
+  [610] = {.lex_state = 145},
+  [611] = {.lex_state = 0},
+  [612] = {.lex_state = 0},
+  [613] = {.lex_state = 0},
+  [614] = {.lex_state = 0},
+  [615] = {.lex_state = 0},
+  [616] = {.lex_state = 0},
+  [617] = {.lex_state = 0},
+  [618] = {.lex_state = 0},
+  [619] = {.lex_state = 23},
+  [620] = {.lex_state = 0},
+  [621] = {.lex_state = 23},
+  [622] = {.lex_state = 23},
+  [623] = {.lex_state = 23},
+  [624] = {.lex_state = 0},
+  [625] = {.lex_state = 0},
+  [626] = {.lex_state = 0},
+  [627] = {.lex_state = 23},
+  [628] = {.lex_state = 0},
+  [629] = {.lex_state = 0},
+  [630] = {.lex_state = 0},
+  [631] = {.lex_state = 23},
+  [632] = {.lex_state = 0},
+  [633] = {.lex_state = 0},
+  [634] = {.lex_state = 0},
+  [635] = {.lex_state = 0},
+  [636] = {.lex_state = 0},
+  [637] = {.lex_state = 0},
+  [638] = {.lex_state = 0},
+  [639] = {.lex_state = 0},
+  [640] = {.lex_state = 0},
+  [641] = {.lex_state = 0},
+  [642] = {.lex_state = 21},
+  [643] = {.lex_state = 0},
+  [644] = {.lex_state = 0},
+  [645] = {.lex_state = 21},
+  [646] = {.lex_state = 21},
+  [647] = {.lex_state = 145},
+  [648] = {.lex_state = 0},
+  [649] = {.lex_state = 39},
+  [650] = {.lex_state = 145},
+  [651] = {.lex_state = 0},
+  [652] = {.lex_state = 0},
+  [653] = {.lex_state = 0},
+  [654] = {.lex_state = 0},
+  [655] = {.lex_state = 0},
+  [656] = {.lex_state = 0},
+  [657] = {.lex_state = 39},
+  [658] = {.lex_state = 21},
+  [659] = {.lex_state = 0},
+  [660] = {.lex_state = 0},
+  [661] = {.lex_state = 21},
+  [662] = {.lex_state = 0},
+  [663] = {.lex_state = 0},
+  [664] = {.lex_state = 0},
+  [665] = {.lex_state = 0},
+  [666] = {.lex_state = 21},
+  [667] = {.lex_state = 0},
+  [668] = {.lex_state = 21},
+  [669] = {.lex_state = 49},
+  [670] = {.lex_state = 145},
+  [671] = {.lex_state = 0},
+  [672] = {.lex_state = 21},
+  [673] = {.lex_state = 0},
+  [674] = {.lex_state = 0},
+  [675] = {.lex_state = 21},
+  [676] = {.lex_state = 0},
+  [677] = {.lex_state = 0},
+  [678] = {.lex_state = 0},
+  [679] = {.lex_state = 0},
+  [680] = {.lex_state = 23},
+  [681] = {.lex_state = 0},
+  [682] = {.lex_state = 0},
+  [683] = {.lex_state = 23},
+  [684] = {.lex_state = 0},
+  [685] = {.lex_state = 23},
+  [686] = {.lex_state = 0},
+  [687] = {.lex_state = 0},
+  [688] = {.lex_state = 0},
+  [689] = {.lex_state = 23},
+  [690] = {.lex_state = 0},
+  [691] = {.lex_state = 23},
+  [692] = {.lex_state = 0},
+  [693] = {.lex_state = 0},
+  [694] = {.lex_state = 0},
+  [695] = {.lex_state = 0},
+  [696] = {.lex_state = 0},
+  [697] = {.lex_state = 0},
+  [698] = {.lex_state = 0},
+  [699] = {.lex_state = 0},
+  [700] = {.lex_state = 0},
+  [701] = {.lex_state = 0},
+  [702] = {.lex_state = 0},
+  [703] = {.lex_state = 23},
+  [704] = {.lex_state = 0},
+  [705] = {.lex_state = 0},
+  [706] = {.lex_state = 145},
+  [707] = {.lex_state = 23},
+  [708] = {.lex_state = 23},
+  [709] = {.lex_state = 0},
+  [710] = {.lex_state = 0},
+  [711] = {.lex_state = 0},
+  [712] = {.lex_state = 0},
+  [713] = {.lex_state = 0},
+  [714] = {.lex_state = 23},
+  [715] = {.lex_state = 0},
+  [716] = {.lex_state = 0},
+  [717] = {.lex_state = 0},
+  [718] = {.lex_state = 0},
+  [719] = {.lex_state = 0},
+  [720] = {.lex_state = 0},
+  [721] = {.lex_state = 0},
+  [722] = {.lex_state = 23},
+  [723] = {.lex_state = 0},
+  [724] = {.lex_state = 21},
+  [725] = {.lex_state = 0},
+  [726] = {.lex_state = 0},
+  [727] = {.lex_state = 145},
+  [728] = {.lex_state = 0},
+  [729] = {.lex_state = 0},
+  [730] = {.lex_state = 0},
+  [731] = {.lex_state = 145},
+  [732] = {.lex_state = 0},
+  [733] = {.lex_state = 0},
+  [734] = {.lex_state = 0},
+  [735] = {.lex_state = 0},
+  [736] = {.lex_state = 0},
+  [737] = {.lex_state = 0},
+  [738] = {.lex_state = 0},
+  [739] = {.lex_state = 38},
+  [740] = {.lex_state = 145},
+  [741] = {.lex_state = 145},
+  [742] = {.lex_state = 0},
+  [743] = {.lex_state = 0},
+  [744] = {.lex_state = 145},
+  [745] = {.lex_state = 23},
+};
+
+static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
+  [0] = {
+    [ts_builtin_sym_end] = ACTIONS(1),
+    [anon_sym_ATimport] = ACTIONS(1),
+    [anon_sym_COMMA] = ACTIONS(1),
+    [anon_sym_SEMI] = ACTIONS(1),
+    [anon_sym_ATmedia] = ACTIONS(1),
+    [anon_sym_ATcharset] = ACTIONS(1),
+    [anon_sym_ATnamespace] = ACTIONS(1),
+    [anon_sym_ATkeyframes] = ACTIONS(1),
+    [aux_sym_keyframes_statement_token1] = ACTIONS(1),
+    [anon_sym_LBRACE] = ACTIONS(1),
+    [anon_sym_RBRACE] = ACTIONS(1),
+    [anon_sym_from] = ACTIONS(1),
+    [anon_sym_to] = ACTIONS(1),
+    [anon_sym_ATsupports] = ACTIONS(1),
+    [sym_nesting_selector] = ACTIONS(1),
+    [anon_sym_STAR] = ACTIONS(1),
+    [anon_sym_DOT] = ACTIONS(1),
+    [anon_sym_COLON] = ACTIONS(1),
+    [anon_sym_COLON_COLON] = ACTIONS(1),
+    [anon_sym_POUND] = ACTIONS(1),
+    [sym_equal] = ACTIONS(1),
+    [sym_contains_word_equal] = ACTIONS(1),
+    [sym_starts_with_equal] = ACTIONS(1),
+    [sym_dash_equal] = ACTIONS(1),
+    [sym_contains_equal] = ACTIONS(1),
+    [sym_ends_equal] = ACTIONS(1),
+    [anon_sym_LBRACK] = ACTIONS(1),
+    [anon_sym_RBRACK] = ACTIONS(1),
+    [anon_sym_GT] = ACTIONS(1),
+    [anon_sym_TILDE] = ACTIONS(1),
+    [anon_sym_PLUS] = ACTIONS(1),
+    [anon_sym_LPAREN] = ACTIONS(1),
+    [anon_sym_RPAREN] = ACTIONS(1),
+    [sym_important] = ACTIONS(1),
+    [anon_sym_LPAREN2] = ACTIONS(1),
+    [anon_sym_and] = ACTIONS(1),
+    [anon_sym_or] = ACTIONS(1),
+    [sym_not] = ACTIONS(1),
+    [sym_only] = ACTIONS(1),
+    [anon_sym_selector] = ACTIONS(1),
+    [aux_sym_color_value_token1] = ACTIONS(1),
+    [sym_string_value] = ACTIONS(1),
+    [sym_minus] = ACTIONS(1),
+    [sym_divide] = ACTIONS(1),
+    [anon_sym_EQ_EQ] = ACTIONS(1),
+    [anon_sym_BANG_EQ] = ACTIONS(1),
+    [anon_sym_LT] = ACTIONS(1),
+    [anon_sym_LT_EQ] = ACTIONS(1),
+    [anon_sym_GT_EQ] = ACTIONS(1),
+    [sym_at_keyword] = ACTIONS(1),
+    [sym_comment] = ACTIONS(3),
+    [sym_grit_metavariable] = ACTIONS(1),
+    [anon_sym_BANGdefault] = ACTIONS(1),
+    [anon_sym_BANGglobal] = ACTIONS(1),
+    [anon_sym_ATuse] = ACTIONS(1),
+    [anon_sym_as] = ACTIONS(1),
+    [anon_sym_with] = ACTIONS(1),
+    [anon_sym_ATforward] = ACTIONS(1),
+    [anon_sym_STAR2] = ACTIONS(1),
+    [anon_sym_show] = ACTIONS(1),
+    [anon_sym_hide] = ACTIONS(1),
+    [anon_sym_ATmixin] = ACTIONS(1),
+    [anon_sym_ATinclude] = ACTIONS(1),
+    [anon_sym_ATfunction] = ACTIONS(1),
+    [anon_sym_ATreturn] = ACTIONS(1),
+    [anon_sym_ATextend] = ACTIONS(1),
+    [anon_sym_BANGoptional] = ACTIONS(1),
+    [anon_sym_ATerror] = ACTIONS(1),
+    [anon_sym_ATwarn] = ACTIONS(1),
+    [anon_sym_ATdebug] = ACTIONS(1),
+    [anon_sym_ATat_DASHroot] = ACTIONS(1),
+    [anon_sym_ATif] = ACTIONS(1),
+    [anon_sym_ATelse] = ACTIONS(1),
+    [anon_sym_if] = ACTIONS(1),
+    [anon_sym_ATeach] = ACTIONS(1),
+    [anon_sym_in] = ACTIONS(1),
+    [anon_sym_ATfor] = ACTIONS(1),
+    [anon_sym_through] = ACTIONS(1),
+    [anon_sym_ATwhile] = ACTIONS(1),
+    [anon_sym_POUND_LBRACE] = ACTIONS(1),
+    [anon_sym_PERCENT] = ACTIONS(1),
+    [anon_sym_DASH] = ACTIONS(1),
+    [sym__descendant_operator] = ACTIONS(1),
+    [sym__pseudo_class_selector_colon] = ACTIONS(1),
+    [sym___error_recovery] = ACTIONS(1),
+    [sym__concat] = ACTIONS(1),
+  },
+  [1] = {
+    [sym_stylesheet] = STATE(682),
+    [sym_import_statement] = STATE(90),
+    [sym_media_statement] = STATE(90),
+    [sym_charset_statement] = STATE(90),
+    [sym_namespace_statement] = STATE(90),
+    [sym_keyframes_statement] = STATE(90),
+    [sym_supports_statement] = STATE(90),
+    [sym_at_rule] = STATE(90),
+    [sym_rule_set] = STATE(90),
+    [sym_selectors] = STATE(667),
+    [sym__selector] = STATE(481),
+    [sym_universal_selector] = STATE(481),
+    [sym_class_selector] = STATE(481),
+    [sym_pseudo_class_selector] = STATE(481),
+    [sym_pseudo_element_selector] = STATE(481),
+    [sym_id_selector] = STATE(481),
+    [sym_attribute_selector] = STATE(481),
+    [sym_child_selector] = STATE(481),
+    [sym_descendant_selector] = STATE(481),
+    [sym_sibling_selector] = STATE(481),
+    [sym_adjacent_sibling_selector] = STATE(481),
+    [sym_declaration] = STATE(90),
+    [sym_use_statement] = STATE(90),
+    [sym_forward_statement] = STATE(90),
+    [sym_mixin_statement] = STATE(90),
+    [sym_include_statement] = STATE(90),
+    [sym_function_statement] = STATE(90),
+    [sym_return_statement] = STATE(90),
+    [sym_extend_statement] = STATE(90),
+    [sym_error_statement] = STATE(90),
+    [sym_warn_statement] = STATE(90),
+    [sym_debug_statement] = STATE(90),
+    [sym_at_root_statement] = STATE(90),
+    [sym_if_statement] = STATE(90),
+    [sym_each_statement] = STATE(90),
+    [sym_for_statement] = STATE(90),
+    [sym_while_statement] = STATE(90),
+    [sym_interpolation] = STATE(225),
+    [sym_placeholder] = STATE(481),
+    [sym__concatenated_identifier] = STATE(511),
+    [aux_sym_stylesheet_repeat1] = STATE(2),
+    [ts_builtin_sym_end] = ACTIONS(5),
+    [anon_sym_ATimport] = ACTIONS(7),
+    [anon_sym_ATmedia] = ACTIONS(9),
+    [anon_sym_ATcharset] = ACTIONS(11),
+    [anon_sym_ATnamespace] = ACTIONS(13),
+    [anon_sym_ATkeyframes] = ACTIONS(15),
+    [aux_sym_keyframes_statement_token1] = ACTIONS(15),
+    [anon_sym_ATsupports] = ACTIONS(17),
+    [sym_nesting_selector] = ACTIONS(19),
+    [anon_sym_STAR] = ACTIONS(21),
+    [anon_sym_DOT] = ACTIONS(23),
+    [anon_sym_COLON] = ACTIONS(25),
+    [anon_sym_COLON_COLON] = ACTIONS(27),
+    [anon_sym_POUND] = ACTIONS(29),
+    [anon_sym_LBRACK] = ACTIONS(31),
+    [sym_string_value] = ACTIONS(33),
+    [sym_identifier] = ACTIONS(35),
+    [sym_at_keyword] = ACTIONS(37),
+    [sym_comment] = ACTIONS(3),
+    [sym_grit_metavariable] = ACTIONS(33),
+    [anon_sym_ATuse] = ACTIONS(39),
+    [anon_sym_ATforward] = ACTIONS(41),
+    [anon_sym_ATmixin] = ACTIONS(43),
+    [anon_sym_ATinclude] = ACTIONS(45),
+    [anon_sym_ATfunction] = ACTIONS(47),
+    [anon_sym_ATreturn] = ACTIONS(49),
+    [anon_sym_ATextend] = ACTIONS(51),
+    [anon_sym_ATerror] = ACTIONS(53),
+    [anon_sym_ATwarn] = ACTIONS(55),
+    [anon_sym_ATdebug] = ACTIONS(57),
+    [anon_sym_ATat_DASHroot] = ACTIONS(59),
+    [anon_sym_ATif] = ACTIONS(61),
+    [anon_sym_ATeach] = ACTIONS(63),
+    [anon_sym_ATfor] = ACTIONS(65),
+    [anon_sym_ATwhile] = ACTIONS(67),
+    [anon_sym_POUND_LBRACE] = ACTIONS(69),
+    [anon_sym_PERCENT] = ACTIONS(71),
+    [sym_variable] = ACTIONS(73),
+  },
+  [2] = {
+    [sym_import_statement] = STATE(90),
+    [sym_media_statement] = STATE(90),
+    [sym_charset_statement] = STATE(90),
+    [sym_namespace_statement] = STATE(90),
+    [sym_keyframes_statement] = STATE(90),
+    [sym_supports_statement] = STATE(90),
+    [sym_at_rule] = STATE(90),
+    [sym_rule_set] = STATE(90),
+    [sym_selectors] = STATE(667),
+    [sym__selector] = STATE(481),
+    [sym_universal_selector] = STATE(481),
+    [sym_class_selector] = STATE(481),
+    [sym_pseudo_class_selector] = STATE(481),
+    [sym_pseudo_element_selector] = STATE(481),
+    [sym_id_selector] = STATE(481),
+    [sym_attribute_selector] = STATE(481),
+    [sym_child_selector] = STATE(481),
+    [sym_descendant_selector] = STATE(481),
+    [sym_sibling_selector] = STATE(481),
+    [sym_adjacent_sibling_selector] = STATE(481),
+    [sym_declaration] = STATE(90),
+    [sym_use_statement] = STATE(90),
+    [sym_forward_statement] = STATE(90),
+    [sym_mixin_statement] = STATE(90),
+    [sym_include_statement] = STATE(90),
+    [sym_function_statement] = STATE(90),
+    [sym_return_statement] = STATE(90),
+    [sym_extend_statement] = STATE(90),
+    [sym_error_statement] = STATE(90),
+    [sym_warn_statement] = STATE(90),
+    [sym_debug_statement] = STATE(90),
+    [sym_at_root_statement] = STATE(90),
+    [sym_if_statement] = STATE(90),
+    [sym_each_statement] = STATE(90),
+    [sym_for_statement] = STATE(90),
+    [sym_while_statement] = STATE(90),
+    [sym_interpolation] = STATE(225),
+    [sym_placeholder] = STATE(481),
+    [sym__concatenated_identifier] = STATE(511),
+    [aux_sym_stylesheet_repeat1] = STATE(3),
+    [ts_builtin_sym_end] = ACTIONS(75),
+    [anon_sym_ATimport] = ACTIONS(7),
+    [anon_sym_ATmedia] = ACTIONS(9),
+    [anon_sym_ATcharset] = ACTIONS(11),
+    [anon_sym_ATnamespace] = ACTIONS(13),
+    [anon_sym_ATkeyframes] = ACTIONS(15),
+    [aux_sym_keyframes_statement_token1] = ACTIONS(15),
+    [anon_sym_ATsupports] = ACTIONS(17),
+    [sym_nesting_selector] = ACTIONS(19),
+    [anon_sym_STAR] = ACTIONS(21),
+    [anon_sym_DOT] = ACTIONS(23),
+    [anon_sym_COLON] = ACTIONS(25),
+    [anon_sym_COLON_COLON] = ACTIONS(27),
+    [anon_sym_POUND] = ACTIONS(29),
+    [anon_sym_LBRACK] = ACTIONS(31),
+    [sym_string_value] = ACTIONS(33),
+    [sym_identifier] = ACTIONS(35),
+    [sym_at_keyword] = ACTIONS(37),
+    [sym_comment] = ACTIONS(3),
+    [sym_grit_metavariable] = ACTIONS(33),
+    [anon_sym_ATuse] = ACTIONS(39),
+    [anon_sym_ATforward] = ACTIONS(41),
+    [anon_sym_ATmixin] = ACTIONS(43),
+    [anon_sym_ATinclude] = ACTIONS(45),
+    [anon_sym_ATfunction] = ACTIONS(47),
+    [anon_sym_ATreturn] = ACTIONS(49),
+    [anon_sym_ATextend] = ACTIONS(51),
+    [anon_sym_ATerror] = ACTIONS(53),
+    [anon_sym_ATwarn] = ACTIONS(55),
+    [anon_sym_ATdebug] = ACTIONS(57),
+    [anon_sym_ATat_DASHroot] = ACTIONS(59),
+    [anon_sym_ATif] = ACTIONS(61),
+    [anon_sym_ATeach] = ACTIONS(63),
+    [anon_sym_ATfor] = ACTIONS(65),
+    [anon_sym_ATwhile] = ACTIONS(67),
+    [anon_sym_POUND_LBRACE] = ACTIONS(69),
+    [anon_sym_PERCENT] = ACTIONS(71),
+    [sym_variable] = ACTIONS(73),
+  },
+  [3] = {
+    [sym_import_statement] = STATE(90),
+    [sym_media_statement] = STATE(90),
+    [sym_charset_statement] = STATE(90),
+    [sym_namespace_statement] = STATE(90),
+    [sym_keyframes_statement] = STATE(90),
+    [sym_supports_statement] = STATE(90),
+    [sym_at_rule] = STATE(90),
+    [sym_rule_set] = STATE(90),
+    [sym_selectors] = STATE(667),
+    [sym__selector] = STATE(481),
+    [sym_universal_selector] = STATE(481),
+    [sym_class_selector] = STATE(481),
+    [sym_pseudo_class_selector] = STATE(481),
+    [sym_pseudo_element_selector] = STATE(481),
+    [sym_id_selector] = STATE(481),
+    [sym_attribute_selector] = STATE(481),
+    [sym_child_selector] = STATE(481),
+    [sym_descendant_selector] = STATE(481),
+    [sym_sibling_selector] = STATE(481),
+    [sym_adjacent_sibling_selector] = STATE(481),
+    [sym_declaration] = STATE(90),
+    [sym_use_statement] = STATE(90),
+    [sym_forward_statement] = STATE(90),
+    [sym_mixin_statement] = STATE(90),
+    [sym_include_statement] = STATE(90),
+    [sym_function_statement] = STATE(90),
+    [sym_return_statement] = STATE(90),
+    [sym_extend_statement] = STATE(90),
+    [sym_error_statement] = STATE(90),
+    [sym_warn_statement] = STATE(90),
+    [sym_debug_statement] = STATE(90),
+    [sym_at_root_statement] = STATE(90),
+    [sym_if_statement] = STATE(90),
+    [sym_each_statement] = STATE(90),
+    [sym_for_statement] = STATE(90),
+    [sym_while_statement] = STATE(90),
+    [sym_interpolation] = STATE(225),
+    [sym_placeholder] = STATE(481),
+    [sym__concatenated_identifier] = STATE(511),
+    [aux_sym_stylesheet_repeat1] = STATE(3),
+    [ts_builtin_sym_end] = ACTIONS(77),
+    [anon_sym_ATimport] = ACTIONS(79),
+    [anon_sym_ATmedia] = ACTIONS(82),
+    [anon_sym_ATcharset] = ACTIONS(85),
+    [anon_sym_ATnamespace] = ACTIONS(88),
+    [anon_sym_ATkeyframes] = ACTIONS(91),
+    [aux_sym_keyframes_statement_token1] = ACTIONS(91),
+    [anon_sym_ATsupports] = ACTIONS(94),
+    [sym_nesting_selector] = ACTIONS(97),
+    [anon_sym_STAR] = ACTIONS(100),
+    [anon_sym_DOT] = ACTIONS(103),
+    [anon_sym_COLON] = ACTIONS(106),
+    [anon_sym_COLON_COLON] = ACTIONS(109),
+    [anon_sym_POUND] = ACTIONS(112),
+    [anon_sym_LBRACK] = ACTIONS(115),
+    [sym_string_value] = ACTIONS(118),
+    [sym_identifier] = ACTIONS(121),
+    [sym_at_keyword] = ACTIONS(124),
+    [sym_comment] = ACTIONS(3),
+    [sym_grit_metavariable] = ACTIONS(118),
+    [anon_sym_ATuse] = ACTIONS(127),
+    [anon_sym_ATforward] = ACTIONS(130),
+    [anon_sym_ATmixin] = ACTIONS(133),
+    [anon_sym_ATinclude] = ACTIONS(136),
+    [anon_sym_ATfunction] = ACTIONS(139),
+    [anon_sym_ATreturn] = ACTIONS(142),
+    [anon_sym_ATextend] = ACTIONS(145),
+    [anon_sym_ATerror] = ACTIONS(148),
+    [anon_sym_ATwarn] = ACTIONS(151),
+    [anon_sym_ATdebug] = ACTIONS(154),
+    [anon_sym_ATat_DASHroot] = ACTIONS(157),
+    [anon_sym_ATif] = ACTIONS(160),
+    [anon_sym_ATeach] = ACTIONS(163),
+    [anon_sym_ATfor] = ACTIONS(166),
+    [anon_sym_ATwhile] = ACTIONS(169),
+    [anon_sym_POUND_LBRACE] = ACTIONS(172),
+    [anon_sym_PERCENT] = ACTIONS(175),
+    [sym_variable] = ACTIONS(178),
+  },
+  [4] = {
+    [sym_import_statement] = STATE(5),
+    [sym_media_statement] = STATE(5),
+    [sym_charset_statement] = STATE(5),
+    [sym_namespace_statement] = STATE(5),
+    [sym_keyframes_statement] = STATE(5),
+    [sym_supports_statement] = STATE(5),
+    [sym_at_rule] = STATE(5),
+    [sym_rule_set] = STATE(5),
+    [sym_selectors] = STATE(655),
+    [sym__selector] = STATE(481),
+    [sym_universal_selector] = STATE(481),
+    [sym_class_selector] = STATE(481),
+    [sym_pseudo_class_selector] = STATE(481),
+    [sym_pseudo_element_selector] = STATE(481),
+    [sym_id_selector] = STATE(481),
+    [sym_attribute_selector] = STATE(481),
+    [sym_child_selector] = STATE(481),
+    [sym_descendant_selector] = STATE(481),
+    [sym_sibling_selector] = STATE(481),
+    [sym_adjacent_sibling_selector] = STATE(481),
+    [sym_declaration] = STATE(5),
+    [sym_last_declaration] = STATE(717),
+    [sym_mixin_statement] = STATE(5),
+    [sym_include_statement] = STATE(5),
+    [sym_function_statement] = STATE(5),
+    [sym_return_statement] = STATE(5),
+    [sym_extend_statement] = STATE(5),
+    [sym_error_statement] = STATE(5),
+    [sym_warn_statement] = STATE(5),
+    [sym_debug_statement] = STATE(5),
+    [sym_at_root_statement] = STATE(5),
+    [sym_if_statement] = STATE(5),
+    [sym_each_statement] = STATE(5),
+    [sym_for_statement] = STATE(5),
+    [sym_while_statement] = STATE(5),
+    [sym_interpolation] = STATE(225),
+    [sym_placeholder] = STATE(481),
+    [sym__concatenated_identifier] = STATE(508),
+    [aux_sym_block_repeat1] = STATE(5),
+    [anon_sym_ATimport] = ACTIONS(181),
+    [anon_sym_ATmedia] = ACTIONS(183),
+    [anon_sym_ATcharset] = ACTIONS(185),
+    [anon_sym_ATnamespace] = ACTIONS(187),
+    [anon_sym_ATkeyframes] = ACTIONS(189),
+    [aux_sym_keyframes_statement_token1] = ACTIONS(189),
+    [anon_sym_RBRACE] = ACTIONS(191),
+    [anon_sym_ATsupports] = ACTIONS(193),
+    [sym_nesting_selector] = ACTIONS(19),
+    [anon_sym_STAR] = ACTIONS(21),
+    [anon_sym_DOT] = ACTIONS(23),
+    [anon_sym_COLON] = ACTIONS(25),
+    [anon_sym_COLON_COLON] = ACTIONS(27),
+    [anon_sym_POUND] = ACTIONS(29),
+    [anon_sym_LBRACK] = ACTIONS(31),
+    [sym_string_value] = ACTIONS(33),
+    [sym_identifier] = ACTIONS(195),
+    [sym_at_keyword] = ACTIONS(197),
+    [sym_comment] = ACTIONS(3),
+    [sym_grit_metavariable] = ACTIONS(199),
+    [anon_sym_ATmixin] = ACTIONS(201),
+    [anon_sym_ATinclude] = ACTIONS(203),
+    [anon_sym_ATfunction] = ACTIONS(205),
+    [anon_sym_ATreturn] = ACTIONS(207),
+    [anon_sym_ATextend] = ACTIONS(209),
+    [anon_sym_ATerror] = ACTIONS(211),
+    [anon_sym_ATwarn] = ACTIONS(213),
+    [anon_sym_ATdebug] = ACTIONS(215),
+    [anon_sym_ATat_DASHroot] = ACTIONS(217),
+    [anon_sym_ATif] = ACTIONS(219),
+    [anon_sym_ATeach] = ACTIONS(221),
+    [anon_sym_ATfor] = ACTIONS(223),
+    [anon_sym_ATwhile] = ACTIONS(225),
+    [anon_sym_POUND_LBRACE] = ACTIONS(69),
+    [anon_sym_PERCENT] = ACTIONS(71),
+    [sym_variable] = ACTIONS(227),
+  },
+  [5] = {
+    [sym_import_statement] = STATE(14),
+    [sym_media_statement] = STATE(14),
+    [sym_charset_statement] = STATE(14),
+    [sym_namespace_statement] = STATE(14),
+    [sym_keyframes_statement] = STATE(14),
+    [sym_supports_statement] = STATE(14),
+    [sym_at_rule] = STATE(14),
+    [sym_rule_set] = STATE(14),
+    [sym_selectors] = STATE(655),
+    [sym__selector] = STATE(481),
+    [sym_universal_selector] = STATE(481),
+    [sym_class_selector] = STATE(481),
+    [sym_pseudo_class_selector] = STATE(481),
+    [sym_pseudo_element_selector] = STATE(481),
+    [sym_id_selector] = STATE(481),
+    [sym_attribute_selector] = STATE(481),
+    [sym_child_selector] = STATE(481),
+    [sym_descendant_selector] = STATE(481),
+    [sym_sibling_selector] = STATE(481),
+    [sym_adjacent_sibling_selector] = STATE(481),
+    [sym_declaration] = STATE(14),
+    [sym_last_declaration] = STATE(725),
+    [sym_mixin_statement] = STATE(14),
+    [sym_include_statement] = STATE(14),
+    [sym_function_statement] = STATE(14),
+    [sym_return_statement] = STATE(14),
+    [sym_extend_statement] = STATE(14),
+    [sym_error_statement] = STATE(14),
+    [sym_warn_statement] = STATE(14),
+    [sym_debug_statement] = STATE(14),
+    [sym_at_root_statement] = STATE(14),
+    [sym_if_statement] = STATE(14),
+    [sym_each_statement] = STATE(14),
+    [sym_for_statement] = STATE(14),
+    [sym_while_statement] = STATE(14),
+    [sym_interpolation] = STATE(225),
+    [sym_placeholder] = STATE(481),
+    [sym__concatenated_identifier] = STATE(508),
+    [aux_sym_block_repeat1] = STATE(14),
+    [anon_sym_ATimport] = ACTIONS(181),
+    [anon_sym_ATmedia] = ACTIONS(183),
+    [anon_sym_ATcharset] = ACTIONS(185),
+    [anon_sym_ATnamespace] = ACTIONS(187),
+    [anon_sym_ATkeyframes] = ACTIONS(189),
+    [aux_sym_keyframes_statement_token1] = ACTIONS(189),
+    [anon_sym_RBRACE] = ACTIONS(229),
+    [anon_sym_ATsupports] = ACTIONS(193),
+    [sym_nesting_selector] = ACTIONS(19),
+    [anon_sym_STAR] = ACTIONS(21),
+    [anon_sym_DOT] = ACTIONS(23),
+    [anon_sym_COLON] = ACTIONS(25),
+    [anon_sym_COLON_COLON] = ACTIONS(27),
+    [anon_sym_POUND] = ACTIONS(29),
+    [anon_sym_LBRACK] = ACTIONS(31),
+    [sym_string_value] = ACTIONS(33),
+    [sym_identifier] = ACTIONS(195),
+    [sym_at_keyword] = ACTIONS(197),
+    [sym_comment] = ACTIONS(3),
+    [sym_grit_metavariable] = ACTIONS(199),
+    [anon_sym_ATmixin] = ACTIONS(201),
+    [anon_sym_ATinclude] = ACTIONS(203),
+    [anon_sym_ATfunction] = ACTIONS(205),
+    [anon_sym_ATreturn] = ACTIONS(207),
+    [anon_sym_ATextend] = ACTIONS(209),
+    [anon_sym_ATerror] = ACTIONS(211),
+    [anon_sym_ATwarn] = ACTIONS(213),
+    [anon_sym_ATdebug] = ACTIONS(215),
+    [anon_sym_ATat_DASHroot] = ACTIONS(217),
+    [anon_sym_ATif] = ACTIONS(219),
+    [anon_sym_ATeach] = ACTIONS(221),
+    [anon_sym_ATfor] = ACTIONS(223),
+    [anon_sym_ATwhile] = ACTIONS(225),
+    [anon_sym_POUND_LBRACE] = ACTIONS(69),
+    [anon_sym_PERCENT] = ACTIONS(71),
+    [sym_variable] = ACTIONS(227),
+  },
+  [6] = {
+    [sym_import_statement] = STATE(13),
+    [sym_media_statement] = STATE(13),
+    [sym_charset_statement] = STATE(13),
+    [sym_namespace_statement] = STATE(13),
+    [sym_keyframes_statement] = STATE(13),
+    [sym_supports_statement] = STATE(13),
+    [sym_at_rule] = STATE(13),
+    [sym_rule_set] = STATE(13),
+    [sym_selectors] = STATE(655),
+    [sym__selector] = STATE(481),
+    [sym_universal_selector] = STATE(481),
+    [sym_class_selector] = STATE(481),
+    [sym_pseudo_class_selector] = STATE(481),
+    [sym_pseudo_element_selector] = STATE(481),
+    [sym_id_selector] = STATE(481),
+    [sym_attribute_selector] = STATE(481),
+    [sym_child_selector] = STATE(481),
+    [sym_descendant_selector] = STATE(481),
+    [sym_sibling_selector] = STATE(481),
+    [sym_adjacent_sibling_selector] = STATE(481),
+    [sym_declaration] = STATE(13),
+    [sym_last_declaration] = STATE(700),
+    [sym_mixin_statement] = STATE(13),
+    [sym_include_statement] = STATE(13),
+    [sym_function_statement] = STATE(13),
+    [sym_return_statement] = STATE(13),
+    [sym_extend_statement] = STATE(13),
+    [sym_error_statement] = STATE(13),
+    [sym_warn_statement] = STATE(13),
+    [sym_debug_statement] = STATE(13),
+    [sym_at_root_statement] = STATE(13),
+    [sym_if_statement] = STATE(13),
+    [sym_each_statement] = STATE(13),
+    [sym_for_statement] = STATE(13),
+    [sym_while_statement] = STATE(13),
+    [sym_interpolation] = STATE(225),
+    [sym_placeholder] = STATE(481),
+    [sym__concatenated_identifier] = STATE(508),
+    [aux_sym_block_repeat1] = STATE(13),
+    [anon_sym_ATimport] = ACTIONS(181),
+    [anon_sym_ATmedia] = ACTIONS(183),
+    [anon_sym_ATcharset] = ACTIONS(185),
+    [anon_sym_ATnamespace] = ACTIONS(187),
+    [anon_sym_ATkeyframes] = ACTIONS(189),
+    [aux_sym_keyframes_statement_token1] = ACTIONS(189),
+    [anon_sym_RBRACE] = ACTIONS(231),
+    [anon_sym_ATsupports] = ACTIONS(193),
+    [sym_nesting_selector] = ACTIONS(19),
+    [anon_sym_STAR] = ACTIONS(21),
+    [anon_sym_DOT] = ACTIONS(23),
+    [anon_sym_COLON] = ACTIONS(25),
+    [anon_sym_COLON_COLON] = ACTIONS(27),
+    [anon_sym_POUND] = ACTIONS(29),
+    [anon_sym_LBRACK] = ACTIONS(31),
+    [sym_string_value] = ACTIONS(33),
+    [sym_identifier] = ACTIONS(195),
+    [sym_at_keyword] = ACTIONS(197),
+    [sym_comment] = ACTIONS(3),
+    [sym_grit_metavariable] = ACTIONS(199),
+    [anon_sym_ATmixin] = ACTIONS(201),
+    [anon_sym_ATinclude] = ACTIONS(203),
+    [anon_sym_ATfunction] = ACTIONS(205),
+    [anon_sym_ATreturn] = ACTIONS(207),
+    [anon_sym_ATextend] = ACTIONS(209),
+    [anon_sym_ATerror] = ACTIONS(211),
+    [anon_sym_ATwarn] = ACTIONS(213),
+    [anon_sym_ATdebug] = ACTIONS(215),
+    [anon_sym_ATat_DASHroot] = ACTIONS(217),
+    [anon_sym_ATif] = ACTIONS(219),
+    [anon_sym_ATeach] = ACTIONS(221),
+    [anon_sym_ATfor] = ACTIONS(223),
+    [anon_sym_ATwhile] = ACTIONS(225),
+    [anon_sym_POUND_LBRACE] = ACTIONS(69),
+    [anon_sym_PERCENT] = ACTIONS(71),
+    [sym_variable] = ACTIONS(227),
+  },
+  [7] = {
+    [sym_import_statement] = STATE(14),
+    [sym_media_statement] = STATE(14),
+    [sym_charset_statement] = STATE(14),
+    [sym_namespace_statement] = STATE(14),
+    [sym_keyframes_statement] = STATE(14),
+    [sym_supports_statement] = STATE(14),
+    [sym_at_rule] = STATE(14),
+    [sym_rule_set] = STATE(14),
+    [sym_selectors] = STATE(655),
+    [sym__selector] = STATE(481),
+    [sym_universal_selector] = STATE(481),
+    [sym_class_selector] = STATE(481),
+    [sym_pseudo_class_selector] = STATE(481),
+    [sym_pseudo_element_selector] = STATE(481),
+    [sym_id_selector] = STATE(481),
+    [sym_attribute_selector] = STATE(481),
+    [sym_child_selector] = STATE(481),
+    [sym_descendant_selector] = STATE(481),
+    [sym_sibling_selector] = STATE(481),
+    [sym_adjacent_sibling_selector] = STATE(481),
+    [sym_declaration] = STATE(14),
+    [sym_last_declaration] = STATE(679),
+    [sym_mixin_statement] = STATE(14),
+    [sym_include_statement] = STATE(14),
+    [sym_function_statement] = STATE(14),
+    [sym_return_statement] = STATE(14),
+    [sym_extend_statement] = STATE(14),
+    [sym_error_statement] = STATE(14),
+    [sym_warn_statement] = STATE(14),
+    [sym_debug_statement] = STATE(14),
+    [sym_at_root_statement] = STATE(14),
+    [sym_if_statement] = STATE(14),
+    [sym_each_statement] = STATE(14),
+    [sym_for_statement] = STATE(14),
+    [sym_while_statement] = STATE(14),
+    [sym_interpolation] = STATE(225),
+    [sym_placeholder] = STATE(481),
+    [sym__concatenated_identifier] = STATE(508),
+    [aux_sym_block_repeat1] = STATE(14),
+    [anon_sym_ATimport] = ACTIONS(181),
+    [anon_sym_ATmedia] = ACTIONS(183),
+    [anon_sym_ATcharset] = ACTIONS(185),
+    [anon_sym_ATnamespace] = ACTIONS(187),
+    [anon_sym_ATkeyframes] = ACTIONS(189),
+    [aux_sym_keyframes_statement_token1] = ACTIONS(189),
+    [anon_sym_RBRACE] = ACTIONS(233),
+    [anon_sym_ATsupports] = ACTIONS(193),
+    [sym_nesting_selector] = ACTIONS(19),
+    [anon_sym_STAR] = ACTIONS(21),
+    [anon_sym_DOT] = ACTIONS(23),
+    [anon_sym_COLON] = ACTIONS(25),
+    [anon_sym_COLON_COLON] = ACTIONS(27),
+    [anon_sym_POUND] = ACTIONS(29),
+    [anon_sym_LBRACK] = ACTIONS(31),
+    [sym_string_value] = ACTIONS(33),
+    [sym_identifier] = ACTIONS(195),
+    [sym_at_keyword] = ACTIONS(197),
+    [sym_comment] = ACTIONS(3),
+    [sym_grit_metavariable] = ACTIONS(199),
+    [anon_sym_ATmixin] = ACTIONS(201),
+    [anon_sym_ATinclude] = ACTIONS(203),
+    [anon_sym_ATfunction] = ACTIONS(205),
+    [anon_sym_ATreturn] = ACTIONS(207),
+    [anon_sym_ATextend] = ACTIONS(209),
+    [anon_sym_ATerror] = ACTIONS(211),
+    [anon_sym_ATwarn] = ACTIONS(213),
+    [anon_sym_ATdebug] = ACTIONS(215),
+    [anon_sym_ATat_DASHroot] = ACTIONS(217),
+    [anon_sym_ATif] = ACTIONS(219),
+    [anon_sym_ATeach] = ACTIONS(221),
+    [anon_sym_ATfor] = ACTIONS(223),
+    [anon_sym_ATwhile] = ACTIONS(225),
+    [anon_sym_POUND_LBRACE] = ACTIONS(69),
+    [anon_sym_PERCENT] = ACTIONS(71),
+    [sym_variable] = ACTIONS(227),
+  },
+  [8] = {
+    [sym_import_statement] = STATE(14),
+    [sym_media_statement] = STATE(14),
+    [sym_charset_statement] = STATE(14),
+    [sym_namespace_statement] = STATE(14),
+    [sym_keyframes_statement] = STATE(14),
+    [sym_supports_statement] = STATE(14),
+    [sym_at_rule] = STATE(14),
+    [sym_rule_set] = STATE(14),
+    [sym_selectors] = STATE(655),
+    [sym__selector] = STATE(481),
+    [sym_universal_selector] = STATE(481),
+    [sym_class_selector] = STATE(481),
+    [sym_pseudo_class_selector] = STATE(481),
+    [sym_pseudo_element_selector] = STATE(481),
+    [sym_id_selector] = STATE(481),
+    [sym_attribute_selector] = STATE(481),
+    [sym_child_selector] = STATE(481),
+    [sym_descendant_selector] = STATE(481),
+    [sym_sibling_selector] = STATE(481),
+    [sym_adjacent_sibling_selector] = STATE(481),
+    [sym_declaration] = STATE(14),
+    [sym_last_declaration] = STATE(695),
+    [sym_mixin_statement] = STATE(14),
+    [sym_include_statement] = STATE(14),
+    [sym_function_statement] = STATE(14),
+    [sym_return_statement] = STATE(14),
+    [sym_extend_statement] = STATE(14),
+    [sym_error_statement] = STATE(14),
+    [sym_warn_statement] = STATE(14),
+    [sym_debug_statement] = STATE(14),
+    [sym_at_root_statement] = STATE(14),
+    [sym_if_statement] = STATE(14),
+    [sym_each_statement] = STATE(14),
+    [sym_for_statement] = STATE(14),
+    [sym_while_statement] = STATE(14),
+    [sym_interpolation] = STATE(225),
+    [sym_placeholder] = STATE(481),
+    [sym__concatenated_identifier] = STATE(508),
+    [aux_sym_block_repeat1] = STATE(14),
+    [anon_sym_ATimport] = ACTIONS(181),
+    [anon_sym_ATmedia] = ACTIONS(183),
+    [anon_sym_ATcharset] = ACTIONS(185),
+    [anon_sym_ATnamespace] = ACTIONS(187),
+    [anon_sym_ATkeyframes] = ACTIONS(189),
+    [aux_sym_keyframes_statement_token1] = ACTIONS(189),
+    [anon_sym_RBRACE] = ACTIONS(235),
+    [anon_sym_ATsupports] = ACTIONS(193),
+    [sym_nesting_selector] = ACTIONS(19),
+    [anon_sym_STAR] = ACTIONS(21),
+    [anon_sym_DOT] = ACTIONS(23),
+    [anon_sym_COLON] = ACTIONS(25),
+    [anon_sym_COLON_COLON] = ACTIONS(27),
+    [anon_sym_POUND] = ACTIONS(29),
+    [anon_sym_LBRACK] = ACTIONS(31),
+    [sym_string_value] = ACTIONS(33),
+    [sym_identifier] = ACTIONS(195),
+    [sym_at_keyword] = ACTIONS(197),
+    [sym_comment] = ACTIONS(3),
+    [sym_grit_metavariable] = ACTIONS(199),
+    [anon_sym_ATmixin] = ACTIONS(201),
+    [anon_sym_ATinclude] = ACTIONS(203),
+    [anon_sym_ATfunction] = ACTIONS(205),
+    [anon_sym_ATreturn] = ACTIONS(207),
+    [anon_sym_ATextend] = ACTIONS(209),
+    [anon_sym_ATerror] = ACTIONS(211),
+    [anon_sym_ATwarn] = ACTIONS(213),
+    [anon_sym_ATdebug] = ACTIONS(215),
+    [anon_sym_ATat_DASHroot] = ACTIONS(217),
+    [anon_sym_ATif] = ACTIONS(219),
+    [anon_sym_ATeach] = ACTIONS(221),
+    [anon_sym_ATfor] = ACTIONS(223),
+    [anon_sym_ATwhile] = ACTIONS(225),
+    [anon_sym_POUND_LBRACE] = ACTIONS(69),
+    [anon_sym_PERCENT] = ACTIONS(71),
+    [sym_variable] = ACTIONS(227),
+  },
+  [9] = {
+    [sym_import_statement] = STATE(14),
+    [sym_media_statement] = STATE(14),
+    [sym_charset_statement] = STATE(14),
+    [sym_namespace_statement] = STATE(14),
+    [sym_keyframes_statement] = STATE(14),
+    [sym_supports_statement] = STATE(14),
+    [sym_at_rule] = STATE(14),
+    [sym_rule_set] = STATE(14),
+    [sym_selectors] = STATE(655),
+    [sym__selector] = STATE(481),
+    [sym_universal_selector] = STATE(481),
+    [sym_class_selector] = STATE(481),
+    [sym_pseudo_class_selector] = STATE(481),
+    [sym_pseudo_element_selector] = STATE(481),
+    [sym_id_selector] = STATE(481),
+    [sym_attribute_selector] = STATE(481),
+    [sym_child_selector] = STATE(481),
+    [sym_descendant_selector] = STATE(481),
+    [sym_sibling_selector] = STATE(481),
+    [sym_adjacent_sibling_selector] = STATE(481),
+    [sym_declaration] = STATE(14),
+    [sym_last_declaration] = STATE(692),
+    [sym_mixin_statement] = STATE(14),
+    [sym_include_statement] = STATE(14),
+    [sym_function_statement] = STATE(14),
+    [sym_return_statement] = STATE(14),
+    [sym_extend_statement] = STATE(14),
+    [sym_error_statement] = STATE(14),
+    [sym_warn_statement] = STATE(14),
+    [sym_debug_statement] = STATE(14),
+    [sym_at_root_statement] = STATE(14),
+    [sym_if_statement] = STATE(14),
+    [sym_each_statement] = STATE(14),
+    [sym_for_statement] = STATE(14),
+    [sym_while_statement] = STATE(14),
+    [sym_interpolation] = STATE(225),
+    [sym_placeholder] = STATE(481),
+    [sym__concatenated_identifier] = STATE(508),
+    [aux_sym_block_repeat1] = STATE(14),
+    [anon_sym_ATimport] = ACTIONS(181),
+    [anon_sym_ATmedia] = ACTIONS(183),
+    [anon_sym_ATcharset] = ACTIONS(185),
+    [anon_sym_ATnamespace] = ACTIONS(187),
+    [anon_sym_ATkeyframes] = ACTIONS(189),
+    [aux_sym_keyframes_statement_token1] = ACTIONS(189),
+    [anon_sym_RBRACE] = ACTIONS(237),
+    [anon_sym_ATsupports] = ACTIONS(193),
+    [sym_nesting_selector] = ACTIONS(19),
+    [anon_sym_STAR] = ACTIONS(21),
+    [anon_sym_DOT] = ACTIONS(23),
+    [anon_sym_COLON] = ACTIONS(25),
+    [anon_sym_COLON_COLON] = ACTIONS(27),
+    [anon_sym_POUND] = ACTIONS(29),
+    [anon_sym_LBRACK] = ACTIONS(31),
+    [sym_string_value] = ACTIONS(33),
+    [sym_identifier] = ACTIONS(195),
+    [sym_at_keyword] = ACTIONS(197),
+    [sym_comment] = ACTIONS(3),
+    [sym_grit_metavariable] = ACTIONS(199),
+    [anon_sym_ATmixin] = ACTIONS(201),
+    [anon_sym_ATinclude] = ACTIONS(203),
+    [anon_sym_ATfunction] = ACTIONS(205),
+    [anon_sym_ATreturn] = ACTIONS(207),
+    [anon_sym_ATextend] = ACTIONS(209),
+    [anon_sym_ATerror] = ACTIONS(211),
+    [anon_sym_ATwarn] = ACTIONS(213),
+    [anon_sym_ATdebug] = ACTIONS(215),
+    [anon_sym_ATat_DASHroot] = ACTIONS(217),
+    [anon_sym_ATif] = ACTIONS(219),
+    [anon_sym_ATeach] = ACTIONS(221),
+    [anon_sym_ATfor] = ACTIONS(223),
+    [anon_sym_ATwhile] = ACTIONS(225),
+    [anon_sym_POUND_LBRACE] = ACTIONS(69),
+    [anon_sym_PERCENT] = ACTIONS(71),
+    [sym_variable] = ACTIONS(227),
+  },
+  [10] = {
+    [sym_import_statement] = STATE(8),
+    [sym_media_statement] = STATE(8),
+    [sym_charset_statement] = STATE(8),
+    [sym_namespace_statement] = STATE(8),
+    [sym_keyframes_statement] = STATE(8),
+    [sym_supports_statement] = STATE(8),
+    [sym_at_rule] = STATE(8),
+    [sym_rule_set] = STATE(8),
+    [sym_selectors] = STATE(655),
+    [sym__selector] = STATE(481),
+    [sym_universal_selector] = STATE(481),
+    [sym_class_selector] = STATE(481),
+    [sym_pseudo_class_selector] = STATE(481),
+    [sym_pseudo_element_selector] = STATE(481),
+    [sym_id_selector] = STATE(481),
+    [sym_attribute_selector] = STATE(481),
+    [sym_child_selector] = STATE(481),
+    [sym_descendant_selector] = STATE(481),
+    [sym_sibling_selector] = STATE(481),
+    [sym_adjacent_sibling_selector] = STATE(481),
+    [sym_declaration] = STATE(8),
+    [sym_last_declaration] = STATE(715),
+    [sym_mixin_statement] = STATE(8),
+    [sym_include_statement] = STATE(8),
+    [sym_function_statement] = STATE(8),
+    [sym_return_statement] = STATE(8),
+    [sym_extend_statement] = STATE(8),
+    [sym_error_statement] = STATE(8),
+    [sym_warn_statement] = STATE(8),
+    [sym_debug_statement] = STATE(8),
+    [sym_at_root_statement] = STATE(8),
+    [sym_if_statement] = STATE(8),
+    [sym_each_statement] = STATE(8),
+    [sym_for_statement] = STATE(8),
+    [sym_while_statement] = STATE(8),
+    [sym_interpolation] = STATE(225),
+    [sym_placeholder] = STATE(481),
+    [sym__concatenated_identifier] = STATE(508),
+    [aux_sym_block_repeat1] = STATE(8),
+    [anon_sym_ATimport] = ACTIONS(181),
+    [anon_sym_ATmedia] = ACTIONS(183),
+    [anon_sym_ATcharset] = ACTIONS(185),
+    [anon_sym_ATnamespace] = ACTIONS(187),
+    [anon_sym_ATkeyframes] = ACTIONS(189),
+    [aux_sym_keyframes_statement_token1] = ACTIONS(189),
+    [anon_sym_RBRACE] = ACTIONS(239),
+    [anon_sym_ATsupports] = ACTIONS(193),
+    [sym_nesting_selector] = ACTIONS(19),
+    [anon_sym_STAR] = ACTIONS(21),
+    [anon_sym_DOT] = ACTIONS(23),
+    [anon_sym_COLON] = ACTIONS(25),
+    [anon_sym_COLON_COLON] = ACTIONS(27),
+    [anon_sym_POUND] = ACTIONS(29),
+    [anon_sym_LBRACK] = ACTIONS(31),
+    [sym_string_value] = ACTIONS(33),
+    [sym_identifier] = ACTIONS(195),
+    [sym_at_keyword] = ACTIONS(197),
+    [sym_comment] = ACTIONS(3),
+    [sym_grit_metavariable] = ACTIONS(199),
+    [anon_sym_ATmixin] = ACTIONS(201),
+    [anon_sym_ATinclude] = ACTIONS(203),
+    [anon_sym_ATfunction] = ACTIONS(205),
+    [anon_sym_ATreturn] = ACTIONS(207),
+    [anon_sym_ATextend] = ACTIONS(209),
+    [anon_sym_ATerror] = ACTIONS(211),
+    [anon_sym_ATwarn] = ACTIONS(213),
+    [anon_sym_ATdebug] = ACTIONS(215),
+    [anon_sym_ATat_DASHroot] = ACTIONS(217),
+    [anon_sym_ATif] = ACTIONS(219),
+    [anon_sym_ATeach] = ACTIONS(221),
+    [anon_sym_ATfor] = ACTIONS(223),
+    [anon_sym_ATwhile] = ACTIONS(225),
+    [anon_sym_POUND_LBRACE] = ACTIONS(69),
+    [anon_sym_PERCENT] = ACTIONS(71),
+    [sym_variable] = ACTIONS(227),
+  },
+  [11] = {
+    [sym_import_statement] = STATE(7),
+    [sym_media_statement] = STATE(7),
+    [sym_charset_statement] = STATE(7),
+    [sym_namespace_statement] = STATE(7),
+    [sym_keyframes_statement] = STATE(7),
+    [sym_supports_statement] = STATE(7),
+    [sym_at_rule] = STATE(7),
+    [sym_rule_set] = STATE(7),
+    [sym_selectors] = STATE(655),
+    [sym__selector] = STATE(481),
+    [sym_universal_selector] = STATE(481),
+    [sym_class_selector] = STATE(481),
+    [sym_pseudo_class_selector] = STATE(481),
+    [sym_pseudo_element_selector] = STATE(481),
+    [sym_id_selector] = STATE(481),
+    [sym_attribute_selector] = STATE(481),
+    [sym_child_selector] = STATE(481),
+    [sym_descendant_selector] = STATE(481),
+    [sym_sibling_selector] = STATE(481),
+    [sym_adjacent_sibling_selector] = STATE(481),
+    [sym_declaration] = STATE(7),
+    [sym_last_declaration] = STATE(736),
+    [sym_mixin_statement] = STATE(7),
+    [sym_include_statement] = STATE(7),
+    [sym_function_statement] = STATE(7),
+    [sym_return_statement] = STATE(7),
+    [sym_extend_statement] = STATE(7),
+    [sym_error_statement] = STATE(7),
+    [sym_warn_statement] = STATE(7),
+    [sym_debug_statement] = STATE(7),
+    [sym_at_root_statement] = STATE(7),
+    [sym_if_statement] = STATE(7),
+    [sym_each_statement] = STATE(7),
+    [sym_for_statement] = STATE(7),
+    [sym_while_statement] = STATE(7),
+    [sym_interpolation] = STATE(225),
+    [sym_placeholder] = STATE(481),
+    [sym__concatenated_identifier] = STATE(508),
+    [aux_sym_block_repeat1] = STATE(7),
+    [anon_sym_ATimport] = ACTIONS(181),
+    [anon_sym_ATmedia] = ACTIONS(183),
+    [anon_sym_ATcharset] = ACTIONS(185),
+    [anon_sym_ATnamespace] = ACTIONS(187),
+    [anon_sym_ATkeyframes] = ACTIONS(189),
+    [aux_sym_keyframes_statement_token1] = ACTIONS(189),
+    [anon_sym_RBRACE] = ACTIONS(241),
+    [anon_sym_ATsupports] = ACTIONS(193),
+    [sym_nesting_selector] = ACTIONS(19),
+    [anon_sym_STAR] = ACTIONS(21),
+    [anon_sym_DOT] = ACTIONS(23),
+    [anon_sym_COLON] = ACTIONS(25),
+    [anon_sym_COLON_COLON] = ACTIONS(27),
+    [anon_sym_POUND] = ACTIONS(29),
+    [anon_sym_LBRACK] = ACTIONS(31),
+    [sym_string_value] = ACTIONS(33),
+    [sym_identifier] = ACTIONS(195),
+    [sym_at_keyword] = ACTIONS(197),
+    [sym_comment] = ACTIONS(3),
+    [sym_grit_metavariable] = ACTIONS(199),
+    [anon_sym_ATmixin] = ACTIONS(201),
+    [anon_sym_ATinclude] = ACTIONS(203),
+    [anon_sym_ATfunction] = ACTIONS(205),
+    [anon_sym_ATreturn] = ACTIONS(207),
+    [anon_sym_ATextend] = ACTIONS(209),
+    [anon_sym_ATerror] = ACTIONS(211),
+    [anon_sym_ATwarn] = ACTIONS(213),
+    [anon_sym_ATdebug] = ACTIONS(215),
+    [anon_sym_ATat_DASHroot] = ACTIONS(217),
+    [anon_sym_ATif] = ACTIONS(219),
+    [anon_sym_ATeach] = ACTIONS(221),
+    [anon_sym_ATfor] = ACTIONS(223),
+    [anon_sym_ATwhile] = ACTIONS(225),
+    [anon_sym_POUND_LBRACE] = ACTIONS(69),
+    [anon_sym_PERCENT] = ACTIONS(71),
+    [sym_variable] = ACTIONS(227),
+  },
+  [12] = {
+    [sym_import_statement] = STATE(9),
+    [sym_media_statement] = STATE(9),
+    [sym_charset_statement] = STATE(9),
+    [sym_namespace_statement] = STATE(9),
+    [sym_keyframes_statement] = STATE(9),
+    [sym_supports_statement] = STATE(9),
+    [sym_at_rule] = STATE(9),
+    [sym_rule_set] = STATE(9),
+    [sym_selectors] = STATE(655),
+    [sym__selector] = STATE(481),
+    [sym_universal_selector] = STATE(481),
+    [sym_class_selector] = STATE(481),
+    [sym_pseudo_class_selector] = STATE(481),
+    [sym_pseudo_element_selector] = STATE(481),
+    [sym_id_selector] = STATE(481),
+    [sym_attribute_selector] = STATE(481),
+    [sym_child_selector] = STATE(481),
+    [sym_descendant_selector] = STATE(481),
+    [sym_sibling_selector] = STATE(481),
+    [sym_adjacent_sibling_selector] = STATE(481),
+    [sym_declaration] = STATE(9),
+    [sym_last_declaration] = STATE(690),
+    [sym_mixin_statement] = STATE(9),
+    [sym_include_statement] = STATE(9),
+    [sym_function_statement] = STATE(9),
+    [sym_return_statement] = STATE(9),
+    [sym_extend_statement] = STATE(9),
+    [sym_error_statement] = STATE(9),
+    [sym_warn_statement] = STATE(9),
+    [sym_debug_statement] = STATE(9),
+    [sym_at_root_statement] = STATE(9),
+    [sym_if_statement] = STATE(9),
+    [sym_each_statement] = STATE(9),
+    [sym_for_statement] = STATE(9),
+    [sym_while_statement] = STATE(9),
+    [sym_interpolation] = STATE(225),
+    [sym_placeholder] = STATE(481),
+    [sym__concatenated_identifier] = STATE(508),
+    [aux_sym_block_repeat1] = STATE(9),
+    [anon_sym_ATimport] = ACTIONS(181),
+    [anon_sym_ATmedia] = ACTIONS(183),
+    [anon_sym_ATcharset] = ACTIONS(185),
+    [anon_sym_ATnamespace] = ACTIONS(187),
+    [anon_sym_ATkeyframes] = ACTIONS(189),
+    [aux_sym_keyframes_statement_token1] = ACTIONS(189),
+    [anon_sym_RBRACE] = ACTIONS(243),
+    [anon_sym_ATsupports] = ACTIONS(193),
+    [sym_nesting_selector] = ACTIONS(19),
+    [anon_sym_STAR] = ACTIONS(21),
+    [anon_sym_DOT] = ACTIONS(23),
+    [anon_sym_COLON] = ACTIONS(25),
+    [anon_sym_COLON_COLON] = ACTIONS(27),
+    [anon_sym_POUND] = ACTIONS(29),
+    [anon_sym_LBRACK] = ACTIONS(31),
+    [sym_string_value] = ACTIONS(33),
+    [sym_identifier] = ACTIONS(195),
+    [sym_at_keyword] = ACTIONS(197),
+    [sym_comment] = ACTIONS(3),
+    [sym_grit_metavariable] = ACTIONS(199),
+    [anon_sym_ATmixin] = ACTIONS(201),
+    [anon_sym_ATinclude] = ACTIONS(203),
+    [anon_sym_ATfunction] = ACTIONS(205),
+    [anon_sym_ATreturn] = ACTIONS(207),
+    [anon_sym_ATextend] = ACTIONS(209),
+    [anon_sym_ATerror] = ACTIONS(211),
+    [anon_sym_ATwarn] = ACTIONS(213),
+    [anon_sym_ATdebug] = ACTIONS(215),
+    [anon_sym_ATat_DASHroot] = ACTIONS(217),
+    [anon_sym_ATif] = ACTIONS(219),
+    [anon_sym_ATeach] = ACTIONS(221),
+    [anon_sym_ATfor] = ACTIONS(223),
+    [anon_sym_ATwhile] = ACTIONS(225),
+    [anon_sym_POUND_LBRACE] = ACTIONS(69),
+    [anon_sym_PERCENT] = ACTIONS(71),
+    [sym_variable] = ACTIONS(227),
+  },
+  [13] = {
+    [sym_import_statement] = STATE(14),
+    [sym_media_statement] = STATE(14),
+    [sym_charset_statement] = STATE(14),
+    [sym_namespace_statement] = STATE(14),
+    [sym_keyframes_statement] = STATE(14),
+    [sym_supports_statement] = STATE(14),
+    [sym_at_rule] = STATE(14),
+    [sym_rule_set] = STATE(14),
+    [sym_selectors] = STATE(655),
+    [sym__selector] = STATE(481),
+    [sym_universal_selector] = STATE(481),
+    [sym_class_selector] = STATE(481),
+    [sym_pseudo_class_selector] = STATE(481),
+    [sym_pseudo_element_selector] = STATE(481),
+    [sym_id_selector] = STATE(481),
+    [sym_attribute_selector] = STATE(481),
+    [sym_child_selector] = STATE(481),
+    [sym_descendant_selector] = STATE(481),
+    [sym_sibling_selector] = STATE(481),
+    [sym_adjacent_sibling_selector] = STATE(481),
+    [sym_declaration] = STATE(14),
+    [sym_last_declaration] = STATE(702),
+    [sym_mixin_statement] = STATE(14),
+    [sym_include_statement] = STATE(14),
+    [sym_function_statement] = STATE(14),
+    [sym_return_statement] = STATE(14),
+    [sym_extend_statement] = STATE(14),
+    [sym_error_statement] = STATE(14),
+    [sym_warn_statement] = STATE(14),
+    [sym_debug_statement] = STATE(14),
+    [sym_at_root_statement] = STATE(14),
+    [sym_if_statement] = STATE(14),
+    [sym_each_statement] = STATE(14),
+    [sym_for_statement] = STATE(14),
+    [sym_while_statement] = STATE(14),
+    [sym_interpolation] = STATE(225),
+    [sym_placeholder] = STATE(481),
+    [sym__concatenated_identifier] = STATE(508),
+    [aux_sym_block_repeat1] = STATE(14),
+    [anon_sym_ATimport] = ACTIONS(181),
+    [anon_sym_ATmedia] = ACTIONS(183),
+    [anon_sym_ATcharset] = ACTIONS(185),
+    [anon_sym_ATnamespace] = ACTIONS(187),
+    [anon_sym_ATkeyframes] = ACTIONS(189),
+    [aux_sym_keyframes_statement_token1] = ACTIONS(189),
+    [anon_sym_RBRACE] = ACTIONS(245),
+    [anon_sym_ATsupports] = ACTIONS(193),
+    [sym_nesting_selector] = ACTIONS(19),
+    [anon_sym_STAR] = ACTIONS(21),
+    [anon_sym_DOT] = ACTIONS(23),
+    [anon_sym_COLON] = ACTIONS(25),
+    [anon_sym_COLON_COLON] = ACTIONS(27),
+    [anon_sym_POUND] = ACTIONS(29),
+    [anon_sym_LBRACK] = ACTIONS(31),
+    [sym_string_value] = ACTIONS(33),
+    [sym_identifier] = ACTIONS(195),
+    [sym_at_keyword] = ACTIONS(197),
+    [sym_comment] = ACTIONS(3),
+    [sym_grit_metavariable] = ACTIONS(199),
+    [anon_sym_ATmixin] = ACTIONS(201),
+    [anon_sym_ATinclude] = ACTIONS(203),
+    [anon_sym_ATfunction] = ACTIONS(205),
+    [anon_sym_ATreturn] = ACTIONS(207),
+    [anon_sym_ATextend] = ACTIONS(209),
+    [anon_sym_ATerror] = ACTIONS(211),
+    [anon_sym_ATwarn] = ACTIONS(213),
+    [anon_sym_ATdebug] = ACTIONS(215),
+    [anon_sym_ATat_DASHroot] = ACTIONS(217),
+    [anon_sym_ATif] = ACTIONS(219),
+    [anon_sym_ATeach] = ACTIONS(221),
+    [anon_sym_ATfor] = ACTIONS(223),
+    [anon_sym_ATwhile] = ACTIONS(225),
+    [anon_sym_POUND_LBRACE] = ACTIONS(69),
+    [anon_sym_PERCENT] = ACTIONS(71),
+    [sym_variable] = ACTIONS(227),
+  },
+  [14] = {
+    [sym_import_statement] = STATE(14),
+    [sym_media_statement] = STATE(14),
+    [sym_charset_statement] = STATE(14),
+    [sym_namespace_statement] = STATE(14),
+    [sym_keyframes_statement] = STATE(14),
+    [sym_supports_statement] = STATE(14),
+    [sym_at_rule] = STATE(14),
+    [sym_rule_set] = STATE(14),
+    [sym_selectors] = STATE(655),
+    [sym__selector] = STATE(481),
+    [sym_universal_selector] = STATE(481),
+    [sym_class_selector] = STATE(481),
+    [sym_pseudo_class_selector] = STATE(481),
+    [sym_pseudo_element_selector] = STATE(481),
+    [sym_id_selector] = STATE(481),
+    [sym_attribute_selector] = STATE(481),
+    [sym_child_selector] = STATE(481),
+    [sym_descendant_selector] = STATE(481),
+    [sym_sibling_selector] = STATE(481),
+    [sym_adjacent_sibling_selector] = STATE(481),
+    [sym_declaration] = STATE(14),
+    [sym_mixin_statement] = STATE(14),
+    [sym_include_statement] = STATE(14),
+    [sym_function_statement] = STATE(14),
+    [sym_return_statement] = STATE(14),
+    [sym_extend_statement] = STATE(14),
+    [sym_error_statement] = STATE(14),
+    [sym_warn_statement] = STATE(14),
+    [sym_debug_statement] = STATE(14),
+    [sym_at_root_statement] = STATE(14),
+    [sym_if_statement] = STATE(14),
+    [sym_each_statement] = STATE(14),
+    [sym_for_statement] = STATE(14),
+    [sym_while_statement] = STATE(14),
+    [sym_interpolation] = STATE(225),
+    [sym_placeholder] = STATE(481),
+    [sym__concatenated_identifier] = STATE(504),
+    [aux_sym_block_repeat1] = STATE(14),
+    [anon_sym_ATimport] = ACTIONS(247),
+    [anon_sym_ATmedia] = ACTIONS(250),
+    [anon_sym_ATcharset] = ACTIONS(253),
+    [anon_sym_ATnamespace] = ACTIONS(256),
+    [anon_sym_ATkeyframes] = ACTIONS(259),
+    [aux_sym_keyframes_statement_token1] = ACTIONS(259),
+    [anon_sym_RBRACE] = ACTIONS(262),
+    [anon_sym_ATsupports] = ACTIONS(264),
+    [sym_nesting_selector] = ACTIONS(267),
+    [anon_sym_STAR] = ACTIONS(270),
+    [anon_sym_DOT] = ACTIONS(273),
+    [anon_sym_COLON] = ACTIONS(276),
+    [anon_sym_COLON_COLON] = ACTIONS(279),
+    [anon_sym_POUND] = ACTIONS(282),
+    [anon_sym_LBRACK] = ACTIONS(285),
+    [sym_string_value] = ACTIONS(288),
+    [sym_identifier] = ACTIONS(291),
+    [sym_at_keyword] = ACTIONS(294),
+    [sym_comment] = ACTIONS(3),
+    [sym_grit_metavariable] = ACTIONS(297),
+    [anon_sym_ATmixin] = ACTIONS(300),
+    [anon_sym_ATinclude] = ACTIONS(303),
+    [anon_sym_ATfunction] = ACTIONS(306),
+    [anon_sym_ATreturn] = ACTIONS(309),
+    [anon_sym_ATextend] = ACTIONS(312),
+    [anon_sym_ATerror] = ACTIONS(315),
+    [anon_sym_ATwarn] = ACTIONS(318),
+    [anon_sym_ATdebug] = ACTIONS(321),
+    [anon_sym_ATat_DASHroot] = ACTIONS(324),
+    [anon_sym_ATif] = ACTIONS(327),
+    [anon_sym_ATeach] = ACTIONS(330),
+    [anon_sym_ATfor] = ACTIONS(333),
+    [anon_sym_ATwhile] = ACTIONS(336),
+    [anon_sym_POUND_LBRACE] = ACTIONS(339),
+    [anon_sym_PERCENT] = ACTIONS(342),
+    [sym_variable] = ACTIONS(345),
+  },
+};
+
+static const uint16_t ts_small_parse_table[] = {
+  [0] = 28,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(348), 1,
+      anon_sym_COMMA,
+    ACTIONS(350), 1,
+      anon_sym_SEMI,
+    ACTIONS(352), 1,
+      anon_sym_RBRACE,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(358), 1,
+      anon_sym_POUND,
+    ACTIONS(360), 1,
+      anon_sym_GT,
+    ACTIONS(362), 1,
+      anon_sym_PLUS,
+    ACTIONS(364), 1,
+      sym_important,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(368), 1,
+      anon_sym_and,
+    ACTIONS(370), 1,
+      anon_sym_or,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_identifier,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(390), 1,
+      sym_variable,
+    STATE(181), 1,
+      sym_interpolation,
+    STATE(194), 1,
+      aux_sym_declaration_repeat1,
+    STATE(659), 1,
+      sym_default_flag,
+    ACTIONS(384), 2,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+    STATE(325), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(354), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    ACTIONS(376), 3,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(378), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    STATE(192), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [103] = 27,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(348), 1,
+      anon_sym_COMMA,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(358), 1,
+      anon_sym_POUND,
+    ACTIONS(360), 1,
+      anon_sym_GT,
+    ACTIONS(362), 1,
+      anon_sym_PLUS,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(368), 1,
+      anon_sym_and,
+    ACTIONS(370), 1,
+      anon_sym_or,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_identifier,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(390), 1,
+      sym_variable,
+    ACTIONS(392), 1,
+      anon_sym_SEMI,
+    ACTIONS(394), 1,
+      sym_important,
+    STATE(181), 1,
+      sym_interpolation,
+    STATE(209), 1,
+      aux_sym_declaration_repeat1,
+    STATE(687), 1,
+      sym_default_flag,
+    ACTIONS(384), 2,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+    STATE(325), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(354), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    ACTIONS(376), 3,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(378), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    STATE(192), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [203] = 27,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(348), 1,
+      anon_sym_COMMA,
+    ACTIONS(350), 1,
+      anon_sym_SEMI,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(358), 1,
+      anon_sym_POUND,
+    ACTIONS(360), 1,
+      anon_sym_GT,
+    ACTIONS(362), 1,
+      anon_sym_PLUS,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(368), 1,
+      anon_sym_and,
+    ACTIONS(370), 1,
+      anon_sym_or,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_identifier,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(390), 1,
+      sym_variable,
+    ACTIONS(396), 1,
+      sym_important,
+    STATE(181), 1,
+      sym_interpolation,
+    STATE(211), 1,
+      aux_sym_declaration_repeat1,
+    STATE(686), 1,
+      sym_default_flag,
+    ACTIONS(384), 2,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+    STATE(325), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(354), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    ACTIONS(376), 3,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(378), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    STATE(192), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [303] = 24,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(21), 1,
+      anon_sym_STAR,
+    ACTIONS(25), 1,
+      anon_sym_COLON,
+    ACTIONS(27), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(31), 1,
+      anon_sym_LBRACK,
+    ACTIONS(71), 1,
+      anon_sym_PERCENT,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(398), 1,
+      sym_nesting_selector,
+    ACTIONS(400), 1,
+      anon_sym_DOT,
+    ACTIONS(402), 1,
+      anon_sym_POUND,
+    ACTIONS(404), 1,
+      anon_sym_RPAREN,
+    ACTIONS(408), 1,
+      sym_identifier,
+    ACTIONS(410), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(412), 1,
+      sym_variable,
+    STATE(172), 1,
+      sym_interpolation,
+    STATE(187), 1,
+      sym__concatenated_identifier,
+    STATE(227), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    ACTIONS(406), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(213), 9,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+    STATE(484), 12,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+      sym_placeholder,
+  [396] = 24,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(21), 1,
+      anon_sym_STAR,
+    ACTIONS(25), 1,
+      anon_sym_COLON,
+    ACTIONS(27), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(31), 1,
+      anon_sym_LBRACK,
+    ACTIONS(71), 1,
+      anon_sym_PERCENT,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(398), 1,
+      sym_nesting_selector,
+    ACTIONS(400), 1,
+      anon_sym_DOT,
+    ACTIONS(402), 1,
+      anon_sym_POUND,
+    ACTIONS(408), 1,
+      sym_identifier,
+    ACTIONS(410), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(412), 1,
+      sym_variable,
+    ACTIONS(414), 1,
+      anon_sym_RPAREN,
+    STATE(172), 1,
+      sym_interpolation,
+    STATE(187), 1,
+      sym__concatenated_identifier,
+    STATE(228), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    ACTIONS(406), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(213), 9,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+    STATE(490), 12,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+      sym_placeholder,
+  [489] = 23,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(21), 1,
+      anon_sym_STAR,
+    ACTIONS(25), 1,
+      anon_sym_COLON,
+    ACTIONS(27), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(31), 1,
+      anon_sym_LBRACK,
+    ACTIONS(71), 1,
+      anon_sym_PERCENT,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(398), 1,
+      sym_nesting_selector,
+    ACTIONS(400), 1,
+      anon_sym_DOT,
+    ACTIONS(402), 1,
+      anon_sym_POUND,
+    ACTIONS(408), 1,
+      sym_identifier,
+    ACTIONS(410), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(412), 1,
+      sym_variable,
+    STATE(172), 1,
+      sym_interpolation,
+    STATE(187), 1,
+      sym__concatenated_identifier,
+    STATE(251), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    ACTIONS(406), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(213), 9,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+    STATE(510), 12,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+      sym_placeholder,
+  [579] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(420), 1,
+      anon_sym_ATelse,
+    STATE(23), 1,
+      aux_sym_if_statement_repeat1,
+    STATE(31), 1,
+      sym_else_if_clause,
+    STATE(52), 1,
+      sym_else_clause,
+    ACTIONS(416), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(418), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [636] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(431), 2,
+      anon_sym_COLON,
+      anon_sym_POUND,
+    ACTIONS(428), 4,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+    ACTIONS(424), 6,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+    ACTIONS(426), 7,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(422), 22,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [691] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(420), 1,
+      anon_sym_ATelse,
+    STATE(26), 1,
+      aux_sym_if_statement_repeat1,
+    STATE(31), 1,
+      sym_else_if_clause,
+    STATE(68), 1,
+      sym_else_clause,
+    ACTIONS(434), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(436), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [748] = 23,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(21), 1,
+      anon_sym_STAR,
+    ACTIONS(25), 1,
+      anon_sym_COLON,
+    ACTIONS(27), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(31), 1,
+      anon_sym_LBRACK,
+    ACTIONS(69), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(71), 1,
+      anon_sym_PERCENT,
+    ACTIONS(400), 1,
+      anon_sym_DOT,
+    ACTIONS(438), 1,
+      sym_nesting_selector,
+    ACTIONS(440), 1,
+      anon_sym_POUND,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(450), 1,
+      sym_identifier,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(456), 1,
+      sym_variable,
+    STATE(225), 1,
+      sym_interpolation,
+    STATE(351), 1,
+      sym__concatenated_identifier,
+    ACTIONS(444), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(512), 2,
+      sym_class_selector,
+      sym_placeholder,
+    STATE(419), 9,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+    STATE(514), 10,
+      sym__selector,
+      sym_universal_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+  [837] = 23,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(21), 1,
+      anon_sym_STAR,
+    ACTIONS(25), 1,
+      anon_sym_COLON,
+    ACTIONS(27), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(31), 1,
+      anon_sym_LBRACK,
+    ACTIONS(69), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(71), 1,
+      anon_sym_PERCENT,
+    ACTIONS(400), 1,
+      anon_sym_DOT,
+    ACTIONS(438), 1,
+      sym_nesting_selector,
+    ACTIONS(440), 1,
+      anon_sym_POUND,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(450), 1,
+      sym_identifier,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(458), 1,
+      sym_variable,
+    STATE(225), 1,
+      sym_interpolation,
+    STATE(351), 1,
+      sym__concatenated_identifier,
+    ACTIONS(444), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(509), 2,
+      sym_class_selector,
+      sym_placeholder,
+    STATE(420), 9,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+    STATE(514), 10,
+      sym__selector,
+      sym_universal_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+  [926] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(464), 1,
+      anon_sym_ATelse,
+    STATE(26), 1,
+      aux_sym_if_statement_repeat1,
+    STATE(31), 1,
+      sym_else_if_clause,
+    ACTIONS(460), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(462), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [980] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(467), 1,
+      anon_sym_ATelse,
+    STATE(30), 1,
+      aux_sym_if_statement_repeat1,
+    STATE(112), 1,
+      sym_else_if_clause,
+    STATE(158), 1,
+      sym_else_clause,
+    ACTIONS(434), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(436), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [1035] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(467), 1,
+      anon_sym_ATelse,
+    STATE(27), 1,
+      aux_sym_if_statement_repeat1,
+    STATE(112), 1,
+      sym_else_if_clause,
+    STATE(151), 1,
+      sym_else_clause,
+    ACTIONS(416), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(418), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [1090] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(469), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(471), 27,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATelse,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [1136] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(473), 1,
+      anon_sym_ATelse,
+    STATE(30), 1,
+      aux_sym_if_statement_repeat1,
+    STATE(112), 1,
+      sym_else_if_clause,
+    ACTIONS(460), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(462), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [1188] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(476), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(478), 27,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATelse,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [1234] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(480), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(482), 27,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATelse,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [1280] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(484), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(486), 27,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATelse,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [1326] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(488), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(490), 27,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATelse,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [1372] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(492), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(494), 27,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATelse,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [1418] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(496), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(498), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [1463] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(500), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(502), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [1508] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(504), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(506), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [1553] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(508), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(510), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [1598] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(512), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(514), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [1643] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(488), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(490), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [1688] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(492), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(494), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [1733] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(516), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(518), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [1778] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(520), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(522), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [1823] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(524), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(526), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [1868] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(528), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(530), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [1913] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(532), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(534), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [1958] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(536), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(538), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [2003] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(540), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(542), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [2048] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(544), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(546), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [2093] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(548), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(550), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [2138] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(552), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(554), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [2183] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(556), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(558), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [2228] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(560), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(562), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [2273] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(564), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(566), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [2318] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(568), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(570), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [2363] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(572), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(574), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [2408] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(576), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(578), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [2453] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(580), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(582), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [2498] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(584), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(586), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [2543] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(469), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(471), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [2588] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(588), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(590), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [2633] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(592), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(594), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [2678] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(596), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(598), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [2723] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(600), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(602), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [2768] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(604), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(606), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [2813] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(608), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(610), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [2858] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(612), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(614), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [2903] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(616), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(618), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [2948] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(620), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(622), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [2993] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(624), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(626), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [3038] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(628), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(630), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [3083] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(632), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(634), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [3128] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(636), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(638), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [3173] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(640), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(642), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [3218] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(644), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(646), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [3263] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(648), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(650), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [3308] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(652), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(654), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [3353] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(656), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(658), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [3398] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(660), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(662), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [3443] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(664), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(666), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [3488] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(668), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(670), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [3533] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(672), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(674), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [3578] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(676), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(678), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [3623] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(680), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(682), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [3668] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(684), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(686), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [3713] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(688), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(690), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [3758] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(692), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(694), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [3803] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(696), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(698), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [3848] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(700), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(702), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [3893] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(704), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(706), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [3938] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(708), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(710), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [3983] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(712), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(714), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [4028] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(484), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(486), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [4073] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(716), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(718), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [4118] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(720), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(722), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [4163] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(724), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(726), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [4208] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(728), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(730), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [4253] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(732), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(734), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [4298] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(736), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(738), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [4343] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(740), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(742), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [4388] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(744), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(746), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [4433] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(748), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(750), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [4478] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(752), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(754), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [4523] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(756), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(758), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [4568] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(760), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(762), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [4613] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(764), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(766), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [4658] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(768), 11,
+      ts_builtin_sym_end,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(770), 26,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATuse,
+      anon_sym_ATforward,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [4703] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(488), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(490), 25,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATelse,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [4747] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(492), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(494), 25,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATelse,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [4791] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(469), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(471), 25,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATelse,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [4835] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(476), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(478), 25,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATelse,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [4879] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(778), 1,
+      anon_sym_LPAREN,
+    ACTIONS(782), 1,
+      sym__concat,
+    STATE(173), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    STATE(200), 1,
+      sym_arguments,
+    ACTIONS(776), 5,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+    ACTIONS(772), 7,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      sym_nesting_selector,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+    ACTIONS(774), 8,
+      anon_sym_STAR,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(780), 12,
+      anon_sym_LPAREN2,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+  [4935] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(480), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(482), 25,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATelse,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [4979] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(484), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(486), 25,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATelse,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [5023] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(704), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(706), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [5066] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(716), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(718), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [5109] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(616), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(618), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [5152] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(696), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(698), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [5195] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(724), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(726), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [5238] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(708), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(710), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [5281] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(516), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(518), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [5324] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(556), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(558), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [5367] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(568), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(570), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [5410] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(584), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(586), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [5453] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(728), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(730), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [5496] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(732), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(734), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [5539] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(744), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(746), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [5582] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(748), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(750), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [5625] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(752), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(754), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [5668] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(756), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(758), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [5711] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(760), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(762), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [5754] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(764), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(766), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [5797] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(512), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(514), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [5840] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(548), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(550), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [5883] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(660), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(662), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [5926] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(496), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(498), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [5969] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(712), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(714), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [6012] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(720), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(722), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [6055] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(469), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(471), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [6098] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(504), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(506), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [6141] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(508), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(510), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [6184] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(768), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(770), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [6227] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(520), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(522), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [6270] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(524), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(526), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [6313] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(528), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(530), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [6356] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(532), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(534), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [6399] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(536), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(538), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [6442] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(540), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(542), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [6485] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(544), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(546), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [6528] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(552), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(554), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [6571] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(560), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(562), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [6614] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(564), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(566), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [6657] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(572), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(574), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [6700] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(576), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(578), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [6743] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(580), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(582), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [6786] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(608), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(610), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [6829] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(612), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(614), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [6872] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(620), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(622), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [6915] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(624), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(626), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [6958] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(628), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(630), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [7001] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(632), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(634), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [7044] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(644), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(646), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [7087] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(664), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(666), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [7130] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(668), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(670), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [7173] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(778), 1,
+      anon_sym_LPAREN,
+    ACTIONS(784), 1,
+      sym__concat,
+    STATE(182), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    STATE(200), 1,
+      sym_arguments,
+    ACTIONS(780), 15,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(774), 16,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_RPAREN,
+      sym_important,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [7224] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(484), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(486), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [7267] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(488), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(490), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [7310] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(492), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(494), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [7353] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(500), 11,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+      anon_sym_PERCENT,
+      sym_variable,
+    ACTIONS(502), 24,
+      anon_sym_ATimport,
+      anon_sym_ATmedia,
+      anon_sym_ATcharset,
+      anon_sym_ATnamespace,
+      anon_sym_ATkeyframes,
+      aux_sym_keyframes_statement_token1,
+      anon_sym_ATsupports,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      sym_identifier,
+      sym_at_keyword,
+      anon_sym_ATmixin,
+      anon_sym_ATinclude,
+      anon_sym_ATfunction,
+      anon_sym_ATreturn,
+      anon_sym_ATextend,
+      anon_sym_ATerror,
+      anon_sym_ATwarn,
+      anon_sym_ATdebug,
+      anon_sym_ATat_DASHroot,
+      anon_sym_ATif,
+      anon_sym_ATeach,
+      anon_sym_ATfor,
+      anon_sym_ATwhile,
+  [7396] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(69), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(792), 1,
+      sym_identifier,
+    STATE(225), 1,
+      sym_interpolation,
+    STATE(445), 1,
+      sym__concatenated_identifier,
+    ACTIONS(788), 5,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+    ACTIONS(424), 7,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      sym_nesting_selector,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+    ACTIONS(786), 8,
+      anon_sym_STAR,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+    ACTIONS(790), 10,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+  [7450] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(782), 1,
+      sym__concat,
+    STATE(173), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(794), 16,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(796), 16,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+  [7496] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(782), 1,
+      sym__concat,
+    STATE(174), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(798), 16,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(800), 16,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+  [7542] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(806), 1,
+      sym__concat,
+    STATE(174), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(802), 16,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(804), 16,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+  [7588] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(811), 16,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(809), 17,
+      sym__descendant_operator,
+      sym__concat,
+      anon_sym_COMMA,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+  [7629] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(778), 1,
+      anon_sym_LPAREN,
+    STATE(200), 1,
+      sym_arguments,
+    ACTIONS(790), 15,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(786), 16,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_RPAREN,
+      sym_important,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [7674] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(325), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(815), 14,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(813), 17,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [7717] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(325), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(819), 14,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(817), 17,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [7760] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(325), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(823), 14,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(821), 17,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [7803] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(325), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(827), 14,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(825), 17,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [7846] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(784), 1,
+      sym__concat,
+    STATE(182), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(796), 14,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(794), 17,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [7891] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(784), 1,
+      sym__concat,
+    STATE(184), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(800), 14,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(798), 17,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [7936] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(804), 16,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(802), 17,
+      sym__descendant_operator,
+      sym__concat,
+      anon_sym_COMMA,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+  [7977] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(829), 1,
+      sym__concat,
+    STATE(184), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(804), 14,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(802), 17,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [8022] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(360), 1,
+      anon_sym_GT,
+    ACTIONS(362), 1,
+      anon_sym_PLUS,
+    ACTIONS(368), 1,
+      anon_sym_and,
+    ACTIONS(370), 1,
+      anon_sym_or,
+    STATE(325), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(376), 3,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(378), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(834), 7,
+      anon_sym_POUND,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(832), 11,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [8078] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(788), 5,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+    ACTIONS(424), 7,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      sym_nesting_selector,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+    ACTIONS(786), 9,
+      anon_sym_STAR,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(790), 11,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+  [8122] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(776), 5,
+      anon_sym_DOT,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+    ACTIONS(772), 7,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      sym_nesting_selector,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_RPAREN,
+    ACTIONS(786), 9,
+      anon_sym_STAR,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(790), 11,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+  [8166] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(840), 1,
+      sym_unit,
+    ACTIONS(838), 14,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(836), 17,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [8208] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(804), 14,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(802), 18,
+      sym__concat,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [8248] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(846), 1,
+      sym_unit,
+    ACTIONS(844), 14,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(842), 17,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [8290] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(811), 14,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(809), 18,
+      sym__concat,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [8330] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(360), 1,
+      anon_sym_GT,
+    ACTIONS(362), 1,
+      anon_sym_PLUS,
+    ACTIONS(368), 1,
+      anon_sym_and,
+    ACTIONS(370), 1,
+      anon_sym_or,
+    STATE(325), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(376), 3,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(378), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(850), 7,
+      anon_sym_POUND,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(848), 11,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [8386] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(854), 14,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(852), 17,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [8425] = 20,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(348), 1,
+      anon_sym_COMMA,
+    ACTIONS(358), 1,
+      anon_sym_POUND,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_identifier,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(390), 1,
+      sym_variable,
+    ACTIONS(856), 1,
+      anon_sym_SEMI,
+    ACTIONS(858), 1,
+      anon_sym_RBRACE,
+    ACTIONS(860), 1,
+      sym_important,
+    STATE(181), 1,
+      sym_interpolation,
+    STATE(208), 1,
+      aux_sym_declaration_repeat1,
+    STATE(634), 1,
+      sym_default_flag,
+    ACTIONS(384), 2,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+    ACTIONS(354), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(192), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [8498] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(864), 14,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(862), 17,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [8537] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(868), 14,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(866), 17,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [8576] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(872), 14,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(870), 17,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [8615] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(876), 14,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(874), 17,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [8654] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(880), 14,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(878), 17,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [8693] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(884), 14,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(882), 17,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [8732] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(888), 14,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(886), 17,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [8771] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(892), 14,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(890), 17,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [8810] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(896), 14,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(894), 17,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [8849] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(900), 14,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(898), 17,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [8888] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(904), 14,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(902), 17,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [8927] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(908), 14,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(906), 17,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [8966] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(912), 14,
+      anon_sym_POUND,
+      anon_sym_GT,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(910), 17,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_RPAREN,
+      sym_important,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+      anon_sym_POUND_LBRACE,
+  [9005] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(914), 1,
+      anon_sym_COMMA,
+    ACTIONS(922), 1,
+      anon_sym_POUND,
+    ACTIONS(925), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(928), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(931), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(934), 1,
+      sym_identifier,
+    ACTIONS(937), 1,
+      sym_plain_value,
+    ACTIONS(940), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(943), 1,
+      sym_module_member,
+    ACTIONS(946), 1,
+      sym_variable,
+    STATE(181), 1,
+      sym_interpolation,
+    STATE(208), 1,
+      aux_sym_declaration_repeat1,
+    ACTIONS(919), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    ACTIONS(917), 5,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      sym_important,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+    STATE(192), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [9069] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(348), 1,
+      anon_sym_COMMA,
+    ACTIONS(358), 1,
+      anon_sym_POUND,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_identifier,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(390), 1,
+      sym_variable,
+    ACTIONS(949), 1,
+      anon_sym_SEMI,
+    ACTIONS(951), 1,
+      sym_important,
+    STATE(181), 1,
+      sym_interpolation,
+    STATE(208), 1,
+      aux_sym_declaration_repeat1,
+    STATE(688), 1,
+      sym_default_flag,
+    ACTIONS(384), 2,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+    ACTIONS(354), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(192), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [9139] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(953), 1,
+      anon_sym_LPAREN,
+    ACTIONS(955), 1,
+      sym__concat,
+    STATE(216), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    STATE(232), 1,
+      sym_arguments,
+    ACTIONS(780), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(774), 23,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_to,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+      anon_sym_show,
+      anon_sym_hide,
+      anon_sym_BANGoptional,
+      anon_sym_through,
+  [9185] = 19,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(348), 1,
+      anon_sym_COMMA,
+    ACTIONS(358), 1,
+      anon_sym_POUND,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_identifier,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(390), 1,
+      sym_variable,
+    ACTIONS(856), 1,
+      anon_sym_SEMI,
+    ACTIONS(957), 1,
+      sym_important,
+    STATE(181), 1,
+      sym_interpolation,
+    STATE(208), 1,
+      aux_sym_declaration_repeat1,
+    STATE(709), 1,
+      sym_default_flag,
+    ACTIONS(384), 2,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+    ACTIONS(354), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(192), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [9255] = 17,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(959), 1,
+      anon_sym_SEMI,
+    ACTIONS(961), 1,
+      anon_sym_GT,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(965), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(967), 1,
+      anon_sym_and,
+    ACTIONS(969), 1,
+      anon_sym_or,
+    ACTIONS(973), 1,
+      anon_sym_selector,
+    ACTIONS(979), 1,
+      sym_identifier,
+    ACTIONS(981), 1,
+      sym_grit_metavariable,
+    ACTIONS(983), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(971), 2,
+      sym_not,
+      sym_only,
+    STATE(297), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(975), 3,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(977), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    STATE(533), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [9320] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(360), 1,
+      anon_sym_GT,
+    ACTIONS(362), 1,
+      anon_sym_PLUS,
+    ACTIONS(368), 1,
+      anon_sym_and,
+    ACTIONS(370), 1,
+      anon_sym_or,
+    STATE(325), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(376), 3,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(378), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(987), 7,
+      anon_sym_POUND,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+    ACTIONS(985), 8,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      sym_nesting_selector,
+      anon_sym_RPAREN,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+  [9373] = 17,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(961), 1,
+      anon_sym_GT,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(965), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(967), 1,
+      anon_sym_and,
+    ACTIONS(969), 1,
+      anon_sym_or,
+    ACTIONS(973), 1,
+      anon_sym_selector,
+    ACTIONS(979), 1,
+      sym_identifier,
+    ACTIONS(983), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(989), 1,
+      anon_sym_SEMI,
+    ACTIONS(991), 1,
+      sym_grit_metavariable,
+    ACTIONS(971), 2,
+      sym_not,
+      sym_only,
+    STATE(297), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(975), 3,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(977), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    STATE(527), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [9438] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(993), 1,
+      sym__concat,
+    STATE(215), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(804), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(802), 23,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_to,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+      anon_sym_show,
+      anon_sym_hide,
+      anon_sym_BANGoptional,
+      anon_sym_through,
+  [9478] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(955), 1,
+      sym__concat,
+    STATE(215), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(800), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(798), 23,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_to,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+      anon_sym_show,
+      anon_sym_hide,
+      anon_sym_BANGoptional,
+      anon_sym_through,
+  [9518] = 17,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(358), 1,
+      anon_sym_POUND,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_identifier,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(412), 1,
+      sym_variable,
+    ACTIONS(1000), 1,
+      anon_sym_RPAREN,
+    STATE(181), 1,
+      sym_interpolation,
+    STATE(226), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    STATE(572), 1,
+      aux_sym_arguments_repeat1,
+    ACTIONS(996), 2,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+    ACTIONS(998), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(213), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [9582] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(955), 1,
+      sym__concat,
+    STATE(216), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(796), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(794), 23,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_to,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+      anon_sym_show,
+      anon_sym_hide,
+      anon_sym_BANGoptional,
+      anon_sym_through,
+  [9622] = 17,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(358), 1,
+      anon_sym_POUND,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_identifier,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(412), 1,
+      sym_variable,
+    ACTIONS(1002), 1,
+      anon_sym_RPAREN,
+    STATE(181), 1,
+      sym_interpolation,
+    STATE(226), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    STATE(568), 1,
+      aux_sym_arguments_repeat1,
+    ACTIONS(996), 2,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+    ACTIONS(998), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(213), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [9686] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(953), 1,
+      anon_sym_LPAREN,
+    STATE(232), 1,
+      sym_arguments,
+    ACTIONS(790), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(786), 23,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_to,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+      anon_sym_show,
+      anon_sym_hide,
+      anon_sym_BANGoptional,
+      anon_sym_through,
+  [9726] = 17,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(358), 1,
+      anon_sym_POUND,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_identifier,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(412), 1,
+      sym_variable,
+    ACTIONS(1004), 1,
+      anon_sym_RPAREN,
+    STATE(181), 1,
+      sym_interpolation,
+    STATE(226), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    STATE(561), 1,
+      aux_sym_arguments_repeat1,
+    ACTIONS(996), 2,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+    ACTIONS(998), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(213), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [9790] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1006), 1,
+      sym__concat,
+    STATE(222), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(804), 4,
+      anon_sym_COLON,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(802), 21,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_BANGoptional,
+  [9829] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(804), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(802), 24,
+      sym__concat,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_to,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+      anon_sym_show,
+      anon_sym_hide,
+      anon_sym_BANGoptional,
+      anon_sym_through,
+  [9864] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1009), 1,
+      sym__concat,
+    STATE(222), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(800), 4,
+      anon_sym_COLON,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(798), 21,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_BANGoptional,
+  [9903] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1009), 1,
+      sym__concat,
+    STATE(224), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(796), 4,
+      anon_sym_COLON,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(794), 21,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_BANGoptional,
+  [9942] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1016), 1,
+      anon_sym_POUND,
+    ACTIONS(1019), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(1022), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1025), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(1028), 1,
+      sym_identifier,
+    ACTIONS(1031), 1,
+      sym_plain_value,
+    ACTIONS(1034), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1037), 1,
+      sym_module_member,
+    ACTIONS(1040), 1,
+      sym_variable,
+    STATE(181), 1,
+      sym_interpolation,
+    STATE(226), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    ACTIONS(1011), 3,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
+    ACTIONS(1013), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(213), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [10001] = 17,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(358), 1,
+      anon_sym_POUND,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_identifier,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(412), 1,
+      sym_variable,
+    ACTIONS(1043), 1,
+      anon_sym_COMMA,
+    ACTIONS(1045), 1,
+      anon_sym_RPAREN,
+    STATE(181), 1,
+      sym_interpolation,
+    STATE(226), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    STATE(591), 1,
+      aux_sym_pseudo_class_arguments_repeat2,
+    ACTIONS(998), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(213), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [10064] = 17,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(358), 1,
+      anon_sym_POUND,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_identifier,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(412), 1,
+      sym_variable,
+    ACTIONS(1043), 1,
+      anon_sym_COMMA,
+    ACTIONS(1047), 1,
+      anon_sym_RPAREN,
+    STATE(181), 1,
+      sym_interpolation,
+    STATE(226), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    STATE(599), 1,
+      aux_sym_pseudo_class_arguments_repeat2,
+    ACTIONS(998), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(213), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [10127] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(358), 1,
+      anon_sym_POUND,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_identifier,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(412), 1,
+      sym_variable,
+    STATE(181), 1,
+      sym_interpolation,
+    STATE(226), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    ACTIONS(998), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    ACTIONS(1049), 3,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
+    STATE(213), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [10186] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(811), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(809), 24,
+      sym__concat,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_to,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+      anon_sym_show,
+      anon_sym_hide,
+      anon_sym_BANGoptional,
+      anon_sym_through,
+  [10221] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(19), 1,
+      sym_nesting_selector,
+    ACTIONS(21), 1,
+      anon_sym_STAR,
+    ACTIONS(23), 1,
+      anon_sym_DOT,
+    ACTIONS(25), 1,
+      anon_sym_COLON,
+    ACTIONS(27), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(29), 1,
+      anon_sym_POUND,
+    ACTIONS(31), 1,
+      anon_sym_LBRACK,
+    ACTIONS(69), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(71), 1,
+      anon_sym_PERCENT,
+    ACTIONS(1053), 1,
+      sym_identifier,
+    STATE(225), 1,
+      sym_interpolation,
+    STATE(495), 1,
+      sym__concatenated_identifier,
+    ACTIONS(1051), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(491), 12,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+      sym_placeholder,
+  [10279] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(884), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(882), 23,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_to,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+      anon_sym_show,
+      anon_sym_hide,
+      anon_sym_BANGoptional,
+      anon_sym_through,
+  [10313] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(19), 1,
+      sym_nesting_selector,
+    ACTIONS(21), 1,
+      anon_sym_STAR,
+    ACTIONS(23), 1,
+      anon_sym_DOT,
+    ACTIONS(25), 1,
+      anon_sym_COLON,
+    ACTIONS(27), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(29), 1,
+      anon_sym_POUND,
+    ACTIONS(31), 1,
+      anon_sym_LBRACK,
+    ACTIONS(69), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(71), 1,
+      anon_sym_PERCENT,
+    ACTIONS(1053), 1,
+      sym_identifier,
+    STATE(225), 1,
+      sym_interpolation,
+    STATE(495), 1,
+      sym__concatenated_identifier,
+    ACTIONS(1055), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(492), 12,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+      sym_placeholder,
+  [10371] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(19), 1,
+      sym_nesting_selector,
+    ACTIONS(21), 1,
+      anon_sym_STAR,
+    ACTIONS(23), 1,
+      anon_sym_DOT,
+    ACTIONS(25), 1,
+      anon_sym_COLON,
+    ACTIONS(27), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(29), 1,
+      anon_sym_POUND,
+    ACTIONS(31), 1,
+      anon_sym_LBRACK,
+    ACTIONS(69), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(71), 1,
+      anon_sym_PERCENT,
+    ACTIONS(1053), 1,
+      sym_identifier,
+    STATE(225), 1,
+      sym_interpolation,
+    STATE(495), 1,
+      sym__concatenated_identifier,
+    ACTIONS(1057), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(496), 12,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+      sym_placeholder,
+  [10429] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(892), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(890), 23,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_to,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+      anon_sym_show,
+      anon_sym_hide,
+      anon_sym_BANGoptional,
+      anon_sym_through,
+  [10463] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(908), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(906), 23,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_to,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+      anon_sym_show,
+      anon_sym_hide,
+      anon_sym_BANGoptional,
+      anon_sym_through,
+  [10497] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(912), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(910), 23,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_to,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+      anon_sym_show,
+      anon_sym_hide,
+      anon_sym_BANGoptional,
+      anon_sym_through,
+  [10531] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(864), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(862), 23,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_to,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+      anon_sym_show,
+      anon_sym_hide,
+      anon_sym_BANGoptional,
+      anon_sym_through,
+  [10565] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(19), 1,
+      sym_nesting_selector,
+    ACTIONS(21), 1,
+      anon_sym_STAR,
+    ACTIONS(23), 1,
+      anon_sym_DOT,
+    ACTIONS(25), 1,
+      anon_sym_COLON,
+    ACTIONS(27), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(29), 1,
+      anon_sym_POUND,
+    ACTIONS(31), 1,
+      anon_sym_LBRACK,
+    ACTIONS(69), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(71), 1,
+      anon_sym_PERCENT,
+    ACTIONS(1053), 1,
+      sym_identifier,
+    STATE(225), 1,
+      sym_interpolation,
+    STATE(495), 1,
+      sym__concatenated_identifier,
+    ACTIONS(1059), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(503), 12,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+      sym_placeholder,
+  [10623] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(868), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(866), 23,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_to,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+      anon_sym_show,
+      anon_sym_hide,
+      anon_sym_BANGoptional,
+      anon_sym_through,
+  [10657] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(872), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(870), 23,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_to,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+      anon_sym_show,
+      anon_sym_hide,
+      anon_sym_BANGoptional,
+      anon_sym_through,
+  [10691] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(811), 4,
+      anon_sym_COLON,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(809), 22,
+      sym__descendant_operator,
+      sym__concat,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_BANGoptional,
+  [10725] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(19), 1,
+      sym_nesting_selector,
+    ACTIONS(21), 1,
+      anon_sym_STAR,
+    ACTIONS(23), 1,
+      anon_sym_DOT,
+    ACTIONS(25), 1,
+      anon_sym_COLON,
+    ACTIONS(27), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(29), 1,
+      anon_sym_POUND,
+    ACTIONS(31), 1,
+      anon_sym_LBRACK,
+    ACTIONS(69), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(71), 1,
+      anon_sym_PERCENT,
+    ACTIONS(1053), 1,
+      sym_identifier,
+    STATE(225), 1,
+      sym_interpolation,
+    STATE(495), 1,
+      sym__concatenated_identifier,
+    ACTIONS(1061), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(513), 12,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+      sym_placeholder,
+  [10783] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(804), 4,
+      anon_sym_COLON,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(802), 22,
+      sym__descendant_operator,
+      sym__concat,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_STAR,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_BANGoptional,
+  [10817] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(896), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(894), 23,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_to,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+      anon_sym_show,
+      anon_sym_hide,
+      anon_sym_BANGoptional,
+      anon_sym_through,
+  [10851] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(19), 1,
+      sym_nesting_selector,
+    ACTIONS(21), 1,
+      anon_sym_STAR,
+    ACTIONS(23), 1,
+      anon_sym_DOT,
+    ACTIONS(25), 1,
+      anon_sym_COLON,
+    ACTIONS(27), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(29), 1,
+      anon_sym_POUND,
+    ACTIONS(31), 1,
+      anon_sym_LBRACK,
+    ACTIONS(69), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(71), 1,
+      anon_sym_PERCENT,
+    ACTIONS(1053), 1,
+      sym_identifier,
+    STATE(225), 1,
+      sym_interpolation,
+    STATE(495), 1,
+      sym__concatenated_identifier,
+    ACTIONS(1063), 2,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(505), 12,
+      sym__selector,
+      sym_universal_selector,
+      sym_class_selector,
+      sym_pseudo_class_selector,
+      sym_pseudo_element_selector,
+      sym_id_selector,
+      sym_attribute_selector,
+      sym_child_selector,
+      sym_descendant_selector,
+      sym_sibling_selector,
+      sym_adjacent_sibling_selector,
+      sym_placeholder,
+  [10909] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(904), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(902), 23,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_to,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+      anon_sym_show,
+      anon_sym_hide,
+      anon_sym_BANGoptional,
+      anon_sym_through,
+  [10943] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(854), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(852), 23,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_to,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+      anon_sym_show,
+      anon_sym_hide,
+      anon_sym_BANGoptional,
+      anon_sym_through,
+  [10977] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(888), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(886), 23,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_to,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+      anon_sym_show,
+      anon_sym_hide,
+      anon_sym_BANGoptional,
+      anon_sym_through,
+  [11011] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(876), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(874), 23,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_to,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+      anon_sym_show,
+      anon_sym_hide,
+      anon_sym_BANGoptional,
+      anon_sym_through,
+  [11045] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(358), 1,
+      anon_sym_POUND,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_identifier,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(412), 1,
+      sym_variable,
+    STATE(181), 1,
+      sym_interpolation,
+    STATE(226), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    ACTIONS(1065), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+    ACTIONS(998), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(213), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [11103] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(69), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(792), 1,
+      sym_identifier,
+    STATE(225), 1,
+      sym_interpolation,
+    STATE(445), 1,
+      sym__concatenated_identifier,
+    ACTIONS(788), 3,
+      anon_sym_COLON,
+      anon_sym_POUND,
+      anon_sym_GT,
+    ACTIONS(790), 5,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(424), 7,
+      sym__descendant_operator,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+    ACTIONS(786), 7,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_BANGoptional,
+  [11149] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(953), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1009), 1,
+      sym__concat,
+    STATE(224), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    STATE(232), 1,
+      sym_arguments,
+    ACTIONS(776), 2,
+      anon_sym_COLON,
+      anon_sym_GT,
+    ACTIONS(780), 2,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(772), 8,
+      sym__descendant_operator,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+    ACTIONS(774), 10,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_BANGoptional,
+  [11195] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(880), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(878), 23,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_to,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+      anon_sym_show,
+      anon_sym_hide,
+      anon_sym_BANGoptional,
+      anon_sym_through,
+  [11229] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(900), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(898), 23,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_to,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+      anon_sym_show,
+      anon_sym_hide,
+      anon_sym_BANGoptional,
+      anon_sym_through,
+  [11263] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1071), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1077), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    STATE(636), 1,
+      sym_map_pair,
+    ACTIONS(1067), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(442), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [11320] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1079), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1083), 1,
+      sym_variable,
+    STATE(89), 1,
+      sym_block,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1081), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(413), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [11377] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(358), 1,
+      anon_sym_POUND,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_identifier,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(412), 1,
+      sym_variable,
+    ACTIONS(1085), 1,
+      anon_sym_RPAREN,
+    STATE(181), 1,
+      sym_interpolation,
+    STATE(221), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    ACTIONS(998), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(213), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [11434] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1077), 1,
+      sym_variable,
+    ACTIONS(1087), 1,
+      anon_sym_RPAREN,
+    STATE(218), 1,
+      sym_interpolation,
+    STATE(636), 1,
+      sym_map_pair,
+    ACTIONS(1067), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(442), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [11491] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1091), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1093), 1,
+      sym_grit_metavariable,
+    ACTIONS(1095), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    STATE(615), 1,
+      sym__include_argument,
+    ACTIONS(1089), 2,
+      sym_nesting_selector,
+      sym_string_value,
+    STATE(417), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [11550] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1077), 1,
+      sym_variable,
+    ACTIONS(1097), 1,
+      anon_sym_RPAREN,
+    STATE(218), 1,
+      sym_interpolation,
+    STATE(636), 1,
+      sym_map_pair,
+    ACTIONS(1067), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(442), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [11607] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1077), 1,
+      sym_variable,
+    ACTIONS(1099), 1,
+      anon_sym_RPAREN,
+    STATE(218), 1,
+      sym_interpolation,
+    STATE(636), 1,
+      sym_map_pair,
+    ACTIONS(1067), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(442), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [11664] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(358), 1,
+      anon_sym_POUND,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_identifier,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(412), 1,
+      sym_variable,
+    ACTIONS(1101), 1,
+      anon_sym_RPAREN,
+    STATE(181), 1,
+      sym_interpolation,
+    STATE(217), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    ACTIONS(998), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(213), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [11721] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1077), 1,
+      sym_variable,
+    ACTIONS(1103), 1,
+      anon_sym_RPAREN,
+    STATE(218), 1,
+      sym_interpolation,
+    STATE(636), 1,
+      sym_map_pair,
+    ACTIONS(1067), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(442), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [11778] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1077), 1,
+      sym_variable,
+    ACTIONS(1105), 1,
+      anon_sym_RPAREN,
+    STATE(218), 1,
+      sym_interpolation,
+    STATE(636), 1,
+      sym_map_pair,
+    ACTIONS(1067), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(442), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [11835] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(358), 1,
+      anon_sym_POUND,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_identifier,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(412), 1,
+      sym_variable,
+    ACTIONS(1107), 1,
+      anon_sym_RPAREN,
+    STATE(181), 1,
+      sym_interpolation,
+    STATE(219), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    ACTIONS(998), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(213), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [11892] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(358), 1,
+      anon_sym_POUND,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_identifier,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(412), 1,
+      sym_variable,
+    ACTIONS(1109), 1,
+      anon_sym_RPAREN,
+    STATE(181), 1,
+      sym_interpolation,
+    STATE(226), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    ACTIONS(998), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(213), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [11949] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1111), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1115), 1,
+      sym_variable,
+    STATE(119), 1,
+      sym_block,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1113), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(404), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [12006] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1119), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    STATE(617), 1,
+      sym_map_pair,
+    ACTIONS(1117), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(390), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [12060] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(358), 1,
+      anon_sym_POUND,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_identifier,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(412), 1,
+      sym_variable,
+    STATE(181), 1,
+      sym_interpolation,
+    STATE(229), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    ACTIONS(998), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(213), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [12114] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1123), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1125), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1121), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(416), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [12168] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1077), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    STATE(636), 1,
+      sym_map_pair,
+    ACTIONS(1067), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(442), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [12222] = 15,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1095), 1,
+      sym_variable,
+    ACTIONS(1127), 1,
+      sym_grit_metavariable,
+    STATE(218), 1,
+      sym_interpolation,
+    STATE(637), 1,
+      sym__include_argument,
+    ACTIONS(1089), 2,
+      sym_nesting_selector,
+      sym_string_value,
+    STATE(417), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [12278] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1125), 1,
+      sym_variable,
+    ACTIONS(1129), 1,
+      anon_sym_RPAREN,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1121), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(416), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [12332] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1125), 1,
+      sym_variable,
+    ACTIONS(1131), 1,
+      anon_sym_RPAREN,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1121), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(416), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [12386] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1077), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    STATE(617), 1,
+      sym_map_pair,
+    ACTIONS(1067), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(442), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [12440] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(358), 1,
+      anon_sym_POUND,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_identifier,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(412), 1,
+      sym_variable,
+    STATE(181), 1,
+      sym_interpolation,
+    STATE(267), 1,
+      aux_sym_pseudo_class_arguments_repeat1,
+    ACTIONS(998), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(213), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [12494] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1135), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    STATE(601), 1,
+      sym_map_pair,
+    ACTIONS(1133), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(392), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [12548] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1139), 1,
+      sym__concat,
+    STATE(350), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    STATE(381), 1,
+      sym_arguments,
+    ACTIONS(774), 9,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(780), 11,
+      anon_sym_GT,
+      anon_sym_LPAREN2,
+      anon_sym_and,
+      anon_sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+  [12588] = 14,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1143), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    STATE(611), 1,
+      sym_map_pair,
+    ACTIONS(1141), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(391), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [12642] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1147), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1145), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(411), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [12693] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1151), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1149), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(453), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [12744] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1155), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1157), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(1159), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1153), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(395), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [12795] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1163), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1165), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(1167), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1161), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(384), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [12846] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1171), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1169), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(427), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [12897] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1175), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1173), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(429), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [12948] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1179), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1177), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(430), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [12999] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1183), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1181), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(451), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [13050] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1187), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1185), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(408), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [13101] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1191), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1189), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(415), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [13152] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1195), 1,
+      anon_sym_POUND,
+    ACTIONS(1197), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(1199), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1201), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(1203), 1,
+      sym_identifier,
+    ACTIONS(1205), 1,
+      sym_plain_value,
+    ACTIONS(1207), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1209), 1,
+      sym_module_member,
+    ACTIONS(1211), 1,
+      sym_variable,
+    STATE(358), 1,
+      sym_interpolation,
+    ACTIONS(1193), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(212), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [13203] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1215), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1213), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(444), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [13254] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1219), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1217), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(454), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [13305] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1195), 1,
+      anon_sym_POUND,
+    ACTIONS(1197), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(1199), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1201), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(1203), 1,
+      sym_identifier,
+    ACTIONS(1205), 1,
+      sym_plain_value,
+    ACTIONS(1207), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1209), 1,
+      sym_module_member,
+    ACTIONS(1223), 1,
+      sym_variable,
+    STATE(358), 1,
+      sym_interpolation,
+    ACTIONS(1221), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(359), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [13356] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1195), 1,
+      anon_sym_POUND,
+    ACTIONS(1197), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(1199), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1201), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(1203), 1,
+      sym_identifier,
+    ACTIONS(1205), 1,
+      sym_plain_value,
+    ACTIONS(1207), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1209), 1,
+      sym_module_member,
+    ACTIONS(1227), 1,
+      sym_variable,
+    STATE(358), 1,
+      sym_interpolation,
+    ACTIONS(1225), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(360), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [13407] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1195), 1,
+      anon_sym_POUND,
+    ACTIONS(1197), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(1199), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1201), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(1203), 1,
+      sym_identifier,
+    ACTIONS(1205), 1,
+      sym_plain_value,
+    ACTIONS(1207), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1209), 1,
+      sym_module_member,
+    ACTIONS(1231), 1,
+      sym_variable,
+    STATE(358), 1,
+      sym_interpolation,
+    ACTIONS(1229), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(361), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [13458] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1195), 1,
+      anon_sym_POUND,
+    ACTIONS(1197), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(1199), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1201), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(1203), 1,
+      sym_identifier,
+    ACTIONS(1205), 1,
+      sym_plain_value,
+    ACTIONS(1207), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1209), 1,
+      sym_module_member,
+    ACTIONS(1235), 1,
+      sym_variable,
+    STATE(358), 1,
+      sym_interpolation,
+    ACTIONS(1233), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(362), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [13509] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1239), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1237), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(455), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [13560] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1243), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1241), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(414), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [13611] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1247), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1245), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(426), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [13662] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1251), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1249), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(458), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [13713] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1255), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1253), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(459), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [13764] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1259), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1257), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(353), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [13815] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1263), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1261), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(354), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [13866] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1267), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1265), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(355), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [13917] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1271), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1269), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(356), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [13968] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1275), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1273), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(461), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [14019] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1279), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1277), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(421), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [14070] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1283), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1285), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(1287), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1281), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(428), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [14121] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1125), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1121), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(416), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [14172] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1291), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1289), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(418), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [14223] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1155), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1157), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(1295), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1293), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(400), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [14274] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1155), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1157), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(1299), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1297), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(399), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [14325] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1155), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1157), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(1303), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1301), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(398), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [14376] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1155), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1157), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(1307), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1305), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(401), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [14427] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(358), 1,
+      anon_sym_POUND,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_identifier,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(1311), 1,
+      sym_variable,
+    STATE(181), 1,
+      sym_interpolation,
+    ACTIONS(1309), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(16), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [14478] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1163), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1165), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(1315), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1313), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(387), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [14529] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1163), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1165), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(1319), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1317), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(386), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [14580] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1163), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1165), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(1323), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1321), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(385), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [14631] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1163), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1165), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(1327), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1325), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(383), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [14682] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(358), 1,
+      anon_sym_POUND,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_identifier,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(1331), 1,
+      sym_variable,
+    STATE(181), 1,
+      sym_interpolation,
+    ACTIONS(1329), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(185), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [14733] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(358), 1,
+      anon_sym_POUND,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_identifier,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(1335), 1,
+      sym_variable,
+    STATE(181), 1,
+      sym_interpolation,
+    ACTIONS(1333), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(178), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [14784] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(358), 1,
+      anon_sym_POUND,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_identifier,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(1339), 1,
+      sym_variable,
+    STATE(181), 1,
+      sym_interpolation,
+    ACTIONS(1337), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(179), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [14835] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(358), 1,
+      anon_sym_POUND,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_identifier,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(1343), 1,
+      sym_variable,
+    STATE(181), 1,
+      sym_interpolation,
+    ACTIONS(1341), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(177), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [14886] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(358), 1,
+      anon_sym_POUND,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_identifier,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(1347), 1,
+      sym_variable,
+    STATE(181), 1,
+      sym_interpolation,
+    ACTIONS(1345), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(180), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [14937] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(358), 1,
+      anon_sym_POUND,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_identifier,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(1351), 1,
+      sym_variable,
+    STATE(181), 1,
+      sym_interpolation,
+    ACTIONS(1349), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(15), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [14988] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1283), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1285), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(1355), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1353), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(422), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [15039] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1283), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1285), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(1359), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1357), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(423), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [15090] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1283), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1285), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(1363), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1361), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(424), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [15141] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1283), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1285), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(1367), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1365), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(425), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [15192] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1195), 1,
+      anon_sym_POUND,
+    ACTIONS(1197), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(1199), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1201), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(1203), 1,
+      sym_identifier,
+    ACTIONS(1205), 1,
+      sym_plain_value,
+    ACTIONS(1207), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1209), 1,
+      sym_module_member,
+    ACTIONS(1371), 1,
+      sym_variable,
+    STATE(358), 1,
+      sym_interpolation,
+    ACTIONS(1369), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(214), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [15243] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1375), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1373), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(437), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [15294] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1379), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1377), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(438), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [15345] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1383), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1381), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(440), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [15396] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1387), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1385), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(432), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [15447] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1391), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1389), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(441), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [15498] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1395), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1393), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(405), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [15549] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1399), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1397), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(406), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [15600] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1403), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1401), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(443), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [15651] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(358), 1,
+      anon_sym_POUND,
+    ACTIONS(366), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(372), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(374), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(380), 1,
+      sym_identifier,
+    ACTIONS(382), 1,
+      sym_plain_value,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(388), 1,
+      sym_module_member,
+    ACTIONS(1407), 1,
+      sym_variable,
+    STATE(181), 1,
+      sym_interpolation,
+    ACTIONS(1405), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(17), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [15702] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1411), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1409), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(403), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [15753] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1415), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1413), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(409), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [15804] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1419), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1417), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(410), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [15855] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1423), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1421), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(448), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [15906] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1427), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1425), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(452), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [15957] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1431), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1429), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(456), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [16008] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(446), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(448), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1435), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1433), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(457), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [16059] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(442), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(452), 1,
+      sym_plain_value,
+    ACTIONS(454), 1,
+      sym_module_member,
+    ACTIONS(1069), 1,
+      anon_sym_POUND,
+    ACTIONS(1073), 1,
+      sym_identifier,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1283), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1285), 1,
+      aux_sym_float_value_token1,
+    ACTIONS(1439), 1,
+      sym_variable,
+    STATE(218), 1,
+      sym_interpolation,
+    ACTIONS(1437), 3,
+      sym_nesting_selector,
+      sym_string_value,
+      sym_grit_metavariable,
+    STATE(431), 10,
+      sym__value,
+      sym_parenthesized_value,
+      sym_color_value,
+      sym_integer_value,
+      sym_float_value,
+      sym_call_expression,
+      sym_binary_expression,
+      sym_list_value,
+      sym_map_value,
+      sym__concatenated_identifier,
+  [16110] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(788), 2,
+      anon_sym_COLON,
+      anon_sym_GT,
+    ACTIONS(790), 2,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(424), 8,
+      sym__descendant_operator,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+    ACTIONS(786), 10,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_BANGoptional,
+  [16144] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1139), 1,
+      sym__concat,
+    STATE(352), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(798), 10,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(800), 10,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+  [16178] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(776), 2,
+      anon_sym_COLON,
+      anon_sym_GT,
+    ACTIONS(790), 2,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(772), 8,
+      sym__descendant_operator,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+    ACTIONS(786), 10,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_BANGoptional,
+  [16212] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1441), 1,
+      sym__concat,
+    STATE(352), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(802), 10,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(804), 10,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+  [16246] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(819), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(817), 17,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_BANGoptional,
+  [16278] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(823), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(821), 17,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_BANGoptional,
+  [16310] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(815), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(813), 17,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_BANGoptional,
+  [16342] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(827), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(825), 17,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_BANGoptional,
+  [16374] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1137), 1,
+      anon_sym_LPAREN,
+    STATE(381), 1,
+      sym_arguments,
+    ACTIONS(786), 9,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(790), 11,
+      anon_sym_GT,
+      anon_sym_LPAREN2,
+      anon_sym_and,
+      anon_sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+  [16408] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1139), 1,
+      sym__concat,
+    STATE(350), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(794), 10,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(796), 10,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+  [16442] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(297), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(817), 10,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(819), 10,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+  [16474] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(297), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(821), 10,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(823), 10,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+  [16506] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(297), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(813), 10,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(815), 10,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+  [16538] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(297), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(825), 10,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(827), 10,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+  [16570] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1444), 1,
+      sym_unit,
+    ACTIONS(842), 10,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(844), 10,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+  [16601] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(876), 10,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(874), 11,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+  [16630] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1446), 1,
+      sym_unit,
+    ACTIONS(836), 10,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(838), 10,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+  [16661] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(804), 10,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(802), 11,
+      sym__concat,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+  [16690] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(811), 10,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+    ACTIONS(809), 11,
+      sym__concat,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+  [16719] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1448), 1,
+      sym_unit,
+    ACTIONS(844), 5,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(842), 15,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_BANGoptional,
+  [16750] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1450), 1,
+      sym_unit,
+    ACTIONS(838), 5,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(836), 15,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_STAR,
+      anon_sym_COLON,
+      anon_sym_RBRACK,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_BANGoptional,
+  [16781] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(886), 10,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(888), 10,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+  [16809] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(866), 10,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(868), 10,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+  [16837] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(910), 10,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(912), 10,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+  [16865] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(862), 10,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(864), 10,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+  [16893] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(902), 10,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(904), 10,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+  [16921] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(852), 10,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(854), 10,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+  [16949] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(890), 10,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(892), 10,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+  [16977] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(870), 10,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(872), 10,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+  [17005] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(894), 10,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(896), 10,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+  [17033] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(898), 10,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(900), 10,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+  [17061] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(906), 10,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(908), 10,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+  [17089] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(882), 10,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(884), 10,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+  [17117] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(878), 10,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_LPAREN2,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(880), 10,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_minus,
+      sym_divide,
+      anon_sym_LT,
+      sym_identifier,
+  [17145] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(320), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(827), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(825), 14,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+      anon_sym_show,
+      anon_sym_hide,
+  [17174] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1452), 1,
+      anon_sym_SEMI,
+    ACTIONS(1454), 1,
+      anon_sym_GT,
+    ACTIONS(1456), 1,
+      anon_sym_and,
+    ACTIONS(1458), 1,
+      anon_sym_or,
+    ACTIONS(1464), 1,
+      anon_sym_as,
+    ACTIONS(1466), 1,
+      anon_sym_with,
+    ACTIONS(1462), 2,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(1468), 2,
+      anon_sym_show,
+      anon_sym_hide,
+    STATE(320), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1460), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [17221] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(320), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(815), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(813), 14,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+      anon_sym_show,
+      anon_sym_hide,
+  [17250] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(320), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(823), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(821), 14,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+      anon_sym_show,
+      anon_sym_hide,
+  [17279] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(320), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(819), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(817), 14,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+      anon_sym_show,
+      anon_sym_hide,
+  [17308] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1450), 1,
+      sym_unit,
+    ACTIONS(836), 8,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(838), 9,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_divide,
+      anon_sym_LT,
+      anon_sym_as,
+      anon_sym_with,
+      anon_sym_show,
+      anon_sym_hide,
+  [17336] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1448), 1,
+      sym_unit,
+    ACTIONS(842), 8,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(844), 9,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_divide,
+      anon_sym_LT,
+      anon_sym_as,
+      anon_sym_with,
+      anon_sym_show,
+      anon_sym_hide,
+  [17364] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1470), 1,
+      anon_sym_COMMA,
+    ACTIONS(1472), 1,
+      anon_sym_COLON,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1476), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    STATE(593), 1,
+      aux_sym_list_value_repeat1,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [17410] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1470), 1,
+      anon_sym_COMMA,
+    ACTIONS(1472), 1,
+      anon_sym_COLON,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1486), 1,
+      anon_sym_RPAREN,
+    STATE(612), 1,
+      aux_sym_list_value_repeat1,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [17456] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1470), 1,
+      anon_sym_COMMA,
+    ACTIONS(1472), 1,
+      anon_sym_COLON,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1488), 1,
+      anon_sym_RPAREN,
+    STATE(603), 1,
+      aux_sym_list_value_repeat1,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [17502] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1009), 1,
+      sym__concat,
+    ACTIONS(1492), 1,
+      anon_sym_COLON,
+    STATE(224), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(1490), 14,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_BANGoptional,
+  [17531] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(965), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(973), 1,
+      anon_sym_selector,
+    ACTIONS(979), 1,
+      sym_identifier,
+    ACTIONS(983), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1079), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1494), 1,
+      anon_sym_SEMI,
+    ACTIONS(1496), 1,
+      sym_grit_metavariable,
+    STATE(69), 1,
+      sym_block,
+    ACTIONS(971), 2,
+      sym_not,
+      sym_only,
+    STATE(517), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [17572] = 12,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1498), 1,
+      anon_sym_SEMI,
+    ACTIONS(1500), 1,
+      anon_sym_GT,
+    ACTIONS(1502), 1,
+      anon_sym_and,
+    ACTIONS(1504), 1,
+      anon_sym_or,
+    ACTIONS(1510), 1,
+      anon_sym_as,
+    ACTIONS(1512), 1,
+      anon_sym_with,
+    ACTIONS(1508), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(315), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1506), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [17615] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1009), 1,
+      sym__concat,
+    ACTIONS(1516), 1,
+      anon_sym_COLON,
+    STATE(224), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(1514), 14,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_BANGoptional,
+  [17644] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(965), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(973), 1,
+      anon_sym_selector,
+    ACTIONS(979), 1,
+      sym_identifier,
+    ACTIONS(983), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1111), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1518), 1,
+      anon_sym_SEMI,
+    ACTIONS(1520), 1,
+      sym_grit_metavariable,
+    STATE(118), 1,
+      sym_block,
+    ACTIONS(971), 2,
+      sym_not,
+      sym_only,
+    STATE(518), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [17685] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(315), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(815), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(813), 12,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+  [17712] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(315), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(823), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(821), 12,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+  [17739] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(315), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(819), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(817), 12,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+  [17766] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(315), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(827), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(825), 12,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_as,
+      anon_sym_with,
+  [17793] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(69), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1522), 1,
+      sym_identifier,
+    STATE(225), 1,
+      sym_interpolation,
+    STATE(445), 1,
+      sym__concatenated_identifier,
+    ACTIONS(788), 2,
+      anon_sym_COLON,
+      anon_sym_POUND,
+    ACTIONS(424), 11,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [17826] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1111), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    STATE(159), 1,
+      sym_block,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [17866] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1111), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    STATE(137), 1,
+      sym_block,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [17906] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1524), 1,
+      anon_sym_LBRACE,
+    STATE(28), 1,
+      sym_block,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [17946] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1111), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    STATE(138), 1,
+      sym_block,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [17986] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1448), 1,
+      sym_unit,
+    ACTIONS(844), 7,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_divide,
+      anon_sym_LT,
+      anon_sym_as,
+      anon_sym_with,
+    ACTIONS(842), 8,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18012] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1079), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    STATE(81), 1,
+      sym_block,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18052] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1524), 1,
+      anon_sym_LBRACE,
+    STATE(114), 1,
+      sym_block,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18092] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1111), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    STATE(164), 1,
+      sym_block,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18132] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1111), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    STATE(165), 1,
+      sym_block,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18172] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1450), 1,
+      sym_unit,
+    ACTIONS(838), 7,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_divide,
+      anon_sym_LT,
+      anon_sym_as,
+      anon_sym_with,
+    ACTIONS(836), 8,
+      anon_sym_SEMI,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18198] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1079), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    STATE(36), 1,
+      sym_block,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18238] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1526), 1,
+      anon_sym_LBRACE,
+    STATE(21), 1,
+      sym_block,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18278] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1079), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    STATE(82), 1,
+      sym_block,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18318] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(1528), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18356] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(1530), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18394] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(1532), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18432] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1534), 1,
+      anon_sym_SEMI,
+    ACTIONS(1536), 1,
+      anon_sym_BANGoptional,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18472] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1538), 1,
+      anon_sym_SEMI,
+    ACTIONS(1540), 1,
+      anon_sym_BANGoptional,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18512] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1079), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    STATE(70), 1,
+      sym_block,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18552] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(330), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(819), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(817), 11,
+      anon_sym_to,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_through,
+  [18578] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(330), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(823), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(821), 11,
+      anon_sym_to,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_through,
+  [18604] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(330), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(815), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(813), 11,
+      anon_sym_to,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_through,
+  [18630] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(330), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(827), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(825), 11,
+      anon_sym_to,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_through,
+  [18656] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1079), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    STATE(93), 1,
+      sym_block,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18696] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(1542), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18734] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1546), 1,
+      anon_sym_GT,
+    ACTIONS(1548), 1,
+      anon_sym_and,
+    ACTIONS(1550), 1,
+      anon_sym_or,
+    ACTIONS(1544), 2,
+      anon_sym_to,
+      anon_sym_through,
+    ACTIONS(1554), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(330), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1552), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18772] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(1556), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18810] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1526), 1,
+      anon_sym_LBRACE,
+    STATE(32), 1,
+      sym_block,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18850] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1546), 1,
+      anon_sym_GT,
+    ACTIONS(1548), 1,
+      anon_sym_and,
+    ACTIONS(1550), 1,
+      anon_sym_or,
+    ACTIONS(1554), 2,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(1558), 2,
+      anon_sym_to,
+      anon_sym_through,
+    STATE(330), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1552), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18888] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1560), 1,
+      anon_sym_SEMI,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [18925] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1492), 1,
+      anon_sym_COLON,
+    ACTIONS(1490), 14,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_BANGoptional,
+  [18948] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1450), 1,
+      sym_unit,
+    ACTIONS(836), 7,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(838), 7,
+      anon_sym_to,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_divide,
+      anon_sym_LT,
+      anon_sym_through,
+  [18973] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1564), 1,
+      anon_sym_COLON,
+    ACTIONS(1566), 1,
+      anon_sym_LPAREN,
+    STATE(493), 1,
+      sym_pseudo_class_arguments,
+    ACTIONS(1562), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [19000] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1448), 1,
+      sym_unit,
+    ACTIONS(842), 7,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(844), 7,
+      anon_sym_to,
+      anon_sym_GT,
+      anon_sym_and,
+      anon_sym_or,
+      sym_divide,
+      anon_sym_LT,
+      anon_sym_through,
+  [19025] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1568), 1,
+      anon_sym_SEMI,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [19062] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1570), 1,
+      anon_sym_SEMI,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [19099] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1574), 1,
+      anon_sym_COLON,
+    ACTIONS(1576), 1,
+      anon_sym_LPAREN,
+    STATE(497), 1,
+      sym_pseudo_element_arguments,
+    ACTIONS(1572), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [19126] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1578), 1,
+      anon_sym_SEMI,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [19163] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1580), 1,
+      anon_sym_SEMI,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [19200] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1472), 1,
+      anon_sym_COLON,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [19237] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1582), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [19274] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1584), 1,
+      anon_sym_SEMI,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [19311] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1516), 1,
+      anon_sym_COLON,
+    ACTIONS(1514), 14,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_BANGoptional,
+  [19334] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1566), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1588), 1,
+      anon_sym_COLON,
+    STATE(489), 1,
+      sym_pseudo_class_arguments,
+    ACTIONS(1586), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [19361] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1576), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1592), 1,
+      anon_sym_COLON,
+    STATE(494), 1,
+      sym_pseudo_element_arguments,
+    ACTIONS(1590), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [19388] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1594), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [19425] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1598), 1,
+      anon_sym_COLON,
+    ACTIONS(1596), 14,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_BANGoptional,
+  [19448] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1600), 1,
+      anon_sym_COLON,
+    ACTIONS(790), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(786), 11,
+      anon_sym_COMMA,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [19473] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1602), 1,
+      anon_sym_SEMI,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [19510] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1604), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [19547] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1606), 1,
+      anon_sym_RBRACK,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [19584] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1608), 1,
+      anon_sym_SEMI,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [19621] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1610), 1,
+      anon_sym_SEMI,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [19658] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1612), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [19695] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1614), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [19732] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1616), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [19769] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1618), 1,
+      anon_sym_RBRACK,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [19806] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(776), 1,
+      anon_sym_COLON,
+    ACTIONS(1009), 1,
+      sym__concat,
+    STATE(224), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(772), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [19833] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(356), 1,
+      anon_sym_STAR,
+    ACTIONS(963), 1,
+      anon_sym_PLUS,
+    ACTIONS(1474), 1,
+      anon_sym_GT,
+    ACTIONS(1478), 1,
+      anon_sym_and,
+    ACTIONS(1480), 1,
+      anon_sym_or,
+    ACTIONS(1620), 1,
+      anon_sym_SEMI,
+    ACTIONS(1484), 2,
+      sym_divide,
+      anon_sym_LT,
+    STATE(306), 2,
+      sym_plus,
+      sym_times,
+    ACTIONS(1482), 5,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [19870] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1622), 1,
+      anon_sym_COMMA,
+    ACTIONS(1624), 1,
+      anon_sym_RPAREN,
+    STATE(608), 1,
+      aux_sym__include_arguments_repeat1,
+    ACTIONS(790), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(786), 9,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [19899] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(965), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(973), 1,
+      anon_sym_selector,
+    ACTIONS(979), 1,
+      sym_identifier,
+    ACTIONS(983), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1626), 1,
+      sym_grit_metavariable,
+    ACTIONS(971), 2,
+      sym_not,
+      sym_only,
+    STATE(536), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [19931] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(965), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(973), 1,
+      anon_sym_selector,
+    ACTIONS(979), 1,
+      sym_identifier,
+    ACTIONS(983), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1628), 1,
+      sym_grit_metavariable,
+    ACTIONS(971), 2,
+      sym_not,
+      sym_only,
+    STATE(524), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [19963] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(965), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(973), 1,
+      anon_sym_selector,
+    ACTIONS(979), 1,
+      sym_identifier,
+    ACTIONS(983), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1630), 1,
+      sym_grit_metavariable,
+    ACTIONS(971), 2,
+      sym_not,
+      sym_only,
+    STATE(531), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [19995] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(965), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(973), 1,
+      anon_sym_selector,
+    ACTIONS(979), 1,
+      sym_identifier,
+    ACTIONS(983), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1632), 1,
+      sym_grit_metavariable,
+    ACTIONS(971), 2,
+      sym_not,
+      sym_only,
+    STATE(537), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [20027] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(965), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(973), 1,
+      anon_sym_selector,
+    ACTIONS(979), 1,
+      sym_identifier,
+    ACTIONS(983), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1634), 1,
+      sym_grit_metavariable,
+    ACTIONS(971), 2,
+      sym_not,
+      sym_only,
+    STATE(540), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [20059] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(965), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(973), 1,
+      anon_sym_selector,
+    ACTIONS(979), 1,
+      sym_identifier,
+    ACTIONS(983), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1636), 1,
+      sym_grit_metavariable,
+    ACTIONS(971), 2,
+      sym_not,
+      sym_only,
+    STATE(542), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [20091] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(965), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(973), 1,
+      anon_sym_selector,
+    ACTIONS(979), 1,
+      sym_identifier,
+    ACTIONS(983), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1638), 1,
+      sym_grit_metavariable,
+    ACTIONS(971), 2,
+      sym_not,
+      sym_only,
+    STATE(525), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [20123] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1009), 1,
+      sym__concat,
+    ACTIONS(1640), 1,
+      anon_sym_COLON,
+    STATE(224), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(772), 11,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+  [20149] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1643), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+    ACTIONS(790), 3,
+      anon_sym_GT,
+      sym_divide,
+      anon_sym_LT,
+    ACTIONS(786), 9,
+      anon_sym_STAR,
+      anon_sym_PLUS,
+      anon_sym_and,
+      anon_sym_or,
+      sym_minus,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [20173] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(965), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(973), 1,
+      anon_sym_selector,
+    ACTIONS(979), 1,
+      sym_identifier,
+    ACTIONS(983), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1645), 1,
+      sym_grit_metavariable,
+    ACTIONS(971), 2,
+      sym_not,
+      sym_only,
+    STATE(523), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [20205] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(965), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(973), 1,
+      anon_sym_selector,
+    ACTIONS(983), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1647), 1,
+      sym_identifier,
+    ACTIONS(1649), 1,
+      sym_grit_metavariable,
+    ACTIONS(971), 2,
+      sym_not,
+      sym_only,
+    STATE(548), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [20237] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(965), 1,
+      anon_sym_LPAREN2,
+    ACTIONS(973), 1,
+      anon_sym_selector,
+    ACTIONS(979), 1,
+      sym_identifier,
+    ACTIONS(983), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1651), 1,
+      sym_grit_metavariable,
+    ACTIONS(971), 2,
+      sym_not,
+      sym_only,
+    STATE(522), 7,
+      sym__query,
+      sym_feature_query,
+      sym_parenthesized_query,
+      sym_binary_query,
+      sym_unary_query,
+      sym_selector_query,
+      sym_interpolation,
+  [20269] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1009), 1,
+      sym__concat,
+    ACTIONS(1653), 1,
+      anon_sym_COLON,
+    STATE(224), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(772), 11,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+  [20295] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1009), 1,
+      sym__concat,
+    ACTIONS(1655), 1,
+      anon_sym_COLON,
+    STATE(224), 1,
+      aux_sym__concatenated_identifier_repeat1,
+    ACTIONS(772), 11,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+  [20321] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1660), 1,
+      anon_sym_COLON,
+    ACTIONS(1658), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [20342] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1664), 1,
+      anon_sym_COLON,
+    ACTIONS(1662), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [20363] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1668), 1,
+      anon_sym_COLON,
+    ACTIONS(1666), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [20384] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1672), 1,
+      anon_sym_COLON,
+    ACTIONS(1670), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [20405] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1674), 1,
+      anon_sym_COMMA,
+    ACTIONS(1676), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1680), 1,
+      anon_sym_COLON,
+    ACTIONS(1682), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(1684), 1,
+      anon_sym_POUND,
+    ACTIONS(1686), 1,
+      anon_sym_LBRACK,
+    ACTIONS(1688), 1,
+      anon_sym_GT,
+    ACTIONS(1690), 1,
+      anon_sym_TILDE,
+    ACTIONS(1692), 1,
+      anon_sym_PLUS,
+    ACTIONS(1694), 1,
+      sym__descendant_operator,
+    STATE(594), 1,
+      aux_sym_selectors_repeat1,
+    ACTIONS(1678), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+  [20446] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1698), 1,
+      anon_sym_COLON,
+    ACTIONS(1696), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [20467] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1664), 1,
+      anon_sym_COLON,
+    ACTIONS(1662), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [20488] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1043), 1,
+      anon_sym_COMMA,
+    ACTIONS(1045), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1680), 1,
+      anon_sym_COLON,
+    ACTIONS(1682), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(1684), 1,
+      anon_sym_POUND,
+    ACTIONS(1686), 1,
+      anon_sym_LBRACK,
+    ACTIONS(1688), 1,
+      anon_sym_GT,
+    ACTIONS(1690), 1,
+      anon_sym_TILDE,
+    ACTIONS(1692), 1,
+      anon_sym_PLUS,
+    ACTIONS(1694), 1,
+      sym__descendant_operator,
+    STATE(620), 1,
+      aux_sym_pseudo_class_arguments_repeat2,
+    ACTIONS(1678), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+  [20529] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1702), 1,
+      anon_sym_COLON,
+    ACTIONS(1700), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [20550] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1706), 1,
+      anon_sym_COLON,
+    ACTIONS(1704), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [20571] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1710), 1,
+      anon_sym_COLON,
+    ACTIONS(1708), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [20592] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1714), 1,
+      anon_sym_COLON,
+    ACTIONS(1712), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [20613] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1718), 1,
+      anon_sym_COLON,
+    ACTIONS(1716), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [20634] = 13,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1043), 1,
+      anon_sym_COMMA,
+    ACTIONS(1047), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1680), 1,
+      anon_sym_COLON,
+    ACTIONS(1682), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(1684), 1,
+      anon_sym_POUND,
+    ACTIONS(1686), 1,
+      anon_sym_LBRACK,
+    ACTIONS(1688), 1,
+      anon_sym_GT,
+    ACTIONS(1690), 1,
+      anon_sym_TILDE,
+    ACTIONS(1692), 1,
+      anon_sym_PLUS,
+    ACTIONS(1694), 1,
+      sym__descendant_operator,
+    STATE(598), 1,
+      aux_sym_pseudo_class_arguments_repeat2,
+    ACTIONS(1678), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+  [20675] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1722), 1,
+      anon_sym_COLON,
+    ACTIONS(1678), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+    ACTIONS(1720), 10,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [20698] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1726), 1,
+      anon_sym_COLON,
+    ACTIONS(1678), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+    ACTIONS(1724), 10,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [20721] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1730), 1,
+      anon_sym_COLON,
+    ACTIONS(1728), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [20742] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1734), 1,
+      anon_sym_COLON,
+    ACTIONS(1732), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [20763] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(776), 1,
+      anon_sym_COLON,
+    ACTIONS(772), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [20784] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1738), 1,
+      anon_sym_COLON,
+    ACTIONS(1678), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+    ACTIONS(1736), 10,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [20807] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1742), 1,
+      anon_sym_COLON,
+    ACTIONS(1740), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [20828] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1746), 1,
+      anon_sym_COLON,
+    ACTIONS(1744), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [20849] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1746), 1,
+      anon_sym_COLON,
+    ACTIONS(1744), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [20870] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1750), 1,
+      anon_sym_COLON,
+    ACTIONS(1748), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [20891] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1754), 1,
+      anon_sym_COLON,
+    ACTIONS(1752), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [20912] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1758), 1,
+      anon_sym_COLON,
+    ACTIONS(1756), 12,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [20933] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1762), 1,
+      anon_sym_COLON,
+    ACTIONS(1678), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+    ACTIONS(1760), 10,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+      anon_sym_RPAREN,
+  [20956] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1655), 1,
+      anon_sym_COLON,
+    ACTIONS(772), 11,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+  [20976] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1680), 1,
+      anon_sym_COLON,
+    ACTIONS(1682), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(1684), 1,
+      anon_sym_POUND,
+    ACTIONS(1686), 1,
+      anon_sym_LBRACK,
+    ACTIONS(1688), 1,
+      anon_sym_GT,
+    ACTIONS(1690), 1,
+      anon_sym_TILDE,
+    ACTIONS(1692), 1,
+      anon_sym_PLUS,
+    ACTIONS(1694), 1,
+      sym__descendant_operator,
+    ACTIONS(1678), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+    ACTIONS(1764), 2,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+  [21012] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1766), 5,
+      sym_nesting_selector,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1768), 7,
+      anon_sym_POUND,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+  [21032] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1770), 5,
+      sym_nesting_selector,
+      anon_sym_LPAREN2,
+      sym_string_value,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1772), 7,
+      anon_sym_POUND,
+      aux_sym_integer_value_token1,
+      aux_sym_float_value_token1,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+      sym_variable,
+  [21052] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1653), 1,
+      anon_sym_COLON,
+    ACTIONS(772), 11,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+  [21072] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(788), 1,
+      anon_sym_COLON,
+    ACTIONS(1538), 1,
+      anon_sym_SEMI,
+    ACTIONS(1540), 1,
+      anon_sym_BANGoptional,
+    ACTIONS(424), 9,
+      sym__descendant_operator,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+  [21096] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1680), 1,
+      anon_sym_COLON,
+    ACTIONS(1682), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(1684), 1,
+      anon_sym_POUND,
+    ACTIONS(1686), 1,
+      anon_sym_LBRACK,
+    ACTIONS(1688), 1,
+      anon_sym_GT,
+    ACTIONS(1690), 1,
+      anon_sym_TILDE,
+    ACTIONS(1692), 1,
+      anon_sym_PLUS,
+    ACTIONS(1694), 1,
+      sym__descendant_operator,
+    ACTIONS(1065), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+    ACTIONS(1678), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+  [21132] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1640), 1,
+      anon_sym_COLON,
+    ACTIONS(772), 11,
+      sym__descendant_operator,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+  [21152] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(788), 1,
+      anon_sym_COLON,
+    ACTIONS(1534), 1,
+      anon_sym_SEMI,
+    ACTIONS(1536), 1,
+      anon_sym_BANGoptional,
+    ACTIONS(424), 9,
+      sym__descendant_operator,
+      sym_nesting_selector,
+      anon_sym_DOT,
+      anon_sym_COLON_COLON,
+      anon_sym_POUND,
+      anon_sym_LBRACK,
+      anon_sym_GT,
+      anon_sym_TILDE,
+      anon_sym_PLUS,
+  [21176] = 11,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1680), 1,
+      anon_sym_COLON,
+    ACTIONS(1682), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(1684), 1,
+      anon_sym_POUND,
+    ACTIONS(1686), 1,
+      anon_sym_LBRACK,
+    ACTIONS(1688), 1,
+      anon_sym_GT,
+    ACTIONS(1690), 1,
+      anon_sym_TILDE,
+    ACTIONS(1692), 1,
+      anon_sym_PLUS,
+    ACTIONS(1694), 1,
+      sym__descendant_operator,
+    ACTIONS(1774), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1678), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+  [21211] = 10,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1680), 1,
+      anon_sym_COLON,
+    ACTIONS(1682), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(1684), 1,
+      anon_sym_POUND,
+    ACTIONS(1686), 1,
+      anon_sym_LBRACK,
+    ACTIONS(1688), 1,
+      anon_sym_GT,
+    ACTIONS(1690), 1,
+      anon_sym_TILDE,
+    ACTIONS(1692), 1,
+      anon_sym_PLUS,
+    ACTIONS(1694), 1,
+      sym__descendant_operator,
+    ACTIONS(1678), 2,
+      sym_nesting_selector,
+      anon_sym_DOT,
+  [21243] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1776), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1778), 1,
+      anon_sym_from,
+    ACTIONS(1780), 1,
+      anon_sym_to,
+    ACTIONS(1782), 1,
+      aux_sym_integer_value_token1,
+    STATE(516), 2,
+      sym_keyframe_block,
+      aux_sym_keyframe_block_list_repeat1,
+    STATE(662), 3,
+      sym_from,
+      sym_to,
+      sym_integer_value,
+  [21268] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1778), 1,
+      anon_sym_from,
+    ACTIONS(1780), 1,
+      anon_sym_to,
+    ACTIONS(1782), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1784), 1,
+      anon_sym_RBRACE,
+    STATE(521), 2,
+      sym_keyframe_block,
+      aux_sym_keyframe_block_list_repeat1,
+    STATE(662), 3,
+      sym_from,
+      sym_to,
+      sym_integer_value,
+  [21293] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1079), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1786), 1,
+      anon_sym_COMMA,
+    ACTIONS(1788), 1,
+      anon_sym_SEMI,
+    ACTIONS(1790), 1,
+      anon_sym_and,
+    ACTIONS(1792), 1,
+      anon_sym_or,
+    STATE(99), 1,
+      sym_block,
+    STATE(549), 1,
+      aux_sym_at_rule_repeat1,
+    STATE(472), 2,
+      sym_and,
+      sym_or,
+  [21322] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1111), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1786), 1,
+      anon_sym_COMMA,
+    ACTIONS(1790), 1,
+      anon_sym_and,
+    ACTIONS(1792), 1,
+      anon_sym_or,
+    ACTIONS(1794), 1,
+      anon_sym_SEMI,
+    STATE(127), 1,
+      sym_block,
+    STATE(552), 1,
+      aux_sym_at_rule_repeat1,
+    STATE(472), 2,
+      sym_and,
+      sym_or,
+  [21351] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1778), 1,
+      anon_sym_from,
+    ACTIONS(1780), 1,
+      anon_sym_to,
+    ACTIONS(1782), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1796), 1,
+      anon_sym_RBRACE,
+    STATE(521), 2,
+      sym_keyframe_block,
+      aux_sym_keyframe_block_list_repeat1,
+    STATE(662), 3,
+      sym_from,
+      sym_to,
+      sym_integer_value,
+  [21376] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1778), 1,
+      anon_sym_from,
+    ACTIONS(1780), 1,
+      anon_sym_to,
+    ACTIONS(1782), 1,
+      aux_sym_integer_value_token1,
+    ACTIONS(1798), 1,
+      anon_sym_RBRACE,
+    STATE(519), 2,
+      sym_keyframe_block,
+      aux_sym_keyframe_block_list_repeat1,
+    STATE(662), 3,
+      sym_from,
+      sym_to,
+      sym_integer_value,
+  [21401] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1800), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1802), 1,
+      anon_sym_from,
+    ACTIONS(1805), 1,
+      anon_sym_to,
+    ACTIONS(1808), 1,
+      aux_sym_integer_value_token1,
+    STATE(521), 2,
+      sym_keyframe_block,
+      aux_sym_keyframe_block_list_repeat1,
+    STATE(662), 3,
+      sym_from,
+      sym_to,
+      sym_integer_value,
+  [21426] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(472), 2,
+      sym_and,
+      sym_or,
+    ACTIONS(1811), 6,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+  [21442] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(472), 2,
+      sym_and,
+      sym_or,
+    ACTIONS(1813), 6,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+  [21458] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1111), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1790), 1,
+      anon_sym_and,
+    ACTIONS(1792), 1,
+      anon_sym_or,
+    ACTIONS(1815), 1,
+      anon_sym_COMMA,
+    STATE(121), 1,
+      sym_block,
+    STATE(584), 1,
+      aux_sym_media_statement_repeat1,
+    STATE(472), 2,
+      sym_and,
+      sym_or,
+  [21484] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1079), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1790), 1,
+      anon_sym_and,
+    ACTIONS(1792), 1,
+      anon_sym_or,
+    ACTIONS(1815), 1,
+      anon_sym_COMMA,
+    STATE(92), 1,
+      sym_block,
+    STATE(556), 1,
+      aux_sym_media_statement_repeat1,
+    STATE(472), 2,
+      sym_and,
+      sym_or,
+  [21510] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1817), 1,
+      sym_string_value,
+    STATE(381), 1,
+      sym_arguments,
+    STATE(696), 1,
+      sym_call_expression,
+    ACTIONS(1209), 3,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+  [21531] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1790), 1,
+      anon_sym_and,
+    ACTIONS(1792), 1,
+      anon_sym_or,
+    ACTIONS(1819), 1,
+      anon_sym_COMMA,
+    ACTIONS(1821), 1,
+      anon_sym_SEMI,
+    STATE(605), 1,
+      aux_sym_import_statement_repeat1,
+    STATE(472), 2,
+      sym_and,
+      sym_or,
+  [21554] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1823), 3,
+      anon_sym_LPAREN2,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1825), 4,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_identifier,
+  [21569] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1827), 3,
+      anon_sym_LPAREN2,
+      sym_grit_metavariable,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1829), 4,
+      sym_not,
+      sym_only,
+      anon_sym_selector,
+      sym_identifier,
+  [21584] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1833), 1,
+      anon_sym_RBRACK,
+    ACTIONS(1831), 6,
+      sym_equal,
+      sym_contains_word_equal,
+      sym_starts_with_equal,
+      sym_dash_equal,
+      sym_contains_equal,
+      sym_ends_equal,
+  [21599] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1790), 1,
+      anon_sym_and,
+    ACTIONS(1792), 1,
+      anon_sym_or,
+    STATE(472), 2,
+      sym_and,
+      sym_or,
+    ACTIONS(1835), 3,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+  [21618] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1137), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1837), 1,
+      sym_string_value,
+    STATE(381), 1,
+      sym_arguments,
+    STATE(711), 1,
+      sym_call_expression,
+    ACTIONS(1209), 3,
+      sym_identifier,
+      sym_plain_value,
+      sym_module_member,
+  [21639] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1790), 1,
+      anon_sym_and,
+    ACTIONS(1792), 1,
+      anon_sym_or,
+    ACTIONS(1819), 1,
+      anon_sym_COMMA,
+    ACTIONS(1839), 1,
+      anon_sym_SEMI,
+    STATE(626), 1,
+      aux_sym_import_statement_repeat1,
+    STATE(472), 2,
+      sym_and,
+      sym_or,
+  [21662] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1843), 1,
+      anon_sym_RBRACK,
+    ACTIONS(1841), 6,
+      sym_equal,
+      sym_contains_word_equal,
+      sym_starts_with_equal,
+      sym_dash_equal,
+      sym_contains_equal,
+      sym_ends_equal,
+  [21677] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1845), 6,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+  [21689] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1790), 1,
+      anon_sym_and,
+    ACTIONS(1792), 1,
+      anon_sym_or,
+    ACTIONS(1847), 2,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+    STATE(472), 2,
+      sym_and,
+      sym_or,
+  [21707] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1111), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1790), 1,
+      anon_sym_and,
+    ACTIONS(1792), 1,
+      anon_sym_or,
+    STATE(125), 1,
+      sym_block,
+    STATE(472), 2,
+      sym_and,
+      sym_or,
+  [21727] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(809), 6,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+  [21739] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1849), 6,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+  [21751] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1079), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1790), 1,
+      anon_sym_and,
+    ACTIONS(1792), 1,
+      anon_sym_or,
+    STATE(60), 1,
+      sym_block,
+    STATE(472), 2,
+      sym_and,
+      sym_or,
+  [21771] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1851), 6,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+  [21783] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1790), 1,
+      anon_sym_and,
+    ACTIONS(1792), 1,
+      anon_sym_or,
+    ACTIONS(1853), 2,
+      anon_sym_COMMA,
+      anon_sym_LBRACE,
+    STATE(472), 2,
+      sym_and,
+      sym_or,
+  [21801] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1855), 6,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+  [21813] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1111), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1857), 1,
+      anon_sym_SEMI,
+    ACTIONS(1859), 1,
+      anon_sym_LPAREN,
+    STATE(130), 1,
+      sym_block,
+    STATE(589), 1,
+      sym__include_arguments,
+  [21832] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1861), 1,
+      anon_sym_SEMI,
+    ACTIONS(1863), 1,
+      anon_sym_RBRACE,
+    STATE(641), 1,
+      sym_default_flag,
+    ACTIONS(384), 2,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+  [21849] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1079), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1859), 1,
+      anon_sym_LPAREN,
+    ACTIONS(1865), 1,
+      anon_sym_SEMI,
+    STATE(104), 1,
+      sym_block,
+    STATE(625), 1,
+      sym__include_arguments,
+  [21868] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1867), 1,
+      sym_string_value,
+    ACTIONS(1869), 1,
+      sym_identifier,
+    STATE(734), 1,
+      sym_call_expression,
+    ACTIONS(1209), 2,
+      sym_plain_value,
+      sym_module_member,
+  [21885] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1790), 1,
+      anon_sym_and,
+    ACTIONS(1792), 1,
+      anon_sym_or,
+    ACTIONS(1871), 1,
+      anon_sym_RPAREN,
+    STATE(472), 2,
+      sym_and,
+      sym_or,
+  [21902] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1079), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1786), 1,
+      anon_sym_COMMA,
+    ACTIONS(1873), 1,
+      anon_sym_SEMI,
+    STATE(45), 1,
+      sym_block,
+    STATE(564), 1,
+      aux_sym_at_rule_repeat1,
+  [21921] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1875), 1,
+      sym_string_value,
+    ACTIONS(1877), 1,
+      sym_identifier,
+    STATE(729), 1,
+      sym_call_expression,
+    ACTIONS(1209), 2,
+      sym_plain_value,
+      sym_module_member,
+  [21938] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1879), 1,
+      anon_sym_SEMI,
+    ACTIONS(1881), 1,
+      anon_sym_RBRACE,
+    STATE(674), 1,
+      sym_default_flag,
+    ACTIONS(384), 2,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+  [21955] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1111), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1786), 1,
+      anon_sym_COMMA,
+    ACTIONS(1883), 1,
+      anon_sym_SEMI,
+    STATE(145), 1,
+      sym_block,
+    STATE(564), 1,
+      aux_sym_at_rule_repeat1,
+  [21974] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(386), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1885), 1,
+      sym_identifier,
+    ACTIONS(1887), 1,
+      anon_sym_DASH,
+    STATE(189), 1,
+      sym_interpolation,
+  [21990] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1889), 1,
+      anon_sym_SEMI,
+    ACTIONS(1891), 1,
+      anon_sym_with,
+    ACTIONS(1893), 2,
+      anon_sym_show,
+      anon_sym_hide,
+  [22004] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(488), 4,
+      anon_sym_RBRACE,
+      anon_sym_from,
+      anon_sym_to,
+      aux_sym_integer_value_token1,
+  [22014] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1079), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1815), 1,
+      anon_sym_COMMA,
+    STATE(37), 1,
+      sym_block,
+    STATE(595), 1,
+      aux_sym_media_statement_repeat1,
+  [22030] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(492), 4,
+      anon_sym_RBRACE,
+      anon_sym_from,
+      anon_sym_to,
+      aux_sym_integer_value_token1,
+  [22040] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1879), 1,
+      anon_sym_SEMI,
+    STATE(705), 1,
+      sym_default_flag,
+    ACTIONS(384), 2,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+  [22054] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1895), 1,
+      anon_sym_COMMA,
+    ACTIONS(1897), 1,
+      anon_sym_SEMI,
+    ACTIONS(1899), 1,
+      anon_sym_with,
+    STATE(585), 1,
+      aux_sym_forward_statement_repeat1,
+  [22070] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1904), 1,
+      anon_sym_RPAREN,
+    STATE(560), 1,
+      aux_sym_arguments_repeat1,
+    ACTIONS(1901), 2,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+  [22084] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1906), 1,
+      anon_sym_RPAREN,
+    STATE(560), 1,
+      aux_sym_arguments_repeat1,
+    ACTIONS(996), 2,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+  [22098] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1861), 1,
+      anon_sym_SEMI,
+    STATE(710), 1,
+      sym_default_flag,
+    ACTIONS(384), 2,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+  [22112] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1079), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1908), 1,
+      anon_sym_LPAREN2,
+    STATE(102), 1,
+      sym_block,
+    STATE(654), 1,
+      sym_parameters,
+  [22128] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1910), 1,
+      anon_sym_COMMA,
+    STATE(564), 1,
+      aux_sym_at_rule_repeat1,
+    ACTIONS(1913), 2,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+  [22142] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1895), 1,
+      anon_sym_COMMA,
+    ACTIONS(1915), 1,
+      anon_sym_SEMI,
+    ACTIONS(1917), 1,
+      anon_sym_with,
+    STATE(569), 1,
+      aux_sym_forward_statement_repeat1,
+  [22158] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1919), 1,
+      anon_sym_SEMI,
+    STATE(704), 1,
+      sym_default_flag,
+    ACTIONS(384), 2,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+  [22172] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1075), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1921), 1,
+      sym_identifier,
+    ACTIONS(1923), 1,
+      anon_sym_DASH,
+    STATE(223), 1,
+      sym_interpolation,
+  [22188] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1925), 1,
+      anon_sym_RPAREN,
+    STATE(560), 1,
+      aux_sym_arguments_repeat1,
+    ACTIONS(996), 2,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+  [22202] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1895), 1,
+      anon_sym_COMMA,
+    ACTIONS(1927), 1,
+      anon_sym_SEMI,
+    ACTIONS(1929), 1,
+      anon_sym_with,
+    STATE(579), 1,
+      aux_sym_forward_statement_repeat1,
+  [22218] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1079), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1908), 1,
+      anon_sym_LPAREN2,
+    STATE(105), 1,
+      sym_block,
+    STATE(664), 1,
+      sym_parameters,
+  [22234] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1207), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1931), 1,
+      sym_identifier,
+    ACTIONS(1933), 1,
+      anon_sym_DASH,
+    STATE(366), 1,
+      sym_interpolation,
+  [22250] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1935), 1,
+      anon_sym_RPAREN,
+    STATE(560), 1,
+      aux_sym_arguments_repeat1,
+    ACTIONS(996), 2,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+  [22264] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1937), 1,
+      anon_sym_SEMI,
+    STATE(737), 1,
+      sym_default_flag,
+    ACTIONS(384), 2,
+      anon_sym_BANGdefault,
+      anon_sym_BANGglobal,
+  [22278] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1939), 1,
+      anon_sym_STAR,
+    STATE(640), 1,
+      sym__name,
+    ACTIONS(1941), 2,
+      sym_identifier,
+      sym_grit_metavariable,
+  [22292] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1111), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1908), 1,
+      anon_sym_LPAREN2,
+    STATE(128), 1,
+      sym_block,
+    STATE(632), 1,
+      sym_parameters,
+  [22308] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1943), 4,
+      anon_sym_RBRACE,
+      anon_sym_from,
+      anon_sym_to,
+      aux_sym_integer_value_token1,
+  [22318] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1945), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1947), 1,
+      sym_grit_metavariable,
+    ACTIONS(1949), 1,
+      sym_variable,
+    STATE(607), 1,
+      sym_parameter,
+  [22334] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(410), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1951), 1,
+      sym_identifier,
+    ACTIONS(1953), 1,
+      anon_sym_DASH,
+    STATE(183), 1,
+      sym_interpolation,
+  [22350] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1955), 1,
+      anon_sym_COMMA,
+    STATE(579), 1,
+      aux_sym_forward_statement_repeat1,
+    ACTIONS(1958), 2,
+      anon_sym_SEMI,
+      anon_sym_with,
+  [22364] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1111), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1908), 1,
+      anon_sym_LPAREN2,
+    STATE(131), 1,
+      sym_block,
+    STATE(653), 1,
+      sym_parameters,
+  [22380] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(484), 4,
+      anon_sym_RBRACE,
+      anon_sym_from,
+      anon_sym_to,
+      aux_sym_integer_value_token1,
+  [22390] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(69), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1522), 1,
+      sym_identifier,
+    STATE(225), 1,
+      sym_interpolation,
+    STATE(445), 1,
+      sym__concatenated_identifier,
+  [22406] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(69), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1960), 1,
+      sym_identifier,
+    STATE(225), 1,
+      sym_interpolation,
+    STATE(433), 1,
+      sym__concatenated_identifier,
+  [22422] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1111), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1815), 1,
+      anon_sym_COMMA,
+    STATE(170), 1,
+      sym_block,
+    STATE(595), 1,
+      aux_sym_media_statement_repeat1,
+  [22438] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1895), 1,
+      anon_sym_COMMA,
+    ACTIONS(1962), 1,
+      anon_sym_SEMI,
+    ACTIONS(1964), 1,
+      anon_sym_with,
+    STATE(579), 1,
+      aux_sym_forward_statement_repeat1,
+  [22454] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1966), 1,
+      anon_sym_COLON,
+    ACTIONS(1845), 3,
+      anon_sym_RPAREN,
+      anon_sym_and,
+      anon_sym_or,
+  [22466] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(69), 1,
+      anon_sym_POUND_LBRACE,
+    ACTIONS(1968), 1,
+      sym_identifier,
+    ACTIONS(1970), 1,
+      anon_sym_DASH,
+    STATE(244), 1,
+      sym_interpolation,
+  [22482] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(469), 4,
+      anon_sym_RBRACE,
+      anon_sym_from,
+      anon_sym_to,
+      aux_sym_integer_value_token1,
+  [22492] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1111), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1972), 1,
+      anon_sym_SEMI,
+    STATE(148), 1,
+      sym_block,
+  [22505] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1974), 1,
+      anon_sym_COMMA,
+    ACTIONS(1977), 1,
+      anon_sym_RPAREN,
+    STATE(590), 1,
+      aux_sym__include_arguments_repeat1,
+  [22518] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1043), 1,
+      anon_sym_COMMA,
+    ACTIONS(1979), 1,
+      anon_sym_RPAREN,
+    STATE(613), 1,
+      aux_sym_pseudo_class_arguments_repeat2,
+  [22531] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1981), 1,
+      anon_sym_COMMA,
+    ACTIONS(1984), 1,
+      anon_sym_RPAREN,
+    STATE(592), 1,
+      aux_sym_list_value_repeat1,
+  [22544] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1986), 1,
+      anon_sym_COMMA,
+    ACTIONS(1988), 1,
+      anon_sym_RPAREN,
+    STATE(592), 1,
+      aux_sym_list_value_repeat1,
+  [22557] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1674), 1,
+      anon_sym_COMMA,
+    ACTIONS(1990), 1,
+      anon_sym_LBRACE,
+    STATE(628), 1,
+      aux_sym_selectors_repeat1,
+  [22570] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1992), 1,
+      anon_sym_COMMA,
+    ACTIONS(1995), 1,
+      anon_sym_LBRACE,
+    STATE(595), 1,
+      aux_sym_media_statement_repeat1,
+  [22583] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1997), 1,
+      anon_sym_COMMA,
+    ACTIONS(1999), 1,
+      anon_sym_RPAREN,
+    STATE(616), 1,
+      aux_sym_map_value_repeat1,
+  [22596] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(449), 1,
+      sym__name,
+    ACTIONS(2001), 2,
+      sym_identifier,
+      sym_grit_metavariable,
+  [22607] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1043), 1,
+      anon_sym_COMMA,
+    ACTIONS(2003), 1,
+      anon_sym_RPAREN,
+    STATE(613), 1,
+      aux_sym_pseudo_class_arguments_repeat2,
+  [22620] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1043), 1,
+      anon_sym_COMMA,
+    ACTIONS(2005), 1,
+      anon_sym_RPAREN,
+    STATE(613), 1,
+      aux_sym_pseudo_class_arguments_repeat2,
+  [22633] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2007), 1,
+      anon_sym_COMMA,
+    ACTIONS(2010), 1,
+      anon_sym_SEMI,
+    STATE(600), 1,
+      aux_sym_import_statement_repeat1,
+  [22646] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2012), 1,
+      anon_sym_COMMA,
+    ACTIONS(2014), 1,
+      anon_sym_RPAREN,
+    STATE(604), 1,
+      aux_sym_map_value_repeat1,
+  [22659] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2016), 1,
+      anon_sym_COMMA,
+    ACTIONS(2019), 1,
+      anon_sym_RPAREN,
+    STATE(602), 1,
+      aux_sym_parameters_repeat1,
+  [22672] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2021), 1,
+      anon_sym_COMMA,
+    ACTIONS(2023), 1,
+      anon_sym_RPAREN,
+    STATE(592), 1,
+      aux_sym_list_value_repeat1,
+  [22685] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2025), 1,
+      anon_sym_COMMA,
+    ACTIONS(2027), 1,
+      anon_sym_RPAREN,
+    STATE(616), 1,
+      aux_sym_map_value_repeat1,
+  [22698] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1819), 1,
+      anon_sym_COMMA,
+    ACTIONS(2029), 1,
+      anon_sym_SEMI,
+    STATE(600), 1,
+      aux_sym_import_statement_repeat1,
+  [22711] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1111), 1,
+      anon_sym_LBRACE,
+    ACTIONS(2031), 1,
+      anon_sym_if,
+    STATE(157), 1,
+      sym_block,
+  [22724] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2033), 1,
+      anon_sym_COMMA,
+    ACTIONS(2035), 1,
+      anon_sym_RPAREN,
+    STATE(630), 1,
+      aux_sym_parameters_repeat1,
+  [22737] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1622), 1,
+      anon_sym_COMMA,
+    ACTIONS(2037), 1,
+      anon_sym_RPAREN,
+    STATE(590), 1,
+      aux_sym__include_arguments_repeat1,
+  [22750] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2041), 1,
+      anon_sym_COLON,
+    ACTIONS(2039), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [22761] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1949), 1,
+      sym_variable,
+    ACTIONS(2043), 1,
+      sym_grit_metavariable,
+    STATE(660), 1,
+      sym_parameter,
+  [22774] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2045), 1,
+      anon_sym_COMMA,
+    ACTIONS(2047), 1,
+      anon_sym_RPAREN,
+    STATE(614), 1,
+      aux_sym_map_value_repeat1,
+  [22787] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2049), 1,
+      anon_sym_COMMA,
+    ACTIONS(2051), 1,
+      anon_sym_RPAREN,
+    STATE(592), 1,
+      aux_sym_list_value_repeat1,
+  [22800] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2053), 1,
+      anon_sym_COMMA,
+    ACTIONS(2056), 1,
+      anon_sym_RPAREN,
+    STATE(613), 1,
+      aux_sym_pseudo_class_arguments_repeat2,
+  [22813] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2058), 1,
+      anon_sym_COMMA,
+    ACTIONS(2060), 1,
+      anon_sym_RPAREN,
+    STATE(616), 1,
+      aux_sym_map_value_repeat1,
+  [22826] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1622), 1,
+      anon_sym_COMMA,
+    ACTIONS(2062), 1,
+      anon_sym_RPAREN,
+    STATE(618), 1,
+      aux_sym__include_arguments_repeat1,
+  [22839] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2064), 1,
+      anon_sym_COMMA,
+    ACTIONS(2067), 1,
+      anon_sym_RPAREN,
+    STATE(616), 1,
+      aux_sym_map_value_repeat1,
+  [22852] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2069), 1,
+      anon_sym_COMMA,
+    ACTIONS(2071), 1,
+      anon_sym_RPAREN,
+    STATE(596), 1,
+      aux_sym_map_value_repeat1,
+  [22865] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1622), 1,
+      anon_sym_COMMA,
+    ACTIONS(2073), 1,
+      anon_sym_RPAREN,
+    STATE(590), 1,
+      aux_sym__include_arguments_repeat1,
+  [22878] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(563), 1,
+      sym__name,
+    ACTIONS(2075), 2,
+      sym_identifier,
+      sym_grit_metavariable,
+  [22889] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1043), 1,
+      anon_sym_COMMA,
+    ACTIONS(2077), 1,
+      anon_sym_RPAREN,
+    STATE(613), 1,
+      aux_sym_pseudo_class_arguments_repeat2,
+  [22902] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(575), 1,
+      sym__name,
+    ACTIONS(2079), 2,
+      sym_identifier,
+      sym_grit_metavariable,
+  [22913] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(544), 1,
+      sym__name,
+    ACTIONS(2081), 2,
+      sym_identifier,
+      sym_grit_metavariable,
+  [22924] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(580), 1,
+      sym__name,
+    ACTIONS(2083), 2,
+      sym_identifier,
+      sym_grit_metavariable,
+  [22935] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1079), 1,
+      anon_sym_LBRACE,
+    ACTIONS(2085), 1,
+      anon_sym_if,
+    STATE(67), 1,
+      sym_block,
+  [22948] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1079), 1,
+      anon_sym_LBRACE,
+    ACTIONS(2087), 1,
+      anon_sym_SEMI,
+    STATE(48), 1,
+      sym_block,
+  [22961] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1819), 1,
+      anon_sym_COMMA,
+    ACTIONS(2089), 1,
+      anon_sym_SEMI,
+    STATE(600), 1,
+      aux_sym_import_statement_repeat1,
+  [22974] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(546), 1,
+      sym__name,
+    ACTIONS(2091), 2,
+      sym_identifier,
+      sym_grit_metavariable,
+  [22985] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2093), 1,
+      anon_sym_COMMA,
+    ACTIONS(2096), 1,
+      anon_sym_LBRACE,
+    STATE(628), 1,
+      aux_sym_selectors_repeat1,
+  [22998] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2098), 3,
+      anon_sym_COMMA,
+      anon_sym_SEMI,
+      anon_sym_with,
+  [23007] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2033), 1,
+      anon_sym_COMMA,
+    ACTIONS(2100), 1,
+      anon_sym_RPAREN,
+    STATE(602), 1,
+      aux_sym_parameters_repeat1,
+  [23020] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(570), 1,
+      sym__name,
+    ACTIONS(2102), 2,
+      sym_identifier,
+      sym_grit_metavariable,
+  [23031] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1111), 1,
+      anon_sym_LBRACE,
+    STATE(146), 1,
+      sym_block,
+  [23041] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2104), 2,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+  [23049] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2106), 1,
+      anon_sym_SEMI,
+    ACTIONS(2108), 1,
+      anon_sym_RBRACE,
+  [23059] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2110), 1,
+      anon_sym_COMMA,
+    ACTIONS(2112), 1,
+      anon_sym_in,
+  [23069] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2114), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [23077] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2116), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [23085] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2118), 2,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+  [23093] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2120), 1,
+      anon_sym_SEMI,
+    ACTIONS(2122), 1,
+      anon_sym_with,
+  [23103] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2124), 1,
+      anon_sym_SEMI,
+    ACTIONS(2126), 1,
+      anon_sym_with,
+  [23113] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2128), 1,
+      anon_sym_SEMI,
+    ACTIONS(2130), 1,
+      anon_sym_RBRACE,
+  [23123] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2132), 1,
+      anon_sym_LPAREN2,
+    STATE(742), 1,
+      sym_map_value,
+  [23133] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2134), 2,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+  [23141] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2136), 2,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+  [23149] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2132), 1,
+      anon_sym_LPAREN2,
+    STATE(723), 1,
+      sym_map_value,
+  [23159] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2132), 1,
+      anon_sym_LPAREN2,
+    STATE(677), 1,
+      sym_map_value,
+  [23169] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2138), 1,
+      sym_identifier,
+    ACTIONS(2140), 1,
+      sym_variable,
+  [23179] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2142), 2,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+  [23187] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2144), 1,
+      aux_sym_color_value_token1,
+    ACTIONS(2146), 1,
+      sym_identifier,
+  [23197] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2148), 1,
+      sym_identifier,
+    ACTIONS(2150), 1,
+      sym_variable,
+  [23207] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2152), 1,
+      anon_sym_LBRACE,
+    STATE(56), 1,
+      sym_keyframe_block_list,
+  [23217] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(778), 1,
+      anon_sym_LPAREN,
+    STATE(200), 1,
+      sym_arguments,
+  [23227] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1111), 1,
+      anon_sym_LBRACE,
+    STATE(149), 1,
+      sym_block,
+  [23237] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1079), 1,
+      anon_sym_LBRACE,
+    STATE(46), 1,
+      sym_block,
+  [23247] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1111), 1,
+      anon_sym_LBRACE,
+    STATE(116), 1,
+      sym_block,
+  [23257] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(953), 1,
+      anon_sym_LPAREN,
+    STATE(232), 1,
+      sym_arguments,
+  [23267] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2146), 1,
+      sym_identifier,
+    ACTIONS(2154), 1,
+      aux_sym_color_value_token1,
+  [23277] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2132), 1,
+      anon_sym_LPAREN2,
+    STATE(698), 1,
+      sym_map_value,
+  [23287] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2156), 1,
+      anon_sym_SEMI,
+    ACTIONS(2158), 1,
+      anon_sym_RBRACE,
+  [23297] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2160), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [23305] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2132), 1,
+      anon_sym_LPAREN2,
+    STATE(719), 1,
+      sym_map_value,
+  [23315] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2162), 1,
+      anon_sym_LBRACE,
+    STATE(576), 1,
+      sym_block,
+  [23325] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1137), 1,
+      anon_sym_LPAREN,
+    STATE(381), 1,
+      sym_arguments,
+  [23335] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1079), 1,
+      anon_sym_LBRACE,
+    STATE(49), 1,
+      sym_block,
+  [23345] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2164), 1,
+      anon_sym_LBRACE,
+    STATE(124), 1,
+      sym_keyframe_block_list,
+  [23355] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2132), 1,
+      anon_sym_LPAREN2,
+    STATE(713), 1,
+      sym_map_value,
+  [23365] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1079), 1,
+      anon_sym_LBRACE,
+    STATE(91), 1,
+      sym_block,
+  [23375] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2132), 1,
+      anon_sym_LPAREN2,
+    STATE(678), 1,
+      sym_map_value,
+  [23385] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(842), 1,
+      anon_sym_LBRACE,
+    ACTIONS(2166), 1,
+      sym_unit,
+  [23395] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2168), 1,
+      sym_identifier,
+    ACTIONS(2170), 1,
+      sym_variable,
+  [23405] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2172), 2,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+  [23413] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2132), 1,
+      anon_sym_LPAREN2,
+    STATE(697), 1,
+      sym_map_value,
+  [23423] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2174), 1,
+      anon_sym_COMMA,
+    ACTIONS(2176), 1,
+      anon_sym_in,
+  [23433] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2178), 1,
+      anon_sym_SEMI,
+    ACTIONS(2180), 1,
+      anon_sym_RBRACE,
+  [23443] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2132), 1,
+      anon_sym_LPAREN2,
+    STATE(720), 1,
+      sym_map_value,
+  [23453] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2182), 1,
+      anon_sym_in,
+  [23460] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2184), 1,
+      anon_sym_SEMI,
+  [23467] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2186), 1,
+      anon_sym_SEMI,
+  [23474] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2188), 1,
+      anon_sym_RBRACE,
+  [23481] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2190), 1,
+      sym_identifier,
+  [23488] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2192), 1,
+      anon_sym_COLON,
+  [23495] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2194), 1,
+      ts_builtin_sym_end,
+  [23502] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2196), 1,
+      sym_identifier,
+  [23509] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2198), 1,
+      aux_sym_color_value_token1,
+  [23516] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2200), 1,
+      sym_identifier,
+  [23523] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2156), 1,
+      anon_sym_SEMI,
+  [23530] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2202), 1,
+      anon_sym_SEMI,
+  [23537] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2204), 1,
+      anon_sym_SEMI,
+  [23544] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2206), 1,
+      sym_identifier,
+  [23551] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2208), 1,
+      anon_sym_RBRACE,
+  [23558] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2210), 1,
+      sym_identifier,
+  [23565] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2212), 1,
+      anon_sym_RBRACE,
+  [23572] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2214), 1,
+      anon_sym_LBRACE,
+  [23579] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2216), 1,
+      aux_sym_color_value_token1,
+  [23586] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2218), 1,
+      anon_sym_RBRACE,
+  [23593] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2220), 1,
+      anon_sym_SEMI,
+  [23600] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2222), 1,
+      anon_sym_SEMI,
+  [23607] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2224), 1,
+      anon_sym_SEMI,
+  [23614] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2226), 1,
+      anon_sym_LBRACE,
+  [23621] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2228), 1,
+      anon_sym_RBRACE,
+  [23628] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2085), 1,
+      anon_sym_if,
+  [23635] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2230), 1,
+      anon_sym_RBRACE,
+  [23642] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2232), 1,
+      sym_identifier,
+  [23649] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2234), 1,
+      anon_sym_SEMI,
+  [23656] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2178), 1,
+      anon_sym_SEMI,
+  [23663] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2236), 1,
+      sym_variable,
+  [23670] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2238), 1,
+      sym_identifier,
+  [23677] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2240), 1,
+      sym_identifier,
+  [23684] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2106), 1,
+      anon_sym_SEMI,
+  [23691] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2128), 1,
+      anon_sym_SEMI,
+  [23698] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2242), 1,
+      anon_sym_SEMI,
+  [23705] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2244), 1,
+      anon_sym_LBRACE,
+  [23712] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2246), 1,
+      anon_sym_SEMI,
+  [23719] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2248), 1,
+      sym_identifier,
+  [23726] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2250), 1,
+      anon_sym_RBRACE,
+  [23733] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2252), 1,
+      anon_sym_COLON,
+  [23740] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2254), 1,
+      anon_sym_RBRACE,
+  [23747] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2256), 1,
+      aux_sym_color_value_token1,
+  [23754] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2258), 1,
+      anon_sym_SEMI,
+  [23761] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2260), 1,
+      anon_sym_SEMI,
+  [23768] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2262), 1,
+      anon_sym_SEMI,
+  [23775] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2264), 1,
+      sym_identifier,
+  [23782] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2266), 1,
+      anon_sym_SEMI,
+  [23789] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2268), 1,
+      anon_sym_LPAREN2,
+  [23796] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2270), 1,
+      anon_sym_RBRACE,
+  [23803] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2272), 1,
+      anon_sym_LBRACE,
+  [23810] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2274), 1,
+      sym_variable,
+  [23817] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2031), 1,
+      anon_sym_if,
+  [23824] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2276), 1,
+      anon_sym_SEMI,
+  [23831] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2278), 1,
+      anon_sym_LBRACE,
+  [23838] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2280), 1,
+      sym_variable,
+  [23845] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2282), 1,
+      anon_sym_from,
+  [23852] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2284), 1,
+      anon_sym_SEMI,
+  [23859] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2286), 1,
+      anon_sym_SEMI,
+  [23866] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2288), 1,
+      anon_sym_COLON,
+  [23873] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2290), 1,
+      anon_sym_RBRACE,
+  [23880] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2292), 1,
+      anon_sym_SEMI,
+  [23887] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2294), 1,
+      anon_sym_in,
+  [23894] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2296), 1,
+      anon_sym_STAR2,
+  [23901] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2298), 1,
+      sym_variable,
+  [23908] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2300), 1,
+      sym_variable,
+  [23915] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2302), 1,
+      anon_sym_SEMI,
+  [23922] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2304), 1,
+      anon_sym_from,
+  [23929] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2306), 1,
+      sym_variable,
+  [23936] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(2308), 1,
+      sym_identifier,
+};
+
+static const uint32_t ts_small_parse_table_map[] = {
+  [SMALL_STATE(15)] = 0,
+  [SMALL_STATE(16)] = 103,
+  [SMALL_STATE(17)] = 203,
+  [SMALL_STATE(18)] = 303,
+  [SMALL_STATE(19)] = 396,
+  [SMALL_STATE(20)] = 489,
+  [SMALL_STATE(21)] = 579,
+  [SMALL_STATE(22)] = 636,
+  [SMALL_STATE(23)] = 691,
+  [SMALL_STATE(24)] = 748,
+  [SMALL_STATE(25)] = 837,
+  [SMALL_STATE(26)] = 926,
+  [SMALL_STATE(27)] = 980,
+  [SMALL_STATE(28)] = 1035,
+  [SMALL_STATE(29)] = 1090,
+  [SMALL_STATE(30)] = 1136,
+  [SMALL_STATE(31)] = 1188,
+  [SMALL_STATE(32)] = 1234,
+  [SMALL_STATE(33)] = 1280,
+  [SMALL_STATE(34)] = 1326,
+  [SMALL_STATE(35)] = 1372,
+  [SMALL_STATE(36)] = 1418,
+  [SMALL_STATE(37)] = 1463,
+  [SMALL_STATE(38)] = 1508,
+  [SMALL_STATE(39)] = 1553,
+  [SMALL_STATE(40)] = 1598,
+  [SMALL_STATE(41)] = 1643,
+  [SMALL_STATE(42)] = 1688,
+  [SMALL_STATE(43)] = 1733,
+  [SMALL_STATE(44)] = 1778,
+  [SMALL_STATE(45)] = 1823,
+  [SMALL_STATE(46)] = 1868,
+  [SMALL_STATE(47)] = 1913,
+  [SMALL_STATE(48)] = 1958,
+  [SMALL_STATE(49)] = 2003,
+  [SMALL_STATE(50)] = 2048,
+  [SMALL_STATE(51)] = 2093,
+  [SMALL_STATE(52)] = 2138,
+  [SMALL_STATE(53)] = 2183,
+  [SMALL_STATE(54)] = 2228,
+  [SMALL_STATE(55)] = 2273,
+  [SMALL_STATE(56)] = 2318,
+  [SMALL_STATE(57)] = 2363,
+  [SMALL_STATE(58)] = 2408,
+  [SMALL_STATE(59)] = 2453,
+  [SMALL_STATE(60)] = 2498,
+  [SMALL_STATE(61)] = 2543,
+  [SMALL_STATE(62)] = 2588,
+  [SMALL_STATE(63)] = 2633,
+  [SMALL_STATE(64)] = 2678,
+  [SMALL_STATE(65)] = 2723,
+  [SMALL_STATE(66)] = 2768,
+  [SMALL_STATE(67)] = 2813,
+  [SMALL_STATE(68)] = 2858,
+  [SMALL_STATE(69)] = 2903,
+  [SMALL_STATE(70)] = 2948,
+  [SMALL_STATE(71)] = 2993,
+  [SMALL_STATE(72)] = 3038,
+  [SMALL_STATE(73)] = 3083,
+  [SMALL_STATE(74)] = 3128,
+  [SMALL_STATE(75)] = 3173,
+  [SMALL_STATE(76)] = 3218,
+  [SMALL_STATE(77)] = 3263,
+  [SMALL_STATE(78)] = 3308,
+  [SMALL_STATE(79)] = 3353,
+  [SMALL_STATE(80)] = 3398,
+  [SMALL_STATE(81)] = 3443,
+  [SMALL_STATE(82)] = 3488,
+  [SMALL_STATE(83)] = 3533,
+  [SMALL_STATE(84)] = 3578,
+  [SMALL_STATE(85)] = 3623,
+  [SMALL_STATE(86)] = 3668,
+  [SMALL_STATE(87)] = 3713,
+  [SMALL_STATE(88)] = 3758,
+  [SMALL_STATE(89)] = 3803,
+  [SMALL_STATE(90)] = 3848,
+  [SMALL_STATE(91)] = 3893,
+  [SMALL_STATE(92)] = 3938,
+  [SMALL_STATE(93)] = 3983,
+  [SMALL_STATE(94)] = 4028,
+  [SMALL_STATE(95)] = 4073,
+  [SMALL_STATE(96)] = 4118,
+  [SMALL_STATE(97)] = 4163,
+  [SMALL_STATE(98)] = 4208,
+  [SMALL_STATE(99)] = 4253,
+  [SMALL_STATE(100)] = 4298,
+  [SMALL_STATE(101)] = 4343,
+  [SMALL_STATE(102)] = 4388,
+  [SMALL_STATE(103)] = 4433,
+  [SMALL_STATE(104)] = 4478,
+  [SMALL_STATE(105)] = 4523,
+  [SMALL_STATE(106)] = 4568,
+  [SMALL_STATE(107)] = 4613,
+  [SMALL_STATE(108)] = 4658,
+  [SMALL_STATE(109)] = 4703,
+  [SMALL_STATE(110)] = 4747,
+  [SMALL_STATE(111)] = 4791,
+  [SMALL_STATE(112)] = 4835,
+  [SMALL_STATE(113)] = 4879,
+  [SMALL_STATE(114)] = 4935,
+  [SMALL_STATE(115)] = 4979,
+  [SMALL_STATE(116)] = 5023,
+  [SMALL_STATE(117)] = 5066,
+  [SMALL_STATE(118)] = 5109,
+  [SMALL_STATE(119)] = 5152,
+  [SMALL_STATE(120)] = 5195,
+  [SMALL_STATE(121)] = 5238,
+  [SMALL_STATE(122)] = 5281,
+  [SMALL_STATE(123)] = 5324,
+  [SMALL_STATE(124)] = 5367,
+  [SMALL_STATE(125)] = 5410,
+  [SMALL_STATE(126)] = 5453,
+  [SMALL_STATE(127)] = 5496,
+  [SMALL_STATE(128)] = 5539,
+  [SMALL_STATE(129)] = 5582,
+  [SMALL_STATE(130)] = 5625,
+  [SMALL_STATE(131)] = 5668,
+  [SMALL_STATE(132)] = 5711,
+  [SMALL_STATE(133)] = 5754,
+  [SMALL_STATE(134)] = 5797,
+  [SMALL_STATE(135)] = 5840,
+  [SMALL_STATE(136)] = 5883,
+  [SMALL_STATE(137)] = 5926,
+  [SMALL_STATE(138)] = 5969,
+  [SMALL_STATE(139)] = 6012,
+  [SMALL_STATE(140)] = 6055,
+  [SMALL_STATE(141)] = 6098,
+  [SMALL_STATE(142)] = 6141,
+  [SMALL_STATE(143)] = 6184,
+  [SMALL_STATE(144)] = 6227,
+  [SMALL_STATE(145)] = 6270,
+  [SMALL_STATE(146)] = 6313,
+  [SMALL_STATE(147)] = 6356,
+  [SMALL_STATE(148)] = 6399,
+  [SMALL_STATE(149)] = 6442,
+  [SMALL_STATE(150)] = 6485,
+  [SMALL_STATE(151)] = 6528,
+  [SMALL_STATE(152)] = 6571,
+  [SMALL_STATE(153)] = 6614,
+  [SMALL_STATE(154)] = 6657,
+  [SMALL_STATE(155)] = 6700,
+  [SMALL_STATE(156)] = 6743,
+  [SMALL_STATE(157)] = 6786,
+  [SMALL_STATE(158)] = 6829,
+  [SMALL_STATE(159)] = 6872,
+  [SMALL_STATE(160)] = 6915,
+  [SMALL_STATE(161)] = 6958,
+  [SMALL_STATE(162)] = 7001,
+  [SMALL_STATE(163)] = 7044,
+  [SMALL_STATE(164)] = 7087,
+  [SMALL_STATE(165)] = 7130,
+  [SMALL_STATE(166)] = 7173,
+  [SMALL_STATE(167)] = 7224,
+  [SMALL_STATE(168)] = 7267,
+  [SMALL_STATE(169)] = 7310,
+  [SMALL_STATE(170)] = 7353,
+  [SMALL_STATE(171)] = 7396,
+  [SMALL_STATE(172)] = 7450,
+  [SMALL_STATE(173)] = 7496,
+  [SMALL_STATE(174)] = 7542,
+  [SMALL_STATE(175)] = 7588,
+  [SMALL_STATE(176)] = 7629,
+  [SMALL_STATE(177)] = 7674,
+  [SMALL_STATE(178)] = 7717,
+  [SMALL_STATE(179)] = 7760,
+  [SMALL_STATE(180)] = 7803,
+  [SMALL_STATE(181)] = 7846,
+  [SMALL_STATE(182)] = 7891,
+  [SMALL_STATE(183)] = 7936,
+  [SMALL_STATE(184)] = 7977,
+  [SMALL_STATE(185)] = 8022,
+  [SMALL_STATE(186)] = 8078,
+  [SMALL_STATE(187)] = 8122,
+  [SMALL_STATE(188)] = 8166,
+  [SMALL_STATE(189)] = 8208,
+  [SMALL_STATE(190)] = 8248,
+  [SMALL_STATE(191)] = 8290,
+  [SMALL_STATE(192)] = 8330,
+  [SMALL_STATE(193)] = 8386,
+  [SMALL_STATE(194)] = 8425,
+  [SMALL_STATE(195)] = 8498,
+  [SMALL_STATE(196)] = 8537,
+  [SMALL_STATE(197)] = 8576,
+  [SMALL_STATE(198)] = 8615,
+  [SMALL_STATE(199)] = 8654,
+  [SMALL_STATE(200)] = 8693,
+  [SMALL_STATE(201)] = 8732,
+  [SMALL_STATE(202)] = 8771,
+  [SMALL_STATE(203)] = 8810,
+  [SMALL_STATE(204)] = 8849,
+  [SMALL_STATE(205)] = 8888,
+  [SMALL_STATE(206)] = 8927,
+  [SMALL_STATE(207)] = 8966,
+  [SMALL_STATE(208)] = 9005,
+  [SMALL_STATE(209)] = 9069,
+  [SMALL_STATE(210)] = 9139,
+  [SMALL_STATE(211)] = 9185,
+  [SMALL_STATE(212)] = 9255,
+  [SMALL_STATE(213)] = 9320,
+  [SMALL_STATE(214)] = 9373,
+  [SMALL_STATE(215)] = 9438,
+  [SMALL_STATE(216)] = 9478,
+  [SMALL_STATE(217)] = 9518,
+  [SMALL_STATE(218)] = 9582,
+  [SMALL_STATE(219)] = 9622,
+  [SMALL_STATE(220)] = 9686,
+  [SMALL_STATE(221)] = 9726,
+  [SMALL_STATE(222)] = 9790,
+  [SMALL_STATE(223)] = 9829,
+  [SMALL_STATE(224)] = 9864,
+  [SMALL_STATE(225)] = 9903,
+  [SMALL_STATE(226)] = 9942,
+  [SMALL_STATE(227)] = 10001,
+  [SMALL_STATE(228)] = 10064,
+  [SMALL_STATE(229)] = 10127,
+  [SMALL_STATE(230)] = 10186,
+  [SMALL_STATE(231)] = 10221,
+  [SMALL_STATE(232)] = 10279,
+  [SMALL_STATE(233)] = 10313,
+  [SMALL_STATE(234)] = 10371,
+  [SMALL_STATE(235)] = 10429,
+  [SMALL_STATE(236)] = 10463,
+  [SMALL_STATE(237)] = 10497,
+  [SMALL_STATE(238)] = 10531,
+  [SMALL_STATE(239)] = 10565,
+  [SMALL_STATE(240)] = 10623,
+  [SMALL_STATE(241)] = 10657,
+  [SMALL_STATE(242)] = 10691,
+  [SMALL_STATE(243)] = 10725,
+  [SMALL_STATE(244)] = 10783,
+  [SMALL_STATE(245)] = 10817,
+  [SMALL_STATE(246)] = 10851,
+  [SMALL_STATE(247)] = 10909,
+  [SMALL_STATE(248)] = 10943,
+  [SMALL_STATE(249)] = 10977,
+  [SMALL_STATE(250)] = 11011,
+  [SMALL_STATE(251)] = 11045,
+  [SMALL_STATE(252)] = 11103,
+  [SMALL_STATE(253)] = 11149,
+  [SMALL_STATE(254)] = 11195,
+  [SMALL_STATE(255)] = 11229,
+  [SMALL_STATE(256)] = 11263,
+  [SMALL_STATE(257)] = 11320,
+  [SMALL_STATE(258)] = 11377,
+  [SMALL_STATE(259)] = 11434,
+  [SMALL_STATE(260)] = 11491,
+  [SMALL_STATE(261)] = 11550,
+  [SMALL_STATE(262)] = 11607,
+  [SMALL_STATE(263)] = 11664,
+  [SMALL_STATE(264)] = 11721,
+  [SMALL_STATE(265)] = 11778,
+  [SMALL_STATE(266)] = 11835,
+  [SMALL_STATE(267)] = 11892,
+  [SMALL_STATE(268)] = 11949,
+  [SMALL_STATE(269)] = 12006,
+  [SMALL_STATE(270)] = 12060,
+  [SMALL_STATE(271)] = 12114,
+  [SMALL_STATE(272)] = 12168,
+  [SMALL_STATE(273)] = 12222,
+  [SMALL_STATE(274)] = 12278,
+  [SMALL_STATE(275)] = 12332,
+  [SMALL_STATE(276)] = 12386,
+  [SMALL_STATE(277)] = 12440,
+  [SMALL_STATE(278)] = 12494,
+  [SMALL_STATE(279)] = 12548,
+  [SMALL_STATE(280)] = 12588,
+  [SMALL_STATE(281)] = 12642,
+  [SMALL_STATE(282)] = 12693,
+  [SMALL_STATE(283)] = 12744,
+  [SMALL_STATE(284)] = 12795,
+  [SMALL_STATE(285)] = 12846,
+  [SMALL_STATE(286)] = 12897,
+  [SMALL_STATE(287)] = 12948,
+  [SMALL_STATE(288)] = 12999,
+  [SMALL_STATE(289)] = 13050,
+  [SMALL_STATE(290)] = 13101,
+  [SMALL_STATE(291)] = 13152,
+  [SMALL_STATE(292)] = 13203,
+  [SMALL_STATE(293)] = 13254,
+  [SMALL_STATE(294)] = 13305,
+  [SMALL_STATE(295)] = 13356,
+  [SMALL_STATE(296)] = 13407,
+  [SMALL_STATE(297)] = 13458,
+  [SMALL_STATE(298)] = 13509,
+  [SMALL_STATE(299)] = 13560,
+  [SMALL_STATE(300)] = 13611,
+  [SMALL_STATE(301)] = 13662,
+  [SMALL_STATE(302)] = 13713,
+  [SMALL_STATE(303)] = 13764,
+  [SMALL_STATE(304)] = 13815,
+  [SMALL_STATE(305)] = 13866,
+  [SMALL_STATE(306)] = 13917,
+  [SMALL_STATE(307)] = 13968,
+  [SMALL_STATE(308)] = 14019,
+  [SMALL_STATE(309)] = 14070,
+  [SMALL_STATE(310)] = 14121,
+  [SMALL_STATE(311)] = 14172,
+  [SMALL_STATE(312)] = 14223,
+  [SMALL_STATE(313)] = 14274,
+  [SMALL_STATE(314)] = 14325,
+  [SMALL_STATE(315)] = 14376,
+  [SMALL_STATE(316)] = 14427,
+  [SMALL_STATE(317)] = 14478,
+  [SMALL_STATE(318)] = 14529,
+  [SMALL_STATE(319)] = 14580,
+  [SMALL_STATE(320)] = 14631,
+  [SMALL_STATE(321)] = 14682,
+  [SMALL_STATE(322)] = 14733,
+  [SMALL_STATE(323)] = 14784,
+  [SMALL_STATE(324)] = 14835,
+  [SMALL_STATE(325)] = 14886,
+  [SMALL_STATE(326)] = 14937,
+  [SMALL_STATE(327)] = 14988,
+  [SMALL_STATE(328)] = 15039,
+  [SMALL_STATE(329)] = 15090,
+  [SMALL_STATE(330)] = 15141,
+  [SMALL_STATE(331)] = 15192,
+  [SMALL_STATE(332)] = 15243,
+  [SMALL_STATE(333)] = 15294,
+  [SMALL_STATE(334)] = 15345,
+  [SMALL_STATE(335)] = 15396,
+  [SMALL_STATE(336)] = 15447,
+  [SMALL_STATE(337)] = 15498,
+  [SMALL_STATE(338)] = 15549,
+  [SMALL_STATE(339)] = 15600,
+  [SMALL_STATE(340)] = 15651,
+  [SMALL_STATE(341)] = 15702,
+  [SMALL_STATE(342)] = 15753,
+  [SMALL_STATE(343)] = 15804,
+  [SMALL_STATE(344)] = 15855,
+  [SMALL_STATE(345)] = 15906,
+  [SMALL_STATE(346)] = 15957,
+  [SMALL_STATE(347)] = 16008,
+  [SMALL_STATE(348)] = 16059,
+  [SMALL_STATE(349)] = 16110,
+  [SMALL_STATE(350)] = 16144,
+  [SMALL_STATE(351)] = 16178,
+  [SMALL_STATE(352)] = 16212,
+  [SMALL_STATE(353)] = 16246,
+  [SMALL_STATE(354)] = 16278,
+  [SMALL_STATE(355)] = 16310,
+  [SMALL_STATE(356)] = 16342,
+  [SMALL_STATE(357)] = 16374,
+  [SMALL_STATE(358)] = 16408,
+  [SMALL_STATE(359)] = 16442,
+  [SMALL_STATE(360)] = 16474,
+  [SMALL_STATE(361)] = 16506,
+  [SMALL_STATE(362)] = 16538,
+  [SMALL_STATE(363)] = 16570,
+  [SMALL_STATE(364)] = 16601,
+  [SMALL_STATE(365)] = 16630,
+  [SMALL_STATE(366)] = 16661,
+  [SMALL_STATE(367)] = 16690,
+  [SMALL_STATE(368)] = 16719,
+  [SMALL_STATE(369)] = 16750,
+  [SMALL_STATE(370)] = 16781,
+  [SMALL_STATE(371)] = 16809,
+  [SMALL_STATE(372)] = 16837,
+  [SMALL_STATE(373)] = 16865,
+  [SMALL_STATE(374)] = 16893,
+  [SMALL_STATE(375)] = 16921,
+  [SMALL_STATE(376)] = 16949,
+  [SMALL_STATE(377)] = 16977,
+  [SMALL_STATE(378)] = 17005,
+  [SMALL_STATE(379)] = 17033,
+  [SMALL_STATE(380)] = 17061,
+  [SMALL_STATE(381)] = 17089,
+  [SMALL_STATE(382)] = 17117,
+  [SMALL_STATE(383)] = 17145,
+  [SMALL_STATE(384)] = 17174,
+  [SMALL_STATE(385)] = 17221,
+  [SMALL_STATE(386)] = 17250,
+  [SMALL_STATE(387)] = 17279,
+  [SMALL_STATE(388)] = 17308,
+  [SMALL_STATE(389)] = 17336,
+  [SMALL_STATE(390)] = 17364,
+  [SMALL_STATE(391)] = 17410,
+  [SMALL_STATE(392)] = 17456,
+  [SMALL_STATE(393)] = 17502,
+  [SMALL_STATE(394)] = 17531,
+  [SMALL_STATE(395)] = 17572,
+  [SMALL_STATE(396)] = 17615,
+  [SMALL_STATE(397)] = 17644,
+  [SMALL_STATE(398)] = 17685,
+  [SMALL_STATE(399)] = 17712,
+  [SMALL_STATE(400)] = 17739,
+  [SMALL_STATE(401)] = 17766,
+  [SMALL_STATE(402)] = 17793,
+  [SMALL_STATE(403)] = 17826,
+  [SMALL_STATE(404)] = 17866,
+  [SMALL_STATE(405)] = 17906,
+  [SMALL_STATE(406)] = 17946,
+  [SMALL_STATE(407)] = 17986,
+  [SMALL_STATE(408)] = 18012,
+  [SMALL_STATE(409)] = 18052,
+  [SMALL_STATE(410)] = 18092,
+  [SMALL_STATE(411)] = 18132,
+  [SMALL_STATE(412)] = 18172,
+  [SMALL_STATE(413)] = 18198,
+  [SMALL_STATE(414)] = 18238,
+  [SMALL_STATE(415)] = 18278,
+  [SMALL_STATE(416)] = 18318,
+  [SMALL_STATE(417)] = 18356,
+  [SMALL_STATE(418)] = 18394,
+  [SMALL_STATE(419)] = 18432,
+  [SMALL_STATE(420)] = 18472,
+  [SMALL_STATE(421)] = 18512,
+  [SMALL_STATE(422)] = 18552,
+  [SMALL_STATE(423)] = 18578,
+  [SMALL_STATE(424)] = 18604,
+  [SMALL_STATE(425)] = 18630,
+  [SMALL_STATE(426)] = 18656,
+  [SMALL_STATE(427)] = 18696,
+  [SMALL_STATE(428)] = 18734,
+  [SMALL_STATE(429)] = 18772,
+  [SMALL_STATE(430)] = 18810,
+  [SMALL_STATE(431)] = 18850,
+  [SMALL_STATE(432)] = 18888,
+  [SMALL_STATE(433)] = 18925,
+  [SMALL_STATE(434)] = 18948,
+  [SMALL_STATE(435)] = 18973,
+  [SMALL_STATE(436)] = 19000,
+  [SMALL_STATE(437)] = 19025,
+  [SMALL_STATE(438)] = 19062,
+  [SMALL_STATE(439)] = 19099,
+  [SMALL_STATE(440)] = 19126,
+  [SMALL_STATE(441)] = 19163,
+  [SMALL_STATE(442)] = 19200,
+  [SMALL_STATE(443)] = 19237,
+  [SMALL_STATE(444)] = 19274,
+  [SMALL_STATE(445)] = 19311,
+  [SMALL_STATE(446)] = 19334,
+  [SMALL_STATE(447)] = 19361,
+  [SMALL_STATE(448)] = 19388,
+  [SMALL_STATE(449)] = 19425,
+  [SMALL_STATE(450)] = 19448,
+  [SMALL_STATE(451)] = 19473,
+  [SMALL_STATE(452)] = 19510,
+  [SMALL_STATE(453)] = 19547,
+  [SMALL_STATE(454)] = 19584,
+  [SMALL_STATE(455)] = 19621,
+  [SMALL_STATE(456)] = 19658,
+  [SMALL_STATE(457)] = 19695,
+  [SMALL_STATE(458)] = 19732,
+  [SMALL_STATE(459)] = 19769,
+  [SMALL_STATE(460)] = 19806,
+  [SMALL_STATE(461)] = 19833,
+  [SMALL_STATE(462)] = 19870,
+  [SMALL_STATE(463)] = 19899,
+  [SMALL_STATE(464)] = 19931,
+  [SMALL_STATE(465)] = 19963,
+  [SMALL_STATE(466)] = 19995,
+  [SMALL_STATE(467)] = 20027,
+  [SMALL_STATE(468)] = 20059,
+  [SMALL_STATE(469)] = 20091,
+  [SMALL_STATE(470)] = 20123,
+  [SMALL_STATE(471)] = 20149,
+  [SMALL_STATE(472)] = 20173,
+  [SMALL_STATE(473)] = 20205,
+  [SMALL_STATE(474)] = 20237,
+  [SMALL_STATE(475)] = 20269,
+  [SMALL_STATE(476)] = 20295,
+  [SMALL_STATE(477)] = 20321,
+  [SMALL_STATE(478)] = 20342,
+  [SMALL_STATE(479)] = 20363,
+  [SMALL_STATE(480)] = 20384,
+  [SMALL_STATE(481)] = 20405,
+  [SMALL_STATE(482)] = 20446,
+  [SMALL_STATE(483)] = 20467,
+  [SMALL_STATE(484)] = 20488,
+  [SMALL_STATE(485)] = 20529,
+  [SMALL_STATE(486)] = 20550,
+  [SMALL_STATE(487)] = 20571,
+  [SMALL_STATE(488)] = 20592,
+  [SMALL_STATE(489)] = 20613,
+  [SMALL_STATE(490)] = 20634,
+  [SMALL_STATE(491)] = 20675,
+  [SMALL_STATE(492)] = 20698,
+  [SMALL_STATE(493)] = 20721,
+  [SMALL_STATE(494)] = 20742,
+  [SMALL_STATE(495)] = 20763,
+  [SMALL_STATE(496)] = 20784,
+  [SMALL_STATE(497)] = 20807,
+  [SMALL_STATE(498)] = 20828,
+  [SMALL_STATE(499)] = 20849,
+  [SMALL_STATE(500)] = 20870,
+  [SMALL_STATE(501)] = 20891,
+  [SMALL_STATE(502)] = 20912,
+  [SMALL_STATE(503)] = 20933,
+  [SMALL_STATE(504)] = 20956,
+  [SMALL_STATE(505)] = 20976,
+  [SMALL_STATE(506)] = 21012,
+  [SMALL_STATE(507)] = 21032,
+  [SMALL_STATE(508)] = 21052,
+  [SMALL_STATE(509)] = 21072,
+  [SMALL_STATE(510)] = 21096,
+  [SMALL_STATE(511)] = 21132,
+  [SMALL_STATE(512)] = 21152,
+  [SMALL_STATE(513)] = 21176,
+  [SMALL_STATE(514)] = 21211,
+  [SMALL_STATE(515)] = 21243,
+  [SMALL_STATE(516)] = 21268,
+  [SMALL_STATE(517)] = 21293,
+  [SMALL_STATE(518)] = 21322,
+  [SMALL_STATE(519)] = 21351,
+  [SMALL_STATE(520)] = 21376,
+  [SMALL_STATE(521)] = 21401,
+  [SMALL_STATE(522)] = 21426,
+  [SMALL_STATE(523)] = 21442,
+  [SMALL_STATE(524)] = 21458,
+  [SMALL_STATE(525)] = 21484,
+  [SMALL_STATE(526)] = 21510,
+  [SMALL_STATE(527)] = 21531,
+  [SMALL_STATE(528)] = 21554,
+  [SMALL_STATE(529)] = 21569,
+  [SMALL_STATE(530)] = 21584,
+  [SMALL_STATE(531)] = 21599,
+  [SMALL_STATE(532)] = 21618,
+  [SMALL_STATE(533)] = 21639,
+  [SMALL_STATE(534)] = 21662,
+  [SMALL_STATE(535)] = 21677,
+  [SMALL_STATE(536)] = 21689,
+  [SMALL_STATE(537)] = 21707,
+  [SMALL_STATE(538)] = 21727,
+  [SMALL_STATE(539)] = 21739,
+  [SMALL_STATE(540)] = 21751,
+  [SMALL_STATE(541)] = 21771,
+  [SMALL_STATE(542)] = 21783,
+  [SMALL_STATE(543)] = 21801,
+  [SMALL_STATE(544)] = 21813,
+  [SMALL_STATE(545)] = 21832,
+  [SMALL_STATE(546)] = 21849,
+  [SMALL_STATE(547)] = 21868,
+  [SMALL_STATE(548)] = 21885,
+  [SMALL_STATE(549)] = 21902,
+  [SMALL_STATE(550)] = 21921,
+  [SMALL_STATE(551)] = 21938,
+  [SMALL_STATE(552)] = 21955,
+  [SMALL_STATE(553)] = 21974,
+  [SMALL_STATE(554)] = 21990,
+  [SMALL_STATE(555)] = 22004,
+  [SMALL_STATE(556)] = 22014,
+  [SMALL_STATE(557)] = 22030,
+  [SMALL_STATE(558)] = 22040,
+  [SMALL_STATE(559)] = 22054,
+  [SMALL_STATE(560)] = 22070,
+  [SMALL_STATE(561)] = 22084,
+  [SMALL_STATE(562)] = 22098,
+  [SMALL_STATE(563)] = 22112,
+  [SMALL_STATE(564)] = 22128,
+  [SMALL_STATE(565)] = 22142,
+  [SMALL_STATE(566)] = 22158,
+  [SMALL_STATE(567)] = 22172,
+  [SMALL_STATE(568)] = 22188,
+  [SMALL_STATE(569)] = 22202,
+  [SMALL_STATE(570)] = 22218,
+  [SMALL_STATE(571)] = 22234,
+  [SMALL_STATE(572)] = 22250,
+  [SMALL_STATE(573)] = 22264,
+  [SMALL_STATE(574)] = 22278,
+  [SMALL_STATE(575)] = 22292,
+  [SMALL_STATE(576)] = 22308,
+  [SMALL_STATE(577)] = 22318,
+  [SMALL_STATE(578)] = 22334,
+  [SMALL_STATE(579)] = 22350,
+  [SMALL_STATE(580)] = 22364,
+  [SMALL_STATE(581)] = 22380,
+  [SMALL_STATE(582)] = 22390,
+  [SMALL_STATE(583)] = 22406,
+  [SMALL_STATE(584)] = 22422,
+  [SMALL_STATE(585)] = 22438,
+  [SMALL_STATE(586)] = 22454,
+  [SMALL_STATE(587)] = 22466,
+  [SMALL_STATE(588)] = 22482,
+  [SMALL_STATE(589)] = 22492,
+  [SMALL_STATE(590)] = 22505,
+  [SMALL_STATE(591)] = 22518,
+  [SMALL_STATE(592)] = 22531,
+  [SMALL_STATE(593)] = 22544,
+  [SMALL_STATE(594)] = 22557,
+  [SMALL_STATE(595)] = 22570,
+  [SMALL_STATE(596)] = 22583,
+  [SMALL_STATE(597)] = 22596,
+  [SMALL_STATE(598)] = 22607,
+  [SMALL_STATE(599)] = 22620,
+  [SMALL_STATE(600)] = 22633,
+  [SMALL_STATE(601)] = 22646,
+  [SMALL_STATE(602)] = 22659,
+  [SMALL_STATE(603)] = 22672,
+  [SMALL_STATE(604)] = 22685,
+  [SMALL_STATE(605)] = 22698,
+  [SMALL_STATE(606)] = 22711,
+  [SMALL_STATE(607)] = 22724,
+  [SMALL_STATE(608)] = 22737,
+  [SMALL_STATE(609)] = 22750,
+  [SMALL_STATE(610)] = 22761,
+  [SMALL_STATE(611)] = 22774,
+  [SMALL_STATE(612)] = 22787,
+  [SMALL_STATE(613)] = 22800,
+  [SMALL_STATE(614)] = 22813,
+  [SMALL_STATE(615)] = 22826,
+  [SMALL_STATE(616)] = 22839,
+  [SMALL_STATE(617)] = 22852,
+  [SMALL_STATE(618)] = 22865,
+  [SMALL_STATE(619)] = 22878,
+  [SMALL_STATE(620)] = 22889,
+  [SMALL_STATE(621)] = 22902,
+  [SMALL_STATE(622)] = 22913,
+  [SMALL_STATE(623)] = 22924,
+  [SMALL_STATE(624)] = 22935,
+  [SMALL_STATE(625)] = 22948,
+  [SMALL_STATE(626)] = 22961,
+  [SMALL_STATE(627)] = 22974,
+  [SMALL_STATE(628)] = 22985,
+  [SMALL_STATE(629)] = 22998,
+  [SMALL_STATE(630)] = 23007,
+  [SMALL_STATE(631)] = 23020,
+  [SMALL_STATE(632)] = 23031,
+  [SMALL_STATE(633)] = 23041,
+  [SMALL_STATE(634)] = 23049,
+  [SMALL_STATE(635)] = 23059,
+  [SMALL_STATE(636)] = 23069,
+  [SMALL_STATE(637)] = 23077,
+  [SMALL_STATE(638)] = 23085,
+  [SMALL_STATE(639)] = 23093,
+  [SMALL_STATE(640)] = 23103,
+  [SMALL_STATE(641)] = 23113,
+  [SMALL_STATE(642)] = 23123,
+  [SMALL_STATE(643)] = 23133,
+  [SMALL_STATE(644)] = 23141,
+  [SMALL_STATE(645)] = 23149,
+  [SMALL_STATE(646)] = 23159,
+  [SMALL_STATE(647)] = 23169,
+  [SMALL_STATE(648)] = 23179,
+  [SMALL_STATE(649)] = 23187,
+  [SMALL_STATE(650)] = 23197,
+  [SMALL_STATE(651)] = 23207,
+  [SMALL_STATE(652)] = 23217,
+  [SMALL_STATE(653)] = 23227,
+  [SMALL_STATE(654)] = 23237,
+  [SMALL_STATE(655)] = 23247,
+  [SMALL_STATE(656)] = 23257,
+  [SMALL_STATE(657)] = 23267,
+  [SMALL_STATE(658)] = 23277,
+  [SMALL_STATE(659)] = 23287,
+  [SMALL_STATE(660)] = 23297,
+  [SMALL_STATE(661)] = 23305,
+  [SMALL_STATE(662)] = 23315,
+  [SMALL_STATE(663)] = 23325,
+  [SMALL_STATE(664)] = 23335,
+  [SMALL_STATE(665)] = 23345,
+  [SMALL_STATE(666)] = 23355,
+  [SMALL_STATE(667)] = 23365,
+  [SMALL_STATE(668)] = 23375,
+  [SMALL_STATE(669)] = 23385,
+  [SMALL_STATE(670)] = 23395,
+  [SMALL_STATE(671)] = 23405,
+  [SMALL_STATE(672)] = 23413,
+  [SMALL_STATE(673)] = 23423,
+  [SMALL_STATE(674)] = 23433,
+  [SMALL_STATE(675)] = 23443,
+  [SMALL_STATE(676)] = 23453,
+  [SMALL_STATE(677)] = 23460,
+  [SMALL_STATE(678)] = 23467,
+  [SMALL_STATE(679)] = 23474,
+  [SMALL_STATE(680)] = 23481,
+  [SMALL_STATE(681)] = 23488,
+  [SMALL_STATE(682)] = 23495,
+  [SMALL_STATE(683)] = 23502,
+  [SMALL_STATE(684)] = 23509,
+  [SMALL_STATE(685)] = 23516,
+  [SMALL_STATE(686)] = 23523,
+  [SMALL_STATE(687)] = 23530,
+  [SMALL_STATE(688)] = 23537,
+  [SMALL_STATE(689)] = 23544,
+  [SMALL_STATE(690)] = 23551,
+  [SMALL_STATE(691)] = 23558,
+  [SMALL_STATE(692)] = 23565,
+  [SMALL_STATE(693)] = 23572,
+  [SMALL_STATE(694)] = 23579,
+  [SMALL_STATE(695)] = 23586,
+  [SMALL_STATE(696)] = 23593,
+  [SMALL_STATE(697)] = 23600,
+  [SMALL_STATE(698)] = 23607,
+  [SMALL_STATE(699)] = 23614,
+  [SMALL_STATE(700)] = 23621,
+  [SMALL_STATE(701)] = 23628,
+  [SMALL_STATE(702)] = 23635,
+  [SMALL_STATE(703)] = 23642,
+  [SMALL_STATE(704)] = 23649,
+  [SMALL_STATE(705)] = 23656,
+  [SMALL_STATE(706)] = 23663,
+  [SMALL_STATE(707)] = 23670,
+  [SMALL_STATE(708)] = 23677,
+  [SMALL_STATE(709)] = 23684,
+  [SMALL_STATE(710)] = 23691,
+  [SMALL_STATE(711)] = 23698,
+  [SMALL_STATE(712)] = 23705,
+  [SMALL_STATE(713)] = 23712,
+  [SMALL_STATE(714)] = 23719,
+  [SMALL_STATE(715)] = 23726,
+  [SMALL_STATE(716)] = 23733,
+  [SMALL_STATE(717)] = 23740,
+  [SMALL_STATE(718)] = 23747,
+  [SMALL_STATE(719)] = 23754,
+  [SMALL_STATE(720)] = 23761,
+  [SMALL_STATE(721)] = 23768,
+  [SMALL_STATE(722)] = 23775,
+  [SMALL_STATE(723)] = 23782,
+  [SMALL_STATE(724)] = 23789,
+  [SMALL_STATE(725)] = 23796,
+  [SMALL_STATE(726)] = 23803,
+  [SMALL_STATE(727)] = 23810,
+  [SMALL_STATE(728)] = 23817,
+  [SMALL_STATE(729)] = 23824,
+  [SMALL_STATE(730)] = 23831,
+  [SMALL_STATE(731)] = 23838,
+  [SMALL_STATE(732)] = 23845,
+  [SMALL_STATE(733)] = 23852,
+  [SMALL_STATE(734)] = 23859,
+  [SMALL_STATE(735)] = 23866,
+  [SMALL_STATE(736)] = 23873,
+  [SMALL_STATE(737)] = 23880,
+  [SMALL_STATE(738)] = 23887,
+  [SMALL_STATE(739)] = 23894,
+  [SMALL_STATE(740)] = 23901,
+  [SMALL_STATE(741)] = 23908,
+  [SMALL_STATE(742)] = 23915,
+  [SMALL_STATE(743)] = 23922,
+  [SMALL_STATE(744)] = 23929,
+  [SMALL_STATE(745)] = 23936,
+};
+
+static const TSParseActionEntry ts_parse_actions[] = {
+  [0] = {.entry = {.count = 0, .reusable = false}},
+  [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
+  [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT_EXTRA(),
+  [5] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_stylesheet, 0, 0, 0),
+  [7] = {.entry = {.count = 1, .reusable = false}}, SHIFT(291),
+  [9] = {.entry = {.count = 1, .reusable = false}}, SHIFT(469),
+  [11] = {.entry = {.count = 1, .reusable = false}}, SHIFT(307),
+  [13] = {.entry = {.count = 1, .reusable = false}}, SHIFT(547),
+  [15] = {.entry = {.count = 1, .reusable = false}}, SHIFT(680),
+  [17] = {.entry = {.count = 1, .reusable = false}}, SHIFT(467),
+  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(402),
+  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(477),
+  [23] = {.entry = {.count = 1, .reusable = true}}, SHIFT(582),
+  [25] = {.entry = {.count = 1, .reusable = false}}, SHIFT(685),
+  [27] = {.entry = {.count = 1, .reusable = true}}, SHIFT(707),
+  [29] = {.entry = {.count = 1, .reusable = false}}, SHIFT(722),
+  [31] = {.entry = {.count = 1, .reusable = true}}, SHIFT(691),
+  [33] = {.entry = {.count = 1, .reusable = true}}, SHIFT(481),
+  [35] = {.entry = {.count = 1, .reusable = false}}, SHIFT(470),
+  [37] = {.entry = {.count = 1, .reusable = false}}, SHIFT(394),
+  [39] = {.entry = {.count = 1, .reusable = false}}, SHIFT(283),
+  [41] = {.entry = {.count = 1, .reusable = false}}, SHIFT(284),
+  [43] = {.entry = {.count = 1, .reusable = false}}, SHIFT(619),
+  [45] = {.entry = {.count = 1, .reusable = false}}, SHIFT(627),
+  [47] = {.entry = {.count = 1, .reusable = false}}, SHIFT(631),
+  [49] = {.entry = {.count = 1, .reusable = false}}, SHIFT(288),
+  [51] = {.entry = {.count = 1, .reusable = false}}, SHIFT(24),
+  [53] = {.entry = {.count = 1, .reusable = false}}, SHIFT(292),
+  [55] = {.entry = {.count = 1, .reusable = false}}, SHIFT(293),
+  [57] = {.entry = {.count = 1, .reusable = false}}, SHIFT(298),
+  [59] = {.entry = {.count = 1, .reusable = false}}, SHIFT(257),
+  [61] = {.entry = {.count = 1, .reusable = false}}, SHIFT(299),
+  [63] = {.entry = {.count = 1, .reusable = false}}, SHIFT(706),
+  [65] = {.entry = {.count = 1, .reusable = false}}, SHIFT(727),
+  [67] = {.entry = {.count = 1, .reusable = false}}, SHIFT(300),
+  [69] = {.entry = {.count = 1, .reusable = true}}, SHIFT(301),
+  [71] = {.entry = {.count = 1, .reusable = true}}, SHIFT(597),
+  [73] = {.entry = {.count = 1, .reusable = true}}, SHIFT(681),
+  [75] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_stylesheet, 1, 0, 4),
+  [77] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16),
+  [79] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(291),
+  [82] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(469),
+  [85] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(307),
+  [88] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(547),
+  [91] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(680),
+  [94] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(467),
+  [97] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(402),
+  [100] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(477),
+  [103] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(582),
+  [106] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(685),
+  [109] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(707),
+  [112] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(722),
+  [115] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(691),
+  [118] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(481),
+  [121] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(470),
+  [124] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(394),
+  [127] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(283),
+  [130] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(284),
+  [133] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(619),
+  [136] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(627),
+  [139] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(631),
+  [142] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(288),
+  [145] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(24),
+  [148] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(292),
+  [151] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(293),
+  [154] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(298),
+  [157] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(257),
+  [160] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(299),
+  [163] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(706),
+  [166] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(727),
+  [169] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(300),
+  [172] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(301),
+  [175] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(597),
+  [178] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_stylesheet_repeat1, 2, 0, 16), SHIFT_REPEAT(681),
+  [181] = {.entry = {.count = 1, .reusable = false}}, SHIFT(331),
+  [183] = {.entry = {.count = 1, .reusable = false}}, SHIFT(464),
+  [185] = {.entry = {.count = 1, .reusable = false}}, SHIFT(332),
+  [187] = {.entry = {.count = 1, .reusable = false}}, SHIFT(550),
+  [189] = {.entry = {.count = 1, .reusable = false}}, SHIFT(708),
+  [191] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
+  [193] = {.entry = {.count = 1, .reusable = false}}, SHIFT(466),
+  [195] = {.entry = {.count = 1, .reusable = false}}, SHIFT(475),
+  [197] = {.entry = {.count = 1, .reusable = false}}, SHIFT(397),
+  [199] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
+  [201] = {.entry = {.count = 1, .reusable = false}}, SHIFT(621),
+  [203] = {.entry = {.count = 1, .reusable = false}}, SHIFT(622),
+  [205] = {.entry = {.count = 1, .reusable = false}}, SHIFT(623),
+  [207] = {.entry = {.count = 1, .reusable = false}}, SHIFT(333),
+  [209] = {.entry = {.count = 1, .reusable = false}}, SHIFT(25),
+  [211] = {.entry = {.count = 1, .reusable = false}}, SHIFT(334),
+  [213] = {.entry = {.count = 1, .reusable = false}}, SHIFT(335),
+  [215] = {.entry = {.count = 1, .reusable = false}}, SHIFT(336),
+  [217] = {.entry = {.count = 1, .reusable = false}}, SHIFT(268),
+  [219] = {.entry = {.count = 1, .reusable = false}}, SHIFT(337),
+  [221] = {.entry = {.count = 1, .reusable = false}}, SHIFT(740),
+  [223] = {.entry = {.count = 1, .reusable = false}}, SHIFT(744),
+  [225] = {.entry = {.count = 1, .reusable = false}}, SHIFT(338),
+  [227] = {.entry = {.count = 1, .reusable = true}}, SHIFT(716),
+  [229] = {.entry = {.count = 1, .reusable = true}}, SHIFT(42),
+  [231] = {.entry = {.count = 1, .reusable = true}}, SHIFT(115),
+  [233] = {.entry = {.count = 1, .reusable = true}}, SHIFT(169),
+  [235] = {.entry = {.count = 1, .reusable = true}}, SHIFT(35),
+  [237] = {.entry = {.count = 1, .reusable = true}}, SHIFT(557),
+  [239] = {.entry = {.count = 1, .reusable = true}}, SHIFT(33),
+  [241] = {.entry = {.count = 1, .reusable = true}}, SHIFT(167),
+  [243] = {.entry = {.count = 1, .reusable = true}}, SHIFT(581),
+  [245] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
+  [247] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(331),
+  [250] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(464),
+  [253] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(332),
+  [256] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(550),
+  [259] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(708),
+  [262] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0),
+  [264] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(466),
+  [267] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(402),
+  [270] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(477),
+  [273] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(582),
+  [276] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(685),
+  [279] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(707),
+  [282] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(722),
+  [285] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(691),
+  [288] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(481),
+  [291] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(476),
+  [294] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(397),
+  [297] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(22),
+  [300] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(621),
+  [303] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(622),
+  [306] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(623),
+  [309] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(333),
+  [312] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(25),
+  [315] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(334),
+  [318] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(335),
+  [321] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(336),
+  [324] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(268),
+  [327] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(337),
+  [330] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(740),
+  [333] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(744),
+  [336] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(338),
+  [339] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(301),
+  [342] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(597),
+  [345] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 2, 0, 0), SHIFT_REPEAT(735),
+  [348] = {.entry = {.count = 1, .reusable = true}}, SHIFT(321),
+  [350] = {.entry = {.count = 1, .reusable = true}}, SHIFT(143),
+  [352] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_last_declaration, 3, 0, 56),
+  [354] = {.entry = {.count = 1, .reusable = true}}, SHIFT(192),
+  [356] = {.entry = {.count = 1, .reusable = true}}, SHIFT(506),
+  [358] = {.entry = {.count = 1, .reusable = false}}, SHIFT(718),
+  [360] = {.entry = {.count = 1, .reusable = false}}, SHIFT(322),
+  [362] = {.entry = {.count = 1, .reusable = false}}, SHIFT(507),
+  [364] = {.entry = {.count = 1, .reusable = true}}, SHIFT(551),
+  [366] = {.entry = {.count = 1, .reusable = true}}, SHIFT(280),
+  [368] = {.entry = {.count = 1, .reusable = false}}, SHIFT(323),
+  [370] = {.entry = {.count = 1, .reusable = false}}, SHIFT(324),
+  [372] = {.entry = {.count = 1, .reusable = false}}, SHIFT(190),
+  [374] = {.entry = {.count = 1, .reusable = false}}, SHIFT(188),
+  [376] = {.entry = {.count = 1, .reusable = false}}, SHIFT(325),
+  [378] = {.entry = {.count = 1, .reusable = true}}, SHIFT(325),
+  [380] = {.entry = {.count = 1, .reusable = false}}, SHIFT(166),
+  [382] = {.entry = {.count = 1, .reusable = false}}, SHIFT(176),
+  [384] = {.entry = {.count = 1, .reusable = true}}, SHIFT(644),
+  [386] = {.entry = {.count = 1, .reusable = true}}, SHIFT(346),
+  [388] = {.entry = {.count = 1, .reusable = false}}, SHIFT(652),
+  [390] = {.entry = {.count = 1, .reusable = false}}, SHIFT(192),
+  [392] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
+  [394] = {.entry = {.count = 1, .reusable = true}}, SHIFT(573),
+  [396] = {.entry = {.count = 1, .reusable = true}}, SHIFT(558),
+  [398] = {.entry = {.count = 1, .reusable = true}}, SHIFT(171),
+  [400] = {.entry = {.count = 1, .reusable = false}}, SHIFT(582),
+  [402] = {.entry = {.count = 1, .reusable = false}}, SHIFT(657),
+  [404] = {.entry = {.count = 1, .reusable = true}}, SHIFT(480),
+  [406] = {.entry = {.count = 1, .reusable = true}}, SHIFT(186),
+  [408] = {.entry = {.count = 1, .reusable = false}}, SHIFT(113),
+  [410] = {.entry = {.count = 1, .reusable = true}}, SHIFT(347),
+  [412] = {.entry = {.count = 1, .reusable = false}}, SHIFT(213),
+  [414] = {.entry = {.count = 1, .reusable = true}}, SHIFT(485),
+  [416] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 3, 0, 33),
+  [418] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_statement, 3, 0, 33),
+  [420] = {.entry = {.count = 1, .reusable = false}}, SHIFT(624),
+  [422] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_block_repeat1, 1, 0, 0),
+  [424] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__selector, 1, 0, 0),
+  [426] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_block_repeat1, 1, 0, 0),
+  [428] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym__selector, 1, 0, 0), REDUCE(aux_sym_block_repeat1, 1, 0, 0),
+  [431] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym__selector, 1, 0, 0), REDUCE(aux_sym_block_repeat1, 1, 0, 0),
+  [434] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 4, 0, 71),
+  [436] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_statement, 4, 0, 71),
+  [438] = {.entry = {.count = 1, .reusable = true}}, SHIFT(252),
+  [440] = {.entry = {.count = 1, .reusable = false}}, SHIFT(649),
+  [442] = {.entry = {.count = 1, .reusable = true}}, SHIFT(278),
+  [444] = {.entry = {.count = 1, .reusable = true}}, SHIFT(349),
+  [446] = {.entry = {.count = 1, .reusable = false}}, SHIFT(368),
+  [448] = {.entry = {.count = 1, .reusable = false}}, SHIFT(369),
+  [450] = {.entry = {.count = 1, .reusable = false}}, SHIFT(253),
+  [452] = {.entry = {.count = 1, .reusable = false}}, SHIFT(220),
+  [454] = {.entry = {.count = 1, .reusable = false}}, SHIFT(656),
+  [456] = {.entry = {.count = 1, .reusable = false}}, SHIFT(419),
+  [458] = {.entry = {.count = 1, .reusable = false}}, SHIFT(420),
+  [460] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_if_statement_repeat1, 2, 0, 101),
+  [462] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_if_statement_repeat1, 2, 0, 101),
+  [464] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_if_statement_repeat1, 2, 0, 101), SHIFT_REPEAT(701),
+  [467] = {.entry = {.count = 1, .reusable = false}}, SHIFT(606),
+  [469] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 4, 0, 92),
+  [471] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block, 4, 0, 92),
+  [473] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_if_statement_repeat1, 2, 0, 101), SHIFT_REPEAT(728),
+  [476] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_if_statement_repeat1, 1, 0, 69),
+  [478] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_if_statement_repeat1, 1, 0, 69),
+  [480] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_else_if_clause, 4, 0, 125),
+  [482] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_else_if_clause, 4, 0, 125),
+  [484] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 2, 0, 0),
+  [486] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block, 2, 0, 0),
+  [488] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 3, 0, 58),
+  [490] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block, 3, 0, 58),
+  [492] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 3, 0, 59),
+  [494] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block, 3, 0, 59),
+  [496] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_at_root_statement, 3, 0, 32),
+  [498] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_at_root_statement, 3, 0, 32),
+  [500] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_media_statement, 4, 0, 53),
+  [502] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_media_statement, 4, 0, 53),
+  [504] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_namespace_statement, 4, 0, 55),
+  [506] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_namespace_statement, 4, 0, 55),
+  [508] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_keyframe_block_list, 2, 0, 0),
+  [510] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_keyframe_block_list, 2, 0, 0),
+  [512] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_error_statement, 3, 0, 18),
+  [514] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_error_statement, 3, 0, 18),
+  [516] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_charset_statement, 3, 0, 21),
+  [518] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_charset_statement, 3, 0, 21),
+  [520] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_at_rule, 4, 0, 60),
+  [522] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_at_rule, 4, 0, 60),
+  [524] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_at_rule, 4, 0, 61),
+  [526] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_at_rule, 4, 0, 61),
+  [528] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_mixin_statement, 4, 0, 64),
+  [530] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_mixin_statement, 4, 0, 64),
+  [532] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include_statement, 4, 0, 66),
+  [534] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_include_statement, 4, 0, 66),
+  [536] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include_statement, 4, 0, 67),
+  [538] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_include_statement, 4, 0, 67),
+  [540] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_statement, 4, 0, 64),
+  [542] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_function_statement, 4, 0, 64),
+  [544] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extend_statement, 4, 0, 68),
+  [546] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extend_statement, 4, 0, 68),
+  [548] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_warn_statement, 3, 0, 18),
+  [550] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_warn_statement, 3, 0, 18),
+  [552] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 4, 0, 70),
+  [554] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_statement, 4, 0, 70),
+  [556] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_namespace_statement, 3, 0, 18),
+  [558] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_namespace_statement, 3, 0, 18),
+  [560] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_statement, 5, 0, 82),
+  [562] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_import_statement, 5, 0, 82),
+  [564] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_keyframe_block_list, 3, 0, 86),
+  [566] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_keyframe_block_list, 3, 0, 86),
+  [568] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_keyframes_statement, 3, 0, 22),
+  [570] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_keyframes_statement, 3, 0, 22),
+  [572] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 5, 0, 89),
+  [574] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_declaration, 5, 0, 89),
+  [576] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 5, 0, 90),
+  [578] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_declaration, 5, 0, 90),
+  [580] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 5, 0, 91),
+  [582] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_declaration, 5, 0, 91),
+  [584] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_supports_statement, 3, 0, 23),
+  [586] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_supports_statement, 3, 0, 23),
+  [588] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_use_statement, 5, 0, 93),
+  [590] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_use_statement, 5, 0, 93),
+  [592] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_use_statement, 5, 0, 94),
+  [594] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_use_statement, 5, 0, 94),
+  [596] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_use_statement, 5, 0, 95),
+  [598] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_use_statement, 5, 0, 95),
+  [600] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_forward_statement, 5, 0, 95),
+  [602] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_forward_statement, 5, 0, 95),
+  [604] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_forward_statement, 5, 0, 96),
+  [606] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_forward_statement, 5, 0, 96),
+  [608] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_else_clause, 2, 0, 12),
+  [610] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_else_clause, 2, 0, 12),
+  [612] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 5, 0, 100),
+  [614] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_statement, 5, 0, 100),
+  [616] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_at_rule, 2, 0, 11),
+  [618] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_at_rule, 2, 0, 11),
+  [620] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_statement, 5, 0, 102),
+  [622] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_statement, 5, 0, 102),
+  [624] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 6, 0, 106),
+  [626] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_declaration, 6, 0, 106),
+  [628] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 6, 0, 107),
+  [630] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_declaration, 6, 0, 107),
+  [632] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 6, 0, 108),
+  [634] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_declaration, 6, 0, 108),
+  [636] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_forward_statement, 6, 0, 109),
+  [638] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_forward_statement, 6, 0, 109),
+  [640] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_forward_statement, 6, 0, 111),
+  [642] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_forward_statement, 6, 0, 111),
+  [644] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 7, 0, 121),
+  [646] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_declaration, 7, 0, 121),
+  [648] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_use_statement, 7, 0, 122),
+  [650] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_use_statement, 7, 0, 122),
+  [652] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_use_statement, 7, 0, 123),
+  [654] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_use_statement, 7, 0, 123),
+  [656] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_forward_statement, 7, 0, 124),
+  [658] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_forward_statement, 7, 0, 124),
+  [660] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_debug_statement, 3, 0, 18),
+  [662] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_debug_statement, 3, 0, 18),
+  [664] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_statement, 7, 0, 126),
+  [666] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_statement, 7, 0, 126),
+  [668] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_statement, 7, 0, 127),
+  [670] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_for_statement, 7, 0, 127),
+  [672] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_forward_statement, 8, 0, 128),
+  [674] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_forward_statement, 8, 0, 128),
+  [676] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_forward_statement, 8, 0, 129),
+  [678] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_forward_statement, 8, 0, 129),
+  [680] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_forward_statement, 8, 0, 130),
+  [682] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_forward_statement, 8, 0, 130),
+  [684] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_forward_statement, 9, 0, 131),
+  [686] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_forward_statement, 9, 0, 131),
+  [688] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_forward_statement, 10, 0, 132),
+  [690] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_forward_statement, 10, 0, 132),
+  [692] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_forward_statement, 11, 0, 133),
+  [694] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_forward_statement, 11, 0, 133),
+  [696] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_at_root_statement, 2, 0, 12),
+  [698] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_at_root_statement, 2, 0, 12),
+  [700] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_stylesheet_repeat1, 1, 0, 2),
+  [702] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_stylesheet_repeat1, 1, 0, 2),
+  [704] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_rule_set, 2, 0, 14),
+  [706] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_rule_set, 2, 0, 14),
+  [708] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_media_statement, 3, 0, 20),
+  [710] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_media_statement, 3, 0, 20),
+  [712] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_while_statement, 3, 0, 34),
+  [714] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_while_statement, 3, 0, 34),
+  [716] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_at_rule, 2, 0, 10),
+  [718] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_at_rule, 2, 0, 10),
+  [720] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_statement, 4, 0, 49),
+  [722] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_import_statement, 4, 0, 49),
+  [724] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_statement, 3, 0, 18),
+  [726] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_import_statement, 3, 0, 18),
+  [728] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_at_rule, 3, 0, 27),
+  [730] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_at_rule, 3, 0, 27),
+  [732] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_at_rule, 3, 0, 28),
+  [734] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_at_rule, 3, 0, 28),
+  [736] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_use_statement, 3, 0, 29),
+  [738] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_use_statement, 3, 0, 29),
+  [740] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_forward_statement, 3, 0, 29),
+  [742] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_forward_statement, 3, 0, 29),
+  [744] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_mixin_statement, 3, 0, 30),
+  [746] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_mixin_statement, 3, 0, 30),
+  [748] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include_statement, 3, 0, 13),
+  [750] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_include_statement, 3, 0, 13),
+  [752] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_include_statement, 3, 0, 30),
+  [754] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_include_statement, 3, 0, 30),
+  [756] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_statement, 3, 0, 30),
+  [758] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_function_statement, 3, 0, 30),
+  [760] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_return_statement, 3, 0, 18),
+  [762] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_return_statement, 3, 0, 18),
+  [764] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extend_statement, 3, 0, 31),
+  [766] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_extend_statement, 3, 0, 31),
+  [768] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declaration, 4, 0, 56),
+  [770] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_declaration, 4, 0, 56),
+  [772] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__selector, 1, 0, 1),
+  [774] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__value, 1, 0, 5),
+  [776] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__selector, 1, 0, 1),
+  [778] = {.entry = {.count = 1, .reusable = true}}, SHIFT(263),
+  [780] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__value, 1, 0, 5),
+  [782] = {.entry = {.count = 1, .reusable = true}}, SHIFT(578),
+  [784] = {.entry = {.count = 1, .reusable = true}}, SHIFT(553),
+  [786] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__value, 1, 0, 0),
+  [788] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__selector, 1, 0, 0),
+  [790] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__value, 1, 0, 0),
+  [792] = {.entry = {.count = 1, .reusable = false}}, SHIFT(396),
+  [794] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__concatenated_identifier, 1, 0, 0),
+  [796] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__concatenated_identifier, 1, 0, 0),
+  [798] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__concatenated_identifier, 2, 0, 0),
+  [800] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__concatenated_identifier, 2, 0, 0),
+  [802] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__concatenated_identifier_repeat1, 2, 0, 0),
+  [804] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym__concatenated_identifier_repeat1, 2, 0, 0),
+  [806] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__concatenated_identifier_repeat1, 2, 0, 0), SHIFT_REPEAT(578),
+  [809] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_interpolation, 3, 0, 18),
+  [811] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_interpolation, 3, 0, 18),
+  [813] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binary_expression, 3, 0, 47),
+  [815] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_binary_expression, 3, 0, 47),
+  [817] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binary_expression, 3, 0, 45),
+  [819] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_binary_expression, 3, 0, 45),
+  [821] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binary_expression, 3, 0, 46),
+  [823] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_binary_expression, 3, 0, 46),
+  [825] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binary_expression, 3, 0, 48),
+  [827] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_binary_expression, 3, 0, 48),
+  [829] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__concatenated_identifier_repeat1, 2, 0, 0), SHIFT_REPEAT(553),
+  [832] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 75),
+  [834] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 75),
+  [836] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_float_value, 1, 0, 0),
+  [838] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_float_value, 1, 0, 0),
+  [840] = {.entry = {.count = 1, .reusable = false}}, SHIFT(199),
+  [842] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_integer_value, 1, 0, 0),
+  [844] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_integer_value, 1, 0, 0),
+  [846] = {.entry = {.count = 1, .reusable = false}}, SHIFT(198),
+  [848] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_declaration_repeat1, 1, 0, 57),
+  [850] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_declaration_repeat1, 1, 0, 57),
+  [852] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arguments, 3, 0, 75),
+  [854] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arguments, 3, 0, 75),
+  [856] = {.entry = {.count = 1, .reusable = true}}, SHIFT(156),
+  [858] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_last_declaration, 4, 0, 91),
+  [860] = {.entry = {.count = 1, .reusable = true}}, SHIFT(545),
+  [862] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_map_value, 5, 0, 79),
+  [864] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_map_value, 5, 0, 79),
+  [866] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arguments, 4, 0, 77),
+  [868] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arguments, 4, 0, 77),
+  [870] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arguments, 2, 0, 0),
+  [872] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arguments, 2, 0, 0),
+  [874] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_integer_value, 2, 0, 0),
+  [876] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_integer_value, 2, 0, 0),
+  [878] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_float_value, 2, 0, 0),
+  [880] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_float_value, 2, 0, 0),
+  [882] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_call_expression, 2, 0, 17),
+  [884] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_call_expression, 2, 0, 17),
+  [886] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_color_value, 2, 0, 0),
+  [888] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_color_value, 2, 0, 0),
+  [890] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesized_value, 3, 0, 18),
+  [892] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parenthesized_value, 3, 0, 18),
+  [894] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list_value, 4, 0, 77),
+  [896] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_list_value, 4, 0, 77),
+  [898] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_map_value, 4, 0, 44),
+  [900] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_map_value, 4, 0, 44),
+  [902] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_map_value, 4, 0, 79),
+  [904] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_map_value, 4, 0, 79),
+  [906] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_map_value, 3, 0, 44),
+  [908] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_map_value, 3, 0, 44),
+  [910] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list_value, 5, 0, 77),
+  [912] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_list_value, 5, 0, 77),
+  [914] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 78), SHIFT_REPEAT(321),
+  [917] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 78),
+  [919] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 78), SHIFT_REPEAT(192),
+  [922] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 78), SHIFT_REPEAT(718),
+  [925] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 78), SHIFT_REPEAT(280),
+  [928] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 78), SHIFT_REPEAT(190),
+  [931] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 78), SHIFT_REPEAT(188),
+  [934] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 78), SHIFT_REPEAT(166),
+  [937] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 78), SHIFT_REPEAT(176),
+  [940] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 78), SHIFT_REPEAT(346),
+  [943] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 78), SHIFT_REPEAT(652),
+  [946] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_declaration_repeat1, 2, 0, 78), SHIFT_REPEAT(192),
+  [949] = {.entry = {.count = 1, .reusable = true}}, SHIFT(59),
+  [951] = {.entry = {.count = 1, .reusable = true}}, SHIFT(566),
+  [953] = {.entry = {.count = 1, .reusable = true}}, SHIFT(258),
+  [955] = {.entry = {.count = 1, .reusable = true}}, SHIFT(567),
+  [957] = {.entry = {.count = 1, .reusable = true}}, SHIFT(562),
+  [959] = {.entry = {.count = 1, .reusable = true}}, SHIFT(97),
+  [961] = {.entry = {.count = 1, .reusable = false}}, SHIFT(294),
+  [963] = {.entry = {.count = 1, .reusable = true}}, SHIFT(507),
+  [965] = {.entry = {.count = 1, .reusable = true}}, SHIFT(473),
+  [967] = {.entry = {.count = 1, .reusable = false}}, SHIFT(295),
+  [969] = {.entry = {.count = 1, .reusable = false}}, SHIFT(296),
+  [971] = {.entry = {.count = 1, .reusable = false}}, SHIFT(474),
+  [973] = {.entry = {.count = 1, .reusable = false}}, SHIFT(724),
+  [975] = {.entry = {.count = 1, .reusable = false}}, SHIFT(297),
+  [977] = {.entry = {.count = 1, .reusable = true}}, SHIFT(297),
+  [979] = {.entry = {.count = 1, .reusable = false}}, SHIFT(535),
+  [981] = {.entry = {.count = 1, .reusable = true}}, SHIFT(533),
+  [983] = {.entry = {.count = 1, .reusable = true}}, SHIFT(344),
+  [985] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 1, 0, 0),
+  [987] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 1, 0, 0),
+  [989] = {.entry = {.count = 1, .reusable = true}}, SHIFT(120),
+  [991] = {.entry = {.count = 1, .reusable = true}}, SHIFT(527),
+  [993] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__concatenated_identifier_repeat1, 2, 0, 0), SHIFT_REPEAT(567),
+  [996] = {.entry = {.count = 1, .reusable = true}}, SHIFT(270),
+  [998] = {.entry = {.count = 1, .reusable = true}}, SHIFT(213),
+  [1000] = {.entry = {.count = 1, .reusable = true}}, SHIFT(193),
+  [1002] = {.entry = {.count = 1, .reusable = true}}, SHIFT(375),
+  [1004] = {.entry = {.count = 1, .reusable = true}}, SHIFT(248),
+  [1006] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__concatenated_identifier_repeat1, 2, 0, 0), SHIFT_REPEAT(587),
+  [1009] = {.entry = {.count = 1, .reusable = true}}, SHIFT(587),
+  [1011] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 2, 0, 0),
+  [1013] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 2, 0, 0), SHIFT_REPEAT(213),
+  [1016] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 2, 0, 0), SHIFT_REPEAT(718),
+  [1019] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 2, 0, 0), SHIFT_REPEAT(280),
+  [1022] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 2, 0, 0), SHIFT_REPEAT(190),
+  [1025] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 2, 0, 0), SHIFT_REPEAT(188),
+  [1028] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 2, 0, 0), SHIFT_REPEAT(166),
+  [1031] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 2, 0, 0), SHIFT_REPEAT(176),
+  [1034] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 2, 0, 0), SHIFT_REPEAT(346),
+  [1037] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 2, 0, 0), SHIFT_REPEAT(652),
+  [1040] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_pseudo_class_arguments_repeat1, 2, 0, 0), SHIFT_REPEAT(213),
+  [1043] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
+  [1045] = {.entry = {.count = 1, .reusable = true}}, SHIFT(486),
+  [1047] = {.entry = {.count = 1, .reusable = true}}, SHIFT(487),
+  [1049] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_arguments_repeat1, 2, 0, 75),
+  [1051] = {.entry = {.count = 1, .reusable = true}}, SHIFT(491),
+  [1053] = {.entry = {.count = 1, .reusable = true}}, SHIFT(460),
+  [1055] = {.entry = {.count = 1, .reusable = true}}, SHIFT(492),
+  [1057] = {.entry = {.count = 1, .reusable = true}}, SHIFT(496),
+  [1059] = {.entry = {.count = 1, .reusable = true}}, SHIFT(503),
+  [1061] = {.entry = {.count = 1, .reusable = true}}, SHIFT(513),
+  [1063] = {.entry = {.count = 1, .reusable = true}}, SHIFT(505),
+  [1065] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_pseudo_class_arguments_repeat2, 2, 0, 87),
+  [1067] = {.entry = {.count = 1, .reusable = true}}, SHIFT(442),
+  [1069] = {.entry = {.count = 1, .reusable = false}}, SHIFT(694),
+  [1071] = {.entry = {.count = 1, .reusable = true}}, SHIFT(379),
+  [1073] = {.entry = {.count = 1, .reusable = false}}, SHIFT(210),
+  [1075] = {.entry = {.count = 1, .reusable = true}}, SHIFT(345),
+  [1077] = {.entry = {.count = 1, .reusable = false}}, SHIFT(442),
+  [1079] = {.entry = {.count = 1, .reusable = true}}, SHIFT(4),
+  [1081] = {.entry = {.count = 1, .reusable = true}}, SHIFT(413),
+  [1083] = {.entry = {.count = 1, .reusable = false}}, SHIFT(413),
+  [1085] = {.entry = {.count = 1, .reusable = true}}, SHIFT(241),
+  [1087] = {.entry = {.count = 1, .reusable = true}}, SHIFT(255),
+  [1089] = {.entry = {.count = 1, .reusable = true}}, SHIFT(417),
+  [1091] = {.entry = {.count = 1, .reusable = true}}, SHIFT(638),
+  [1093] = {.entry = {.count = 1, .reusable = true}}, SHIFT(462),
+  [1095] = {.entry = {.count = 1, .reusable = false}}, SHIFT(450),
+  [1097] = {.entry = {.count = 1, .reusable = true}}, SHIFT(373),
+  [1099] = {.entry = {.count = 1, .reusable = true}}, SHIFT(238),
+  [1101] = {.entry = {.count = 1, .reusable = true}}, SHIFT(197),
+  [1103] = {.entry = {.count = 1, .reusable = true}}, SHIFT(195),
+  [1105] = {.entry = {.count = 1, .reusable = true}}, SHIFT(204),
+  [1107] = {.entry = {.count = 1, .reusable = true}}, SHIFT(377),
+  [1109] = {.entry = {.count = 1, .reusable = true}}, SHIFT(543),
+  [1111] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
+  [1113] = {.entry = {.count = 1, .reusable = true}}, SHIFT(404),
+  [1115] = {.entry = {.count = 1, .reusable = false}}, SHIFT(404),
+  [1117] = {.entry = {.count = 1, .reusable = true}}, SHIFT(390),
+  [1119] = {.entry = {.count = 1, .reusable = false}}, SHIFT(390),
+  [1121] = {.entry = {.count = 1, .reusable = true}}, SHIFT(416),
+  [1123] = {.entry = {.count = 1, .reusable = true}}, SHIFT(237),
+  [1125] = {.entry = {.count = 1, .reusable = false}}, SHIFT(416),
+  [1127] = {.entry = {.count = 1, .reusable = true}}, SHIFT(471),
+  [1129] = {.entry = {.count = 1, .reusable = true}}, SHIFT(207),
+  [1131] = {.entry = {.count = 1, .reusable = true}}, SHIFT(372),
+  [1133] = {.entry = {.count = 1, .reusable = true}}, SHIFT(392),
+  [1135] = {.entry = {.count = 1, .reusable = false}}, SHIFT(392),
+  [1137] = {.entry = {.count = 1, .reusable = true}}, SHIFT(266),
+  [1139] = {.entry = {.count = 1, .reusable = true}}, SHIFT(571),
+  [1141] = {.entry = {.count = 1, .reusable = true}}, SHIFT(391),
+  [1143] = {.entry = {.count = 1, .reusable = false}}, SHIFT(391),
+  [1145] = {.entry = {.count = 1, .reusable = true}}, SHIFT(411),
+  [1147] = {.entry = {.count = 1, .reusable = false}}, SHIFT(411),
+  [1149] = {.entry = {.count = 1, .reusable = true}}, SHIFT(453),
+  [1151] = {.entry = {.count = 1, .reusable = false}}, SHIFT(453),
+  [1153] = {.entry = {.count = 1, .reusable = true}}, SHIFT(395),
+  [1155] = {.entry = {.count = 1, .reusable = false}}, SHIFT(407),
+  [1157] = {.entry = {.count = 1, .reusable = false}}, SHIFT(412),
+  [1159] = {.entry = {.count = 1, .reusable = false}}, SHIFT(395),
+  [1161] = {.entry = {.count = 1, .reusable = true}}, SHIFT(384),
+  [1163] = {.entry = {.count = 1, .reusable = false}}, SHIFT(389),
+  [1165] = {.entry = {.count = 1, .reusable = false}}, SHIFT(388),
+  [1167] = {.entry = {.count = 1, .reusable = false}}, SHIFT(384),
+  [1169] = {.entry = {.count = 1, .reusable = true}}, SHIFT(427),
+  [1171] = {.entry = {.count = 1, .reusable = false}}, SHIFT(427),
+  [1173] = {.entry = {.count = 1, .reusable = true}}, SHIFT(429),
+  [1175] = {.entry = {.count = 1, .reusable = false}}, SHIFT(429),
+  [1177] = {.entry = {.count = 1, .reusable = true}}, SHIFT(430),
+  [1179] = {.entry = {.count = 1, .reusable = false}}, SHIFT(430),
+  [1181] = {.entry = {.count = 1, .reusable = true}}, SHIFT(451),
+  [1183] = {.entry = {.count = 1, .reusable = false}}, SHIFT(451),
+  [1185] = {.entry = {.count = 1, .reusable = true}}, SHIFT(408),
+  [1187] = {.entry = {.count = 1, .reusable = false}}, SHIFT(408),
+  [1189] = {.entry = {.count = 1, .reusable = true}}, SHIFT(415),
+  [1191] = {.entry = {.count = 1, .reusable = false}}, SHIFT(415),
+  [1193] = {.entry = {.count = 1, .reusable = true}}, SHIFT(212),
+  [1195] = {.entry = {.count = 1, .reusable = false}}, SHIFT(684),
+  [1197] = {.entry = {.count = 1, .reusable = true}}, SHIFT(269),
+  [1199] = {.entry = {.count = 1, .reusable = false}}, SHIFT(363),
+  [1201] = {.entry = {.count = 1, .reusable = false}}, SHIFT(365),
+  [1203] = {.entry = {.count = 1, .reusable = false}}, SHIFT(279),
+  [1205] = {.entry = {.count = 1, .reusable = false}}, SHIFT(357),
+  [1207] = {.entry = {.count = 1, .reusable = true}}, SHIFT(339),
+  [1209] = {.entry = {.count = 1, .reusable = false}}, SHIFT(663),
+  [1211] = {.entry = {.count = 1, .reusable = false}}, SHIFT(212),
+  [1213] = {.entry = {.count = 1, .reusable = true}}, SHIFT(444),
+  [1215] = {.entry = {.count = 1, .reusable = false}}, SHIFT(444),
+  [1217] = {.entry = {.count = 1, .reusable = true}}, SHIFT(454),
+  [1219] = {.entry = {.count = 1, .reusable = false}}, SHIFT(454),
+  [1221] = {.entry = {.count = 1, .reusable = true}}, SHIFT(359),
+  [1223] = {.entry = {.count = 1, .reusable = false}}, SHIFT(359),
+  [1225] = {.entry = {.count = 1, .reusable = true}}, SHIFT(360),
+  [1227] = {.entry = {.count = 1, .reusable = false}}, SHIFT(360),
+  [1229] = {.entry = {.count = 1, .reusable = true}}, SHIFT(361),
+  [1231] = {.entry = {.count = 1, .reusable = false}}, SHIFT(361),
+  [1233] = {.entry = {.count = 1, .reusable = true}}, SHIFT(362),
+  [1235] = {.entry = {.count = 1, .reusable = false}}, SHIFT(362),
+  [1237] = {.entry = {.count = 1, .reusable = true}}, SHIFT(455),
+  [1239] = {.entry = {.count = 1, .reusable = false}}, SHIFT(455),
+  [1241] = {.entry = {.count = 1, .reusable = true}}, SHIFT(414),
+  [1243] = {.entry = {.count = 1, .reusable = false}}, SHIFT(414),
+  [1245] = {.entry = {.count = 1, .reusable = true}}, SHIFT(426),
+  [1247] = {.entry = {.count = 1, .reusable = false}}, SHIFT(426),
+  [1249] = {.entry = {.count = 1, .reusable = true}}, SHIFT(458),
+  [1251] = {.entry = {.count = 1, .reusable = false}}, SHIFT(458),
+  [1253] = {.entry = {.count = 1, .reusable = true}}, SHIFT(459),
+  [1255] = {.entry = {.count = 1, .reusable = false}}, SHIFT(459),
+  [1257] = {.entry = {.count = 1, .reusable = true}}, SHIFT(353),
+  [1259] = {.entry = {.count = 1, .reusable = false}}, SHIFT(353),
+  [1261] = {.entry = {.count = 1, .reusable = true}}, SHIFT(354),
+  [1263] = {.entry = {.count = 1, .reusable = false}}, SHIFT(354),
+  [1265] = {.entry = {.count = 1, .reusable = true}}, SHIFT(355),
+  [1267] = {.entry = {.count = 1, .reusable = false}}, SHIFT(355),
+  [1269] = {.entry = {.count = 1, .reusable = true}}, SHIFT(356),
+  [1271] = {.entry = {.count = 1, .reusable = false}}, SHIFT(356),
+  [1273] = {.entry = {.count = 1, .reusable = true}}, SHIFT(461),
+  [1275] = {.entry = {.count = 1, .reusable = false}}, SHIFT(461),
+  [1277] = {.entry = {.count = 1, .reusable = true}}, SHIFT(421),
+  [1279] = {.entry = {.count = 1, .reusable = false}}, SHIFT(421),
+  [1281] = {.entry = {.count = 1, .reusable = true}}, SHIFT(428),
+  [1283] = {.entry = {.count = 1, .reusable = false}}, SHIFT(436),
+  [1285] = {.entry = {.count = 1, .reusable = false}}, SHIFT(434),
+  [1287] = {.entry = {.count = 1, .reusable = false}}, SHIFT(428),
+  [1289] = {.entry = {.count = 1, .reusable = true}}, SHIFT(418),
+  [1291] = {.entry = {.count = 1, .reusable = false}}, SHIFT(418),
+  [1293] = {.entry = {.count = 1, .reusable = true}}, SHIFT(400),
+  [1295] = {.entry = {.count = 1, .reusable = false}}, SHIFT(400),
+  [1297] = {.entry = {.count = 1, .reusable = true}}, SHIFT(399),
+  [1299] = {.entry = {.count = 1, .reusable = false}}, SHIFT(399),
+  [1301] = {.entry = {.count = 1, .reusable = true}}, SHIFT(398),
+  [1303] = {.entry = {.count = 1, .reusable = false}}, SHIFT(398),
+  [1305] = {.entry = {.count = 1, .reusable = true}}, SHIFT(401),
+  [1307] = {.entry = {.count = 1, .reusable = false}}, SHIFT(401),
+  [1309] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
+  [1311] = {.entry = {.count = 1, .reusable = false}}, SHIFT(16),
+  [1313] = {.entry = {.count = 1, .reusable = true}}, SHIFT(387),
+  [1315] = {.entry = {.count = 1, .reusable = false}}, SHIFT(387),
+  [1317] = {.entry = {.count = 1, .reusable = true}}, SHIFT(386),
+  [1319] = {.entry = {.count = 1, .reusable = false}}, SHIFT(386),
+  [1321] = {.entry = {.count = 1, .reusable = true}}, SHIFT(385),
+  [1323] = {.entry = {.count = 1, .reusable = false}}, SHIFT(385),
+  [1325] = {.entry = {.count = 1, .reusable = true}}, SHIFT(383),
+  [1327] = {.entry = {.count = 1, .reusable = false}}, SHIFT(383),
+  [1329] = {.entry = {.count = 1, .reusable = true}}, SHIFT(185),
+  [1331] = {.entry = {.count = 1, .reusable = false}}, SHIFT(185),
+  [1333] = {.entry = {.count = 1, .reusable = true}}, SHIFT(178),
+  [1335] = {.entry = {.count = 1, .reusable = false}}, SHIFT(178),
+  [1337] = {.entry = {.count = 1, .reusable = true}}, SHIFT(179),
+  [1339] = {.entry = {.count = 1, .reusable = false}}, SHIFT(179),
+  [1341] = {.entry = {.count = 1, .reusable = true}}, SHIFT(177),
+  [1343] = {.entry = {.count = 1, .reusable = false}}, SHIFT(177),
+  [1345] = {.entry = {.count = 1, .reusable = true}}, SHIFT(180),
+  [1347] = {.entry = {.count = 1, .reusable = false}}, SHIFT(180),
+  [1349] = {.entry = {.count = 1, .reusable = true}}, SHIFT(15),
+  [1351] = {.entry = {.count = 1, .reusable = false}}, SHIFT(15),
+  [1353] = {.entry = {.count = 1, .reusable = true}}, SHIFT(422),
+  [1355] = {.entry = {.count = 1, .reusable = false}}, SHIFT(422),
+  [1357] = {.entry = {.count = 1, .reusable = true}}, SHIFT(423),
+  [1359] = {.entry = {.count = 1, .reusable = false}}, SHIFT(423),
+  [1361] = {.entry = {.count = 1, .reusable = true}}, SHIFT(424),
+  [1363] = {.entry = {.count = 1, .reusable = false}}, SHIFT(424),
+  [1365] = {.entry = {.count = 1, .reusable = true}}, SHIFT(425),
+  [1367] = {.entry = {.count = 1, .reusable = false}}, SHIFT(425),
+  [1369] = {.entry = {.count = 1, .reusable = true}}, SHIFT(214),
+  [1371] = {.entry = {.count = 1, .reusable = false}}, SHIFT(214),
+  [1373] = {.entry = {.count = 1, .reusable = true}}, SHIFT(437),
+  [1375] = {.entry = {.count = 1, .reusable = false}}, SHIFT(437),
+  [1377] = {.entry = {.count = 1, .reusable = true}}, SHIFT(438),
+  [1379] = {.entry = {.count = 1, .reusable = false}}, SHIFT(438),
+  [1381] = {.entry = {.count = 1, .reusable = true}}, SHIFT(440),
+  [1383] = {.entry = {.count = 1, .reusable = false}}, SHIFT(440),
+  [1385] = {.entry = {.count = 1, .reusable = true}}, SHIFT(432),
+  [1387] = {.entry = {.count = 1, .reusable = false}}, SHIFT(432),
+  [1389] = {.entry = {.count = 1, .reusable = true}}, SHIFT(441),
+  [1391] = {.entry = {.count = 1, .reusable = false}}, SHIFT(441),
+  [1393] = {.entry = {.count = 1, .reusable = true}}, SHIFT(405),
+  [1395] = {.entry = {.count = 1, .reusable = false}}, SHIFT(405),
+  [1397] = {.entry = {.count = 1, .reusable = true}}, SHIFT(406),
+  [1399] = {.entry = {.count = 1, .reusable = false}}, SHIFT(406),
+  [1401] = {.entry = {.count = 1, .reusable = true}}, SHIFT(443),
+  [1403] = {.entry = {.count = 1, .reusable = false}}, SHIFT(443),
+  [1405] = {.entry = {.count = 1, .reusable = true}}, SHIFT(17),
+  [1407] = {.entry = {.count = 1, .reusable = false}}, SHIFT(17),
+  [1409] = {.entry = {.count = 1, .reusable = true}}, SHIFT(403),
+  [1411] = {.entry = {.count = 1, .reusable = false}}, SHIFT(403),
+  [1413] = {.entry = {.count = 1, .reusable = true}}, SHIFT(409),
+  [1415] = {.entry = {.count = 1, .reusable = false}}, SHIFT(409),
+  [1417] = {.entry = {.count = 1, .reusable = true}}, SHIFT(410),
+  [1419] = {.entry = {.count = 1, .reusable = false}}, SHIFT(410),
+  [1421] = {.entry = {.count = 1, .reusable = true}}, SHIFT(448),
+  [1423] = {.entry = {.count = 1, .reusable = false}}, SHIFT(448),
+  [1425] = {.entry = {.count = 1, .reusable = true}}, SHIFT(452),
+  [1427] = {.entry = {.count = 1, .reusable = false}}, SHIFT(452),
+  [1429] = {.entry = {.count = 1, .reusable = true}}, SHIFT(456),
+  [1431] = {.entry = {.count = 1, .reusable = false}}, SHIFT(456),
+  [1433] = {.entry = {.count = 1, .reusable = true}}, SHIFT(457),
+  [1435] = {.entry = {.count = 1, .reusable = false}}, SHIFT(457),
+  [1437] = {.entry = {.count = 1, .reusable = true}}, SHIFT(431),
+  [1439] = {.entry = {.count = 1, .reusable = false}}, SHIFT(431),
+  [1441] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__concatenated_identifier_repeat1, 2, 0, 0), SHIFT_REPEAT(571),
+  [1444] = {.entry = {.count = 1, .reusable = false}}, SHIFT(364),
+  [1446] = {.entry = {.count = 1, .reusable = false}}, SHIFT(382),
+  [1448] = {.entry = {.count = 1, .reusable = false}}, SHIFT(250),
+  [1450] = {.entry = {.count = 1, .reusable = false}}, SHIFT(254),
+  [1452] = {.entry = {.count = 1, .reusable = true}}, SHIFT(101),
+  [1454] = {.entry = {.count = 1, .reusable = false}}, SHIFT(317),
+  [1456] = {.entry = {.count = 1, .reusable = true}}, SHIFT(318),
+  [1458] = {.entry = {.count = 1, .reusable = true}}, SHIFT(319),
+  [1460] = {.entry = {.count = 1, .reusable = true}}, SHIFT(320),
+  [1462] = {.entry = {.count = 1, .reusable = false}}, SHIFT(320),
+  [1464] = {.entry = {.count = 1, .reusable = true}}, SHIFT(689),
+  [1466] = {.entry = {.count = 1, .reusable = true}}, SHIFT(646),
+  [1468] = {.entry = {.count = 1, .reusable = true}}, SHIFT(647),
+  [1470] = {.entry = {.count = 1, .reusable = true}}, SHIFT(310),
+  [1472] = {.entry = {.count = 1, .reusable = true}}, SHIFT(311),
+  [1474] = {.entry = {.count = 1, .reusable = false}}, SHIFT(303),
+  [1476] = {.entry = {.count = 1, .reusable = true}}, SHIFT(376),
+  [1478] = {.entry = {.count = 1, .reusable = true}}, SHIFT(304),
+  [1480] = {.entry = {.count = 1, .reusable = true}}, SHIFT(305),
+  [1482] = {.entry = {.count = 1, .reusable = true}}, SHIFT(306),
+  [1484] = {.entry = {.count = 1, .reusable = false}}, SHIFT(306),
+  [1486] = {.entry = {.count = 1, .reusable = true}}, SHIFT(202),
+  [1488] = {.entry = {.count = 1, .reusable = true}}, SHIFT(235),
+  [1490] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_class_selector, 3, 0, 37),
+  [1492] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_class_selector, 3, 0, 37),
+  [1494] = {.entry = {.count = 1, .reusable = true}}, SHIFT(95),
+  [1496] = {.entry = {.count = 1, .reusable = true}}, SHIFT(517),
+  [1498] = {.entry = {.count = 1, .reusable = true}}, SHIFT(100),
+  [1500] = {.entry = {.count = 1, .reusable = false}}, SHIFT(312),
+  [1502] = {.entry = {.count = 1, .reusable = true}}, SHIFT(313),
+  [1504] = {.entry = {.count = 1, .reusable = true}}, SHIFT(314),
+  [1506] = {.entry = {.count = 1, .reusable = true}}, SHIFT(315),
+  [1508] = {.entry = {.count = 1, .reusable = false}}, SHIFT(315),
+  [1510] = {.entry = {.count = 1, .reusable = true}}, SHIFT(574),
+  [1512] = {.entry = {.count = 1, .reusable = true}}, SHIFT(645),
+  [1514] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_class_selector, 2, 0, 7),
+  [1516] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_class_selector, 2, 0, 7),
+  [1518] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
+  [1520] = {.entry = {.count = 1, .reusable = true}}, SHIFT(518),
+  [1522] = {.entry = {.count = 1, .reusable = true}}, SHIFT(396),
+  [1524] = {.entry = {.count = 1, .reusable = true}}, SHIFT(6),
+  [1526] = {.entry = {.count = 1, .reusable = true}}, SHIFT(10),
+  [1528] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_list_value_repeat1, 2, 0, 75),
+  [1530] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__include_argument, 1, 0, 65),
+  [1532] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_map_pair, 3, 0, 76),
+  [1534] = {.entry = {.count = 1, .reusable = true}}, SHIFT(107),
+  [1536] = {.entry = {.count = 1, .reusable = true}}, SHIFT(721),
+  [1538] = {.entry = {.count = 1, .reusable = true}}, SHIFT(133),
+  [1540] = {.entry = {.count = 1, .reusable = true}}, SHIFT(733),
+  [1542] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameter, 3, 0, 115),
+  [1544] = {.entry = {.count = 1, .reusable = true}}, SHIFT(290),
+  [1546] = {.entry = {.count = 1, .reusable = false}}, SHIFT(327),
+  [1548] = {.entry = {.count = 1, .reusable = true}}, SHIFT(328),
+  [1550] = {.entry = {.count = 1, .reusable = true}}, SHIFT(329),
+  [1552] = {.entry = {.count = 1, .reusable = true}}, SHIFT(330),
+  [1554] = {.entry = {.count = 1, .reusable = false}}, SHIFT(330),
+  [1556] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__include_argument, 3, 0, 118),
+  [1558] = {.entry = {.count = 1, .reusable = true}}, SHIFT(281),
+  [1560] = {.entry = {.count = 1, .reusable = true}}, SHIFT(135),
+  [1562] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_class_selector, 3, 0, 37),
+  [1564] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_class_selector, 3, 0, 37),
+  [1566] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
+  [1568] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
+  [1570] = {.entry = {.count = 1, .reusable = true}}, SHIFT(132),
+  [1572] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_element_selector, 3, 0, 38),
+  [1574] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_element_selector, 3, 0, 38),
+  [1576] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
+  [1578] = {.entry = {.count = 1, .reusable = true}}, SHIFT(134),
+  [1580] = {.entry = {.count = 1, .reusable = true}}, SHIFT(136),
+  [1582] = {.entry = {.count = 1, .reusable = true}}, SHIFT(367),
+  [1584] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
+  [1586] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_class_selector, 2, 0, 7),
+  [1588] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_class_selector, 2, 0, 7),
+  [1590] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_element_selector, 2, 0, 8),
+  [1592] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_element_selector, 2, 0, 8),
+  [1594] = {.entry = {.count = 1, .reusable = true}}, SHIFT(538),
+  [1596] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_placeholder, 2, 0, 13),
+  [1598] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_placeholder, 2, 0, 13),
+  [1600] = {.entry = {.count = 1, .reusable = true}}, SHIFT(286),
+  [1602] = {.entry = {.count = 1, .reusable = true}}, SHIFT(106),
+  [1604] = {.entry = {.count = 1, .reusable = true}}, SHIFT(230),
+  [1606] = {.entry = {.count = 1, .reusable = true}}, SHIFT(500),
+  [1608] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
+  [1610] = {.entry = {.count = 1, .reusable = true}}, SHIFT(80),
+  [1612] = {.entry = {.count = 1, .reusable = true}}, SHIFT(191),
+  [1614] = {.entry = {.count = 1, .reusable = true}}, SHIFT(175),
+  [1616] = {.entry = {.count = 1, .reusable = true}}, SHIFT(242),
+  [1618] = {.entry = {.count = 1, .reusable = true}}, SHIFT(488),
+  [1620] = {.entry = {.count = 1, .reusable = true}}, SHIFT(43),
+  [1622] = {.entry = {.count = 1, .reusable = true}}, SHIFT(273),
+  [1624] = {.entry = {.count = 1, .reusable = true}}, SHIFT(671),
+  [1626] = {.entry = {.count = 1, .reusable = true}}, SHIFT(536),
+  [1628] = {.entry = {.count = 1, .reusable = true}}, SHIFT(524),
+  [1630] = {.entry = {.count = 1, .reusable = true}}, SHIFT(531),
+  [1632] = {.entry = {.count = 1, .reusable = true}}, SHIFT(537),
+  [1634] = {.entry = {.count = 1, .reusable = true}}, SHIFT(540),
+  [1636] = {.entry = {.count = 1, .reusable = true}}, SHIFT(542),
+  [1638] = {.entry = {.count = 1, .reusable = true}}, SHIFT(525),
+  [1640] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym__selector, 1, 0, 1), SHIFT(316),
+  [1643] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__include_arguments_repeat1, 2, 0, 98),
+  [1645] = {.entry = {.count = 1, .reusable = true}}, SHIFT(523),
+  [1647] = {.entry = {.count = 1, .reusable = false}}, SHIFT(586),
+  [1649] = {.entry = {.count = 1, .reusable = true}}, SHIFT(548),
+  [1651] = {.entry = {.count = 1, .reusable = true}}, SHIFT(522),
+  [1653] = {.entry = {.count = 1, .reusable = false}}, SHIFT(326),
+  [1655] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym__selector, 1, 0, 1), SHIFT(340),
+  [1658] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_universal_selector, 1, 0, 0),
+  [1660] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_universal_selector, 1, 0, 0),
+  [1662] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_class_arguments, 4, 0, 104),
+  [1664] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_class_arguments, 4, 0, 104),
+  [1666] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute_selector, 3, 0, 26),
+  [1668] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute_selector, 3, 0, 26),
+  [1670] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_class_arguments, 2, 0, 0),
+  [1672] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_class_arguments, 2, 0, 0),
+  [1674] = {.entry = {.count = 1, .reusable = true}}, SHIFT(246),
+  [1676] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_selectors, 1, 0, 3),
+  [1678] = {.entry = {.count = 1, .reusable = true}}, SHIFT(583),
+  [1680] = {.entry = {.count = 1, .reusable = false}}, SHIFT(683),
+  [1682] = {.entry = {.count = 1, .reusable = true}}, SHIFT(714),
+  [1684] = {.entry = {.count = 1, .reusable = true}}, SHIFT(745),
+  [1686] = {.entry = {.count = 1, .reusable = true}}, SHIFT(703),
+  [1688] = {.entry = {.count = 1, .reusable = true}}, SHIFT(234),
+  [1690] = {.entry = {.count = 1, .reusable = true}}, SHIFT(231),
+  [1692] = {.entry = {.count = 1, .reusable = true}}, SHIFT(233),
+  [1694] = {.entry = {.count = 1, .reusable = true}}, SHIFT(239),
+  [1696] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_id_selector, 3, 0, 39),
+  [1698] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_id_selector, 3, 0, 39),
+  [1700] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_element_arguments, 2, 0, 0),
+  [1702] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_element_arguments, 2, 0, 0),
+  [1704] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_class_arguments, 3, 0, 87),
+  [1706] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_class_arguments, 3, 0, 87),
+  [1708] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_element_arguments, 3, 0, 87),
+  [1710] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_element_arguments, 3, 0, 87),
+  [1712] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute_selector, 5, 0, 88),
+  [1714] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute_selector, 5, 0, 88),
+  [1716] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_class_selector, 3, 0, 24),
+  [1718] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_class_selector, 3, 0, 24),
+  [1720] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_sibling_selector, 3, 0, 41),
+  [1722] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_sibling_selector, 3, 0, 41),
+  [1724] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_adjacent_sibling_selector, 3, 0, 42),
+  [1726] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_adjacent_sibling_selector, 3, 0, 42),
+  [1728] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_class_selector, 4, 0, 72),
+  [1730] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_class_selector, 4, 0, 72),
+  [1732] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_element_selector, 3, 0, 25),
+  [1734] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_element_selector, 3, 0, 25),
+  [1736] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_child_selector, 3, 0, 40),
+  [1738] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_child_selector, 3, 0, 40),
+  [1740] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_element_selector, 4, 0, 73),
+  [1742] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_element_selector, 4, 0, 73),
+  [1744] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pseudo_element_arguments, 4, 0, 104),
+  [1746] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pseudo_element_arguments, 4, 0, 104),
+  [1748] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute_selector, 6, 0, 120),
+  [1750] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute_selector, 6, 0, 120),
+  [1752] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute_selector, 4, 0, 74),
+  [1754] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute_selector, 4, 0, 74),
+  [1756] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_id_selector, 2, 0, 9),
+  [1758] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_id_selector, 2, 0, 9),
+  [1760] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_descendant_selector, 3, 0, 35),
+  [1762] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_descendant_selector, 3, 0, 35),
+  [1764] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_selectors_repeat1, 2, 0, 36),
+  [1766] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_times, 1, 0, 0),
+  [1768] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_times, 1, 0, 0),
+  [1770] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_plus, 1, 0, 0),
+  [1772] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_plus, 1, 0, 0),
+  [1774] = {.entry = {.count = 1, .reusable = true}}, SHIFT(541),
+  [1776] = {.entry = {.count = 1, .reusable = true}}, SHIFT(39),
+  [1778] = {.entry = {.count = 1, .reusable = true}}, SHIFT(730),
+  [1780] = {.entry = {.count = 1, .reusable = true}}, SHIFT(712),
+  [1782] = {.entry = {.count = 1, .reusable = true}}, SHIFT(669),
+  [1784] = {.entry = {.count = 1, .reusable = true}}, SHIFT(55),
+  [1786] = {.entry = {.count = 1, .reusable = true}}, SHIFT(465),
+  [1788] = {.entry = {.count = 1, .reusable = true}}, SHIFT(98),
+  [1790] = {.entry = {.count = 1, .reusable = true}}, SHIFT(528),
+  [1792] = {.entry = {.count = 1, .reusable = true}}, SHIFT(529),
+  [1794] = {.entry = {.count = 1, .reusable = true}}, SHIFT(126),
+  [1796] = {.entry = {.count = 1, .reusable = true}}, SHIFT(153),
+  [1798] = {.entry = {.count = 1, .reusable = true}}, SHIFT(142),
+  [1800] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_keyframe_block_list_repeat1, 2, 0, 0),
+  [1802] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_keyframe_block_list_repeat1, 2, 0, 0), SHIFT_REPEAT(730),
+  [1805] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_keyframe_block_list_repeat1, 2, 0, 0), SHIFT_REPEAT(712),
+  [1808] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_keyframe_block_list_repeat1, 2, 0, 0), SHIFT_REPEAT(669),
+  [1811] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unary_query, 2, 0, 19),
+  [1813] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binary_query, 3, 0, 52),
+  [1815] = {.entry = {.count = 1, .reusable = true}}, SHIFT(468),
+  [1817] = {.entry = {.count = 1, .reusable = true}}, SHIFT(696),
+  [1819] = {.entry = {.count = 1, .reusable = true}}, SHIFT(463),
+  [1821] = {.entry = {.count = 1, .reusable = true}}, SHIFT(139),
+  [1823] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_and, 1, 0, 0),
+  [1825] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_and, 1, 0, 0),
+  [1827] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_or, 1, 0, 0),
+  [1829] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_or, 1, 0, 0),
+  [1831] = {.entry = {.count = 1, .reusable = true}}, SHIFT(282),
+  [1833] = {.entry = {.count = 1, .reusable = true}}, SHIFT(501),
+  [1835] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_at_rule_repeat1, 2, 0, 50),
+  [1837] = {.entry = {.count = 1, .reusable = true}}, SHIFT(711),
+  [1839] = {.entry = {.count = 1, .reusable = true}}, SHIFT(96),
+  [1841] = {.entry = {.count = 1, .reusable = true}}, SHIFT(302),
+  [1843] = {.entry = {.count = 1, .reusable = true}}, SHIFT(479),
+  [1845] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__query, 1, 0, 6),
+  [1847] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_import_statement_repeat1, 2, 0, 81),
+  [1849] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesized_query, 3, 0, 50),
+  [1851] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_selector_query, 4, 0, 84),
+  [1853] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_media_statement_repeat1, 2, 0, 51),
+  [1855] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_feature_query, 5, 0, 103),
+  [1857] = {.entry = {.count = 1, .reusable = true}}, SHIFT(129),
+  [1859] = {.entry = {.count = 1, .reusable = true}}, SHIFT(260),
+  [1861] = {.entry = {.count = 1, .reusable = true}}, SHIFT(161),
+  [1863] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_last_declaration, 5, 0, 107),
+  [1865] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
+  [1867] = {.entry = {.count = 1, .reusable = true}}, SHIFT(734),
+  [1869] = {.entry = {.count = 1, .reusable = false}}, SHIFT(526),
+  [1871] = {.entry = {.count = 1, .reusable = true}}, SHIFT(539),
+  [1873] = {.entry = {.count = 1, .reusable = true}}, SHIFT(44),
+  [1875] = {.entry = {.count = 1, .reusable = true}}, SHIFT(729),
+  [1877] = {.entry = {.count = 1, .reusable = false}}, SHIFT(532),
+  [1879] = {.entry = {.count = 1, .reusable = true}}, SHIFT(154),
+  [1881] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_last_declaration, 4, 0, 89),
+  [1883] = {.entry = {.count = 1, .reusable = true}}, SHIFT(144),
+  [1885] = {.entry = {.count = 1, .reusable = true}}, SHIFT(189),
+  [1887] = {.entry = {.count = 1, .reusable = false}}, SHIFT(189),
+  [1889] = {.entry = {.count = 1, .reusable = true}}, SHIFT(74),
+  [1891] = {.entry = {.count = 1, .reusable = true}}, SHIFT(668),
+  [1893] = {.entry = {.count = 1, .reusable = true}}, SHIFT(670),
+  [1895] = {.entry = {.count = 1, .reusable = true}}, SHIFT(650),
+  [1897] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
+  [1899] = {.entry = {.count = 1, .reusable = true}}, SHIFT(642),
+  [1901] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_arguments_repeat1, 2, 0, 78), SHIFT_REPEAT(270),
+  [1904] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_arguments_repeat1, 2, 0, 78),
+  [1906] = {.entry = {.count = 1, .reusable = true}}, SHIFT(240),
+  [1908] = {.entry = {.count = 1, .reusable = true}}, SHIFT(577),
+  [1910] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_at_rule_repeat1, 2, 0, 62), SHIFT_REPEAT(465),
+  [1913] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_at_rule_repeat1, 2, 0, 62),
+  [1915] = {.entry = {.count = 1, .reusable = true}}, SHIFT(84),
+  [1917] = {.entry = {.count = 1, .reusable = true}}, SHIFT(658),
+  [1919] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
+  [1921] = {.entry = {.count = 1, .reusable = true}}, SHIFT(223),
+  [1923] = {.entry = {.count = 1, .reusable = false}}, SHIFT(223),
+  [1925] = {.entry = {.count = 1, .reusable = true}}, SHIFT(371),
+  [1927] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
+  [1929] = {.entry = {.count = 1, .reusable = true}}, SHIFT(666),
+  [1931] = {.entry = {.count = 1, .reusable = true}}, SHIFT(366),
+  [1933] = {.entry = {.count = 1, .reusable = false}}, SHIFT(366),
+  [1935] = {.entry = {.count = 1, .reusable = true}}, SHIFT(196),
+  [1937] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
+  [1939] = {.entry = {.count = 1, .reusable = true}}, SHIFT(639),
+  [1941] = {.entry = {.count = 1, .reusable = true}}, SHIFT(640),
+  [1943] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_keyframe_block, 2, 0, 85),
+  [1945] = {.entry = {.count = 1, .reusable = true}}, SHIFT(699),
+  [1947] = {.entry = {.count = 1, .reusable = true}}, SHIFT(607),
+  [1949] = {.entry = {.count = 1, .reusable = true}}, SHIFT(609),
+  [1951] = {.entry = {.count = 1, .reusable = true}}, SHIFT(183),
+  [1953] = {.entry = {.count = 1, .reusable = false}}, SHIFT(183),
+  [1955] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_forward_statement_repeat1, 2, 0, 112), SHIFT_REPEAT(650),
+  [1958] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_forward_statement_repeat1, 2, 0, 112),
+  [1960] = {.entry = {.count = 1, .reusable = true}}, SHIFT(393),
+  [1962] = {.entry = {.count = 1, .reusable = true}}, SHIFT(75),
+  [1964] = {.entry = {.count = 1, .reusable = true}}, SHIFT(672),
+  [1966] = {.entry = {.count = 1, .reusable = true}}, SHIFT(277),
+  [1968] = {.entry = {.count = 1, .reusable = true}}, SHIFT(244),
+  [1970] = {.entry = {.count = 1, .reusable = false}}, SHIFT(244),
+  [1972] = {.entry = {.count = 1, .reusable = true}}, SHIFT(147),
+  [1974] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__include_arguments_repeat1, 2, 0, 117), SHIFT_REPEAT(273),
+  [1977] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__include_arguments_repeat1, 2, 0, 117),
+  [1979] = {.entry = {.count = 1, .reusable = true}}, SHIFT(483),
+  [1981] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_list_value_repeat1, 2, 0, 78), SHIFT_REPEAT(310),
+  [1984] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_list_value_repeat1, 2, 0, 78),
+  [1986] = {.entry = {.count = 1, .reusable = true}}, SHIFT(275),
+  [1988] = {.entry = {.count = 1, .reusable = true}}, SHIFT(378),
+  [1990] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_selectors, 2, 0, 15),
+  [1992] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_media_statement_repeat1, 2, 0, 54), SHIFT_REPEAT(468),
+  [1995] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_media_statement_repeat1, 2, 0, 54),
+  [1997] = {.entry = {.count = 1, .reusable = true}}, SHIFT(261),
+  [1999] = {.entry = {.count = 1, .reusable = true}}, SHIFT(374),
+  [2001] = {.entry = {.count = 1, .reusable = true}}, SHIFT(449),
+  [2003] = {.entry = {.count = 1, .reusable = true}}, SHIFT(498),
+  [2005] = {.entry = {.count = 1, .reusable = true}}, SHIFT(499),
+  [2007] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_import_statement_repeat1, 2, 0, 83), SHIFT_REPEAT(463),
+  [2010] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_import_statement_repeat1, 2, 0, 83),
+  [2012] = {.entry = {.count = 1, .reusable = true}}, SHIFT(259),
+  [2014] = {.entry = {.count = 1, .reusable = true}}, SHIFT(236),
+  [2016] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_parameters_repeat1, 2, 0, 114), SHIFT_REPEAT(610),
+  [2019] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_parameters_repeat1, 2, 0, 114),
+  [2021] = {.entry = {.count = 1, .reusable = true}}, SHIFT(271),
+  [2023] = {.entry = {.count = 1, .reusable = true}}, SHIFT(245),
+  [2025] = {.entry = {.count = 1, .reusable = true}}, SHIFT(262),
+  [2027] = {.entry = {.count = 1, .reusable = true}}, SHIFT(247),
+  [2029] = {.entry = {.count = 1, .reusable = true}}, SHIFT(152),
+  [2031] = {.entry = {.count = 1, .reusable = true}}, SHIFT(342),
+  [2033] = {.entry = {.count = 1, .reusable = true}}, SHIFT(610),
+  [2035] = {.entry = {.count = 1, .reusable = true}}, SHIFT(693),
+  [2037] = {.entry = {.count = 1, .reusable = true}}, SHIFT(643),
+  [2039] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameter, 1, 0, 63),
+  [2041] = {.entry = {.count = 1, .reusable = true}}, SHIFT(285),
+  [2043] = {.entry = {.count = 1, .reusable = true}}, SHIFT(660),
+  [2045] = {.entry = {.count = 1, .reusable = true}}, SHIFT(265),
+  [2047] = {.entry = {.count = 1, .reusable = true}}, SHIFT(206),
+  [2049] = {.entry = {.count = 1, .reusable = true}}, SHIFT(274),
+  [2051] = {.entry = {.count = 1, .reusable = true}}, SHIFT(203),
+  [2053] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_pseudo_class_arguments_repeat2, 2, 0, 105), SHIFT_REPEAT(20),
+  [2056] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_pseudo_class_arguments_repeat2, 2, 0, 105),
+  [2058] = {.entry = {.count = 1, .reusable = true}}, SHIFT(264),
+  [2060] = {.entry = {.count = 1, .reusable = true}}, SHIFT(205),
+  [2062] = {.entry = {.count = 1, .reusable = true}}, SHIFT(648),
+  [2064] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_map_value_repeat1, 2, 0, 80), SHIFT_REPEAT(272),
+  [2067] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_map_value_repeat1, 2, 0, 80),
+  [2069] = {.entry = {.count = 1, .reusable = true}}, SHIFT(256),
+  [2071] = {.entry = {.count = 1, .reusable = true}}, SHIFT(380),
+  [2073] = {.entry = {.count = 1, .reusable = true}}, SHIFT(633),
+  [2075] = {.entry = {.count = 1, .reusable = true}}, SHIFT(563),
+  [2077] = {.entry = {.count = 1, .reusable = true}}, SHIFT(478),
+  [2079] = {.entry = {.count = 1, .reusable = true}}, SHIFT(575),
+  [2081] = {.entry = {.count = 1, .reusable = true}}, SHIFT(544),
+  [2083] = {.entry = {.count = 1, .reusable = true}}, SHIFT(580),
+  [2085] = {.entry = {.count = 1, .reusable = true}}, SHIFT(287),
+  [2087] = {.entry = {.count = 1, .reusable = true}}, SHIFT(47),
+  [2089] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
+  [2091] = {.entry = {.count = 1, .reusable = true}}, SHIFT(546),
+  [2093] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_selectors_repeat1, 2, 0, 43), SHIFT_REPEAT(246),
+  [2096] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_selectors_repeat1, 2, 0, 43),
+  [2098] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_forward_statement_repeat1, 2, 0, 110),
+  [2100] = {.entry = {.count = 1, .reusable = true}}, SHIFT(726),
+  [2102] = {.entry = {.count = 1, .reusable = true}}, SHIFT(570),
+  [2104] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__include_arguments, 4, 0, 119),
+  [2106] = {.entry = {.count = 1, .reusable = true}}, SHIFT(162),
+  [2108] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_last_declaration, 5, 0, 108),
+  [2110] = {.entry = {.count = 1, .reusable = true}}, SHIFT(731),
+  [2112] = {.entry = {.count = 1, .reusable = true}}, SHIFT(308),
+  [2114] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_map_value_repeat1, 2, 0, 44),
+  [2116] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__include_arguments_repeat1, 2, 0, 99),
+  [2118] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__include_arguments, 2, 0, 0),
+  [2120] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
+  [2122] = {.entry = {.count = 1, .reusable = true}}, SHIFT(661),
+  [2124] = {.entry = {.count = 1, .reusable = true}}, SHIFT(63),
+  [2126] = {.entry = {.count = 1, .reusable = true}}, SHIFT(675),
+  [2128] = {.entry = {.count = 1, .reusable = true}}, SHIFT(163),
+  [2130] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_last_declaration, 6, 0, 121),
+  [2132] = {.entry = {.count = 1, .reusable = true}}, SHIFT(276),
+  [2134] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__include_arguments, 4, 0, 116),
+  [2136] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_default_flag, 1, 0, 0),
+  [2138] = {.entry = {.count = 1, .reusable = false}}, SHIFT(559),
+  [2140] = {.entry = {.count = 1, .reusable = true}}, SHIFT(559),
+  [2142] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__include_arguments, 3, 0, 99),
+  [2144] = {.entry = {.count = 1, .reusable = false}}, SHIFT(249),
+  [2146] = {.entry = {.count = 1, .reusable = false}}, SHIFT(502),
+  [2148] = {.entry = {.count = 1, .reusable = false}}, SHIFT(629),
+  [2150] = {.entry = {.count = 1, .reusable = true}}, SHIFT(629),
+  [2152] = {.entry = {.count = 1, .reusable = true}}, SHIFT(515),
+  [2154] = {.entry = {.count = 1, .reusable = false}}, SHIFT(201),
+  [2156] = {.entry = {.count = 1, .reusable = true}}, SHIFT(155),
+  [2158] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_last_declaration, 4, 0, 90),
+  [2160] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_parameters_repeat1, 2, 0, 97),
+  [2162] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
+  [2164] = {.entry = {.count = 1, .reusable = true}}, SHIFT(520),
+  [2166] = {.entry = {.count = 1, .reusable = true}}, SHIFT(364),
+  [2168] = {.entry = {.count = 1, .reusable = false}}, SHIFT(565),
+  [2170] = {.entry = {.count = 1, .reusable = true}}, SHIFT(565),
+  [2172] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__include_arguments, 3, 0, 98),
+  [2174] = {.entry = {.count = 1, .reusable = true}}, SHIFT(741),
+  [2176] = {.entry = {.count = 1, .reusable = true}}, SHIFT(341),
+  [2178] = {.entry = {.count = 1, .reusable = true}}, SHIFT(160),
+  [2180] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_last_declaration, 5, 0, 106),
+  [2182] = {.entry = {.count = 1, .reusable = true}}, SHIFT(289),
+  [2184] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
+  [2186] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
+  [2188] = {.entry = {.count = 1, .reusable = true}}, SHIFT(140),
+  [2190] = {.entry = {.count = 1, .reusable = true}}, SHIFT(651),
+  [2192] = {.entry = {.count = 1, .reusable = true}}, SHIFT(316),
+  [2194] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [2196] = {.entry = {.count = 1, .reusable = true}}, SHIFT(435),
+  [2198] = {.entry = {.count = 1, .reusable = true}}, SHIFT(370),
+  [2200] = {.entry = {.count = 1, .reusable = true}}, SHIFT(446),
+  [2202] = {.entry = {.count = 1, .reusable = true}}, SHIFT(58),
+  [2204] = {.entry = {.count = 1, .reusable = true}}, SHIFT(73),
+  [2206] = {.entry = {.count = 1, .reusable = true}}, SHIFT(739),
+  [2208] = {.entry = {.count = 1, .reusable = true}}, SHIFT(555),
+  [2210] = {.entry = {.count = 1, .reusable = true}}, SHIFT(534),
+  [2212] = {.entry = {.count = 1, .reusable = true}}, SHIFT(588),
+  [2214] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameters, 3, 0, 97),
+  [2216] = {.entry = {.count = 1, .reusable = true}}, SHIFT(249),
+  [2218] = {.entry = {.count = 1, .reusable = true}}, SHIFT(29),
+  [2220] = {.entry = {.count = 1, .reusable = true}}, SHIFT(38),
+  [2222] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
+  [2224] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
+  [2226] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameters, 2, 0, 0),
+  [2228] = {.entry = {.count = 1, .reusable = true}}, SHIFT(109),
+  [2230] = {.entry = {.count = 1, .reusable = true}}, SHIFT(111),
+  [2232] = {.entry = {.count = 1, .reusable = true}}, SHIFT(530),
+  [2234] = {.entry = {.count = 1, .reusable = true}}, SHIFT(76),
+  [2236] = {.entry = {.count = 1, .reusable = true}}, SHIFT(635),
+  [2238] = {.entry = {.count = 1, .reusable = true}}, SHIFT(447),
+  [2240] = {.entry = {.count = 1, .reusable = true}}, SHIFT(665),
+  [2242] = {.entry = {.count = 1, .reusable = true}}, SHIFT(141),
+  [2244] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_to, 1, 0, 0),
+  [2246] = {.entry = {.count = 1, .reusable = true}}, SHIFT(88),
+  [2248] = {.entry = {.count = 1, .reusable = true}}, SHIFT(439),
+  [2250] = {.entry = {.count = 1, .reusable = true}}, SHIFT(34),
+  [2252] = {.entry = {.count = 1, .reusable = true}}, SHIFT(326),
+  [2254] = {.entry = {.count = 1, .reusable = true}}, SHIFT(41),
+  [2256] = {.entry = {.count = 1, .reusable = true}}, SHIFT(201),
+  [2258] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
+  [2260] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
+  [2262] = {.entry = {.count = 1, .reusable = true}}, SHIFT(50),
+  [2264] = {.entry = {.count = 1, .reusable = true}}, SHIFT(502),
+  [2266] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
+  [2268] = {.entry = {.count = 1, .reusable = true}}, SHIFT(243),
+  [2270] = {.entry = {.count = 1, .reusable = true}}, SHIFT(61),
+  [2272] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parameters, 4, 0, 113),
+  [2274] = {.entry = {.count = 1, .reusable = true}}, SHIFT(732),
+  [2276] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
+  [2278] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_from, 1, 0, 0),
+  [2280] = {.entry = {.count = 1, .reusable = true}}, SHIFT(676),
+  [2282] = {.entry = {.count = 1, .reusable = true}}, SHIFT(309),
+  [2284] = {.entry = {.count = 1, .reusable = true}}, SHIFT(150),
+  [2286] = {.entry = {.count = 1, .reusable = true}}, SHIFT(53),
+  [2288] = {.entry = {.count = 1, .reusable = true}}, SHIFT(340),
+  [2290] = {.entry = {.count = 1, .reusable = true}}, SHIFT(168),
+  [2292] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
+  [2294] = {.entry = {.count = 1, .reusable = true}}, SHIFT(343),
+  [2296] = {.entry = {.count = 1, .reusable = true}}, SHIFT(554),
+  [2298] = {.entry = {.count = 1, .reusable = true}}, SHIFT(673),
+  [2300] = {.entry = {.count = 1, .reusable = true}}, SHIFT(738),
+  [2302] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
+  [2304] = {.entry = {.count = 1, .reusable = true}}, SHIFT(348),
+  [2306] = {.entry = {.count = 1, .reusable = true}}, SHIFT(743),
+  [2308] = {.entry = {.count = 1, .reusable = true}}, SHIFT(482),
+};
+
+enum ts_external_scanner_symbol_identifiers {
+  ts_external_token__descendant_operator = 0,
+  ts_external_token__pseudo_class_selector_colon = 1,
+  ts_external_token___error_recovery = 2,
+  ts_external_token__concat = 3,
+};
+
+static const TSSymbol ts_external_scanner_symbol_map[EXTERNAL_TOKEN_COUNT] = {
+  [ts_external_token__descendant_operator] = sym__descendant_operator,
+  [ts_external_token__pseudo_class_selector_colon] = sym__pseudo_class_selector_colon,
+  [ts_external_token___error_recovery] = sym___error_recovery,
+  [ts_external_token__concat] = sym__concat,
+};
+
+static const bool ts_external_scanner_states[5][EXTERNAL_TOKEN_COUNT] = {
+  [1] = {
+    [ts_external_token__descendant_operator] = true,
+    [ts_external_token__pseudo_class_selector_colon] = true,
+    [ts_external_token___error_recovery] = true,
+    [ts_external_token__concat] = true,
+  },
+  [2] = {
+    [ts_external_token__descendant_operator] = true,
+  },
+  [3] = {
+    [ts_external_token__descendant_operator] = true,
+    [ts_external_token__concat] = true,
+  },
+  [4] = {
+    [ts_external_token__concat] = true,
+  },
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+void *tree_sitter_scss_external_scanner_create(void);
+void tree_sitter_scss_external_scanner_destroy(void *);
+bool tree_sitter_scss_external_scanner_scan(void *, TSLexer *, const bool *);
+unsigned tree_sitter_scss_external_scanner_serialize(void *, char *);
+void tree_sitter_scss_external_scanner_deserialize(void *, const char *, unsigned);
+
+#ifdef TREE_SITTER_HIDE_SYMBOLS
+#define TS_PUBLIC
+#elif defined(_WIN32)
+#define TS_PUBLIC __declspec(dllexport)
+#else
+#define TS_PUBLIC __attribute__((visibility("default")))
+#endif
+
+TS_PUBLIC const TSLanguage *tree_sitter_scss(void) {
+  static const TSLanguage language = {
+    .version = LANGUAGE_VERSION,
+    .symbol_count = SYMBOL_COUNT,
+    .alias_count = ALIAS_COUNT,
+    .token_count = TOKEN_COUNT,
+    .external_token_count = EXTERNAL_TOKEN_COUNT,
+    .state_count = STATE_COUNT,
+    .large_state_count = LARGE_STATE_COUNT,
+    .production_id_count = PRODUCTION_ID_COUNT,
+    .field_count = FIELD_COUNT,
+    .max_alias_sequence_length = MAX_ALIAS_SEQUENCE_LENGTH,
+    .parse_table = &ts_parse_table[0][0],
+    .small_parse_table = ts_small_parse_table,
+    .small_parse_table_map = ts_small_parse_table_map,
+    .parse_actions = ts_parse_actions,
+    .symbol_names = ts_symbol_names,
+    .field_names = ts_field_names,
+    .field_map_slices = ts_field_map_slices,
+    .field_map_entries = ts_field_map_entries,
+    .symbol_metadata = ts_symbol_metadata,
+    .public_symbol_map = ts_symbol_map,
+    .alias_map = ts_non_terminal_alias_map,
+    .alias_sequences = &ts_alias_sequences[0][0],
+    .lex_modes = ts_lex_modes,
+    .lex_fn = ts_lex,
+    .external_scanner = {
+      &ts_external_scanner_states[0][0],
+      ts_external_scanner_symbol_map,
+      tree_sitter_scss_external_scanner_create,
+      tree_sitter_scss_external_scanner_destroy,
+      tree_sitter_scss_external_scanner_scan,
+      tree_sitter_scss_external_scanner_serialize,
+      tree_sitter_scss_external_scanner_deserialize,
+    },
+    .primary_state_ids = ts_primary_state_ids,
+  };
+  return &language;
+}
+#ifdef __cplusplus
+}
+#endif
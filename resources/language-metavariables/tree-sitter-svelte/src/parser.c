@@ -0,0 +1,9800 @@
+#include "tree_sitter/parser.h"
+
+#if defined(__GNUC__) || defined(__clang__)
+#pragma GCC diagnostic ignored "-Wmissing-field-initializers"
+#endif
+
+#define LANGUAGE_VERSION 14
+#define STATE_COUNT 351
+#define LARGE_STATE_COUNT 2
+#define SYMBOL_COUNT 122
+#define ALIAS_COUNT 0
+#define TOKEN_COUNT 53
+#define EXTERNAL_TOKEN_COUNT 16
+#define FIELD_COUNT 4
+#define MAX_ALIAS_SEQUENCE_LENGTH 7
+#define PRODUCTION_ID_COUNT 8
+
+enum ts_symbol_identifiers {
+  anon_sym_LT_BANG = 1,
+  aux_sym_doctype_token1 = 2,
+  anon_sym_GT = 3,
+  sym__doctype = 4,
+  anon_sym_LT = 5,
+  anon_sym_SLASH_GT = 6,
+  anon_sym_LT_SLASH = 7,
+  anon_sym_EQ = 8,
+  aux_sym_attribute_name_token1 = 9,
+  aux_sym_attribute_value_token1 = 10,
+  sym_entity = 11,
+  anon_sym_SQUOTE = 12,
+  anon_sym_DQUOTE = 13,
+  aux_sym_text_token1 = 14,
+  aux_sym__single_quoted_attribute_value_token1 = 15,
+  aux_sym__double_quoted_attribute_value_token1 = 16,
+  anon_sym_POUND = 17,
+  anon_sym_if = 18,
+  anon_sym_LBRACE = 19,
+  anon_sym_RBRACE = 20,
+  anon_sym_COLON = 21,
+  anon_sym_elseif = 22,
+  anon_sym_else = 23,
+  anon_sym_SLASH = 24,
+  anon_sym_each = 25,
+  anon_sym_as = 26,
+  anon_sym_await = 27,
+  anon_sym_then = 28,
+  anon_sym_catch = 29,
+  anon_sym_key = 30,
+  anon_sym_snippet = 31,
+  aux_sym_snippet_start_token1 = 32,
+  anon_sym_LPAREN = 33,
+  anon_sym_RPAREN = 34,
+  aux_sym__tag_value_token1 = 35,
+  anon_sym_AT = 36,
+  anon_sym_html = 37,
+  anon_sym_const = 38,
+  anon_sym_debug = 39,
+  anon_sym_render = 40,
+  sym_grit_metavariable = 41,
+  sym__start_tag_name = 42,
+  sym__script_start_tag_name = 43,
+  sym__style_start_tag_name = 44,
+  sym__end_tag_name = 45,
+  sym_erroneous_end_tag_name = 46,
+  sym__implicit_end_tag = 47,
+  sym_raw_text = 48,
+  sym_comment = 49,
+  sym_svelte_raw_text = 50,
+  sym_svelte_raw_text_each = 51,
+  sym_svelte_raw_text_snippet_arguments = 52,
+  sym_document = 53,
+  sym_doctype = 54,
+  sym__node = 55,
+  sym_element = 56,
+  sym_script_element = 57,
+  sym_style_element = 58,
+  sym_start_tag = 59,
+  sym_script_start_tag = 60,
+  sym_style_start_tag = 61,
+  sym_self_closing_tag = 62,
+  sym_end_tag = 63,
+  sym_erroneous_end_tag = 64,
+  sym_attribute = 65,
+  sym_attribute_name = 66,
+  sym_attribute_value = 67,
+  sym_quoted_attribute_value = 68,
+  sym_text = 69,
+  aux_sym__single_quoted_attribute_value = 70,
+  aux_sym__double_quoted_attribute_value = 71,
+  sym_if_statement = 72,
+  sym__if_start_tag = 73,
+  sym_if_start = 74,
+  sym__else_if_tag = 75,
+  sym_else_if_start = 76,
+  sym_else_if_block = 77,
+  sym__else_tag = 78,
+  sym_else_start = 79,
+  sym_else_block = 80,
+  sym__if_end_tag = 81,
+  sym_if_end = 82,
+  sym_each_statement = 83,
+  sym__each_start_tag = 84,
+  sym_each_start = 85,
+  sym__each_end_tag = 86,
+  sym_each_end = 87,
+  sym_await_statement = 88,
+  sym__await_start_tag = 89,
+  sym_await_start = 90,
+  sym__then_tag = 91,
+  sym_then_start = 92,
+  sym_then_block = 93,
+  sym__catch_tag = 94,
+  sym_catch_start = 95,
+  sym_catch_block = 96,
+  sym__await_end_tag = 97,
+  sym_await_end = 98,
+  sym_key_statement = 99,
+  sym__key_start_tag = 100,
+  sym_key_start = 101,
+  sym__key_end_tag = 102,
+  sym_key_end = 103,
+  sym_snippet_statement = 104,
+  sym__snippet_start_tag = 105,
+  sym_snippet_start = 106,
+  sym__snippet_end_tag = 107,
+  sym_snippet_end = 108,
+  sym_expression = 109,
+  sym__tag_value = 110,
+  sym__html_tag = 111,
+  sym_html_tag = 112,
+  sym__const_tag = 113,
+  sym_const_tag = 114,
+  sym__debug_tag = 115,
+  sym_debug_tag = 116,
+  sym__render_tag = 117,
+  sym_render_tag = 118,
+  aux_sym_document_repeat1 = 119,
+  aux_sym_start_tag_repeat1 = 120,
+  aux_sym_if_statement_repeat1 = 121,
+};
+
+static const char * const ts_symbol_names[] = {
+  [ts_builtin_sym_end] = "end",
+  [anon_sym_LT_BANG] = "<!",
+  [aux_sym_doctype_token1] = "doctype_token1",
+  [anon_sym_GT] = ">",
+  [sym__doctype] = "doctype",
+  [anon_sym_LT] = "<",
+  [anon_sym_SLASH_GT] = "/>",
+  [anon_sym_LT_SLASH] = "</",
+  [anon_sym_EQ] = "=",
+  [aux_sym_attribute_name_token1] = "attribute_name_token1",
+  [aux_sym_attribute_value_token1] = "attribute_value_token1",
+  [sym_entity] = "entity",
+  [anon_sym_SQUOTE] = "'",
+  [anon_sym_DQUOTE] = "\"",
+  [aux_sym_text_token1] = "text_token1",
+  [aux_sym__single_quoted_attribute_value_token1] = "_single_quoted_attribute_value_token1",
+  [aux_sym__double_quoted_attribute_value_token1] = "_double_quoted_attribute_value_token1",
+  [anon_sym_POUND] = "#",
+  [anon_sym_if] = "if",
+  [anon_sym_LBRACE] = "{",
+  [anon_sym_RBRACE] = "}",
+  [anon_sym_COLON] = ":",
+  [anon_sym_elseif] = "else if",
+  [anon_sym_else] = "else",
+  [anon_sym_SLASH] = "/",
+  [anon_sym_each] = "each",
+  [anon_sym_as] = "as",
+  [anon_sym_await] = "await",
+  [anon_sym_then] = "then",
+  [anon_sym_catch] = "catch",
+  [anon_sym_key] = "key",
+  [anon_sym_snippet] = "snippet",
+  [aux_sym_snippet_start_token1] = "snippet_name",
+  [anon_sym_LPAREN] = "(",
+  [anon_sym_RPAREN] = ")",
+  [aux_sym__tag_value_token1] = "_tag_value_token1",
+  [anon_sym_AT] = "@",
+  [anon_sym_html] = "html",
+  [anon_sym_const] = "const",
+  [anon_sym_debug] = "debug",
+  [anon_sym_render] = "render",
+  [sym_grit_metavariable] = "grit_metavariable",
+  [sym__start_tag_name] = "tag_name",
+  [sym__script_start_tag_name] = "tag_name",
+  [sym__style_start_tag_name] = "tag_name",
+  [sym__end_tag_name] = "tag_name",
+  [sym_erroneous_end_tag_name] = "erroneous_end_tag_name",
+  [sym__implicit_end_tag] = "_implicit_end_tag",
+  [sym_raw_text] = "raw_text",
+  [sym_comment] = "comment",
+  [sym_svelte_raw_text] = "svelte_raw_text",
+  [sym_svelte_raw_text_each] = "svelte_raw_text",
+  [sym_svelte_raw_text_snippet_arguments] = "svelte_raw_text",
+  [sym_document] = "document",
+  [sym_doctype] = "doctype",
+  [sym__node] = "_node",
+  [sym_element] = "element",
+  [sym_script_element] = "script_element",
+  [sym_style_element] = "style_element",
+  [sym_start_tag] = "start_tag",
+  [sym_script_start_tag] = "start_tag",
+  [sym_style_start_tag] = "start_tag",
+  [sym_self_closing_tag] = "self_closing_tag",
+  [sym_end_tag] = "end_tag",
+  [sym_erroneous_end_tag] = "erroneous_end_tag",
+  [sym_attribute] = "attribute",
+  [sym_attribute_name] = "attribute_name",
+  [sym_attribute_value] = "attribute_value",
+  [sym_quoted_attribute_value] = "quoted_attribute_value",
+  [sym_text] = "text",
+  [aux_sym__single_quoted_attribute_value] = "_single_quoted_attribute_value",
+  [aux_sym__double_quoted_attribute_value] = "_double_quoted_attribute_value",
+  [sym_if_statement] = "if_statement",
+  [sym__if_start_tag] = "block_start_tag",
+  [sym_if_start] = "if_start",
+  [sym__else_if_tag] = "block_tag",
+  [sym_else_if_start] = "else_if_start",
+  [sym_else_if_block] = "else_if_block",
+  [sym__else_tag] = "block_tag",
+  [sym_else_start] = "else_start",
+  [sym_else_block] = "else_block",
+  [sym__if_end_tag] = "block_end_tag",
+  [sym_if_end] = "if_end",
+  [sym_each_statement] = "each_statement",
+  [sym__each_start_tag] = "block_start_tag",
+  [sym_each_start] = "each_start",
+  [sym__each_end_tag] = "block_end_tag",
+  [sym_each_end] = "each_end",
+  [sym_await_statement] = "await_statement",
+  [sym__await_start_tag] = "block_start_tag",
+  [sym_await_start] = "await_start",
+  [sym__then_tag] = "block_tag",
+  [sym_then_start] = "then_start",
+  [sym_then_block] = "then_block",
+  [sym__catch_tag] = "block_tag",
+  [sym_catch_start] = "catch_start",
+  [sym_catch_block] = "catch_block",
+  [sym__await_end_tag] = "block_end_tag",
+  [sym_await_end] = "await_end",
+  [sym_key_statement] = "key_statement",
+  [sym__key_start_tag] = "block_start_tag",
+  [sym_key_start] = "key_start",
+  [sym__key_end_tag] = "block_end_tag",
+  [sym_key_end] = "key_end",
+  [sym_snippet_statement] = "snippet_statement",
+  [sym__snippet_start_tag] = "block_start_tag",
+  [sym_snippet_start] = "snippet_start",
+  [sym__snippet_end_tag] = "block_end_tag",
+  [sym_snippet_end] = "snippet_end",
+  [sym_expression] = "expression",
+  [sym__tag_value] = "_tag_value",
+  [sym__html_tag] = "expression_tag",
+  [sym_html_tag] = "html_tag",
+  [sym__const_tag] = "expression_tag",
+  [sym_const_tag] = "const_tag",
+  [sym__debug_tag] = "expression_tag",
+  [sym_debug_tag] = "debug_tag",
+  [sym__render_tag] = "expression_tag",
+  [sym_render_tag] = "render_tag",
+  [aux_sym_document_repeat1] = "document_repeat1",
+  [aux_sym_start_tag_repeat1] = "start_tag_repeat1",
+  [aux_sym_if_statement_repeat1] = "if_statement_repeat1",
+};
+
+static const TSSymbol ts_symbol_map[] = {
+  [ts_builtin_sym_end] = ts_builtin_sym_end,
+  [anon_sym_LT_BANG] = anon_sym_LT_BANG,
+  [aux_sym_doctype_token1] = aux_sym_doctype_token1,
+  [anon_sym_GT] = anon_sym_GT,
+  [sym__doctype] = sym__doctype,
+  [anon_sym_LT] = anon_sym_LT,
+  [anon_sym_SLASH_GT] = anon_sym_SLASH_GT,
+  [anon_sym_LT_SLASH] = anon_sym_LT_SLASH,
+  [anon_sym_EQ] = anon_sym_EQ,
+  [aux_sym_attribute_name_token1] = aux_sym_attribute_name_token1,
+  [aux_sym_attribute_value_token1] = aux_sym_attribute_value_token1,
+  [sym_entity] = sym_entity,
+  [anon_sym_SQUOTE] = anon_sym_SQUOTE,
+  [anon_sym_DQUOTE] = anon_sym_DQUOTE,
+  [aux_sym_text_token1] = aux_sym_text_token1,
+  [aux_sym__single_quoted_attribute_value_token1] = aux_sym__single_quoted_attribute_value_token1,
+  [aux_sym__double_quoted_attribute_value_token1] = aux_sym__double_quoted_attribute_value_token1,
+  [anon_sym_POUND] = anon_sym_POUND,
+  [anon_sym_if] = anon_sym_if,
+  [anon_sym_LBRACE] = anon_sym_LBRACE,
+  [anon_sym_RBRACE] = anon_sym_RBRACE,
+  [anon_sym_COLON] = anon_sym_COLON,
+  [anon_sym_elseif] = anon_sym_elseif,
+  [anon_sym_else] = anon_sym_else,
+  [anon_sym_SLASH] = anon_sym_SLASH,
+  [anon_sym_each] = anon_sym_each,
+  [anon_sym_as] = anon_sym_as,
+  [anon_sym_await] = anon_sym_await,
+  [anon_sym_then] = anon_sym_then,
+  [anon_sym_catch] = anon_sym_catch,
+  [anon_sym_key] = anon_sym_key,
+  [anon_sym_snippet] = anon_sym_snippet,
+  [aux_sym_snippet_start_token1] = aux_sym_snippet_start_token1,
+  [anon_sym_LPAREN] = anon_sym_LPAREN,
+  [anon_sym_RPAREN] = anon_sym_RPAREN,
+  [aux_sym__tag_value_token1] = aux_sym__tag_value_token1,
+  [anon_sym_AT] = anon_sym_AT,
+  [anon_sym_html] = anon_sym_html,
+  [anon_sym_const] = anon_sym_const,
+  [anon_sym_debug] = anon_sym_debug,
+  [anon_sym_render] = anon_sym_render,
+  [sym_grit_metavariable] = sym_grit_metavariable,
+  [sym__start_tag_name] = sym__start_tag_name,
+  [sym__script_start_tag_name] = sym__start_tag_name,
+  [sym__style_start_tag_name] = sym__start_tag_name,
+  [sym__end_tag_name] = sym__start_tag_name,
+  [sym_erroneous_end_tag_name] = sym_erroneous_end_tag_name,
+  [sym__implicit_end_tag] = sym__implicit_end_tag,
+  [sym_raw_text] = sym_raw_text,
+  [sym_comment] = sym_comment,
+  [sym_svelte_raw_text] = sym_svelte_raw_text,
+  [sym_svelte_raw_text_each] = sym_svelte_raw_text,
+  [sym_svelte_raw_text_snippet_arguments] = sym_svelte_raw_text,
+  [sym_document] = sym_document,
+  [sym_doctype] = sym_doctype,
+  [sym__node] = sym__node,
+  [sym_element] = sym_element,
+  [sym_script_element] = sym_script_element,
+  [sym_style_element] = sym_style_element,
+  [sym_start_tag] = sym_start_tag,
+  [sym_script_start_tag] = sym_start_tag,
+  [sym_style_start_tag] = sym_start_tag,
+  [sym_self_closing_tag] = sym_self_closing_tag,
+  [sym_end_tag] = sym_end_tag,
+  [sym_erroneous_end_tag] = sym_erroneous_end_tag,
+  [sym_attribute] = sym_attribute,
+  [sym_attribute_name] = sym_attribute_name,
+  [sym_attribute_value] = sym_attribute_value,
+  [sym_quoted_attribute_value] = sym_quoted_attribute_value,
+  [sym_text] = sym_text,
+  [aux_sym__single_quoted_attribute_value] = aux_sym__single_quoted_attribute_value,
+  [aux_sym__double_quoted_attribute_value] = aux_sym__double_quoted_attribute_value,
+  [sym_if_statement] = sym_if_statement,
+  [sym__if_start_tag] = sym__if_start_tag,
+  [sym_if_start] = sym_if_start,
+  [sym__else_if_tag] = sym__else_if_tag,
+  [sym_else_if_start] = sym_else_if_start,
+  [sym_else_if_block] = sym_else_if_block,
+  [sym__else_tag] = sym__else_if_tag,
+  [sym_else_start] = sym_else_start,
+  [sym_else_block] = sym_else_block,
+  [sym__if_end_tag] = sym__if_end_tag,
+  [sym_if_end] = sym_if_end,
+  [sym_each_statement] = sym_each_statement,
+  [sym__each_start_tag] = sym__if_start_tag,
+  [sym_each_start] = sym_each_start,
+  [sym__each_end_tag] = sym__if_end_tag,
+  [sym_each_end] = sym_each_end,
+  [sym_await_statement] = sym_await_statement,
+  [sym__await_start_tag] = sym__if_start_tag,
+  [sym_await_start] = sym_await_start,
+  [sym__then_tag] = sym__else_if_tag,
+  [sym_then_start] = sym_then_start,
+  [sym_then_block] = sym_then_block,
+  [sym__catch_tag] = sym__else_if_tag,
+  [sym_catch_start] = sym_catch_start,
+  [sym_catch_block] = sym_catch_block,
+  [sym__await_end_tag] = sym__if_end_tag,
+  [sym_await_end] = sym_await_end,
+  [sym_key_statement] = sym_key_statement,
+  [sym__key_start_tag] = sym__if_start_tag,
+  [sym_key_start] = sym_key_start,
+  [sym__key_end_tag] = sym__if_end_tag,
+  [sym_key_end] = sym_key_end,
+  [sym_snippet_statement] = sym_snippet_statement,
+  [sym__snippet_start_tag] = sym__if_start_tag,
+  [sym_snippet_start] = sym_snippet_start,
+  [sym__snippet_end_tag] = sym__if_end_tag,
+  [sym_snippet_end] = sym_snippet_end,
+  [sym_expression] = sym_expression,
+  [sym__tag_value] = sym__tag_value,
+  [sym__html_tag] = sym__html_tag,
+  [sym_html_tag] = sym_html_tag,
+  [sym__const_tag] = sym__html_tag,
+  [sym_const_tag] = sym_const_tag,
+  [sym__debug_tag] = sym__html_tag,
+  [sym_debug_tag] = sym_debug_tag,
+  [sym__render_tag] = sym__html_tag,
+  [sym_render_tag] = sym_render_tag,
+  [aux_sym_document_repeat1] = aux_sym_document_repeat1,
+  [aux_sym_start_tag_repeat1] = aux_sym_start_tag_repeat1,
+  [aux_sym_if_statement_repeat1] = aux_sym_if_statement_repeat1,
+};
+
+static const TSSymbolMetadata ts_symbol_metadata[] = {
+  [ts_builtin_sym_end] = {
+    .visible = false,
+    .named = true,
+  },
+  [anon_sym_LT_BANG] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym_doctype_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [anon_sym_GT] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym__doctype] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_SLASH_GT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LT_SLASH] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym_attribute_name_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_attribute_value_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [sym_entity] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_SQUOTE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DQUOTE] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym_text_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym__single_quoted_attribute_value_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym__double_quoted_attribute_value_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [anon_sym_POUND] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_if] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RBRACE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_COLON] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_elseif] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_else] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_SLASH] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_each] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_as] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_await] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_then] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_catch] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_key] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_snippet] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym_snippet_start_token1] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_LPAREN] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RPAREN] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym__tag_value_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [anon_sym_AT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_html] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_const] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_debug] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_render] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_grit_metavariable] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__start_tag_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__script_start_tag_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__style_start_tag_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__end_tag_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_erroneous_end_tag_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__implicit_end_tag] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_raw_text] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_comment] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_svelte_raw_text] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_svelte_raw_text_each] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_svelte_raw_text_snippet_arguments] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_document] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_doctype] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__node] = {
+    .visible = false,
+    .named = true,
+    .supertype = true,
+  },
+  [sym_element] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_script_element] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_style_element] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_start_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_script_start_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_style_start_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_self_closing_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_end_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_erroneous_end_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_attribute] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_attribute_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_attribute_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_quoted_attribute_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_text] = {
+    .visible = true,
+    .named = true,
+  },
+  [aux_sym__single_quoted_attribute_value] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym__double_quoted_attribute_value] = {
+    .visible = false,
+    .named = false,
+  },
+  [sym_if_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__if_start_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_if_start] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__else_if_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_else_if_start] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_else_if_block] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__else_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_else_start] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_else_block] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__if_end_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_if_end] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_each_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__each_start_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_each_start] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__each_end_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_each_end] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_await_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__await_start_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_await_start] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__then_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_then_start] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_then_block] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__catch_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_catch_start] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_catch_block] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__await_end_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_await_end] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_key_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__key_start_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_key_start] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__key_end_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_key_end] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_snippet_statement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__snippet_start_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_snippet_start] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__snippet_end_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_snippet_end] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_expression] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__tag_value] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym__html_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_html_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__const_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_const_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__debug_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_debug_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__render_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_render_tag] = {
+    .visible = true,
+    .named = true,
+  },
+  [aux_sym_document_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_start_tag_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_if_statement_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+};
+
+enum ts_field_identifiers {
+  field_condition = 1,
+  field_identifier = 2,
+  field_parameter = 3,
+  field_tag = 4,
+};
+
+static const char * const ts_field_names[] = {
+  [0] = NULL,
+  [field_condition] = "condition",
+  [field_identifier] = "identifier",
+  [field_parameter] = "parameter",
+  [field_tag] = "tag",
+};
+
+static const TSFieldMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
+  [2] = {.index = 0, .length = 1},
+  [3] = {.index = 1, .length = 2},
+  [4] = {.index = 3, .length = 2},
+  [5] = {.index = 5, .length = 1},
+  [6] = {.index = 6, .length = 3},
+};
+
+static const TSFieldMapEntry ts_field_map_entries[] = {
+  [0] =
+    {field_tag, 1},
+  [1] =
+    {field_condition, 2},
+    {field_tag, 1, .inherited = true},
+  [3] =
+    {field_identifier, 2},
+    {field_tag, 1, .inherited = true},
+  [5] =
+    {field_tag, 1, .inherited = true},
+  [6] =
+    {field_identifier, 2},
+    {field_parameter, 4},
+    {field_tag, 1, .inherited = true},
+};
+
+static const TSSymbol ts_alias_sequences[PRODUCTION_ID_COUNT][MAX_ALIAS_SEQUENCE_LENGTH] = {
+  [0] = {0},
+  [1] = {
+    [0] = sym_attribute_name,
+  },
+  [7] = {
+    [1] = sym_attribute_value,
+  },
+};
+
+static const uint16_t ts_non_terminal_alias_map[] = {
+  aux_sym__single_quoted_attribute_value, 2,
+    aux_sym__single_quoted_attribute_value,
+    sym_attribute_value,
+  aux_sym__double_quoted_attribute_value, 2,
+    aux_sym__double_quoted_attribute_value,
+    sym_attribute_value,
+  sym_expression, 2,
+    sym_expression,
+    sym_attribute_name,
+  0,
+};
+
+static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
+  [0] = 0,
+  [1] = 1,
+  [2] = 2,
+  [3] = 3,
+  [4] = 2,
+  [5] = 3,
+  [6] = 6,
+  [7] = 7,
+  [8] = 6,
+  [9] = 7,
+  [10] = 10,
+  [11] = 11,
+  [12] = 10,
+  [13] = 11,
+  [14] = 14,
+  [15] = 15,
+  [16] = 14,
+  [17] = 15,
+  [18] = 18,
+  [19] = 19,
+  [20] = 20,
+  [21] = 21,
+  [22] = 22,
+  [23] = 23,
+  [24] = 19,
+  [25] = 22,
+  [26] = 26,
+  [27] = 27,
+  [28] = 26,
+  [29] = 29,
+  [30] = 30,
+  [31] = 29,
+  [32] = 20,
+  [33] = 21,
+  [34] = 18,
+  [35] = 23,
+  [36] = 27,
+  [37] = 37,
+  [38] = 38,
+  [39] = 39,
+  [40] = 40,
+  [41] = 41,
+  [42] = 42,
+  [43] = 38,
+  [44] = 44,
+  [45] = 45,
+  [46] = 42,
+  [47] = 47,
+  [48] = 48,
+  [49] = 48,
+  [50] = 47,
+  [51] = 51,
+  [52] = 51,
+  [53] = 53,
+  [54] = 54,
+  [55] = 55,
+  [56] = 54,
+  [57] = 55,
+  [58] = 53,
+  [59] = 59,
+  [60] = 59,
+  [61] = 61,
+  [62] = 62,
+  [63] = 63,
+  [64] = 63,
+  [65] = 61,
+  [66] = 66,
+  [67] = 67,
+  [68] = 68,
+  [69] = 69,
+  [70] = 70,
+  [71] = 71,
+  [72] = 72,
+  [73] = 73,
+  [74] = 74,
+  [75] = 75,
+  [76] = 76,
+  [77] = 77,
+  [78] = 78,
+  [79] = 79,
+  [80] = 80,
+  [81] = 81,
+  [82] = 82,
+  [83] = 83,
+  [84] = 84,
+  [85] = 85,
+  [86] = 86,
+  [87] = 87,
+  [88] = 88,
+  [89] = 89,
+  [90] = 90,
+  [91] = 66,
+  [92] = 90,
+  [93] = 93,
+  [94] = 72,
+  [95] = 95,
+  [96] = 96,
+  [97] = 93,
+  [98] = 98,
+  [99] = 99,
+  [100] = 82,
+  [101] = 101,
+  [102] = 102,
+  [103] = 101,
+  [104] = 104,
+  [105] = 105,
+  [106] = 106,
+  [107] = 107,
+  [108] = 108,
+  [109] = 109,
+  [110] = 95,
+  [111] = 111,
+  [112] = 112,
+  [113] = 113,
+  [114] = 114,
+  [115] = 67,
+  [116] = 68,
+  [117] = 71,
+  [118] = 62,
+  [119] = 73,
+  [120] = 74,
+  [121] = 75,
+  [122] = 76,
+  [123] = 77,
+  [124] = 78,
+  [125] = 79,
+  [126] = 80,
+  [127] = 81,
+  [128] = 83,
+  [129] = 84,
+  [130] = 85,
+  [131] = 86,
+  [132] = 87,
+  [133] = 88,
+  [134] = 89,
+  [135] = 98,
+  [136] = 102,
+  [137] = 137,
+  [138] = 138,
+  [139] = 104,
+  [140] = 113,
+  [141] = 96,
+  [142] = 142,
+  [143] = 143,
+  [144] = 105,
+  [145] = 114,
+  [146] = 106,
+  [147] = 107,
+  [148] = 108,
+  [149] = 69,
+  [150] = 109,
+  [151] = 151,
+  [152] = 111,
+  [153] = 99,
+  [154] = 112,
+  [155] = 155,
+  [156] = 156,
+  [157] = 157,
+  [158] = 158,
+  [159] = 159,
+  [160] = 160,
+  [161] = 161,
+  [162] = 161,
+  [163] = 163,
+  [164] = 164,
+  [165] = 165,
+  [166] = 166,
+  [167] = 167,
+  [168] = 168,
+  [169] = 169,
+  [170] = 170,
+  [171] = 165,
+  [172] = 172,
+  [173] = 173,
+  [174] = 174,
+  [175] = 175,
+  [176] = 176,
+  [177] = 177,
+  [178] = 178,
+  [179] = 179,
+  [180] = 178,
+  [181] = 179,
+  [182] = 175,
+  [183] = 174,
+  [184] = 184,
+  [185] = 185,
+  [186] = 186,
+  [187] = 106,
+  [188] = 188,
+  [189] = 189,
+  [190] = 177,
+  [191] = 191,
+  [192] = 192,
+  [193] = 185,
+  [194] = 176,
+  [195] = 195,
+  [196] = 196,
+  [197] = 197,
+  [198] = 198,
+  [199] = 188,
+  [200] = 200,
+  [201] = 201,
+  [202] = 186,
+  [203] = 189,
+  [204] = 204,
+  [205] = 191,
+  [206] = 192,
+  [207] = 106,
+  [208] = 106,
+  [209] = 106,
+  [210] = 198,
+  [211] = 201,
+  [212] = 200,
+  [213] = 213,
+  [214] = 213,
+  [215] = 215,
+  [216] = 216,
+  [217] = 216,
+  [218] = 215,
+  [219] = 219,
+  [220] = 220,
+  [221] = 221,
+  [222] = 222,
+  [223] = 223,
+  [224] = 224,
+  [225] = 225,
+  [226] = 219,
+  [227] = 227,
+  [228] = 228,
+  [229] = 229,
+  [230] = 230,
+  [231] = 231,
+  [232] = 232,
+  [233] = 233,
+  [234] = 234,
+  [235] = 235,
+  [236] = 233,
+  [237] = 237,
+  [238] = 238,
+  [239] = 239,
+  [240] = 240,
+  [241] = 220,
+  [242] = 242,
+  [243] = 243,
+  [244] = 232,
+  [245] = 229,
+  [246] = 246,
+  [247] = 230,
+  [248] = 231,
+  [249] = 237,
+  [250] = 238,
+  [251] = 251,
+  [252] = 251,
+  [253] = 253,
+  [254] = 227,
+  [255] = 255,
+  [256] = 256,
+  [257] = 257,
+  [258] = 258,
+  [259] = 259,
+  [260] = 260,
+  [261] = 261,
+  [262] = 262,
+  [263] = 263,
+  [264] = 264,
+  [265] = 265,
+  [266] = 266,
+  [267] = 267,
+  [268] = 268,
+  [269] = 269,
+  [270] = 270,
+  [271] = 271,
+  [272] = 272,
+  [273] = 273,
+  [274] = 274,
+  [275] = 275,
+  [276] = 276,
+  [277] = 277,
+  [278] = 278,
+  [279] = 279,
+  [280] = 280,
+  [281] = 281,
+  [282] = 282,
+  [283] = 283,
+  [284] = 284,
+  [285] = 285,
+  [286] = 286,
+  [287] = 287,
+  [288] = 288,
+  [289] = 289,
+  [290] = 290,
+  [291] = 291,
+  [292] = 292,
+  [293] = 255,
+  [294] = 294,
+  [295] = 295,
+  [296] = 296,
+  [297] = 269,
+  [298] = 287,
+  [299] = 299,
+  [300] = 300,
+  [301] = 301,
+  [302] = 302,
+  [303] = 294,
+  [304] = 304,
+  [305] = 305,
+  [306] = 261,
+  [307] = 307,
+  [308] = 271,
+  [309] = 309,
+  [310] = 310,
+  [311] = 311,
+  [312] = 312,
+  [313] = 292,
+  [314] = 302,
+  [315] = 315,
+  [316] = 281,
+  [317] = 317,
+  [318] = 299,
+  [319] = 319,
+  [320] = 300,
+  [321] = 321,
+  [322] = 322,
+  [323] = 282,
+  [324] = 282,
+  [325] = 282,
+  [326] = 282,
+  [327] = 327,
+  [328] = 309,
+  [329] = 329,
+  [330] = 286,
+  [331] = 284,
+  [332] = 332,
+  [333] = 327,
+  [334] = 262,
+  [335] = 282,
+  [336] = 285,
+  [337] = 337,
+  [338] = 338,
+  [339] = 339,
+  [340] = 340,
+  [341] = 296,
+  [342] = 312,
+  [343] = 312,
+  [344] = 312,
+  [345] = 311,
+  [346] = 279,
+  [347] = 347,
+  [348] = 348,
+  [349] = 349,
+  [350] = 350,
+};
+
+static TSCharacterRange aux_sym_attribute_name_token1_character_set_1[] = {
+  {0, 0x08}, {0x0e, 0x1f}, {'!', '!'}, {'#', '&'}, {'(', '.'}, {'0', ';'}, {'?', 'z'}, {'|', '|'},
+  {'~', 0x10ffff},
+};
+
+static bool ts_lex(TSLexer *lexer, TSStateId state) {
+  START_LEXER();
+  eof = lexer->eof(lexer);
+  switch (state) {
+    case 0:
+      if (eof) ADVANCE(68);
+      ADVANCE_MAP(
+        '"', 127,
+        '#', 142,
+        '&', 4,
+        '\'', 126,
+        '(', 160,
+        ')', 161,
+        '/', 151,
+        ':', 146,
+        '<', 74,
+        '=', 77,
+        '>', 72,
+        '@', 163,
+        'D', 58,
+        'a', 45,
+        'c', 12,
+        'd', 18,
+        'e', 11,
+        'h', 49,
+        'i', 26,
+        'k', 19,
+        'r', 23,
+        's', 38,
+        't', 31,
+        '{', 144,
+        '}', 145,
+        0xb5, 6,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(162);
+      END_STATE();
+    case 1:
+      if (lookahead == '"') ADVANCE(127);
+      if (lookahead == '\'') ADVANCE(126);
+      if (lookahead == '{') ADVANCE(144);
+      if (lookahead == 0xb5) ADVANCE(83);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(162);
+      if (lookahead != 0 &&
+          (lookahead < '<' || '>' < lookahead) &&
+          lookahead != '}') ADVANCE(85);
+      END_STATE();
+    case 2:
+      if (lookahead == '"') ADVANCE(127);
+      if (lookahead == '{') ADVANCE(144);
+      if (lookahead == 0xb5) ADVANCE(138);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(140);
+      if (lookahead != 0) ADVANCE(141);
+      END_STATE();
+    case 3:
+      if (lookahead == '#') ADVANCE(142);
+      if (lookahead == '/') ADVANCE(150);
+      if (lookahead == ':') ADVANCE(146);
+      if (lookahead == '@') ADVANCE(163);
+      if (lookahead == 'e') ADVANCE(36);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(162);
+      END_STATE();
+    case 4:
+      if (lookahead == '#') ADVANCE(61);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(125);
+      END_STATE();
+    case 5:
+      if (lookahead == '\'') ADVANCE(126);
+      if (lookahead == '{') ADVANCE(144);
+      if (lookahead == 0xb5) ADVANCE(133);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(135);
+      if (lookahead != 0) ADVANCE(136);
+      END_STATE();
+    case 6:
+      if (lookahead == '.') ADVANCE(8);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(169);
+      END_STATE();
+    case 7:
+      if (lookahead == '.') ADVANCE(168);
+      END_STATE();
+    case 8:
+      if (lookahead == '.') ADVANCE(7);
+      END_STATE();
+    case 9:
+      if (lookahead == '/') ADVANCE(10);
+      if (lookahead == '=') ADVANCE(77);
+      if (lookahead == '>') ADVANCE(72);
+      if (lookahead == '{') ADVANCE(144);
+      if (lookahead == 0xb5) ADVANCE(79);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(162);
+      if (lookahead != 0 &&
+          lookahead != '"' &&
+          lookahead != '\'' &&
+          (lookahead < '<' || '>' < lookahead) &&
+          lookahead != '}') ADVANCE(81);
+      END_STATE();
+    case 10:
+      if (lookahead == '>') ADVANCE(75);
+      END_STATE();
+    case 11:
+      if (lookahead == 'a') ADVANCE(15);
+      if (lookahead == 'l') ADVANCE(46);
+      END_STATE();
+    case 12:
+      if (lookahead == 'a') ADVANCE(53);
+      if (lookahead == 'o') ADVANCE(41);
+      END_STATE();
+    case 13:
+      if (lookahead == 'a') ADVANCE(34);
+      END_STATE();
+    case 14:
+      if (lookahead == 'b') ADVANCE(54);
+      END_STATE();
+    case 15:
+      if (lookahead == 'c') ADVANCE(29);
+      END_STATE();
+    case 16:
+      if (lookahead == 'c') ADVANCE(30);
+      END_STATE();
+    case 17:
+      if (lookahead == 'd') ADVANCE(21);
+      END_STATE();
+    case 18:
+      if (lookahead == 'e') ADVANCE(14);
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(56);
+      END_STATE();
+    case 19:
+      if (lookahead == 'e') ADVANCE(55);
+      END_STATE();
+    case 20:
+      if (lookahead == 'e') ADVANCE(149);
+      END_STATE();
+    case 21:
+      if (lookahead == 'e') ADVANCE(44);
+      END_STATE();
+    case 22:
+      if (lookahead == 'e') ADVANCE(148);
+      END_STATE();
+    case 23:
+      if (lookahead == 'e') ADVANCE(39);
+      END_STATE();
+    case 24:
+      if (lookahead == 'e') ADVANCE(40);
+      END_STATE();
+    case 25:
+      if (lookahead == 'e') ADVANCE(52);
+      END_STATE();
+    case 26:
+      if (lookahead == 'f') ADVANCE(143);
+      END_STATE();
+    case 27:
+      if (lookahead == 'f') ADVANCE(147);
+      END_STATE();
+    case 28:
+      if (lookahead == 'g') ADVANCE(166);
+      END_STATE();
+    case 29:
+      if (lookahead == 'h') ADVANCE(152);
+      END_STATE();
+    case 30:
+      if (lookahead == 'h') ADVANCE(156);
+      END_STATE();
+    case 31:
+      if (lookahead == 'h') ADVANCE(24);
+      END_STATE();
+    case 32:
+      if (lookahead == 'i') ADVANCE(42);
+      END_STATE();
+    case 33:
+      if (lookahead == 'i') ADVANCE(27);
+      END_STATE();
+    case 34:
+      if (lookahead == 'i') ADVANCE(50);
+      END_STATE();
+    case 35:
+      if (lookahead == 'l') ADVANCE(164);
+      END_STATE();
+    case 36:
+      if (lookahead == 'l') ADVANCE(48);
+      END_STATE();
+    case 37:
+      if (lookahead == 'm') ADVANCE(35);
+      END_STATE();
+    case 38:
+      if (lookahead == 'n') ADVANCE(32);
+      END_STATE();
+    case 39:
+      if (lookahead == 'n') ADVANCE(17);
+      END_STATE();
+    case 40:
+      if (lookahead == 'n') ADVANCE(155);
+      END_STATE();
+    case 41:
+      if (lookahead == 'n') ADVANCE(47);
+      END_STATE();
+    case 42:
+      if (lookahead == 'p') ADVANCE(43);
+      END_STATE();
+    case 43:
+      if (lookahead == 'p') ADVANCE(25);
+      END_STATE();
+    case 44:
+      if (lookahead == 'r') ADVANCE(167);
+      END_STATE();
+    case 45:
+      if (lookahead == 's') ADVANCE(153);
+      if (lookahead == 'w') ADVANCE(13);
+      END_STATE();
+    case 46:
+      if (lookahead == 's') ADVANCE(20);
+      END_STATE();
+    case 47:
+      if (lookahead == 's') ADVANCE(51);
+      END_STATE();
+    case 48:
+      if (lookahead == 's') ADVANCE(22);
+      END_STATE();
+    case 49:
+      if (lookahead == 't') ADVANCE(37);
+      END_STATE();
+    case 50:
+      if (lookahead == 't') ADVANCE(154);
+      END_STATE();
+    case 51:
+      if (lookahead == 't') ADVANCE(165);
+      END_STATE();
+    case 52:
+      if (lookahead == 't') ADVANCE(158);
+      END_STATE();
+    case 53:
+      if (lookahead == 't') ADVANCE(16);
+      END_STATE();
+    case 54:
+      if (lookahead == 'u') ADVANCE(28);
+      END_STATE();
+    case 55:
+      if (lookahead == 'y') ADVANCE(157);
+      END_STATE();
+    case 56:
+      if (lookahead == 'C' ||
+          lookahead == 'c') ADVANCE(60);
+      END_STATE();
+    case 57:
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(73);
+      END_STATE();
+    case 58:
+      if (lookahead == 'O' ||
+          lookahead == 'o') ADVANCE(56);
+      END_STATE();
+    case 59:
+      if (lookahead == 'P' ||
+          lookahead == 'p') ADVANCE(57);
+      END_STATE();
+    case 60:
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(62);
+      END_STATE();
+    case 61:
+      if (lookahead == 'X' ||
+          lookahead == 'x') ADVANCE(66);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(91);
+      END_STATE();
+    case 62:
+      if (lookahead == 'Y' ||
+          lookahead == 'y') ADVANCE(59);
+      END_STATE();
+    case 63:
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(162);
+      if (lookahead == '$' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(159);
+      END_STATE();
+    case 64:
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(64);
+      if (lookahead != 0 &&
+          lookahead != '&' &&
+          lookahead != '<' &&
+          lookahead != '>' &&
+          lookahead != '{' &&
+          lookahead != '}') ADVANCE(131);
+      END_STATE();
+    case 65:
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(70);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(71);
+      END_STATE();
+    case 66:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(96);
+      END_STATE();
+    case 67:
+      if (eof) ADVANCE(68);
+      if (lookahead == '&') ADVANCE(4);
+      if (lookahead == '<') ADVANCE(74);
+      if (lookahead == '{') ADVANCE(144);
+      if (lookahead == 0xb5) ADVANCE(129);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(162);
+      if (lookahead != 0 &&
+          lookahead != '>' &&
+          lookahead != '}') ADVANCE(131);
+      END_STATE();
+    case 68:
+      ACCEPT_TOKEN(ts_builtin_sym_end);
+      END_STATE();
+    case 69:
+      ACCEPT_TOKEN(anon_sym_LT_BANG);
+      END_STATE();
+    case 70:
+      ACCEPT_TOKEN(aux_sym_doctype_token1);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(70);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(71);
+      END_STATE();
+    case 71:
+      ACCEPT_TOKEN(aux_sym_doctype_token1);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(71);
+      END_STATE();
+    case 72:
+      ACCEPT_TOKEN(anon_sym_GT);
+      END_STATE();
+    case 73:
+      ACCEPT_TOKEN(sym__doctype);
+      END_STATE();
+    case 74:
+      ACCEPT_TOKEN(anon_sym_LT);
+      if (lookahead == '!') ADVANCE(69);
+      if (lookahead == '/') ADVANCE(76);
+      END_STATE();
+    case 75:
+      ACCEPT_TOKEN(anon_sym_SLASH_GT);
+      END_STATE();
+    case 76:
+      ACCEPT_TOKEN(anon_sym_LT_SLASH);
+      END_STATE();
+    case 77:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      END_STATE();
+    case 78:
+      ACCEPT_TOKEN(aux_sym_attribute_name_token1);
+      if (lookahead == '.') ADVANCE(168);
+      if ((!eof && set_contains(aux_sym_attribute_name_token1_character_set_1, 9, lookahead))) ADVANCE(81);
+      END_STATE();
+    case 79:
+      ACCEPT_TOKEN(aux_sym_attribute_name_token1);
+      if (lookahead == '.') ADVANCE(80);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(169);
+      if ((!eof && set_contains(aux_sym_attribute_name_token1_character_set_1, 9, lookahead))) ADVANCE(81);
+      END_STATE();
+    case 80:
+      ACCEPT_TOKEN(aux_sym_attribute_name_token1);
+      if (lookahead == '.') ADVANCE(78);
+      if ((!eof && set_contains(aux_sym_attribute_name_token1_character_set_1, 9, lookahead))) ADVANCE(81);
+      END_STATE();
+    case 81:
+      ACCEPT_TOKEN(aux_sym_attribute_name_token1);
+      if ((!eof && set_contains(aux_sym_attribute_name_token1_character_set_1, 9, lookahead))) ADVANCE(81);
+      END_STATE();
+    case 82:
+      ACCEPT_TOKEN(aux_sym_attribute_value_token1);
+      if (lookahead == '.') ADVANCE(168);
+      if ((!eof && set_contains(aux_sym_attribute_name_token1_character_set_1, 9, lookahead)) ||
+          lookahead == '/') ADVANCE(85);
+      END_STATE();
+    case 83:
+      ACCEPT_TOKEN(aux_sym_attribute_value_token1);
+      if (lookahead == '.') ADVANCE(84);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(169);
+      if ((!eof && set_contains(aux_sym_attribute_name_token1_character_set_1, 9, lookahead)) ||
+          lookahead == '/') ADVANCE(85);
+      END_STATE();
+    case 84:
+      ACCEPT_TOKEN(aux_sym_attribute_value_token1);
+      if (lookahead == '.') ADVANCE(82);
+      if ((!eof && set_contains(aux_sym_attribute_name_token1_character_set_1, 9, lookahead)) ||
+          lookahead == '/') ADVANCE(85);
+      END_STATE();
+    case 85:
+      ACCEPT_TOKEN(aux_sym_attribute_value_token1);
+      if ((!eof && set_contains(aux_sym_attribute_name_token1_character_set_1, 9, lookahead)) ||
+          lookahead == '/') ADVANCE(85);
+      END_STATE();
+    case 86:
+      ACCEPT_TOKEN(sym_entity);
+      END_STATE();
+    case 87:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      END_STATE();
+    case 88:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(87);
+      END_STATE();
+    case 89:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(88);
+      END_STATE();
+    case 90:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(89);
+      END_STATE();
+    case 91:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(90);
+      END_STATE();
+    case 92:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(87);
+      END_STATE();
+    case 93:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(92);
+      END_STATE();
+    case 94:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(93);
+      END_STATE();
+    case 95:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(94);
+      END_STATE();
+    case 96:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(95);
+      END_STATE();
+    case 97:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(87);
+      END_STATE();
+    case 98:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(97);
+      END_STATE();
+    case 99:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(98);
+      END_STATE();
+    case 100:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(99);
+      END_STATE();
+    case 101:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(100);
+      END_STATE();
+    case 102:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(101);
+      END_STATE();
+    case 103:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(102);
+      END_STATE();
+    case 104:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(103);
+      END_STATE();
+    case 105:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(104);
+      END_STATE();
+    case 106:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(105);
+      END_STATE();
+    case 107:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(106);
+      END_STATE();
+    case 108:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(107);
+      END_STATE();
+    case 109:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(108);
+      END_STATE();
+    case 110:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(109);
+      END_STATE();
+    case 111:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(110);
+      END_STATE();
+    case 112:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(111);
+      END_STATE();
+    case 113:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(112);
+      END_STATE();
+    case 114:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(113);
+      END_STATE();
+    case 115:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(114);
+      END_STATE();
+    case 116:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(115);
+      END_STATE();
+    case 117:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(116);
+      END_STATE();
+    case 118:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(117);
+      END_STATE();
+    case 119:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(118);
+      END_STATE();
+    case 120:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(119);
+      END_STATE();
+    case 121:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(120);
+      END_STATE();
+    case 122:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(121);
+      END_STATE();
+    case 123:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(122);
+      END_STATE();
+    case 124:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(123);
+      END_STATE();
+    case 125:
+      ACCEPT_TOKEN(sym_entity);
+      if (lookahead == ';') ADVANCE(86);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(124);
+      END_STATE();
+    case 126:
+      ACCEPT_TOKEN(anon_sym_SQUOTE);
+      END_STATE();
+    case 127:
+      ACCEPT_TOKEN(anon_sym_DQUOTE);
+      END_STATE();
+    case 128:
+      ACCEPT_TOKEN(aux_sym_text_token1);
+      if (lookahead == '.') ADVANCE(168);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(64);
+      if (lookahead != 0 &&
+          lookahead != '&' &&
+          lookahead != '<' &&
+          lookahead != '>' &&
+          lookahead != '{' &&
+          lookahead != '}') ADVANCE(131);
+      END_STATE();
+    case 129:
+      ACCEPT_TOKEN(aux_sym_text_token1);
+      if (lookahead == '.') ADVANCE(130);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(64);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(169);
+      if (lookahead != 0 &&
+          lookahead != '&' &&
+          lookahead != '<' &&
+          lookahead != '>' &&
+          (lookahead < 'a' || '{' < lookahead) &&
+          lookahead != '}') ADVANCE(131);
+      END_STATE();
+    case 130:
+      ACCEPT_TOKEN(aux_sym_text_token1);
+      if (lookahead == '.') ADVANCE(128);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(64);
+      if (lookahead != 0 &&
+          lookahead != '&' &&
+          lookahead != '<' &&
+          lookahead != '>' &&
+          lookahead != '{' &&
+          lookahead != '}') ADVANCE(131);
+      END_STATE();
+    case 131:
+      ACCEPT_TOKEN(aux_sym_text_token1);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(64);
+      if (lookahead != 0 &&
+          lookahead != '&' &&
+          lookahead != '<' &&
+          lookahead != '>' &&
+          lookahead != '{' &&
+          lookahead != '}') ADVANCE(131);
+      END_STATE();
+    case 132:
+      ACCEPT_TOKEN(aux_sym__single_quoted_attribute_value_token1);
+      if (lookahead == '.') ADVANCE(168);
+      if (lookahead != 0 &&
+          lookahead != '\'' &&
+          lookahead != '{') ADVANCE(136);
+      END_STATE();
+    case 133:
+      ACCEPT_TOKEN(aux_sym__single_quoted_attribute_value_token1);
+      if (lookahead == '.') ADVANCE(134);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(169);
+      if (lookahead != 0 &&
+          lookahead != '\'' &&
+          (lookahead < 'a' || '{' < lookahead)) ADVANCE(136);
+      END_STATE();
+    case 134:
+      ACCEPT_TOKEN(aux_sym__single_quoted_attribute_value_token1);
+      if (lookahead == '.') ADVANCE(132);
+      if (lookahead != 0 &&
+          lookahead != '\'' &&
+          lookahead != '{') ADVANCE(136);
+      END_STATE();
+    case 135:
+      ACCEPT_TOKEN(aux_sym__single_quoted_attribute_value_token1);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(135);
+      if (lookahead != 0 &&
+          lookahead != '\'' &&
+          lookahead != '{') ADVANCE(136);
+      END_STATE();
+    case 136:
+      ACCEPT_TOKEN(aux_sym__single_quoted_attribute_value_token1);
+      if (lookahead != 0 &&
+          lookahead != '\'' &&
+          lookahead != '{') ADVANCE(136);
+      END_STATE();
+    case 137:
+      ACCEPT_TOKEN(aux_sym__double_quoted_attribute_value_token1);
+      if (lookahead == '.') ADVANCE(168);
+      if (lookahead != 0 &&
+          lookahead != '"' &&
+          lookahead != '{') ADVANCE(141);
+      END_STATE();
+    case 138:
+      ACCEPT_TOKEN(aux_sym__double_quoted_attribute_value_token1);
+      if (lookahead == '.') ADVANCE(139);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(169);
+      if (lookahead != 0 &&
+          lookahead != '"' &&
+          (lookahead < 'a' || '{' < lookahead)) ADVANCE(141);
+      END_STATE();
+    case 139:
+      ACCEPT_TOKEN(aux_sym__double_quoted_attribute_value_token1);
+      if (lookahead == '.') ADVANCE(137);
+      if (lookahead != 0 &&
+          lookahead != '"' &&
+          lookahead != '{') ADVANCE(141);
+      END_STATE();
+    case 140:
+      ACCEPT_TOKEN(aux_sym__double_quoted_attribute_value_token1);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(140);
+      if (lookahead != 0 &&
+          lookahead != '"' &&
+          lookahead != '{') ADVANCE(141);
+      END_STATE();
+    case 141:
+      ACCEPT_TOKEN(aux_sym__double_quoted_attribute_value_token1);
+      if (lookahead != 0 &&
+          lookahead != '"' &&
+          lookahead != '{') ADVANCE(141);
+      END_STATE();
+    case 142:
+      ACCEPT_TOKEN(anon_sym_POUND);
+      END_STATE();
+    case 143:
+      ACCEPT_TOKEN(anon_sym_if);
+      END_STATE();
+    case 144:
+      ACCEPT_TOKEN(anon_sym_LBRACE);
+      END_STATE();
+    case 145:
+      ACCEPT_TOKEN(anon_sym_RBRACE);
+      END_STATE();
+    case 146:
+      ACCEPT_TOKEN(anon_sym_COLON);
+      END_STATE();
+    case 147:
+      ACCEPT_TOKEN(anon_sym_elseif);
+      END_STATE();
+    case 148:
+      ACCEPT_TOKEN(anon_sym_else);
+      END_STATE();
+    case 149:
+      ACCEPT_TOKEN(anon_sym_else);
+      if (lookahead == ' ') ADVANCE(33);
+      END_STATE();
+    case 150:
+      ACCEPT_TOKEN(anon_sym_SLASH);
+      END_STATE();
+    case 151:
+      ACCEPT_TOKEN(anon_sym_SLASH);
+      if (lookahead == '>') ADVANCE(75);
+      END_STATE();
+    case 152:
+      ACCEPT_TOKEN(anon_sym_each);
+      END_STATE();
+    case 153:
+      ACCEPT_TOKEN(anon_sym_as);
+      END_STATE();
+    case 154:
+      ACCEPT_TOKEN(anon_sym_await);
+      END_STATE();
+    case 155:
+      ACCEPT_TOKEN(anon_sym_then);
+      END_STATE();
+    case 156:
+      ACCEPT_TOKEN(anon_sym_catch);
+      END_STATE();
+    case 157:
+      ACCEPT_TOKEN(anon_sym_key);
+      END_STATE();
+    case 158:
+      ACCEPT_TOKEN(anon_sym_snippet);
+      END_STATE();
+    case 159:
+      ACCEPT_TOKEN(aux_sym_snippet_start_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(159);
+      END_STATE();
+    case 160:
+      ACCEPT_TOKEN(anon_sym_LPAREN);
+      END_STATE();
+    case 161:
+      ACCEPT_TOKEN(anon_sym_RPAREN);
+      END_STATE();
+    case 162:
+      ACCEPT_TOKEN(aux_sym__tag_value_token1);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(162);
+      END_STATE();
+    case 163:
+      ACCEPT_TOKEN(anon_sym_AT);
+      END_STATE();
+    case 164:
+      ACCEPT_TOKEN(anon_sym_html);
+      END_STATE();
+    case 165:
+      ACCEPT_TOKEN(anon_sym_const);
+      END_STATE();
+    case 166:
+      ACCEPT_TOKEN(anon_sym_debug);
+      END_STATE();
+    case 167:
+      ACCEPT_TOKEN(anon_sym_render);
+      END_STATE();
+    case 168:
+      ACCEPT_TOKEN(sym_grit_metavariable);
+      END_STATE();
+    case 169:
+      ACCEPT_TOKEN(sym_grit_metavariable);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(169);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static const TSLexMode ts_lex_modes[STATE_COUNT] = {
+  [0] = {.lex_state = 0, .external_lex_state = 1},
+  [1] = {.lex_state = 67, .external_lex_state = 2},
+  [2] = {.lex_state = 67, .external_lex_state = 2},
+  [3] = {.lex_state = 67, .external_lex_state = 2},
+  [4] = {.lex_state = 67, .external_lex_state = 2},
+  [5] = {.lex_state = 67, .external_lex_state = 2},
+  [6] = {.lex_state = 67, .external_lex_state = 2},
+  [7] = {.lex_state = 67, .external_lex_state = 2},
+  [8] = {.lex_state = 67, .external_lex_state = 2},
+  [9] = {.lex_state = 67, .external_lex_state = 2},
+  [10] = {.lex_state = 67, .external_lex_state = 2},
+  [11] = {.lex_state = 67, .external_lex_state = 2},
+  [12] = {.lex_state = 67, .external_lex_state = 2},
+  [13] = {.lex_state = 67, .external_lex_state = 2},
+  [14] = {.lex_state = 67, .external_lex_state = 3},
+  [15] = {.lex_state = 67, .external_lex_state = 3},
+  [16] = {.lex_state = 67, .external_lex_state = 3},
+  [17] = {.lex_state = 67, .external_lex_state = 3},
+  [18] = {.lex_state = 67, .external_lex_state = 2},
+  [19] = {.lex_state = 67, .external_lex_state = 2},
+  [20] = {.lex_state = 67, .external_lex_state = 2},
+  [21] = {.lex_state = 67, .external_lex_state = 2},
+  [22] = {.lex_state = 67, .external_lex_state = 2},
+  [23] = {.lex_state = 67, .external_lex_state = 2},
+  [24] = {.lex_state = 67, .external_lex_state = 2},
+  [25] = {.lex_state = 67, .external_lex_state = 3},
+  [26] = {.lex_state = 67, .external_lex_state = 2},
+  [27] = {.lex_state = 67, .external_lex_state = 2},
+  [28] = {.lex_state = 67, .external_lex_state = 2},
+  [29] = {.lex_state = 67, .external_lex_state = 2},
+  [30] = {.lex_state = 67, .external_lex_state = 2},
+  [31] = {.lex_state = 67, .external_lex_state = 2},
+  [32] = {.lex_state = 67, .external_lex_state = 2},
+  [33] = {.lex_state = 67, .external_lex_state = 2},
+  [34] = {.lex_state = 67, .external_lex_state = 2},
+  [35] = {.lex_state = 67, .external_lex_state = 2},
+  [36] = {.lex_state = 67, .external_lex_state = 2},
+  [37] = {.lex_state = 67, .external_lex_state = 2},
+  [38] = {.lex_state = 67, .external_lex_state = 2},
+  [39] = {.lex_state = 67, .external_lex_state = 2},
+  [40] = {.lex_state = 67, .external_lex_state = 2},
+  [41] = {.lex_state = 67, .external_lex_state = 2},
+  [42] = {.lex_state = 67, .external_lex_state = 2},
+  [43] = {.lex_state = 67, .external_lex_state = 2},
+  [44] = {.lex_state = 67, .external_lex_state = 2},
+  [45] = {.lex_state = 67, .external_lex_state = 2},
+  [46] = {.lex_state = 67, .external_lex_state = 2},
+  [47] = {.lex_state = 3, .external_lex_state = 4},
+  [48] = {.lex_state = 3, .external_lex_state = 4},
+  [49] = {.lex_state = 3, .external_lex_state = 4},
+  [50] = {.lex_state = 3, .external_lex_state = 4},
+  [51] = {.lex_state = 3, .external_lex_state = 4},
+  [52] = {.lex_state = 3, .external_lex_state = 4},
+  [53] = {.lex_state = 3, .external_lex_state = 5},
+  [54] = {.lex_state = 3, .external_lex_state = 5},
+  [55] = {.lex_state = 3, .external_lex_state = 5},
+  [56] = {.lex_state = 3, .external_lex_state = 5},
+  [57] = {.lex_state = 3, .external_lex_state = 5},
+  [58] = {.lex_state = 3, .external_lex_state = 5},
+  [59] = {.lex_state = 0, .external_lex_state = 6},
+  [60] = {.lex_state = 0, .external_lex_state = 6},
+  [61] = {.lex_state = 9, .external_lex_state = 7},
+  [62] = {.lex_state = 9, .external_lex_state = 7},
+  [63] = {.lex_state = 9, .external_lex_state = 7},
+  [64] = {.lex_state = 9, .external_lex_state = 7},
+  [65] = {.lex_state = 9, .external_lex_state = 7},
+  [66] = {.lex_state = 67, .external_lex_state = 2},
+  [67] = {.lex_state = 67, .external_lex_state = 2},
+  [68] = {.lex_state = 67, .external_lex_state = 2},
+  [69] = {.lex_state = 1, .external_lex_state = 2},
+  [70] = {.lex_state = 67, .external_lex_state = 3},
+  [71] = {.lex_state = 67, .external_lex_state = 2},
+  [72] = {.lex_state = 67, .external_lex_state = 2},
+  [73] = {.lex_state = 67, .external_lex_state = 2},
+  [74] = {.lex_state = 67, .external_lex_state = 2},
+  [75] = {.lex_state = 67, .external_lex_state = 2},
+  [76] = {.lex_state = 67, .external_lex_state = 2},
+  [77] = {.lex_state = 67, .external_lex_state = 2},
+  [78] = {.lex_state = 67, .external_lex_state = 2},
+  [79] = {.lex_state = 67, .external_lex_state = 2},
+  [80] = {.lex_state = 67, .external_lex_state = 2},
+  [81] = {.lex_state = 67, .external_lex_state = 2},
+  [82] = {.lex_state = 67, .external_lex_state = 2},
+  [83] = {.lex_state = 67, .external_lex_state = 2},
+  [84] = {.lex_state = 67, .external_lex_state = 2},
+  [85] = {.lex_state = 67, .external_lex_state = 2},
+  [86] = {.lex_state = 67, .external_lex_state = 2},
+  [87] = {.lex_state = 67, .external_lex_state = 2},
+  [88] = {.lex_state = 67, .external_lex_state = 2},
+  [89] = {.lex_state = 67, .external_lex_state = 2},
+  [90] = {.lex_state = 67, .external_lex_state = 2},
+  [91] = {.lex_state = 67, .external_lex_state = 3},
+  [92] = {.lex_state = 67, .external_lex_state = 3},
+  [93] = {.lex_state = 67, .external_lex_state = 3},
+  [94] = {.lex_state = 67, .external_lex_state = 3},
+  [95] = {.lex_state = 67, .external_lex_state = 3},
+  [96] = {.lex_state = 67, .external_lex_state = 3},
+  [97] = {.lex_state = 67, .external_lex_state = 2},
+  [98] = {.lex_state = 67, .external_lex_state = 3},
+  [99] = {.lex_state = 67, .external_lex_state = 3},
+  [100] = {.lex_state = 67, .external_lex_state = 3},
+  [101] = {.lex_state = 67, .external_lex_state = 3},
+  [102] = {.lex_state = 67, .external_lex_state = 3},
+  [103] = {.lex_state = 67, .external_lex_state = 2},
+  [104] = {.lex_state = 67, .external_lex_state = 3},
+  [105] = {.lex_state = 67, .external_lex_state = 3},
+  [106] = {.lex_state = 67, .external_lex_state = 3},
+  [107] = {.lex_state = 67, .external_lex_state = 3},
+  [108] = {.lex_state = 67, .external_lex_state = 3},
+  [109] = {.lex_state = 67, .external_lex_state = 3},
+  [110] = {.lex_state = 67, .external_lex_state = 2},
+  [111] = {.lex_state = 67, .external_lex_state = 3},
+  [112] = {.lex_state = 67, .external_lex_state = 3},
+  [113] = {.lex_state = 67, .external_lex_state = 3},
+  [114] = {.lex_state = 67, .external_lex_state = 3},
+  [115] = {.lex_state = 67, .external_lex_state = 3},
+  [116] = {.lex_state = 67, .external_lex_state = 3},
+  [117] = {.lex_state = 67, .external_lex_state = 3},
+  [118] = {.lex_state = 9, .external_lex_state = 2},
+  [119] = {.lex_state = 67, .external_lex_state = 3},
+  [120] = {.lex_state = 67, .external_lex_state = 3},
+  [121] = {.lex_state = 67, .external_lex_state = 3},
+  [122] = {.lex_state = 67, .external_lex_state = 3},
+  [123] = {.lex_state = 67, .external_lex_state = 3},
+  [124] = {.lex_state = 67, .external_lex_state = 3},
+  [125] = {.lex_state = 67, .external_lex_state = 3},
+  [126] = {.lex_state = 67, .external_lex_state = 3},
+  [127] = {.lex_state = 67, .external_lex_state = 3},
+  [128] = {.lex_state = 67, .external_lex_state = 3},
+  [129] = {.lex_state = 67, .external_lex_state = 3},
+  [130] = {.lex_state = 67, .external_lex_state = 3},
+  [131] = {.lex_state = 67, .external_lex_state = 3},
+  [132] = {.lex_state = 67, .external_lex_state = 3},
+  [133] = {.lex_state = 67, .external_lex_state = 3},
+  [134] = {.lex_state = 67, .external_lex_state = 3},
+  [135] = {.lex_state = 67, .external_lex_state = 2},
+  [136] = {.lex_state = 67, .external_lex_state = 2},
+  [137] = {.lex_state = 9, .external_lex_state = 2},
+  [138] = {.lex_state = 67, .external_lex_state = 3},
+  [139] = {.lex_state = 67, .external_lex_state = 2},
+  [140] = {.lex_state = 67, .external_lex_state = 2},
+  [141] = {.lex_state = 67, .external_lex_state = 2},
+  [142] = {.lex_state = 9, .external_lex_state = 2},
+  [143] = {.lex_state = 9, .external_lex_state = 2},
+  [144] = {.lex_state = 67, .external_lex_state = 2},
+  [145] = {.lex_state = 67, .external_lex_state = 2},
+  [146] = {.lex_state = 67, .external_lex_state = 2},
+  [147] = {.lex_state = 67, .external_lex_state = 2},
+  [148] = {.lex_state = 67, .external_lex_state = 2},
+  [149] = {.lex_state = 1, .external_lex_state = 2},
+  [150] = {.lex_state = 67, .external_lex_state = 2},
+  [151] = {.lex_state = 9, .external_lex_state = 2},
+  [152] = {.lex_state = 67, .external_lex_state = 2},
+  [153] = {.lex_state = 67, .external_lex_state = 2},
+  [154] = {.lex_state = 67, .external_lex_state = 2},
+  [155] = {.lex_state = 67, .external_lex_state = 2},
+  [156] = {.lex_state = 67, .external_lex_state = 2},
+  [157] = {.lex_state = 67, .external_lex_state = 2},
+  [158] = {.lex_state = 67, .external_lex_state = 2},
+  [159] = {.lex_state = 67, .external_lex_state = 2},
+  [160] = {.lex_state = 67, .external_lex_state = 2},
+  [161] = {.lex_state = 0, .external_lex_state = 2},
+  [162] = {.lex_state = 0, .external_lex_state = 2},
+  [163] = {.lex_state = 67, .external_lex_state = 2},
+  [164] = {.lex_state = 67, .external_lex_state = 2},
+  [165] = {.lex_state = 0, .external_lex_state = 2},
+  [166] = {.lex_state = 67, .external_lex_state = 2},
+  [167] = {.lex_state = 67, .external_lex_state = 2},
+  [168] = {.lex_state = 67, .external_lex_state = 2},
+  [169] = {.lex_state = 67, .external_lex_state = 2},
+  [170] = {.lex_state = 67, .external_lex_state = 2},
+  [171] = {.lex_state = 0, .external_lex_state = 2},
+  [172] = {.lex_state = 5, .external_lex_state = 2},
+  [173] = {.lex_state = 2, .external_lex_state = 2},
+  [174] = {.lex_state = 2, .external_lex_state = 2},
+  [175] = {.lex_state = 5, .external_lex_state = 2},
+  [176] = {.lex_state = 9, .external_lex_state = 7},
+  [177] = {.lex_state = 9, .external_lex_state = 7},
+  [178] = {.lex_state = 5, .external_lex_state = 2},
+  [179] = {.lex_state = 2, .external_lex_state = 2},
+  [180] = {.lex_state = 5, .external_lex_state = 2},
+  [181] = {.lex_state = 2, .external_lex_state = 2},
+  [182] = {.lex_state = 5, .external_lex_state = 2},
+  [183] = {.lex_state = 2, .external_lex_state = 2},
+  [184] = {.lex_state = 0, .external_lex_state = 2},
+  [185] = {.lex_state = 3, .external_lex_state = 8},
+  [186] = {.lex_state = 9, .external_lex_state = 7},
+  [187] = {.lex_state = 9, .external_lex_state = 7},
+  [188] = {.lex_state = 9, .external_lex_state = 7},
+  [189] = {.lex_state = 9, .external_lex_state = 7},
+  [190] = {.lex_state = 9, .external_lex_state = 2},
+  [191] = {.lex_state = 9, .external_lex_state = 7},
+  [192] = {.lex_state = 9, .external_lex_state = 7},
+  [193] = {.lex_state = 3, .external_lex_state = 8},
+  [194] = {.lex_state = 9, .external_lex_state = 2},
+  [195] = {.lex_state = 5, .external_lex_state = 2},
+  [196] = {.lex_state = 0, .external_lex_state = 2},
+  [197] = {.lex_state = 2, .external_lex_state = 2},
+  [198] = {.lex_state = 0, .external_lex_state = 2},
+  [199] = {.lex_state = 9, .external_lex_state = 2},
+  [200] = {.lex_state = 0, .external_lex_state = 2},
+  [201] = {.lex_state = 3, .external_lex_state = 8},
+  [202] = {.lex_state = 9, .external_lex_state = 2},
+  [203] = {.lex_state = 9, .external_lex_state = 2},
+  [204] = {.lex_state = 0, .external_lex_state = 2},
+  [205] = {.lex_state = 9, .external_lex_state = 2},
+  [206] = {.lex_state = 9, .external_lex_state = 2},
+  [207] = {.lex_state = 9, .external_lex_state = 2},
+  [208] = {.lex_state = 5, .external_lex_state = 2},
+  [209] = {.lex_state = 2, .external_lex_state = 2},
+  [210] = {.lex_state = 0, .external_lex_state = 2},
+  [211] = {.lex_state = 3, .external_lex_state = 8},
+  [212] = {.lex_state = 0, .external_lex_state = 2},
+  [213] = {.lex_state = 0, .external_lex_state = 9},
+  [214] = {.lex_state = 0, .external_lex_state = 9},
+  [215] = {.lex_state = 0, .external_lex_state = 10},
+  [216] = {.lex_state = 0, .external_lex_state = 10},
+  [217] = {.lex_state = 0, .external_lex_state = 10},
+  [218] = {.lex_state = 0, .external_lex_state = 10},
+  [219] = {.lex_state = 3, .external_lex_state = 11},
+  [220] = {.lex_state = 0, .external_lex_state = 2},
+  [221] = {.lex_state = 0, .external_lex_state = 12},
+  [222] = {.lex_state = 0, .external_lex_state = 2},
+  [223] = {.lex_state = 0, .external_lex_state = 13},
+  [224] = {.lex_state = 0, .external_lex_state = 10},
+  [225] = {.lex_state = 0, .external_lex_state = 13},
+  [226] = {.lex_state = 3, .external_lex_state = 11},
+  [227] = {.lex_state = 0, .external_lex_state = 2},
+  [228] = {.lex_state = 0, .external_lex_state = 14},
+  [229] = {.lex_state = 0, .external_lex_state = 2},
+  [230] = {.lex_state = 0, .external_lex_state = 2},
+  [231] = {.lex_state = 3, .external_lex_state = 11},
+  [232] = {.lex_state = 0, .external_lex_state = 15},
+  [233] = {.lex_state = 0, .external_lex_state = 2},
+  [234] = {.lex_state = 0, .external_lex_state = 14},
+  [235] = {.lex_state = 0, .external_lex_state = 2},
+  [236] = {.lex_state = 0, .external_lex_state = 2},
+  [237] = {.lex_state = 0, .external_lex_state = 2},
+  [238] = {.lex_state = 0, .external_lex_state = 2},
+  [239] = {.lex_state = 0, .external_lex_state = 16},
+  [240] = {.lex_state = 0, .external_lex_state = 10},
+  [241] = {.lex_state = 0, .external_lex_state = 2},
+  [242] = {.lex_state = 0, .external_lex_state = 13},
+  [243] = {.lex_state = 0, .external_lex_state = 13},
+  [244] = {.lex_state = 0, .external_lex_state = 15},
+  [245] = {.lex_state = 0, .external_lex_state = 2},
+  [246] = {.lex_state = 0, .external_lex_state = 10},
+  [247] = {.lex_state = 0, .external_lex_state = 2},
+  [248] = {.lex_state = 3, .external_lex_state = 11},
+  [249] = {.lex_state = 0, .external_lex_state = 2},
+  [250] = {.lex_state = 0, .external_lex_state = 2},
+  [251] = {.lex_state = 0, .external_lex_state = 2},
+  [252] = {.lex_state = 0, .external_lex_state = 2},
+  [253] = {.lex_state = 0, .external_lex_state = 10},
+  [254] = {.lex_state = 0, .external_lex_state = 2},
+  [255] = {.lex_state = 0, .external_lex_state = 2},
+  [256] = {.lex_state = 0, .external_lex_state = 13},
+  [257] = {.lex_state = 0, .external_lex_state = 13},
+  [258] = {.lex_state = 3, .external_lex_state = 2},
+  [259] = {.lex_state = 0, .external_lex_state = 2},
+  [260] = {.lex_state = 0, .external_lex_state = 2},
+  [261] = {.lex_state = 0, .external_lex_state = 2},
+  [262] = {.lex_state = 0, .external_lex_state = 2},
+  [263] = {.lex_state = 0, .external_lex_state = 13},
+  [264] = {.lex_state = 63, .external_lex_state = 2},
+  [265] = {.lex_state = 0, .external_lex_state = 13},
+  [266] = {.lex_state = 0, .external_lex_state = 2},
+  [267] = {.lex_state = 0, .external_lex_state = 2},
+  [268] = {.lex_state = 0, .external_lex_state = 2},
+  [269] = {.lex_state = 0, .external_lex_state = 2},
+  [270] = {.lex_state = 0, .external_lex_state = 13},
+  [271] = {.lex_state = 0, .external_lex_state = 2},
+  [272] = {.lex_state = 0, .external_lex_state = 13},
+  [273] = {.lex_state = 0, .external_lex_state = 2},
+  [274] = {.lex_state = 0, .external_lex_state = 2},
+  [275] = {.lex_state = 0, .external_lex_state = 13},
+  [276] = {.lex_state = 0, .external_lex_state = 2},
+  [277] = {.lex_state = 0, .external_lex_state = 2},
+  [278] = {.lex_state = 0, .external_lex_state = 2},
+  [279] = {.lex_state = 0, .external_lex_state = 2},
+  [280] = {.lex_state = 0, .external_lex_state = 13},
+  [281] = {.lex_state = 0, .external_lex_state = 2},
+  [282] = {.lex_state = 0, .external_lex_state = 2},
+  [283] = {.lex_state = 0, .external_lex_state = 13},
+  [284] = {.lex_state = 0, .external_lex_state = 2},
+  [285] = {.lex_state = 0, .external_lex_state = 17},
+  [286] = {.lex_state = 65, .external_lex_state = 2},
+  [287] = {.lex_state = 0, .external_lex_state = 2},
+  [288] = {.lex_state = 0, .external_lex_state = 2},
+  [289] = {.lex_state = 0, .external_lex_state = 2},
+  [290] = {.lex_state = 63, .external_lex_state = 2},
+  [291] = {.lex_state = 0, .external_lex_state = 2},
+  [292] = {.lex_state = 0, .external_lex_state = 2},
+  [293] = {.lex_state = 0, .external_lex_state = 2},
+  [294] = {.lex_state = 0, .external_lex_state = 2},
+  [295] = {.lex_state = 0, .external_lex_state = 2},
+  [296] = {.lex_state = 0, .external_lex_state = 2},
+  [297] = {.lex_state = 0, .external_lex_state = 2},
+  [298] = {.lex_state = 0, .external_lex_state = 2},
+  [299] = {.lex_state = 0, .external_lex_state = 2},
+  [300] = {.lex_state = 0, .external_lex_state = 2},
+  [301] = {.lex_state = 0, .external_lex_state = 2},
+  [302] = {.lex_state = 0, .external_lex_state = 2},
+  [303] = {.lex_state = 0, .external_lex_state = 2},
+  [304] = {.lex_state = 0, .external_lex_state = 2},
+  [305] = {.lex_state = 0, .external_lex_state = 2},
+  [306] = {.lex_state = 0, .external_lex_state = 2},
+  [307] = {.lex_state = 0, .external_lex_state = 2},
+  [308] = {.lex_state = 0, .external_lex_state = 2},
+  [309] = {.lex_state = 0, .external_lex_state = 18},
+  [310] = {.lex_state = 0, .external_lex_state = 2},
+  [311] = {.lex_state = 0, .external_lex_state = 2},
+  [312] = {.lex_state = 0, .external_lex_state = 13},
+  [313] = {.lex_state = 0, .external_lex_state = 2},
+  [314] = {.lex_state = 0, .external_lex_state = 2},
+  [315] = {.lex_state = 0, .external_lex_state = 2},
+  [316] = {.lex_state = 0, .external_lex_state = 2},
+  [317] = {.lex_state = 0, .external_lex_state = 2},
+  [318] = {.lex_state = 0, .external_lex_state = 2},
+  [319] = {.lex_state = 0, .external_lex_state = 2},
+  [320] = {.lex_state = 0, .external_lex_state = 2},
+  [321] = {.lex_state = 0, .external_lex_state = 2},
+  [322] = {.lex_state = 0, .external_lex_state = 2},
+  [323] = {.lex_state = 0, .external_lex_state = 2},
+  [324] = {.lex_state = 0, .external_lex_state = 2},
+  [325] = {.lex_state = 0, .external_lex_state = 2},
+  [326] = {.lex_state = 0, .external_lex_state = 2},
+  [327] = {.lex_state = 0, .external_lex_state = 2},
+  [328] = {.lex_state = 0, .external_lex_state = 18},
+  [329] = {.lex_state = 0, .external_lex_state = 2},
+  [330] = {.lex_state = 65, .external_lex_state = 2},
+  [331] = {.lex_state = 0, .external_lex_state = 2},
+  [332] = {.lex_state = 0, .external_lex_state = 2},
+  [333] = {.lex_state = 0, .external_lex_state = 2},
+  [334] = {.lex_state = 0, .external_lex_state = 2},
+  [335] = {.lex_state = 0, .external_lex_state = 2},
+  [336] = {.lex_state = 0, .external_lex_state = 17},
+  [337] = {.lex_state = 0, .external_lex_state = 2},
+  [338] = {.lex_state = 0, .external_lex_state = 2},
+  [339] = {.lex_state = 0, .external_lex_state = 2},
+  [340] = {.lex_state = 0, .external_lex_state = 13},
+  [341] = {.lex_state = 0, .external_lex_state = 2},
+  [342] = {.lex_state = 0, .external_lex_state = 13},
+  [343] = {.lex_state = 0, .external_lex_state = 13},
+  [344] = {.lex_state = 0, .external_lex_state = 13},
+  [345] = {.lex_state = 0, .external_lex_state = 2},
+  [346] = {.lex_state = 0, .external_lex_state = 2},
+  [347] = {.lex_state = 0, .external_lex_state = 2},
+  [348] = {.lex_state = 0, .external_lex_state = 2},
+  [349] = {.lex_state = 0, .external_lex_state = 2},
+  [350] = {.lex_state = 0, .external_lex_state = 2},
+};
+
+static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
+  [0] = {
+    [ts_builtin_sym_end] = ACTIONS(1),
+    [anon_sym_LT_BANG] = ACTIONS(1),
+    [anon_sym_GT] = ACTIONS(1),
+    [sym__doctype] = ACTIONS(1),
+    [anon_sym_LT] = ACTIONS(1),
+    [anon_sym_SLASH_GT] = ACTIONS(1),
+    [anon_sym_LT_SLASH] = ACTIONS(1),
+    [anon_sym_EQ] = ACTIONS(1),
+    [sym_entity] = ACTIONS(1),
+    [anon_sym_SQUOTE] = ACTIONS(1),
+    [anon_sym_DQUOTE] = ACTIONS(1),
+    [anon_sym_POUND] = ACTIONS(1),
+    [anon_sym_if] = ACTIONS(1),
+    [anon_sym_LBRACE] = ACTIONS(1),
+    [anon_sym_RBRACE] = ACTIONS(1),
+    [anon_sym_COLON] = ACTIONS(1),
+    [anon_sym_elseif] = ACTIONS(1),
+    [anon_sym_else] = ACTIONS(1),
+    [anon_sym_SLASH] = ACTIONS(1),
+    [anon_sym_each] = ACTIONS(1),
+    [anon_sym_as] = ACTIONS(1),
+    [anon_sym_await] = ACTIONS(1),
+    [anon_sym_then] = ACTIONS(1),
+    [anon_sym_catch] = ACTIONS(1),
+    [anon_sym_key] = ACTIONS(1),
+    [anon_sym_snippet] = ACTIONS(1),
+    [anon_sym_LPAREN] = ACTIONS(1),
+    [anon_sym_RPAREN] = ACTIONS(1),
+    [aux_sym__tag_value_token1] = ACTIONS(3),
+    [anon_sym_AT] = ACTIONS(1),
+    [anon_sym_html] = ACTIONS(1),
+    [anon_sym_const] = ACTIONS(1),
+    [anon_sym_debug] = ACTIONS(1),
+    [anon_sym_render] = ACTIONS(1),
+    [sym_grit_metavariable] = ACTIONS(1),
+    [sym__start_tag_name] = ACTIONS(1),
+    [sym__script_start_tag_name] = ACTIONS(1),
+    [sym__style_start_tag_name] = ACTIONS(1),
+    [sym__end_tag_name] = ACTIONS(1),
+    [sym_erroneous_end_tag_name] = ACTIONS(1),
+    [sym__implicit_end_tag] = ACTIONS(1),
+    [sym_raw_text] = ACTIONS(1),
+    [sym_comment] = ACTIONS(3),
+    [sym_svelte_raw_text] = ACTIONS(1),
+    [sym_svelte_raw_text_each] = ACTIONS(1),
+    [sym_svelte_raw_text_snippet_arguments] = ACTIONS(1),
+  },
+  [1] = {
+    [sym_document] = STATE(310),
+    [sym_doctype] = STATE(66),
+    [sym__node] = STATE(30),
+    [sym_element] = STATE(66),
+    [sym_script_element] = STATE(66),
+    [sym_style_element] = STATE(66),
+    [sym_start_tag] = STATE(14),
+    [sym_script_start_tag] = STATE(216),
+    [sym_style_start_tag] = STATE(215),
+    [sym_self_closing_tag] = STATE(97),
+    [sym_erroneous_end_tag] = STATE(66),
+    [sym_text] = STATE(66),
+    [sym_if_statement] = STATE(66),
+    [sym_if_start] = STATE(2),
+    [sym_each_statement] = STATE(66),
+    [sym_each_start] = STATE(11),
+    [sym_await_statement] = STATE(66),
+    [sym_await_start] = STATE(7),
+    [sym_key_statement] = STATE(66),
+    [sym_key_start] = STATE(36),
+    [sym_snippet_statement] = STATE(66),
+    [sym_snippet_start] = STATE(26),
+    [sym_expression] = STATE(66),
+    [sym_html_tag] = STATE(66),
+    [sym_const_tag] = STATE(66),
+    [sym_debug_tag] = STATE(66),
+    [sym_render_tag] = STATE(66),
+    [aux_sym_document_repeat1] = STATE(30),
+    [ts_builtin_sym_end] = ACTIONS(5),
+    [anon_sym_LT_BANG] = ACTIONS(7),
+    [anon_sym_LT] = ACTIONS(9),
+    [anon_sym_LT_SLASH] = ACTIONS(11),
+    [sym_entity] = ACTIONS(13),
+    [aux_sym_text_token1] = ACTIONS(15),
+    [anon_sym_LBRACE] = ACTIONS(17),
+    [aux_sym__tag_value_token1] = ACTIONS(3),
+    [sym_grit_metavariable] = ACTIONS(19),
+    [sym_comment] = ACTIONS(3),
+  },
+};
+
+static const uint16_t ts_small_parse_table[] = {
+  [0] = 24,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(21), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(38), 1,
+      sym_else_start,
+    STATE(44), 1,
+      sym_else_if_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(135), 1,
+      sym_if_end,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    STATE(249), 1,
+      sym_else_block,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(3), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(161), 2,
+      sym_else_if_block,
+      aux_sym_if_statement_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [91] = 24,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(21), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(38), 1,
+      sym_else_start,
+    STATE(44), 1,
+      sym_else_if_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(152), 1,
+      sym_if_end,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    STATE(229), 1,
+      sym_else_block,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(22), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(171), 2,
+      sym_else_if_block,
+      aux_sym_if_statement_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [182] = 24,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(23), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(38), 1,
+      sym_else_start,
+    STATE(44), 1,
+      sym_else_if_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(98), 1,
+      sym_if_end,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    STATE(237), 1,
+      sym_else_block,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(5), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(162), 2,
+      sym_else_if_block,
+      aux_sym_if_statement_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [273] = 24,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(23), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(38), 1,
+      sym_else_start,
+    STATE(44), 1,
+      sym_else_if_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(111), 1,
+      sym_if_end,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    STATE(245), 1,
+      sym_else_block,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(22), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(165), 2,
+      sym_else_if_block,
+      aux_sym_if_statement_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [364] = 24,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(25), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(41), 1,
+      sym_then_start,
+    STATE(45), 1,
+      sym_catch_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(140), 1,
+      sym_await_end,
+    STATE(200), 1,
+      sym_then_block,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    STATE(230), 1,
+      sym_catch_block,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(22), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [454] = 24,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(25), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(41), 1,
+      sym_then_start,
+    STATE(45), 1,
+      sym_catch_start,
+    STATE(82), 1,
+      sym_await_end,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(198), 1,
+      sym_then_block,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    STATE(220), 1,
+      sym_catch_block,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(6), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [544] = 24,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(27), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(41), 1,
+      sym_then_start,
+    STATE(45), 1,
+      sym_catch_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(113), 1,
+      sym_await_end,
+    STATE(212), 1,
+      sym_then_block,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    STATE(247), 1,
+      sym_catch_block,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(22), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [634] = 24,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(27), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(41), 1,
+      sym_then_start,
+    STATE(45), 1,
+      sym_catch_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(100), 1,
+      sym_await_end,
+    STATE(210), 1,
+      sym_then_block,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    STATE(241), 1,
+      sym_catch_block,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(8), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [724] = 22,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(29), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(23), 1,
+      sym_else_block,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(43), 1,
+      sym_else_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(154), 1,
+      sym_each_end,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(22), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [808] = 22,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(29), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(29), 1,
+      sym_else_block,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(43), 1,
+      sym_else_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(153), 1,
+      sym_each_end,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(10), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [892] = 22,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(31), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(35), 1,
+      sym_else_block,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(43), 1,
+      sym_else_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(112), 1,
+      sym_each_end,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(22), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [976] = 22,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(31), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(31), 1,
+      sym_else_block,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(43), 1,
+      sym_else_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(99), 1,
+      sym_each_end,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(12), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [1060] = 21,
+    ACTIONS(33), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(35), 1,
+      anon_sym_LT,
+    ACTIONS(37), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(39), 1,
+      sym_entity,
+    ACTIONS(41), 1,
+      aux_sym_text_token1,
+    ACTIONS(43), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      sym_grit_metavariable,
+    ACTIONS(47), 1,
+      sym__implicit_end_tag,
+    STATE(4), 1,
+      sym_if_start,
+    STATE(9), 1,
+      sym_await_start,
+    STATE(13), 1,
+      sym_each_start,
+    STATE(16), 1,
+      sym_start_tag,
+    STATE(27), 1,
+      sym_key_start,
+    STATE(28), 1,
+      sym_snippet_start,
+    STATE(72), 1,
+      sym_end_tag,
+    STATE(93), 1,
+      sym_self_closing_tag,
+    STATE(217), 1,
+      sym_script_start_tag,
+    STATE(218), 1,
+      sym_style_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(15), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(91), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [1141] = 21,
+    ACTIONS(33), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(35), 1,
+      anon_sym_LT,
+    ACTIONS(37), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(39), 1,
+      sym_entity,
+    ACTIONS(41), 1,
+      aux_sym_text_token1,
+    ACTIONS(43), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      sym_grit_metavariable,
+    ACTIONS(49), 1,
+      sym__implicit_end_tag,
+    STATE(4), 1,
+      sym_if_start,
+    STATE(9), 1,
+      sym_await_start,
+    STATE(13), 1,
+      sym_each_start,
+    STATE(16), 1,
+      sym_start_tag,
+    STATE(27), 1,
+      sym_key_start,
+    STATE(28), 1,
+      sym_snippet_start,
+    STATE(93), 1,
+      sym_self_closing_tag,
+    STATE(147), 1,
+      sym_end_tag,
+    STATE(217), 1,
+      sym_script_start_tag,
+    STATE(218), 1,
+      sym_style_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(25), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(91), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [1222] = 21,
+    ACTIONS(33), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(35), 1,
+      anon_sym_LT,
+    ACTIONS(39), 1,
+      sym_entity,
+    ACTIONS(41), 1,
+      aux_sym_text_token1,
+    ACTIONS(43), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      sym_grit_metavariable,
+    ACTIONS(51), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(53), 1,
+      sym__implicit_end_tag,
+    STATE(4), 1,
+      sym_if_start,
+    STATE(9), 1,
+      sym_await_start,
+    STATE(13), 1,
+      sym_each_start,
+    STATE(16), 1,
+      sym_start_tag,
+    STATE(27), 1,
+      sym_key_start,
+    STATE(28), 1,
+      sym_snippet_start,
+    STATE(93), 1,
+      sym_self_closing_tag,
+    STATE(94), 1,
+      sym_end_tag,
+    STATE(217), 1,
+      sym_script_start_tag,
+    STATE(218), 1,
+      sym_style_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(17), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(91), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [1303] = 21,
+    ACTIONS(33), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(35), 1,
+      anon_sym_LT,
+    ACTIONS(39), 1,
+      sym_entity,
+    ACTIONS(41), 1,
+      aux_sym_text_token1,
+    ACTIONS(43), 1,
+      anon_sym_LBRACE,
+    ACTIONS(45), 1,
+      sym_grit_metavariable,
+    ACTIONS(51), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(55), 1,
+      sym__implicit_end_tag,
+    STATE(4), 1,
+      sym_if_start,
+    STATE(9), 1,
+      sym_await_start,
+    STATE(13), 1,
+      sym_each_start,
+    STATE(16), 1,
+      sym_start_tag,
+    STATE(27), 1,
+      sym_key_start,
+    STATE(28), 1,
+      sym_snippet_start,
+    STATE(93), 1,
+      sym_self_closing_tag,
+    STATE(107), 1,
+      sym_end_tag,
+    STATE(217), 1,
+      sym_script_start_tag,
+    STATE(218), 1,
+      sym_style_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(25), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(91), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [1384] = 20,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(81), 1,
+      sym_each_end,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(22), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [1462] = 20,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(59), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(133), 1,
+      sym_each_end,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(22), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [1540] = 20,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(61), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(145), 1,
+      sym_key_end,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(22), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [1618] = 20,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(63), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(67), 1,
+      sym_snippet_end,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(22), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [1696] = 20,
+    ACTIONS(65), 1,
+      ts_builtin_sym_end,
+    ACTIONS(67), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(70), 1,
+      anon_sym_LT,
+    ACTIONS(73), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(76), 1,
+      sym_entity,
+    ACTIONS(79), 1,
+      aux_sym_text_token1,
+    ACTIONS(82), 1,
+      anon_sym_LBRACE,
+    ACTIONS(85), 1,
+      sym_grit_metavariable,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(22), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [1774] = 20,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(81), 1,
+      sym_each_end,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(24), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [1852] = 20,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(88), 1,
+      sym_each_end,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(22), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [1930] = 20,
+    ACTIONS(65), 1,
+      sym__implicit_end_tag,
+    ACTIONS(88), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(91), 1,
+      anon_sym_LT,
+    ACTIONS(94), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(97), 1,
+      sym_entity,
+    ACTIONS(100), 1,
+      aux_sym_text_token1,
+    ACTIONS(103), 1,
+      anon_sym_LBRACE,
+    ACTIONS(106), 1,
+      sym_grit_metavariable,
+    STATE(4), 1,
+      sym_if_start,
+    STATE(9), 1,
+      sym_await_start,
+    STATE(13), 1,
+      sym_each_start,
+    STATE(16), 1,
+      sym_start_tag,
+    STATE(27), 1,
+      sym_key_start,
+    STATE(28), 1,
+      sym_snippet_start,
+    STATE(93), 1,
+      sym_self_closing_tag,
+    STATE(217), 1,
+      sym_script_start_tag,
+    STATE(218), 1,
+      sym_style_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(25), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(91), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [2008] = 20,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(63), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(136), 1,
+      sym_snippet_end,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(21), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [2086] = 20,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(109), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(101), 1,
+      sym_key_end,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(32), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [2164] = 20,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(111), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(102), 1,
+      sym_snippet_end,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(33), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [2242] = 20,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(57), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(154), 1,
+      sym_each_end,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(18), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [2320] = 20,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(17), 1,
+      anon_sym_LBRACE,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(113), 1,
+      ts_builtin_sym_end,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(22), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [2398] = 20,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(59), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(112), 1,
+      sym_each_end,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(34), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [2476] = 20,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(109), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(114), 1,
+      sym_key_end,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(22), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [2554] = 20,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(111), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(115), 1,
+      sym_snippet_end,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(22), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [2632] = 20,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(59), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(127), 1,
+      sym_each_end,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(22), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [2710] = 20,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(59), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(127), 1,
+      sym_each_end,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(19), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [2788] = 20,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(61), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(103), 1,
+      sym_key_end,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(20), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [2866] = 19,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(115), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(22), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [2941] = 19,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(118), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(42), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [3016] = 19,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(121), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(22), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [3091] = 19,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(124), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(22), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [3166] = 19,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(127), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(40), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [3241] = 19,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(130), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(22), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [3316] = 19,
+    ACTIONS(118), 1,
+      anon_sym_LBRACE,
+    ACTIONS(133), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(136), 1,
+      anon_sym_LT,
+    ACTIONS(139), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(142), 1,
+      sym_entity,
+    ACTIONS(145), 1,
+      aux_sym_text_token1,
+    ACTIONS(148), 1,
+      sym_grit_metavariable,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(46), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [3391] = 19,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(151), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(39), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [3466] = 19,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(13), 1,
+      sym_entity,
+    ACTIONS(15), 1,
+      aux_sym_text_token1,
+    ACTIONS(19), 1,
+      sym_grit_metavariable,
+    ACTIONS(154), 1,
+      anon_sym_LBRACE,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(37), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [3541] = 19,
+    ACTIONS(130), 1,
+      anon_sym_LBRACE,
+    ACTIONS(157), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(160), 1,
+      anon_sym_LT,
+    ACTIONS(163), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(166), 1,
+      sym_entity,
+    ACTIONS(169), 1,
+      aux_sym_text_token1,
+    ACTIONS(172), 1,
+      sym_grit_metavariable,
+    STATE(2), 1,
+      sym_if_start,
+    STATE(7), 1,
+      sym_await_start,
+    STATE(11), 1,
+      sym_each_start,
+    STATE(14), 1,
+      sym_start_tag,
+    STATE(26), 1,
+      sym_snippet_start,
+    STATE(36), 1,
+      sym_key_start,
+    STATE(97), 1,
+      sym_self_closing_tag,
+    STATE(215), 1,
+      sym_style_start_tag,
+    STATE(216), 1,
+      sym_script_start_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(22), 2,
+      sym__node,
+      aux_sym_document_repeat1,
+    STATE(66), 16,
+      sym_doctype,
+      sym_element,
+      sym_script_element,
+      sym_style_element,
+      sym_erroneous_end_tag,
+      sym_text,
+      sym_if_statement,
+      sym_each_statement,
+      sym_await_statement,
+      sym_key_statement,
+      sym_snippet_statement,
+      sym_expression,
+      sym_html_tag,
+      sym_const_tag,
+      sym_debug_tag,
+      sym_render_tag,
+  [3616] = 18,
+    ACTIONS(175), 1,
+      anon_sym_POUND,
+    ACTIONS(177), 1,
+      anon_sym_COLON,
+    ACTIONS(179), 1,
+      anon_sym_SLASH,
+    ACTIONS(181), 1,
+      anon_sym_AT,
+    ACTIONS(183), 1,
+      sym_svelte_raw_text,
+    STATE(223), 1,
+      sym__then_tag,
+    STATE(225), 1,
+      sym__catch_tag,
+    STATE(228), 1,
+      sym__each_start_tag,
+    STATE(257), 1,
+      sym__await_start_tag,
+    STATE(262), 1,
+      sym__render_tag,
+    STATE(263), 1,
+      sym__key_start_tag,
+    STATE(264), 1,
+      sym__snippet_start_tag,
+    STATE(265), 1,
+      sym__if_start_tag,
+    STATE(271), 1,
+      sym__await_end_tag,
+    STATE(284), 1,
+      sym__html_tag,
+    STATE(293), 1,
+      sym__const_tag,
+    STATE(327), 1,
+      sym__debug_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [3672] = 18,
+    ACTIONS(175), 1,
+      anon_sym_POUND,
+    ACTIONS(181), 1,
+      anon_sym_AT,
+    ACTIONS(183), 1,
+      sym_svelte_raw_text,
+    ACTIONS(185), 1,
+      anon_sym_COLON,
+    ACTIONS(187), 1,
+      anon_sym_SLASH,
+    STATE(228), 1,
+      sym__each_start_tag,
+    STATE(257), 1,
+      sym__await_start_tag,
+    STATE(262), 1,
+      sym__render_tag,
+    STATE(263), 1,
+      sym__key_start_tag,
+    STATE(264), 1,
+      sym__snippet_start_tag,
+    STATE(265), 1,
+      sym__if_start_tag,
+    STATE(270), 1,
+      sym__else_if_tag,
+    STATE(273), 1,
+      sym__else_tag,
+    STATE(279), 1,
+      sym__if_end_tag,
+    STATE(284), 1,
+      sym__html_tag,
+    STATE(293), 1,
+      sym__const_tag,
+    STATE(327), 1,
+      sym__debug_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [3728] = 18,
+    ACTIONS(175), 1,
+      anon_sym_POUND,
+    ACTIONS(181), 1,
+      anon_sym_AT,
+    ACTIONS(183), 1,
+      sym_svelte_raw_text,
+    ACTIONS(185), 1,
+      anon_sym_COLON,
+    ACTIONS(187), 1,
+      anon_sym_SLASH,
+    STATE(228), 1,
+      sym__each_start_tag,
+    STATE(257), 1,
+      sym__await_start_tag,
+    STATE(262), 1,
+      sym__render_tag,
+    STATE(263), 1,
+      sym__key_start_tag,
+    STATE(264), 1,
+      sym__snippet_start_tag,
+    STATE(265), 1,
+      sym__if_start_tag,
+    STATE(270), 1,
+      sym__else_if_tag,
+    STATE(273), 1,
+      sym__else_tag,
+    STATE(284), 1,
+      sym__html_tag,
+    STATE(293), 1,
+      sym__const_tag,
+    STATE(327), 1,
+      sym__debug_tag,
+    STATE(346), 1,
+      sym__if_end_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [3784] = 18,
+    ACTIONS(175), 1,
+      anon_sym_POUND,
+    ACTIONS(177), 1,
+      anon_sym_COLON,
+    ACTIONS(179), 1,
+      anon_sym_SLASH,
+    ACTIONS(181), 1,
+      anon_sym_AT,
+    ACTIONS(183), 1,
+      sym_svelte_raw_text,
+    STATE(223), 1,
+      sym__then_tag,
+    STATE(225), 1,
+      sym__catch_tag,
+    STATE(228), 1,
+      sym__each_start_tag,
+    STATE(257), 1,
+      sym__await_start_tag,
+    STATE(262), 1,
+      sym__render_tag,
+    STATE(263), 1,
+      sym__key_start_tag,
+    STATE(264), 1,
+      sym__snippet_start_tag,
+    STATE(265), 1,
+      sym__if_start_tag,
+    STATE(284), 1,
+      sym__html_tag,
+    STATE(293), 1,
+      sym__const_tag,
+    STATE(308), 1,
+      sym__await_end_tag,
+    STATE(327), 1,
+      sym__debug_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [3840] = 17,
+    ACTIONS(175), 1,
+      anon_sym_POUND,
+    ACTIONS(181), 1,
+      anon_sym_AT,
+    ACTIONS(183), 1,
+      sym_svelte_raw_text,
+    ACTIONS(189), 1,
+      anon_sym_COLON,
+    ACTIONS(191), 1,
+      anon_sym_SLASH,
+    STATE(228), 1,
+      sym__each_start_tag,
+    STATE(257), 1,
+      sym__await_start_tag,
+    STATE(261), 1,
+      sym__each_end_tag,
+    STATE(262), 1,
+      sym__render_tag,
+    STATE(263), 1,
+      sym__key_start_tag,
+    STATE(264), 1,
+      sym__snippet_start_tag,
+    STATE(265), 1,
+      sym__if_start_tag,
+    STATE(273), 1,
+      sym__else_tag,
+    STATE(284), 1,
+      sym__html_tag,
+    STATE(293), 1,
+      sym__const_tag,
+    STATE(327), 1,
+      sym__debug_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [3893] = 17,
+    ACTIONS(175), 1,
+      anon_sym_POUND,
+    ACTIONS(181), 1,
+      anon_sym_AT,
+    ACTIONS(183), 1,
+      sym_svelte_raw_text,
+    ACTIONS(189), 1,
+      anon_sym_COLON,
+    ACTIONS(191), 1,
+      anon_sym_SLASH,
+    STATE(228), 1,
+      sym__each_start_tag,
+    STATE(257), 1,
+      sym__await_start_tag,
+    STATE(262), 1,
+      sym__render_tag,
+    STATE(263), 1,
+      sym__key_start_tag,
+    STATE(264), 1,
+      sym__snippet_start_tag,
+    STATE(265), 1,
+      sym__if_start_tag,
+    STATE(273), 1,
+      sym__else_tag,
+    STATE(284), 1,
+      sym__html_tag,
+    STATE(293), 1,
+      sym__const_tag,
+    STATE(306), 1,
+      sym__each_end_tag,
+    STATE(327), 1,
+      sym__debug_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [3946] = 15,
+    ACTIONS(175), 1,
+      anon_sym_POUND,
+    ACTIONS(181), 1,
+      anon_sym_AT,
+    ACTIONS(183), 1,
+      sym_svelte_raw_text,
+    ACTIONS(193), 1,
+      anon_sym_SLASH,
+    STATE(228), 1,
+      sym__each_start_tag,
+    STATE(257), 1,
+      sym__await_start_tag,
+    STATE(262), 1,
+      sym__render_tag,
+    STATE(263), 1,
+      sym__key_start_tag,
+    STATE(264), 1,
+      sym__snippet_start_tag,
+    STATE(265), 1,
+      sym__if_start_tag,
+    STATE(284), 1,
+      sym__html_tag,
+    STATE(292), 1,
+      sym__key_end_tag,
+    STATE(293), 1,
+      sym__const_tag,
+    STATE(327), 1,
+      sym__debug_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [3993] = 15,
+    ACTIONS(175), 1,
+      anon_sym_POUND,
+    ACTIONS(181), 1,
+      anon_sym_AT,
+    ACTIONS(183), 1,
+      sym_svelte_raw_text,
+    ACTIONS(195), 1,
+      anon_sym_SLASH,
+    STATE(228), 1,
+      sym__each_start_tag,
+    STATE(257), 1,
+      sym__await_start_tag,
+    STATE(262), 1,
+      sym__render_tag,
+    STATE(263), 1,
+      sym__key_start_tag,
+    STATE(264), 1,
+      sym__snippet_start_tag,
+    STATE(265), 1,
+      sym__if_start_tag,
+    STATE(284), 1,
+      sym__html_tag,
+    STATE(293), 1,
+      sym__const_tag,
+    STATE(302), 1,
+      sym__snippet_end_tag,
+    STATE(327), 1,
+      sym__debug_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [4040] = 15,
+    ACTIONS(175), 1,
+      anon_sym_POUND,
+    ACTIONS(181), 1,
+      anon_sym_AT,
+    ACTIONS(183), 1,
+      sym_svelte_raw_text,
+    ACTIONS(191), 1,
+      anon_sym_SLASH,
+    STATE(228), 1,
+      sym__each_start_tag,
+    STATE(257), 1,
+      sym__await_start_tag,
+    STATE(261), 1,
+      sym__each_end_tag,
+    STATE(262), 1,
+      sym__render_tag,
+    STATE(263), 1,
+      sym__key_start_tag,
+    STATE(264), 1,
+      sym__snippet_start_tag,
+    STATE(265), 1,
+      sym__if_start_tag,
+    STATE(284), 1,
+      sym__html_tag,
+    STATE(293), 1,
+      sym__const_tag,
+    STATE(327), 1,
+      sym__debug_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [4087] = 15,
+    ACTIONS(175), 1,
+      anon_sym_POUND,
+    ACTIONS(181), 1,
+      anon_sym_AT,
+    ACTIONS(183), 1,
+      sym_svelte_raw_text,
+    ACTIONS(195), 1,
+      anon_sym_SLASH,
+    STATE(228), 1,
+      sym__each_start_tag,
+    STATE(257), 1,
+      sym__await_start_tag,
+    STATE(262), 1,
+      sym__render_tag,
+    STATE(263), 1,
+      sym__key_start_tag,
+    STATE(264), 1,
+      sym__snippet_start_tag,
+    STATE(265), 1,
+      sym__if_start_tag,
+    STATE(284), 1,
+      sym__html_tag,
+    STATE(293), 1,
+      sym__const_tag,
+    STATE(314), 1,
+      sym__snippet_end_tag,
+    STATE(327), 1,
+      sym__debug_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [4134] = 15,
+    ACTIONS(175), 1,
+      anon_sym_POUND,
+    ACTIONS(181), 1,
+      anon_sym_AT,
+    ACTIONS(183), 1,
+      sym_svelte_raw_text,
+    ACTIONS(191), 1,
+      anon_sym_SLASH,
+    STATE(228), 1,
+      sym__each_start_tag,
+    STATE(257), 1,
+      sym__await_start_tag,
+    STATE(262), 1,
+      sym__render_tag,
+    STATE(263), 1,
+      sym__key_start_tag,
+    STATE(264), 1,
+      sym__snippet_start_tag,
+    STATE(265), 1,
+      sym__if_start_tag,
+    STATE(284), 1,
+      sym__html_tag,
+    STATE(293), 1,
+      sym__const_tag,
+    STATE(306), 1,
+      sym__each_end_tag,
+    STATE(327), 1,
+      sym__debug_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [4181] = 15,
+    ACTIONS(175), 1,
+      anon_sym_POUND,
+    ACTIONS(181), 1,
+      anon_sym_AT,
+    ACTIONS(183), 1,
+      sym_svelte_raw_text,
+    ACTIONS(193), 1,
+      anon_sym_SLASH,
+    STATE(228), 1,
+      sym__each_start_tag,
+    STATE(257), 1,
+      sym__await_start_tag,
+    STATE(262), 1,
+      sym__render_tag,
+    STATE(263), 1,
+      sym__key_start_tag,
+    STATE(264), 1,
+      sym__snippet_start_tag,
+    STATE(265), 1,
+      sym__if_start_tag,
+    STATE(284), 1,
+      sym__html_tag,
+    STATE(293), 1,
+      sym__const_tag,
+    STATE(313), 1,
+      sym__key_end_tag,
+    STATE(327), 1,
+      sym__debug_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [4228] = 13,
+    ACTIONS(175), 1,
+      anon_sym_POUND,
+    ACTIONS(181), 1,
+      anon_sym_AT,
+    ACTIONS(197), 1,
+      sym_svelte_raw_text,
+    STATE(228), 1,
+      sym__each_start_tag,
+    STATE(255), 1,
+      sym__const_tag,
+    STATE(257), 1,
+      sym__await_start_tag,
+    STATE(263), 1,
+      sym__key_start_tag,
+    STATE(264), 1,
+      sym__snippet_start_tag,
+    STATE(265), 1,
+      sym__if_start_tag,
+    STATE(331), 1,
+      sym__html_tag,
+    STATE(333), 1,
+      sym__debug_tag,
+    STATE(334), 1,
+      sym__render_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [4269] = 13,
+    ACTIONS(175), 1,
+      anon_sym_POUND,
+    ACTIONS(181), 1,
+      anon_sym_AT,
+    ACTIONS(183), 1,
+      sym_svelte_raw_text,
+    STATE(228), 1,
+      sym__each_start_tag,
+    STATE(257), 1,
+      sym__await_start_tag,
+    STATE(262), 1,
+      sym__render_tag,
+    STATE(263), 1,
+      sym__key_start_tag,
+    STATE(264), 1,
+      sym__snippet_start_tag,
+    STATE(265), 1,
+      sym__if_start_tag,
+    STATE(284), 1,
+      sym__html_tag,
+    STATE(293), 1,
+      sym__const_tag,
+    STATE(327), 1,
+      sym__debug_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [4310] = 9,
+    ACTIONS(199), 1,
+      anon_sym_GT,
+    ACTIONS(201), 1,
+      anon_sym_SLASH_GT,
+    ACTIONS(203), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(205), 1,
+      anon_sym_LBRACE,
+    ACTIONS(207), 1,
+      sym_grit_metavariable,
+    STATE(177), 1,
+      sym_attribute_name,
+    STATE(188), 1,
+      sym_expression,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(62), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [4340] = 8,
+    ACTIONS(211), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(214), 1,
+      anon_sym_LBRACE,
+    ACTIONS(217), 1,
+      sym_grit_metavariable,
+    STATE(177), 1,
+      sym_attribute_name,
+    STATE(188), 1,
+      sym_expression,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(209), 2,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+    STATE(62), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [4368] = 9,
+    ACTIONS(203), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(205), 1,
+      anon_sym_LBRACE,
+    ACTIONS(207), 1,
+      sym_grit_metavariable,
+    ACTIONS(220), 1,
+      anon_sym_GT,
+    ACTIONS(222), 1,
+      anon_sym_SLASH_GT,
+    STATE(177), 1,
+      sym_attribute_name,
+    STATE(188), 1,
+      sym_expression,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(65), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [4398] = 9,
+    ACTIONS(203), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(205), 1,
+      anon_sym_LBRACE,
+    ACTIONS(207), 1,
+      sym_grit_metavariable,
+    ACTIONS(220), 1,
+      anon_sym_GT,
+    ACTIONS(224), 1,
+      anon_sym_SLASH_GT,
+    STATE(177), 1,
+      sym_attribute_name,
+    STATE(188), 1,
+      sym_expression,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(61), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [4428] = 9,
+    ACTIONS(199), 1,
+      anon_sym_GT,
+    ACTIONS(203), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(205), 1,
+      anon_sym_LBRACE,
+    ACTIONS(207), 1,
+      sym_grit_metavariable,
+    ACTIONS(226), 1,
+      anon_sym_SLASH_GT,
+    STATE(177), 1,
+      sym_attribute_name,
+    STATE(188), 1,
+      sym_expression,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(62), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [4458] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(230), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(228), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4475] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(234), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(232), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4492] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(238), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(236), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4509] = 7,
+    ACTIONS(205), 1,
+      anon_sym_LBRACE,
+    ACTIONS(240), 1,
+      aux_sym_attribute_value_token1,
+    ACTIONS(242), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(244), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(246), 1,
+      sym_grit_metavariable,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(189), 3,
+      sym_attribute_value,
+      sym_quoted_attribute_value,
+      sym_expression,
+  [4534] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(250), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(248), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4551] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(254), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(252), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4568] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(258), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(256), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4585] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(262), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(260), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4602] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(266), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(264), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4619] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(270), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(268), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4636] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(274), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(272), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4653] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(278), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(276), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4670] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(282), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(280), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4687] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(286), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(284), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4704] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(290), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(288), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4721] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(294), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(292), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4738] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(298), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(296), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4755] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(302), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(300), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4772] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(306), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(304), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4789] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(310), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(308), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4806] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(314), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(312), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4823] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(318), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(316), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4840] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(322), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(320), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4857] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(326), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(324), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4874] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(330), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(328), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4891] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(230), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(228), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4908] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(330), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(328), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4925] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(334), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(332), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4942] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(258), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(256), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4959] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(338), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(336), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4976] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(342), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(340), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [4993] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(334), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(332), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5010] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(346), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(344), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5027] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(350), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(348), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5044] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(298), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(296), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5061] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(354), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(352), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5078] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(358), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(356), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5095] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(354), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(352), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5112] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(362), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(360), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5129] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(366), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(364), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5146] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(370), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(368), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5163] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(374), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(372), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5180] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(378), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(376), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5197] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(382), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(380), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5214] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(338), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(336), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5231] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(386), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(384), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5248] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(390), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(388), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5265] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(394), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(392), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5282] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(398), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(396), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5299] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(234), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(232), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5316] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(238), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(236), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5333] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(254), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(252), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5350] = 8,
+    ACTIONS(209), 1,
+      anon_sym_GT,
+    ACTIONS(400), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(403), 1,
+      anon_sym_LBRACE,
+    ACTIONS(406), 1,
+      sym_grit_metavariable,
+    STATE(190), 1,
+      sym_attribute_name,
+    STATE(199), 1,
+      sym_expression,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(118), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [5377] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(262), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(260), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5394] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(266), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(264), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5411] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(270), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(268), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5428] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(274), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(272), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5445] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(278), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(276), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5462] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(282), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(280), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5479] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(286), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(284), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5496] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(290), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(288), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5513] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(294), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(292), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5530] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(302), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(300), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5547] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(306), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(304), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5564] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(310), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(308), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5581] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(314), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(312), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5598] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(318), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(316), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5615] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(322), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(320), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5632] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(326), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(324), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5649] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(346), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(344), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5666] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(358), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(356), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5683] = 8,
+    ACTIONS(409), 1,
+      anon_sym_GT,
+    ACTIONS(411), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(413), 1,
+      anon_sym_LBRACE,
+    ACTIONS(415), 1,
+      sym_grit_metavariable,
+    STATE(190), 1,
+      sym_attribute_name,
+    STATE(199), 1,
+      sym_expression,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(142), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [5710] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(419), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(417), 6,
+      sym__implicit_end_tag,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5727] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(362), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(360), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5744] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(394), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(392), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5761] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(342), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(340), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5778] = 8,
+    ACTIONS(411), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(413), 1,
+      anon_sym_LBRACE,
+    ACTIONS(415), 1,
+      sym_grit_metavariable,
+    ACTIONS(421), 1,
+      anon_sym_GT,
+    STATE(190), 1,
+      sym_attribute_name,
+    STATE(199), 1,
+      sym_expression,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(118), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [5805] = 8,
+    ACTIONS(411), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(413), 1,
+      anon_sym_LBRACE,
+    ACTIONS(415), 1,
+      sym_grit_metavariable,
+    ACTIONS(423), 1,
+      anon_sym_GT,
+    STATE(190), 1,
+      sym_attribute_name,
+    STATE(199), 1,
+      sym_expression,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(118), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [5832] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(366), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(364), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5849] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(398), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(396), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5866] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(370), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(368), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5883] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(374), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(372), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5900] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(378), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(376), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5917] = 7,
+    ACTIONS(413), 1,
+      anon_sym_LBRACE,
+    ACTIONS(425), 1,
+      aux_sym_attribute_value_token1,
+    ACTIONS(427), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(429), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(431), 1,
+      sym_grit_metavariable,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(203), 3,
+      sym_attribute_value,
+      sym_quoted_attribute_value,
+      sym_expression,
+  [5942] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(382), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(380), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [5959] = 8,
+    ACTIONS(411), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(413), 1,
+      anon_sym_LBRACE,
+    ACTIONS(415), 1,
+      sym_grit_metavariable,
+    ACTIONS(433), 1,
+      anon_sym_GT,
+    STATE(190), 1,
+      sym_attribute_name,
+    STATE(199), 1,
+      sym_expression,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(143), 2,
+      sym_attribute,
+      aux_sym_start_tag_repeat1,
+  [5986] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(386), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(384), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6003] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(350), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(348), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6020] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(390), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(388), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6037] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(437), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(435), 5,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6053] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(441), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(439), 5,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6069] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(445), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(443), 5,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6085] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(449), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(447), 5,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6101] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(453), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(451), 5,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6117] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(457), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(455), 5,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6133] = 7,
+    ACTIONS(459), 1,
+      anon_sym_LBRACE,
+    STATE(38), 1,
+      sym_else_start,
+    STATE(44), 1,
+      sym_else_if_start,
+    STATE(152), 1,
+      sym_if_end,
+    STATE(229), 1,
+      sym_else_block,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(196), 2,
+      sym_else_if_block,
+      aux_sym_if_statement_repeat1,
+  [6157] = 7,
+    ACTIONS(461), 1,
+      anon_sym_LBRACE,
+    STATE(38), 1,
+      sym_else_start,
+    STATE(44), 1,
+      sym_else_if_start,
+    STATE(111), 1,
+      sym_if_end,
+    STATE(245), 1,
+      sym_else_block,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(196), 2,
+      sym_else_if_block,
+      aux_sym_if_statement_repeat1,
+  [6181] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(465), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(463), 5,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6197] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(469), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(467), 5,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6213] = 7,
+    ACTIONS(461), 1,
+      anon_sym_LBRACE,
+    STATE(38), 1,
+      sym_else_start,
+    STATE(44), 1,
+      sym_else_if_start,
+    STATE(125), 1,
+      sym_if_end,
+    STATE(250), 1,
+      sym_else_block,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(196), 2,
+      sym_else_if_block,
+      aux_sym_if_statement_repeat1,
+  [6237] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(473), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(471), 5,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6253] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(477), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(475), 5,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6269] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(481), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(479), 5,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6285] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(485), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(483), 5,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6301] = 3,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(489), 2,
+      anon_sym_LT,
+      aux_sym_text_token1,
+    ACTIONS(487), 5,
+      anon_sym_LT_BANG,
+      anon_sym_LT_SLASH,
+      sym_entity,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6317] = 7,
+    ACTIONS(459), 1,
+      anon_sym_LBRACE,
+    STATE(38), 1,
+      sym_else_start,
+    STATE(44), 1,
+      sym_else_if_start,
+    STATE(79), 1,
+      sym_if_end,
+    STATE(238), 1,
+      sym_else_block,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(196), 2,
+      sym_else_if_block,
+      aux_sym_if_statement_repeat1,
+  [6341] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(491), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(493), 1,
+      aux_sym__single_quoted_attribute_value_token1,
+    ACTIONS(496), 1,
+      anon_sym_LBRACE,
+    ACTIONS(499), 1,
+      aux_sym__tag_value_token1,
+    ACTIONS(501), 1,
+      sym_grit_metavariable,
+    STATE(172), 1,
+      aux_sym__single_quoted_attribute_value,
+    STATE(195), 1,
+      sym_expression,
+  [6366] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(499), 1,
+      aux_sym__tag_value_token1,
+    ACTIONS(504), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(506), 1,
+      aux_sym__double_quoted_attribute_value_token1,
+    ACTIONS(509), 1,
+      anon_sym_LBRACE,
+    ACTIONS(512), 1,
+      sym_grit_metavariable,
+    STATE(173), 1,
+      aux_sym__double_quoted_attribute_value,
+    STATE(197), 1,
+      sym_expression,
+  [6391] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(499), 1,
+      aux_sym__tag_value_token1,
+    ACTIONS(515), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(517), 1,
+      aux_sym__double_quoted_attribute_value_token1,
+    ACTIONS(519), 1,
+      anon_sym_LBRACE,
+    ACTIONS(521), 1,
+      sym_grit_metavariable,
+    STATE(173), 1,
+      aux_sym__double_quoted_attribute_value,
+    STATE(197), 1,
+      sym_expression,
+  [6416] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(499), 1,
+      aux_sym__tag_value_token1,
+    ACTIONS(515), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(523), 1,
+      aux_sym__single_quoted_attribute_value_token1,
+    ACTIONS(525), 1,
+      anon_sym_LBRACE,
+    ACTIONS(527), 1,
+      sym_grit_metavariable,
+    STATE(172), 1,
+      aux_sym__single_quoted_attribute_value,
+    STATE(195), 1,
+      sym_expression,
+  [6441] = 3,
+    ACTIONS(531), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(529), 5,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      anon_sym_EQ,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6456] = 4,
+    ACTIONS(535), 1,
+      anon_sym_EQ,
+    ACTIONS(537), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(533), 4,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6473] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(499), 1,
+      aux_sym__tag_value_token1,
+    ACTIONS(523), 1,
+      aux_sym__single_quoted_attribute_value_token1,
+    ACTIONS(525), 1,
+      anon_sym_LBRACE,
+    ACTIONS(527), 1,
+      sym_grit_metavariable,
+    ACTIONS(539), 1,
+      anon_sym_SQUOTE,
+    STATE(175), 1,
+      aux_sym__single_quoted_attribute_value,
+    STATE(195), 1,
+      sym_expression,
+  [6498] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(499), 1,
+      aux_sym__tag_value_token1,
+    ACTIONS(517), 1,
+      aux_sym__double_quoted_attribute_value_token1,
+    ACTIONS(519), 1,
+      anon_sym_LBRACE,
+    ACTIONS(521), 1,
+      sym_grit_metavariable,
+    ACTIONS(539), 1,
+      anon_sym_DQUOTE,
+    STATE(174), 1,
+      aux_sym__double_quoted_attribute_value,
+    STATE(197), 1,
+      sym_expression,
+  [6523] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(499), 1,
+      aux_sym__tag_value_token1,
+    ACTIONS(523), 1,
+      aux_sym__single_quoted_attribute_value_token1,
+    ACTIONS(525), 1,
+      anon_sym_LBRACE,
+    ACTIONS(527), 1,
+      sym_grit_metavariable,
+    ACTIONS(541), 1,
+      anon_sym_SQUOTE,
+    STATE(182), 1,
+      aux_sym__single_quoted_attribute_value,
+    STATE(195), 1,
+      sym_expression,
+  [6548] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(499), 1,
+      aux_sym__tag_value_token1,
+    ACTIONS(517), 1,
+      aux_sym__double_quoted_attribute_value_token1,
+    ACTIONS(519), 1,
+      anon_sym_LBRACE,
+    ACTIONS(521), 1,
+      sym_grit_metavariable,
+    ACTIONS(541), 1,
+      anon_sym_DQUOTE,
+    STATE(183), 1,
+      aux_sym__double_quoted_attribute_value,
+    STATE(197), 1,
+      sym_expression,
+  [6573] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(499), 1,
+      aux_sym__tag_value_token1,
+    ACTIONS(523), 1,
+      aux_sym__single_quoted_attribute_value_token1,
+    ACTIONS(525), 1,
+      anon_sym_LBRACE,
+    ACTIONS(527), 1,
+      sym_grit_metavariable,
+    ACTIONS(543), 1,
+      anon_sym_SQUOTE,
+    STATE(172), 1,
+      aux_sym__single_quoted_attribute_value,
+    STATE(195), 1,
+      sym_expression,
+  [6598] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(499), 1,
+      aux_sym__tag_value_token1,
+    ACTIONS(517), 1,
+      aux_sym__double_quoted_attribute_value_token1,
+    ACTIONS(519), 1,
+      anon_sym_LBRACE,
+    ACTIONS(521), 1,
+      sym_grit_metavariable,
+    ACTIONS(543), 1,
+      anon_sym_DQUOTE,
+    STATE(173), 1,
+      aux_sym__double_quoted_attribute_value,
+    STATE(197), 1,
+      sym_expression,
+  [6623] = 6,
+    ACTIONS(545), 1,
+      anon_sym_if,
+    ACTIONS(547), 1,
+      anon_sym_each,
+    ACTIONS(549), 1,
+      anon_sym_await,
+    ACTIONS(551), 1,
+      anon_sym_key,
+    ACTIONS(553), 1,
+      anon_sym_snippet,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [6643] = 6,
+    ACTIONS(185), 1,
+      anon_sym_COLON,
+    ACTIONS(187), 1,
+      anon_sym_SLASH,
+    STATE(270), 1,
+      sym__else_if_tag,
+    STATE(273), 1,
+      sym__else_tag,
+    STATE(346), 1,
+      sym__if_end_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [6663] = 3,
+    ACTIONS(557), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(555), 4,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6677] = 3,
+    ACTIONS(370), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(368), 4,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6691] = 3,
+    ACTIONS(561), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(559), 4,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6705] = 3,
+    ACTIONS(565), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(563), 4,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6719] = 4,
+    ACTIONS(537), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(567), 1,
+      anon_sym_EQ,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(533), 3,
+      anon_sym_GT,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6735] = 3,
+    ACTIONS(571), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(569), 4,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6749] = 3,
+    ACTIONS(575), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(573), 4,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6763] = 6,
+    ACTIONS(185), 1,
+      anon_sym_COLON,
+    ACTIONS(187), 1,
+      anon_sym_SLASH,
+    STATE(270), 1,
+      sym__else_if_tag,
+    STATE(273), 1,
+      sym__else_tag,
+    STATE(279), 1,
+      sym__if_end_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [6783] = 3,
+    ACTIONS(531), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(529), 4,
+      anon_sym_GT,
+      anon_sym_EQ,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6797] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(499), 1,
+      aux_sym__tag_value_token1,
+    ACTIONS(579), 1,
+      aux_sym__single_quoted_attribute_value_token1,
+    ACTIONS(577), 3,
+      anon_sym_SQUOTE,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6812] = 4,
+    ACTIONS(581), 1,
+      anon_sym_LBRACE,
+    STATE(44), 1,
+      sym_else_if_start,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    STATE(196), 2,
+      sym_else_if_block,
+      aux_sym_if_statement_repeat1,
+  [6827] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(499), 1,
+      aux_sym__tag_value_token1,
+    ACTIONS(586), 1,
+      aux_sym__double_quoted_attribute_value_token1,
+    ACTIONS(584), 3,
+      anon_sym_DQUOTE,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6842] = 5,
+    ACTIONS(588), 1,
+      anon_sym_LBRACE,
+    STATE(45), 1,
+      sym_catch_start,
+    STATE(140), 1,
+      sym_await_end,
+    STATE(230), 1,
+      sym_catch_block,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [6859] = 3,
+    ACTIONS(561), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(559), 3,
+      anon_sym_GT,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6872] = 5,
+    ACTIONS(588), 1,
+      anon_sym_LBRACE,
+    STATE(45), 1,
+      sym_catch_start,
+    STATE(84), 1,
+      sym_await_end,
+    STATE(252), 1,
+      sym_catch_block,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [6889] = 5,
+    ACTIONS(179), 1,
+      anon_sym_SLASH,
+    ACTIONS(590), 1,
+      anon_sym_COLON,
+    STATE(225), 1,
+      sym__catch_tag,
+    STATE(271), 1,
+      sym__await_end_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [6906] = 3,
+    ACTIONS(557), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(555), 3,
+      anon_sym_GT,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6919] = 3,
+    ACTIONS(565), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(563), 3,
+      anon_sym_GT,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6932] = 5,
+    ACTIONS(592), 1,
+      anon_sym_html,
+    ACTIONS(594), 1,
+      anon_sym_const,
+    ACTIONS(596), 1,
+      anon_sym_debug,
+    ACTIONS(598), 1,
+      anon_sym_render,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [6949] = 3,
+    ACTIONS(571), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(569), 3,
+      anon_sym_GT,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6962] = 3,
+    ACTIONS(575), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(573), 3,
+      anon_sym_GT,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6975] = 3,
+    ACTIONS(370), 1,
+      aux_sym_attribute_name_token1,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(368), 3,
+      anon_sym_GT,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [6988] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(370), 1,
+      aux_sym__single_quoted_attribute_value_token1,
+    ACTIONS(499), 1,
+      aux_sym__tag_value_token1,
+    ACTIONS(368), 3,
+      anon_sym_SQUOTE,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [7003] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(370), 1,
+      aux_sym__double_quoted_attribute_value_token1,
+    ACTIONS(499), 1,
+      aux_sym__tag_value_token1,
+    ACTIONS(368), 3,
+      anon_sym_DQUOTE,
+      anon_sym_LBRACE,
+      sym_grit_metavariable,
+  [7018] = 5,
+    ACTIONS(600), 1,
+      anon_sym_LBRACE,
+    STATE(45), 1,
+      sym_catch_start,
+    STATE(113), 1,
+      sym_await_end,
+    STATE(247), 1,
+      sym_catch_block,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7035] = 5,
+    ACTIONS(179), 1,
+      anon_sym_SLASH,
+    ACTIONS(590), 1,
+      anon_sym_COLON,
+    STATE(225), 1,
+      sym__catch_tag,
+    STATE(308), 1,
+      sym__await_end_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7052] = 5,
+    ACTIONS(600), 1,
+      anon_sym_LBRACE,
+    STATE(45), 1,
+      sym_catch_start,
+    STATE(129), 1,
+      sym_await_end,
+    STATE(251), 1,
+      sym_catch_block,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7069] = 4,
+    ACTIONS(602), 1,
+      sym__start_tag_name,
+    ACTIONS(604), 1,
+      sym__script_start_tag_name,
+    ACTIONS(606), 1,
+      sym__style_start_tag_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7083] = 4,
+    ACTIONS(604), 1,
+      sym__script_start_tag_name,
+    ACTIONS(606), 1,
+      sym__style_start_tag_name,
+    ACTIONS(608), 1,
+      sym__start_tag_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7097] = 4,
+    ACTIONS(610), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(612), 1,
+      sym_raw_text,
+    STATE(141), 1,
+      sym_end_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7111] = 4,
+    ACTIONS(610), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(614), 1,
+      sym_raw_text,
+    STATE(110), 1,
+      sym_end_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7125] = 4,
+    ACTIONS(616), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(618), 1,
+      sym_raw_text,
+    STATE(95), 1,
+      sym_end_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7139] = 4,
+    ACTIONS(616), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(620), 1,
+      sym_raw_text,
+    STATE(96), 1,
+      sym_end_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7153] = 3,
+    ACTIONS(187), 1,
+      anon_sym_SLASH,
+    STATE(346), 1,
+      sym__if_end_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7164] = 3,
+    ACTIONS(622), 1,
+      anon_sym_LBRACE,
+    STATE(140), 1,
+      sym_await_end,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7175] = 3,
+    ACTIONS(624), 1,
+      anon_sym_RPAREN,
+    ACTIONS(626), 1,
+      sym_svelte_raw_text_snippet_arguments,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7186] = 3,
+    ACTIONS(628), 1,
+      anon_sym_then,
+    ACTIONS(630), 1,
+      anon_sym_catch,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7197] = 3,
+    ACTIONS(632), 1,
+      anon_sym_RBRACE,
+    ACTIONS(634), 1,
+      sym_svelte_raw_text,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7208] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(636), 2,
+      sym_raw_text,
+      anon_sym_LT_SLASH,
+  [7217] = 3,
+    ACTIONS(638), 1,
+      anon_sym_RBRACE,
+    ACTIONS(640), 1,
+      sym_svelte_raw_text,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7228] = 3,
+    ACTIONS(187), 1,
+      anon_sym_SLASH,
+    STATE(279), 1,
+      sym__if_end_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7239] = 3,
+    ACTIONS(610), 1,
+      anon_sym_LT_SLASH,
+    STATE(148), 1,
+      sym_end_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7250] = 3,
+    ACTIONS(642), 1,
+      sym_svelte_raw_text,
+    ACTIONS(644), 1,
+      sym_svelte_raw_text_each,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7261] = 3,
+    ACTIONS(646), 1,
+      anon_sym_LBRACE,
+    STATE(79), 1,
+      sym_if_end,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7272] = 3,
+    ACTIONS(622), 1,
+      anon_sym_LBRACE,
+    STATE(84), 1,
+      sym_await_end,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7283] = 3,
+    ACTIONS(179), 1,
+      anon_sym_SLASH,
+    STATE(271), 1,
+      sym__await_end_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7294] = 3,
+    ACTIONS(648), 1,
+      sym__end_tag_name,
+    ACTIONS(650), 1,
+      sym_erroneous_end_tag_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7305] = 3,
+    ACTIONS(610), 1,
+      anon_sym_LT_SLASH,
+    STATE(150), 1,
+      sym_end_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7316] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(652), 2,
+      sym_svelte_raw_text,
+      sym_svelte_raw_text_each,
+  [7325] = 3,
+    ACTIONS(654), 1,
+      anon_sym_elseif,
+    ACTIONS(656), 1,
+      anon_sym_else,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7336] = 3,
+    ACTIONS(616), 1,
+      anon_sym_LT_SLASH,
+    STATE(109), 1,
+      sym_end_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7347] = 3,
+    ACTIONS(658), 1,
+      anon_sym_LBRACE,
+    STATE(111), 1,
+      sym_if_end,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7358] = 3,
+    ACTIONS(646), 1,
+      anon_sym_LBRACE,
+    STATE(87), 1,
+      sym_if_end,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7369] = 3,
+    ACTIONS(660), 1,
+      anon_sym_COLON,
+    STATE(270), 1,
+      sym__else_if_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7380] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(662), 2,
+      sym_raw_text,
+      anon_sym_LT_SLASH,
+  [7389] = 3,
+    ACTIONS(664), 1,
+      anon_sym_LBRACE,
+    STATE(113), 1,
+      sym_await_end,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7400] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(666), 2,
+      sym_svelte_raw_text,
+      anon_sym_RBRACE,
+  [7409] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(668), 2,
+      sym_svelte_raw_text,
+      anon_sym_RBRACE,
+  [7418] = 3,
+    ACTIONS(650), 1,
+      sym_erroneous_end_tag_name,
+    ACTIONS(670), 1,
+      sym__end_tag_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7429] = 3,
+    ACTIONS(658), 1,
+      anon_sym_LBRACE,
+    STATE(125), 1,
+      sym_if_end,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7440] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(672), 2,
+      sym_raw_text,
+      anon_sym_LT_SLASH,
+  [7449] = 3,
+    ACTIONS(664), 1,
+      anon_sym_LBRACE,
+    STATE(129), 1,
+      sym_await_end,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7460] = 3,
+    ACTIONS(179), 1,
+      anon_sym_SLASH,
+    STATE(308), 1,
+      sym__await_end_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7471] = 3,
+    ACTIONS(646), 1,
+      anon_sym_LBRACE,
+    STATE(152), 1,
+      sym_if_end,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7482] = 3,
+    ACTIONS(658), 1,
+      anon_sym_LBRACE,
+    STATE(132), 1,
+      sym_if_end,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7493] = 3,
+    ACTIONS(664), 1,
+      anon_sym_LBRACE,
+    STATE(134), 1,
+      sym_await_end,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7504] = 3,
+    ACTIONS(622), 1,
+      anon_sym_LBRACE,
+    STATE(89), 1,
+      sym_await_end,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7515] = 2,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+    ACTIONS(674), 2,
+      sym_raw_text,
+      anon_sym_LT_SLASH,
+  [7524] = 3,
+    ACTIONS(616), 1,
+      anon_sym_LT_SLASH,
+    STATE(108), 1,
+      sym_end_tag,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7535] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(676), 1,
+      aux_sym__tag_value_token1,
+    STATE(297), 1,
+      sym__tag_value,
+  [7545] = 2,
+    ACTIONS(678), 1,
+      sym_svelte_raw_text,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7553] = 2,
+    ACTIONS(680), 1,
+      sym_svelte_raw_text,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7561] = 2,
+    ACTIONS(682), 1,
+      anon_sym_else,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7569] = 2,
+    ACTIONS(684), 1,
+      anon_sym_each,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7577] = 2,
+    ACTIONS(686), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7585] = 2,
+    ACTIONS(688), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7593] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(676), 1,
+      aux_sym__tag_value_token1,
+    STATE(318), 1,
+      sym__tag_value,
+  [7603] = 2,
+    ACTIONS(690), 1,
+      sym_svelte_raw_text,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7611] = 2,
+    ACTIONS(692), 1,
+      aux_sym_snippet_start_token1,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7619] = 2,
+    ACTIONS(694), 1,
+      sym_svelte_raw_text,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7627] = 2,
+    ACTIONS(696), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7635] = 2,
+    ACTIONS(698), 1,
+      anon_sym_if,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7643] = 2,
+    ACTIONS(700), 1,
+      anon_sym_await,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7651] = 2,
+    ACTIONS(702), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7659] = 2,
+    ACTIONS(704), 1,
+      sym_svelte_raw_text,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7667] = 2,
+    ACTIONS(706), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7675] = 2,
+    ACTIONS(708), 1,
+      sym_svelte_raw_text,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7683] = 2,
+    ACTIONS(710), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7691] = 2,
+    ACTIONS(712), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7699] = 2,
+    ACTIONS(714), 1,
+      sym_svelte_raw_text,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7707] = 2,
+    ACTIONS(716), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7715] = 2,
+    ACTIONS(718), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7723] = 2,
+    ACTIONS(720), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7731] = 2,
+    ACTIONS(722), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7739] = 2,
+    ACTIONS(724), 1,
+      sym_svelte_raw_text,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7747] = 2,
+    ACTIONS(726), 1,
+      anon_sym_GT,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7755] = 2,
+    ACTIONS(728), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7763] = 2,
+    ACTIONS(730), 1,
+      sym_svelte_raw_text,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7771] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(676), 1,
+      aux_sym__tag_value_token1,
+    STATE(341), 1,
+      sym__tag_value,
+  [7781] = 2,
+    ACTIONS(648), 1,
+      sym__end_tag_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7789] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(499), 1,
+      aux_sym__tag_value_token1,
+    ACTIONS(732), 1,
+      aux_sym_doctype_token1,
+  [7799] = 2,
+    ACTIONS(734), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7807] = 2,
+    ACTIONS(654), 1,
+      anon_sym_elseif,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7815] = 2,
+    ACTIONS(736), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7823] = 2,
+    ACTIONS(738), 1,
+      aux_sym_snippet_start_token1,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7831] = 2,
+    ACTIONS(740), 1,
+      anon_sym_key,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7839] = 2,
+    ACTIONS(742), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7847] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(676), 1,
+      aux_sym__tag_value_token1,
+    STATE(269), 1,
+      sym__tag_value,
+  [7857] = 2,
+    ACTIONS(744), 1,
+      anon_sym_GT,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7865] = 2,
+    ACTIONS(746), 1,
+      anon_sym_snippet,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7873] = 2,
+    ACTIONS(748), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7881] = 2,
+    ACTIONS(750), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7889] = 2,
+    ACTIONS(752), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7897] = 2,
+    ACTIONS(754), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7905] = 2,
+    ACTIONS(756), 1,
+      anon_sym_GT,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7913] = 2,
+    ACTIONS(758), 1,
+      anon_sym_RPAREN,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7921] = 2,
+    ACTIONS(760), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7929] = 2,
+    ACTIONS(762), 1,
+      anon_sym_GT,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7937] = 2,
+    ACTIONS(764), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7945] = 2,
+    ACTIONS(766), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7953] = 2,
+    ACTIONS(768), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7961] = 2,
+    ACTIONS(770), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7969] = 2,
+    ACTIONS(772), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7977] = 2,
+    ACTIONS(774), 1,
+      sym_erroneous_end_tag_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7985] = 2,
+    ACTIONS(776), 1,
+      ts_builtin_sym_end,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [7993] = 2,
+    ACTIONS(778), 1,
+      sym__doctype,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8001] = 2,
+    ACTIONS(780), 1,
+      sym_svelte_raw_text,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8009] = 2,
+    ACTIONS(782), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8017] = 2,
+    ACTIONS(784), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8025] = 2,
+    ACTIONS(786), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8033] = 2,
+    ACTIONS(788), 1,
+      anon_sym_GT,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8041] = 2,
+    ACTIONS(630), 1,
+      anon_sym_catch,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8049] = 2,
+    ACTIONS(790), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8057] = 2,
+    ACTIONS(792), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8065] = 2,
+    ACTIONS(794), 1,
+      anon_sym_GT,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8073] = 2,
+    ACTIONS(796), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8081] = 2,
+    ACTIONS(798), 1,
+      anon_sym_as,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8089] = 2,
+    ACTIONS(800), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8097] = 2,
+    ACTIONS(802), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8105] = 2,
+    ACTIONS(804), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8113] = 2,
+    ACTIONS(806), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8121] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(676), 1,
+      aux_sym__tag_value_token1,
+    STATE(287), 1,
+      sym__tag_value,
+  [8131] = 2,
+    ACTIONS(650), 1,
+      sym_erroneous_end_tag_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8139] = 2,
+    ACTIONS(808), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8147] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(499), 1,
+      aux_sym__tag_value_token1,
+    ACTIONS(810), 1,
+      aux_sym_doctype_token1,
+  [8157] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(676), 1,
+      aux_sym__tag_value_token1,
+    STATE(296), 1,
+      sym__tag_value,
+  [8167] = 2,
+    ACTIONS(812), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8175] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(676), 1,
+      aux_sym__tag_value_token1,
+    STATE(298), 1,
+      sym__tag_value,
+  [8185] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(676), 1,
+      aux_sym__tag_value_token1,
+    STATE(299), 1,
+      sym__tag_value,
+  [8195] = 2,
+    ACTIONS(814), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8203] = 2,
+    ACTIONS(670), 1,
+      sym__end_tag_name,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8211] = 2,
+    ACTIONS(816), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8219] = 2,
+    ACTIONS(818), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8227] = 2,
+    ACTIONS(820), 1,
+      anon_sym_LPAREN,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8235] = 2,
+    ACTIONS(822), 1,
+      sym_svelte_raw_text,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8243] = 2,
+    ACTIONS(824), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8251] = 2,
+    ACTIONS(826), 1,
+      sym_svelte_raw_text,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8259] = 2,
+    ACTIONS(828), 1,
+      sym_svelte_raw_text,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8267] = 2,
+    ACTIONS(830), 1,
+      sym_svelte_raw_text,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8275] = 2,
+    ACTIONS(832), 1,
+      sym__doctype,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8283] = 2,
+    ACTIONS(834), 1,
+      anon_sym_RBRACE,
+    ACTIONS(3), 2,
+      sym_comment,
+      aux_sym__tag_value_token1,
+  [8291] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(836), 1,
+      aux_sym__tag_value_token1,
+  [8298] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(838), 1,
+      aux_sym__tag_value_token1,
+  [8305] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(840), 1,
+      aux_sym__tag_value_token1,
+  [8312] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(842), 1,
+      aux_sym__tag_value_token1,
+};
+
+static const uint32_t ts_small_parse_table_map[] = {
+  [SMALL_STATE(2)] = 0,
+  [SMALL_STATE(3)] = 91,
+  [SMALL_STATE(4)] = 182,
+  [SMALL_STATE(5)] = 273,
+  [SMALL_STATE(6)] = 364,
+  [SMALL_STATE(7)] = 454,
+  [SMALL_STATE(8)] = 544,
+  [SMALL_STATE(9)] = 634,
+  [SMALL_STATE(10)] = 724,
+  [SMALL_STATE(11)] = 808,
+  [SMALL_STATE(12)] = 892,
+  [SMALL_STATE(13)] = 976,
+  [SMALL_STATE(14)] = 1060,
+  [SMALL_STATE(15)] = 1141,
+  [SMALL_STATE(16)] = 1222,
+  [SMALL_STATE(17)] = 1303,
+  [SMALL_STATE(18)] = 1384,
+  [SMALL_STATE(19)] = 1462,
+  [SMALL_STATE(20)] = 1540,
+  [SMALL_STATE(21)] = 1618,
+  [SMALL_STATE(22)] = 1696,
+  [SMALL_STATE(23)] = 1774,
+  [SMALL_STATE(24)] = 1852,
+  [SMALL_STATE(25)] = 1930,
+  [SMALL_STATE(26)] = 2008,
+  [SMALL_STATE(27)] = 2086,
+  [SMALL_STATE(28)] = 2164,
+  [SMALL_STATE(29)] = 2242,
+  [SMALL_STATE(30)] = 2320,
+  [SMALL_STATE(31)] = 2398,
+  [SMALL_STATE(32)] = 2476,
+  [SMALL_STATE(33)] = 2554,
+  [SMALL_STATE(34)] = 2632,
+  [SMALL_STATE(35)] = 2710,
+  [SMALL_STATE(36)] = 2788,
+  [SMALL_STATE(37)] = 2866,
+  [SMALL_STATE(38)] = 2941,
+  [SMALL_STATE(39)] = 3016,
+  [SMALL_STATE(40)] = 3091,
+  [SMALL_STATE(41)] = 3166,
+  [SMALL_STATE(42)] = 3241,
+  [SMALL_STATE(43)] = 3316,
+  [SMALL_STATE(44)] = 3391,
+  [SMALL_STATE(45)] = 3466,
+  [SMALL_STATE(46)] = 3541,
+  [SMALL_STATE(47)] = 3616,
+  [SMALL_STATE(48)] = 3672,
+  [SMALL_STATE(49)] = 3728,
+  [SMALL_STATE(50)] = 3784,
+  [SMALL_STATE(51)] = 3840,
+  [SMALL_STATE(52)] = 3893,
+  [SMALL_STATE(53)] = 3946,
+  [SMALL_STATE(54)] = 3993,
+  [SMALL_STATE(55)] = 4040,
+  [SMALL_STATE(56)] = 4087,
+  [SMALL_STATE(57)] = 4134,
+  [SMALL_STATE(58)] = 4181,
+  [SMALL_STATE(59)] = 4228,
+  [SMALL_STATE(60)] = 4269,
+  [SMALL_STATE(61)] = 4310,
+  [SMALL_STATE(62)] = 4340,
+  [SMALL_STATE(63)] = 4368,
+  [SMALL_STATE(64)] = 4398,
+  [SMALL_STATE(65)] = 4428,
+  [SMALL_STATE(66)] = 4458,
+  [SMALL_STATE(67)] = 4475,
+  [SMALL_STATE(68)] = 4492,
+  [SMALL_STATE(69)] = 4509,
+  [SMALL_STATE(70)] = 4534,
+  [SMALL_STATE(71)] = 4551,
+  [SMALL_STATE(72)] = 4568,
+  [SMALL_STATE(73)] = 4585,
+  [SMALL_STATE(74)] = 4602,
+  [SMALL_STATE(75)] = 4619,
+  [SMALL_STATE(76)] = 4636,
+  [SMALL_STATE(77)] = 4653,
+  [SMALL_STATE(78)] = 4670,
+  [SMALL_STATE(79)] = 4687,
+  [SMALL_STATE(80)] = 4704,
+  [SMALL_STATE(81)] = 4721,
+  [SMALL_STATE(82)] = 4738,
+  [SMALL_STATE(83)] = 4755,
+  [SMALL_STATE(84)] = 4772,
+  [SMALL_STATE(85)] = 4789,
+  [SMALL_STATE(86)] = 4806,
+  [SMALL_STATE(87)] = 4823,
+  [SMALL_STATE(88)] = 4840,
+  [SMALL_STATE(89)] = 4857,
+  [SMALL_STATE(90)] = 4874,
+  [SMALL_STATE(91)] = 4891,
+  [SMALL_STATE(92)] = 4908,
+  [SMALL_STATE(93)] = 4925,
+  [SMALL_STATE(94)] = 4942,
+  [SMALL_STATE(95)] = 4959,
+  [SMALL_STATE(96)] = 4976,
+  [SMALL_STATE(97)] = 4993,
+  [SMALL_STATE(98)] = 5010,
+  [SMALL_STATE(99)] = 5027,
+  [SMALL_STATE(100)] = 5044,
+  [SMALL_STATE(101)] = 5061,
+  [SMALL_STATE(102)] = 5078,
+  [SMALL_STATE(103)] = 5095,
+  [SMALL_STATE(104)] = 5112,
+  [SMALL_STATE(105)] = 5129,
+  [SMALL_STATE(106)] = 5146,
+  [SMALL_STATE(107)] = 5163,
+  [SMALL_STATE(108)] = 5180,
+  [SMALL_STATE(109)] = 5197,
+  [SMALL_STATE(110)] = 5214,
+  [SMALL_STATE(111)] = 5231,
+  [SMALL_STATE(112)] = 5248,
+  [SMALL_STATE(113)] = 5265,
+  [SMALL_STATE(114)] = 5282,
+  [SMALL_STATE(115)] = 5299,
+  [SMALL_STATE(116)] = 5316,
+  [SMALL_STATE(117)] = 5333,
+  [SMALL_STATE(118)] = 5350,
+  [SMALL_STATE(119)] = 5377,
+  [SMALL_STATE(120)] = 5394,
+  [SMALL_STATE(121)] = 5411,
+  [SMALL_STATE(122)] = 5428,
+  [SMALL_STATE(123)] = 5445,
+  [SMALL_STATE(124)] = 5462,
+  [SMALL_STATE(125)] = 5479,
+  [SMALL_STATE(126)] = 5496,
+  [SMALL_STATE(127)] = 5513,
+  [SMALL_STATE(128)] = 5530,
+  [SMALL_STATE(129)] = 5547,
+  [SMALL_STATE(130)] = 5564,
+  [SMALL_STATE(131)] = 5581,
+  [SMALL_STATE(132)] = 5598,
+  [SMALL_STATE(133)] = 5615,
+  [SMALL_STATE(134)] = 5632,
+  [SMALL_STATE(135)] = 5649,
+  [SMALL_STATE(136)] = 5666,
+  [SMALL_STATE(137)] = 5683,
+  [SMALL_STATE(138)] = 5710,
+  [SMALL_STATE(139)] = 5727,
+  [SMALL_STATE(140)] = 5744,
+  [SMALL_STATE(141)] = 5761,
+  [SMALL_STATE(142)] = 5778,
+  [SMALL_STATE(143)] = 5805,
+  [SMALL_STATE(144)] = 5832,
+  [SMALL_STATE(145)] = 5849,
+  [SMALL_STATE(146)] = 5866,
+  [SMALL_STATE(147)] = 5883,
+  [SMALL_STATE(148)] = 5900,
+  [SMALL_STATE(149)] = 5917,
+  [SMALL_STATE(150)] = 5942,
+  [SMALL_STATE(151)] = 5959,
+  [SMALL_STATE(152)] = 5986,
+  [SMALL_STATE(153)] = 6003,
+  [SMALL_STATE(154)] = 6020,
+  [SMALL_STATE(155)] = 6037,
+  [SMALL_STATE(156)] = 6053,
+  [SMALL_STATE(157)] = 6069,
+  [SMALL_STATE(158)] = 6085,
+  [SMALL_STATE(159)] = 6101,
+  [SMALL_STATE(160)] = 6117,
+  [SMALL_STATE(161)] = 6133,
+  [SMALL_STATE(162)] = 6157,
+  [SMALL_STATE(163)] = 6181,
+  [SMALL_STATE(164)] = 6197,
+  [SMALL_STATE(165)] = 6213,
+  [SMALL_STATE(166)] = 6237,
+  [SMALL_STATE(167)] = 6253,
+  [SMALL_STATE(168)] = 6269,
+  [SMALL_STATE(169)] = 6285,
+  [SMALL_STATE(170)] = 6301,
+  [SMALL_STATE(171)] = 6317,
+  [SMALL_STATE(172)] = 6341,
+  [SMALL_STATE(173)] = 6366,
+  [SMALL_STATE(174)] = 6391,
+  [SMALL_STATE(175)] = 6416,
+  [SMALL_STATE(176)] = 6441,
+  [SMALL_STATE(177)] = 6456,
+  [SMALL_STATE(178)] = 6473,
+  [SMALL_STATE(179)] = 6498,
+  [SMALL_STATE(180)] = 6523,
+  [SMALL_STATE(181)] = 6548,
+  [SMALL_STATE(182)] = 6573,
+  [SMALL_STATE(183)] = 6598,
+  [SMALL_STATE(184)] = 6623,
+  [SMALL_STATE(185)] = 6643,
+  [SMALL_STATE(186)] = 6663,
+  [SMALL_STATE(187)] = 6677,
+  [SMALL_STATE(188)] = 6691,
+  [SMALL_STATE(189)] = 6705,
+  [SMALL_STATE(190)] = 6719,
+  [SMALL_STATE(191)] = 6735,
+  [SMALL_STATE(192)] = 6749,
+  [SMALL_STATE(193)] = 6763,
+  [SMALL_STATE(194)] = 6783,
+  [SMALL_STATE(195)] = 6797,
+  [SMALL_STATE(196)] = 6812,
+  [SMALL_STATE(197)] = 6827,
+  [SMALL_STATE(198)] = 6842,
+  [SMALL_STATE(199)] = 6859,
+  [SMALL_STATE(200)] = 6872,
+  [SMALL_STATE(201)] = 6889,
+  [SMALL_STATE(202)] = 6906,
+  [SMALL_STATE(203)] = 6919,
+  [SMALL_STATE(204)] = 6932,
+  [SMALL_STATE(205)] = 6949,
+  [SMALL_STATE(206)] = 6962,
+  [SMALL_STATE(207)] = 6975,
+  [SMALL_STATE(208)] = 6988,
+  [SMALL_STATE(209)] = 7003,
+  [SMALL_STATE(210)] = 7018,
+  [SMALL_STATE(211)] = 7035,
+  [SMALL_STATE(212)] = 7052,
+  [SMALL_STATE(213)] = 7069,
+  [SMALL_STATE(214)] = 7083,
+  [SMALL_STATE(215)] = 7097,
+  [SMALL_STATE(216)] = 7111,
+  [SMALL_STATE(217)] = 7125,
+  [SMALL_STATE(218)] = 7139,
+  [SMALL_STATE(219)] = 7153,
+  [SMALL_STATE(220)] = 7164,
+  [SMALL_STATE(221)] = 7175,
+  [SMALL_STATE(222)] = 7186,
+  [SMALL_STATE(223)] = 7197,
+  [SMALL_STATE(224)] = 7208,
+  [SMALL_STATE(225)] = 7217,
+  [SMALL_STATE(226)] = 7228,
+  [SMALL_STATE(227)] = 7239,
+  [SMALL_STATE(228)] = 7250,
+  [SMALL_STATE(229)] = 7261,
+  [SMALL_STATE(230)] = 7272,
+  [SMALL_STATE(231)] = 7283,
+  [SMALL_STATE(232)] = 7294,
+  [SMALL_STATE(233)] = 7305,
+  [SMALL_STATE(234)] = 7316,
+  [SMALL_STATE(235)] = 7325,
+  [SMALL_STATE(236)] = 7336,
+  [SMALL_STATE(237)] = 7347,
+  [SMALL_STATE(238)] = 7358,
+  [SMALL_STATE(239)] = 7369,
+  [SMALL_STATE(240)] = 7380,
+  [SMALL_STATE(241)] = 7389,
+  [SMALL_STATE(242)] = 7400,
+  [SMALL_STATE(243)] = 7409,
+  [SMALL_STATE(244)] = 7418,
+  [SMALL_STATE(245)] = 7429,
+  [SMALL_STATE(246)] = 7440,
+  [SMALL_STATE(247)] = 7449,
+  [SMALL_STATE(248)] = 7460,
+  [SMALL_STATE(249)] = 7471,
+  [SMALL_STATE(250)] = 7482,
+  [SMALL_STATE(251)] = 7493,
+  [SMALL_STATE(252)] = 7504,
+  [SMALL_STATE(253)] = 7515,
+  [SMALL_STATE(254)] = 7524,
+  [SMALL_STATE(255)] = 7535,
+  [SMALL_STATE(256)] = 7545,
+  [SMALL_STATE(257)] = 7553,
+  [SMALL_STATE(258)] = 7561,
+  [SMALL_STATE(259)] = 7569,
+  [SMALL_STATE(260)] = 7577,
+  [SMALL_STATE(261)] = 7585,
+  [SMALL_STATE(262)] = 7593,
+  [SMALL_STATE(263)] = 7603,
+  [SMALL_STATE(264)] = 7611,
+  [SMALL_STATE(265)] = 7619,
+  [SMALL_STATE(266)] = 7627,
+  [SMALL_STATE(267)] = 7635,
+  [SMALL_STATE(268)] = 7643,
+  [SMALL_STATE(269)] = 7651,
+  [SMALL_STATE(270)] = 7659,
+  [SMALL_STATE(271)] = 7667,
+  [SMALL_STATE(272)] = 7675,
+  [SMALL_STATE(273)] = 7683,
+  [SMALL_STATE(274)] = 7691,
+  [SMALL_STATE(275)] = 7699,
+  [SMALL_STATE(276)] = 7707,
+  [SMALL_STATE(277)] = 7715,
+  [SMALL_STATE(278)] = 7723,
+  [SMALL_STATE(279)] = 7731,
+  [SMALL_STATE(280)] = 7739,
+  [SMALL_STATE(281)] = 7747,
+  [SMALL_STATE(282)] = 7755,
+  [SMALL_STATE(283)] = 7763,
+  [SMALL_STATE(284)] = 7771,
+  [SMALL_STATE(285)] = 7781,
+  [SMALL_STATE(286)] = 7789,
+  [SMALL_STATE(287)] = 7799,
+  [SMALL_STATE(288)] = 7807,
+  [SMALL_STATE(289)] = 7815,
+  [SMALL_STATE(290)] = 7823,
+  [SMALL_STATE(291)] = 7831,
+  [SMALL_STATE(292)] = 7839,
+  [SMALL_STATE(293)] = 7847,
+  [SMALL_STATE(294)] = 7857,
+  [SMALL_STATE(295)] = 7865,
+  [SMALL_STATE(296)] = 7873,
+  [SMALL_STATE(297)] = 7881,
+  [SMALL_STATE(298)] = 7889,
+  [SMALL_STATE(299)] = 7897,
+  [SMALL_STATE(300)] = 7905,
+  [SMALL_STATE(301)] = 7913,
+  [SMALL_STATE(302)] = 7921,
+  [SMALL_STATE(303)] = 7929,
+  [SMALL_STATE(304)] = 7937,
+  [SMALL_STATE(305)] = 7945,
+  [SMALL_STATE(306)] = 7953,
+  [SMALL_STATE(307)] = 7961,
+  [SMALL_STATE(308)] = 7969,
+  [SMALL_STATE(309)] = 7977,
+  [SMALL_STATE(310)] = 7985,
+  [SMALL_STATE(311)] = 7993,
+  [SMALL_STATE(312)] = 8001,
+  [SMALL_STATE(313)] = 8009,
+  [SMALL_STATE(314)] = 8017,
+  [SMALL_STATE(315)] = 8025,
+  [SMALL_STATE(316)] = 8033,
+  [SMALL_STATE(317)] = 8041,
+  [SMALL_STATE(318)] = 8049,
+  [SMALL_STATE(319)] = 8057,
+  [SMALL_STATE(320)] = 8065,
+  [SMALL_STATE(321)] = 8073,
+  [SMALL_STATE(322)] = 8081,
+  [SMALL_STATE(323)] = 8089,
+  [SMALL_STATE(324)] = 8097,
+  [SMALL_STATE(325)] = 8105,
+  [SMALL_STATE(326)] = 8113,
+  [SMALL_STATE(327)] = 8121,
+  [SMALL_STATE(328)] = 8131,
+  [SMALL_STATE(329)] = 8139,
+  [SMALL_STATE(330)] = 8147,
+  [SMALL_STATE(331)] = 8157,
+  [SMALL_STATE(332)] = 8167,
+  [SMALL_STATE(333)] = 8175,
+  [SMALL_STATE(334)] = 8185,
+  [SMALL_STATE(335)] = 8195,
+  [SMALL_STATE(336)] = 8203,
+  [SMALL_STATE(337)] = 8211,
+  [SMALL_STATE(338)] = 8219,
+  [SMALL_STATE(339)] = 8227,
+  [SMALL_STATE(340)] = 8235,
+  [SMALL_STATE(341)] = 8243,
+  [SMALL_STATE(342)] = 8251,
+  [SMALL_STATE(343)] = 8259,
+  [SMALL_STATE(344)] = 8267,
+  [SMALL_STATE(345)] = 8275,
+  [SMALL_STATE(346)] = 8283,
+  [SMALL_STATE(347)] = 8291,
+  [SMALL_STATE(348)] = 8298,
+  [SMALL_STATE(349)] = 8305,
+  [SMALL_STATE(350)] = 8312,
+};
+
+static const TSParseActionEntry ts_parse_actions[] = {
+  [0] = {.entry = {.count = 0, .reusable = false}},
+  [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
+  [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT_EXTRA(),
+  [5] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_document, 0, 0, 0),
+  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(311),
+  [9] = {.entry = {.count = 1, .reusable = false}}, SHIFT(214),
+  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(309),
+  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
+  [15] = {.entry = {.count = 1, .reusable = false}}, SHIFT(90),
+  [17] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
+  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(90),
+  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
+  [23] = {.entry = {.count = 1, .reusable = true}}, SHIFT(49),
+  [25] = {.entry = {.count = 1, .reusable = true}}, SHIFT(47),
+  [27] = {.entry = {.count = 1, .reusable = true}}, SHIFT(50),
+  [29] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
+  [31] = {.entry = {.count = 1, .reusable = true}}, SHIFT(52),
+  [33] = {.entry = {.count = 1, .reusable = true}}, SHIFT(345),
+  [35] = {.entry = {.count = 1, .reusable = false}}, SHIFT(213),
+  [37] = {.entry = {.count = 1, .reusable = true}}, SHIFT(232),
+  [39] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
+  [41] = {.entry = {.count = 1, .reusable = false}}, SHIFT(92),
+  [43] = {.entry = {.count = 1, .reusable = true}}, SHIFT(59),
+  [45] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
+  [47] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
+  [49] = {.entry = {.count = 1, .reusable = true}}, SHIFT(147),
+  [51] = {.entry = {.count = 1, .reusable = true}}, SHIFT(244),
+  [53] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
+  [55] = {.entry = {.count = 1, .reusable = true}}, SHIFT(107),
+  [57] = {.entry = {.count = 1, .reusable = true}}, SHIFT(55),
+  [59] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
+  [61] = {.entry = {.count = 1, .reusable = true}}, SHIFT(53),
+  [63] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
+  [65] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0),
+  [67] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(311),
+  [70] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(214),
+  [73] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(309),
+  [76] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(66),
+  [79] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(90),
+  [82] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(60),
+  [85] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(90),
+  [88] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(345),
+  [91] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(213),
+  [94] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(328),
+  [97] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(91),
+  [100] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(92),
+  [103] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(59),
+  [106] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0), SHIFT_REPEAT(92),
+  [109] = {.entry = {.count = 1, .reusable = true}}, SHIFT(58),
+  [111] = {.entry = {.count = 1, .reusable = true}}, SHIFT(56),
+  [113] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_document, 1, 0, 0),
+  [115] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_catch_block, 2, 0, 0), SHIFT(60),
+  [118] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_else_block, 1, 0, 0), SHIFT(60),
+  [121] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_else_if_block, 2, 0, 0), SHIFT(60),
+  [124] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_then_block, 2, 0, 0), SHIFT(60),
+  [127] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_then_block, 1, 0, 0), SHIFT(60),
+  [130] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_else_block, 2, 0, 0), SHIFT(60),
+  [133] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_else_block, 1, 0, 0), SHIFT(311),
+  [136] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_else_block, 1, 0, 0), SHIFT(214),
+  [139] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_else_block, 1, 0, 0), SHIFT(309),
+  [142] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_else_block, 1, 0, 0), SHIFT(66),
+  [145] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_else_block, 1, 0, 0), SHIFT(90),
+  [148] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_else_block, 1, 0, 0), SHIFT(90),
+  [151] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_else_if_block, 1, 0, 0), SHIFT(60),
+  [154] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_catch_block, 1, 0, 0), SHIFT(60),
+  [157] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_else_block, 2, 0, 0), SHIFT(311),
+  [160] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_else_block, 2, 0, 0), SHIFT(214),
+  [163] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_else_block, 2, 0, 0), SHIFT(309),
+  [166] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_else_block, 2, 0, 0), SHIFT(66),
+  [169] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_else_block, 2, 0, 0), SHIFT(90),
+  [172] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_else_block, 2, 0, 0), SHIFT(90),
+  [175] = {.entry = {.count = 1, .reusable = true}}, SHIFT(184),
+  [177] = {.entry = {.count = 1, .reusable = true}}, SHIFT(222),
+  [179] = {.entry = {.count = 1, .reusable = true}}, SHIFT(268),
+  [181] = {.entry = {.count = 1, .reusable = true}}, SHIFT(204),
+  [183] = {.entry = {.count = 1, .reusable = true}}, SHIFT(335),
+  [185] = {.entry = {.count = 1, .reusable = true}}, SHIFT(235),
+  [187] = {.entry = {.count = 1, .reusable = true}}, SHIFT(267),
+  [189] = {.entry = {.count = 1, .reusable = true}}, SHIFT(258),
+  [191] = {.entry = {.count = 1, .reusable = true}}, SHIFT(259),
+  [193] = {.entry = {.count = 1, .reusable = true}}, SHIFT(291),
+  [195] = {.entry = {.count = 1, .reusable = true}}, SHIFT(295),
+  [197] = {.entry = {.count = 1, .reusable = true}}, SHIFT(282),
+  [199] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
+  [201] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
+  [203] = {.entry = {.count = 1, .reusable = false}}, SHIFT(176),
+  [205] = {.entry = {.count = 1, .reusable = true}}, SHIFT(312),
+  [207] = {.entry = {.count = 1, .reusable = true}}, SHIFT(176),
+  [209] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0),
+  [211] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(176),
+  [214] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(312),
+  [217] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(176),
+  [220] = {.entry = {.count = 1, .reusable = true}}, SHIFT(138),
+  [222] = {.entry = {.count = 1, .reusable = true}}, SHIFT(139),
+  [224] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
+  [226] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
+  [228] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__node, 1, 0, 0),
+  [230] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__node, 1, 0, 0),
+  [232] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_snippet_statement, 3, 0, 0),
+  [234] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_snippet_statement, 3, 0, 0),
+  [236] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctype, 4, 0, 0),
+  [238] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctype, 4, 0, 0),
+  [240] = {.entry = {.count = 1, .reusable = false}}, SHIFT(186),
+  [242] = {.entry = {.count = 1, .reusable = true}}, SHIFT(178),
+  [244] = {.entry = {.count = 1, .reusable = true}}, SHIFT(179),
+  [246] = {.entry = {.count = 1, .reusable = true}}, SHIFT(186),
+  [248] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_start_tag, 4, 0, 0),
+  [250] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_start_tag, 4, 0, 0),
+  [252] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_self_closing_tag, 4, 0, 0),
+  [254] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_self_closing_tag, 4, 0, 0),
+  [256] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 2, 0, 0),
+  [258] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 2, 0, 0),
+  [260] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_html_tag, 4, 0, 5),
+  [262] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_html_tag, 4, 0, 5),
+  [264] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_const_tag, 4, 0, 5),
+  [266] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_const_tag, 4, 0, 5),
+  [268] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_debug_tag, 4, 0, 5),
+  [270] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_debug_tag, 4, 0, 5),
+  [272] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_render_tag, 4, 0, 5),
+  [274] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_render_tag, 4, 0, 5),
+  [276] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_end_tag, 3, 0, 0),
+  [278] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_end_tag, 3, 0, 0),
+  [280] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_end, 3, 0, 5),
+  [282] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_end, 3, 0, 5),
+  [284] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 4, 0, 0),
+  [286] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_statement, 4, 0, 0),
+  [288] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_end, 3, 0, 5),
+  [290] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_end, 3, 0, 5),
+  [292] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_statement, 4, 0, 0),
+  [294] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_statement, 4, 0, 0),
+  [296] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_await_statement, 2, 0, 0),
+  [298] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_await_statement, 2, 0, 0),
+  [300] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_await_end, 3, 0, 5),
+  [302] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_await_end, 3, 0, 5),
+  [304] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_await_statement, 4, 0, 0),
+  [306] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_await_statement, 4, 0, 0),
+  [308] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_key_end, 3, 0, 5),
+  [310] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_key_end, 3, 0, 5),
+  [312] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_snippet_end, 3, 0, 5),
+  [314] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_snippet_end, 3, 0, 5),
+  [316] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 5, 0, 0),
+  [318] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_statement, 5, 0, 0),
+  [320] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_statement, 5, 0, 0),
+  [322] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_statement, 5, 0, 0),
+  [324] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_await_statement, 5, 0, 0),
+  [326] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_await_statement, 5, 0, 0),
+  [328] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_text, 1, 0, 0),
+  [330] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_text, 1, 0, 0),
+  [332] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 1, 0, 0),
+  [334] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 1, 0, 0),
+  [336] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_script_element, 2, 0, 0),
+  [338] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_script_element, 2, 0, 0),
+  [340] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_style_element, 2, 0, 0),
+  [342] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_style_element, 2, 0, 0),
+  [344] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 2, 0, 0),
+  [346] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_statement, 2, 0, 0),
+  [348] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_statement, 2, 0, 0),
+  [350] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_statement, 2, 0, 0),
+  [352] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_key_statement, 2, 0, 0),
+  [354] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_key_statement, 2, 0, 0),
+  [356] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_snippet_statement, 2, 0, 0),
+  [358] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_snippet_statement, 2, 0, 0),
+  [360] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_self_closing_tag, 3, 0, 0),
+  [362] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_self_closing_tag, 3, 0, 0),
+  [364] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_erroneous_end_tag, 3, 0, 0),
+  [366] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_erroneous_end_tag, 3, 0, 0),
+  [368] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 3, 0, 0),
+  [370] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 3, 0, 0),
+  [372] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 3, 0, 0),
+  [374] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 3, 0, 0),
+  [376] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_script_element, 3, 0, 0),
+  [378] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_script_element, 3, 0, 0),
+  [380] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_style_element, 3, 0, 0),
+  [382] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_style_element, 3, 0, 0),
+  [384] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_statement, 3, 0, 0),
+  [386] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_statement, 3, 0, 0),
+  [388] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_statement, 3, 0, 0),
+  [390] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_statement, 3, 0, 0),
+  [392] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_await_statement, 3, 0, 0),
+  [394] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_await_statement, 3, 0, 0),
+  [396] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_key_statement, 3, 0, 0),
+  [398] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_key_statement, 3, 0, 0),
+  [400] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(194),
+  [403] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(342),
+  [406] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_start_tag_repeat1, 2, 0, 0), SHIFT_REPEAT(194),
+  [409] = {.entry = {.count = 1, .reusable = true}}, SHIFT(253),
+  [411] = {.entry = {.count = 1, .reusable = false}}, SHIFT(194),
+  [413] = {.entry = {.count = 1, .reusable = true}}, SHIFT(342),
+  [415] = {.entry = {.count = 1, .reusable = true}}, SHIFT(194),
+  [417] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_start_tag, 3, 0, 0),
+  [419] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_start_tag, 3, 0, 0),
+  [421] = {.entry = {.count = 1, .reusable = true}}, SHIFT(240),
+  [423] = {.entry = {.count = 1, .reusable = true}}, SHIFT(246),
+  [425] = {.entry = {.count = 1, .reusable = false}}, SHIFT(202),
+  [427] = {.entry = {.count = 1, .reusable = true}}, SHIFT(180),
+  [429] = {.entry = {.count = 1, .reusable = true}}, SHIFT(181),
+  [431] = {.entry = {.count = 1, .reusable = true}}, SHIFT(202),
+  [433] = {.entry = {.count = 1, .reusable = true}}, SHIFT(224),
+  [435] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_start, 4, 0, 4),
+  [437] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_start, 4, 0, 4),
+  [439] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_key_start, 4, 0, 5),
+  [441] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_key_start, 4, 0, 5),
+  [443] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_await_start, 4, 0, 5),
+  [445] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_await_start, 4, 0, 5),
+  [447] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_start, 6, 0, 6),
+  [449] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_start, 6, 0, 6),
+  [451] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_snippet_start, 6, 0, 5),
+  [453] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_snippet_start, 6, 0, 5),
+  [455] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_snippet_start, 7, 0, 5),
+  [457] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_snippet_start, 7, 0, 5),
+  [459] = {.entry = {.count = 1, .reusable = true}}, SHIFT(193),
+  [461] = {.entry = {.count = 1, .reusable = true}}, SHIFT(185),
+  [463] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_start, 4, 0, 3),
+  [465] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_start, 4, 0, 3),
+  [467] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_then_start, 3, 0, 5),
+  [469] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_then_start, 3, 0, 5),
+  [471] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_catch_start, 4, 0, 5),
+  [473] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_catch_start, 4, 0, 5),
+  [475] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_else_if_start, 4, 0, 3),
+  [477] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_else_if_start, 4, 0, 3),
+  [479] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_else_start, 3, 0, 5),
+  [481] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_else_start, 3, 0, 5),
+  [483] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_then_start, 4, 0, 5),
+  [485] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_then_start, 4, 0, 5),
+  [487] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_catch_start, 3, 0, 5),
+  [489] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_catch_start, 3, 0, 5),
+  [491] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__single_quoted_attribute_value, 2, 0, 0),
+  [493] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym__single_quoted_attribute_value, 2, 0, 0), SHIFT_REPEAT(195),
+  [496] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__single_quoted_attribute_value, 2, 0, 0), SHIFT_REPEAT(343),
+  [499] = {.entry = {.count = 1, .reusable = false}}, SHIFT_EXTRA(),
+  [501] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__single_quoted_attribute_value, 2, 0, 0), SHIFT_REPEAT(195),
+  [504] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__double_quoted_attribute_value, 2, 0, 0),
+  [506] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym__double_quoted_attribute_value, 2, 0, 0), SHIFT_REPEAT(197),
+  [509] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__double_quoted_attribute_value, 2, 0, 0), SHIFT_REPEAT(344),
+  [512] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym__double_quoted_attribute_value, 2, 0, 0), SHIFT_REPEAT(197),
+  [515] = {.entry = {.count = 1, .reusable = true}}, SHIFT(192),
+  [517] = {.entry = {.count = 1, .reusable = false}}, SHIFT(197),
+  [519] = {.entry = {.count = 1, .reusable = true}}, SHIFT(344),
+  [521] = {.entry = {.count = 1, .reusable = true}}, SHIFT(197),
+  [523] = {.entry = {.count = 1, .reusable = false}}, SHIFT(195),
+  [525] = {.entry = {.count = 1, .reusable = true}}, SHIFT(343),
+  [527] = {.entry = {.count = 1, .reusable = true}}, SHIFT(195),
+  [529] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute_name, 1, 0, 0),
+  [531] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute_name, 1, 0, 0),
+  [533] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 1, 0, 0),
+  [535] = {.entry = {.count = 1, .reusable = true}}, SHIFT(69),
+  [537] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute, 1, 0, 0),
+  [539] = {.entry = {.count = 1, .reusable = true}}, SHIFT(191),
+  [541] = {.entry = {.count = 1, .reusable = true}}, SHIFT(205),
+  [543] = {.entry = {.count = 1, .reusable = true}}, SHIFT(206),
+  [545] = {.entry = {.count = 1, .reusable = true}}, SHIFT(272),
+  [547] = {.entry = {.count = 1, .reusable = true}}, SHIFT(234),
+  [549] = {.entry = {.count = 1, .reusable = true}}, SHIFT(280),
+  [551] = {.entry = {.count = 1, .reusable = true}}, SHIFT(283),
+  [553] = {.entry = {.count = 1, .reusable = true}}, SHIFT(290),
+  [555] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute_value, 1, 0, 0),
+  [557] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute_value, 1, 0, 0),
+  [559] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 1, 0, 1),
+  [561] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute, 1, 0, 1),
+  [563] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 3, 0, 0),
+  [565] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_attribute, 3, 0, 0),
+  [567] = {.entry = {.count = 1, .reusable = true}}, SHIFT(149),
+  [569] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_quoted_attribute_value, 2, 0, 0),
+  [571] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_quoted_attribute_value, 2, 0, 0),
+  [573] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_quoted_attribute_value, 3, 0, 7),
+  [575] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_quoted_attribute_value, 3, 0, 7),
+  [577] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__single_quoted_attribute_value, 1, 0, 0),
+  [579] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym__single_quoted_attribute_value, 1, 0, 0),
+  [581] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_if_statement_repeat1, 2, 0, 0), SHIFT_REPEAT(239),
+  [584] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym__double_quoted_attribute_value, 1, 0, 0),
+  [586] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym__double_quoted_attribute_value, 1, 0, 0),
+  [588] = {.entry = {.count = 1, .reusable = true}}, SHIFT(201),
+  [590] = {.entry = {.count = 1, .reusable = true}}, SHIFT(317),
+  [592] = {.entry = {.count = 1, .reusable = true}}, SHIFT(347),
+  [594] = {.entry = {.count = 1, .reusable = true}}, SHIFT(349),
+  [596] = {.entry = {.count = 1, .reusable = true}}, SHIFT(350),
+  [598] = {.entry = {.count = 1, .reusable = true}}, SHIFT(348),
+  [600] = {.entry = {.count = 1, .reusable = true}}, SHIFT(211),
+  [602] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
+  [604] = {.entry = {.count = 1, .reusable = true}}, SHIFT(137),
+  [606] = {.entry = {.count = 1, .reusable = true}}, SHIFT(151),
+  [608] = {.entry = {.count = 1, .reusable = true}}, SHIFT(63),
+  [610] = {.entry = {.count = 1, .reusable = true}}, SHIFT(285),
+  [612] = {.entry = {.count = 1, .reusable = true}}, SHIFT(233),
+  [614] = {.entry = {.count = 1, .reusable = true}}, SHIFT(227),
+  [616] = {.entry = {.count = 1, .reusable = true}}, SHIFT(336),
+  [618] = {.entry = {.count = 1, .reusable = true}}, SHIFT(254),
+  [620] = {.entry = {.count = 1, .reusable = true}}, SHIFT(236),
+  [622] = {.entry = {.count = 1, .reusable = true}}, SHIFT(231),
+  [624] = {.entry = {.count = 1, .reusable = true}}, SHIFT(266),
+  [626] = {.entry = {.count = 1, .reusable = true}}, SHIFT(301),
+  [628] = {.entry = {.count = 1, .reusable = true}}, SHIFT(242),
+  [630] = {.entry = {.count = 1, .reusable = true}}, SHIFT(243),
+  [632] = {.entry = {.count = 1, .reusable = true}}, SHIFT(164),
+  [634] = {.entry = {.count = 1, .reusable = true}}, SHIFT(305),
+  [636] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_style_start_tag, 3, 0, 0),
+  [638] = {.entry = {.count = 1, .reusable = true}}, SHIFT(170),
+  [640] = {.entry = {.count = 1, .reusable = true}}, SHIFT(307),
+  [642] = {.entry = {.count = 1, .reusable = true}}, SHIFT(319),
+  [644] = {.entry = {.count = 1, .reusable = true}}, SHIFT(322),
+  [646] = {.entry = {.count = 1, .reusable = true}}, SHIFT(226),
+  [648] = {.entry = {.count = 1, .reusable = true}}, SHIFT(320),
+  [650] = {.entry = {.count = 1, .reusable = true}}, SHIFT(281),
+  [652] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__each_start_tag, 2, 0, 2),
+  [654] = {.entry = {.count = 1, .reusable = true}}, SHIFT(275),
+  [656] = {.entry = {.count = 1, .reusable = false}}, SHIFT(276),
+  [658] = {.entry = {.count = 1, .reusable = true}}, SHIFT(219),
+  [660] = {.entry = {.count = 1, .reusable = true}}, SHIFT(288),
+  [662] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_script_start_tag, 4, 0, 0),
+  [664] = {.entry = {.count = 1, .reusable = true}}, SHIFT(248),
+  [666] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__then_tag, 2, 0, 2),
+  [668] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__catch_tag, 2, 0, 2),
+  [670] = {.entry = {.count = 1, .reusable = true}}, SHIFT(300),
+  [672] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_style_start_tag, 4, 0, 0),
+  [674] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_script_start_tag, 3, 0, 0),
+  [676] = {.entry = {.count = 1, .reusable = true}}, SHIFT(340),
+  [678] = {.entry = {.count = 1, .reusable = true}}, SHIFT(332),
+  [680] = {.entry = {.count = 1, .reusable = true}}, SHIFT(337),
+  [682] = {.entry = {.count = 1, .reusable = true}}, SHIFT(276),
+  [684] = {.entry = {.count = 1, .reusable = true}}, SHIFT(289),
+  [686] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__tag_value, 2, 0, 0),
+  [688] = {.entry = {.count = 1, .reusable = true}}, SHIFT(80),
+  [690] = {.entry = {.count = 1, .reusable = true}}, SHIFT(338),
+  [692] = {.entry = {.count = 1, .reusable = true}}, SHIFT(339),
+  [694] = {.entry = {.count = 1, .reusable = true}}, SHIFT(315),
+  [696] = {.entry = {.count = 1, .reusable = true}}, SHIFT(159),
+  [698] = {.entry = {.count = 1, .reusable = true}}, SHIFT(277),
+  [700] = {.entry = {.count = 1, .reusable = true}}, SHIFT(304),
+  [702] = {.entry = {.count = 1, .reusable = true}}, SHIFT(74),
+  [704] = {.entry = {.count = 1, .reusable = true}}, SHIFT(278),
+  [706] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
+  [708] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__if_start_tag, 2, 0, 2),
+  [710] = {.entry = {.count = 1, .reusable = true}}, SHIFT(168),
+  [712] = {.entry = {.count = 1, .reusable = true}}, SHIFT(160),
+  [714] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__else_if_tag, 2, 0, 2),
+  [716] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__else_tag, 2, 0, 2),
+  [718] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__if_end_tag, 2, 0, 2),
+  [720] = {.entry = {.count = 1, .reusable = true}}, SHIFT(167),
+  [722] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
+  [724] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__await_start_tag, 2, 0, 2),
+  [726] = {.entry = {.count = 1, .reusable = true}}, SHIFT(105),
+  [728] = {.entry = {.count = 1, .reusable = true}}, SHIFT(106),
+  [730] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__key_start_tag, 2, 0, 2),
+  [732] = {.entry = {.count = 1, .reusable = true}}, SHIFT(303),
+  [734] = {.entry = {.count = 1, .reusable = true}}, SHIFT(75),
+  [736] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__each_end_tag, 2, 0, 2),
+  [738] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__snippet_start_tag, 2, 0, 2),
+  [740] = {.entry = {.count = 1, .reusable = true}}, SHIFT(321),
+  [742] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
+  [744] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
+  [746] = {.entry = {.count = 1, .reusable = true}}, SHIFT(329),
+  [748] = {.entry = {.count = 1, .reusable = true}}, SHIFT(119),
+  [750] = {.entry = {.count = 1, .reusable = true}}, SHIFT(120),
+  [752] = {.entry = {.count = 1, .reusable = true}}, SHIFT(121),
+  [754] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
+  [756] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
+  [758] = {.entry = {.count = 1, .reusable = true}}, SHIFT(274),
+  [760] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
+  [762] = {.entry = {.count = 1, .reusable = true}}, SHIFT(68),
+  [764] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__await_end_tag, 2, 0, 2),
+  [766] = {.entry = {.count = 1, .reusable = true}}, SHIFT(169),
+  [768] = {.entry = {.count = 1, .reusable = true}}, SHIFT(126),
+  [770] = {.entry = {.count = 1, .reusable = true}}, SHIFT(166),
+  [772] = {.entry = {.count = 1, .reusable = true}}, SHIFT(128),
+  [774] = {.entry = {.count = 1, .reusable = true}}, SHIFT(316),
+  [776] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [778] = {.entry = {.count = 1, .reusable = true}}, SHIFT(286),
+  [780] = {.entry = {.count = 1, .reusable = true}}, SHIFT(323),
+  [782] = {.entry = {.count = 1, .reusable = true}}, SHIFT(130),
+  [784] = {.entry = {.count = 1, .reusable = true}}, SHIFT(131),
+  [786] = {.entry = {.count = 1, .reusable = true}}, SHIFT(163),
+  [788] = {.entry = {.count = 1, .reusable = true}}, SHIFT(144),
+  [790] = {.entry = {.count = 1, .reusable = true}}, SHIFT(76),
+  [792] = {.entry = {.count = 1, .reusable = true}}, SHIFT(155),
+  [794] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
+  [796] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__key_end_tag, 2, 0, 2),
+  [798] = {.entry = {.count = 1, .reusable = true}}, SHIFT(256),
+  [800] = {.entry = {.count = 1, .reusable = true}}, SHIFT(187),
+  [802] = {.entry = {.count = 1, .reusable = true}}, SHIFT(207),
+  [804] = {.entry = {.count = 1, .reusable = true}}, SHIFT(208),
+  [806] = {.entry = {.count = 1, .reusable = true}}, SHIFT(209),
+  [808] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__snippet_end_tag, 2, 0, 2),
+  [810] = {.entry = {.count = 1, .reusable = true}}, SHIFT(294),
+  [812] = {.entry = {.count = 1, .reusable = true}}, SHIFT(158),
+  [814] = {.entry = {.count = 1, .reusable = true}}, SHIFT(146),
+  [816] = {.entry = {.count = 1, .reusable = true}}, SHIFT(157),
+  [818] = {.entry = {.count = 1, .reusable = true}}, SHIFT(156),
+  [820] = {.entry = {.count = 1, .reusable = true}}, SHIFT(221),
+  [822] = {.entry = {.count = 1, .reusable = true}}, SHIFT(260),
+  [824] = {.entry = {.count = 1, .reusable = true}}, SHIFT(73),
+  [826] = {.entry = {.count = 1, .reusable = true}}, SHIFT(324),
+  [828] = {.entry = {.count = 1, .reusable = true}}, SHIFT(325),
+  [830] = {.entry = {.count = 1, .reusable = true}}, SHIFT(326),
+  [832] = {.entry = {.count = 1, .reusable = true}}, SHIFT(330),
+  [834] = {.entry = {.count = 1, .reusable = true}}, SHIFT(124),
+  [836] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__html_tag, 2, 0, 2),
+  [838] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__render_tag, 2, 0, 2),
+  [840] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__const_tag, 2, 0, 2),
+  [842] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__debug_tag, 2, 0, 2),
+};
+
+enum ts_external_scanner_symbol_identifiers {
+  ts_external_token__start_tag_name = 0,
+  ts_external_token__script_start_tag_name = 1,
+  ts_external_token__style_start_tag_name = 2,
+  ts_external_token__end_tag_name = 3,
+  ts_external_token_erroneous_end_tag_name = 4,
+  ts_external_token_SLASH_GT = 5,
+  ts_external_token__implicit_end_tag = 6,
+  ts_external_token_raw_text = 7,
+  ts_external_token_comment = 8,
+  ts_external_token_svelte_raw_text = 9,
+  ts_external_token_svelte_raw_text_each = 10,
+  ts_external_token_svelte_raw_text_snippet_arguments = 11,
+  ts_external_token_AT = 12,
+  ts_external_token_POUND = 13,
+  ts_external_token_SLASH = 14,
+  ts_external_token_COLON = 15,
+};
+
+static const TSSymbol ts_external_scanner_symbol_map[EXTERNAL_TOKEN_COUNT] = {
+  [ts_external_token__start_tag_name] = sym__start_tag_name,
+  [ts_external_token__script_start_tag_name] = sym__script_start_tag_name,
+  [ts_external_token__style_start_tag_name] = sym__style_start_tag_name,
+  [ts_external_token__end_tag_name] = sym__end_tag_name,
+  [ts_external_token_erroneous_end_tag_name] = sym_erroneous_end_tag_name,
+  [ts_external_token_SLASH_GT] = anon_sym_SLASH_GT,
+  [ts_external_token__implicit_end_tag] = sym__implicit_end_tag,
+  [ts_external_token_raw_text] = sym_raw_text,
+  [ts_external_token_comment] = sym_comment,
+  [ts_external_token_svelte_raw_text] = sym_svelte_raw_text,
+  [ts_external_token_svelte_raw_text_each] = sym_svelte_raw_text_each,
+  [ts_external_token_svelte_raw_text_snippet_arguments] = sym_svelte_raw_text_snippet_arguments,
+  [ts_external_token_AT] = anon_sym_AT,
+  [ts_external_token_POUND] = anon_sym_POUND,
+  [ts_external_token_SLASH] = anon_sym_SLASH,
+  [ts_external_token_COLON] = anon_sym_COLON,
+};
+
+static const bool ts_external_scanner_states[19][EXTERNAL_TOKEN_COUNT] = {
+  [1] = {
+    [ts_external_token__start_tag_name] = true,
+    [ts_external_token__script_start_tag_name] = true,
+    [ts_external_token__style_start_tag_name] = true,
+    [ts_external_token__end_tag_name] = true,
+    [ts_external_token_erroneous_end_tag_name] = true,
+    [ts_external_token_SLASH_GT] = true,
+    [ts_external_token__implicit_end_tag] = true,
+    [ts_external_token_raw_text] = true,
+    [ts_external_token_comment] = true,
+    [ts_external_token_svelte_raw_text] = true,
+    [ts_external_token_svelte_raw_text_each] = true,
+    [ts_external_token_svelte_raw_text_snippet_arguments] = true,
+    [ts_external_token_AT] = true,
+    [ts_external_token_POUND] = true,
+    [ts_external_token_SLASH] = true,
+    [ts_external_token_COLON] = true,
+  },
+  [2] = {
+    [ts_external_token_comment] = true,
+  },
+  [3] = {
+    [ts_external_token__implicit_end_tag] = true,
+    [ts_external_token_comment] = true,
+  },
+  [4] = {
+    [ts_external_token_comment] = true,
+    [ts_external_token_svelte_raw_text] = true,
+    [ts_external_token_AT] = true,
+    [ts_external_token_POUND] = true,
+    [ts_external_token_SLASH] = true,
+    [ts_external_token_COLON] = true,
+  },
+  [5] = {
+    [ts_external_token_comment] = true,
+    [ts_external_token_svelte_raw_text] = true,
+    [ts_external_token_AT] = true,
+    [ts_external_token_POUND] = true,
+    [ts_external_token_SLASH] = true,
+  },
+  [6] = {
+    [ts_external_token_comment] = true,
+    [ts_external_token_svelte_raw_text] = true,
+    [ts_external_token_AT] = true,
+    [ts_external_token_POUND] = true,
+  },
+  [7] = {
+    [ts_external_token_SLASH_GT] = true,
+    [ts_external_token_comment] = true,
+  },
+  [8] = {
+    [ts_external_token_comment] = true,
+    [ts_external_token_SLASH] = true,
+    [ts_external_token_COLON] = true,
+  },
+  [9] = {
+    [ts_external_token__start_tag_name] = true,
+    [ts_external_token__script_start_tag_name] = true,
+    [ts_external_token__style_start_tag_name] = true,
+    [ts_external_token_comment] = true,
+  },
+  [10] = {
+    [ts_external_token_raw_text] = true,
+    [ts_external_token_comment] = true,
+  },
+  [11] = {
+    [ts_external_token_comment] = true,
+    [ts_external_token_SLASH] = true,
+  },
+  [12] = {
+    [ts_external_token_comment] = true,
+    [ts_external_token_svelte_raw_text_snippet_arguments] = true,
+  },
+  [13] = {
+    [ts_external_token_comment] = true,
+    [ts_external_token_svelte_raw_text] = true,
+  },
+  [14] = {
+    [ts_external_token_comment] = true,
+    [ts_external_token_svelte_raw_text] = true,
+    [ts_external_token_svelte_raw_text_each] = true,
+  },
+  [15] = {
+    [ts_external_token__end_tag_name] = true,
+    [ts_external_token_erroneous_end_tag_name] = true,
+    [ts_external_token_comment] = true,
+  },
+  [16] = {
+    [ts_external_token_comment] = true,
+    [ts_external_token_COLON] = true,
+  },
+  [17] = {
+    [ts_external_token__end_tag_name] = true,
+    [ts_external_token_comment] = true,
+  },
+  [18] = {
+    [ts_external_token_erroneous_end_tag_name] = true,
+    [ts_external_token_comment] = true,
+  },
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+void *tree_sitter_svelte_external_scanner_create(void);
+void tree_sitter_svelte_external_scanner_destroy(void *);
+bool tree_sitter_svelte_external_scanner_scan(void *, TSLexer *, const bool *);
+unsigned tree_sitter_svelte_external_scanner_serialize(void *, char *);
+void tree_sitter_svelte_external_scanner_deserialize(void *, const char *, unsigned);
+
+#ifdef TREE_SITTER_HIDE_SYMBOLS
+#define TS_PUBLIC
+#elif defined(_WIN32)
+#define TS_PUBLIC __declspec(dllexport)
+#else
+#define TS_PUBLIC __attribute__((visibility("default")))
+#endif
+
+TS_PUBLIC const TSLanguage *tree_sitter_svelte(void) {
+  static const TSLanguage language = {
+    .version = LANGUAGE_VERSION,
+    .symbol_count = SYMBOL_COUNT,
+    .alias_count = ALIAS_COUNT,
+    .token_count = TOKEN_COUNT,
+    .external_token_count = EXTERNAL_TOKEN_COUNT,
+    .state_count = STATE_COUNT,
+    .large_state_count = LARGE_STATE_COUNT,
+    .production_id_count = PRODUCTION_ID_COUNT,
+    .field_count = FIELD_COUNT,
+    .max_alias_sequence_length = MAX_ALIAS_SEQUENCE_LENGTH,
+    .parse_table = &ts_parse_table[0][0],
+    .small_parse_table = ts_small_parse_table,
+    .small_parse_table_map = ts_small_parse_table_map,
+    .parse_actions = ts_parse_actions,
+    .symbol_names = ts_symbol_names,
+    .field_names = ts_field_names,
+    .field_map_slices = ts_field_map_slices,
+    .field_map_entries = ts_field_map_entries,
+    .symbol_metadata = ts_symbol_metadata,
+    .public_symbol_map = ts_symbol_map,
+    .alias_map = ts_non_terminal_alias_map,
+    .alias_sequences = &ts_alias_sequences[0][0],
+    .lex_modes = ts_lex_modes,
+    .lex_fn = ts_lex,
+    .external_scanner = {
+      &ts_external_scanner_states[0][0],
+      ts_external_scanner_symbol_map,
+      tree_sitter_svelte_external_scanner_create,
+      tree_sitter_svelte_external_scanner_destroy,
+      tree_sitter_svelte_external_scanner_scan,
+      tree_sitter_svelte_external_scanner_serialize,
+      tree_sitter_svelte_external_scanner_deserialize,
+    },
+    .primary_state_ids = ts_primary_state_ids,
+  };
+  return &language;
+}
+#ifdef __cplusplus
+}
+#endif
@@ -0,0 +1,152 @@
+/**
+ * @file Tree-sitter grammar definition for XML
+ * @author ObserverOfTime
+ * @license MIT
+ */
+
+/// <reference types="tree-sitter-cli/dsl" />
+// @ts-check
+
+// Unlike the upstream grammar, whitespace between markup is an extra and tag
+// names are not checked by an external scanner, so that snippets with
+// metavariables can be parsed and child elements keep their separators.
+
+module.exports = grammar({
+  name: 'xml',
+
+  extras: $ => [
+    $.Comment,
+    /\s+/,
+  ],
+
+  rules: {
+    document: $ => seq(
+      optional(field('declaration', $.XMLDecl)),
+      repeat1(field('content', choice($.element, $.PI, $.doctypedecl))),
+    ),
+
+    XMLDecl: $ => seq(
+      '<?',
+      'xml',
+      repeat(field('attribute', $.Attribute)),
+      '?>',
+    ),
+
+    PI: $ => seq(
+      '<?',
+      field('target', $.PITarget),
+      optional(field('content', $.PIContent)),
+      '?>',
+    ),
+
+    PITarget: _ => /[A-Za-z_][A-Za-z0-9_.:\-]*/,
+
+    PIContent: _ => /([^?\s]|\?[^>])([^?]|\?[^>])*/,
+
+    doctypedecl: $ => seq(
+      '<!',
+      'DOCTYPE',
+      field('name', $.Name),
+      optional(field('external_id', $.ExternalID)),
+      optional(field('subset', $.intSubset)),
+      '>',
+    ),
+
+    ExternalID: $ => seq(
+      choice('SYSTEM', 'PUBLIC'),
+      repeat1($.SystemLiteral),
+    ),
+
+    SystemLiteral: _ => /"[^"]*"|'[^']*'/,
+
+    intSubset: _ => seq('[', optional(/[^\]]+/), ']'),
+
+    element: $ => choice(
+      seq(
+        field('start', $.STag),
+        repeat(field('content', $._content)),
+        field('end', $.ETag),
+      ),
+      field('start', $.EmptyElemTag),
+    ),
+
+    _content: $ => choice(
+      $.element,
+      $.CharData,
+      $.Reference,
+      $.CDSect,
+      $.PI,
+    ),
+
+    STag: $ => seq(
+      '<',
+      field('name', $.Name),
+      repeat(field('attribute', $.Attribute)),
+      '>',
+    ),
+
+    EmptyElemTag: $ => seq(
+      '<',
+      field('name', $.Name),
+      repeat(field('attribute', $.Attribute)),
+      '/>',
+    ),
+
+    ETag: $ => seq(
+      '</',
+      field('name', $.Name),
+      '>',
+    ),
+
+    Attribute: $ => seq(
+      field('name', $.Name),
+      '=',
+      field('value', $.AttValue),
+    ),
+
+    AttValue: $ => choice(
+      seq('"', optional(field('content', $._double_quoted_content)), '"'),
+      seq('\'', optional(field('content', $._single_quoted_content)), '\''),
+    ),
+
+    _double_quoted_content: $ => choice(
+      alias(token.immediate(/[^"]+/), $.AttContent),
+      $.grit_metavariable,
+    ),
+
+    _single_quoted_content: $ => choice(
+      alias(token.immediate(/[^']+/), $.AttContent),
+      $.grit_metavariable,
+    ),
+
+    // Qualified names keep their namespace prefix apart from the local name.
+    Name: $ => seq(
+      optional(seq(field('prefix', $.NCName), token.immediate(':'))),
+      field('local_name', $.NCName),
+    ),
+
+    NCName: $ => choice(
+      /[A-Za-z_À-￿][A-Za-z0-9_.\-·À-￿]*/,
+      $.grit_metavariable,
+    ),
+
+    CharData: $ => choice(
+      /[^<&\s]([^<&]*[^<&\s])?/,
+      $.grit_metavariable,
+    ),
+
+    Reference: _ => /&(#[0-9]+|#x[0-9a-fA-F]+|[A-Za-z_:][A-Za-z0-9_.:\-]*);/,
+
+    CDSect: $ => seq(
+      '<![CDATA[',
+      optional(field('content', $.CData)),
+      ']]>',
+    ),
+
+    CData: _ => /([^\]]|\][^\]]|\]\]+[^\]>])+/,
+
+    Comment: _ => token(seq('<!--', /([^-]|-[^-])*/, '-->')),
+
+    grit_metavariable: _ => token(prec(100, choice('µ...', /µ[a-zA-Z_][a-zA-Z0-9_]*/))),
+  },
+});
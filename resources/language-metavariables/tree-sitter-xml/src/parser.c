@@ -0,0 +1,2660 @@
+#include "tree_sitter/parser.h"
+
+#if defined(__GNUC__) || defined(__clang__)
+#pragma GCC diagnostic ignored "-Wmissing-field-initializers"
+#endif
+
+#define LANGUAGE_VERSION 14
+#define STATE_COUNT 76
+#define LARGE_STATE_COUNT 2
+#define SYMBOL_COUNT 55
+#define ALIAS_COUNT 0
+#define TOKEN_COUNT 32
+#define EXTERNAL_TOKEN_COUNT 0
+#define FIELD_COUNT 12
+#define MAX_ALIAS_SEQUENCE_LENGTH 6
+#define PRODUCTION_ID_COUNT 23
+
+enum ts_symbol_identifiers {
+  anon_sym_LT_QMARK = 1,
+  anon_sym_xml = 2,
+  anon_sym_QMARK_GT = 3,
+  sym_PITarget = 4,
+  sym_PIContent = 5,
+  anon_sym_LT_BANG = 6,
+  anon_sym_DOCTYPE = 7,
+  anon_sym_GT = 8,
+  anon_sym_SYSTEM = 9,
+  anon_sym_PUBLIC = 10,
+  sym_SystemLiteral = 11,
+  anon_sym_LBRACK = 12,
+  aux_sym_intSubset_token1 = 13,
+  anon_sym_RBRACK = 14,
+  anon_sym_LT = 15,
+  anon_sym_SLASH_GT = 16,
+  anon_sym_LT_SLASH = 17,
+  anon_sym_EQ = 18,
+  anon_sym_DQUOTE = 19,
+  anon_sym_SQUOTE = 20,
+  aux_sym__double_quoted_content_token1 = 21,
+  aux_sym__single_quoted_content_token1 = 22,
+  anon_sym_COLON = 23,
+  aux_sym_NCName_token1 = 24,
+  aux_sym_CharData_token1 = 25,
+  sym_Reference = 26,
+  anon_sym_LT_BANG_LBRACKCDATA_LBRACK = 27,
+  anon_sym_RBRACK_RBRACK_GT = 28,
+  sym_CData = 29,
+  sym_Comment = 30,
+  sym_grit_metavariable = 31,
+  sym_document = 32,
+  sym_XMLDecl = 33,
+  sym_PI = 34,
+  sym_doctypedecl = 35,
+  sym_ExternalID = 36,
+  sym_intSubset = 37,
+  sym_element = 38,
+  sym__content = 39,
+  sym_STag = 40,
+  sym_EmptyElemTag = 41,
+  sym_ETag = 42,
+  sym_Attribute = 43,
+  sym_AttValue = 44,
+  sym__double_quoted_content = 45,
+  sym__single_quoted_content = 46,
+  sym_Name = 47,
+  sym_NCName = 48,
+  sym_CharData = 49,
+  sym_CDSect = 50,
+  aux_sym_document_repeat1 = 51,
+  aux_sym_XMLDecl_repeat1 = 52,
+  aux_sym_ExternalID_repeat1 = 53,
+  aux_sym_element_repeat1 = 54,
+};
+
+static const char * const ts_symbol_names[] = {
+  [ts_builtin_sym_end] = "end",
+  [anon_sym_LT_QMARK] = "<\?",
+  [anon_sym_xml] = "xml",
+  [anon_sym_QMARK_GT] = "\?>",
+  [sym_PITarget] = "PITarget",
+  [sym_PIContent] = "PIContent",
+  [anon_sym_LT_BANG] = "<!",
+  [anon_sym_DOCTYPE] = "DOCTYPE",
+  [anon_sym_GT] = ">",
+  [anon_sym_SYSTEM] = "SYSTEM",
+  [anon_sym_PUBLIC] = "PUBLIC",
+  [sym_SystemLiteral] = "SystemLiteral",
+  [anon_sym_LBRACK] = "[",
+  [aux_sym_intSubset_token1] = "intSubset_token1",
+  [anon_sym_RBRACK] = "]",
+  [anon_sym_LT] = "<",
+  [anon_sym_SLASH_GT] = "/>",
+  [anon_sym_LT_SLASH] = "</",
+  [anon_sym_EQ] = "=",
+  [anon_sym_DQUOTE] = "\"",
+  [anon_sym_SQUOTE] = "'",
+  [aux_sym__double_quoted_content_token1] = "AttContent",
+  [aux_sym__single_quoted_content_token1] = "AttContent",
+  [anon_sym_COLON] = ":",
+  [aux_sym_NCName_token1] = "NCName_token1",
+  [aux_sym_CharData_token1] = "CharData_token1",
+  [sym_Reference] = "Reference",
+  [anon_sym_LT_BANG_LBRACKCDATA_LBRACK] = "<![CDATA[",
+  [anon_sym_RBRACK_RBRACK_GT] = "]]>",
+  [sym_CData] = "CData",
+  [sym_Comment] = "Comment",
+  [sym_grit_metavariable] = "grit_metavariable",
+  [sym_document] = "document",
+  [sym_XMLDecl] = "XMLDecl",
+  [sym_PI] = "PI",
+  [sym_doctypedecl] = "doctypedecl",
+  [sym_ExternalID] = "ExternalID",
+  [sym_intSubset] = "intSubset",
+  [sym_element] = "element",
+  [sym__content] = "_content",
+  [sym_STag] = "STag",
+  [sym_EmptyElemTag] = "EmptyElemTag",
+  [sym_ETag] = "ETag",
+  [sym_Attribute] = "Attribute",
+  [sym_AttValue] = "AttValue",
+  [sym__double_quoted_content] = "_double_quoted_content",
+  [sym__single_quoted_content] = "_single_quoted_content",
+  [sym_Name] = "Name",
+  [sym_NCName] = "NCName",
+  [sym_CharData] = "CharData",
+  [sym_CDSect] = "CDSect",
+  [aux_sym_document_repeat1] = "document_repeat1",
+  [aux_sym_XMLDecl_repeat1] = "XMLDecl_repeat1",
+  [aux_sym_ExternalID_repeat1] = "ExternalID_repeat1",
+  [aux_sym_element_repeat1] = "element_repeat1",
+};
+
+static const TSSymbol ts_symbol_map[] = {
+  [ts_builtin_sym_end] = ts_builtin_sym_end,
+  [anon_sym_LT_QMARK] = anon_sym_LT_QMARK,
+  [anon_sym_xml] = anon_sym_xml,
+  [anon_sym_QMARK_GT] = anon_sym_QMARK_GT,
+  [sym_PITarget] = sym_PITarget,
+  [sym_PIContent] = sym_PIContent,
+  [anon_sym_LT_BANG] = anon_sym_LT_BANG,
+  [anon_sym_DOCTYPE] = anon_sym_DOCTYPE,
+  [anon_sym_GT] = anon_sym_GT,
+  [anon_sym_SYSTEM] = anon_sym_SYSTEM,
+  [anon_sym_PUBLIC] = anon_sym_PUBLIC,
+  [sym_SystemLiteral] = sym_SystemLiteral,
+  [anon_sym_LBRACK] = anon_sym_LBRACK,
+  [aux_sym_intSubset_token1] = aux_sym_intSubset_token1,
+  [anon_sym_RBRACK] = anon_sym_RBRACK,
+  [anon_sym_LT] = anon_sym_LT,
+  [anon_sym_SLASH_GT] = anon_sym_SLASH_GT,
+  [anon_sym_LT_SLASH] = anon_sym_LT_SLASH,
+  [anon_sym_EQ] = anon_sym_EQ,
+  [anon_sym_DQUOTE] = anon_sym_DQUOTE,
+  [anon_sym_SQUOTE] = anon_sym_SQUOTE,
+  [aux_sym__double_quoted_content_token1] = aux_sym__double_quoted_content_token1,
+  [aux_sym__single_quoted_content_token1] = aux_sym__double_quoted_content_token1,
+  [anon_sym_COLON] = anon_sym_COLON,
+  [aux_sym_NCName_token1] = aux_sym_NCName_token1,
+  [aux_sym_CharData_token1] = aux_sym_CharData_token1,
+  [sym_Reference] = sym_Reference,
+  [anon_sym_LT_BANG_LBRACKCDATA_LBRACK] = anon_sym_LT_BANG_LBRACKCDATA_LBRACK,
+  [anon_sym_RBRACK_RBRACK_GT] = anon_sym_RBRACK_RBRACK_GT,
+  [sym_CData] = sym_CData,
+  [sym_Comment] = sym_Comment,
+  [sym_grit_metavariable] = sym_grit_metavariable,
+  [sym_document] = sym_document,
+  [sym_XMLDecl] = sym_XMLDecl,
+  [sym_PI] = sym_PI,
+  [sym_doctypedecl] = sym_doctypedecl,
+  [sym_ExternalID] = sym_ExternalID,
+  [sym_intSubset] = sym_intSubset,
+  [sym_element] = sym_element,
+  [sym__content] = sym__content,
+  [sym_STag] = sym_STag,
+  [sym_EmptyElemTag] = sym_EmptyElemTag,
+  [sym_ETag] = sym_ETag,
+  [sym_Attribute] = sym_Attribute,
+  [sym_AttValue] = sym_AttValue,
+  [sym__double_quoted_content] = sym__double_quoted_content,
+  [sym__single_quoted_content] = sym__single_quoted_content,
+  [sym_Name] = sym_Name,
+  [sym_NCName] = sym_NCName,
+  [sym_CharData] = sym_CharData,
+  [sym_CDSect] = sym_CDSect,
+  [aux_sym_document_repeat1] = aux_sym_document_repeat1,
+  [aux_sym_XMLDecl_repeat1] = aux_sym_XMLDecl_repeat1,
+  [aux_sym_ExternalID_repeat1] = aux_sym_ExternalID_repeat1,
+  [aux_sym_element_repeat1] = aux_sym_element_repeat1,
+};
+
+static const TSSymbolMetadata ts_symbol_metadata[] = {
+  [ts_builtin_sym_end] = {
+    .visible = false,
+    .named = true,
+  },
+  [anon_sym_LT_QMARK] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_xml] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_QMARK_GT] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_PITarget] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_PIContent] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_LT_BANG] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOCTYPE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_GT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_SYSTEM] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_PUBLIC] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_SystemLiteral] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_LBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym_intSubset_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [anon_sym_RBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_SLASH_GT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LT_SLASH] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DQUOTE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_SQUOTE] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym__double_quoted_content_token1] = {
+    .visible = true,
+    .named = true,
+  },
+  [aux_sym__single_quoted_content_token1] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_COLON] = {
+    .visible = true,
+    .named = false,
+  },
+  [aux_sym_NCName_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_CharData_token1] = {
+    .visible = false,
+    .named = false,
+  },
+  [sym_Reference] = {
+    .visible = true,
+    .named = true,
+  },
+  [anon_sym_LT_BANG_LBRACKCDATA_LBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RBRACK_RBRACK_GT] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_CData] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_Comment] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_grit_metavariable] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_document] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_XMLDecl] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_PI] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_doctypedecl] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_ExternalID] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_intSubset] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_element] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__content] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_STag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_EmptyElemTag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_ETag] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_Attribute] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_AttValue] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__double_quoted_content] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym__single_quoted_content] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_Name] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_NCName] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_CharData] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_CDSect] = {
+    .visible = true,
+    .named = true,
+  },
+  [aux_sym_document_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_XMLDecl_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_ExternalID_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_element_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+};
+
+enum ts_field_identifiers {
+  field_attribute = 1,
+  field_content = 2,
+  field_declaration = 3,
+  field_end = 4,
+  field_external_id = 5,
+  field_local_name = 6,
+  field_name = 7,
+  field_prefix = 8,
+  field_start = 9,
+  field_subset = 10,
+  field_target = 11,
+  field_value = 12,
+};
+
+static const char * const ts_field_names[] = {
+  [0] = NULL,
+  [field_attribute] = "attribute",
+  [field_content] = "content",
+  [field_declaration] = "declaration",
+  [field_end] = "end",
+  [field_external_id] = "external_id",
+  [field_local_name] = "local_name",
+  [field_name] = "name",
+  [field_prefix] = "prefix",
+  [field_start] = "start",
+  [field_subset] = "subset",
+  [field_target] = "target",
+  [field_value] = "value",
+};
+
+static const TSFieldMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
+  [1] = {.index = 0, .length = 1},
+  [2] = {.index = 1, .length = 1},
+  [3] = {.index = 2, .length = 1},
+  [4] = {.index = 3, .length = 1},
+  [5] = {.index = 4, .length = 2},
+  [6] = {.index = 6, .length = 2},
+  [7] = {.index = 8, .length = 2},
+  [8] = {.index = 10, .length = 1},
+  [9] = {.index = 11, .length = 1},
+  [10] = {.index = 12, .length = 1},
+  [11] = {.index = 13, .length = 3},
+  [12] = {.index = 16, .length = 1},
+  [13] = {.index = 17, .length = 2},
+  [14] = {.index = 19, .length = 2},
+  [15] = {.index = 21, .length = 1},
+  [16] = {.index = 22, .length = 2},
+  [17] = {.index = 24, .length = 2},
+  [18] = {.index = 26, .length = 1},
+  [19] = {.index = 27, .length = 2},
+  [20] = {.index = 29, .length = 2},
+  [21] = {.index = 31, .length = 2},
+  [22] = {.index = 33, .length = 3},
+};
+
+static const TSFieldMapEntry ts_field_map_entries[] = {
+  [0] =
+    {field_content, 0},
+  [1] =
+    {field_start, 0},
+  [2] =
+    {field_content, 0, .inherited = true},
+  [3] =
+    {field_local_name, 0},
+  [4] =
+    {field_content, 1, .inherited = true},
+    {field_declaration, 0},
+  [6] =
+    {field_end, 1},
+    {field_start, 0},
+  [8] =
+    {field_content, 0, .inherited = true},
+    {field_content, 1, .inherited = true},
+  [10] =
+    {field_attribute, 0},
+  [11] =
+    {field_target, 1},
+  [12] =
+    {field_name, 1},
+  [13] =
+    {field_content, 1, .inherited = true},
+    {field_end, 2},
+    {field_start, 0},
+  [16] =
+    {field_attribute, 2, .inherited = true},
+  [17] =
+    {field_attribute, 0, .inherited = true},
+    {field_attribute, 1, .inherited = true},
+  [19] =
+    {field_content, 2},
+    {field_target, 1},
+  [21] =
+    {field_name, 2},
+  [22] =
+    {field_attribute, 2, .inherited = true},
+    {field_name, 1},
+  [24] =
+    {field_local_name, 2},
+    {field_prefix, 0},
+  [26] =
+    {field_content, 1},
+  [27] =
+    {field_name, 0},
+    {field_value, 2},
+  [29] =
+    {field_external_id, 3},
+    {field_name, 2},
+  [31] =
+    {field_name, 2},
+    {field_subset, 3},
+  [33] =
+    {field_external_id, 3},
+    {field_name, 2},
+    {field_subset, 4},
+};
+
+static const TSSymbol ts_alias_sequences[PRODUCTION_ID_COUNT][MAX_ALIAS_SEQUENCE_LENGTH] = {
+  [0] = {0},
+};
+
+static const uint16_t ts_non_terminal_alias_map[] = {
+  0,
+};
+
+static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
+  [0] = 0,
+  [1] = 1,
+  [2] = 2,
+  [3] = 3,
+  [4] = 4,
+  [5] = 5,
+  [6] = 6,
+  [7] = 7,
+  [8] = 8,
+  [9] = 9,
+  [10] = 10,
+  [11] = 11,
+  [12] = 12,
+  [13] = 13,
+  [14] = 14,
+  [15] = 15,
+  [16] = 16,
+  [17] = 17,
+  [18] = 18,
+  [19] = 19,
+  [20] = 20,
+  [21] = 21,
+  [22] = 22,
+  [23] = 23,
+  [24] = 24,
+  [25] = 25,
+  [26] = 26,
+  [27] = 27,
+  [28] = 28,
+  [29] = 29,
+  [30] = 30,
+  [31] = 31,
+  [32] = 32,
+  [33] = 33,
+  [34] = 34,
+  [35] = 28,
+  [36] = 30,
+  [37] = 37,
+  [38] = 38,
+  [39] = 39,
+  [40] = 40,
+  [41] = 41,
+  [42] = 42,
+  [43] = 43,
+  [44] = 44,
+  [45] = 45,
+  [46] = 46,
+  [47] = 47,
+  [48] = 48,
+  [49] = 49,
+  [50] = 32,
+  [51] = 51,
+  [52] = 52,
+  [53] = 53,
+  [54] = 54,
+  [55] = 55,
+  [56] = 53,
+  [57] = 57,
+  [58] = 58,
+  [59] = 59,
+  [60] = 60,
+  [61] = 61,
+  [62] = 62,
+  [63] = 63,
+  [64] = 64,
+  [65] = 65,
+  [66] = 66,
+  [67] = 67,
+  [68] = 68,
+  [69] = 69,
+  [70] = 70,
+  [71] = 71,
+  [72] = 72,
+  [73] = 73,
+  [74] = 74,
+  [75] = 75,
+};
+
+static bool ts_lex(TSLexer *lexer, TSStateId state) {
+  START_LEXER();
+  eof = lexer->eof(lexer);
+  switch (state) {
+    case 0:
+      if (eof) ADVANCE(69);
+      ADVANCE_MAP(
+        '"', 108,
+        '&', 4,
+        '\'', 109,
+        '/', 27,
+        ':', 132,
+        '<', 102,
+        '=', 107,
+        '>', 87,
+        '?', 28,
+        'D', 45,
+        'P', 51,
+        'S', 52,
+        '[', 91,
+        ']', 101,
+        'x', 60,
+        0xb5, 16,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(65);
+      END_STATE();
+    case 1:
+      if (lookahead == '!') ADVANCE(8);
+      END_STATE();
+    case 2:
+      if (lookahead == '"') ADVANCE(108);
+      if (lookahead == '<') ADVANCE(110);
+      if (lookahead == 0xb5) ADVANCE(116);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(118);
+      if (lookahead != 0) ADVANCE(120);
+      END_STATE();
+    case 3:
+      if (lookahead == '"') ADVANCE(90);
+      if (lookahead != 0) ADVANCE(3);
+      END_STATE();
+    case 4:
+      if (lookahead == '#') ADVANCE(61);
+      if (lookahead == ':' ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(21);
+      END_STATE();
+    case 5:
+      if (lookahead == '&') ADVANCE(4);
+      if (lookahead == '<') ADVANCE(104);
+      if (lookahead == 0xb5) ADVANCE(135);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(5);
+      if (lookahead != 0) ADVANCE(137);
+      END_STATE();
+    case 6:
+      if (lookahead == '\'') ADVANCE(109);
+      if (lookahead == '<') ADVANCE(121);
+      if (lookahead == 0xb5) ADVANCE(127);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(129);
+      if (lookahead != 0) ADVANCE(131);
+      END_STATE();
+    case 7:
+      if (lookahead == '\'') ADVANCE(90);
+      if (lookahead != 0) ADVANCE(7);
+      END_STATE();
+    case 8:
+      if (lookahead == '-') ADVANCE(10);
+      END_STATE();
+    case 9:
+      if (lookahead == '-') ADVANCE(10);
+      if (lookahead == '[') ADVANCE(37);
+      END_STATE();
+    case 10:
+      if (lookahead == '-') ADVANCE(11);
+      END_STATE();
+    case 11:
+      if (lookahead == '-') ADVANCE(15);
+      if (lookahead != 0) ADVANCE(11);
+      END_STATE();
+    case 12:
+      if (lookahead == '-') ADVANCE(142);
+      if (lookahead == '>') ADVANCE(11);
+      if (lookahead == ']') ADVANCE(12);
+      if (lookahead != 0) ADVANCE(144);
+      END_STATE();
+    case 13:
+      if (lookahead == '-') ADVANCE(142);
+      if (lookahead == ']') ADVANCE(12);
+      if (lookahead != 0) ADVANCE(144);
+      END_STATE();
+    case 14:
+      if (lookahead == '-') ADVANCE(78);
+      if (lookahead == '>') ADVANCE(11);
+      if (lookahead != 0) ADVANCE(80);
+      END_STATE();
+    case 15:
+      if (lookahead == '-') ADVANCE(32);
+      if (lookahead != 0) ADVANCE(11);
+      END_STATE();
+    case 16:
+      if (lookahead == '.') ADVANCE(18);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(151);
+      END_STATE();
+    case 17:
+      if (lookahead == '.') ADVANCE(150);
+      END_STATE();
+    case 18:
+      if (lookahead == '.') ADVANCE(17);
+      END_STATE();
+    case 19:
+      if (lookahead == ';') ADVANCE(138);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(19);
+      END_STATE();
+    case 20:
+      if (lookahead == ';') ADVANCE(138);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(20);
+      END_STATE();
+    case 21:
+      if (lookahead == ';') ADVANCE(138);
+      if (lookahead == '-' ||
+          lookahead == '.' ||
+          ('0' <= lookahead && lookahead <= ':') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(21);
+      END_STATE();
+    case 22:
+      if (lookahead == '<') ADVANCE(141);
+      if (lookahead == ']') ADVANCE(55);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(146);
+      if (lookahead != 0) ADVANCE(148);
+      END_STATE();
+    case 23:
+      if (lookahead == '<') ADVANCE(77);
+      if (lookahead == '?') ADVANCE(29);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(23);
+      if (lookahead != 0) ADVANCE(83);
+      END_STATE();
+    case 24:
+      if (lookahead == '<') ADVANCE(92);
+      if (lookahead == ']') ADVANCE(100);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(97);
+      if (lookahead != 0) ADVANCE(99);
+      END_STATE();
+    case 25:
+      if (lookahead == '<') ADVANCE(1);
+      if (lookahead == ']') ADVANCE(100);
+      if (lookahead == 'x') ADVANCE(75);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(25);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(76);
+      END_STATE();
+    case 26:
+      if (lookahead == '<') ADVANCE(1);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(26);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(76);
+      END_STATE();
+    case 27:
+      if (lookahead == '>') ADVANCE(105);
+      END_STATE();
+    case 28:
+      if (lookahead == '>') ADVANCE(73);
+      END_STATE();
+    case 29:
+      if (lookahead == '>') ADVANCE(73);
+      if (lookahead != 0) ADVANCE(83);
+      END_STATE();
+    case 30:
+      if (lookahead == '>') ADVANCE(140);
+      END_STATE();
+    case 31:
+      if (lookahead == '>') ADVANCE(140);
+      if (lookahead == ']') ADVANCE(56);
+      if (lookahead != 0) ADVANCE(148);
+      END_STATE();
+    case 32:
+      if (lookahead == '>') ADVANCE(149);
+      END_STATE();
+    case 33:
+      if (lookahead == 'A') ADVANCE(54);
+      END_STATE();
+    case 34:
+      if (lookahead == 'A') ADVANCE(50);
+      END_STATE();
+    case 35:
+      if (lookahead == 'B') ADVANCE(43);
+      END_STATE();
+    case 36:
+      if (lookahead == 'C') ADVANCE(49);
+      END_STATE();
+    case 37:
+      if (lookahead == 'C') ADVANCE(39);
+      END_STATE();
+    case 38:
+      if (lookahead == 'C') ADVANCE(89);
+      END_STATE();
+    case 39:
+      if (lookahead == 'D') ADVANCE(34);
+      END_STATE();
+    case 40:
+      if (lookahead == 'E') ADVANCE(44);
+      END_STATE();
+    case 41:
+      if (lookahead == 'E') ADVANCE(86);
+      END_STATE();
+    case 42:
+      if (lookahead == 'I') ADVANCE(38);
+      END_STATE();
+    case 43:
+      if (lookahead == 'L') ADVANCE(42);
+      END_STATE();
+    case 44:
+      if (lookahead == 'M') ADVANCE(88);
+      END_STATE();
+    case 45:
+      if (lookahead == 'O') ADVANCE(36);
+      END_STATE();
+    case 46:
+      if (lookahead == 'P') ADVANCE(41);
+      END_STATE();
+    case 47:
+      if (lookahead == 'S') ADVANCE(48);
+      END_STATE();
+    case 48:
+      if (lookahead == 'T') ADVANCE(40);
+      END_STATE();
+    case 49:
+      if (lookahead == 'T') ADVANCE(53);
+      END_STATE();
+    case 50:
+      if (lookahead == 'T') ADVANCE(33);
+      END_STATE();
+    case 51:
+      if (lookahead == 'U') ADVANCE(35);
+      END_STATE();
+    case 52:
+      if (lookahead == 'Y') ADVANCE(47);
+      END_STATE();
+    case 53:
+      if (lookahead == 'Y') ADVANCE(46);
+      END_STATE();
+    case 54:
+      if (lookahead == '[') ADVANCE(139);
+      END_STATE();
+    case 55:
+      if (lookahead == ']') ADVANCE(31);
+      if (lookahead != 0) ADVANCE(148);
+      END_STATE();
+    case 56:
+      if (lookahead == ']') ADVANCE(56);
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(148);
+      END_STATE();
+    case 57:
+      if (lookahead == ']') ADVANCE(56);
+      if (lookahead != 0) ADVANCE(148);
+      END_STATE();
+    case 58:
+      if (lookahead == ']') ADVANCE(30);
+      END_STATE();
+    case 59:
+      if (lookahead == 'l') ADVANCE(71);
+      END_STATE();
+    case 60:
+      if (lookahead == 'm') ADVANCE(59);
+      END_STATE();
+    case 61:
+      if (lookahead == 'x') ADVANCE(63);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(19);
+      END_STATE();
+    case 62:
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(62);
+      if (lookahead != 0 &&
+          lookahead != '&' &&
+          lookahead != '<') ADVANCE(137);
+      END_STATE();
+    case 63:
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(20);
+      END_STATE();
+    case 64:
+      if (lookahead != 0 &&
+          lookahead != '>') ADVANCE(83);
+      END_STATE();
+    case 65:
+      if (eof) ADVANCE(69);
+      ADVANCE_MAP(
+        '"', 108,
+        '&', 4,
+        '\'', 109,
+        '/', 27,
+        '<', 102,
+        '=', 107,
+        '>', 87,
+        '?', 28,
+        'D', 45,
+        'P', 51,
+        'S', 52,
+        '[', 91,
+        ']', 101,
+        'x', 60,
+        0xb5, 16,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(65);
+      END_STATE();
+    case 66:
+      if (eof) ADVANCE(69);
+      ADVANCE_MAP(
+        '"', 3,
+        '\'', 7,
+        '/', 27,
+        ':', 132,
+        '<', 103,
+        '=', 107,
+        '>', 87,
+        '?', 28,
+        '[', 91,
+        ']', 58,
+        0xb5, 16,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(67);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z') ||
+          (0xc0 <= lookahead && lookahead <= 0xffff)) ADVANCE(133);
+      END_STATE();
+    case 67:
+      if (eof) ADVANCE(69);
+      ADVANCE_MAP(
+        '"', 3,
+        '\'', 7,
+        '/', 27,
+        '<', 103,
+        '=', 107,
+        '>', 87,
+        '?', 28,
+        '[', 91,
+        ']', 58,
+        0xb5, 16,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(67);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z') ||
+          (0xc0 <= lookahead && lookahead <= 0xffff)) ADVANCE(133);
+      END_STATE();
+    case 68:
+      if (eof) ADVANCE(69);
+      if (lookahead == '&') ADVANCE(4);
+      if (lookahead == '<') ADVANCE(102);
+      if (lookahead == 0xb5) ADVANCE(135);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(68);
+      if (lookahead != 0) ADVANCE(137);
+      END_STATE();
+    case 69:
+      ACCEPT_TOKEN(ts_builtin_sym_end);
+      END_STATE();
+    case 70:
+      ACCEPT_TOKEN(anon_sym_LT_QMARK);
+      END_STATE();
+    case 71:
+      ACCEPT_TOKEN(anon_sym_xml);
+      END_STATE();
+    case 72:
+      ACCEPT_TOKEN(anon_sym_xml);
+      if (lookahead == '-' ||
+          lookahead == '.' ||
+          ('0' <= lookahead && lookahead <= ':') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(76);
+      END_STATE();
+    case 73:
+      ACCEPT_TOKEN(anon_sym_QMARK_GT);
+      END_STATE();
+    case 74:
+      ACCEPT_TOKEN(sym_PITarget);
+      if (lookahead == 'l') ADVANCE(72);
+      if (lookahead == '-' ||
+          lookahead == '.' ||
+          ('0' <= lookahead && lookahead <= ':') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(76);
+      END_STATE();
+    case 75:
+      ACCEPT_TOKEN(sym_PITarget);
+      if (lookahead == 'm') ADVANCE(74);
+      if (lookahead == '-' ||
+          lookahead == '.' ||
+          ('0' <= lookahead && lookahead <= ':') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(76);
+      END_STATE();
+    case 76:
+      ACCEPT_TOKEN(sym_PITarget);
+      if (lookahead == '-' ||
+          lookahead == '.' ||
+          ('0' <= lookahead && lookahead <= ':') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(76);
+      END_STATE();
+    case 77:
+      ACCEPT_TOKEN(sym_PIContent);
+      if (lookahead == '!') ADVANCE(81);
+      if (lookahead == '?') ADVANCE(64);
+      if (lookahead != 0) ADVANCE(83);
+      END_STATE();
+    case 78:
+      ACCEPT_TOKEN(sym_PIContent);
+      if (lookahead == '-') ADVANCE(82);
+      if (lookahead == '?') ADVANCE(14);
+      if (lookahead != 0) ADVANCE(80);
+      END_STATE();
+    case 79:
+      ACCEPT_TOKEN(sym_PIContent);
+      if (lookahead == '-') ADVANCE(80);
+      if (lookahead == '?') ADVANCE(64);
+      if (lookahead != 0) ADVANCE(83);
+      END_STATE();
+    case 80:
+      ACCEPT_TOKEN(sym_PIContent);
+      if (lookahead == '-') ADVANCE(78);
+      if (lookahead == '?') ADVANCE(14);
+      if (lookahead != 0) ADVANCE(80);
+      END_STATE();
+    case 81:
+      ACCEPT_TOKEN(sym_PIContent);
+      if (lookahead == '-') ADVANCE(79);
+      if (lookahead == '?') ADVANCE(64);
+      if (lookahead != 0) ADVANCE(83);
+      END_STATE();
+    case 82:
+      ACCEPT_TOKEN(sym_PIContent);
+      if (lookahead == '>') ADVANCE(83);
+      if (lookahead == '?') ADVANCE(64);
+      if (lookahead != 0) ADVANCE(83);
+      END_STATE();
+    case 83:
+      ACCEPT_TOKEN(sym_PIContent);
+      if (lookahead == '?') ADVANCE(64);
+      if (lookahead != 0) ADVANCE(83);
+      END_STATE();
+    case 84:
+      ACCEPT_TOKEN(anon_sym_LT_BANG);
+      if (lookahead == '-') ADVANCE(10);
+      END_STATE();
+    case 85:
+      ACCEPT_TOKEN(anon_sym_LT_BANG);
+      if (lookahead == '-') ADVANCE(10);
+      if (lookahead == '[') ADVANCE(37);
+      END_STATE();
+    case 86:
+      ACCEPT_TOKEN(anon_sym_DOCTYPE);
+      END_STATE();
+    case 87:
+      ACCEPT_TOKEN(anon_sym_GT);
+      END_STATE();
+    case 88:
+      ACCEPT_TOKEN(anon_sym_SYSTEM);
+      END_STATE();
+    case 89:
+      ACCEPT_TOKEN(anon_sym_PUBLIC);
+      END_STATE();
+    case 90:
+      ACCEPT_TOKEN(sym_SystemLiteral);
+      END_STATE();
+    case 91:
+      ACCEPT_TOKEN(anon_sym_LBRACK);
+      END_STATE();
+    case 92:
+      ACCEPT_TOKEN(aux_sym_intSubset_token1);
+      if (lookahead == '!') ADVANCE(95);
+      if (lookahead != 0 &&
+          lookahead != ']') ADVANCE(99);
+      END_STATE();
+    case 93:
+      ACCEPT_TOKEN(aux_sym_intSubset_token1);
+      if (lookahead == '-') ADVANCE(96);
+      if (lookahead != 0 &&
+          lookahead != ']') ADVANCE(99);
+      END_STATE();
+    case 94:
+      ACCEPT_TOKEN(aux_sym_intSubset_token1);
+      if (lookahead == '-') ADVANCE(98);
+      if (lookahead == ']') ADVANCE(11);
+      if (lookahead != 0) ADVANCE(96);
+      END_STATE();
+    case 95:
+      ACCEPT_TOKEN(aux_sym_intSubset_token1);
+      if (lookahead == '-') ADVANCE(93);
+      if (lookahead != 0 &&
+          lookahead != ']') ADVANCE(99);
+      END_STATE();
+    case 96:
+      ACCEPT_TOKEN(aux_sym_intSubset_token1);
+      if (lookahead == '-') ADVANCE(94);
+      if (lookahead == ']') ADVANCE(11);
+      if (lookahead != 0) ADVANCE(96);
+      END_STATE();
+    case 97:
+      ACCEPT_TOKEN(aux_sym_intSubset_token1);
+      if (lookahead == '<') ADVANCE(92);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(97);
+      if (lookahead != 0 &&
+          lookahead != ']') ADVANCE(99);
+      END_STATE();
+    case 98:
+      ACCEPT_TOKEN(aux_sym_intSubset_token1);
+      if (lookahead == '>') ADVANCE(99);
+      if (lookahead != 0 &&
+          lookahead != ']') ADVANCE(99);
+      END_STATE();
+    case 99:
+      ACCEPT_TOKEN(aux_sym_intSubset_token1);
+      if (lookahead != 0 &&
+          lookahead != ']') ADVANCE(99);
+      END_STATE();
+    case 100:
+      ACCEPT_TOKEN(anon_sym_RBRACK);
+      END_STATE();
+    case 101:
+      ACCEPT_TOKEN(anon_sym_RBRACK);
+      if (lookahead == ']') ADVANCE(30);
+      END_STATE();
+    case 102:
+      ACCEPT_TOKEN(anon_sym_LT);
+      if (lookahead == '!') ADVANCE(85);
+      if (lookahead == '/') ADVANCE(106);
+      if (lookahead == '?') ADVANCE(70);
+      END_STATE();
+    case 103:
+      ACCEPT_TOKEN(anon_sym_LT);
+      if (lookahead == '!') ADVANCE(84);
+      if (lookahead == '?') ADVANCE(70);
+      END_STATE();
+    case 104:
+      ACCEPT_TOKEN(anon_sym_LT);
+      if (lookahead == '!') ADVANCE(9);
+      if (lookahead == '/') ADVANCE(106);
+      if (lookahead == '?') ADVANCE(70);
+      END_STATE();
+    case 105:
+      ACCEPT_TOKEN(anon_sym_SLASH_GT);
+      END_STATE();
+    case 106:
+      ACCEPT_TOKEN(anon_sym_LT_SLASH);
+      END_STATE();
+    case 107:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      END_STATE();
+    case 108:
+      ACCEPT_TOKEN(anon_sym_DQUOTE);
+      END_STATE();
+    case 109:
+      ACCEPT_TOKEN(anon_sym_SQUOTE);
+      END_STATE();
+    case 110:
+      ACCEPT_TOKEN(aux_sym__double_quoted_content_token1);
+      if (lookahead == '!') ADVANCE(114);
+      if (lookahead != 0 &&
+          lookahead != '!' &&
+          lookahead != '"') ADVANCE(120);
+      END_STATE();
+    case 111:
+      ACCEPT_TOKEN(aux_sym__double_quoted_content_token1);
+      if (lookahead == '"') ADVANCE(11);
+      if (lookahead == '-') ADVANCE(119);
+      if (lookahead != 0) ADVANCE(112);
+      END_STATE();
+    case 112:
+      ACCEPT_TOKEN(aux_sym__double_quoted_content_token1);
+      if (lookahead == '"') ADVANCE(11);
+      if (lookahead == '-') ADVANCE(111);
+      if (lookahead != 0) ADVANCE(112);
+      END_STATE();
+    case 113:
+      ACCEPT_TOKEN(aux_sym__double_quoted_content_token1);
+      if (lookahead == '-') ADVANCE(112);
+      if (lookahead != 0 &&
+          lookahead != '"') ADVANCE(120);
+      END_STATE();
+    case 114:
+      ACCEPT_TOKEN(aux_sym__double_quoted_content_token1);
+      if (lookahead == '-') ADVANCE(113);
+      if (lookahead != 0 &&
+          lookahead != '"') ADVANCE(120);
+      END_STATE();
+    case 115:
+      ACCEPT_TOKEN(aux_sym__double_quoted_content_token1);
+      if (lookahead == '.') ADVANCE(150);
+      if (lookahead != 0 &&
+          lookahead != '"') ADVANCE(120);
+      END_STATE();
+    case 116:
+      ACCEPT_TOKEN(aux_sym__double_quoted_content_token1);
+      if (lookahead == '.') ADVANCE(117);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(151);
+      if (lookahead != 0 &&
+          lookahead != '"') ADVANCE(120);
+      END_STATE();
+    case 117:
+      ACCEPT_TOKEN(aux_sym__double_quoted_content_token1);
+      if (lookahead == '.') ADVANCE(115);
+      if (lookahead != 0 &&
+          lookahead != '"') ADVANCE(120);
+      END_STATE();
+    case 118:
+      ACCEPT_TOKEN(aux_sym__double_quoted_content_token1);
+      if (lookahead == '<') ADVANCE(110);
+      if (lookahead == 0xb5) ADVANCE(116);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(118);
+      if (lookahead != 0 &&
+          lookahead != '"') ADVANCE(120);
+      END_STATE();
+    case 119:
+      ACCEPT_TOKEN(aux_sym__double_quoted_content_token1);
+      if (lookahead == '>') ADVANCE(120);
+      if (lookahead != 0 &&
+          lookahead != '"') ADVANCE(120);
+      END_STATE();
+    case 120:
+      ACCEPT_TOKEN(aux_sym__double_quoted_content_token1);
+      if (lookahead != 0 &&
+          lookahead != '"') ADVANCE(120);
+      END_STATE();
+    case 121:
+      ACCEPT_TOKEN(aux_sym__single_quoted_content_token1);
+      if (lookahead == '!') ADVANCE(125);
+      if (lookahead != 0 &&
+          lookahead != '\'') ADVANCE(131);
+      END_STATE();
+    case 122:
+      ACCEPT_TOKEN(aux_sym__single_quoted_content_token1);
+      if (lookahead == '\'') ADVANCE(11);
+      if (lookahead == '-') ADVANCE(130);
+      if (lookahead != 0) ADVANCE(123);
+      END_STATE();
+    case 123:
+      ACCEPT_TOKEN(aux_sym__single_quoted_content_token1);
+      if (lookahead == '\'') ADVANCE(11);
+      if (lookahead == '-') ADVANCE(122);
+      if (lookahead != 0) ADVANCE(123);
+      END_STATE();
+    case 124:
+      ACCEPT_TOKEN(aux_sym__single_quoted_content_token1);
+      if (lookahead == '-') ADVANCE(123);
+      if (lookahead != 0 &&
+          lookahead != '\'') ADVANCE(131);
+      END_STATE();
+    case 125:
+      ACCEPT_TOKEN(aux_sym__single_quoted_content_token1);
+      if (lookahead == '-') ADVANCE(124);
+      if (lookahead != 0 &&
+          lookahead != '\'') ADVANCE(131);
+      END_STATE();
+    case 126:
+      ACCEPT_TOKEN(aux_sym__single_quoted_content_token1);
+      if (lookahead == '.') ADVANCE(150);
+      if (lookahead != 0 &&
+          lookahead != '\'') ADVANCE(131);
+      END_STATE();
+    case 127:
+      ACCEPT_TOKEN(aux_sym__single_quoted_content_token1);
+      if (lookahead == '.') ADVANCE(128);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(151);
+      if (lookahead != 0 &&
+          lookahead != '\'') ADVANCE(131);
+      END_STATE();
+    case 128:
+      ACCEPT_TOKEN(aux_sym__single_quoted_content_token1);
+      if (lookahead == '.') ADVANCE(126);
+      if (lookahead != 0 &&
+          lookahead != '\'') ADVANCE(131);
+      END_STATE();
+    case 129:
+      ACCEPT_TOKEN(aux_sym__single_quoted_content_token1);
+      if (lookahead == '<') ADVANCE(121);
+      if (lookahead == 0xb5) ADVANCE(127);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(129);
+      if (lookahead != 0 &&
+          lookahead != '\'') ADVANCE(131);
+      END_STATE();
+    case 130:
+      ACCEPT_TOKEN(aux_sym__single_quoted_content_token1);
+      if (lookahead == '>') ADVANCE(131);
+      if (lookahead != 0 &&
+          lookahead != '\'') ADVANCE(131);
+      END_STATE();
+    case 131:
+      ACCEPT_TOKEN(aux_sym__single_quoted_content_token1);
+      if (lookahead != 0 &&
+          lookahead != '\'') ADVANCE(131);
+      END_STATE();
+    case 132:
+      ACCEPT_TOKEN(anon_sym_COLON);
+      END_STATE();
+    case 133:
+      ACCEPT_TOKEN(aux_sym_NCName_token1);
+      if (lookahead == '-' ||
+          lookahead == '.' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z') ||
+          lookahead == 0xb7 ||
+          (0xc0 <= lookahead && lookahead <= 0xffff)) ADVANCE(133);
+      END_STATE();
+    case 134:
+      ACCEPT_TOKEN(aux_sym_CharData_token1);
+      if (lookahead == '.') ADVANCE(150);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(62);
+      if (lookahead != 0 &&
+          lookahead != '&' &&
+          lookahead != '<') ADVANCE(137);
+      END_STATE();
+    case 135:
+      ACCEPT_TOKEN(aux_sym_CharData_token1);
+      if (lookahead == '.') ADVANCE(136);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(62);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(151);
+      if (lookahead != 0 &&
+          lookahead != '&' &&
+          lookahead != '<') ADVANCE(137);
+      END_STATE();
+    case 136:
+      ACCEPT_TOKEN(aux_sym_CharData_token1);
+      if (lookahead == '.') ADVANCE(134);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(62);
+      if (lookahead != 0 &&
+          lookahead != '&' &&
+          lookahead != '<') ADVANCE(137);
+      END_STATE();
+    case 137:
+      ACCEPT_TOKEN(aux_sym_CharData_token1);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(62);
+      if (lookahead != 0 &&
+          lookahead != '&' &&
+          lookahead != '<') ADVANCE(137);
+      END_STATE();
+    case 138:
+      ACCEPT_TOKEN(sym_Reference);
+      END_STATE();
+    case 139:
+      ACCEPT_TOKEN(anon_sym_LT_BANG_LBRACKCDATA_LBRACK);
+      END_STATE();
+    case 140:
+      ACCEPT_TOKEN(anon_sym_RBRACK_RBRACK_GT);
+      END_STATE();
+    case 141:
+      ACCEPT_TOKEN(sym_CData);
+      if (lookahead == '!') ADVANCE(145);
+      if (lookahead == ']') ADVANCE(57);
+      if (lookahead != 0) ADVANCE(148);
+      END_STATE();
+    case 142:
+      ACCEPT_TOKEN(sym_CData);
+      if (lookahead == '-') ADVANCE(147);
+      if (lookahead == ']') ADVANCE(13);
+      if (lookahead != 0) ADVANCE(144);
+      END_STATE();
+    case 143:
+      ACCEPT_TOKEN(sym_CData);
+      if (lookahead == '-') ADVANCE(144);
+      if (lookahead == ']') ADVANCE(57);
+      if (lookahead != 0) ADVANCE(148);
+      END_STATE();
+    case 144:
+      ACCEPT_TOKEN(sym_CData);
+      if (lookahead == '-') ADVANCE(142);
+      if (lookahead == ']') ADVANCE(13);
+      if (lookahead != 0) ADVANCE(144);
+      END_STATE();
+    case 145:
+      ACCEPT_TOKEN(sym_CData);
+      if (lookahead == '-') ADVANCE(143);
+      if (lookahead == ']') ADVANCE(57);
+      if (lookahead != 0) ADVANCE(148);
+      END_STATE();
+    case 146:
+      ACCEPT_TOKEN(sym_CData);
+      if (lookahead == '<') ADVANCE(141);
+      if (lookahead == ']') ADVANCE(55);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(146);
+      if (lookahead != 0) ADVANCE(148);
+      END_STATE();
+    case 147:
+      ACCEPT_TOKEN(sym_CData);
+      if (lookahead == '>') ADVANCE(148);
+      if (lookahead == ']') ADVANCE(57);
+      if (lookahead != 0) ADVANCE(148);
+      END_STATE();
+    case 148:
+      ACCEPT_TOKEN(sym_CData);
+      if (lookahead == ']') ADVANCE(57);
+      if (lookahead != 0) ADVANCE(148);
+      END_STATE();
+    case 149:
+      ACCEPT_TOKEN(sym_Comment);
+      END_STATE();
+    case 150:
+      ACCEPT_TOKEN(sym_grit_metavariable);
+      END_STATE();
+    case 151:
+      ACCEPT_TOKEN(sym_grit_metavariable);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(151);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static const TSLexMode ts_lex_modes[STATE_COUNT] = {
+  [0] = {.lex_state = 0},
+  [1] = {.lex_state = 66},
+  [2] = {.lex_state = 5},
+  [3] = {.lex_state = 5},
+  [4] = {.lex_state = 5},
+  [5] = {.lex_state = 66},
+  [6] = {.lex_state = 66},
+  [7] = {.lex_state = 66},
+  [8] = {.lex_state = 68},
+  [9] = {.lex_state = 66},
+  [10] = {.lex_state = 68},
+  [11] = {.lex_state = 68},
+  [12] = {.lex_state = 68},
+  [13] = {.lex_state = 68},
+  [14] = {.lex_state = 68},
+  [15] = {.lex_state = 66},
+  [16] = {.lex_state = 68},
+  [17] = {.lex_state = 68},
+  [18] = {.lex_state = 66},
+  [19] = {.lex_state = 66},
+  [20] = {.lex_state = 66},
+  [21] = {.lex_state = 5},
+  [22] = {.lex_state = 5},
+  [23] = {.lex_state = 5},
+  [24] = {.lex_state = 66},
+  [25] = {.lex_state = 5},
+  [26] = {.lex_state = 5},
+  [27] = {.lex_state = 5},
+  [28] = {.lex_state = 66},
+  [29] = {.lex_state = 0},
+  [30] = {.lex_state = 66},
+  [31] = {.lex_state = 66},
+  [32] = {.lex_state = 66},
+  [33] = {.lex_state = 66},
+  [34] = {.lex_state = 66},
+  [35] = {.lex_state = 0},
+  [36] = {.lex_state = 0},
+  [37] = {.lex_state = 66},
+  [38] = {.lex_state = 66},
+  [39] = {.lex_state = 66},
+  [40] = {.lex_state = 66},
+  [41] = {.lex_state = 2},
+  [42] = {.lex_state = 6},
+  [43] = {.lex_state = 66},
+  [44] = {.lex_state = 66},
+  [45] = {.lex_state = 66},
+  [46] = {.lex_state = 66},
+  [47] = {.lex_state = 66},
+  [48] = {.lex_state = 66},
+  [49] = {.lex_state = 66},
+  [50] = {.lex_state = 0},
+  [51] = {.lex_state = 66},
+  [52] = {.lex_state = 66},
+  [53] = {.lex_state = 66},
+  [54] = {.lex_state = 0},
+  [55] = {.lex_state = 0},
+  [56] = {.lex_state = 66},
+  [57] = {.lex_state = 22},
+  [58] = {.lex_state = 23},
+  [59] = {.lex_state = 24},
+  [60] = {.lex_state = 66},
+  [61] = {.lex_state = 25},
+  [62] = {.lex_state = 0},
+  [63] = {.lex_state = 0},
+  [64] = {.lex_state = 0},
+  [65] = {.lex_state = 66},
+  [66] = {.lex_state = 25},
+  [67] = {.lex_state = 0},
+  [68] = {.lex_state = 0},
+  [69] = {.lex_state = 0},
+  [70] = {.lex_state = 0},
+  [71] = {.lex_state = 0},
+  [72] = {.lex_state = 0},
+  [73] = {.lex_state = 26},
+  [74] = {.lex_state = 0},
+  [75] = {.lex_state = 0},
+};
+
+static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
+  [0] = {
+    [ts_builtin_sym_end] = ACTIONS(1),
+    [anon_sym_LT_QMARK] = ACTIONS(1),
+    [anon_sym_xml] = ACTIONS(1),
+    [anon_sym_QMARK_GT] = ACTIONS(1),
+    [anon_sym_LT_BANG] = ACTIONS(1),
+    [anon_sym_DOCTYPE] = ACTIONS(1),
+    [anon_sym_GT] = ACTIONS(1),
+    [anon_sym_SYSTEM] = ACTIONS(1),
+    [anon_sym_PUBLIC] = ACTIONS(1),
+    [anon_sym_LBRACK] = ACTIONS(1),
+    [anon_sym_RBRACK] = ACTIONS(1),
+    [anon_sym_LT] = ACTIONS(1),
+    [anon_sym_SLASH_GT] = ACTIONS(1),
+    [anon_sym_LT_SLASH] = ACTIONS(1),
+    [anon_sym_EQ] = ACTIONS(1),
+    [anon_sym_DQUOTE] = ACTIONS(1),
+    [anon_sym_SQUOTE] = ACTIONS(1),
+    [anon_sym_COLON] = ACTIONS(1),
+    [sym_Reference] = ACTIONS(1),
+    [anon_sym_LT_BANG_LBRACKCDATA_LBRACK] = ACTIONS(1),
+    [anon_sym_RBRACK_RBRACK_GT] = ACTIONS(1),
+    [sym_Comment] = ACTIONS(3),
+    [sym_grit_metavariable] = ACTIONS(1),
+  },
+  [1] = {
+    [sym_document] = STATE(63),
+    [sym_XMLDecl] = STATE(9),
+    [sym_PI] = STATE(40),
+    [sym_doctypedecl] = STATE(40),
+    [sym_element] = STATE(40),
+    [sym_STag] = STATE(2),
+    [sym_EmptyElemTag] = STATE(11),
+    [aux_sym_document_repeat1] = STATE(5),
+    [anon_sym_LT_QMARK] = ACTIONS(5),
+    [anon_sym_LT_BANG] = ACTIONS(7),
+    [anon_sym_LT] = ACTIONS(9),
+    [sym_Comment] = ACTIONS(3),
+  },
+};
+
+static const uint16_t ts_small_parse_table[] = {
+  [0] = 13,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_QMARK,
+    ACTIONS(13), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(15), 1,
+      aux_sym_CharData_token1,
+    ACTIONS(17), 1,
+      sym_Reference,
+    ACTIONS(19), 1,
+      anon_sym_LT_BANG_LBRACKCDATA_LBRACK,
+    ACTIONS(21), 1,
+      sym_grit_metavariable,
+    STATE(2), 1,
+      sym_STag,
+    STATE(3), 1,
+      aux_sym_element_repeat1,
+    STATE(11), 1,
+      sym_EmptyElemTag,
+    STATE(12), 1,
+      sym_ETag,
+    STATE(22), 5,
+      sym_PI,
+      sym_element,
+      sym__content,
+      sym_CharData,
+      sym_CDSect,
+  [44] = 13,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_QMARK,
+    ACTIONS(13), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(15), 1,
+      aux_sym_CharData_token1,
+    ACTIONS(17), 1,
+      sym_Reference,
+    ACTIONS(19), 1,
+      anon_sym_LT_BANG_LBRACKCDATA_LBRACK,
+    ACTIONS(21), 1,
+      sym_grit_metavariable,
+    STATE(2), 1,
+      sym_STag,
+    STATE(4), 1,
+      aux_sym_element_repeat1,
+    STATE(11), 1,
+      sym_EmptyElemTag,
+    STATE(13), 1,
+      sym_ETag,
+    STATE(22), 5,
+      sym_PI,
+      sym_element,
+      sym__content,
+      sym_CharData,
+      sym_CDSect,
+  [88] = 12,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(23), 1,
+      anon_sym_LT_QMARK,
+    ACTIONS(26), 1,
+      anon_sym_LT,
+    ACTIONS(29), 1,
+      anon_sym_LT_SLASH,
+    ACTIONS(31), 1,
+      aux_sym_CharData_token1,
+    ACTIONS(34), 1,
+      sym_Reference,
+    ACTIONS(37), 1,
+      anon_sym_LT_BANG_LBRACKCDATA_LBRACK,
+    ACTIONS(40), 1,
+      sym_grit_metavariable,
+    STATE(2), 1,
+      sym_STag,
+    STATE(4), 1,
+      aux_sym_element_repeat1,
+    STATE(11), 1,
+      sym_EmptyElemTag,
+    STATE(22), 5,
+      sym_PI,
+      sym_element,
+      sym__content,
+      sym_CharData,
+      sym_CDSect,
+  [129] = 9,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_QMARK,
+    ACTIONS(43), 1,
+      ts_builtin_sym_end,
+    STATE(2), 1,
+      sym_STag,
+    STATE(7), 1,
+      aux_sym_document_repeat1,
+    STATE(11), 1,
+      sym_EmptyElemTag,
+    STATE(40), 3,
+      sym_PI,
+      sym_doctypedecl,
+      sym_element,
+  [159] = 9,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_QMARK,
+    ACTIONS(45), 1,
+      ts_builtin_sym_end,
+    STATE(2), 1,
+      sym_STag,
+    STATE(7), 1,
+      aux_sym_document_repeat1,
+    STATE(11), 1,
+      sym_EmptyElemTag,
+    STATE(40), 3,
+      sym_PI,
+      sym_doctypedecl,
+      sym_element,
+  [189] = 9,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(47), 1,
+      ts_builtin_sym_end,
+    ACTIONS(49), 1,
+      anon_sym_LT_QMARK,
+    ACTIONS(52), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(55), 1,
+      anon_sym_LT,
+    STATE(2), 1,
+      sym_STag,
+    STATE(7), 1,
+      aux_sym_document_repeat1,
+    STATE(11), 1,
+      sym_EmptyElemTag,
+    STATE(40), 3,
+      sym_PI,
+      sym_doctypedecl,
+      sym_element,
+  [219] = 3,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(60), 3,
+      anon_sym_LT_BANG,
+      anon_sym_LT,
+      aux_sym_CharData_token1,
+    ACTIONS(58), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_QMARK,
+      anon_sym_LT_SLASH,
+      sym_Reference,
+      anon_sym_LT_BANG_LBRACKCDATA_LBRACK,
+      sym_grit_metavariable,
+  [236] = 8,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(7), 1,
+      anon_sym_LT_BANG,
+    ACTIONS(9), 1,
+      anon_sym_LT,
+    ACTIONS(11), 1,
+      anon_sym_LT_QMARK,
+    STATE(2), 1,
+      sym_STag,
+    STATE(6), 1,
+      aux_sym_document_repeat1,
+    STATE(11), 1,
+      sym_EmptyElemTag,
+    STATE(40), 3,
+      sym_PI,
+      sym_doctypedecl,
+      sym_element,
+  [263] = 3,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(64), 3,
+      anon_sym_LT_BANG,
+      anon_sym_LT,
+      aux_sym_CharData_token1,
+    ACTIONS(62), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_QMARK,
+      anon_sym_LT_SLASH,
+      sym_Reference,
+      anon_sym_LT_BANG_LBRACKCDATA_LBRACK,
+      sym_grit_metavariable,
+  [280] = 3,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(68), 3,
+      anon_sym_LT_BANG,
+      anon_sym_LT,
+      aux_sym_CharData_token1,
+    ACTIONS(66), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_QMARK,
+      anon_sym_LT_SLASH,
+      sym_Reference,
+      anon_sym_LT_BANG_LBRACKCDATA_LBRACK,
+      sym_grit_metavariable,
+  [297] = 3,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(72), 3,
+      anon_sym_LT_BANG,
+      anon_sym_LT,
+      aux_sym_CharData_token1,
+    ACTIONS(70), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_QMARK,
+      anon_sym_LT_SLASH,
+      sym_Reference,
+      anon_sym_LT_BANG_LBRACKCDATA_LBRACK,
+      sym_grit_metavariable,
+  [314] = 3,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(76), 3,
+      anon_sym_LT_BANG,
+      anon_sym_LT,
+      aux_sym_CharData_token1,
+    ACTIONS(74), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_QMARK,
+      anon_sym_LT_SLASH,
+      sym_Reference,
+      anon_sym_LT_BANG_LBRACKCDATA_LBRACK,
+      sym_grit_metavariable,
+  [331] = 3,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(80), 3,
+      anon_sym_LT_BANG,
+      anon_sym_LT,
+      aux_sym_CharData_token1,
+    ACTIONS(78), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_QMARK,
+      anon_sym_LT_SLASH,
+      sym_Reference,
+      anon_sym_LT_BANG_LBRACKCDATA_LBRACK,
+      sym_grit_metavariable,
+  [348] = 7,
+    ACTIONS(3), 1,
+      sym_Comment,
+    STATE(15), 1,
+      aux_sym_XMLDecl_repeat1,
+    STATE(28), 1,
+      sym_NCName,
+    STATE(34), 1,
+      sym_Attribute,
+    STATE(64), 1,
+      sym_Name,
+    ACTIONS(84), 2,
+      aux_sym_NCName_token1,
+      sym_grit_metavariable,
+    ACTIONS(82), 3,
+      anon_sym_QMARK_GT,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+  [373] = 3,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(89), 3,
+      anon_sym_LT_BANG,
+      anon_sym_LT,
+      aux_sym_CharData_token1,
+    ACTIONS(87), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_QMARK,
+      anon_sym_LT_SLASH,
+      sym_Reference,
+      anon_sym_LT_BANG_LBRACKCDATA_LBRACK,
+      sym_grit_metavariable,
+  [390] = 3,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(93), 3,
+      anon_sym_LT_BANG,
+      anon_sym_LT,
+      aux_sym_CharData_token1,
+    ACTIONS(91), 6,
+      ts_builtin_sym_end,
+      anon_sym_LT_QMARK,
+      anon_sym_LT_SLASH,
+      sym_Reference,
+      anon_sym_LT_BANG_LBRACKCDATA_LBRACK,
+      sym_grit_metavariable,
+  [407] = 8,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(95), 1,
+      anon_sym_GT,
+    ACTIONS(97), 1,
+      anon_sym_SLASH_GT,
+    STATE(19), 1,
+      aux_sym_XMLDecl_repeat1,
+    STATE(28), 1,
+      sym_NCName,
+    STATE(34), 1,
+      sym_Attribute,
+    STATE(64), 1,
+      sym_Name,
+    ACTIONS(99), 2,
+      aux_sym_NCName_token1,
+      sym_grit_metavariable,
+  [433] = 8,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(101), 1,
+      anon_sym_GT,
+    ACTIONS(103), 1,
+      anon_sym_SLASH_GT,
+    STATE(15), 1,
+      aux_sym_XMLDecl_repeat1,
+    STATE(28), 1,
+      sym_NCName,
+    STATE(34), 1,
+      sym_Attribute,
+    STATE(64), 1,
+      sym_Name,
+    ACTIONS(99), 2,
+      aux_sym_NCName_token1,
+      sym_grit_metavariable,
+  [459] = 7,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(105), 1,
+      anon_sym_QMARK_GT,
+    STATE(15), 1,
+      aux_sym_XMLDecl_repeat1,
+    STATE(28), 1,
+      sym_NCName,
+    STATE(34), 1,
+      sym_Attribute,
+    STATE(64), 1,
+      sym_Name,
+    ACTIONS(99), 2,
+      aux_sym_NCName_token1,
+      sym_grit_metavariable,
+  [482] = 3,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(109), 2,
+      anon_sym_LT,
+      aux_sym_CharData_token1,
+    ACTIONS(107), 5,
+      anon_sym_LT_QMARK,
+      anon_sym_LT_SLASH,
+      sym_Reference,
+      anon_sym_LT_BANG_LBRACKCDATA_LBRACK,
+      sym_grit_metavariable,
+  [497] = 3,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(113), 2,
+      anon_sym_LT,
+      aux_sym_CharData_token1,
+    ACTIONS(111), 5,
+      anon_sym_LT_QMARK,
+      anon_sym_LT_SLASH,
+      sym_Reference,
+      anon_sym_LT_BANG_LBRACKCDATA_LBRACK,
+      sym_grit_metavariable,
+  [512] = 3,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(117), 2,
+      anon_sym_LT,
+      aux_sym_CharData_token1,
+    ACTIONS(115), 5,
+      anon_sym_LT_QMARK,
+      anon_sym_LT_SLASH,
+      sym_Reference,
+      anon_sym_LT_BANG_LBRACKCDATA_LBRACK,
+      sym_grit_metavariable,
+  [527] = 7,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(119), 1,
+      anon_sym_QMARK_GT,
+    STATE(20), 1,
+      aux_sym_XMLDecl_repeat1,
+    STATE(28), 1,
+      sym_NCName,
+    STATE(34), 1,
+      sym_Attribute,
+    STATE(64), 1,
+      sym_Name,
+    ACTIONS(99), 2,
+      aux_sym_NCName_token1,
+      sym_grit_metavariable,
+  [550] = 3,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(123), 2,
+      anon_sym_LT,
+      aux_sym_CharData_token1,
+    ACTIONS(121), 5,
+      anon_sym_LT_QMARK,
+      anon_sym_LT_SLASH,
+      sym_Reference,
+      anon_sym_LT_BANG_LBRACKCDATA_LBRACK,
+      sym_grit_metavariable,
+  [565] = 3,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(127), 2,
+      anon_sym_LT,
+      aux_sym_CharData_token1,
+    ACTIONS(125), 5,
+      anon_sym_LT_QMARK,
+      anon_sym_LT_SLASH,
+      sym_Reference,
+      anon_sym_LT_BANG_LBRACKCDATA_LBRACK,
+      sym_grit_metavariable,
+  [580] = 3,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(131), 2,
+      anon_sym_LT,
+      aux_sym_CharData_token1,
+    ACTIONS(129), 5,
+      anon_sym_LT_QMARK,
+      anon_sym_LT_SLASH,
+      sym_Reference,
+      anon_sym_LT_BANG_LBRACKCDATA_LBRACK,
+      sym_grit_metavariable,
+  [595] = 3,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(135), 1,
+      anon_sym_COLON,
+    ACTIONS(133), 5,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      anon_sym_EQ,
+      aux_sym_NCName_token1,
+      sym_grit_metavariable,
+  [609] = 6,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(137), 1,
+      anon_sym_GT,
+    ACTIONS(141), 1,
+      anon_sym_LBRACK,
+    STATE(55), 1,
+      sym_ExternalID,
+    STATE(75), 1,
+      sym_intSubset,
+    ACTIONS(139), 2,
+      anon_sym_SYSTEM,
+      anon_sym_PUBLIC,
+  [629] = 2,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(143), 6,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      anon_sym_EQ,
+      anon_sym_COLON,
+      aux_sym_NCName_token1,
+      sym_grit_metavariable,
+  [641] = 2,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(145), 5,
+      anon_sym_QMARK_GT,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_NCName_token1,
+      sym_grit_metavariable,
+  [652] = 2,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(147), 5,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      anon_sym_EQ,
+      aux_sym_NCName_token1,
+      sym_grit_metavariable,
+  [663] = 2,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(149), 5,
+      anon_sym_QMARK_GT,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_NCName_token1,
+      sym_grit_metavariable,
+  [674] = 2,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(151), 5,
+      anon_sym_QMARK_GT,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_NCName_token1,
+      sym_grit_metavariable,
+  [685] = 3,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(153), 1,
+      anon_sym_COLON,
+    ACTIONS(133), 4,
+      anon_sym_GT,
+      anon_sym_SYSTEM,
+      anon_sym_PUBLIC,
+      anon_sym_LBRACK,
+  [698] = 2,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(143), 5,
+      anon_sym_GT,
+      anon_sym_SYSTEM,
+      anon_sym_PUBLIC,
+      anon_sym_LBRACK,
+      anon_sym_COLON,
+  [709] = 2,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(155), 5,
+      anon_sym_QMARK_GT,
+      anon_sym_GT,
+      anon_sym_SLASH_GT,
+      aux_sym_NCName_token1,
+      sym_grit_metavariable,
+  [720] = 3,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(157), 2,
+      ts_builtin_sym_end,
+      anon_sym_LT_QMARK,
+    ACTIONS(159), 2,
+      anon_sym_LT_BANG,
+      anon_sym_LT,
+  [732] = 4,
+    ACTIONS(3), 1,
+      sym_Comment,
+    STATE(29), 1,
+      sym_Name,
+    STATE(35), 1,
+      sym_NCName,
+    ACTIONS(161), 2,
+      aux_sym_NCName_token1,
+      sym_grit_metavariable,
+  [746] = 3,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(163), 2,
+      ts_builtin_sym_end,
+      anon_sym_LT_QMARK,
+    ACTIONS(165), 2,
+      anon_sym_LT_BANG,
+      anon_sym_LT,
+  [758] = 4,
+    ACTIONS(167), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(171), 1,
+      sym_Comment,
+    STATE(71), 1,
+      sym__double_quoted_content,
+    ACTIONS(169), 2,
+      aux_sym__double_quoted_content_token1,
+      sym_grit_metavariable,
+  [772] = 4,
+    ACTIONS(167), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(171), 1,
+      sym_Comment,
+    STATE(69), 1,
+      sym__single_quoted_content,
+    ACTIONS(173), 2,
+      aux_sym__single_quoted_content_token1,
+      sym_grit_metavariable,
+  [786] = 4,
+    ACTIONS(3), 1,
+      sym_Comment,
+    STATE(28), 1,
+      sym_NCName,
+    STATE(62), 1,
+      sym_Name,
+    ACTIONS(99), 2,
+      aux_sym_NCName_token1,
+      sym_grit_metavariable,
+  [800] = 4,
+    ACTIONS(3), 1,
+      sym_Comment,
+    STATE(18), 1,
+      sym_Name,
+    STATE(28), 1,
+      sym_NCName,
+    ACTIONS(99), 2,
+      aux_sym_NCName_token1,
+      sym_grit_metavariable,
+  [814] = 4,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(177), 1,
+      sym_SystemLiteral,
+    STATE(49), 1,
+      aux_sym_ExternalID_repeat1,
+    ACTIONS(175), 2,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+  [828] = 3,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(179), 2,
+      ts_builtin_sym_end,
+      anon_sym_LT_QMARK,
+    ACTIONS(181), 2,
+      anon_sym_LT_BANG,
+      anon_sym_LT,
+  [840] = 3,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(183), 2,
+      ts_builtin_sym_end,
+      anon_sym_LT_QMARK,
+    ACTIONS(185), 2,
+      anon_sym_LT_BANG,
+      anon_sym_LT,
+  [852] = 3,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(187), 2,
+      ts_builtin_sym_end,
+      anon_sym_LT_QMARK,
+    ACTIONS(189), 2,
+      anon_sym_LT_BANG,
+      anon_sym_LT,
+  [864] = 4,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(193), 1,
+      sym_SystemLiteral,
+    STATE(49), 1,
+      aux_sym_ExternalID_repeat1,
+    ACTIONS(191), 2,
+      anon_sym_GT,
+      anon_sym_LBRACK,
+  [878] = 2,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(147), 4,
+      anon_sym_GT,
+      anon_sym_SYSTEM,
+      anon_sym_PUBLIC,
+      anon_sym_LBRACK,
+  [888] = 3,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(196), 1,
+      anon_sym_LT_QMARK,
+    ACTIONS(198), 2,
+      anon_sym_LT_BANG,
+      anon_sym_LT,
+  [899] = 3,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(200), 1,
+      anon_sym_LT_QMARK,
+    ACTIONS(202), 2,
+      anon_sym_LT_BANG,
+      anon_sym_LT,
+  [910] = 3,
+    ACTIONS(3), 1,
+      sym_Comment,
+    STATE(32), 1,
+      sym_NCName,
+    ACTIONS(99), 2,
+      aux_sym_NCName_token1,
+      sym_grit_metavariable,
+  [921] = 4,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(204), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(206), 1,
+      anon_sym_SQUOTE,
+    STATE(33), 1,
+      sym_AttValue,
+  [934] = 4,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(141), 1,
+      anon_sym_LBRACK,
+    ACTIONS(208), 1,
+      anon_sym_GT,
+    STATE(68), 1,
+      sym_intSubset,
+  [947] = 3,
+    ACTIONS(3), 1,
+      sym_Comment,
+    STATE(50), 1,
+      sym_NCName,
+    ACTIONS(161), 2,
+      aux_sym_NCName_token1,
+      sym_grit_metavariable,
+  [958] = 3,
+    ACTIONS(171), 1,
+      sym_Comment,
+    ACTIONS(210), 1,
+      anon_sym_RBRACK_RBRACK_GT,
+    ACTIONS(212), 1,
+      sym_CData,
+  [968] = 3,
+    ACTIONS(171), 1,
+      sym_Comment,
+    ACTIONS(214), 1,
+      anon_sym_QMARK_GT,
+    ACTIONS(216), 1,
+      sym_PIContent,
+  [978] = 3,
+    ACTIONS(171), 1,
+      sym_Comment,
+    ACTIONS(218), 1,
+      aux_sym_intSubset_token1,
+    ACTIONS(220), 1,
+      anon_sym_RBRACK,
+  [988] = 3,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(222), 1,
+      sym_SystemLiteral,
+    STATE(45), 1,
+      aux_sym_ExternalID_repeat1,
+  [998] = 3,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(224), 1,
+      anon_sym_xml,
+    ACTIONS(226), 1,
+      sym_PITarget,
+  [1008] = 2,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(228), 1,
+      anon_sym_GT,
+  [1015] = 2,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(230), 1,
+      ts_builtin_sym_end,
+  [1022] = 2,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(232), 1,
+      anon_sym_EQ,
+  [1029] = 2,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(234), 1,
+      anon_sym_RBRACK_RBRACK_GT,
+  [1036] = 2,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(236), 1,
+      anon_sym_RBRACK,
+  [1043] = 2,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(238), 1,
+      anon_sym_GT,
+  [1050] = 2,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(240), 1,
+      anon_sym_GT,
+  [1057] = 2,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(242), 1,
+      anon_sym_SQUOTE,
+  [1064] = 2,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(244), 1,
+      anon_sym_QMARK_GT,
+  [1071] = 2,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(242), 1,
+      anon_sym_DQUOTE,
+  [1078] = 2,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(246), 1,
+      anon_sym_GT,
+  [1085] = 2,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(248), 1,
+      sym_PITarget,
+  [1092] = 2,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(250), 1,
+      anon_sym_DOCTYPE,
+  [1099] = 2,
+    ACTIONS(3), 1,
+      sym_Comment,
+    ACTIONS(252), 1,
+      anon_sym_GT,
+};
+
+static const uint32_t ts_small_parse_table_map[] = {
+  [SMALL_STATE(2)] = 0,
+  [SMALL_STATE(3)] = 44,
+  [SMALL_STATE(4)] = 88,
+  [SMALL_STATE(5)] = 129,
+  [SMALL_STATE(6)] = 159,
+  [SMALL_STATE(7)] = 189,
+  [SMALL_STATE(8)] = 219,
+  [SMALL_STATE(9)] = 236,
+  [SMALL_STATE(10)] = 263,
+  [SMALL_STATE(11)] = 280,
+  [SMALL_STATE(12)] = 297,
+  [SMALL_STATE(13)] = 314,
+  [SMALL_STATE(14)] = 331,
+  [SMALL_STATE(15)] = 348,
+  [SMALL_STATE(16)] = 373,
+  [SMALL_STATE(17)] = 390,
+  [SMALL_STATE(18)] = 407,
+  [SMALL_STATE(19)] = 433,
+  [SMALL_STATE(20)] = 459,
+  [SMALL_STATE(21)] = 482,
+  [SMALL_STATE(22)] = 497,
+  [SMALL_STATE(23)] = 512,
+  [SMALL_STATE(24)] = 527,
+  [SMALL_STATE(25)] = 550,
+  [SMALL_STATE(26)] = 565,
+  [SMALL_STATE(27)] = 580,
+  [SMALL_STATE(28)] = 595,
+  [SMALL_STATE(29)] = 609,
+  [SMALL_STATE(30)] = 629,
+  [SMALL_STATE(31)] = 641,
+  [SMALL_STATE(32)] = 652,
+  [SMALL_STATE(33)] = 663,
+  [SMALL_STATE(34)] = 674,
+  [SMALL_STATE(35)] = 685,
+  [SMALL_STATE(36)] = 698,
+  [SMALL_STATE(37)] = 709,
+  [SMALL_STATE(38)] = 720,
+  [SMALL_STATE(39)] = 732,
+  [SMALL_STATE(40)] = 746,
+  [SMALL_STATE(41)] = 758,
+  [SMALL_STATE(42)] = 772,
+  [SMALL_STATE(43)] = 786,
+  [SMALL_STATE(44)] = 800,
+  [SMALL_STATE(45)] = 814,
+  [SMALL_STATE(46)] = 828,
+  [SMALL_STATE(47)] = 840,
+  [SMALL_STATE(48)] = 852,
+  [SMALL_STATE(49)] = 864,
+  [SMALL_STATE(50)] = 878,
+  [SMALL_STATE(51)] = 888,
+  [SMALL_STATE(52)] = 899,
+  [SMALL_STATE(53)] = 910,
+  [SMALL_STATE(54)] = 921,
+  [SMALL_STATE(55)] = 934,
+  [SMALL_STATE(56)] = 947,
+  [SMALL_STATE(57)] = 958,
+  [SMALL_STATE(58)] = 968,
+  [SMALL_STATE(59)] = 978,
+  [SMALL_STATE(60)] = 988,
+  [SMALL_STATE(61)] = 998,
+  [SMALL_STATE(62)] = 1008,
+  [SMALL_STATE(63)] = 1015,
+  [SMALL_STATE(64)] = 1022,
+  [SMALL_STATE(65)] = 1029,
+  [SMALL_STATE(66)] = 1036,
+  [SMALL_STATE(67)] = 1043,
+  [SMALL_STATE(68)] = 1050,
+  [SMALL_STATE(69)] = 1057,
+  [SMALL_STATE(70)] = 1064,
+  [SMALL_STATE(71)] = 1071,
+  [SMALL_STATE(72)] = 1078,
+  [SMALL_STATE(73)] = 1085,
+  [SMALL_STATE(74)] = 1092,
+  [SMALL_STATE(75)] = 1099,
+};
+
+static const TSParseActionEntry ts_parse_actions[] = {
+  [0] = {.entry = {.count = 0, .reusable = false}},
+  [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
+  [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT_EXTRA(),
+  [5] = {.entry = {.count = 1, .reusable = true}}, SHIFT(61),
+  [7] = {.entry = {.count = 1, .reusable = false}}, SHIFT(74),
+  [9] = {.entry = {.count = 1, .reusable = false}}, SHIFT(44),
+  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(73),
+  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(43),
+  [15] = {.entry = {.count = 1, .reusable = false}}, SHIFT(21),
+  [17] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
+  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
+  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(21),
+  [23] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_element_repeat1, 2, 0, 7), SHIFT_REPEAT(73),
+  [26] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_element_repeat1, 2, 0, 7), SHIFT_REPEAT(44),
+  [29] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_element_repeat1, 2, 0, 7),
+  [31] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_element_repeat1, 2, 0, 7), SHIFT_REPEAT(21),
+  [34] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_element_repeat1, 2, 0, 7), SHIFT_REPEAT(22),
+  [37] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_element_repeat1, 2, 0, 7), SHIFT_REPEAT(57),
+  [40] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_element_repeat1, 2, 0, 7), SHIFT_REPEAT(21),
+  [43] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_document, 1, 0, 3),
+  [45] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_document, 2, 0, 5),
+  [47] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 7),
+  [49] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 7), SHIFT_REPEAT(73),
+  [52] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_document_repeat1, 2, 0, 7), SHIFT_REPEAT(74),
+  [55] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_document_repeat1, 2, 0, 7), SHIFT_REPEAT(44),
+  [58] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_EmptyElemTag, 3, 0, 10),
+  [60] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_EmptyElemTag, 3, 0, 10),
+  [62] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_PI, 3, 0, 9),
+  [64] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_PI, 3, 0, 9),
+  [66] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 1, 0, 2),
+  [68] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 1, 0, 2),
+  [70] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 2, 0, 6),
+  [72] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 2, 0, 6),
+  [74] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_element, 3, 0, 11),
+  [76] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_element, 3, 0, 11),
+  [78] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_EmptyElemTag, 4, 0, 16),
+  [80] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_EmptyElemTag, 4, 0, 16),
+  [82] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_XMLDecl_repeat1, 2, 0, 13),
+  [84] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_XMLDecl_repeat1, 2, 0, 13), SHIFT_REPEAT(30),
+  [87] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_PI, 4, 0, 14),
+  [89] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_PI, 4, 0, 14),
+  [91] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ETag, 3, 0, 10),
+  [93] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ETag, 3, 0, 10),
+  [95] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
+  [97] = {.entry = {.count = 1, .reusable = true}}, SHIFT(8),
+  [99] = {.entry = {.count = 1, .reusable = true}}, SHIFT(30),
+  [101] = {.entry = {.count = 1, .reusable = true}}, SHIFT(26),
+  [103] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
+  [105] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
+  [107] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_CharData, 1, 0, 0),
+  [109] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_CharData, 1, 0, 0),
+  [111] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_element_repeat1, 1, 0, 1),
+  [113] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_element_repeat1, 1, 0, 1),
+  [115] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_STag, 3, 0, 10),
+  [117] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_STag, 3, 0, 10),
+  [119] = {.entry = {.count = 1, .reusable = true}}, SHIFT(52),
+  [121] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_CDSect, 2, 0, 0),
+  [123] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_CDSect, 2, 0, 0),
+  [125] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_STag, 4, 0, 16),
+  [127] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_STag, 4, 0, 16),
+  [129] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_CDSect, 3, 0, 18),
+  [131] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_CDSect, 3, 0, 18),
+  [133] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_Name, 1, 0, 4),
+  [135] = {.entry = {.count = 1, .reusable = true}}, SHIFT(53),
+  [137] = {.entry = {.count = 1, .reusable = true}}, SHIFT(38),
+  [139] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
+  [141] = {.entry = {.count = 1, .reusable = true}}, SHIFT(59),
+  [143] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_NCName, 1, 0, 0),
+  [145] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_AttValue, 2, 0, 0),
+  [147] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_Name, 3, 0, 17),
+  [149] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_Attribute, 3, 0, 19),
+  [151] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_XMLDecl_repeat1, 1, 0, 8),
+  [153] = {.entry = {.count = 1, .reusable = true}}, SHIFT(56),
+  [155] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_AttValue, 3, 0, 18),
+  [157] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctypedecl, 4, 0, 15),
+  [159] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctypedecl, 4, 0, 15),
+  [161] = {.entry = {.count = 1, .reusable = true}}, SHIFT(36),
+  [163] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 1, 0, 1),
+  [165] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_document_repeat1, 1, 0, 1),
+  [167] = {.entry = {.count = 1, .reusable = false}}, SHIFT(31),
+  [169] = {.entry = {.count = 1, .reusable = false}}, SHIFT(71),
+  [171] = {.entry = {.count = 1, .reusable = false}}, SHIFT_EXTRA(),
+  [173] = {.entry = {.count = 1, .reusable = false}}, SHIFT(69),
+  [175] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ExternalID, 2, 0, 0),
+  [177] = {.entry = {.count = 1, .reusable = true}}, SHIFT(49),
+  [179] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctypedecl, 5, 0, 21),
+  [181] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctypedecl, 5, 0, 21),
+  [183] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctypedecl, 6, 0, 22),
+  [185] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctypedecl, 6, 0, 22),
+  [187] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_doctypedecl, 5, 0, 20),
+  [189] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_doctypedecl, 5, 0, 20),
+  [191] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_ExternalID_repeat1, 2, 0, 0),
+  [193] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_ExternalID_repeat1, 2, 0, 0), SHIFT_REPEAT(49),
+  [196] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_XMLDecl, 4, 0, 12),
+  [198] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_XMLDecl, 4, 0, 12),
+  [200] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_XMLDecl, 3, 0, 0),
+  [202] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_XMLDecl, 3, 0, 0),
+  [204] = {.entry = {.count = 1, .reusable = true}}, SHIFT(41),
+  [206] = {.entry = {.count = 1, .reusable = true}}, SHIFT(42),
+  [208] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
+  [210] = {.entry = {.count = 1, .reusable = false}}, SHIFT(25),
+  [212] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
+  [214] = {.entry = {.count = 1, .reusable = true}}, SHIFT(10),
+  [216] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
+  [218] = {.entry = {.count = 1, .reusable = false}}, SHIFT(66),
+  [220] = {.entry = {.count = 1, .reusable = false}}, SHIFT(67),
+  [222] = {.entry = {.count = 1, .reusable = true}}, SHIFT(45),
+  [224] = {.entry = {.count = 1, .reusable = false}}, SHIFT(24),
+  [226] = {.entry = {.count = 1, .reusable = false}}, SHIFT(58),
+  [228] = {.entry = {.count = 1, .reusable = true}}, SHIFT(17),
+  [230] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [232] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
+  [234] = {.entry = {.count = 1, .reusable = true}}, SHIFT(27),
+  [236] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
+  [238] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_intSubset, 2, 0, 0),
+  [240] = {.entry = {.count = 1, .reusable = true}}, SHIFT(47),
+  [242] = {.entry = {.count = 1, .reusable = true}}, SHIFT(37),
+  [244] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
+  [246] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_intSubset, 3, 0, 0),
+  [248] = {.entry = {.count = 1, .reusable = true}}, SHIFT(58),
+  [250] = {.entry = {.count = 1, .reusable = true}}, SHIFT(39),
+  [252] = {.entry = {.count = 1, .reusable = true}}, SHIFT(46),
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+#ifdef TREE_SITTER_HIDE_SYMBOLS
+#define TS_PUBLIC
+#elif defined(_WIN32)
+#define TS_PUBLIC __declspec(dllexport)
+#else
+#define TS_PUBLIC __attribute__((visibility("default")))
+#endif
+
+TS_PUBLIC const TSLanguage *tree_sitter_xml(void) {
+  static const TSLanguage language = {
+    .version = LANGUAGE_VERSION,
+    .symbol_count = SYMBOL_COUNT,
+    .alias_count = ALIAS_COUNT,
+    .token_count = TOKEN_COUNT,
+    .external_token_count = EXTERNAL_TOKEN_COUNT,
+    .state_count = STATE_COUNT,
+    .large_state_count = LARGE_STATE_COUNT,
+    .production_id_count = PRODUCTION_ID_COUNT,
+    .field_count = FIELD_COUNT,
+    .max_alias_sequence_length = MAX_ALIAS_SEQUENCE_LENGTH,
+    .parse_table = &ts_parse_table[0][0],
+    .small_parse_table = ts_small_parse_table,
+    .small_parse_table_map = ts_small_parse_table_map,
+    .parse_actions = ts_parse_actions,
+    .symbol_names = ts_symbol_names,
+    .field_names = ts_field_names,
+    .field_map_slices = ts_field_map_slices,
+    .field_map_entries = ts_field_map_entries,
+    .symbol_metadata = ts_symbol_metadata,
+    .public_symbol_map = ts_symbol_map,
+    .alias_map = ts_non_terminal_alias_map,
+    .alias_sequences = &ts_alias_sequences[0][0],
+    .lex_modes = ts_lex_modes,
+    .lex_fn = ts_lex,
+    .primary_state_ids = ts_primary_state_ids,
+  };
+  return &language;
+}
+#ifdef __cplusplus
+}
+#endif
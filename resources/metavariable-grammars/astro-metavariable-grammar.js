@@ -0,0 +1,213 @@
+/**
+ * @file Astro grammar for tree-sitter
+ * @see {@link https://astro.build|Official website}
+ */
+
+/// <reference types="tree-sitter-cli/dsl" />
+// @ts-check
+
+module.exports = grammar({
+  name: 'astro',
+
+  extras: $ => [
+    $.comment,
+    /\s+/,
+  ],
+
+  externals: $ => [
+    $._start_tag_name,
+    $._script_start_tag_name,
+    $._style_start_tag_name,
+    $._end_tag_name,
+    $.erroneous_end_tag_name,
+    '/>',
+    $._implicit_end_tag,
+    $.raw_text,
+    $.comment,
+    $._html_interpolation_start,
+    $._html_interpolation_end,
+    $.frontmatter_js_block,
+    $.attribute_js_expr,
+    $.attribute_backtick_string,
+    $.permissible_text,
+    $._fragment_tag_delim,
+  ],
+
+  rules: {
+    document: $ => seq(
+      optional($.frontmatter),
+      repeat($._node),
+    ),
+
+    doctype: $ => seq(
+      '<!',
+      alias($._doctype, 'doctype'),
+      /[^>]+/,
+      '>',
+    ),
+
+    _doctype: _ => /[Dd][Oo][Cc][Tt][Yy][Pp][Ee]/,
+
+    _node: $ => choice(
+      $.doctype,
+      $.text,
+      $.element,
+      $.script_element,
+      $.style_element,
+      $.erroneous_end_tag,
+      $.html_interpolation,
+    ),
+
+    element: $ => choice(
+      seq(
+        $.start_tag,
+        repeat($._node),
+        choice($.end_tag, $._implicit_end_tag),
+      ),
+      $.self_closing_tag,
+    ),
+
+    script_element: $ => choice(
+      seq(
+        alias($.script_start_tag, $.start_tag),
+        optional($.raw_text),
+        $.end_tag,
+      ),
+      alias($.self_closing_script_tag, $.self_closing_tag),
+    ),
+
+    style_element: $ => choice(
+      seq(
+        alias($.style_start_tag, $.start_tag),
+        optional($.raw_text),
+        $.end_tag,
+      ),
+      alias($.self_closing_style_tag, $.self_closing_tag),
+    ),
+
+    start_tag: $ => choice(
+      seq(
+        '<',
+        alias($._start_tag_name, $.tag_name),
+        repeat($.attribute),
+        '>',
+      ),
+      seq('<', alias($._fragment_tag_delim, '>')),
+    ),
+
+    script_start_tag: $ => seq(
+      '<',
+      alias($._script_start_tag_name, $.tag_name),
+      repeat($.attribute),
+      '>',
+    ),
+
+    style_start_tag: $ => seq(
+      '<',
+      alias($._style_start_tag_name, $.tag_name),
+      repeat($.attribute),
+      '>',
+    ),
+
+    self_closing_tag: $ => seq(
+      '<',
+      alias($._start_tag_name, $.tag_name),
+      repeat($.attribute),
+      '/>',
+    ),
+
+    end_tag: $ => choice(
+      seq(
+        '</',
+        alias($._end_tag_name, $.tag_name),
+        '>',
+      ),
+      seq('</', alias($._fragment_tag_delim, '>')),
+    ),
+
+    erroneous_end_tag: $ => seq(
+      '</',
+      $.erroneous_end_tag_name,
+      '>',
+    ),
+
+    attribute: $ => choice(
+      seq(
+        $.attribute_name,
+        optional(seq(
+          '=',
+          choice(
+            $.attribute_value,
+            $.quoted_attribute_value,
+            $.attribute_interpolation,
+            $.attribute_backtick_string,
+          ),
+        )),
+      ),
+      $.attribute_interpolation,
+    ),
+
+    attribute_name: $ => choice(
+      /[^<>"'/=\s]+/,
+      $.grit_metavariable,
+    ),
+
+    attribute_value: $ => choice(
+      /[^<>"'=\s]+/,
+      $.grit_metavariable,
+    ),
+
+    quoted_attribute_value: $ => choice(
+      seq('\'', optional(choice(alias(/[^']+/, $.attribute_value), $.grit_metavariable)), '\''),
+      seq('"', optional(choice(alias(/[^"]+/, $.attribute_value), $.grit_metavariable)), '"'),
+    ),
+
+    text: $ => choice(
+      /[^<>{}\s]([^<>{}]*[^<>{}\s])?/,
+      $.grit_metavariable,
+    ),
+
+    _node_with_permissible_text: $ => choice(
+      $.doctype,
+      $.permissible_text,
+      $.element,
+      $.script_element,
+      $.style_element,
+      $.html_interpolation,
+    ),
+
+    frontmatter: $ => seq(
+      token(prec(1, '---')),
+      optional($.frontmatter_js_block),
+      '---',
+    ),
+
+    attribute_interpolation: $ => seq(
+      '{',
+      $.attribute_js_expr,
+      '}',
+    ),
+
+    html_interpolation: $ => seq(
+      alias($._html_interpolation_start, '{'),
+      repeat($._node_with_permissible_text),
+      alias($._html_interpolation_end, '}'),
+    ),
+
+    self_closing_script_tag: $ => seq(
+      '<',
+      alias($._script_start_tag_name, $.tag_name),
+      repeat($.attribute),
+      '/>',
+    ),
+
+    self_closing_style_tag: $ => seq(
+      '<',
+      alias($._style_start_tag_name, $.tag_name),
+      repeat($.attribute),
+      '/>',
+    ),
+
+    grit_metavariable: _ => token(prec(100, choice('µ...', /µ[a-zA-Z_][a-zA-Z0-9_]*/))),
+  },
+});
@@ -0,0 +1,567 @@
+/**
+ * @file Parser for proto2 and proto3 files
+ * @author Mohammad Ashar Khan <ashar786khan@gmail.com>
+ * @license MIT
+ */
+
+/// <reference types="tree-sitter-cli/dsl" />
+// @ts-check
+
+const
+  letter = /[a-zA-Z]/;
+const decimal_digit = /[0-9]/;
+const octal_digit = /[0-7]/;
+const hex_digit = /[0-9A-Fa-f]/;
+
+/**
+ *
+ * @param {any} content
+ */
+function array_of(content) {
+  return seq(
+    '[',
+    optional(seq(content, repeat(seq(',', content)))),
+    ']',
+  );
+}
+
+module.exports = grammar({
+  name: 'proto',
+
+  extras: $ => [$.comment, /\s/],
+
+  rules: {
+    // proto = syntax { import | package | option | topLevelDef | emptyStatement }
+    // topLevelDef = message | enum | service
+    source_file: $ => seq(
+      optional(field('syntax', choice($.syntax, $.edition))),
+      optional(repeat(field('item', choice(
+        $.import,
+        $.package,
+        $.option,
+        $.enum,
+        $.message,
+        $.extend,
+        $.service,
+        $.empty_statement,
+      )))),
+    ),
+
+    empty_statement: _ => ';',
+
+    // edition  = "edition" "=" quote numeric quote ";"
+    edition: $ => seq('edition', '=', field('year', $.string), ';'),
+    // syntax = "syntax" "=" quote "proto3" quote ";"
+    syntax: $ => seq('syntax', '=', choice('"proto3"', '"proto2"'), ';'),
+
+    // import = "import" [ "weak" | "public" ] strLit ";"
+    import: $ => seq(
+      'import',
+      optional(field('modifier', $.import_modifier)),
+      field('path', $.string),
+      ';',
+    ),
+
+    import_modifier: _ => choice('weak', 'public'),
+
+    // package = "package" fullIdent ";"
+    package: $ => seq(
+      'package',
+      field('name', $.full_ident),
+      ';',
+    ),
+
+    // option = "option" optionName  "=" constant ";"
+    // optionName = ( ident | "(" fullIdent ")" ) { "." ident }
+    option: $ => seq(
+      'option',
+      field('name', $.option_name),
+      '=',
+      field('value', $.constant),
+      ';',
+    ),
+
+    option_name: $ => seq(
+      choice(
+        $.identifier,
+        seq('(', $.full_ident, ')'),
+      ),
+      repeat(seq(
+        '.',
+        $.identifier,
+      )),
+    ),
+
+    // enum = "enum" enumName enumBody
+    // enumBody = "{" { option | enumField | emptyStatement } "}"
+    // enumField = ident "=" [ "-" ] intLit [ "[" enumValueOption { ","  enumValueOption } "]" ]";"
+    // enumValueOption = optionName "=" constant
+    enum: $ => seq(
+      'enum',
+      field('name', $.enum_name),
+      field('body', $.enum_body),
+    ),
+
+    enum_name: $ => $.identifier,
+
+    enum_body: $ => seq(
+      '{',
+      repeat(field('item', choice(
+        $.option,
+        $.enum_field,
+        $.empty_statement,
+        $.reserved,
+      ))),
+      '}',
+    ),
+
+    enum_field: $ => seq(
+      field('name', $.identifier),
+      '=',
+      field('number', $.signed_int_lit),
+      optional(seq(
+        '[',
+        field('option', $.enum_value_option),
+        repeat(seq(',', field('option', $.enum_value_option))),
+        ']',
+      )),
+      ';',
+    ),
+
+    signed_int_lit: $ => seq(optional('-'), $.int_lit),
+
+    enum_value_option: $ => seq(
+      field('name', $.option_name),
+      '=',
+      field('value', $.constant),
+    ),
+
+    // message = "message" messageName messageBody
+    // messageBody = "{" { field | enum | message | option | oneof | mapField | reserved | emptyStatement } "}"
+    message: $ => seq(
+      'message',
+      field('name', $.message_name),
+      field('body', $.message_body),
+    ),
+
+    message_body: $ => seq(
+      '{',
+      repeat(field('item', choice(
+        $.field,
+        $.enum,
+        $.message,
+        $.option,
+        $.oneof,
+        $.map_field,
+        $.reserved,
+        $.extensions,
+        $.extend,
+        $.empty_statement,
+      ))),
+      '}',
+    ),
+
+    message_name: $ => $.identifier,
+
+    extend: $ => seq(
+      'extend',
+      field('name', $.full_ident),
+      field('body', $.message_body),
+    ),
+
+    // field = [ "repeated" ] type fieldName "=" fieldNumber [ "[" fieldOptions "]" ] ";"
+    // fieldOptions = fieldOption { ","  fieldOption }
+    // fieldOption = optionName "=" constant
+    field: $ => seq(
+      // This isn't allowed according to the spec and yet the proto3 compiler
+      // accepts it so we put it here for parsing.
+      optional(field('label', $.field_label)),
+      field('type', $.type),
+      field('name', $.identifier),
+      '=',
+      field('number', $.field_number),
+      optional(seq('[', field('options', $.field_options), ']')),
+      ';',
+    ),
+
+    field_label: _ => choice('optional', 'required', 'repeated'),
+
+    field_options: $ => seq(
+      field('option', $.field_option),
+      repeat(seq(',', field('option', $.field_option))),
+    ),
+
+    field_option: $ => seq(
+      field('name', $.option_name),
+      '=',
+      field('value', $.constant),
+    ),
+
+    // oneof = "oneof" oneofName "{" { option | oneofField | emptyStatement } "}"
+    // oneofField = type fieldName "=" fieldNumber [ "[" fieldOptions "]" ] ";"
+    oneof: $ => seq(
+      'oneof',
+      field('name', $.identifier),
+      '{',
+      repeat(field('item', choice(
+        $.option,
+        $.oneof_field,
+        $.empty_statement,
+      ))),
+      '}',
+    ),
+
+    oneof_field: $ => seq(
+      field('type', $.type),
+      field('name', $.identifier),
+      '=',
+      field('number', $.field_number),
+      optional(seq('[', field('options', $.field_options), ']')),
+    ),
+
+    // mapField = "map" "<" keyType "," type ">" mapName "=" fieldNumber [ "[" fieldOptions "]" ] ";"
+    // keyType = "int32" | "int64" | "uint32" | "uint64" | "sint32" | "sint64" |
+    //        "fixed32" | "fixed64" | "sfixed32" | "sfixed64" | "bool" | "string"
+    map_field: $ => seq(
+      'map',
+      '<',
+      field('key_type', $.key_type),
+      ',',
+      field('value_type', $.type),
+      '>',
+      field('name', $.identifier),
+      '=',
+      field('number', $.field_number),
+      optional(seq('[', field('options', $.field_options), ']')),
+      ';',
+    ),
+
+    key_type: $ => choice(
+      'int32',
+      'int64',
+      'uint32',
+      'uint64',
+      'sint32',
+      'sint64',
+      'fixed32',
+      'fixed64',
+      'sfixed32',
+      'sfixed64',
+      'bool',
+      'string',
+    ),
+
+    // type = "double" | "float" | "int32" | "int64" | "uint32" | "uint64"
+    //    | "sint32" | "sint64" | "fixed32" | "fixed64" | "sfixed32" | "sfixed64"
+    //    | "bool" | "string" | "bytes" | messageType | enumType
+    type: $ => choice(
+      'double',
+      'float',
+      'int32',
+      'int64',
+      'uint32',
+      'uint64',
+      'sint32',
+      'sint64',
+      'fixed32',
+      'fixed64',
+      'sfixed32',
+      'sfixed64',
+      'bool',
+      'string',
+      'bytes',
+      $.message_or_enum_type,
+    ),
+
+    // reserved = "reserved" ( ranges | fieldNames ) ";"
+    // ranges = range { "," range }
+    // range =  intLit [ "to" ( intLit | "max" ) ]
+    // fieldNames = fieldName { "," fieldName }
+    reserved: $ => seq(
+      'reserved',
+      field('reserved', choice($.ranges, $.reserved_field_names)),
+      ';',
+    ),
+
+    extensions: $ => seq(
+      'extensions',
+      field('ranges', $.ranges),
+      ';',
+    ),
+
+    ranges: $ => seq(field('range', $.range), repeat(seq(',', field('range', $.range)))),
+
+    range: $ => seq(
+      field('start', $.int_lit),
+      optional(seq(
+        'to',
+        field('end', choice($.int_lit, $.max)),
+      )),
+    ),
+
+    max: _ => 'max',
+
+    field_names: $ => seq(
+      $._identifier_or_string,
+      repeat(seq(',', $._identifier_or_string)),
+    ),
+
+    reserved_field_names: $ => seq(
+      field('name', $.reserved_identifier),
+      repeat(seq(',', field('name', $.reserved_identifier))),
+    ),
+
+    // messageType = [ "." ] { ident "." } messageName
+    message_or_enum_type: $ => seq(
+      optional('.'),
+      repeat(seq(
+        $.identifier,
+        '.',
+      )),
+      $.identifier,
+    ),
+
+    // fieldNumber = intLit;
+    field_number: $ => $.int_lit,
+
+    // service = "service" serviceName "{" { option | rpc | emptyStatement } "}"
+    // rpc = "rpc" rpcName "(" [ "stream" ] messageType ")" "returns" "(" [ "stream" ]
+    //          messageType ")" (( "{" {option | emptyStatement } "}" ) | ";")
+    service: $ => seq(
+      'service',
+      field('name', $.service_name),
+      '{',
+      repeat(field('item', choice(
+        $.option,
+        $.rpc,
+        $.empty_statement,
+      ))),
+      '}',
+    ),
+
+    service_name: $ => $.identifier,
+
+    rpc: $ => seq(
+      'rpc',
+      field('name', $.rpc_name),
+      '(',
+      optional(field('request_stream', $.stream)),
+      field('request', $.message_or_enum_type),
+      ')',
+      'returns',
+      '(',
+      optional(field('response_stream', $.stream)),
+      field('response', $.message_or_enum_type),
+      ')',
+      choice(
+        seq(
+          '{',
+          repeat(field('item', choice(
+            $.option,
+            $.empty_statement,
+          ))),
+          '}',
+        ),
+        ';',
+      ),
+    ),
+
+    stream: _ => 'stream',
+
+    rpc_name: $ => $.identifier,
+
+    // constant = fullIdent | ( [ "-" | "+" ] intLit ) | ( [ "-" | "+" ] floatLit ) | strLit | boolLit
+    constant: $ => choice(
+      $.full_ident,
+      seq(
+        optional(choice('-', '+')),
+        $.int_lit,
+      ),
+      seq(
+        optional(choice('-', '+')),
+        $.float_lit,
+      ),
+      $.string,
+      $.bool,
+
+      // block_lit is not specified but is used in the real world
+      // (i.e. grpc-gateway) so we define it
+      $.block_lit,
+    ),
+
+    // block_lit is completely unspecified. I determined what is allowed
+    // based on the "a bit of everything" grpc-gateway example which has
+    // wildly inconsistent syntax and yet it actually parses and compiles
+    // with protoc.
+    block_lit: $ => seq(
+      '{',
+      repeat(seq(
+        choice(
+          $.identifier,
+          seq('[', $.full_ident, ']'),
+        ),
+        optional(':'),
+        choice(
+          $.constant,
+          array_of($.constant),
+        ),
+        optional(choice(',', ';')),
+      )),
+      '}',
+    ),
+
+    // identifier = letter { letter | decimalDigit | "_" }
+    identifier: $ => choice($._identifier, prec(1, $.grit_metavariable)),
+
+    _identifier: _ => token(seq(
+      choice(letter, '_'),
+      optional(repeat(choice(
+        letter,
+        decimal_digit,
+        '_',
+      ))),
+    )),
+
+    // reserved_identifier = \" | ' letter { letter | decimalDigit | "_" } ' | \"
+    reserved_identifier: $ => token(
+      choice(
+        seq(
+          '"',
+          letter,
+          optional(repeat(choice(letter, decimal_digit, '_'))),
+          '"',
+        ),
+        seq(
+          '\'',
+          letter,
+          optional(repeat(choice(letter, decimal_digit, '_'))),
+          '\'',
+        ),
+        seq(
+          letter,
+          optional(repeat(choice(letter, decimal_digit, '_'))),
+        ),
+      ),
+    ),
+    _identifier_or_string: $ => choice($.identifier, $.string),
+
+    // fullIdent = ident { "." ident }
+    full_ident: $ => seq(
+      $.identifier,
+      optional(repeat(seq('.', $.identifier))),
+    ),
+
+    // boolLit = "true" | "false"
+    bool: $ => choice($.true, $.false),
+    true: $ => 'true',
+    false: $ => 'false',
+
+    // intLit     = decimalLit | octalLit | hexLit
+    int_lit: $ => choice(
+      $.decimal_lit,
+      $.octal_lit,
+      $.hex_lit,
+      $.grit_metavariable,
+    ),
+
+    // decimalLit = ( "1" … "9" ) { decimalDigit }
+    decimal_lit: $ => token(seq(
+      /[1-9]/,
+      repeat(decimal_digit),
+    )),
+
+    // octalLit   = "0" { octalDigit }
+    octal_lit: $ => token(seq(
+      '0',
+      repeat(octal_digit),
+    )),
+
+    // hexLit     = "0" ( "x" | "X" ) hexDigit { hexDigit }
+    hex_lit: $ => token(seq(
+      '0',
+      choice('x', 'X'),
+      hex_digit,
+      repeat(hex_digit),
+    )),
+
+    // floatLit = ( decimals "." [ decimals ] [ exponent ] | decimals exponent | "."decimals [ exponent ] ) | "inf" | "nan"
+    // decimals  = decimalDigit { decimalDigit }
+    // exponent  = ( "e" | "E" ) [ "+" | "-" ] decimals
+    float_lit: $ => {
+      const decimals = seq(
+        decimal_digit,
+        repeat(decimal_digit),
+      );
+
+      const exponent = seq(
+        choice('e', 'E'),
+        optional(choice('+', '-')),
+        decimals,
+      );
+
+      return token(choice(
+        seq(
+          decimals,
+          '.',
+          optional(decimals),
+          optional(exponent),
+        ),
+        seq(
+          decimals,
+          exponent,
+        ),
+        seq(
+          '.',
+          decimals,
+          optional(exponent),
+        ),
+        'inf',
+        'nan',
+      ));
+    },
+
+    string: $ => choice(repeat1(
+      choice(
+        seq(
+          '"',
+          repeat(choice(
+            token.immediate(prec(1, /[^"\\]+/)),
+            $.escape_sequence,
+          )),
+          '"',
+        ),
+
+        seq(
+          '\'',
+          repeat(choice(
+            token.immediate(prec(1, /[^'\\]+/)),
+            $.escape_sequence,
+          )),
+          '\'',
+        ),
+      ),
+    ), $.grit_metavariable),
+
+    escape_sequence: $ => token.immediate(seq(
+      '\\',
+      choice(
+        /[^xuU]/,
+        /\d{2,3}/,
+        /x[0-9a-fA-F]{2,}/,
+        /u[0-9a-fA-F]{4}/,
+        /U[0-9a-fA-F]{8}/,
+      ),
+    )),
+
+    grit_metavariable: _ => token(prec(100, choice('µ...', /µ[a-zA-Z_][a-zA-Z0-9_]*/))),
+
+    comment: $ => token(choice(
+      seq('//', /.*/),
+      seq(
+        '/*',
+        /[^*]*\*+([^/*][^*]*\*+)*/,
+        '/',
+      ),
+    )),
+  },
+});
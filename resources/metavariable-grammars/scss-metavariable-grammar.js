@@ -0,0 +1,372 @@
+/**
+ * @file SCSS grammar for tree-sitter
+ * @author Amaan Qureshi <amaanq12@gmail.com>
+ * @license MIT
+ */
+
+/// <reference types="tree-sitter-cli/dsl" />
+// @ts-check
+
+// Extends the CSS metavariable grammar rather than upstream tree-sitter-css, so
+// that selectors, declarations and values keep the same node kinds and fields
+// as in CSS and CSS snippets can be reused against SCSS files.
+const CSS = require('../tree-sitter-css/grammar');
+
+module.exports = grammar(CSS, {
+  name: 'scss',
+
+  externals: ($, original) => original.concat([
+    $._pseudo_class_selector_colon,
+    $.__error_recovery,
+    $._concat,
+  ]),
+
+  rules: {
+    _top_level_item: ($, original) => choice(
+      original,
+      $.use_statement,
+      $.forward_statement,
+      $.mixin_statement,
+      $.include_statement,
+      $.function_statement,
+      $.return_statement,
+      $.extend_statement,
+      $.error_statement,
+      $.warn_statement,
+      $.debug_statement,
+      $.at_root_statement,
+      $.if_statement,
+      $.each_statement,
+      $.for_statement,
+      $.while_statement,
+    ),
+
+    _block_item: ($, original) => choice(
+      original,
+      $.mixin_statement,
+      $.include_statement,
+      $.function_statement,
+      $.return_statement,
+      $.extend_statement,
+      $.error_statement,
+      $.warn_statement,
+      $.debug_statement,
+      $.at_root_statement,
+      $.if_statement,
+      $.each_statement,
+      $.for_statement,
+      $.while_statement,
+    ),
+
+    // Selectors
+
+    _selector: ($, original) => choice(
+      original,
+      alias($._concatenated_identifier, $.tag_name),
+      $.placeholder,
+    ),
+
+    class_selector: $ => prec(1, seq(
+      optional(field('selector', $._selector)),
+      choice('.', $.nesting_selector),
+      field('class', alias(choice($.identifier, $._concatenated_identifier), $.class_name)),
+    )),
+
+    // Declarations
+
+    declaration: $ => seq(
+      field('name', alias(
+        choice($.identifier, $.variable, $._concatenated_identifier),
+        $.property_name,
+      )),
+      ':',
+      field('values', $._value),
+      repeat(seq(optional(','), field('values', $._value))),
+      optional(field('important', $.important)),
+      optional(field('flag', $.default_flag)),
+      ';',
+    ),
+
+    last_declaration: $ => prec(1, seq(
+      field('name', alias(
+        choice($.identifier, $.variable, $._concatenated_identifier),
+        $.property_name,
+      )),
+      ':',
+      field('values', $._value),
+      repeat(seq(optional(','), field('values', $._value))),
+      optional(field('important', $.important)),
+      optional(field('flag', $.default_flag)),
+    )),
+
+    default_flag: _ => choice('!default', '!global'),
+
+    // Media queries
+
+    _query: ($, original) => choice(
+      original,
+      prec(-1, $.interpolation),
+    ),
+
+    // Property Values
+
+    _value: ($, original) => choice(
+      original,
+      prec(-1, choice(
+        $.nesting_selector,
+        $._concatenated_identifier,
+        $.list_value,
+        $.map_value,
+      )),
+      $.variable,
+    ),
+
+    // Statements
+
+    use_statement: $ => seq(
+      '@use',
+      field('module', $._value),
+      optional(seq('as', field('namespace', choice($._name, alias('*', $.wildcard))))),
+      optional(seq('with', field('configuration', $.map_value))),
+      ';',
+    ),
+
+    forward_statement: $ => seq(
+      '@forward',
+      field('module', $._value),
+      optional(seq('as', field('prefix', $.identifier), token.immediate('*'))),
+      optional(seq(
+        field('visibility', choice(alias('show', $.show), alias('hide', $.hide))),
+        sep1(',', field('member', choice($.identifier, $.variable))),
+      )),
+      optional(seq('with', field('configuration', $.map_value))),
+      ';',
+    ),
+
+    mixin_statement: $ => seq(
+      '@mixin',
+      field('name', $._name),
+      optional(field('parameters', $.parameters)),
+      field('body', $.block),
+    ),
+
+    include_statement: $ => seq(
+      '@include',
+      field('name', $._name),
+      optional(field('arguments', alias($._include_arguments, $.arguments))),
+      choice(field('body', $.block), ';'),
+    ),
+
+    _include_arguments: $ => seq(
+      token.immediate('('),
+      sep(',', field('argument', choice(
+        alias($._include_argument, $.argument),
+        prec(1, $.grit_metavariable),
+      ))),
+      ')',
+    ),
+
+    _include_argument: $ => seq(
+      optional(seq(field('name', $.variable), ':')),
+      field('value', $._value),
+    ),
+
+    function_statement: $ => seq(
+      '@function',
+      field('name', $._name),
+      optional(field('parameters', $.parameters)),
+      field('body', $.block),
+    ),
+
+    parameters: $ => seq('(', sep(',', field('parameter', choice($.parameter, $.grit_metavariable))), ')'),
+
+    parameter: $ => seq(
+      field('name', $.variable),
+      optional(seq(':', field('default', $._value))),
+    ),
+
+    return_statement: $ => seq('@return', field('value', $._value), ';'),
+
+    extend_statement: $ => seq(
+      '@extend',
+      field('selector', choice($._value, $.class_selector, $.placeholder)),
+      optional(field('flag', alias('!optional', $.optional_flag))),
+      ';',
+    ),
+
+    error_statement: $ => seq('@error', field('value', $._value), ';'),
+
+    warn_statement: $ => seq('@warn', field('value', $._value), ';'),
+
+    debug_statement: $ => seq('@debug', field('value', $._value), ';'),
+
+    at_root_statement: $ => seq('@at-root', optional(field('selector', $._value)), field('body', $.block)),
+
+    if_statement: $ => seq(
+      '@if',
+      field('condition', $._value),
+      field('consequence', $.block),
+      repeat(field('alternative', $.else_if_clause)),
+      optional(field('alternative', $.else_clause)),
+    ),
+
+    else_if_clause: $ => seq(
+      '@else',
+      'if',
+      field('condition', $._value),
+      field('consequence', $.block),
+    ),
+
+    else_clause: $ => seq('@else', field('body', $.block)),
+
+    each_statement: $ => seq(
+      '@each',
+      optional(seq(field('key', $.variable), ',')),
+      field('value', $.variable),
+      'in',
+      field('collection', $._value),
+      field('body', $.block),
+    ),
+
+    for_statement: $ => seq(
+      '@for',
+      field('variable', $.variable),
+      'from',
+      field('from', $._value),
+      choice('through', 'to'),
+      field('through', $._value),
+      field('body', $.block),
+    ),
+
+    while_statement: $ => seq('@while', field('condition', $._value), field('body', $.block)),
+
+    call_expression: $ => seq(
+      field('name', alias(choice($.identifier, $.plain_value, $.module_member), $.function_name)),
+      field('arguments', $.arguments),
+    ),
+
+    binary_expression: $ => prec.left(seq(
+      field('left', $._value),
+      field('operator', choice(
+        $.plus,
+        $.minus,
+        $.times,
+        $.divide,
+        alias('==', $.equal),
+        alias('!=', $.not_equal),
+        alias('<', $.less_than),
+        alias('>', $.greater_than),
+        alias('<=', $.less_than_or_equal),
+        alias('>=', $.greater_than_or_equal),
+        alias('and', $.and),
+        alias('or', $.or),
+      )),
+      field('right', $._value),
+    )),
+
+    list_value: $ => seq(
+      '(',
+      sep2(',', field('values', $._value)),
+      optional(','),
+      ')',
+    ),
+
+    map_value: $ => seq(
+      '(',
+      sep1(',', field('pair', $.map_pair)),
+      optional(','),
+      ')',
+    ),
+
+    map_pair: $ => seq(field('key', $._value), ':', field('value', $._value)),
+
+    interpolation: $ => seq('#{', field('value', $._value), '}'),
+
+    placeholder: $ => seq('%', field('name', $._name)),
+
+    _name: $ => choice($.identifier, $.grit_metavariable),
+
+    // `math.div` in `math.div(10px, 2)`
+    module_member: _ => /[a-zA-Z_][a-zA-Z0-9_-]*\.[a-zA-Z_][a-zA-Z0-9_-]*/,
+
+    _concatenated_identifier: $ => choice(
+      seq(
+        $.identifier,
+        repeat1(seq(
+          $._concat,
+          choice($.interpolation, $.identifier, alias(token.immediate('-'), $.identifier)),
+        )),
+      ),
+      seq(
+        $.interpolation,
+        repeat(seq(
+          $._concat,
+          choice($.interpolation, $.identifier, alias(token.immediate('-'), $.identifier)),
+        )),
+      ),
+    ),
+
+    // Unlike CSS, a plain value can't end with a colon, so that map keys such
+    // as `small: 576px` aren't lexed as a single value.
+    plain_value: _ => token(seq(
+      repeat(choice(
+        /[-_]/,
+        /\/[^\*\s,;!{}()\[\]]/,
+      )),
+      /[a-zA-Z]/,
+      repeat(choice(
+        /[^/\s,;!{}()\[\]:]/,
+        /:[^\s,;!{}()\[\]:]/,
+        /\/[^\*\s,;!{}()\[\]]/,
+      )),
+    )),
+
+    variable: _ => /([a-zA-Z_]+\.)?\$[a-zA-Z-_][a-zA-Z0-9-_]*/,
+
+    comment: _ => token(choice(
+      seq('//', /.*/),
+      seq('/*', /[^*]*\*+([^/*][^*]*\*+)*/, '/'),
+    )),
+  },
+});
+
+/**
+ * Creates a rule to optionally match one or more of the rules separated by `separator`
+ *
+ * @param {RuleOrLiteral} separator
+ *
+ * @param {RuleOrLiteral} rule
+ *
+ * @return {ChoiceRule}
+ *
+ */
+function sep(separator, rule) {
+  return optional(sep1(separator, rule));
+}
+
+/**
+ * Creates a rule to match one or more of the rules separated by `separator`
+ *
+ * @param {RuleOrLiteral} separator
+ *
+ * @param {RuleOrLiteral} rule
+ *
+ * @return {SeqRule}
+ *
+ */
+function sep1(separator, rule) {
+  return seq(rule, repeat(seq(separator, rule)));
+}
+
+/**
+ * Creates a rule to match two or more of the rules separated by `separator`
+ *
+ * @param {RuleOrLiteral} separator
+ *
+ * @param {RuleOrLiteral} rules
+ *
+ * @return {SeqRule}
+ */
+function sep2(separator, rules) {
+  return seq(rules, repeat1(seq(separator, rules)));
+}